@@ -0,0 +1,60 @@
+//! Pure domain work on the hottest endpoints: urgency for every listed
+//! product and pantry ranking before each suggestion prompt.
+//!
+//! Run with `cargo bench -p business`.
+
+use chrono::Utc;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+use business::domain::product::fixtures::sample_pantry;
+use business::domain::product::urgency::{ExpiringSoonWindow, get_urgency_level};
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::prioritized_pantry::{PantryPromptLimits, prioritize_pantry};
+
+const SEED: u64 = 42;
+
+fn urgency(c: &mut Criterion) {
+    let user_id = UserId::new("bench-user");
+    let mut group = c.benchmark_group("urgency");
+    for size in [100, 1_000, 10_000] {
+        let products = sample_pantry(&user_id, size, SEED, Utc::now());
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &products,
+            |b, products| {
+                b.iter(|| {
+                    for product in products {
+                        black_box(get_urgency_level(product, ExpiringSoonWindow::default()));
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn prioritize(c: &mut Criterion) {
+    let user_id = UserId::new("bench-user");
+    let limits = PantryPromptLimits::default();
+    let mut group = c.benchmark_group("prioritize_pantry");
+    for size in [50, 500, 5_000] {
+        let products = sample_pantry(&user_id, size, SEED, Utc::now());
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &products,
+            |b, products| {
+                b.iter(|| {
+                    prioritize_pantry(
+                        black_box(products.clone()),
+                        &limits,
+                        ExpiringSoonWindow::default(),
+                    )
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, urgency, prioritize);
+criterion_main!(benches);
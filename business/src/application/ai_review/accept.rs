@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::ai_review::use_cases::accept::{AcceptAiChangeParams, AcceptAiChangeUseCase};
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::product::events::ProductUpdated;
+use crate::domain::product::model::Product;
+use crate::domain::product::repository::ProductRepository;
+
+pub struct AcceptAiChangeUseCaseImpl {
+    pub repository: Arc<dyn AiReviewRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl AcceptAiChangeUseCase for AcceptAiChangeUseCaseImpl {
+    async fn execute(&self, params: AcceptAiChangeParams) -> Result<Product, AiReviewError> {
+        self.logger
+            .info(&format!("Accepting AI change: {}", params.id));
+
+        let not_found = |e| match e {
+            RepositoryError::NotFound => AiReviewError::NotFound,
+            other => AiReviewError::Repository(other),
+        };
+
+        let pending = self
+            .repository
+            .get_pending_by_id(params.id, &params.user_id)
+            .await
+            .map_err(not_found)?;
+        let mut product = self
+            .product_repository
+            .get_by_id(pending.product_id, &params.user_id)
+            .await
+            .map_err(not_found)?;
+
+        pending.change.apply_to(&mut product);
+        self.product_repository
+            .update(&product)
+            .await
+            .map_err(not_found)?;
+        self.event_publisher
+            .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                product_id: product.id,
+                user_id: params.user_id.clone(),
+            }))
+            .await;
+        self.repository
+            .delete_pending(pending.id, &params.user_id)
+            .await?;
+
+        self.logger.info(&format!(
+            "AI change {} applied to product {}",
+            pending.id, product.id
+        ));
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(id: Uuid) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            "Leche".to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn pending_expiry(product_id: Uuid, date: DateTime<Utc>) -> PendingAiChange {
+        PendingAiChange::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            product_id,
+            AiChange::EstimatedExpiryDate(date),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_apply_change_and_remove_it() {
+        let product_id = Uuid::new_v4();
+        let in_a_week = Utc::now() + Duration::days(7);
+        let pending = pending_expiry(product_id, in_a_week);
+        let pending_id = pending.id;
+
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo
+            .expect_get_pending_by_id()
+            .returning(move |_, _| Ok(pending.clone()));
+        mock_repo
+            .expect_delete_pending()
+            .withf(move |id, _| *id == pending_id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_get_by_id()
+            .returning(|id, _| Ok(make_product(id)));
+        mock_products
+            .expect_update()
+            .withf(move |p| p.estimated_expiry_date == Some(in_a_week))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = AcceptAiChangeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(AcceptAiChangeParams {
+                id: pending_id,
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.estimated_expiry_date, Some(in_a_week));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_change_does_not_exist() {
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo
+            .expect_get_pending_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = AcceptAiChangeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(AcceptAiChangeParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AiReviewError::NotFound)));
+    }
+}
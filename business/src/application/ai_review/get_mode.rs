@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::AiWriteMode;
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::ai_review::use_cases::get_mode::{GetAiWriteModeParams, GetAiWriteModeUseCase};
+use crate::domain::logger::Logger;
+
+pub struct GetAiWriteModeUseCaseImpl {
+    pub repository: Arc<dyn AiReviewRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetAiWriteModeUseCase for GetAiWriteModeUseCaseImpl {
+    async fn execute(&self, params: GetAiWriteModeParams) -> Result<AiWriteMode, AiReviewError> {
+        self.logger.info("Getting AI write mode");
+        Ok(self.repository.get_mode(&params.user_id).await?)
+    }
+}
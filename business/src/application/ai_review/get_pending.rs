@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::PendingProductChanges;
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::ai_review::use_cases::get_pending::{
+    GetPendingAiChangesParams, GetPendingAiChangesUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+
+pub struct GetPendingAiChangesUseCaseImpl {
+    pub repository: Arc<dyn AiReviewRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetPendingAiChangesUseCase for GetPendingAiChangesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetPendingAiChangesParams,
+    ) -> Result<Vec<PendingProductChanges>, AiReviewError> {
+        self.logger.info("Getting pending AI changes");
+
+        let pending = self.repository.get_pending(&params.user_id).await?;
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+        let products = self
+            .product_repository
+            .find(&ProductQuery::all(params.user_id.clone()))
+            .await?;
+
+        let mut grouped: Vec<PendingProductChanges> = Vec::new();
+        for change in pending {
+            if let Some(group) = grouped
+                .iter_mut()
+                .find(|g| g.product.id == change.product_id)
+            {
+                group.changes.push(change);
+            } else if let Some(product) = products.iter().find(|p| p.id == change.product_id) {
+                grouped.push(PendingProductChanges {
+                    product: product.clone(),
+                    changes: vec![change],
+                });
+            }
+        }
+
+        self.logger.info(&format!(
+            "Retrieved pending AI changes for {} products",
+            grouped.len()
+        ));
+        Ok(grouped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(id: Uuid) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            "Leche".to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn pending_expiry(product_id: Uuid, date: DateTime<Utc>) -> PendingAiChange {
+        PendingAiChange::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            product_id,
+            AiChange::EstimatedExpiryDate(date),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_group_pending_changes_by_product() {
+        let (milk_id, eggs_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let in_a_week = Utc::now() + Duration::days(7);
+
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo.expect_get_pending().returning(move |_| {
+            Ok(vec![
+                pending_expiry(milk_id, in_a_week),
+                pending_expiry(eggs_id, in_a_week),
+                pending_expiry(milk_id, in_a_week),
+            ])
+        });
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_find()
+            .returning(move |_| Ok(vec![make_product(eggs_id), make_product(milk_id)]));
+
+        let use_case = GetPendingAiChangesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            logger: mock_logger(),
+        };
+
+        let grouped = use_case
+            .execute(GetPendingAiChangesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].product.id, milk_id);
+        assert_eq!(grouped[0].changes.len(), 2);
+        assert_eq!(grouped[1].product.id, eggs_id);
+    }
+
+    #[tokio::test]
+    async fn should_not_load_products_when_nothing_is_pending() {
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo.expect_get_pending().returning(|_| Ok(vec![]));
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().never();
+
+        let use_case = GetPendingAiChangesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            logger: mock_logger(),
+        };
+
+        let grouped = use_case
+            .execute(GetPendingAiChangesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(grouped.is_empty());
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::ai_review::use_cases::reject::{RejectAiChangeParams, RejectAiChangeUseCase};
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+
+pub struct RejectAiChangeUseCaseImpl {
+    pub repository: Arc<dyn AiReviewRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RejectAiChangeUseCase for RejectAiChangeUseCaseImpl {
+    async fn execute(&self, params: RejectAiChangeParams) -> Result<(), AiReviewError> {
+        self.logger
+            .info(&format!("Rejecting AI change: {}", params.id));
+
+        self.repository
+            .delete_pending(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => AiReviewError::NotFound,
+                other => AiReviewError::Repository(other),
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::{AiWriteMode, PendingAiChange};
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_change_does_not_exist() {
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo
+            .expect_delete_pending()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = RejectAiChangeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RejectAiChangeParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(AiReviewError::NotFound)));
+    }
+}
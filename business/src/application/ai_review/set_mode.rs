@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::AiWriteMode;
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::ai_review::use_cases::set_mode::{SetAiWriteModeParams, SetAiWriteModeUseCase};
+use crate::domain::logger::Logger;
+
+pub struct SetAiWriteModeUseCaseImpl {
+    pub repository: Arc<dyn AiReviewRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl SetAiWriteModeUseCase for SetAiWriteModeUseCaseImpl {
+    async fn execute(&self, params: SetAiWriteModeParams) -> Result<AiWriteMode, AiReviewError> {
+        self.logger
+            .info(&format!("Setting AI write mode to {}", params.mode));
+        self.repository
+            .set_mode(&params.user_id, params.mode)
+            .await?;
+        Ok(params.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::PendingAiChange;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_save_chosen_mode() {
+        let mut mock_repo = MockAiReviewRepo::new();
+        mock_repo
+            .expect_set_mode()
+            .with(eq(test_user_id()), eq(AiWriteMode::Review))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = SetAiWriteModeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let mode = use_case
+            .execute(SetAiWriteModeParams {
+                user_id: test_user_id(),
+                mode: AiWriteMode::Review,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(mode, AiWriteMode::Review);
+    }
+}
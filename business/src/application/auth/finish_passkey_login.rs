@@ -0,0 +1,263 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, IssuedToken, hash_challenge};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::services::{PasskeyVerifier, TokenIssuer};
+use crate::domain::auth::use_cases::finish_passkey_login::{
+    FinishPasskeyLoginParams, FinishPasskeyLoginUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct FinishPasskeyLoginUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub verifier: Arc<dyn PasskeyVerifier>,
+    pub tokens: Arc<dyn TokenIssuer>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl FinishPasskeyLoginUseCase for FinishPasskeyLoginUseCaseImpl {
+    async fn execute(&self, params: FinishPasskeyLoginParams) -> Result<IssuedToken, AuthError> {
+        let assertion = params.assertion;
+        let passkey = self
+            .repository
+            .find_passkey(&assertion.credential_id)
+            .await?
+            .ok_or(AuthError::UnknownPasskey)?;
+
+        // The signature is checked before the challenge is used up, so a
+        // forged response cannot burn someone else's sign-in attempt
+        let verified = self
+            .verifier
+            .verify_assertion(&assertion, &passkey.public_key)?;
+        let now = Utc::now();
+        self.repository
+            .take_challenge(&hash_challenge(&verified.challenge))
+            .await?
+            .filter(|challenge| challenge.is_valid_for(ChallengePurpose::PasskeyLogin, now))
+            .ok_or(AuthError::InvalidPasskey)?;
+
+        if !passkey.accepts_sign_count(verified.sign_count) {
+            self.logger.warn(&format!(
+                "Passkey {} reported sign count {} after {}; it may have been cloned",
+                passkey.credential_id, verified.sign_count, passkey.sign_count
+            ));
+            return Err(AuthError::InvalidPasskey);
+        }
+        self.repository
+            .record_passkey_use(&passkey.credential_id, verified.sign_count, now)
+            .await?;
+
+        let token = self.tokens.issue(&passkey.user_id)?;
+        self.logger
+            .info(&format!("Signed in with passkey: {}", passkey.user_id));
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LocalAccount, LoginChallenge, Passkey};
+    use crate::domain::auth::services::{
+        PasskeyAssertion, PasskeyAttestation, VerifiedAssertion, VerifiedRegistration,
+    };
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    mock! {
+        pub Tokens {}
+
+        impl TokenIssuer for Tokens {
+            fn issue(&self, user_id: &UserId) -> Result<IssuedToken, AuthError>;
+        }
+    }
+
+    fn issuing_tokens() -> MockTokens {
+        let mut tokens = MockTokens::new();
+        tokens.expect_issue().returning(|user_id| {
+            Ok(IssuedToken {
+                token: format!("token-for-{user_id}"),
+                user_id: user_id.clone(),
+                expires_at: Utc::now() + Duration::days(30),
+            })
+        });
+        tokens
+    }
+
+    mock! {
+        pub Verifier {}
+
+        impl PasskeyVerifier for Verifier {
+            fn verify_registration(&self, attestation: &PasskeyAttestation) -> Result<VerifiedRegistration, AuthError>;
+            fn verify_assertion(&self, assertion: &PasskeyAssertion, public_key: &[u8]) -> Result<VerifiedAssertion, AuthError>;
+        }
+    }
+
+    fn assertion() -> PasskeyAssertion {
+        PasskeyAssertion {
+            credential_id: "cred-1".to_string(),
+            client_data_json: "client-data".to_string(),
+            authenticator_data: "auth-data".to_string(),
+            signature: "signature".to_string(),
+        }
+    }
+
+    fn stored_passkey(sign_count: u32) -> Passkey {
+        Passkey {
+            credential_id: "cred-1".to_string(),
+            user_id: UserId::new("test-user-id"),
+            public_key: vec![4, 1, 2],
+            sign_count,
+            created_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+
+    fn verifying(sign_count: u32) -> MockVerifier {
+        let mut verifier = MockVerifier::new();
+        verifier
+            .expect_verify_assertion()
+            .withf(|_, public_key| public_key == [4, 1, 2])
+            .returning(move |_, _| {
+                Ok(VerifiedAssertion {
+                    challenge: "challenge".to_string(),
+                    sign_count,
+                })
+            });
+        verifier
+    }
+
+    fn login_challenge() -> LoginChallenge {
+        LoginChallenge::from_repository(
+            hash_challenge("challenge"),
+            ChallengePurpose::PasskeyLogin,
+            None,
+            None,
+            Utc::now() + Duration::minutes(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_issue_token_to_passkey_owner() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_find_passkey()
+            .returning(|_| Ok(Some(stored_passkey(3))));
+        repository
+            .expect_take_challenge()
+            .withf(|hash| hash == hash_challenge("challenge"))
+            .times(1)
+            .returning(|_| Ok(Some(login_challenge())));
+        repository
+            .expect_record_passkey_use()
+            .withf(|credential_id, sign_count, _| credential_id == "cred-1" && *sign_count == 4)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let use_case = FinishPasskeyLoginUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(verifying(4)),
+            tokens: Arc::new(issuing_tokens()),
+            logger: mock_logger(),
+        };
+
+        let token = use_case
+            .execute(FinishPasskeyLoginParams {
+                assertion: assertion(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token.user_id, UserId::new("test-user-id"));
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_passkey_without_touching_challenges() {
+        let mut repository = MockAuthRepo::new();
+        repository.expect_find_passkey().returning(|_| Ok(None));
+        repository.expect_take_challenge().never();
+        let use_case = FinishPasskeyLoginUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(MockVerifier::new()),
+            tokens: Arc::new(MockTokens::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(FinishPasskeyLoginParams {
+                assertion: assertion(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::UnknownPasskey));
+    }
+
+    #[tokio::test]
+    async fn should_reject_sign_count_that_went_backwards() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_find_passkey()
+            .returning(|_| Ok(Some(stored_passkey(7))));
+        repository
+            .expect_take_challenge()
+            .returning(|_| Ok(Some(login_challenge())));
+        repository.expect_record_passkey_use().never();
+        let mut tokens = MockTokens::new();
+        tokens.expect_issue().never();
+        let use_case = FinishPasskeyLoginUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(verifying(2)),
+            tokens: Arc::new(tokens),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(FinishPasskeyLoginParams {
+                assertion: assertion(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::InvalidPasskey));
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, Passkey, hash_challenge};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::services::PasskeyVerifier;
+use crate::domain::auth::use_cases::finish_passkey_registration::{
+    FinishPasskeyRegistrationParams, FinishPasskeyRegistrationUseCase,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+
+pub struct FinishPasskeyRegistrationUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub verifier: Arc<dyn PasskeyVerifier>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl FinishPasskeyRegistrationUseCase for FinishPasskeyRegistrationUseCaseImpl {
+    async fn execute(&self, params: FinishPasskeyRegistrationParams) -> Result<Passkey, AuthError> {
+        self.logger.info("Registering passkey");
+
+        let verified = self.verifier.verify_registration(&params.attestation)?;
+        let now = Utc::now();
+        self.repository
+            .take_challenge(&hash_challenge(&verified.challenge))
+            .await?
+            .filter(|challenge| {
+                challenge.is_valid_for(ChallengePurpose::PasskeyRegistration, now)
+                    && challenge.user_id.as_ref() == Some(&params.user_id)
+            })
+            .ok_or(AuthError::InvalidPasskey)?;
+
+        let passkey = Passkey {
+            credential_id: params.attestation.credential_id,
+            user_id: params.user_id,
+            public_key: verified.public_key,
+            sign_count: verified.sign_count,
+            created_at: now,
+            last_used_at: None,
+        };
+        match self.repository.insert_passkey(&passkey).await {
+            Ok(()) => {}
+            Err(RepositoryError::Duplicated) => return Err(AuthError::InvalidPasskey),
+            Err(e) => return Err(e.into()),
+        }
+
+        self.logger.info(&format!(
+            "Passkey registered for {}: {}",
+            passkey.user_id, passkey.credential_id
+        ));
+        Ok(passkey)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LocalAccount, LoginChallenge};
+    use crate::domain::auth::services::{
+        PasskeyAssertion, PasskeyAttestation, VerifiedAssertion, VerifiedRegistration,
+    };
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    mock! {
+        pub Verifier {}
+
+        impl PasskeyVerifier for Verifier {
+            fn verify_registration(&self, attestation: &PasskeyAttestation) -> Result<VerifiedRegistration, AuthError>;
+            fn verify_assertion(&self, assertion: &PasskeyAssertion, public_key: &[u8]) -> Result<VerifiedAssertion, AuthError>;
+        }
+    }
+
+    fn attestation() -> PasskeyAttestation {
+        PasskeyAttestation {
+            credential_id: "cred-1".to_string(),
+            client_data_json: "client-data".to_string(),
+            attestation_object: "attestation".to_string(),
+        }
+    }
+
+    fn verifying() -> MockVerifier {
+        let mut verifier = MockVerifier::new();
+        verifier.expect_verify_registration().returning(|_| {
+            Ok(VerifiedRegistration {
+                challenge: "challenge".to_string(),
+                public_key: vec![4, 1, 2],
+                sign_count: 0,
+            })
+        });
+        verifier
+    }
+
+    fn registration_challenge(user_id: &str) -> LoginChallenge {
+        LoginChallenge::from_repository(
+            hash_challenge("challenge"),
+            ChallengePurpose::PasskeyRegistration,
+            None,
+            Some(UserId::new(user_id)),
+            Utc::now() + Duration::minutes(5),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_store_verified_passkey_for_user() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_take_challenge()
+            .withf(|hash| hash == hash_challenge("challenge"))
+            .times(1)
+            .returning(|_| Ok(Some(registration_challenge("test-user-id"))));
+        repository
+            .expect_insert_passkey()
+            .withf(|passkey| {
+                passkey.credential_id == "cred-1"
+                    && passkey.user_id == UserId::new("test-user-id")
+                    && passkey.public_key == vec![4, 1, 2]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = FinishPasskeyRegistrationUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(verifying()),
+            logger: mock_logger(),
+        };
+
+        let passkey = use_case
+            .execute(FinishPasskeyRegistrationParams {
+                user_id: UserId::new("test-user-id"),
+                attestation: attestation(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(passkey.sign_count, 0);
+    }
+
+    #[tokio::test]
+    async fn should_reject_challenge_issued_to_another_user() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_take_challenge()
+            .returning(|_| Ok(Some(registration_challenge("other-user-id"))));
+        repository.expect_insert_passkey().never();
+        let use_case = FinishPasskeyRegistrationUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(verifying()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(FinishPasskeyRegistrationParams {
+                user_id: UserId::new("test-user-id"),
+                attestation: attestation(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::InvalidPasskey));
+    }
+}
@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, LoginChallenge, normalize_email};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::services::MagicLinkSender;
+use crate::domain::auth::use_cases::request_magic_link::{
+    RequestMagicLinkParams, RequestMagicLinkUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct RequestMagicLinkUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub sender: Arc<dyn MagicLinkSender>,
+    /// Page of the app that completes the sign-in; the token is appended
+    /// as a `token` query parameter
+    pub link_url: String,
+    pub ttl: Duration,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl RequestMagicLinkUseCaseImpl {
+    fn link(&self, token: &str) -> String {
+        let separator = if self.link_url.contains('?') {
+            '&'
+        } else {
+            '?'
+        };
+        format!("{}{}token={}", self.link_url, separator, token)
+    }
+}
+
+#[async_trait]
+impl RequestMagicLinkUseCase for RequestMagicLinkUseCaseImpl {
+    async fn execute(&self, params: RequestMagicLinkParams) -> Result<(), AuthError> {
+        let email = normalize_email(&params.email)?;
+        self.logger.info("Sending magic sign-in link");
+
+        let (challenge, token) = LoginChallenge::issue(
+            ChallengePurpose::MagicLink,
+            Some(email.clone()),
+            None,
+            self.ttl,
+        );
+        self.repository.save_challenge(&challenge).await?;
+        self.sender.send(&email, &self.link(&token)).await?;
+
+        self.logger.info(&format!(
+            "Magic sign-in link sent (expires at {})",
+            challenge.expires_at
+        ));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LocalAccount, Passkey, hash_challenge};
+    use crate::domain::errors::RepositoryError;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+    use std::sync::Mutex;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    mock! {
+        pub Sender {}
+
+        #[async_trait]
+        impl MagicLinkSender for Sender {
+            async fn send(&self, email: &str, link: &str) -> Result<(), AuthError>;
+        }
+    }
+
+    fn use_case(repository: MockAuthRepo, sender: MockSender) -> RequestMagicLinkUseCaseImpl {
+        RequestMagicLinkUseCaseImpl {
+            repository: Arc::new(repository),
+            sender: Arc::new(sender),
+            link_url: "https://foodie.example.com/sign-in".to_string(),
+            ttl: Duration::minutes(15),
+            logger: mock_logger(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_email_link_whose_token_matches_saved_challenge() {
+        let saved_hash = Arc::new(Mutex::new(String::new()));
+        let mut repository = MockAuthRepo::new();
+        let saved = saved_hash.clone();
+        repository
+            .expect_save_challenge()
+            .withf(|challenge| {
+                challenge.purpose == ChallengePurpose::MagicLink
+                    && challenge.email.as_deref() == Some("ana@example.com")
+            })
+            .times(1)
+            .returning(move |challenge| {
+                *saved.lock().unwrap() = challenge.challenge_hash.clone();
+                Ok(())
+            });
+        let sent_link = Arc::new(Mutex::new(String::new()));
+        let mut sender = MockSender::new();
+        let sent = sent_link.clone();
+        sender
+            .expect_send()
+            .withf(|email, _| email == "ana@example.com")
+            .times(1)
+            .returning(move |_, link| {
+                *sent.lock().unwrap() = link.to_string();
+                Ok(())
+            });
+
+        use_case(repository, sender)
+            .execute(RequestMagicLinkParams {
+                email: " Ana@Example.com".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let link = sent_link.lock().unwrap().clone();
+        let token = link
+            .strip_prefix("https://foodie.example.com/sign-in?token=")
+            .unwrap();
+        assert_eq!(*saved_hash.lock().unwrap(), hash_challenge(token));
+    }
+
+    #[tokio::test]
+    async fn should_reject_malformed_email_without_sending() {
+        let mut repository = MockAuthRepo::new();
+        repository.expect_save_challenge().never();
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let result = use_case(repository, sender)
+            .execute(RequestMagicLinkParams {
+                email: "not-an-email".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::InvalidEmail));
+    }
+}
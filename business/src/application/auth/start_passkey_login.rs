@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, LoginChallenge, PasskeyOptions};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::use_cases::start_passkey_login::StartPasskeyLoginUseCase;
+use crate::domain::logger::Logger;
+
+pub struct StartPasskeyLoginUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub rp_id: String,
+    pub ttl: Duration,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl StartPasskeyLoginUseCase for StartPasskeyLoginUseCaseImpl {
+    async fn execute(&self) -> Result<PasskeyOptions, AuthError> {
+        self.logger.debug("Starting passkey sign-in");
+
+        // Not bound to a user: the passkey picked by the user tells who it is
+        let (challenge, secret) =
+            LoginChallenge::issue(ChallengePurpose::PasskeyLogin, None, None, self.ttl);
+        self.repository.save_challenge(&challenge).await?;
+
+        Ok(PasskeyOptions {
+            challenge: secret,
+            rp_id: self.rp_id.clone(),
+            user_id: None,
+            expires_at: challenge.expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LocalAccount, Passkey, hash_challenge};
+    use crate::domain::errors::RepositoryError;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+    use std::sync::Mutex;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_save_hash_of_returned_challenge() {
+        let saved_hash = Arc::new(Mutex::new(String::new()));
+        let saved = saved_hash.clone();
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_save_challenge()
+            .withf(|challenge| {
+                challenge.purpose == ChallengePurpose::PasskeyLogin && challenge.user_id.is_none()
+            })
+            .times(1)
+            .returning(move |challenge| {
+                *saved.lock().unwrap() = challenge.challenge_hash.clone();
+                Ok(())
+            });
+        let use_case = StartPasskeyLoginUseCaseImpl {
+            repository: Arc::new(repository),
+            rp_id: "foodie.example.com".to_string(),
+            ttl: Duration::minutes(15),
+            logger: mock_logger(),
+        };
+
+        let options = use_case.execute().await.unwrap();
+
+        assert_eq!(
+            *saved_hash.lock().unwrap(),
+            hash_challenge(&options.challenge)
+        );
+        assert!(options.user_id.is_none());
+    }
+}
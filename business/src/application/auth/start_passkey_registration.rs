@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Duration;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, LoginChallenge, PasskeyOptions};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::use_cases::start_passkey_registration::{
+    StartPasskeyRegistrationParams, StartPasskeyRegistrationUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct StartPasskeyRegistrationUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub rp_id: String,
+    pub ttl: Duration,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl StartPasskeyRegistrationUseCase for StartPasskeyRegistrationUseCaseImpl {
+    async fn execute(
+        &self,
+        params: StartPasskeyRegistrationParams,
+    ) -> Result<PasskeyOptions, AuthError> {
+        self.logger.info("Starting passkey registration");
+
+        let (challenge, secret) = LoginChallenge::issue(
+            ChallengePurpose::PasskeyRegistration,
+            None,
+            Some(params.user_id.clone()),
+            self.ttl,
+        );
+        self.repository.save_challenge(&challenge).await?;
+
+        Ok(PasskeyOptions {
+            challenge: secret,
+            rp_id: self.rp_id.clone(),
+            user_id: Some(params.user_id),
+            expires_at: challenge.expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LocalAccount, Passkey, hash_challenge};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_bind_challenge_to_user() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_save_challenge()
+            .withf(|challenge| {
+                challenge.purpose == ChallengePurpose::PasskeyRegistration
+                    && challenge.user_id == Some(UserId::new("test-user-id"))
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = StartPasskeyRegistrationUseCaseImpl {
+            repository: Arc::new(repository),
+            rp_id: "foodie.example.com".to_string(),
+            ttl: Duration::minutes(15),
+            logger: mock_logger(),
+        };
+
+        let options = use_case
+            .execute(StartPasskeyRegistrationParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(options.rp_id, "foodie.example.com");
+        assert_eq!(options.user_id, Some(UserId::new("test-user-id")));
+        assert_ne!(hash_challenge(&options.challenge), options.challenge);
+    }
+}
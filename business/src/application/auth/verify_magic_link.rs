@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::{ChallengePurpose, IssuedToken, LocalAccount, hash_challenge};
+use crate::domain::auth::repository::AuthRepository;
+use crate::domain::auth::services::TokenIssuer;
+use crate::domain::auth::use_cases::verify_magic_link::{
+    VerifyMagicLinkParams, VerifyMagicLinkUseCase,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+
+pub struct VerifyMagicLinkUseCaseImpl {
+    pub repository: Arc<dyn AuthRepository>,
+    pub tokens: Arc<dyn TokenIssuer>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl VerifyMagicLinkUseCaseImpl {
+    /// The account of `email`, created on its first sign-in.
+    async fn account(&self, email: String) -> Result<LocalAccount, AuthError> {
+        if let Some(account) = self.repository.find_account_by_email(&email).await? {
+            return Ok(account);
+        }
+
+        let account = LocalAccount::new(email);
+        match self.repository.insert_account(&account).await {
+            Ok(()) => {
+                self.logger
+                    .info(&format!("Local account created: {}", account.user_id));
+                Ok(account)
+            }
+            // Two links for the same address opened at once
+            Err(RepositoryError::Duplicated) => self
+                .repository
+                .find_account_by_email(&account.email)
+                .await?
+                .ok_or(AuthError::Repository(RepositoryError::NotFound)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl VerifyMagicLinkUseCase for VerifyMagicLinkUseCaseImpl {
+    async fn execute(&self, params: VerifyMagicLinkParams) -> Result<IssuedToken, AuthError> {
+        let challenge = self
+            .repository
+            .take_challenge(&hash_challenge(&params.token))
+            .await?
+            .filter(|challenge| challenge.is_valid_for(ChallengePurpose::MagicLink, Utc::now()))
+            .ok_or(AuthError::InvalidLink)?;
+        let email = challenge.email.ok_or(AuthError::InvalidLink)?;
+
+        let account = self.account(email).await?;
+        let token = self.tokens.issue(&account.user_id)?;
+
+        self.logger
+            .info(&format!("Signed in with magic link: {}", account.user_id));
+        Ok(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::auth::model::{LoginChallenge, Passkey};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+
+    mock! {
+        pub AuthRepo {}
+
+        #[async_trait]
+        impl AuthRepository for AuthRepo {
+            async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+            async fn take_challenge(&self, challenge_hash: &str) -> Result<Option<LoginChallenge>, RepositoryError>;
+            async fn find_account_by_email(&self, email: &str) -> Result<Option<LocalAccount>, RepositoryError>;
+            async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+            async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+            async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+            async fn record_passkey_use(&self, credential_id: &str, sign_count: u32, used_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    mock! {
+        pub Tokens {}
+
+        impl TokenIssuer for Tokens {
+            fn issue(&self, user_id: &UserId) -> Result<IssuedToken, AuthError>;
+        }
+    }
+
+    fn issuing_tokens() -> MockTokens {
+        let mut tokens = MockTokens::new();
+        tokens.expect_issue().returning(|user_id| {
+            Ok(IssuedToken {
+                token: format!("token-for-{user_id}"),
+                user_id: user_id.clone(),
+                expires_at: Utc::now() + Duration::days(30),
+            })
+        });
+        tokens
+    }
+
+    fn magic_link_challenge(token: &str, expires_at: DateTime<Utc>) -> LoginChallenge {
+        LoginChallenge::from_repository(
+            hash_challenge(token),
+            ChallengePurpose::MagicLink,
+            Some("ana@example.com".to_string()),
+            None,
+            expires_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_create_account_on_first_sign_in() {
+        let mut repository = MockAuthRepo::new();
+        repository
+            .expect_take_challenge()
+            .withf(|hash| hash == hash_challenge("link-token"))
+            .times(1)
+            .returning(|_| {
+                Ok(Some(magic_link_challenge(
+                    "link-token",
+                    Utc::now() + Duration::minutes(5),
+                )))
+            });
+        repository
+            .expect_find_account_by_email()
+            .returning(|_| Ok(None));
+        repository
+            .expect_insert_account()
+            .withf(|account| account.email == "ana@example.com")
+            .times(1)
+            .returning(|_| Ok(()));
+        let use_case = VerifyMagicLinkUseCaseImpl {
+            repository: Arc::new(repository),
+            tokens: Arc::new(issuing_tokens()),
+            logger: mock_logger(),
+        };
+
+        let token = use_case
+            .execute(VerifyMagicLinkParams {
+                token: "link-token".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token.token, format!("token-for-{}", token.user_id));
+    }
+
+    #[tokio::test]
+    async fn should_sign_in_existing_account() {
+        let existing = LocalAccount::from_repository(
+            UserId::new("test-user-id"),
+            "ana@example.com".to_string(),
+            Utc::now(),
+        );
+        let mut repository = MockAuthRepo::new();
+        repository.expect_take_challenge().returning(|_| {
+            Ok(Some(magic_link_challenge(
+                "link-token",
+                Utc::now() + Duration::minutes(5),
+            )))
+        });
+        repository
+            .expect_find_account_by_email()
+            .returning(move |_| Ok(Some(existing.clone())));
+        repository.expect_insert_account().never();
+        let use_case = VerifyMagicLinkUseCaseImpl {
+            repository: Arc::new(repository),
+            tokens: Arc::new(issuing_tokens()),
+            logger: mock_logger(),
+        };
+
+        let token = use_case
+            .execute(VerifyMagicLinkParams {
+                token: "link-token".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(token.user_id, UserId::new("test-user-id"));
+    }
+
+    #[tokio::test]
+    async fn should_reject_expired_link() {
+        let mut repository = MockAuthRepo::new();
+        repository.expect_take_challenge().returning(|_| {
+            Ok(Some(magic_link_challenge(
+                "link-token",
+                Utc::now() - Duration::minutes(1),
+            )))
+        });
+        repository.expect_find_account_by_email().never();
+        let mut tokens = MockTokens::new();
+        tokens.expect_issue().never();
+        let use_case = VerifyMagicLinkUseCaseImpl {
+            repository: Arc::new(repository),
+            tokens: Arc::new(tokens),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(VerifyMagicLinkParams {
+                token: "link-token".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), AuthError::InvalidLink));
+    }
+}
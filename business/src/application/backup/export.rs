@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::model::BackupArchive;
+use crate::domain::backup::repository::BackupRepository;
+use crate::domain::backup::use_cases::export::{ExportBackupParams, ExportBackupUseCase};
+use crate::domain::logger::Logger;
+
+pub struct ExportBackupUseCaseImpl {
+    pub repository: Arc<dyn BackupRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ExportBackupUseCase for ExportBackupUseCaseImpl {
+    async fn execute(&self, params: ExportBackupParams) -> Result<BackupArchive, BackupError> {
+        self.logger
+            .info(&format!("Exporting backup for user: {}", params.user_id));
+
+        let schema_version = self.repository.schema_version().await?;
+        let tables = self.repository.export(&params.user_id).await?;
+        let archive = BackupArchive::new(schema_version, tables);
+
+        self.logger.info(&format!(
+            "Exported {} rows for user {}",
+            archive.row_count(),
+            params.user_id
+        ));
+        Ok(archive)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::backup::model::{BACKUP_FORMAT_VERSION, BackupRows};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use std::collections::BTreeMap;
+
+    mock! {
+        pub BackupRepo {}
+
+        #[async_trait]
+        impl BackupRepository for BackupRepo {
+            async fn schema_version(&self) -> Result<i64, RepositoryError>;
+            async fn export(&self, user_id: &UserId) -> Result<BTreeMap<String, BackupRows>, RepositoryError>;
+            async fn restore(&self, user_id: &UserId, tables: &BTreeMap<String, BackupRows>) -> Result<usize, BackupError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_stamp_exported_rows_with_format_and_schema_version() {
+        let mut repo = MockBackupRepo::new();
+        repo.expect_schema_version()
+            .returning(|| Ok(20260303090000));
+        repo.expect_export()
+            .withf(|user_id| *user_id == test_user_id())
+            .returning(|_| {
+                Ok(BTreeMap::from([(
+                    "products".to_string(),
+                    vec![serde_json::json!({"name": "Leche entera"})],
+                )]))
+            });
+
+        let use_case = ExportBackupUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let archive = use_case
+            .execute(ExportBackupParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(archive.format_version, BACKUP_FORMAT_VERSION);
+        assert_eq!(archive.schema_version, 20260303090000);
+        assert_eq!(archive.row_count(), 1);
+    }
+}
@@ -0,0 +1,166 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::repository::BackupRepository;
+use crate::domain::backup::use_cases::restore::{RestoreBackupParams, RestoreBackupUseCase};
+use crate::domain::logger::Logger;
+
+pub struct RestoreBackupUseCaseImpl {
+    pub repository: Arc<dyn BackupRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RestoreBackupUseCase for RestoreBackupUseCaseImpl {
+    async fn execute(&self, params: RestoreBackupParams) -> Result<usize, BackupError> {
+        self.logger.info(&format!(
+            "Restoring backup from {} for user: {}",
+            params.archive.exported_at, params.user_id
+        ));
+
+        let schema_version = self.repository.schema_version().await?;
+        params.archive.ensure_restorable(schema_version)?;
+
+        // Merging into existing data would clash on per-user settings and
+        // unique rules, so the repository only restores into an account that
+        // has nothing yet.
+        let restored = self
+            .repository
+            .restore(&params.user_id, &params.archive.tables)
+            .await?;
+
+        self.logger.info(&format!(
+            "Restored {} rows for user {}",
+            restored, params.user_id
+        ));
+        Ok(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::backup::model::{BackupArchive, BackupRows};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use std::collections::BTreeMap;
+
+    mock! {
+        pub BackupRepo {}
+
+        #[async_trait]
+        impl BackupRepository for BackupRepo {
+            async fn schema_version(&self) -> Result<i64, RepositoryError>;
+            async fn export(&self, user_id: &UserId) -> Result<BTreeMap<String, BackupRows>, RepositoryError>;
+            async fn restore(&self, user_id: &UserId, tables: &BTreeMap<String, BackupRows>) -> Result<usize, BackupError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn archive() -> BackupArchive {
+        BackupArchive::new(
+            20260303090000,
+            BTreeMap::from([(
+                "products".to_string(),
+                vec![serde_json::json!({"name": "Leche entera"})],
+            )]),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_restore_into_empty_account() {
+        let mut repo = MockBackupRepo::new();
+        repo.expect_schema_version()
+            .returning(|| Ok(20260303090000));
+        repo.expect_restore()
+            .withf(|user_id, tables| *user_id == test_user_id() && tables.contains_key("products"))
+            .times(1)
+            .returning(|_, _| Ok(1));
+
+        let use_case = RestoreBackupUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let restored = use_case
+            .execute(RestoreBackupParams {
+                user_id: test_user_id(),
+                archive: archive(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(restored, 1);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_when_account_has_data() {
+        let mut repo = MockBackupRepo::new();
+        repo.expect_schema_version()
+            .returning(|| Ok(20260303090000));
+        repo.expect_restore()
+            .returning(|_, _| Err(BackupError::AccountNotEmpty));
+
+        let use_case = RestoreBackupUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RestoreBackupParams {
+                user_id: test_user_id(),
+                archive: archive(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(BackupError::AccountNotEmpty)));
+    }
+
+    #[tokio::test]
+    async fn should_refuse_archive_from_newer_schema() {
+        let mut repo = MockBackupRepo::new();
+        repo.expect_schema_version()
+            .returning(|| Ok(20260301090000));
+        repo.expect_restore().never();
+
+        let use_case = RestoreBackupUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RestoreBackupParams {
+                user_id: test_user_id(),
+                archive: archive(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(BackupError::NewerSchema)));
+    }
+}
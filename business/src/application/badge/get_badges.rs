@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::badge::errors::BadgeError;
+use crate::domain::badge::model::{BadgeCounts, BadgeWindow};
+use crate::domain::badge::repository::BadgeRepository;
+use crate::domain::badge::use_cases::get_badges::{GetBadgesParams, GetBadgesUseCase};
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+
+pub struct GetBadgesUseCaseImpl {
+    pub repository: Arc<dyn BadgeRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetBadgesUseCase for GetBadgesUseCaseImpl {
+    async fn execute(&self, params: GetBadgesParams) -> Result<BadgeCounts, BadgeError> {
+        // Polled frequently by widgets, so keep it at debug level
+        self.logger
+            .debug(&format!("Getting badges for user: {}", params.user_id));
+
+        let preferences = self.preference_repository.get(&params.user_id).await?;
+        let window = BadgeWindow::at(Utc::now(), preferences.expiring_soon);
+        Ok(self.repository.get_counts(&params.user_id, &window).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub BadgeRepo {}
+
+        #[async_trait]
+        impl BadgeRepository for BadgeRepo {
+            async fn get_counts(&self, user_id: &UserId, window: &BadgeWindow) -> Result<BadgeCounts, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_counts_for_current_window() {
+        let mut mock_repo = MockBadgeRepo::new();
+        mock_repo
+            .expect_get_counts()
+            .withf(|user_id, window| {
+                user_id.as_str() == "test-user-id"
+                    && window.fresh_since <= window.now
+                    && window.now < window.urgent_until
+            })
+            .returning(|_, _| {
+                Ok(BadgeCounts {
+                    urgent_products: 3,
+                    unbought_items: 5,
+                    new_suggestions: 4,
+                    unread_notifications: 2,
+                })
+            });
+
+        let use_case = GetBadgesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let counts = use_case
+            .execute(GetBadgesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(counts.urgent_products, 3);
+        assert_eq!(counts.unbought_items, 5);
+        assert_eq!(counts.new_suggestions, 4);
+        assert_eq!(counts.unread_notifications, 2);
+    }
+
+    #[tokio::test]
+    async fn should_return_repository_error_when_query_fails() {
+        let mut mock_repo = MockBadgeRepo::new();
+        mock_repo
+            .expect_get_counts()
+            .returning(|_, _| Err(RepositoryError::database_error("connection reset")));
+
+        let use_case = GetBadgesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetBadgesParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(BadgeError::Repository(_))));
+    }
+}
@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::barcode_contribution::errors::BarcodeContributionError;
+use crate::domain::barcode_contribution::model::{BarcodeContribution, UpstreamStatus};
+use crate::domain::barcode_contribution::repository::BarcodeContributionRepository;
+use crate::domain::barcode_contribution::services::ProductDatabaseContributor;
+use crate::domain::barcode_contribution::use_cases::submit::{
+    SubmitBarcodeContributionParams, SubmitBarcodeContributionUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::storage::model::ImageUpload;
+
+/// Saves the name a user gave to an unknown barcode, so their next scan finds
+/// it, and forwards it to Open Food Facts in the background when credentials
+/// are configured.
+pub struct SubmitBarcodeContributionUseCaseImpl {
+    pub repository: Arc<dyn BarcodeContributionRepository>,
+    /// `None` keeps contributions local
+    pub contributor: Option<Arc<dyn ProductDatabaseContributor>>,
+    pub logger: Arc<dyn Logger>,
+}
+
+/// Sends the contribution upstream and records how it went. Failures are
+/// recorded rather than returned: the mapping already works locally.
+async fn forward(
+    contributor: Arc<dyn ProductDatabaseContributor>,
+    repository: Arc<dyn BarcodeContributionRepository>,
+    logger: Arc<dyn Logger>,
+    mut contribution: BarcodeContribution,
+    photo: Option<ImageUpload>,
+) -> BarcodeContribution {
+    match contributor
+        .contribute(&contribution.barcode, &contribution.name, photo)
+        .await
+    {
+        Ok(()) => {
+            logger.info(&format!(
+                "Barcode {} sent to Open Food Facts",
+                contribution.barcode
+            ));
+            contribution.set_upstream_status(UpstreamStatus::Submitted);
+        }
+        Err(e) => {
+            logger.warn(&format!(
+                "Could not send barcode {} to Open Food Facts: {}",
+                contribution.barcode, e
+            ));
+            contribution.set_upstream_status(UpstreamStatus::Failed);
+        }
+    }
+
+    if let Err(e) = repository.save(&contribution).await {
+        logger.error(&format!(
+            "Failed to save upstream status of barcode {}: {}",
+            contribution.barcode, e
+        ));
+    }
+    contribution
+}
+
+#[async_trait]
+impl SubmitBarcodeContributionUseCase for SubmitBarcodeContributionUseCaseImpl {
+    async fn execute(
+        &self,
+        params: SubmitBarcodeContributionParams,
+    ) -> Result<BarcodeContribution, BarcodeContributionError> {
+        let status = match self.contributor {
+            Some(_) => UpstreamStatus::Pending,
+            None => UpstreamStatus::LocalOnly,
+        };
+        let contribution =
+            BarcodeContribution::new(params.user_id, &params.barcode, &params.name, status)?;
+        let photo = params
+            .image_base64
+            .as_deref()
+            .map(|photo| {
+                ImageUpload::from_base64(photo).ok_or(BarcodeContributionError::InvalidImage)
+            })
+            .transpose()?;
+
+        self.repository.save(&contribution).await?;
+        self.logger.info(&format!(
+            "Barcode {} contributed by user {} as {}",
+            contribution.barcode, contribution.user_id, contribution.name
+        ));
+
+        if let Some(contributor) = &self.contributor {
+            tokio::spawn(forward(
+                contributor.clone(),
+                self.repository.clone(),
+                self.logger.clone(),
+                contribution.clone(),
+                photo,
+            ));
+        }
+
+        Ok(contribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub ContributionRepo {}
+
+        #[async_trait]
+        impl BarcodeContributionRepository for ContributionRepo {
+            async fn find(&self, user_id: &UserId, barcode: &str) -> Result<Option<BarcodeContribution>, RepositoryError>;
+            async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Contributor {}
+
+        #[async_trait]
+        impl ProductDatabaseContributor for Contributor {
+            async fn contribute(&self, barcode: &str, name: &str, photo: Option<ImageUpload>) -> Result<(), BarcodeContributionError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn params(image_base64: Option<&str>) -> SubmitBarcodeContributionParams {
+        SubmitBarcodeContributionParams {
+            user_id: UserId::new("test-user-id"),
+            barcode: "8410000000001".to_string(),
+            name: "Turrón blando".to_string(),
+            image_base64: image_base64.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_keep_contribution_local_without_credentials() {
+        let mut repo = MockContributionRepo::new();
+        repo.expect_save()
+            .withf(|c| c.upstream_status == UpstreamStatus::LocalOnly)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = SubmitBarcodeContributionUseCaseImpl {
+            repository: Arc::new(repo),
+            contributor: None,
+            logger: mock_logger(),
+        };
+
+        let contribution = use_case.execute(params(None)).await.unwrap();
+
+        assert_eq!(contribution.upstream_status, UpstreamStatus::LocalOnly);
+        assert_eq!(contribution.name, "Turrón blando");
+    }
+
+    #[tokio::test]
+    async fn should_reject_photo_that_is_not_an_image() {
+        let mut repo = MockContributionRepo::new();
+        repo.expect_save().never();
+
+        let use_case = SubmitBarcodeContributionUseCaseImpl {
+            repository: Arc::new(repo),
+            contributor: Some(Arc::new(MockContributor::new())),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params(Some("not an image"))).await;
+
+        assert!(matches!(
+            result,
+            Err(BarcodeContributionError::InvalidImage)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_record_failed_upstream_write() {
+        let mut contributor = MockContributor::new();
+        contributor.expect_contribute().returning(|_, _, _| {
+            Err(BarcodeContributionError::upstream_failed(
+                "Open Food Facts answered 503",
+            ))
+        });
+        let mut repo = MockContributionRepo::new();
+        repo.expect_save()
+            .withf(|c| c.upstream_status == UpstreamStatus::Failed)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let contribution = BarcodeContribution::new(
+            UserId::new("test-user-id"),
+            "8410000000001",
+            "Turrón blando",
+            UpstreamStatus::Pending,
+        )
+        .unwrap();
+
+        let forwarded = forward(
+            Arc::new(contributor),
+            Arc::new(repo),
+            mock_logger(),
+            contribution,
+            None,
+        )
+        .await;
+
+        assert_eq!(forwarded.upstream_status, UpstreamStatus::Failed);
+    }
+}
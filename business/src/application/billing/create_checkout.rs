@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::billing::errors::BillingError;
+use crate::domain::billing::model::CheckoutSession;
+use crate::domain::billing::services::PlanProvider;
+use crate::domain::billing::use_cases::create_checkout::{
+    CreateCheckoutParams, CreateCheckoutUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct CreateCheckoutUseCaseImpl {
+    pub plan_provider: Arc<dyn PlanProvider>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl CreateCheckoutUseCase for CreateCheckoutUseCaseImpl {
+    async fn execute(&self, params: CreateCheckoutParams) -> Result<CheckoutSession, BillingError> {
+        self.logger.info(&format!(
+            "Creating checkout session for user: {}",
+            params.user_id
+        ));
+
+        let session = self
+            .plan_provider
+            .create_checkout_session(&params.user_id)
+            .await
+            .inspect_err(|e| {
+                self.logger
+                    .error(&format!("Failed to create checkout session: {}", e))
+            })?;
+
+        self.logger
+            .info(&format!("Checkout session created: {}", session.id));
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::billing::model::WebhookEvent;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub Provider {}
+
+        #[async_trait]
+        impl PlanProvider for Provider {
+            async fn create_checkout_session(
+                &self,
+                user_id: &UserId,
+            ) -> Result<CheckoutSession, BillingError>;
+            fn parse_webhook(
+                &self,
+                payload: &[u8],
+                signature: &str,
+            ) -> Result<WebhookEvent, BillingError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_checkout_session_from_provider() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider
+            .expect_create_checkout_session()
+            .withf(|user_id| user_id.as_str() == "test-user-id")
+            .returning(|_| {
+                Ok(CheckoutSession {
+                    id: "cs_test_123".to_string(),
+                    url: "https://checkout.stripe.com/c/pay/cs_test_123".to_string(),
+                })
+            });
+
+        let use_case = CreateCheckoutUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            logger: mock_logger(),
+        };
+
+        let session = use_case
+            .execute(CreateCheckoutParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(session.id, "cs_test_123");
+    }
+
+    #[tokio::test]
+    async fn should_return_checkout_failed_when_provider_fails() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider
+            .expect_create_checkout_session()
+            .returning(|_| Err(BillingError::CheckoutFailed));
+
+        let use_case = CreateCheckoutUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateCheckoutParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), BillingError::CheckoutFailed));
+    }
+}
@@ -0,0 +1,300 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::billing::errors::BillingError;
+use crate::domain::billing::model::{BillingEvent, PlanChangeOutcome};
+use crate::domain::billing::repository::PlanRepository;
+use crate::domain::billing::services::PlanProvider;
+use crate::domain::billing::use_cases::handle_webhook::{
+    HandleWebhookParams, HandleWebhookUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::quota::model::PlanTier;
+
+pub struct HandleWebhookUseCaseImpl {
+    pub plan_provider: Arc<dyn PlanProvider>,
+    pub plan_repository: Arc<dyn PlanRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl HandleWebhookUseCase for HandleWebhookUseCaseImpl {
+    async fn execute(&self, params: HandleWebhookParams) -> Result<(), BillingError> {
+        let event = self
+            .plan_provider
+            .parse_webhook(&params.payload, &params.signature)
+            .inspect_err(|e| {
+                self.logger
+                    .warn(&format!("Rejected billing webhook: {}", e))
+            })?;
+
+        let (user_id, plan, customer_id) = match &event.change {
+            BillingEvent::SubscriptionActivated {
+                user_id,
+                customer_id,
+            } => {
+                self.logger
+                    .info(&format!("Activating premium plan for user: {}", user_id));
+                (user_id, PlanTier::Premium, customer_id.clone())
+            }
+            BillingEvent::SubscriptionCanceled { user_id } => {
+                self.logger
+                    .info(&format!("Downgrading user to free plan: {}", user_id));
+                (user_id, PlanTier::Free, None)
+            }
+            BillingEvent::Ignored => {
+                self.logger.debug("Ignoring billing webhook event");
+                return Ok(());
+            }
+        };
+
+        // Retries and out-of-order deliveries are answered 200 so the
+        // provider stops sending them
+        match self
+            .plan_repository
+            .set_plan(user_id, plan, customer_id, &event)
+            .await?
+        {
+            PlanChangeOutcome::Applied => {}
+            PlanChangeOutcome::Duplicate => self.logger.info(&format!(
+                "Skipping billing event {}: already processed",
+                event.id
+            )),
+            PlanChangeOutcome::Stale => self.logger.info(&format!(
+                "Skipping billing event {}: a newer event set the plan of user {}",
+                event.id, user_id
+            )),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::billing::model::{CheckoutSession, WebhookEvent};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub Provider {}
+
+        #[async_trait]
+        impl PlanProvider for Provider {
+            async fn create_checkout_session(
+                &self,
+                user_id: &UserId,
+            ) -> Result<CheckoutSession, BillingError>;
+            fn parse_webhook(
+                &self,
+                payload: &[u8],
+                signature: &str,
+            ) -> Result<WebhookEvent, BillingError>;
+        }
+    }
+
+    mock! {
+        pub PlanRepo {}
+
+        #[async_trait]
+        impl PlanRepository for PlanRepo {
+            async fn set_plan(
+                &self,
+                user_id: &UserId,
+                plan: PlanTier,
+                customer_id: Option<String>,
+                event: &WebhookEvent,
+            ) -> Result<PlanChangeOutcome, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn webhook_event(change: BillingEvent) -> WebhookEvent {
+        WebhookEvent {
+            id: "evt_123".to_string(),
+            created: chrono::DateTime::from_timestamp(1_700_000_000, 0).unwrap(),
+            change,
+        }
+    }
+
+    fn webhook_params() -> HandleWebhookParams {
+        HandleWebhookParams {
+            payload: br#"{"type":"checkout.session.completed"}"#.to_vec(),
+            signature: "t=1700000000,v1=abc".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_activate_premium_when_subscription_activated() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider.expect_parse_webhook().returning(|_, _| {
+            Ok(webhook_event(BillingEvent::SubscriptionActivated {
+                user_id: test_user_id(),
+                customer_id: Some("cus_123".to_string()),
+            }))
+        });
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo
+            .expect_set_plan()
+            .withf(|user_id, plan, customer_id, event| {
+                user_id.as_str() == "test-user-id"
+                    && *plan == PlanTier::Premium
+                    && customer_id.as_deref() == Some("cus_123")
+                    && event.id == "evt_123"
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(PlanChangeOutcome::Applied));
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        assert!(use_case.execute(webhook_params()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_downgrade_to_free_when_subscription_canceled() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider.expect_parse_webhook().returning(|_, _| {
+            Ok(webhook_event(BillingEvent::SubscriptionCanceled {
+                user_id: test_user_id(),
+            }))
+        });
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo
+            .expect_set_plan()
+            .withf(|_, plan, customer_id, _| *plan == PlanTier::Free && customer_id.is_none())
+            .times(1)
+            .returning(|_, _, _, _| Ok(PlanChangeOutcome::Applied));
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        assert!(use_case.execute(webhook_params()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_not_touch_plan_when_event_ignored() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider
+            .expect_parse_webhook()
+            .returning(|_, _| Ok(webhook_event(BillingEvent::Ignored)));
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo.expect_set_plan().never();
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        assert!(use_case.execute(webhook_params()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_accept_redelivered_event_without_failing() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider.expect_parse_webhook().returning(|_, _| {
+            Ok(webhook_event(BillingEvent::SubscriptionCanceled {
+                user_id: test_user_id(),
+            }))
+        });
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo
+            .expect_set_plan()
+            .times(1)
+            .returning(|_, _, _, _| Ok(PlanChangeOutcome::Duplicate));
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        assert!(use_case.execute(webhook_params()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_accept_stale_event_without_failing() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider.expect_parse_webhook().returning(|_, _| {
+            Ok(webhook_event(BillingEvent::SubscriptionActivated {
+                user_id: test_user_id(),
+                customer_id: None,
+            }))
+        });
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo
+            .expect_set_plan()
+            .times(1)
+            .returning(|_, _, _, _| Ok(PlanChangeOutcome::Stale));
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        assert!(use_case.execute(webhook_params()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_reject_webhook_when_signature_invalid() {
+        let mut mock_provider = MockProvider::new();
+        mock_provider
+            .expect_parse_webhook()
+            .returning(|_, _| Err(BillingError::InvalidSignature));
+
+        let mut mock_repo = MockPlanRepo::new();
+        mock_repo.expect_set_plan().never();
+
+        let use_case = HandleWebhookUseCaseImpl {
+            plan_provider: Arc::new(mock_provider),
+            plan_repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(webhook_params()).await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            BillingError::InvalidSignature
+        ));
+    }
+}
@@ -0,0 +1,207 @@
+use std::sync::{Arc, Weak};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::events::BudgetThresholdReached;
+use crate::domain::budget::model::{BudgetThreshold, month_start};
+use crate::domain::budget::repository::BudgetRepository;
+use crate::domain::events::{DomainEvent, EventHandler, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::shopping_trip::events::PurchaseRecorded;
+
+/// Watches purchases against the user's monthly budget and announces when
+/// one takes the spend past 80% or 100% of it, so the user can be told.
+pub struct BudgetAlertPolicy {
+    pub repository: Arc<dyn BudgetRepository>,
+    /// Weak because the policy is registered on the bus it publishes to.
+    pub event_publisher: Weak<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl BudgetAlertPolicy {
+    async fn purchase_recorded(&self, event: &PurchaseRecorded) -> Result<(), BudgetError> {
+        let Some(budget) = self.repository.get(&event.user_id).await? else {
+            return Ok(());
+        };
+        if !budget.alerts_enabled {
+            return Ok(());
+        }
+
+        let spent = self
+            .repository
+            .get_spent_since(&event.user_id, month_start(Utc::now()))
+            .await?;
+        let before = spent.saturating_sub(event.added_cents);
+        let Some(threshold) = BudgetThreshold::crossed(budget.monthly_cents, before, spent) else {
+            return Ok(());
+        };
+
+        self.logger.info(&format!(
+            "User {} reached {}% of their budget",
+            event.user_id,
+            threshold.percent()
+        ));
+        if let Some(publisher) = self.event_publisher.upgrade() {
+            publisher
+                .publish(DomainEvent::BudgetThresholdReached(
+                    BudgetThresholdReached {
+                        user_id: event.user_id.clone(),
+                        threshold,
+                        spent_cents: spent,
+                        budget_cents: budget.monthly_cents,
+                    },
+                ))
+                .await;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler for BudgetAlertPolicy {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::PurchaseRecorded(recorded) => {
+                if let Err(e) = self.purchase_recorded(recorded).await {
+                    self.logger.warn(&format!(
+                        "Failed to check budget for user {}: {}",
+                        recorded.user_id, e
+                    ));
+                }
+            }
+            DomainEvent::BudgetThresholdReached(_)
+            | DomainEvent::ChallengeCompleted(_)
+            | DomainEvent::ProductAdded(_)
+            | DomainEvent::ProductDeleted(_)
+            | DomainEvent::ProductUpdated(_)
+            | DomainEvent::ProductOutcomeRecorded(_)
+            | DomainEvent::ProductStatusChanged(_)
+            | DomainEvent::StreakMilestoneReached(_)
+            | DomainEvent::VacationEnded(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::budget::model::Budget;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+
+    mock! {
+        pub BudgetRepo {}
+
+        #[async_trait]
+        impl BudgetRepository for BudgetRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<Budget>, RepositoryError>;
+            async fn save(&self, user_id: &UserId, budget: &Budget) -> Result<(), RepositoryError>;
+            async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_spent_since(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn budget_repo(alerts_enabled: bool, spent: u64) -> MockBudgetRepo {
+        let mut repo = MockBudgetRepo::new();
+        repo.expect_get()
+            .returning(move |_| Ok(Some(Budget::new(10_000, alerts_enabled).unwrap())));
+        repo.expect_get_spent_since()
+            .returning(move |_, _| Ok(spent));
+        repo
+    }
+
+    fn purchase(added_cents: u64) -> DomainEvent {
+        DomainEvent::PurchaseRecorded(PurchaseRecorded {
+            user_id: UserId::new("test-user-id"),
+            added_cents,
+        })
+    }
+
+    #[tokio::test]
+    async fn should_announce_threshold_crossed_by_purchase() {
+        let mut publisher = MockPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|event| {
+                matches!(
+                    event,
+                    DomainEvent::BudgetThresholdReached(reached)
+                        if reached.threshold == BudgetThreshold::Warning && reached.spent_cents == 8_200
+                )
+            })
+            .times(1)
+            .returning(|_| ());
+        let publisher: Arc<dyn EventPublisher> = Arc::new(publisher);
+
+        let policy = BudgetAlertPolicy {
+            repository: Arc::new(budget_repo(true, 8_200)),
+            event_publisher: Arc::downgrade(&publisher),
+            logger: mock_logger(),
+        };
+
+        policy.handle(&purchase(500)).await;
+    }
+
+    #[tokio::test]
+    async fn should_stay_quiet_when_threshold_was_already_passed() {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().never();
+        let publisher: Arc<dyn EventPublisher> = Arc::new(publisher);
+
+        let policy = BudgetAlertPolicy {
+            repository: Arc::new(budget_repo(true, 9_000)),
+            event_publisher: Arc::downgrade(&publisher),
+            logger: mock_logger(),
+        };
+
+        policy.handle(&purchase(500)).await;
+    }
+
+    #[tokio::test]
+    async fn should_stay_quiet_when_alerts_are_off() {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().never();
+        let publisher: Arc<dyn EventPublisher> = Arc::new(publisher);
+
+        let policy = BudgetAlertPolicy {
+            repository: Arc::new(budget_repo(false, 12_000)),
+            event_publisher: Arc::downgrade(&publisher),
+            logger: mock_logger(),
+        };
+
+        policy.handle(&purchase(5_000)).await;
+    }
+}
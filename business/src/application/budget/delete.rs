@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::repository::BudgetRepository;
+use crate::domain::budget::use_cases::delete::{DeleteBudgetParams, DeleteBudgetUseCase};
+use crate::domain::logger::Logger;
+
+pub struct DeleteBudgetUseCaseImpl {
+    pub repository: Arc<dyn BudgetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl DeleteBudgetUseCase for DeleteBudgetUseCaseImpl {
+    async fn execute(&self, params: DeleteBudgetParams) -> Result<(), BudgetError> {
+        self.logger
+            .info(&format!("Removing budget for user: {}", params.user_id));
+        Ok(self.repository.delete(&params.user_id).await?)
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::model::{BudgetStatus, month_start};
+use crate::domain::budget::repository::BudgetRepository;
+use crate::domain::budget::use_cases::get_status::{GetBudgetStatusParams, GetBudgetStatusUseCase};
+use crate::domain::logger::Logger;
+
+pub struct GetBudgetStatusUseCaseImpl {
+    pub repository: Arc<dyn BudgetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetBudgetStatusUseCase for GetBudgetStatusUseCaseImpl {
+    async fn execute(&self, params: GetBudgetStatusParams) -> Result<BudgetStatus, BudgetError> {
+        self.logger.debug(&format!(
+            "Getting budget status for user: {}",
+            params.user_id
+        ));
+
+        let budget = self
+            .repository
+            .get(&params.user_id)
+            .await?
+            .ok_or(BudgetError::NotSet)?;
+        let now = Utc::now();
+        let spent = self
+            .repository
+            .get_spent_since(&params.user_id, month_start(now))
+            .await?;
+
+        Ok(BudgetStatus::compute(&budget, spent, now))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::budget::model::Budget;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Datelike, Timelike};
+    use mockall::mock;
+
+    mock! {
+        pub BudgetRepo {}
+
+        #[async_trait]
+        impl BudgetRepository for BudgetRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<Budget>, RepositoryError>;
+            async fn save(&self, user_id: &UserId, budget: &Budget) -> Result<(), RepositoryError>;
+            async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_spent_since(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_sum_spend_since_start_of_current_month() {
+        let mut mock_repo = MockBudgetRepo::new();
+        mock_repo
+            .expect_get()
+            .returning(|_| Ok(Some(Budget::new(30_000, true).unwrap())));
+        mock_repo
+            .expect_get_spent_since()
+            .withf(|user_id, since| {
+                let now = Utc::now();
+                user_id.as_str() == "test-user-id"
+                    && since.day() == 1
+                    && since.month() == now.month()
+                    && since.year() == now.year()
+                    && since.num_seconds_from_midnight() == 0
+            })
+            .times(1)
+            .returning(|_, _| Ok(12_000));
+
+        let use_case = GetBudgetStatusUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let status = use_case
+            .execute(GetBudgetStatusParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(status.month, month_start(Utc::now()).date_naive());
+        assert_eq!(status.budget_cents, 30_000);
+        assert_eq!(status.spent_cents, 12_000);
+        assert_eq!(status.percent_used(), 40);
+        assert_eq!(
+            status.projected_overrun_cents,
+            status.projected_cents.saturating_sub(30_000)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_with_not_set_when_user_has_no_budget() {
+        let mut mock_repo = MockBudgetRepo::new();
+        mock_repo.expect_get().returning(|_| Ok(None));
+        mock_repo.expect_get_spent_since().never();
+
+        let use_case = GetBudgetStatusUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetBudgetStatusParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(BudgetError::NotSet)));
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::model::Budget;
+use crate::domain::budget::repository::BudgetRepository;
+use crate::domain::budget::use_cases::set::{SetBudgetParams, SetBudgetUseCase};
+use crate::domain::logger::Logger;
+
+pub struct SetBudgetUseCaseImpl {
+    pub repository: Arc<dyn BudgetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl SetBudgetUseCase for SetBudgetUseCaseImpl {
+    async fn execute(&self, params: SetBudgetParams) -> Result<Budget, BudgetError> {
+        self.logger.info(&format!(
+            "Setting monthly budget to {} cents",
+            params.monthly_cents
+        ));
+
+        let budget = Budget::new(params.monthly_cents, params.alerts_enabled)?;
+        self.repository.save(&params.user_id, &budget).await?;
+        Ok(budget)
+    }
+}
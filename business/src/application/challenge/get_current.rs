@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::challenge::errors::ChallengeError;
+use crate::domain::challenge::model::{Challenge, ChallengeSettings};
+use crate::domain::challenge::repository::ChallengeRepository;
+use crate::domain::challenge::use_cases::get_current::{
+    GetCurrentChallengeParams, GetCurrentChallengeUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct GetCurrentChallengeUseCaseImpl {
+    pub repository: Arc<dyn ChallengeRepository>,
+    pub settings: ChallengeSettings,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetCurrentChallengeUseCase for GetCurrentChallengeUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetCurrentChallengeParams,
+    ) -> Result<Challenge, ChallengeError> {
+        self.logger.info("Fetching current challenge");
+
+        let challenge = self
+            .repository
+            .start(&Challenge::weekly(
+                params.user_id,
+                self.settings,
+                Utc::now(),
+            ))
+            .await?;
+
+        self.logger.info(&format!(
+            "Current challenge {}: {}/{}",
+            challenge.id, challenge.progress, challenge.target
+        ));
+        Ok(challenge)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::challenge::model::week_start;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub ChallengeRepo {}
+
+        #[async_trait]
+        impl ChallengeRepository for ChallengeRepo {
+            async fn start(&self, challenge: &Challenge) -> Result<Challenge, RepositoryError>;
+            async fn update(&self, challenge: &Challenge) -> Result<(), RepositoryError>;
+            async fn get_history(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<Challenge>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_start_this_weeks_challenge_with_configured_target() {
+        let mut repo = MockChallengeRepo::new();
+        repo.expect_start()
+            .withf(|challenge| {
+                challenge.user_id == UserId::new("test-user-id")
+                    && challenge.target == 5
+                    && challenge.starts_at == week_start(Utc::now())
+            })
+            .times(1)
+            .returning(|challenge| Ok(challenge.clone()));
+        let use_case = GetCurrentChallengeUseCaseImpl {
+            repository: Arc::new(repo),
+            settings: ChallengeSettings {
+                target: 5,
+                ..ChallengeSettings::default()
+            },
+            logger: mock_logger(),
+        };
+
+        let challenge = use_case
+            .execute(GetCurrentChallengeParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(challenge.progress, 0);
+    }
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::challenge::errors::ChallengeError;
+use crate::domain::challenge::model::Challenge;
+use crate::domain::challenge::repository::ChallengeRepository;
+use crate::domain::challenge::use_cases::get_history::{
+    GetChallengeHistoryParams, GetChallengeHistoryUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::shared::pagination::{Cursor, CursorPage};
+
+pub struct GetChallengeHistoryUseCaseImpl {
+    pub repository: Arc<dyn ChallengeRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetChallengeHistoryUseCase for GetChallengeHistoryUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetChallengeHistoryParams,
+    ) -> Result<CursorPage<Challenge>, ChallengeError> {
+        self.logger.info("Fetching challenge history");
+
+        let rows = self
+            .repository
+            .get_history(&params.user_id, &params.page)
+            .await?;
+        let page = CursorPage::from_rows(rows, &params.page, |challenge| {
+            Cursor::new(challenge.starts_at, challenge.id)
+        });
+
+        self.logger.info(&format!(
+            "Found {} challenges (more: {})",
+            page.items.len(),
+            page.next_cursor.is_some()
+        ));
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::challenge::model::ChallengeSettings;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+
+    mock! {
+        pub ChallengeRepo {}
+
+        #[async_trait]
+        impl ChallengeRepository for ChallengeRepo {
+            async fn start(&self, challenge: &Challenge) -> Result<Challenge, RepositoryError>;
+            async fn update(&self, challenge: &Challenge) -> Result<(), RepositoryError>;
+            async fn get_history(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<Challenge>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn challenge(weeks_ago: i64) -> Challenge {
+        Challenge::weekly(
+            test_user_id(),
+            ChallengeSettings::default(),
+            Utc::now() - Duration::weeks(weeks_ago),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_page_challenges_with_cursor_of_last_returned_week() {
+        let rows = vec![challenge(0), challenge(1), challenge(2)];
+        let second = Cursor::new(rows[1].starts_at, rows[1].id);
+        let mut repo = MockChallengeRepo::new();
+        repo.expect_get_history()
+            .withf(|user_id, page| *user_id == test_user_id() && page.fetch_limit() == 3)
+            .times(1)
+            .returning(move |_, _| Ok(rows.clone()));
+        let use_case = GetChallengeHistoryUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let page = use_case
+            .execute(GetChallengeHistoryParams {
+                user_id: test_user_id(),
+                page: KeysetPage::new(2, None),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_cursor, Some(second));
+    }
+}
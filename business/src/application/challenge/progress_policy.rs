@@ -0,0 +1,246 @@
+use std::sync::{Arc, Weak};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::challenge::errors::ChallengeError;
+use crate::domain::challenge::events::ChallengeCompleted;
+use crate::domain::challenge::model::{Challenge, ChallengeSettings, ChallengeStatus};
+use crate::domain::challenge::repository::ChallengeRepository;
+use crate::domain::events::{DomainEvent, EventHandler, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::product::events::ProductOutcomeRecorded;
+
+/// Moves the user's weekly challenge forward as products are finished, and
+/// announces its completion so the user can be told about it.
+pub struct ChallengeProgressPolicy {
+    pub repository: Arc<dyn ChallengeRepository>,
+    pub settings: ChallengeSettings,
+    /// Weak because the policy is registered on the bus it publishes to.
+    pub event_publisher: Weak<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl ChallengeProgressPolicy {
+    async fn outcome_recorded(&self, event: &ProductOutcomeRecorded) -> Result<(), ChallengeError> {
+        if !self.settings.kind.counts(event) {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        let mut challenge = self
+            .repository
+            .start(&Challenge::weekly(
+                event.user_id.clone(),
+                self.settings,
+                now,
+            ))
+            .await?;
+        if challenge.status(now) != ChallengeStatus::Active {
+            return Ok(());
+        }
+
+        let completed = challenge.record_progress(now);
+        self.repository.update(&challenge).await?;
+
+        if completed {
+            self.logger
+                .info(&format!("Challenge {} completed", challenge.id));
+            if let Some(publisher) = self.event_publisher.upgrade() {
+                publisher
+                    .publish(DomainEvent::ChallengeCompleted(ChallengeCompleted {
+                        challenge_id: challenge.id,
+                        user_id: challenge.user_id,
+                        kind: challenge.kind,
+                        target: challenge.target,
+                    }))
+                    .await;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHandler for ChallengeProgressPolicy {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::ProductOutcomeRecorded(recorded) => {
+                if let Err(e) = self.outcome_recorded(recorded).await {
+                    self.logger.warn(&format!(
+                        "Failed to record challenge progress for product {}: {}",
+                        recorded.product_id, e
+                    ));
+                }
+            }
+            DomainEvent::ProductAdded(_)
+            | DomainEvent::ProductDeleted(_)
+            | DomainEvent::ProductUpdated(_)
+            | DomainEvent::ProductStatusChanged(_)
+            | DomainEvent::ChallengeCompleted(_)
+            | DomainEvent::StreakMilestoneReached(_)
+            | DomainEvent::VacationEnded(_)
+            | DomainEvent::BudgetThresholdReached(_)
+            | DomainEvent::PurchaseRecorded(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::urgency::UrgencyLevel;
+    use crate::domain::product::value_objects::ProductOutcome;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ChallengeRepo {}
+
+        #[async_trait]
+        impl ChallengeRepository for ChallengeRepo {
+            async fn start(&self, challenge: &Challenge) -> Result<Challenge, RepositoryError>;
+            async fn update(&self, challenge: &Challenge) -> Result<(), RepositoryError>;
+            async fn get_history(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<Challenge>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn outcome(outcome: ProductOutcome, urgency: UrgencyLevel) -> DomainEvent {
+        DomainEvent::ProductOutcomeRecorded(ProductOutcomeRecorded {
+            product_id: Uuid::new_v4(),
+            user_id: UserId::new("test-user-id"),
+            product_name: "Yogur".to_string(),
+            outcome,
+            urgency,
+        })
+    }
+
+    /// Repository whose stored challenge already has `progress` steps.
+    fn repo_with_progress(progress: u32) -> MockChallengeRepo {
+        let mut repo = MockChallengeRepo::new();
+        repo.expect_start().returning(move |challenge| {
+            let mut stored = challenge.clone();
+            stored.progress = progress;
+            Ok(stored)
+        });
+        repo
+    }
+
+    fn policy(
+        repo: MockChallengeRepo,
+        publisher: &Arc<dyn EventPublisher>,
+    ) -> ChallengeProgressPolicy {
+        ChallengeProgressPolicy {
+            repository: Arc::new(repo),
+            settings: ChallengeSettings::default(),
+            event_publisher: Arc::downgrade(publisher),
+            logger: mock_logger(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_count_urgent_product_used() {
+        let mut repo = repo_with_progress(0);
+        repo.expect_update()
+            .withf(|challenge| challenge.progress == 1 && challenge.completed_at.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().never();
+        let publisher: Arc<dyn EventPublisher> = Arc::new(publisher);
+
+        policy(repo, &publisher)
+            .handle(&outcome(ProductOutcome::Used, UrgencyLevel::UseToday))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_publish_completion_when_target_reached() {
+        let mut repo = repo_with_progress(2);
+        repo.expect_update()
+            .withf(|challenge| challenge.progress == 3 && challenge.completed_at.is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut publisher = MockPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|event| {
+                matches!(
+                    event,
+                    DomainEvent::ChallengeCompleted(completed) if completed.target == 3
+                )
+            })
+            .times(1)
+            .returning(|_| ());
+        let publisher: Arc<dyn EventPublisher> = Arc::new(publisher);
+
+        policy(repo, &publisher)
+            .handle(&outcome(ProductOutcome::Used, UrgencyLevel::UseSoon))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_ignore_products_thrown_away_or_not_urgent() {
+        let mut repo = MockChallengeRepo::new();
+        repo.expect_start().never();
+        repo.expect_update().never();
+        let publisher: Arc<dyn EventPublisher> = Arc::new(MockPublisher::new());
+        let policy = policy(repo, &publisher);
+
+        policy
+            .handle(&outcome(ProductOutcome::ThrownAway, UrgencyLevel::UseToday))
+            .await;
+        policy
+            .handle(&outcome(ProductOutcome::Used, UrgencyLevel::Ok))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_not_count_past_completion() {
+        let mut repo = MockChallengeRepo::new();
+        repo.expect_start().returning(|challenge| {
+            let mut stored = challenge.clone();
+            stored.progress = 3;
+            stored.completed_at = Some(Utc::now());
+            Ok(stored)
+        });
+        repo.expect_update().never();
+        let publisher: Arc<dyn EventPublisher> = Arc::new(MockPublisher::new());
+
+        policy(repo, &publisher)
+            .handle(&outcome(ProductOutcome::Used, UrgencyLevel::UseToday))
+            .await;
+    }
+}
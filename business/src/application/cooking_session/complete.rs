@@ -0,0 +1,394 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::cooking_session::repository::CookingSessionRepository;
+use crate::domain::cooking_session::use_cases::complete::{
+    CompleteCookingSessionParams, CompleteCookingSessionUseCase,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::experiment::model::AiFeature;
+use crate::domain::experiment::repository::AiCallRepository;
+use crate::domain::logger::Logger;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::update::{UpdateProductParams, UpdateProductUseCase};
+use crate::domain::product::value_objects::{ProductOutcome, ProductStatus};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CompleteCookingSessionUseCaseImpl {
+    pub repository: Arc<dyn CookingSessionRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub update_product_use_case: Arc<dyn UpdateProductUseCase>,
+    /// Credits the suggestion's experiment variant with the cooked recipe
+    pub ai_call_repository: Arc<dyn AiCallRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl CompleteCookingSessionUseCaseImpl {
+    /// Moves a used ingredient one status down through the regular product update
+    /// flow, so finished products land on the shopping list as usual.
+    async fn decrement_product(&self, product_id: &str, user_id: &UserId) -> Result<(), String> {
+        let id = Uuid::parse_str(product_id).map_err(|e| e.to_string())?;
+        let product = self
+            .product_repository
+            .get_by_id(id, user_id)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let status = product.status.after_use();
+        let outcome = if status == ProductStatus::Finished {
+            Some(ProductOutcome::Used)
+        } else {
+            product.outcome
+        };
+
+        self.update_product_use_case
+            .execute(UpdateProductParams {
+                id: product.id,
+                user_id: user_id.clone(),
+                name: product.name,
+                status,
+                location: product.location,
+                quantity: product.quantity,
+                expiry_date: product.expiry_date,
+                estimated_expiry_date: product.estimated_expiry_date,
+                expiry_type: product.expiry_type,
+                outcome,
+                category: product.category,
+                tags: product.tags,
+            })
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[async_trait]
+impl CompleteCookingSessionUseCase for CompleteCookingSessionUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CompleteCookingSessionParams,
+    ) -> Result<CookingSession, CookingSessionError> {
+        self.logger
+            .info(&format!("Completing cooking session: {}", params.id));
+
+        let mut session = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => CookingSessionError::NotFound,
+                other => CookingSessionError::Repository(other),
+            })?;
+
+        session.complete()?;
+        self.repository
+            .update(&session)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => CookingSessionError::NotFound,
+                other => CookingSessionError::Repository(other),
+            })?;
+
+        // Best-effort: a missing or already removed product must not block completion
+        for ingredient in &session.ingredients {
+            if let Err(e) = self
+                .decrement_product(&ingredient.product_id, &params.user_id)
+                .await
+            {
+                self.logger.warn(&format!(
+                    "Failed to decrement product {} after cooking: {}",
+                    ingredient.product_id, e
+                ));
+            }
+        }
+
+        if let Err(e) = self
+            .ai_call_repository
+            .accept_by_subject(
+                &params.user_id,
+                AiFeature::Suggestions,
+                &session.suggestion_id,
+            )
+            .await
+        {
+            self.logger.warn(&format!(
+                "Failed to record suggestion {} as cooked: {}",
+                session.suggestion_id, e
+            ));
+        }
+
+        self.logger
+            .info(&format!("Cooking session completed: {}", session.id));
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::cooking_session::model::CookingSessionStatus;
+    use crate::domain::experiment::model::{AiCall, VariantResult};
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::ExpiryType;
+    use crate::domain::suggestion::model::{Suggestion, SuggestionIngredient, TimeRange};
+    use chrono::Utc;
+    use mockall::mock;
+
+    mock! {
+        pub CookingSessionRepo {}
+
+        #[async_trait]
+        impl CookingSessionRepository for CookingSessionRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<CookingSession, RepositoryError>;
+            async fn find_in_progress_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<CookingSession>, RepositoryError>;
+            async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+            async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub UpdateProduct {}
+
+        #[async_trait]
+        impl UpdateProductUseCase for UpdateProduct {
+            async fn execute(&self, params: UpdateProductParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: chrono::DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Expects only the session's suggestion to be credited as cooked.
+    fn cooked_suggestion_calls() -> Arc<dyn AiCallRepository> {
+        let mut calls = MockAiCallRepo::new();
+        calls
+            .expect_accept_by_subject()
+            .withf(|_, feature, subject_id| {
+                *feature == AiFeature::Suggestions && subject_id == "openai-1700000000000-2"
+            })
+            .returning(|_, _, _| Ok(()));
+        Arc::new(calls)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn product_with_status(id: Uuid, name: &str, status: ProductStatus) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            name.to_string(),
+            status,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn session_using(product_ids: &[Uuid]) -> CookingSession {
+        let suggestion = Suggestion {
+            id: "openai-1700000000000-2".to_string(),
+            title: "Arroz con pollo".to_string(),
+            description: None,
+            estimated_time: TimeRange::Long,
+            ingredients: product_ids
+                .iter()
+                .map(|id| SuggestionIngredient {
+                    product_id: id.to_string(),
+                    product_name: "Ingrediente".to_string(),
+                    quantity: None,
+                    is_urgent: false,
+                })
+                .collect(),
+            urgent_ingredients: vec![],
+            steps: Some(vec![
+                "Dorar el pollo".to_string(),
+                "Añadir el arroz y el caldo".to_string(),
+            ]),
+            created_at: Utc::now(),
+        };
+        CookingSession::start(test_user_id(), &suggestion).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_decrement_each_ingredient_when_completed() {
+        let rice_id = Uuid::new_v4();
+        let chicken_id = Uuid::new_v4();
+        let session = session_using(&[rice_id, chicken_id]);
+
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(session.clone()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_get_by_id().returning(move |id, _| {
+            if id == rice_id {
+                Ok(product_with_status(id, "Arroz", ProductStatus::Opened))
+            } else {
+                Ok(product_with_status(id, "Pollo", ProductStatus::AlmostEmpty))
+            }
+        });
+
+        let mut mock_update = MockUpdateProduct::new();
+        mock_update
+            .expect_execute()
+            .withf(move |p| p.id == rice_id && p.status == ProductStatus::AlmostEmpty)
+            .times(1)
+            .returning(|p| Ok(product_with_status(p.id, &p.name, p.status)));
+        mock_update
+            .expect_execute()
+            .withf(move |p| {
+                p.id == chicken_id
+                    && p.status == ProductStatus::Finished
+                    && p.outcome == Some(ProductOutcome::Used)
+            })
+            .times(1)
+            .returning(|p| Ok(product_with_status(p.id, &p.name, p.status)));
+
+        let use_case = CompleteCookingSessionUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            update_product_use_case: Arc::new(mock_update),
+            ai_call_repository: cooked_suggestion_calls(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CompleteCookingSessionParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().status, CookingSessionStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn should_complete_even_when_product_no_longer_exists() {
+        let session = session_using(&[Uuid::new_v4()]);
+
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(session.clone()));
+        mock_repo.expect_update().returning(|_| Ok(()));
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let mut mock_update = MockUpdateProduct::new();
+        mock_update.expect_execute().never();
+
+        let use_case = CompleteCookingSessionUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            update_product_use_case: Arc::new(mock_update),
+            ai_call_repository: cooked_suggestion_calls(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CompleteCookingSessionParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_already_completed_when_completing_twice() {
+        let mut session = session_using(&[Uuid::new_v4()]);
+        session.complete().unwrap();
+
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(session.clone()));
+        mock_repo.expect_update().never();
+
+        let use_case = CompleteCookingSessionUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            update_product_use_case: Arc::new(MockUpdateProduct::new()),
+            ai_call_repository: cooked_suggestion_calls(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CompleteCookingSessionParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CookingSessionError::AlreadyCompleted
+        ));
+    }
+}
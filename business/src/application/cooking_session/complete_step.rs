@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::cooking_session::repository::CookingSessionRepository;
+use crate::domain::cooking_session::use_cases::complete_step::{
+    CompleteCookingStepParams, CompleteCookingStepUseCase,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+
+pub struct CompleteCookingStepUseCaseImpl {
+    pub repository: Arc<dyn CookingSessionRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl CompleteCookingStepUseCase for CompleteCookingStepUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CompleteCookingStepParams,
+    ) -> Result<CookingSession, CookingSessionError> {
+        self.logger.info(&format!(
+            "Completing step {} of cooking session: {}",
+            params.position, params.id
+        ));
+
+        let mut session = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => CookingSessionError::NotFound,
+                other => CookingSessionError::Repository(other),
+            })?;
+
+        session.complete_step(params.position)?;
+        self.repository
+            .update(&session)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => CookingSessionError::NotFound,
+                other => CookingSessionError::Repository(other),
+            })?;
+
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::cooking_session::model::CookingStepStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::suggestion::model::{Suggestion, SuggestionIngredient, TimeRange};
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub CookingSessionRepo {}
+
+        #[async_trait]
+        impl CookingSessionRepository for CookingSessionRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<CookingSession, RepositoryError>;
+            async fn find_in_progress_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<CookingSession>, RepositoryError>;
+            async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+            async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn lentejas_session() -> CookingSession {
+        let suggestion = Suggestion {
+            id: "openai-1700000000000-1".to_string(),
+            title: "Lentejas estofadas".to_string(),
+            description: None,
+            estimated_time: TimeRange::Long,
+            ingredients: vec![SuggestionIngredient {
+                product_id: Uuid::new_v4().to_string(),
+                product_name: "Lentejas".to_string(),
+                quantity: None,
+                is_urgent: false,
+            }],
+            urgent_ingredients: vec![],
+            steps: Some(vec![
+                "Sofreír la verdura".to_string(),
+                "Añadir las lentejas y el agua".to_string(),
+            ]),
+            created_at: Utc::now(),
+        };
+        CookingSession::start(test_user_id(), &suggestion).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_persist_session_after_completing_step() {
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Ok(lentejas_session()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+
+        let use_case = CompleteCookingStepUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let session = use_case
+            .execute(CompleteCookingStepParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                position: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(session.steps[0].status, CookingStepStatus::Active);
+        assert_eq!(session.steps[1].status, CookingStepStatus::Done);
+    }
+
+    #[tokio::test]
+    async fn should_return_invalid_step_when_position_out_of_range() {
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Ok(lentejas_session()));
+        mock_repo.expect_update().never();
+
+        let use_case = CompleteCookingStepUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CompleteCookingStepParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                position: 5,
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CookingSessionError::InvalidStep
+        ));
+    }
+}
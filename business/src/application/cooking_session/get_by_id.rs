@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::cooking_session::repository::CookingSessionRepository;
+use crate::domain::cooking_session::use_cases::get_by_id::{
+    GetCookingSessionParams, GetCookingSessionUseCase,
+};
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+
+pub struct GetCookingSessionUseCaseImpl {
+    pub repository: Arc<dyn CookingSessionRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetCookingSessionUseCase for GetCookingSessionUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetCookingSessionParams,
+    ) -> Result<CookingSession, CookingSessionError> {
+        self.logger
+            .info(&format!("Getting cooking session: {}", params.id));
+
+        self.repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => CookingSessionError::NotFound,
+                other => CookingSessionError::Repository(other),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub CookingSessionRepo {}
+
+        #[async_trait]
+        impl CookingSessionRepository for CookingSessionRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<CookingSession, RepositoryError>;
+            async fn find_in_progress_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<CookingSession>, RepositoryError>;
+            async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+            async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_session_missing() {
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = GetCookingSessionUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetCookingSessionParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), CookingSessionError::NotFound));
+    }
+}
@@ -0,0 +1,246 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::cooking_session::repository::CookingSessionRepository;
+use crate::domain::cooking_session::use_cases::start::{StartCookingParams, StartCookingUseCase};
+use crate::domain::logger::Logger;
+use crate::domain::suggestion::repository::SuggestionRepository;
+
+pub struct StartCookingUseCaseImpl {
+    pub repository: Arc<dyn CookingSessionRepository>,
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl StartCookingUseCase for StartCookingUseCaseImpl {
+    async fn execute(
+        &self,
+        params: StartCookingParams,
+    ) -> Result<CookingSession, CookingSessionError> {
+        self.logger.info(&format!(
+            "Starting cooking session for suggestion: {}",
+            params.suggestion_id
+        ));
+
+        // Resume the unfinished session instead of starting over
+        if let Some(existing) = self
+            .repository
+            .find_in_progress_by_suggestion(&params.suggestion_id, &params.user_id)
+            .await?
+        {
+            self.logger
+                .info(&format!("Resuming cooking session: {}", existing.id));
+            return Ok(existing);
+        }
+
+        // Suggestions are only addressable while they belong to the latest batch
+        let suggestion = self
+            .suggestion_repository
+            .get_latest_batch(&params.user_id)
+            .await?
+            .and_then(|batch| {
+                batch
+                    .suggestions
+                    .into_iter()
+                    .find(|s| s.id == params.suggestion_id)
+            })
+            .ok_or(CookingSessionError::SuggestionNotFound)?;
+
+        let session = CookingSession::start(params.user_id, &suggestion)?;
+        self.repository.insert(&session).await?;
+
+        self.logger
+            .info(&format!("Cooking session started: {}", session.id));
+        Ok(session)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::suggestion::model::{
+        Suggestion, SuggestionBatch, SuggestionIngredient, TimeRange,
+    };
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub CookingSessionRepo {}
+
+        #[async_trait]
+        impl CookingSessionRepository for CookingSessionRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<CookingSession, RepositoryError>;
+            async fn find_in_progress_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<CookingSession>, RepositoryError>;
+            async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+            async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn gazpacho() -> Suggestion {
+        Suggestion {
+            id: "openai-1700000000000-0".to_string(),
+            title: "Gazpacho".to_string(),
+            description: None,
+            estimated_time: TimeRange::Quick,
+            ingredients: vec![SuggestionIngredient {
+                product_id: Uuid::new_v4().to_string(),
+                product_name: "Tomates".to_string(),
+                quantity: Some("1kg".to_string()),
+                is_urgent: true,
+            }],
+            urgent_ingredients: vec![],
+            steps: Some(vec![
+                "Trocear las verduras".to_string(),
+                "Triturar con aceite y vinagre".to_string(),
+            ]),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn batch_with(suggestion: Suggestion) -> SuggestionBatch {
+        SuggestionBatch {
+            id: Uuid::new_v4(),
+            user_id: test_user_id(),
+            suggestions: vec![suggestion],
+            generated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_start_session_from_latest_batch() {
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_find_in_progress_by_suggestion()
+            .returning(|_, _| Ok(None));
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(Some(batch_with(gazpacho()))));
+
+        let use_case = StartCookingUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestions),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(StartCookingParams {
+                user_id: test_user_id(),
+                suggestion_id: "openai-1700000000000-0".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+        let session = result.unwrap();
+        assert_eq!(session.title, "Gazpacho");
+        assert_eq!(session.steps.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn should_resume_in_progress_session() {
+        let existing = CookingSession::start(test_user_id(), &gazpacho()).unwrap();
+        let existing_id = existing.id;
+
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_find_in_progress_by_suggestion()
+            .returning(move |_, _| Ok(Some(existing.clone())));
+        mock_repo.expect_insert().never();
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions.expect_get_latest_batch().never();
+
+        let use_case = StartCookingUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestions),
+            logger: mock_logger(),
+        };
+
+        let session = use_case
+            .execute(StartCookingParams {
+                user_id: test_user_id(),
+                suggestion_id: "openai-1700000000000-0".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(session.id, existing_id);
+    }
+
+    #[tokio::test]
+    async fn should_return_suggestion_not_found_when_not_in_latest_batch() {
+        let mut mock_repo = MockCookingSessionRepo::new();
+        mock_repo
+            .expect_find_in_progress_by_suggestion()
+            .returning(|_, _| Ok(None));
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(Some(batch_with(gazpacho()))));
+
+        let use_case = StartCookingUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestions),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(StartCookingParams {
+                user_id: test_user_id(),
+                suggestion_id: "unknown".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CookingSessionError::SuggestionNotFound
+        ));
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::domain::device::errors::DeviceError;
+use crate::domain::device::model::InstallCount;
+use crate::domain::device::repository::DeviceRepository;
+use crate::domain::device::use_cases::get_active_installs::{
+    GetActiveInstallsParams, GetActiveInstallsUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct GetActiveInstallsUseCaseImpl {
+    pub repository: Arc<dyn DeviceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetActiveInstallsUseCase for GetActiveInstallsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetActiveInstallsParams,
+    ) -> Result<Vec<InstallCount>, DeviceError> {
+        self.logger.info(&format!(
+            "Counting installs seen in the last {} days",
+            params.days
+        ));
+
+        let since = Utc::now() - Duration::days(i64::from(params.days));
+        Ok(self.repository.count_seen_since(since).await?)
+    }
+}
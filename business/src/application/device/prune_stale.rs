@@ -0,0 +1,124 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::domain::device::errors::DeviceError;
+use crate::domain::device::repository::DeviceRepository;
+use crate::domain::device::use_cases::prune_stale::{
+    PruneStaleDevicesParams, PruneStaleDevicesUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct PruneStaleDevicesUseCaseImpl {
+    pub repository: Arc<dyn DeviceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl PruneStaleDevicesUseCase for PruneStaleDevicesUseCaseImpl {
+    async fn execute(&self, params: PruneStaleDevicesParams) -> Result<u64, DeviceError> {
+        let before = Utc::now() - Duration::days(i64::from(params.stale_after_days));
+        let pruned = self.repository.delete_not_seen_since(before).await?;
+
+        if pruned > 0 {
+            self.logger.info(&format!(
+                "Pruned {} installs not seen for {} days",
+                pruned, params.stale_after_days
+            ));
+        }
+        Ok(pruned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::device::model::{Device, InstallCount};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+
+    mock! {
+        pub DeviceRepo {}
+
+        #[async_trait]
+        impl DeviceRepository for DeviceRepo {
+            async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+            async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+            async fn count_seen_since(&self, since: DateTime<Utc>) -> Result<Vec<InstallCount>, RepositoryError>;
+            async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_delete_installs_not_seen_since_staleness_cutoff() {
+        let earliest = Utc::now() - Duration::days(60);
+        let mut mock_repo = MockDeviceRepo::new();
+        mock_repo
+            .expect_delete_not_seen_since()
+            .withf(move |before| *before >= earliest && *before <= Utc::now() - Duration::days(60))
+            .times(1)
+            .returning(|_| Ok(3));
+
+        let use_case = PruneStaleDevicesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let pruned = use_case
+            .execute(PruneStaleDevicesParams {
+                stale_after_days: 60,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(pruned, 3);
+    }
+
+    #[tokio::test]
+    async fn should_not_log_when_nothing_is_stale() {
+        let mut mock_repo = MockDeviceRepo::new();
+        mock_repo
+            .expect_delete_not_seen_since()
+            .times(1)
+            .returning(|_| Ok(0));
+        let mut logger = MockLog::new();
+        logger.expect_info().never();
+
+        let use_case = PruneStaleDevicesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: Arc::new(logger),
+        };
+
+        let pruned = use_case
+            .execute(PruneStaleDevicesParams {
+                stale_after_days: 60,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(pruned, 0);
+    }
+}
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::device::errors::DeviceError;
+use crate::domain::device::model::Device;
+use crate::domain::device::repository::DeviceRepository;
+use crate::domain::device::use_cases::register::{RegisterDeviceParams, RegisterDeviceUseCase};
+use crate::domain::logger::Logger;
+
+pub struct RegisterDeviceUseCaseImpl {
+    pub repository: Arc<dyn DeviceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RegisterDeviceUseCase for RegisterDeviceUseCaseImpl {
+    async fn execute(&self, params: RegisterDeviceParams) -> Result<Device, DeviceError> {
+        let device = Device::register(
+            params.user_id,
+            &params.installation_id,
+            params.platform,
+            &params.app_version,
+            params.push_token.as_deref(),
+            Utc::now(),
+        )?;
+
+        let device = self.repository.register(&device).await?;
+        self.logger.debug(&format!(
+            "Registered {} install {} of user {} on {}",
+            device.platform, device.installation_id, device.user_id, device.app_version
+        ));
+        Ok(device)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::device::model::{DevicePlatform, InstallCount};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+
+    mock! {
+        pub DeviceRepo {}
+
+        #[async_trait]
+        impl DeviceRepository for DeviceRepo {
+            async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+            async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+            async fn count_seen_since(&self, since: DateTime<Utc>) -> Result<Vec<InstallCount>, RepositoryError>;
+            async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn params(app_version: &str) -> RegisterDeviceParams {
+        RegisterDeviceParams {
+            user_id: UserId::new("test-user-id"),
+            installation_id: "install-1".to_string(),
+            platform: DevicePlatform::Ios,
+            app_version: app_version.to_string(),
+            push_token: Some("apns-token".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_store_the_install_and_return_it_as_stored() {
+        let mut repository = MockDeviceRepo::new();
+        repository
+            .expect_register()
+            .withf(|device| {
+                device.installation_id == "install-1"
+                    && device.push_token.as_deref() == Some("apns-token")
+            })
+            .times(1)
+            .returning(|device| {
+                Ok(Device {
+                    registered_at: device.registered_at - chrono::Duration::days(30),
+                    ..device.clone()
+                })
+            });
+
+        let use_case = RegisterDeviceUseCaseImpl {
+            repository: Arc::new(repository),
+            logger: mock_logger(),
+        };
+
+        let device = use_case.execute(params("3.1.0")).await.unwrap();
+
+        assert!(device.registered_at < device.last_seen_at);
+    }
+
+    #[tokio::test]
+    async fn should_reject_blank_app_version_without_storing() {
+        let mut repository = MockDeviceRepo::new();
+        repository.expect_register().never();
+
+        let use_case = RegisterDeviceUseCaseImpl {
+            repository: Arc::new(repository),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params(" ")).await;
+
+        assert!(matches!(result, Err(DeviceError::InvalidAppVersion)));
+    }
+}
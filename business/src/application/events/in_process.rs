@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::events::{DomainEvent, EventHandler, EventPublisher};
+
+/// Delivers events to every registered handler, in registration order, before
+/// `publish` returns.
+pub struct InProcessEventBus {
+    pub handlers: Vec<Arc<dyn EventHandler>>,
+}
+
+#[async_trait]
+impl EventPublisher for InProcessEventBus {
+    async fn publish(&self, event: DomainEvent) {
+        for handler in &self.handlers {
+            handler.handle(&event).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::events::ProductStatusChanged;
+    use crate::domain::product::lifecycle::StatusTransition;
+    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub Handler {}
+
+        #[async_trait]
+        impl EventHandler for Handler {
+            async fn handle(&self, event: &DomainEvent);
+        }
+    }
+
+    #[tokio::test]
+    async fn should_deliver_event_to_every_handler() {
+        let event = DomainEvent::ProductStatusChanged(ProductStatusChanged {
+            product_id: Uuid::new_v4(),
+            user_id: UserId::new("test-user-id"),
+            product_name: "Leche".to_string(),
+            previous_status: ProductStatus::Opened,
+            new_status: ProductStatus::Finished,
+            transition: StatusTransition::Finished,
+        });
+
+        let mut first = MockHandler::new();
+        first.expect_handle().times(1).returning(|_| ());
+        let mut second = MockHandler::new();
+        second.expect_handle().times(1).returning(|_| ());
+
+        let bus = InProcessEventBus {
+            handlers: vec![Arc::new(first), Arc::new(second)],
+        };
+
+        bus.publish(event).await;
+    }
+}
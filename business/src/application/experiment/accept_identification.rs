@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::experiment::errors::ExperimentError;
+use crate::domain::experiment::model::AiFeature;
+use crate::domain::experiment::repository::AiCallRepository;
+use crate::domain::experiment::use_cases::accept_identification::{
+    AcceptIdentificationParams, AcceptIdentificationUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct AcceptIdentificationUseCaseImpl {
+    pub ai_call_repository: Arc<dyn AiCallRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl AcceptIdentificationUseCase for AcceptIdentificationUseCaseImpl {
+    async fn execute(&self, params: AcceptIdentificationParams) -> Result<(), ExperimentError> {
+        let accepted = self
+            .ai_call_repository
+            .accept(
+                params.ai_call_id,
+                &params.user_id,
+                AiFeature::ProductIdentification,
+            )
+            .await?;
+        if !accepted {
+            return Err(ExperimentError::AiCallNotFound);
+        }
+
+        self.logger
+            .info(&format!("Identification {} accepted", params.ai_call_id));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::experiment::model::{AiCall, VariantResult};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_accept_the_users_identification() {
+        let id = Uuid::new_v4();
+        let mut repository = MockAiCallRepo::new();
+        repository
+            .expect_accept()
+            .withf(move |call_id, user_id, feature| {
+                *call_id == id
+                    && user_id.as_str() == "user"
+                    && *feature == AiFeature::ProductIdentification
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let use_case = AcceptIdentificationUseCaseImpl {
+            ai_call_repository: Arc::new(repository),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(AcceptIdentificationParams {
+                user_id: UserId::new("user"),
+                ai_call_id: id,
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_fail_for_unknown_calls() {
+        let mut repository = MockAiCallRepo::new();
+        repository.expect_accept().returning(|_, _, _| Ok(false));
+
+        let use_case = AcceptIdentificationUseCaseImpl {
+            ai_call_repository: Arc::new(repository),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(AcceptIdentificationParams {
+                user_id: UserId::new("user"),
+                ai_call_id: Uuid::new_v4(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ExperimentError::AiCallNotFound)));
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::experiment::model::{AiCall, Experiment};
+use crate::domain::experiment::repository::AiCallRepository;
+use crate::domain::logger::Logger;
+use crate::domain::shared::value_objects::UserId;
+
+/// The experiment running for one AI feature, if any, with the services of
+/// its variants. Calls made under it are recorded with their variant so the
+/// variants can later be compared on what users did with the results.
+pub struct AiExperiment<S: ?Sized> {
+    pub experiment: Option<Experiment>,
+    /// Services of the variants that change the model or prompt, by variant
+    /// name. The other variants run the feature's regular service.
+    pub variant_services: HashMap<String, Arc<S>>,
+    pub ai_call_repository: Arc<dyn AiCallRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+/// Variant a user's call runs under.
+pub struct Assignment<'a, S: ?Sized> {
+    pub variant: &'a str,
+    /// `None` for variants running the regular service
+    pub service: Option<&'a Arc<S>>,
+}
+
+impl<S: ?Sized> AiExperiment<S> {
+    /// No experiment: every call runs the regular service and none is recorded.
+    pub fn inactive(
+        ai_call_repository: Arc<dyn AiCallRepository>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            experiment: None,
+            variant_services: HashMap::new(),
+            ai_call_repository,
+            logger,
+        }
+    }
+
+    pub fn assign(&self, user_id: &UserId) -> Option<Assignment<'_, S>> {
+        let variant = self.experiment.as_ref()?.assign(user_id)?;
+        Some(Assignment {
+            variant: &variant.name,
+            service: self.variant_services.get(&variant.name),
+        })
+    }
+
+    /// Records a call made under `variant`, returning its ID. Recording is
+    /// bookkeeping, so a failure is logged and the call's result kept.
+    pub async fn record(
+        &self,
+        user_id: &UserId,
+        variant: &str,
+        subject_ids: Vec<String>,
+    ) -> Option<Uuid> {
+        let experiment = self.experiment.as_ref()?;
+        let call = AiCall::new(
+            user_id.clone(),
+            experiment.feature,
+            variant.to_string(),
+            subject_ids,
+        );
+        match self.ai_call_repository.insert(&call).await {
+            Ok(()) => Some(call.id),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Could not record {} call under variant {}: {}",
+                    experiment.feature, variant, e
+                ));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::experiment::model::{AiFeature, Variant, VariantResult};
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait::async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn variant(name: &str, weight: u32, model: Option<&str>) -> Variant {
+        Variant {
+            name: name.to_string(),
+            weight,
+            model: model.map(str::to_string),
+            prompt_version: None,
+        }
+    }
+
+    /// Half the users on the regular service, half on a variant model.
+    fn running(repository: MockAiCallRepo) -> AiExperiment<String> {
+        AiExperiment {
+            experiment: Some(Experiment {
+                feature: AiFeature::Suggestions,
+                variants: vec![
+                    variant("control", 1, None),
+                    variant("mini", 1, Some("gpt-4o-mini")),
+                ],
+            }),
+            variant_services: HashMap::from([(
+                "mini".to_string(),
+                Arc::new("mini service".to_string()),
+            )]),
+            ai_call_repository: Arc::new(repository),
+            logger: mock_logger(),
+        }
+    }
+
+    #[test]
+    fn should_give_variant_service_only_to_users_in_that_variant() {
+        let experiment = running(MockAiCallRepo::new());
+
+        let assignments: Vec<(String, Option<String>)> = (0..200)
+            .map(|i| {
+                let assignment = experiment
+                    .assign(&UserId::new(format!("user-{i}")))
+                    .unwrap();
+                (
+                    assignment.variant.to_string(),
+                    assignment.service.map(|s| s.to_string()),
+                )
+            })
+            .collect();
+
+        assert!(assignments.iter().any(|(v, _)| v == "control"));
+        assert!(assignments.iter().any(|(v, _)| v == "mini"));
+        for (variant, service) in assignments {
+            match variant.as_str() {
+                "mini" => assert_eq!(service.as_deref(), Some("mini service")),
+                _ => assert_eq!(service, None),
+            }
+        }
+    }
+
+    #[test]
+    fn should_keep_user_in_same_variant_across_calls() {
+        let experiment = running(MockAiCallRepo::new());
+        let user_id = UserId::new("test-user-id");
+
+        let first = experiment.assign(&user_id).unwrap().variant.to_string();
+
+        for _ in 0..10 {
+            assert_eq!(experiment.assign(&user_id).unwrap().variant, first);
+        }
+        // A second instance, as after a restart, agrees
+        let restarted = running(MockAiCallRepo::new());
+        assert_eq!(restarted.assign(&user_id).unwrap().variant, first);
+    }
+
+    #[tokio::test]
+    async fn should_neither_assign_nor_record_without_experiment() {
+        let mut mock_repo = MockAiCallRepo::new();
+        mock_repo.expect_insert().never();
+        let experiment: AiExperiment<String> =
+            AiExperiment::inactive(Arc::new(mock_repo), mock_logger());
+        let user_id = UserId::new("test-user-id");
+
+        assert!(experiment.assign(&user_id).is_none());
+        assert!(
+            experiment
+                .record(&user_id, "control", vec![])
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn should_record_call_under_assigned_variant() {
+        let mut mock_repo = MockAiCallRepo::new();
+        mock_repo
+            .expect_insert()
+            .withf(|call| {
+                call.feature == AiFeature::Suggestions
+                    && call.variant == "mini"
+                    && call.subject_ids == vec!["s1".to_string()]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        let experiment = running(mock_repo);
+
+        let id = experiment
+            .record(&UserId::new("test-user-id"), "mini", vec!["s1".to_string()])
+            .await;
+
+        assert!(id.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_keep_going_when_recording_fails() {
+        let mut mock_repo = MockAiCallRepo::new();
+        mock_repo
+            .expect_insert()
+            .returning(|_| Err(RepositoryError::Persistence));
+        let experiment = running(mock_repo);
+
+        let id = experiment
+            .record(&UserId::new("test-user-id"), "control", vec![])
+            .await;
+
+        assert!(id.is_none());
+    }
+}
@@ -0,0 +1,151 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::domain::experiment::errors::ExperimentError;
+use crate::domain::experiment::model::{Experiment, VariantResult};
+use crate::domain::experiment::repository::AiCallRepository;
+use crate::domain::experiment::use_cases::get_results::{
+    GetExperimentResultsParams, GetExperimentResultsUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct GetExperimentResultsUseCaseImpl {
+    pub ai_call_repository: Arc<dyn AiCallRepository>,
+    /// Experiments currently running, so their variants are listed even
+    /// before any call
+    pub experiments: Vec<Experiment>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetExperimentResultsUseCase for GetExperimentResultsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetExperimentResultsParams,
+    ) -> Result<Vec<VariantResult>, ExperimentError> {
+        self.logger.info(&format!(
+            "Getting experiment results for the last {} days",
+            params.days
+        ));
+
+        let since = Utc::now() - Duration::days(i64::from(params.days));
+        let mut results = self.ai_call_repository.get_results(since).await?;
+
+        for experiment in &self.experiments {
+            for variant in &experiment.variants {
+                let recorded = results
+                    .iter()
+                    .any(|r| r.feature == experiment.feature && r.variant == variant.name);
+                if !recorded {
+                    results.push(VariantResult {
+                        feature: experiment.feature,
+                        variant: variant.name.clone(),
+                        calls: 0,
+                        accepted: 0,
+                    });
+                }
+            }
+        }
+        results.sort_by(|a, b| {
+            (a.feature.to_string(), &a.variant).cmp(&(b.feature.to_string(), &b.variant))
+        });
+
+        Ok(results)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::experiment::model::{AiCall, AiFeature, Variant};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn result(feature: AiFeature, variant: &str, calls: u64, accepted: u64) -> VariantResult {
+        VariantResult {
+            feature,
+            variant: variant.to_string(),
+            calls,
+            accepted,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_list_running_variants_without_calls_next_to_recorded_ones() {
+        let mut repository = MockAiCallRepo::new();
+        repository.expect_get_results().times(1).returning(|_| {
+            Ok(vec![
+                result(AiFeature::Suggestions, "control", 10, 4),
+                result(AiFeature::ProductIdentification, "old", 3, 1),
+            ])
+        });
+
+        let use_case = GetExperimentResultsUseCaseImpl {
+            ai_call_repository: Arc::new(repository),
+            experiments: vec![Experiment {
+                feature: AiFeature::Suggestions,
+                variants: ["control", "v2"]
+                    .into_iter()
+                    .map(|name| Variant {
+                        name: name.to_string(),
+                        weight: 1,
+                        model: None,
+                        prompt_version: None,
+                    })
+                    .collect(),
+            }],
+            logger: mock_logger(),
+        };
+
+        let results = use_case
+            .execute(GetExperimentResultsParams { days: 30 })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            results,
+            vec![
+                result(AiFeature::ProductIdentification, "old", 3, 1),
+                result(AiFeature::Suggestions, "control", 10, 4),
+                result(AiFeature::Suggestions, "v2", 0, 0),
+            ]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::feature_flag::errors::FeatureFlagError;
+use crate::domain::feature_flag::model::{ClientConfig, FeatureFlags};
+use crate::domain::feature_flag::use_cases::get_client_config::{
+    GetClientConfigParams, GetClientConfigUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::quota::services::QuotaService;
+
+pub struct GetClientConfigUseCaseImpl {
+    pub flags: FeatureFlags,
+    pub min_app_version: String,
+    pub quota_service: Arc<dyn QuotaService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetClientConfigUseCase for GetClientConfigUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetClientConfigParams,
+    ) -> Result<ClientConfig, FeatureFlagError> {
+        self.logger.info(&format!(
+            "Getting client config for user: {}",
+            params.user_id
+        ));
+
+        let usage = self.quota_service.get_usage(&params.user_id).await?;
+
+        Ok(ClientConfig {
+            flags: self.flags.clone(),
+            usage,
+            min_app_version: self.min_app_version.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::feature_flag::model::Feature;
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::{PlanTier, Usage};
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_combine_flags_with_user_usage() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_get_usage()
+            .withf(|user_id| *user_id == test_user_id())
+            .returning(|_| {
+                Ok(Usage {
+                    plan: PlanTier::Free,
+                    limits: PlanTier::Free.limits(),
+                    ai_calls_today: 5,
+                    products: 40,
+                })
+            });
+
+        let use_case = GetClientConfigUseCaseImpl {
+            flags: FeatureFlags::with_disabled([Feature::ReceiptScanning]),
+            min_app_version: "2.3.0".to_string(),
+            quota_service: Arc::new(mock_quota),
+            logger: mock_logger(),
+        };
+
+        let config = use_case
+            .execute(GetClientConfigParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(!config.flags.is_enabled(Feature::ReceiptScanning));
+        assert!(config.flags.is_enabled(Feature::Suggestions));
+        assert_eq!(config.usage.ai_calls_today, 5);
+        assert_eq!(config.min_app_version, "2.3.0");
+    }
+
+    #[tokio::test]
+    async fn should_propagate_error_when_usage_unavailable() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_get_usage().returning(|_| {
+            Err(QuotaError::Repository(RepositoryError::database_error(
+                "connection reset",
+            )))
+        });
+
+        let use_case = GetClientConfigUseCaseImpl {
+            flags: FeatureFlags::default(),
+            min_app_version: "1.0.0".to_string(),
+            quota_service: Arc::new(mock_quota),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetClientConfigParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            FeatureFlagError::Quota(QuotaError::Repository(_))
+        ));
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::{InboundAddress, InboundMailbox};
+use crate::domain::inbound_email::repository::InboundAddressRepository;
+use crate::domain::inbound_email::use_cases::get_address::{
+    GetInboundAddressParams, GetInboundAddressUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct GetInboundAddressUseCaseImpl {
+    pub repository: Arc<dyn InboundAddressRepository>,
+    /// Domain the provider receives mail for
+    pub domain: String,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetInboundAddressUseCase for GetInboundAddressUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetInboundAddressParams,
+    ) -> Result<InboundMailbox, InboundEmailError> {
+        self.logger.info(&format!(
+            "Getting inbound address for user: {}",
+            params.user_id
+        ));
+
+        let address = match self.repository.find_by_user(&params.user_id).await? {
+            Some(address) => address,
+            None => {
+                self.repository
+                    .create(&InboundAddress::generate(params.user_id))
+                    .await?
+            }
+        };
+
+        Ok(InboundMailbox {
+            address: address.email(&self.domain),
+            created_at: address.created_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItem;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+
+    mock! {
+        pub AddressRepo {}
+
+        #[async_trait]
+        impl InboundAddressRepository for AddressRepo {
+            async fn find_by_user(&self, user_id: &UserId) -> Result<Option<InboundAddress>, RepositoryError>;
+            async fn find_by_token(&self, token: &str) -> Result<Option<InboundAddress>, RepositoryError>;
+            async fn create(&self, address: &InboundAddress) -> Result<InboundAddress, RepositoryError>;
+            async fn replace(&self, address: &InboundAddress) -> Result<(), RepositoryError>;
+            async fn count_received_since(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<u32, RepositoryError>;
+            async fn record_received(&self, user_id: &UserId, webhook_token: &str, items: &[ShoppingItem]) -> Result<bool, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_create_address_on_first_use() {
+        let mut mock_repo = MockAddressRepo::new();
+        mock_repo.expect_find_by_user().returning(|_| Ok(None));
+        mock_repo
+            .expect_create()
+            .withf(|address| address.user_id.as_str() == "test-user-id")
+            .times(1)
+            .returning(|address| Ok(address.clone()));
+
+        let use_case = GetInboundAddressUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            domain: "in.foodie.app".to_string(),
+            logger: mock_logger(),
+        };
+
+        let mailbox = use_case
+            .execute(GetInboundAddressParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert!(mailbox.address.ends_with("@in.foodie.app"));
+    }
+}
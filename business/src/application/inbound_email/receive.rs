@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::{InboundEmailReceipt, InboundLimits, recipient_token};
+use crate::domain::inbound_email::repository::InboundAddressRepository;
+use crate::domain::inbound_email::services::InboundEmailVerifier;
+use crate::domain::inbound_email::use_cases::receive::{
+    ReceiveInboundEmailParams, ReceiveInboundEmailUseCase,
+};
+use crate::domain::logger::Logger;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::text_list::parse_items;
+
+/// Turns an email sent to a user's inbound address into shopping list
+/// items. Unsigned calls, unknown addresses and addresses over their daily
+/// allowance are rejected before anything is read.
+pub struct ReceiveInboundEmailUseCaseImpl {
+    pub repository: Arc<dyn InboundAddressRepository>,
+    pub verifier: Arc<dyn InboundEmailVerifier>,
+    /// Domain the provider receives mail for
+    pub domain: String,
+    pub limits: InboundLimits,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ReceiveInboundEmailUseCase for ReceiveInboundEmailUseCaseImpl {
+    async fn execute(
+        &self,
+        params: ReceiveInboundEmailParams,
+    ) -> Result<InboundEmailReceipt, InboundEmailError> {
+        self.verifier.verify(&params.signature)?;
+
+        let token = recipient_token(&params.email.recipient, &self.domain)
+            .ok_or(InboundEmailError::UnknownRecipient)?;
+        let address = self
+            .repository
+            .find_by_token(&token)
+            .await?
+            .ok_or(InboundEmailError::UnknownRecipient)?;
+        let user_id = address.user_id;
+
+        let received = self
+            .repository
+            .count_received_since(&user_id, Utc::now() - Duration::days(1))
+            .await?;
+        if received >= self.limits.max_emails_per_day {
+            self.logger.warn(&format!(
+                "Rejecting inbound email from {} for user {}: daily limit reached",
+                params.email.sender, user_id
+            ));
+            return Err(InboundEmailError::TooManyEmails);
+        }
+
+        let items: Vec<ShoppingItem> =
+            parse_items(&params.email.text, self.limits.max_items_per_email)
+                .into_iter()
+                .filter_map(|name| ShoppingItem::new(user_id.clone(), name, None).ok())
+                .collect();
+        // The token is only kept if the items are too, so a failed insert
+        // leaves the provider's retry free to add them
+        let recorded = self
+            .repository
+            .record_received(&user_id, &params.signature.token, &items)
+            .await?;
+        if !recorded {
+            self.logger.info(&format!(
+                "Inbound email {} already handled, skipping",
+                params.signature.token
+            ));
+            return Ok(InboundEmailReceipt {
+                added: 0,
+                duplicate: true,
+            });
+        }
+        let receipt = InboundEmailReceipt {
+            added: items.len() as u32,
+            duplicate: false,
+        };
+
+        self.logger.info(&format!(
+            "Inbound email from {} added {} items for user {}",
+            params.email.sender, receipt.added, user_id
+        ));
+        Ok(receipt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::inbound_email::model::{InboundAddress, InboundEmail, WebhookSignature};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+
+    mock! {
+        pub AddressRepo {}
+
+        #[async_trait]
+        impl InboundAddressRepository for AddressRepo {
+            async fn find_by_user(&self, user_id: &UserId) -> Result<Option<InboundAddress>, RepositoryError>;
+            async fn find_by_token(&self, token: &str) -> Result<Option<InboundAddress>, RepositoryError>;
+            async fn create(&self, address: &InboundAddress) -> Result<InboundAddress, RepositoryError>;
+            async fn replace(&self, address: &InboundAddress) -> Result<(), RepositoryError>;
+            async fn count_received_since(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<u32, RepositoryError>;
+            async fn record_received(&self, user_id: &UserId, webhook_token: &str, items: &[ShoppingItem]) -> Result<bool, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Verifier {}
+
+        impl InboundEmailVerifier for Verifier {
+            fn verify(&self, signature: &WebhookSignature) -> Result<(), InboundEmailError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn valid_signature() -> MockVerifier {
+        let mut verifier = MockVerifier::new();
+        verifier.expect_verify().returning(|_| Ok(()));
+        verifier
+    }
+
+    /// An address "abc123" owned by "ana" that has received `received` emails today.
+    fn address_repo(received: u32) -> MockAddressRepo {
+        let mut repo = MockAddressRepo::new();
+        repo.expect_find_by_token().returning(|token| {
+            Ok((token == "abc123").then(|| InboundAddress {
+                user_id: UserId::new("ana"),
+                token: token.to_string(),
+                created_at: Utc::now(),
+            }))
+        });
+        repo.expect_count_received_since()
+            .returning(move |_, _| Ok(received));
+        repo
+    }
+
+    fn params(recipient: &str) -> ReceiveInboundEmailParams {
+        ReceiveInboundEmailParams {
+            email: InboundEmail {
+                recipient: recipient.to_string(),
+                sender: "ana@gmail.com".to_string(),
+                text: "- leche\n- huevos\n".to_string(),
+            },
+            signature: WebhookSignature {
+                timestamp: "1773000000".to_string(),
+                token: "webhook-token".to_string(),
+                signature: "deadbeef".to_string(),
+            },
+        }
+    }
+
+    fn use_case(
+        repository: MockAddressRepo,
+        verifier: MockVerifier,
+    ) -> ReceiveInboundEmailUseCaseImpl {
+        ReceiveInboundEmailUseCaseImpl {
+            repository: Arc::new(repository),
+            verifier: Arc::new(verifier),
+            domain: "in.foodie.app".to_string(),
+            limits: InboundLimits::default(),
+            logger: mock_logger(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_add_listed_items_to_owner_list() {
+        let mut repo = address_repo(0);
+        repo.expect_record_received()
+            .withf(|user_id, token, items| {
+                user_id.as_str() == "ana"
+                    && token == "webhook-token"
+                    && items.len() == 2
+                    && items
+                        .iter()
+                        .all(|item| item.user_id.as_str() == "ana" && item.product_id.is_none())
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(true));
+
+        let receipt = use_case(repo, valid_signature())
+            .execute(params("abc123@in.foodie.app"))
+            .await
+            .unwrap();
+
+        assert_eq!(receipt.added, 2);
+        assert!(!receipt.duplicate);
+    }
+
+    #[tokio::test]
+    async fn should_skip_emails_already_handled() {
+        let mut repo = address_repo(0);
+        repo.expect_record_received().returning(|_, _, _| Ok(false));
+
+        let receipt = use_case(repo, valid_signature())
+            .execute(params("abc123@in.foodie.app"))
+            .await
+            .unwrap();
+
+        assert!(receipt.duplicate);
+        assert_eq!(receipt.added, 0);
+    }
+
+    #[tokio::test]
+    async fn should_reject_unsigned_calls() {
+        let mut verifier = MockVerifier::new();
+        verifier
+            .expect_verify()
+            .returning(|_| Err(InboundEmailError::InvalidSignature));
+
+        let mut repo = MockAddressRepo::new();
+        repo.expect_find_by_token().never();
+
+        let result = use_case(repo, verifier)
+            .execute(params("abc123@in.foodie.app"))
+            .await;
+
+        assert!(matches!(result, Err(InboundEmailError::InvalidSignature)));
+    }
+
+    #[tokio::test]
+    async fn should_reject_unknown_addresses() {
+        let result = use_case(address_repo(0), valid_signature())
+            .execute(params("zzz999@in.foodie.app"))
+            .await;
+
+        assert!(matches!(result, Err(InboundEmailError::UnknownRecipient)));
+    }
+
+    #[tokio::test]
+    async fn should_reject_emails_over_the_daily_limit() {
+        let mut repo = address_repo(InboundLimits::default().max_emails_per_day);
+        repo.expect_record_received().never();
+
+        let result = use_case(repo, valid_signature())
+            .execute(params("abc123@in.foodie.app"))
+            .await;
+
+        assert!(matches!(result, Err(InboundEmailError::TooManyEmails)));
+    }
+}
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::{InboundAddress, InboundMailbox};
+use crate::domain::inbound_email::repository::InboundAddressRepository;
+use crate::domain::inbound_email::use_cases::rotate_address::{
+    RotateInboundAddressParams, RotateInboundAddressUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct RotateInboundAddressUseCaseImpl {
+    pub repository: Arc<dyn InboundAddressRepository>,
+    /// Domain the provider receives mail for
+    pub domain: String,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RotateInboundAddressUseCase for RotateInboundAddressUseCaseImpl {
+    async fn execute(
+        &self,
+        params: RotateInboundAddressParams,
+    ) -> Result<InboundMailbox, InboundEmailError> {
+        self.logger.info(&format!(
+            "Rotating inbound address for user: {}",
+            params.user_id
+        ));
+
+        let address = InboundAddress::generate(params.user_id);
+        self.repository.replace(&address).await?;
+
+        Ok(InboundMailbox {
+            address: address.email(&self.domain),
+            created_at: address.created_at,
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::job::errors::JobError;
+use crate::domain::job::model::Job;
+use crate::domain::job::repository::JobRepository;
+use crate::domain::job::use_cases::get_by_id::{GetJobParams, GetJobUseCase};
+use crate::domain::logger::Logger;
+
+pub struct GetJobUseCaseImpl {
+    pub repository: Arc<dyn JobRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetJobUseCase for GetJobUseCaseImpl {
+    async fn execute(&self, params: GetJobParams) -> Result<Job, JobError> {
+        self.logger.debug(&format!("Getting job: {}", params.id));
+
+        self.repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => JobError::NotFound,
+                other => JobError::Repository(other),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::model::JobKind;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub JobRepo {}
+
+        #[async_trait]
+        impl JobRepository for JobRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+            async fn find_unfinished(&self, user_id: &UserId, kind: JobKind) -> Result<Option<Job>, RepositoryError>;
+            async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+            async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_job_missing() {
+        let mut mock_repo = MockJobRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = GetJobUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetJobParams {
+                id: Uuid::new_v4(),
+                user_id: UserId::new("test-user-id"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(JobError::NotFound)));
+    }
+}
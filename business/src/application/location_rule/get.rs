@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::location_rule::errors::LocationRuleError;
+use crate::domain::location_rule::model::LocationRule;
+use crate::domain::location_rule::repository::LocationRuleRepository;
+use crate::domain::location_rule::use_cases::get::{
+    GetLocationRulesParams, GetLocationRulesUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct GetLocationRulesUseCaseImpl {
+    pub repository: Arc<dyn LocationRuleRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetLocationRulesUseCase for GetLocationRulesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetLocationRulesParams,
+    ) -> Result<Vec<LocationRule>, LocationRuleError> {
+        self.logger.info("Getting location rules");
+        let rules = self
+            .repository
+            .get(&params.user_id)
+            .await?
+            .map(|rule_set| rule_set.rules)
+            .unwrap_or_default();
+        self.logger
+            .info(&format!("Retrieved {} location rules", rules.len()));
+        Ok(rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::location_rule::model::LocationRuleSet;
+    use crate::domain::product::value_objects::ProductLocation;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use mockall::mock;
+
+    mock! {
+        pub LocationRuleRepo {}
+
+        #[async_trait]
+        impl LocationRuleRepository for LocationRuleRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+            async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_saved_rules() {
+        let mut mock_repo = MockLocationRuleRepo::new();
+        mock_repo.expect_get().returning(|user_id| {
+            Ok(Some(LocationRuleSet::from_repository(
+                user_id.clone(),
+                vec![LocationRule {
+                    keyword: "pan".to_string(),
+                    location: ProductLocation::Pantry,
+                }],
+                Utc::now(),
+            )))
+        });
+
+        let use_case = GetLocationRulesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let rules = use_case
+            .execute(GetLocationRulesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].keyword, "pan");
+    }
+
+    #[tokio::test]
+    async fn should_return_empty_list_when_user_has_no_rules() {
+        let mut mock_repo = MockLocationRuleRepo::new();
+        mock_repo.expect_get().returning(|_| Ok(None));
+
+        let use_case = GetLocationRulesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let rules = use_case
+            .execute(GetLocationRulesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(rules.is_empty());
+    }
+}
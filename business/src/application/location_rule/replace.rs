@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::location_rule::errors::LocationRuleError;
+use crate::domain::location_rule::model::{LocationRule, LocationRuleSet};
+use crate::domain::location_rule::repository::LocationRuleRepository;
+use crate::domain::location_rule::use_cases::replace::{
+    ReplaceLocationRulesParams, ReplaceLocationRulesUseCase,
+};
+use crate::domain::logger::Logger;
+
+pub struct ReplaceLocationRulesUseCaseImpl {
+    pub repository: Arc<dyn LocationRuleRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ReplaceLocationRulesUseCase for ReplaceLocationRulesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: ReplaceLocationRulesParams,
+    ) -> Result<Vec<LocationRule>, LocationRuleError> {
+        self.logger.info(&format!(
+            "Replacing location rules with {} rules",
+            params.rules.len()
+        ));
+
+        let rules = params
+            .rules
+            .into_iter()
+            .map(|rule| LocationRule {
+                keyword: rule.keyword.trim().to_string(),
+                location: rule.location,
+            })
+            .collect();
+        let rule_set = LocationRuleSet::new(params.user_id, rules)?;
+
+        self.repository.save(&rule_set).await?;
+
+        Ok(rule_set.rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::value_objects::ProductLocation;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub LocationRuleRepo {}
+
+        #[async_trait]
+        impl LocationRuleRepository for LocationRuleRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+            async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_save_trimmed_rules() {
+        let mut mock_repo = MockLocationRuleRepo::new();
+        mock_repo
+            .expect_save()
+            .withf(|rule_set| rule_set.rules[0].keyword == "leche")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = ReplaceLocationRulesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let rules = use_case
+            .execute(ReplaceLocationRulesParams {
+                user_id: test_user_id(),
+                rules: vec![LocationRule {
+                    keyword: "  leche ".to_string(),
+                    location: ProductLocation::Fridge,
+                }],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(rules[0].keyword, "leche");
+        assert_eq!(rules[0].location, ProductLocation::Fridge);
+    }
+
+    #[tokio::test]
+    async fn should_not_save_when_keyword_is_empty() {
+        let mut mock_repo = MockLocationRuleRepo::new();
+        mock_repo.expect_save().never();
+
+        let use_case = ReplaceLocationRulesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ReplaceLocationRulesParams {
+                user_id: test_user_id(),
+                rules: vec![LocationRule {
+                    keyword: "".to_string(),
+                    location: ProductLocation::Pantry,
+                }],
+            })
+            .await;
+
+        assert!(matches!(result, Err(LocationRuleError::KeywordEmpty)));
+    }
+}
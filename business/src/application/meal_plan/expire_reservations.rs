@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::meal_plan::errors::MealPlanError;
+use crate::domain::meal_plan::repository::MealPlanRepository;
+use crate::domain::meal_plan::use_cases::expire_reservations::ExpireReservationsUseCase;
+
+pub struct ExpireReservationsUseCaseImpl {
+    pub repository: Arc<dyn MealPlanRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ExpireReservationsUseCase for ExpireReservationsUseCaseImpl {
+    async fn execute(&self) -> Result<u64, MealPlanError> {
+        let released = self
+            .repository
+            .delete_expired_reservations(Utc::now())
+            .await?;
+
+        if released > 0 {
+            self.logger.info(&format!(
+                "Released {} expired product reservations",
+                released
+            ));
+        }
+        Ok(released)
+    }
+}
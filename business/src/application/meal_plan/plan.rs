@@ -0,0 +1,530 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::meal_plan::errors::MealPlanError;
+use crate::domain::meal_plan::model::{MealPlanOutcome, PlannedMeal};
+use crate::domain::meal_plan::repository::MealPlanRepository;
+use crate::domain::meal_plan::use_cases::plan::{PlanSuggestionParams, PlanSuggestionUseCase};
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::value_objects::ProductStatus;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::suggestion::model::SuggestionIngredient;
+use crate::domain::suggestion::repository::SuggestionRepository;
+
+pub struct PlanSuggestionUseCaseImpl {
+    pub repository: Arc<dyn MealPlanRepository>,
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+/// An ingredient the pantry can't cover, with its product when the user has
+/// one (finished, or set aside for another meal).
+struct MissingIngredient {
+    name: String,
+    product_id: Option<Uuid>,
+}
+
+impl PlanSuggestionUseCaseImpl {
+    /// Splits the ingredients into products free to reserve and ingredients
+    /// to buy.
+    async fn allocate(
+        &self,
+        user_id: &UserId,
+        ingredients: &[SuggestionIngredient],
+    ) -> Result<(Vec<Uuid>, Vec<MissingIngredient>), MealPlanError> {
+        let mut taken = self.repository.get_reserved_product_ids(user_id).await?;
+        let mut reserved = Vec::new();
+        let mut missing = Vec::new();
+
+        for ingredient in ingredients {
+            let product = match ingredient.product_id.parse::<Uuid>() {
+                Ok(id) => match self.product_repository.get_by_id(id, user_id).await {
+                    Ok(product) => Some(product),
+                    Err(RepositoryError::NotFound) => None,
+                    Err(e) => return Err(e.into()),
+                },
+                Err(_) => None,
+            };
+
+            match product {
+                Some(product)
+                    if product.status != ProductStatus::Finished && taken.insert(product.id) =>
+                {
+                    reserved.push(product.id);
+                }
+                Some(product) => missing.push(MissingIngredient {
+                    name: product.name,
+                    product_id: Some(product.id),
+                }),
+                None => missing.push(MissingIngredient {
+                    name: ingredient.product_name.clone(),
+                    product_id: None,
+                }),
+            }
+        }
+
+        Ok((reserved, missing))
+    }
+
+    /// Puts the missing ingredients on the shopping list, skipping those
+    /// already on it.
+    async fn add_to_shopping_list(
+        &self,
+        user_id: &UserId,
+        missing: Vec<MissingIngredient>,
+    ) -> Result<Vec<ShoppingItem>, MealPlanError> {
+        if missing.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut listed: HashSet<String> = self
+            .shopping_item_repository
+            .get_all(user_id)
+            .await?
+            .into_iter()
+            .filter(|item| !item.is_bought)
+            .map(|item| item.name.trim().to_lowercase())
+            .collect();
+
+        let mut added = Vec::new();
+        for ingredient in missing {
+            let Ok(item) =
+                ShoppingItem::new(user_id.clone(), ingredient.name, ingredient.product_id)
+            else {
+                continue;
+            };
+            match item.product_id {
+                Some(_) => {
+                    // The list holds one unbought item per product
+                    let saved = self
+                        .shopping_item_repository
+                        .save_for_product(&item)
+                        .await?;
+                    if saved.id == item.id {
+                        listed.insert(item.name.trim().to_lowercase());
+                        added.push(saved);
+                    }
+                }
+                None if listed.insert(item.name.trim().to_lowercase()) => {
+                    self.shopping_item_repository.insert(&item).await?;
+                    added.push(item);
+                }
+                None => {}
+            }
+        }
+        Ok(added)
+    }
+}
+
+#[async_trait]
+impl PlanSuggestionUseCase for PlanSuggestionUseCaseImpl {
+    async fn execute(
+        &self,
+        params: PlanSuggestionParams,
+    ) -> Result<MealPlanOutcome, MealPlanError> {
+        self.logger.info(&format!(
+            "Planning suggestion {} for {}",
+            params.suggestion_id, params.planned_for
+        ));
+
+        if self
+            .repository
+            .find_by_suggestion(&params.suggestion_id, &params.user_id)
+            .await?
+            .is_some()
+        {
+            return Err(MealPlanError::AlreadyPlanned);
+        }
+
+        // Suggestions are only addressable while they belong to the latest batch
+        let suggestion = self
+            .suggestion_repository
+            .get_latest_batch(&params.user_id)
+            .await?
+            .and_then(|batch| {
+                batch
+                    .suggestions
+                    .into_iter()
+                    .find(|s| s.id == params.suggestion_id)
+            })
+            .ok_or(MealPlanError::SuggestionNotFound)?;
+
+        let (reserved, missing) = self
+            .allocate(&params.user_id, &suggestion.ingredients)
+            .await?;
+
+        let meal = PlannedMeal::new(
+            params.user_id.clone(),
+            &suggestion,
+            reserved,
+            params.planned_for,
+        )?;
+        self.repository.insert(&meal).await.map_err(|e| match e {
+            RepositoryError::Duplicated => MealPlanError::AlreadyPlanned,
+            other => MealPlanError::Repository(other),
+        })?;
+
+        let added_to_shopping_list = self.add_to_shopping_list(&params.user_id, missing).await?;
+
+        self.logger.info(&format!(
+            "Meal planned: {} ({} products reserved, {} items added to the shopping list)",
+            meal.id,
+            meal.product_ids.len(),
+            added_to_shopping_list.len()
+        ));
+        Ok(MealPlanOutcome {
+            meal,
+            added_to_shopping_list,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::ExpiryType;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use crate::domain::suggestion::model::{Suggestion, SuggestionBatch, TimeRange};
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use std::sync::Mutex;
+
+    mock! {
+        pub MealPlanRepo {}
+
+        #[async_trait]
+        impl MealPlanRepository for MealPlanRepo {
+            async fn find_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<PlannedMeal>, RepositoryError>;
+            async fn get_reserved_product_ids(&self, user_id: &UserId) -> Result<HashSet<Uuid>, RepositoryError>;
+            async fn insert(&self, meal: &PlannedMeal) -> Result<(), RepositoryError>;
+            async fn delete_expired_reservations(&self, now: chrono::DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn product(id: Uuid, name: &str, status: ProductStatus) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            name.to_string(),
+            status,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::default(),
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn ingredient(product_id: &str, name: &str) -> SuggestionIngredient {
+        SuggestionIngredient {
+            product_id: product_id.to_string(),
+            product_name: name.to_string(),
+            quantity: None,
+            is_urgent: false,
+        }
+    }
+
+    fn latest_batch(ingredients: Vec<SuggestionIngredient>) -> MockSuggestionRepo {
+        let suggestion = Suggestion {
+            id: "openai-1700000000000-0".to_string(),
+            title: "Tortilla de patatas".to_string(),
+            description: None,
+            estimated_time: TimeRange::Medium,
+            ingredients,
+            urgent_ingredients: vec![],
+            steps: None,
+            created_at: Utc::now(),
+        };
+        let mut repo = MockSuggestionRepo::new();
+        repo.expect_get_latest_batch().returning(move |_| {
+            Ok(Some(SuggestionBatch {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                suggestions: vec![suggestion.clone()],
+                generated_at: Utc::now(),
+            }))
+        });
+        repo
+    }
+
+    fn params() -> PlanSuggestionParams {
+        PlanSuggestionParams {
+            user_id: test_user_id(),
+            suggestion_id: "openai-1700000000000-0".to_string(),
+            planned_for: Utc::now().date_naive(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reserve_free_products_and_list_the_rest() {
+        let eggs = Uuid::new_v4();
+        let potatoes = Uuid::new_v4();
+        let oil = Uuid::new_v4();
+        let deleted = Uuid::new_v4();
+
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans
+            .expect_find_by_suggestion()
+            .returning(|_, _| Ok(None));
+        // Potatoes are already set aside for another meal
+        meal_plans
+            .expect_get_reserved_product_ids()
+            .returning(move |_| Ok(HashSet::from([potatoes])));
+        meal_plans
+            .expect_insert()
+            .withf(move |meal| meal.product_ids == [eggs])
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut products = MockProductRepo::new();
+        products.expect_get_by_id().returning(move |id, _| {
+            if id == eggs {
+                Ok(product(id, "Huevos", ProductStatus::New))
+            } else if id == potatoes {
+                Ok(product(id, "Patatas", ProductStatus::Opened))
+            } else if id == oil {
+                Ok(product(id, "Aceite", ProductStatus::Finished))
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        });
+
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let mut shopping = MockShoppingItemRepo::new();
+        shopping.expect_get_all().returning(|_| Ok(vec![]));
+        let saved_for_product = saved.clone();
+        shopping.expect_save_for_product().returning(move |item| {
+            saved_for_product.lock().unwrap().push(item.name.clone());
+            Ok(item.clone())
+        });
+        let inserted = saved.clone();
+        shopping.expect_insert().returning(move |item| {
+            inserted.lock().unwrap().push(item.name.clone());
+            Ok(())
+        });
+
+        let use_case = PlanSuggestionUseCaseImpl {
+            repository: Arc::new(meal_plans),
+            suggestion_repository: Arc::new(latest_batch(vec![
+                ingredient(&eggs.to_string(), "Huevos"),
+                ingredient(&potatoes.to_string(), "Patatas"),
+                ingredient(&oil.to_string(), "Aceite"),
+                ingredient(&deleted.to_string(), "Cebolla"),
+            ])),
+            product_repository: Arc::new(products),
+            shopping_item_repository: Arc::new(shopping),
+            logger: mock_logger(),
+        };
+
+        let outcome = use_case.execute(params()).await.unwrap();
+
+        assert_eq!(outcome.meal.product_ids, vec![eggs]);
+        assert_eq!(outcome.added_to_shopping_list.len(), 3);
+        assert_eq!(*saved.lock().unwrap(), ["Patatas", "Aceite", "Cebolla"]);
+    }
+
+    #[tokio::test]
+    async fn should_not_list_ingredients_already_on_the_shopping_list() {
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans
+            .expect_find_by_suggestion()
+            .returning(|_, _| Ok(None));
+        meal_plans
+            .expect_get_reserved_product_ids()
+            .returning(|_| Ok(HashSet::new()));
+        meal_plans.expect_insert().returning(|_| Ok(()));
+
+        let mut shopping = MockShoppingItemRepo::new();
+        shopping.expect_get_all().returning(|_| {
+            Ok(vec![
+                ShoppingItem::new(test_user_id(), "cebolla ".to_string(), None).unwrap(),
+            ])
+        });
+        shopping.expect_insert().never();
+
+        let use_case = PlanSuggestionUseCaseImpl {
+            repository: Arc::new(meal_plans),
+            suggestion_repository: Arc::new(latest_batch(vec![ingredient(
+                "not-a-uuid",
+                "Cebolla",
+            )])),
+            product_repository: Arc::new(MockProductRepo::new()),
+            shopping_item_repository: Arc::new(shopping),
+            logger: mock_logger(),
+        };
+
+        let outcome = use_case.execute(params()).await.unwrap();
+
+        assert!(outcome.meal.product_ids.is_empty());
+        assert!(outcome.added_to_shopping_list.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_reject_suggestion_planned_before() {
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans.expect_find_by_suggestion().returning(|_, _| {
+            Ok(Some(PlannedMeal::from_repository(
+                Uuid::new_v4(),
+                test_user_id(),
+                "openai-1700000000000-0".to_string(),
+                "Tortilla de patatas".to_string(),
+                Utc::now().date_naive(),
+                vec![],
+                Utc::now(),
+            )))
+        });
+        meal_plans.expect_insert().never();
+
+        let use_case = PlanSuggestionUseCaseImpl {
+            repository: Arc::new(meal_plans),
+            suggestion_repository: Arc::new(MockSuggestionRepo::new()),
+            product_repository: Arc::new(MockProductRepo::new()),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params()).await;
+
+        assert!(matches!(result, Err(MealPlanError::AlreadyPlanned)));
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_suggestion_not_in_latest_batch() {
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans
+            .expect_find_by_suggestion()
+            .returning(|_, _| Ok(None));
+        let mut suggestions = MockSuggestionRepo::new();
+        suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let use_case = PlanSuggestionUseCaseImpl {
+            repository: Arc::new(meal_plans),
+            suggestion_repository: Arc::new(suggestions),
+            product_repository: Arc::new(MockProductRepo::new()),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params()).await;
+
+        assert!(matches!(result, Err(MealPlanError::SuggestionNotFound)));
+    }
+
+    #[tokio::test]
+    async fn should_reject_days_already_gone() {
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans
+            .expect_find_by_suggestion()
+            .returning(|_, _| Ok(None));
+        meal_plans
+            .expect_get_reserved_product_ids()
+            .returning(|_| Ok(HashSet::new()));
+        meal_plans.expect_insert().never();
+
+        let use_case = PlanSuggestionUseCaseImpl {
+            repository: Arc::new(meal_plans),
+            suggestion_repository: Arc::new(latest_batch(vec![])),
+            product_repository: Arc::new(MockProductRepo::new()),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(PlanSuggestionParams {
+                planned_for: Utc::now().date_naive() - Duration::days(1),
+                ..params()
+            })
+            .await;
+
+        assert!(matches!(result, Err(MealPlanError::DateInPast)));
+    }
+}
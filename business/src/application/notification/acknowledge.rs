@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::repository::NotificationInboxRepository;
+use crate::domain::notification::use_cases::acknowledge::{
+    AcknowledgeNotificationsParams, AcknowledgeNotificationsUseCase,
+};
+
+pub struct AcknowledgeNotificationsUseCaseImpl {
+    pub repository: Arc<dyn NotificationInboxRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl AcknowledgeNotificationsUseCase for AcknowledgeNotificationsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: AcknowledgeNotificationsParams,
+    ) -> Result<u64, NotificationError> {
+        let acknowledged = self
+            .repository
+            .acknowledge(&params.user_id, &params.acknowledgement, Utc::now())
+            .await?;
+
+        self.logger.debug(&format!(
+            "Acknowledged {} notifications of user {}",
+            acknowledged, params.user_id
+        ));
+        Ok(acknowledged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::model::{Acknowledgement, Notification};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub InboxRepo {}
+
+        #[async_trait]
+        impl NotificationInboxRepository for InboxRepo {
+            async fn record(&self, notification: &Notification, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+            async fn acknowledge(&self, user_id: &UserId, acknowledgement: &Acknowledgement, read_at: DateTime<Utc>) -> Result<u64, RepositoryError>;
+            async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_mark_everything_sent_before_read_in_one_update() {
+        let before = Utc::now() - Duration::hours(1);
+        let mut mock_repo = MockInboxRepo::new();
+        mock_repo
+            .expect_acknowledge()
+            .withf(move |user_id, acknowledgement, read_at| {
+                user_id.as_str() == "test-user-id"
+                    && *acknowledgement == Acknowledgement::SentBefore(before)
+                    && *read_at > before
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(12));
+
+        let use_case = AcknowledgeNotificationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let acknowledged = use_case
+            .execute(AcknowledgeNotificationsParams {
+                user_id: UserId::new("test-user-id"),
+                acknowledgement: Acknowledgement::SentBefore(before),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(acknowledged, 12);
+    }
+
+    #[tokio::test]
+    async fn should_mark_selected_notifications_read() {
+        let ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let expected = Acknowledgement::Ids(ids.clone());
+        let mut mock_repo = MockInboxRepo::new();
+        mock_repo
+            .expect_acknowledge()
+            .withf(move |user_id, acknowledgement, _| {
+                user_id.as_str() == "test-user-id" && *acknowledgement == expected
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(2));
+
+        let use_case = AcknowledgeNotificationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let acknowledged = use_case
+            .execute(AcknowledgeNotificationsParams {
+                user_id: UserId::new("test-user-id"),
+                acknowledgement: Acknowledgement::ids(ids).unwrap(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(acknowledged, 2);
+    }
+}
@@ -0,0 +1,611 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::domain::budget::events::BudgetThresholdReached;
+use crate::domain::budget::model::BudgetThreshold;
+use crate::domain::challenge::events::ChallengeCompleted;
+use crate::domain::device::repository::DeviceRepository;
+use crate::domain::events::{DomainEvent, EventHandler};
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::{Delivery, Notification, NotificationCategory};
+use crate::domain::notification::repository::{
+    NotificationInboxRepository, NotificationPreferenceRepository,
+};
+use crate::domain::notification::services::NotificationSender;
+use crate::domain::stats::events::StreakMilestoneReached;
+use crate::domain::vacation::events::VacationEnded;
+use crate::domain::vacation::repository::VacationRepository;
+
+/// Sends notifications when the user wants them: muted categories are
+/// dropped, and digest categories or anything raised in the quiet hours is
+/// held back until its time comes. Expiry alerts are dropped while the user
+/// is on vacation. Notifications go to the installs the user registered
+/// by the time they are sent, and are kept in the user's inbox once sent; a
+/// failed inbox write is logged, since the push already went out.
+///
+/// Domain events reach it as an event handler; the expiry alert and
+/// reminder jobs call [`dispatch`](Self::dispatch) directly.
+///
+/// Held notifications live in memory, so a restart drops them.
+pub struct NotificationDispatcher {
+    pub preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    pub vacation_repository: Arc<dyn VacationRepository>,
+    pub device_repository: Arc<dyn DeviceRepository>,
+    pub inbox_repository: Arc<dyn NotificationInboxRepository>,
+    pub sender: Arc<dyn NotificationSender>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl NotificationDispatcher {
+    pub async fn dispatch(
+        &self,
+        notification: Notification,
+    ) -> Result<Delivery, NotificationError> {
+        if notification.category == NotificationCategory::ExpiryAlerts
+            && self
+                .vacation_repository
+                .find_current(&notification.user_id)
+                .await?
+                .is_some()
+        {
+            self.logger.debug(&format!(
+                "Dropping {} notification for user {}: on vacation",
+                notification.category, notification.user_id
+            ));
+            return Ok(Delivery::Paused);
+        }
+
+        let preferences = self
+            .preference_repository
+            .get(&notification.user_id)
+            .await?;
+        let now = Utc::now();
+
+        let Some(at) = preferences.delivery_time(notification.category, now) else {
+            self.logger.debug(&format!(
+                "Dropping {} notification for user {}: category muted",
+                notification.category, notification.user_id
+            ));
+            return Ok(Delivery::Muted);
+        };
+        if at <= now {
+            deliver(
+                &*self.sender,
+                &*self.device_repository,
+                &*self.inbox_repository,
+                &*self.logger,
+                &notification,
+            )
+            .await?;
+            return Ok(Delivery::Sent);
+        }
+
+        self.logger.debug(&format!(
+            "Holding {} notification for user {} until {}",
+            notification.category, notification.user_id, at
+        ));
+        let wait = (at - now).to_std().unwrap_or_default();
+        let sender = self.sender.clone();
+        let devices = self.device_repository.clone();
+        let inbox = self.inbox_repository.clone();
+        let logger = self.logger.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(wait).await;
+            if let Err(e) = deliver(&*sender, &*devices, &*inbox, &*logger, &notification).await {
+                logger.warn(&format!(
+                    "Failed to send held notification to user {}: {}",
+                    notification.user_id, e
+                ));
+            }
+        });
+        Ok(Delivery::Scheduled(at))
+    }
+
+    async fn notify(&self, notification: Notification) {
+        let user_id = notification.user_id.clone();
+        if let Err(e) = self.dispatch(notification).await {
+            self.logger
+                .warn(&format!("Failed to notify user {}: {}", user_id, e));
+        }
+    }
+}
+
+async fn deliver(
+    sender: &dyn NotificationSender,
+    device_repository: &dyn DeviceRepository,
+    inbox_repository: &dyn NotificationInboxRepository,
+    logger: &dyn Logger,
+    notification: &Notification,
+) -> Result<(), NotificationError> {
+    let devices = device_repository
+        .find_pushable(&notification.user_id)
+        .await?;
+    sender.send(notification, &devices).await?;
+
+    // Already on the user's devices: a missing inbox entry must not report
+    // the delivery as failed and get it sent again
+    if let Err(e) = inbox_repository.record(notification, Utc::now()).await {
+        logger.warn(&format!(
+            "Sent notification {} to user {} but could not keep it in the inbox: {}",
+            notification.id, notification.user_id, e
+        ));
+    }
+    Ok(())
+}
+
+fn challenge_completed(event: &ChallengeCompleted) -> Notification {
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: event.user_id.clone(),
+        category: NotificationCategory::Achievements,
+        title: "Challenge completed".to_string(),
+        body: format!(
+            "You used {} urgent items before they expired this week.",
+            event.target
+        ),
+    }
+}
+
+fn streak_milestone(event: &StreakMilestoneReached) -> Notification {
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: event.user_id.clone(),
+        category: NotificationCategory::Achievements,
+        title: format!("{} weeks without waste", event.weeks),
+        body: format!(
+            "Nothing thrown away for {} weeks in a row. Keep it up!",
+            event.weeks
+        ),
+    }
+}
+
+fn budget_threshold_reached(event: &BudgetThresholdReached) -> Notification {
+    let (title, body) = match event.threshold {
+        BudgetThreshold::Warning => (
+            "80% of your grocery budget spent",
+            "You're getting close to this month's grocery budget.",
+        ),
+        BudgetThreshold::Exceeded => (
+            "Grocery budget spent",
+            "You've spent this month's whole grocery budget.",
+        ),
+    };
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: event.user_id.clone(),
+        category: NotificationCategory::ShoppingReminders,
+        title: title.to_string(),
+        body: body.to_string(),
+    }
+}
+
+fn vacation_ended(event: &VacationEnded) -> Notification {
+    let body = match event.expired {
+        0 => "Everything in your pantry made it through your trip.".to_string(),
+        1 => "1 item expired while you were away. Review it to clean up.".to_string(),
+        n => format!("{n} items expired while you were away. Review them to clean up."),
+    };
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: event.user_id.clone(),
+        category: NotificationCategory::ExpiryAlerts,
+        title: "Welcome back".to_string(),
+        body,
+    }
+}
+
+#[async_trait]
+impl EventHandler for NotificationDispatcher {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::ChallengeCompleted(completed) => {
+                self.notify(challenge_completed(completed)).await
+            }
+            DomainEvent::StreakMilestoneReached(reached) => {
+                self.notify(streak_milestone(reached)).await
+            }
+            DomainEvent::VacationEnded(ended) => self.notify(vacation_ended(ended)).await,
+            DomainEvent::BudgetThresholdReached(reached) => {
+                self.notify(budget_threshold_reached(reached)).await
+            }
+            DomainEvent::ProductAdded(_)
+            | DomainEvent::ProductDeleted(_)
+            | DomainEvent::ProductUpdated(_)
+            | DomainEvent::ProductOutcomeRecorded(_)
+            | DomainEvent::ProductStatusChanged(_)
+            | DomainEvent::PurchaseRecorded(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::challenge::model::ChallengeKind;
+    use crate::domain::device::model::{Device, DevicePlatform, InstallCount};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::model::{
+        Acknowledgement, CategoryToggles, NotificationPreferences, QuietHours,
+    };
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::vacation::model::Vacation;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub NotificationPreferenceRepo {}
+
+        #[async_trait]
+        impl NotificationPreferenceRepository for NotificationPreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &NotificationPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub DeviceRepo {}
+
+        #[async_trait]
+        impl DeviceRepository for DeviceRepo {
+            async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+            async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+            async fn count_seen_since(&self, since: DateTime<Utc>) -> Result<Vec<InstallCount>, RepositoryError>;
+            async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub InboxRepo {}
+
+        #[async_trait]
+        impl NotificationInboxRepository for InboxRepo {
+            async fn record(&self, notification: &Notification, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+            async fn acknowledge(&self, user_id: &UserId, acknowledgement: &Acknowledgement, read_at: DateTime<Utc>) -> Result<u64, RepositoryError>;
+            async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Sender {}
+
+        #[async_trait]
+        impl NotificationSender for Sender {
+            async fn send(&self, notification: &Notification, devices: &[Device]) -> Result<(), NotificationError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Preferences with no quiet hours, so only the category decides.
+    fn preferences(categories: CategoryToggles) -> MockNotificationPreferenceRepo {
+        let mut repo = MockNotificationPreferenceRepo::new();
+        repo.expect_get().returning(move |_| {
+            Ok(NotificationPreferences {
+                quiet_hours: None,
+                categories,
+                ..NotificationPreferences::default()
+            })
+        });
+        repo
+    }
+
+    fn at_home() -> Arc<dyn VacationRepository> {
+        let mut repo = MockVacationRepo::new();
+        repo.expect_find_current().returning(|_| Ok(None));
+        Arc::new(repo)
+    }
+
+    fn no_devices() -> Arc<dyn DeviceRepository> {
+        let mut repo = MockDeviceRepo::new();
+        repo.expect_find_pushable().returning(|_| Ok(vec![]));
+        Arc::new(repo)
+    }
+
+    fn inbox() -> Arc<dyn NotificationInboxRepository> {
+        let mut repo = MockInboxRepo::new();
+        repo.expect_record().returning(|_, _| Ok(()));
+        Arc::new(repo)
+    }
+
+    fn notification(category: NotificationCategory) -> Notification {
+        Notification {
+            id: Uuid::new_v4(),
+            user_id: UserId::new("test-user-id"),
+            category,
+            title: "Leche".to_string(),
+            body: "Expires tomorrow".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_send_right_away_when_category_is_not_digested() {
+        let mut sender = MockSender::new();
+        sender.expect_send().times(1).returning(|_, _| Ok(()));
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ExpiryAlerts))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery, Delivery::Sent);
+    }
+
+    #[tokio::test]
+    async fn should_push_to_the_installs_the_user_registered() {
+        let mut devices = MockDeviceRepo::new();
+        devices.expect_find_pushable().returning(|user_id| {
+            Ok(vec![
+                Device::register(
+                    user_id.clone(),
+                    "install-1",
+                    DevicePlatform::Android,
+                    "3.1.0",
+                    Some("fcm-token"),
+                    Utc::now(),
+                )
+                .unwrap(),
+            ])
+        });
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .withf(|_, devices| {
+                devices.len() == 1 && devices[0].push_token.as_deref() == Some("fcm-token")
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: Arc::new(devices),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ShoppingReminders))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery, Delivery::Sent);
+    }
+
+    #[tokio::test]
+    async fn should_report_sent_when_inbox_write_fails() {
+        let mut sender = MockSender::new();
+        sender.expect_send().times(1).returning(|_, _| Ok(()));
+        let mut inbox = MockInboxRepo::new();
+        inbox
+            .expect_record()
+            .times(1)
+            .returning(|_, _| Err(RepositoryError::database_error("connection reset")));
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: Arc::new(inbox),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ExpiryAlerts))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery, Delivery::Sent);
+    }
+
+    #[tokio::test]
+    async fn should_keep_sent_notifications_in_the_inbox() {
+        let notification = notification(NotificationCategory::ExpiryAlerts);
+        let id = notification.id;
+        let mut inbox = MockInboxRepo::new();
+        inbox
+            .expect_record()
+            .withf(move |recorded, _| recorded.id == id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut sender = MockSender::new();
+        sender.expect_send().times(1).returning(|_, _| Ok(()));
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: Arc::new(inbox),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        dispatcher.dispatch(notification).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn should_hold_digest_categories_for_later() {
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::Suggestions))
+            .await
+            .unwrap();
+
+        assert!(matches!(delivery, Delivery::Scheduled(at) if at > Utc::now()));
+    }
+
+    #[tokio::test]
+    async fn should_hold_reminders_raised_in_quiet_hours() {
+        let now = Utc::now();
+        let mut quiet = MockNotificationPreferenceRepo::new();
+        quiet.expect_get().returning(move |_| {
+            Ok(NotificationPreferences {
+                quiet_hours: Some(
+                    QuietHours::new(
+                        (now - Duration::hours(1)).time(),
+                        (now + Duration::hours(1)).time(),
+                    )
+                    .unwrap(),
+                ),
+                ..NotificationPreferences::default()
+            })
+        });
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(quiet),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ShoppingReminders))
+            .await
+            .unwrap();
+
+        assert!(matches!(delivery, Delivery::Scheduled(at) if at > now));
+    }
+
+    #[tokio::test]
+    async fn should_drop_notifications_of_muted_categories() {
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles {
+                expiry_alerts: false,
+                ..CategoryToggles::default()
+            })),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ExpiryAlerts))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery, Delivery::Muted);
+    }
+
+    #[tokio::test]
+    async fn should_pause_expiry_alerts_while_on_vacation() {
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+        let mut vacations = MockVacationRepo::new();
+        vacations.expect_find_current().returning(|user_id| {
+            Ok(Some(
+                Vacation::start(user_id.clone(), None, Utc::now()).unwrap(),
+            ))
+        });
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: Arc::new(vacations),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        let delivery = dispatcher
+            .dispatch(notification(NotificationCategory::ExpiryAlerts))
+            .await
+            .unwrap();
+
+        assert_eq!(delivery, Delivery::Paused);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_send_achievement_at_digest_when_challenge_completed() {
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .withf(|notification, _| notification.category == NotificationCategory::Achievements)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let dispatcher = NotificationDispatcher {
+            preference_repository: Arc::new(preferences(CategoryToggles::default())),
+            vacation_repository: at_home(),
+            device_repository: no_devices(),
+            inbox_repository: inbox(),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        };
+
+        dispatcher
+            .handle(&DomainEvent::ChallengeCompleted(ChallengeCompleted {
+                challenge_id: Uuid::new_v4(),
+                user_id: UserId::new("test-user-id"),
+                kind: ChallengeKind::UseUrgentItems,
+                target: 3,
+            }))
+            .await;
+        tokio::time::sleep(std::time::Duration::from_secs(24 * 60 * 60)).await;
+    }
+}
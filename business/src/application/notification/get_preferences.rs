@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::NotificationPreferences;
+use crate::domain::notification::repository::NotificationPreferenceRepository;
+use crate::domain::notification::use_cases::get_preferences::{
+    GetNotificationPreferencesParams, GetNotificationPreferencesUseCase,
+};
+
+pub struct GetNotificationPreferencesUseCaseImpl {
+    pub repository: Arc<dyn NotificationPreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetNotificationPreferencesUseCase for GetNotificationPreferencesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetNotificationPreferencesParams,
+    ) -> Result<NotificationPreferences, NotificationError> {
+        self.logger.info("Getting notification preferences");
+        Ok(self.repository.get(&params.user_id).await?)
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::repository::NotificationInboxRepository;
+use crate::domain::notification::use_cases::prune::{
+    PruneNotificationsParams, PruneNotificationsUseCase,
+};
+
+pub struct PruneNotificationsUseCaseImpl {
+    pub repository: Arc<dyn NotificationInboxRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl PruneNotificationsUseCase for PruneNotificationsUseCaseImpl {
+    async fn execute(&self, params: PruneNotificationsParams) -> Result<u64, NotificationError> {
+        let before = Utc::now() - Duration::days(i64::from(params.retention_days));
+        let pruned = self.repository.delete_sent_before(before).await?;
+
+        if pruned > 0 {
+            self.logger.info(&format!(
+                "Pruned {} notifications older than {} days",
+                pruned, params.retention_days
+            ));
+        }
+        Ok(pruned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::model::{Acknowledgement, Notification};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::DateTime;
+    use mockall::mock;
+
+    mock! {
+        pub InboxRepo {}
+
+        #[async_trait]
+        impl NotificationInboxRepository for InboxRepo {
+            async fn record(&self, notification: &Notification, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+            async fn acknowledge(&self, user_id: &UserId, acknowledgement: &Acknowledgement, read_at: DateTime<Utc>) -> Result<u64, RepositoryError>;
+            async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_delete_notifications_sent_before_retention_cutoff() {
+        let earliest = Utc::now() - Duration::days(90);
+        let mut mock_repo = MockInboxRepo::new();
+        mock_repo
+            .expect_delete_sent_before()
+            .withf(move |before| *before >= earliest && *before <= Utc::now() - Duration::days(90))
+            .times(1)
+            .returning(|_| Ok(7));
+
+        let use_case = PruneNotificationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let pruned = use_case
+            .execute(PruneNotificationsParams { retention_days: 90 })
+            .await
+            .unwrap();
+
+        assert_eq!(pruned, 7);
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_repository_fails() {
+        let mut mock_repo = MockInboxRepo::new();
+        mock_repo
+            .expect_delete_sent_before()
+            .returning(|_| Err(RepositoryError::Persistence));
+
+        let use_case = PruneNotificationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(PruneNotificationsParams { retention_days: 90 })
+            .await;
+
+        assert!(result.is_err());
+    }
+}
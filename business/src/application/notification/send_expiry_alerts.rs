@@ -0,0 +1,514 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::notification::dispatcher::NotificationDispatcher;
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::{
+    Delivery, Notification, NotificationCategory, NotificationLanguage,
+};
+use crate::domain::notification::use_cases::send_expiry_alerts::{
+    SendExpiryAlertsParams, SendExpiryAlertsUseCase,
+};
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::model::Product;
+use crate::domain::product::query::{ProductQuery, ProductSort};
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::urgency::urgent_until;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct SendExpiryAlertsUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    /// Alerts cover the products the user's expiring-soon window flags
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    /// Alerts go through the dispatcher, which drops them for users on
+    /// vacation or with the category muted
+    pub dispatcher: Arc<NotificationDispatcher>,
+    pub language: NotificationLanguage,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl SendExpiryAlertsUseCaseImpl {
+    async fn alert(&self, user_id: &UserId) -> Result<bool, NotificationError> {
+        let now = Utc::now();
+        let preferences = self.preference_repository.get(user_id).await?;
+        let query = ProductQuery::active(user_id.clone())
+            .expiring_between(now, urgent_until(now, preferences.expiring_soon))
+            .sorted_by(ProductSort::ExpiryAsc);
+        let products = self.product_repository.find(&query).await?;
+        if products.is_empty() {
+            return Ok(false);
+        }
+
+        let delivery = self
+            .dispatcher
+            .dispatch(expiry_alert(user_id, &products, self.language))
+            .await?;
+        Ok(matches!(delivery, Delivery::Sent | Delivery::Scheduled(_)))
+    }
+}
+
+#[async_trait]
+impl SendExpiryAlertsUseCase for SendExpiryAlertsUseCaseImpl {
+    async fn execute(&self, params: SendExpiryAlertsParams) -> Result<u32, NotificationError> {
+        let mut alerted = 0;
+        let mut after = None;
+        loop {
+            let users = self
+                .product_repository
+                .get_users_with_active_products(after.take(), params.batch_size)
+                .await?;
+            let Some(last) = users.last().cloned() else {
+                break;
+            };
+
+            for user_id in users {
+                match self.alert(&user_id).await {
+                    Ok(true) => alerted += 1,
+                    Ok(false) => {}
+                    Err(e) => self.logger.warn(&format!(
+                        "Failed to send expiry alert to user {}: {}",
+                        user_id, e
+                    )),
+                }
+            }
+
+            after = Some(last);
+        }
+
+        if alerted > 0 {
+            self.logger
+                .info(&format!("Sent expiry alerts to {} users", alerted));
+        }
+        Ok(alerted)
+    }
+}
+
+/// Wording of an expiry alert. In the bodies, `{first}` is the product
+/// expiring soonest and `{others}` how many more there are.
+struct ExpiryAlertTemplate {
+    title: &'static str,
+    one: &'static str,
+    two: &'static str,
+    many: &'static str,
+}
+
+impl ExpiryAlertTemplate {
+    fn for_language(language: NotificationLanguage) -> Self {
+        match language {
+            NotificationLanguage::En => Self {
+                title: "Expiring soon",
+                one: "{first} is expiring soon. Use it while it's good.",
+                two: "{first} and 1 more item are expiring soon.",
+                many: "{first} and {others} more items are expiring soon.",
+            },
+            NotificationLanguage::Es => Self {
+                title: "Caduca pronto",
+                one: "{first} caduca pronto. Aprovéchalo mientras esté bueno.",
+                two: "{first} y 1 producto más caducan pronto.",
+                many: "{first} y {others} productos más caducan pronto.",
+            },
+        }
+    }
+
+    /// `products` is soonest first and never empty.
+    fn body(&self, products: &[Product]) -> String {
+        let body = match products.len() {
+            1 => self.one,
+            2 => self.two,
+            _ => self.many,
+        };
+        body.replace("{first}", &products[0].name)
+            .replace("{others}", &(products.len() - 1).to_string())
+    }
+}
+
+/// `products` is soonest first and never empty.
+fn expiry_alert(
+    user_id: &UserId,
+    products: &[Product],
+    language: NotificationLanguage,
+) -> Notification {
+    let template = ExpiryAlertTemplate::for_language(language);
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: user_id.clone(),
+        category: NotificationCategory::ExpiryAlerts,
+        title: template.title.to_string(),
+        body: template.body(products),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::device::model::{Device, InstallCount};
+    use crate::domain::device::repository::DeviceRepository;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::model::{Acknowledgement, NotificationPreferences};
+    use crate::domain::notification::repository::{
+        NotificationInboxRepository, NotificationPreferenceRepository,
+    };
+    use crate::domain::notification::services::NotificationSender;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::urgency::ExpiringSoonWindow;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::vacation::model::Vacation;
+    use crate::domain::vacation::repository::VacationRepository;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub NotificationPreferenceRepo {}
+
+        #[async_trait]
+        impl NotificationPreferenceRepository for NotificationPreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &NotificationPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub DeviceRepo {}
+
+        #[async_trait]
+        impl DeviceRepository for DeviceRepo {
+            async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+            async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+            async fn count_seen_since(&self, since: DateTime<Utc>) -> Result<Vec<InstallCount>, RepositoryError>;
+            async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub InboxRepo {}
+
+        #[async_trait]
+        impl NotificationInboxRepository for InboxRepo {
+            async fn record(&self, notification: &Notification, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+            async fn acknowledge(&self, user_id: &UserId, acknowledgement: &Acknowledgement, read_at: DateTime<Utc>) -> Result<u64, RepositoryError>;
+            async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Sender {}
+
+        #[async_trait]
+        impl NotificationSender for Sender {
+            async fn send(&self, notification: &Notification, devices: &[Device]) -> Result<(), NotificationError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Dispatcher with no quiet hours, so alerts go out right away unless
+    /// the user is on vacation.
+    fn dispatcher(sender: MockSender, on_vacation: bool) -> Arc<NotificationDispatcher> {
+        let mut preferences = MockNotificationPreferenceRepo::new();
+        preferences.expect_get().returning(|_| {
+            Ok(NotificationPreferences {
+                quiet_hours: None,
+                ..NotificationPreferences::default()
+            })
+        });
+        let mut vacations = MockVacationRepo::new();
+        vacations.expect_find_current().returning(move |user_id| {
+            Ok(on_vacation.then(|| Vacation::start(user_id.clone(), None, Utc::now()).unwrap()))
+        });
+        let mut devices = MockDeviceRepo::new();
+        devices.expect_find_pushable().returning(|_| Ok(vec![]));
+        let mut inbox = MockInboxRepo::new();
+        inbox.expect_record().returning(|_, _| Ok(()));
+
+        Arc::new(NotificationDispatcher {
+            preference_repository: Arc::new(preferences),
+            vacation_repository: Arc::new(vacations),
+            device_repository: Arc::new(devices),
+            inbox_repository: Arc::new(inbox),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        })
+    }
+
+    fn expiring(name: &str) -> Product {
+        let now = Utc::now();
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            name.to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            Some(now + Duration::hours(6)),
+            None,
+            ExpiryType::BestBefore,
+            None,
+            now,
+            now,
+        )
+    }
+
+    /// Users whose expiring-soon window is `days` long.
+    fn expiring_soon_days(days: u8) -> Arc<dyn PreferenceRepository> {
+        let mut preferences = MockPreferenceRepo::new();
+        preferences.expect_get().returning(move |_| {
+            Ok(UserPreferences {
+                expiring_soon: ExpiringSoonWindow::new(days).unwrap(),
+                ..UserPreferences::default()
+            })
+        });
+        Arc::new(preferences)
+    }
+
+    /// Each of `users`, paged through like the repository does, has
+    /// `products` expiring within the default expiring-soon window.
+    fn products_expiring(
+        users: &'static [&'static str],
+        products: Vec<Product>,
+    ) -> MockProductRepo {
+        products_expiring_within(users, products, ExpiringSoonWindow::default())
+    }
+
+    fn products_expiring_within(
+        users: &'static [&'static str],
+        products: Vec<Product>,
+        window: ExpiringSoonWindow,
+    ) -> MockProductRepo {
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_users_with_active_products()
+            .returning(move |after, limit| {
+                Ok(users
+                    .iter()
+                    .filter(|id| after.as_ref().is_none_or(|after| **id > after.as_str()))
+                    .take(limit)
+                    .map(|id| UserId::new(*id))
+                    .collect())
+            });
+        repo.expect_find()
+            .withf(move |query| {
+                query.expiring_between.is_some_and(|(from, until)| {
+                    from <= Utc::now() && until == urgent_until(from, window)
+                })
+            })
+            .returning(move |_| Ok(products.clone()));
+        repo
+    }
+
+    #[tokio::test]
+    async fn should_alert_user_about_products_expiring_soon() {
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .withf(|notification, _| {
+                notification.category == NotificationCategory::ExpiryAlerts
+                    && notification.body == "Leche and 1 more item are expiring soon."
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring(
+                &["test-user-id"],
+                vec![expiring("Leche"), expiring("Yogur")],
+            )),
+            preference_repository: expiring_soon_days(ExpiringSoonWindow::DEFAULT_DAYS),
+            dispatcher: dispatcher(sender, false),
+            language: NotificationLanguage::En,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_alert_user_without_products_expiring_soon() {
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring(&["test-user-id"], vec![])),
+            preference_repository: expiring_soon_days(ExpiringSoonWindow::DEFAULT_DAYS),
+            dispatcher: dispatcher(sender, false),
+            language: NotificationLanguage::En,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 0);
+    }
+
+    #[tokio::test]
+    async fn should_not_alert_user_on_vacation() {
+        let mut sender = MockSender::new();
+        sender.expect_send().never();
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring(
+                &["test-user-id"],
+                vec![expiring("Leche")],
+            )),
+            preference_repository: expiring_soon_days(ExpiringSoonWindow::DEFAULT_DAYS),
+            dispatcher: dispatcher(sender, true),
+            language: NotificationLanguage::En,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 0);
+    }
+
+    #[tokio::test]
+    async fn should_alert_every_user_past_the_first_batch() {
+        let mut sender = MockSender::new();
+        sender.expect_send().times(3).returning(|_, _| Ok(()));
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring(
+                &["ana", "luis", "marta"],
+                vec![expiring("Leche")],
+            )),
+            preference_repository: expiring_soon_days(ExpiringSoonWindow::DEFAULT_DAYS),
+            dispatcher: dispatcher(sender, false),
+            language: NotificationLanguage::En,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 2 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 3);
+    }
+
+    #[tokio::test]
+    async fn should_look_as_far_ahead_as_the_users_expiring_soon_window() {
+        let mut sender = MockSender::new();
+        sender.expect_send().times(1).returning(|_, _| Ok(()));
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring_within(
+                &["test-user-id"],
+                vec![expiring("Leche")],
+                ExpiringSoonWindow::new(7).unwrap(),
+            )),
+            preference_repository: expiring_soon_days(7),
+            dispatcher: dispatcher(sender, false),
+            language: NotificationLanguage::En,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 1);
+    }
+
+    #[tokio::test]
+    async fn should_write_alert_in_configured_language() {
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .withf(|notification, _| {
+                notification.title == "Caduca pronto"
+                    && notification.body == "Leche y 2 productos más caducan pronto."
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = SendExpiryAlertsUseCaseImpl {
+            product_repository: Arc::new(products_expiring(
+                &["test-user-id"],
+                vec![expiring("Leche"), expiring("Yogur"), expiring("Pan")],
+            )),
+            preference_repository: expiring_soon_days(ExpiringSoonWindow::DEFAULT_DAYS),
+            dispatcher: dispatcher(sender, false),
+            language: NotificationLanguage::Es,
+            logger: mock_logger(),
+        };
+
+        let alerted = use_case
+            .execute(SendExpiryAlertsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(alerted, 1);
+    }
+}
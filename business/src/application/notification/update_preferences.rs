@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::NotificationPreferences;
+use crate::domain::notification::repository::NotificationPreferenceRepository;
+use crate::domain::notification::use_cases::update_preferences::{
+    UpdateNotificationPreferencesParams, UpdateNotificationPreferencesUseCase,
+};
+
+pub struct UpdateNotificationPreferencesUseCaseImpl {
+    pub repository: Arc<dyn NotificationPreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl UpdateNotificationPreferencesUseCase for UpdateNotificationPreferencesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: UpdateNotificationPreferencesParams,
+    ) -> Result<NotificationPreferences, NotificationError> {
+        self.logger.info(&format!(
+            "Setting notification digest to {} at UTC offset {} minutes",
+            params.digest_at, params.utc_offset_minutes
+        ));
+
+        let preferences = NotificationPreferences::new(
+            params.utc_offset_minutes,
+            params.digest_at,
+            params.quiet_hours,
+            params.categories,
+        )?;
+        self.repository.save(&params.user_id, &preferences).await?;
+        Ok(preferences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::model::CategoryToggles;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::NaiveTime;
+    use mockall::mock;
+
+    mock! {
+        pub NotificationPreferenceRepo {}
+
+        #[async_trait]
+        impl NotificationPreferenceRepository for NotificationPreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &NotificationPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn time(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    fn params(quiet_hours: Option<(NaiveTime, NaiveTime)>) -> UpdateNotificationPreferencesParams {
+        UpdateNotificationPreferencesParams {
+            user_id: UserId::new("test-user-id"),
+            utc_offset_minutes: 120,
+            digest_at: time(19),
+            quiet_hours,
+            categories: CategoryToggles {
+                suggestions: false,
+                ..CategoryToggles::default()
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn should_save_preferences_when_valid() {
+        let mut mock_repo = MockNotificationPreferenceRepo::new();
+        mock_repo
+            .expect_save()
+            .withf(|_, preferences| {
+                preferences.utc_offset_minutes() == 120
+                    && preferences.quiet_hours.is_none()
+                    && !preferences.categories.suggestions
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = UpdateNotificationPreferencesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let preferences = use_case.execute(params(None)).await.unwrap();
+
+        assert_eq!(preferences.digest_at, time(19));
+    }
+
+    #[tokio::test]
+    async fn should_reject_empty_quiet_hours_without_saving() {
+        let mut mock_repo = MockNotificationPreferenceRepo::new();
+        mock_repo.expect_save().never();
+
+        let use_case = UpdateNotificationPreferencesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params(Some((time(23), time(23))))).await;
+
+        assert!(matches!(result, Err(NotificationError::InvalidQuietHours)));
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::errors::PreferenceError;
+use crate::domain::preference::model::UserPreferences;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::preference::use_cases::get::{GetPreferencesParams, GetPreferencesUseCase};
+
+pub struct GetPreferencesUseCaseImpl {
+    pub repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetPreferencesUseCase for GetPreferencesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetPreferencesParams,
+    ) -> Result<UserPreferences, PreferenceError> {
+        self.logger.info("Getting user preferences");
+        Ok(self.repository.get(&params.user_id).await?)
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::errors::PreferenceError;
+use crate::domain::preference::model::UserPreferences;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::preference::use_cases::update::{
+    UpdatePreferencesParams, UpdatePreferencesUseCase,
+};
+use crate::domain::product::urgency::ExpiringSoonWindow;
+
+pub struct UpdatePreferencesUseCaseImpl {
+    pub repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl UpdatePreferencesUseCase for UpdatePreferencesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: UpdatePreferencesParams,
+    ) -> Result<UserPreferences, PreferenceError> {
+        self.logger.info(&format!(
+            "Setting expiring-soon window to {} days",
+            params.expiring_soon_days
+        ));
+
+        let mut allergies = params.allergies;
+        allergies.sort();
+        allergies.dedup();
+
+        let preferences = UserPreferences {
+            expiring_soon: ExpiringSoonWindow::new(params.expiring_soon_days)
+                .ok_or(PreferenceError::InvalidExpiringSoonDays)?,
+            allergies,
+        };
+        self.repository.save(&params.user_id, &preferences).await?;
+        Ok(preferences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::enrichment::Allergen;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use mockall::predicate::eq;
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_save_chosen_expiring_soon_window() {
+        let expected = UserPreferences {
+            expiring_soon: ExpiringSoonWindow::new(5).unwrap(),
+            allergies: vec![],
+        };
+        let mut mock_repo = MockPreferenceRepo::new();
+        mock_repo
+            .expect_save()
+            .with(eq(test_user_id()), eq(expected.clone()))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = UpdatePreferencesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let preferences = use_case
+            .execute(UpdatePreferencesParams {
+                user_id: test_user_id(),
+                expiring_soon_days: 5,
+                allergies: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(preferences, expected);
+    }
+
+    #[tokio::test]
+    async fn should_reject_window_outside_allowed_range() {
+        let mut mock_repo = MockPreferenceRepo::new();
+        mock_repo.expect_save().never();
+
+        let use_case = UpdatePreferencesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        for days in [0, ExpiringSoonWindow::MAX_DAYS + 1] {
+            let result = use_case
+                .execute(UpdatePreferencesParams {
+                    user_id: test_user_id(),
+                    expiring_soon_days: days,
+                    allergies: vec![],
+                })
+                .await;
+
+            assert!(matches!(
+                result.unwrap_err(),
+                PreferenceError::InvalidExpiringSoonDays
+            ));
+        }
+    }
+    #[tokio::test]
+    async fn should_save_allergies_without_duplicates() {
+        let mut mock_repo = MockPreferenceRepo::new();
+        mock_repo
+            .expect_save()
+            .withf(|_, preferences| preferences.allergies == [Allergen::Milk, Allergen::Peanuts])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = UpdatePreferencesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let preferences = use_case
+            .execute(UpdatePreferencesParams {
+                user_id: test_user_id(),
+                expiring_soon_days: 3,
+                allergies: vec![Allergen::Peanuts, Allergen::Milk, Allergen::Peanuts],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(preferences.allergies, [Allergen::Milk, Allergen::Peanuts]);
+    }
+}
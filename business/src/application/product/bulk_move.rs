@@ -0,0 +1,396 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::application::product::estimate_missing::EstimateMissingRunner;
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::job::model::{Job, JobKind};
+use crate::domain::job::repository::JobRepository;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductUpdated;
+use crate::domain::product::model::Product;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::bulk_move::{
+    BulkMoveProductsParams, BulkMoveProductsUseCase,
+};
+use crate::domain::product::value_objects::ProductStatus;
+
+pub struct BulkMoveProductsUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub job_repository: Arc<dyn JobRepository>,
+    /// Shared with estimate-missing: re-estimates one product at a time
+    pub runner: Arc<EstimateMissingRunner>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+/// Whether a moved product's estimated expiry date should be redone. Printed
+/// dates don't depend on where the product is kept, and finished products
+/// don't expire anymore.
+fn needs_reestimate(product: &Product) -> bool {
+    product.expiry_date.is_none() && product.status != ProductStatus::Finished
+}
+
+#[async_trait]
+impl BulkMoveProductsUseCase for BulkMoveProductsUseCaseImpl {
+    async fn execute(&self, params: BulkMoveProductsParams) -> Result<Job, ProductError> {
+        let mut product_ids = params.product_ids;
+        let mut seen = std::collections::HashSet::new();
+        product_ids.retain(|id| seen.insert(*id));
+
+        self.logger.info(&format!(
+            "Moving {} products to {}",
+            product_ids.len(),
+            params.location
+        ));
+
+        // Every product is loaded before any is written, so a wrong ID moves nothing
+        let mut products = Vec::with_capacity(product_ids.len());
+        for id in &product_ids {
+            let product = self
+                .product_repository
+                .get_by_id(*id, &params.user_id)
+                .await
+                .map_err(|e| match e {
+                    RepositoryError::NotFound => ProductError::NotFound,
+                    other => ProductError::Repository(other),
+                })?;
+            products.push(product);
+        }
+
+        let mut to_reestimate: Vec<Uuid> = Vec::new();
+        for mut product in products {
+            if !product.move_to(params.location.clone()) {
+                continue;
+            }
+            self.product_repository.update(&product).await?;
+            self.event_publisher
+                .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                    product_id: product.id,
+                    user_id: params.user_id.clone(),
+                }))
+                .await;
+            if needs_reestimate(&product) {
+                to_reestimate.push(product.id);
+            }
+        }
+
+        let mut job = Job::new(
+            params.user_id,
+            JobKind::ReestimateMovedExpiry,
+            to_reestimate.len() as u32,
+        );
+        if to_reestimate.is_empty() {
+            job.complete();
+            self.job_repository.insert(&job).await?;
+            return Ok(job);
+        }
+        self.job_repository.insert(&job).await?;
+
+        let runner = self.runner.clone();
+        let pending = job.clone();
+        tokio::spawn(async move {
+            runner.run(pending, to_reestimate).await;
+        });
+
+        self.logger.info(&format!(
+            "Expiry re-estimation job {} queued for {} moved products",
+            job.id, job.progress.total
+        ));
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::events::in_process::InProcessEventBus;
+    use crate::application::product::change_feed::ProductChangeFeed;
+    use crate::domain::job::model::JobStatus;
+    use crate::domain::product::events::ProductChangeKind;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::use_cases::estimate_expiry::{
+        EstimateExpiryParams, EstimateExpiryUseCase,
+    };
+    use crate::domain::product::value_objects::{ExpiryType, ProductLocation};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use futures::StreamExt;
+    use mockall::mock;
+    use std::time::Duration;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub JobRepo {}
+
+        #[async_trait]
+        impl JobRepository for JobRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+            async fn find_unfinished(&self, user_id: &UserId, kind: JobKind) -> Result<Option<Job>, RepositoryError>;
+            async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+            async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub EstimateExpiry {}
+
+        #[async_trait]
+        impl EstimateExpiryUseCase for EstimateExpiry {
+            async fn execute(&self, params: EstimateExpiryParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(
+        location: ProductLocation,
+        expiry_date: Option<chrono::DateTime<Utc>>,
+    ) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Pechuga de pollo".to_string(),
+            ProductStatus::New,
+            Some(location),
+            None,
+            expiry_date,
+            Some(Utc::now()),
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn runner() -> Arc<EstimateMissingRunner> {
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs.expect_update().returning(|_| Ok(()));
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate
+            .expect_execute()
+            .returning(|_| Ok(make_product(ProductLocation::Freezer, None)));
+        Arc::new(EstimateMissingRunner {
+            job_repository: Arc::new(mock_jobs),
+            estimate_use_case: Arc::new(mock_estimate),
+            pause: Duration::ZERO,
+            logger: mock_logger(),
+        })
+    }
+
+    #[tokio::test]
+    async fn should_move_products_and_reestimate_only_moved_ones_without_printed_date() {
+        let estimated = make_product(ProductLocation::Fridge, None);
+        let printed = make_product(ProductLocation::Fridge, Some(Utc::now()));
+        let already_there = make_product(ProductLocation::Freezer, None);
+        let ids = vec![estimated.id, printed.id, already_there.id, estimated.id];
+        let products = [estimated.clone(), printed.clone(), already_there];
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_get_by_id()
+            .returning(move |id, _| Ok(products.iter().find(|p| p.id == id).cloned().unwrap()));
+        mock_products
+            .expect_update()
+            .withf(|p| p.location == Some(ProductLocation::Freezer))
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_insert()
+            .withf(|job| {
+                job.kind == JobKind::ReestimateMovedExpiry
+                    && job.status == JobStatus::Pending
+                    && job.progress.total == 1
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = BulkMoveProductsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: runner(),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let job = use_case
+            .execute(BulkMoveProductsParams {
+                user_id: test_user_id(),
+                product_ids: ids,
+                location: ProductLocation::Freezer,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.progress.total, 1);
+    }
+
+    #[tokio::test]
+    async fn should_move_nothing_when_a_product_is_missing() {
+        let existing = make_product(ProductLocation::Fridge, None);
+        let existing_id = existing.id;
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_get_by_id().returning(move |id, _| {
+            if id == existing_id {
+                Ok(existing.clone())
+            } else {
+                Err(RepositoryError::NotFound)
+            }
+        });
+        mock_products.expect_update().never();
+
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs.expect_insert().never();
+
+        let use_case = BulkMoveProductsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: runner(),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(BulkMoveProductsParams {
+                user_id: test_user_id(),
+                product_ids: vec![existing_id, Uuid::new_v4()],
+                location: ProductLocation::Freezer,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn should_complete_right_away_when_nothing_needs_reestimating() {
+        let printed = make_product(ProductLocation::Fridge, Some(Utc::now()));
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(printed.clone()));
+        mock_products.expect_update().times(1).returning(|_| Ok(()));
+
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_insert()
+            .withf(|job| job.status == JobStatus::Completed)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = BulkMoveProductsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: runner(),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let job = use_case
+            .execute(BulkMoveProductsParams {
+                user_id: test_user_id(),
+                product_ids: vec![Uuid::new_v4()],
+                location: ProductLocation::Pantry,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.progress.total, 0);
+    }
+
+    #[tokio::test]
+    async fn should_show_moved_products_on_the_change_feed() {
+        let moved = make_product(ProductLocation::Fridge, Some(Utc::now()));
+        let moved_id = moved.id;
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(moved.clone()));
+        mock_products.expect_update().returning(|_| Ok(()));
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs.expect_insert().returning(|_| Ok(()));
+
+        let feed = Arc::new(ProductChangeFeed::default());
+        let mut changes = feed.subscribe(test_user_id());
+        let use_case = BulkMoveProductsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: runner(),
+            event_publisher: Arc::new(InProcessEventBus {
+                handlers: vec![feed.clone()],
+            }),
+            logger: mock_logger(),
+        };
+
+        use_case
+            .execute(BulkMoveProductsParams {
+                user_id: test_user_id(),
+                product_ids: vec![moved_id],
+                location: ProductLocation::Pantry,
+            })
+            .await
+            .unwrap();
+
+        let change = changes.next().await.unwrap();
+        assert_eq!(change.product_id, moved_id);
+        assert_eq!(change.kind, ProductChangeKind::Updated);
+    }
+}
@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+use crate::domain::events::{DomainEvent, EventHandler};
+use crate::domain::product::events::ProductChange;
+use crate::domain::shared::value_objects::UserId;
+
+/// Product writes not yet read by the slowest subscriber before it is dropped
+const FEED_CAPACITY: usize = 256;
+
+/// Fans product writes out to the devices following a pantry, so phones
+/// sharing a kitchen stay in sync without reloading.
+///
+/// Writes are only seen by subscribers connected to this process when they
+/// happen; nothing is replayed.
+pub struct ProductChangeFeed {
+    sender: broadcast::Sender<ProductChange>,
+}
+
+impl Default for ProductChangeFeed {
+    fn default() -> Self {
+        let (sender, _) = broadcast::channel(FEED_CAPACITY);
+        Self { sender }
+    }
+}
+
+impl ProductChangeFeed {
+    /// Writes to the user's products from now on.
+    pub fn subscribe(&self, user_id: UserId) -> BoxStream<'static, ProductChange> {
+        let receiver = self.sender.subscribe();
+        Box::pin(stream::unfold(receiver, move |mut receiver| {
+            let user_id = user_id.clone();
+            async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(change) if change.user_id == user_id => {
+                            return Some((change, receiver));
+                        }
+                        Ok(_) => continue,
+                        // A lagging subscriber has missed writes; ending the
+                        // stream makes the client reload instead of drifting
+                        Err(RecvError::Lagged(_) | RecvError::Closed) => return None,
+                    }
+                }
+            }
+        }))
+    }
+}
+
+#[async_trait]
+impl EventHandler for ProductChangeFeed {
+    async fn handle(&self, event: &DomainEvent) {
+        if let Some(change) = ProductChange::from_event(event) {
+            // No subscriber is the common case, not an error
+            let _ = self.sender.send(change);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::events::{ProductChangeKind, ProductDeleted, ProductUpdated};
+    use futures::StreamExt;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn should_stream_only_the_subscribers_own_products() {
+        let feed = ProductChangeFeed::default();
+        let mine = Uuid::new_v4();
+        let mut changes = feed.subscribe(UserId::new("me"));
+
+        feed.handle(&DomainEvent::ProductUpdated(ProductUpdated {
+            product_id: Uuid::new_v4(),
+            user_id: UserId::new("someone-else"),
+        }))
+        .await;
+        feed.handle(&DomainEvent::ProductDeleted(ProductDeleted {
+            product_id: mine,
+            user_id: UserId::new("me"),
+        }))
+        .await;
+
+        let change = changes.next().await.unwrap();
+        assert_eq!(change.product_id, mine);
+        assert_eq!(change.kind, ProductChangeKind::Deleted);
+    }
+
+    #[tokio::test]
+    async fn should_end_the_stream_of_a_subscriber_left_behind() {
+        let feed = ProductChangeFeed::default();
+        let mut changes = feed.subscribe(UserId::new("me"));
+
+        for _ in 0..=FEED_CAPACITY {
+            feed.handle(&DomainEvent::ProductUpdated(ProductUpdated {
+                product_id: Uuid::new_v4(),
+                user_id: UserId::new("me"),
+            }))
+            .await;
+        }
+
+        assert!(changes.next().await.is_none());
+    }
+}
@@ -1,64 +1,267 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 
+use crate::application::product::estimation_retry::EstimationRetryRunner;
+use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::feature_flag::model::{Feature, FeatureFlags};
+use crate::domain::location_rule::repository::LocationRuleRepository;
 use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductAdded;
+use crate::domain::product::expiry_snap::ExpirySnap;
 use crate::domain::product::model::{NewProductProps, Product};
-use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::repository::{ProductEnrichmentRepository, ProductRepository};
 use crate::domain::product::services::ExpiryEstimatorService;
+use crate::domain::product::shelf_life::estimate_from_category;
+use crate::domain::product::urgency::{ExpiringSoonWindow, get_urgency_level};
 use crate::domain::product::use_cases::create::{CreateProductParams, CreateProductUseCase};
+use crate::domain::product::value_objects::{EstimationStatus, ProductLocation};
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::shared::value_objects::UserId;
 
 pub struct CreateProductUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
     pub estimator: Arc<dyn ExpiryEstimatorService>,
+    /// Normalization applied to estimated dates before they are stored.
+    pub expiry_snap: ExpirySnap,
+    /// Adding a name the user already added within this window returns the
+    /// earlier product instead, so two devices adding the same item at once
+    /// don't duplicate it. `None` allows duplicates.
+    pub duplicate_window: Option<Duration>,
+    pub quota_service: Arc<dyn QuotaService>,
+    /// With `ExpiryEstimation` off, estimates come from the category defaults.
+    pub flags: FeatureFlags,
+    /// User rules filling in the location when the request has none.
+    pub location_rules: Arc<dyn LocationRuleRepository>,
+    /// Decides whether the estimated expiry is written or staged for review.
+    pub ai_review_repository: Arc<dyn AiReviewRepository>,
+    /// Links products created from a scanned barcode to its enrichment.
+    pub enrichment_repository: Arc<dyn ProductEnrichmentRepository>,
+    /// Retries the estimation later when the estimator is down. `None` marks
+    /// such products failed straight away.
+    pub estimation_retry: Option<Arc<EstimationRetryRunner>>,
+    pub event_publisher: Arc<dyn EventPublisher>,
     pub logger: Arc<dyn Logger>,
 }
 
+impl CreateProductUseCaseImpl {
+    /// Default location from the user's rules. Rules are a convenience, so a
+    /// failure to load them is logged and the product keeps no location.
+    pub(crate) async fn rule_location(
+        &self,
+        user_id: &UserId,
+        product_name: &str,
+    ) -> Option<ProductLocation> {
+        match self.location_rules.get(user_id).await {
+            Ok(rule_set) => rule_set.and_then(|rules| rules.location_for(product_name)),
+            Err(e) => {
+                self.logger
+                    .warn(&format!("Could not load location rules: {}", e));
+                None
+            }
+        }
+    }
+
+    /// Whether the expiry can be estimated by AI: the feature is on and the
+    /// user has an AI call left today, which this uses up.
+    async fn can_use_ai(&self, user_id: &UserId) -> Result<bool, ProductError> {
+        if !self.flags.is_enabled(Feature::ExpiryEstimation) {
+            return Ok(false);
+        }
+        match self.quota_service.consume_ai_call(user_id).await {
+            Ok(()) => Ok(true),
+            Err(QuotaError::AiCallsExceeded) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn estimate_with_ai(&self, product: &mut Product) -> Result<(), ProductError> {
+        let status_str = product.status.to_string();
+        let location_str = product.location.as_ref().map(|l| l.to_string());
+
+        let estimation = self
+            .estimator
+            .estimate_expiry_date(&product.name, &status_str, location_str)
+            .await;
+
+        if estimation.unavailable {
+            return self.retry_estimation(product).await;
+        }
+
+        if let Some(date) = estimation.date.map(|d| self.expiry_snap.apply(d)) {
+            self.logger.info(&format!(
+                "Estimated expiry for product {}: confidence={}",
+                product.id, estimation.confidence
+            ));
+            let change = AiChange::EstimatedExpiryDate(date);
+            match self.ai_review_repository.get_mode(&product.user_id).await? {
+                AiWriteMode::Auto => {
+                    change.apply_to(product);
+                    product.expiry_confidence = Some(estimation.confidence);
+                    self.repository.update(product).await?;
+                }
+                AiWriteMode::Review => {
+                    self.ai_review_repository
+                        .stage(&PendingAiChange::new(product, change))
+                        .await?;
+                }
+            }
+        } else {
+            self.logger.info(&format!(
+                "No expiry estimation available for product {}",
+                product.id
+            ));
+        }
+        Ok(())
+    }
+
+    /// Marks the product as waiting on a retry job, or as failed when retries
+    /// are off or can't be queued. The product is created either way.
+    async fn retry_estimation(&self, product: &mut Product) -> Result<(), ProductError> {
+        product.estimation_status = EstimationStatus::Failed;
+        if let Some(retry) = &self.estimation_retry {
+            match retry.schedule(product).await {
+                Ok(_) => product.estimation_status = EstimationStatus::Pending,
+                Err(e) => self.logger.warn(&format!(
+                    "Could not queue estimation retry for product {}: {}",
+                    product.id, e
+                )),
+            }
+        }
+        self.logger.info(&format!(
+            "Expiry estimator unavailable for product {}: estimation {}",
+            product.id, product.estimation_status
+        ));
+        self.repository.update(product).await?;
+        Ok(())
+    }
+
+    /// Fills the estimate from the category defaults. They are not AI output,
+    /// so they are written even in review mode.
+    async fn estimate_from_category(&self, product: &mut Product) -> Result<(), ProductError> {
+        let estimation = estimate_from_category(
+            &product.name,
+            &product.status,
+            product.location.as_ref(),
+            Utc::now(),
+        );
+        let Some(date) = estimation.date.map(|d| self.expiry_snap.apply(d)) else {
+            self.logger.info(&format!(
+                "No category default expiry for product {}",
+                product.id
+            ));
+            return Ok(());
+        };
+        self.logger.info(&format!(
+            "Estimated expiry for product {} from its category: confidence={}",
+            product.id, estimation.confidence
+        ));
+        product.estimated_expiry_date = Some(date);
+        product.expiry_confidence = Some(estimation.confidence);
+        product.updated_at = Utc::now();
+        self.repository.update(product).await?;
+        Ok(())
+    }
+
+    /// Links a saved product to the barcode it was scanned with. The link only
+    /// adds nutrition data, so a failure is logged and the product kept.
+    pub(crate) async fn link_barcode(&self, product: &Product, barcode: &str) {
+        if let Err(e) = self
+            .enrichment_repository
+            .link(product.id, &product.user_id, barcode)
+            .await
+        {
+            self.logger.warn(&format!(
+                "Could not link product {} to barcode {}: {}",
+                product.id, barcode, e
+            ));
+        }
+    }
+
+    /// Estimates the expiry of a saved product that came without a date, by
+    /// AI when allowed and from its category otherwise.
+    pub(crate) async fn estimate_missing_expiry(
+        &self,
+        product: &mut Product,
+    ) -> Result<(), ProductError> {
+        if product.expiry_date.is_some() {
+            return Ok(());
+        }
+        if self.can_use_ai(&product.user_id).await? {
+            self.estimate_with_ai(product).await
+        } else {
+            self.estimate_from_category(product).await
+        }
+    }
+
+    pub(crate) async fn publish_added(&self, product: &Product) {
+        self.event_publisher
+            .publish(DomainEvent::ProductAdded(ProductAdded {
+                product_id: product.id,
+                user_id: product.user_id.clone(),
+                product_name: product.name.clone(),
+                urgency: get_urgency_level(product, ExpiringSoonWindow::default()),
+            }))
+            .await;
+    }
+}
+
 #[async_trait]
 impl CreateProductUseCase for CreateProductUseCaseImpl {
     async fn execute(&self, params: CreateProductParams) -> Result<Product, ProductError> {
         self.logger
             .info(&format!("Creating product: {}", params.name));
 
+        self.quota_service
+            .ensure_product_capacity(&params.user_id)
+            .await?;
+
+        let location = match params.location {
+            Some(location) => Some(location),
+            None => self.rule_location(&params.user_id, &params.name).await,
+        };
+
         let mut product = Product::new(NewProductProps {
             user_id: params.user_id,
             name: params.name,
             status: params.status,
-            location: params.location,
+            location,
             quantity: params.quantity,
             expiry_date: params.expiry_date,
             estimated_expiry_date: params.estimated_expiry_date,
+            expiry_type: params.expiry_type,
             outcome: params.outcome,
+            category: params.category,
+            tags: params.tags,
         })?;
 
-        self.repository.save(&product).await?;
-
-        if product.expiry_date.is_none() {
-            let status_str = product.status.to_string();
-            let location_str = product.location.as_ref().map(|l| l.to_string());
-
-            let estimation = self
-                .estimator
-                .estimate_expiry_date(&product.name, &status_str, location_str)
-                .await;
-
-            if let Some(date) = estimation.date {
-                self.logger.info(&format!(
-                    "Estimated expiry for product {}: confidence={}",
-                    product.id, estimation.confidence
-                ));
-                product.estimated_expiry_date = Some(date);
-                product.updated_at = Utc::now();
-                self.repository.save(&product).await?;
-            } else {
-                self.logger.info(&format!(
-                    "No expiry estimation available for product {}",
-                    product.id
-                ));
+        match self.duplicate_window {
+            Some(window) => {
+                if let Some(existing) = self
+                    .repository
+                    .insert_unless_duplicate(&product, window)
+                    .await?
+                {
+                    self.logger.info(&format!(
+                        "Product {} was just added, returning it instead",
+                        existing.id
+                    ));
+                    return Ok(existing);
+                }
             }
+            None => self.repository.insert(&product).await?,
+        }
+
+        if let Some(barcode) = &params.barcode {
+            self.link_barcode(&product, barcode).await;
         }
+        self.estimate_missing_expiry(&mut product).await?;
+        self.publish_added(&product).await;
 
         self.logger
             .info(&format!("Product created with id: {}", product.id));
@@ -70,22 +273,34 @@ impl CreateProductUseCase for CreateProductUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
+    use crate::domain::job::model::{Job, JobKind, RetryBackoff};
+    use crate::domain::job::repository::JobRepository;
+    use crate::domain::location_rule::model::{LocationRule, LocationRuleSet};
+    use crate::domain::product::enrichment::ProductEnrichment;
+    use crate::domain::product::query::ProductQuery;
     use crate::domain::product::services::{Confidence, ExpiryEstimation};
-    use crate::domain::product::value_objects::{ProductOutcome, ProductStatus};
-    use crate::domain::shared::value_objects::UserId;
-    use chrono::Duration;
+    use crate::domain::product::urgency::UrgencyLevel;
+    use crate::domain::product::value_objects::{ExpiryType, ProductOutcome, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
+    use chrono::Utc;
     use mockall::mock;
+    use std::collections::HashMap;
 
     mock! {
         pub ProductRepo {}
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: uuid::Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
         }
     }
 
@@ -103,6 +318,73 @@ mod tests {
         }
     }
 
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub LocationRuleRepo {}
+
+        #[async_trait]
+        impl LocationRuleRepository for LocationRuleRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+            async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: uuid::Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[uuid::Uuid]) -> Result<HashMap<uuid::Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub JobRepo {}
+
+        #[async_trait]
+        impl JobRepository for JobRepo {
+            async fn get_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+            async fn find_unfinished(&self, user_id: &UserId, kind: JobKind) -> Result<Option<Job>, RepositoryError>;
+            async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+            async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
     mock! {
         pub Log {}
 
@@ -127,6 +409,55 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
+    fn no_location_rules() -> Arc<dyn LocationRuleRepository> {
+        let mut repo = MockLocationRuleRepo::new();
+        repo.expect_get().returning(|_| Ok(None));
+        Arc::new(repo)
+    }
+
+    fn location_rules(
+        keyword: &'static str,
+        location: ProductLocation,
+    ) -> Arc<dyn LocationRuleRepository> {
+        let mut repo = MockLocationRuleRepo::new();
+        repo.expect_get().returning(move |user_id| {
+            Ok(Some(LocationRuleSet::from_repository(
+                user_id.clone(),
+                vec![LocationRule {
+                    keyword: keyword.to_string(),
+                    location: location.clone(),
+                }],
+                Utc::now(),
+            )))
+        });
+        Arc::new(repo)
+    }
+
+    fn no_enrichment() -> Arc<dyn ProductEnrichmentRepository> {
+        let mut repo = MockEnrichmentRepo::new();
+        repo.expect_link().never();
+        Arc::new(repo)
+    }
+
+    fn ignoring_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
+    fn ai_write_mode(mode: AiWriteMode) -> Arc<dyn AiReviewRepository> {
+        let mut repo = MockAiReviewRepo::new();
+        repo.expect_get_mode().returning(move |_| Ok(mode));
+        Arc::new(repo)
+    }
+
     fn mock_estimator_returning_none() -> Arc<dyn ExpiryEstimatorService> {
         let mut estimator = MockExpiryEstimator::new();
         estimator
@@ -134,6 +465,7 @@ mod tests {
             .returning(|_, _, _| ExpiryEstimation {
                 date: None,
                 confidence: Confidence::None,
+                unavailable: false,
             });
         Arc::new(estimator)
     }
@@ -141,11 +473,20 @@ mod tests {
     #[tokio::test]
     async fn should_create_product_when_valid_name() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_insert().returning(|_| Ok(()));
 
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -158,7 +499,11 @@ mod tests {
                 quantity: Some("1L".to_string()),
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
@@ -176,6 +521,15 @@ mod tests {
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -188,7 +542,11 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
@@ -203,6 +561,15 @@ mod tests {
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -215,7 +582,11 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: Some(ProductOutcome::Used),
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
@@ -229,7 +600,8 @@ mod tests {
     #[tokio::test]
     async fn should_estimate_expiry_when_no_expiry_date_provided() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_save().times(2).returning(|_| Ok(()));
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
 
         let estimated_date = Utc::now() + Duration::days(7);
         let mut mock_estimator = MockExpiryEstimator::new();
@@ -238,11 +610,21 @@ mod tests {
             .returning(move |_, _, _| ExpiryEstimation {
                 date: Some(estimated_date),
                 confidence: Confidence::High,
+                unavailable: false,
             });
 
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -255,20 +637,189 @@ mod tests {
                 quantity: Some("500g".to_string()),
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
         assert!(result.is_ok());
         let product = result.unwrap();
+        assert_eq!(
+            product.estimated_expiry_date,
+            Some(ExpirySnap::default().apply(estimated_date))
+        );
+        assert_eq!(product.expiry_confidence, Some(Confidence::High));
+    }
+
+    #[tokio::test]
+    async fn should_stage_estimated_expiry_in_review_mode() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+
+        let estimated_date = Utc::now() + Duration::days(7);
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator
+            .expect_estimate_expiry_date()
+            .returning(move |_, _, _| ExpiryEstimation {
+                date: Some(estimated_date),
+                confidence: Confidence::High,
+                unavailable: false,
+            });
+
+        let mut mock_review = MockAiReviewRepo::new();
+        mock_review
+            .expect_get_mode()
+            .returning(|_| Ok(AiWriteMode::Review));
+        mock_review.expect_stage().times(1).returning(|_| Ok(()));
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: Arc::new(mock_review),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Leche".to_string(),
+                status: ProductStatus::New,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.estimated_expiry_date, None);
+    }
+
+    fn create_params(name: &str, location: Option<ProductLocation>) -> CreateProductParams {
+        CreateProductParams {
+            user_id: test_user_id(),
+            name: name.to_string(),
+            status: ProductStatus::New,
+            location,
+            quantity: None,
+            expiry_date: None,
+            estimated_expiry_date: None,
+            expiry_type: ExpiryType::None,
+            outcome: None,
+            category: None,
+            tags: Vec::new(),
+            barcode: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_use_category_default_when_ai_calls_run_out() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator.expect_estimate_expiry_date().never();
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_ensure_product_capacity()
+            .returning(|_| Ok(()));
+        mock_quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+
+        // Review mode only holds back AI estimates
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: Arc::new(mock_quota),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Review),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let before = Utc::now();
+        let product = use_case
+            .execute(create_params(
+                "Pechuga de pollo",
+                Some(ProductLocation::Fridge),
+            ))
+            .await
+            .unwrap();
+
+        let estimated = product.estimated_expiry_date.unwrap();
+        assert!(estimated >= ExpirySnap::default().apply(before + Duration::days(2)));
+        assert!(estimated <= ExpirySnap::default().apply(Utc::now() + Duration::days(2)));
+    }
+
+    #[tokio::test]
+    async fn should_not_call_ai_when_expiry_estimation_is_disabled() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator.expect_estimate_expiry_date().never();
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_ensure_product_capacity()
+            .returning(|_| Ok(()));
+        mock_quota.expect_consume_ai_call().never();
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: Arc::new(mock_quota),
+            flags: FeatureFlags::with_disabled([Feature::ExpiryEstimation]),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(create_params(
+                "Yogures naturales",
+                Some(ProductLocation::Fridge),
+            ))
+            .await
+            .unwrap();
+
         assert!(product.estimated_expiry_date.is_some());
-        assert_eq!(product.estimated_expiry_date.unwrap(), estimated_date);
     }
 
     #[tokio::test]
     async fn should_skip_estimation_when_expiry_date_already_provided() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
 
         let mut mock_estimator = MockExpiryEstimator::new();
         mock_estimator.expect_estimate_expiry_date().never();
@@ -278,6 +829,15 @@ mod tests {
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -290,7 +850,11 @@ mod tests {
                 quantity: Some("500g".to_string()),
                 expiry_date: Some(expiry_date),
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
@@ -303,11 +867,20 @@ mod tests {
     #[tokio::test]
     async fn should_create_product_even_when_estimation_fails() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_save().times(1).returning(|_| Ok(()));
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
 
         let use_case = CreateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
             logger: mock_logger(),
         };
 
@@ -320,7 +893,11 @@ mod tests {
                 quantity: Some("1 loaf".to_string()),
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
             })
             .await;
 
@@ -329,4 +906,421 @@ mod tests {
         assert_eq!(product.name, "Artisan Sourdough Bread");
         assert!(product.estimated_expiry_date.is_none());
     }
+
+    #[tokio::test]
+    async fn should_queue_estimation_retry_when_estimator_is_unavailable() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        mock_repo
+            .expect_update()
+            .withf(|p| p.estimation_status == EstimationStatus::Pending)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator
+            .expect_estimate_expiry_date()
+            .returning(|_, _, _| ExpiryEstimation::unavailable());
+        let estimator: Arc<dyn ExpiryEstimatorService> = Arc::new(mock_estimator);
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_insert()
+            .withf(|job| job.kind == JobKind::RetryExpiryEstimation && job.progress.total == 3)
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_jobs.expect_update().returning(|_| Ok(()));
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: estimator.clone(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: Some(Arc::new(EstimationRetryRunner {
+                repository: Arc::new(MockProductRepo::new()),
+                job_repository: Arc::new(mock_jobs),
+                estimator,
+                expiry_snap: ExpirySnap::default(),
+                ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+                backoff: RetryBackoff {
+                    first_delay: std::time::Duration::from_secs(3600),
+                    max_attempts: 3,
+                },
+                event_publisher: ignoring_events(),
+                logger: mock_logger(),
+            })),
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Leche".to_string(),
+                status: ProductStatus::Opened,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.estimation_status, EstimationStatus::Pending);
+        assert!(product.estimated_expiry_date.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_mark_estimation_failed_when_retries_are_off() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator
+            .expect_estimate_expiry_date()
+            .returning(|_, _, _| ExpiryEstimation::unavailable());
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Leche".to_string(),
+                status: ProductStatus::Opened,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.estimation_status, EstimationStatus::Failed);
+    }
+
+    #[tokio::test]
+    async fn should_reject_product_when_plan_limit_reached() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().never();
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_ensure_product_capacity()
+            .returning(|_| Err(QuotaError::ProductLimitReached));
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: Arc::new(mock_quota),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Garbanzos".to_string(),
+                status: ProductStatus::New,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ProductError::Quota(QuotaError::ProductLimitReached)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_apply_location_rule_when_no_location_given() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().returning(|_| Ok(()));
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: location_rules("yogur", ProductLocation::Fridge),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Yogur griego".to_string(),
+                status: ProductStatus::New,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.location, Some(ProductLocation::Fridge));
+    }
+
+    #[tokio::test]
+    async fn should_keep_given_location_over_location_rule() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().returning(|_| Ok(()));
+        let mut rules = MockLocationRuleRepo::new();
+        rules.expect_get().never();
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: Arc::new(rules),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Yogur griego".to_string(),
+                status: ProductStatus::New,
+                location: Some(ProductLocation::Freezer),
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.location, Some(ProductLocation::Freezer));
+    }
+
+    #[tokio::test]
+    async fn should_link_barcode_when_created_from_scan() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().returning(|_| Ok(()));
+        let mut enrichment = MockEnrichmentRepo::new();
+        enrichment
+            .expect_link()
+            .withf(|_, user_id, barcode| *user_id == test_user_id() && barcode == "8410000810004")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: Arc::new(enrichment),
+            estimation_retry: None,
+            event_publisher: ignoring_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Leche entera".to_string(),
+                status: ProductStatus::New,
+                location: Some(ProductLocation::Fridge),
+                quantity: Some("1 L".to_string()),
+                expiry_date: Some(Utc::now() + Duration::days(7)),
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::UseBy,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: Some("8410000810004".to_string()),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_existing_product_when_added_again_within_window() {
+        let winner = Product::from_repository(
+            uuid::Uuid::new_v4(),
+            test_user_id(),
+            "Leche entera".to_string(),
+            ProductStatus::New,
+            Some(ProductLocation::Fridge),
+            None,
+            Some(Utc::now() + Duration::days(7)),
+            None,
+            ExpiryType::UseBy,
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let returned = winner.clone();
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().never();
+        mock_repo
+            .expect_insert_unless_duplicate()
+            .withf(|product, window| {
+                product.name == " leche entera" && *window == Duration::seconds(30)
+            })
+            .times(1)
+            .returning(move |_, _| Ok(Some(returned.clone())));
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().never();
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: Some(Duration::seconds(30)),
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: Arc::new(publisher),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: " leche entera".to_string(),
+                status: ProductStatus::New,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.id, winner.id);
+        assert_eq!(product.name, "Leche entera");
+    }
+
+    #[tokio::test]
+    async fn should_publish_product_added_with_its_urgency() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_insert().returning(|_| Ok(()));
+        let mut publisher = MockPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|event| {
+                matches!(
+                    event,
+                    DomainEvent::ProductAdded(added)
+                        if added.product_name == "Yogur"
+                            && added.urgency == UrgencyLevel::UseSoon
+                            && added.urgent()
+                )
+            })
+            .times(1)
+            .returning(|_| ());
+
+        let use_case = CreateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: mock_estimator_returning_none(),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service: unlimited_quota(),
+            flags: FeatureFlags::default(),
+            location_rules: no_location_rules(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            enrichment_repository: no_enrichment(),
+            estimation_retry: None,
+            event_publisher: Arc::new(publisher),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateProductParams {
+                user_id: test_user_id(),
+                name: "Yogur".to_string(),
+                status: ProductStatus::New,
+                location: Some(ProductLocation::Fridge),
+                quantity: None,
+                expiry_date: Some(Utc::now() + Duration::days(2)),
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::UseBy,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+                barcode: None,
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
 }
@@ -0,0 +1,485 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+
+use crate::application::product::create::CreateProductUseCaseImpl;
+use crate::application::product::estimate_expiry_batch::MAX_CONCURRENT_ESTIMATIONS;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::{NewProductProps, Product};
+use crate::domain::product::repository::ProductBatchRepository;
+use crate::domain::product::use_cases::create_batch::{
+    CreateProductsBatchParams, CreateProductsBatchUseCase, CreatedProductOutcome,
+};
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Creates the products of a batch like single creates, except that they are
+/// saved together and never merged into a recently added duplicate: a
+/// receipt can list the same item twice.
+pub struct CreateProductsBatchUseCaseImpl {
+    /// Location rules, estimation, barcode links and events of single creates.
+    pub create: Arc<CreateProductUseCaseImpl>,
+    pub batch_repository: Arc<dyn ProductBatchRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl CreateProductsBatchUseCaseImpl {
+    /// Fails with `ProductLimitReached` unless the user's plan has room for
+    /// `count` more products.
+    async fn ensure_capacity(&self, user_id: &UserId, count: usize) -> Result<(), ProductError> {
+        let usage = self.create.quota_service.get_usage(user_id).await?;
+        if let Some(max_products) = usage.limits.max_products
+            && usage.products as usize + count > max_products as usize
+        {
+            return Err(QuotaError::ProductLimitReached.into());
+        }
+        Ok(())
+    }
+
+    /// Links, estimates and announces a saved product. Estimation failures
+    /// are reported with the product instead of failing the batch.
+    async fn finish(&self, mut product: Product, barcode: Option<String>) -> CreatedProductOutcome {
+        if let Some(barcode) = &barcode {
+            self.create.link_barcode(&product, barcode).await;
+        }
+        let estimation_error = self
+            .create
+            .estimate_missing_expiry(&mut product)
+            .await
+            .err();
+        if let Some(e) = &estimation_error {
+            self.logger.warn(&format!(
+                "Could not estimate expiry for product {}: {}",
+                product.id, e
+            ));
+        }
+        self.create.publish_added(&product).await;
+        CreatedProductOutcome {
+            product,
+            estimation_error,
+        }
+    }
+}
+
+#[async_trait]
+impl CreateProductsBatchUseCase for CreateProductsBatchUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CreateProductsBatchParams,
+    ) -> Result<Vec<CreatedProductOutcome>, ProductError> {
+        if params.items.is_empty() {
+            return Ok(vec![]);
+        }
+        self.logger.info(&format!(
+            "Creating a batch of {} products",
+            params.items.len()
+        ));
+
+        self.ensure_capacity(&params.user_id, params.items.len())
+            .await?;
+
+        let mut products = Vec::with_capacity(params.items.len());
+        let mut barcodes = Vec::with_capacity(params.items.len());
+        for (index, item) in params.items.into_iter().enumerate() {
+            let location = match item.location {
+                Some(location) => Some(location),
+                None => self.create.rule_location(&params.user_id, &item.name).await,
+            };
+            let product = Product::new(NewProductProps {
+                user_id: params.user_id.clone(),
+                name: item.name,
+                status: item.status,
+                location,
+                quantity: item.quantity,
+                expiry_date: item.expiry_date,
+                estimated_expiry_date: item.estimated_expiry_date,
+                expiry_type: item.expiry_type,
+                outcome: item.outcome,
+                category: item.category,
+                tags: item.tags,
+            })
+            .map_err(|e| ProductError::InvalidBatchItem {
+                index,
+                source: Box::new(e),
+            })?;
+            products.push(product);
+            barcodes.push(item.barcode);
+        }
+
+        self.batch_repository.insert_all(&products).await?;
+
+        let outcomes: Vec<CreatedProductOutcome> = stream::iter(products.into_iter().zip(barcodes))
+            .map(|(product, barcode)| self.finish(product, barcode))
+            .buffered(MAX_CONCURRENT_ESTIMATIONS)
+            .collect()
+            .await;
+
+        let failed = outcomes
+            .iter()
+            .filter(|o| o.estimation_error.is_some())
+            .count();
+        self.logger.info(&format!(
+            "Created {} products in a batch, {} without an estimate after a failure",
+            outcomes.len(),
+            failed
+        ));
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::{AiWriteMode, PendingAiChange};
+    use crate::domain::ai_review::repository::AiReviewRepository;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::events::{DomainEvent, EventPublisher};
+    use crate::domain::feature_flag::model::{Feature, FeatureFlags};
+    use crate::domain::location_rule::model::LocationRuleSet;
+    use crate::domain::location_rule::repository::LocationRuleRepository;
+    use crate::domain::product::enrichment::ProductEnrichment;
+    use crate::domain::product::expiry_snap::ExpirySnap;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::repository::{ProductEnrichmentRepository, ProductRepository};
+    use crate::domain::product::services::{ExpiryEstimation, ExpiryEstimatorService};
+    use crate::domain::product::use_cases::create_batch::CreateProductsBatchItem;
+    use crate::domain::product::value_objects::{ExpiryType, ProductOutcome, ProductStatus};
+    use crate::domain::quota::model::{PlanTier, Usage};
+    use crate::domain::quota::services::QuotaService;
+    use chrono::Utc;
+    use mockall::mock;
+    use std::collections::HashMap;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: uuid::Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub BatchRepo {}
+
+        #[async_trait]
+        impl ProductBatchRepository for BatchRepo {
+            async fn insert_all(&self, products: &[Product]) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ExpiryEstimator {}
+
+        #[async_trait]
+        impl ExpiryEstimatorService for ExpiryEstimator {
+            async fn estimate_expiry_date(
+                &self,
+                product_name: &str,
+                status: &str,
+                location: Option<String>,
+            ) -> ExpiryEstimation;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub LocationRuleRepo {}
+
+        #[async_trait]
+        impl LocationRuleRepository for LocationRuleRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+            async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: uuid::Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[uuid::Uuid]) -> Result<HashMap<uuid::Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn quota_with_products(plan: PlanTier, products: u32) -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_get_usage().returning(move |_| {
+            Ok(Usage {
+                limits: plan.limits(),
+                plan: plan.clone(),
+                ai_calls_today: 0,
+                products,
+            })
+        });
+        Arc::new(quota)
+    }
+
+    /// A single create use case estimating from category defaults, so no
+    /// AI call is made.
+    fn create_use_case(
+        repository: MockProductRepo,
+        quota_service: Arc<dyn QuotaService>,
+        enrichment_repository: MockEnrichmentRepo,
+    ) -> Arc<CreateProductUseCaseImpl> {
+        let mut location_rules = MockLocationRuleRepo::new();
+        location_rules.expect_get().returning(|_| Ok(None));
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+
+        Arc::new(CreateProductUseCaseImpl {
+            repository: Arc::new(repository),
+            estimator: Arc::new(estimator),
+            expiry_snap: ExpirySnap::default(),
+            duplicate_window: None,
+            quota_service,
+            flags: FeatureFlags::with_disabled([Feature::ExpiryEstimation]),
+            location_rules: Arc::new(location_rules),
+            ai_review_repository: Arc::new(MockAiReviewRepo::new()),
+            enrichment_repository: Arc::new(enrichment_repository),
+            estimation_retry: None,
+            event_publisher: Arc::new(publisher),
+            logger: mock_logger(),
+        })
+    }
+
+    fn item(name: &str) -> CreateProductsBatchItem {
+        CreateProductsBatchItem {
+            name: name.to_string(),
+            status: ProductStatus::New,
+            location: None,
+            quantity: None,
+            expiry_date: None,
+            estimated_expiry_date: None,
+            expiry_type: ExpiryType::None,
+            outcome: None,
+            category: None,
+            tags: Vec::new(),
+            barcode: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_save_all_items_together_and_estimate_each() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_insert().never();
+        repo.expect_update().times(2).returning(|_| Ok(()));
+        let mut batch_repo = MockBatchRepo::new();
+        batch_repo
+            .expect_insert_all()
+            .withf(|products| products.len() == 3)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut enrichment_repo = MockEnrichmentRepo::new();
+        enrichment_repo
+            .expect_link()
+            .withf(|_, _, barcode| barcode == "8410000810004")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let use_case = CreateProductsBatchUseCaseImpl {
+            create: create_use_case(
+                repo,
+                quota_with_products(PlanTier::Free, 0),
+                enrichment_repo,
+            ),
+            batch_repository: Arc::new(batch_repo),
+            logger: mock_logger(),
+        };
+
+        let dated = CreateProductsBatchItem {
+            expiry_date: Some(Utc::now()),
+            ..item("Arroz")
+        };
+        let scanned = CreateProductsBatchItem {
+            barcode: Some("8410000810004".to_string()),
+            ..item("Leche entera")
+        };
+        let outcomes = use_case
+            .execute(CreateProductsBatchParams {
+                user_id: test_user_id(),
+                items: vec![item("Yogur natural"), dated, scanned],
+            })
+            .await
+            .unwrap();
+
+        let names: Vec<&str> = outcomes.iter().map(|o| o.product.name.as_str()).collect();
+        assert_eq!(names, ["Yogur natural", "Arroz", "Leche entera"]);
+        assert!(outcomes[0].product.estimated_expiry_date.is_some());
+        assert!(outcomes[1].product.estimated_expiry_date.is_none());
+        assert!(outcomes.iter().all(|o| o.estimation_error.is_none()));
+    }
+
+    #[tokio::test]
+    async fn should_save_nothing_when_an_item_is_invalid() {
+        let mut batch_repo = MockBatchRepo::new();
+        batch_repo.expect_insert_all().never();
+
+        let use_case = CreateProductsBatchUseCaseImpl {
+            create: create_use_case(
+                MockProductRepo::new(),
+                quota_with_products(PlanTier::Free, 0),
+                MockEnrichmentRepo::new(),
+            ),
+            batch_repository: Arc::new(batch_repo),
+            logger: mock_logger(),
+        };
+
+        let finished_too_early = CreateProductsBatchItem {
+            outcome: Some(ProductOutcome::Used),
+            category: None,
+            tags: Vec::new(),
+            ..item("Pan")
+        };
+        let result = use_case
+            .execute(CreateProductsBatchParams {
+                user_id: test_user_id(),
+                items: vec![item("Yogur natural"), finished_too_early],
+            })
+            .await;
+
+        match result {
+            Err(ProductError::InvalidBatchItem { index, source }) => {
+                assert_eq!(index, 1);
+                assert!(matches!(
+                    *source,
+                    ProductError::OutcomeRequiresFinishedStatus
+                ));
+            }
+            _ => panic!("expected an invalid batch item"),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_reject_batch_larger_than_remaining_capacity() {
+        let mut batch_repo = MockBatchRepo::new();
+        batch_repo.expect_insert_all().never();
+        let max_products = PlanTier::Free.limits().max_products.unwrap();
+
+        let use_case = CreateProductsBatchUseCaseImpl {
+            create: create_use_case(
+                MockProductRepo::new(),
+                quota_with_products(PlanTier::Free, max_products - 1),
+                MockEnrichmentRepo::new(),
+            ),
+            batch_repository: Arc::new(batch_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateProductsBatchParams {
+                user_id: test_user_id(),
+                items: vec![item("Yogur natural"), item("Pan")],
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProductError::Quota(QuotaError::ProductLimitReached))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_report_estimation_failure_with_the_saved_product() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_update()
+            .returning(|_| Err(RepositoryError::database_error("connection reset")));
+        let mut batch_repo = MockBatchRepo::new();
+        batch_repo.expect_insert_all().returning(|_| Ok(()));
+
+        let use_case = CreateProductsBatchUseCaseImpl {
+            create: create_use_case(
+                repo,
+                quota_with_products(PlanTier::Premium, 0),
+                MockEnrichmentRepo::new(),
+            ),
+            batch_repository: Arc::new(batch_repo),
+            logger: mock_logger(),
+        };
+
+        let outcomes = use_case
+            .execute(CreateProductsBatchParams {
+                user_id: test_user_id(),
+                items: vec![item("Yogur natural")],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(outcomes[0].product.name, "Yogur natural");
+        assert!(matches!(
+            outcomes[0].estimation_error,
+            Some(ProductError::Repository(_))
+        ));
+    }
+}
@@ -2,14 +2,19 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
 use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductDeleted;
 use crate::domain::product::repository::ProductRepository;
 use crate::domain::product::use_cases::delete::{DeleteProductParams, DeleteProductUseCase};
+use crate::domain::storage::model::{product_image_key, thumbnail_key};
+use crate::domain::storage::services::BlobStorage;
 
 pub struct DeleteProductUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub event_publisher: Arc<dyn EventPublisher>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -19,17 +24,30 @@ impl DeleteProductUseCase for DeleteProductUseCaseImpl {
         self.logger
             .info(&format!("Deleting product: {}", params.id));
 
-        // Verify product exists before deleting
-        self.repository
-            .get_by_id(params.id, &params.user_id)
-            .await
-            .map_err(|e| match e {
-                RepositoryError::NotFound => ProductError::NotFound,
-                other => ProductError::Repository(other),
-            })?;
+        if !self.repository.exists(params.id, &params.user_id).await? {
+            return Err(ProductError::NotFound);
+        }
 
         self.repository.delete(params.id, &params.user_id).await?;
 
+        self.event_publisher
+            .publish(DomainEvent::ProductDeleted(ProductDeleted {
+                product_id: params.id,
+                user_id: params.user_id.clone(),
+            }))
+            .await;
+
+        // The product is gone either way; a leftover photo is only wasted space
+        let key = product_image_key(&params.user_id, params.id);
+        for key in [thumbnail_key(&key), key] {
+            if let Err(e) = self.storage.delete(&key).await {
+                self.logger.warn(&format!(
+                    "Could not delete image of product {}: {}",
+                    params.id, e
+                ));
+            }
+        }
+
         self.logger.info(&format!("Product deleted: {}", params.id));
         Ok(())
     }
@@ -38,10 +56,12 @@ impl DeleteProductUseCase for DeleteProductUseCaseImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::errors::RepositoryError;
     use crate::domain::product::model::Product;
-    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::product::query::ProductQuery;
     use crate::domain::shared::value_objects::UserId;
-    use chrono::Utc;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
     use mockall::mock;
     use uuid::Uuid;
 
@@ -50,11 +70,38 @@ mod tests {
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
         }
     }
 
@@ -73,6 +120,12 @@ mod tests {
         UserId::new("test-user-id")
     }
 
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
     fn mock_logger() -> Arc<dyn Logger> {
         let mut logger = MockLog::new();
         logger.expect_info().returning(|_| ());
@@ -85,28 +138,41 @@ mod tests {
     #[tokio::test]
     async fn should_delete_product_when_exists() {
         let product_id = Uuid::new_v4();
-        let now = Utc::now();
         let mut mock_repo = MockProductRepo::new();
 
-        mock_repo.expect_get_by_id().returning(move |_, _| {
-            Ok(Product::from_repository(
-                product_id,
-                UserId::new("test-user-id"),
-                "Expired Yogurt".to_string(),
-                ProductStatus::Finished,
-                None,
-                None,
-                None,
-                None,
-                None,
-                now,
-                now,
-            ))
-        });
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
         mock_repo.expect_delete().returning(|_, _| Ok(()));
+        let mut storage = MockStorage::new();
+        let image_key = format!("products/test-user-id/{product_id}");
+        let thumbnail_key = format!("thumbnails/{image_key}");
+        storage
+            .expect_delete()
+            .withf(move |key| key == image_key)
+            .times(1)
+            .returning(|_| Ok(()));
+        storage
+            .expect_delete()
+            .withf(move |key| key == thumbnail_key)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut publisher = MockPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(move |event| {
+                *event
+                    == DomainEvent::ProductDeleted(ProductDeleted {
+                        product_id,
+                        user_id: test_user_id(),
+                    })
+            })
+            .times(1)
+            .returning(|_| ());
 
         let use_case = DeleteProductUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            event_publisher: Arc::new(publisher),
             logger: mock_logger(),
         };
 
@@ -123,12 +189,12 @@ mod tests {
     #[tokio::test]
     async fn should_return_not_found_when_deleting_nonexistent_product() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo
-            .expect_get_by_id()
-            .returning(|_, _| Err(RepositoryError::NotFound));
+        mock_repo.expect_exists().returning(|_, _| Ok(false));
 
         let use_case = DeleteProductUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
+            event_publisher: Arc::new(MockPublisher::new()),
             logger: mock_logger(),
         };
 
@@ -146,13 +212,13 @@ mod tests {
     #[tokio::test]
     async fn should_return_not_found_when_deleting_product_from_other_user() {
         let mut mock_repo = MockProductRepo::new();
-        // Repository returns NotFound for products belonging to other users
-        mock_repo
-            .expect_get_by_id()
-            .returning(|_, _| Err(RepositoryError::NotFound));
+        // Products belonging to other users do not exist for this user
+        mock_repo.expect_exists().returning(|_, _| Ok(false));
 
         let use_case = DeleteProductUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
+            event_publisher: Arc::new(MockPublisher::new()),
             logger: mock_logger(),
         };
 
@@ -166,4 +232,31 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), ProductError::NotFound));
     }
+
+    #[tokio::test]
+    async fn should_delete_product_when_image_cleanup_fails() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
+        mock_repo.expect_delete().returning(|_, _| Ok(()));
+        let mut storage = MockStorage::new();
+        storage
+            .expect_delete()
+            .returning(|_| Err(StorageError::unavailable("bucket unreachable")));
+
+        let use_case = DeleteProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(DeleteProductParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
 }
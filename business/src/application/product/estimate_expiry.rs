@@ -1,20 +1,30 @@
 use std::sync::Arc;
 
-use async_trait::async_trait;
-use chrono::Utc;
-
+use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::events::{DomainEvent, EventPublisher};
 use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductUpdated;
+use crate::domain::product::expiry_snap::ExpirySnap;
 use crate::domain::product::model::Product;
 use crate::domain::product::repository::ProductRepository;
 use crate::domain::product::services::ExpiryEstimatorService;
 use crate::domain::product::use_cases::estimate_expiry::{
     EstimateExpiryParams, EstimateExpiryUseCase,
 };
+use crate::domain::quota::services::QuotaService;
+use async_trait::async_trait;
 
 pub struct EstimateExpiryUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
     pub estimator: Arc<dyn ExpiryEstimatorService>,
+    /// Normalization applied to estimated dates before they are stored.
+    pub expiry_snap: ExpirySnap,
+    pub quota_service: Arc<dyn QuotaService>,
+    /// Decides whether the estimation is written or staged for review.
+    pub ai_review_repository: Arc<dyn AiReviewRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -35,6 +45,8 @@ impl EstimateExpiryUseCase for EstimateExpiryUseCaseImpl {
                 other => ProductError::Repository(other),
             })?;
 
+        self.quota_service.consume_ai_call(&params.user_id).await?;
+
         let status_str = product.status.to_string();
         let location_str = product.location.as_ref().map(|l| l.to_string());
 
@@ -43,10 +55,38 @@ impl EstimateExpiryUseCase for EstimateExpiryUseCaseImpl {
             .estimate_expiry_date(&product.name, &status_str, location_str)
             .await;
 
-        if let Some(date) = estimation.date {
-            product.estimated_expiry_date = Some(date);
-            product.updated_at = Utc::now();
-            self.repository.save(&product).await?;
+        if let Some(date) = estimation.date.map(|d| self.expiry_snap.apply(d)) {
+            let change = AiChange::EstimatedExpiryDate(date);
+            match self.ai_review_repository.get_mode(&params.user_id).await? {
+                AiWriteMode::Auto => {
+                    change.apply_to(&mut product);
+                    product.expiry_confidence = Some(estimation.confidence.clone());
+                    self.repository
+                        .update(&product)
+                        .await
+                        .map_err(|e| match e {
+                            crate::domain::errors::RepositoryError::NotFound => {
+                                ProductError::NotFound
+                            }
+                            other => ProductError::Repository(other),
+                        })?;
+                    self.event_publisher
+                        .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                            product_id: product.id,
+                            user_id: params.user_id.clone(),
+                        }))
+                        .await;
+                }
+                AiWriteMode::Review => {
+                    self.logger.info(&format!(
+                        "Expiry estimation for product {} staged for review",
+                        product.id
+                    ));
+                    self.ai_review_repository
+                        .stage(&PendingAiChange::new(&product, change))
+                        .await?;
+                }
+            }
         }
 
         self.logger.info(&format!(
@@ -62,8 +102,11 @@ impl EstimateExpiryUseCase for EstimateExpiryUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
+    use crate::domain::product::query::ProductQuery;
     use crate::domain::product::services::{Confidence, ExpiryEstimation};
-    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
     use crate::domain::shared::value_objects::UserId;
     use chrono::{Duration, Utc};
     use mockall::mock;
@@ -74,11 +117,15 @@ mod tests {
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
         }
     }
 
@@ -96,6 +143,40 @@ mod tests {
         }
     }
 
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
     mock! {
         pub Log {}
 
@@ -107,6 +188,12 @@ mod tests {
         }
     }
 
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
     fn mock_logger() -> Arc<dyn Logger> {
         let mut logger = MockLog::new();
         logger.expect_info().returning(|_| ());
@@ -116,6 +203,19 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
+    fn ai_write_mode(mode: AiWriteMode) -> Arc<dyn AiReviewRepository> {
+        let mut repo = MockAiReviewRepo::new();
+        repo.expect_get_mode().returning(move |_| Ok(mode));
+        Arc::new(repo)
+    }
+
     fn test_user_id() -> UserId {
         UserId::new("test-user-id")
     }
@@ -130,6 +230,7 @@ mod tests {
             Some("1L".to_string()),
             None,
             None,
+            ExpiryType::None,
             None,
             Utc::now(),
             Utc::now(),
@@ -147,7 +248,7 @@ mod tests {
             .expect_get_by_id()
             .withf(move |id, _| *id == product_id)
             .returning(move |_, _| Ok(product.clone()));
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_update().returning(|_| Ok(()));
 
         let mut mock_estimator = MockExpiryEstimator::new();
         mock_estimator
@@ -155,11 +256,16 @@ mod tests {
             .returning(move |_, _, _| ExpiryEstimation {
                 date: Some(estimated_date),
                 confidence: Confidence::High,
+                unavailable: false,
             });
 
         let use_case = EstimateExpiryUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: unlimited_quota(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            event_publisher: ignored_events(),
             logger: mock_logger(),
         };
 
@@ -175,6 +281,64 @@ mod tests {
         assert!(updated.estimated_expiry_date.is_some());
     }
 
+    #[tokio::test]
+    async fn should_stage_estimation_instead_of_saving_in_review_mode() {
+        let product_id = Uuid::new_v4();
+        let product = sample_product(product_id);
+        let estimated_date = Utc::now() + Duration::days(3);
+
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(product.clone()));
+        mock_repo.expect_update().never();
+
+        let mut mock_estimator = MockExpiryEstimator::new();
+        mock_estimator
+            .expect_estimate_expiry_date()
+            .returning(move |_, _, _| ExpiryEstimation {
+                date: Some(estimated_date),
+                confidence: Confidence::High,
+                unavailable: false,
+            });
+
+        let mut mock_review = MockAiReviewRepo::new();
+        mock_review
+            .expect_get_mode()
+            .returning(|_| Ok(AiWriteMode::Review));
+        mock_review
+            .expect_stage()
+            .withf(move |pending| {
+                pending.product_id == product_id
+                    && pending.change
+                        == AiChange::EstimatedExpiryDate(
+                            ExpirySnap::default().apply(estimated_date),
+                        )
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = EstimateExpiryUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: unlimited_quota(),
+            ai_review_repository: Arc::new(mock_review),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(EstimateExpiryParams {
+                product_id,
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.estimated_expiry_date, None);
+    }
+
     #[tokio::test]
     async fn should_not_save_when_estimation_returns_no_date() {
         let product_id = Uuid::new_v4();
@@ -185,7 +349,7 @@ mod tests {
             .expect_get_by_id()
             .returning(move |_, _| Ok(product.clone()));
         // save should NOT be called
-        mock_repo.expect_save().never();
+        mock_repo.expect_update().never();
 
         let mut mock_estimator = MockExpiryEstimator::new();
         mock_estimator
@@ -193,11 +357,16 @@ mod tests {
             .returning(|_, _, _| ExpiryEstimation {
                 date: None,
                 confidence: Confidence::None,
+                unavailable: false,
             });
 
         let use_case = EstimateExpiryUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: unlimited_quota(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            event_publisher: ignored_events(),
             logger: mock_logger(),
         };
 
@@ -225,6 +394,10 @@ mod tests {
         let use_case = EstimateExpiryUseCaseImpl {
             repository: Arc::new(mock_repo),
             estimator: Arc::new(mock_estimator),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: unlimited_quota(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            event_publisher: ignored_events(),
             logger: mock_logger(),
         };
 
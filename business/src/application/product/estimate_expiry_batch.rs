@@ -0,0 +1,179 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+
+use crate::domain::logger::Logger;
+use crate::domain::product::use_cases::estimate_expiry::{
+    EstimateExpiryParams, EstimateExpiryUseCase,
+};
+use crate::domain::product::use_cases::estimate_expiry_batch::{
+    EstimateExpiryBatchParams, EstimateExpiryBatchUseCase, EstimateExpiryOutcome,
+};
+
+/// Estimations running at once, to stay under the AI provider's rate limits.
+pub(crate) const MAX_CONCURRENT_ESTIMATIONS: usize = 4;
+
+pub struct EstimateExpiryBatchUseCaseImpl {
+    pub estimate_use_case: Arc<dyn EstimateExpiryUseCase>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl EstimateExpiryBatchUseCase for EstimateExpiryBatchUseCaseImpl {
+    async fn execute(&self, params: EstimateExpiryBatchParams) -> Vec<EstimateExpiryOutcome> {
+        let mut product_ids = params.product_ids;
+        let mut seen = std::collections::HashSet::new();
+        product_ids.retain(|id| seen.insert(*id));
+
+        self.logger.info(&format!(
+            "Estimating expiry dates for {} products",
+            product_ids.len()
+        ));
+
+        let outcomes: Vec<EstimateExpiryOutcome> = stream::iter(product_ids)
+            .map(|product_id| {
+                let user_id = params.user_id.clone();
+                async move {
+                    let result = self
+                        .estimate_use_case
+                        .execute(EstimateExpiryParams {
+                            product_id,
+                            user_id,
+                        })
+                        .await;
+                    EstimateExpiryOutcome { product_id, result }
+                }
+            })
+            .buffered(MAX_CONCURRENT_ESTIMATIONS)
+            .collect()
+            .await;
+
+        let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+        self.logger.info(&format!(
+            "Batch expiry estimation complete: {} succeeded, {} failed",
+            outcomes.len() - failed,
+            failed
+        ));
+
+        outcomes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub EstimateExpiry {}
+
+        #[async_trait]
+        impl EstimateExpiryUseCase for EstimateExpiry {
+            async fn execute(&self, params: EstimateExpiryParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(id: Uuid) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            "Pechuga de pollo".to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            None,
+            Some(Utc::now()),
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_return_outcome_per_product_in_input_order() {
+        let found = Uuid::new_v4();
+        let missing = Uuid::new_v4();
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate.expect_execute().returning(move |params| {
+            if params.product_id == found {
+                Ok(make_product(found))
+            } else {
+                Err(ProductError::NotFound)
+            }
+        });
+
+        let use_case = EstimateExpiryBatchUseCaseImpl {
+            estimate_use_case: Arc::new(mock_estimate),
+            logger: mock_logger(),
+        };
+
+        let outcomes = use_case
+            .execute(EstimateExpiryBatchParams {
+                user_id: test_user_id(),
+                product_ids: vec![missing, found],
+            })
+            .await;
+
+        assert_eq!(outcomes.len(), 2);
+        assert_eq!(outcomes[0].product_id, missing);
+        assert!(matches!(outcomes[0].result, Err(ProductError::NotFound)));
+        assert_eq!(outcomes[1].product_id, found);
+        assert!(outcomes[1].result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_estimate_duplicated_ids_once() {
+        let product_id = Uuid::new_v4();
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate
+            .expect_execute()
+            .times(1)
+            .returning(move |_| Ok(make_product(product_id)));
+
+        let use_case = EstimateExpiryBatchUseCaseImpl {
+            estimate_use_case: Arc::new(mock_estimate),
+            logger: mock_logger(),
+        };
+
+        let outcomes = use_case
+            .execute(EstimateExpiryBatchParams {
+                user_id: test_user_id(),
+                product_ids: vec![product_id, product_id],
+            })
+            .await;
+
+        assert_eq!(outcomes.len(), 1);
+    }
+}
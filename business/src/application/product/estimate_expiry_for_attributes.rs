@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::expiry_snap::ExpirySnap;
+use crate::domain::product::services::{ExpiryEstimation, ExpiryEstimatorService};
+use crate::domain::product::use_cases::estimate_expiry_for_attributes::{
+    EstimateExpiryForAttributesParams, EstimateExpiryForAttributesUseCase,
+};
+use crate::domain::quota::services::QuotaService;
+
+/// How long an estimate is served again for the same attributes. Dates are
+/// counted from the moment of the estimation, so entries can't live for long.
+const CACHE_TTL: Duration = Duration::hours(1);
+
+/// Estimates expiry dates for products that are not saved yet, e.g. while the
+/// user fills in the create form. Repeated questions within [`CACHE_TTL`] are
+/// answered from memory without using up the user's AI quota.
+pub struct EstimateExpiryForAttributesUseCaseImpl {
+    estimator: Arc<dyn ExpiryEstimatorService>,
+    /// Normalization applied to estimated dates, as for stored estimates.
+    expiry_snap: ExpirySnap,
+    quota_service: Arc<dyn QuotaService>,
+    logger: Arc<dyn Logger>,
+    cache: Mutex<HashMap<String, (DateTime<Utc>, ExpiryEstimation)>>,
+}
+
+impl EstimateExpiryForAttributesUseCaseImpl {
+    pub fn new(
+        estimator: Arc<dyn ExpiryEstimatorService>,
+        expiry_snap: ExpirySnap,
+        quota_service: Arc<dyn QuotaService>,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            estimator,
+            expiry_snap,
+            quota_service,
+            logger,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn cached(&self, key: &str, now: DateTime<Utc>) -> Option<ExpiryEstimation> {
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.retain(|_, (estimated_at, _)| now - *estimated_at < CACHE_TTL);
+        cache.get(key).map(|(_, estimation)| estimation.clone())
+    }
+}
+
+#[async_trait]
+impl EstimateExpiryForAttributesUseCase for EstimateExpiryForAttributesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: EstimateExpiryForAttributesParams,
+    ) -> Result<ExpiryEstimation, ProductError> {
+        let product_name = params.product_name.trim();
+        if product_name.is_empty() {
+            return Err(ProductError::NameEmpty);
+        }
+        let status = params.status.to_string();
+        let location = params.location.map(|l| l.to_string());
+
+        let key = format!(
+            "{}|{}|{}",
+            product_name.to_lowercase(),
+            status,
+            location.as_deref().unwrap_or_default()
+        );
+        let now = Utc::now();
+        if let Some(estimation) = self.cached(&key, now) {
+            self.logger.debug(&format!(
+                "Serving cached expiry estimation for attributes: {}",
+                product_name
+            ));
+            return Ok(estimation);
+        }
+
+        self.logger.info(&format!(
+            "Estimating expiry date for attributes: {}",
+            product_name
+        ));
+        self.quota_service.consume_ai_call(&params.user_id).await?;
+
+        // Snapped like a stored estimate, since clients save it back on create
+        let estimation = self
+            .estimator
+            .estimate_expiry_date(product_name, &status, location)
+            .await;
+        let estimation = ExpiryEstimation {
+            date: estimation.date.map(|d| self.expiry_snap.apply(d)),
+            ..estimation
+        };
+
+        // Failed calls are worth retrying, so they are not remembered
+        if !estimation.unavailable {
+            self.cache
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(key, (now, estimation.clone()));
+        }
+
+        self.logger.info(&format!(
+            "Expiry estimation for attributes complete: confidence={}",
+            estimation.confidence
+        ));
+        Ok(estimation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::services::Confidence;
+    use crate::domain::product::value_objects::{ProductLocation, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub ExpiryEstimator {}
+
+        #[async_trait]
+        impl ExpiryEstimatorService for ExpiryEstimator {
+            async fn estimate_expiry_date(
+                &self,
+                product_name: &str,
+                status: &str,
+                location: Option<String>,
+            ) -> ExpiryEstimation;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn params(product_name: &str) -> EstimateExpiryForAttributesParams {
+        EstimateExpiryForAttributesParams {
+            user_id: test_user_id(),
+            product_name: product_name.to_string(),
+            status: ProductStatus::Opened,
+            location: Some(ProductLocation::Fridge),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_estimate_from_attributes_and_consume_ai_call() {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator
+            .expect_estimate_expiry_date()
+            .withf(|name, status, location| {
+                name == "Leche" && status == "opened" && location.as_deref() == Some("fridge")
+            })
+            .returning(|_, _, _| ExpiryEstimation {
+                date: Some(Utc::now() + Duration::days(5)),
+                confidence: Confidence::Medium,
+                unavailable: false,
+            });
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = EstimateExpiryForAttributesUseCaseImpl::new(
+            Arc::new(estimator),
+            ExpirySnap::default(),
+            Arc::new(quota),
+            mock_logger(),
+        );
+
+        let estimation = use_case.execute(params(" Leche ")).await.unwrap();
+
+        assert!(estimation.date.is_some());
+        assert_eq!(estimation.confidence, Confidence::Medium);
+    }
+
+    #[tokio::test]
+    async fn should_serve_repeated_attributes_from_cache_without_using_quota() {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator
+            .expect_estimate_expiry_date()
+            .times(1)
+            .returning(|_, _, _| ExpiryEstimation {
+                date: Some(Utc::now() + Duration::days(5)),
+                confidence: Confidence::High,
+                unavailable: false,
+            });
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = EstimateExpiryForAttributesUseCaseImpl::new(
+            Arc::new(estimator),
+            ExpirySnap::default(),
+            Arc::new(quota),
+            mock_logger(),
+        );
+
+        let first = use_case.execute(params("Leche")).await.unwrap();
+        let second = use_case.execute(params("leche")).await.unwrap();
+
+        assert_eq!(first.date, second.date);
+        assert_eq!(second.confidence, Confidence::High);
+    }
+
+    #[tokio::test]
+    async fn should_not_cache_unavailable_estimator() {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator
+            .expect_estimate_expiry_date()
+            .times(2)
+            .returning(|_, _, _| ExpiryEstimation::unavailable());
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let use_case = EstimateExpiryForAttributesUseCaseImpl::new(
+            Arc::new(estimator),
+            ExpirySnap::default(),
+            Arc::new(quota),
+            mock_logger(),
+        );
+
+        use_case.execute(params("Leche")).await.unwrap();
+        let retried = use_case.execute(params("Leche")).await.unwrap();
+
+        assert!(retried.unavailable);
+    }
+
+    #[tokio::test]
+    async fn should_reject_blank_name_before_using_ai_quota() {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().never();
+
+        let use_case = EstimateExpiryForAttributesUseCaseImpl::new(
+            Arc::new(estimator),
+            ExpirySnap::default(),
+            Arc::new(quota),
+            mock_logger(),
+        );
+
+        let result = use_case.execute(params("   ")).await;
+
+        assert!(matches!(result, Err(ProductError::NameEmpty)));
+    }
+
+    #[tokio::test]
+    async fn should_propagate_exhausted_ai_quota() {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+
+        let use_case = EstimateExpiryForAttributesUseCaseImpl::new(
+            Arc::new(estimator),
+            ExpirySnap::default(),
+            Arc::new(quota),
+            mock_logger(),
+        );
+
+        let result = use_case.execute(params("Leche")).await;
+
+        assert!(matches!(
+            result,
+            Err(ProductError::Quota(QuotaError::AiCallsExceeded))
+        ));
+    }
+}
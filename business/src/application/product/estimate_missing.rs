@@ -0,0 +1,400 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::job::model::{Job, JobKind};
+use crate::domain::job::repository::JobRepository;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::estimate_expiry::{
+    EstimateExpiryParams, EstimateExpiryUseCase,
+};
+use crate::domain::product::use_cases::estimate_missing::{
+    EstimateMissingParams, EstimateMissingUseCase,
+};
+
+pub struct EstimateMissingUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub job_repository: Arc<dyn JobRepository>,
+    pub runner: Arc<EstimateMissingRunner>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl EstimateMissingUseCase for EstimateMissingUseCaseImpl {
+    async fn execute(&self, params: EstimateMissingParams) -> Result<Job, ProductError> {
+        let kind = JobKind::EstimateMissingExpiry;
+        if let Some(job) = self
+            .job_repository
+            .find_unfinished(&params.user_id, kind)
+            .await?
+        {
+            self.logger.info(&format!(
+                "Expiry estimation job {} already underway",
+                job.id
+            ));
+            return Ok(job);
+        }
+
+        let product_ids: Vec<Uuid> = self
+            .product_repository
+            .find(&ProductQuery::active(params.user_id.clone()))
+            .await?
+            .into_iter()
+            .filter(|p| p.expiry_date.is_none() && p.estimated_expiry_date.is_none())
+            .map(|p| p.id)
+            .collect();
+
+        let mut job = Job::new(params.user_id, kind, product_ids.len() as u32);
+        if product_ids.is_empty() {
+            job.complete();
+            self.job_repository.insert(&job).await?;
+            return Ok(job);
+        }
+        self.job_repository.insert(&job).await?;
+
+        let runner = self.runner.clone();
+        let pending = job.clone();
+        tokio::spawn(async move {
+            runner.run(pending, product_ids).await;
+        });
+
+        self.logger.info(&format!(
+            "Expiry estimation job {} queued for {} products",
+            job.id, job.progress.total
+        ));
+        Ok(job)
+    }
+}
+
+/// Background work behind expiry estimation jobs (estimate-missing and bulk
+/// moves): estimates one product at a time, recording progress on the job
+/// after each.
+pub struct EstimateMissingRunner {
+    pub job_repository: Arc<dyn JobRepository>,
+    pub estimate_use_case: Arc<dyn EstimateExpiryUseCase>,
+    /// Wait between two estimations, to stay well under the AI provider's
+    /// rate limits while the user keeps using the app.
+    pub pause: Duration,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl EstimateMissingRunner {
+    /// Runs the job to completion and returns it in its final state. A
+    /// product that fails is counted and skipped; running out of AI calls
+    /// fails the whole job.
+    pub async fn run(&self, mut job: Job, product_ids: Vec<Uuid>) -> Job {
+        job.start();
+        self.persist(&job).await;
+
+        for (i, product_id) in product_ids.into_iter().enumerate() {
+            if i > 0 && !self.pause.is_zero() {
+                tokio::time::sleep(self.pause).await;
+            }
+
+            match self
+                .estimate_use_case
+                .execute(EstimateExpiryParams {
+                    product_id,
+                    user_id: job.user_id.clone(),
+                })
+                .await
+            {
+                Ok(_) => job.record(true),
+                Err(ProductError::Quota(e)) => {
+                    self.logger
+                        .warn(&format!("Expiry estimation job {} stopped: {}", job.id, e));
+                    job.fail(e);
+                    self.persist(&job).await;
+                    return job;
+                }
+                Err(e) => {
+                    self.logger.warn(&format!(
+                        "Expiry estimation failed for product {}: {}",
+                        product_id, e
+                    ));
+                    job.record(false);
+                }
+            }
+            self.persist(&job).await;
+        }
+
+        job.complete();
+        self.persist(&job).await;
+        self.logger.info(&format!(
+            "Expiry estimation job {} completed: {} processed, {} failed",
+            job.id, job.progress.processed, job.progress.failed
+        ));
+        job
+    }
+
+    async fn persist(&self, job: &Job) {
+        if let Err(e) = self.job_repository.update(job).await {
+            self.logger
+                .error(&format!("Failed to save job {}: {}", job.id, e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::job::model::{JobProgress, JobStatus};
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use mockall::mock;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub JobRepo {}
+
+        #[async_trait]
+        impl JobRepository for JobRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+            async fn find_unfinished(&self, user_id: &UserId, kind: JobKind) -> Result<Option<Job>, RepositoryError>;
+            async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+            async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub EstimateExpiry {}
+
+        #[async_trait]
+        impl EstimateExpiryUseCase for EstimateExpiry {
+            async fn execute(&self, params: EstimateExpiryParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(estimated_expiry_date: Option<chrono::DateTime<Utc>>) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Pechuga de pollo".to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            None,
+            estimated_expiry_date,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn runner(estimate_use_case: MockEstimateExpiry) -> EstimateMissingRunner {
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs.expect_update().returning(|_| Ok(()));
+        EstimateMissingRunner {
+            job_repository: Arc::new(mock_jobs),
+            estimate_use_case: Arc::new(estimate_use_case),
+            pause: Duration::ZERO,
+            logger: mock_logger(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_queue_only_products_without_any_expiry() {
+        let missing = make_product(None);
+        let estimated = make_product(Some(Utc::now()));
+        let mut mock_products = MockProductRepo::new();
+        let products = vec![missing.clone(), estimated];
+        mock_products
+            .expect_find()
+            .returning(move |_| Ok(products.clone()));
+
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_find_unfinished()
+            .returning(|_, _| Ok(None));
+        mock_jobs
+            .expect_insert()
+            .withf(|job| job.status == JobStatus::Pending && job.progress.total == 1)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate
+            .expect_execute()
+            .returning(move |_| Ok(make_product(Some(Utc::now()))));
+
+        let use_case = EstimateMissingUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: Arc::new(runner(mock_estimate)),
+            logger: mock_logger(),
+        };
+
+        let job = use_case
+            .execute(EstimateMissingParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.kind, JobKind::EstimateMissingExpiry);
+        assert_eq!(job.progress.total, 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_job_already_underway() {
+        let underway = Job::new(test_user_id(), JobKind::EstimateMissingExpiry, 5);
+        let underway_id = underway.id;
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_find_unfinished()
+            .returning(move |_, _| Ok(Some(underway.clone())));
+        mock_jobs.expect_insert().never();
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().never();
+
+        let use_case = EstimateMissingUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: Arc::new(runner(MockEstimateExpiry::new())),
+            logger: mock_logger(),
+        };
+
+        let job = use_case
+            .execute(EstimateMissingParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.id, underway_id);
+    }
+
+    #[tokio::test]
+    async fn should_complete_right_away_when_nothing_is_missing() {
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_find()
+            .returning(|_| Ok(vec![make_product(Some(Utc::now()))]));
+
+        let mut mock_jobs = MockJobRepo::new();
+        mock_jobs
+            .expect_find_unfinished()
+            .returning(|_, _| Ok(None));
+        mock_jobs
+            .expect_insert()
+            .withf(|job| job.status == JobStatus::Completed)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = EstimateMissingUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            job_repository: Arc::new(mock_jobs),
+            runner: Arc::new(runner(MockEstimateExpiry::new())),
+            logger: mock_logger(),
+        };
+
+        let job = use_case
+            .execute(EstimateMissingParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(job.progress.total, 0);
+    }
+
+    #[tokio::test]
+    async fn should_count_failures_and_keep_going() {
+        let failing = Uuid::new_v4();
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate
+            .expect_execute()
+            .times(3)
+            .returning(move |params| {
+                if params.product_id == failing {
+                    Err(ProductError::NotFound)
+                } else {
+                    Ok(make_product(Some(Utc::now())))
+                }
+            });
+
+        let job = Job::new(test_user_id(), JobKind::EstimateMissingExpiry, 3);
+        let job = runner(mock_estimate)
+            .run(job, vec![Uuid::new_v4(), failing, Uuid::new_v4()])
+            .await;
+
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(
+            job.progress,
+            JobProgress {
+                total: 3,
+                processed: 3,
+                failed: 1,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_job_when_ai_quota_runs_out() {
+        let mut mock_estimate = MockEstimateExpiry::new();
+        mock_estimate
+            .expect_execute()
+            .times(1)
+            .returning(|_| Err(ProductError::Quota(QuotaError::AiCallsExceeded)));
+
+        let job = Job::new(test_user_id(), JobKind::EstimateMissingExpiry, 2);
+        let job = runner(mock_estimate)
+            .run(job, vec![Uuid::new_v4(), Uuid::new_v4()])
+            .await;
+
+        assert_eq!(job.status, JobStatus::Failed);
+        assert_eq!(job.error.as_deref(), Some("quota.ai_calls_exceeded"));
+        assert_eq!(job.progress.processed, 0);
+    }
+}
@@ -0,0 +1,473 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::job::model::{Job, JobKind, RetryBackoff};
+use crate::domain::job::repository::JobRepository;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductUpdated;
+use crate::domain::product::expiry_snap::ExpirySnap;
+use crate::domain::product::model::Product;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::services::ExpiryEstimatorService;
+use crate::domain::product::value_objects::EstimationStatus;
+
+/// Error recorded on a retry job whose attempts all found the estimator down.
+const ESTIMATOR_UNAVAILABLE: &str = "product.estimator_unavailable";
+
+/// Background work behind expiry estimation retries: estimates a product
+/// added while the estimator was down, waiting longer after each failed
+/// attempt. Retries finish the AI call the product was added with, so they
+/// don't count against the quota.
+pub struct EstimationRetryRunner {
+    pub repository: Arc<dyn ProductRepository>,
+    pub job_repository: Arc<dyn JobRepository>,
+    pub estimator: Arc<dyn ExpiryEstimatorService>,
+    /// Normalization applied to estimated dates before they are stored.
+    pub expiry_snap: ExpirySnap,
+    /// Decides whether the estimation is written or staged for review.
+    pub ai_review_repository: Arc<dyn AiReviewRepository>,
+    pub backoff: RetryBackoff,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl EstimationRetryRunner {
+    /// Queues a retry job for the product and starts it.
+    pub async fn schedule(self: &Arc<Self>, product: &Product) -> Result<Job, RepositoryError> {
+        let job = Job::new(
+            product.user_id.clone(),
+            JobKind::RetryExpiryEstimation,
+            self.backoff.max_attempts,
+        );
+        self.job_repository.insert(&job).await?;
+
+        let runner = self.clone();
+        let pending = job.clone();
+        let product_id = product.id;
+        tokio::spawn(async move {
+            runner.run(pending, product_id).await;
+        });
+
+        self.logger.info(&format!(
+            "Expiry estimation retry job {} queued for product {}",
+            job.id, product.id
+        ));
+        Ok(job)
+    }
+
+    /// Runs the job to completion and returns it in its final state. Stops
+    /// early once the product no longer needs an estimate; fails once every
+    /// attempt found the estimator down, marking the product failed.
+    pub async fn run(&self, mut job: Job, product_id: Uuid) -> Job {
+        job.start();
+        self.persist(&job).await;
+
+        for attempt in 1..=self.backoff.max_attempts {
+            tokio::time::sleep(self.backoff.delay_before(attempt)).await;
+
+            match self.attempt(&job, product_id).await {
+                Ok(true) => {
+                    job.record(true);
+                    job.complete();
+                    self.persist(&job).await;
+                    self.logger.info(&format!(
+                        "Expiry estimation retry job {} completed after {} attempts",
+                        job.id, attempt
+                    ));
+                    return job;
+                }
+                Ok(false) => {
+                    self.logger.warn(&format!(
+                        "Expiry estimator still unavailable for product {} (attempt {} of {})",
+                        product_id, attempt, self.backoff.max_attempts
+                    ));
+                    job.record(false);
+                }
+                Err(e) => {
+                    self.logger.warn(&format!(
+                        "Expiry estimation retry job {} stopped: {}",
+                        job.id, e
+                    ));
+                    job.fail(e);
+                    self.persist(&job).await;
+                    return job;
+                }
+            }
+            self.persist(&job).await;
+        }
+
+        if let Err(e) = self.mark_failed(&job, product_id).await {
+            self.logger.error(&format!(
+                "Failed to mark estimation of product {} as failed: {}",
+                product_id, e
+            ));
+        }
+        job.fail(ESTIMATOR_UNAVAILABLE);
+        self.persist(&job).await;
+        job
+    }
+
+    /// One try at estimating the product. `Ok(false)` when the estimator is
+    /// still down; `Ok(true)` when the product no longer waits on it.
+    async fn attempt(&self, job: &Job, product_id: Uuid) -> Result<bool, ProductError> {
+        let mut product = match self.repository.get_by_id(product_id, &job.user_id).await {
+            Ok(product) => product,
+            Err(RepositoryError::NotFound) => {
+                self.logger.info(&format!(
+                    "Product {} deleted before its expiry was estimated",
+                    product_id
+                ));
+                return Ok(true);
+            }
+            Err(e) => return Err(e.into()),
+        };
+        if product.estimation_status != EstimationStatus::Pending {
+            return Ok(true);
+        }
+        if product.expiry_date.is_some() {
+            product.estimation_status = EstimationStatus::Done;
+            self.save(&product).await?;
+            return Ok(true);
+        }
+
+        let status_str = product.status.to_string();
+        let location_str = product.location.as_ref().map(|l| l.to_string());
+        let estimation = self
+            .estimator
+            .estimate_expiry_date(&product.name, &status_str, location_str)
+            .await;
+        if estimation.unavailable {
+            return Ok(false);
+        }
+
+        product.estimation_status = EstimationStatus::Done;
+        match estimation.date.map(|d| self.expiry_snap.apply(d)) {
+            Some(date) => {
+                let change = AiChange::EstimatedExpiryDate(date);
+                match self.ai_review_repository.get_mode(&product.user_id).await? {
+                    AiWriteMode::Auto => {
+                        change.apply_to(&mut product);
+                        product.expiry_confidence = Some(estimation.confidence.clone());
+                    }
+                    AiWriteMode::Review => {
+                        self.ai_review_repository
+                            .stage(&PendingAiChange::new(&product, change))
+                            .await?;
+                    }
+                }
+                self.logger.info(&format!(
+                    "Estimated expiry for product {} on retry: confidence={}",
+                    product.id, estimation.confidence
+                ));
+            }
+            None => self.logger.info(&format!(
+                "No expiry estimation available for product {}",
+                product.id
+            )),
+        }
+        self.save(&product).await?;
+        Ok(true)
+    }
+
+    async fn mark_failed(&self, job: &Job, product_id: Uuid) -> Result<(), RepositoryError> {
+        let mut product = match self.repository.get_by_id(product_id, &job.user_id).await {
+            Err(RepositoryError::NotFound) => return Ok(()),
+            other => other?,
+        };
+        if product.estimation_status == EstimationStatus::Pending {
+            product.estimation_status = EstimationStatus::Failed;
+            self.save(&product).await?;
+        }
+        Ok(())
+    }
+
+    async fn save(&self, product: &Product) -> Result<(), RepositoryError> {
+        self.repository.update(product).await?;
+        self.event_publisher
+            .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                product_id: product.id,
+                user_id: product.user_id.clone(),
+            }))
+            .await;
+        Ok(())
+    }
+
+    async fn persist(&self, job: &Job) {
+        if let Err(e) = self.job_repository.update(job).await {
+            self.logger
+                .error(&format!("Failed to save job {}: {}", job.id, e));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::job::model::JobStatus;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::services::{Confidence, ExpiryEstimation};
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use std::sync::Mutex;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub JobRepo {}
+
+        #[async_trait]
+        impl JobRepository for JobRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+            async fn find_unfinished(&self, user_id: &UserId, kind: JobKind) -> Result<Option<Job>, RepositoryError>;
+            async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+            async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ExpiryEstimator {}
+
+        #[async_trait]
+        impl ExpiryEstimatorService for ExpiryEstimator {
+            async fn estimate_expiry_date(
+                &self,
+                product_name: &str,
+                status: &str,
+                location: Option<String>,
+            ) -> ExpiryEstimation;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn pending_product() -> Product {
+        let mut product = Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Leche".to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        product.estimation_status = EstimationStatus::Pending;
+        product
+    }
+
+    /// Product repository holding one product, keeping the updates made to it.
+    fn stored(product: Product) -> (MockProductRepo, Arc<Mutex<Vec<Product>>>) {
+        let updates = Arc::new(Mutex::new(Vec::<Product>::new()));
+        let mut repository = MockProductRepo::new();
+        let latest = updates.clone();
+        repository.expect_get_by_id().returning(move |_, _| {
+            Ok(latest
+                .lock()
+                .unwrap()
+                .last()
+                .cloned()
+                .unwrap_or_else(|| product.clone()))
+        });
+        let saved = updates.clone();
+        repository.expect_update().returning(move |p| {
+            saved.lock().unwrap().push(p.clone());
+            Ok(())
+        });
+        (repository, updates)
+    }
+
+    fn runner(
+        repository: MockProductRepo,
+        estimator: MockExpiryEstimator,
+        max_attempts: u32,
+    ) -> EstimationRetryRunner {
+        let mut jobs = MockJobRepo::new();
+        jobs.expect_update().returning(|_| Ok(()));
+        let mut ai_review_repository = MockAiReviewRepo::new();
+        ai_review_repository
+            .expect_get_mode()
+            .returning(|_| Ok(AiWriteMode::Auto));
+        EstimationRetryRunner {
+            repository: Arc::new(repository),
+            job_repository: Arc::new(jobs),
+            estimator: Arc::new(estimator),
+            expiry_snap: ExpirySnap::default(),
+            ai_review_repository: Arc::new(ai_review_repository),
+            backoff: RetryBackoff {
+                first_delay: std::time::Duration::ZERO,
+                max_attempts,
+            },
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_estimate_once_the_estimator_is_back() {
+        let product = pending_product();
+        let product_id = product.id;
+        let (repository, updates) = stored(product);
+        let attempts = Arc::new(Mutex::new(0));
+        let mut estimator = MockExpiryEstimator::new();
+        let counted = attempts.clone();
+        estimator
+            .expect_estimate_expiry_date()
+            .returning(move |_, _, _| {
+                let mut attempts = counted.lock().unwrap();
+                *attempts += 1;
+                if *attempts < 2 {
+                    ExpiryEstimation::unavailable()
+                } else {
+                    ExpiryEstimation {
+                        date: Some(Utc::now() + Duration::days(5)),
+                        confidence: Confidence::High,
+                        unavailable: false,
+                    }
+                }
+            });
+
+        let job = runner(repository, estimator, 3)
+            .run(
+                Job::new(test_user_id(), JobKind::RetryExpiryEstimation, 3),
+                product_id,
+            )
+            .await;
+
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(job.progress.processed, 2);
+        let updates = updates.lock().unwrap();
+        let saved = updates.last().unwrap();
+        assert_eq!(saved.estimation_status, EstimationStatus::Done);
+        assert!(saved.estimated_expiry_date.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_mark_product_failed_when_attempts_run_out() {
+        let product = pending_product();
+        let product_id = product.id;
+        let (repository, updates) = stored(product);
+        let mut estimator = MockExpiryEstimator::new();
+        estimator
+            .expect_estimate_expiry_date()
+            .times(3)
+            .returning(|_, _, _| ExpiryEstimation::unavailable());
+
+        let job = runner(repository, estimator, 3)
+            .run(
+                Job::new(test_user_id(), JobKind::RetryExpiryEstimation, 3),
+                product_id,
+            )
+            .await;
+
+        assert_eq!(job.status, JobStatus::Failed);
+        assert_eq!(job.error.as_deref(), Some(ESTIMATOR_UNAVAILABLE));
+        assert_eq!(job.progress.failed, 3);
+        let updates = updates.lock().unwrap();
+        assert_eq!(
+            updates.last().unwrap().estimation_status,
+            EstimationStatus::Failed
+        );
+    }
+
+    #[tokio::test]
+    async fn should_stop_when_the_user_dated_the_product_meanwhile() {
+        let mut product = pending_product();
+        product.expiry_date = Some(Utc::now() + Duration::days(3));
+        let product_id = product.id;
+        let (repository, updates) = stored(product);
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+
+        let job = runner(repository, estimator, 3)
+            .run(
+                Job::new(test_user_id(), JobKind::RetryExpiryEstimation, 3),
+                product_id,
+            )
+            .await;
+
+        assert_eq!(job.status, JobStatus::Completed);
+        assert_eq!(
+            updates.lock().unwrap().last().unwrap().estimation_status,
+            EstimationStatus::Done
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::UnwantedFlagRepository;
+use crate::domain::product::use_cases::flag_unwanted::{FlagUnwantedParams, FlagUnwantedUseCase};
+
+pub struct FlagUnwantedUseCaseImpl {
+    pub repository: Arc<dyn UnwantedFlagRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl FlagUnwantedUseCase for FlagUnwantedUseCaseImpl {
+    async fn execute(&self, params: FlagUnwantedParams) -> Result<(), ProductError> {
+        self.logger.info(&format!(
+            "Setting unwanted flag of product {} to {}",
+            params.id, params.unwanted
+        ));
+
+        self.repository
+            .set_unwanted(params.id, &params.user_id, params.unwanted)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ProductError::NotFound,
+                other => ProductError::Repository(other),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub UnwantedFlagRepo {}
+
+        #[async_trait]
+        impl UnwantedFlagRepository for UnwantedFlagRepo {
+            async fn set_unwanted(&self, id: Uuid, user_id: &UserId, unwanted: bool) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_product_missing() {
+        let mut mock_repo = MockUnwantedFlagRepo::new();
+        mock_repo
+            .expect_set_unwanted()
+            .withf(|_, _, unwanted| *unwanted)
+            .returning(|_, _, _| Err(RepositoryError::NotFound));
+
+        let use_case = FlagUnwantedUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(FlagUnwantedParams {
+                id: Uuid::new_v4(),
+                user_id: UserId::new("test-user-id"),
+                unwanted: true,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::NotFound)));
+    }
+}
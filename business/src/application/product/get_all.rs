@@ -1,15 +1,20 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::{Duration, Utc};
 
 use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::model::Product;
+use crate::domain::product::query::{ProductQuery, ProductSort};
 use crate::domain::product::repository::ProductRepository;
 use crate::domain::product::use_cases::get_all::{GetAllProductsParams, GetAllProductsUseCase};
+use crate::domain::product::value_objects::ProductStatus;
 
 pub struct GetAllProductsUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -17,7 +22,40 @@ pub struct GetAllProductsUseCaseImpl {
 impl GetAllProductsUseCase for GetAllProductsUseCaseImpl {
     async fn execute(&self, params: GetAllProductsParams) -> Result<Vec<Product>, ProductError> {
         self.logger.info("Fetching all active products");
-        let products = self.repository.get_active_products(&params.user_id).await?;
+        // The active scope already excludes them, so the page would be empty
+        if params.status == Some(ProductStatus::Finished) {
+            return Err(ProductError::FinishedNotInActiveList);
+        }
+        let mut query = ProductQuery::active(params.user_id).sorted_by(params.sort);
+        if let Some(term) = params.search.as_deref() {
+            query = query.name_contains(term);
+        }
+        if let Some(days) = params.expiring_within_days {
+            query = query.expiring_before(Utc::now() + Duration::days(i64::from(days)));
+        }
+        if let Some(status) = params.status {
+            query = query.in_status(status);
+        }
+        if let Some(location) = params.location {
+            query = query.stored_in(location);
+        }
+        if let Some(category) = params.category {
+            query = query.in_category(category);
+        }
+        if let Some(tag) = params.tag.as_deref() {
+            query = query.tagged(tag);
+        }
+        if let Some(level) = params.urgency {
+            query = query.with_urgency(level);
+        }
+        if let Some(page) = params.page {
+            query = query.paged(page);
+        }
+        if params.sort == ProductSort::UrgencyDesc || query.urgency.is_some() {
+            let preferences = self.preference_repository.get(&query.user_id).await?;
+            query = query.expiring_soon(preferences.expiring_soon);
+        }
+        let products = self.repository.find(&query).await?;
         self.logger
             .info(&format!("Found {} active products", products.len()));
         Ok(products)
@@ -28,9 +66,13 @@ impl GetAllProductsUseCase for GetAllProductsUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
-    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::query::{Page, ProductScope};
+    use crate::domain::product::urgency::{ExpiringSoonWindow, UrgencyLevel};
+    use crate::domain::product::value_objects::{
+        ExpiryType, ProductCategory, ProductLocation, ProductStatus,
+    };
     use crate::domain::shared::value_objects::UserId;
-    use chrono::Utc;
     use mockall::mock;
     use uuid::Uuid;
 
@@ -39,11 +81,25 @@ mod tests {
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
         }
     }
 
@@ -62,6 +118,13 @@ mod tests {
         UserId::new("test-user-id")
     }
 
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
     fn mock_logger() -> Arc<dyn Logger> {
         let mut logger = MockLog::new();
         logger.expect_info().returning(|_| ());
@@ -76,7 +139,7 @@ mod tests {
         let mut mock_repo = MockProductRepo::new();
         let now = Utc::now();
         let user_id = test_user_id();
-        mock_repo.expect_get_active_products().returning(move |_| {
+        mock_repo.expect_find().returning(move |_| {
             Ok(vec![Product::from_repository(
                 Uuid::new_v4(),
                 UserId::new("test-user-id"),
@@ -86,6 +149,7 @@ mod tests {
                 Some("500g".to_string()),
                 None,
                 None,
+                ExpiryType::None,
                 None,
                 now,
                 now,
@@ -94,10 +158,13 @@ mod tests {
 
         let use_case = GetAllProductsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
             logger: mock_logger(),
         };
 
-        let result = use_case.execute(GetAllProductsParams { user_id }).await;
+        let result = use_case
+            .execute(GetAllProductsParams::active(user_id))
+            .await;
 
         assert!(result.is_ok());
         let products = result.unwrap();
@@ -109,23 +176,179 @@ mod tests {
     async fn should_not_return_products_from_other_users_when_getting_all() {
         let mut mock_repo = MockProductRepo::new();
         // Repository returns empty for a different user - simulating user isolation
+        mock_repo.expect_find().returning(|_| Ok(vec![]));
+
+        let use_case = GetAllProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllProductsParams::active(UserId::new("other-user-id")))
+            .await;
+
+        assert!(result.is_ok());
+        let products = result.unwrap();
+        assert!(products.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_translate_params_into_active_product_query() {
+        let mut mock_repo = MockProductRepo::new();
         mock_repo
-            .expect_get_active_products()
+            .expect_find()
+            .withf(|query| {
+                query.scope == ProductScope::Active
+                    && query.name_contains.as_deref() == Some("milk")
+                    && query.expiring_before.is_some_and(|d| d > Utc::now())
+                    && query.sort == ProductSort::ExpiryAsc
+                    && query.page == Some(Page::new(20, 40))
+            })
+            .times(1)
             .returning(|_| Ok(vec![]));
 
         let use_case = GetAllProductsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute(GetAllProductsParams {
-                user_id: UserId::new("other-user-id"),
+                search: Some(" milk ".to_string()),
+                expiring_within_days: Some(3),
+                sort: ProductSort::ExpiryAsc,
+                page: Some(Page::new(20, 40)),
+                ..GetAllProductsParams::active(test_user_id())
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_rank_urgency_with_user_expiring_soon_window() {
+        let window = ExpiringSoonWindow::new(7).unwrap();
+        let mut mock_preferences = MockPreferenceRepo::new();
+        mock_preferences.expect_get().times(1).returning(move |_| {
+            Ok(UserPreferences {
+                expiring_soon: window,
+                allergies: vec![],
+            })
+        });
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(move |query| {
+                query.sort == ProductSort::UrgencyDesc && query.expiring_soon == window
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = GetAllProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: Arc::new(mock_preferences),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllProductsParams {
+                sort: ProductSort::UrgencyDesc,
+                ..GetAllProductsParams::active(test_user_id())
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_filter_by_status_location_and_urgency_with_user_window() {
+        let window = ExpiringSoonWindow::new(5).unwrap();
+        let mut mock_preferences = MockPreferenceRepo::new();
+        mock_preferences.expect_get().times(1).returning(move |_| {
+            Ok(UserPreferences {
+                expiring_soon: window,
+                allergies: vec![],
+            })
+        });
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(move |query| {
+                query.status == Some(ProductStatus::Opened)
+                    && query.location == Some(ProductLocation::Fridge)
+                    && query.urgency == Some(UrgencyLevel::UseSoon)
+                    && query.expiring_soon == window
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = GetAllProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: Arc::new(mock_preferences),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllProductsParams {
+                status: Some(ProductStatus::Opened),
+                location: Some(ProductLocation::Fridge),
+                urgency: Some(UrgencyLevel::UseSoon),
+                ..GetAllProductsParams::active(test_user_id())
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_reject_finished_status_filter_without_querying() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().never();
+
+        let use_case = GetAllProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: Arc::new(MockPreferenceRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllProductsParams {
+                status: Some(ProductStatus::Finished),
+                ..GetAllProductsParams::active(test_user_id())
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::FinishedNotInActiveList)));
+    }
+
+    #[tokio::test]
+    async fn should_filter_by_category_and_normalized_tag() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(|query| {
+                query.category == Some(ProductCategory::Dairy)
+                    && query.tag.as_deref() == Some("desayuno")
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = GetAllProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: Arc::new(MockPreferenceRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllProductsParams {
+                category: Some(ProductCategory::Dairy),
+                tag: Some("Desayuno".to_string()),
+                ..GetAllProductsParams::active(test_user_id())
             })
             .await;
 
         assert!(result.is_ok());
-        let products = result.unwrap();
-        assert!(products.is_empty());
     }
 }
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::enrichment::Allergen;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::ProductEnrichmentRepository;
+use crate::domain::product::use_cases::get_allergen_warnings::{
+    GetAllergenWarningsParams, GetAllergenWarningsUseCase,
+};
+
+pub struct GetAllergenWarningsUseCaseImpl {
+    pub enrichment_repository: Arc<dyn ProductEnrichmentRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetAllergenWarningsUseCase for GetAllergenWarningsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetAllergenWarningsParams,
+    ) -> Result<HashMap<Uuid, Vec<Allergen>>, ProductError> {
+        if params.product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let preferences = self.preference_repository.get(&params.user_id).await?;
+        if preferences.allergies.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let warnings: HashMap<Uuid, Vec<Allergen>> = self
+            .enrichment_repository
+            .find_for_products(&params.user_id, &params.product_ids)
+            .await?
+            .into_iter()
+            .filter_map(|(product_id, enrichment)| {
+                let matched = enrichment.allergens_among(&preferences.allergies);
+                (!matched.is_empty()).then_some((product_id, matched))
+            })
+            .collect();
+
+        self.logger.debug(&format!(
+            "Found allergen warnings for {} of {} products",
+            warnings.len(),
+            params.product_ids.len()
+        ));
+        Ok(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::enrichment::{NutritionFacts, ProductEnrichment};
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn preferences_with(allergies: Vec<Allergen>) -> MockPreferenceRepo {
+        let mut mock = MockPreferenceRepo::new();
+        mock.expect_get().returning(move |_| {
+            Ok(UserPreferences {
+                allergies: allergies.clone(),
+                ..UserPreferences::default()
+            })
+        });
+        mock
+    }
+
+    fn enrichment_with(allergens: Vec<Allergen>) -> ProductEnrichment {
+        ProductEnrichment::new(
+            "8410000810004".to_string(),
+            None,
+            None,
+            NutritionFacts::default(),
+            allergens,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_warn_only_about_products_with_matching_allergens() {
+        let cheese = Uuid::new_v4();
+        let bread = Uuid::new_v4();
+        let mut mock_enrichment = MockEnrichmentRepo::new();
+        mock_enrichment
+            .expect_find_for_products()
+            .returning(move |_, _| {
+                Ok(HashMap::from([
+                    (cheese, enrichment_with(vec![Allergen::Milk])),
+                    (bread, enrichment_with(vec![Allergen::Gluten])),
+                ]))
+            });
+
+        let use_case = GetAllergenWarningsUseCaseImpl {
+            enrichment_repository: Arc::new(mock_enrichment),
+            preference_repository: Arc::new(preferences_with(vec![Allergen::Milk])),
+            logger: mock_logger(),
+        };
+
+        let warnings = use_case
+            .execute(GetAllergenWarningsParams {
+                user_id: test_user_id(),
+                product_ids: vec![cheese, bread],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[&cheese], vec![Allergen::Milk]);
+    }
+
+    #[tokio::test]
+    async fn should_not_query_enrichment_when_no_allergies_declared() {
+        let mut mock_enrichment = MockEnrichmentRepo::new();
+        mock_enrichment.expect_find_for_products().never();
+
+        let use_case = GetAllergenWarningsUseCaseImpl {
+            enrichment_repository: Arc::new(mock_enrichment),
+            preference_repository: Arc::new(preferences_with(vec![])),
+            logger: mock_logger(),
+        };
+
+        let warnings = use_case
+            .execute(GetAllergenWarningsParams {
+                user_id: test_user_id(),
+                product_ids: vec![Uuid::new_v4()],
+            })
+            .await
+            .unwrap();
+
+        assert!(warnings.is_empty());
+    }
+}
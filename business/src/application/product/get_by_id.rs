@@ -36,7 +36,8 @@ impl GetProductByIdUseCase for GetProductByIdUseCaseImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
     use crate::domain::shared::value_objects::UserId;
     use chrono::Utc;
     use mockall::mock;
@@ -47,11 +48,15 @@ mod tests {
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
         }
     }
 
@@ -99,6 +104,7 @@ mod tests {
                     Some("200g".to_string()),
                     None,
                     None,
+                    ExpiryType::None,
                     None,
                     now,
                     now,
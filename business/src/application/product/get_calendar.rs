@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::calendar::ExpiryCalendar;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::query::{ProductQuery, ProductSort};
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::get_calendar::{
+    GetExpiryCalendarParams, GetExpiryCalendarUseCase,
+};
+
+pub struct GetExpiryCalendarUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetExpiryCalendarUseCase for GetExpiryCalendarUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetExpiryCalendarParams,
+    ) -> Result<ExpiryCalendar, ProductError> {
+        self.logger.info(&format!(
+            "Getting expiry calendar of {} for user: {}",
+            params.month, params.user_id
+        ));
+
+        let query = ProductQuery::active(params.user_id)
+            .expiring_between(params.month.start(), params.month.end())
+            .sorted_by(ProductSort::ExpiryAsc);
+        let products = self.repository.find(&query).await?;
+
+        Ok(ExpiryCalendar::build(params.month, products))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::calendar::CalendarMonth;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductScope;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{TimeZone, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_query_active_products_expiring_within_the_month() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(|query| {
+                query.scope == ProductScope::Active
+                    && query.sort == ProductSort::ExpiryAsc
+                    && query.expiring_between
+                        == Some((
+                            Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+                            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(),
+                        ))
+            })
+            .times(1)
+            .returning(|_| {
+                let expires = Utc.with_ymd_and_hms(2026, 2, 14, 12, 0, 0).unwrap();
+                Ok(vec![Product::from_repository(
+                    Uuid::new_v4(),
+                    UserId::new("test-user-id"),
+                    "Leche".to_string(),
+                    ProductStatus::Opened,
+                    None,
+                    None,
+                    None,
+                    Some(expires),
+                    ExpiryType::None,
+                    None,
+                    expires,
+                    expires,
+                )])
+            });
+
+        let use_case = GetExpiryCalendarUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let calendar = use_case
+            .execute(GetExpiryCalendarParams {
+                user_id: UserId::new("test-user-id"),
+                month: CalendarMonth::new(2026, 2).unwrap(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(calendar.days.len(), 1);
+        assert_eq!(calendar.days[0].estimated.len(), 1);
+    }
+}
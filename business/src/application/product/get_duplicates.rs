@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::duplicates::{DuplicateCluster, find_duplicate_clusters};
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::get_duplicates::{
+    GetDuplicateProductsParams, GetDuplicateProductsUseCase,
+};
+
+pub struct GetDuplicateProductsUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetDuplicateProductsUseCase for GetDuplicateProductsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetDuplicateProductsParams,
+    ) -> Result<Vec<DuplicateCluster>, ProductError> {
+        self.logger.debug(&format!(
+            "Looking for duplicate products of user: {}",
+            params.user_id
+        ));
+
+        let products = self
+            .repository
+            .find(&ProductQuery::active(params.user_id))
+            .await?;
+        Ok(find_duplicate_clusters(products))
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::facets::{ProductFacet, ProductFacets};
+use crate::domain::product::repository::{ProductEnrichmentRepository, ProductFacetRepository};
+use crate::domain::product::use_cases::get_facets::{
+    GetProductFacetsParams, GetProductFacetsUseCase,
+};
+
+pub struct GetProductFacetsUseCaseImpl {
+    pub enrichment_repository: Arc<dyn ProductEnrichmentRepository>,
+    pub facet_repository: Arc<dyn ProductFacetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetProductFacetsUseCase for GetProductFacetsUseCaseImpl {
+    async fn execute(&self, params: GetProductFacetsParams) -> Result<ProductFacets, ProductError> {
+        let mut facets = ProductFacets::default();
+        if params.product_ids.is_empty() {
+            return Ok(facets);
+        }
+
+        let ids = &params.product_ids;
+        if params.facets.contains(&ProductFacet::ShoppingItem) {
+            facets.shopping_items = self
+                .facet_repository
+                .find_shopping_items(&params.user_id, ids)
+                .await?;
+        }
+        if params.facets.contains(&ProductFacet::Enrichment) {
+            facets.enrichments = self
+                .enrichment_repository
+                .find_for_products(&params.user_id, ids)
+                .await?;
+        }
+        if params.facets.contains(&ProductFacet::HistorySummary) {
+            facets.history = self
+                .facet_repository
+                .summarize_history(&params.user_id, ids)
+                .await?;
+        }
+
+        self.logger.debug(&format!(
+            "Loaded {:?} for {} products",
+            params.facets,
+            ids.len()
+        ));
+        Ok(facets)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::enrichment::{NutritionFacts, ProductEnrichment, ScoreGrade};
+    use crate::domain::product::facets::ProductHistorySummary;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItem;
+    use mockall::mock;
+    use std::collections::{HashMap, HashSet};
+    use uuid::Uuid;
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub FacetRepo {}
+
+        #[async_trait]
+        impl ProductFacetRepository for FacetRepo {
+            async fn find_shopping_items(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ShoppingItem>, RepositoryError>;
+            async fn summarize_history(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductHistorySummary>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_enrichment_keyed_by_product() {
+        let product_id = Uuid::new_v4();
+        let mut enrichment_repo = MockEnrichmentRepo::new();
+        enrichment_repo
+            .expect_find_for_products()
+            .withf(move |_, ids| ids == [product_id])
+            .returning(move |_, _| {
+                let enrichment = ProductEnrichment::new(
+                    "8410000810004".to_string(),
+                    Some(ScoreGrade::B),
+                    None,
+                    NutritionFacts::default(),
+                    vec![],
+                )
+                .unwrap();
+                Ok(HashMap::from([(product_id, enrichment)]))
+            });
+        let mut facet_repo = MockFacetRepo::new();
+        facet_repo.expect_find_shopping_items().never();
+        facet_repo.expect_summarize_history().never();
+
+        let use_case = GetProductFacetsUseCaseImpl {
+            enrichment_repository: Arc::new(enrichment_repo),
+            facet_repository: Arc::new(facet_repo),
+            logger: mock_logger(),
+        };
+
+        let facets = use_case
+            .execute(GetProductFacetsParams {
+                user_id: test_user_id(),
+                product_ids: vec![product_id],
+                facets: HashSet::from([ProductFacet::Enrichment]),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            facets.enrichments[&product_id].nutriscore,
+            Some(ScoreGrade::B)
+        );
+        assert!(facets.shopping_items.is_empty());
+    }
+
+    #[tokio::test]
+    async fn should_combine_every_requested_facet() {
+        let product_id = Uuid::new_v4();
+        let mut enrichment_repo = MockEnrichmentRepo::new();
+        enrichment_repo.expect_find_for_products().never();
+        let mut facet_repo = MockFacetRepo::new();
+        facet_repo
+            .expect_find_shopping_items()
+            .times(1)
+            .returning(move |user_id, _| {
+                let item =
+                    ShoppingItem::new(user_id.clone(), "Leche".to_string(), Some(product_id))
+                        .unwrap();
+                Ok(HashMap::from([(product_id, item)]))
+            });
+        facet_repo
+            .expect_summarize_history()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(HashMap::from([(
+                    product_id,
+                    ProductHistorySummary {
+                        finished: 3,
+                        used: 2,
+                        thrown_away: 1,
+                        ..Default::default()
+                    },
+                )]))
+            });
+
+        let use_case = GetProductFacetsUseCaseImpl {
+            enrichment_repository: Arc::new(enrichment_repo),
+            facet_repository: Arc::new(facet_repo),
+            logger: mock_logger(),
+        };
+
+        let facets = use_case
+            .execute(GetProductFacetsParams {
+                user_id: test_user_id(),
+                product_ids: vec![product_id],
+                facets: HashSet::from([ProductFacet::ShoppingItem, ProductFacet::HistorySummary]),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(facets.shopping_items[&product_id].name, "Leche");
+        assert_eq!(facets.history[&product_id].thrown_away, 1);
+    }
+
+    #[tokio::test]
+    async fn should_not_query_repositories_when_no_products() {
+        let mut enrichment_repo = MockEnrichmentRepo::new();
+        enrichment_repo.expect_find_for_products().never();
+        let mut facet_repo = MockFacetRepo::new();
+        facet_repo.expect_find_shopping_items().never();
+        facet_repo.expect_summarize_history().never();
+
+        let use_case = GetProductFacetsUseCaseImpl {
+            enrichment_repository: Arc::new(enrichment_repo),
+            facet_repository: Arc::new(facet_repo),
+            logger: mock_logger(),
+        };
+
+        let facets = use_case
+            .execute(GetProductFacetsParams {
+                user_id: test_user_id(),
+                product_ids: vec![],
+                facets: HashSet::from([ProductFacet::Enrichment, ProductFacet::ShoppingItem]),
+            })
+            .await
+            .unwrap();
+
+        assert!(facets.enrichments.is_empty());
+    }
+}
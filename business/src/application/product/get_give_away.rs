@@ -0,0 +1,194 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::product::query::{ProductQuery, ProductSort};
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::urgency::{is_expired, urgent_until};
+use crate::domain::product::use_cases::get_give_away::{
+    GetGiveAwayCandidatesParams, GetGiveAwayCandidatesUseCase,
+};
+
+pub struct GetGiveAwayCandidatesUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetGiveAwayCandidatesUseCase for GetGiveAwayCandidatesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetGiveAwayCandidatesParams,
+    ) -> Result<Vec<Product>, ProductError> {
+        self.logger.info("Fetching give-away candidates");
+
+        let preferences = self.preference_repository.get(&params.user_id).await?;
+        let query = ProductQuery::active(params.user_id)
+            .unwanted_only()
+            .expiring_before(urgent_until(Utc::now(), preferences.expiring_soon))
+            .sorted_by(ProductSort::ExpiryAsc);
+
+        // Expired food can't be given away anymore
+        let candidates: Vec<Product> = self
+            .repository
+            .find(&query)
+            .await?
+            .into_iter()
+            .filter(|product| !is_expired(product))
+            .collect();
+
+        self.logger
+            .info(&format!("Found {} give-away candidates", candidates.len()));
+        Ok(candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::query::ProductScope;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn product_expiring_at(name: &str, expiry: DateTime<Utc>) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            Some(expiry),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_query_unwanted_products_expiring_soon() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(|query| {
+                query.scope == ProductScope::Active
+                    && query.unwanted_only
+                    && query.expiring_before.is_some_and(|d| d > Utc::now())
+                    && query.sort == ProductSort::ExpiryAsc
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = GetGiveAwayCandidatesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetGiveAwayCandidatesParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_leave_out_expired_products() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().returning(|_| {
+            Ok(vec![
+                product_expiring_at("Yogur", Utc::now() - Duration::hours(3)),
+                product_expiring_at("Pan de molde", Utc::now() + Duration::days(1)),
+            ])
+        });
+
+        let use_case = GetGiveAwayCandidatesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let candidates = use_case
+            .execute(GetGiveAwayCandidatesParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].name, "Pan de molde");
+    }
+}
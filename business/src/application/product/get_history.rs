@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::get_history::{
+    GetProductHistoryParams, GetProductHistoryUseCase,
+};
+use crate::domain::shared::pagination::{Cursor, CursorPage};
+
+pub struct GetProductHistoryUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetProductHistoryUseCase for GetProductHistoryUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetProductHistoryParams,
+    ) -> Result<CursorPage<Product>, ProductError> {
+        self.logger.info("Fetching product history");
+
+        let query = ProductQuery::finished(params.user_id).keyset(params.page);
+        let rows = self.repository.find(&query).await?;
+        let page = CursorPage::from_rows(rows, &params.page, |p| Cursor::new(p.created_at, p.id));
+
+        self.logger.info(&format!(
+            "Found {} finished products (more: {})",
+            page.items.len(),
+            page.next_cursor.is_some()
+        ));
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::query::{ProductScope, ProductSort};
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn finished_product(minutes_ago: i64) -> Product {
+        let created_at = Utc::now() - Duration::minutes(minutes_ago);
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Milk".to_string(),
+            ProductStatus::Finished,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            created_at,
+            created_at,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_query_finished_products_past_cursor() {
+        let cursor = Cursor::new(Utc::now(), Uuid::new_v4());
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .withf(move |query| {
+                query.scope == ProductScope::Finished
+                    && query.sort == ProductSort::CreatedAtDesc
+                    && query.after == Some(cursor)
+                    && query.page.is_some_and(|p| p.limit == 11 && p.offset == 0)
+            })
+            .times(1)
+            .returning(|_| Ok(vec![]));
+        let use_case = GetProductHistoryUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let page = use_case
+            .execute(GetProductHistoryParams {
+                user_id: test_user_id(),
+                page: KeysetPage::new(10, Some(cursor)),
+            })
+            .await
+            .unwrap();
+
+        assert!(page.items.is_empty());
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[tokio::test]
+    async fn should_return_cursor_of_last_item_when_more_remain() {
+        let rows = vec![
+            finished_product(1),
+            finished_product(2),
+            finished_product(3),
+        ];
+        let second = Cursor::new(rows[1].created_at, rows[1].id);
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().returning(move |_| Ok(rows.clone()));
+        let use_case = GetProductHistoryUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let page = use_case
+            .execute(GetProductHistoryParams {
+                user_id: test_user_id(),
+                page: KeysetPage::new(2, None),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_cursor, Some(second));
+    }
+}
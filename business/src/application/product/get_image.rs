@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::{ProductImageRepository, ProductRepository};
+use crate::domain::product::use_cases::get_image::{GetProductImageParams, GetProductImageUseCase};
+use crate::domain::storage::model::{ImageLinks, product_image_key};
+use crate::domain::storage::services::BlobStorage;
+
+pub struct GetProductImageUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub image_repository: Arc<dyn ProductImageRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetProductImageUseCase for GetProductImageUseCaseImpl {
+    async fn execute(&self, params: GetProductImageParams) -> Result<ImageLinks, ProductError> {
+        self.logger
+            .debug(&format!("Getting image for product: {}", params.id));
+
+        if !self.repository.exists(params.id, &params.user_id).await? {
+            return Err(ProductError::NotFound);
+        }
+
+        let key = product_image_key(&params.user_id, params.id);
+        if !self.storage.exists(&key).await? {
+            return Err(ProductError::ImageNotFound);
+        }
+
+        let thumbnail = match self
+            .image_repository
+            .find_thumbnails(&params.user_id, &[params.id])
+            .await?
+            .remove(&params.id)
+        {
+            Some(thumbnail_key) => Some(self.storage.signed_url(&thumbnail_key)?),
+            None => None,
+        };
+
+        Ok(ImageLinks {
+            original: self.storage.signed_url(&key)?,
+            thumbnail,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
+    use chrono::Utc;
+    use mockall::mock;
+    use std::collections::HashMap;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub ImageRepo {}
+
+        #[async_trait]
+        impl ProductImageRepository for ImageRepo {
+            async fn save_thumbnail(&self, product_id: Uuid, user_id: &UserId, thumbnail_key: &str) -> Result<(), RepositoryError>;
+            async fn find_thumbnails(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, String>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_link_photo_and_thumbnail_when_both_are_stored() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
+        let mut image_repo = MockImageRepo::new();
+        image_repo
+            .expect_find_thumbnails()
+            .returning(move |_, _| Ok(HashMap::from([(product_id, "thumbnails/key".to_string())])));
+        let mut storage = MockStorage::new();
+        storage.expect_exists().returning(|_| Ok(true));
+        storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: format!("https://cdn.example.com/{key}"),
+                expires_at: Utc::now(),
+            })
+        });
+
+        let use_case = GetProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(image_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let links = use_case
+            .execute(GetProductImageParams {
+                id: product_id,
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            links.original.url,
+            format!("https://cdn.example.com/products/test-user-id/{product_id}")
+        );
+        assert_eq!(
+            links.thumbnail.map(|t| t.url).as_deref(),
+            Some("https://cdn.example.com/thumbnails/key")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_image_not_found_when_product_has_no_photo() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
+        let mut storage = MockStorage::new();
+        storage.expect_exists().returning(|_| Ok(false));
+        storage.expect_signed_url().never();
+
+        let use_case = GetProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(MockImageRepo::new()),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetProductImageParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::ImageNotFound)));
+    }
+
+    #[tokio::test]
+    async fn should_not_check_storage_when_product_from_other_user() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(false));
+        let mut storage = MockStorage::new();
+        storage.expect_exists().never();
+
+        let use_case = GetProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(MockImageRepo::new()),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetProductImageParams {
+                id: Uuid::new_v4(),
+                user_id: UserId::new("other-user-id"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::NotFound)));
+    }
+}
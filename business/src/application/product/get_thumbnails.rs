@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::ProductImageRepository;
+use crate::domain::product::use_cases::get_thumbnails::{
+    GetProductThumbnailsParams, GetProductThumbnailsUseCase,
+};
+use crate::domain::storage::model::SignedUrl;
+use crate::domain::storage::services::BlobStorage;
+
+pub struct GetProductThumbnailsUseCaseImpl {
+    pub repository: Arc<dyn ProductImageRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetProductThumbnailsUseCase for GetProductThumbnailsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetProductThumbnailsParams,
+    ) -> Result<HashMap<Uuid, SignedUrl>, ProductError> {
+        if params.product_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let keys = self
+            .repository
+            .find_thumbnails(&params.user_id, &params.product_ids)
+            .await?;
+
+        self.logger.debug(&format!(
+            "Found thumbnails for {} of {} products",
+            keys.len(),
+            params.product_ids.len()
+        ));
+        keys.into_iter()
+            .map(|(id, key)| Ok((id, self.storage.signed_url(&key)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::StoredObject;
+    use chrono::Utc;
+    use mockall::mock;
+
+    mock! {
+        pub ImageRepo {}
+
+        #[async_trait]
+        impl ProductImageRepository for ImageRepo {
+            async fn save_thumbnail(&self, product_id: Uuid, user_id: &UserId, thumbnail_key: &str) -> Result<(), RepositoryError>;
+            async fn find_thumbnails(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, String>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_sign_thumbnails_keyed_by_product() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockImageRepo::new();
+        mock_repo
+            .expect_find_thumbnails()
+            .withf(move |_, ids| ids == [product_id])
+            .returning(move |_, _| Ok(HashMap::from([(product_id, "thumbnails/a".to_string())])));
+        let mut storage = MockStorage::new();
+        storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: format!("https://cdn.example.com/{key}"),
+                expires_at: Utc::now(),
+            })
+        });
+
+        let use_case = GetProductThumbnailsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let thumbnails = use_case
+            .execute(GetProductThumbnailsParams {
+                user_id: test_user_id(),
+                product_ids: vec![product_id],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            thumbnails[&product_id].url,
+            "https://cdn.example.com/thumbnails/a"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_query_repository_when_no_products() {
+        let mut mock_repo = MockImageRepo::new();
+        mock_repo.expect_find_thumbnails().never();
+
+        let use_case = GetProductThumbnailsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
+            logger: mock_logger(),
+        };
+
+        let thumbnails = use_case
+            .execute(GetProductThumbnailsParams {
+                user_id: test_user_id(),
+                product_ids: vec![],
+            })
+            .await
+            .unwrap();
+
+        assert!(thumbnails.is_empty());
+    }
+}
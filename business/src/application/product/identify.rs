@@ -2,18 +2,63 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::application::experiment::ai_experiment::AiExperiment;
+use crate::domain::barcode_contribution::repository::BarcodeContributionRepository;
+use crate::domain::location_rule::repository::LocationRuleRepository;
 use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::ProductEnrichmentRepository;
 use crate::domain::product::services::{ProductIdentification, ProductIdentifierService};
 use crate::domain::product::use_cases::identify::{
     IdentifyByBarcodeParams, IdentifyByImageParams, IdentifyProductUseCase,
 };
+use crate::domain::product::value_objects::ProductLocation;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::shared::value_objects::UserId;
 
 pub struct IdentifyProductUseCaseImpl {
     pub identifier: Arc<dyn ProductIdentifierService>,
+    /// Experiment on photo identifications, with its variants' identifiers
+    pub experiment: Arc<AiExperiment<dyn ProductIdentifierService>>,
+    pub quota_service: Arc<dyn QuotaService>,
+    /// User rules overriding the location suggested for images.
+    pub location_rules: Arc<dyn LocationRuleRepository>,
+    /// Keeps the nutrition and eco data found for barcodes.
+    pub enrichment_repository: Arc<dyn ProductEnrichmentRepository>,
+    /// Names users gave to barcodes Open Food Facts doesn't know.
+    pub contribution_repository: Arc<dyn BarcodeContributionRepository>,
     pub logger: Arc<dyn Logger>,
 }
 
+impl IdentifyProductUseCaseImpl {
+    /// Default location from the user's rules. Rules are a convenience, so a
+    /// failure to load them is logged and the suggestion is kept.
+    async fn rule_location(&self, user_id: &UserId, product_name: &str) -> Option<ProductLocation> {
+        match self.location_rules.get(user_id).await {
+            Ok(rule_set) => rule_set.and_then(|rules| rules.location_for(product_name)),
+            Err(e) => {
+                self.logger
+                    .warn(&format!("Could not load location rules: {}", e));
+                None
+            }
+        }
+    }
+
+    /// The user's own name for a barcode the identifier couldn't resolve.
+    async fn contributed(&self, user_id: &UserId, barcode: &str) -> Option<ProductIdentification> {
+        match self.contribution_repository.find(user_id, barcode).await {
+            Ok(contribution) => contribution.map(|c| c.identification()),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Could not load contribution for barcode {}: {}",
+                    barcode, e
+                ));
+                None
+            }
+        }
+    }
+}
+
 #[async_trait]
 impl IdentifyProductUseCase for IdentifyProductUseCaseImpl {
     async fn execute_by_image(
@@ -22,10 +67,25 @@ impl IdentifyProductUseCase for IdentifyProductUseCaseImpl {
     ) -> Result<ProductIdentification, ProductError> {
         self.logger.info("Identifying product by image");
 
-        let result = self
-            .identifier
-            .identify_by_image(&params.image_base64)
-            .await?;
+        self.quota_service.consume_ai_call(&params.user_id).await?;
+
+        let assignment = self.experiment.assign(&params.user_id);
+        let identifier = assignment
+            .as_ref()
+            .and_then(|a| a.service)
+            .unwrap_or(&self.identifier);
+        let mut result = identifier.identify_by_image(&params.image_base64).await?;
+
+        if let Some(assignment) = &assignment {
+            result.ai_call_id = self
+                .experiment
+                .record(&params.user_id, assignment.variant, Vec::new())
+                .await;
+        }
+
+        if let Some(location) = self.rule_location(&params.user_id, &result.name).await {
+            result.suggested_location = Some(location);
+        }
 
         self.logger.info(&format!(
             "Product identified by image: {} (confidence: {})",
@@ -44,7 +104,29 @@ impl IdentifyProductUseCase for IdentifyProductUseCaseImpl {
             params.barcode
         ));
 
-        let result = self.identifier.identify_by_barcode(&params.barcode).await?;
+        let result = match self.identifier.identify_by_barcode(&params.barcode).await {
+            Ok(result) => result,
+            Err(e) => {
+                let Some(result) = self.contributed(&params.user_id, &params.barcode).await else {
+                    return Err(e);
+                };
+                self.logger.info(&format!(
+                    "Barcode {} answered by the user's contribution",
+                    params.barcode
+                ));
+                return Ok(result);
+            }
+        };
+
+        // Enrichment is a bonus: failing to keep it doesn't fail the lookup
+        if let Some(enrichment) = &result.enrichment
+            && let Err(e) = self.enrichment_repository.save(enrichment).await
+        {
+            self.logger.warn(&format!(
+                "Could not save enrichment for barcode {}: {}",
+                params.barcode, e
+            ));
+        }
 
         self.logger.info(&format!(
             "Product identified by barcode: {} (confidence: {})",
@@ -58,11 +140,23 @@ impl IdentifyProductUseCase for IdentifyProductUseCaseImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::barcode_contribution::model::{BarcodeContribution, UpstreamStatus};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::experiment::model::{AiCall, AiFeature, Experiment, Variant, VariantResult};
+    use crate::domain::experiment::repository::AiCallRepository;
+    use crate::domain::location_rule::model::{LocationRule, LocationRuleSet};
+    use crate::domain::product::enrichment::{
+        Allergen, NutritionFacts, ProductEnrichment, ScoreGrade,
+    };
     use crate::domain::product::services::{
         IdentificationConfidence, IdentificationMethod, ProductIdentification,
     };
-    use crate::domain::product::value_objects::ProductLocation;
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
+    use chrono::{DateTime, Utc};
     use mockall::mock;
+    use std::collections::HashMap;
+    use uuid::Uuid;
 
     mock! {
         pub ProductIdentifier {}
@@ -78,6 +172,65 @@ mod tests {
                 &self,
                 barcode: &str,
             ) -> Result<ProductIdentification, ProductError>;
+
+            async fn identify_all_by_image(
+                &self,
+                image_base64: &str,
+            ) -> Result<Vec<ProductIdentification>, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub LocationRuleRepo {}
+
+        #[async_trait]
+        impl LocationRuleRepository for LocationRuleRepo {
+            async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+            async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ContributionRepo {}
+
+        #[async_trait]
+        impl BarcodeContributionRepository for ContributionRepo {
+            async fn find(&self, user_id: &UserId, barcode: &str) -> Result<Option<BarcodeContribution>, RepositoryError>;
+            async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
         }
     }
 
@@ -101,6 +254,60 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn no_experiment() -> Arc<AiExperiment<dyn ProductIdentifierService>> {
+        Arc::new(AiExperiment::inactive(
+            Arc::new(MockAiCallRepo::new()),
+            mock_logger(),
+        ))
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn no_location_rules() -> Arc<dyn LocationRuleRepository> {
+        let mut repo = MockLocationRuleRepo::new();
+        repo.expect_get().returning(|_| Ok(None));
+        Arc::new(repo)
+    }
+
+    fn location_rules(
+        keyword: &'static str,
+        location: ProductLocation,
+    ) -> Arc<dyn LocationRuleRepository> {
+        let mut repo = MockLocationRuleRepo::new();
+        repo.expect_get().returning(move |user_id| {
+            Ok(Some(LocationRuleSet::from_repository(
+                user_id.clone(),
+                vec![LocationRule {
+                    keyword: keyword.to_string(),
+                    location: location.clone(),
+                }],
+                Utc::now(),
+            )))
+        });
+        Arc::new(repo)
+    }
+
+    fn no_enrichment() -> Arc<dyn ProductEnrichmentRepository> {
+        let mut repo = MockEnrichmentRepo::new();
+        repo.expect_save().never();
+        Arc::new(repo)
+    }
+
+    fn no_contributions() -> Arc<dyn BarcodeContributionRepository> {
+        let mut repo = MockContributionRepo::new();
+        repo.expect_find().returning(|_, _| Ok(None));
+        Arc::new(repo)
+    }
+
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
     #[tokio::test]
     async fn should_identify_product_when_image_is_clear() {
         let mut mock_identifier = MockProductIdentifier::new();
@@ -111,16 +318,26 @@ mod tests {
                 method: IdentificationMethod::Visual,
                 suggested_location: Some(ProductLocation::Fridge),
                 suggested_quantity: Some("4 x 125 g".to_string()),
+                suggested_expiry_type: None,
+                suggested_category: None,
+                enrichment: None,
+                ai_call_id: None,
             })
         });
 
         let use_case = IdentifyProductUseCaseImpl {
             identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute_by_image(IdentifyByImageParams {
+                user_id: test_user_id(),
                 image_base64: "base64data".to_string(),
             })
             .await;
@@ -132,6 +349,73 @@ mod tests {
         assert_eq!(identification.method, IdentificationMethod::Visual);
     }
 
+    #[tokio::test]
+    async fn should_identify_with_the_variant_identifier_and_record_the_call() {
+        let mut regular = MockProductIdentifier::new();
+        regular.expect_identify_by_image().never();
+        let mut variant = MockProductIdentifier::new();
+        variant.expect_identify_by_image().times(1).returning(|_| {
+            Ok(ProductIdentification {
+                name: "Yogur natural".to_string(),
+                confidence: IdentificationConfidence::High,
+                method: IdentificationMethod::Visual,
+                suggested_location: None,
+                suggested_quantity: None,
+                suggested_expiry_type: None,
+                suggested_category: None,
+                enrichment: None,
+                ai_call_id: None,
+            })
+        });
+
+        let mut calls = MockAiCallRepo::new();
+        calls
+            .expect_insert()
+            .withf(|call| {
+                call.feature == AiFeature::ProductIdentification
+                    && call.variant == "gpt-4o-mini"
+                    && call.user_id == test_user_id()
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let variant: Arc<dyn ProductIdentifierService> = Arc::new(variant);
+        let experiment = AiExperiment {
+            experiment: Some(Experiment {
+                feature: AiFeature::ProductIdentification,
+                variants: vec![Variant {
+                    name: "gpt-4o-mini".to_string(),
+                    weight: 1,
+                    model: Some("gpt-4o-mini".to_string()),
+                    prompt_version: None,
+                }],
+            }),
+            variant_services: HashMap::from([("gpt-4o-mini".to_string(), variant)]),
+            ai_call_repository: Arc::new(calls),
+            logger: mock_logger(),
+        };
+
+        let use_case = IdentifyProductUseCaseImpl {
+            identifier: Arc::new(regular),
+            experiment: Arc::new(experiment),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
+            logger: mock_logger(),
+        };
+
+        let identification = use_case
+            .execute_by_image(IdentifyByImageParams {
+                user_id: test_user_id(),
+                image_base64: "base64data".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(identification.ai_call_id.is_some());
+    }
+
     #[tokio::test]
     async fn should_identify_product_when_barcode_found() {
         let mut mock_identifier = MockProductIdentifier::new();
@@ -142,16 +426,26 @@ mod tests {
                 method: IdentificationMethod::Barcode,
                 suggested_location: Some(ProductLocation::Fridge),
                 suggested_quantity: Some("1 L".to_string()),
+                suggested_expiry_type: None,
+                suggested_category: None,
+                enrichment: None,
+                ai_call_id: None,
             })
         });
 
         let use_case = IdentifyProductUseCaseImpl {
             identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute_by_barcode(IdentifyByBarcodeParams {
+                user_id: test_user_id(),
                 barcode: "8410000810004".to_string(),
             })
             .await;
@@ -162,20 +456,82 @@ mod tests {
         assert_eq!(identification.method, IdentificationMethod::Barcode);
     }
 
+    #[tokio::test]
+    async fn should_save_enrichment_when_barcode_lookup_has_it() {
+        let mut mock_identifier = MockProductIdentifier::new();
+        mock_identifier
+            .expect_identify_by_barcode()
+            .returning(|barcode| {
+                Ok(ProductIdentification {
+                    name: "Leche entera".to_string(),
+                    confidence: IdentificationConfidence::High,
+                    method: IdentificationMethod::Barcode,
+                    suggested_location: Some(ProductLocation::Fridge),
+                    suggested_quantity: Some("1 L".to_string()),
+                    suggested_expiry_type: None,
+                    suggested_category: None,
+                    enrichment: ProductEnrichment::new(
+                        barcode.to_string(),
+                        Some(ScoreGrade::B),
+                        None,
+                        NutritionFacts {
+                            energy_kcal: Some(63.0),
+                            ..NutritionFacts::default()
+                        },
+                        vec![Allergen::Milk],
+                    ),
+                    ai_call_id: None,
+                })
+            });
+
+        let mut mock_enrichment = MockEnrichmentRepo::new();
+        mock_enrichment
+            .expect_save()
+            .withf(|e| e.barcode == "8410000810004" && e.nutriscore == Some(ScoreGrade::B))
+            .times(1)
+            .returning(|_| Err(RepositoryError::Persistence));
+
+        let use_case = IdentifyProductUseCaseImpl {
+            identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: Arc::new(mock_enrichment),
+            contribution_repository: no_contributions(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute_by_barcode(IdentifyByBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "8410000810004".to_string(),
+            })
+            .await;
+
+        // A failed save is only logged
+        assert_eq!(result.unwrap().name, "Leche entera");
+    }
+
     #[tokio::test]
     async fn should_return_error_when_image_identification_fails() {
         let mut mock_identifier = MockProductIdentifier::new();
         mock_identifier
             .expect_identify_by_image()
-            .returning(|_| Err(ProductError::IdentificationFailed));
+            .returning(|_| Err(ProductError::identification_failed("model unavailable")));
 
         let use_case = IdentifyProductUseCaseImpl {
             identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute_by_image(IdentifyByImageParams {
+                user_id: test_user_id(),
                 image_base64: "bad_data".to_string(),
             })
             .await;
@@ -183,7 +539,7 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            ProductError::IdentificationFailed
+            ProductError::IdentificationFailed(_)
         ));
     }
 
@@ -192,15 +548,21 @@ mod tests {
         let mut mock_identifier = MockProductIdentifier::new();
         mock_identifier
             .expect_identify_by_barcode()
-            .returning(|_| Err(ProductError::IdentificationFailed));
+            .returning(|_| Err(ProductError::identification_failed("model unavailable")));
 
         let use_case = IdentifyProductUseCaseImpl {
             identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute_by_barcode(IdentifyByBarcodeParams {
+                user_id: test_user_id(),
                 barcode: "0000000000000".to_string(),
             })
             .await;
@@ -208,7 +570,130 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(
             result.unwrap_err(),
-            ProductError::IdentificationFailed
+            ProductError::IdentificationFailed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_answer_with_own_contribution_when_barcode_not_found() {
+        let mut mock_identifier = MockProductIdentifier::new();
+        mock_identifier
+            .expect_identify_by_barcode()
+            .returning(|barcode| {
+                Err(ProductError::identification_failed(format!(
+                    "barcode {barcode} not found in Open Food Facts"
+                )))
+            });
+
+        let mut contributions = MockContributionRepo::new();
+        contributions
+            .expect_find()
+            .withf(|user_id, barcode| *user_id == test_user_id() && barcode == "8410000000001")
+            .returning(|user_id, barcode| {
+                Ok(Some(
+                    BarcodeContribution::new(
+                        user_id.clone(),
+                        barcode,
+                        "Turrón blando",
+                        UpstreamStatus::Pending,
+                    )
+                    .unwrap(),
+                ))
+            });
+
+        let use_case = IdentifyProductUseCaseImpl {
+            identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: Arc::new(contributions),
+            logger: mock_logger(),
+        };
+
+        let identification = use_case
+            .execute_by_barcode(IdentifyByBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "8410000000001".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(identification.name, "Turrón blando");
+        assert_eq!(identification.method, IdentificationMethod::Contribution);
+    }
+
+    #[tokio::test]
+    async fn should_not_call_identifier_when_ai_quota_exceeded() {
+        let mut mock_identifier = MockProductIdentifier::new();
+        mock_identifier.expect_identify_by_image().never();
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+
+        let use_case = IdentifyProductUseCaseImpl {
+            identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: Arc::new(mock_quota),
+            location_rules: no_location_rules(),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute_by_image(IdentifyByImageParams {
+                user_id: test_user_id(),
+                image_base64: "aW1hZ2U=".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ProductError::Quota(QuotaError::AiCallsExceeded)
         ));
     }
+
+    #[tokio::test]
+    async fn should_override_suggested_location_with_matching_rule() {
+        let mut mock_identifier = MockProductIdentifier::new();
+        mock_identifier.expect_identify_by_image().returning(|_| {
+            Ok(ProductIdentification {
+                name: "Pan de molde".to_string(),
+                confidence: IdentificationConfidence::High,
+                method: IdentificationMethod::Visual,
+                suggested_location: Some(ProductLocation::Pantry),
+                suggested_quantity: None,
+                suggested_expiry_type: None,
+                suggested_category: None,
+                enrichment: None,
+                ai_call_id: None,
+            })
+        });
+
+        let use_case = IdentifyProductUseCaseImpl {
+            identifier: Arc::new(mock_identifier),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            location_rules: location_rules("pan", ProductLocation::Freezer),
+            enrichment_repository: no_enrichment(),
+            contribution_repository: no_contributions(),
+            logger: mock_logger(),
+        };
+
+        let identification = use_case
+            .execute_by_image(IdentifyByImageParams {
+                user_id: test_user_id(),
+                image_base64: "aW1hZ2U=".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            identification.suggested_location,
+            Some(ProductLocation::Freezer)
+        );
+    }
 }
@@ -0,0 +1,261 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::product::duplicates::{duplicate_key, merge_into};
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::ProductUpdated;
+use crate::domain::product::model::Product;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::delete::{DeleteProductParams, DeleteProductUseCase};
+use crate::domain::product::use_cases::merge_duplicates::{
+    MergeDuplicateProductsParams, MergeDuplicateProductsUseCase,
+};
+use crate::domain::product::value_objects::ProductStatus;
+
+pub struct MergeDuplicateProductsUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    /// Also removes the photos of the products merged away
+    pub delete_use_case: Arc<dyn DeleteProductUseCase>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl MergeDuplicateProductsUseCase for MergeDuplicateProductsUseCaseImpl {
+    async fn execute(&self, params: MergeDuplicateProductsParams) -> Result<Product, ProductError> {
+        let mut product_ids = params.product_ids;
+        let mut seen = HashSet::new();
+        product_ids.retain(|id| seen.insert(*id));
+        if product_ids.len() < 2 {
+            return Err(ProductError::NotDuplicates);
+        }
+
+        // Every product is loaded and checked before any is written
+        let mut products = Vec::with_capacity(product_ids.len());
+        for id in &product_ids {
+            let product = self
+                .repository
+                .get_by_id(*id, &params.user_id)
+                .await
+                .map_err(|e| match e {
+                    RepositoryError::NotFound => ProductError::NotFound,
+                    other => ProductError::Repository(other),
+                })?;
+            products.push(product);
+        }
+        let key = duplicate_key(&products[0].name);
+        if products
+            .iter()
+            .any(|p| p.status == ProductStatus::Finished || duplicate_key(&p.name) != key)
+        {
+            return Err(ProductError::NotDuplicates);
+        }
+
+        products.sort_by_key(|p| (p.created_at, p.id));
+        let keep_at = match params.keep_id {
+            Some(keep_id) => products
+                .iter()
+                .position(|p| p.id == keep_id)
+                .ok_or(ProductError::NotDuplicates)?,
+            None => 0,
+        };
+        let mut keeper = products.remove(keep_at);
+        merge_into(&mut keeper, &products);
+        self.repository.update(&keeper).await?;
+        self.event_publisher
+            .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                product_id: keeper.id,
+                user_id: params.user_id.clone(),
+            }))
+            .await;
+
+        for other in &products {
+            self.delete_use_case
+                .execute(DeleteProductParams {
+                    id: other.id,
+                    user_id: params.user_id.clone(),
+                })
+                .await?;
+        }
+
+        self.logger.info(&format!(
+            "Merged {} duplicates into product {}",
+            products.len(),
+            keeper.id
+        ));
+        Ok(keeper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::ExpiryType;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Delete {}
+
+        #[async_trait]
+        impl DeleteProductUseCase for Delete {
+            async fn execute(&self, params: DeleteProductParams) -> Result<(), ProductError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn ignored_events() -> Arc<dyn EventPublisher> {
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().returning(|_| ());
+        Arc::new(publisher)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(name: &str, status: ProductStatus, created_days_ago: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            status,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now() - Duration::days(created_days_ago),
+            Utc::now(),
+        )
+    }
+
+    fn repo_with(products: Vec<Product>) -> MockProductRepo {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |id, _| Ok(products.iter().find(|p| p.id == id).cloned().unwrap()));
+        mock_repo
+    }
+
+    #[tokio::test]
+    async fn should_keep_oldest_and_delete_the_rest() {
+        let oldest = make_product("Leche entera", ProductStatus::Opened, 7);
+        let newer = make_product("LECHE ENTERA", ProductStatus::New, 1);
+        let (oldest_id, newer_id) = (oldest.id, newer.id);
+
+        let mut mock_repo = repo_with(vec![oldest, newer]);
+        mock_repo
+            .expect_update()
+            .withf(move |p| p.id == oldest_id)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut mock_delete = MockDelete::new();
+        mock_delete
+            .expect_execute()
+            .withf(move |params| params.id == newer_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = MergeDuplicateProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            delete_use_case: Arc::new(mock_delete),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let kept = use_case
+            .execute(MergeDuplicateProductsParams {
+                user_id: test_user_id(),
+                product_ids: vec![newer_id, oldest_id],
+                keep_id: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(kept.id, oldest_id);
+    }
+
+    #[tokio::test]
+    async fn should_refuse_products_that_are_not_duplicates() {
+        let milk = make_product("Leche", ProductStatus::New, 2);
+        let bread = make_product("Pan", ProductStatus::New, 1);
+        let ids = vec![milk.id, bread.id];
+
+        let mut mock_repo = repo_with(vec![milk, bread]);
+        mock_repo.expect_update().never();
+        let mut mock_delete = MockDelete::new();
+        mock_delete.expect_execute().never();
+
+        let use_case = MergeDuplicateProductsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            delete_use_case: Arc::new(mock_delete),
+            event_publisher: ignored_events(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(MergeDuplicateProductsParams {
+                user_id: test_user_id(),
+                product_ids: ids,
+                keep_id: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::NotDuplicates)));
+    }
+}
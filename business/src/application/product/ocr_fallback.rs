@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::receipt_text::{parse_receipt_text, receipt_header};
+use crate::domain::product::services::{
+    ReceiptScanResult, ReceiptScannerService, TextRecognitionService,
+};
+
+/// Receipt scanner that falls back to local OCR and rule-based line parsing
+/// when the vision model fails, so scanning still returns best-effort items.
+/// Also serves `scan_without_ai` for users whose AI allowance is used up.
+pub struct OcrFallbackScanner {
+    pub scanner: Arc<dyn ReceiptScannerService>,
+    pub recognizer: Arc<dyn TextRecognitionService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ReceiptScannerService for OcrFallbackScanner {
+    async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        match self.scanner.scan(image_base64).await {
+            Ok(result) => Ok(result),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Vision receipt scan failed, falling back to OCR: {}",
+                    e
+                ));
+                // The vision error says more about what went wrong
+                self.scan_without_ai(image_base64).await.map_err(|_| e)
+            }
+        }
+    }
+
+    fn can_scan_without_ai(&self) -> bool {
+        true
+    }
+
+    async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        let text = self.recognizer.recognize(image_base64).await?;
+        let items = parse_receipt_text(&text);
+        self.logger.info(&format!(
+            "Receipt read with OCR: {} items found",
+            items.len()
+        ));
+        Ok(ReceiptScanResult {
+            items,
+            store: receipt_header(&text),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::services::{IdentificationConfidence, ReceiptItem};
+    use mockall::mock;
+
+    mock! {
+        pub ReceiptScanner {}
+
+        #[async_trait]
+        impl ReceiptScannerService for ReceiptScanner {
+            async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Recognizer {}
+
+        #[async_trait]
+        impl TextRecognitionService for Recognizer {
+            async fn recognize(&self, image_base64: &str) -> Result<String, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn failing_scanner() -> MockReceiptScanner {
+        let mut scanner = MockReceiptScanner::new();
+        scanner
+            .expect_scan()
+            .returning(|_| Err(ProductError::scan_failed("model unavailable")));
+        scanner
+    }
+
+    #[tokio::test]
+    async fn should_keep_vision_result_when_it_succeeds() {
+        let mut scanner = MockReceiptScanner::new();
+        scanner.expect_scan().returning(|_| {
+            Ok(ReceiptScanResult {
+                items: vec![ReceiptItem {
+                    name: "Leche entera".to_string(),
+                    confidence: IdentificationConfidence::High,
+                }],
+                store: None,
+            })
+        });
+        let mut recognizer = MockRecognizer::new();
+        recognizer.expect_recognize().never();
+
+        let fallback = OcrFallbackScanner {
+            scanner: Arc::new(scanner),
+            recognizer: Arc::new(recognizer),
+            logger: mock_logger(),
+        };
+
+        let result = fallback.scan("receipt").await.unwrap();
+
+        assert_eq!(result.items[0].name, "Leche entera");
+    }
+
+    #[tokio::test]
+    async fn should_read_text_when_vision_model_fails() {
+        let mut recognizer = MockRecognizer::new();
+        recognizer
+            .expect_recognize()
+            .returning(|_| Ok("LECHE ENTERA 0,89\nTOTAL 0,89\n".to_string()));
+
+        let fallback = OcrFallbackScanner {
+            scanner: Arc::new(failing_scanner()),
+            recognizer: Arc::new(recognizer),
+            logger: mock_logger(),
+        };
+
+        let result = fallback.scan("receipt").await.unwrap();
+
+        assert_eq!(result.items.len(), 1);
+        assert_eq!(result.items[0].name, "LECHE ENTERA");
+    }
+
+    #[tokio::test]
+    async fn should_return_vision_error_when_ocr_also_fails() {
+        let mut recognizer = MockRecognizer::new();
+        recognizer
+            .expect_recognize()
+            .returning(|_| Err(ProductError::scan_failed("tesseract missing")));
+
+        let fallback = OcrFallbackScanner {
+            scanner: Arc::new(failing_scanner()),
+            recognizer: Arc::new(recognizer),
+            logger: mock_logger(),
+        };
+
+        let result = fallback.scan("receipt").await;
+
+        assert!(matches!(result, Err(ProductError::ScanFailed(_))));
+    }
+}
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::patch::{PatchProductParams, PatchProductUseCase};
+use crate::domain::product::use_cases::update::{UpdateProductParams, UpdateProductUseCase};
+use crate::domain::product::value_objects::ProductStatus;
+
+/// Partial updates, merged onto the stored product and saved through the
+/// full update so both follow the same rules.
+pub struct PatchProductUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub update_use_case: Arc<dyn UpdateProductUseCase>,
+    pub logger: Arc<dyn Logger>,
+}
+
+/// Full update of `existing` with the patched fields replaced. Moving a
+/// finished product back to an active status drops its outcome unless the
+/// patch sets one, since only finished products have one.
+fn merge(existing: Product, patch: PatchProductParams) -> UpdateProductParams {
+    let status = patch.status.unwrap_or(existing.status);
+    let outcome = match patch.outcome {
+        Some(outcome) => outcome,
+        None if status != ProductStatus::Finished => None,
+        None => existing.outcome,
+    };
+
+    UpdateProductParams {
+        id: patch.id,
+        user_id: patch.user_id,
+        name: patch.name.unwrap_or(existing.name),
+        status,
+        location: patch.location.unwrap_or(existing.location),
+        quantity: patch.quantity.unwrap_or(existing.quantity),
+        expiry_date: patch.expiry_date.unwrap_or(existing.expiry_date),
+        estimated_expiry_date: patch
+            .estimated_expiry_date
+            .unwrap_or(existing.estimated_expiry_date),
+        expiry_type: patch.expiry_type.unwrap_or(existing.expiry_type),
+        outcome,
+        category: patch.category.unwrap_or(existing.category),
+        tags: patch.tags.unwrap_or(existing.tags),
+    }
+}
+
+#[async_trait]
+impl PatchProductUseCase for PatchProductUseCaseImpl {
+    async fn execute(&self, params: PatchProductParams) -> Result<Product, ProductError> {
+        self.logger
+            .info(&format!("Patching product: {}", params.id));
+
+        let existing = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ProductError::NotFound,
+                other => ProductError::Repository(other),
+            })?;
+
+        self.update_use_case.execute(merge(existing, params)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::value_objects::{
+        ExpiryType, ProductCategory, ProductLocation, ProductOutcome,
+    };
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub UpdateProduct {}
+
+        #[async_trait]
+        impl UpdateProductUseCase for UpdateProduct {
+            async fn execute(&self, params: UpdateProductParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn stored_product(id: Uuid) -> Product {
+        Product::from_repository(
+            id,
+            test_user_id(),
+            "Leche entera".to_string(),
+            ProductStatus::Opened,
+            Some(ProductLocation::Fridge),
+            Some("1L".to_string()),
+            Some(Utc::now() + Duration::days(4)),
+            None,
+            ExpiryType::UseBy,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn empty_patch(id: Uuid) -> PatchProductParams {
+        PatchProductParams {
+            id,
+            user_id: test_user_id(),
+            name: None,
+            status: None,
+            location: None,
+            quantity: None,
+            expiry_date: None,
+            estimated_expiry_date: None,
+            expiry_type: None,
+            outcome: None,
+            category: None,
+            tags: None,
+        }
+    }
+
+    #[test]
+    fn should_keep_fields_left_out_of_the_patch() {
+        let id = Uuid::new_v4();
+        let mut existing = stored_product(id);
+        existing.category = Some(ProductCategory::Dairy);
+        existing.tags = vec!["desayuno".to_string()];
+        let expiry_date = existing.expiry_date;
+
+        let merged = merge(
+            existing,
+            PatchProductParams {
+                status: Some(ProductStatus::AlmostEmpty),
+                ..empty_patch(id)
+            },
+        );
+
+        assert_eq!(merged.status, ProductStatus::AlmostEmpty);
+        assert_eq!(merged.name, "Leche entera");
+        assert_eq!(merged.location, Some(ProductLocation::Fridge));
+        assert_eq!(merged.quantity.as_deref(), Some("1L"));
+        assert_eq!(merged.expiry_date, expiry_date);
+        assert_eq!(merged.expiry_type, ExpiryType::UseBy);
+        assert_eq!(merged.category, Some(ProductCategory::Dairy));
+        assert_eq!(merged.tags, ["desayuno"]);
+    }
+
+    #[test]
+    fn should_clear_fields_patched_to_none() {
+        let id = Uuid::new_v4();
+
+        let merged = merge(
+            stored_product(id),
+            PatchProductParams {
+                quantity: Some(None),
+                expiry_date: Some(None),
+                ..empty_patch(id)
+            },
+        );
+
+        assert!(merged.quantity.is_none());
+        assert!(merged.expiry_date.is_none());
+        assert_eq!(merged.location, Some(ProductLocation::Fridge));
+    }
+
+    #[test]
+    fn should_drop_outcome_when_reviving_a_finished_product() {
+        let id = Uuid::new_v4();
+        let mut finished = stored_product(id);
+        finished.status = ProductStatus::Finished;
+        finished.outcome = Some(ProductOutcome::Used);
+
+        let merged = merge(
+            finished,
+            PatchProductParams {
+                status: Some(ProductStatus::Opened),
+                ..empty_patch(id)
+            },
+        );
+
+        assert!(merged.outcome.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_save_merged_product_through_full_update() {
+        let id = Uuid::new_v4();
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_by_id()
+            .returning(move |_, _| Ok(stored_product(id)));
+        let mut update = MockUpdateProduct::new();
+        update
+            .expect_execute()
+            .withf(|params| params.name == "Leche desnatada" && params.quantity.is_some())
+            .times(1)
+            .returning(|params| {
+                Ok(Product::from_repository(
+                    params.id,
+                    params.user_id,
+                    params.name,
+                    params.status,
+                    params.location,
+                    params.quantity,
+                    params.expiry_date,
+                    params.estimated_expiry_date,
+                    params.expiry_type,
+                    params.outcome,
+                    Utc::now(),
+                    Utc::now(),
+                ))
+            });
+
+        let use_case = PatchProductUseCaseImpl {
+            repository: Arc::new(repo),
+            update_use_case: Arc::new(update),
+            logger: mock_logger(),
+        };
+
+        let product = use_case
+            .execute(PatchProductParams {
+                name: Some("Leche desnatada".to_string()),
+                ..empty_patch(id)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(product.name, "Leche desnatada");
+        assert!(product.expiry_date.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_product_does_not_exist() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+        let mut update = MockUpdateProduct::new();
+        update.expect_execute().never();
+
+        let use_case = PatchProductUseCaseImpl {
+            repository: Arc::new(repo),
+            update_use_case: Arc::new(update),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(empty_patch(Uuid::new_v4())).await;
+
+        assert!(matches!(result, Err(ProductError::NotFound)));
+    }
+}
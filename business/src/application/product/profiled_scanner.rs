@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::receipt_profile::ReceiptProfiles;
+use crate::domain::product::services::{ReceiptScanResult, ReceiptScannerService};
+
+/// Receipt scanner that recognizes the store a receipt comes from and
+/// applies its parsing profile to whatever the wrapped scanner read.
+pub struct ProfiledReceiptScanner {
+    pub scanner: Arc<dyn ReceiptScannerService>,
+    pub profiles: ReceiptProfiles,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl ProfiledReceiptScanner {
+    fn apply(&self, result: ReceiptScanResult) -> ReceiptScanResult {
+        let read = result.items.len();
+        let result = self.profiles.apply(result);
+        if let Some(store) = &result.store {
+            self.logger.debug(&format!(
+                "Receipt from {}: kept {} of {} items",
+                store,
+                result.items.len(),
+                read
+            ));
+        }
+        result
+    }
+}
+
+#[async_trait]
+impl ReceiptScannerService for ProfiledReceiptScanner {
+    async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        let result = self.scanner.scan(image_base64).await?;
+        Ok(self.apply(result))
+    }
+
+    fn can_scan_without_ai(&self) -> bool {
+        self.scanner.can_scan_without_ai()
+    }
+
+    async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        let result = self.scanner.scan_without_ai(image_base64).await?;
+        Ok(self.apply(result))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::services::{IdentificationConfidence, ReceiptItem};
+    use mockall::mock;
+
+    mock! {
+        pub ReceiptScanner {}
+
+        #[async_trait]
+        impl ReceiptScannerService for ReceiptScanner {
+            async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+            fn can_scan_without_ai(&self) -> bool;
+            async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn lidl_receipt(_: &str) -> Result<ReceiptScanResult, ProductError> {
+        Ok(ReceiptScanResult {
+            items: ["YOG NAT", "W5 LAVAVAJILLAS", "Pan de molde"]
+                .iter()
+                .map(|name| ReceiptItem {
+                    name: name.to_string(),
+                    confidence: IdentificationConfidence::Low,
+                })
+                .collect(),
+            store: Some("LIDL SUPERMERCADOS S.A.U.".to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn should_apply_profile_of_detected_store() {
+        let mut scanner = MockReceiptScanner::new();
+        scanner.expect_scan().returning(lidl_receipt);
+
+        let profiled = ProfiledReceiptScanner {
+            scanner: Arc::new(scanner),
+            profiles: ReceiptProfiles::default(),
+            logger: mock_logger(),
+        };
+
+        let result = profiled.scan("receipt").await.unwrap();
+
+        assert_eq!(result.store.as_deref(), Some("Lidl"));
+        let names: Vec<_> = result.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["YOGUR NATURAL", "Pan de molde"]);
+    }
+
+    #[tokio::test]
+    async fn should_apply_profile_to_scans_without_ai() {
+        let mut scanner = MockReceiptScanner::new();
+        scanner.expect_can_scan_without_ai().return_const(true);
+        scanner.expect_scan_without_ai().returning(lidl_receipt);
+
+        let profiled = ProfiledReceiptScanner {
+            scanner: Arc::new(scanner),
+            profiles: ReceiptProfiles::default(),
+            logger: mock_logger(),
+        };
+
+        assert!(profiled.can_scan_without_ai());
+        let result = profiled.scan_without_ai("receipt").await.unwrap();
+
+        assert_eq!(result.items.len(), 2);
+    }
+}
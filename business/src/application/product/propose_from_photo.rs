@@ -0,0 +1,233 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::photo_diff::{PhotoDiff, diff_photo_against_pantry};
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::services::ProductIdentifierService;
+use crate::domain::product::use_cases::propose_from_photo::{
+    ProposeFromPhotoParams, ProposeFromPhotoUseCase,
+};
+use crate::domain::quota::services::QuotaService;
+
+pub struct ProposeFromPhotoUseCaseImpl {
+    pub identifier: Arc<dyn ProductIdentifierService>,
+    pub repository: Arc<dyn ProductRepository>,
+    pub quota_service: Arc<dyn QuotaService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ProposeFromPhotoUseCase for ProposeFromPhotoUseCaseImpl {
+    async fn execute(&self, params: ProposeFromPhotoParams) -> Result<PhotoDiff, ProductError> {
+        self.logger.info(&format!(
+            "Proposing pantry changes from a {} photo",
+            params.location
+        ));
+
+        self.quota_service.consume_ai_call(&params.user_id).await?;
+
+        let detected = self
+            .identifier
+            .identify_all_by_image(&params.image_base64)
+            .await?;
+        let products = self
+            .repository
+            .find(&ProductQuery::active(params.user_id.clone()))
+            .await?;
+
+        let diff = diff_photo_against_pantry(detected, &products, &params.location);
+
+        self.logger.info(&format!(
+            "Photo diff: {} creations, {} updates, {} unchanged",
+            diff.creations.len(),
+            diff.updates.len(),
+            diff.unchanged.len()
+        ));
+
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::services::{
+        IdentificationConfidence, IdentificationMethod, ProductIdentification,
+    };
+    use crate::domain::product::value_objects::{ExpiryType, ProductLocation, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductIdentifier {}
+
+        #[async_trait]
+        impl ProductIdentifierService for ProductIdentifier {
+            async fn identify_by_image(
+                &self,
+                image_base64: &str,
+            ) -> Result<ProductIdentification, ProductError>;
+
+            async fn identify_by_barcode(
+                &self,
+                barcode: &str,
+            ) -> Result<ProductIdentification, ProductError>;
+
+            async fn identify_all_by_image(
+                &self,
+                image_base64: &str,
+            ) -> Result<Vec<ProductIdentification>, ProductError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
+    fn params() -> ProposeFromPhotoParams {
+        ProposeFromPhotoParams {
+            user_id: test_user_id(),
+            image_base64: "fridge_shelf".to_string(),
+            location: ProductLocation::Fridge,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_diff_identified_items_against_active_products() {
+        let milk = Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Leche".to_string(),
+            ProductStatus::Opened,
+            Some(ProductLocation::Fridge),
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        let milk_id = milk.id;
+
+        let mut identifier = MockProductIdentifier::new();
+        identifier.expect_identify_all_by_image().returning(|_| {
+            Ok(["Leche", "Pimientos"]
+                .into_iter()
+                .map(|name| ProductIdentification {
+                    name: name.to_string(),
+                    confidence: IdentificationConfidence::High,
+                    method: IdentificationMethod::Visual,
+                    suggested_location: None,
+                    suggested_quantity: None,
+                    suggested_expiry_type: None,
+                    suggested_category: None,
+                    enrichment: None,
+                    ai_call_id: None,
+                })
+                .collect())
+        });
+        let mut repository = MockProductRepo::new();
+        repository
+            .expect_find()
+            .returning(move |_| Ok(vec![milk.clone()]));
+
+        let use_case = ProposeFromPhotoUseCaseImpl {
+            identifier: Arc::new(identifier),
+            repository: Arc::new(repository),
+            quota_service: unlimited_quota(),
+            logger: mock_logger(),
+        };
+
+        let diff = use_case.execute(params()).await.unwrap();
+
+        assert_eq!(diff.unchanged, vec![milk_id]);
+        assert_eq!(diff.creations.len(), 1);
+        assert_eq!(diff.creations[0].name, "Pimientos");
+    }
+
+    #[tokio::test]
+    async fn should_not_call_identifier_when_quota_exceeded() {
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+        let mut identifier = MockProductIdentifier::new();
+        identifier.expect_identify_all_by_image().never();
+
+        let use_case = ProposeFromPhotoUseCaseImpl {
+            identifier: Arc::new(identifier),
+            repository: Arc::new(MockProductRepo::new()),
+            quota_service: Arc::new(quota),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params()).await;
+
+        assert!(matches!(result, Err(ProductError::Quota(_))));
+    }
+}
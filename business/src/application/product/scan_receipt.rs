@@ -6,9 +6,12 @@ use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::services::{ReceiptScanResult, ReceiptScannerService};
 use crate::domain::product::use_cases::scan_receipt::{ScanReceiptParams, ScanReceiptUseCase};
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::services::QuotaService;
 
 pub struct ScanReceiptUseCaseImpl {
     pub scanner: Arc<dyn ReceiptScannerService>,
+    pub quota_service: Arc<dyn QuotaService>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -17,7 +20,18 @@ impl ScanReceiptUseCase for ScanReceiptUseCaseImpl {
     async fn execute(&self, params: ScanReceiptParams) -> Result<ReceiptScanResult, ProductError> {
         self.logger.info("Scanning receipt image");
 
-        let result = self.scanner.scan(&params.image_base64).await?;
+        let result = match self.quota_service.consume_ai_call(&params.user_id).await {
+            Ok(()) => self.scanner.scan(&params.image_base64).await?,
+            // Out of AI calls, local OCR still gives best-effort items
+            Err(QuotaError::AiCallsExceeded) if self.scanner.can_scan_without_ai() => {
+                self.logger.info(&format!(
+                    "AI quota exhausted for user {}, reading receipt with OCR",
+                    params.user_id
+                ));
+                self.scanner.scan_without_ai(&params.image_base64).await?
+            }
+            Err(e) => return Err(e.into()),
+        };
 
         self.logger.info(&format!(
             "Receipt scanned: {} items found",
@@ -32,6 +46,8 @@ impl ScanReceiptUseCase for ScanReceiptUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::product::services::{IdentificationConfidence, ReceiptItem};
+    use crate::domain::quota::model::Usage;
+    use crate::domain::shared::value_objects::UserId;
     use mockall::mock;
 
     mock! {
@@ -43,6 +59,28 @@ mod tests {
         }
     }
 
+    mock! {
+        pub OcrScanner {}
+
+        #[async_trait]
+        impl ReceiptScannerService for OcrScanner {
+            async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+            fn can_scan_without_ai(&self) -> bool;
+            async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
     mock! {
         pub Log {}
 
@@ -63,6 +101,17 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
     #[tokio::test]
     async fn should_return_items_when_receipt_scanned_successfully() {
         let mut mock_scanner = MockReceiptScanner::new();
@@ -82,16 +131,19 @@ mod tests {
                         confidence: IdentificationConfidence::Low,
                     },
                 ],
+                store: None,
             })
         });
 
         let use_case = ScanReceiptUseCaseImpl {
             scanner: Arc::new(mock_scanner),
+            quota_service: unlimited_quota(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute(ScanReceiptParams {
+                user_id: test_user_id(),
                 image_base64: "receipt_image_data".to_string(),
             })
             .await;
@@ -105,17 +157,22 @@ mod tests {
     #[tokio::test]
     async fn should_return_empty_items_when_receipt_has_no_products() {
         let mut mock_scanner = MockReceiptScanner::new();
-        mock_scanner
-            .expect_scan()
-            .returning(|_| Ok(ReceiptScanResult { items: vec![] }));
+        mock_scanner.expect_scan().returning(|_| {
+            Ok(ReceiptScanResult {
+                items: vec![],
+                store: None,
+            })
+        });
 
         let use_case = ScanReceiptUseCaseImpl {
             scanner: Arc::new(mock_scanner),
+            quota_service: unlimited_quota(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute(ScanReceiptParams {
+                user_id: test_user_id(),
                 image_base64: "blank_receipt".to_string(),
             })
             .await;
@@ -129,20 +186,89 @@ mod tests {
         let mut mock_scanner = MockReceiptScanner::new();
         mock_scanner
             .expect_scan()
-            .returning(|_| Err(ProductError::ScanFailed));
+            .returning(|_| Err(ProductError::scan_failed("model unavailable")));
 
         let use_case = ScanReceiptUseCaseImpl {
             scanner: Arc::new(mock_scanner),
+            quota_service: unlimited_quota(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute(ScanReceiptParams {
+                user_id: test_user_id(),
                 image_base64: "corrupted_image".to_string(),
             })
             .await;
 
         assert!(result.is_err());
-        assert!(matches!(result.unwrap_err(), ProductError::ScanFailed));
+        assert!(matches!(result.unwrap_err(), ProductError::ScanFailed(_)));
+    }
+
+    fn exhausted_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+        Arc::new(quota)
+    }
+
+    #[tokio::test]
+    async fn should_read_receipt_without_ai_when_quota_exhausted() {
+        let mut mock_scanner = MockOcrScanner::new();
+        mock_scanner.expect_scan().never();
+        mock_scanner.expect_can_scan_without_ai().returning(|| true);
+        mock_scanner
+            .expect_scan_without_ai()
+            .times(1)
+            .returning(|_| {
+                Ok(ReceiptScanResult {
+                    items: vec![ReceiptItem {
+                        name: "LECHE ENTERA".to_string(),
+                        confidence: IdentificationConfidence::Low,
+                    }],
+                    store: None,
+                })
+            });
+
+        let use_case = ScanReceiptUseCaseImpl {
+            scanner: Arc::new(mock_scanner),
+            quota_service: exhausted_quota(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ScanReceiptParams {
+                user_id: test_user_id(),
+                image_base64: "receipt_image_data".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.items.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_quota_error_when_exhausted_and_no_ocr() {
+        let mut mock_scanner = MockReceiptScanner::new();
+        mock_scanner.expect_scan().never();
+
+        let use_case = ScanReceiptUseCaseImpl {
+            scanner: Arc::new(mock_scanner),
+            quota_service: exhausted_quota(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ScanReceiptParams {
+                user_id: test_user_id(),
+                image_base64: "receipt_image_data".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ProductError::Quota(QuotaError::AiCallsExceeded))
+        ));
     }
 }
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::fixtures::sample_pantry;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::seed::{
+    MAX_SEED_PRODUCTS, SeedProductsParams, SeedProductsUseCase,
+};
+
+/// Seeds pantries for load tests. Writes straight to the repository: no
+/// quota checks, no AI estimation and no events.
+pub struct SeedProductsUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl SeedProductsUseCase for SeedProductsUseCaseImpl {
+    async fn execute(&self, params: SeedProductsParams) -> Result<usize, ProductError> {
+        let count = params.count.min(MAX_SEED_PRODUCTS);
+        self.logger.info(&format!(
+            "Seeding {} products for load testing (seed {})",
+            count, params.seed
+        ));
+
+        let products = sample_pantry(&params.user_id, count, params.seed, Utc::now());
+        for product in &products {
+            self.repository.insert(product).await?;
+        }
+
+        self.logger
+            .info(&format!("Seeded {} products", products.len()));
+        Ok(products.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: uuid::Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_insert_requested_products_for_user() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_insert()
+            .withf(|product| product.user_id == test_user_id())
+            .times(25)
+            .returning(|_| Ok(()));
+        let use_case = SeedProductsUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let created = use_case
+            .execute(SeedProductsParams {
+                user_id: test_user_id(),
+                count: 25,
+                seed: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(created, 25);
+    }
+
+    #[tokio::test]
+    async fn should_cap_count_at_max_seed_products() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_insert()
+            .times(MAX_SEED_PRODUCTS)
+            .returning(|_| Ok(()));
+        let use_case = SeedProductsUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let created = use_case
+            .execute(SeedProductsParams {
+                user_id: test_user_id(),
+                count: MAX_SEED_PRODUCTS * 10,
+                seed: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(created, MAX_SEED_PRODUCTS);
+    }
+
+    #[tokio::test]
+    async fn should_stop_on_repository_error() {
+        let mut repo = MockProductRepo::new();
+        repo.expect_insert()
+            .times(1)
+            .returning(|_| Err(RepositoryError::Persistence));
+        let use_case = SeedProductsUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(SeedProductsParams {
+                user_id: test_user_id(),
+                count: 10,
+                seed: 1,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::Repository(_))));
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use futures::stream::BoxStream;
+
+use crate::application::product::change_feed::ProductChangeFeed;
+use crate::domain::logger::Logger;
+use crate::domain::product::events::ProductChange;
+use crate::domain::product::use_cases::subscribe_changes::{
+    SubscribeProductChangesParams, SubscribeProductChangesUseCase,
+};
+
+pub struct SubscribeProductChangesUseCaseImpl {
+    pub feed: Arc<ProductChangeFeed>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl SubscribeProductChangesUseCase for SubscribeProductChangesUseCaseImpl {
+    fn execute(&self, params: SubscribeProductChangesParams) -> BoxStream<'static, ProductChange> {
+        self.logger.info(&format!(
+            "Streaming product changes to user {}",
+            params.user_id
+        ));
+        self.feed.subscribe(params.user_id)
+    }
+}
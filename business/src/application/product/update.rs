@@ -3,18 +3,23 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
 use crate::domain::logger::Logger;
 use crate::domain::product::errors::ProductError;
+use crate::domain::product::events::{
+    ProductOutcomeRecorded, ProductStatusChanged, ProductUpdated,
+};
+use crate::domain::product::lifecycle::StatusTransition;
 use crate::domain::product::model::Product;
 use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::tags::normalize_tags;
+use crate::domain::product::urgency::{ExpiringSoonWindow, get_urgency_level};
 use crate::domain::product::use_cases::update::{UpdateProductParams, UpdateProductUseCase};
 use crate::domain::product::value_objects::ProductStatus;
-use crate::domain::shopping_item::model::ShoppingItem;
-use crate::domain::shopping_item::repository::ShoppingItemRepository;
 
 pub struct UpdateProductUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
-    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -32,6 +37,8 @@ impl UpdateProductUseCase for UpdateProductUseCaseImpl {
             return Err(ProductError::OutcomeRequiresFinishedStatus);
         }
 
+        let tags = normalize_tags(params.tags)?;
+
         // Verify product exists
         let existing = self
             .repository
@@ -44,8 +51,9 @@ impl UpdateProductUseCase for UpdateProductUseCaseImpl {
 
         let old_status = existing.status.clone();
         let new_status = params.status.clone();
+        let transition = old_status.transition_to(&new_status)?;
 
-        let updated_product = Product::from_repository(
+        let mut updated_product = Product::from_repository(
             existing.id,
             existing.user_id.clone(),
             params.name.clone(),
@@ -54,44 +62,63 @@ impl UpdateProductUseCase for UpdateProductUseCaseImpl {
             params.quantity,
             params.expiry_date,
             params.estimated_expiry_date,
+            params.expiry_type,
             params.outcome,
             existing.created_at,
             chrono::Utc::now(),
         );
+        // The estimator's confidence only holds while its estimate is kept
+        if updated_product.estimated_expiry_date == existing.estimated_expiry_date {
+            updated_product.expiry_confidence = existing.expiry_confidence.clone();
+        }
+        updated_product.estimation_status = existing.estimation_status;
+        updated_product.category = params.category;
+        updated_product.tags = tags;
 
-        self.repository.save(&updated_product).await?;
-
-        // Auto-add to shopping list when transitioning to Finished
-        if new_status == ProductStatus::Finished
-            && old_status != ProductStatus::Finished
-            && let Ok(None) = self
-                .shopping_item_repository
-                .find_by_product_id(existing.id, &params.user_id)
-                .await
-            && let Ok(item) =
-                ShoppingItem::new(params.user_id.clone(), params.name, Some(existing.id))
-            && let Err(e) = self.shopping_item_repository.save(&item).await
-        {
-            self.logger.warn(&format!(
-                "Failed to auto-add shopping item for product {}: {}",
-                existing.id, e
-            ));
+        self.repository
+            .update(&updated_product)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ProductError::NotFound,
+                other => ProductError::Repository(other),
+            })?;
+
+        if let Some(transition) = transition {
+            self.event_publisher
+                .publish(DomainEvent::ProductStatusChanged(ProductStatusChanged {
+                    product_id: updated_product.id,
+                    user_id: params.user_id.clone(),
+                    product_name: updated_product.name.clone(),
+                    previous_status: old_status,
+                    new_status,
+                    transition,
+                }))
+                .await;
         }
 
-        // Remove from shopping list when reverting from Finished
-        if old_status == ProductStatus::Finished
-            && new_status != ProductStatus::Finished
-            && let Err(e) = self
-                .shopping_item_repository
-                .delete_by_product_id(existing.id, &params.user_id)
-                .await
+        if transition == Some(StatusTransition::Finished)
+            && let Some(outcome) = updated_product.outcome.clone()
         {
-            self.logger.warn(&format!(
-                "Failed to remove shopping item for product {}: {}",
-                existing.id, e
-            ));
+            self.event_publisher
+                .publish(DomainEvent::ProductOutcomeRecorded(
+                    ProductOutcomeRecorded {
+                        product_id: updated_product.id,
+                        user_id: params.user_id.clone(),
+                        product_name: updated_product.name.clone(),
+                        outcome,
+                        urgency: get_urgency_level(&existing, ExpiringSoonWindow::default()),
+                    },
+                ))
+                .await;
         }
 
+        self.event_publisher
+            .publish(DomainEvent::ProductUpdated(ProductUpdated {
+                product_id: updated_product.id,
+                user_id: params.user_id,
+            }))
+            .await;
+
         self.logger
             .info(&format!("Product updated: {}", updated_product.id));
         Ok(updated_product)
@@ -101,9 +128,12 @@ impl UpdateProductUseCase for UpdateProductUseCaseImpl {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::product::value_objects::{ProductOutcome, ProductStatus};
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::urgency::UrgencyLevel;
+    use crate::domain::product::value_objects::{
+        ExpiryType, ProductCategory, ProductOutcome, ProductStatus,
+    };
     use crate::domain::shared::value_objects::UserId;
-    use crate::domain::shopping_item::model::ShoppingItem;
     use chrono::Utc;
     use mockall::mock;
     use uuid::Uuid;
@@ -113,26 +143,24 @@ mod tests {
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
         }
     }
 
     mock! {
-        pub ShoppingItemRepo {}
+        pub Publisher {}
 
         #[async_trait]
-        impl ShoppingItemRepository for ShoppingItemRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
-            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
-            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
-            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
         }
     }
 
@@ -170,6 +198,7 @@ mod tests {
             None,
             None,
             None,
+            ExpiryType::None,
             None,
             Utc::now(),
             Utc::now(),
@@ -181,7 +210,7 @@ mod tests {
         let product_id = Uuid::new_v4();
         let now = Utc::now();
         let mut mock_repo = MockProductRepo::new();
-        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        let mut mock_publisher = MockPublisher::new();
 
         mock_repo.expect_get_by_id().returning(move |_, _| {
             Ok(Product::from_repository(
@@ -193,17 +222,19 @@ mod tests {
                 None,
                 None,
                 None,
+                ExpiryType::None,
                 None,
                 now,
                 now,
             ))
         });
-        mock_repo.expect_save().returning(|_| Ok(()));
-        mock_shopping_repo.expect_find_by_product_id().never();
+        mock_repo.expect_update().returning(|_| Ok(()));
+        // Status change, then the edit itself
+        mock_publisher.expect_publish().times(2).returning(|_| ());
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -217,7 +248,10 @@ mod tests {
                 quantity: Some("750ml".to_string()),
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -227,14 +261,117 @@ mod tests {
         assert_eq!(product.status, ProductStatus::Opened);
     }
 
+    #[tokio::test]
+    async fn should_return_not_found_when_product_deleted_before_update() {
+        let product_id = Uuid::new_v4();
+        let now = Utc::now();
+        let mut mock_repo = MockProductRepo::new();
+        let mut mock_publisher = MockPublisher::new();
+
+        mock_repo.expect_get_by_id().returning(move |_, _| {
+            Ok(Product::from_repository(
+                product_id,
+                UserId::new("test-user-id"),
+                "Old Name".to_string(),
+                ProductStatus::New,
+                None,
+                None,
+                None,
+                None,
+                ExpiryType::None,
+                None,
+                now,
+                now,
+            ))
+        });
+        mock_repo
+            .expect_update()
+            .returning(|_| Err(RepositoryError::NotFound));
+        mock_publisher.expect_publish().never();
+
+        let use_case = UpdateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            event_publisher: Arc::new(mock_publisher),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateProductParams {
+                id: product_id,
+                user_id: test_user_id(),
+                name: "Old Name".to_string(),
+                status: ProductStatus::Opened,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ProductError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn should_save_category_and_normalized_tags() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockProductRepo::new();
+        let mut mock_publisher = MockPublisher::new();
+
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Opened)));
+        mock_repo
+            .expect_update()
+            .withf(|product| {
+                product.category == Some(ProductCategory::Dairy)
+                    && product.tags == ["desayuno", "sin lactosa"]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_publisher.expect_publish().returning(|_| ());
+
+        let use_case = UpdateProductUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            event_publisher: Arc::new(mock_publisher),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateProductParams {
+                id: product_id,
+                user_id: test_user_id(),
+                name: "Leche".to_string(),
+                status: ProductStatus::Opened,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: Some(ProductCategory::Dairy),
+                tags: vec![
+                    "Desayuno".to_string(),
+                    " sin lactosa ".to_string(),
+                    "desayuno".to_string(),
+                ],
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn should_reject_update_when_name_is_empty() {
         let mock_repo = MockProductRepo::new();
-        let mock_shopping_repo = MockShoppingItemRepo::new();
+        let mock_publisher = MockPublisher::new();
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -248,7 +385,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -259,11 +399,11 @@ mod tests {
     #[tokio::test]
     async fn should_reject_update_outcome_when_status_not_finished() {
         let mock_repo = MockProductRepo::new();
-        let mock_shopping_repo = MockShoppingItemRepo::new();
+        let mock_publisher = MockPublisher::new();
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -277,7 +417,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: Some(ProductOutcome::ThrownAway),
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -291,14 +434,14 @@ mod tests {
     #[tokio::test]
     async fn should_return_not_found_when_updating_nonexistent_product() {
         let mut mock_repo = MockProductRepo::new();
-        let mock_shopping_repo = MockShoppingItemRepo::new();
+        let mock_publisher = MockPublisher::new();
         mock_repo
             .expect_get_by_id()
             .returning(|_, _| Err(RepositoryError::NotFound));
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -312,7 +455,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -323,7 +469,7 @@ mod tests {
     #[tokio::test]
     async fn should_return_not_found_when_updating_product_from_other_user() {
         let mut mock_repo = MockProductRepo::new();
-        let mock_shopping_repo = MockShoppingItemRepo::new();
+        let mock_publisher = MockPublisher::new();
         // Repository returns NotFound for products belonging to other users
         mock_repo
             .expect_get_by_id()
@@ -331,7 +477,7 @@ mod tests {
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -345,7 +491,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -354,24 +503,54 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_auto_add_shopping_item_when_product_transitions_to_finished() {
+    async fn should_publish_status_changed_when_product_transitions_to_finished() {
         let product_id = Uuid::new_v4();
         let mut mock_repo = MockProductRepo::new();
-        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        let mut mock_publisher = MockPublisher::new();
 
         mock_repo
             .expect_get_by_id()
             .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Opened)));
-        mock_repo.expect_save().returning(|_| Ok(()));
-
-        mock_shopping_repo
-            .expect_find_by_product_id()
-            .returning(|_, _| Ok(None));
-        mock_shopping_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_update().returning(|_| Ok(()));
+
+        mock_publisher
+            .expect_publish()
+            .withf(move |event| {
+                *event
+                    == DomainEvent::ProductStatusChanged(ProductStatusChanged {
+                        product_id,
+                        user_id: UserId::new("test-user-id"),
+                        product_name: "Test Product".to_string(),
+                        previous_status: ProductStatus::Opened,
+                        new_status: ProductStatus::Finished,
+                        transition: StatusTransition::Finished,
+                    })
+            })
+            .times(1)
+            .returning(|_| ());
+        mock_publisher
+            .expect_publish()
+            .withf(move |event| {
+                *event
+                    == DomainEvent::ProductOutcomeRecorded(ProductOutcomeRecorded {
+                        product_id,
+                        user_id: UserId::new("test-user-id"),
+                        product_name: "Test Product".to_string(),
+                        outcome: ProductOutcome::Used,
+                        urgency: UrgencyLevel::Ok,
+                    })
+            })
+            .times(1)
+            .returning(|_| ());
+        mock_publisher
+            .expect_publish()
+            .withf(|event| matches!(event, DomainEvent::ProductUpdated(_)))
+            .times(1)
+            .returning(|_| ());
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -385,7 +564,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: Some(ProductOutcome::Used),
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -393,36 +575,33 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_not_duplicate_when_already_in_shopping_list() {
+    async fn should_publish_status_changed_when_reverted_from_finished() {
         let product_id = Uuid::new_v4();
         let mut mock_repo = MockProductRepo::new();
-        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        let mut mock_publisher = MockPublisher::new();
 
         mock_repo
             .expect_get_by_id()
-            .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Opened)));
-        mock_repo.expect_save().returning(|_| Ok(()));
-
-        // Already exists in shopping list
-        mock_shopping_repo
-            .expect_find_by_product_id()
-            .returning(move |_, _| {
-                Ok(Some(ShoppingItem::from_repository(
-                    Uuid::new_v4(),
-                    UserId::new("test-user-id"),
-                    "Test Product".to_string(),
-                    Some(product_id),
-                    false,
-                    Utc::now(),
-                    Utc::now(),
-                )))
-            });
-        // save should NOT be called on shopping_item_repository
-        mock_shopping_repo.expect_save().never();
+            .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Finished)));
+        mock_repo.expect_update().returning(|_| Ok(()));
+
+        mock_publisher
+            .expect_publish()
+            .withf(|event| match event {
+                DomainEvent::ProductStatusChanged(changed) => changed.restored(),
+                _ => false,
+            })
+            .times(1)
+            .returning(|_| ());
+        mock_publisher
+            .expect_publish()
+            .withf(|event| matches!(event, DomainEvent::ProductUpdated(_)))
+            .times(1)
+            .returning(|_| ());
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -431,12 +610,15 @@ mod tests {
                 id: product_id,
                 user_id: test_user_id(),
                 name: "Test Product".to_string(),
-                status: ProductStatus::Finished,
+                status: ProductStatus::Opened,
                 location: None,
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
-                outcome: Some(ProductOutcome::Used),
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
@@ -444,23 +626,20 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_remove_shopping_item_when_reverted_from_finished() {
+    async fn should_reject_update_when_opened_product_goes_back_to_new() {
         let product_id = Uuid::new_v4();
         let mut mock_repo = MockProductRepo::new();
-        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        let mut mock_publisher = MockPublisher::new();
 
         mock_repo
             .expect_get_by_id()
-            .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Finished)));
-        mock_repo.expect_save().returning(|_| Ok(()));
-
-        mock_shopping_repo
-            .expect_delete_by_product_id()
-            .returning(|_, _| Ok(()));
+            .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Opened)));
+        mock_repo.expect_update().never();
+        mock_publisher.expect_publish().never();
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -469,37 +648,53 @@ mod tests {
                 id: product_id,
                 user_id: test_user_id(),
                 name: "Test Product".to_string(),
-                status: ProductStatus::Opened,
+                status: ProductStatus::New,
                 location: None,
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: None,
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
-        assert!(result.is_ok());
+        assert!(matches!(
+            result.unwrap_err(),
+            ProductError::InvalidStatusTransition {
+                from: ProductStatus::Opened,
+                to: ProductStatus::New,
+            }
+        ));
     }
 
     #[tokio::test]
-    async fn should_not_affect_shopping_list_when_status_stays_finished() {
+    async fn should_only_publish_the_edit_when_status_unchanged() {
         let product_id = Uuid::new_v4();
         let mut mock_repo = MockProductRepo::new();
-        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        let mut mock_publisher = MockPublisher::new();
 
         mock_repo
             .expect_get_by_id()
             .returning(move |_, _| Ok(make_product(product_id, ProductStatus::Finished)));
-        mock_repo.expect_save().returning(|_| Ok(()));
-
-        // Neither find_by_product_id nor delete_by_product_id should be called
-        mock_shopping_repo.expect_find_by_product_id().never();
-        mock_shopping_repo.expect_delete_by_product_id().never();
-        mock_shopping_repo.expect_save().never();
+        mock_repo.expect_update().returning(|_| Ok(()));
+
+        mock_publisher
+            .expect_publish()
+            .withf(move |event| {
+                *event
+                    == DomainEvent::ProductUpdated(ProductUpdated {
+                        product_id,
+                        user_id: UserId::new("test-user-id"),
+                    })
+            })
+            .times(1)
+            .returning(|_| ());
 
         let use_case = UpdateProductUseCaseImpl {
             repository: Arc::new(mock_repo),
-            shopping_item_repository: Arc::new(mock_shopping_repo),
+            event_publisher: Arc::new(mock_publisher),
             logger: mock_logger(),
         };
 
@@ -513,7 +708,10 @@ mod tests {
                 quantity: None,
                 expiry_date: None,
                 estimated_expiry_date: None,
+                expiry_type: ExpiryType::None,
                 outcome: Some(ProductOutcome::Used),
+                category: None,
+                tags: Vec::new(),
             })
             .await;
 
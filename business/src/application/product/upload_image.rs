@@ -0,0 +1,323 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::storage::thumbnail::ThumbnailJob;
+use crate::domain::logger::Logger;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::repository::{ProductImageRepository, ProductRepository};
+use crate::domain::product::use_cases::upload_image::{
+    UploadProductImageParams, UploadProductImageUseCase,
+};
+use crate::domain::storage::model::{ImageUpload, SignedUrl, product_image_key};
+use crate::domain::storage::services::BlobStorage;
+
+pub struct UploadProductImageUseCaseImpl {
+    pub repository: Arc<dyn ProductRepository>,
+    pub image_repository: Arc<dyn ProductImageRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    /// Makes the thumbnail in the background once the photo is stored.
+    pub thumbnails: Arc<ThumbnailJob>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl UploadProductImageUseCase for UploadProductImageUseCaseImpl {
+    async fn execute(&self, params: UploadProductImageParams) -> Result<SignedUrl, ProductError> {
+        self.logger
+            .info(&format!("Uploading image for product: {}", params.id));
+
+        let image =
+            ImageUpload::from_base64(&params.image_base64).ok_or(ProductError::InvalidImage)?;
+
+        if !self.repository.exists(params.id, &params.user_id).await? {
+            return Err(ProductError::NotFound);
+        }
+
+        let key = product_image_key(&params.user_id, params.id);
+        self.storage
+            .put(&key, image.bytes.clone(), image.content_type)
+            .await?;
+
+        let thumbnails = self.thumbnails.clone();
+        let image_repository = self.image_repository.clone();
+        let logger = self.logger.clone();
+        let image_key = key.clone();
+        tokio::spawn(async move {
+            let Some(thumbnail_key) = thumbnails.run(&image_key, image.bytes).await else {
+                return;
+            };
+            if let Err(e) = image_repository
+                .save_thumbnail(params.id, &params.user_id, &thumbnail_key)
+                .await
+            {
+                logger.warn(&format!(
+                    "Could not record thumbnail of product {}: {}",
+                    params.id, e
+                ));
+            }
+        });
+
+        Ok(self.storage.signed_url(&key)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::StoredObject;
+    use crate::domain::storage::services::ThumbnailService;
+    use chrono::Utc;
+    use mockall::mock;
+    use std::collections::HashMap;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub ImageRepo {}
+
+        #[async_trait]
+        impl ProductImageRepository for ImageRepo {
+            async fn save_thumbnail(&self, product_id: Uuid, user_id: &UserId, thumbnail_key: &str) -> Result<(), RepositoryError>;
+            async fn find_thumbnails(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, String>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Thumbnailer {}
+
+        impl ThumbnailService for Thumbnailer {
+            fn thumbnail(&self, image: &[u8]) -> Result<Vec<u8>, StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Job whose thumbnails always fail, for tests that don't look at them.
+    fn failing_thumbnails() -> Arc<ThumbnailJob> {
+        let mut thumbnailer = MockThumbnailer::new();
+        thumbnailer
+            .expect_thumbnail()
+            .returning(|_| Err(StorageError::UnreadableImage));
+        Arc::new(ThumbnailJob {
+            storage: Arc::new(MockStorage::new()),
+            thumbnailer: Arc::new(thumbnailer),
+            logger: mock_logger(),
+        })
+    }
+
+    /// Smallest byte sequence recognized as a JPEG, base64-encoded.
+    const JPEG_BASE64: &str = "/9j/4AAQ";
+
+    #[tokio::test]
+    async fn should_store_image_under_product_key_when_product_exists() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
+        let expected_key = format!("products/test-user-id/{product_id}");
+        let mut storage = MockStorage::new();
+        let put_key = expected_key.clone();
+        storage
+            .expect_put()
+            .withf(move |key, _, content_type| key == put_key && content_type == "image/jpeg")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: format!("https://cdn.example.com/{key}"),
+                expires_at: Utc::now(),
+            })
+        });
+
+        let use_case = UploadProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(MockImageRepo::new()),
+            storage: Arc::new(storage),
+            thumbnails: failing_thumbnails(),
+            logger: mock_logger(),
+        };
+
+        let signed = use_case
+            .execute(UploadProductImageParams {
+                id: product_id,
+                user_id: test_user_id(),
+                image_base64: JPEG_BASE64.to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            signed.url,
+            format!("https://cdn.example.com/{expected_key}")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_reject_upload_that_is_not_an_image() {
+        let mock_repo = MockProductRepo::new();
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+
+        let use_case = UploadProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(MockImageRepo::new()),
+            storage: Arc::new(storage),
+            thumbnails: failing_thumbnails(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UploadProductImageParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                image_base64: "aGVsbG8=".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::InvalidImage)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_uploading_for_missing_product() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(false));
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+
+        let use_case = UploadProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(MockImageRepo::new()),
+            storage: Arc::new(storage),
+            thumbnails: failing_thumbnails(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UploadProductImageParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                image_base64: JPEG_BASE64.to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ProductError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn should_record_thumbnail_once_generated() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_exists().returning(|_, _| Ok(true));
+        let mut storage = MockStorage::new();
+        storage.expect_put().returning(|_, _, _| Ok(()));
+        storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: key.to_string(),
+                expires_at: Utc::now(),
+            })
+        });
+        let mut thumbnail_storage = MockStorage::new();
+        thumbnail_storage.expect_put().returning(|_, _, _| Ok(()));
+        let mut thumbnailer = MockThumbnailer::new();
+        thumbnailer.expect_thumbnail().returning(|_| Ok(vec![1]));
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let mut image_repo = MockImageRepo::new();
+        image_repo
+            .expect_save_thumbnail()
+            .returning(move |id, _, key| {
+                sender.send((id, key.to_string())).unwrap();
+                Ok(())
+            });
+
+        let use_case = UploadProductImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            image_repository: Arc::new(image_repo),
+            storage: Arc::new(storage),
+            thumbnails: Arc::new(ThumbnailJob {
+                storage: Arc::new(thumbnail_storage),
+                thumbnailer: Arc::new(thumbnailer),
+                logger: mock_logger(),
+            }),
+            logger: mock_logger(),
+        };
+
+        use_case
+            .execute(UploadProductImageParams {
+                id: product_id,
+                user_id: test_user_id(),
+                image_base64: JPEG_BASE64.to_string(),
+            })
+            .await
+            .unwrap();
+
+        let recorded = tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .unwrap();
+        assert_eq!(
+            recorded,
+            Some((
+                product_id,
+                format!("thumbnails/products/test-user-id/{product_id}")
+            ))
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::prompt::errors::PromptError;
+use crate::domain::prompt::model::PromptTemplateInfo;
+use crate::domain::prompt::services::PromptTemplateService;
+use crate::domain::prompt::use_cases::reload::ReloadPromptTemplatesUseCase;
+
+pub struct ReloadPromptTemplatesUseCaseImpl {
+    pub prompt_templates: Arc<dyn PromptTemplateService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ReloadPromptTemplatesUseCase for ReloadPromptTemplatesUseCaseImpl {
+    async fn execute(&self) -> Result<Vec<PromptTemplateInfo>, PromptError> {
+        self.logger.info("Reloading prompt templates");
+
+        let mut templates = self.prompt_templates.reload().await.inspect_err(|e| {
+            self.logger.warn(&format!(
+                "Prompt templates not reloaded, keeping the current ones: {}",
+                e
+            ))
+        })?;
+        templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+        self.logger.info(&format!(
+            "Prompt templates reloaded: {}",
+            templates
+                .iter()
+                .map(|t| format!("{} v{} ({})", t.name, t.version, t.source))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+        Ok(templates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::prompt::model::TemplateSource;
+    use mockall::mock;
+
+    mock! {
+        pub Templates {}
+
+        #[async_trait]
+        impl PromptTemplateService for Templates {
+            async fn reload(&self) -> Result<Vec<PromptTemplateInfo>, PromptError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn info(name: &str, version: u32, source: TemplateSource) -> PromptTemplateInfo {
+        PromptTemplateInfo {
+            name: name.to_string(),
+            version,
+            source,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_reloaded_templates_sorted_by_name() {
+        let mut templates = MockTemplates::new();
+        templates.expect_reload().times(1).returning(|| {
+            Ok(vec![
+                info("suggestion_generator", 1, TemplateSource::Builtin),
+                info("expiry_estimator", 2, TemplateSource::Directory),
+            ])
+        });
+
+        let use_case = ReloadPromptTemplatesUseCaseImpl {
+            prompt_templates: Arc::new(templates),
+            logger: mock_logger(),
+        };
+
+        let reloaded = use_case.execute().await.unwrap();
+
+        assert_eq!(
+            reloaded,
+            vec![
+                info("expiry_estimator", 2, TemplateSource::Directory),
+                info("suggestion_generator", 1, TemplateSource::Builtin),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn should_report_invalid_templates() {
+        let mut templates = MockTemplates::new();
+        templates
+            .expect_reload()
+            .returning(|| Err(PromptError::invalid_template("unexpected end of block")));
+
+        let use_case = ReloadPromptTemplatesUseCaseImpl {
+            prompt_templates: Arc::new(templates),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute().await;
+
+        assert!(matches!(result, Err(PromptError::InvalidTemplate(_))));
+    }
+}
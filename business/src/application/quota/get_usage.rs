@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::model::Usage;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::quota::use_cases::get_usage::{GetUsageParams, GetUsageUseCase};
+
+pub struct GetUsageUseCaseImpl {
+    pub quota_service: Arc<dyn QuotaService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetUsageUseCase for GetUsageUseCaseImpl {
+    async fn execute(&self, params: GetUsageParams) -> Result<Usage, QuotaError> {
+        self.logger
+            .info(&format!("Getting plan usage for user: {}", params.user_id));
+
+        self.quota_service.get_usage(&params.user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::quota::model::PlanTier;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_usage_from_quota_service() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_get_usage().returning(|_| {
+            Ok(Usage {
+                plan: PlanTier::Free,
+                limits: PlanTier::Free.limits(),
+                ai_calls_today: 3,
+                products: 12,
+            })
+        });
+
+        let use_case = GetUsageUseCaseImpl {
+            quota_service: Arc::new(mock_quota),
+            logger: mock_logger(),
+        };
+
+        let usage = use_case
+            .execute(GetUsageParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(usage.ai_calls_today, 3);
+        assert_eq!(usage.products, 12);
+    }
+
+    #[tokio::test]
+    async fn should_propagate_error_when_counters_unavailable() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_get_usage().returning(|_| {
+            Err(QuotaError::Repository(RepositoryError::database_error(
+                "connection reset",
+            )))
+        });
+
+        let use_case = GetUsageUseCaseImpl {
+            quota_service: Arc::new(mock_quota),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetUsageParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), QuotaError::Repository(_)));
+    }
+}
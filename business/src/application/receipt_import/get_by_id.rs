@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::receipt_import::model::ReceiptImport;
+use crate::domain::receipt_import::repository::ReceiptImportRepository;
+use crate::domain::receipt_import::use_cases::get_by_id::{
+    GetReceiptImportParams, GetReceiptImportUseCase,
+};
+
+pub struct GetReceiptImportUseCaseImpl {
+    pub repository: Arc<dyn ReceiptImportRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetReceiptImportUseCase for GetReceiptImportUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetReceiptImportParams,
+    ) -> Result<ReceiptImport, ReceiptImportError> {
+        self.logger
+            .debug(&format!("Getting receipt import: {}", params.id));
+
+        self.repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReceiptImportError::NotFound,
+                other => ReceiptImportError::Repository(other),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ImportRepo {}
+
+        #[async_trait]
+        impl ReceiptImportRepository for ImportRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ReceiptImport, RepositoryError>;
+            async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+            async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_import_missing() {
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = GetReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetReceiptImportParams {
+                id: Uuid::new_v4(),
+                user_id: UserId::new("test-user-id"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ReceiptImportError::NotFound)));
+    }
+}
@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::receipt_import::repository::ReceiptImportRepository;
+use crate::domain::receipt_import::use_cases::get_image::{
+    GetReceiptImageParams, GetReceiptImageUseCase,
+};
+use crate::domain::storage::model::ImageLinks;
+use crate::domain::storage::services::BlobStorage;
+
+pub struct GetReceiptImageUseCaseImpl {
+    pub repository: Arc<dyn ReceiptImportRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetReceiptImageUseCase for GetReceiptImageUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetReceiptImageParams,
+    ) -> Result<ImageLinks, ReceiptImportError> {
+        self.logger
+            .debug(&format!("Getting photo of receipt import: {}", params.id));
+
+        let import = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReceiptImportError::NotFound,
+                other => ReceiptImportError::Repository(other),
+            })?;
+
+        let key = import.image_key.ok_or(ReceiptImportError::ImageNotFound)?;
+        let thumbnail = match import.thumbnail_key {
+            Some(thumbnail_key) => Some(self.storage.signed_url(&thumbnail_key)?),
+            None => None,
+        };
+        Ok(ImageLinks {
+            original: self.storage.signed_url(&key)?,
+            thumbnail,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::receipt_import::model::ReceiptImport;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ImportRepo {}
+
+        #[async_trait]
+        impl ReceiptImportRepository for ImportRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ReceiptImport, RepositoryError>;
+            async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+            async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_sign_urls_for_kept_receipt_photo_and_thumbnail() {
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo.expect_get_by_id().returning(|id, user_id| {
+            let mut import = ReceiptImport::new(user_id.clone());
+            import.id = id;
+            import.image_key = Some(format!("receipts/test-user-id/{id}"));
+            import.thumbnail_key = Some(format!("thumbnails/receipts/test-user-id/{id}"));
+            Ok(import)
+        });
+        let mut mock_storage = MockStorage::new();
+        mock_storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: format!("https://cdn.example.com/{key}"),
+                expires_at: Utc::now(),
+            })
+        });
+
+        let use_case = GetReceiptImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(mock_storage),
+            logger: mock_logger(),
+        };
+
+        let id = Uuid::new_v4();
+        let links = use_case
+            .execute(GetReceiptImageParams {
+                id,
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            links.original.url,
+            format!("https://cdn.example.com/receipts/test-user-id/{id}")
+        );
+        assert_eq!(
+            links.thumbnail.map(|t| t.url),
+            Some(format!(
+                "https://cdn.example.com/thumbnails/receipts/test-user-id/{id}"
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_image_not_found_when_photo_was_not_kept() {
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, user_id| Ok(ReceiptImport::new(user_id.clone())));
+        let mut mock_storage = MockStorage::new();
+        mock_storage.expect_signed_url().never();
+
+        let use_case = GetReceiptImageUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(mock_storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetReceiptImageParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ReceiptImportError::ImageNotFound)));
+    }
+}
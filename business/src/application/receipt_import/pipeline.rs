@@ -0,0 +1,667 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use futures::stream::{self, StreamExt};
+
+use crate::domain::ai_review::model::{AiChange, AiWriteMode, PendingAiChange};
+use crate::domain::ai_review::repository::AiReviewRepository;
+use crate::domain::feature_flag::model::{Feature, FeatureFlags};
+use crate::domain::logger::Logger;
+use crate::domain::product::expiry_snap::ExpirySnap;
+use crate::domain::product::model::{NewProductProps, Product};
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::services::{ExpiryEstimatorService, ReceiptScannerService};
+use crate::domain::product::shelf_life::estimate_from_category;
+use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::receipt_import::model::{
+    ImportReview, ImportedProduct, ReceiptImport, SkipReason, SkippedLine, name_key,
+    normalize_receipt_name,
+};
+use crate::domain::receipt_import::repository::ReceiptImportRepository;
+
+/// Expiry estimations run at the same time for one receipt.
+const MAX_CONCURRENT_ESTIMATIONS: usize = 4;
+
+/// Background work behind a receipt import: scan, normalize, dedupe, estimate
+/// expiry and create products, recording the outcome on the import.
+pub struct ReceiptImportPipeline {
+    pub import_repository: Arc<dyn ReceiptImportRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub scanner: Arc<dyn ReceiptScannerService>,
+    pub estimator: Arc<dyn ExpiryEstimatorService>,
+    /// Normalization applied to estimated dates before they are stored.
+    pub expiry_snap: ExpirySnap,
+    pub quota_service: Arc<dyn QuotaService>,
+    /// With `ExpiryEstimation` off, estimates come from the category defaults.
+    pub flags: FeatureFlags,
+    /// Decides whether estimated expiry dates are written or staged for review.
+    pub ai_review_repository: Arc<dyn AiReviewRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl ReceiptImportPipeline {
+    /// Runs the import to completion and returns it in its final state. Failures
+    /// are recorded on the import rather than returned.
+    pub async fn run(&self, import: ReceiptImport, image_base64: String) -> ReceiptImport {
+        self.run_with(import, image_base64, true).await
+    }
+
+    /// Like `run`, for users out of AI calls: the receipt is read with the
+    /// scanner's OCR fallback and expiry dates come from category defaults.
+    pub async fn run_without_ai(
+        &self,
+        import: ReceiptImport,
+        image_base64: String,
+    ) -> ReceiptImport {
+        self.run_with(import, image_base64, false).await
+    }
+
+    async fn run_with(
+        &self,
+        mut import: ReceiptImport,
+        image_base64: String,
+        ai: bool,
+    ) -> ReceiptImport {
+        import.start();
+        self.persist(&import).await;
+
+        match self.import_products(&import, &image_base64, ai).await {
+            Ok(review) => {
+                self.logger.info(&format!(
+                    "Receipt import {} completed: {} created, {} skipped",
+                    import.id,
+                    review.created.len(),
+                    review.skipped.len()
+                ));
+                import.complete(review);
+            }
+            Err(code) => {
+                self.logger
+                    .warn(&format!("Receipt import {} failed: {}", import.id, code));
+                import.fail(code);
+            }
+        }
+
+        self.persist(&import).await;
+        import
+    }
+
+    async fn import_products(
+        &self,
+        import: &ReceiptImport,
+        image_base64: &str,
+        ai: bool,
+    ) -> Result<ImportReview, String> {
+        let scan = if ai {
+            self.scanner.scan(image_base64).await
+        } else {
+            self.scanner.scan_without_ai(image_base64).await
+        }
+        .map_err(|e| e.to_string())?;
+
+        let existing: HashMap<String, uuid::Uuid> = self
+            .product_repository
+            .find(&ProductQuery::active(import.user_id.clone()))
+            .await
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|p| (name_key(&p.name), p.id))
+            .collect();
+
+        let mut review = ImportReview::default();
+        let mut candidates: Vec<String> = Vec::new();
+        for item in scan.items {
+            let Some(name) = normalize_receipt_name(&item.name) else {
+                review
+                    .skipped
+                    .push(skipped(item.name, SkipReason::InvalidName));
+                continue;
+            };
+            let key = name_key(&name);
+            if let Some(product_id) = existing.get(&key) {
+                review.skipped.push(SkippedLine {
+                    name: item.name,
+                    reason: SkipReason::AlreadyInPantry,
+                    existing_product_id: Some(*product_id),
+                });
+            } else if candidates.iter().any(|c| name_key(c) == key) {
+                review
+                    .skipped
+                    .push(skipped(item.name, SkipReason::DuplicateOnReceipt));
+            } else {
+                candidates.push(name);
+            }
+        }
+
+        // Without AI, estimates come from the category defaults
+        let ai_estimates = ai && self.flags.is_enabled(Feature::ExpiryEstimation);
+        let status = ProductStatus::New.to_string();
+        let estimations: Vec<_> = stream::iter(candidates)
+            .map(|name| {
+                let status = status.clone();
+                async move {
+                    if !ai_estimates {
+                        let estimation =
+                            estimate_from_category(&name, &ProductStatus::New, None, Utc::now());
+                        return (
+                            name,
+                            estimation.date.map(|d| self.expiry_snap.apply(d)),
+                            estimation.confidence,
+                        );
+                    }
+                    let estimation = self
+                        .estimator
+                        .estimate_expiry_date(&name, &status, None)
+                        .await;
+                    (
+                        name,
+                        estimation.date.map(|d| self.expiry_snap.apply(d)),
+                        estimation.confidence,
+                    )
+                }
+            })
+            .buffered(MAX_CONCURRENT_ESTIMATIONS)
+            .collect()
+            .await;
+
+        // Review mode only holds back AI estimates
+        let mode = if !ai_estimates || estimations.is_empty() {
+            AiWriteMode::Auto
+        } else {
+            self.ai_review_repository
+                .get_mode(&import.user_id)
+                .await
+                .map_err(|e| e.to_string())?
+        };
+
+        let mut limit_reached = false;
+        for (name, estimated_expiry_date, confidence) in estimations {
+            if !limit_reached {
+                match self
+                    .quota_service
+                    .ensure_product_capacity(&import.user_id)
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(QuotaError::ProductLimitReached) => limit_reached = true,
+                    Err(e) => return Err(e.to_string()),
+                }
+            }
+            if limit_reached {
+                review
+                    .skipped
+                    .push(skipped(name, SkipReason::PlanLimitReached));
+                continue;
+            }
+
+            let mut product = Product::new(NewProductProps {
+                user_id: import.user_id.clone(),
+                name,
+                status: ProductStatus::New,
+                location: None,
+                quantity: None,
+                expiry_date: None,
+                estimated_expiry_date: estimated_expiry_date.filter(|_| mode == AiWriteMode::Auto),
+                expiry_type: ExpiryType::None,
+                outcome: None,
+                category: None,
+                tags: Vec::new(),
+            })
+            .map_err(|e| e.to_string())?;
+            if product.estimated_expiry_date.is_some() {
+                product.expiry_confidence = Some(confidence);
+            }
+            self.product_repository
+                .insert(&product)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            if let (AiWriteMode::Review, Some(date)) = (mode, estimated_expiry_date) {
+                self.ai_review_repository
+                    .stage(&PendingAiChange::new(
+                        &product,
+                        AiChange::EstimatedExpiryDate(date),
+                    ))
+                    .await
+                    .map_err(|e| e.to_string())?;
+            }
+
+            review.created.push(ImportedProduct {
+                product_id: product.id,
+                name: product.name,
+                estimated_expiry_date: product.estimated_expiry_date,
+            });
+        }
+
+        Ok(review)
+    }
+
+    async fn persist(&self, import: &ReceiptImport) {
+        if let Err(e) = self.import_repository.update(import).await {
+            self.logger.error(&format!(
+                "Failed to save receipt import {}: {}",
+                import.id, e
+            ));
+        }
+    }
+}
+
+fn skipped(name: String, reason: SkipReason) -> SkippedLine {
+    SkippedLine {
+        name,
+        reason,
+        existing_product_id: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::services::{
+        Confidence, ExpiryEstimation, IdentificationConfidence, ReceiptItem, ReceiptScanResult,
+    };
+    use crate::domain::quota::model::Usage;
+    use crate::domain::receipt_import::model::ReceiptImportStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use async_trait::async_trait;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    mock! {
+        pub ImportRepo {}
+
+        #[async_trait]
+        impl ReceiptImportRepository for ImportRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ReceiptImport, RepositoryError>;
+            async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+            async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ReceiptScanner {}
+
+        #[async_trait]
+        impl ReceiptScannerService for ReceiptScanner {
+            async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+            fn can_scan_without_ai(&self) -> bool;
+            async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+        }
+    }
+
+    mock! {
+        pub ExpiryEstimator {}
+
+        #[async_trait]
+        impl ExpiryEstimatorService for ExpiryEstimator {
+            async fn estimate_expiry_date(
+                &self,
+                product_name: &str,
+                status: &str,
+                location: Option<String>,
+            ) -> ExpiryEstimation;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn ai_write_mode(mode: AiWriteMode) -> Arc<dyn AiReviewRepository> {
+        let mut repo = MockAiReviewRepo::new();
+        repo.expect_get_mode().returning(move |_| Ok(mode));
+        Arc::new(repo)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn mock_import_repository() -> Arc<dyn ReceiptImportRepository> {
+        let mut repo = MockImportRepo::new();
+        repo.expect_update().returning(|_| Ok(()));
+        Arc::new(repo)
+    }
+
+    fn scanner_returning(names: &'static [&'static str]) -> Arc<dyn ReceiptScannerService> {
+        let mut scanner = MockReceiptScanner::new();
+        scanner.expect_scan().returning(move |_| {
+            Ok(ReceiptScanResult {
+                items: names
+                    .iter()
+                    .map(|name| ReceiptItem {
+                        name: name.to_string(),
+                        confidence: IdentificationConfidence::High,
+                    })
+                    .collect(),
+                store: None,
+            })
+        });
+        Arc::new(scanner)
+    }
+
+    fn estimator_in_days(days: i64) -> Arc<dyn ExpiryEstimatorService> {
+        let mut estimator = MockExpiryEstimator::new();
+        estimator
+            .expect_estimate_expiry_date()
+            .returning(move |_, _, _| ExpiryEstimation {
+                date: Some(Utc::now() + Duration::days(days)),
+                confidence: Confidence::Medium,
+                unavailable: false,
+            });
+        Arc::new(estimator)
+    }
+
+    fn existing_product(name: &str) -> Product {
+        Product::new(NewProductProps {
+            user_id: test_user_id(),
+            name: name.to_string(),
+            status: ProductStatus::Opened,
+            location: None,
+            quantity: None,
+            expiry_date: None,
+            estimated_expiry_date: None,
+            expiry_type: ExpiryType::None,
+            outcome: None,
+            category: None,
+            tags: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_create_new_products_and_skip_duplicates() {
+        let pantry_milk = existing_product("Leche entera");
+        let pantry_milk_id = pantry_milk.id;
+
+        let saved = Arc::new(Mutex::new(Vec::new()));
+        let saved_in_mock = saved.clone();
+        let mut product_repo = MockProductRepo::new();
+        product_repo
+            .expect_find()
+            .returning(move |_| Ok(vec![pantry_milk.clone()]));
+        product_repo.expect_insert().returning(move |p| {
+            saved_in_mock.lock().unwrap().push(p.name.clone());
+            Ok(())
+        });
+
+        let mut quota = MockQuota::new();
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+
+        let pipeline = ReceiptImportPipeline {
+            import_repository: mock_import_repository(),
+            product_repository: Arc::new(product_repo),
+            scanner: scanner_returning(&[
+                "LECHE ENTERA",
+                "2x Tomate rama",
+                "TOMATE RAMA",
+                "0,99",
+                "Yogur griego",
+            ]),
+            estimator: estimator_in_days(5),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(quota),
+            flags: FeatureFlags::default(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            logger: mock_logger(),
+        };
+
+        let import = pipeline
+            .run(ReceiptImport::new(test_user_id()), "aGVsbG8=".to_string())
+            .await;
+
+        assert_eq!(import.status, ReceiptImportStatus::Completed);
+        let review = import.review.unwrap();
+        let created: Vec<_> = review.created.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(created, vec!["Tomate rama", "Yogur griego"]);
+        assert!(
+            review
+                .created
+                .iter()
+                .all(|p| p.estimated_expiry_date.is_some())
+        );
+        assert_eq!(*saved.lock().unwrap(), vec!["Tomate rama", "Yogur griego"]);
+
+        let reasons: Vec<_> = review.skipped.iter().map(|s| s.reason.clone()).collect();
+        assert_eq!(
+            reasons,
+            vec![
+                SkipReason::AlreadyInPantry,
+                SkipReason::DuplicateOnReceipt,
+                SkipReason::InvalidName,
+            ]
+        );
+        assert_eq!(review.skipped[0].existing_product_id, Some(pantry_milk_id));
+    }
+
+    #[tokio::test]
+    async fn should_stage_estimations_in_review_mode() {
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(|_| Ok(vec![]));
+        product_repo
+            .expect_insert()
+            .withf(|p| p.estimated_expiry_date.is_none())
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let mut quota = MockQuota::new();
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+
+        let mut review_repo = MockAiReviewRepo::new();
+        review_repo
+            .expect_get_mode()
+            .returning(|_| Ok(AiWriteMode::Review));
+        review_repo.expect_stage().times(2).returning(|_| Ok(()));
+
+        let pipeline = ReceiptImportPipeline {
+            import_repository: mock_import_repository(),
+            product_repository: Arc::new(product_repo),
+            scanner: scanner_returning(&["Arroz", "Lentejas"]),
+            estimator: estimator_in_days(365),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(quota),
+            flags: FeatureFlags::default(),
+            ai_review_repository: Arc::new(review_repo),
+            logger: mock_logger(),
+        };
+
+        let import = pipeline
+            .run(ReceiptImport::new(test_user_id()), "aGVsbG8=".to_string())
+            .await;
+
+        let review = import.review.unwrap();
+        assert_eq!(review.created.len(), 2);
+        assert!(
+            review
+                .created
+                .iter()
+                .all(|p| p.estimated_expiry_date.is_none())
+        );
+    }
+
+    #[tokio::test]
+    async fn should_skip_remaining_lines_when_plan_limit_reached() {
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(|_| Ok(vec![]));
+        product_repo.expect_insert().times(1).returning(|_| Ok(()));
+
+        let mut quota = MockQuota::new();
+        let mut calls = 0;
+        quota.expect_ensure_product_capacity().returning(move |_| {
+            calls += 1;
+            if calls == 1 {
+                Ok(())
+            } else {
+                Err(QuotaError::ProductLimitReached)
+            }
+        });
+
+        let pipeline = ReceiptImportPipeline {
+            import_repository: mock_import_repository(),
+            product_repository: Arc::new(product_repo),
+            scanner: scanner_returning(&["Arroz", "Lentejas", "Garbanzos"]),
+            estimator: estimator_in_days(365),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(quota),
+            flags: FeatureFlags::default(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            logger: mock_logger(),
+        };
+
+        let import = pipeline
+            .run(ReceiptImport::new(test_user_id()), "aGVsbG8=".to_string())
+            .await;
+
+        let review = import.review.unwrap();
+        assert_eq!(review.created.len(), 1);
+        assert_eq!(review.skipped.len(), 2);
+        assert!(
+            review
+                .skipped
+                .iter()
+                .all(|s| s.reason == SkipReason::PlanLimitReached)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_fail_import_when_scan_fails() {
+        let mut scanner = MockReceiptScanner::new();
+        scanner
+            .expect_scan()
+            .returning(|_| Err(ProductError::scan_failed("model unavailable")));
+
+        let pipeline = ReceiptImportPipeline {
+            import_repository: mock_import_repository(),
+            product_repository: Arc::new(MockProductRepo::new()),
+            scanner: Arc::new(scanner),
+            estimator: Arc::new(MockExpiryEstimator::new()),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(MockQuota::new()),
+            flags: FeatureFlags::default(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            logger: mock_logger(),
+        };
+
+        let import = pipeline
+            .run(ReceiptImport::new(test_user_id()), "aGVsbG8=".to_string())
+            .await;
+
+        assert_eq!(import.status, ReceiptImportStatus::Failed);
+        assert_eq!(import.error.as_deref(), Some("product.scan_failed"));
+        assert!(import.review.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_import_with_category_defaults_when_run_without_ai() {
+        let mut scanner = MockReceiptScanner::new();
+        scanner.expect_scan().never();
+        scanner.expect_scan_without_ai().returning(|_| {
+            Ok(ReceiptScanResult {
+                items: vec![ReceiptItem {
+                    name: "ARROZ REDONDO".to_string(),
+                    confidence: IdentificationConfidence::Low,
+                }],
+                store: None,
+            })
+        });
+
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(|_| Ok(vec![]));
+        product_repo
+            .expect_insert()
+            .withf(|p| p.estimated_expiry_date.is_some())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut estimator = MockExpiryEstimator::new();
+        estimator.expect_estimate_expiry_date().never();
+
+        let mut quota = MockQuota::new();
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+
+        let pipeline = ReceiptImportPipeline {
+            import_repository: mock_import_repository(),
+            product_repository: Arc::new(product_repo),
+            scanner: Arc::new(scanner),
+            estimator: Arc::new(estimator),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(quota),
+            flags: FeatureFlags::default(),
+            ai_review_repository: ai_write_mode(AiWriteMode::Auto),
+            logger: mock_logger(),
+        };
+
+        let import = pipeline
+            .run_without_ai(ReceiptImport::new(test_user_id()), "aGVsbG8=".to_string())
+            .await;
+
+        assert_eq!(import.status, ReceiptImportStatus::Completed);
+        assert_eq!(import.review.unwrap().created.len(), 1);
+    }
+}
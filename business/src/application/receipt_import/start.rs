@@ -0,0 +1,457 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::receipt_import::pipeline::ReceiptImportPipeline;
+use crate::application::storage::thumbnail::ThumbnailJob;
+use crate::domain::logger::Logger;
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::receipt_import::model::ReceiptImport;
+use crate::domain::receipt_import::repository::ReceiptImportRepository;
+use crate::domain::receipt_import::use_cases::start::{
+    StartReceiptImportParams, StartReceiptImportUseCase,
+};
+use crate::domain::storage::model::{ImageUpload, receipt_image_key};
+use crate::domain::storage::services::BlobStorage;
+
+pub struct StartReceiptImportUseCaseImpl {
+    pub repository: Arc<dyn ReceiptImportRepository>,
+    pub quota_service: Arc<dyn QuotaService>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub thumbnails: Arc<ThumbnailJob>,
+    pub pipeline: Arc<ReceiptImportPipeline>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl StartReceiptImportUseCaseImpl {
+    /// Keeps the receipt photo so the user can look at it later. Returns its
+    /// key and bytes, or `None` when it is not an image or could not be
+    /// stored; the import goes ahead either way.
+    async fn keep_image(
+        &self,
+        import: &ReceiptImport,
+        image_base64: &str,
+    ) -> Option<(String, Vec<u8>)> {
+        let image = ImageUpload::from_base64(image_base64)?;
+        let key = receipt_image_key(&import.user_id, import.id);
+        match self
+            .storage
+            .put(&key, image.bytes.clone(), image.content_type)
+            .await
+        {
+            Ok(()) => Some((key, image.bytes)),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Could not store photo of receipt import {}: {}",
+                    import.id, e
+                ));
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl StartReceiptImportUseCase for StartReceiptImportUseCaseImpl {
+    async fn execute(
+        &self,
+        params: StartReceiptImportParams,
+    ) -> Result<ReceiptImport, ReceiptImportError> {
+        self.logger.info("Starting receipt import");
+
+        // Charge the scan up front so an exhausted quota is reported right away,
+        // unless the scanner can still read the receipt without AI
+        let ai = match self.quota_service.consume_ai_call(&params.user_id).await {
+            Ok(()) => true,
+            Err(QuotaError::AiCallsExceeded) if self.pipeline.scanner.can_scan_without_ai() => {
+                self.logger.info(&format!(
+                    "AI quota exhausted for user {}, importing receipt with OCR",
+                    params.user_id
+                ));
+                false
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut import = ReceiptImport::new(params.user_id);
+        let kept = self.keep_image(&import, &params.image_base64).await;
+        import.image_key = kept.as_ref().map(|(key, _)| key.clone());
+        self.repository.insert(&import).await?;
+
+        let thumbnails = self.thumbnails.clone();
+        let pipeline = self.pipeline.clone();
+        let mut pending = import.clone();
+        tokio::spawn(async move {
+            // Thumbnails take a fraction of the scan, and the pipeline saves
+            // the key along with the import's first status change
+            if let Some((key, image)) = kept {
+                pending.thumbnail_key = thumbnails.run(&key, image).await;
+            }
+            if ai {
+                pipeline.run(pending, params.image_base64).await;
+            } else {
+                pipeline.run_without_ai(pending, params.image_base64).await;
+            }
+        });
+
+        self.logger
+            .info(&format!("Receipt import queued with id: {}", import.id));
+        Ok(import)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ai_review::model::{AiWriteMode, PendingAiChange};
+    use crate::domain::ai_review::repository::AiReviewRepository;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::feature_flag::model::FeatureFlags;
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::expiry_snap::ExpirySnap;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::product::repository::ProductRepository;
+    use crate::domain::product::services::{
+        ExpiryEstimation, ExpiryEstimatorService, ReceiptScanResult, ReceiptScannerService,
+    };
+    use crate::domain::quota::model::Usage;
+    use crate::domain::receipt_import::model::ReceiptImportStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
+    use crate::domain::storage::services::ThumbnailService;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ImportRepo {}
+
+        #[async_trait]
+        impl ReceiptImportRepository for ImportRepo {
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ReceiptImport, RepositoryError>;
+            async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+            async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ReceiptScanner {}
+
+        #[async_trait]
+        impl ReceiptScannerService for ReceiptScanner {
+            async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+            fn can_scan_without_ai(&self) -> bool;
+            async fn scan_without_ai(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+        }
+    }
+
+    mock! {
+        pub ExpiryEstimator {}
+
+        #[async_trait]
+        impl ExpiryEstimatorService for ExpiryEstimator {
+            async fn estimate_expiry_date(
+                &self,
+                product_name: &str,
+                status: &str,
+                location: Option<String>,
+            ) -> ExpiryEstimation;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub AiReviewRepo {}
+
+        #[async_trait]
+        impl AiReviewRepository for AiReviewRepo {
+            async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+            async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+            async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+            async fn get_pending_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+            async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+            async fn delete_pending(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Thumbnailer {}
+
+        impl ThumbnailService for Thumbnailer {
+            fn thumbnail(&self, image: &[u8]) -> Result<Vec<u8>, StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn failing_thumbnails() -> Arc<ThumbnailJob> {
+        let mut thumbnailer = MockThumbnailer::new();
+        thumbnailer
+            .expect_thumbnail()
+            .returning(|_| Err(StorageError::UnreadableImage));
+        Arc::new(ThumbnailJob {
+            storage: Arc::new(MockStorage::new()),
+            thumbnailer: Arc::new(thumbnailer),
+            logger: mock_logger(),
+        })
+    }
+
+    fn failing_pipeline(ocr: bool) -> Arc<ReceiptImportPipeline> {
+        let mut import_repo = MockImportRepo::new();
+        import_repo.expect_update().returning(|_| Ok(()));
+        let mut scanner = MockReceiptScanner::new();
+        scanner
+            .expect_scan()
+            .returning(|_| Err(ProductError::scan_failed("model unavailable")));
+        scanner.expect_can_scan_without_ai().return_const(ocr);
+        scanner
+            .expect_scan_without_ai()
+            .returning(|_| Err(ProductError::scan_failed("unreadable")));
+
+        Arc::new(ReceiptImportPipeline {
+            import_repository: Arc::new(import_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            scanner: Arc::new(scanner),
+            estimator: Arc::new(MockExpiryEstimator::new()),
+            expiry_snap: ExpirySnap::default(),
+            quota_service: Arc::new(MockQuota::new()),
+            flags: FeatureFlags::default(),
+            ai_review_repository: Arc::new(MockAiReviewRepo::new()),
+            logger: mock_logger(),
+        })
+    }
+
+    #[tokio::test]
+    async fn should_return_pending_import_when_quota_available() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_consume_ai_call().returning(|_| Ok(()));
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo
+            .expect_insert()
+            .withf(|import| import.status == ReceiptImportStatus::Pending)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = StartReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            quota_service: Arc::new(mock_quota),
+            storage: Arc::new(MockStorage::new()),
+            thumbnails: failing_thumbnails(),
+            pipeline: failing_pipeline(false),
+            logger: mock_logger(),
+        };
+
+        let import = use_case
+            .execute(StartReceiptImportParams {
+                user_id: test_user_id(),
+                image_base64: "aGVsbG8=".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(import.status, ReceiptImportStatus::Pending);
+        assert_eq!(import.user_id, test_user_id());
+    }
+
+    #[tokio::test]
+    async fn should_not_queue_import_when_ai_quota_exceeded() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo.expect_insert().never();
+
+        let use_case = StartReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            quota_service: Arc::new(mock_quota),
+            storage: Arc::new(MockStorage::new()),
+            thumbnails: failing_thumbnails(),
+            pipeline: failing_pipeline(false),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(StartReceiptImportParams {
+                user_id: test_user_id(),
+                image_base64: "aGVsbG8=".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ReceiptImportError::Quota(QuotaError::AiCallsExceeded))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_queue_import_when_ai_quota_exceeded_and_ocr_available() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+
+        let use_case = StartReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            quota_service: Arc::new(mock_quota),
+            storage: Arc::new(MockStorage::new()),
+            thumbnails: failing_thumbnails(),
+            pipeline: failing_pipeline(true),
+            logger: mock_logger(),
+        };
+
+        let import = use_case
+            .execute(StartReceiptImportParams {
+                user_id: test_user_id(),
+                image_base64: "aGVsbG8=".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(import.status, ReceiptImportStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn should_keep_receipt_photo_when_it_is_an_image() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_consume_ai_call().returning(|_| Ok(()));
+        let mut mock_storage = MockStorage::new();
+        mock_storage
+            .expect_put()
+            .withf(|key, _, content_type| {
+                key.starts_with("receipts/test-user-id/") && content_type == "image/jpeg"
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo
+            .expect_insert()
+            .withf(|import| {
+                import.image_key.as_deref()
+                    == Some(format!("receipts/test-user-id/{}", import.id).as_str())
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = StartReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            quota_service: Arc::new(mock_quota),
+            storage: Arc::new(mock_storage),
+            thumbnails: failing_thumbnails(),
+            pipeline: failing_pipeline(false),
+            logger: mock_logger(),
+        };
+
+        let import = use_case
+            .execute(StartReceiptImportParams {
+                user_id: test_user_id(),
+                image_base64: "data:image/jpeg;base64,/9j/4AAQ".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert!(import.image_key.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_queue_import_without_photo_when_storage_fails() {
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_consume_ai_call().returning(|_| Ok(()));
+        let mut mock_storage = MockStorage::new();
+        mock_storage
+            .expect_put()
+            .returning(|_, _, _| Err(StorageError::unavailable("disk full")));
+        let mut mock_repo = MockImportRepo::new();
+        mock_repo
+            .expect_insert()
+            .withf(|import| import.image_key.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = StartReceiptImportUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            quota_service: Arc::new(mock_quota),
+            storage: Arc::new(mock_storage),
+            thumbnails: failing_thumbnails(),
+            pipeline: failing_pipeline(false),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(StartReceiptImportParams {
+                user_id: test_user_id(),
+                image_base64: "/9j/4AAQ".to_string(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::reminder::repository::ReminderRepository;
+use crate::domain::reminder::use_cases::create::{CreateReminderParams, CreateReminderUseCase};
+
+pub struct CreateReminderUseCaseImpl {
+    pub repository: Arc<dyn ReminderRepository>,
+    /// Checks the product exists and belongs to the user.
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl CreateReminderUseCase for CreateReminderUseCaseImpl {
+    async fn execute(&self, params: CreateReminderParams) -> Result<Reminder, ReminderError> {
+        self.logger.info(&format!(
+            "Creating reminder for product: {}",
+            params.product_id
+        ));
+
+        if !self
+            .product_repository
+            .exists(params.product_id, &params.user_id)
+            .await?
+        {
+            return Err(ReminderError::ProductNotFound);
+        }
+
+        let reminder = Reminder::new(
+            params.user_id,
+            params.product_id,
+            params.note,
+            params.remind_at,
+        )?;
+        self.repository.insert(&reminder).await?;
+
+        self.logger
+            .info(&format!("Reminder created: {}", reminder.id));
+        Ok(reminder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ReminderRepo {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepo {
+            async fn get_by_product(&self, product_id: Uuid, user_id: &UserId) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<Reminder, RepositoryError>;
+            async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn existing_product() -> Arc<dyn ProductRepository> {
+        let mut repo = MockProductRepo::new();
+        repo.expect_exists().returning(|_, _| Ok(true));
+        Arc::new(repo)
+    }
+
+    #[tokio::test]
+    async fn should_create_reminder_for_own_product() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo
+            .expect_insert()
+            .withf(move |reminder| reminder.product_id == product_id)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateReminderUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: existing_product(),
+            logger: mock_logger(),
+        };
+
+        let reminder = use_case
+            .execute(CreateReminderParams {
+                user_id: test_user_id(),
+                product_id,
+                note: "Check the sourdough starter".to_string(),
+                remind_at: Utc::now() + Duration::days(3),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(reminder.note, "Check the sourdough starter");
+    }
+
+    #[tokio::test]
+    async fn should_return_product_not_found_when_product_missing() {
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo.expect_insert().never();
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_exists().returning(|_, _| Ok(false));
+
+        let use_case = CreateReminderUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateReminderParams {
+                user_id: test_user_id(),
+                product_id: Uuid::new_v4(),
+                note: "Check the sourdough starter".to_string(),
+                remind_at: Utc::now() + Duration::days(3),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ReminderError::ProductNotFound)));
+    }
+}
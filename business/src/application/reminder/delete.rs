@@ -0,0 +1,111 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::repository::ReminderRepository;
+use crate::domain::reminder::use_cases::delete::{DeleteReminderParams, DeleteReminderUseCase};
+
+pub struct DeleteReminderUseCaseImpl {
+    pub repository: Arc<dyn ReminderRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl DeleteReminderUseCase for DeleteReminderUseCaseImpl {
+    async fn execute(&self, params: DeleteReminderParams) -> Result<(), ReminderError> {
+        self.logger
+            .info(&format!("Deleting reminder: {}", params.id));
+
+        self.repository
+            .delete(params.id, params.product_id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReminderError::NotFound,
+                other => ReminderError::Repository(other),
+            })?;
+
+        self.logger
+            .info(&format!("Reminder deleted: {}", params.id));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::reminder::model::Reminder;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+    use mockall::predicate::eq;
+    use uuid::Uuid;
+
+    mock! {
+        pub ReminderRepo {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepo {
+            async fn get_by_product(&self, product_id: Uuid, user_id: &UserId) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<Reminder, RepositoryError>;
+            async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_delete_reminder_scoped_to_product_and_user() {
+        let id = Uuid::new_v4();
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo
+            .expect_delete()
+            .with(eq(id), eq(product_id), eq(test_user_id()))
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let use_case = DeleteReminderUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(DeleteReminderParams {
+                id,
+                product_id,
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
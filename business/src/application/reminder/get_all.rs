@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::reminder::repository::ReminderRepository;
+use crate::domain::reminder::use_cases::get_all::{GetRemindersParams, GetRemindersUseCase};
+
+pub struct GetRemindersUseCaseImpl {
+    pub repository: Arc<dyn ReminderRepository>,
+    /// Tells an unknown product apart from one without reminders.
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetRemindersUseCase for GetRemindersUseCaseImpl {
+    async fn execute(&self, params: GetRemindersParams) -> Result<Vec<Reminder>, ReminderError> {
+        self.logger.info(&format!(
+            "Getting reminders for product: {}",
+            params.product_id
+        ));
+
+        if !self
+            .product_repository
+            .exists(params.product_id, &params.user_id)
+            .await?
+        {
+            return Err(ReminderError::ProductNotFound);
+        }
+
+        Ok(self
+            .repository
+            .get_by_product(params.product_id, &params.user_id)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ReminderRepo {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepo {
+            async fn get_by_product(&self, product_id: Uuid, user_id: &UserId) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<Reminder, RepositoryError>;
+            async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_product_not_found_instead_of_empty_list() {
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo.expect_get_by_product().never();
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_exists().returning(|_, _| Ok(false));
+
+        let use_case = GetRemindersUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetRemindersParams {
+                user_id: test_user_id(),
+                product_id: Uuid::new_v4(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ReminderError::ProductNotFound)));
+    }
+}
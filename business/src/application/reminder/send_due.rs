@@ -0,0 +1,283 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::application::notification::dispatcher::NotificationDispatcher;
+use crate::domain::logger::Logger;
+use crate::domain::notification::model::{Notification, NotificationCategory};
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::reminder::repository::ReminderRepository;
+use crate::domain::reminder::use_cases::send_due::{
+    SendDueRemindersParams, SendDueRemindersUseCase,
+};
+
+pub struct SendDueRemindersUseCaseImpl {
+    pub repository: Arc<dyn ReminderRepository>,
+    /// Reminders go through the dispatcher, so quiet hours and the shopping
+    /// reminders toggle apply to them
+    pub dispatcher: Arc<NotificationDispatcher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl SendDueRemindersUseCase for SendDueRemindersUseCaseImpl {
+    async fn execute(&self, params: SendDueRemindersParams) -> Result<u32, ReminderError> {
+        let now = Utc::now();
+        let due = self.repository.find_due(now, params.max_reminders).await?;
+
+        let mut sent = 0;
+        for reminder in due {
+            // Left pending when the dispatch fails, so the next run retries it
+            if let Err(e) = self
+                .dispatcher
+                .dispatch(reminder_notification(&reminder))
+                .await
+            {
+                self.logger.warn(&format!(
+                    "Failed to send reminder {} to user {}: {}",
+                    reminder.id, reminder.user_id, e
+                ));
+                continue;
+            }
+            match self.repository.mark_sent(reminder.id, now).await {
+                Ok(()) => sent += 1,
+                Err(e) => self.logger.warn(&format!(
+                    "Sent reminder {} but could not mark it sent: {}",
+                    reminder.id, e
+                )),
+            }
+        }
+
+        if sent > 0 {
+            self.logger.info(&format!("Sent {} due reminders", sent));
+        }
+        Ok(sent)
+    }
+}
+
+fn reminder_notification(reminder: &Reminder) -> Notification {
+    Notification {
+        id: Uuid::new_v4(),
+        user_id: reminder.user_id.clone(),
+        category: NotificationCategory::ShoppingReminders,
+        title: "Reminder".to_string(),
+        body: reminder.note.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::device::model::{Device, InstallCount};
+    use crate::domain::device::repository::DeviceRepository;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::notification::errors::NotificationError;
+    use crate::domain::notification::model::{Acknowledgement, NotificationPreferences};
+    use crate::domain::notification::repository::{
+        NotificationInboxRepository, NotificationPreferenceRepository,
+    };
+    use crate::domain::notification::services::NotificationSender;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::vacation::model::Vacation;
+    use crate::domain::vacation::repository::VacationRepository;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+
+    mock! {
+        pub ReminderRepo {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepo {
+            async fn get_by_product(&self, product_id: Uuid, user_id: &UserId) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<Reminder, RepositoryError>;
+            async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub NotificationPreferenceRepo {}
+
+        #[async_trait]
+        impl NotificationPreferenceRepository for NotificationPreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &NotificationPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub DeviceRepo {}
+
+        #[async_trait]
+        impl DeviceRepository for DeviceRepo {
+            async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+            async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+            async fn count_seen_since(&self, since: DateTime<Utc>) -> Result<Vec<InstallCount>, RepositoryError>;
+            async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub InboxRepo {}
+
+        #[async_trait]
+        impl NotificationInboxRepository for InboxRepo {
+            async fn record(&self, notification: &Notification, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+            async fn acknowledge(&self, user_id: &UserId, acknowledgement: &Acknowledgement, read_at: DateTime<Utc>) -> Result<u64, RepositoryError>;
+            async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Sender {}
+
+        #[async_trait]
+        impl NotificationSender for Sender {
+            async fn send(&self, notification: &Notification, devices: &[Device]) -> Result<(), NotificationError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Dispatcher with no quiet hours, so reminders go out right away.
+    fn dispatcher(sender: MockSender) -> Arc<NotificationDispatcher> {
+        let mut preferences = MockNotificationPreferenceRepo::new();
+        preferences.expect_get().returning(|_| {
+            Ok(NotificationPreferences {
+                quiet_hours: None,
+                ..NotificationPreferences::default()
+            })
+        });
+        let mut vacations = MockVacationRepo::new();
+        vacations.expect_find_current().returning(|_| Ok(None));
+        let mut devices = MockDeviceRepo::new();
+        devices.expect_find_pushable().returning(|_| Ok(vec![]));
+        let mut inbox = MockInboxRepo::new();
+        inbox.expect_record().returning(|_, _| Ok(()));
+
+        Arc::new(NotificationDispatcher {
+            preference_repository: Arc::new(preferences),
+            vacation_repository: Arc::new(vacations),
+            device_repository: Arc::new(devices),
+            inbox_repository: Arc::new(inbox),
+            sender: Arc::new(sender),
+            logger: mock_logger(),
+        })
+    }
+
+    fn due_reminder() -> Reminder {
+        let now = Utc::now();
+        Reminder::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            Uuid::new_v4(),
+            "Check the sourdough starter".to_string(),
+            now - Duration::minutes(5),
+            None,
+            now - Duration::days(2),
+            now - Duration::days(2),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_send_due_reminder_once_and_mark_it_sent() {
+        let reminder = due_reminder();
+        let reminder_id = reminder.id;
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo
+            .expect_find_due()
+            .withf(|now, limit| *now <= Utc::now() && *limit == 100)
+            .times(1)
+            .returning(move |_, _| Ok(vec![reminder.clone()]));
+        mock_repo
+            .expect_mark_sent()
+            .withf(move |id, _| *id == reminder_id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .withf(|notification, _| {
+                notification.category == NotificationCategory::ShoppingReminders
+                    && notification.body == "Check the sourdough starter"
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = SendDueRemindersUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            dispatcher: dispatcher(sender),
+            logger: mock_logger(),
+        };
+
+        let sent = use_case
+            .execute(SendDueRemindersParams { max_reminders: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(sent, 1);
+    }
+
+    #[tokio::test]
+    async fn should_leave_reminder_pending_when_sending_fails() {
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo
+            .expect_find_due()
+            .returning(|_, _| Ok(vec![due_reminder()]));
+        mock_repo.expect_mark_sent().never();
+        let mut sender = MockSender::new();
+        sender
+            .expect_send()
+            .returning(|_, _| Err(NotificationError::delivery_failed("push service down")));
+
+        let use_case = SendDueRemindersUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            dispatcher: dispatcher(sender),
+            logger: mock_logger(),
+        };
+
+        let sent = use_case
+            .execute(SendDueRemindersParams { max_reminders: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(sent, 0);
+    }
+}
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::reminder::repository::ReminderRepository;
+use crate::domain::reminder::use_cases::update::{UpdateReminderParams, UpdateReminderUseCase};
+
+pub struct UpdateReminderUseCaseImpl {
+    pub repository: Arc<dyn ReminderRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl UpdateReminderUseCase for UpdateReminderUseCaseImpl {
+    async fn execute(&self, params: UpdateReminderParams) -> Result<Reminder, ReminderError> {
+        self.logger
+            .info(&format!("Updating reminder: {}", params.id));
+
+        let mut reminder = self
+            .repository
+            .get_by_id(params.id, params.product_id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReminderError::NotFound,
+                other => ReminderError::Repository(other),
+            })?;
+
+        reminder.update(params.note, params.remind_at)?;
+        self.repository
+            .update(&reminder)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ReminderError::NotFound,
+                other => ReminderError::Repository(other),
+            })?;
+
+        self.logger
+            .info(&format!("Reminder updated: {}", reminder.id));
+        Ok(reminder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ReminderRepo {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepo {
+            async fn get_by_product(&self, product_id: Uuid, user_id: &UserId) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<Reminder, RepositoryError>;
+            async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Reminder>, RepositoryError>;
+            async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_reminder_belongs_to_other_product() {
+        let mut mock_repo = MockReminderRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _, _| Err(RepositoryError::NotFound));
+        mock_repo.expect_update().never();
+
+        let use_case = UpdateReminderUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateReminderParams {
+                id: Uuid::new_v4(),
+                product_id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                note: None,
+                remind_at: Some(Utc::now() + Duration::days(1)),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ReminderError::NotFound)));
+    }
+}
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::fixtures::sample_pantry;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::sandbox::errors::SandboxError;
+use crate::domain::sandbox::repository::SandboxRepository;
+use crate::domain::sandbox::use_cases::reset::{
+    DEMO_PANTRY_SEED, DEMO_PANTRY_SIZE, ResetSandboxParams, ResetSandboxUseCase,
+};
+
+/// Restores the demo user's pantry. Like seeding, products go straight to
+/// the repository: no quota checks, no AI estimation and no events.
+pub struct ResetSandboxUseCaseImpl {
+    pub sandbox_repository: Arc<dyn SandboxRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ResetSandboxUseCase for ResetSandboxUseCaseImpl {
+    async fn execute(&self, params: ResetSandboxParams) -> Result<usize, SandboxError> {
+        self.logger
+            .info(&format!("Resetting sandbox user {}", params.user_id));
+
+        self.sandbox_repository.wipe(&params.user_id).await?;
+
+        let products = sample_pantry(
+            &params.user_id,
+            DEMO_PANTRY_SIZE,
+            DEMO_PANTRY_SEED,
+            Utc::now(),
+        );
+        for product in &products {
+            self.product_repository.insert(product).await?;
+        }
+
+        self.logger
+            .info(&format!("Sandbox reset with {} products", products.len()));
+        Ok(products.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+
+    mock! {
+        pub SandboxRepo {}
+
+        #[async_trait]
+        impl SandboxRepository for SandboxRepo {
+            async fn wipe(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: uuid::Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: uuid::Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: uuid::Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn demo_user_id() -> UserId {
+        UserId::new("demo-user")
+    }
+
+    #[tokio::test]
+    async fn should_wipe_then_restore_curated_pantry() {
+        let mut sandbox_repo = MockSandboxRepo::new();
+        sandbox_repo
+            .expect_wipe()
+            .withf(|user_id| *user_id == demo_user_id())
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut product_repo = MockProductRepo::new();
+        product_repo
+            .expect_insert()
+            .withf(|product| product.user_id == demo_user_id())
+            .times(DEMO_PANTRY_SIZE)
+            .returning(|_| Ok(()));
+        let use_case = ResetSandboxUseCaseImpl {
+            sandbox_repository: Arc::new(sandbox_repo),
+            product_repository: Arc::new(product_repo),
+            logger: mock_logger(),
+        };
+
+        let restored = use_case
+            .execute(ResetSandboxParams {
+                user_id: demo_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(restored, DEMO_PANTRY_SIZE);
+    }
+
+    #[tokio::test]
+    async fn should_not_restore_pantry_when_wipe_fails() {
+        let mut sandbox_repo = MockSandboxRepo::new();
+        sandbox_repo
+            .expect_wipe()
+            .returning(|_| Err(RepositoryError::Persistence));
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_insert().never();
+        let use_case = ResetSandboxUseCaseImpl {
+            sandbox_repository: Arc::new(sandbox_repo),
+            product_repository: Arc::new(product_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ResetSandboxParams {
+                user_id: demo_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(SandboxError::Repository(_))));
+    }
+}
@@ -0,0 +1,249 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::query::{Page, ProductQuery};
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::search::errors::SearchError;
+use crate::domain::search::model::{SearchHit, SearchResult, SearchTerm, rank};
+use crate::domain::search::use_cases::global::{GlobalSearchParams, GlobalSearchUseCase};
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::suggestion::repository::SuggestionRepository;
+
+pub struct GlobalSearchUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GlobalSearchUseCase for GlobalSearchUseCaseImpl {
+    async fn execute(&self, params: GlobalSearchParams) -> Result<Vec<SearchHit>, SearchError> {
+        let term = SearchTerm::new(&params.query)?;
+        self.logger.debug(&format!(
+            "Searching for '{}' for user: {}",
+            term.as_str(),
+            params.user_id
+        ));
+
+        // Each source may fill the whole page on its own, so each is asked for all of it
+        let products = self
+            .product_repository
+            .find(
+                &ProductQuery::all(params.user_id.clone())
+                    .name_contains(term.as_str())
+                    .paged(Page::new(params.limit, 0)),
+            )
+            .await?;
+        let shopping_items = self
+            .shopping_item_repository
+            .search(&params.user_id, term.as_str(), params.limit)
+            .await?;
+        let suggestions = self
+            .suggestion_repository
+            .search(&params.user_id, term.as_str(), params.limit)
+            .await?;
+
+        let results = products
+            .into_iter()
+            .map(SearchResult::Product)
+            .chain(shopping_items.into_iter().map(SearchResult::ShoppingItem))
+            .chain(suggestions.into_iter().map(SearchResult::Suggestion))
+            .collect();
+
+        Ok(rank(&term, results, params.limit as usize))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductLocation, ProductStatus};
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
+    use crate::domain::suggestion::model::{Suggestion, SuggestionBatch, TimeRange};
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_product(name: &str) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            ProductStatus::New,
+            Some(ProductLocation::Pantry),
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn make_suggestion(title: &str) -> Suggestion {
+        Suggestion {
+            id: Uuid::new_v4().to_string(),
+            title: title.to_string(),
+            description: None,
+            estimated_time: TimeRange::Quick,
+            ingredients: vec![],
+            urgent_ingredients: vec![],
+            steps: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_merge_every_source_by_relevance() {
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_find()
+            .withf(|query| query.name_contains.as_deref() == Some("arroz"))
+            .times(1)
+            .returning(|_| Ok(vec![make_product("Arroz integral")]));
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items
+            .expect_search()
+            .withf(|_, term, limit| term == "arroz" && *limit == 10)
+            .times(1)
+            .returning(|user_id, _, _| {
+                Ok(vec![
+                    ShoppingItem::new(user_id.clone(), "Arroz".to_string(), None).unwrap(),
+                ])
+            });
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_search()
+            .times(1)
+            .returning(|_, _, _| Ok(vec![make_suggestion("Paella de arroz")]));
+
+        let use_case = GlobalSearchUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            shopping_item_repository: Arc::new(mock_items),
+            suggestion_repository: Arc::new(mock_suggestions),
+            logger: mock_logger(),
+        };
+
+        let hits = use_case
+            .execute(GlobalSearchParams {
+                user_id: test_user_id(),
+                query: " arroz ".to_string(),
+                limit: 10,
+            })
+            .await
+            .unwrap();
+
+        let kinds: Vec<&str> = hits
+            .iter()
+            .map(|hit| match &hit.result {
+                SearchResult::Product(_) => "product",
+                SearchResult::ShoppingItem(_) => "shopping_item",
+                SearchResult::Suggestion(_) => "suggestion",
+            })
+            .collect();
+        assert_eq!(kinds, vec!["shopping_item", "product", "suggestion"]);
+    }
+
+    #[tokio::test]
+    async fn should_reject_short_query_without_searching() {
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().never();
+
+        let use_case = GlobalSearchUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            suggestion_repository: Arc::new(MockSuggestionRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GlobalSearchParams {
+                user_id: test_user_id(),
+                query: "a".to_string(),
+                limit: 10,
+            })
+            .await;
+
+        assert!(matches!(result, Err(SearchError::QueryTooShort)));
+    }
+}
@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::share_link::model::ShareLink;
+use crate::domain::share_link::repository::ShareLinkRepository;
+use crate::domain::share_link::use_cases::create::{
+    CreateShareLinkParams, CreateShareLinkUseCase, CreatedShareLink,
+};
+
+pub struct CreateShareLinkUseCaseImpl {
+    pub repository: Arc<dyn ShareLinkRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl CreateShareLinkUseCase for CreateShareLinkUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CreateShareLinkParams,
+    ) -> Result<CreatedShareLink, ShareLinkError> {
+        self.logger.info("Creating share link");
+
+        let (link, token) = ShareLink::issue(
+            params.user_id,
+            params.include_expiring_products,
+            params.expires_in_hours,
+        )?;
+
+        self.repository.save(&link).await?;
+
+        self.logger.info(&format!(
+            "Share link created: {} (expires at {})",
+            link.id, link.expires_at
+        ));
+        Ok(CreatedShareLink { link, token })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::share_link::model::hash_token;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShareLinkRepo {}
+
+        #[async_trait]
+        impl ShareLinkRepository for ShareLinkRepo {
+            async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<ShareLink>, RepositoryError>;
+            async fn save(&self, link: &ShareLink) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_persist_link_and_return_plain_token() {
+        let mut mock_repo = MockShareLinkRepo::new();
+        mock_repo
+            .expect_save()
+            .withf(|link| link.include_expiring_products)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = CreateShareLinkUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let created = use_case
+            .execute(CreateShareLinkParams {
+                user_id: test_user_id(),
+                include_expiring_products: true,
+                expires_in_hours: 48,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(created.link.token_hash, hash_token(&created.token));
+    }
+
+    #[tokio::test]
+    async fn should_reject_invalid_expiry_without_saving() {
+        let mut mock_repo = MockShareLinkRepo::new();
+        mock_repo.expect_save().never();
+
+        let use_case = CreateShareLinkUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateShareLinkParams {
+                user_id: test_user_id(),
+                include_expiring_products: false,
+                expires_in_hours: 1000,
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ShareLinkError::InvalidExpiry));
+    }
+}
@@ -0,0 +1,332 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::urgency::is_expiring_soon;
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::share_link::model::hash_token;
+use crate::domain::share_link::repository::ShareLinkRepository;
+use crate::domain::share_link::use_cases::get_shared_view::{
+    GetSharedViewParams, GetSharedViewUseCase, SharedView,
+};
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+
+pub struct GetSharedViewUseCaseImpl {
+    pub repository: Arc<dyn ShareLinkRepository>,
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetSharedViewUseCase for GetSharedViewUseCaseImpl {
+    async fn execute(&self, params: GetSharedViewParams) -> Result<SharedView, ShareLinkError> {
+        let link = self
+            .repository
+            .find_by_token_hash(&hash_token(&params.token))
+            .await?
+            .ok_or(ShareLinkError::NotFound)?;
+
+        if link.is_expired(Utc::now()) {
+            return Err(ShareLinkError::Expired);
+        }
+
+        self.logger
+            .info(&format!("Serving shared view for link: {}", link.id));
+
+        let shopping_items = self.shopping_item_repository.get_all(&link.user_id).await?;
+
+        let expiring_products = if link.include_expiring_products {
+            let window = self
+                .preference_repository
+                .get(&link.user_id)
+                .await?
+                .expiring_soon;
+            let products = self
+                .product_repository
+                .find(&ProductQuery::active(link.user_id.clone()))
+                .await?;
+            Some(
+                products
+                    .into_iter()
+                    .filter(|product| is_expiring_soon(product, window))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+
+        Ok(SharedView {
+            shopping_items,
+            expiring_products,
+            expires_at: link.expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::share_link::model::ShareLink;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItem;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use chrono::Duration;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShareLinkRepo {}
+
+        #[async_trait]
+        impl ShareLinkRepository for ShareLinkRepo {
+            async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<ShareLink>, RepositoryError>;
+            async fn save(&self, link: &ShareLink) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn link(include_expiring_products: bool, expires_at: chrono::DateTime<Utc>) -> ShareLink {
+        ShareLink::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            hash_token("token"),
+            include_expiring_products,
+            expires_at,
+            Utc::now(),
+        )
+    }
+
+    fn product_expiring_in(name: &str, days: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            Some(Utc::now() + Duration::days(days)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn shopping_items() -> Vec<ShoppingItem> {
+        vec![ShoppingItem::new(test_user_id(), "Leche".to_string(), None).unwrap()]
+    }
+
+    #[tokio::test]
+    async fn should_return_only_shopping_list_when_products_not_shared() {
+        let mut mock_links = MockShareLinkRepo::new();
+        mock_links
+            .expect_find_by_token_hash()
+            .returning(|_| Ok(Some(link(false, Utc::now() + Duration::hours(1)))));
+
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items
+            .expect_get_all()
+            .returning(|_| Ok(shopping_items()));
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().never();
+
+        let use_case = GetSharedViewUseCaseImpl {
+            repository: Arc::new(mock_links),
+            shopping_item_repository: Arc::new(mock_items),
+            product_repository: Arc::new(mock_products),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let view = use_case
+            .execute(GetSharedViewParams {
+                token: "token".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(view.shopping_items.len(), 1);
+        assert!(view.expiring_products.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_include_only_expiring_products_when_enabled() {
+        let mut mock_links = MockShareLinkRepo::new();
+        mock_links
+            .expect_find_by_token_hash()
+            .returning(|_| Ok(Some(link(true, Utc::now() + Duration::hours(1)))));
+
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items
+            .expect_get_all()
+            .returning(|_| Ok(shopping_items()));
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().returning(|_| {
+            Ok(vec![
+                product_expiring_in("Yogur natural", 1),
+                product_expiring_in("Arroz", 200),
+            ])
+        });
+
+        let use_case = GetSharedViewUseCaseImpl {
+            repository: Arc::new(mock_links),
+            shopping_item_repository: Arc::new(mock_items),
+            product_repository: Arc::new(mock_products),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let view = use_case
+            .execute(GetSharedViewParams {
+                token: "token".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let products = view.expiring_products.unwrap();
+        assert_eq!(products.len(), 1);
+        assert_eq!(products[0].name, "Yogur natural");
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_token_unknown() {
+        let mut mock_links = MockShareLinkRepo::new();
+        mock_links
+            .expect_find_by_token_hash()
+            .returning(|_| Ok(None));
+
+        let use_case = GetSharedViewUseCaseImpl {
+            repository: Arc::new(mock_links),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            product_repository: Arc::new(MockProductRepo::new()),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetSharedViewParams {
+                token: "unknown".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ShareLinkError::NotFound));
+    }
+
+    #[tokio::test]
+    async fn should_return_expired_when_link_expired() {
+        let mut mock_links = MockShareLinkRepo::new();
+        mock_links
+            .expect_find_by_token_hash()
+            .returning(|_| Ok(Some(link(false, Utc::now() - Duration::minutes(1)))));
+
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items.expect_get_all().never();
+
+        let use_case = GetSharedViewUseCaseImpl {
+            repository: Arc::new(mock_links),
+            shopping_item_repository: Arc::new(mock_items),
+            product_repository: Arc::new(MockProductRepo::new()),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetSharedViewParams {
+                token: "token".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ShareLinkError::Expired));
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::share_link::repository::ShareLinkRepository;
+use crate::domain::share_link::use_cases::revoke::{RevokeShareLinkParams, RevokeShareLinkUseCase};
+
+pub struct RevokeShareLinkUseCaseImpl {
+    pub repository: Arc<dyn ShareLinkRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RevokeShareLinkUseCase for RevokeShareLinkUseCaseImpl {
+    async fn execute(&self, params: RevokeShareLinkParams) -> Result<(), ShareLinkError> {
+        self.logger
+            .info(&format!("Revoking share link: {}", params.id));
+
+        self.repository
+            .delete(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ShareLinkError::NotFound,
+                other => ShareLinkError::Repository(other),
+            })?;
+
+        self.logger
+            .info(&format!("Share link revoked: {}", params.id));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::share_link::model::ShareLink;
+    use crate::domain::shared::value_objects::UserId;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShareLinkRepo {}
+
+        #[async_trait]
+        impl ShareLinkRepository for ShareLinkRepo {
+            async fn find_by_token_hash(&self, token_hash: &str) -> Result<Option<ShareLink>, RepositoryError>;
+            async fn save(&self, link: &ShareLink) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_link_belongs_to_another_user() {
+        let mut mock_repo = MockShareLinkRepo::new();
+        mock_repo
+            .expect_delete()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = RevokeShareLinkUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RevokeShareLinkParams {
+                id: Uuid::new_v4(),
+                user_id: UserId::new("test-user-id"),
+            })
+            .await;
+
+        assert!(matches!(result.unwrap_err(), ShareLinkError::NotFound));
+    }
+}
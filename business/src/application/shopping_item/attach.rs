@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::shopping_item::use_cases::attach::{
+    AttachToShoppingItemParams, AttachToShoppingItemUseCase,
+};
+use crate::domain::storage::model::{AttachmentUpload, SignedUrl, shopping_item_attachment_key};
+use crate::domain::storage::services::BlobStorage;
+
+pub struct AttachToShoppingItemUseCaseImpl {
+    pub repository: Arc<dyn ShoppingItemRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl AttachToShoppingItemUseCase for AttachToShoppingItemUseCaseImpl {
+    async fn execute(
+        &self,
+        params: AttachToShoppingItemParams,
+    ) -> Result<SignedUrl, ShoppingItemError> {
+        self.logger
+            .info(&format!("Attaching to shopping item: {}", params.id));
+
+        let attachment = AttachmentUpload::from_base64(&params.attachment_base64)
+            .ok_or(ShoppingItemError::InvalidAttachment)?;
+
+        let mut item = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => ShoppingItemError::NotFound,
+                other => ShoppingItemError::Repository(other),
+            })?;
+
+        let key = shopping_item_attachment_key(&params.user_id, params.id);
+        self.storage
+            .put(&key, attachment.bytes, attachment.content_type)
+            .await?;
+
+        item.attachment_content_type = Some(attachment.content_type.to_string());
+        item.updated_at = Utc::now();
+        self.repository.update(&item).await?;
+
+        Ok(self.storage.signed_url(&key)?)
+    }
+}
+
+/// Deletes the stored attachments of items that were just removed. The items
+/// are gone either way; a leftover attachment is only wasted space.
+pub(crate) async fn delete_attachments(
+    storage: &dyn BlobStorage,
+    logger: &dyn Logger,
+    items: &[ShoppingItem],
+) {
+    for item in items.iter().filter(|item| item.has_attachment()) {
+        let key = shopping_item_attachment_key(&item.user_id, item.id);
+        if let Err(e) = storage.delete(&key).await {
+            logger.warn(&format!(
+                "Could not delete attachment of shopping item {}: {}",
+                item.id, e
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::StoredObject;
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn voice_note() -> String {
+        STANDARD.encode(b"OggS\x00\x02")
+    }
+
+    #[tokio::test]
+    async fn should_store_attachment_and_record_its_type() {
+        let item = ShoppingItem::new(test_user_id(), "Harina".to_string(), None).unwrap();
+        let item_id = item.id;
+        let expected_key = format!("shopping_items/test-user-id/{item_id}");
+
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(item.clone()));
+        mock_repo
+            .expect_update()
+            .withf(|item| item.attachment_content_type.as_deref() == Some("audio/ogg"))
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut storage = MockStorage::new();
+        let put_key = expected_key.clone();
+        storage
+            .expect_put()
+            .withf(move |key, _, content_type| key == put_key && content_type == "audio/ogg")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        storage.expect_signed_url().returning(|key| {
+            Ok(SignedUrl {
+                url: format!("https://cdn.example.com/{key}"),
+                expires_at: Utc::now(),
+            })
+        });
+
+        let use_case = AttachToShoppingItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let url = use_case
+            .execute(AttachToShoppingItemParams {
+                id: item_id,
+                user_id: test_user_id(),
+                attachment_base64: voice_note(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(url.url, format!("https://cdn.example.com/{expected_key}"));
+    }
+
+    #[tokio::test]
+    async fn should_reject_unsupported_attachment_before_storing() {
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+
+        let use_case = AttachToShoppingItemUseCaseImpl {
+            repository: Arc::new(MockShoppingItemRepo::new()),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(AttachToShoppingItemParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                attachment_base64: STANDARD.encode(b"%PDF-1.7"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShoppingItemError::InvalidAttachment)));
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_item_missing() {
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+
+        let use_case = AttachToShoppingItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(AttachToShoppingItemParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                attachment_base64: voice_note(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShoppingItemError::NotFound)));
+    }
+}
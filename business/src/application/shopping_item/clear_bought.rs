@@ -2,15 +2,18 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::application::shopping_item::attach::delete_attachments;
 use crate::domain::logger::Logger;
 use crate::domain::shopping_item::errors::ShoppingItemError;
 use crate::domain::shopping_item::repository::ShoppingItemRepository;
 use crate::domain::shopping_item::use_cases::clear_bought::{
     ClearBoughtItemsParams, ClearBoughtItemsUseCase,
 };
+use crate::domain::storage::services::BlobStorage;
 
 pub struct ClearBoughtItemsUseCaseImpl {
     pub repository: Arc<dyn ShoppingItemRepository>,
+    pub storage: Arc<dyn BlobStorage>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -19,7 +22,15 @@ impl ClearBoughtItemsUseCase for ClearBoughtItemsUseCaseImpl {
     async fn execute(&self, params: ClearBoughtItemsParams) -> Result<u64, ShoppingItemError> {
         self.logger.info("Clearing bought shopping items");
 
+        let bought: Vec<_> = self
+            .repository
+            .get_all(&params.user_id)
+            .await?
+            .into_iter()
+            .filter(|item| item.is_bought)
+            .collect();
         let count = self.repository.delete_bought(&params.user_id).await?;
+        delete_attachments(self.storage.as_ref(), self.logger.as_ref(), &bought).await;
 
         self.logger
             .info(&format!("Cleared {} bought shopping items", count));
@@ -33,6 +44,9 @@ mod tests {
     use crate::domain::errors::RepositoryError;
     use crate::domain::shared::value_objects::UserId;
     use crate::domain::shopping_item::model::ShoppingItem;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
     use mockall::mock;
     use uuid::Uuid;
 
@@ -42,12 +56,30 @@ mod tests {
         #[async_trait]
         impl ShoppingItemRepository for ShoppingItemRepo {
             async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
             async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
         }
     }
 
@@ -78,10 +110,12 @@ mod tests {
     #[tokio::test]
     async fn should_delete_only_bought_items() {
         let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo.expect_get_all().returning(|_| Ok(vec![]));
         mock_repo.expect_delete_bought().returning(|_| Ok(3));
 
         let use_case = ClearBoughtItemsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
             logger: mock_logger(),
         };
 
@@ -98,10 +132,12 @@ mod tests {
     #[tokio::test]
     async fn should_return_zero_when_no_bought_items() {
         let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo.expect_get_all().returning(|_| Ok(vec![]));
         mock_repo.expect_delete_bought().returning(|_| Ok(0));
 
         let use_case = ClearBoughtItemsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
             logger: mock_logger(),
         };
 
@@ -114,4 +150,42 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), 0);
     }
+
+    #[tokio::test]
+    async fn should_delete_attachments_of_bought_items_only() {
+        let attached = |is_bought: bool| {
+            let mut item = ShoppingItem::new(test_user_id(), "Harina".to_string(), None).unwrap();
+            item.is_bought = is_bought;
+            item.attachment_content_type = Some("audio/ogg".to_string());
+            item
+        };
+        let bought = attached(true);
+        let bought_key = format!("shopping_items/test-user-id/{}", bought.id);
+        let items = vec![bought, attached(false)];
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo
+            .expect_get_all()
+            .returning(move |_| Ok(items.clone()));
+        mock_repo.expect_delete_bought().returning(|_| Ok(1));
+        let mut storage = MockStorage::new();
+        storage
+            .expect_delete()
+            .withf(move |key| key == bought_key)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = ClearBoughtItemsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ClearBoughtItemsParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 1);
+    }
 }
@@ -24,27 +24,21 @@ impl CreateShoppingItemUseCase for CreateShoppingItemUseCaseImpl {
         self.logger
             .info(&format!("Creating shopping item: {}", params.name));
 
-        // If product_id is provided, check if it already exists in the list (skip silently)
-        if let Some(product_id) = params.product_id
-            && let Ok(Some(_)) = self
-                .repository
-                .find_by_product_id(product_id, &params.user_id)
-                .await
-        {
-            self.logger.info(&format!(
-                "Shopping item for product {} already exists, skipping",
-                product_id
-            ));
-            let existing = self
-                .repository
-                .find_by_product_id(product_id, &params.user_id)
-                .await?
-                .ok_or(ShoppingItemError::NotFound)?;
-            return Ok(existing);
+        let item = ShoppingItem::new(params.user_id, params.name, params.product_id)?;
+
+        // Product-linked items are unique while unbought; an existing one is returned as-is
+        if item.product_id.is_some() {
+            let stored = self.repository.save_for_product(&item).await?;
+            if stored.id != item.id {
+                self.logger.info(&format!(
+                    "Shopping item for product {:?} already exists, skipping",
+                    stored.product_id
+                ));
+            }
+            return Ok(stored);
         }
 
-        let item = ShoppingItem::new(params.user_id, params.name, params.product_id)?;
-        self.repository.save(&item).await?;
+        self.repository.insert(&item).await?;
 
         self.logger
             .info(&format!("Shopping item created: {}", item.id));
@@ -57,6 +51,7 @@ mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
     use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItemView;
     use mockall::mock;
     use uuid::Uuid;
 
@@ -66,12 +61,16 @@ mod tests {
         #[async_trait]
         impl ShoppingItemRepository for ShoppingItemRepo {
             async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
             async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
         }
     }
 
@@ -102,7 +101,7 @@ mod tests {
     #[tokio::test]
     async fn should_create_shopping_item_when_valid() {
         let mut mock_repo = MockShoppingItemRepo::new();
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_insert().returning(|_| Ok(()));
 
         let use_case = CreateShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
@@ -160,8 +159,9 @@ mod tests {
         let mut mock_repo = MockShoppingItemRepo::new();
         let existing_clone = existing_item.clone();
         mock_repo
-            .expect_find_by_product_id()
-            .returning(move |_, _| Ok(Some(existing_clone.clone())));
+            .expect_save_for_product()
+            .returning(move |_| Ok(existing_clone.clone()));
+        mock_repo.expect_insert().never();
 
         let use_case = CreateShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
@@ -181,10 +181,37 @@ mod tests {
         assert_eq!(item.id, existing_item.id);
     }
 
+    #[tokio::test]
+    async fn should_insert_product_linked_item_when_not_in_list() {
+        let product_id = Uuid::new_v4();
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo
+            .expect_save_for_product()
+            .withf(move |item| item.product_id == Some(product_id))
+            .times(1)
+            .returning(|item| Ok(item.clone()));
+        mock_repo.expect_insert().never();
+
+        let use_case = CreateShoppingItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateShoppingItemParams {
+                user_id: test_user_id(),
+                name: "Huevos".to_string(),
+                product_id: Some(product_id),
+            })
+            .await;
+
+        assert_eq!(result.unwrap().name, "Huevos");
+    }
+
     #[tokio::test]
     async fn should_create_manual_item_without_product_id() {
         let mut mock_repo = MockShoppingItemRepo::new();
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_insert().returning(|_| Ok(()));
 
         let use_case = CreateShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
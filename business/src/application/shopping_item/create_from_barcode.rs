@@ -0,0 +1,375 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::barcode_contribution::repository::BarcodeContributionRepository;
+use crate::domain::logger::Logger;
+use crate::domain::product::model::Product;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::services::{ProductIdentification, ProductIdentifierService};
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::use_cases::create::{
+    CreateShoppingItemParams, CreateShoppingItemUseCase,
+};
+use crate::domain::shopping_item::use_cases::create_from_barcode::{
+    CreateShoppingItemFromBarcodeParams, CreateShoppingItemFromBarcodeUseCase,
+};
+
+pub struct CreateShoppingItemFromBarcodeUseCaseImpl {
+    pub identifier: Arc<dyn ProductIdentifierService>,
+    /// Names users gave to barcodes Open Food Facts doesn't know.
+    pub contribution_repository: Arc<dyn BarcodeContributionRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub create_use_case: Arc<dyn CreateShoppingItemUseCase>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl CreateShoppingItemFromBarcodeUseCaseImpl {
+    /// Pantry product the scanned item is a re-buy of. Products don't keep
+    /// their barcode, so the match is on the identified name; the most recent
+    /// product wins, finished ones included (the jar is usually empty).
+    async fn matching_product(
+        &self,
+        user_id: &UserId,
+        name: &str,
+    ) -> Result<Option<Product>, ShoppingItemError> {
+        let wanted = name.trim().to_lowercase();
+        let candidates = self
+            .product_repository
+            .find(&ProductQuery::all(user_id.clone()).name_contains(name))
+            .await?;
+
+        Ok(candidates
+            .into_iter()
+            .find(|product| product.name.trim().to_lowercase() == wanted))
+    }
+}
+
+impl CreateShoppingItemFromBarcodeUseCaseImpl {
+    /// Identifies the barcode, falling back to the user's own name for it.
+    async fn identify(
+        &self,
+        user_id: &UserId,
+        barcode: &str,
+    ) -> Result<ProductIdentification, ShoppingItemError> {
+        let err = match self.identifier.identify_by_barcode(barcode).await {
+            Ok(identification) => return Ok(identification),
+            Err(err) => err,
+        };
+        match self.contribution_repository.find(user_id, barcode).await {
+            Ok(Some(contribution)) => Ok(contribution.identification()),
+            Ok(None) => Err(ShoppingItemError::barcode_not_recognized(err)),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Could not load contribution for barcode {}: {}",
+                    barcode, e
+                ));
+                Err(ShoppingItemError::barcode_not_recognized(err))
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl CreateShoppingItemFromBarcodeUseCase for CreateShoppingItemFromBarcodeUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CreateShoppingItemFromBarcodeParams,
+    ) -> Result<ShoppingItem, ShoppingItemError> {
+        self.logger.info(&format!(
+            "Creating shopping item from barcode: {}",
+            params.barcode
+        ));
+
+        let identification = self.identify(&params.user_id, &params.barcode).await?;
+
+        let product = self
+            .matching_product(&params.user_id, &identification.name)
+            .await?;
+        if let Some(product) = &product {
+            self.logger.info(&format!(
+                "Barcode {} matches pantry product {}",
+                params.barcode, product.id
+            ));
+        }
+
+        // Linked items keep the pantry name so the list reads the way the user named it
+        let (name, product_id) = match product {
+            Some(product) => (product.name, Some(product.id)),
+            None => (identification.name, None),
+        };
+
+        self.create_use_case
+            .execute(CreateShoppingItemParams {
+                user_id: params.user_id,
+                name,
+                product_id,
+            })
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::barcode_contribution::model::{BarcodeContribution, UpstreamStatus};
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::services::{
+        IdentificationConfidence, IdentificationMethod, ProductIdentification,
+    };
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use chrono::Utc;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductIdentifier {}
+
+        #[async_trait]
+        impl ProductIdentifierService for ProductIdentifier {
+            async fn identify_by_image(&self, image_base64: &str) -> Result<ProductIdentification, ProductError>;
+            async fn identify_by_barcode(&self, barcode: &str) -> Result<ProductIdentification, ProductError>;
+            async fn identify_all_by_image(&self, image_base64: &str) -> Result<Vec<ProductIdentification>, ProductError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub CreateShoppingItem {}
+
+        #[async_trait]
+        impl CreateShoppingItemUseCase for CreateShoppingItem {
+            async fn execute(&self, params: CreateShoppingItemParams) -> Result<ShoppingItem, ShoppingItemError>;
+        }
+    }
+
+    mock! {
+        pub ContributionRepo {}
+
+        #[async_trait]
+        impl BarcodeContributionRepository for ContributionRepo {
+            async fn find(&self, user_id: &UserId, barcode: &str) -> Result<Option<BarcodeContribution>, RepositoryError>;
+            async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn identifier_returning(name: &'static str) -> Arc<dyn ProductIdentifierService> {
+        let mut identifier = MockProductIdentifier::new();
+        identifier.expect_identify_by_barcode().returning(move |_| {
+            Ok(ProductIdentification {
+                name: name.to_string(),
+                confidence: IdentificationConfidence::High,
+                method: IdentificationMethod::Barcode,
+                suggested_location: None,
+                suggested_quantity: None,
+                suggested_expiry_type: None,
+                suggested_category: None,
+                enrichment: None,
+                ai_call_id: None,
+            })
+        });
+        Arc::new(identifier)
+    }
+
+    fn no_contributions() -> Arc<dyn BarcodeContributionRepository> {
+        let mut repo = MockContributionRepo::new();
+        repo.expect_find().returning(|_, _| Ok(None));
+        Arc::new(repo)
+    }
+
+    fn product_named(name: &str) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            ProductStatus::Finished,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn create_echoing_params() -> MockCreateShoppingItem {
+        let mut create = MockCreateShoppingItem::new();
+        create.expect_execute().times(1).returning(|params| {
+            Ok(ShoppingItem::new(params.user_id, params.name, params.product_id).unwrap())
+        });
+        create
+    }
+
+    #[tokio::test]
+    async fn should_link_item_to_pantry_product_with_same_name() {
+        let pantry_product = product_named("Tomate frito");
+        let pantry_id = pantry_product.id;
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(move |_| {
+            Ok(vec![
+                product_named("Tomate frito casero"),
+                pantry_product.clone(),
+            ])
+        });
+
+        let use_case = CreateShoppingItemFromBarcodeUseCaseImpl {
+            identifier: identifier_returning("tomate frito"),
+            contribution_repository: no_contributions(),
+            product_repository: Arc::new(product_repo),
+            create_use_case: Arc::new(create_echoing_params()),
+            logger: mock_logger(),
+        };
+
+        let item = use_case
+            .execute(CreateShoppingItemFromBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "8410188012092".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(item.product_id, Some(pantry_id));
+        assert_eq!(item.name, "Tomate frito");
+    }
+
+    #[tokio::test]
+    async fn should_create_free_text_item_when_no_pantry_match() {
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(|_| Ok(vec![]));
+
+        let use_case = CreateShoppingItemFromBarcodeUseCaseImpl {
+            identifier: identifier_returning("Tomate frito"),
+            contribution_repository: no_contributions(),
+            product_repository: Arc::new(product_repo),
+            create_use_case: Arc::new(create_echoing_params()),
+            logger: mock_logger(),
+        };
+
+        let item = use_case
+            .execute(CreateShoppingItemFromBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "8410188012092".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(item.product_id, None);
+        assert_eq!(item.name, "Tomate frito");
+    }
+
+    #[tokio::test]
+    async fn should_return_barcode_not_recognized_when_identification_fails() {
+        let mut identifier = MockProductIdentifier::new();
+        identifier
+            .expect_identify_by_barcode()
+            .returning(|_| Err(ProductError::identification_failed("not in database")));
+        let mut create = MockCreateShoppingItem::new();
+        create.expect_execute().never();
+
+        let use_case = CreateShoppingItemFromBarcodeUseCaseImpl {
+            identifier: Arc::new(identifier),
+            contribution_repository: no_contributions(),
+            product_repository: Arc::new(MockProductRepo::new()),
+            create_use_case: Arc::new(create),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateShoppingItemFromBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "0000000000000".to_string(),
+            })
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ShoppingItemError::BarcodeNotRecognized(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_use_own_contribution_when_barcode_unknown() {
+        let mut identifier = MockProductIdentifier::new();
+        identifier
+            .expect_identify_by_barcode()
+            .returning(|_| Err(ProductError::identification_failed("not in database")));
+        let mut contributions = MockContributionRepo::new();
+        contributions.expect_find().returning(|user_id, barcode| {
+            Ok(Some(
+                BarcodeContribution::new(
+                    user_id.clone(),
+                    barcode,
+                    "Turrón blando",
+                    UpstreamStatus::LocalOnly,
+                )
+                .unwrap(),
+            ))
+        });
+        let mut product_repo = MockProductRepo::new();
+        product_repo.expect_find().returning(|_| Ok(vec![]));
+
+        let use_case = CreateShoppingItemFromBarcodeUseCaseImpl {
+            identifier: Arc::new(identifier),
+            contribution_repository: Arc::new(contributions),
+            product_repository: Arc::new(product_repo),
+            create_use_case: Arc::new(create_echoing_params()),
+            logger: mock_logger(),
+        };
+
+        let item = use_case
+            .execute(CreateShoppingItemFromBarcodeParams {
+                user_id: test_user_id(),
+                barcode: "8410000000001".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(item.name, "Turrón blando");
+    }
+}
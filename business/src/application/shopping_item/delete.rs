@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
+use crate::application::shopping_item::attach::delete_attachments;
 use crate::domain::errors::RepositoryError;
 use crate::domain::logger::Logger;
 use crate::domain::shopping_item::errors::ShoppingItemError;
@@ -9,9 +10,11 @@ use crate::domain::shopping_item::repository::ShoppingItemRepository;
 use crate::domain::shopping_item::use_cases::delete::{
     DeleteShoppingItemParams, DeleteShoppingItemUseCase,
 };
+use crate::domain::storage::services::BlobStorage;
 
 pub struct DeleteShoppingItemUseCaseImpl {
     pub repository: Arc<dyn ShoppingItemRepository>,
+    pub storage: Arc<dyn BlobStorage>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -22,7 +25,8 @@ impl DeleteShoppingItemUseCase for DeleteShoppingItemUseCaseImpl {
             .info(&format!("Deleting shopping item: {}", params.id));
 
         // Verify it exists and belongs to the user
-        self.repository
+        let item = self
+            .repository
             .get_by_id(params.id, &params.user_id)
             .await
             .map_err(|e| match e {
@@ -32,6 +36,8 @@ impl DeleteShoppingItemUseCase for DeleteShoppingItemUseCaseImpl {
 
         self.repository.delete(params.id, &params.user_id).await?;
 
+        delete_attachments(self.storage.as_ref(), self.logger.as_ref(), &[item]).await;
+
         self.logger
             .info(&format!("Shopping item deleted: {}", params.id));
         Ok(())
@@ -43,6 +49,9 @@ mod tests {
     use super::*;
     use crate::domain::shared::value_objects::UserId;
     use crate::domain::shopping_item::model::ShoppingItem;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
     use mockall::mock;
     use uuid::Uuid;
 
@@ -52,12 +61,30 @@ mod tests {
         #[async_trait]
         impl ShoppingItemRepository for ShoppingItemRepo {
             async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
             async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
         }
     }
 
@@ -104,9 +131,12 @@ mod tests {
             ))
         });
         mock_repo.expect_delete().returning(|_, _| Ok(()));
+        let mut storage = MockStorage::new();
+        storage.expect_delete().never();
 
         let use_case = DeleteShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
             logger: mock_logger(),
         };
 
@@ -129,6 +159,7 @@ mod tests {
 
         let use_case = DeleteShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
+            storage: Arc::new(MockStorage::new()),
             logger: mock_logger(),
         };
 
@@ -142,4 +173,37 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), ShoppingItemError::NotFound));
     }
+
+    #[tokio::test]
+    async fn should_delete_attachment_with_the_item() {
+        let mut item = ShoppingItem::new(test_user_id(), "Harina".to_string(), None).unwrap();
+        item.attachment_content_type = Some("image/jpeg".to_string());
+        let item_id = item.id;
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(item.clone()));
+        mock_repo.expect_delete().returning(|_, _| Ok(()));
+        let mut storage = MockStorage::new();
+        storage
+            .expect_delete()
+            .withf(move |key| key == format!("shopping_items/test-user-id/{item_id}"))
+            .times(1)
+            .returning(|_| Err(StorageError::unavailable("disk full")));
+
+        let use_case = DeleteShoppingItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(DeleteShoppingItemParams {
+                id: item_id,
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
 }
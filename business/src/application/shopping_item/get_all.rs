@@ -3,15 +3,19 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
 use crate::domain::shopping_item::errors::ShoppingItemError;
-use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::model::ShoppingItemView;
 use crate::domain::shopping_item::repository::ShoppingItemRepository;
 use crate::domain::shopping_item::use_cases::get_all::{
-    GetAllShoppingItemsParams, GetAllShoppingItemsUseCase,
+    GetAllShoppingItemsParams, GetAllShoppingItemsUseCase, ShoppingItemSort,
 };
+use crate::domain::store_profile::repository::StoreProfileRepository;
 
 pub struct GetAllShoppingItemsUseCaseImpl {
     pub repository: Arc<dyn ShoppingItemRepository>,
+    pub store_profile_repository: Arc<dyn StoreProfileRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -20,9 +24,40 @@ impl GetAllShoppingItemsUseCase for GetAllShoppingItemsUseCaseImpl {
     async fn execute(
         &self,
         params: GetAllShoppingItemsParams,
-    ) -> Result<Vec<ShoppingItem>, ShoppingItemError> {
+    ) -> Result<Vec<ShoppingItemView>, ShoppingItemError> {
         self.logger.info("Getting all shopping items");
-        let items = self.repository.get_all(&params.user_id).await?;
+        let mut items = if params.include_product {
+            let preferences = self.preference_repository.get(&params.user_id).await?;
+            let mut views = self
+                .repository
+                .get_all_with_products(&params.user_id)
+                .await?;
+            for view in &mut views {
+                view.expiring_soon = preferences.expiring_soon;
+            }
+            views
+        } else {
+            self.repository
+                .get_all(&params.user_id)
+                .await?
+                .into_iter()
+                .map(ShoppingItemView::without_product)
+                .collect()
+        };
+
+        if params.sort == ShoppingItemSort::StoreOrder {
+            match self
+                .store_profile_repository
+                .get_active(&params.user_id)
+                .await?
+            {
+                Some(profile) => profile.sort_by_aisle(&mut items, |view| &view.item.name),
+                None => self
+                    .logger
+                    .debug("No active store profile, keeping creation order"),
+            }
+        }
+
         self.logger
             .info(&format!("Retrieved {} shopping items", items.len()));
         Ok(items)
@@ -33,7 +68,13 @@ impl GetAllShoppingItemsUseCase for GetAllShoppingItemsUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::urgency::{ExpiringSoonWindow, UrgencyLevel};
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
     use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItem;
+    use crate::domain::store_profile::model::{Aisle, StoreProfile};
     use mockall::mock;
     use uuid::Uuid;
 
@@ -43,12 +84,41 @@ mod tests {
         #[async_trait]
         impl ShoppingItemRepository for ShoppingItemRepo {
             async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
             async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
         }
     }
 
@@ -72,6 +142,13 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
     fn test_user_id() -> UserId {
         UserId::new("test-user-id")
     }
@@ -106,11 +183,17 @@ mod tests {
 
         let use_case = GetAllShoppingItemsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            store_profile_repository: Arc::new(MockStoreProfileRepo::new()),
+            preference_repository: default_preferences(),
             logger: mock_logger(),
         };
 
         let result = use_case
-            .execute(GetAllShoppingItemsParams { user_id })
+            .execute(GetAllShoppingItemsParams {
+                user_id,
+                sort: ShoppingItemSort::CreatedAt,
+                include_product: false,
+            })
             .await;
 
         assert!(result.is_ok());
@@ -124,16 +207,153 @@ mod tests {
 
         let use_case = GetAllShoppingItemsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            store_profile_repository: Arc::new(MockStoreProfileRepo::new()),
+            preference_repository: default_preferences(),
             logger: mock_logger(),
         };
 
         let result = use_case
             .execute(GetAllShoppingItemsParams {
                 user_id: test_user_id(),
+                sort: ShoppingItemSort::CreatedAt,
+                include_product: false,
             })
             .await;
 
         assert!(result.is_ok());
         assert!(result.unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn should_sort_by_active_store_profile_when_store_order_requested() {
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo.expect_get_all().returning(|user_id| {
+            Ok(["Detergente", "Leche", "Manzanas"]
+                .into_iter()
+                .map(|name| ShoppingItem::new(user_id.clone(), name.to_string(), None).unwrap())
+                .collect())
+        });
+        let mut mock_profiles = MockStoreProfileRepo::new();
+        mock_profiles.expect_get_active().returning(|user_id| {
+            Ok(Some(StoreProfile::from_repository(
+                Uuid::new_v4(),
+                user_id.clone(),
+                "Mercadona".to_string(),
+                vec![
+                    Aisle {
+                        name: "Fruta".to_string(),
+                        keywords: vec!["manzana".to_string()],
+                    },
+                    Aisle {
+                        name: "Lácteos".to_string(),
+                        keywords: vec!["leche".to_string()],
+                    },
+                ],
+                true,
+                chrono::Utc::now(),
+                chrono::Utc::now(),
+            )))
+        });
+
+        let use_case = GetAllShoppingItemsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            store_profile_repository: Arc::new(mock_profiles),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let items = use_case
+            .execute(GetAllShoppingItemsParams {
+                user_id: test_user_id(),
+                sort: ShoppingItemSort::StoreOrder,
+                include_product: false,
+            })
+            .await
+            .unwrap();
+
+        let names: Vec<_> = items.iter().map(|i| i.item.name.as_str()).collect();
+        assert_eq!(names, vec!["Manzanas", "Leche", "Detergente"]);
+    }
+
+    #[tokio::test]
+    async fn should_keep_creation_order_when_no_active_store_profile() {
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo.expect_get_all().returning(|user_id| {
+            Ok(["Detergente", "Manzanas"]
+                .into_iter()
+                .map(|name| ShoppingItem::new(user_id.clone(), name.to_string(), None).unwrap())
+                .collect())
+        });
+        let mut mock_profiles = MockStoreProfileRepo::new();
+        mock_profiles.expect_get_active().returning(|_| Ok(None));
+
+        let use_case = GetAllShoppingItemsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            store_profile_repository: Arc::new(mock_profiles),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let items = use_case
+            .execute(GetAllShoppingItemsParams {
+                user_id: test_user_id(),
+                sort: ShoppingItemSort::StoreOrder,
+                include_product: false,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(items[0].item.name, "Detergente");
+    }
+
+    #[tokio::test]
+    async fn should_join_linked_product_when_include_product_requested() {
+        let mut mock_repo = MockShoppingItemRepo::new();
+        mock_repo.expect_get_all().never();
+        mock_repo
+            .expect_get_all_with_products()
+            .returning(|user_id| {
+                let now = chrono::Utc::now();
+                let product = Product::from_repository(
+                    Uuid::new_v4(),
+                    user_id.clone(),
+                    "Leche".to_string(),
+                    ProductStatus::Opened,
+                    None,
+                    None,
+                    Some(now - chrono::Duration::days(1)),
+                    None,
+                    ExpiryType::None,
+                    None,
+                    now,
+                    now,
+                );
+                let item =
+                    ShoppingItem::new(user_id.clone(), "Leche".to_string(), Some(product.id))
+                        .unwrap();
+                Ok(vec![ShoppingItemView {
+                    item,
+                    product: Some(product),
+                    expiring_soon: ExpiringSoonWindow::default(),
+                }])
+            });
+
+        let use_case = GetAllShoppingItemsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            store_profile_repository: Arc::new(MockStoreProfileRepo::new()),
+            preference_repository: default_preferences(),
+            logger: mock_logger(),
+        };
+
+        let items = use_case
+            .execute(GetAllShoppingItemsParams {
+                user_id: test_user_id(),
+                sort: ShoppingItemSort::CreatedAt,
+                include_product: true,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(items[0].product_urgency(), Some(UrgencyLevel::WouldntTrust));
+    }
 }
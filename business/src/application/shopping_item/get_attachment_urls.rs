@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::shopping_item::use_cases::get_attachment_urls::{
+    GetAttachmentUrlsParams, GetAttachmentUrlsUseCase,
+};
+use crate::domain::storage::model::{SignedUrl, shopping_item_attachment_key};
+use crate::domain::storage::services::BlobStorage;
+
+pub struct GetAttachmentUrlsUseCaseImpl {
+    pub storage: Arc<dyn BlobStorage>,
+}
+
+#[async_trait]
+impl GetAttachmentUrlsUseCase for GetAttachmentUrlsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetAttachmentUrlsParams,
+    ) -> Result<HashMap<Uuid, SignedUrl>, ShoppingItemError> {
+        params
+            .item_ids
+            .into_iter()
+            .map(|id| {
+                let key = shopping_item_attachment_key(&params.user_id, id);
+                Ok((id, self.storage.signed_url(&key)?))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,282 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::shopping_item::attach::delete_attachments;
+use crate::domain::events::{DomainEvent, EventHandler};
+use crate::domain::logger::Logger;
+use crate::domain::product::events::ProductStatusChanged;
+use crate::domain::product::lifecycle::StatusTransition;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::storage::services::BlobStorage;
+
+/// Keeps the shopping list in step with the pantry: a product that runs out is
+/// added to the list, and removed again if it is marked as available.
+pub struct ShoppingListRestockPolicy {
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl ShoppingListRestockPolicy {
+    async fn add_if_missing(&self, event: &ProductStatusChanged) {
+        if let Ok(item) = ShoppingItem::new(
+            event.user_id.clone(),
+            event.product_name.clone(),
+            Some(event.product_id),
+        ) && let Err(e) = self.shopping_item_repository.save_for_product(&item).await
+        {
+            self.logger.warn(&format!(
+                "Failed to auto-add shopping item for product {}: {}",
+                event.product_id, e
+            ));
+        }
+    }
+
+    async fn remove(&self, event: &ProductStatusChanged) {
+        let removed: Vec<_> = match self.shopping_item_repository.get_all(&event.user_id).await {
+            Ok(items) => items
+                .into_iter()
+                .filter(|item| item.product_id == Some(event.product_id))
+                .collect(),
+            Err(e) => {
+                self.logger.warn(&format!(
+                    "Failed to remove shopping item for product {}: {}",
+                    event.product_id, e
+                ));
+                return;
+            }
+        };
+
+        if let Err(e) = self
+            .shopping_item_repository
+            .delete_by_product_id(event.product_id, &event.user_id)
+            .await
+        {
+            self.logger.warn(&format!(
+                "Failed to remove shopping item for product {}: {}",
+                event.product_id, e
+            ));
+            return;
+        }
+        delete_attachments(self.storage.as_ref(), self.logger.as_ref(), &removed).await;
+    }
+}
+
+#[async_trait]
+impl EventHandler for ShoppingListRestockPolicy {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::ProductStatusChanged(changed) => match changed.transition {
+                StatusTransition::Finished => self.add_if_missing(changed).await,
+                StatusTransition::Restored => self.remove(changed).await,
+                StatusTransition::Opened
+                | StatusTransition::RunningLow
+                | StatusTransition::Corrected => {}
+            },
+            DomainEvent::ProductAdded(_)
+            | DomainEvent::ProductDeleted(_)
+            | DomainEvent::ProductUpdated(_)
+            | DomainEvent::ProductOutcomeRecorded(_)
+            | DomainEvent::ChallengeCompleted(_)
+            | DomainEvent::StreakMilestoneReached(_)
+            | DomainEvent::VacationEnded(_)
+            | DomainEvent::BudgetThresholdReached(_)
+            | DomainEvent::PurchaseRecorded(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItemView;
+    use crate::domain::storage::errors::StorageError;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn status_changed(
+        product_id: Uuid,
+        previous_status: ProductStatus,
+        new_status: ProductStatus,
+    ) -> DomainEvent {
+        DomainEvent::ProductStatusChanged(ProductStatusChanged {
+            product_id,
+            user_id: UserId::new("test-user-id"),
+            product_name: "Leche".to_string(),
+            transition: previous_status
+                .transition_to(&new_status)
+                .unwrap()
+                .expect("status changes"),
+            previous_status,
+            new_status,
+        })
+    }
+
+    #[tokio::test]
+    async fn should_add_shopping_item_when_product_finished() {
+        let product_id = Uuid::new_v4();
+        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        mock_shopping_repo
+            .expect_save_for_product()
+            .withf(move |item| item.product_id == Some(product_id) && item.name == "Leche")
+            .times(1)
+            .returning(|item| Ok(item.clone()));
+
+        let policy = ShoppingListRestockPolicy {
+            shopping_item_repository: Arc::new(mock_shopping_repo),
+            storage: Arc::new(MockStorage::new()),
+            logger: mock_logger(),
+        };
+
+        policy
+            .handle(&status_changed(
+                product_id,
+                ProductStatus::Opened,
+                ProductStatus::Finished,
+            ))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_remove_shopping_item_and_its_attachment_when_product_restored() {
+        let product_id = Uuid::new_v4();
+        let mut item = ShoppingItem::new(
+            UserId::new("test-user-id"),
+            "Leche".to_string(),
+            Some(product_id),
+        )
+        .unwrap();
+        item.attachment_content_type = Some("image/png".to_string());
+        let attachment_key = format!("shopping_items/test-user-id/{}", item.id);
+        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        mock_shopping_repo
+            .expect_get_all()
+            .returning(move |_| Ok(vec![item.clone()]));
+        mock_shopping_repo
+            .expect_delete_by_product_id()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        let mut storage = MockStorage::new();
+        storage
+            .expect_delete()
+            .withf(move |key| key == attachment_key)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let policy = ShoppingListRestockPolicy {
+            shopping_item_repository: Arc::new(mock_shopping_repo),
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        policy
+            .handle(&status_changed(
+                product_id,
+                ProductStatus::Finished,
+                ProductStatus::Opened,
+            ))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_ignore_transitions_that_do_not_involve_finished() {
+        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        mock_shopping_repo.expect_save_for_product().never();
+        mock_shopping_repo.expect_delete_by_product_id().never();
+
+        let policy = ShoppingListRestockPolicy {
+            shopping_item_repository: Arc::new(mock_shopping_repo),
+            storage: Arc::new(MockStorage::new()),
+            logger: mock_logger(),
+        };
+
+        policy
+            .handle(&status_changed(
+                Uuid::new_v4(),
+                ProductStatus::New,
+                ProductStatus::Opened,
+            ))
+            .await;
+    }
+
+    #[tokio::test]
+    async fn should_swallow_error_when_save_fails() {
+        let mut mock_shopping_repo = MockShoppingItemRepo::new();
+        mock_shopping_repo
+            .expect_save_for_product()
+            .returning(|_| Err(RepositoryError::database_error("connection reset")));
+
+        let policy = ShoppingListRestockPolicy {
+            shopping_item_repository: Arc::new(mock_shopping_repo),
+            storage: Arc::new(MockStorage::new()),
+            logger: mock_logger(),
+        };
+
+        policy
+            .handle(&status_changed(
+                Uuid::new_v4(),
+                ProductStatus::AlmostEmpty,
+                ProductStatus::Finished,
+            ))
+            .await;
+    }
+}
@@ -42,7 +42,7 @@ impl UpdateShoppingItemUseCase for UpdateShoppingItemUseCaseImpl {
 
         let is_bought = params.is_bought.unwrap_or(existing.is_bought);
 
-        let updated = ShoppingItem::from_repository(
+        let mut updated = ShoppingItem::from_repository(
             existing.id,
             existing.user_id,
             name,
@@ -51,8 +51,16 @@ impl UpdateShoppingItemUseCase for UpdateShoppingItemUseCaseImpl {
             existing.created_at,
             chrono::Utc::now(),
         );
+        updated.attachment_content_type = existing.attachment_content_type;
 
-        self.repository.save(&updated).await?;
+        self.repository
+            .update(&updated)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::Duplicated => ShoppingItemError::AlreadyExists,
+                RepositoryError::NotFound => ShoppingItemError::NotFound,
+                other => ShoppingItemError::Repository(other),
+            })?;
 
         self.logger
             .info(&format!("Shopping item updated: {}", updated.id));
@@ -64,6 +72,7 @@ impl UpdateShoppingItemUseCase for UpdateShoppingItemUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItemView;
     use mockall::mock;
     use uuid::Uuid;
 
@@ -73,12 +82,16 @@ mod tests {
         #[async_trait]
         impl ShoppingItemRepository for ShoppingItemRepo {
             async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
             async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
-            async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
             async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
         }
     }
 
@@ -124,7 +137,7 @@ mod tests {
                 chrono::Utc::now(),
             ))
         });
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_update().returning(|_| Ok(()));
 
         let use_case = UpdateShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
@@ -162,7 +175,7 @@ mod tests {
                 chrono::Utc::now(),
             ))
         });
-        mock_repo.expect_save().returning(|_| Ok(()));
+        mock_repo.expect_update().returning(|_| Ok(()));
 
         let use_case = UpdateShoppingItemUseCaseImpl {
             repository: Arc::new(mock_repo),
@@ -182,6 +195,48 @@ mod tests {
         assert_eq!(result.unwrap().name, "Whole Milk");
     }
 
+    #[tokio::test]
+    async fn should_return_already_exists_when_unbought_duplicate_for_product() {
+        let item_id = Uuid::new_v4();
+        let user_id = test_user_id();
+        let user_id_clone = user_id.clone();
+        let mut mock_repo = MockShoppingItemRepo::new();
+
+        mock_repo.expect_get_by_id().returning(move |_, _| {
+            Ok(ShoppingItem::from_repository(
+                item_id,
+                user_id_clone.clone(),
+                "Milk".to_string(),
+                Some(Uuid::new_v4()),
+                true,
+                chrono::Utc::now(),
+                chrono::Utc::now(),
+            ))
+        });
+        mock_repo
+            .expect_update()
+            .returning(|_| Err(RepositoryError::Duplicated));
+
+        let use_case = UpdateShoppingItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateShoppingItemParams {
+                id: item_id,
+                user_id,
+                name: None,
+                is_bought: Some(false),
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            ShoppingItemError::AlreadyExists
+        ));
+    }
+
     #[tokio::test]
     async fn should_return_not_found_when_item_does_not_exist() {
         let mut mock_repo = MockShoppingItemRepo::new();
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+use crate::domain::shopping_trip::repository::ShoppingTripRepository;
+use crate::domain::shopping_trip::use_cases::finish::{
+    FinishShoppingTripParams, FinishShoppingTripUseCase,
+};
+
+pub struct FinishShoppingTripUseCaseImpl {
+    pub repository: Arc<dyn ShoppingTripRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl FinishShoppingTripUseCase for FinishShoppingTripUseCaseImpl {
+    async fn execute(
+        &self,
+        params: FinishShoppingTripParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError> {
+        let mut trip = self
+            .repository
+            .find_in_progress(&params.user_id)
+            .await?
+            .ok_or(ShoppingTripError::NotInProgress)?;
+
+        let summary = trip.finish()?;
+        self.repository.update(&trip).await?;
+
+        self.logger.info(&format!(
+            "Shopping trip finished: {} ({}/{} items bought)",
+            trip.id, summary.items_bought, summary.items_planned
+        ));
+        Ok(trip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::ShoppingItem;
+    use crate::domain::shopping_trip::model::ShoppingTripStatus;
+    use mockall::mock;
+
+    mock! {
+        pub ShoppingTripRepo {}
+
+        #[async_trait]
+        impl ShoppingTripRepository for ShoppingTripRepo {
+            async fn find_in_progress(&self, user_id: &UserId) -> Result<Option<ShoppingTrip>, RepositoryError>;
+            async fn insert(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+            async fn update(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_persist_finished_trip() {
+        let item = ShoppingItem::new(test_user_id(), "Leche".to_string(), None).unwrap();
+        let trip = ShoppingTrip::start(test_user_id(), &[item]).unwrap();
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo
+            .expect_find_in_progress()
+            .returning(move |_| Ok(Some(trip.clone())));
+        mock_repo
+            .expect_update()
+            .withf(|trip| trip.status == ShoppingTripStatus::Finished)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = FinishShoppingTripUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let trip = use_case
+            .execute(FinishShoppingTripParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(trip.finished_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_in_progress_when_no_trip_started() {
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo.expect_find_in_progress().returning(|_| Ok(None));
+        mock_repo.expect_update().never();
+
+        let use_case = FinishShoppingTripUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(FinishShoppingTripParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShoppingTripError::NotInProgress)));
+    }
+}
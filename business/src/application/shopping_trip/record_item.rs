@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::events::PurchaseRecorded;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+use crate::domain::shopping_trip::repository::ShoppingTripRepository;
+use crate::domain::shopping_trip::use_cases::record_item::{
+    RecordTripItemParams, RecordTripItemUseCase,
+};
+
+pub struct RecordTripItemUseCaseImpl {
+    pub repository: Arc<dyn ShoppingTripRepository>,
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RecordTripItemUseCase for RecordTripItemUseCaseImpl {
+    async fn execute(
+        &self,
+        params: RecordTripItemParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError> {
+        self.logger.info(&format!(
+            "Recording shopping trip item as bought: {}",
+            params.shopping_item_id
+        ));
+
+        let mut trip = self
+            .repository
+            .find_in_progress(&params.user_id)
+            .await?
+            .ok_or(ShoppingTripError::NotInProgress)?;
+
+        let previous_price = trip
+            .items
+            .iter()
+            .find(|item| item.shopping_item_id == params.shopping_item_id)
+            .and_then(|item| item.price_cents);
+        trip.record_bought(params.shopping_item_id, params.price_cents)?;
+        self.repository.update(&trip).await?;
+
+        // Keep the list in sync; the trip snapshot stays the source of truth
+        // for the summary, so a list item deleted meanwhile is not an error
+        match self
+            .shopping_item_repository
+            .get_by_id(params.shopping_item_id, &params.user_id)
+            .await
+        {
+            Ok(mut item) if !item.is_bought => {
+                item.is_bought = true;
+                item.updated_at = chrono::Utc::now();
+                self.shopping_item_repository.update(&item).await?;
+            }
+            Ok(_) => {}
+            Err(RepositoryError::NotFound) => self.logger.warn(&format!(
+                "Shopping item {} is no longer on the list",
+                params.shopping_item_id
+            )),
+            Err(other) => return Err(other.into()),
+        }
+
+        let added_cents = u64::from(params.price_cents.unwrap_or(0))
+            .saturating_sub(u64::from(previous_price.unwrap_or(0)));
+        if added_cents > 0 {
+            self.event_publisher
+                .publish(DomainEvent::PurchaseRecorded(PurchaseRecorded {
+                    user_id: params.user_id,
+                    added_cents,
+                }))
+                .await;
+        }
+
+        Ok(trip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShoppingTripRepo {}
+
+        #[async_trait]
+        impl ShoppingTripRepository for ShoppingTripRepo {
+            async fn find_in_progress(&self, user_id: &UserId) -> Result<Option<ShoppingTrip>, RepositoryError>;
+            async fn insert(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+            async fn update(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_record_price_and_mark_list_item_bought() {
+        let item = ShoppingItem::new(test_user_id(), "Leche".to_string(), None).unwrap();
+        let item_id = item.id;
+        let trip = ShoppingTrip::start(test_user_id(), std::slice::from_ref(&item)).unwrap();
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo
+            .expect_find_in_progress()
+            .returning(move |_| Ok(Some(trip.clone())));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items
+            .expect_get_by_id()
+            .returning(move |_, _| Ok(item.clone()));
+        mock_items
+            .expect_update()
+            .withf(|item| item.is_bought)
+            .times(1)
+            .returning(|_| Ok(()));
+        let mut mock_publisher = MockPublisher::new();
+        mock_publisher
+            .expect_publish()
+            .withf(|event| {
+                matches!(event, DomainEvent::PurchaseRecorded(recorded) if recorded.added_cents == 115)
+            })
+            .times(1)
+            .returning(|_| ());
+
+        let use_case = RecordTripItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(mock_items),
+            event_publisher: Arc::new(mock_publisher),
+            logger: mock_logger(),
+        };
+
+        let trip = use_case
+            .execute(RecordTripItemParams {
+                user_id: test_user_id(),
+                shopping_item_id: item_id,
+                price_cents: Some(115),
+            })
+            .await
+            .unwrap();
+
+        assert!(trip.items[0].bought_at.is_some());
+        assert_eq!(trip.items[0].price_cents, Some(115));
+    }
+
+    #[tokio::test]
+    async fn should_keep_recording_when_list_item_was_deleted() {
+        let item = ShoppingItem::new(test_user_id(), "Leche".to_string(), None).unwrap();
+        let item_id = item.id;
+        let trip = ShoppingTrip::start(test_user_id(), &[item]).unwrap();
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo
+            .expect_find_in_progress()
+            .returning(move |_| Ok(Some(trip.clone())));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+        mock_items.expect_update().never();
+
+        let use_case = RecordTripItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(mock_items),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RecordTripItemParams {
+                user_id: test_user_id(),
+                shopping_item_id: item_id,
+                price_cents: None,
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_not_in_progress_when_no_trip_started() {
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo.expect_find_in_progress().returning(|_| Ok(None));
+        mock_repo.expect_update().never();
+
+        let use_case = RecordTripItemUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(MockShoppingItemRepo::new()),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(RecordTripItemParams {
+                user_id: test_user_id(),
+                shopping_item_id: Uuid::new_v4(),
+                price_cents: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShoppingTripError::NotInProgress)));
+    }
+}
@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::shopping_item::repository::ShoppingItemRepository;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+use crate::domain::shopping_trip::repository::ShoppingTripRepository;
+use crate::domain::shopping_trip::use_cases::start::{
+    StartShoppingTripParams, StartShoppingTripUseCase,
+};
+
+pub struct StartShoppingTripUseCaseImpl {
+    pub repository: Arc<dyn ShoppingTripRepository>,
+    pub shopping_item_repository: Arc<dyn ShoppingItemRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl StartShoppingTripUseCase for StartShoppingTripUseCaseImpl {
+    async fn execute(
+        &self,
+        params: StartShoppingTripParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError> {
+        self.logger.info("Starting shopping trip");
+
+        // Resume the unfinished trip instead of taking a new snapshot
+        if let Some(existing) = self.repository.find_in_progress(&params.user_id).await? {
+            self.logger
+                .info(&format!("Resuming shopping trip: {}", existing.id));
+            return Ok(existing);
+        }
+
+        let list = self
+            .shopping_item_repository
+            .get_all(&params.user_id)
+            .await?;
+        let trip = ShoppingTrip::start(params.user_id, &list)?;
+        self.repository.insert(&trip).await?;
+
+        self.logger.info(&format!(
+            "Shopping trip started: {} ({} items)",
+            trip.id,
+            trip.items.len()
+        ));
+        Ok(trip)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ShoppingTripRepo {}
+
+        #[async_trait]
+        impl ShoppingTripRepository for ShoppingTripRepo {
+            async fn find_in_progress(&self, user_id: &UserId) -> Result<Option<ShoppingTrip>, RepositoryError>;
+            async fn insert(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+            async fn update(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ShoppingItemRepo {}
+
+        #[async_trait]
+        impl ShoppingItemRepository for ShoppingItemRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+            async fn get_all_with_products(&self, user_id: &UserId) -> Result<Vec<ShoppingItemView>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
+            async fn find_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<Option<ShoppingItem>, RepositoryError>;
+            async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+            async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_by_product_id(&self, product_id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<ShoppingItem>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn leche() -> ShoppingItem {
+        ShoppingItem::new(test_user_id(), "Leche".to_string(), None).unwrap()
+    }
+
+    #[tokio::test]
+    async fn should_snapshot_list_when_no_trip_in_progress() {
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo.expect_find_in_progress().returning(|_| Ok(None));
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items.expect_get_all().returning(|_| Ok(vec![leche()]));
+
+        let use_case = StartShoppingTripUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(mock_items),
+            logger: mock_logger(),
+        };
+
+        let trip = use_case
+            .execute(StartShoppingTripParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(trip.items.len(), 1);
+        assert_eq!(trip.items[0].name, "Leche");
+    }
+
+    #[tokio::test]
+    async fn should_resume_trip_in_progress() {
+        let existing = ShoppingTrip::start(test_user_id(), &[leche()]).unwrap();
+        let existing_id = existing.id;
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo
+            .expect_find_in_progress()
+            .returning(move |_| Ok(Some(existing.clone())));
+        mock_repo.expect_insert().never();
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items.expect_get_all().never();
+
+        let use_case = StartShoppingTripUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(mock_items),
+            logger: mock_logger(),
+        };
+
+        let trip = use_case
+            .execute(StartShoppingTripParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(trip.id, existing_id);
+    }
+
+    #[tokio::test]
+    async fn should_return_empty_list_when_nothing_to_buy() {
+        let mut mock_repo = MockShoppingTripRepo::new();
+        mock_repo.expect_find_in_progress().returning(|_| Ok(None));
+        mock_repo.expect_insert().never();
+        let mut mock_items = MockShoppingItemRepo::new();
+        mock_items.expect_get_all().returning(|_| Ok(vec![]));
+
+        let use_case = StartShoppingTripUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            shopping_item_repository: Arc::new(mock_items),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(StartShoppingTripParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(ShoppingTripError::EmptyList)));
+    }
+}
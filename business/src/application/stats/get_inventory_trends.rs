@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::InventorySnapshot;
+use crate::domain::stats::repository::InventorySnapshotRepository;
+use crate::domain::stats::use_cases::get_inventory_trends::{
+    GetInventoryTrendsParams, GetInventoryTrendsUseCase,
+};
+
+pub struct GetInventoryTrendsUseCaseImpl {
+    pub repository: Arc<dyn InventorySnapshotRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetInventoryTrendsUseCase for GetInventoryTrendsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetInventoryTrendsParams,
+    ) -> Result<Vec<InventorySnapshot>, StatsError> {
+        self.logger.info(&format!(
+            "Getting {}-day inventory trends for user: {}",
+            params.period.days(),
+            params.user_id
+        ));
+
+        let since = params.period.start_date(Utc::now());
+        Ok(self.repository.find_since(&params.user_id, since).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::stats::model::TrendPeriod;
+    use chrono::{Days, NaiveDate};
+    use mockall::mock;
+
+    mock! {
+        pub SnapshotRepo {}
+
+        #[async_trait]
+        impl InventorySnapshotRepository for SnapshotRepo {
+            async fn save(&self, user_id: &UserId, snapshot: &InventorySnapshot) -> Result<(), RepositoryError>;
+            async fn find_since(&self, user_id: &UserId, since: NaiveDate) -> Result<Vec<InventorySnapshot>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_read_snapshots_from_start_of_period() {
+        let mut mock_repo = MockSnapshotRepo::new();
+        mock_repo
+            .expect_find_since()
+            .withf(|_, since| *since == Utc::now().date_naive() - Days::new(6))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+
+        let use_case = GetInventoryTrendsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let snapshots = use_case
+            .execute(GetInventoryTrendsParams {
+                user_id: UserId::new("test-user-id"),
+                period: TrendPeriod::new(7),
+            })
+            .await
+            .unwrap();
+
+        assert!(snapshots.is_empty());
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::{InventoryValue, fill_months};
+use crate::domain::stats::repository::StatsRepository;
+use crate::domain::stats::use_cases::get_inventory_value::{
+    GetInventoryValueParams, GetInventoryValueUseCase,
+};
+
+pub struct GetInventoryValueUseCaseImpl {
+    pub repository: Arc<dyn StatsRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetInventoryValueUseCase for GetInventoryValueUseCaseImpl {
+    async fn execute(&self, params: GetInventoryValueParams) -> Result<InventoryValue, StatsError> {
+        self.logger.info(&format!(
+            "Getting inventory value for user: {}",
+            params.user_id
+        ));
+
+        let now = Utc::now();
+        let active = self.repository.get_active_value(&params.user_id).await?;
+        let outcomes = self
+            .repository
+            .get_outcomes_by_month(&params.user_id, params.period.start_at(now))
+            .await?;
+
+        Ok(InventoryValue {
+            active,
+            by_month: fill_months(params.period, now, &outcomes),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::stats::model::{MonthlyOutcomes, ProductValue, WastePeriod, WeeklyOutcomes};
+    use chrono::{DateTime, Datelike};
+    use mockall::mock;
+
+    mock! {
+        pub StatsRepo {}
+
+        #[async_trait]
+        impl StatsRepository for StatsRepo {
+            async fn get_active_value(&self, user_id: &UserId) -> Result<ProductValue, RepositoryError>;
+            async fn get_outcomes_by_month(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<Vec<MonthlyOutcomes>, RepositoryError>;
+            async fn get_outcomes_by_week(&self, user_id: &UserId, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_report_every_month_of_period_with_active_value() {
+        let active = ProductValue {
+            products: 4,
+            priced_products: 3,
+            value_cents: 1275,
+        };
+        let mut mock_repo = MockStatsRepo::new();
+        mock_repo
+            .expect_get_active_value()
+            .returning(move |_| Ok(active));
+        mock_repo
+            .expect_get_outcomes_by_month()
+            .withf(|_, since| since.day() == 1)
+            .returning(|_, _| {
+                Ok(vec![MonthlyOutcomes {
+                    month: Utc::now().date_naive().with_day(1).unwrap(),
+                    wasted: ProductValue {
+                        products: 1,
+                        priced_products: 1,
+                        value_cents: 199,
+                    },
+                    given_away: ProductValue::default(),
+                }])
+            });
+
+        let use_case = GetInventoryValueUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let value = use_case
+            .execute(GetInventoryValueParams {
+                user_id: test_user_id(),
+                period: WastePeriod::new(3),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(value.active, active);
+        assert_eq!(value.by_month.len(), 3);
+        assert_eq!(value.by_month[2].wasted.value_cents, 199);
+        assert_eq!(value.by_month[0].wasted, ProductValue::default());
+    }
+}
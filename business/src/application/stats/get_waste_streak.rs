@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::{WasteStreak, WasteStreakHistory};
+use crate::domain::stats::repository::WasteStreakRepository;
+use crate::domain::stats::use_cases::get_waste_streak::{
+    GetWasteStreakParams, GetWasteStreakUseCase,
+};
+
+pub struct GetWasteStreakUseCaseImpl {
+    pub repository: Arc<dyn WasteStreakRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetWasteStreakUseCase for GetWasteStreakUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetWasteStreakParams,
+    ) -> Result<WasteStreakHistory, StatsError> {
+        self.logger.info(&format!(
+            "Getting waste streak for user: {}",
+            params.user_id
+        ));
+
+        let streak = self.repository.get(&params.user_id).await?;
+        let weeks = self
+            .repository
+            .find_weeks_since(&params.user_id, WasteStreak::history_start(Utc::now()))
+            .await?;
+
+        Ok(WasteStreakHistory { streak, weeks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::stats::model::WeeklyOutcomes;
+    use chrono::{Datelike, NaiveDate, Weekday};
+    use mockall::mock;
+
+    mock! {
+        pub StreakRepo {}
+
+        #[async_trait]
+        impl WasteStreakRepository for StreakRepo {
+            async fn get(&self, user_id: &UserId) -> Result<WasteStreak, RepositoryError>;
+            async fn save(&self, user_id: &UserId, streak: &WasteStreak, weeks: &[WeeklyOutcomes]) -> Result<(), RepositoryError>;
+            async fn find_weeks_since(&self, user_id: &UserId, since: NaiveDate) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_return_streak_with_recent_weeks() {
+        let mut repo = MockStreakRepo::new();
+        repo.expect_get().returning(|_| {
+            Ok(WasteStreak {
+                current: 2,
+                longest: 5,
+                last_week: NaiveDate::from_ymd_opt(2026, 3, 9),
+            })
+        });
+        repo.expect_find_weeks_since()
+            .withf(|_, since| {
+                since.weekday() == Weekday::Mon
+                    && Utc::now().date_naive() - *since >= chrono::Duration::weeks(12)
+            })
+            .times(1)
+            .returning(|_, since| Ok(vec![WeeklyOutcomes::empty(since)]));
+
+        let use_case = GetWasteStreakUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let history = use_case
+            .execute(GetWasteStreakParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(history.streak.longest, 5);
+        assert_eq!(history.weeks.len(), 1);
+    }
+}
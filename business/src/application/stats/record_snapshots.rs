@@ -0,0 +1,378 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::InventorySnapshot;
+use crate::domain::stats::repository::{InventorySnapshotRepository, StatsRepository};
+use crate::domain::stats::use_cases::record_snapshots::{
+    RecordInventorySnapshotsParams, RecordInventorySnapshotsUseCase, SnapshotRunSummary,
+};
+use crate::domain::vacation::repository::VacationRepository;
+
+pub struct RecordInventorySnapshotsUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub stats_repository: Arc<dyn StatsRepository>,
+    pub snapshot_repository: Arc<dyn InventorySnapshotRepository>,
+    pub vacation_repository: Arc<dyn VacationRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl RecordInventorySnapshotsUseCaseImpl {
+    async fn record_for(&self, user_id: &UserId, date: NaiveDate) -> Result<(), RepositoryError> {
+        let products = self
+            .product_repository
+            .find(&ProductQuery::active(user_id.clone()))
+            .await?;
+        let preferences = self.preference_repository.get(user_id).await?;
+        let wasted_to_date = self
+            .stats_repository
+            .get_outcomes_by_month(user_id, DateTime::UNIX_EPOCH)
+            .await?
+            .into_iter()
+            .map(|month| month.wasted)
+            .sum();
+
+        let mut snapshot =
+            InventorySnapshot::capture(date, &products, preferences.expiring_soon, wasted_to_date);
+        snapshot.on_vacation = self
+            .vacation_repository
+            .find_current(user_id)
+            .await?
+            .is_some();
+        self.snapshot_repository.save(user_id, &snapshot).await
+    }
+}
+
+#[async_trait]
+impl RecordInventorySnapshotsUseCase for RecordInventorySnapshotsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: RecordInventorySnapshotsParams,
+    ) -> Result<SnapshotRunSummary, StatsError> {
+        self.logger.info("Starting inventory snapshot run");
+
+        let mut summary = SnapshotRunSummary::default();
+        let today = Utc::now().date_naive();
+        let mut after = None;
+
+        loop {
+            let users = self
+                .product_repository
+                .get_users_with_active_products(after.take(), params.batch_size)
+                .await?;
+            let Some(last) = users.last().cloned() else {
+                break;
+            };
+
+            for user_id in users {
+                match self.record_for(&user_id, today).await {
+                    Ok(()) => summary.recorded += 1,
+                    Err(e) => {
+                        self.logger.warn(&format!(
+                            "Inventory snapshot failed for user {}: {}",
+                            user_id, e
+                        ));
+                        summary.failed += 1;
+                    }
+                }
+            }
+
+            after = Some(last);
+        }
+
+        self.logger.info(&format!(
+            "Inventory snapshot run finished: recorded={}, failed={}",
+            summary.recorded, summary.failed
+        ));
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::stats::model::{MonthlyOutcomes, ProductValue, WeeklyOutcomes};
+    use crate::domain::vacation::model::Vacation;
+    use chrono::Duration;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub StatsRepo {}
+
+        #[async_trait]
+        impl StatsRepository for StatsRepo {
+            async fn get_active_value(&self, user_id: &UserId) -> Result<ProductValue, RepositoryError>;
+            async fn get_outcomes_by_month(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<Vec<MonthlyOutcomes>, RepositoryError>;
+            async fn get_outcomes_by_week(&self, user_id: &UserId, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SnapshotRepo {}
+
+        #[async_trait]
+        impl InventorySnapshotRepository for SnapshotRepo {
+            async fn save(&self, user_id: &UserId, snapshot: &InventorySnapshot) -> Result<(), RepositoryError>;
+            async fn find_since(&self, user_id: &UserId, since: NaiveDate) -> Result<Vec<InventorySnapshot>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
+    /// Only "ana" is on vacation.
+    fn vacations() -> Arc<dyn VacationRepository> {
+        let mut repo = MockVacationRepo::new();
+        repo.expect_find_current().returning(|user_id| {
+            Ok((user_id.as_str() == "ana")
+                .then(|| Vacation::start(user_id.clone(), None, Utc::now()).unwrap()))
+        });
+        Arc::new(repo)
+    }
+
+    fn wasted_in(month: NaiveDate, products: u32) -> MonthlyOutcomes {
+        MonthlyOutcomes {
+            month,
+            wasted: ProductValue {
+                products,
+                priced_products: 0,
+                value_cents: 0,
+            },
+            given_away: ProductValue::default(),
+        }
+    }
+
+    fn product_expiring_in(user: &str, days: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new(user),
+            "Leche".to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            Some(Utc::now() + Duration::days(days)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    /// Pages through `ids` like the repository does.
+    fn active_users(ids: &'static [&'static str]) -> MockProductRepo {
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_users_with_active_products()
+            .returning(move |after, limit| {
+                Ok(ids
+                    .iter()
+                    .filter(|id| after.as_ref().is_none_or(|after| **id > after.as_str()))
+                    .take(limit)
+                    .map(|id| UserId::new(*id))
+                    .collect())
+            });
+        repo
+    }
+
+    #[tokio::test]
+    async fn should_record_snapshot_with_waste_since_the_start() {
+        let mut mock_products = active_users(&["ana"]);
+        mock_products
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("ana", 1)]));
+
+        let mut mock_stats = MockStatsRepo::new();
+        mock_stats
+            .expect_get_outcomes_by_month()
+            .withf(|_, since| *since == DateTime::UNIX_EPOCH)
+            .returning(|_, _| {
+                Ok(vec![
+                    wasted_in(NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(), 2),
+                    wasted_in(NaiveDate::from_ymd_opt(2026, 2, 1).unwrap(), 3),
+                ])
+            });
+
+        let mut mock_snapshots = MockSnapshotRepo::new();
+        mock_snapshots
+            .expect_save()
+            .withf(|user_id, snapshot| {
+                user_id.as_str() == "ana"
+                    && snapshot.date == Utc::now().date_naive()
+                    && snapshot.by_status.opened == 1
+                    && snapshot.urgent == 1
+                    && snapshot.wasted_to_date.products == 5
+                    && snapshot.on_vacation
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = RecordInventorySnapshotsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            preference_repository: default_preferences(),
+            stats_repository: Arc::new(mock_stats),
+            snapshot_repository: Arc::new(mock_snapshots),
+            vacation_repository: vacations(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordInventorySnapshotsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 1);
+        assert_eq!(summary.failed, 0);
+    }
+
+    #[tokio::test]
+    async fn should_keep_going_when_one_user_fails() {
+        let mut mock_products = active_users(&["ana", "luis"]);
+        mock_products.expect_find().returning(|query| {
+            if query.user_id.as_str() == "ana" {
+                Err(RepositoryError::Persistence)
+            } else {
+                Ok(vec![])
+            }
+        });
+
+        let mut mock_stats = MockStatsRepo::new();
+        mock_stats
+            .expect_get_outcomes_by_month()
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_snapshots = MockSnapshotRepo::new();
+        mock_snapshots
+            .expect_save()
+            .withf(|user_id, _| user_id.as_str() == "luis")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = RecordInventorySnapshotsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            preference_repository: default_preferences(),
+            stats_repository: Arc::new(mock_stats),
+            snapshot_repository: Arc::new(mock_snapshots),
+            vacation_repository: vacations(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordInventorySnapshotsParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 1);
+        assert_eq!(summary.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn should_snapshot_every_user_past_the_first_batch() {
+        let mut mock_products = active_users(&["ana", "luis", "marta"]);
+        mock_products.expect_find().returning(|_| Ok(vec![]));
+
+        let mut mock_stats = MockStatsRepo::new();
+        mock_stats
+            .expect_get_outcomes_by_month()
+            .returning(|_, _| Ok(vec![]));
+
+        let mut mock_snapshots = MockSnapshotRepo::new();
+        mock_snapshots
+            .expect_save()
+            .times(3)
+            .returning(|_, _| Ok(()));
+
+        let use_case = RecordInventorySnapshotsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            preference_repository: default_preferences(),
+            stats_repository: Arc::new(mock_stats),
+            snapshot_repository: Arc::new(mock_snapshots),
+            vacation_repository: vacations(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordInventorySnapshotsParams { batch_size: 2 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 3);
+    }
+}
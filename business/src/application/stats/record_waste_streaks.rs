@@ -0,0 +1,384 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Days, NaiveTime, Utc};
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::events::StreakMilestoneReached;
+use crate::domain::stats::model::fill_weeks;
+use crate::domain::stats::repository::{StatsRepository, WasteStreakRepository};
+use crate::domain::stats::use_cases::record_waste_streaks::{
+    RecordWasteStreaksParams, RecordWasteStreaksUseCase, StreakRunSummary,
+};
+
+/// Closes the weeks that ended since the last run, counts them towards each
+/// user's zero-waste streak and announces the milestones reached so the user
+/// can be congratulated.
+pub struct RecordWasteStreaksUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub stats_repository: Arc<dyn StatsRepository>,
+    pub streak_repository: Arc<dyn WasteStreakRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl RecordWasteStreaksUseCaseImpl {
+    /// Returns the milestone reached, if any.
+    async fn record_for(
+        &self,
+        user_id: &UserId,
+        now: DateTime<Utc>,
+    ) -> Result<Option<StreakMilestoneReached>, RepositoryError> {
+        let mut streak = self.streak_repository.get(user_id).await?;
+        let weeks = streak.weeks_to_close(now);
+        let (Some(first), Some(last)) = (weeks.first(), weeks.last()) else {
+            return Ok(None);
+        };
+
+        let found = self
+            .stats_repository
+            .get_outcomes_by_week(
+                user_id,
+                first.and_time(NaiveTime::MIN).and_utc(),
+                (*last + Days::new(7)).and_time(NaiveTime::MIN).and_utc(),
+            )
+            .await?;
+        let closed = fill_weeks(&weeks, &found);
+        let reached = streak.close(&closed);
+        self.streak_repository
+            .save(user_id, &streak, &closed)
+            .await?;
+
+        Ok(reached.map(|weeks| StreakMilestoneReached {
+            user_id: user_id.clone(),
+            weeks,
+            week: *last,
+        }))
+    }
+}
+
+#[async_trait]
+impl RecordWasteStreaksUseCase for RecordWasteStreaksUseCaseImpl {
+    async fn execute(
+        &self,
+        params: RecordWasteStreaksParams,
+    ) -> Result<StreakRunSummary, StatsError> {
+        self.logger.info("Starting waste streak run");
+
+        let mut summary = StreakRunSummary::default();
+        let now = Utc::now();
+        let mut after = None;
+
+        loop {
+            let users = self
+                .product_repository
+                .get_users_with_active_products(after.take(), params.batch_size)
+                .await?;
+            let Some(last) = users.last().cloned() else {
+                break;
+            };
+
+            for user_id in users {
+                match self.record_for(&user_id, now).await {
+                    Ok(reached) => {
+                        summary.recorded += 1;
+                        if let Some(milestone) = reached {
+                            summary.milestones += 1;
+                            self.event_publisher
+                                .publish(DomainEvent::StreakMilestoneReached(milestone))
+                                .await;
+                        }
+                    }
+                    Err(e) => {
+                        self.logger
+                            .warn(&format!("Waste streak failed for user {}: {}", user_id, e));
+                        summary.failed += 1;
+                    }
+                }
+            }
+
+            after = Some(last);
+        }
+
+        self.logger.info(&format!(
+            "Waste streak run finished: recorded={}, milestones={}, failed={}",
+            summary.recorded, summary.milestones, summary.failed
+        ));
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::stats::model::{MonthlyOutcomes, ProductValue, WasteStreak, WeeklyOutcomes};
+    use chrono::NaiveDate;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub StatsRepo {}
+
+        #[async_trait]
+        impl StatsRepository for StatsRepo {
+            async fn get_active_value(&self, user_id: &UserId) -> Result<ProductValue, RepositoryError>;
+            async fn get_outcomes_by_month(&self, user_id: &UserId, since: DateTime<Utc>) -> Result<Vec<MonthlyOutcomes>, RepositoryError>;
+            async fn get_outcomes_by_week(&self, user_id: &UserId, since: DateTime<Utc>, until: DateTime<Utc>) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub StreakRepo {}
+
+        #[async_trait]
+        impl WasteStreakRepository for StreakRepo {
+            async fn get(&self, user_id: &UserId) -> Result<WasteStreak, RepositoryError>;
+            async fn save(&self, user_id: &UserId, streak: &WasteStreak, weeks: &[WeeklyOutcomes]) -> Result<(), RepositoryError>;
+            async fn find_weeks_since(&self, user_id: &UserId, since: NaiveDate) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Pages through `ids` like the repository does.
+    fn users(ids: &'static [&'static str]) -> MockProductRepo {
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_users_with_active_products()
+            .returning(move |after, limit| {
+                Ok(ids
+                    .iter()
+                    .filter(|id| after.as_ref().is_none_or(|after| **id > after.as_str()))
+                    .take(limit)
+                    .map(|id| UserId::new(*id))
+                    .collect())
+            });
+        repo
+    }
+
+    /// Streak of `current` weeks counted up to the week before the last one.
+    fn streak_missing_last_week(current: u32) -> WasteStreak {
+        let weeks = WasteStreak::default().weeks_to_close(Utc::now());
+        WasteStreak {
+            current,
+            longest: current,
+            last_week: weeks.iter().rev().nth(1).copied(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_publish_milestone_when_streak_reaches_it() {
+        let mut streaks = MockStreakRepo::new();
+        streaks
+            .expect_get()
+            .returning(|_| Ok(streak_missing_last_week(3)));
+        streaks
+            .expect_save()
+            .withf(|_, streak, weeks| streak.current == 4 && weeks.len() == 1)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut stats = MockStatsRepo::new();
+        stats
+            .expect_get_outcomes_by_week()
+            .returning(|_, since, _| {
+                Ok(vec![WeeklyOutcomes {
+                    week: since.date_naive(),
+                    used: 2,
+                    thrown_away: 0,
+                    given_away: 1,
+                }])
+            });
+
+        let mut publisher = MockPublisher::new();
+        publisher
+            .expect_publish()
+            .withf(|event| {
+                matches!(
+                    event,
+                    DomainEvent::StreakMilestoneReached(reached)
+                        if reached.weeks == 4 && reached.user_id.as_str() == "ana"
+                )
+            })
+            .times(1)
+            .returning(|_| ());
+
+        let use_case = RecordWasteStreaksUseCaseImpl {
+            product_repository: Arc::new(users(&["ana"])),
+            stats_repository: Arc::new(stats),
+            streak_repository: Arc::new(streaks),
+            event_publisher: Arc::new(publisher),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordWasteStreaksParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 1);
+        assert_eq!(summary.milestones, 1);
+    }
+
+    #[tokio::test]
+    async fn should_break_streak_without_publishing_when_food_was_thrown_away() {
+        let mut streaks = MockStreakRepo::new();
+        streaks
+            .expect_get()
+            .returning(|_| Ok(streak_missing_last_week(3)));
+        streaks
+            .expect_save()
+            .withf(|_, streak, _| streak.current == 0 && streak.longest == 3)
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut stats = MockStatsRepo::new();
+        stats
+            .expect_get_outcomes_by_week()
+            .returning(|_, since, _| {
+                Ok(vec![WeeklyOutcomes {
+                    week: since.date_naive(),
+                    used: 2,
+                    thrown_away: 1,
+                    given_away: 0,
+                }])
+            });
+
+        let mut publisher = MockPublisher::new();
+        publisher.expect_publish().never();
+
+        let use_case = RecordWasteStreaksUseCaseImpl {
+            product_repository: Arc::new(users(&["ana"])),
+            stats_repository: Arc::new(stats),
+            streak_repository: Arc::new(streaks),
+            event_publisher: Arc::new(publisher),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordWasteStreaksParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 1);
+        assert_eq!(summary.milestones, 0);
+    }
+
+    #[tokio::test]
+    async fn should_keep_going_when_one_user_fails() {
+        let mut streaks = MockStreakRepo::new();
+        streaks.expect_get().returning(|user_id| {
+            if user_id.as_str() == "ana" {
+                Err(RepositoryError::Persistence)
+            } else {
+                Ok(streak_missing_last_week(0))
+            }
+        });
+        streaks
+            .expect_save()
+            .withf(|user_id, _, _| user_id.as_str() == "luis")
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+
+        let mut stats = MockStatsRepo::new();
+        stats
+            .expect_get_outcomes_by_week()
+            .returning(|_, _, _| Ok(vec![]));
+
+        let use_case = RecordWasteStreaksUseCaseImpl {
+            product_repository: Arc::new(users(&["ana", "luis"])),
+            stats_repository: Arc::new(stats),
+            streak_repository: Arc::new(streaks),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordWasteStreaksParams { batch_size: 100 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 1);
+        assert_eq!(summary.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn should_record_every_user_past_the_first_batch() {
+        let mut streaks = MockStreakRepo::new();
+        streaks
+            .expect_get()
+            .returning(|_| Ok(streak_missing_last_week(0)));
+        streaks.expect_save().times(3).returning(|_, _, _| Ok(()));
+
+        let mut stats = MockStatsRepo::new();
+        stats
+            .expect_get_outcomes_by_week()
+            .returning(|_, _, _| Ok(vec![]));
+
+        let use_case = RecordWasteStreaksUseCaseImpl {
+            product_repository: Arc::new(users(&["ana", "luis", "marta"])),
+            stats_repository: Arc::new(stats),
+            streak_repository: Arc::new(streaks),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(RecordWasteStreaksParams { batch_size: 2 })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.recorded, 3);
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::storage::errors::StorageError;
+use crate::domain::storage::model::StoredObject;
+use crate::domain::storage::services::BlobStorage;
+use crate::domain::storage::use_cases::download::{DownloadBlobParams, DownloadBlobUseCase};
+
+pub struct DownloadBlobUseCaseImpl {
+    pub storage: Arc<dyn BlobStorage>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl DownloadBlobUseCase for DownloadBlobUseCaseImpl {
+    async fn execute(&self, params: DownloadBlobParams) -> Result<StoredObject, StorageError> {
+        self.logger
+            .debug(&format!("Downloading blob: {}", params.key));
+
+        self.storage
+            .verify_signature(&params.key, params.expires_at, &params.signature)?;
+
+        self.storage.get(&params.key).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::storage::model::SignedUrl;
+    use mockall::mock;
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn params() -> DownloadBlobParams {
+        DownloadBlobParams {
+            key: "receipts/user-1/abc".to_string(),
+            expires_at: 1_900_000_000,
+            signature: "deadbeef".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_return_object_when_signature_is_valid() {
+        let mut storage = MockStorage::new();
+        storage
+            .expect_verify_signature()
+            .withf(|key, expires_at, signature| {
+                key == "receipts/user-1/abc"
+                    && *expires_at == 1_900_000_000
+                    && signature == "deadbeef"
+            })
+            .returning(|_, _, _| Ok(()));
+        storage.expect_get().returning(|_| {
+            Ok(StoredObject {
+                bytes: vec![1, 2, 3],
+                content_type: "image/jpeg".to_string(),
+            })
+        });
+        let use_case = DownloadBlobUseCaseImpl {
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let object = use_case.execute(params()).await.unwrap();
+
+        assert_eq!(object.bytes, vec![1, 2, 3]);
+        assert_eq!(object.content_type, "image/jpeg");
+    }
+
+    #[tokio::test]
+    async fn should_not_read_object_when_signature_is_invalid() {
+        let mut storage = MockStorage::new();
+        storage
+            .expect_verify_signature()
+            .returning(|_, _, _| Err(StorageError::InvalidSignature));
+        storage.expect_get().never();
+        let use_case = DownloadBlobUseCaseImpl {
+            storage: Arc::new(storage),
+            logger: mock_logger(),
+        };
+
+        let result = use_case.execute(params()).await;
+
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use crate::domain::logger::Logger;
+use crate::domain::storage::errors::StorageError;
+use crate::domain::storage::model::{THUMBNAIL_CONTENT_TYPE, thumbnail_key};
+use crate::domain::storage::services::{BlobStorage, ThumbnailService};
+
+/// Background work run after a photo is stored: shrinks it and stores the
+/// result next to the original.
+pub struct ThumbnailJob {
+    pub storage: Arc<dyn BlobStorage>,
+    pub thumbnailer: Arc<dyn ThumbnailService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl ThumbnailJob {
+    /// Stores the thumbnail of the photo kept under `image_key` and returns
+    /// its key, or `None` if it could not be made; the photo itself stays
+    /// usable either way.
+    pub async fn run(&self, image_key: &str, image: Vec<u8>) -> Option<String> {
+        match self.generate(image_key, image).await {
+            Ok(key) => {
+                self.logger
+                    .debug(&format!("Stored thumbnail of {image_key}"));
+                Some(key)
+            }
+            Err(e) => {
+                self.logger
+                    .warn(&format!("Could not make thumbnail of {image_key}: {e}"));
+                None
+            }
+        }
+    }
+
+    async fn generate(&self, image_key: &str, image: Vec<u8>) -> Result<String, StorageError> {
+        let thumbnailer = self.thumbnailer.clone();
+        let thumbnail = tokio::task::spawn_blocking(move || thumbnailer.thumbnail(&image))
+            .await
+            .map_err(StorageError::unavailable)??;
+
+        let key = thumbnail_key(image_key);
+        self.storage
+            .put(&key, thumbnail, THUMBNAIL_CONTENT_TYPE)
+            .await?;
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::storage::model::{SignedUrl, StoredObject};
+    use async_trait::async_trait;
+    use mockall::mock;
+
+    mock! {
+        pub Storage {}
+
+        #[async_trait]
+        impl BlobStorage for Storage {
+            async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+            async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+            fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+            fn verify_signature(&self, key: &str, expires_at: i64, signature: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    mock! {
+        pub Thumbnailer {}
+
+        impl ThumbnailService for Thumbnailer {
+            fn thumbnail(&self, image: &[u8]) -> Result<Vec<u8>, StorageError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_store_thumbnail_under_its_own_prefix() {
+        let mut thumbnailer = MockThumbnailer::new();
+        thumbnailer
+            .expect_thumbnail()
+            .withf(|image| image == [1, 2, 3])
+            .returning(|_| Ok(vec![9]));
+        let mut storage = MockStorage::new();
+        storage
+            .expect_put()
+            .withf(|key, bytes, content_type| {
+                key == "thumbnails/products/user-1/abc"
+                    && bytes == &vec![9]
+                    && content_type == "image/jpeg"
+            })
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        let job = ThumbnailJob {
+            storage: Arc::new(storage),
+            thumbnailer: Arc::new(thumbnailer),
+            logger: mock_logger(),
+        };
+
+        let key = job.run("products/user-1/abc", vec![1, 2, 3]).await;
+
+        assert_eq!(key.as_deref(), Some("thumbnails/products/user-1/abc"));
+    }
+
+    #[tokio::test]
+    async fn should_store_nothing_when_photo_cannot_be_read() {
+        let mut thumbnailer = MockThumbnailer::new();
+        thumbnailer
+            .expect_thumbnail()
+            .returning(|_| Err(StorageError::UnreadableImage));
+        let mut storage = MockStorage::new();
+        storage.expect_put().never();
+        let job = ThumbnailJob {
+            storage: Arc::new(storage),
+            thumbnailer: Arc::new(thumbnailer),
+            logger: mock_logger(),
+        };
+
+        let key = job.run("products/user-1/abc", vec![1, 2, 3]).await;
+
+        assert!(key.is_none());
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+use crate::domain::store_profile::repository::StoreProfileRepository;
+use crate::domain::store_profile::use_cases::activate::{
+    ActivateStoreProfileParams, ActivateStoreProfileUseCase,
+};
+
+pub struct ActivateStoreProfileUseCaseImpl {
+    pub repository: Arc<dyn StoreProfileRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl ActivateStoreProfileUseCase for ActivateStoreProfileUseCaseImpl {
+    async fn execute(
+        &self,
+        params: ActivateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError> {
+        self.logger
+            .info(&format!("Activating store profile: {}", params.id));
+
+        let mut profile = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => StoreProfileError::NotFound,
+                other => StoreProfileError::Repository(other),
+            })?;
+
+        if !profile.is_active {
+            self.repository
+                .set_active(profile.id, &params.user_id)
+                .await?;
+            profile.is_active = true;
+        }
+
+        self.logger
+            .info(&format!("Store profile activated: {}", profile.id));
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::store_profile::model::Aisle;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_profile(id: Uuid, is_active: bool) -> StoreProfile {
+        StoreProfile::from_repository(
+            id,
+            test_user_id(),
+            "Mercadona".to_string(),
+            vec![Aisle {
+                name: "Fruta".to_string(),
+                keywords: vec!["manzana".to_string()],
+            }],
+            is_active,
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_activate_inactive_profile() {
+        let id = Uuid::new_v4();
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |id, _| Ok(make_profile(id, false)));
+        mock_repo
+            .expect_set_active()
+            .withf(move |active_id, _| *active_id == id)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let use_case = ActivateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ActivateStoreProfileParams {
+                id,
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.unwrap().is_active);
+    }
+
+    #[tokio::test]
+    async fn should_skip_write_when_already_active() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |id, _| Ok(make_profile(id, true)));
+        mock_repo.expect_set_active().never();
+
+        let use_case = ActivateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(ActivateStoreProfileParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
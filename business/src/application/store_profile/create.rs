@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+use crate::domain::store_profile::repository::StoreProfileRepository;
+use crate::domain::store_profile::use_cases::create::{
+    CreateStoreProfileParams, CreateStoreProfileUseCase,
+};
+
+pub struct CreateStoreProfileUseCaseImpl {
+    pub repository: Arc<dyn StoreProfileRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl CreateStoreProfileUseCase for CreateStoreProfileUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CreateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError> {
+        self.logger
+            .info(&format!("Creating store profile: {}", params.name));
+
+        let profile = StoreProfile::new(params.user_id, params.name, params.aisles)?;
+        self.repository.insert(&profile).await?;
+
+        self.logger
+            .info(&format!("Store profile created: {}", profile.id));
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::store_profile::model::Aisle;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_save_inactive_profile_when_valid() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo.expect_insert().times(1).returning(|_| Ok(()));
+
+        let use_case = CreateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateStoreProfileParams {
+                user_id: test_user_id(),
+                name: "Mercadona".to_string(),
+                aisles: vec![Aisle {
+                    name: "Fruta".to_string(),
+                    keywords: vec![],
+                }],
+            })
+            .await;
+
+        let profile = result.unwrap();
+        assert_eq!(profile.aisles.len(), 1);
+        assert!(!profile.is_active);
+    }
+
+    #[tokio::test]
+    async fn should_not_save_when_name_empty() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo.expect_insert().never();
+
+        let use_case = CreateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CreateStoreProfileParams {
+                user_id: test_user_id(),
+                name: " ".to_string(),
+                aisles: vec![],
+            })
+            .await;
+
+        assert!(matches!(result, Err(StoreProfileError::NameEmpty)));
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::repository::StoreProfileRepository;
+use crate::domain::store_profile::use_cases::delete::{
+    DeleteStoreProfileParams, DeleteStoreProfileUseCase,
+};
+
+pub struct DeleteStoreProfileUseCaseImpl {
+    pub repository: Arc<dyn StoreProfileRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl DeleteStoreProfileUseCase for DeleteStoreProfileUseCaseImpl {
+    async fn execute(&self, params: DeleteStoreProfileParams) -> Result<(), StoreProfileError> {
+        self.logger
+            .info(&format!("Deleting store profile: {}", params.id));
+
+        self.repository
+            .delete(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => StoreProfileError::NotFound,
+                other => StoreProfileError::Repository(other),
+            })?;
+
+        self.logger
+            .info(&format!("Store profile deleted: {}", params.id));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::store_profile::model::StoreProfile;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_profile_missing() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_delete()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = DeleteStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(DeleteStoreProfileParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(StoreProfileError::NotFound)));
+    }
+}
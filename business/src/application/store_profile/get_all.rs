@@ -0,0 +1,115 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+use crate::domain::store_profile::repository::StoreProfileRepository;
+use crate::domain::store_profile::use_cases::get_all::{
+    GetAllStoreProfilesParams, GetAllStoreProfilesUseCase,
+};
+
+pub struct GetAllStoreProfilesUseCaseImpl {
+    pub repository: Arc<dyn StoreProfileRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetAllStoreProfilesUseCase for GetAllStoreProfilesUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetAllStoreProfilesParams,
+    ) -> Result<Vec<StoreProfile>, StoreProfileError> {
+        self.logger.info("Getting all store profiles");
+        let profiles = self.repository.get_all(&params.user_id).await?;
+        self.logger
+            .info(&format!("Retrieved {} store profiles", profiles.len()));
+        Ok(profiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::store_profile::model::Aisle;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_profile(id: Uuid, is_active: bool) -> StoreProfile {
+        StoreProfile::from_repository(
+            id,
+            test_user_id(),
+            "Mercadona".to_string(),
+            vec![Aisle {
+                name: "Fruta".to_string(),
+                keywords: vec!["manzana".to_string()],
+            }],
+            is_active,
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_return_user_profiles() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_get_all()
+            .returning(|_| Ok(vec![make_profile(Uuid::new_v4(), true)]));
+
+        let use_case = GetAllStoreProfilesUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetAllStoreProfilesParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::logger::Logger;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+use crate::domain::store_profile::repository::StoreProfileRepository;
+use crate::domain::store_profile::use_cases::update::{
+    UpdateStoreProfileParams, UpdateStoreProfileUseCase,
+};
+
+pub struct UpdateStoreProfileUseCaseImpl {
+    pub repository: Arc<dyn StoreProfileRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl UpdateStoreProfileUseCase for UpdateStoreProfileUseCaseImpl {
+    async fn execute(
+        &self,
+        params: UpdateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError> {
+        self.logger
+            .info(&format!("Updating store profile: {}", params.id));
+
+        let mut profile = self
+            .repository
+            .get_by_id(params.id, &params.user_id)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => StoreProfileError::NotFound,
+                other => StoreProfileError::Repository(other),
+            })?;
+
+        profile.update(params.name, params.aisles)?;
+        self.repository
+            .update(&profile)
+            .await
+            .map_err(|e| match e {
+                RepositoryError::NotFound => StoreProfileError::NotFound,
+                other => StoreProfileError::Repository(other),
+            })?;
+
+        self.logger
+            .info(&format!("Store profile updated: {}", profile.id));
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::store_profile::model::Aisle;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub StoreProfileRepo {}
+
+        #[async_trait]
+        impl StoreProfileRepository for StoreProfileRepo {
+            async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+            async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+            async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn make_profile(id: Uuid, is_active: bool) -> StoreProfile {
+        StoreProfile::from_repository(
+            id,
+            test_user_id(),
+            "Mercadona".to_string(),
+            vec![Aisle {
+                name: "Fruta".to_string(),
+                keywords: vec!["manzana".to_string()],
+            }],
+            is_active,
+            chrono::Utc::now(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_replace_aisles_and_keep_name() {
+        let id = Uuid::new_v4();
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(move |id, _| Ok(make_profile(id, true)));
+        mock_repo.expect_update().times(1).returning(|_| Ok(()));
+
+        let use_case = UpdateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateStoreProfileParams {
+                id,
+                user_id: test_user_id(),
+                name: None,
+                aisles: Some(vec![
+                    Aisle {
+                        name: "Lácteos".to_string(),
+                        keywords: vec![],
+                    },
+                    Aisle {
+                        name: "Fruta".to_string(),
+                        keywords: vec![],
+                    },
+                ]),
+            })
+            .await;
+
+        let profile = result.unwrap();
+        assert_eq!(profile.name, "Mercadona");
+        assert_eq!(profile.aisles[0].name, "Lácteos");
+        assert!(profile.is_active);
+    }
+
+    #[tokio::test]
+    async fn should_return_not_found_when_profile_missing() {
+        let mut mock_repo = MockStoreProfileRepo::new();
+        mock_repo
+            .expect_get_by_id()
+            .returning(|_, _| Err(RepositoryError::NotFound));
+
+        let use_case = UpdateStoreProfileUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(UpdateStoreProfileParams {
+                id: Uuid::new_v4(),
+                user_id: test_user_id(),
+                name: Some("Lidl".to_string()),
+                aisles: None,
+            })
+            .await;
+
+        assert!(matches!(result, Err(StoreProfileError::NotFound)));
+    }
+}
@@ -1,12 +1,22 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
 
+use crate::application::experiment::ai_experiment::AiExperiment;
 use crate::domain::logger::Logger;
-use crate::domain::product::repository::ProductRepository;
-use crate::domain::product::urgency::{UrgencyLevel, get_urgency_level, is_expired};
+use crate::domain::meal_plan::repository::MealPlanRepository;
+use crate::domain::metrics::Metrics;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::{ProductEnrichmentRepository, ProductRepository};
+use crate::domain::quota::services::QuotaService;
 use crate::domain::suggestion::errors::SuggestionError;
+use crate::domain::suggestion::ingredient_validation::validate_against_pantry;
 use crate::domain::suggestion::model::Suggestion;
+use crate::domain::suggestion::prioritized_pantry::{PantryPromptLimits, prioritize_pantry};
+use crate::domain::suggestion::repository::SuggestionRepository;
 use crate::domain::suggestion::services::SuggestionGeneratorService;
 use crate::domain::suggestion::use_cases::generate::{
     GenerateSuggestionsParams, GenerateSuggestionsUseCase,
@@ -14,7 +24,17 @@ use crate::domain::suggestion::use_cases::generate::{
 
 pub struct GenerateSuggestionsUseCaseImpl {
     pub repository: Arc<dyn ProductRepository>,
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub enrichment_repository: Arc<dyn ProductEnrichmentRepository>,
+    /// Products reserved for planned meals are left out of new suggestions
+    pub meal_plan_repository: Arc<dyn MealPlanRepository>,
     pub generator: Arc<dyn SuggestionGeneratorService>,
+    /// Experiment on suggestions, with its variants' generators
+    pub experiment: Arc<AiExperiment<dyn SuggestionGeneratorService>>,
+    pub quota_service: Arc<dyn QuotaService>,
+    pub pantry_limits: PantryPromptLimits,
+    pub metrics: Arc<dyn Metrics>,
     pub logger: Arc<dyn Logger>,
 }
 
@@ -29,35 +49,135 @@ impl GenerateSuggestionsUseCase for GenerateSuggestionsUseCaseImpl {
             params.limit
         ));
 
-        let products = self
+        // Serve today's cached batch unless the caller explicitly asks for a refresh
+        if !params.refresh {
+            match self
+                .suggestion_repository
+                .get_latest_batch(&params.user_id)
+                .await
+            {
+                Ok(Some(batch)) if batch.is_fresh(Utc::now()) && !batch.suggestions.is_empty() => {
+                    self.logger.info(&format!(
+                        "Serving {} cached suggestions generated at {}",
+                        batch.suggestions.len(),
+                        batch.generated_at
+                    ));
+                    return Ok(batch.suggestions.into_iter().take(params.limit).collect());
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    self.logger
+                        .warn(&format!("Failed to read cached suggestions: {}", e));
+                }
+            }
+        }
+
+        let mut products = self
             .repository
-            .get_active_products(&params.user_id)
+            .find(&ProductQuery::active(params.user_id.clone()))
             .await
             .map_err(|_| SuggestionError::GenerationFailed)?;
 
-        // Filter out expired products
-        let mut usable: Vec<_> = products.into_iter().filter(|p| !is_expired(p)).collect();
+        // Products set aside for a planned meal are already spoken for
+        match self
+            .meal_plan_repository
+            .get_reserved_product_ids(&params.user_id)
+            .await
+        {
+            Ok(reserved) => products.retain(|p| !reserved.contains(&p.id)),
+            Err(e) => self
+                .logger
+                .warn(&format!("Failed to read reserved products: {}", e)),
+        }
+
+        let preferences = self.preference_repository.get(&params.user_id).await?;
+
+        // Never suggest cooking with something the user is allergic to
+        if !preferences.allergies.is_empty() {
+            let product_ids: Vec<Uuid> = products.iter().map(|p| p.id).collect();
+            let enrichments = self
+                .enrichment_repository
+                .find_for_products(&params.user_id, &product_ids)
+                .await
+                .map_err(|_| SuggestionError::GenerationFailed)?;
+            products.retain(|p| {
+                enrichments
+                    .get(&p.id)
+                    .is_none_or(|e| e.allergens_among(&preferences.allergies).is_empty())
+            });
+        }
+
+        // Drop expired products, rank the rest and keep the prompt bounded
+        let pantry = prioritize_pantry(products, &self.pantry_limits, preferences.expiring_soon);
+
+        if pantry.is_empty() {
+            return Err(SuggestionError::EmptyPantry);
+        }
+
+        if pantry.omitted > 0 {
+            self.logger.info(&format!(
+                "Prompt lists {} products, {} left out",
+                pantry.items.len(),
+                pantry.omitted
+            ));
+        }
 
-        if usable.is_empty() {
-            return Ok(vec![]);
+        if params.metered {
+            self.quota_service.consume_ai_call(&params.user_id).await?;
         }
 
-        // Sort by urgency: most urgent first
-        usable.sort_by(|a, b| {
-            let urgency_order = |level: &UrgencyLevel| -> u8 {
-                match level {
-                    UrgencyLevel::UseToday => 0,
-                    UrgencyLevel::UseSoon => 1,
-                    UrgencyLevel::Ok => 2,
-                    UrgencyLevel::WouldntTrust => 3,
+        let assignment = self.experiment.assign(&params.user_id);
+        let generator = assignment
+            .as_ref()
+            .and_then(|a| a.service)
+            .unwrap_or(&self.generator);
+        let suggestions = generator
+            .generate(&pantry, params.limit)
+            .await
+            .inspect_err(|e| {
+                if let SuggestionError::ParseFailed { raw } = e {
+                    let preview: String = raw.chars().take(500).collect();
+                    self.logger
+                        .warn(&format!("Unreadable suggestion response: {}", preview));
                 }
-            };
-            let a_urgency = urgency_order(&get_urgency_level(a));
-            let b_urgency = urgency_order(&get_urgency_level(b));
-            a_urgency.cmp(&b_urgency)
-        });
+            })?;
 
-        let suggestions = self.generator.generate(&usable, params.limit).await?;
+        // The model may invent product ids; keep only what's really in the pantry
+        let validation = validate_against_pantry(suggestions, &pantry);
+        if validation.remapped > 0 {
+            self.metrics.increment(
+                "suggestion.ingredients.remapped",
+                validation.remapped as u64,
+            );
+        }
+        if !validation.hallucinated.is_empty() {
+            self.logger.warn(&format!(
+                "Dropped ingredients not in the pantry: {}",
+                validation.hallucinated.join(", ")
+            ));
+            self.metrics.increment(
+                "suggestion.ingredients.hallucinated",
+                validation.hallucinated.len() as u64,
+            );
+        }
+        let suggestions = validation.suggestions;
+
+        // Cooking one of them later counts as a success for the variant
+        if let Some(assignment) = &assignment {
+            let ids = suggestions.iter().map(|s| s.id.clone()).collect();
+            self.experiment
+                .record(&params.user_id, assignment.variant, ids)
+                .await;
+        }
+
+        if let Err(e) = self
+            .suggestion_repository
+            .save_batch(&params.user_id, &suggestions)
+            .await
+        {
+            self.logger
+                .warn(&format!("Failed to cache generated suggestions: {}", e));
+        }
 
         self.logger
             .info(&format!("Generated {} suggestions", suggestions.len()));
@@ -70,24 +190,39 @@ impl GenerateSuggestionsUseCase for GenerateSuggestionsUseCaseImpl {
 mod tests {
     use super::*;
     use crate::domain::errors::RepositoryError;
+    use crate::domain::experiment::model::{AiCall, AiFeature, Experiment, Variant, VariantResult};
+    use crate::domain::experiment::repository::AiCallRepository;
+    use crate::domain::meal_plan::model::PlannedMeal;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::product::enrichment::{Allergen, NutritionFacts, ProductEnrichment};
     use crate::domain::product::model::Product;
-    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::Usage;
+    use crate::domain::shared::pagination::KeysetPage;
     use crate::domain::shared::value_objects::UserId;
-    use crate::domain::suggestion::model::{Suggestion, SuggestionIngredient, TimeRange};
+    use crate::domain::suggestion::model::{
+        Suggestion, SuggestionBatch, SuggestionIngredient, TimeRange,
+    };
+    use crate::domain::suggestion::prioritized_pantry::PrioritizedPantry;
     use chrono::{Duration, Utc};
     use mockall::mock;
-    use uuid::Uuid;
+    use std::collections::{HashMap, HashSet};
 
     mock! {
         pub ProductRepo {}
 
         #[async_trait]
         impl ProductRepository for ProductRepo {
-            async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
             async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-            async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
             async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-            async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
         }
     }
 
@@ -98,12 +233,80 @@ mod tests {
         impl SuggestionGeneratorService for SuggestionGenerator {
             async fn generate(
                 &self,
-                products: &[Product],
+                pantry: &PrioritizedPantry,
                 limit: usize,
             ) -> Result<Vec<Suggestion>, SuggestionError>;
         }
     }
 
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    mock! {
+        pub EnrichmentRepo {}
+
+        #[async_trait]
+        impl ProductEnrichmentRepository for EnrichmentRepo {
+            async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+            async fn link(&self, product_id: Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+            async fn find_for_products(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub MealPlanRepo {}
+
+        #[async_trait]
+        impl MealPlanRepository for MealPlanRepo {
+            async fn find_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<PlannedMeal>, RepositoryError>;
+            async fn get_reserved_product_ids(&self, user_id: &UserId) -> Result<HashSet<Uuid>, RepositoryError>;
+            async fn insert(&self, meal: &PlannedMeal) -> Result<(), RepositoryError>;
+            async fn delete_expired_reservations(&self, now: chrono::DateTime<Utc>) -> Result<u64, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub AiCallRepo {}
+
+        #[async_trait]
+        impl AiCallRepository for AiCallRepo {
+            async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+            async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+            async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+            async fn get_results(&self, since: chrono::DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+        }
+    }
+
     mock! {
         pub Log {}
 
@@ -115,6 +318,35 @@ mod tests {
         }
     }
 
+    mock! {
+        pub MetricsRecorder {}
+
+        impl Metrics for MetricsRecorder {
+            fn increment(&self, name: &str, value: u64);
+        }
+    }
+
+    fn default_preferences() -> Arc<dyn PreferenceRepository> {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        Arc::new(repo)
+    }
+
+    fn no_enrichment() -> Arc<dyn ProductEnrichmentRepository> {
+        let mut repo = MockEnrichmentRepo::new();
+        repo.expect_find_for_products()
+            .returning(|_, _| Ok(HashMap::new()));
+        Arc::new(repo)
+    }
+
+    fn no_reservations() -> Arc<dyn MealPlanRepository> {
+        let mut repo = MockMealPlanRepo::new();
+        repo.expect_get_reserved_product_ids()
+            .returning(|_| Ok(HashSet::new()));
+        Arc::new(repo)
+    }
+
     fn mock_logger() -> Arc<dyn Logger> {
         let mut logger = MockLog::new();
         logger.expect_info().returning(|_| ());
@@ -124,6 +356,33 @@ mod tests {
         Arc::new(logger)
     }
 
+    fn no_experiment() -> Arc<AiExperiment<dyn SuggestionGeneratorService>> {
+        Arc::new(AiExperiment::inactive(
+            Arc::new(MockAiCallRepo::new()),
+            mock_logger(),
+        ))
+    }
+
+    fn mock_metrics() -> Arc<dyn Metrics> {
+        let mut metrics = MockMetricsRecorder::new();
+        metrics.expect_increment().returning(|_, _| ());
+        Arc::new(metrics)
+    }
+
+    fn unlimited_quota() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_consume_ai_call().returning(|_| Ok(()));
+        quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+        Arc::new(quota)
+    }
+
+    fn mock_suggestion_repository() -> Arc<dyn SuggestionRepository> {
+        let mut repo = MockSuggestionRepo::new();
+        repo.expect_get_latest_batch().returning(|_| Ok(None));
+        repo.expect_save_batch().returning(|_, _| Ok(()));
+        Arc::new(repo)
+    }
+
     fn test_user_id() -> UserId {
         UserId::new("test-user-id")
     }
@@ -138,6 +397,7 @@ mod tests {
             None,
             Some(Utc::now() + Duration::days(days)),
             None,
+            ExpiryType::None,
             None,
             Utc::now(),
             Utc::now(),
@@ -154,6 +414,7 @@ mod tests {
             None,
             Some(Utc::now() - Duration::days(2)),
             None,
+            ExpiryType::None,
             None,
             Utc::now(),
             Utc::now(),
@@ -181,7 +442,7 @@ mod tests {
     #[tokio::test]
     async fn should_return_suggestions_when_products_available() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_get_active_products().returning(|_| {
+        mock_repo.expect_find().returning(|_| {
             Ok(vec![
                 product_expiring_in("Chicken breast", 1),
                 product_expiring_in("Rice", 30),
@@ -195,7 +456,15 @@ mod tests {
 
         let use_case = GenerateSuggestionsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
             generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
             logger: mock_logger(),
         };
 
@@ -203,6 +472,8 @@ mod tests {
             .execute(GenerateSuggestionsParams {
                 user_id: test_user_id(),
                 limit: 5,
+                refresh: false,
+                metered: true,
             })
             .await;
 
@@ -211,17 +482,89 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_return_empty_when_no_active_products() {
+    async fn should_record_the_variant_that_generated_the_suggestions() {
         let mut mock_repo = MockProductRepo::new();
         mock_repo
-            .expect_get_active_products()
-            .returning(|_| Ok(vec![]));
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Chicken breast", 1)]));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .times(1)
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let mut calls = MockAiCallRepo::new();
+        calls
+            .expect_insert()
+            .withf(|call| {
+                call.feature == AiFeature::Suggestions
+                    && call.variant == "control"
+                    && call.subject_ids == vec!["test-1".to_string()]
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // The control runs the regular generator, so it has no service of its own
+        let experiment = AiExperiment {
+            experiment: Some(Experiment {
+                feature: AiFeature::Suggestions,
+                variants: vec![Variant {
+                    name: "control".to_string(),
+                    weight: 1,
+                    model: None,
+                    prompt_version: None,
+                }],
+            }),
+            variant_services: HashMap::new(),
+            ai_call_repository: Arc::new(calls),
+            logger: mock_logger(),
+        };
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: Arc::new(experiment),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: true,
+                metered: true,
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_return_empty_pantry_error_when_no_active_products() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().returning(|_| Ok(vec![]));
 
         let mock_generator = MockSuggestionGenerator::new();
 
         let use_case = GenerateSuggestionsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
             generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
             logger: mock_logger(),
         };
 
@@ -229,17 +572,18 @@ mod tests {
             .execute(GenerateSuggestionsParams {
                 user_id: test_user_id(),
                 limit: 5,
+                refresh: false,
+                metered: true,
             })
             .await;
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(matches!(result, Err(SuggestionError::EmptyPantry)));
     }
 
     #[tokio::test]
     async fn should_filter_out_expired_products_before_generating() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_get_active_products().returning(|_| {
+        mock_repo.expect_find().returning(|_| {
             Ok(vec![
                 expired_product("Old yogurt"),
                 product_expiring_in("Fresh milk", 2),
@@ -249,15 +593,23 @@ mod tests {
         let mut mock_generator = MockSuggestionGenerator::new();
         mock_generator
             .expect_generate()
-            .withf(|products, _| {
+            .withf(|pantry, _| {
                 // Only the non-expired product should be passed
-                products.len() == 1 && products[0].name == "Fresh milk"
+                pantry.items.len() == 1 && pantry.items[0].product.name == "Fresh milk"
             })
             .returning(|_, _| Ok(vec![sample_suggestion()]));
 
         let use_case = GenerateSuggestionsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
             generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
             logger: mock_logger(),
         };
 
@@ -265,6 +617,8 @@ mod tests {
             .execute(GenerateSuggestionsParams {
                 user_id: test_user_id(),
                 limit: 5,
+                refresh: false,
+                metered: true,
             })
             .await;
 
@@ -272,9 +626,9 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn should_return_empty_when_all_products_expired() {
+    async fn should_return_empty_pantry_error_when_all_products_expired() {
         let mut mock_repo = MockProductRepo::new();
-        mock_repo.expect_get_active_products().returning(|_| {
+        mock_repo.expect_find().returning(|_| {
             Ok(vec![
                 expired_product("Old yogurt"),
                 expired_product("Expired milk"),
@@ -285,7 +639,15 @@ mod tests {
 
         let use_case = GenerateSuggestionsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
             generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
             logger: mock_logger(),
         };
 
@@ -293,25 +655,34 @@ mod tests {
             .execute(GenerateSuggestionsParams {
                 user_id: test_user_id(),
                 limit: 5,
+                refresh: false,
+                metered: true,
             })
             .await;
 
-        assert!(result.is_ok());
-        assert!(result.unwrap().is_empty());
+        assert!(matches!(result, Err(SuggestionError::EmptyPantry)));
     }
 
     #[tokio::test]
     async fn should_return_error_when_repository_fails() {
         let mut mock_repo = MockProductRepo::new();
         mock_repo
-            .expect_get_active_products()
+            .expect_find()
             .returning(|_| Err(RepositoryError::Persistence));
 
         let mock_generator = MockSuggestionGenerator::new();
 
         let use_case = GenerateSuggestionsUseCaseImpl {
             repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
             generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
             logger: mock_logger(),
         };
 
@@ -319,6 +690,8 @@ mod tests {
             .execute(GenerateSuggestionsParams {
                 user_id: test_user_id(),
                 limit: 5,
+                refresh: false,
+                metered: true,
             })
             .await;
 
@@ -328,4 +701,465 @@ mod tests {
             SuggestionError::GenerationFailed
         ));
     }
+
+    #[tokio::test]
+    async fn should_serve_cached_batch_when_generated_today() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().never();
+
+        let mut mock_suggestion_repo = MockSuggestionRepo::new();
+        mock_suggestion_repo
+            .expect_get_latest_batch()
+            .returning(|_| {
+                Ok(Some(SuggestionBatch {
+                    id: Uuid::new_v4(),
+                    user_id: test_user_id(),
+                    suggestions: vec![sample_suggestion(), sample_suggestion()],
+                    generated_at: Utc::now(),
+                }))
+            });
+        mock_suggestion_repo.expect_save_batch().never();
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator.expect_generate().never();
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestion_repo),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 1,
+                refresh: false,
+                metered: true,
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_regenerate_when_refresh_requested_even_if_cache_is_fresh() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Chicken breast", 1)]));
+
+        let mut mock_suggestion_repo = MockSuggestionRepo::new();
+        mock_suggestion_repo.expect_get_latest_batch().never();
+        mock_suggestion_repo
+            .expect_save_batch()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .times(1)
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestion_repo),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: true,
+                metered: true,
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_regenerate_when_cached_batch_is_from_a_previous_day() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Spinach", 1)]));
+
+        let mut mock_suggestion_repo = MockSuggestionRepo::new();
+        mock_suggestion_repo
+            .expect_get_latest_batch()
+            .returning(|_| {
+                Ok(Some(SuggestionBatch {
+                    id: Uuid::new_v4(),
+                    user_id: test_user_id(),
+                    suggestions: vec![sample_suggestion()],
+                    generated_at: Utc::now() - Duration::days(2),
+                }))
+            });
+        mock_suggestion_repo
+            .expect_save_batch()
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .times(1)
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: Arc::new(mock_suggestion_repo),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: false,
+                metered: true,
+            })
+            .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_return_quota_error_when_daily_ai_calls_exceeded() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Arroz", 30)]));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator.expect_generate().never();
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota
+            .expect_consume_ai_call()
+            .returning(|_| Err(QuotaError::AiCallsExceeded));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: Arc::new(mock_quota),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: true,
+                metered: true,
+            })
+            .await;
+
+        assert!(matches!(
+            result.unwrap_err(),
+            SuggestionError::Quota(QuotaError::AiCallsExceeded)
+        ));
+    }
+
+    #[tokio::test]
+    async fn should_not_consume_quota_when_unmetered() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Chicken breast", 30)]));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let mut mock_quota = MockQuota::new();
+        mock_quota.expect_consume_ai_call().never();
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: Arc::new(mock_quota),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: true,
+                metered: false,
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_send_only_most_urgent_products_when_pantry_exceeds_limit() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo.expect_find().returning(|_| {
+            Ok(vec![
+                product_expiring_in("Arroz", 60),
+                product_expiring_in("Chicken", 1),
+                product_expiring_in("Lentejas", 90),
+                product_expiring_in("Yogur", 2),
+                product_expiring_in("Chicken", 3),
+            ])
+        });
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .withf(|pantry, _| {
+                let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+                names == vec!["Chicken", "Yogur"]
+                    && pantry.items[0].count == 2
+                    && pantry.omitted == 2
+            })
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits {
+                max_products: 2,
+                ..PantryPromptLimits::default()
+            },
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: false,
+                metered: true,
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_drop_ingredients_not_in_pantry_and_record_metric() {
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(|_| Ok(vec![product_expiring_in("Chicken breast", 1)]));
+
+        let mut hallucinated = sample_suggestion();
+        hallucinated.ingredients[0].product_id = "p-99".to_string();
+        hallucinated.ingredients[0].product_name = "Gambas".to_string();
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .returning(move |_, _| Ok(vec![sample_suggestion(), hallucinated.clone()]));
+
+        let mut mock_metrics = MockMetricsRecorder::new();
+        mock_metrics
+            .expect_increment()
+            .withf(|name, value| name == "suggestion.ingredients.hallucinated" && *value == 1)
+            .times(1)
+            .returning(|_, _| ());
+        mock_metrics
+            .expect_increment()
+            .withf(|name, _| name == "suggestion.ingredients.remapped")
+            .returning(|_, _| ());
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: Arc::new(mock_metrics),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: false,
+                metered: true,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].ingredients[0].product_name, "Chicken");
+    }
+
+    #[tokio::test]
+    async fn should_leave_out_products_with_declared_allergies() {
+        let cheese = product_expiring_in("Queso", 1);
+        let cheese_id = cheese.id;
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(move |_| Ok(vec![cheese.clone(), product_expiring_in("Chicken", 2)]));
+
+        let mut mock_preferences = MockPreferenceRepo::new();
+        mock_preferences.expect_get().returning(|_| {
+            Ok(UserPreferences {
+                allergies: vec![Allergen::Milk],
+                ..UserPreferences::default()
+            })
+        });
+
+        let mut mock_enrichment = MockEnrichmentRepo::new();
+        mock_enrichment
+            .expect_find_for_products()
+            .returning(move |_, _| {
+                let enrichment = ProductEnrichment::new(
+                    "8410000810004".to_string(),
+                    None,
+                    None,
+                    NutritionFacts::default(),
+                    vec![Allergen::Milk],
+                )
+                .unwrap();
+                Ok(HashMap::from([(cheese_id, enrichment)]))
+            });
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .withf(|pantry, _| {
+                let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+                names == vec!["Chicken"]
+            })
+            .times(1)
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: Arc::new(mock_preferences),
+            enrichment_repository: Arc::new(mock_enrichment),
+            meal_plan_repository: no_reservations(),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: false,
+                metered: true,
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn should_leave_out_products_reserved_for_planned_meals() {
+        let reserved = product_expiring_in("Eggs", 1);
+        let reserved_id = reserved.id;
+        let mut mock_repo = MockProductRepo::new();
+        mock_repo
+            .expect_find()
+            .returning(move |_| Ok(vec![reserved.clone(), product_expiring_in("Chicken", 2)]));
+
+        let mut meal_plans = MockMealPlanRepo::new();
+        meal_plans
+            .expect_get_reserved_product_ids()
+            .returning(move |_| Ok(HashSet::from([reserved_id])));
+
+        let mut mock_generator = MockSuggestionGenerator::new();
+        mock_generator
+            .expect_generate()
+            .withf(|pantry, _| {
+                let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+                names == vec!["Chicken"]
+            })
+            .times(1)
+            .returning(|_, _| Ok(vec![sample_suggestion()]));
+
+        let use_case = GenerateSuggestionsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            suggestion_repository: mock_suggestion_repository(),
+            preference_repository: default_preferences(),
+            enrichment_repository: no_enrichment(),
+            meal_plan_repository: Arc::new(meal_plans),
+            generator: Arc::new(mock_generator),
+            experiment: no_experiment(),
+            quota_service: unlimited_quota(),
+            pantry_limits: PantryPromptLimits::default(),
+            metrics: mock_metrics(),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GenerateSuggestionsParams {
+                user_id: test_user_id(),
+                limit: 5,
+                refresh: false,
+                metered: true,
+            })
+            .await;
+
+        assert_eq!(result.unwrap().len(), 1);
+    }
 }
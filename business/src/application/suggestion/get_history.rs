@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::shared::pagination::{Cursor, CursorPage};
+use crate::domain::suggestion::errors::SuggestionError;
+use crate::domain::suggestion::model::SuggestionBatch;
+use crate::domain::suggestion::repository::SuggestionRepository;
+use crate::domain::suggestion::use_cases::get_history::{
+    GetSuggestionHistoryParams, GetSuggestionHistoryUseCase,
+};
+
+pub struct GetSuggestionHistoryUseCaseImpl {
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetSuggestionHistoryUseCase for GetSuggestionHistoryUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetSuggestionHistoryParams,
+    ) -> Result<CursorPage<SuggestionBatch>, SuggestionError> {
+        self.logger.info("Fetching suggestion history");
+
+        let rows = self
+            .suggestion_repository
+            .get_batches(&params.user_id, &params.page)
+            .await?;
+        let page = CursorPage::from_rows(rows, &params.page, |batch| {
+            Cursor::new(batch.generated_at, batch.id)
+        });
+
+        self.logger.info(&format!(
+            "Found {} suggestion batches (more: {})",
+            page.items.len(),
+            page.next_cursor.is_some()
+        ));
+        Ok(page)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::suggestion::model::Suggestion;
+    use chrono::{Duration, Utc};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn batch(days_ago: i64) -> SuggestionBatch {
+        SuggestionBatch {
+            id: Uuid::new_v4(),
+            user_id: test_user_id(),
+            suggestions: vec![],
+            generated_at: Utc::now() - Duration::days(days_ago),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_page_batches_with_cursor_of_last_returned_batch() {
+        let rows = vec![batch(0), batch(1), batch(2)];
+        let second = Cursor::new(rows[1].generated_at, rows[1].id);
+        let mut repo = MockSuggestionRepo::new();
+        repo.expect_get_batches()
+            .withf(|user_id, page| *user_id == test_user_id() && page.fetch_limit() == 3)
+            .times(1)
+            .returning(move |_, _| Ok(rows.clone()));
+        let use_case = GetSuggestionHistoryUseCaseImpl {
+            suggestion_repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let page = use_case
+            .execute(GetSuggestionHistoryParams {
+                user_id: test_user_id(),
+                page: KeysetPage::new(2, None),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(page.items.len(), 2);
+        assert_eq!(page.next_cursor, Some(second));
+    }
+
+    #[tokio::test]
+    async fn should_surface_repository_errors() {
+        let mut repo = MockSuggestionRepo::new();
+        repo.expect_get_batches()
+            .returning(|_, _| Err(RepositoryError::database_error("connection reset")));
+        let use_case = GetSuggestionHistoryUseCaseImpl {
+            suggestion_repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetSuggestionHistoryParams {
+                user_id: test_user_id(),
+                page: KeysetPage::new(20, None),
+            })
+            .await;
+
+        assert!(matches!(result, Err(SuggestionError::Repository(_))));
+    }
+}
@@ -0,0 +1,521 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::quota::services::QuotaService;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::suggestion::errors::SuggestionError;
+use crate::domain::suggestion::repository::SuggestionRepository;
+use crate::domain::suggestion::use_cases::generate::{
+    GenerateSuggestionsParams, GenerateSuggestionsUseCase,
+};
+use crate::domain::suggestion::use_cases::pregenerate::{
+    PregenerateSuggestionsParams, PregenerateSuggestionsUseCase, PregenerationSummary,
+};
+
+/// Users loaded at a time while walking those with active products.
+const USER_PAGE_SIZE: usize = 500;
+
+pub struct PregenerateSuggestionsUseCaseImpl {
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub suggestion_repository: Arc<dyn SuggestionRepository>,
+    pub generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+    /// Generations aren't metered, but users out of AI calls get none.
+    pub quota_service: Arc<dyn QuotaService>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl PregenerateSuggestionsUseCase for PregenerateSuggestionsUseCaseImpl {
+    async fn execute(
+        &self,
+        params: PregenerateSuggestionsParams,
+    ) -> Result<PregenerationSummary, SuggestionError> {
+        self.logger.info("Starting suggestion pre-generation run");
+
+        let mut summary = PregenerationSummary::default();
+        let now = Utc::now();
+        let mut attempted = 0;
+        let mut after: Option<UserId> = None;
+
+        'users: loop {
+            let users = self
+                .product_repository
+                .get_users_with_active_products(after.clone(), USER_PAGE_SIZE)
+                .await
+                .map_err(|_| SuggestionError::GenerationFailed)?;
+            let Some(last) = users.last().cloned() else {
+                break;
+            };
+
+            for user_id in users {
+                // Business rule: at most one AI generation per user per day from the job
+                if let Ok(Some(batch)) = self.suggestion_repository.get_latest_batch(&user_id).await
+                    && batch.is_fresh(now)
+                {
+                    summary.skipped += 1;
+                    continue;
+                }
+
+                match self.quota_service.get_usage(&user_id).await {
+                    Ok(usage) if usage.has_ai_calls_left() => {}
+                    Ok(_) => {
+                        summary.skipped += 1;
+                        continue;
+                    }
+                    Err(e) => {
+                        self.logger.warn(&format!(
+                            "Could not check AI quota for user {}: {}",
+                            user_id, e
+                        ));
+                        summary.failed += 1;
+                        continue;
+                    }
+                }
+
+                // The cap bounds AI calls, so only generations count towards it
+                if attempted == params.max_users {
+                    self.logger.warn(&format!(
+                        "Suggestion pre-generation stopped at its cap of {} users; the rest wait for the next run",
+                        params.max_users
+                    ));
+                    break 'users;
+                }
+                attempted += 1;
+
+                match self
+                    .generate_use_case
+                    .execute(GenerateSuggestionsParams {
+                        user_id: user_id.clone(),
+                        limit: params.limit,
+                        refresh: true,
+                        metered: false,
+                    })
+                    .await
+                {
+                    Ok(_) => summary.generated += 1,
+                    // Everything the user has is expired: nothing to suggest, not a failure
+                    Err(SuggestionError::EmptyPantry) => summary.skipped += 1,
+                    Err(e) => {
+                        self.logger.warn(&format!(
+                            "Suggestion pre-generation failed for user {}: {}",
+                            user_id, e
+                        ));
+                        summary.failed += 1;
+                    }
+                }
+            }
+
+            after = Some(last);
+        }
+
+        self.logger.info(&format!(
+            "Suggestion pre-generation finished: generated={}, skipped={}, failed={}",
+            summary.generated, summary.skipped, summary.failed
+        ));
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::query::ProductQuery;
+    use crate::domain::quota::errors::QuotaError;
+    use crate::domain::quota::model::{FREE_AI_CALLS_PER_DAY, PlanTier, Usage};
+    use crate::domain::shared::pagination::KeysetPage;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::suggestion::model::{Suggestion, SuggestionBatch};
+    use chrono::Duration;
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SuggestionRepo {}
+
+        #[async_trait]
+        impl SuggestionRepository for SuggestionRepo {
+            async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+            async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+            async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+            async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub GenerateUseCase {}
+
+        #[async_trait]
+        impl GenerateSuggestionsUseCase for GenerateUseCase {
+            async fn execute(
+                &self,
+                params: GenerateSuggestionsParams,
+            ) -> Result<Vec<Suggestion>, SuggestionError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    mock! {
+        pub Quota {}
+
+        #[async_trait]
+        impl QuotaService for Quota {
+            async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+            async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+        }
+    }
+
+    fn usage(ai_calls_today: u32) -> Usage {
+        Usage {
+            plan: PlanTier::Free,
+            limits: PlanTier::Free.limits(),
+            ai_calls_today,
+            products: 0,
+        }
+    }
+
+    fn quota_left() -> Arc<dyn QuotaService> {
+        let mut quota = MockQuota::new();
+        quota.expect_get_usage().returning(|_| Ok(usage(0)));
+        Arc::new(quota)
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    /// Pages through `ids` like the repository does.
+    fn active_users(ids: &'static [&'static str]) -> MockProductRepo {
+        let mut repo = MockProductRepo::new();
+        repo.expect_get_users_with_active_products()
+            .returning(move |after, limit| {
+                Ok(ids
+                    .iter()
+                    .filter(|id| after.as_ref().is_none_or(|after| **id > after.as_str()))
+                    .take(limit)
+                    .map(|id| UserId::new(*id))
+                    .collect())
+            });
+        repo
+    }
+
+    fn batch_generated_at(user: &str, generated_at: chrono::DateTime<Utc>) -> SuggestionBatch {
+        SuggestionBatch {
+            id: Uuid::new_v4(),
+            user_id: UserId::new(user),
+            suggestions: vec![],
+            generated_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_generate_for_every_active_user_without_fresh_batch() {
+        let mock_products = active_users(&["ana", "luis"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .withf(|params| params.refresh)
+            .times(2)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 100,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 2);
+        assert_eq!(summary.skipped, 0);
+    }
+
+    #[tokio::test]
+    async fn should_skip_users_whose_batch_was_generated_today() {
+        let mock_products = active_users(&["ana", "luis"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|user_id| {
+                if user_id.as_str() == "ana" {
+                    Ok(Some(batch_generated_at("ana", Utc::now())))
+                } else {
+                    Ok(Some(batch_generated_at(
+                        "luis",
+                        Utc::now() - Duration::days(1),
+                    )))
+                }
+            });
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .withf(|params| params.user_id.as_str() == "luis")
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 100,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 1);
+        assert_eq!(summary.skipped, 1);
+    }
+
+    #[tokio::test]
+    async fn should_continue_with_other_users_when_one_generation_fails() {
+        let mock_products = active_users(&["ana", "luis"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate.expect_execute().returning(|params| {
+            if params.user_id.as_str() == "ana" {
+                Err(SuggestionError::GenerationFailed)
+            } else {
+                Ok(vec![])
+            }
+        });
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 100,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 1);
+        assert_eq!(summary.failed, 1);
+    }
+
+    #[tokio::test]
+    async fn should_cap_users_processed_per_run() {
+        let mock_products = active_users(&["ana", "luis", "marta"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .times(2)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 2,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 2);
+    }
+
+    #[tokio::test]
+    async fn should_not_count_fresh_users_against_the_cap() {
+        let mock_products = active_users(&["ana", "luis", "marta", "pablo"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|user_id| {
+                Ok(matches!(user_id.as_str(), "ana" | "luis")
+                    .then(|| batch_generated_at(user_id.as_str(), Utc::now())))
+            });
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .withf(|params| matches!(params.user_id.as_str(), "marta" | "pablo"))
+            .times(2)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 2,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 2);
+        assert_eq!(summary.skipped, 2);
+    }
+
+    #[tokio::test]
+    async fn should_skip_user_when_pantry_is_empty() {
+        let mock_products = active_users(&["ana"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .returning(|_| Err(SuggestionError::EmptyPantry));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: quota_left(),
+            logger: mock_logger(),
+        };
+
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 100,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.skipped, 1);
+        assert_eq!(summary.failed, 0);
+    }
+
+    #[tokio::test]
+    async fn should_skip_users_without_ai_calls_left() {
+        let mock_products = active_users(&["ana", "luis"]);
+
+        let mut mock_suggestions = MockSuggestionRepo::new();
+        mock_suggestions
+            .expect_get_latest_batch()
+            .returning(|_| Ok(None));
+
+        let mut quota = MockQuota::new();
+        quota.expect_get_usage().returning(|user_id| {
+            if user_id.as_str() == "ana" {
+                Ok(usage(FREE_AI_CALLS_PER_DAY))
+            } else {
+                Ok(usage(0))
+            }
+        });
+
+        let mut mock_generate = MockGenerateUseCase::new();
+        mock_generate
+            .expect_execute()
+            .withf(|params| params.user_id.as_str() == "luis")
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let use_case = PregenerateSuggestionsUseCaseImpl {
+            product_repository: Arc::new(mock_products),
+            suggestion_repository: Arc::new(mock_suggestions),
+            generate_use_case: Arc::new(mock_generate),
+            quota_service: Arc::new(quota),
+            logger: mock_logger(),
+        };
+
+        // A cap of one still reaches luis: ana's skip is not a generation
+        let summary = use_case
+            .execute(PregenerateSuggestionsParams {
+                limit: 5,
+                max_users: 1,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(summary.generated, 1);
+        assert_eq!(summary.skipped, 1);
+    }
+}
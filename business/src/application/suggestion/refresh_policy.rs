@@ -0,0 +1,301 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
+use crate::domain::events::{DomainEvent, EventHandler};
+use crate::domain::logger::Logger;
+use crate::domain::product::events::ProductAdded;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::suggestion::errors::SuggestionError;
+use crate::domain::suggestion::use_cases::generate::{
+    GenerateSuggestionsParams, GenerateSuggestionsUseCase,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub struct SuggestionRefreshSettings {
+    /// Products added in one burst that make the cached suggestions stale
+    pub min_added: u32,
+    /// Quiet time after the last addition before regenerating; additions
+    /// further apart than this belong to separate bursts
+    pub debounce: Duration,
+    /// Suggestions generated per refresh
+    pub limit: usize,
+}
+
+impl Default for SuggestionRefreshSettings {
+    fn default() -> Self {
+        Self {
+            min_added: 3,
+            debounce: Duration::from_secs(600),
+            limit: 5,
+        }
+    }
+}
+
+/// Additions seen for one user since their last refresh.
+struct Burst {
+    added: u32,
+    last_added: Instant,
+    refresh: Option<JoinHandle<()>>,
+}
+
+/// Regenerates a user's cached suggestions once their pantry changed enough
+/// for them to be stale: several products added in one go, such as after a
+/// shopping trip, or a single one that already needs using soon. The refresh
+/// waits until additions stop for the debounce time, so a trip unpacked one
+/// item at a time costs a single generation, and is not counted against the
+/// user's AI quota.
+pub struct SuggestionRefreshPolicy {
+    generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+    settings: SuggestionRefreshSettings,
+    logger: Arc<dyn Logger>,
+    bursts: Arc<Mutex<HashMap<UserId, Burst>>>,
+}
+
+impl SuggestionRefreshPolicy {
+    pub fn new(
+        generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+        settings: SuggestionRefreshSettings,
+        logger: Arc<dyn Logger>,
+    ) -> Self {
+        Self {
+            generate_use_case,
+            settings,
+            logger,
+            bursts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn product_added(&self, added: &ProductAdded) {
+        let now = Instant::now();
+        let debounce = self.settings.debounce;
+        let mut bursts = self.bursts.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Bursts that ended below the threshold are forgotten
+        bursts.retain(|_, burst| {
+            burst.refresh.is_some() || now.duration_since(burst.last_added) < debounce
+        });
+
+        let burst = bursts.entry(added.user_id.clone()).or_insert(Burst {
+            added: 0,
+            last_added: now,
+            refresh: None,
+        });
+        burst.added += 1;
+        burst.last_added = now;
+
+        if burst.added < self.settings.min_added && !added.urgent() {
+            return;
+        }
+
+        if let Some(pending) = burst.refresh.take() {
+            pending.abort();
+        }
+        burst.refresh = Some(tokio::spawn(refresh_after(
+            debounce,
+            added.user_id.clone(),
+            self.settings.limit,
+            self.bursts.clone(),
+            self.generate_use_case.clone(),
+            self.logger.clone(),
+        )));
+    }
+}
+
+async fn refresh_after(
+    debounce: Duration,
+    user_id: UserId,
+    limit: usize,
+    bursts: Arc<Mutex<HashMap<UserId, Burst>>>,
+    generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+    logger: Arc<dyn Logger>,
+) {
+    tokio::time::sleep(debounce).await;
+
+    // From here on the refresh can no longer be cancelled; later additions
+    // start a new burst
+    bursts
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&user_id);
+
+    match generate_use_case
+        .execute(GenerateSuggestionsParams {
+            user_id: user_id.clone(),
+            limit,
+            refresh: true,
+            metered: false,
+        })
+        .await
+    {
+        Ok(_) => logger.info(&format!(
+            "Refreshed suggestions for user {} after pantry changes",
+            user_id.as_str()
+        )),
+        Err(SuggestionError::EmptyPantry | SuggestionError::NotEnoughProducts) => {}
+        Err(e) => logger.warn(&format!(
+            "Could not refresh suggestions for user {}: {}",
+            user_id.as_str(),
+            e
+        )),
+    }
+}
+
+#[async_trait]
+impl EventHandler for SuggestionRefreshPolicy {
+    async fn handle(&self, event: &DomainEvent) {
+        match event {
+            DomainEvent::ProductAdded(added) => self.product_added(added),
+            DomainEvent::ProductStatusChanged(_)
+            | DomainEvent::ProductDeleted(_)
+            | DomainEvent::ProductUpdated(_)
+            | DomainEvent::ProductOutcomeRecorded(_)
+            | DomainEvent::ChallengeCompleted(_)
+            | DomainEvent::StreakMilestoneReached(_)
+            | DomainEvent::VacationEnded(_)
+            | DomainEvent::BudgetThresholdReached(_)
+            | DomainEvent::PurchaseRecorded(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::urgency::UrgencyLevel;
+    use crate::domain::suggestion::model::Suggestion;
+    use mockall::mock;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use uuid::Uuid;
+
+    mock! {
+        pub GenerateUseCase {}
+
+        #[async_trait]
+        impl GenerateSuggestionsUseCase for GenerateUseCase {
+            async fn execute(&self, params: GenerateSuggestionsParams) -> Result<Vec<Suggestion>, SuggestionError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn counting_generations(refreshes: Arc<AtomicUsize>) -> Arc<dyn GenerateSuggestionsUseCase> {
+        let mut generate = MockGenerateUseCase::new();
+        generate
+            .expect_execute()
+            .withf(|params| params.refresh && !params.metered && params.limit == 5)
+            .returning(move |_| {
+                refreshes.fetch_add(1, Ordering::SeqCst);
+                Ok(Vec::new())
+            });
+        Arc::new(generate)
+    }
+
+    fn added(urgency: UrgencyLevel) -> DomainEvent {
+        DomainEvent::ProductAdded(ProductAdded {
+            product_id: Uuid::new_v4(),
+            user_id: UserId::new("test-user-id"),
+            product_name: "Leche".to_string(),
+            urgency,
+        })
+    }
+
+    fn policy(refreshes: Arc<AtomicUsize>) -> SuggestionRefreshPolicy {
+        SuggestionRefreshPolicy::new(
+            counting_generations(refreshes),
+            SuggestionRefreshSettings::default(),
+            mock_logger(),
+        )
+    }
+
+    const DEBOUNCE: Duration = Duration::from_secs(600);
+
+    #[tokio::test(start_paused = true)]
+    async fn should_refresh_once_after_several_products_added() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let policy = policy(refreshes.clone());
+
+        for _ in 0..5 {
+            policy.handle(&added(UrgencyLevel::Ok)).await;
+        }
+        tokio::time::sleep(DEBOUNCE + Duration::from_secs(1)).await;
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_not_refresh_when_few_products_added() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let policy = policy(refreshes.clone());
+
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        tokio::time::sleep(DEBOUNCE * 2).await;
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_refresh_when_urgent_product_added() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let policy = policy(refreshes.clone());
+
+        policy.handle(&added(UrgencyLevel::UseToday)).await;
+        tokio::time::sleep(DEBOUNCE + Duration::from_secs(1)).await;
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_wait_for_additions_to_stop_before_refreshing() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let policy = policy(refreshes.clone());
+
+        for _ in 0..3 {
+            policy.handle(&added(UrgencyLevel::Ok)).await;
+        }
+        tokio::time::sleep(DEBOUNCE / 2).await;
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        tokio::time::sleep(DEBOUNCE / 2 + Duration::from_secs(1)).await;
+        assert_eq!(refreshes.load(Ordering::SeqCst), 0);
+
+        tokio::time::sleep(DEBOUNCE / 2).await;
+        assert_eq!(refreshes.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn should_not_count_additions_from_an_earlier_burst() {
+        let refreshes = Arc::new(AtomicUsize::new(0));
+        let policy = policy(refreshes.clone());
+
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        tokio::time::sleep(DEBOUNCE + Duration::from_secs(1)).await;
+        policy.handle(&added(UrgencyLevel::Ok)).await;
+        tokio::time::sleep(DEBOUNCE * 2).await;
+
+        assert_eq!(refreshes.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::model::Product;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::product::use_cases::update::{UpdateProductParams, UpdateProductUseCase};
+use crate::domain::product::value_objects::{ProductOutcome, ProductStatus};
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::VacationReport;
+use crate::domain::vacation::repository::VacationRepository;
+use crate::domain::vacation::use_cases::clean_up::{CleanUpVacationParams, CleanUpVacationUseCase};
+
+pub struct CleanUpVacationUseCaseImpl {
+    pub repository: Arc<dyn VacationRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    /// Products are thrown away through the regular update flow, so waste
+    /// stats, challenges and the shopping list see them as usual
+    pub update_product_use_case: Arc<dyn UpdateProductUseCase>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl CleanUpVacationUseCaseImpl {
+    async fn report(&self, user_id: &UserId) -> Result<VacationReport, VacationError> {
+        let vacation = self
+            .repository
+            .find_latest(user_id)
+            .await?
+            .ok_or(VacationError::NotFound)?;
+        let products = self
+            .product_repository
+            .find(&ProductQuery::active(user_id.clone()))
+            .await?;
+        Ok(VacationReport::build(vacation, products, Utc::now()))
+    }
+
+    async fn throw_away(&self, product: Product) -> Result<(), String> {
+        self.update_product_use_case
+            .execute(UpdateProductParams {
+                id: product.id,
+                user_id: product.user_id,
+                name: product.name,
+                status: ProductStatus::Finished,
+                location: product.location,
+                quantity: product.quantity,
+                expiry_date: product.expiry_date,
+                estimated_expiry_date: product.estimated_expiry_date,
+                expiry_type: product.expiry_type,
+                outcome: Some(ProductOutcome::ThrownAway),
+                category: product.category,
+                tags: product.tags,
+            })
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+#[async_trait]
+impl CleanUpVacationUseCase for CleanUpVacationUseCaseImpl {
+    async fn execute(
+        &self,
+        params: CleanUpVacationParams,
+    ) -> Result<VacationReport, VacationError> {
+        let report = self.report(&params.user_id).await?;
+        let total = report.expired.len();
+
+        for product in report.expired {
+            let id = product.id;
+            if let Err(e) = self.throw_away(product).await {
+                self.logger.warn(&format!(
+                    "Failed to throw away product {} after vacation: {}",
+                    id, e
+                ));
+            }
+        }
+
+        self.logger.info(&format!(
+            "Vacation cleanup for user {}: {} products thrown away",
+            params.user_id, total
+        ));
+        self.report(&params.user_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::errors::ProductError;
+    use crate::domain::product::value_objects::ExpiryType;
+    use crate::domain::vacation::model::Vacation;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use std::sync::Mutex;
+    use uuid::Uuid;
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub UpdateProduct {}
+
+        #[async_trait]
+        impl UpdateProductUseCase for UpdateProduct {
+            async fn execute(&self, params: UpdateProductParams) -> Result<Product, ProductError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn product(expiry: DateTime<Utc>) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Yogur".to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            Some(expiry),
+            None,
+            ExpiryType::UseBy,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn should_throw_away_products_that_expired_during_vacation() {
+        let started = Utc::now() - Duration::days(7);
+        let mut vacation = Vacation::start(test_user_id(), None, started).unwrap();
+        vacation.end(Utc::now() - Duration::days(1));
+        let expired = product(started + Duration::days(2));
+        let fresh = product(Utc::now() + Duration::days(5));
+        let expired_id = expired.id;
+
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo
+            .expect_find_latest()
+            .returning(move |_| Ok(Some(vacation.clone())));
+
+        // The second read happens after the expired product was thrown away
+        let pantry = Mutex::new(vec![vec![fresh.clone()], vec![expired, fresh]]);
+        let mut mock_products = MockProductRepo::new();
+        mock_products
+            .expect_find()
+            .returning(move |_| Ok(pantry.lock().unwrap().pop().unwrap()));
+
+        let mut mock_update = MockUpdateProduct::new();
+        mock_update
+            .expect_execute()
+            .withf(move |p| {
+                p.id == expired_id
+                    && p.status == ProductStatus::Finished
+                    && p.outcome == Some(ProductOutcome::ThrownAway)
+            })
+            .times(1)
+            .returning(|_| Err(ProductError::NotFound));
+
+        let use_case = CleanUpVacationUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            update_product_use_case: Arc::new(mock_update),
+            logger: mock_logger(),
+        };
+
+        let report = use_case
+            .execute(CleanUpVacationParams {
+                user_id: test_user_id(),
+            })
+            .await
+            .unwrap();
+
+        assert!(report.expired.is_empty());
+        assert_eq!(report.survived, 1);
+    }
+
+    #[tokio::test]
+    async fn should_fail_when_user_never_went_on_vacation() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo.expect_find_latest().returning(|_| Ok(None));
+
+        let use_case = CleanUpVacationUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            update_product_use_case: Arc::new(MockUpdateProduct::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(CleanUpVacationParams {
+                user_id: test_user_id(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(VacationError::NotFound)));
+    }
+}
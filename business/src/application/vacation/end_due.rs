@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::repository::VacationRepository;
+use crate::domain::vacation::use_cases::end_due::{EndDueVacationsParams, EndDueVacationsUseCase};
+use crate::domain::vacation::use_cases::set::{SetVacationModeParams, SetVacationModeUseCase};
+
+pub struct EndDueVacationsUseCaseImpl {
+    pub repository: Arc<dyn VacationRepository>,
+    /// Vacations end through the regular toggle, so the return is announced
+    /// the same way as when the user ends it by hand
+    pub set_use_case: Arc<dyn SetVacationModeUseCase>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl EndDueVacationsUseCase for EndDueVacationsUseCaseImpl {
+    async fn execute(&self, params: EndDueVacationsParams) -> Result<u32, VacationError> {
+        let due = self
+            .repository
+            .find_due(Utc::now(), params.max_users)
+            .await?;
+
+        let mut ended = 0;
+        for vacation in due {
+            let params = SetVacationModeParams {
+                user_id: vacation.user_id.clone(),
+                enabled: false,
+                until: None,
+            };
+            match self.set_use_case.execute(params).await {
+                Ok(_) => ended += 1,
+                Err(e) => self.logger.warn(&format!(
+                    "Failed to end vacation for user {}: {}",
+                    vacation.user_id, e
+                )),
+            }
+        }
+
+        if ended > 0 {
+            self.logger
+                .info(&format!("Ended {} vacations past their return date", ended));
+        }
+        Ok(ended)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::vacation::model::Vacation;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub SetUseCase {}
+
+        #[async_trait]
+        impl SetVacationModeUseCase for SetUseCase {
+            async fn execute(&self, params: SetVacationModeParams) -> Result<Option<Vacation>, VacationError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn due_vacation(user: &str) -> Vacation {
+        let now = Utc::now();
+        Vacation::from_repository(
+            Uuid::new_v4(),
+            UserId::new(user),
+            now - Duration::days(7),
+            Some(now - Duration::hours(1)),
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn should_end_due_vacations_through_regular_toggle() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo
+            .expect_find_due()
+            .withf(|now, limit| *now <= Utc::now() && *limit == 50)
+            .times(1)
+            .returning(|_, _| Ok(vec![due_vacation("alice"), due_vacation("bob")]));
+        let mut mock_set = MockSetUseCase::new();
+        mock_set
+            .expect_execute()
+            .withf(|params| !params.enabled && params.until.is_none())
+            .times(2)
+            .returning(|_| Ok(None));
+
+        let use_case = EndDueVacationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            set_use_case: Arc::new(mock_set),
+            logger: mock_logger(),
+        };
+
+        let ended = use_case
+            .execute(EndDueVacationsParams { max_users: 50 })
+            .await
+            .unwrap();
+
+        assert_eq!(ended, 2);
+    }
+
+    #[tokio::test]
+    async fn should_keep_ending_others_when_one_fails() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo
+            .expect_find_due()
+            .returning(|_, _| Ok(vec![due_vacation("alice"), due_vacation("bob")]));
+        let mut mock_set = MockSetUseCase::new();
+        mock_set.expect_execute().times(2).returning(|params| {
+            if params.user_id.as_str() == "alice" {
+                Err(VacationError::Repository(RepositoryError::Persistence))
+            } else {
+                Ok(None)
+            }
+        });
+        let mut logger = MockLog::new();
+        logger
+            .expect_warn()
+            .withf(|message| message.contains("alice"))
+            .times(1)
+            .returning(|_| ());
+        logger.expect_info().returning(|_| ());
+
+        let use_case = EndDueVacationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            set_use_case: Arc::new(mock_set),
+            logger: Arc::new(logger),
+        };
+
+        let ended = use_case
+            .execute(EndDueVacationsParams { max_users: 50 })
+            .await
+            .unwrap();
+
+        assert_eq!(ended, 1);
+    }
+
+    #[tokio::test]
+    async fn should_end_nothing_when_no_vacation_is_due() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo.expect_find_due().returning(|_, _| Ok(vec![]));
+        let mut mock_set = MockSetUseCase::new();
+        mock_set.expect_execute().never();
+
+        let use_case = EndDueVacationsUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            set_use_case: Arc::new(mock_set),
+            logger: mock_logger(),
+        };
+
+        let ended = use_case
+            .execute(EndDueVacationsParams { max_users: 50 })
+            .await
+            .unwrap();
+
+        assert_eq!(ended, 0);
+    }
+}
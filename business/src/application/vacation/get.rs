@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::Vacation;
+use crate::domain::vacation::repository::VacationRepository;
+use crate::domain::vacation::use_cases::get::{GetVacationModeParams, GetVacationModeUseCase};
+
+pub struct GetVacationModeUseCaseImpl {
+    pub repository: Arc<dyn VacationRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetVacationModeUseCase for GetVacationModeUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetVacationModeParams,
+    ) -> Result<Option<Vacation>, VacationError> {
+        self.logger.debug(&format!(
+            "Getting vacation mode for user: {}",
+            params.user_id
+        ));
+        Ok(self.repository.find_current(&params.user_id).await?)
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::VacationReport;
+use crate::domain::vacation::repository::VacationRepository;
+use crate::domain::vacation::use_cases::get_report::{
+    GetVacationReportParams, GetVacationReportUseCase,
+};
+
+pub struct GetVacationReportUseCaseImpl {
+    pub repository: Arc<dyn VacationRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetVacationReportUseCase for GetVacationReportUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetVacationReportParams,
+    ) -> Result<VacationReport, VacationError> {
+        self.logger.debug(&format!(
+            "Getting vacation report for user: {}",
+            params.user_id
+        ));
+
+        let vacation = self
+            .repository
+            .find_latest(&params.user_id)
+            .await?
+            .ok_or(VacationError::NotFound)?;
+        let products = self
+            .product_repository
+            .find(&ProductQuery::active(params.user_id))
+            .await?;
+
+        Ok(VacationReport::build(vacation, products, Utc::now()))
+    }
+}
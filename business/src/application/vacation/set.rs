@@ -0,0 +1,257 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::events::{DomainEvent, EventPublisher};
+use crate::domain::logger::Logger;
+use crate::domain::product::query::ProductQuery;
+use crate::domain::product::repository::ProductRepository;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::events::VacationEnded;
+use crate::domain::vacation::model::{Vacation, VacationReport};
+use crate::domain::vacation::repository::VacationRepository;
+use crate::domain::vacation::use_cases::set::{SetVacationModeParams, SetVacationModeUseCase};
+
+pub struct SetVacationModeUseCaseImpl {
+    pub repository: Arc<dyn VacationRepository>,
+    pub product_repository: Arc<dyn ProductRepository>,
+    pub event_publisher: Arc<dyn EventPublisher>,
+    pub logger: Arc<dyn Logger>,
+}
+
+impl SetVacationModeUseCaseImpl {
+    /// Ends the vacation and announces how many products didn't make it.
+    async fn end(&self, mut vacation: Vacation) -> Result<(), VacationError> {
+        let now = Utc::now();
+        vacation.end(now);
+        self.repository.update(&vacation).await?;
+
+        let products = self
+            .product_repository
+            .find(&ProductQuery::active(vacation.user_id.clone()))
+            .await?;
+        let user_id = vacation.user_id.clone();
+        let report = VacationReport::build(vacation, products, now);
+
+        self.logger.info(&format!(
+            "Vacation ended for user {}: {} expired, {} survived",
+            user_id,
+            report.expired.len(),
+            report.survived
+        ));
+        self.event_publisher
+            .publish(DomainEvent::VacationEnded(VacationEnded {
+                user_id,
+                expired: report.expired.len() as u32,
+            }))
+            .await;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SetVacationModeUseCase for SetVacationModeUseCaseImpl {
+    async fn execute(
+        &self,
+        params: SetVacationModeParams,
+    ) -> Result<Option<Vacation>, VacationError> {
+        let current = self.repository.find_current(&params.user_id).await?;
+
+        if !params.enabled {
+            if let Some(vacation) = current {
+                self.end(vacation).await?;
+            }
+            return Ok(None);
+        }
+
+        let now = Utc::now();
+        let vacation = match current {
+            Some(mut vacation) => {
+                vacation.plan_return(params.until, now)?;
+                self.repository.update(&vacation).await?;
+                vacation
+            }
+            None => {
+                let vacation = Vacation::start(params.user_id, params.until, now)?;
+                self.repository.insert(&vacation).await?;
+                self.logger
+                    .info(&format!("Vacation started for user {}", vacation.user_id));
+                vacation
+            }
+        };
+        Ok(Some(vacation))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::product::model::Product;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub VacationRepo {}
+
+        #[async_trait]
+        impl VacationRepository for VacationRepo {
+            async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+            async fn find_due(&self, now: DateTime<Utc>, limit: usize) -> Result<Vec<Vacation>, RepositoryError>;
+            async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+            async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub ProductRepo {}
+
+        #[async_trait]
+        impl ProductRepository for ProductRepo {
+            async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+            async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+            async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+            async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+            async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+            async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+            async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Publisher {}
+
+        #[async_trait]
+        impl EventPublisher for Publisher {
+            async fn publish(&self, event: DomainEvent);
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[tokio::test]
+    async fn should_start_vacation_when_off() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo.expect_find_current().returning(|_| Ok(None));
+        mock_repo
+            .expect_insert()
+            .withf(|v| v.is_active())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = SetVacationModeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let vacation = use_case
+            .execute(SetVacationModeParams {
+                user_id: test_user_id(),
+                enabled: true,
+                until: Some(Utc::now() + Duration::days(7)),
+            })
+            .await
+            .unwrap();
+
+        assert!(vacation.is_some());
+    }
+
+    #[tokio::test]
+    async fn should_end_vacation_and_announce_return() {
+        let started = Utc::now() - Duration::days(7);
+        let vacation = Vacation::start(test_user_id(), None, started).unwrap();
+
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo
+            .expect_find_current()
+            .returning(move |_| Ok(Some(vacation.clone())));
+        mock_repo
+            .expect_update()
+            .withf(|v| !v.is_active())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut mock_products = MockProductRepo::new();
+        mock_products.expect_find().returning(|_| Ok(vec![]));
+
+        let mut mock_publisher = MockPublisher::new();
+        mock_publisher
+            .expect_publish()
+            .withf(|event| matches!(event, DomainEvent::VacationEnded(ended) if ended.expired == 0))
+            .times(1)
+            .returning(|_| ());
+
+        let use_case = SetVacationModeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(mock_products),
+            event_publisher: Arc::new(mock_publisher),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(SetVacationModeParams {
+                user_id: test_user_id(),
+                enabled: false,
+                until: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    async fn should_do_nothing_when_turning_off_while_off() {
+        let mut mock_repo = MockVacationRepo::new();
+        mock_repo.expect_find_current().returning(|_| Ok(None));
+        mock_repo.expect_update().never();
+
+        let use_case = SetVacationModeUseCaseImpl {
+            repository: Arc::new(mock_repo),
+            product_repository: Arc::new(MockProductRepo::new()),
+            event_publisher: Arc::new(MockPublisher::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(SetVacationModeParams {
+                user_id: test_user_id(),
+                enabled: false,
+                until: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::domain::logger::Logger;
+use crate::domain::preference::repository::PreferenceRepository;
+use crate::domain::share_link::model::hash_token;
+use crate::domain::widget::errors::WidgetError;
+use crate::domain::widget::model::{WidgetSnapshot, WidgetWindow};
+use crate::domain::widget::repository::WidgetRepository;
+use crate::domain::widget::use_cases::get_snapshot::{
+    GetWidgetSnapshotParams, GetWidgetSnapshotUseCase,
+};
+
+pub struct GetWidgetSnapshotUseCaseImpl {
+    pub repository: Arc<dyn WidgetRepository>,
+    pub preference_repository: Arc<dyn PreferenceRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl GetWidgetSnapshotUseCase for GetWidgetSnapshotUseCaseImpl {
+    async fn execute(
+        &self,
+        params: GetWidgetSnapshotParams,
+    ) -> Result<WidgetSnapshot, WidgetError> {
+        let user_id = self
+            .repository
+            .find_owner(&hash_token(&params.token))
+            .await?
+            .ok_or(WidgetError::InvalidToken)?;
+
+        // Polled frequently by widgets, so keep it at debug level
+        self.logger
+            .debug(&format!("Getting widget snapshot for user: {}", user_id));
+
+        let preferences = self.preference_repository.get(&user_id).await?;
+        let window = WidgetWindow::at(Utc::now(), preferences.expiring_soon);
+        Ok(self.repository.get_snapshot(&user_id, &window).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::preference::model::UserPreferences;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::widget::model::{WidgetItem, WidgetToken};
+    use mockall::mock;
+    use uuid::Uuid;
+
+    mock! {
+        pub WidgetRepo {}
+
+        #[async_trait]
+        impl WidgetRepository for WidgetRepo {
+            async fn find_owner(&self, token_hash: &str) -> Result<Option<UserId>, RepositoryError>;
+            async fn save_token(&self, token: &WidgetToken) -> Result<(), RepositoryError>;
+            async fn delete_token(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_snapshot(&self, user_id: &UserId, window: &WidgetWindow) -> Result<WidgetSnapshot, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub PreferenceRepo {}
+
+        #[async_trait]
+        impl PreferenceRepository for PreferenceRepo {
+            async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+            async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    fn default_preferences() -> MockPreferenceRepo {
+        let mut repo = MockPreferenceRepo::new();
+        repo.expect_get()
+            .returning(|_| Ok(UserPreferences::default()));
+        repo
+    }
+
+    #[tokio::test]
+    async fn should_return_owner_snapshot_when_token_is_known() {
+        let mut repo = MockWidgetRepo::new();
+        repo.expect_find_owner()
+            .withf(|token_hash| token_hash == hash_token("widget-token"))
+            .returning(|_| Ok(Some(UserId::new("test-user-id"))));
+        repo.expect_get_snapshot()
+            .withf(|user_id, window| {
+                user_id.as_str() == "test-user-id" && window.urgent_until > window.now
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(WidgetSnapshot {
+                    shopping_items: vec![WidgetItem {
+                        id: Uuid::new_v4(),
+                        name: "Leche".to_string(),
+                    }],
+                    unbought_total: 1,
+                    ..WidgetSnapshot::default()
+                })
+            });
+
+        let use_case = GetWidgetSnapshotUseCaseImpl {
+            repository: Arc::new(repo),
+            preference_repository: Arc::new(default_preferences()),
+            logger: mock_logger(),
+        };
+
+        let snapshot = use_case
+            .execute(GetWidgetSnapshotParams {
+                token: "widget-token".to_string(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(snapshot.unbought_total, 1);
+        assert_eq!(snapshot.shopping_items[0].name, "Leche");
+    }
+
+    #[tokio::test]
+    async fn should_reject_when_token_is_unknown() {
+        let mut repo = MockWidgetRepo::new();
+        repo.expect_find_owner().returning(|_| Ok(None));
+        repo.expect_get_snapshot().never();
+
+        let use_case = GetWidgetSnapshotUseCaseImpl {
+            repository: Arc::new(repo),
+            preference_repository: Arc::new(MockPreferenceRepo::new()),
+            logger: mock_logger(),
+        };
+
+        let result = use_case
+            .execute(GetWidgetSnapshotParams {
+                token: "revoked".to_string(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(WidgetError::InvalidToken)));
+    }
+}
@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::widget::errors::WidgetError;
+use crate::domain::widget::model::WidgetToken;
+use crate::domain::widget::repository::WidgetRepository;
+use crate::domain::widget::use_cases::issue_token::{
+    IssueWidgetTokenParams, IssueWidgetTokenUseCase, IssuedWidgetToken,
+};
+
+pub struct IssueWidgetTokenUseCaseImpl {
+    pub repository: Arc<dyn WidgetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl IssueWidgetTokenUseCase for IssueWidgetTokenUseCaseImpl {
+    async fn execute(
+        &self,
+        params: IssueWidgetTokenParams,
+    ) -> Result<IssuedWidgetToken, WidgetError> {
+        let (widget_token, token) = WidgetToken::issue(params.user_id);
+        self.repository.save_token(&widget_token).await?;
+
+        self.logger.info(&format!(
+            "Widget token issued for user: {}",
+            widget_token.user_id
+        ));
+        Ok(IssuedWidgetToken {
+            widget_token,
+            token,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::errors::RepositoryError;
+    use crate::domain::share_link::model::hash_token;
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::widget::model::{WidgetSnapshot, WidgetWindow};
+    use mockall::mock;
+
+    mock! {
+        pub WidgetRepo {}
+
+        #[async_trait]
+        impl WidgetRepository for WidgetRepo {
+            async fn find_owner(&self, token_hash: &str) -> Result<Option<UserId>, RepositoryError>;
+            async fn save_token(&self, token: &WidgetToken) -> Result<(), RepositoryError>;
+            async fn delete_token(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+            async fn get_snapshot(&self, user_id: &UserId, window: &WidgetWindow) -> Result<WidgetSnapshot, RepositoryError>;
+        }
+    }
+
+    mock! {
+        pub Log {}
+
+        impl Logger for Log {
+            fn info(&self, message: &str);
+            fn warn(&self, message: &str);
+            fn error(&self, message: &str);
+            fn debug(&self, message: &str);
+        }
+    }
+
+    fn mock_logger() -> Arc<dyn Logger> {
+        let mut logger = MockLog::new();
+        logger.expect_info().returning(|_| ());
+        logger.expect_warn().returning(|_| ());
+        logger.expect_error().returning(|_| ());
+        logger.expect_debug().returning(|_| ());
+        Arc::new(logger)
+    }
+
+    #[tokio::test]
+    async fn should_save_hash_of_returned_token() {
+        let mut repo = MockWidgetRepo::new();
+        repo.expect_save_token()
+            .withf(|token| token.user_id.as_str() == "test-user-id")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let use_case = IssueWidgetTokenUseCaseImpl {
+            repository: Arc::new(repo),
+            logger: mock_logger(),
+        };
+
+        let issued = use_case
+            .execute(IssueWidgetTokenParams {
+                user_id: UserId::new("test-user-id"),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(issued.widget_token.token_hash, hash_token(&issued.token));
+    }
+}
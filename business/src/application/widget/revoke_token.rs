@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::logger::Logger;
+use crate::domain::widget::errors::WidgetError;
+use crate::domain::widget::repository::WidgetRepository;
+use crate::domain::widget::use_cases::revoke_token::{
+    RevokeWidgetTokenParams, RevokeWidgetTokenUseCase,
+};
+
+pub struct RevokeWidgetTokenUseCaseImpl {
+    pub repository: Arc<dyn WidgetRepository>,
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl RevokeWidgetTokenUseCase for RevokeWidgetTokenUseCaseImpl {
+    async fn execute(&self, params: RevokeWidgetTokenParams) -> Result<(), WidgetError> {
+        self.repository.delete_token(&params.user_id).await?;
+
+        self.logger.info(&format!(
+            "Widget token revoked for user: {}",
+            params.user_id
+        ));
+        Ok(())
+    }
+}
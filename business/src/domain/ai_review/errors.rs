@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum AiReviewError {
+    #[error("ai_review.not_found")]
+    NotFound,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
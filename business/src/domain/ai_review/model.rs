@@ -0,0 +1,111 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::product::model::Product;
+use crate::domain::product::value_objects::EstimationStatus;
+use crate::domain::shared::value_objects::UserId;
+
+/// Whether AI-derived product facts are written straight away or staged for
+/// the user to confirm.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AiWriteMode {
+    #[default]
+    Auto,
+    Review,
+}
+
+impl std::fmt::Display for AiWriteMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AiWriteMode::Auto => write!(f, "auto"),
+            AiWriteMode::Review => write!(f, "review"),
+        }
+    }
+}
+
+impl std::str::FromStr for AiWriteMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(AiWriteMode::Auto),
+            "review" => Ok(AiWriteMode::Review),
+            _ => Err(format!("Invalid AI write mode: {}", s)),
+        }
+    }
+}
+
+/// A product fact proposed by the AI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AiChange {
+    EstimatedExpiryDate(DateTime<Utc>),
+}
+
+impl AiChange {
+    /// Product field the change writes. A product has at most one pending
+    /// change per field.
+    pub fn field(&self) -> &'static str {
+        match self {
+            AiChange::EstimatedExpiryDate(_) => "estimated_expiry_date",
+        }
+    }
+
+    /// Writes the change to the product. The estimator's confidence isn't
+    /// part of the change, so callers holding it set it afterwards.
+    pub fn apply_to(&self, product: &mut Product) {
+        match self {
+            AiChange::EstimatedExpiryDate(date) => {
+                product.estimated_expiry_date = Some(*date);
+                product.expiry_confidence = None;
+                product.estimation_status = EstimationStatus::Done;
+            }
+        }
+        product.updated_at = Utc::now();
+    }
+}
+
+/// An AI change staged while the user is in review mode.
+#[derive(Debug, Clone)]
+pub struct PendingAiChange {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub product_id: Uuid,
+    pub change: AiChange,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PendingAiChange {
+    pub fn new(product: &Product, change: AiChange) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id: product.user_id.clone(),
+            product_id: product.id,
+            change,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        product_id: Uuid,
+        change: AiChange,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            product_id,
+            change,
+            created_at,
+        }
+    }
+}
+
+/// A product with the AI changes waiting for confirmation.
+#[derive(Debug, Clone)]
+pub struct PendingProductChanges {
+    pub product: Product,
+    pub changes: Vec<PendingAiChange>,
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::{AiWriteMode, PendingAiChange};
+
+#[async_trait]
+pub trait AiReviewRepository: Send + Sync {
+    /// The user's mode; `Auto` if they never chose one.
+    async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+    async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+    /// Pending changes, oldest first.
+    async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+    async fn get_pending_by_id(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+    ) -> Result<PendingAiChange, RepositoryError>;
+    /// Stages a change, replacing any pending change to the same product field.
+    async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+    async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+}
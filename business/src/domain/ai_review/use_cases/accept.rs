@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::product::model::Product;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct AcceptAiChangeParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait AcceptAiChangeUseCase: Send + Sync {
+    /// Applies the pending change to its product and returns the product.
+    async fn execute(&self, params: AcceptAiChangeParams) -> Result<Product, AiReviewError>;
+}
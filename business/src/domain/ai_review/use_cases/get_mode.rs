@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::AiWriteMode;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetAiWriteModeParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetAiWriteModeUseCase: Send + Sync {
+    async fn execute(&self, params: GetAiWriteModeParams) -> Result<AiWriteMode, AiReviewError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::PendingProductChanges;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetPendingAiChangesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetPendingAiChangesUseCase: Send + Sync {
+    /// Pending changes grouped by product, products ordered by their oldest
+    /// pending change.
+    async fn execute(
+        &self,
+        params: GetPendingAiChangesParams,
+    ) -> Result<Vec<PendingProductChanges>, AiReviewError>;
+}
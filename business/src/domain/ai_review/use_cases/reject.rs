@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct RejectAiChangeParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait RejectAiChangeUseCase: Send + Sync {
+    async fn execute(&self, params: RejectAiChangeParams) -> Result<(), AiReviewError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::ai_review::errors::AiReviewError;
+use crate::domain::ai_review::model::AiWriteMode;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct SetAiWriteModeParams {
+    pub user_id: UserId,
+    pub mode: AiWriteMode,
+}
+
+#[async_trait]
+pub trait SetAiWriteModeUseCase: Send + Sync {
+    /// Switching back to auto keeps already staged changes; they stay pending
+    /// until accepted or rejected.
+    async fn execute(&self, params: SetAiWriteModeParams) -> Result<AiWriteMode, AiReviewError>;
+}
@@ -0,0 +1,32 @@
+use crate::domain::errors::ErrorSource;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AuthError {
+    #[error("auth.invalid_email")]
+    InvalidEmail,
+    /// The sign-in link is unknown, expired or already used.
+    #[error("auth.invalid_link")]
+    InvalidLink,
+    /// The passkey response failed verification, or answers a challenge that
+    /// is unknown, expired or already used.
+    #[error("auth.invalid_passkey")]
+    InvalidPasskey,
+    #[error("auth.unknown_passkey")]
+    UnknownPasskey,
+    #[error("auth.delivery_failed")]
+    DeliveryFailed(#[source] ErrorSource),
+    #[error("auth.token_failed")]
+    TokenFailed(#[source] ErrorSource),
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
+
+impl AuthError {
+    pub fn delivery_failed(cause: impl Into<ErrorSource>) -> Self {
+        AuthError::DeliveryFailed(cause.into())
+    }
+
+    pub fn token_failed(cause: impl Into<ErrorSource>) -> Self {
+        AuthError::TokenFailed(cause.into())
+    }
+}
@@ -0,0 +1,284 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::errors::AuthError;
+use crate::domain::shared::value_objects::UserId;
+
+const CHALLENGE_BYTES: usize = 32;
+
+/// Longest address accepted, as limited by SMTP.
+const MAX_EMAIL_LEN: usize = 254;
+
+/// What a login challenge can be answered with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengePurpose {
+    /// The token of a link emailed to the user.
+    MagicLink,
+    /// A new passkey of a signed-in user.
+    PasskeyRegistration,
+    /// A signature from a registered passkey.
+    PasskeyLogin,
+}
+
+impl std::fmt::Display for ChallengePurpose {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChallengePurpose::MagicLink => write!(f, "magic_link"),
+            ChallengePurpose::PasskeyRegistration => write!(f, "passkey_registration"),
+            ChallengePurpose::PasskeyLogin => write!(f, "passkey_login"),
+        }
+    }
+}
+
+impl std::str::FromStr for ChallengePurpose {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "magic_link" => Ok(ChallengePurpose::MagicLink),
+            "passkey_registration" => Ok(ChallengePurpose::PasskeyRegistration),
+            "passkey_login" => Ok(ChallengePurpose::PasskeyLogin),
+            _ => Err(format!("Invalid challenge purpose: {}", s)),
+        }
+    }
+}
+
+/// A single-use secret sent to the user's inbox or signed by a passkey.
+///
+/// Only the SHA-256 hash of the secret is stored, so a leaked database row
+/// cannot be turned into a sign-in.
+#[derive(Debug, Clone)]
+pub struct LoginChallenge {
+    pub challenge_hash: String,
+    pub purpose: ChallengePurpose,
+    /// Address a magic link was sent to
+    pub email: Option<String>,
+    /// User adding a passkey
+    pub user_id: Option<UserId>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl LoginChallenge {
+    /// Issues a new challenge and returns it together with the plain secret,
+    /// which is only available at creation time.
+    pub fn issue(
+        purpose: ChallengePurpose,
+        email: Option<String>,
+        user_id: Option<UserId>,
+        ttl: Duration,
+    ) -> (Self, String) {
+        let mut bytes = [0u8; CHALLENGE_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        let secret = URL_SAFE_NO_PAD.encode(bytes);
+
+        let challenge = Self {
+            challenge_hash: hash_challenge(&secret),
+            purpose,
+            email,
+            user_id,
+            expires_at: Utc::now() + ttl,
+        };
+        (challenge, secret)
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        challenge_hash: String,
+        purpose: ChallengePurpose,
+        email: Option<String>,
+        user_id: Option<UserId>,
+        expires_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            challenge_hash,
+            purpose,
+            email,
+            user_id,
+            expires_at,
+        }
+    }
+
+    /// Whether the challenge can still be answered for `purpose`.
+    pub fn is_valid_for(&self, purpose: ChallengePurpose, now: DateTime<Utc>) -> bool {
+        self.purpose == purpose && self.expires_at > now
+    }
+}
+
+/// Hashes a plain challenge secret into the form stored by the repository.
+pub fn hash_challenge(secret: &str) -> String {
+    Sha256::digest(secret.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// A user signing in without Firebase, known by email address.
+#[derive(Debug, Clone)]
+pub struct LocalAccount {
+    pub user_id: UserId,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LocalAccount {
+    /// A new account with a fresh user id. `email` must already be normalized.
+    pub fn new(email: String) -> Self {
+        Self {
+            user_id: UserId::new(Uuid::new_v4().to_string()),
+            email,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(user_id: UserId, email: String, created_at: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            email,
+            created_at,
+        }
+    }
+}
+
+/// A passkey registered by a user, with the public key its signatures are
+/// checked against.
+#[derive(Debug, Clone)]
+pub struct Passkey {
+    /// Credential id, base64url-encoded as the platform APIs report it
+    pub credential_id: String,
+    pub user_id: UserId,
+    /// Uncompressed P-256 point (`0x04 || x || y`)
+    pub public_key: Vec<u8>,
+    /// Signature counter last reported by the authenticator; 0 when it
+    /// does not keep one
+    pub sign_count: u32,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl Passkey {
+    /// Whether `sign_count` could come after the last one seen. A counter that
+    /// does not increase hints at a cloned authenticator; synced passkeys
+    /// always report 0 and are not checked.
+    pub fn accepts_sign_count(&self, sign_count: u32) -> bool {
+        (sign_count == 0 && self.sign_count == 0) || sign_count > self.sign_count
+    }
+}
+
+/// Bearer token issued after a local sign-in.
+#[derive(Debug, Clone)]
+pub struct IssuedToken {
+    pub token: String,
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// What the apps pass to the platform passkey API to create or use a
+/// passkey.
+#[derive(Debug, Clone)]
+pub struct PasskeyOptions {
+    /// Base64url challenge the authenticator signs
+    pub challenge: String,
+    /// Relying party (domain) passkeys are bound to
+    pub rp_id: String,
+    /// User the passkey is created for; `None` when signing in
+    pub user_id: Option<UserId>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Trims and lowercases an email address, rejecting anything that is not
+/// shaped like one. Deliverability is only proven by the magic link itself.
+pub fn normalize_email(email: &str) -> Result<String, AuthError> {
+    let email = email.trim().to_lowercase();
+    let valid = email.len() <= MAX_EMAIL_LEN
+        && !email.chars().any(char::is_whitespace)
+        && email.split_once('@').is_some_and(|(local, domain)| {
+            !local.is_empty()
+                && !domain.contains('@')
+                && domain.split('.').filter(|label| !label.is_empty()).count() >= 2
+                && !domain.starts_with('.')
+                && !domain.ends_with('.')
+        });
+    if !valid {
+        return Err(AuthError::InvalidEmail);
+    }
+    Ok(email)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passkey(sign_count: u32) -> Passkey {
+        Passkey {
+            credential_id: "cred".to_string(),
+            user_id: UserId::new("test-user-id"),
+            public_key: vec![4],
+            sign_count,
+            created_at: Utc::now(),
+            last_used_at: None,
+        }
+    }
+
+    #[test]
+    fn should_store_only_challenge_hash() {
+        let (challenge, secret) = LoginChallenge::issue(
+            ChallengePurpose::PasskeyLogin,
+            None,
+            None,
+            Duration::minutes(5),
+        );
+
+        assert_ne!(challenge.challenge_hash, secret);
+        assert_eq!(challenge.challenge_hash, hash_challenge(&secret));
+    }
+
+    #[test]
+    fn should_only_accept_challenge_for_its_purpose_until_it_expires() {
+        let (challenge, _) = LoginChallenge::issue(
+            ChallengePurpose::MagicLink,
+            Some("ana@example.com".to_string()),
+            None,
+            Duration::minutes(5),
+        );
+        let now = Utc::now();
+
+        assert!(challenge.is_valid_for(ChallengePurpose::MagicLink, now));
+        assert!(!challenge.is_valid_for(ChallengePurpose::PasskeyLogin, now));
+        assert!(!challenge.is_valid_for(ChallengePurpose::MagicLink, now + Duration::minutes(6)));
+    }
+
+    #[test]
+    fn should_normalize_email_and_reject_malformed_ones() {
+        assert_eq!(
+            normalize_email("  Ana.Garcia@Example.COM ").unwrap(),
+            "ana.garcia@example.com"
+        );
+        for email in [
+            "",
+            "ana",
+            "ana@",
+            "@example.com",
+            "ana@example",
+            "a b@example.com",
+            "ana@@example.com",
+        ] {
+            assert!(
+                normalize_email(email).is_err(),
+                "{email} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_sign_count_that_does_not_increase() {
+        assert!(passkey(0).accepts_sign_count(0));
+        assert!(passkey(5).accepts_sign_count(6));
+        assert!(!passkey(5).accepts_sign_count(5));
+        assert!(!passkey(5).accepts_sign_count(0));
+    }
+}
@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::RepositoryError;
+
+use super::model::{LocalAccount, LoginChallenge, Passkey};
+
+#[async_trait]
+pub trait AuthRepository: Send + Sync {
+    async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError>;
+    /// Removes and returns a challenge, so each can be answered only once.
+    async fn take_challenge(
+        &self,
+        challenge_hash: &str,
+    ) -> Result<Option<LoginChallenge>, RepositoryError>;
+    async fn find_account_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<LocalAccount>, RepositoryError>;
+    /// Fails with `Duplicated` if the email already has an account.
+    async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError>;
+    /// Fails with `Duplicated` if the credential is already registered.
+    async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError>;
+    async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError>;
+    async fn record_passkey_use(
+        &self,
+        credential_id: &str,
+        sign_count: u32,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+}
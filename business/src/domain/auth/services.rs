@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+
+use super::errors::AuthError;
+use super::model::IssuedToken;
+use crate::domain::shared::value_objects::UserId;
+
+/// Service port signing the bearer tokens of local sign-ins.
+pub trait TokenIssuer: Send + Sync {
+    fn issue(&self, user_id: &UserId) -> Result<IssuedToken, AuthError>;
+}
+
+/// Service port delivering sign-in links by email.
+#[async_trait]
+pub trait MagicLinkSender: Send + Sync {
+    async fn send(&self, email: &str, link: &str) -> Result<(), AuthError>;
+}
+
+/// Result of a platform `create()` call, with binary fields base64url-encoded
+/// as in the WebAuthn JSON form.
+#[derive(Debug, Clone)]
+pub struct PasskeyAttestation {
+    pub credential_id: String,
+    pub client_data_json: String,
+    pub attestation_object: String,
+}
+
+/// Result of a platform `get()` call, with binary fields base64url-encoded.
+#[derive(Debug, Clone)]
+pub struct PasskeyAssertion {
+    pub credential_id: String,
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+}
+
+/// A checked new passkey, and the challenge it answered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedRegistration {
+    pub challenge: String,
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+}
+
+/// A checked passkey signature, and the challenge it answered.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedAssertion {
+    pub challenge: String,
+    pub sign_count: u32,
+}
+
+/// Service port checking WebAuthn responses against the relying party and
+/// origins this deployment serves. Challenges are returned rather than
+/// checked, since they are looked up by the caller.
+pub trait PasskeyVerifier: Send + Sync {
+    fn verify_registration(
+        &self,
+        attestation: &PasskeyAttestation,
+    ) -> Result<VerifiedRegistration, AuthError>;
+
+    fn verify_assertion(
+        &self,
+        assertion: &PasskeyAssertion,
+        public_key: &[u8],
+    ) -> Result<VerifiedAssertion, AuthError>;
+}
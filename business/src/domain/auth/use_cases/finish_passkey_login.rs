@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::IssuedToken;
+use crate::domain::auth::services::PasskeyAssertion;
+
+pub struct FinishPasskeyLoginParams {
+    pub assertion: PasskeyAssertion,
+}
+
+#[async_trait]
+pub trait FinishPasskeyLoginUseCase: Send + Sync {
+    async fn execute(&self, params: FinishPasskeyLoginParams) -> Result<IssuedToken, AuthError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::Passkey;
+use crate::domain::auth::services::PasskeyAttestation;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct FinishPasskeyRegistrationParams {
+    pub user_id: UserId,
+    pub attestation: PasskeyAttestation,
+}
+
+#[async_trait]
+pub trait FinishPasskeyRegistrationUseCase: Send + Sync {
+    async fn execute(&self, params: FinishPasskeyRegistrationParams) -> Result<Passkey, AuthError>;
+}
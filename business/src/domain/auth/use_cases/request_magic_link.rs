@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+
+pub struct RequestMagicLinkParams {
+    pub email: String,
+}
+
+#[async_trait]
+pub trait RequestMagicLinkUseCase: Send + Sync {
+    async fn execute(&self, params: RequestMagicLinkParams) -> Result<(), AuthError>;
+}
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::PasskeyOptions;
+
+#[async_trait]
+pub trait StartPasskeyLoginUseCase: Send + Sync {
+    async fn execute(&self) -> Result<PasskeyOptions, AuthError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::PasskeyOptions;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct StartPasskeyRegistrationParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait StartPasskeyRegistrationUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: StartPasskeyRegistrationParams,
+    ) -> Result<PasskeyOptions, AuthError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::auth::errors::AuthError;
+use crate::domain::auth::model::IssuedToken;
+
+pub struct VerifyMagicLinkParams {
+    /// Token from the emailed link
+    pub token: String,
+}
+
+#[async_trait]
+pub trait VerifyMagicLinkUseCase: Send + Sync {
+    async fn execute(&self, params: VerifyMagicLinkParams) -> Result<IssuedToken, AuthError>;
+}
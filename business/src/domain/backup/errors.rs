@@ -0,0 +1,14 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("backup.unsupported_format")]
+    UnsupportedFormat,
+    #[error("backup.newer_schema")]
+    NewerSchema,
+    #[error("backup.account_not_empty")]
+    AccountNotEmpty,
+    /// A row the domain wouldn't accept, or that points outside the archive
+    #[error("backup.invalid_row")]
+    InvalidRow { table: String },
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::errors::BackupError;
+
+/// Layout of [`BackupArchive`]; bumped whenever it changes incompatibly.
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+/// Rows of one table, each a JSON object keyed by column.
+pub type BackupRows = Vec<serde_json::Value>;
+
+/// Everything a user stored, table by table, so it can be moved to another
+/// instance. Rows keep their columns as the database had them when the
+/// backup was taken.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupArchive {
+    pub format_version: u32,
+    /// Latest database migration applied where the backup was taken
+    pub schema_version: i64,
+    pub exported_at: DateTime<Utc>,
+    pub tables: BTreeMap<String, BackupRows>,
+}
+
+impl BackupArchive {
+    pub fn new(schema_version: i64, tables: BTreeMap<String, BackupRows>) -> Self {
+        Self {
+            format_version: BACKUP_FORMAT_VERSION,
+            schema_version,
+            exported_at: Utc::now(),
+            tables,
+        }
+    }
+
+    /// Checks the archive can be restored into a database at
+    /// `schema_version`. Archives from older schemas are accepted; newer ones
+    /// may hold columns this database doesn't have.
+    pub fn ensure_restorable(&self, schema_version: i64) -> Result<(), BackupError> {
+        if self.format_version != BACKUP_FORMAT_VERSION {
+            return Err(BackupError::UnsupportedFormat);
+        }
+        if self.schema_version > schema_version {
+            return Err(BackupError::NewerSchema);
+        }
+        Ok(())
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.tables.values().map(Vec::len).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_archives_from_newer_schema_or_other_format() {
+        let mut archive = BackupArchive::new(20260303090000, BTreeMap::new());
+
+        assert!(archive.ensure_restorable(20260303090000).is_ok());
+        assert!(archive.ensure_restorable(20260401090000).is_ok());
+        assert!(matches!(
+            archive.ensure_restorable(20260301090000),
+            Err(BackupError::NewerSchema)
+        ));
+
+        archive.format_version = BACKUP_FORMAT_VERSION + 1;
+        assert!(matches!(
+            archive.ensure_restorable(20260303090000),
+            Err(BackupError::UnsupportedFormat)
+        ));
+    }
+}
@@ -0,0 +1,32 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::errors::BackupError;
+use super::model::BackupRows;
+
+#[async_trait]
+pub trait BackupRepository: Send + Sync {
+    /// Latest migration applied to the database.
+    async fn schema_version(&self) -> Result<i64, RepositoryError>;
+    /// The user's rows, keyed by table.
+    async fn export(
+        &self,
+        user_id: &UserId,
+    ) -> Result<BTreeMap<String, BackupRows>, RepositoryError>;
+    /// Writes the rows as the user's under new ids, all or nothing. Tables
+    /// the database doesn't back up are ignored. Fails with
+    /// [`BackupError::AccountNotEmpty`] when the user already has rows in a
+    /// backed up table, checked in the same transaction as the writes so two
+    /// restores can't both pass it, and with [`BackupError::InvalidRow`] when
+    /// a row holds a value the domain wouldn't accept or points at a row the
+    /// archive doesn't hold. Returns the number of rows written.
+    async fn restore(
+        &self,
+        user_id: &UserId,
+        tables: &BTreeMap<String, BackupRows>,
+    ) -> Result<usize, BackupError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::model::BackupArchive;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct ExportBackupParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait ExportBackupUseCase: Send + Sync {
+    async fn execute(&self, params: ExportBackupParams) -> Result<BackupArchive, BackupError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::backup::errors::BackupError;
+use crate::domain::backup::model::BackupArchive;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct RestoreBackupParams {
+    pub user_id: UserId,
+    pub archive: BackupArchive,
+}
+
+#[async_trait]
+pub trait RestoreBackupUseCase: Send + Sync {
+    /// Restores the archive into the user's account, which must be empty.
+    /// Returns the number of rows restored.
+    async fn execute(&self, params: RestoreBackupParams) -> Result<usize, BackupError>;
+}
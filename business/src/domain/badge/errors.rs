@@ -0,0 +1,5 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BadgeError {
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,60 @@
+use chrono::{DateTime, NaiveTime, Utc};
+
+use crate::domain::product::urgency::{ExpiringSoonWindow, urgent_until};
+
+/// Small counters shown on app icons and widgets.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BadgeCounts {
+    /// Non-finished products that must be used today or in the next days.
+    pub urgent_products: u32,
+    /// Shopping list items not bought yet.
+    pub unbought_items: u32,
+    /// Suggestions in today's batch.
+    pub new_suggestions: u32,
+    /// Sent notifications not acknowledged yet.
+    pub unread_notifications: u32,
+}
+
+/// Time bounds the counts are computed against, derived once per request so
+/// every counter agrees on "now".
+#[derive(Debug, Clone, PartialEq)]
+pub struct BadgeWindow {
+    /// Products expiring before this are already expired and not urgent.
+    pub now: DateTime<Utc>,
+    /// Products expiring at or after this are not urgent yet.
+    pub urgent_until: DateTime<Utc>,
+    /// Suggestion batches generated since this (UTC midnight) are new.
+    pub fresh_since: DateTime<Utc>,
+}
+
+impl BadgeWindow {
+    pub fn at(now: DateTime<Utc>, expiring_soon: ExpiringSoonWindow) -> Self {
+        Self {
+            now,
+            urgent_until: urgent_until(now, expiring_soon),
+            fresh_since: now.date_naive().and_time(NaiveTime::MIN).and_utc(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn should_cover_today_and_next_two_days_when_building_window() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 18, 30, 0).unwrap();
+
+        let window = BadgeWindow::at(now, ExpiringSoonWindow::default());
+
+        assert_eq!(
+            window.urgent_until,
+            Utc.with_ymd_and_hms(2026, 3, 13, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            window.fresh_since,
+            Utc.with_ymd_and_hms(2026, 3, 10, 0, 0, 0).unwrap()
+        );
+    }
+}
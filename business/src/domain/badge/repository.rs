@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use super::model::{BadgeCounts, BadgeWindow};
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Read model for badge counters. Implementations should answer with a single
+/// aggregate query: clients poll it frequently.
+#[async_trait]
+pub trait BadgeRepository: Send + Sync {
+    async fn get_counts(
+        &self,
+        user_id: &UserId,
+        window: &BadgeWindow,
+    ) -> Result<BadgeCounts, RepositoryError>;
+}
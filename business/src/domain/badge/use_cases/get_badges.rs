@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::badge::errors::BadgeError;
+use crate::domain::badge::model::BadgeCounts;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetBadgesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetBadgesUseCase: Send + Sync {
+    async fn execute(&self, params: GetBadgesParams) -> Result<BadgeCounts, BadgeError>;
+}
@@ -0,0 +1,23 @@
+use crate::domain::errors::ErrorSource;
+
+#[derive(Debug, thiserror::Error)]
+pub enum BarcodeContributionError {
+    #[error("barcode_contribution.invalid_barcode")]
+    InvalidBarcode,
+    #[error("barcode_contribution.name_empty")]
+    NameEmpty,
+    #[error("barcode_contribution.name_too_long")]
+    NameTooLong,
+    #[error("barcode_contribution.invalid_image")]
+    InvalidImage,
+    #[error("barcode_contribution.upstream_failed")]
+    UpstreamFailed(#[source] ErrorSource),
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
+
+impl BarcodeContributionError {
+    pub fn upstream_failed(cause: impl Into<ErrorSource>) -> Self {
+        BarcodeContributionError::UpstreamFailed(cause.into())
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{DateTime, Utc};
+
+use super::errors::BarcodeContributionError;
+use crate::domain::product::services::{
+    IdentificationConfidence, IdentificationMethod, ProductIdentification,
+};
+use crate::domain::shared::value_objects::UserId;
+
+const MAX_NAME_LENGTH: usize = 120;
+
+/// Where a contribution stands with Open Food Facts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpstreamStatus {
+    /// No Open Food Facts credentials are configured; the mapping is only
+    /// used here
+    LocalOnly,
+    /// Being sent to Open Food Facts
+    Pending,
+    /// Open Food Facts accepted the write
+    Submitted,
+    /// Open Food Facts could not be reached or refused the write
+    Failed,
+}
+
+impl std::fmt::Display for UpstreamStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UpstreamStatus::LocalOnly => write!(f, "local_only"),
+            UpstreamStatus::Pending => write!(f, "pending"),
+            UpstreamStatus::Submitted => write!(f, "submitted"),
+            UpstreamStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for UpstreamStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local_only" => Ok(UpstreamStatus::LocalOnly),
+            "pending" => Ok(UpstreamStatus::Pending),
+            "submitted" => Ok(UpstreamStatus::Submitted),
+            "failed" => Ok(UpstreamStatus::Failed),
+            _ => Err(format!("Invalid upstream status: {}", s)),
+        }
+    }
+}
+
+/// A name a user gave to a barcode Open Food Facts doesn't know. It answers
+/// that user's later scans of the barcode right away, whatever happens
+/// upstream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarcodeContribution {
+    pub user_id: UserId,
+    pub barcode: String,
+    pub name: String,
+    pub upstream_status: UpstreamStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BarcodeContribution {
+    pub fn new(
+        user_id: UserId,
+        barcode: &str,
+        name: &str,
+        upstream_status: UpstreamStatus,
+    ) -> Result<Self, BarcodeContributionError> {
+        let barcode = barcode.trim();
+        // EAN-8, UPC-A, EAN-13 and GTIN-14
+        if !(8..=14).contains(&barcode.len()) || !barcode.chars().all(|c| c.is_ascii_digit()) {
+            return Err(BarcodeContributionError::InvalidBarcode);
+        }
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(BarcodeContributionError::NameEmpty);
+        }
+        if name.chars().count() > MAX_NAME_LENGTH {
+            return Err(BarcodeContributionError::NameTooLong);
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            user_id,
+            barcode: barcode.to_string(),
+            name: name.to_string(),
+            upstream_status,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    pub fn set_upstream_status(&mut self, status: UpstreamStatus) {
+        self.upstream_status = status;
+        self.updated_at = Utc::now();
+    }
+
+    /// The contribution as the answer to a barcode lookup.
+    pub fn identification(&self) -> ProductIdentification {
+        ProductIdentification {
+            name: self.name.clone(),
+            confidence: IdentificationConfidence::High,
+            method: IdentificationMethod::Contribution,
+            suggested_location: None,
+            suggested_quantity: None,
+            suggested_expiry_type: None,
+            suggested_category: None,
+            enrichment: None,
+            ai_call_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_validate_barcode_and_name() {
+        let user_id = UserId::new("test-user-id");
+        let new = |barcode: &str, name: &str| {
+            BarcodeContribution::new(user_id.clone(), barcode, name, UpstreamStatus::LocalOnly)
+        };
+
+        let contribution = new(" 8410000000001 ", "  Turrón blando ").unwrap();
+        assert_eq!(contribution.barcode, "8410000000001");
+        assert_eq!(contribution.name, "Turrón blando");
+
+        assert!(matches!(
+            new("84100", "Turrón"),
+            Err(BarcodeContributionError::InvalidBarcode)
+        ));
+        assert!(matches!(
+            new("84100000000AB", "Turrón"),
+            Err(BarcodeContributionError::InvalidBarcode)
+        ));
+        assert!(matches!(
+            new("8410000000001", "   "),
+            Err(BarcodeContributionError::NameEmpty)
+        ));
+        assert!(matches!(
+            new("8410000000001", &"a".repeat(MAX_NAME_LENGTH + 1)),
+            Err(BarcodeContributionError::NameTooLong)
+        ));
+    }
+}
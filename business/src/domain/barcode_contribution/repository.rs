@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use super::model::BarcodeContribution;
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait BarcodeContributionRepository: Send + Sync {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        barcode: &str,
+    ) -> Result<Option<BarcodeContribution>, RepositoryError>;
+    /// Stores the contribution, replacing the user's earlier one for the
+    /// same barcode.
+    async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use super::errors::BarcodeContributionError;
+use crate::domain::storage::model::ImageUpload;
+
+/// Service port for adding products to a shared product database (Open Food
+/// Facts).
+#[async_trait]
+pub trait ProductDatabaseContributor: Send + Sync {
+    /// Creates or completes the product behind `barcode`, with its front
+    /// photo when there is one.
+    async fn contribute(
+        &self,
+        barcode: &str,
+        name: &str,
+        photo: Option<ImageUpload>,
+    ) -> Result<(), BarcodeContributionError>;
+}
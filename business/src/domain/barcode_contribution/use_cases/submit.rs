@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::barcode_contribution::errors::BarcodeContributionError;
+use crate::domain::barcode_contribution::model::BarcodeContribution;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct SubmitBarcodeContributionParams {
+    pub user_id: UserId,
+    pub barcode: String,
+    pub name: String,
+    pub image_base64: Option<String>,
+}
+
+#[async_trait]
+pub trait SubmitBarcodeContributionUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: SubmitBarcodeContributionParams,
+    ) -> Result<BarcodeContribution, BarcodeContributionError>;
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BillingError {
+    #[error("billing.checkout_failed")]
+    CheckoutFailed,
+    #[error("billing.invalid_signature")]
+    InvalidSignature,
+    #[error("billing.invalid_event")]
+    InvalidEvent,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
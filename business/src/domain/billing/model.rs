@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::shared::value_objects::UserId;
+
+/// Hosted checkout page where the user pays for the premium plan.
+#[derive(Debug, Clone)]
+pub struct CheckoutSession {
+    pub id: String,
+    pub url: String,
+}
+
+/// Plan change announced by the payment provider through a verified webhook.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BillingEvent {
+    /// Payment went through or the subscription became active again.
+    SubscriptionActivated {
+        user_id: UserId,
+        customer_id: Option<String>,
+    },
+    /// The subscription ended; the user goes back to the free plan.
+    SubscriptionCanceled { user_id: UserId },
+    /// Event types that do not affect plans.
+    Ignored,
+}
+
+/// A verified webhook delivery. The provider sends each event at least once
+/// and in no particular order, so it comes with its id and creation time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookEvent {
+    /// Same on every retry of the event.
+    pub id: String,
+    pub created: DateTime<Utc>,
+    pub change: BillingEvent,
+}
+
+/// What storing the plan change of a webhook event did.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanChangeOutcome {
+    Applied,
+    /// The event was processed before.
+    Duplicate,
+    /// A newer event already set the user's plan.
+    Stale,
+}
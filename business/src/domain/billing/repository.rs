@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::model::{PlanChangeOutcome, WebhookEvent};
+use crate::domain::errors::RepositoryError;
+use crate::domain::quota::model::PlanTier;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait PlanRepository: Send + Sync {
+    /// Stores the user's plan as set by `event`, unless that event was already
+    /// processed or is older than the one behind the current plan. A `None`
+    /// customer keeps the one already on file.
+    async fn set_plan(
+        &self,
+        user_id: &UserId,
+        plan: PlanTier,
+        customer_id: Option<String>,
+        event: &WebhookEvent,
+    ) -> Result<PlanChangeOutcome, RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use super::errors::BillingError;
+use super::model::{CheckoutSession, WebhookEvent};
+use crate::domain::shared::value_objects::UserId;
+
+/// Service port for the payment provider that sells the premium plan.
+#[async_trait]
+pub trait PlanProvider: Send + Sync {
+    /// Opens a checkout session for the premium subscription.
+    async fn create_checkout_session(
+        &self,
+        user_id: &UserId,
+    ) -> Result<CheckoutSession, BillingError>;
+
+    /// Verifies a webhook signature and translates the event into a plan change.
+    fn parse_webhook(&self, payload: &[u8], signature: &str) -> Result<WebhookEvent, BillingError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::billing::errors::BillingError;
+use crate::domain::billing::model::CheckoutSession;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CreateCheckoutParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait CreateCheckoutUseCase: Send + Sync {
+    async fn execute(&self, params: CreateCheckoutParams) -> Result<CheckoutSession, BillingError>;
+}
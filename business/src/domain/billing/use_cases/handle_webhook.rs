@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::billing::errors::BillingError;
+
+pub struct HandleWebhookParams {
+    pub payload: Vec<u8>,
+    pub signature: String,
+}
+
+#[async_trait]
+pub trait HandleWebhookUseCase: Send + Sync {
+    async fn execute(&self, params: HandleWebhookParams) -> Result<(), BillingError>;
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BudgetError {
+    #[error("budget.invalid_amount")]
+    InvalidAmount,
+    #[error("budget.not_set")]
+    NotSet,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
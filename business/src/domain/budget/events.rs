@@ -0,0 +1,12 @@
+use super::model::BudgetThreshold;
+use crate::domain::shared::value_objects::UserId;
+
+/// A purchase took the month's grocery spend past one of the budget's alert
+/// thresholds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetThresholdReached {
+    pub user_id: UserId,
+    pub threshold: BudgetThreshold,
+    pub spent_cents: u64,
+    pub budget_cents: u64,
+}
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+
+use super::errors::BudgetError;
+
+/// A user's monthly grocery budget, in cents of the server's currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Budget {
+    pub monthly_cents: u64,
+    /// Notify at 80% and 100% of the budget
+    pub alerts_enabled: bool,
+}
+
+impl Budget {
+    /// Upper bound on a monthly budget: 1,000,000 in the server's currency.
+    pub const MAX_MONTHLY_CENTS: u64 = 100_000_000;
+
+    pub fn new(monthly_cents: u64, alerts_enabled: bool) -> Result<Self, BudgetError> {
+        if monthly_cents == 0 || monthly_cents > Self::MAX_MONTHLY_CENTS {
+            return Err(BudgetError::InvalidAmount);
+        }
+        Ok(Self {
+            monthly_cents,
+            alerts_enabled,
+        })
+    }
+}
+
+/// Share of the budget spent that triggers an alert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BudgetThreshold {
+    /// 80% spent
+    Warning,
+    /// The whole budget spent
+    Exceeded,
+}
+
+impl BudgetThreshold {
+    pub fn percent(&self) -> u64 {
+        match self {
+            BudgetThreshold::Warning => 80,
+            BudgetThreshold::Exceeded => 100,
+        }
+    }
+
+    /// The highest threshold a purchase took the spend past, if any.
+    pub fn crossed(budget_cents: u64, before_cents: u64, after_cents: u64) -> Option<Self> {
+        [BudgetThreshold::Exceeded, BudgetThreshold::Warning]
+            .into_iter()
+            .find(|threshold| {
+                let at = budget_cents * threshold.percent() / 100;
+                before_cents < at && after_cents >= at
+            })
+    }
+}
+
+/// First instant of the calendar month (UTC) `now` falls in.
+pub fn month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    now.date_naive()
+        .with_day(1)
+        .unwrap_or(now.date_naive())
+        .and_time(chrono::NaiveTime::MIN)
+        .and_utc()
+}
+
+/// Spend against the budget for the current calendar month (UTC).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetStatus {
+    /// First day of the month
+    pub month: NaiveDate,
+    pub budget_cents: u64,
+    pub spent_cents: u64,
+    /// Spend by the end of the month if the user keeps the pace so far
+    pub projected_cents: u64,
+    /// How far the projection goes over the budget; zero when it doesn't
+    pub projected_overrun_cents: u64,
+    pub alerts_enabled: bool,
+}
+
+impl BudgetStatus {
+    pub fn compute(budget: &Budget, spent_cents: u64, now: DateTime<Utc>) -> Self {
+        let month = month_start(now).date_naive();
+        let days_in_month = month
+            .checked_add_months(Months::new(1))
+            .map(|next| (next - month).num_days() as u64)
+            .unwrap_or(30);
+        // Today counts as elapsed, so a purchase on the 1st projects a full month of them
+        let days_elapsed = u64::from(now.day());
+        let projected_cents = spent_cents * days_in_month / days_elapsed;
+
+        Self {
+            month,
+            budget_cents: budget.monthly_cents,
+            spent_cents,
+            projected_cents,
+            projected_overrun_cents: projected_cents.saturating_sub(budget.monthly_cents),
+            alerts_enabled: budget.alerts_enabled,
+        }
+    }
+
+    /// Share of the budget spent so far, rounded down.
+    pub fn percent_used(&self) -> u32 {
+        (self.spent_cents * 100 / self.budget_cents) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn should_reject_zero_and_oversized_budgets() {
+        assert!(matches!(
+            Budget::new(0, true),
+            Err(BudgetError::InvalidAmount)
+        ));
+        assert!(matches!(
+            Budget::new(Budget::MAX_MONTHLY_CENTS + 1, true),
+            Err(BudgetError::InvalidAmount)
+        ));
+        assert!(Budget::new(30_000, false).is_ok());
+    }
+
+    #[test]
+    fn should_project_spend_at_the_current_pace() {
+        let budget = Budget::new(30_000, true).unwrap();
+        // Day 10 of a 30-day month, a third of the budget spent
+        let now = Utc.with_ymd_and_hms(2026, 4, 10, 18, 0, 0).unwrap();
+
+        let status = BudgetStatus::compute(&budget, 12_000, now);
+
+        assert_eq!(status.month, NaiveDate::from_ymd_opt(2026, 4, 1).unwrap());
+        assert_eq!(status.projected_cents, 36_000);
+        assert_eq!(status.projected_overrun_cents, 6_000);
+        assert_eq!(status.percent_used(), 40);
+    }
+
+    #[test]
+    fn should_report_highest_threshold_crossed() {
+        assert_eq!(BudgetThreshold::crossed(10_000, 7_000, 7_900), None);
+        assert_eq!(
+            BudgetThreshold::crossed(10_000, 7_000, 8_000),
+            Some(BudgetThreshold::Warning)
+        );
+        assert_eq!(
+            BudgetThreshold::crossed(10_000, 7_000, 10_500),
+            Some(BudgetThreshold::Exceeded)
+        );
+        assert_eq!(BudgetThreshold::crossed(10_000, 8_500, 9_000), None);
+    }
+}
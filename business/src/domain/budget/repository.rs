@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::Budget;
+
+#[async_trait]
+pub trait BudgetRepository: Send + Sync {
+    async fn get(&self, user_id: &UserId) -> Result<Option<Budget>, RepositoryError>;
+    async fn save(&self, user_id: &UserId, budget: &Budget) -> Result<(), RepositoryError>;
+    /// Removing a budget that was never set is not an error.
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+    /// Sum of the prices recorded for items bought on shopping trips since
+    /// `since`, in cents.
+    async fn get_spent_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<u64, RepositoryError>;
+}
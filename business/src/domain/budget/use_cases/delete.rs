@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct DeleteBudgetParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait DeleteBudgetUseCase: Send + Sync {
+    async fn execute(&self, params: DeleteBudgetParams) -> Result<(), BudgetError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::model::BudgetStatus;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetBudgetStatusParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetBudgetStatusUseCase: Send + Sync {
+    async fn execute(&self, params: GetBudgetStatusParams) -> Result<BudgetStatus, BudgetError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::budget::errors::BudgetError;
+use crate::domain::budget::model::Budget;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct SetBudgetParams {
+    pub user_id: UserId,
+    /// Must be within `1..=Budget::MAX_MONTHLY_CENTS`
+    pub monthly_cents: u64,
+    pub alerts_enabled: bool,
+}
+
+#[async_trait]
+pub trait SetBudgetUseCase: Send + Sync {
+    async fn execute(&self, params: SetBudgetParams) -> Result<Budget, BudgetError>;
+}
@@ -0,0 +1,13 @@
+use uuid::Uuid;
+
+use super::model::ChallengeKind;
+use crate::domain::shared::value_objects::UserId;
+
+/// A user reached the target of a challenge before it ended.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeCompleted {
+    pub challenge_id: Uuid,
+    pub user_id: UserId,
+    pub kind: ChallengeKind,
+    pub target: u32,
+}
@@ -0,0 +1,234 @@
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::product::events::ProductOutcomeRecorded;
+use crate::domain::shared::value_objects::UserId;
+
+/// What a challenge asks the user to do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeKind {
+    /// Eat products that were about to expire, e.g. "use 3 urgent items
+    /// before Sunday".
+    UseUrgentItems,
+}
+
+impl ChallengeKind {
+    /// Whether a recorded outcome moves a challenge of this kind forward.
+    pub fn counts(&self, outcome: &ProductOutcomeRecorded) -> bool {
+        match self {
+            ChallengeKind::UseUrgentItems => outcome.used_in_time(),
+        }
+    }
+}
+
+impl std::fmt::Display for ChallengeKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChallengeKind::UseUrgentItems => write!(f, "use_urgent_items"),
+        }
+    }
+}
+
+impl std::str::FromStr for ChallengeKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "use_urgent_items" => Ok(ChallengeKind::UseUrgentItems),
+            _ => Err(format!("Invalid challenge kind: {}", s)),
+        }
+    }
+}
+
+/// Where a challenge stands at a given moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChallengeStatus {
+    Active,
+    Completed,
+    /// The week ended before the target was reached.
+    Expired,
+}
+
+impl std::fmt::Display for ChallengeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ChallengeStatus::Active => write!(f, "active"),
+            ChallengeStatus::Completed => write!(f, "completed"),
+            ChallengeStatus::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Server-wide shape of the weekly challenge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChallengeSettings {
+    pub kind: ChallengeKind,
+    /// Products to count before the week ends
+    pub target: u32,
+}
+
+impl Default for ChallengeSettings {
+    fn default() -> Self {
+        Self {
+            kind: ChallengeKind::UseUrgentItems,
+            target: 3,
+        }
+    }
+}
+
+/// A goal the user has one week to reach, Monday to Sunday in UTC. Each
+/// user gets one per week, started the first time it is needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Challenge {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub kind: ChallengeKind,
+    pub target: u32,
+    pub progress: u32,
+    pub starts_at: DateTime<Utc>,
+    /// Exclusive: the following Monday at midnight
+    pub ends_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Challenge {
+    /// The challenge for the week containing `now`.
+    pub fn weekly(user_id: UserId, settings: ChallengeSettings, now: DateTime<Utc>) -> Self {
+        let starts_at = week_start(now);
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            kind: settings.kind,
+            target: settings.target,
+            progress: 0,
+            starts_at,
+            ends_at: starts_at + Duration::days(7),
+            completed_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        kind: ChallengeKind,
+        target: u32,
+        progress: u32,
+        starts_at: DateTime<Utc>,
+        ends_at: DateTime<Utc>,
+        completed_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            kind,
+            target,
+            progress,
+            starts_at,
+            ends_at,
+            completed_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn status(&self, now: DateTime<Utc>) -> ChallengeStatus {
+        if self.completed_at.is_some() {
+            ChallengeStatus::Completed
+        } else if now >= self.ends_at {
+            ChallengeStatus::Expired
+        } else {
+            ChallengeStatus::Active
+        }
+    }
+
+    /// Counts one step towards the target. Returns `true` when this step
+    /// completed the challenge; steps outside its week or after completion
+    /// are ignored.
+    pub fn record_progress(&mut self, at: DateTime<Utc>) -> bool {
+        if self.status(at) != ChallengeStatus::Active || at < self.starts_at {
+            return false;
+        }
+
+        self.progress += 1;
+        self.updated_at = at;
+        if self.progress >= self.target {
+            self.completed_at = Some(at);
+            return true;
+        }
+        false
+    }
+}
+
+/// Monday at midnight UTC of the week containing `now`.
+pub fn week_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    let monday = now.date_naive() - Duration::days(now.weekday().num_days_from_monday().into());
+    monday.and_time(NaiveTime::MIN).and_utc()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn wednesday() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 11, 18, 30, 0).unwrap()
+    }
+
+    #[test]
+    fn should_span_monday_to_sunday_when_started_midweek() {
+        let challenge =
+            Challenge::weekly(test_user_id(), ChallengeSettings::default(), wednesday());
+
+        assert_eq!(
+            challenge.starts_at,
+            Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap()
+        );
+        assert_eq!(
+            challenge.ends_at,
+            Utc.with_ymd_and_hms(2026, 3, 16, 0, 0, 0).unwrap()
+        );
+        assert_eq!(challenge.status(wednesday()), ChallengeStatus::Active);
+    }
+
+    #[test]
+    fn should_complete_once_when_target_reached() {
+        let mut challenge =
+            Challenge::weekly(test_user_id(), ChallengeSettings::default(), wednesday());
+
+        assert!(!challenge.record_progress(wednesday()));
+        assert!(!challenge.record_progress(wednesday()));
+        assert!(challenge.record_progress(wednesday()));
+        assert!(!challenge.record_progress(wednesday()));
+
+        assert_eq!(challenge.progress, 3);
+        assert_eq!(challenge.completed_at, Some(wednesday()));
+        assert_eq!(challenge.status(wednesday()), ChallengeStatus::Completed);
+    }
+
+    #[test]
+    fn should_ignore_progress_after_week_ended() {
+        let mut challenge =
+            Challenge::weekly(test_user_id(), ChallengeSettings::default(), wednesday());
+
+        let recorded = challenge.record_progress(challenge.ends_at);
+
+        assert!(!recorded);
+        assert_eq!(challenge.progress, 0);
+        assert_eq!(
+            challenge.status(challenge.ends_at),
+            ChallengeStatus::Expired
+        );
+    }
+}
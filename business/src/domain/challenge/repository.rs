@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use super::model::Challenge;
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::pagination::KeysetPage;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait ChallengeRepository: Send + Sync {
+    /// Stores `challenge` unless the user already has one of the same kind
+    /// for that week, and returns whichever is stored. Concurrent callers
+    /// starting the same week all get the same challenge.
+    async fn start(&self, challenge: &Challenge) -> Result<Challenge, RepositoryError>;
+    async fn update(&self, challenge: &Challenge) -> Result<(), RepositoryError>;
+    /// Up to `page.fetch_limit()` challenges, newest week first, including
+    /// the current one.
+    async fn get_history(
+        &self,
+        user_id: &UserId,
+        page: &KeysetPage,
+    ) -> Result<Vec<Challenge>, RepositoryError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::challenge::errors::ChallengeError;
+use crate::domain::challenge::model::Challenge;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetCurrentChallengeParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetCurrentChallengeUseCase: Send + Sync {
+    /// This week's challenge, started on first access.
+    async fn execute(&self, params: GetCurrentChallengeParams)
+    -> Result<Challenge, ChallengeError>;
+}
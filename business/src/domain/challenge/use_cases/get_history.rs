@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::challenge::errors::ChallengeError;
+use crate::domain::challenge::model::Challenge;
+use crate::domain::shared::pagination::{CursorPage, KeysetPage};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetChallengeHistoryParams {
+    pub user_id: UserId,
+    pub page: KeysetPage,
+}
+
+#[async_trait]
+pub trait GetChallengeHistoryUseCase: Send + Sync {
+    /// Challenges of past weeks and this one, newest first, one keyset page
+    /// at a time.
+    async fn execute(
+        &self,
+        params: GetChallengeHistoryParams,
+    ) -> Result<CursorPage<Challenge>, ChallengeError>;
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum CookingSessionError {
+    #[error("cooking_session.not_found")]
+    NotFound,
+    #[error("cooking_session.suggestion_not_found")]
+    SuggestionNotFound,
+    #[error("cooking_session.no_steps")]
+    NoSteps,
+    #[error("cooking_session.invalid_step")]
+    InvalidStep,
+    #[error("cooking_session.no_remaining_steps")]
+    NoRemainingSteps,
+    #[error("cooking_session.already_completed")]
+    AlreadyCompleted,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,343 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::errors::CookingSessionError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::suggestion::model::{Suggestion, SuggestionIngredient};
+
+/// State of a single step within a cooking session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CookingStepStatus {
+    Pending,
+    Active,
+    Done,
+}
+
+impl std::fmt::Display for CookingStepStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CookingStepStatus::Pending => write!(f, "pending"),
+            CookingStepStatus::Active => write!(f, "active"),
+            CookingStepStatus::Done => write!(f, "done"),
+        }
+    }
+}
+
+impl std::str::FromStr for CookingStepStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(CookingStepStatus::Pending),
+            "active" => Ok(CookingStepStatus::Active),
+            "done" => Ok(CookingStepStatus::Done),
+            _ => Err(format!("Invalid cooking step status: {}", s)),
+        }
+    }
+}
+
+/// Lifecycle state of a cooking session.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CookingSessionStatus {
+    InProgress,
+    Completed,
+}
+
+impl std::fmt::Display for CookingSessionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CookingSessionStatus::InProgress => write!(f, "in_progress"),
+            CookingSessionStatus::Completed => write!(f, "completed"),
+        }
+    }
+}
+
+impl std::str::FromStr for CookingSessionStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "in_progress" => Ok(CookingSessionStatus::InProgress),
+            "completed" => Ok(CookingSessionStatus::Completed),
+            _ => Err(format!("Invalid cooking session status: {}", s)),
+        }
+    }
+}
+
+/// A recipe step tracked while cooking. Positions are zero-based.
+#[derive(Debug, Clone)]
+pub struct CookingStep {
+    pub position: usize,
+    pub instruction: String,
+    pub status: CookingStepStatus,
+}
+
+/// A user walking through the steps of a suggestion.
+///
+/// The session keeps its own copy of the recipe so it can be resumed even after
+/// the suggestion batch it came from has been replaced.
+#[derive(Debug, Clone)]
+pub struct CookingSession {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub suggestion_id: String,
+    pub title: String,
+    pub ingredients: Vec<SuggestionIngredient>,
+    pub steps: Vec<CookingStep>,
+    pub status: CookingSessionStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl CookingSession {
+    /// Starts a new session from a suggestion, activating its first step.
+    pub fn start(user_id: UserId, suggestion: &Suggestion) -> Result<Self, CookingSessionError> {
+        let steps: Vec<CookingStep> = suggestion
+            .steps
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|s| !s.trim().is_empty())
+            .enumerate()
+            .map(|(position, instruction)| CookingStep {
+                position,
+                instruction: instruction.trim().to_string(),
+                status: if position == 0 {
+                    CookingStepStatus::Active
+                } else {
+                    CookingStepStatus::Pending
+                },
+            })
+            .collect();
+
+        if steps.is_empty() {
+            return Err(CookingSessionError::NoSteps);
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            suggestion_id: suggestion.id.clone(),
+            title: suggestion.title.clone(),
+            ingredients: suggestion.ingredients.clone(),
+            steps,
+            status: CookingSessionStatus::InProgress,
+            created_at: now,
+            updated_at: now,
+            completed_at: None,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        suggestion_id: String,
+        title: String,
+        ingredients: Vec<SuggestionIngredient>,
+        steps: Vec<CookingStep>,
+        status: CookingSessionStatus,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+        completed_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            suggestion_id,
+            title,
+            ingredients,
+            steps,
+            status,
+            created_at,
+            updated_at,
+            completed_at,
+        }
+    }
+
+    /// Returns the step the user is currently working on.
+    pub fn current_step(&self) -> Option<&CookingStep> {
+        self.steps
+            .iter()
+            .find(|s| s.status == CookingStepStatus::Active)
+    }
+
+    /// Marks the active step as done and activates the next pending one.
+    pub fn advance(&mut self) -> Result<(), CookingSessionError> {
+        self.ensure_in_progress()?;
+
+        let position = self
+            .current_step()
+            .map(|s| s.position)
+            .ok_or(CookingSessionError::NoRemainingSteps)?;
+
+        self.complete_step(position)
+    }
+
+    /// Marks a specific step as done, allowing users to skip ahead or tick steps
+    /// out of order. The first remaining pending step becomes active.
+    pub fn complete_step(&mut self, position: usize) -> Result<(), CookingSessionError> {
+        self.ensure_in_progress()?;
+
+        let step = self
+            .steps
+            .get_mut(position)
+            .ok_or(CookingSessionError::InvalidStep)?;
+        step.status = CookingStepStatus::Done;
+
+        if self.current_step().is_none()
+            && let Some(next) = self
+                .steps
+                .iter_mut()
+                .find(|s| s.status == CookingStepStatus::Pending)
+        {
+            next.status = CookingStepStatus::Active;
+        }
+
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Finishes the session, marking every remaining step as done.
+    pub fn complete(&mut self) -> Result<(), CookingSessionError> {
+        self.ensure_in_progress()?;
+
+        for step in self.steps.iter_mut() {
+            step.status = CookingStepStatus::Done;
+        }
+
+        let now = Utc::now();
+        self.status = CookingSessionStatus::Completed;
+        self.updated_at = now;
+        self.completed_at = Some(now);
+        Ok(())
+    }
+
+    fn ensure_in_progress(&self) -> Result<(), CookingSessionError> {
+        if self.status == CookingSessionStatus::Completed {
+            return Err(CookingSessionError::AlreadyCompleted);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::suggestion::model::TimeRange;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn tortilla(steps: Option<Vec<String>>) -> Suggestion {
+        Suggestion {
+            id: "openai-1700000000000-0".to_string(),
+            title: "Tortilla de patatas".to_string(),
+            description: None,
+            estimated_time: TimeRange::Medium,
+            ingredients: vec![SuggestionIngredient {
+                product_id: Uuid::new_v4().to_string(),
+                product_name: "Huevos".to_string(),
+                quantity: Some("4".to_string()),
+                is_urgent: true,
+            }],
+            urgent_ingredients: vec![],
+            steps,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn three_steps() -> Option<Vec<String>> {
+        Some(vec![
+            "Pelar y cortar las patatas".to_string(),
+            "Freír las patatas a fuego lento".to_string(),
+            "Cuajar la tortilla con los huevos".to_string(),
+        ])
+    }
+
+    #[test]
+    fn should_activate_first_step_when_started() {
+        let session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+
+        assert_eq!(session.steps.len(), 3);
+        assert_eq!(session.status, CookingSessionStatus::InProgress);
+        assert_eq!(session.current_step().unwrap().position, 0);
+        assert_eq!(session.steps[1].status, CookingStepStatus::Pending);
+    }
+
+    #[test]
+    fn should_reject_when_suggestion_has_no_steps() {
+        let result = CookingSession::start(test_user_id(), &tortilla(None));
+
+        assert!(matches!(result.unwrap_err(), CookingSessionError::NoSteps));
+    }
+
+    #[test]
+    fn should_move_to_next_step_when_advancing() {
+        let mut session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+
+        session.advance().unwrap();
+
+        assert_eq!(session.steps[0].status, CookingStepStatus::Done);
+        assert_eq!(session.current_step().unwrap().position, 1);
+    }
+
+    #[test]
+    fn should_fail_to_advance_when_all_steps_done() {
+        let mut session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+        session.advance().unwrap();
+        session.advance().unwrap();
+        session.advance().unwrap();
+
+        let result = session.advance();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CookingSessionError::NoRemainingSteps
+        ));
+    }
+
+    #[test]
+    fn should_keep_active_step_when_completing_a_later_one() {
+        let mut session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+
+        session.complete_step(2).unwrap();
+
+        assert_eq!(session.steps[2].status, CookingStepStatus::Done);
+        assert_eq!(session.current_step().unwrap().position, 0);
+    }
+
+    #[test]
+    fn should_reject_when_step_position_out_of_range() {
+        let mut session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+
+        let result = session.complete_step(7);
+
+        assert!(matches!(
+            result.unwrap_err(),
+            CookingSessionError::InvalidStep
+        ));
+    }
+
+    #[test]
+    fn should_reject_changes_when_already_completed() {
+        let mut session = CookingSession::start(test_user_id(), &tortilla(three_steps())).unwrap();
+        session.complete().unwrap();
+
+        assert!(session.completed_at.is_some());
+        assert!(
+            session
+                .steps
+                .iter()
+                .all(|s| s.status == CookingStepStatus::Done)
+        );
+        assert!(matches!(
+            session.advance().unwrap_err(),
+            CookingSessionError::AlreadyCompleted
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::CookingSession;
+
+#[async_trait]
+pub trait CookingSessionRepository: Send + Sync {
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+    ) -> Result<CookingSession, RepositoryError>;
+    /// Returns the unfinished session started from the given suggestion, if any.
+    async fn find_in_progress_by_suggestion(
+        &self,
+        suggestion_id: &str,
+        user_id: &UserId,
+    ) -> Result<Option<CookingSession>, RepositoryError>;
+    async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+    async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct AdvanceCookingStepParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait AdvanceCookingStepUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: AdvanceCookingStepParams,
+    ) -> Result<CookingSession, CookingSessionError>;
+}
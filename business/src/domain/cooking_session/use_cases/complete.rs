@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CompleteCookingSessionParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+/// Finishes a cooking session and decrements the pantry products it used.
+#[async_trait]
+pub trait CompleteCookingSessionUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: CompleteCookingSessionParams,
+    ) -> Result<CookingSession, CookingSessionError>;
+}
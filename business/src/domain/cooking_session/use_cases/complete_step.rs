@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CompleteCookingStepParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub position: usize,
+}
+
+#[async_trait]
+pub trait CompleteCookingStepUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: CompleteCookingStepParams,
+    ) -> Result<CookingSession, CookingSessionError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::cooking_session::errors::CookingSessionError;
+use crate::domain::cooking_session::model::CookingSession;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct StartCookingParams {
+    pub user_id: UserId,
+    pub suggestion_id: String,
+}
+
+#[async_trait]
+pub trait StartCookingUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: StartCookingParams,
+    ) -> Result<CookingSession, CookingSessionError>;
+}
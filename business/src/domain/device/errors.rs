@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum DeviceError {
+    #[error("device.invalid_installation_id")]
+    InvalidInstallationId,
+    #[error("device.invalid_app_version")]
+    InvalidAppVersion,
+    #[error("device.invalid_push_token")]
+    InvalidPushToken,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
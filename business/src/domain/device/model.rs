@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+
+use super::errors::DeviceError;
+use crate::domain::shared::value_objects::UserId;
+
+const MAX_INSTALLATION_ID_LENGTH: usize = 128;
+const MAX_APP_VERSION_LENGTH: usize = 32;
+const MAX_PUSH_TOKEN_LENGTH: usize = 4096;
+
+/// Operating system an app install runs on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DevicePlatform {
+    Ios,
+    Android,
+    Web,
+}
+
+impl std::fmt::Display for DevicePlatform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DevicePlatform::Ios => write!(f, "ios"),
+            DevicePlatform::Android => write!(f, "android"),
+            DevicePlatform::Web => write!(f, "web"),
+        }
+    }
+}
+
+impl std::str::FromStr for DevicePlatform {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ios" => Ok(DevicePlatform::Ios),
+            "android" => Ok(DevicePlatform::Android),
+            "web" => Ok(DevicePlatform::Web),
+            _ => Err(format!("Invalid device platform: {}", s)),
+        }
+    }
+}
+
+/// One install of the app signed in as the user, identified by an ID the
+/// app generates on first launch. The app registers it again on every
+/// launch, which keeps `last_seen_at` current; installs not seen for a
+/// while are pruned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Device {
+    pub user_id: UserId,
+    pub installation_id: String,
+    pub platform: DevicePlatform,
+    pub app_version: String,
+    /// Where notifications for this install are pushed; `None` when the user
+    /// didn't allow them
+    pub push_token: Option<String>,
+    pub registered_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl Device {
+    /// Validates what the app reports. Blank push tokens count as none.
+    pub fn register(
+        user_id: UserId,
+        installation_id: &str,
+        platform: DevicePlatform,
+        app_version: &str,
+        push_token: Option<&str>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DeviceError> {
+        let installation_id = installation_id.trim();
+        if installation_id.is_empty() || installation_id.len() > MAX_INSTALLATION_ID_LENGTH {
+            return Err(DeviceError::InvalidInstallationId);
+        }
+        let app_version = app_version.trim();
+        if app_version.is_empty() || app_version.len() > MAX_APP_VERSION_LENGTH {
+            return Err(DeviceError::InvalidAppVersion);
+        }
+        let push_token = push_token.map(str::trim).filter(|t| !t.is_empty());
+        if push_token.is_some_and(|t| t.len() > MAX_PUSH_TOKEN_LENGTH) {
+            return Err(DeviceError::InvalidPushToken);
+        }
+
+        Ok(Self {
+            user_id,
+            installation_id: installation_id.to_string(),
+            platform,
+            app_version: app_version.to_string(),
+            push_token: push_token.map(str::to_string),
+            registered_at: now,
+            last_seen_at: now,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        user_id: UserId,
+        installation_id: String,
+        platform: DevicePlatform,
+        app_version: String,
+        push_token: Option<String>,
+        registered_at: DateTime<Utc>,
+        last_seen_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            installation_id,
+            platform,
+            app_version,
+            push_token,
+            registered_at,
+            last_seen_at,
+        }
+    }
+}
+
+/// Installs of one app version on one platform seen in a period, across
+/// all users.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstallCount {
+    pub platform: DevicePlatform,
+    pub app_version: String,
+    pub installs: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn register(installation_id: &str, push_token: Option<&str>) -> Result<Device, DeviceError> {
+        Device::register(
+            UserId::new("test-user-id"),
+            installation_id,
+            DevicePlatform::Android,
+            " 2.4.0 ",
+            push_token,
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn should_trim_app_version_and_drop_blank_push_token() {
+        let device = register("install-1", Some("   ")).unwrap();
+
+        assert_eq!(device.app_version, "2.4.0");
+        assert_eq!(device.push_token, None);
+        assert_eq!(device.registered_at, device.last_seen_at);
+    }
+
+    #[test]
+    fn should_reject_blank_or_oversized_installation_id() {
+        assert!(matches!(
+            register("  ", None),
+            Err(DeviceError::InvalidInstallationId)
+        ));
+        assert!(matches!(
+            register(&"x".repeat(MAX_INSTALLATION_ID_LENGTH + 1), None),
+            Err(DeviceError::InvalidInstallationId)
+        ));
+    }
+
+    #[test]
+    fn should_reject_oversized_push_token() {
+        let token = "t".repeat(MAX_PUSH_TOKEN_LENGTH + 1);
+
+        assert!(matches!(
+            register("install-1", Some(&token)),
+            Err(DeviceError::InvalidPushToken)
+        ));
+    }
+}
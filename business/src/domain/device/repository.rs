@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::model::{Device, InstallCount};
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait DeviceRepository: Send + Sync {
+    /// Stores the install, or refreshes it when the user already registered
+    /// it; the first registration time is kept. A push token belongs to one
+    /// install only: it is cleared from any other install holding it, such as
+    /// the same phone signed in as another user before. Returns the install
+    /// as stored.
+    async fn register(&self, device: &Device) -> Result<Device, RepositoryError>;
+    /// The user's installs that can receive pushes, most recently seen first.
+    async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError>;
+    /// Installs seen at or after `since` per platform and app version, most
+    /// installs first.
+    async fn count_seen_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<InstallCount>, RepositoryError>;
+    /// Deletes the installs last seen before `before`; returns how many.
+    async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
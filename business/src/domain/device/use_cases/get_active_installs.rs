@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::device::errors::DeviceError;
+use crate::domain::device::model::InstallCount;
+
+pub struct GetActiveInstallsParams {
+    /// Installs seen within this many days count as active
+    pub days: u32,
+}
+
+#[async_trait]
+pub trait GetActiveInstallsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetActiveInstallsParams,
+    ) -> Result<Vec<InstallCount>, DeviceError>;
+}
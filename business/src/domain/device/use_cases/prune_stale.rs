@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::device::errors::DeviceError;
+
+pub struct PruneStaleDevicesParams {
+    /// Installs not seen for this many days are deleted
+    pub stale_after_days: u32,
+}
+
+#[async_trait]
+pub trait PruneStaleDevicesUseCase: Send + Sync {
+    /// Deletes the installs not seen for a while; returns how many.
+    async fn execute(&self, params: PruneStaleDevicesParams) -> Result<u64, DeviceError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::device::errors::DeviceError;
+use crate::domain::device::model::{Device, DevicePlatform};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct RegisterDeviceParams {
+    pub user_id: UserId,
+    pub installation_id: String,
+    pub platform: DevicePlatform,
+    pub app_version: String,
+    pub push_token: Option<String>,
+}
+
+#[async_trait]
+pub trait RegisterDeviceUseCase: Send + Sync {
+    /// Registers the install, or refreshes it when already registered.
+    async fn execute(&self, params: RegisterDeviceParams) -> Result<Device, DeviceError>;
+}
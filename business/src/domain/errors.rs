@@ -1,3 +1,5 @@
+pub use shared_kernel::errors::{ErrorChain, ErrorSource};
+
 /// Repository errors for domain layer.
 /// Use code-style identifiers for all error variants for i18n compatibility.
 #[derive(Debug, thiserror::Error)]
@@ -9,7 +11,7 @@ pub enum RepositoryError {
     #[error("repository.duplicated")]
     Duplicated,
     #[error("repository.database_error")]
-    DatabaseError,
+    DatabaseError(#[source] ErrorSource),
 }
 
 impl RepositoryError {
@@ -22,7 +24,24 @@ impl RepositoryError {
     pub fn duplicated() -> Self {
         RepositoryError::Duplicated
     }
-    pub fn database_error() -> Self {
-        RepositoryError::DatabaseError
+    pub fn database_error(source: impl Into<ErrorSource>) -> Self {
+        RepositoryError::DatabaseError(source.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::errors::ProductError;
+
+    #[test]
+    fn should_display_every_cause_in_the_chain() {
+        let err = ProductError::from(RepositoryError::database_error("connection reset"));
+
+        assert_eq!(err.to_string(), "repository.persistence");
+        assert_eq!(
+            ErrorChain(&err).to_string(),
+            "repository.persistence: repository.database_error: connection reset"
+        );
     }
 }
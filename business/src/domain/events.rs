@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::domain::budget::events::BudgetThresholdReached;
+use crate::domain::challenge::events::ChallengeCompleted;
+use crate::domain::product::events::{
+    ProductAdded, ProductDeleted, ProductOutcomeRecorded, ProductStatusChanged, ProductUpdated,
+};
+use crate::domain::shopping_trip::events::PurchaseRecorded;
+use crate::domain::stats::events::StreakMilestoneReached;
+use crate::domain::vacation::events::VacationEnded;
+
+/// Facts raised by use cases that other parts of the domain react to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DomainEvent {
+    BudgetThresholdReached(BudgetThresholdReached),
+    ChallengeCompleted(ChallengeCompleted),
+    ProductAdded(ProductAdded),
+    ProductDeleted(ProductDeleted),
+    ProductOutcomeRecorded(ProductOutcomeRecorded),
+    ProductStatusChanged(ProductStatusChanged),
+    ProductUpdated(ProductUpdated),
+    PurchaseRecorded(PurchaseRecorded),
+    StreakMilestoneReached(StreakMilestoneReached),
+    VacationEnded(VacationEnded),
+}
+
+/// Port used by use cases to announce domain events.
+///
+/// Publishing never fails the caller: the write that raised the event has
+/// already happened, so handlers report their own failures.
+#[async_trait]
+pub trait EventPublisher: Send + Sync {
+    async fn publish(&self, event: DomainEvent);
+}
+
+/// Reaction to domain events, such as a policy keeping another aggregate in sync.
+#[async_trait]
+pub trait EventHandler: Send + Sync {
+    async fn handle(&self, event: &DomainEvent);
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ExperimentError {
+    /// No AI call with that ID for the user, or one whose outcome can't be
+    /// reported by the client
+    #[error("experiment.ai_call_not_found")]
+    AiCallNotFound,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,187 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+
+/// AI feature whose prompt or model can be experimented with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AiFeature {
+    /// Naming a product from a photo. Counted as accepted when the client
+    /// reports the user kept the identification.
+    ProductIdentification,
+    /// Recipe suggestions. Counted as accepted when one of the suggestions
+    /// is cooked.
+    Suggestions,
+}
+
+impl AiFeature {
+    pub const ALL: [AiFeature; 2] = [AiFeature::ProductIdentification, AiFeature::Suggestions];
+}
+
+impl std::fmt::Display for AiFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AiFeature::ProductIdentification => write!(f, "product_identification"),
+            AiFeature::Suggestions => write!(f, "suggestions"),
+        }
+    }
+}
+
+impl std::str::FromStr for AiFeature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "product_identification" => Ok(AiFeature::ProductIdentification),
+            "suggestions" => Ok(AiFeature::Suggestions),
+            _ => Err(format!("Invalid AI feature: {}", s)),
+        }
+    }
+}
+
+/// One arm of an experiment. Without a model or prompt version it runs the
+/// feature's regular configuration, which makes it the control.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    /// Share of users relative to the other variants' weights
+    pub weight: u32,
+    pub model: Option<String>,
+    pub prompt_version: Option<u32>,
+}
+
+/// Variants competing for one AI feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Experiment {
+    pub feature: AiFeature,
+    pub variants: Vec<Variant>,
+}
+
+impl Experiment {
+    /// Variant a user gets. Assignment hashes the user and feature, so a user
+    /// stays in the same variant across calls and restarts, and independently
+    /// of their variant in other features' experiments. `None` when every
+    /// weight is zero.
+    pub fn assign(&self, user_id: &UserId) -> Option<&Variant> {
+        let total: u64 = self.variants.iter().map(|v| u64::from(v.weight)).sum();
+        if total == 0 {
+            return None;
+        }
+        let mut bucket = bucket(user_id, self.feature) % total;
+        for variant in &self.variants {
+            let weight = u64::from(variant.weight);
+            if bucket < weight {
+                return Some(variant);
+            }
+            bucket -= weight;
+        }
+        None
+    }
+}
+
+/// FNV-1a over the user and feature: stable across builds, unlike the
+/// standard library's hasher.
+fn bucket(user_id: &UserId, feature: AiFeature) -> u64 {
+    let key = format!("{}:{}", feature, user_id.as_str());
+    key.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// An AI call made under an experiment, with the quality signal that came
+/// back from the user.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AiCall {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub feature: AiFeature,
+    pub variant: String,
+    /// What the call produced that a later signal can point to, such as the
+    /// suggestion IDs. Empty for identifications, which are reported by call ID.
+    pub subject_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+impl AiCall {
+    pub fn new(
+        user_id: UserId,
+        feature: AiFeature,
+        variant: String,
+        subject_ids: Vec<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            feature,
+            variant,
+            subject_ids,
+            created_at: Utc::now(),
+            accepted_at: None,
+        }
+    }
+}
+
+/// How one variant did over a period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VariantResult {
+    pub feature: AiFeature,
+    pub variant: String,
+    pub calls: u64,
+    pub accepted: u64,
+}
+
+impl VariantResult {
+    /// Share of calls the user accepted, between 0 and 1.
+    pub fn acceptance_rate(&self) -> f64 {
+        if self.calls == 0 {
+            0.0
+        } else {
+            self.accepted as f64 / self.calls as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(name: &str, weight: u32) -> Variant {
+        Variant {
+            name: name.to_string(),
+            weight,
+            model: None,
+            prompt_version: None,
+        }
+    }
+
+    #[test]
+    fn should_assign_users_stably_and_in_proportion_to_weights() {
+        let experiment = Experiment {
+            feature: AiFeature::Suggestions,
+            variants: vec![variant("control", 3), variant("v2", 1)],
+        };
+
+        let assigned: Vec<&str> = (0..1000)
+            .map(|i| {
+                let user_id = UserId::new(format!("user-{i}"));
+                let first = experiment.assign(&user_id).unwrap();
+                assert_eq!(experiment.assign(&user_id), Some(first));
+                first.name.as_str()
+            })
+            .collect();
+
+        let control = assigned.iter().filter(|name| **name == "control").count();
+        assert!((650..850).contains(&control), "control got {control}");
+    }
+
+    #[test]
+    fn should_assign_nobody_when_every_weight_is_zero() {
+        let experiment = Experiment {
+            feature: AiFeature::ProductIdentification,
+            variants: vec![variant("control", 0)],
+        };
+
+        assert!(experiment.assign(&UserId::new("user")).is_none());
+    }
+}
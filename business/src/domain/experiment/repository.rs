@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::{AiCall, AiFeature, VariantResult};
+
+#[async_trait]
+pub trait AiCallRepository: Send + Sync {
+    async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+    /// Marks the user's call for `feature` as accepted, keeping the first
+    /// time when reported twice. `false` when there is no such call.
+    async fn accept(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+        feature: AiFeature,
+    ) -> Result<bool, RepositoryError>;
+    /// Marks as accepted the user's call for `feature` that produced
+    /// `subject_id`, if any.
+    async fn accept_by_subject(
+        &self,
+        user_id: &UserId,
+        feature: AiFeature,
+        subject_id: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Calls and acceptances per feature and variant since `since`, across
+    /// all users.
+    async fn get_results(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<VariantResult>, RepositoryError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::experiment::errors::ExperimentError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct AcceptIdentificationParams {
+    pub user_id: UserId,
+    /// `aiCallId` of the identification the user kept
+    pub ai_call_id: Uuid,
+}
+
+#[async_trait]
+pub trait AcceptIdentificationUseCase: Send + Sync {
+    async fn execute(&self, params: AcceptIdentificationParams) -> Result<(), ExperimentError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::experiment::errors::ExperimentError;
+use crate::domain::experiment::model::VariantResult;
+
+pub struct GetExperimentResultsParams {
+    /// How far back to count calls
+    pub days: u32,
+}
+
+#[async_trait]
+pub trait GetExperimentResultsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetExperimentResultsParams,
+    ) -> Result<Vec<VariantResult>, ExperimentError>;
+}
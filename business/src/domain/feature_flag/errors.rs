@@ -0,0 +1,5 @@
+#[derive(Debug, thiserror::Error)]
+pub enum FeatureFlagError {
+    #[error(transparent)]
+    Quota(#[from] crate::domain::quota::errors::QuotaError),
+}
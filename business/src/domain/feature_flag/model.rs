@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::domain::quota::model::Usage;
+
+/// App feature that can be switched off server-side, so a release can ship
+/// with it and have it enabled later, or have it pulled when it misbehaves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Feature {
+    /// Importing products from a photo of a till receipt.
+    ReceiptScanning,
+    /// Adding products from a photo of the shelf.
+    PhotoRecognition,
+    /// AI recipe suggestions.
+    Suggestions,
+    /// Read-only share links to the pantry or shopping list.
+    ShareLinks,
+    /// AI expiry estimates for new products; without it they get the
+    /// category defaults.
+    ExpiryEstimation,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 5] = [
+        Feature::ReceiptScanning,
+        Feature::PhotoRecognition,
+        Feature::Suggestions,
+        Feature::ShareLinks,
+        Feature::ExpiryEstimation,
+    ];
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feature::ReceiptScanning => write!(f, "receipt_scanning"),
+            Feature::PhotoRecognition => write!(f, "photo_recognition"),
+            Feature::Suggestions => write!(f, "suggestions"),
+            Feature::ShareLinks => write!(f, "share_links"),
+            Feature::ExpiryEstimation => write!(f, "expiry_estimation"),
+        }
+    }
+}
+
+impl std::str::FromStr for Feature {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "receipt_scanning" => Ok(Feature::ReceiptScanning),
+            "photo_recognition" => Ok(Feature::PhotoRecognition),
+            "suggestions" => Ok(Feature::Suggestions),
+            "share_links" => Ok(Feature::ShareLinks),
+            "expiry_estimation" => Ok(Feature::ExpiryEstimation),
+            _ => Err(format!("Invalid feature: {}", s)),
+        }
+    }
+}
+
+/// Which features this deployment offers. Everything is on unless disabled.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FeatureFlags {
+    disabled: HashSet<Feature>,
+}
+
+impl FeatureFlags {
+    pub fn with_disabled(disabled: impl IntoIterator<Item = Feature>) -> Self {
+        Self {
+            disabled: disabled.into_iter().collect(),
+        }
+    }
+
+    pub fn is_enabled(&self, feature: Feature) -> bool {
+        !self.disabled.contains(&feature)
+    }
+}
+
+/// What a client needs at startup to decide which features to show.
+#[derive(Debug, Clone)]
+pub struct ClientConfig {
+    pub flags: FeatureFlags,
+    /// The user's plan usage, to show remaining quota up front
+    pub usage: Usage,
+    /// Oldest app version still supported; older ones must update
+    pub min_app_version: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_every_feature_name() {
+        for feature in Feature::ALL {
+            assert_eq!(feature.to_string().parse::<Feature>(), Ok(feature));
+        }
+        assert!("dark_mode".parse::<Feature>().is_err());
+    }
+
+    #[test]
+    fn should_enable_everything_not_disabled() {
+        let flags = FeatureFlags::with_disabled([Feature::ReceiptScanning]);
+
+        assert!(!flags.is_enabled(Feature::ReceiptScanning));
+        assert!(flags.is_enabled(Feature::Suggestions));
+        assert!(FeatureFlags::default().is_enabled(Feature::ReceiptScanning));
+    }
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::feature_flag::errors::FeatureFlagError;
+use crate::domain::feature_flag::model::ClientConfig;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetClientConfigParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetClientConfigUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetClientConfigParams,
+    ) -> Result<ClientConfig, FeatureFlagError>;
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum InboundEmailError {
+    #[error("inbound_email.invalid_signature")]
+    InvalidSignature,
+    #[error("inbound_email.unknown_recipient")]
+    UnknownRecipient,
+    #[error("inbound_email.too_many_emails")]
+    TooManyEmails,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
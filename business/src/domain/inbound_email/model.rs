@@ -0,0 +1,144 @@
+use chrono::{DateTime, Utc};
+use rand::Rng;
+
+use crate::domain::shared::value_objects::UserId;
+
+const TOKEN_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+const TOKEN_LENGTH: usize = 20;
+
+/// The user's private address for emailing items to their shopping list,
+/// `<token>@<inbound domain>`. The token is random so addresses cannot be
+/// guessed, and rotating it retires an address that attracts spam.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundAddress {
+    pub user_id: UserId,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InboundAddress {
+    pub fn generate(user_id: UserId) -> Self {
+        let mut rng = rand::rng();
+        let token = (0..TOKEN_LENGTH)
+            .map(|_| char::from(TOKEN_ALPHABET[rng.random_range(0..TOKEN_ALPHABET.len())]))
+            .collect();
+        Self {
+            user_id,
+            token,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn email(&self, domain: &str) -> String {
+        format!("{}@{}", self.token, domain)
+    }
+}
+
+/// Token of the address an email was sent to: the local part, lowercased
+/// and without any `+tag`. `None` when the recipient is not on `domain`.
+pub fn recipient_token(recipient: &str, domain: &str) -> Option<String> {
+    // Providers may pass "Name <address>" or several comma-separated recipients
+    recipient.split(',').find_map(|entry| {
+        let entry = entry.trim();
+        let address = match (entry.rfind('<'), entry.rfind('>')) {
+            (Some(start), Some(end)) if start < end => &entry[start + 1..end],
+            _ => entry,
+        };
+        let (local, host) = address.rsplit_once('@')?;
+        if !host.eq_ignore_ascii_case(domain) {
+            return None;
+        }
+        let token = local.split('+').next().unwrap_or(local).to_lowercase();
+        (!token.is_empty()).then_some(token)
+    })
+}
+
+/// An address ready to be shown to its owner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundMailbox {
+    pub address: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An email forwarded by the mail provider, reduced to what the list needs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InboundEmail {
+    pub recipient: String,
+    pub sender: String,
+    /// Plain-text body, with the quoted reply stripped when the provider
+    /// does it
+    pub text: String,
+}
+
+/// Proof that a webhook call comes from the mail provider. `token` is unique
+/// per call and doubles as the key that makes retries idempotent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookSignature {
+    pub timestamp: String,
+    pub token: String,
+    pub signature: String,
+}
+
+/// Safeguards against a leaked address being used to flood a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InboundLimits {
+    /// Emails accepted per address over any 24 hours
+    pub max_emails_per_day: u32,
+    /// Items read from one email; the rest are ignored
+    pub max_items_per_email: usize,
+}
+
+impl Default for InboundLimits {
+    fn default() -> Self {
+        Self {
+            max_emails_per_day: 20,
+            max_items_per_email: 30,
+        }
+    }
+}
+
+/// What an accepted email added to the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InboundEmailReceipt {
+    pub added: u32,
+    /// The call was a retry of an email already handled
+    pub duplicate: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_generate_lowercase_tokens() {
+        let address = InboundAddress::generate(UserId::new("test-user-id"));
+
+        assert_eq!(address.token.len(), TOKEN_LENGTH);
+        assert!(
+            address
+                .token
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        );
+        assert_eq!(
+            address.email("in.foodie.app"),
+            format!("{}@in.foodie.app", address.token)
+        );
+    }
+
+    #[test]
+    fn should_read_token_from_recipient() {
+        assert_eq!(
+            recipient_token("Lista <AbC123+weekly@In.Foodie.app>", "in.foodie.app"),
+            Some("abc123".to_string())
+        );
+        assert_eq!(
+            recipient_token("ana@gmail.com, abc123@in.foodie.app", "in.foodie.app"),
+            Some("abc123".to_string())
+        );
+        assert_eq!(
+            recipient_token("abc123@elsewhere.com", "in.foodie.app"),
+            None
+        );
+    }
+}
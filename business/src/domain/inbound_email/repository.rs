@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::model::InboundAddress;
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+#[async_trait]
+pub trait InboundAddressRepository: Send + Sync {
+    async fn find_by_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<InboundAddress>, RepositoryError>;
+    async fn find_by_token(&self, token: &str) -> Result<Option<InboundAddress>, RepositoryError>;
+    /// Stores the address unless the user already has one, and returns the
+    /// one that ends up stored.
+    async fn create(&self, address: &InboundAddress) -> Result<InboundAddress, RepositoryError>;
+    /// Replaces the user's address; the old one stops working.
+    async fn replace(&self, address: &InboundAddress) -> Result<(), RepositoryError>;
+    /// Emails accepted for the user since `since`.
+    async fn count_received_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError>;
+    /// Logs an accepted email and adds its items to the user's shopping
+    /// list, in one transaction. Returns `false`, adding nothing, when a
+    /// webhook with the same provider token was already logged, i.e. the call
+    /// is a retry or a replay.
+    async fn record_received(
+        &self,
+        user_id: &UserId,
+        webhook_token: &str,
+        items: &[ShoppingItem],
+    ) -> Result<bool, RepositoryError>;
+}
@@ -0,0 +1,8 @@
+use super::errors::InboundEmailError;
+use super::model::WebhookSignature;
+
+/// Service port for the mail provider that forwards inbound emails.
+pub trait InboundEmailVerifier: Send + Sync {
+    /// Checks that a webhook call was signed by the provider.
+    fn verify(&self, signature: &WebhookSignature) -> Result<(), InboundEmailError>;
+}
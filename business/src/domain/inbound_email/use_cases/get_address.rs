@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::InboundMailbox;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetInboundAddressParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetInboundAddressUseCase: Send + Sync {
+    /// Returns the user's address, giving them one on first use.
+    async fn execute(
+        &self,
+        params: GetInboundAddressParams,
+    ) -> Result<InboundMailbox, InboundEmailError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::{InboundEmail, InboundEmailReceipt, WebhookSignature};
+
+pub struct ReceiveInboundEmailParams {
+    pub email: InboundEmail,
+    pub signature: WebhookSignature,
+}
+
+#[async_trait]
+pub trait ReceiveInboundEmailUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: ReceiveInboundEmailParams,
+    ) -> Result<InboundEmailReceipt, InboundEmailError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::inbound_email::errors::InboundEmailError;
+use crate::domain::inbound_email::model::InboundMailbox;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct RotateInboundAddressParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait RotateInboundAddressUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: RotateInboundAddressParams,
+    ) -> Result<InboundMailbox, InboundEmailError>;
+}
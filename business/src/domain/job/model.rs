@@ -0,0 +1,232 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+
+/// Kind of work a background job does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobKind {
+    /// Estimates expiry dates for active products that have none.
+    EstimateMissingExpiry,
+    /// Re-estimates expiry dates of products moved to another location.
+    ReestimateMovedExpiry,
+    /// Retries the expiry estimation of a product added while the estimator
+    /// was down.
+    RetryExpiryEstimation,
+}
+
+impl std::fmt::Display for JobKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobKind::EstimateMissingExpiry => write!(f, "estimate_missing_expiry"),
+            JobKind::ReestimateMovedExpiry => write!(f, "reestimate_moved_expiry"),
+            JobKind::RetryExpiryEstimation => write!(f, "retry_expiry_estimation"),
+        }
+    }
+}
+
+impl std::str::FromStr for JobKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "estimate_missing_expiry" => Ok(JobKind::EstimateMissingExpiry),
+            "reestimate_moved_expiry" => Ok(JobKind::ReestimateMovedExpiry),
+            "retry_expiry_estimation" => Ok(JobKind::RetryExpiryEstimation),
+            _ => Err(format!("Invalid job kind: {}", s)),
+        }
+    }
+}
+
+/// Lifecycle state of a background job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl std::fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JobStatus::Pending => write!(f, "pending"),
+            JobStatus::Running => write!(f, "running"),
+            JobStatus::Completed => write!(f, "completed"),
+            JobStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for JobStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(JobStatus::Pending),
+            "running" => Ok(JobStatus::Running),
+            "completed" => Ok(JobStatus::Completed),
+            "failed" => Ok(JobStatus::Failed),
+            _ => Err(format!("Invalid job status: {}", s)),
+        }
+    }
+}
+
+/// How far a job got through its items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JobProgress {
+    pub total: u32,
+    /// Items handled so far, failed ones included
+    pub processed: u32,
+    pub failed: u32,
+}
+
+/// Long-running work done for a user in the background, polled by the client.
+#[derive(Debug, Clone)]
+pub struct Job {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub kind: JobKind,
+    pub status: JobStatus,
+    pub progress: JobProgress,
+    /// Error code, present once the job failed.
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Job {
+    pub fn new(user_id: UserId, kind: JobKind, total: u32) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            kind,
+            status: JobStatus::Pending,
+            progress: JobProgress {
+                total,
+                ..JobProgress::default()
+            },
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        kind: JobKind,
+        status: JobStatus,
+        progress: JobProgress,
+        error: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            kind,
+            status,
+            progress,
+            error,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.status = JobStatus::Running;
+        self.updated_at = Utc::now();
+    }
+
+    /// Counts one more item as handled.
+    pub fn record(&mut self, succeeded: bool) {
+        self.progress.processed += 1;
+        if !succeeded {
+            self.progress.failed += 1;
+        }
+        self.updated_at = Utc::now();
+    }
+
+    pub fn complete(&mut self) {
+        self.status = JobStatus::Completed;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn fail(&mut self, error: impl ToString) {
+        self.status = JobStatus::Failed;
+        self.error = Some(error.to_string());
+        self.updated_at = Utc::now();
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status, JobStatus::Completed | JobStatus::Failed)
+    }
+}
+
+/// How often and how far apart a job retries work that failed for a reason
+/// likely to pass, like a provider outage. Delays double after each attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryBackoff {
+    /// Wait before the first attempt
+    pub first_delay: std::time::Duration,
+    pub max_attempts: u32,
+}
+
+impl RetryBackoff {
+    /// Wait before `attempt`, counted from 1.
+    pub fn delay_before(&self, attempt: u32) -> std::time::Duration {
+        self.first_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_track_progress_through_lifecycle() {
+        let mut job = Job::new(
+            UserId::new("test-user-id"),
+            JobKind::EstimateMissingExpiry,
+            3,
+        );
+        assert_eq!(job.status, JobStatus::Pending);
+
+        job.start();
+        job.record(true);
+        job.record(false);
+        assert_eq!(job.status, JobStatus::Running);
+        assert_eq!(
+            job.progress,
+            JobProgress {
+                total: 3,
+                processed: 2,
+                failed: 1,
+            }
+        );
+        assert!(!job.is_finished());
+
+        job.fail("quota.ai_calls_exceeded");
+        assert!(job.is_finished());
+        assert_eq!(job.error.as_deref(), Some("quota.ai_calls_exceeded"));
+    }
+
+    #[test]
+    fn should_double_retry_delays() {
+        let backoff = RetryBackoff {
+            first_delay: std::time::Duration::from_secs(30),
+            max_attempts: 4,
+        };
+
+        let delays: Vec<u64> = (1..=backoff.max_attempts)
+            .map(|attempt| backoff.delay_before(attempt).as_secs())
+            .collect();
+
+        assert_eq!(delays, vec![30, 60, 120, 240]);
+    }
+}
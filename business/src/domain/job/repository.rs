@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::{Job, JobKind};
+
+#[async_trait]
+pub trait JobRepository: Send + Sync {
+    async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError>;
+    /// The user's pending or running job of this kind, if any.
+    async fn find_unfinished(
+        &self,
+        user_id: &UserId,
+        kind: JobKind,
+    ) -> Result<Option<Job>, RepositoryError>;
+    async fn insert(&self, job: &Job) -> Result<(), RepositoryError>;
+    async fn update(&self, job: &Job) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::job::errors::JobError;
+use crate::domain::job::model::Job;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetJobParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetJobUseCase: Send + Sync {
+    async fn execute(&self, params: GetJobParams) -> Result<Job, JobError>;
+}
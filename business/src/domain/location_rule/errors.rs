@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum LocationRuleError {
+    #[error("location_rule.keyword_empty")]
+    KeywordEmpty,
+    #[error("location_rule.too_many")]
+    TooMany,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,148 @@
+use chrono::{DateTime, Utc};
+
+use super::errors::LocationRuleError;
+use crate::domain::product::value_objects::ProductLocation;
+use crate::domain::shared::value_objects::UserId;
+
+/// Maximum number of rules a user can keep.
+pub const MAX_LOCATION_RULES: usize = 50;
+
+/// "Products named like `keyword` are stored in `location`", e.g. leche → fridge.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocationRule {
+    pub keyword: String,
+    pub location: ProductLocation,
+}
+
+impl LocationRule {
+    /// Whether the keyword appears in the name as whole words, so "pan" matches
+    /// "Pan de molde" but not "Panceta".
+    fn matches(&self, name_words: &[String]) -> bool {
+        let keyword: Vec<String> = words(&self.keyword);
+        !keyword.is_empty() && name_words.windows(keyword.len()).any(|w| w == keyword)
+    }
+}
+
+/// A user's default storage locations, checked in order.
+#[derive(Debug, Clone)]
+pub struct LocationRuleSet {
+    pub user_id: UserId,
+    pub rules: Vec<LocationRule>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LocationRuleSet {
+    pub fn new(user_id: UserId, rules: Vec<LocationRule>) -> Result<Self, LocationRuleError> {
+        if rules.len() > MAX_LOCATION_RULES {
+            return Err(LocationRuleError::TooMany);
+        }
+        if rules.iter().any(|rule| rule.keyword.trim().is_empty()) {
+            return Err(LocationRuleError::KeywordEmpty);
+        }
+
+        Ok(Self {
+            user_id,
+            rules,
+            updated_at: Utc::now(),
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        user_id: UserId,
+        rules: Vec<LocationRule>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            user_id,
+            rules,
+            updated_at,
+        }
+    }
+
+    /// Location of the first rule matching the product name.
+    pub fn location_for(&self, product_name: &str) -> Option<ProductLocation> {
+        let name_words = words(product_name);
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(&name_words))
+            .map(|rule| rule.location.clone())
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(keyword: &str, location: ProductLocation) -> LocationRule {
+        LocationRule {
+            keyword: keyword.to_string(),
+            location,
+        }
+    }
+
+    fn rule_set(rules: Vec<LocationRule>) -> LocationRuleSet {
+        LocationRuleSet::new(UserId::new("test-user-id"), rules).unwrap()
+    }
+
+    #[test]
+    fn should_match_keyword_as_whole_words() {
+        let rules = rule_set(vec![
+            rule("pan", ProductLocation::Pantry),
+            rule("queso rallado", ProductLocation::Freezer),
+        ]);
+
+        assert_eq!(
+            rules.location_for("Pan de molde"),
+            Some(ProductLocation::Pantry)
+        );
+        assert_eq!(rules.location_for("Panceta"), None);
+        assert_eq!(
+            rules.location_for("Queso rallado mozzarella"),
+            Some(ProductLocation::Freezer)
+        );
+        assert_eq!(rules.location_for("Queso fresco"), None);
+    }
+
+    #[test]
+    fn should_apply_first_matching_rule() {
+        let rules = rule_set(vec![
+            rule("leche condensada", ProductLocation::Pantry),
+            rule("leche", ProductLocation::Fridge),
+        ]);
+
+        assert_eq!(
+            rules.location_for("Leche condensada"),
+            Some(ProductLocation::Pantry)
+        );
+        assert_eq!(
+            rules.location_for("LECHE entera"),
+            Some(ProductLocation::Fridge)
+        );
+    }
+
+    #[test]
+    fn should_reject_empty_keyword_and_too_many_rules() {
+        let empty = LocationRuleSet::new(
+            UserId::new("test-user-id"),
+            vec![rule("  ", ProductLocation::Fridge)],
+        );
+        assert!(matches!(empty, Err(LocationRuleError::KeywordEmpty)));
+
+        let too_many = LocationRuleSet::new(
+            UserId::new("test-user-id"),
+            (0..=MAX_LOCATION_RULES)
+                .map(|i| rule(&format!("producto {}", i), ProductLocation::Pantry))
+                .collect(),
+        );
+        assert!(matches!(too_many, Err(LocationRuleError::TooMany)));
+    }
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::LocationRuleSet;
+
+#[async_trait]
+pub trait LocationRuleRepository: Send + Sync {
+    /// The user's rules, or `None` if they never saved any.
+    async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+    async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::location_rule::errors::LocationRuleError;
+use crate::domain::location_rule::model::LocationRule;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetLocationRulesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetLocationRulesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetLocationRulesParams,
+    ) -> Result<Vec<LocationRule>, LocationRuleError>;
+}
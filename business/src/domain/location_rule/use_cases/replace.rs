@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::location_rule::errors::LocationRuleError;
+use crate::domain::location_rule::model::LocationRule;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct ReplaceLocationRulesParams {
+    pub user_id: UserId,
+    /// New rules, checked in this order. An empty list removes every rule.
+    pub rules: Vec<LocationRule>,
+}
+
+#[async_trait]
+pub trait ReplaceLocationRulesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: ReplaceLocationRulesParams,
+    ) -> Result<Vec<LocationRule>, LocationRuleError>;
+}
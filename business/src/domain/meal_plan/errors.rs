@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum MealPlanError {
+    #[error("meal_plan.suggestion_not_found")]
+    SuggestionNotFound,
+    #[error("meal_plan.already_planned")]
+    AlreadyPlanned,
+    #[error("meal_plan.date_in_past")]
+    DateInPast,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,134 @@
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use uuid::Uuid;
+
+use super::errors::MealPlanError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::suggestion::model::Suggestion;
+
+/// Days a reservation outlives the day its meal was planned for, so a meal
+/// cooked a little late still finds its products set aside.
+pub const RESERVATION_GRACE_DAYS: i64 = 1;
+
+/// A suggestion the user plans to cook on a given day, with the pantry
+/// products set aside for it so other planned meals don't count on them too.
+#[derive(Debug, Clone)]
+pub struct PlannedMeal {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub suggestion_id: String,
+    pub title: String,
+    /// Day the user plans to cook the meal
+    pub planned_for: NaiveDate,
+    /// Products reserved for this meal
+    pub product_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PlannedMeal {
+    pub fn new(
+        user_id: UserId,
+        suggestion: &Suggestion,
+        product_ids: Vec<Uuid>,
+        planned_for: NaiveDate,
+    ) -> Result<Self, MealPlanError> {
+        if planned_for < Utc::now().date_naive() {
+            return Err(MealPlanError::DateInPast);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            suggestion_id: suggestion.id.clone(),
+            title: suggestion.title.clone(),
+            planned_for,
+            product_ids,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        suggestion_id: String,
+        title: String,
+        planned_for: NaiveDate,
+        product_ids: Vec<Uuid>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            suggestion_id,
+            title,
+            planned_for,
+            product_ids,
+            created_at,
+        }
+    }
+
+    /// One reservation per product set aside for the meal.
+    pub fn reservations(&self) -> Vec<ProductReservation> {
+        self.product_ids
+            .iter()
+            .map(|product_id| ProductReservation::for_meal(self, *product_id))
+            .collect()
+    }
+}
+
+/// A pantry product set aside for a planned meal. Reserved products are kept
+/// out of new suggestions and of other planned meals until the reservation
+/// expires, shortly after the day the meal was planned for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductReservation {
+    pub product_id: Uuid,
+    pub planned_meal_id: Uuid,
+    pub user_id: UserId,
+    pub planned_for: NaiveDate,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl ProductReservation {
+    pub fn for_meal(meal: &PlannedMeal, product_id: Uuid) -> Self {
+        Self {
+            product_id,
+            planned_meal_id: meal.id,
+            user_id: meal.user_id.clone(),
+            planned_for: meal.planned_for,
+            expires_at: Self::expiry_for(meal.planned_for),
+        }
+    }
+
+    /// End of the planned day plus the grace period.
+    pub fn expiry_for(planned_for: NaiveDate) -> DateTime<Utc> {
+        planned_for.and_time(NaiveTime::MIN).and_utc() + Duration::days(1 + RESERVATION_GRACE_DAYS)
+    }
+}
+
+/// A planned meal with the ingredients that had to go on the shopping list
+/// because the pantry can't cover them.
+#[derive(Debug, Clone)]
+pub struct MealPlanOutcome {
+    pub meal: PlannedMeal,
+    /// Items added to the list; ingredients already on it are left out
+    pub added_to_shopping_list: Vec<ShoppingItem>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn should_expire_reservations_the_day_after_the_planned_one() {
+        let planned_for = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+
+        let expires_at = ProductReservation::expiry_for(planned_for);
+
+        assert_eq!(
+            expires_at,
+            Utc.with_ymd_and_hms(2026, 3, 22, 0, 0, 0).unwrap()
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::PlannedMeal;
+
+#[async_trait]
+pub trait MealPlanRepository: Send + Sync {
+    async fn find_by_suggestion(
+        &self,
+        suggestion_id: &str,
+        user_id: &UserId,
+    ) -> Result<Option<PlannedMeal>, RepositoryError>;
+    /// Products reserved by any of the user's planned meals, leaving out
+    /// expired reservations.
+    async fn get_reserved_product_ids(
+        &self,
+        user_id: &UserId,
+    ) -> Result<HashSet<Uuid>, RepositoryError>;
+    /// Saves the meal with its product reservations. Fails with
+    /// [`RepositoryError::Duplicated`] when the suggestion is already planned.
+    async fn insert(&self, meal: &PlannedMeal) -> Result<(), RepositoryError>;
+    /// Releases every reservation expired by `now`; returns how many.
+    async fn delete_expired_reservations(&self, now: DateTime<Utc>)
+    -> Result<u64, RepositoryError>;
+}
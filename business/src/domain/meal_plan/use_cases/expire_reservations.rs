@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::domain::meal_plan::errors::MealPlanError;
+
+#[async_trait]
+pub trait ExpireReservationsUseCase: Send + Sync {
+    /// Releases the products reserved for meals whose day has passed;
+    /// returns how many reservations were released.
+    async fn execute(&self) -> Result<u64, MealPlanError>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+
+use crate::domain::meal_plan::errors::MealPlanError;
+use crate::domain::meal_plan::model::MealPlanOutcome;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct PlanSuggestionParams {
+    pub user_id: UserId,
+    pub suggestion_id: String,
+    /// Day the meal will be cooked; the reservations last until shortly after
+    pub planned_for: NaiveDate,
+}
+
+#[async_trait]
+pub trait PlanSuggestionUseCase: Send + Sync {
+    /// Reserves the pantry products a suggestion uses and puts the
+    /// ingredients the pantry can't cover on the shopping list.
+    async fn execute(&self, params: PlanSuggestionParams)
+    -> Result<MealPlanOutcome, MealPlanError>;
+}
@@ -0,0 +1,5 @@
+/// Port for operational counters, kept apart from logs so they can be
+/// aggregated and alerted on.
+pub trait Metrics: Send + Sync {
+    fn increment(&self, name: &str, value: u64);
+}
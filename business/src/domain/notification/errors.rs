@@ -0,0 +1,21 @@
+use crate::domain::errors::ErrorSource;
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationError {
+    #[error("notification.invalid_utc_offset")]
+    InvalidUtcOffset,
+    #[error("notification.invalid_quiet_hours")]
+    InvalidQuietHours,
+    #[error("notification.invalid_acknowledgement")]
+    InvalidAcknowledgement,
+    #[error("notification.delivery_failed")]
+    DeliveryFailed(#[source] ErrorSource),
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
+
+impl NotificationError {
+    pub fn delivery_failed(cause: impl Into<ErrorSource>) -> Self {
+        NotificationError::DeliveryFailed(cause.into())
+    }
+}
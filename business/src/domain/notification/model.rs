@@ -0,0 +1,403 @@
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, NaiveTime, Offset, Utc};
+use uuid::Uuid;
+
+use super::errors::NotificationError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Most notifications acknowledged by ID in one go
+pub const MAX_ACKNOWLEDGED_IDS: usize = 500;
+
+/// What a notification is about; each one can be switched off by the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    /// Products about to expire
+    ExpiryAlerts,
+    /// Items left on the shopping list, and reminders the user set on
+    /// products
+    ShoppingReminders,
+    /// Recipe ideas for what is in the pantry
+    Suggestions,
+    /// Completed challenges and zero-waste streaks
+    Achievements,
+}
+
+impl NotificationCategory {
+    /// Whether notifications of this category wait for the daily digest
+    /// instead of going out right away.
+    pub fn digest(&self) -> bool {
+        matches!(
+            self,
+            NotificationCategory::Suggestions | NotificationCategory::Achievements
+        )
+    }
+}
+
+impl std::fmt::Display for NotificationCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotificationCategory::ExpiryAlerts => write!(f, "expiry_alerts"),
+            NotificationCategory::ShoppingReminders => write!(f, "shopping_reminders"),
+            NotificationCategory::Suggestions => write!(f, "suggestions"),
+            NotificationCategory::Achievements => write!(f, "achievements"),
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "expiry_alerts" => Ok(NotificationCategory::ExpiryAlerts),
+            "shopping_reminders" => Ok(NotificationCategory::ShoppingReminders),
+            "suggestions" => Ok(NotificationCategory::Suggestions),
+            "achievements" => Ok(NotificationCategory::Achievements),
+            _ => Err(format!("Invalid notification category: {}", s)),
+        }
+    }
+}
+
+/// Language the notifications the server writes are in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationLanguage {
+    #[default]
+    En,
+    Es,
+}
+
+impl std::str::FromStr for NotificationLanguage {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "en" => Ok(NotificationLanguage::En),
+            "es" => Ok(NotificationLanguage::Es),
+            _ => Err(format!("Invalid notification language: {}", s)),
+        }
+    }
+}
+
+/// Local time span in which nothing is pushed. May wrap past midnight,
+/// e.g. 22:00 to 08:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start: NaiveTime,
+    /// Exclusive
+    pub end: NaiveTime,
+}
+
+impl QuietHours {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Result<Self, NotificationError> {
+        if start == end {
+            return Err(NotificationError::InvalidQuietHours);
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start < self.end {
+            time >= self.start && time < self.end
+        } else {
+            time >= self.start || time < self.end
+        }
+    }
+}
+
+impl Default for QuietHours {
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(22, 0, 0).unwrap_or(NaiveTime::MIN),
+            end: NaiveTime::from_hms_opt(8, 0, 0).unwrap_or(NaiveTime::MIN),
+        }
+    }
+}
+
+/// Which categories the user wants to hear about. All on by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryToggles {
+    pub expiry_alerts: bool,
+    pub shopping_reminders: bool,
+    pub suggestions: bool,
+    pub achievements: bool,
+}
+
+impl CategoryToggles {
+    pub fn enabled(&self, category: NotificationCategory) -> bool {
+        match category {
+            NotificationCategory::ExpiryAlerts => self.expiry_alerts,
+            NotificationCategory::ShoppingReminders => self.shopping_reminders,
+            NotificationCategory::Suggestions => self.suggestions,
+            NotificationCategory::Achievements => self.achievements,
+        }
+    }
+}
+
+impl Default for CategoryToggles {
+    fn default() -> Self {
+        Self {
+            expiry_alerts: true,
+            shopping_reminders: true,
+            suggestions: true,
+            achievements: true,
+        }
+    }
+}
+
+/// Per-user rules for when notifications reach them. Times are local to
+/// the user, given by a fixed offset from UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationPreferences {
+    pub utc_offset: FixedOffset,
+    /// When the daily digest goes out
+    pub digest_at: NaiveTime,
+    pub quiet_hours: Option<QuietHours>,
+    pub categories: CategoryToggles,
+}
+
+impl Default for NotificationPreferences {
+    fn default() -> Self {
+        Self {
+            utc_offset: Utc.fix(),
+            digest_at: NaiveTime::from_hms_opt(18, 0, 0).unwrap_or(NaiveTime::MIN),
+            quiet_hours: Some(QuietHours::default()),
+            categories: CategoryToggles::default(),
+        }
+    }
+}
+
+impl NotificationPreferences {
+    /// Furthest offset from UTC in use, either way.
+    pub const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+    pub fn new(
+        utc_offset_minutes: i32,
+        digest_at: NaiveTime,
+        quiet_hours: Option<(NaiveTime, NaiveTime)>,
+        categories: CategoryToggles,
+    ) -> Result<Self, NotificationError> {
+        if utc_offset_minutes.abs() > Self::MAX_UTC_OFFSET_MINUTES {
+            return Err(NotificationError::InvalidUtcOffset);
+        }
+        let utc_offset = FixedOffset::east_opt(utc_offset_minutes * 60)
+            .ok_or(NotificationError::InvalidUtcOffset)?;
+        let quiet_hours = quiet_hours
+            .map(|(start, end)| QuietHours::new(start, end))
+            .transpose()?;
+
+        Ok(Self {
+            utc_offset,
+            digest_at,
+            quiet_hours,
+            categories,
+        })
+    }
+
+    pub fn utc_offset_minutes(&self) -> i32 {
+        self.utc_offset.local_minus_utc() / 60
+    }
+
+    /// When a notification of `category` raised at `now` should reach the
+    /// user, or `None` if they switched the category off. Digest categories
+    /// wait for the next digest; anything landing in the quiet hours waits
+    /// for them to end.
+    pub fn delivery_time(
+        &self,
+        category: NotificationCategory,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        if !self.categories.enabled(category) {
+            return None;
+        }
+
+        let at = if category.digest() {
+            self.next_digest(now)
+        } else {
+            now
+        };
+        Some(self.after_quiet_hours(at))
+    }
+
+    /// The first digest time at or after `now`.
+    pub fn next_digest(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let local = self.local_of(now);
+        let mut digest = local.date().and_time(self.digest_at);
+        if digest < local {
+            digest += Duration::days(1);
+        }
+        self.utc_of(digest)
+    }
+
+    fn after_quiet_hours(&self, at: DateTime<Utc>) -> DateTime<Utc> {
+        let local = self.local_of(at);
+        match self.quiet_hours {
+            Some(quiet) if quiet.contains(local.time()) => {
+                let mut end = local.date().and_time(quiet.end);
+                if end <= local {
+                    end += Duration::days(1);
+                }
+                self.utc_of(end)
+            }
+            _ => at,
+        }
+    }
+
+    fn local_of(&self, at: DateTime<Utc>) -> NaiveDateTime {
+        at.with_timezone(&self.utc_offset).naive_local()
+    }
+
+    fn utc_of(&self, local: NaiveDateTime) -> DateTime<Utc> {
+        (local - Duration::seconds(self.utc_offset.local_minus_utc().into())).and_utc()
+    }
+}
+
+/// A message for one user. Sent notifications are kept in the user's inbox
+/// under `id` until acknowledged.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Notification {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub category: NotificationCategory,
+    pub title: String,
+    pub body: String,
+}
+
+/// Which of the user's sent notifications to mark read.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Acknowledgement {
+    /// These ones; IDs of other users' notifications are ignored
+    Ids(Vec<Uuid>),
+    /// Every one sent before this time
+    SentBefore(DateTime<Utc>),
+}
+
+impl Acknowledgement {
+    /// Drops duplicate IDs. Rejects an empty list or one longer than
+    /// `MAX_ACKNOWLEDGED_IDS`.
+    pub fn ids(ids: Vec<Uuid>) -> Result<Self, NotificationError> {
+        let mut seen = std::collections::HashSet::new();
+        let ids: Vec<Uuid> = ids.into_iter().filter(|id| seen.insert(*id)).collect();
+        if ids.is_empty() || ids.len() > MAX_ACKNOWLEDGED_IDS {
+            return Err(NotificationError::InvalidAcknowledgement);
+        }
+        Ok(Acknowledgement::Ids(ids))
+    }
+}
+
+/// What the dispatcher did with a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    Sent,
+    /// Held back until the digest or the end of the quiet hours
+    Scheduled(DateTime<Utc>),
+    /// The user switched its category off
+    Muted,
+    /// An expiry alert raised while the user is on vacation
+    Paused,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, day, hour, minute, 0).unwrap()
+    }
+
+    /// Madrid in winter: UTC+1, quiet from 22:00 to 08:00, digest at 18:00.
+    fn madrid() -> NotificationPreferences {
+        NotificationPreferences::new(
+            60,
+            time(18, 0),
+            Some((time(22, 0), time(8, 0))),
+            CategoryToggles::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_wrap_quiet_hours_past_midnight() {
+        let quiet = QuietHours::default();
+
+        assert!(quiet.contains(time(23, 30)));
+        assert!(quiet.contains(time(7, 59)));
+        assert!(!quiet.contains(time(8, 0)));
+        assert!(!quiet.contains(time(21, 59)));
+        assert!(QuietHours::new(time(8, 0), time(8, 0)).is_err());
+    }
+
+    #[test]
+    fn should_send_alerts_right_away_outside_quiet_hours() {
+        let now = utc(10, 12, 0);
+
+        let at = madrid().delivery_time(NotificationCategory::ExpiryAlerts, now);
+
+        assert_eq!(at, Some(now));
+    }
+
+    #[test]
+    fn should_hold_alerts_until_quiet_hours_end_in_local_time() {
+        let at = madrid().delivery_time(NotificationCategory::ExpiryAlerts, utc(10, 22, 30));
+
+        assert_eq!(at, Some(utc(11, 7, 0)));
+    }
+
+    #[test]
+    fn should_hold_digest_categories_until_the_next_digest() {
+        let preferences = madrid();
+
+        assert_eq!(
+            preferences.delivery_time(NotificationCategory::Achievements, utc(10, 9, 0)),
+            Some(utc(10, 17, 0))
+        );
+        assert_eq!(
+            preferences.delivery_time(NotificationCategory::Suggestions, utc(10, 17, 30)),
+            Some(utc(11, 17, 0))
+        );
+    }
+
+    #[test]
+    fn should_mute_categories_switched_off() {
+        let mut preferences = madrid();
+        preferences.categories.shopping_reminders = false;
+
+        let at = preferences.delivery_time(NotificationCategory::ShoppingReminders, utc(10, 12, 0));
+
+        assert_eq!(at, None);
+    }
+
+    #[test]
+    fn should_reject_offsets_beyond_fourteen_hours() {
+        let result =
+            NotificationPreferences::new(15 * 60, time(18, 0), None, CategoryToggles::default());
+
+        assert!(matches!(result, Err(NotificationError::InvalidUtcOffset)));
+    }
+
+    #[test]
+    fn should_drop_duplicate_ids_when_acknowledging() {
+        let id = Uuid::new_v4();
+
+        let ack = Acknowledgement::ids(vec![id, id]).unwrap();
+
+        assert_eq!(ack, Acknowledgement::Ids(vec![id]));
+    }
+
+    #[test]
+    fn should_reject_empty_or_oversized_id_lists() {
+        let too_many = (0..=MAX_ACKNOWLEDGED_IDS).map(|_| Uuid::new_v4()).collect();
+
+        assert!(matches!(
+            Acknowledgement::ids(vec![]),
+            Err(NotificationError::InvalidAcknowledgement)
+        ));
+        assert!(matches!(
+            Acknowledgement::ids(too_many),
+            Err(NotificationError::InvalidAcknowledgement)
+        ));
+    }
+}
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use super::model::{Acknowledgement, Notification, NotificationPreferences};
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait NotificationPreferenceRepository: Send + Sync {
+    /// The user's notification settings; the defaults if they never saved any.
+    async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError>;
+    async fn save(
+        &self,
+        user_id: &UserId,
+        preferences: &NotificationPreferences,
+    ) -> Result<(), RepositoryError>;
+}
+
+/// Notifications sent to the user, kept until the retention job prunes them.
+#[async_trait]
+pub trait NotificationInboxRepository: Send + Sync {
+    async fn record(
+        &self,
+        notification: &Notification,
+        sent_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError>;
+    /// Marks the selected unread notifications of the user read, in a single
+    /// update; returns how many changed.
+    async fn acknowledge(
+        &self,
+        user_id: &UserId,
+        acknowledgement: &Acknowledgement,
+        read_at: DateTime<Utc>,
+    ) -> Result<u64, RepositoryError>;
+    /// Deletes the notifications of every user sent before `before`, read
+    /// or not; returns how many.
+    async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
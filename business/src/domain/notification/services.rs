@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use super::errors::NotificationError;
+use super::model::Notification;
+use crate::domain::device::model::Device;
+
+/// Delivers a notification to the user's devices, right away.
+#[async_trait]
+pub trait NotificationSender: Send + Sync {
+    /// `devices` are the installs the user registered for pushes; none for
+    /// users whose apps predate the device registry.
+    async fn send(
+        &self,
+        notification: &Notification,
+        devices: &[Device],
+    ) -> Result<(), NotificationError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::Acknowledgement;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct AcknowledgeNotificationsParams {
+    pub user_id: UserId,
+    pub acknowledgement: Acknowledgement,
+}
+
+#[async_trait]
+pub trait AcknowledgeNotificationsUseCase: Send + Sync {
+    /// Marks the selected notifications read; returns how many were unread.
+    async fn execute(
+        &self,
+        params: AcknowledgeNotificationsParams,
+    ) -> Result<u64, NotificationError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::NotificationPreferences;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetNotificationPreferencesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetNotificationPreferencesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetNotificationPreferencesParams,
+    ) -> Result<NotificationPreferences, NotificationError>;
+}
@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::errors::NotificationError;
+
+pub struct PruneNotificationsParams {
+    /// Notifications sent this many days ago or earlier are deleted
+    pub retention_days: u32,
+}
+
+#[async_trait]
+pub trait PruneNotificationsUseCase: Send + Sync {
+    /// Deletes the notifications past retention; returns how many.
+    async fn execute(&self, params: PruneNotificationsParams) -> Result<u64, NotificationError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::notification::errors::NotificationError;
+
+pub struct SendExpiryAlertsParams {
+    /// Users loaded at a time; every user is walked, a batch at a time
+    pub batch_size: usize,
+}
+
+#[async_trait]
+pub trait SendExpiryAlertsUseCase: Send + Sync {
+    /// Alerts every user with products expiring within the next day; returns
+    /// how many were alerted.
+    async fn execute(&self, params: SendExpiryAlertsParams) -> Result<u32, NotificationError>;
+}
@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+use chrono::NaiveTime;
+
+use crate::domain::notification::errors::NotificationError;
+use crate::domain::notification::model::{CategoryToggles, NotificationPreferences};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct UpdateNotificationPreferencesParams {
+    pub user_id: UserId,
+    /// Minutes east of UTC of the user's local time, within ±14 hours
+    pub utc_offset_minutes: i32,
+    /// Local time of the daily digest
+    pub digest_at: NaiveTime,
+    /// Local start and end of the quiet hours; `None` for none
+    pub quiet_hours: Option<(NaiveTime, NaiveTime)>,
+    pub categories: CategoryToggles,
+}
+
+#[async_trait]
+pub trait UpdateNotificationPreferencesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: UpdateNotificationPreferencesParams,
+    ) -> Result<NotificationPreferences, NotificationError>;
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum PreferenceError {
+    #[error("preference.invalid_expiring_soon_days")]
+    InvalidExpiringSoonDays,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
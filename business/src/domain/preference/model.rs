@@ -0,0 +1,12 @@
+use crate::domain::product::enrichment::Allergen;
+use crate::domain::product::urgency::ExpiringSoonWindow;
+
+/// Per-user tuning of how the pantry is ranked and flagged.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UserPreferences {
+    /// Days after today a product still counts as "expiring soon"
+    pub expiring_soon: ExpiringSoonWindow,
+    /// Allergens the user must be warned about; products declaring any of
+    /// them are also left out of suggestions
+    pub allergies: Vec<Allergen>,
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::UserPreferences;
+
+#[async_trait]
+pub trait PreferenceRepository: Send + Sync {
+    /// The user's preferences; the server defaults if they never saved any.
+    async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+    async fn save(
+        &self,
+        user_id: &UserId,
+        preferences: &UserPreferences,
+    ) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::domain::preference::errors::PreferenceError;
+use crate::domain::preference::model::UserPreferences;
+use crate::domain::product::enrichment::Allergen;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct UpdatePreferencesParams {
+    pub user_id: UserId,
+    /// Must be within `1..=ExpiringSoonWindow::MAX_DAYS`
+    pub expiring_soon_days: u8,
+    pub allergies: Vec<Allergen>,
+}
+
+#[async_trait]
+pub trait UpdatePreferencesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: UpdatePreferencesParams,
+    ) -> Result<UserPreferences, PreferenceError>;
+}
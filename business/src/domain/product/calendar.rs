@@ -0,0 +1,176 @@
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveTime, Utc};
+
+use super::model::Product;
+
+/// A calendar month (UTC), written `YYYY-MM`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarMonth(NaiveDate);
+
+impl CalendarMonth {
+    pub fn new(year: i32, month: u32) -> Option<Self> {
+        NaiveDate::from_ymd_opt(year, month, 1).map(Self)
+    }
+
+    /// The month containing `now`.
+    pub fn current(now: DateTime<Utc>) -> Self {
+        Self(now.date_naive().with_day(1).unwrap_or(now.date_naive()))
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        self.0
+    }
+
+    /// Midnight of the first day.
+    pub fn start(&self) -> DateTime<Utc> {
+        self.0.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// Exclusive: midnight of the first day of the next month.
+    pub fn end(&self) -> DateTime<Utc> {
+        (self.0 + Months::new(1)).and_time(NaiveTime::MIN).and_utc()
+    }
+}
+
+impl std::fmt::Display for CalendarMonth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}", self.0.year(), self.0.month())
+    }
+}
+
+impl std::str::FromStr for CalendarMonth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_once('-')
+            .filter(|(year, month)| year.len() == 4 && month.len() == 2)
+            .and_then(|(year, month)| Self::new(year.parse().ok()?, month.parse().ok()?))
+            .ok_or_else(|| format!("Invalid month: {}", s))
+    }
+}
+
+/// Products expiring on one day, split by where the date came from.
+#[derive(Debug, Clone)]
+pub struct CalendarDay {
+    pub date: NaiveDate,
+    /// Dates read from the label or entered by the user
+    pub real: Vec<Product>,
+    /// Dates estimated by AI for products without one
+    pub estimated: Vec<Product>,
+}
+
+/// Active products by expiry day over one month. Days with nothing
+/// expiring are left out.
+#[derive(Debug, Clone)]
+pub struct ExpiryCalendar {
+    pub month: CalendarMonth,
+    /// Oldest first
+    pub days: Vec<CalendarDay>,
+}
+
+impl ExpiryCalendar {
+    /// Groups `products` by the day of their real expiry date, or of the
+    /// estimate when there is none. Products expiring outside the month
+    /// or with no date at all are ignored.
+    pub fn build(month: CalendarMonth, products: Vec<Product>) -> Self {
+        let mut days: Vec<CalendarDay> = Vec::new();
+        for product in products {
+            let (date, estimated) = match (product.expiry_date, product.estimated_expiry_date) {
+                (Some(date), _) => (date, false),
+                (None, Some(date)) => (date, true),
+                (None, None) => continue,
+            };
+            if date < month.start() || date >= month.end() {
+                continue;
+            }
+
+            let date = date.date_naive();
+            let index = match days.binary_search_by_key(&date, |day| day.date) {
+                Ok(index) => index,
+                Err(index) => {
+                    days.insert(
+                        index,
+                        CalendarDay {
+                            date,
+                            real: Vec::new(),
+                            estimated: Vec::new(),
+                        },
+                    );
+                    index
+                }
+            };
+            if estimated {
+                days[index].estimated.push(product);
+            } else {
+                days[index].real.push(product);
+            }
+        }
+        Self { month, days }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn product(
+        expiry_date: Option<DateTime<Utc>>,
+        estimated_expiry_date: Option<DateTime<Utc>>,
+    ) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            "Yogur".to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            expiry_date,
+            estimated_expiry_date,
+            ExpiryType::UseBy,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn at(month: u32, day: u32, hour: u32) -> Option<DateTime<Utc>> {
+        Some(Utc.with_ymd_and_hms(2026, month, day, hour, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn should_parse_month_and_reject_malformed_ones() {
+        let month: CalendarMonth = "2026-12".parse().unwrap();
+
+        assert_eq!(month.to_string(), "2026-12");
+        assert_eq!(
+            month.end(),
+            Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert!("2026-13".parse::<CalendarMonth>().is_err());
+        assert!("2026-3".parse::<CalendarMonth>().is_err());
+        assert!("march".parse::<CalendarMonth>().is_err());
+    }
+
+    #[test]
+    fn should_group_by_day_keeping_estimates_apart() {
+        let month = CalendarMonth::new(2026, 3).unwrap();
+        let products = vec![
+            product(at(3, 20, 9), None),
+            product(None, at(3, 4, 23)),
+            product(at(3, 4, 8), at(3, 28, 0)),
+            product(at(4, 1, 0), None),
+            product(None, None),
+        ];
+
+        let calendar = ExpiryCalendar::build(month, products);
+
+        let dates: Vec<u32> = calendar.days.iter().map(|d| d.date.day()).collect();
+        assert_eq!(dates, vec![4, 20]);
+        assert_eq!(calendar.days[0].real.len(), 1);
+        assert_eq!(calendar.days[0].estimated.len(), 1);
+        assert_eq!(calendar.days[1].real.len(), 1);
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+
+use super::model::Product;
+use super::value_objects::ProductStatus;
+
+/// Active products whose names only differ in case, accents, spacing or
+/// punctuation, e.g. "LECHE SEMI-DESNATADA" from a receipt and
+/// "Leche semidesnatada" typed by hand.
+#[derive(Debug, Clone)]
+pub struct DuplicateCluster {
+    pub key: String,
+    /// Oldest first; the first one is kept when merging unless told otherwise
+    pub products: Vec<Product>,
+}
+
+/// Name key duplicates are matched on: lowercase letters and digits only,
+/// with accents dropped.
+pub fn duplicate_key(name: &str) -> String {
+    name.chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .filter(|c| c.is_alphanumeric())
+        .collect()
+}
+
+pub(super) fn fold_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'ñ' => 'n',
+        'ç' => 'c',
+        other => other,
+    }
+}
+
+/// Groups the active products sharing a [`duplicate_key`]. Clusters with
+/// the most products come first.
+pub fn find_duplicate_clusters(products: Vec<Product>) -> Vec<DuplicateCluster> {
+    let mut by_key: HashMap<String, Vec<Product>> = HashMap::new();
+    for product in products {
+        if product.status == ProductStatus::Finished {
+            continue;
+        }
+        let key = duplicate_key(&product.name);
+        if key.is_empty() {
+            continue;
+        }
+        by_key.entry(key).or_default().push(product);
+    }
+
+    let mut clusters: Vec<DuplicateCluster> = by_key
+        .into_iter()
+        .filter(|(_, products)| products.len() > 1)
+        .map(|(key, mut products)| {
+            products.sort_by_key(|p| (p.created_at, p.id));
+            DuplicateCluster { key, products }
+        })
+        .collect();
+    clusters.sort_by(|a, b| {
+        b.products
+            .len()
+            .cmp(&a.products.len())
+            .then_with(|| a.key.cmp(&b.key))
+    });
+    clusters
+}
+
+/// Folds `others` into `keeper`.
+///
+/// Business rules:
+/// - The earliest printed expiry date wins, with its expiry type, and so does
+///   the earliest estimate: a duplicate never makes food look fresher
+/// - Location and quantity are filled in from the others when the keeper
+///   has none
+/// - Name and status stay the keeper's
+pub fn merge_into(keeper: &mut Product, others: &[Product]) {
+    for other in others {
+        if let Some(date) = other.expiry_date
+            && keeper.expiry_date.is_none_or(|kept| date < kept)
+        {
+            keeper.expiry_date = Some(date);
+            keeper.expiry_type = other.expiry_type;
+        }
+        if let Some(date) = other.estimated_expiry_date
+            && keeper.estimated_expiry_date.is_none_or(|kept| date < kept)
+        {
+            keeper.estimated_expiry_date = Some(date);
+            keeper.expiry_confidence = other.expiry_confidence.clone();
+        }
+        if keeper.location.is_none() {
+            keeper.location = other.location.clone();
+        }
+        if keeper.quantity.is_none() {
+            keeper.quantity = other.quantity.clone();
+        }
+    }
+    keeper.updated_at = Utc::now();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::value_objects::{ExpiryType, ProductLocation};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{DateTime, Duration};
+    use uuid::Uuid;
+
+    fn make_product(name: &str, status: ProductStatus, created_days_ago: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            name.to_string(),
+            status,
+            None,
+            None,
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now() - Duration::days(created_days_ago),
+            Utc::now(),
+        )
+    }
+
+    fn in_days(days: i64) -> Option<DateTime<Utc>> {
+        Some(Utc::now() + Duration::days(days))
+    }
+
+    #[test]
+    fn should_match_names_ignoring_case_accents_and_punctuation() {
+        assert_eq!(duplicate_key("LECHE SEMI-DESNATADA"), "lechesemidesnatada");
+        assert_eq!(
+            duplicate_key(" Leche semidesnatada. "),
+            "lechesemidesnatada"
+        );
+        assert_eq!(duplicate_key("Plátano"), duplicate_key("PLATANO"));
+        assert_ne!(duplicate_key("Pan"), duplicate_key("Pan integral"));
+    }
+
+    #[test]
+    fn should_cluster_active_products_oldest_first() {
+        let clusters = find_duplicate_clusters(vec![
+            make_product("Yogur natural", ProductStatus::New, 1),
+            make_product("YOGUR NATURAL", ProductStatus::Opened, 5),
+            make_product("Yogur Natural", ProductStatus::Finished, 9),
+            make_product("Pan", ProductStatus::New, 2),
+            make_product("Tomate", ProductStatus::New, 3),
+            make_product("Tomate ", ProductStatus::New, 4),
+            make_product("tomate", ProductStatus::AlmostEmpty, 2),
+        ]);
+
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0].key, "tomate");
+        assert_eq!(clusters[0].products.len(), 3);
+        assert_eq!(clusters[1].key, "yogurnatural");
+        assert_eq!(clusters[1].products[0].name, "YOGUR NATURAL");
+    }
+
+    #[test]
+    fn should_keep_earliest_dates_and_fill_missing_fields() {
+        let mut keeper = make_product("Pollo", ProductStatus::New, 3);
+        keeper.expiry_date = in_days(5);
+        keeper.expiry_type = ExpiryType::BestBefore;
+        let mut other = make_product("POLLO", ProductStatus::New, 1);
+        other.expiry_date = in_days(2);
+        other.expiry_type = ExpiryType::UseBy;
+        other.estimated_expiry_date = in_days(3);
+        other.location = Some(ProductLocation::Fridge);
+        other.quantity = Some("500 g".to_string());
+
+        merge_into(&mut keeper, &[other.clone()]);
+
+        assert_eq!(keeper.name, "Pollo");
+        assert_eq!(keeper.expiry_date, other.expiry_date);
+        assert_eq!(keeper.expiry_type, ExpiryType::UseBy);
+        assert_eq!(keeper.estimated_expiry_date, other.estimated_expiry_date);
+        assert_eq!(keeper.location, Some(ProductLocation::Fridge));
+        assert_eq!(keeper.quantity.as_deref(), Some("500 g"));
+    }
+}
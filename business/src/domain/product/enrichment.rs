@@ -0,0 +1,226 @@
+use chrono::{DateTime, Utc};
+
+/// Letter grade of a Nutri-Score or Eco-Score, from A (best) to E.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreGrade {
+    A,
+    B,
+    C,
+    D,
+    E,
+}
+
+impl std::fmt::Display for ScoreGrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreGrade::A => write!(f, "a"),
+            ScoreGrade::B => write!(f, "b"),
+            ScoreGrade::C => write!(f, "c"),
+            ScoreGrade::D => write!(f, "d"),
+            ScoreGrade::E => write!(f, "e"),
+        }
+    }
+}
+
+impl std::str::FromStr for ScoreGrade {
+    type Err = String;
+
+    /// Accepts either case. Open Food Facts' "a-plus" Eco-Score counts as A.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "a" | "a-plus" => Ok(ScoreGrade::A),
+            "b" => Ok(ScoreGrade::B),
+            "c" => Ok(ScoreGrade::C),
+            "d" => Ok(ScoreGrade::D),
+            "e" => Ok(ScoreGrade::E),
+            _ => Err(format!("Invalid score grade: {}", s)),
+        }
+    }
+}
+
+/// The 14 allergens EU food labels must declare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Allergen {
+    Celery,
+    Crustaceans,
+    Eggs,
+    Fish,
+    Gluten,
+    Lupin,
+    Milk,
+    Molluscs,
+    Mustard,
+    Nuts,
+    Peanuts,
+    SesameSeeds,
+    Soybeans,
+    Sulphites,
+}
+
+impl std::fmt::Display for Allergen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Allergen::Celery => write!(f, "celery"),
+            Allergen::Crustaceans => write!(f, "crustaceans"),
+            Allergen::Eggs => write!(f, "eggs"),
+            Allergen::Fish => write!(f, "fish"),
+            Allergen::Gluten => write!(f, "gluten"),
+            Allergen::Lupin => write!(f, "lupin"),
+            Allergen::Milk => write!(f, "milk"),
+            Allergen::Molluscs => write!(f, "molluscs"),
+            Allergen::Mustard => write!(f, "mustard"),
+            Allergen::Nuts => write!(f, "nuts"),
+            Allergen::Peanuts => write!(f, "peanuts"),
+            Allergen::SesameSeeds => write!(f, "sesame_seeds"),
+            Allergen::Soybeans => write!(f, "soybeans"),
+            Allergen::Sulphites => write!(f, "sulphites"),
+        }
+    }
+}
+
+impl std::str::FromStr for Allergen {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "celery" => Ok(Allergen::Celery),
+            "crustaceans" => Ok(Allergen::Crustaceans),
+            "eggs" => Ok(Allergen::Eggs),
+            "fish" => Ok(Allergen::Fish),
+            "gluten" => Ok(Allergen::Gluten),
+            "lupin" => Ok(Allergen::Lupin),
+            "milk" => Ok(Allergen::Milk),
+            "molluscs" => Ok(Allergen::Molluscs),
+            "mustard" => Ok(Allergen::Mustard),
+            "nuts" => Ok(Allergen::Nuts),
+            "peanuts" => Ok(Allergen::Peanuts),
+            "sesame_seeds" => Ok(Allergen::SesameSeeds),
+            "soybeans" => Ok(Allergen::Soybeans),
+            "sulphites" => Ok(Allergen::Sulphites),
+            _ => Err(format!("Invalid allergen: {}", s)),
+        }
+    }
+}
+
+/// Nutrition facts per 100 g (or 100 ml). Each value is missing when the
+/// source doesn't publish it.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NutritionFacts {
+    pub energy_kcal: Option<f64>,
+    pub fat: Option<f64>,
+    pub saturated_fat: Option<f64>,
+    pub carbohydrates: Option<f64>,
+    pub sugars: Option<f64>,
+    pub fiber: Option<f64>,
+    pub proteins: Option<f64>,
+    pub salt: Option<f64>,
+}
+
+impl NutritionFacts {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Public data about a packaged product, looked up by its barcode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductEnrichment {
+    pub barcode: String,
+    pub nutriscore: Option<ScoreGrade>,
+    pub ecoscore: Option<ScoreGrade>,
+    pub nutrition: NutritionFacts,
+    /// Allergens the label declares, sorted and without duplicates.
+    pub allergens: Vec<Allergen>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ProductEnrichment {
+    /// Returns `None` when there is nothing worth keeping.
+    pub fn new(
+        barcode: String,
+        nutriscore: Option<ScoreGrade>,
+        ecoscore: Option<ScoreGrade>,
+        nutrition: NutritionFacts,
+        mut allergens: Vec<Allergen>,
+    ) -> Option<Self> {
+        if nutriscore.is_none()
+            && ecoscore.is_none()
+            && nutrition.is_empty()
+            && allergens.is_empty()
+        {
+            return None;
+        }
+        allergens.sort();
+        allergens.dedup();
+        Some(Self {
+            barcode,
+            nutriscore,
+            ecoscore,
+            nutrition,
+            allergens,
+            fetched_at: Utc::now(),
+        })
+    }
+
+    /// Declared allergens among `allergies`, for warning the user.
+    pub fn allergens_among(&self, allergies: &[Allergen]) -> Vec<Allergen> {
+        self.allergens
+            .iter()
+            .filter(|a| allergies.contains(a))
+            .copied()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_grades_ignoring_case() {
+        assert_eq!("B".parse::<ScoreGrade>(), Ok(ScoreGrade::B));
+        assert_eq!("a-plus".parse::<ScoreGrade>(), Ok(ScoreGrade::A));
+        assert!("unknown".parse::<ScoreGrade>().is_err());
+        assert!("not-applicable".parse::<ScoreGrade>().is_err());
+    }
+
+    #[test]
+    fn should_skip_enrichment_without_any_data() {
+        let empty = ProductEnrichment::new(
+            "8410000810004".to_string(),
+            None,
+            None,
+            NutritionFacts::default(),
+            vec![],
+        );
+        let scored = ProductEnrichment::new(
+            "8410000810004".to_string(),
+            Some(ScoreGrade::C),
+            None,
+            NutritionFacts::default(),
+            vec![],
+        );
+
+        assert!(empty.is_none());
+        assert!(scored.is_some());
+    }
+
+    #[test]
+    fn should_warn_only_about_declared_allergies() {
+        let enrichment = ProductEnrichment::new(
+            "8410000810004".to_string(),
+            None,
+            None,
+            NutritionFacts::default(),
+            vec![Allergen::Milk, Allergen::Gluten, Allergen::Milk],
+        )
+        .unwrap();
+
+        assert_eq!(enrichment.allergens, vec![Allergen::Gluten, Allergen::Milk]);
+        assert_eq!(
+            enrichment.allergens_among(&[Allergen::Milk, Allergen::Peanuts]),
+            vec![Allergen::Milk]
+        );
+        assert!(enrichment.allergens_among(&[]).is_empty());
+    }
+}
@@ -1,3 +1,6 @@
+use super::value_objects::ProductStatus;
+use crate::domain::errors::ErrorSource;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ProductError {
     #[error("product.name_empty")]
@@ -6,10 +9,49 @@ pub enum ProductError {
     NotFound,
     #[error("product.outcome_requires_finished_status")]
     OutcomeRequiresFinishedStatus,
+    #[error("product.invalid_status_transition")]
+    InvalidStatusTransition {
+        from: ProductStatus,
+        to: ProductStatus,
+    },
     #[error("product.identification_failed")]
-    IdentificationFailed,
+    IdentificationFailed(#[source] ErrorSource),
     #[error("product.scan_failed")]
-    ScanFailed,
+    ScanFailed(#[source] ErrorSource),
+    #[error("product.invalid_image")]
+    InvalidImage,
+    #[error("product.image_not_found")]
+    ImageNotFound,
+    #[error("product.not_duplicates")]
+    NotDuplicates,
+    #[error("product.too_many_tags")]
+    TooManyTags,
+    #[error("product.tag_too_long")]
+    TagTooLong,
+    /// The active list was asked for finished products, which only the
+    /// history lists.
+    #[error("product.finished_not_in_active_list")]
+    FinishedNotInActiveList,
+    /// An item of a batch create failed validation; nothing was saved.
+    #[error("product.invalid_batch_item")]
+    InvalidBatchItem {
+        index: usize,
+        #[source]
+        source: Box<ProductError>,
+    },
+    #[error(transparent)]
+    Quota(#[from] crate::domain::quota::errors::QuotaError),
+    #[error(transparent)]
+    Storage(#[from] crate::domain::storage::errors::StorageError),
     #[error("repository.persistence")]
     Repository(#[from] crate::domain::errors::RepositoryError),
 }
+
+impl ProductError {
+    pub fn identification_failed(cause: impl Into<ErrorSource>) -> Self {
+        ProductError::IdentificationFailed(cause.into())
+    }
+    pub fn scan_failed(cause: impl Into<ErrorSource>) -> Self {
+        ProductError::ScanFailed(cause.into())
+    }
+}
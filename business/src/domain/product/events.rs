@@ -0,0 +1,126 @@
+use uuid::Uuid;
+
+use super::lifecycle::StatusTransition;
+use super::urgency::UrgencyLevel;
+use super::value_objects::{ProductOutcome, ProductStatus};
+use crate::domain::events::DomainEvent;
+use crate::domain::shared::value_objects::UserId;
+
+/// A product moved from one status to another.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductStatusChanged {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+    pub product_name: String,
+    pub previous_status: ProductStatus,
+    pub new_status: ProductStatus,
+    pub transition: StatusTransition,
+}
+
+impl ProductStatusChanged {
+    /// The product has just run out.
+    pub fn finished(&self) -> bool {
+        self.transition == StatusTransition::Finished
+    }
+
+    /// A finished product was marked as available again.
+    pub fn restored(&self) -> bool {
+        self.transition == StatusTransition::Restored
+    }
+}
+
+/// A product was added to the pantry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductAdded {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+    pub product_name: String,
+    /// Urgency when added, under the default expiring-soon window
+    pub urgency: UrgencyLevel,
+}
+
+impl ProductAdded {
+    /// The product already needs using within the next few days.
+    pub fn urgent(&self) -> bool {
+        self.urgency.is_urgent()
+    }
+}
+
+/// A product was finished and the user said what became of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductOutcomeRecorded {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+    pub product_name: String,
+    pub outcome: ProductOutcome,
+    /// Urgency just before it was finished, under the default expiring-soon
+    /// window
+    pub urgency: UrgencyLevel,
+}
+
+impl ProductOutcomeRecorded {
+    /// An urgent product was eaten rather than thrown or given away.
+    pub fn used_in_time(&self) -> bool {
+        self.outcome == ProductOutcome::Used && self.urgency.is_urgent()
+    }
+}
+
+/// A product was edited. Raised on every saved edit, whatever changed, next
+/// to the more specific events above.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductUpdated {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+}
+
+/// A product was deleted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductDeleted {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+}
+
+/// Kind of write made to a product.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+/// A write to one of the user's products, as followed by the devices showing
+/// the pantry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductChange {
+    pub product_id: Uuid,
+    pub user_id: UserId,
+    pub kind: ProductChangeKind,
+}
+
+impl ProductChange {
+    /// The write an event stands for, if any. Status changes and outcomes
+    /// come with a [`ProductUpdated`] and aren't counted twice.
+    pub fn from_event(event: &DomainEvent) -> Option<Self> {
+        let (product_id, user_id, kind) = match event {
+            DomainEvent::ProductAdded(added) => {
+                (added.product_id, &added.user_id, ProductChangeKind::Created)
+            }
+            DomainEvent::ProductUpdated(updated) => (
+                updated.product_id,
+                &updated.user_id,
+                ProductChangeKind::Updated,
+            ),
+            DomainEvent::ProductDeleted(deleted) => (
+                deleted.product_id,
+                &deleted.user_id,
+                ProductChangeKind::Deleted,
+            ),
+            _ => return None,
+        };
+        Some(Self {
+            product_id,
+            user_id: user_id.clone(),
+            kind,
+        })
+    }
+}
@@ -0,0 +1,91 @@
+use chrono::{DateTime, Days, Duration, Utc};
+use chrono_tz::Tz;
+
+use super::local_day;
+
+/// How AI-estimated expiry dates are normalized before they are stored.
+///
+/// Estimators answer "in N days" as `now + N days`, which lands at whatever
+/// time of day the request ran. Such a product turns expired mid-afternoon and
+/// its urgency shifts during the day; snapping to the end of the local day
+/// keeps it stable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpirySnap {
+    /// Store the estimate as returned
+    Exact,
+    /// Last second of the estimate's calendar day in this time zone
+    EndOfLocalDay(Tz),
+}
+
+impl ExpirySnap {
+    pub fn apply(&self, date: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            ExpirySnap::Exact => date,
+            ExpirySnap::EndOfLocalDay(time_zone) => {
+                let local_day = local_day::date_in(*time_zone, date);
+                local_day
+                    .checked_add_days(Days::new(1))
+                    .map_or(date, |next_day| {
+                        local_day::start_of_in(*time_zone, next_day) - Duration::seconds(1)
+                    })
+            }
+        }
+    }
+}
+
+/// End of the UTC day.
+impl Default for ExpirySnap {
+    fn default() -> Self {
+        ExpirySnap::EndOfLocalDay(Tz::UTC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn should_snap_to_end_of_utc_day_by_default() {
+        let estimate = Utc.with_ymd_and_hms(2026, 3, 12, 15, 42, 7).unwrap();
+
+        assert_eq!(
+            ExpirySnap::default().apply(estimate),
+            Utc.with_ymd_and_hms(2026, 3, 12, 23, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_snap_to_end_of_local_day_when_time_zone_configured() {
+        // 23:30 UTC is already the next day in Madrid
+        let estimate = Utc.with_ymd_and_hms(2026, 7, 12, 23, 30, 0).unwrap();
+
+        assert_eq!(
+            ExpirySnap::EndOfLocalDay(Tz::Europe__Madrid).apply(estimate),
+            Utc.with_ymd_and_hms(2026, 7, 13, 21, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_snap_to_local_day_across_daylight_saving_change() {
+        let madrid = ExpirySnap::EndOfLocalDay(Tz::Europe__Madrid);
+        let winter = Utc.with_ymd_and_hms(2026, 3, 28, 12, 0, 0).unwrap();
+        let summer = Utc.with_ymd_and_hms(2026, 3, 29, 12, 0, 0).unwrap();
+
+        assert_eq!(
+            madrid.apply(winter),
+            Utc.with_ymd_and_hms(2026, 3, 28, 22, 59, 59).unwrap()
+        );
+        assert_eq!(
+            madrid.apply(summer),
+            Utc.with_ymd_and_hms(2026, 3, 29, 21, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_keep_estimate_when_exact() {
+        let estimate = Utc.with_ymd_and_hms(2026, 3, 12, 15, 42, 7).unwrap();
+
+        assert_eq!(ExpirySnap::Exact.apply(estimate), estimate);
+    }
+}
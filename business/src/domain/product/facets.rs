@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::enrichment::ProductEnrichment;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+/// Optional data a product can be returned with, each fetched only when
+/// asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProductFacet {
+    /// The unbought shopping item restocking the product
+    ShoppingItem,
+    /// Nutrition data of the barcode the product was scanned with
+    Enrichment,
+    /// How the user's earlier products with the same name ended
+    HistorySummary,
+}
+
+/// Outcomes of the user's finished products with the same name as a product,
+/// case and surrounding spaces ignored.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProductHistorySummary {
+    /// Finished products, including those without an outcome
+    pub finished: u32,
+    pub used: u32,
+    pub thrown_away: u32,
+    pub given_away: u32,
+    /// When the latest of them was last updated
+    pub last_finished_at: Option<DateTime<Utc>>,
+}
+
+/// Facets of a batch of products, keyed by product id. Products without
+/// data for a facet, or whose facet was not asked for, are left out of its
+/// map.
+#[derive(Debug, Default)]
+pub struct ProductFacets {
+    pub shopping_items: HashMap<Uuid, ShoppingItem>,
+    pub enrichments: HashMap<Uuid, ProductEnrichment>,
+    pub history: HashMap<Uuid, ProductHistorySummary>,
+}
@@ -0,0 +1,119 @@
+use chrono::{DateTime, Duration, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use uuid::Uuid;
+
+use super::model::Product;
+use super::value_objects::{ExpiryType, ProductLocation, ProductStatus};
+use crate::domain::shared::value_objects::UserId;
+
+const NAMES: [&str; 24] = [
+    "Leche entera",
+    "Yogur natural",
+    "Pechuga de pollo",
+    "Huevos",
+    "Queso manchego",
+    "Jamón serrano",
+    "Tomates",
+    "Lechuga",
+    "Pimientos",
+    "Cebollas",
+    "Patatas",
+    "Arroz",
+    "Lentejas",
+    "Garbanzos",
+    "Pasta",
+    "Aceite de oliva",
+    "Pan de molde",
+    "Mantequilla",
+    "Salmón",
+    "Merluza",
+    "Guisantes congelados",
+    "Espinacas",
+    "Fresas",
+    "Plátanos",
+];
+
+const STATUSES: [ProductStatus; 4] = [
+    ProductStatus::New,
+    ProductStatus::New,
+    ProductStatus::Opened,
+    ProductStatus::AlmostEmpty,
+];
+
+const LOCATIONS: [Option<ProductLocation>; 4] = [
+    Some(ProductLocation::Fridge),
+    Some(ProductLocation::Pantry),
+    Some(ProductLocation::Freezer),
+    None,
+];
+
+/// Realistic-looking pantry for load tests and benchmarks: repeated names,
+/// a spread of statuses and locations, and expiry dates from a few days ago
+/// to two months ahead (some missing, some only estimated).
+///
+/// The same `seed` always yields the same names, dates and statuses relative
+/// to `now`, so benchmark runs stay comparable. Ids are always fresh.
+pub fn sample_pantry(
+    user_id: &UserId,
+    count: usize,
+    seed: u64,
+    now: DateTime<Utc>,
+) -> Vec<Product> {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..count)
+        .map(|_| {
+            let name = NAMES[rng.random_range(0..NAMES.len())];
+            let expiry = match rng.random_range(0..10) {
+                0 => None,
+                _ => Some(now + Duration::days(rng.random_range(-5..60))),
+            };
+            // A third of the dated products only carry an AI estimate
+            let (expiry_date, estimated_expiry_date) = if rng.random_bool(1.0 / 3.0) {
+                (None, expiry)
+            } else {
+                (expiry, None)
+            };
+            let created_at = now - Duration::minutes(rng.random_range(0..60 * 24 * 30));
+
+            Product::from_repository(
+                Uuid::new_v4(),
+                user_id.clone(),
+                name.to_string(),
+                STATUSES[rng.random_range(0..STATUSES.len())].clone(),
+                LOCATIONS[rng.random_range(0..LOCATIONS.len())].clone(),
+                None,
+                expiry_date,
+                estimated_expiry_date,
+                ExpiryType::None,
+                None,
+                created_at,
+                created_at,
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_generate_same_pantry_for_same_seed() {
+        let user_id = UserId::new("load-test-user");
+        let now = Utc::now();
+
+        let first = sample_pantry(&user_id, 20, 7, now);
+        let second = sample_pantry(&user_id, 20, 7, now);
+
+        assert_eq!(first.len(), 20);
+        for (a, b) in first.iter().zip(&second) {
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.status, b.status);
+            assert_eq!(a.expiry_date, b.expiry_date);
+            assert_eq!(a.estimated_expiry_date, b.estimated_expiry_date);
+        }
+        assert!(first.iter().all(|p| p.user_id == user_id));
+    }
+}
@@ -0,0 +1,119 @@
+use super::errors::ProductError;
+use super::value_objects::ProductStatus;
+
+/// Meaning of an allowed status change, carried by
+/// [`ProductStatusChanged`](super::events::ProductStatusChanged) so handlers
+/// react to what happened instead of comparing statuses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusTransition {
+    /// A new product was opened
+    Opened,
+    /// The product is running out
+    RunningLow,
+    /// The product ran out or was thrown away
+    Finished,
+    /// An almost empty product was marked as opened again
+    Corrected,
+    /// A finished product is available again (restocked or marked by mistake)
+    Restored,
+}
+
+impl ProductStatus {
+    /// Position in the regular lifecycle `New → Opened → AlmostEmpty → Finished`.
+    fn stage(&self) -> u8 {
+        match self {
+            ProductStatus::New => 0,
+            ProductStatus::Opened => 1,
+            ProductStatus::AlmostEmpty => 2,
+            ProductStatus::Finished => 3,
+        }
+    }
+
+    /// Checks a status change against the product lifecycle.
+    ///
+    /// Products may move forward any number of steps (an unopened product
+    /// can be finished straight away). Going back is limited to undoing
+    /// "almost empty" and restoring a finished product; an opened product
+    /// can never become new again. Returns `None` when the status is unchanged.
+    pub fn transition_to(
+        &self,
+        next: &ProductStatus,
+    ) -> Result<Option<StatusTransition>, ProductError> {
+        if self == next {
+            return Ok(None);
+        }
+        let transition = match (self, next) {
+            (ProductStatus::Finished, _) => StatusTransition::Restored,
+            (ProductStatus::AlmostEmpty, ProductStatus::Opened) => StatusTransition::Corrected,
+            (_, next) if next.stage() < self.stage() => {
+                return Err(ProductError::InvalidStatusTransition {
+                    from: self.clone(),
+                    to: next.clone(),
+                });
+            }
+            (_, ProductStatus::Opened) => StatusTransition::Opened,
+            (_, ProductStatus::AlmostEmpty) => StatusTransition::RunningLow,
+            (_, ProductStatus::Finished) => StatusTransition::Finished,
+            (_, ProductStatus::New) => unreachable!("nothing is behind new"),
+        };
+        Ok(Some(transition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ProductStatus::{AlmostEmpty, Finished, New, Opened};
+
+    #[test]
+    fn should_allow_moving_forward_through_the_lifecycle() {
+        assert_eq!(
+            New.transition_to(&Opened).unwrap(),
+            Some(StatusTransition::Opened)
+        );
+        assert_eq!(
+            Opened.transition_to(&AlmostEmpty).unwrap(),
+            Some(StatusTransition::RunningLow)
+        );
+        assert_eq!(
+            AlmostEmpty.transition_to(&Finished).unwrap(),
+            Some(StatusTransition::Finished)
+        );
+        assert_eq!(
+            New.transition_to(&Finished).unwrap(),
+            Some(StatusTransition::Finished)
+        );
+    }
+
+    #[test]
+    fn should_allow_limited_reversals() {
+        assert_eq!(
+            AlmostEmpty.transition_to(&Opened).unwrap(),
+            Some(StatusTransition::Corrected)
+        );
+        for status in [New, Opened, AlmostEmpty] {
+            assert_eq!(
+                Finished.transition_to(&status).unwrap(),
+                Some(StatusTransition::Restored)
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_going_back_to_new_once_opened() {
+        for status in [Opened, AlmostEmpty] {
+            assert!(matches!(
+                status.transition_to(&New),
+                Err(ProductError::InvalidStatusTransition { to: New, .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn should_report_no_transition_when_status_unchanged() {
+        for status in [New, Opened, AlmostEmpty, Finished] {
+            assert_eq!(status.transition_to(&status).unwrap(), None);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::sync::OnceLock;
+
+use chrono::{DateTime, Days, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+/// Time zone of the calendar day products expire on. Dates are stored in UTC,
+/// but "expires today" means the household's today.
+static TIME_ZONE: OnceLock<Tz> = OnceLock::new();
+
+/// Sets the time zone of the local day, once at startup. Later calls are
+/// ignored; returns whether this one took effect.
+pub fn configure(time_zone: Tz) -> bool {
+    TIME_ZONE.set(time_zone).is_ok()
+}
+
+/// The configured time zone, UTC until one is set.
+pub fn time_zone() -> Tz {
+    TIME_ZONE.get().copied().unwrap_or(Tz::UTC)
+}
+
+/// Local calendar day of `at`.
+pub fn date_of(at: DateTime<Utc>) -> NaiveDate {
+    date_in(time_zone(), at)
+}
+
+/// First instant of the local day `date`.
+pub fn start_of(date: NaiveDate) -> DateTime<Utc> {
+    start_of_in(time_zone(), date)
+}
+
+pub fn date_in(time_zone: Tz, at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&time_zone).date_naive()
+}
+
+/// First instant of `date` in `time_zone`. Days are 23 or 25 hours long when
+/// the clocks change, and where they jump forward at midnight the day starts
+/// after the gap.
+pub fn start_of_in(time_zone: Tz, date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    (0..=4)
+        .find_map(|half_hours| {
+            time_zone
+                .from_local_datetime(&(midnight + Duration::minutes(30 * half_hours)))
+                .earliest()
+        })
+        .map_or_else(|| midnight.and_utc(), |start| start.with_timezone(&Utc))
+}
+
+/// First instant of the local day `days` after the one `at` falls on.
+pub fn start_of_day_after(at: DateTime<Utc>, days: u64) -> DateTime<Utc> {
+    let date = date_of(at);
+    start_of(date.checked_add_days(Days::new(days)).unwrap_or(date))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn should_start_days_at_utc_midnight_by_default() {
+        assert_eq!(
+            start_of_in(Tz::UTC, date(2026, 3, 12)),
+            Utc.with_ymd_and_hms(2026, 3, 12, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_follow_daylight_saving_time() {
+        let madrid = Tz::Europe__Madrid;
+
+        // Clocks go forward on 29 March 2026: the day before is still UTC+1
+        assert_eq!(
+            start_of_in(madrid, date(2026, 3, 29)),
+            Utc.with_ymd_and_hms(2026, 3, 28, 23, 0, 0).unwrap()
+        );
+        assert_eq!(
+            start_of_in(madrid, date(2026, 3, 30)),
+            Utc.with_ymd_and_hms(2026, 3, 29, 22, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_take_local_date_in_time_zone() {
+        // 23:30 UTC is already the next day in Madrid
+        let at = Utc.with_ymd_and_hms(2026, 7, 12, 23, 30, 0).unwrap();
+
+        assert_eq!(date_in(Tz::Europe__Madrid, at), date(2026, 7, 13));
+        assert_eq!(date_in(Tz::UTC, at), date(2026, 7, 12));
+    }
+}
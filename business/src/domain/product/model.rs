@@ -2,7 +2,11 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use super::errors::ProductError;
-use super::value_objects::{ProductLocation, ProductOutcome, ProductStatus};
+use super::services::Confidence;
+use super::tags::normalize_tags;
+use super::value_objects::{
+    EstimationStatus, ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
 use crate::domain::shared::value_objects::UserId;
 
 #[derive(Debug, Clone)]
@@ -15,7 +19,15 @@ pub struct Product {
     pub quantity: Option<String>,
     pub expiry_date: Option<DateTime<Utc>>,
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    /// How sure the estimator was of `estimated_expiry_date`; `None` when
+    /// the estimate came from the client or a reviewed change
+    pub expiry_confidence: Option<Confidence>,
+    pub expiry_type: ExpiryType,
     pub outcome: Option<ProductOutcome>,
+    pub estimation_status: EstimationStatus,
+    pub category: Option<ProductCategory>,
+    /// Free-form labels, normalized by [`normalize_tags`]
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -28,7 +40,10 @@ pub struct NewProductProps {
     pub quantity: Option<String>,
     pub expiry_date: Option<DateTime<Utc>>,
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    pub expiry_type: ExpiryType,
     pub outcome: Option<ProductOutcome>,
+    pub category: Option<ProductCategory>,
+    pub tags: Vec<String>,
 }
 
 impl Product {
@@ -41,6 +56,8 @@ impl Product {
             return Err(ProductError::OutcomeRequiresFinishedStatus);
         }
 
+        let tags = normalize_tags(props.tags)?;
+
         let now = Utc::now();
         Ok(Self {
             id: Uuid::new_v4(),
@@ -51,13 +68,21 @@ impl Product {
             quantity: props.quantity,
             expiry_date: props.expiry_date,
             estimated_expiry_date: props.estimated_expiry_date,
+            expiry_confidence: None,
+            expiry_type: props.expiry_type,
             outcome: props.outcome,
+            estimation_status: EstimationStatus::Done,
+            category: props.category,
+            tags,
             created_at: now,
             updated_at: now,
         })
     }
 
-    /// Constructor for data already persisted in the repository (no validation).
+    /// Constructor for data already persisted in the repository (no
+    /// validation). The estimation status is settled, the expiry confidence
+    /// unknown and there is no category or tags; set them afterwards when
+    /// the row holds others.
     #[allow(clippy::too_many_arguments)]
     pub fn from_repository(
         id: Uuid,
@@ -68,6 +93,7 @@ impl Product {
         quantity: Option<String>,
         expiry_date: Option<DateTime<Utc>>,
         estimated_expiry_date: Option<DateTime<Utc>>,
+        expiry_type: ExpiryType,
         outcome: Option<ProductOutcome>,
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -81,9 +107,24 @@ impl Product {
             quantity,
             expiry_date,
             estimated_expiry_date,
+            expiry_confidence: None,
+            expiry_type,
             outcome,
+            estimation_status: EstimationStatus::Done,
+            category: None,
+            tags: Vec::new(),
             created_at,
             updated_at,
         }
     }
+
+    /// Puts the product in `location`. Returns whether it was kept elsewhere.
+    pub fn move_to(&mut self, location: ProductLocation) -> bool {
+        if self.location.as_ref() == Some(&location) {
+            return false;
+        }
+        self.location = Some(location);
+        self.updated_at = Utc::now();
+        true
+    }
 }
@@ -0,0 +1,228 @@
+use uuid::Uuid;
+
+use super::model::Product;
+use super::services::{IdentificationConfidence, ProductIdentification};
+use super::value_objects::ProductLocation;
+
+/// Shortest name allowed to match by containment, so "pan" doesn't match "panceta".
+const MIN_CONTAINED_NAME_LEN: usize = 4;
+
+/// A product seen in the photo that is not in the pantry yet.
+#[derive(Debug, Clone)]
+pub struct ProposedCreation {
+    pub name: String,
+    pub confidence: IdentificationConfidence,
+    pub location: ProductLocation,
+    pub quantity: Option<String>,
+}
+
+/// A pantry product seen in the photo whose stored data disagrees with it.
+/// Only the fields that would change are set.
+#[derive(Debug, Clone)]
+pub struct ProposedUpdate {
+    pub product_id: Uuid,
+    /// Name stored in the pantry.
+    pub name: String,
+    /// Name the identifier gave to the item in the photo.
+    pub detected_name: String,
+    pub confidence: IdentificationConfidence,
+    pub location: Option<ProductLocation>,
+    pub quantity: Option<String>,
+}
+
+/// Changes a photo implies for the pantry, for the client to confirm.
+#[derive(Debug, Clone, Default)]
+pub struct PhotoDiff {
+    pub creations: Vec<ProposedCreation>,
+    pub updates: Vec<ProposedUpdate>,
+    /// Pantry products seen in the photo that need no change.
+    pub unchanged: Vec<Uuid>,
+}
+
+/// Compares the products identified in a photo taken at `location` with the
+/// active pantry.
+///
+/// Business rules:
+/// - Items without a name are ignored; an item seen twice is proposed once
+/// - Items are matched to pantry products by exact name (ignoring case and
+///   surrounding spaces), then by one name containing the other
+/// - Each pantry product is matched at most once
+/// - A matched product gets an update when it is stored elsewhere or the
+///   visible quantity differs; otherwise it is unchanged
+/// - Unmatched items are proposed as new products at `location`
+pub fn diff_photo_against_pantry(
+    detected: Vec<ProductIdentification>,
+    products: &[Product],
+    location: &ProductLocation,
+) -> PhotoDiff {
+    let mut diff = PhotoDiff::default();
+    let mut seen: Vec<String> = Vec::new();
+    let mut matched: Vec<Uuid> = Vec::new();
+
+    for item in detected {
+        let key = name_key(&item.name);
+        if key.is_empty() || seen.contains(&key) {
+            continue;
+        }
+        seen.push(key.clone());
+
+        let product = products
+            .iter()
+            .filter(|p| !matched.contains(&p.id))
+            .find(|p| name_key(&p.name) == key)
+            .or_else(|| {
+                products
+                    .iter()
+                    .filter(|p| !matched.contains(&p.id))
+                    .find(|p| names_overlap(&name_key(&p.name), &key))
+            });
+
+        let Some(product) = product else {
+            diff.creations.push(ProposedCreation {
+                name: item.name.trim().to_string(),
+                confidence: item.confidence,
+                location: location.clone(),
+                quantity: item.suggested_quantity,
+            });
+            continue;
+        };
+        matched.push(product.id);
+
+        let new_location = (product.location.as_ref() != Some(location)).then(|| location.clone());
+        let new_quantity = item
+            .suggested_quantity
+            .filter(|q| product.quantity.as_deref() != Some(q.as_str()));
+
+        if new_location.is_none() && new_quantity.is_none() {
+            diff.unchanged.push(product.id);
+        } else {
+            diff.updates.push(ProposedUpdate {
+                product_id: product.id,
+                name: product.name.clone(),
+                detected_name: item.name,
+                confidence: item.confidence,
+                location: new_location,
+                quantity: new_quantity,
+            });
+        }
+    }
+
+    diff
+}
+
+fn name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+fn names_overlap(a: &str, b: &str) -> bool {
+    let (short, long) = if a.len() < b.len() { (a, b) } else { (b, a) };
+    short.chars().count() >= MIN_CONTAINED_NAME_LEN && long.contains(short)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::services::IdentificationMethod;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+
+    fn make_product(
+        name: &str,
+        location: Option<ProductLocation>,
+        quantity: Option<&str>,
+    ) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            name.to_string(),
+            ProductStatus::New,
+            location,
+            quantity.map(|q| q.to_string()),
+            None,
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn detected(name: &str, quantity: Option<&str>) -> ProductIdentification {
+        ProductIdentification {
+            name: name.to_string(),
+            confidence: IdentificationConfidence::High,
+            method: IdentificationMethod::Visual,
+            suggested_location: None,
+            suggested_quantity: quantity.map(|q| q.to_string()),
+            suggested_expiry_type: None,
+            suggested_category: None,
+            enrichment: None,
+            ai_call_id: None,
+        }
+    }
+
+    #[test]
+    fn should_propose_creations_for_items_not_in_pantry() {
+        let diff = diff_photo_against_pantry(
+            vec![
+                detected("Yogur natural", Some("4 x 125 g")),
+                detected(" yogur NATURAL", None),
+                detected("", None),
+            ],
+            &[make_product("Arroz", Some(ProductLocation::Pantry), None)],
+            &ProductLocation::Fridge,
+        );
+
+        assert_eq!(diff.creations.len(), 1);
+        assert_eq!(diff.creations[0].name, "Yogur natural");
+        assert_eq!(diff.creations[0].location, ProductLocation::Fridge);
+        assert_eq!(diff.creations[0].quantity.as_deref(), Some("4 x 125 g"));
+        assert!(diff.updates.is_empty());
+        assert!(diff.unchanged.is_empty());
+    }
+
+    #[test]
+    fn should_propose_only_changed_fields_for_matched_products() {
+        let milk = make_product("Leche entera", Some(ProductLocation::Pantry), Some("1 L"));
+        let eggs = make_product("Huevos", Some(ProductLocation::Fridge), Some("6"));
+        let (milk_id, eggs_id) = (milk.id, eggs.id);
+
+        let diff = diff_photo_against_pantry(
+            vec![
+                detected("Leche", Some("1 L")),
+                detected("huevos", Some("12")),
+            ],
+            &[milk, eggs],
+            &ProductLocation::Fridge,
+        );
+
+        assert!(diff.creations.is_empty());
+        assert_eq!(diff.updates.len(), 2);
+        assert_eq!(diff.updates[0].product_id, milk_id);
+        assert_eq!(diff.updates[0].location, Some(ProductLocation::Fridge));
+        assert_eq!(diff.updates[0].quantity, None);
+        assert_eq!(diff.updates[1].product_id, eggs_id);
+        assert_eq!(diff.updates[1].location, None);
+        assert_eq!(diff.updates[1].quantity.as_deref(), Some("12"));
+    }
+
+    #[test]
+    fn should_report_unchanged_products_and_match_each_once() {
+        let butter = make_product("Mantequilla", Some(ProductLocation::Fridge), None);
+        let butter_id = butter.id;
+
+        let diff = diff_photo_against_pantry(
+            vec![
+                detected("Mantequilla", None),
+                detected("Mantequilla sin sal", None),
+            ],
+            &[butter],
+            &ProductLocation::Fridge,
+        );
+
+        assert_eq!(diff.unchanged, vec![butter_id]);
+        assert_eq!(diff.creations.len(), 1);
+        assert_eq!(diff.creations[0].name, "Mantequilla sin sal");
+    }
+}
@@ -0,0 +1,221 @@
+use chrono::{DateTime, Utc};
+
+use super::urgency::{ExpiringSoonWindow, UrgencyLevel};
+use super::value_objects::{ProductCategory, ProductLocation, ProductStatus};
+use crate::domain::shared::pagination::{Cursor, KeysetPage, MAX_PAGE_LIMIT};
+use crate::domain::shared::value_objects::UserId;
+
+/// Which products of the user a listing covers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProductScope {
+    #[default]
+    All,
+    /// Not finished yet: the current pantry
+    Active,
+    /// Finished products: the user's history
+    Finished,
+}
+
+/// Order of a product listing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProductSort {
+    /// Newest first
+    #[default]
+    CreatedAtDesc,
+    /// Soonest expiry (real date, or the estimate when missing) first;
+    /// products without any date come last
+    ExpiryAsc,
+    /// Alphabetical by name, case-insensitive
+    NameAsc,
+    /// Most urgent first, by [`UrgencyLevel::rank`](super::urgency::UrgencyLevel::rank),
+    /// then soonest expiry; products without any date close the "ok" group
+    UrgencyDesc,
+}
+
+/// Limit/offset window over a listing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+impl Page {
+    /// Clamps the limit to `1..=MAX_PAGE_LIMIT`.
+    pub fn new(limit: u32, offset: u32) -> Self {
+        Self {
+            limit: limit.clamp(1, MAX_PAGE_LIMIT),
+            offset,
+        }
+    }
+}
+
+/// Describes which of a user's products to list and in what order.
+///
+/// New list variations add a filter here instead of a new repository method.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductQuery {
+    pub user_id: UserId,
+    pub scope: ProductScope,
+    /// Case-insensitive substring match on the name
+    pub name_contains: Option<String>,
+    /// Keeps products whose expiry (real or estimated) falls on or before this instant
+    pub expiring_before: Option<DateTime<Utc>>,
+    /// Keeps products whose expiry (real or estimated) falls on or after the
+    /// first instant and before the second
+    pub expiring_between: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Keeps only products the user flagged as unwanted
+    pub unwanted_only: bool,
+    pub status: Option<ProductStatus>,
+    pub location: Option<ProductLocation>,
+    pub category: Option<ProductCategory>,
+    /// Keeps products carrying this tag, as normalized by
+    /// [`normalize_tags`](super::tags::normalize_tags)
+    pub tag: Option<String>,
+    /// Keeps products at this urgency, as `get_urgency_level` would rate them
+    /// with the `expiring_soon` window
+    pub urgency: Option<UrgencyLevel>,
+    pub sort: ProductSort,
+    pub page: Option<Page>,
+    /// Keyset position: only products older than this cursor
+    pub after: Option<Cursor>,
+    /// Window the urgency sort and filter treat as "use soon"
+    pub expiring_soon: ExpiringSoonWindow,
+}
+
+impl ProductQuery {
+    /// Every product of the user, newest first.
+    pub fn all(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            scope: ProductScope::All,
+            name_contains: None,
+            expiring_before: None,
+            expiring_between: None,
+            unwanted_only: false,
+            status: None,
+            location: None,
+            category: None,
+            tag: None,
+            urgency: None,
+            sort: ProductSort::default(),
+            page: None,
+            after: None,
+            expiring_soon: ExpiringSoonWindow::default(),
+        }
+    }
+
+    /// Products of the user that are not finished, newest first.
+    pub fn active(user_id: UserId) -> Self {
+        Self {
+            scope: ProductScope::Active,
+            ..Self::all(user_id)
+        }
+    }
+
+    /// Finished products of the user, newest first.
+    pub fn finished(user_id: UserId) -> Self {
+        Self {
+            scope: ProductScope::Finished,
+            ..Self::all(user_id)
+        }
+    }
+
+    /// Blank terms are ignored.
+    pub fn name_contains(mut self, term: &str) -> Self {
+        let term = term.trim();
+        self.name_contains = (!term.is_empty()).then(|| term.to_string());
+        self
+    }
+
+    pub fn expiring_before(mut self, date: DateTime<Utc>) -> Self {
+        self.expiring_before = Some(date);
+        self
+    }
+
+    pub fn expiring_between(mut self, from: DateTime<Utc>, until: DateTime<Utc>) -> Self {
+        self.expiring_between = Some((from, until));
+        self
+    }
+
+    pub fn unwanted_only(mut self) -> Self {
+        self.unwanted_only = true;
+        self
+    }
+
+    pub fn in_status(mut self, status: ProductStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn stored_in(mut self, location: ProductLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    pub fn in_category(mut self, category: ProductCategory) -> Self {
+        self.category = Some(category);
+        self
+    }
+
+    /// Matches tags the way they are stored; blank tags are ignored.
+    pub fn tagged(mut self, tag: &str) -> Self {
+        let tag = tag.trim().to_lowercase();
+        self.tag = (!tag.is_empty()).then_some(tag);
+        self
+    }
+
+    pub fn with_urgency(mut self, level: UrgencyLevel) -> Self {
+        self.urgency = Some(level);
+        self
+    }
+
+    pub fn sorted_by(mut self, sort: ProductSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn expiring_soon(mut self, window: ExpiringSoonWindow) -> Self {
+        self.expiring_soon = window;
+        self
+    }
+
+    pub fn paged(mut self, page: Page) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Keyset pagination over the newest-first order, which it enforces.
+    /// Fetches one row beyond the limit; see [`KeysetPage::fetch_limit`].
+    pub fn keyset(mut self, page: KeysetPage) -> Self {
+        self.sort = ProductSort::CreatedAtDesc;
+        self.page = Some(Page {
+            limit: page.fetch_limit(),
+            offset: 0,
+        });
+        self.after = page.after;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_ignore_blank_search_term() {
+        let query = ProductQuery::active(UserId::new("u")).name_contains("   ");
+        assert_eq!(query.name_contains, None);
+    }
+
+    #[test]
+    fn should_match_tags_as_stored() {
+        let query = ProductQuery::active(UserId::new("u")).tagged(" Desayuno ");
+        assert_eq!(query.tag.as_deref(), Some("desayuno"));
+    }
+
+    #[test]
+    fn should_clamp_page_limit() {
+        assert_eq!(Page::new(0, 0).limit, 1);
+        assert_eq!(Page::new(500, 20).limit, MAX_PAGE_LIMIT);
+    }
+}
@@ -0,0 +1,266 @@
+use super::services::{ReceiptItem, ReceiptScanResult};
+
+/// How one supermarket chain prints its receipts, used to clean up what a
+/// scanner read from them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceiptProfile {
+    /// Chain name reported to clients, e.g. "Mercadona"
+    pub store: String,
+    /// Words on the receipt header that identify the chain
+    pub markers: Vec<String>,
+    /// Shortened words the chain prints and what they stand for, e.g.
+    /// ("semi", "semidesnatada")
+    pub abbreviations: Vec<(String, String)>,
+    /// Words or phrases of lines that are not food (bags, cleaning,
+    /// cosmetics, own non-food brands), dropped from the result
+    pub non_food: Vec<String>,
+}
+
+impl ReceiptProfile {
+    fn matches(&self, header: &str) -> bool {
+        let header = header.to_lowercase();
+        self.markers
+            .iter()
+            .map(|marker| marker.trim().to_lowercase())
+            .any(|marker| !marker.is_empty() && header.contains(&marker))
+    }
+
+    /// Expands abbreviations word by word, keeping the receipt's casing.
+    fn expand(&self, name: &str) -> String {
+        name.split_whitespace()
+            .map(|word| {
+                let bare = word.trim_end_matches('.');
+                let expansion = self
+                    .abbreviations
+                    .iter()
+                    .find(|(short, _)| short.eq_ignore_ascii_case(bare));
+                match expansion {
+                    Some((_, long)) if !bare.chars().any(char::is_lowercase) => long.to_uppercase(),
+                    Some((_, long)) => long.clone(),
+                    None => word.to_string(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn is_non_food(&self, name: &str) -> bool {
+        let words = format!(" {} ", words_of(name));
+        self.non_food
+            .iter()
+            .map(|term| words_of(term))
+            .any(|term| !term.is_empty() && words.contains(&format!(" {term} ")))
+    }
+
+    /// Expands abbreviations and drops non-food lines.
+    pub fn apply(&self, items: Vec<ReceiptItem>) -> Vec<ReceiptItem> {
+        items
+            .into_iter()
+            .map(|item| ReceiptItem {
+                name: self.expand(&item.name),
+                ..item
+            })
+            .filter(|item| !self.is_non_food(&item.name))
+            .collect()
+    }
+}
+
+/// Lowercase words separated by single spaces, without punctuation.
+fn words_of(text: &str) -> String {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The parsing profiles a deployment knows about, checked in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReceiptProfiles(pub Vec<ReceiptProfile>);
+
+impl ReceiptProfiles {
+    /// Profile of the chain named in `header`, the store name a scanner read
+    /// off the receipt.
+    pub fn detect(&self, header: &str) -> Option<&ReceiptProfile> {
+        self.0.iter().find(|profile| profile.matches(header))
+    }
+
+    /// Cleans up a scan with the profile of the store it comes from.
+    /// `store` is replaced by the chain name, or cleared when no profile
+    /// matches, so clients only see stores that were recognized.
+    pub fn apply(&self, result: ReceiptScanResult) -> ReceiptScanResult {
+        let profile = result
+            .store
+            .as_deref()
+            .and_then(|header| self.detect(header));
+        match profile {
+            Some(profile) => ReceiptScanResult {
+                items: profile.apply(result.items),
+                store: Some(profile.store.clone()),
+            },
+            None => ReceiptScanResult {
+                items: result.items,
+                store: None,
+            },
+        }
+    }
+}
+
+fn profile(
+    store: &str,
+    markers: &[&str],
+    abbreviations: &[(&str, &str)],
+    non_food: &[&str],
+) -> ReceiptProfile {
+    ReceiptProfile {
+        store: store.to_string(),
+        markers: markers.iter().map(|m| m.to_string()).collect(),
+        abbreviations: abbreviations
+            .iter()
+            .map(|(short, long)| (short.to_string(), long.to_string()))
+            .collect(),
+        non_food: non_food.iter().map(|t| t.to_string()).collect(),
+    }
+}
+
+/// Lines every chain sells that are not food.
+const COMMON_NON_FOOD: [&str; 12] = [
+    "bolsa",
+    "bolsas",
+    "detergente",
+    "suavizante",
+    "lejia",
+    "friegasuelos",
+    "lavavajillas",
+    "papel higienico",
+    "servilletas",
+    "champu",
+    "gel",
+    "desodorante",
+];
+
+impl Default for ReceiptProfiles {
+    /// Mercadona, Lidl and Carrefour, the chains most receipts come from.
+    fn default() -> Self {
+        let with_common = |own: &[&'static str]| -> Vec<&'static str> {
+            COMMON_NON_FOOD.iter().chain(own).copied().collect()
+        };
+        Self(vec![
+            profile(
+                "Mercadona",
+                &["mercadona"],
+                &[
+                    ("semi", "semidesnatada"),
+                    ("desn", "desnatada"),
+                    ("ent", "entera"),
+                    ("yog", "yogur"),
+                    ("qso", "queso"),
+                    ("pech", "pechuga"),
+                    ("tom", "tomate"),
+                    ("hacend", "Hacendado"),
+                ],
+                &with_common(&["bosque verde", "deliplus", "compy"]),
+            ),
+            profile(
+                "Lidl",
+                &["lidl"],
+                &[
+                    ("yog", "yogur"),
+                    ("choc", "chocolate"),
+                    ("nat", "natural"),
+                    ("sem", "semidesnatada"),
+                    ("prot", "proteinas"),
+                ],
+                &with_common(&["w5", "cien", "silvercrest", "parkside", "livarno", "esmara"]),
+            ),
+            profile(
+                "Carrefour",
+                &["carrefour"],
+                &[
+                    ("crf", "Carrefour"),
+                    ("semid", "semidesnatada"),
+                    ("nar", "naranja"),
+                    ("eco", "ecologico"),
+                ],
+                &with_common(&[
+                    "recarga",
+                    "carburante",
+                    "gasolina",
+                    "gasoleo",
+                    "tarjeta regalo",
+                ]),
+            ),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::services::IdentificationConfidence;
+
+    fn scan(store: Option<&str>, names: &[&str]) -> ReceiptScanResult {
+        ReceiptScanResult {
+            items: names
+                .iter()
+                .map(|name| ReceiptItem {
+                    name: name.to_string(),
+                    confidence: IdentificationConfidence::Low,
+                })
+                .collect(),
+            store: store.map(str::to_string),
+        }
+    }
+
+    fn names(result: &ReceiptScanResult) -> Vec<&str> {
+        result.items.iter().map(|i| i.name.as_str()).collect()
+    }
+
+    #[test]
+    fn should_detect_store_from_receipt_header() {
+        let profiles = ReceiptProfiles::default();
+
+        assert_eq!(
+            profiles
+                .detect("MERCADONA, S.A. A-46103834")
+                .map(|p| p.store.as_str()),
+            Some("Mercadona")
+        );
+        assert_eq!(
+            profiles
+                .detect("Lidl Supermercados S.A.U.")
+                .map(|p| p.store.as_str()),
+            Some("Lidl")
+        );
+        assert!(profiles.detect("Fruteria Paqui").is_none());
+    }
+
+    #[test]
+    fn should_expand_abbreviations_and_drop_non_food() {
+        let result = ReceiptProfiles::default().apply(scan(
+            Some("MERCADONA S.A."),
+            &[
+                "LECHE SEMI",
+                "YOG. GRIEGO",
+                "BOLSA PLASTICO",
+                "Gel de ducha",
+                "Tomate rama",
+            ],
+        ));
+
+        assert_eq!(result.store.as_deref(), Some("Mercadona"));
+        assert_eq!(
+            names(&result),
+            vec!["LECHE SEMIDESNATADA", "YOGUR GRIEGO", "Tomate rama"]
+        );
+    }
+
+    #[test]
+    fn should_keep_items_untouched_when_store_unknown() {
+        let result =
+            ReceiptProfiles::default().apply(scan(Some("Fruteria Paqui"), &["BOLSA", "TOM"]));
+
+        assert!(result.store.is_none());
+        assert_eq!(names(&result), vec!["BOLSA", "TOM"]);
+    }
+}
@@ -0,0 +1,194 @@
+use super::services::{IdentificationConfidence, ReceiptItem};
+
+/// Words that mark a receipt line as a total, tax, payment or header rather
+/// than a product, in Spanish and English.
+const NON_PRODUCT_WORDS: [&str; 24] = [
+    "total",
+    "subtotal",
+    "iva",
+    "vat",
+    "tax",
+    "base",
+    "imponible",
+    "cambio",
+    "change",
+    "entregado",
+    "efectivo",
+    "cash",
+    "tarjeta",
+    "card",
+    "visa",
+    "mastercard",
+    "factura",
+    "ticket",
+    "invoice",
+    "nif",
+    "cif",
+    "tel",
+    "descuento",
+    "discount",
+];
+
+/// Lines of the header kept to tell which store printed the receipt.
+const HEADER_LINES: usize = 4;
+
+/// Reads product lines from the raw text of a receipt, as OCR returns it.
+/// Used when the vision model is unavailable, so every item comes back with
+/// low confidence.
+///
+/// Business rules:
+/// - A product line ends in a price ("1,25", "2.50 €"); lines without one
+///   are headers, addresses or wrapped text
+/// - Prices, weights ("0,456 kg"), unit prices ("1,99 €/kg") and tax codes
+///   are dropped, leaving the name
+/// - Totals, taxes, payments and discounts are not products
+/// - Names need at least three letters, so OCR noise is skipped
+pub fn parse_receipt_text(text: &str) -> Vec<ReceiptItem> {
+    text.lines()
+        .filter_map(product_name)
+        .map(|name| ReceiptItem {
+            name,
+            confidence: IdentificationConfidence::Low,
+        })
+        .collect()
+}
+
+/// Lines printed above the first product, where receipts carry the store
+/// name, company and address. `None` when the receipt starts with products.
+pub fn receipt_header(text: &str) -> Option<String> {
+    let header: Vec<&str> = text
+        .lines()
+        .map(str::trim)
+        .take_while(|line| product_name(line).is_none())
+        .filter(|line| !line.is_empty())
+        .take(HEADER_LINES)
+        .collect();
+    (!header.is_empty()).then(|| header.join(" "))
+}
+
+fn product_name(line: &str) -> Option<String> {
+    let mut words: Vec<&str> = line.split_whitespace().collect();
+
+    let mut priced = false;
+    while let Some(last) = words.last() {
+        if is_price(last) {
+            if last.starts_with('-') {
+                return None;
+            }
+            priced = true;
+        } else if !is_price_decoration(last) {
+            break;
+        }
+        words.pop();
+    }
+    if !priced {
+        return None;
+    }
+
+    // Weighed items print the weight and unit price before the total
+    words.retain(|word| !is_weight(word) && !is_price(word) && !word.contains('/'));
+    if words.len() > 1 && words[0].len() <= 2 && words[0].chars().all(|c| c.is_ascii_digit()) {
+        words.remove(0);
+    }
+    if words.iter().any(|word| {
+        let word = word.trim_matches(|c: char| !c.is_alphanumeric());
+        NON_PRODUCT_WORDS
+            .iter()
+            .any(|keyword| word.eq_ignore_ascii_case(keyword))
+    }) {
+        return None;
+    }
+
+    let name = words.join(" ");
+    let letters = name.chars().filter(|c| c.is_alphabetic()).count();
+    (letters >= 3).then_some(name)
+}
+
+/// "1,25", "2.50", "-0,30", "3,10€"
+fn is_price(word: &str) -> bool {
+    let amount = word.trim_end_matches(['€', '$']);
+    let amount = amount.strip_prefix('-').unwrap_or(amount);
+    has_decimals(amount, 2)
+}
+
+/// Currency signs, unit price suffixes and one-letter tax codes printed
+/// after prices.
+fn is_price_decoration(word: &str) -> bool {
+    matches!(
+        word.to_lowercase().as_str(),
+        "€" | "eur" | "$" | "a" | "b" | "c"
+    ) || word.contains('/')
+}
+
+/// "1,234", "1,234kg", "kg", "500g"
+fn is_weight(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    if lower == "kg" || lower == "g" {
+        return true;
+    }
+    match lower.strip_suffix("kg").or_else(|| lower.strip_suffix('g')) {
+        Some(amount) => {
+            !amount.is_empty()
+                && amount
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || c == ',' || c == '.')
+        }
+        // Weights have three decimals, prices two
+        None => has_decimals(&lower, 3),
+    }
+}
+
+fn has_decimals(amount: &str, decimals: usize) -> bool {
+    let Some((units, fraction)) = amount.rsplit_once([',', '.']) else {
+        return false;
+    };
+    !units.is_empty()
+        && units.chars().all(|c| c.is_ascii_digit())
+        && fraction.len() == decimals
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(text: &str) -> Vec<String> {
+        parse_receipt_text(text)
+            .into_iter()
+            .map(|item| item.name)
+            .collect()
+    }
+
+    #[test]
+    fn should_read_product_lines_and_drop_prices() {
+        let text = "MERCADONA S.A.\nC/ Mayor 1, Madrid\n1 LECHE ENTERA 0,89\n2 HUEVOS L 2,40 4,80\nPLATANO 1,234 kg 1,99 €/kg 2,46\nTOTAL (€) 8,15\nTARJETA BANCARIA 8,15\n";
+
+        assert_eq!(names(text), vec!["LECHE ENTERA", "HUEVOS L", "PLATANO"]);
+    }
+
+    #[test]
+    fn should_skip_discounts_and_noise() {
+        let text = "Bread 2.50 A\nDescuento -0,30\nX1 0,50\nIVA 21% 1,05\n";
+
+        assert_eq!(names(text), vec!["Bread"]);
+    }
+
+    #[test]
+    fn should_read_store_from_lines_above_products() {
+        let text = "\nMERCADONA S.A.\nC/ Mayor 1, Madrid\n1 LECHE ENTERA 0,89\nTOTAL 0,89\n";
+
+        assert_eq!(
+            receipt_header(text).as_deref(),
+            Some("MERCADONA S.A. C/ Mayor 1, Madrid")
+        );
+        assert!(receipt_header("LECHE 0,89\n").is_none());
+    }
+
+    #[test]
+    fn should_mark_every_item_as_low_confidence() {
+        let items = parse_receipt_text("Manzanas 1,20\n");
+
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0].confidence, IdentificationConfidence::Low));
+    }
+}
@@ -1,16 +1,127 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use uuid::Uuid;
 
 use crate::domain::errors::RepositoryError;
 use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
 
+use super::enrichment::ProductEnrichment;
+use super::facets::ProductHistorySummary;
 use super::model::Product;
+use super::query::ProductQuery;
 
 #[async_trait]
 pub trait ProductRepository: Send + Sync {
-    async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+    /// Lists the products matching the query, in its sort order.
+    async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
     async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
-    async fn save(&self, product: &Product) -> Result<(), RepositoryError>;
+    /// Whether the user has a product with this id, without loading it.
+    async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+    /// Counts the products matching the query's filters; sort and paging are
+    /// ignored.
+    async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+    /// Fails with `Duplicated` if a product with the same id already exists.
+    async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+    /// Inserts the product unless the user added an active product with the
+    /// same name (case and surrounding spaces ignored) within `window` before
+    /// it, in which case that product is returned and nothing is written.
+    /// Concurrent calls for the same user and name are serialized, so only
+    /// one of them inserts.
+    async fn insert_unless_duplicate(
+        &self,
+        product: &Product,
+        window: chrono::Duration,
+    ) -> Result<Option<Product>, RepositoryError>;
+    /// Fails with `NotFound` if the user has no product with this id.
+    async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
     async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
-    async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError>;
+    /// Returns up to `limit` of the distinct users that currently own at
+    /// least one active product, in id order and after `after` when given, so
+    /// batch jobs can walk every user a page at a time.
+    async fn get_users_with_active_products(
+        &self,
+        after: Option<UserId>,
+        limit: usize,
+    ) -> Result<Vec<UserId>, RepositoryError>;
+}
+
+/// Writes several products at once.
+#[async_trait]
+pub trait ProductBatchRepository: Send + Sync {
+    /// Inserts all the products in one transaction, so either all of them are
+    /// saved or none is. Fails with `Duplicated` if any id already exists.
+    async fn insert_all(&self, products: &[Product]) -> Result<(), RepositoryError>;
+}
+
+/// Flags products the user won't use, so they can be given away in time.
+#[async_trait]
+pub trait UnwantedFlagRepository: Send + Sync {
+    /// Fails with `NotFound` if the user has no product with this id.
+    async fn set_unwanted(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+        unwanted: bool,
+    ) -> Result<(), RepositoryError>;
+}
+
+/// Open Food Facts data cached by barcode, and the products scanned with it.
+#[async_trait]
+pub trait ProductEnrichmentRepository: Send + Sync {
+    /// Replaces the data kept for the enrichment's barcode.
+    async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+    /// Records the barcode a product was scanned with. Fails with `NotFound`
+    /// if the user has no product with this id.
+    async fn link(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+        barcode: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Enrichment of those of the user's products that have one.
+    async fn find_for_products(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+}
+
+/// Thumbnails made from product photos, so lists can link them without
+/// asking the object store about every product.
+#[async_trait]
+pub trait ProductImageRepository: Send + Sync {
+    /// Records the thumbnail key of a product, replacing any previous one.
+    async fn save_thumbnail(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+        thumbnail_key: &str,
+    ) -> Result<(), RepositoryError>;
+    /// Thumbnail keys of those of the user's products that have one.
+    async fn find_thumbnails(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, String>, RepositoryError>;
+}
+
+/// Facets of products that live outside the product row, each fetched for a
+/// whole batch in one query.
+#[async_trait]
+pub trait ProductFacetRepository: Send + Sync {
+    /// Unbought shopping items of those of the user's products that have one.
+    async fn find_shopping_items(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ShoppingItem>, RepositoryError>;
+    /// History of those of the user's products named like an earlier finished
+    /// product; the product itself never counts.
+    async fn summarize_history(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ProductHistorySummary>, RepositoryError>;
 }
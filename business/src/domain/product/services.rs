@@ -1,48 +1,32 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
 
+use super::enrichment::ProductEnrichment;
 use super::errors::ProductError;
-use super::value_objects::ProductLocation;
+use super::value_objects::{ExpiryType, ProductCategory, ProductLocation};
 
-/// Confidence level for AI-based estimations and identifications.
-#[derive(Debug, Clone, PartialEq)]
-pub enum Confidence {
-    High,
-    Medium,
-    Low,
-    None,
-}
-
-impl std::fmt::Display for Confidence {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Confidence::High => write!(f, "high"),
-            Confidence::Medium => write!(f, "medium"),
-            Confidence::Low => write!(f, "low"),
-            Confidence::None => write!(f, "none"),
-        }
-    }
-}
-
-impl std::str::FromStr for Confidence {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "high" => Ok(Confidence::High),
-            "medium" => Ok(Confidence::Medium),
-            "low" => Ok(Confidence::Low),
-            "none" => Ok(Confidence::None),
-            _ => Err(format!("Invalid confidence level: {}", s)),
-        }
-    }
-}
+pub use shared_kernel::confidence::{Confidence, IdentificationConfidence};
 
 /// Result of an expiry date estimation.
 #[derive(Debug, Clone)]
 pub struct ExpiryEstimation {
     pub date: Option<DateTime<Utc>>,
     pub confidence: Confidence,
+    /// The estimator could not be reached, so the missing date says nothing
+    /// about the product and asking again later may give one.
+    pub unavailable: bool,
+}
+
+impl ExpiryEstimation {
+    /// No estimate because the estimator is down.
+    pub fn unavailable() -> Self {
+        Self {
+            date: None,
+            confidence: Confidence::None,
+            unavailable: true,
+        }
+    }
 }
 
 /// Service port for estimating product expiry dates.
@@ -59,39 +43,13 @@ pub trait ExpiryEstimatorService: Send + Sync {
     ) -> ExpiryEstimation;
 }
 
-/// Confidence level for product identification (high or low).
-#[derive(Debug, Clone, PartialEq)]
-pub enum IdentificationConfidence {
-    High,
-    Low,
-}
-
-impl std::fmt::Display for IdentificationConfidence {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            IdentificationConfidence::High => write!(f, "high"),
-            IdentificationConfidence::Low => write!(f, "low"),
-        }
-    }
-}
-
-impl std::str::FromStr for IdentificationConfidence {
-    type Err = String;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "high" => Ok(IdentificationConfidence::High),
-            "low" => Ok(IdentificationConfidence::Low),
-            _ => Err(format!("Invalid identification confidence: {}", s)),
-        }
-    }
-}
-
 /// Method used to identify a product.
 #[derive(Debug, Clone, PartialEq)]
 pub enum IdentificationMethod {
     Barcode,
     Visual,
+    /// A barcode the catalog doesn't know, named by the user.
+    Contribution,
 }
 
 impl std::fmt::Display for IdentificationMethod {
@@ -99,6 +57,7 @@ impl std::fmt::Display for IdentificationMethod {
         match self {
             IdentificationMethod::Barcode => write!(f, "barcode"),
             IdentificationMethod::Visual => write!(f, "visual"),
+            IdentificationMethod::Contribution => write!(f, "contribution"),
         }
     }
 }
@@ -111,6 +70,14 @@ pub struct ProductIdentification {
     pub method: IdentificationMethod,
     pub suggested_location: Option<ProductLocation>,
     pub suggested_quantity: Option<String>,
+    /// Kind of date printed on the label, when it could be read.
+    pub suggested_expiry_type: Option<ExpiryType>,
+    pub suggested_category: Option<ProductCategory>,
+    /// Nutrition and eco data; only for barcode lookups that have some.
+    pub enrichment: Option<ProductEnrichment>,
+    /// Set for photo identifications made under an experiment, so the
+    /// client can report whether the user kept the result.
+    pub ai_call_id: Option<Uuid>,
 }
 
 /// Service port for identifying products by image or barcode.
@@ -125,6 +92,13 @@ pub trait ProductIdentifierService: Send + Sync {
         &self,
         barcode: &str,
     ) -> Result<ProductIdentification, ProductError>;
+
+    /// Identifies every product visible in a photo with several items, such as
+    /// a fridge shelf. Returns an empty list when nothing can be recognized.
+    async fn identify_all_by_image(
+        &self,
+        image_base64: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError>;
 }
 
 /// A single item extracted from a receipt.
@@ -138,10 +112,36 @@ pub struct ReceiptItem {
 #[derive(Debug, Clone)]
 pub struct ReceiptScanResult {
     pub items: Vec<ReceiptItem>,
+    /// Store the receipt comes from, as the scanner read it off the header
+    pub store: Option<String>,
 }
 
 /// Service port for extracting products from receipt images.
 #[async_trait]
 pub trait ReceiptScannerService: Send + Sync {
     async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError>;
+
+    /// Whether `scan_without_ai` can read receipts, so callers can keep
+    /// going when the user's AI allowance is used up.
+    fn can_scan_without_ai(&self) -> bool {
+        false
+    }
+
+    /// Reads the receipt without calling an AI model. Scanners without a
+    /// local fallback fail.
+    async fn scan_without_ai(
+        &self,
+        _image_base64: &str,
+    ) -> Result<ReceiptScanResult, ProductError> {
+        Err(ProductError::scan_failed(
+            "no scanner without AI configured",
+        ))
+    }
+}
+
+/// Service port for reading the printed text of an image locally (OCR),
+/// without an AI model.
+#[async_trait]
+pub trait TextRecognitionService: Send + Sync {
+    async fn recognize(&self, image_base64: &str) -> Result<String, ProductError>;
 }
@@ -0,0 +1,424 @@
+use chrono::{DateTime, Duration, Utc};
+
+use super::duplicates::fold_accent;
+use super::services::{Confidence, ExpiryEstimation};
+use super::value_objects::{ProductLocation, ProductStatus};
+
+/// Broad food category with typical shelf lives, used to estimate expiry
+/// dates without the AI estimator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoodCategory {
+    Canned,
+    Condiments,
+    Drinks,
+    Deli,
+    Meat,
+    Fish,
+    Milk,
+    Dairy,
+    Eggs,
+    Bread,
+    Fruit,
+    Vegetables,
+    DryGoods,
+}
+
+/// Days a category keeps in each storage place. `opened` applies to opened
+/// products outside the freezer.
+struct ShelfLife {
+    category: FoodCategory,
+    keywords: &'static [&'static str],
+    pantry: i64,
+    fridge: i64,
+    freezer: i64,
+    opened: i64,
+}
+
+/// Knowledge base of default shelf lives, checked in order so "atún en lata"
+/// is canned rather than fish and "zumo de naranja" a drink rather than fruit.
+/// Keywords are lowercase and unaccented, in Spanish and English.
+static KNOWLEDGE_BASE: [ShelfLife; 13] = [
+    ShelfLife {
+        category: FoodCategory::Canned,
+        keywords: &["lata", "conserva", "canned", "tinned"],
+        pantry: 730,
+        fridge: 730,
+        freezer: 730,
+        opened: 3,
+    },
+    ShelfLife {
+        category: FoodCategory::Condiments,
+        keywords: &[
+            "salsa",
+            "ketchup",
+            "mayonesa",
+            "mostaza",
+            "aceite",
+            "vinagre",
+            "sal",
+            "especia",
+            "sauce",
+            "mayonnaise",
+            "mustard",
+            "oil",
+            "vinegar",
+            "salt",
+            "spice",
+        ],
+        pantry: 365,
+        fridge: 365,
+        freezer: 365,
+        opened: 60,
+    },
+    ShelfLife {
+        category: FoodCategory::Drinks,
+        keywords: &[
+            "zumo", "refresco", "agua", "cerveza", "vino", "juice", "soda", "water", "beer", "wine",
+        ],
+        pantry: 180,
+        fridge: 180,
+        freezer: 180,
+        opened: 5,
+    },
+    ShelfLife {
+        category: FoodCategory::Deli,
+        keywords: &[
+            "jamon",
+            "chorizo",
+            "salchichon",
+            "fuet",
+            "lomo",
+            "embutido",
+            "salami",
+            "ham",
+        ],
+        pantry: 30,
+        fridge: 30,
+        freezer: 180,
+        opened: 5,
+    },
+    ShelfLife {
+        category: FoodCategory::Meat,
+        keywords: &[
+            "pollo",
+            "pechuga",
+            "ternera",
+            "cerdo",
+            "carne",
+            "pavo",
+            "cordero",
+            "filete",
+            "hamburguesa",
+            "salchicha",
+            "panceta",
+            "picada",
+            "chicken",
+            "beef",
+            "pork",
+            "meat",
+            "turkey",
+            "lamb",
+            "steak",
+            "mince",
+            "sausage",
+            "bacon",
+        ],
+        pantry: 1,
+        fridge: 2,
+        freezer: 180,
+        opened: 1,
+    },
+    ShelfLife {
+        category: FoodCategory::Fish,
+        keywords: &[
+            "pescado",
+            "salmon",
+            "merluza",
+            "bacalao",
+            "atun",
+            "sardina",
+            "gamba",
+            "langostino",
+            "marisco",
+            "fish",
+            "tuna",
+            "cod",
+            "prawn",
+            "shrimp",
+        ],
+        pantry: 1,
+        fridge: 2,
+        freezer: 180,
+        opened: 1,
+    },
+    // Milk sold in Spain is mostly UHT and kept in the pantry until opened
+    ShelfLife {
+        category: FoodCategory::Milk,
+        keywords: &["leche", "milk"],
+        pantry: 90,
+        fridge: 7,
+        freezer: 90,
+        opened: 4,
+    },
+    ShelfLife {
+        category: FoodCategory::Dairy,
+        keywords: &[
+            "yogur",
+            "queso",
+            "nata",
+            "mantequilla",
+            "kefir",
+            "yogurt",
+            "yoghurt",
+            "cheese",
+            "cream",
+            "butter",
+        ],
+        pantry: 1,
+        fridge: 14,
+        freezer: 60,
+        opened: 5,
+    },
+    ShelfLife {
+        category: FoodCategory::Eggs,
+        keywords: &["huevo", "egg"],
+        pantry: 21,
+        fridge: 28,
+        freezer: 120,
+        opened: 2,
+    },
+    ShelfLife {
+        category: FoodCategory::Bread,
+        keywords: &["pan", "baguette", "barra", "bollo", "bread"],
+        pantry: 3,
+        fridge: 5,
+        freezer: 90,
+        opened: 3,
+    },
+    ShelfLife {
+        category: FoodCategory::Fruit,
+        keywords: &[
+            "fruta",
+            "manzana",
+            "platano",
+            "naranja",
+            "fresa",
+            "pera",
+            "uva",
+            "melon",
+            "sandia",
+            "kiwi",
+            "limon",
+            "melocoton",
+            "fruit",
+            "apple",
+            "banana",
+            "orange",
+            "strawberry",
+            "pear",
+            "grape",
+            "lemon",
+        ],
+        pantry: 5,
+        fridge: 10,
+        freezer: 180,
+        opened: 3,
+    },
+    ShelfLife {
+        category: FoodCategory::Vegetables,
+        keywords: &[
+            "verdura",
+            "lechuga",
+            "tomate",
+            "zanahoria",
+            "cebolla",
+            "patata",
+            "pimiento",
+            "calabacin",
+            "berenjena",
+            "pepino",
+            "espinaca",
+            "brocoli",
+            "ajo",
+            "vegetable",
+            "lettuce",
+            "tomato",
+            "carrot",
+            "onion",
+            "potato",
+            "pepper",
+            "spinach",
+            "broccoli",
+            "garlic",
+        ],
+        pantry: 5,
+        fridge: 7,
+        freezer: 240,
+        opened: 3,
+    },
+    ShelfLife {
+        category: FoodCategory::DryGoods,
+        keywords: &[
+            "arroz",
+            "pasta",
+            "macarron",
+            "espagueti",
+            "lenteja",
+            "garbanzo",
+            "alubia",
+            "harina",
+            "azucar",
+            "cereal",
+            "galleta",
+            "avena",
+            "cafe",
+            "rice",
+            "spaghetti",
+            "flour",
+            "sugar",
+            "lentil",
+            "chickpea",
+            "bean",
+            "oat",
+            "biscuit",
+            "cookie",
+            "coffee",
+        ],
+        pantry: 365,
+        fridge: 365,
+        freezer: 365,
+        opened: 90,
+    },
+];
+
+fn find_shelf_life(product_name: &str) -> Option<&'static ShelfLife> {
+    let words = words(product_name);
+    KNOWLEDGE_BASE.iter().find(|entry| {
+        entry
+            .keywords
+            .iter()
+            .any(|keyword| words.iter().any(|word| is_form_of(word, keyword)))
+    })
+}
+
+/// Category of a product name: the first knowledge base entry with a keyword
+/// matching one of its words, in singular or plural form.
+pub fn category_for(product_name: &str) -> Option<FoodCategory> {
+    find_shelf_life(product_name).map(|entry| entry.category)
+}
+
+/// Days a product is expected to keep from now according to its category.
+/// `None` for products in no category and for finished ones. Products with
+/// no location are assumed to be at room temperature.
+pub fn default_shelf_life_days(
+    product_name: &str,
+    status: &ProductStatus,
+    location: Option<&ProductLocation>,
+) -> Option<i64> {
+    if *status == ProductStatus::Finished {
+        return None;
+    }
+    let entry = find_shelf_life(product_name)?;
+    let days = match (location, status) {
+        (Some(ProductLocation::Freezer), _) => entry.freezer,
+        (_, ProductStatus::Opened | ProductStatus::AlmostEmpty) => entry.opened,
+        (Some(ProductLocation::Fridge), _) => entry.fridge,
+        _ => entry.pantry,
+    };
+    Some(days)
+}
+
+/// Expiry estimate from the category defaults, with `Low` confidence when the
+/// product falls in a category and none otherwise.
+pub fn estimate_from_category(
+    product_name: &str,
+    status: &ProductStatus,
+    location: Option<&ProductLocation>,
+    now: DateTime<Utc>,
+) -> ExpiryEstimation {
+    match default_shelf_life_days(product_name, status, location) {
+        Some(days) => ExpiryEstimation {
+            date: Some(now + Duration::days(days)),
+            confidence: Confidence::Low,
+            unavailable: false,
+        },
+        None => ExpiryEstimation {
+            date: None,
+            confidence: Confidence::None,
+            unavailable: false,
+        },
+    }
+}
+
+fn words(text: &str) -> Vec<String> {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .map(fold_accent)
+        .collect::<String>()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_form_of(word: &str, keyword: &str) -> bool {
+    match word.strip_prefix(keyword) {
+        Some(suffix) => matches!(suffix, "" | "s" | "es"),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_categorize_plural_and_accented_names_by_first_matching_entry() {
+        assert_eq!(
+            category_for("Plátanos de Canarias"),
+            Some(FoodCategory::Fruit)
+        );
+        assert_eq!(category_for("Atún en lata"), Some(FoodCategory::Canned));
+        assert_eq!(category_for("Zumo de naranja"), Some(FoodCategory::Drinks));
+        assert_eq!(category_for("Yogures naturales"), Some(FoodCategory::Dairy));
+        assert_eq!(category_for("Panceta"), Some(FoodCategory::Meat));
+        assert_eq!(category_for("Pantalla"), None);
+    }
+
+    #[test]
+    fn should_pick_shelf_life_by_location_and_status() {
+        let fridge = Some(&ProductLocation::Fridge);
+        let freezer = Some(&ProductLocation::Freezer);
+
+        assert_eq!(
+            default_shelf_life_days("Leche entera", &ProductStatus::New, None),
+            Some(90)
+        );
+        assert_eq!(
+            default_shelf_life_days("Leche entera", &ProductStatus::Opened, fridge),
+            Some(4)
+        );
+        assert_eq!(
+            default_shelf_life_days("Pechuga de pollo", &ProductStatus::Opened, freezer),
+            Some(180)
+        );
+        assert_eq!(
+            default_shelf_life_days("Leche entera", &ProductStatus::Finished, fridge),
+            None
+        );
+    }
+
+    #[test]
+    fn should_estimate_with_low_confidence_only_for_known_categories() {
+        let now = Utc::now();
+        let fridge = Some(&ProductLocation::Fridge);
+
+        let known = estimate_from_category("Pechuga de pollo", &ProductStatus::New, fridge, now);
+        assert_eq!(known.date, Some(now + Duration::days(2)));
+        assert_eq!(known.confidence, Confidence::Low);
+
+        let unknown = estimate_from_category("Cosas varias", &ProductStatus::New, fridge, now);
+        assert!(unknown.date.is_none());
+        assert_eq!(unknown.confidence, Confidence::None);
+    }
+}
@@ -0,0 +1,61 @@
+use super::errors::ProductError;
+
+/// Most tags a product can carry.
+pub const MAX_TAGS: usize = 10;
+
+/// Longest tag, in characters.
+pub const MAX_TAG_LENGTH: usize = 30;
+
+/// Cleans up free-form tags the way they are stored and filtered: trimmed,
+/// lowercased, without blanks or repeats, in the order given.
+pub fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>, ProductError> {
+    let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tag.trim().to_lowercase();
+        if tag.is_empty() || normalized.contains(&tag) {
+            continue;
+        }
+        if tag.chars().count() > MAX_TAG_LENGTH {
+            return Err(ProductError::TagTooLong);
+        }
+        normalized.push(tag);
+    }
+
+    if normalized.len() > MAX_TAGS {
+        return Err(ProductError::TooManyTags);
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn should_trim_lowercase_and_drop_blank_or_repeated_tags() {
+        let normalized = normalize_tags(tags(&[" Desayuno", "", "sin gluten ", "DESAYUNO"]));
+
+        assert_eq!(normalized.unwrap(), tags(&["desayuno", "sin gluten"]));
+    }
+
+    #[test]
+    fn should_reject_tags_longer_than_the_limit() {
+        let result = normalize_tags(vec!["a".repeat(MAX_TAG_LENGTH + 1)]);
+
+        assert!(matches!(result, Err(ProductError::TagTooLong)));
+    }
+
+    #[test]
+    fn should_reject_more_distinct_tags_than_the_limit() {
+        let many: Vec<String> = (0..=MAX_TAGS).map(|i| format!("tag{i}")).collect();
+
+        assert!(matches!(
+            normalize_tags(many),
+            Err(ProductError::TooManyTags)
+        ));
+    }
+}
@@ -1,6 +1,9 @@
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 
+use super::local_day;
 use super::model::Product;
+use super::services::Confidence;
+use super::value_objects::ExpiryType;
 
 /// Urgency levels for product expiry.
 #[derive(Debug, Clone, PartialEq)]
@@ -11,6 +14,9 @@ pub enum UrgencyLevel {
     UseSoon,
     /// Product expires today.
     UseToday,
+    /// Product is past its best-before date: likely still safe, but losing
+    /// quality.
+    QualityDeclining,
     /// Product has expired.
     WouldntTrust,
 }
@@ -21,41 +27,104 @@ impl std::fmt::Display for UrgencyLevel {
             UrgencyLevel::Ok => write!(f, "ok"),
             UrgencyLevel::UseSoon => write!(f, "use_soon"),
             UrgencyLevel::UseToday => write!(f, "use_today"),
+            UrgencyLevel::QualityDeclining => write!(f, "quality_declining"),
             UrgencyLevel::WouldntTrust => write!(f, "wouldnt_trust"),
         }
     }
 }
 
-const EXPIRING_SOON_DAYS: i64 = 2;
+impl UrgencyLevel {
+    /// Position in a most-urgent-first list: products to use today, then
+    /// soon, then the ones past their best-before date, then fresh ones.
+    /// Expired products go last, as they are no longer something to cook with.
+    pub fn rank(&self) -> u8 {
+        match self {
+            UrgencyLevel::UseToday => 0,
+            UrgencyLevel::UseSoon => 1,
+            UrgencyLevel::QualityDeclining => 2,
+            UrgencyLevel::Ok => 3,
+            UrgencyLevel::WouldntTrust => 4,
+        }
+    }
+
+    /// The product still needs using today or in the next few days.
+    pub fn is_urgent(&self) -> bool {
+        matches!(self, UrgencyLevel::UseToday | UrgencyLevel::UseSoon)
+    }
+}
+
+/// How many days after today a product still counts as "expiring soon".
+/// A per-user preference, with a server-wide default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExpiringSoonWindow(u8);
+
+impl ExpiringSoonWindow {
+    pub const DEFAULT_DAYS: u8 = 2;
+    pub const MAX_DAYS: u8 = 14;
+
+    /// Returns `None` unless `days` is within `1..=MAX_DAYS`.
+    pub fn new(days: u8) -> Option<Self> {
+        (1..=Self::MAX_DAYS).contains(&days).then_some(Self(days))
+    }
+
+    pub fn days(&self) -> u8 {
+        self.0
+    }
+}
+
+impl Default for ExpiringSoonWindow {
+    fn default() -> Self {
+        Self(Self::DEFAULT_DAYS)
+    }
+}
 
 /// Calculates the number of days until a product expires.
 ///
 /// Returns `None` if the product has no expiry date.
 /// Returns 0 for products expiring today, negative for expired products.
+/// Days are those of the [local day](local_day) expiry dates are snapped to.
 pub fn days_until_expiry(product: &Product) -> Option<i64> {
     let date = product.expiry_date.or(product.estimated_expiry_date)?;
 
-    let today = Utc::now().date_naive();
-    let expiry_day = date.date_naive();
+    let today = local_day::date_of(Utc::now());
+    let expiry_day = local_day::date_of(date);
 
     Some((expiry_day - today).num_days())
 }
 
+/// End (exclusive) of the expiry window in which a product is `UseToday`:
+/// next local midnight.
+pub fn end_of_today(now: DateTime<Utc>) -> DateTime<Utc> {
+    local_day::start_of_day_after(now, 1)
+}
+
+/// End (exclusive) of the expiry window in which a product is `UseToday` or
+/// `UseSoon`: local midnight after the last "expiring soon" day.
+pub fn urgent_until(now: DateTime<Utc>, window: ExpiringSoonWindow) -> DateTime<Utc> {
+    local_day::start_of_day_after(now, 1 + u64::from(window.days()))
+}
+
 /// Determines the urgency level of a product.
 ///
 /// Business rules:
+/// - Past a best-before date, or an estimate the estimator wasn't sure of ->
+///   QualityDeclining
 /// - Expired -> WouldntTrust
 /// - Expires today (0 days) -> UseToday
-/// - Expires in 1-2 days -> UseSoon
-/// - Expires in 3+ days or no date -> Ok
-pub fn get_urgency_level(product: &Product) -> UrgencyLevel {
+/// - Expires within the expiring-soon window (2 days by default) -> UseSoon
+/// - Expires later or no date -> Ok
+pub fn get_urgency_level(product: &Product, window: ExpiringSoonWindow) -> UrgencyLevel {
     let date = product.expiry_date.or(product.estimated_expiry_date);
     if date.is_none() {
         return UrgencyLevel::Ok;
     }
 
-    if is_expired(product) {
-        return UrgencyLevel::WouldntTrust;
+    if is_past_date(product) {
+        return if is_expired(product) {
+            UrgencyLevel::WouldntTrust
+        } else {
+            UrgencyLevel::QualityDeclining
+        };
     }
 
     let days = match days_until_expiry(product) {
@@ -67,15 +136,28 @@ pub fn get_urgency_level(product: &Product) -> UrgencyLevel {
         return UrgencyLevel::UseToday;
     }
 
-    if is_expiring_soon(product) {
+    if is_expiring_soon(product, window) {
         return UrgencyLevel::UseSoon;
     }
 
     UrgencyLevel::Ok
 }
 
-/// Returns true if the product is expired.
+/// Returns true if the product is expired. A product past its best-before
+/// date is not: it can still be eaten. Neither is one past a low-confidence
+/// estimate, which is a guess too rough to call the food unsafe.
 pub fn is_expired(product: &Product) -> bool {
+    product.expiry_type != ExpiryType::BestBefore
+        && !is_unsure_estimate(product)
+        && is_past_date(product)
+}
+
+/// The effective date is an estimate the estimator had low confidence in.
+fn is_unsure_estimate(product: &Product) -> bool {
+    product.expiry_date.is_none() && product.expiry_confidence == Some(Confidence::Low)
+}
+
+fn is_past_date(product: &Product) -> bool {
     let date = product.expiry_date.or(product.estimated_expiry_date);
     match date {
         Some(d) => d < Utc::now(),
@@ -83,10 +165,64 @@ pub fn is_expired(product: &Product) -> bool {
     }
 }
 
-/// Returns true if the product is expiring soon (within 2 days, not expired).
-pub fn is_expiring_soon(product: &Product) -> bool {
+/// Returns true if the product expires today or within the window's days.
+pub fn is_expiring_soon(product: &Product, window: ExpiringSoonWindow) -> bool {
     match days_until_expiry(product) {
-        Some(days) => (0..=EXPIRING_SOON_DAYS).contains(&days),
+        Some(days) => (0..=i64::from(window.days())).contains(&days),
         None => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::value_objects::ProductStatus;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn estimated(days: i64, confidence: Confidence) -> Product {
+        let mut product = Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            "Hummus".to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            None,
+            Some(Utc::now() + Duration::days(days)),
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        );
+        product.expiry_confidence = Some(confidence);
+        product
+    }
+
+    #[test]
+    fn should_not_call_past_low_confidence_estimates_expired() {
+        let product = estimated(-2, Confidence::Low);
+
+        assert!(!is_expired(&product));
+        assert_eq!(
+            get_urgency_level(&product, ExpiringSoonWindow::default()),
+            UrgencyLevel::QualityDeclining
+        );
+    }
+
+    #[test]
+    fn should_trust_past_estimates_of_higher_confidence_and_printed_dates() {
+        let confident = estimated(-2, Confidence::Medium);
+        let mut printed = estimated(-2, Confidence::Low);
+        printed.expiry_date = printed.estimated_expiry_date;
+
+        for product in [confident, printed] {
+            assert!(is_expired(&product));
+            assert_eq!(
+                get_urgency_level(&product, ExpiringSoonWindow::default()),
+                UrgencyLevel::WouldntTrust
+            );
+        }
+    }
+}
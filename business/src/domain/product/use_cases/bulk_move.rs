@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::job::model::Job;
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::value_objects::ProductLocation;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct BulkMoveProductsParams {
+    pub user_id: UserId,
+    pub product_ids: Vec<Uuid>,
+    pub location: ProductLocation,
+}
+
+/// Moves several products to one location at once, e.g. everything put in
+/// the freezer before a trip. The move is saved right away; estimated expiry
+/// dates are redone in the background for the products that changed place.
+/// Returns the job to poll. Nothing moves when any product is missing.
+#[async_trait]
+pub trait BulkMoveProductsUseCase: Send + Sync {
+    async fn execute(&self, params: BulkMoveProductsParams) -> Result<Job, ProductError>;
+}
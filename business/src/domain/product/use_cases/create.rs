@@ -2,7 +2,9 @@ use async_trait::async_trait;
 
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::model::Product;
-use crate::domain::product::value_objects::{ProductLocation, ProductOutcome, ProductStatus};
+use crate::domain::product::value_objects::{
+    ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
 use crate::domain::shared::value_objects::UserId;
 
 pub struct CreateProductParams {
@@ -13,7 +15,13 @@ pub struct CreateProductParams {
     pub quantity: Option<String>,
     pub expiry_date: Option<chrono::DateTime<chrono::Utc>>,
     pub estimated_expiry_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub expiry_type: ExpiryType,
     pub outcome: Option<ProductOutcome>,
+    pub category: Option<ProductCategory>,
+    pub tags: Vec<String>,
+    /// Barcode the product was scanned with, linking it to the nutrition and
+    /// eco data found when identifying it
+    pub barcode: Option<String>,
 }
 
 #[async_trait]
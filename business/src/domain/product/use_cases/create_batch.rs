@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::product::value_objects::{
+    ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
+use crate::domain::shared::value_objects::UserId;
+
+/// One product of a batch, with the same fields as a single create.
+pub struct CreateProductsBatchItem {
+    pub name: String,
+    pub status: ProductStatus,
+    pub location: Option<ProductLocation>,
+    pub quantity: Option<String>,
+    pub expiry_date: Option<DateTime<Utc>>,
+    pub estimated_expiry_date: Option<DateTime<Utc>>,
+    pub expiry_type: ExpiryType,
+    pub outcome: Option<ProductOutcome>,
+    pub category: Option<ProductCategory>,
+    pub tags: Vec<String>,
+    pub barcode: Option<String>,
+}
+
+pub struct CreateProductsBatchParams {
+    pub user_id: UserId,
+    pub items: Vec<CreateProductsBatchItem>,
+}
+
+/// Result of creating one product of a batch.
+pub struct CreatedProductOutcome {
+    /// The saved product, carrying whatever expiry estimate was made for it
+    pub product: Product,
+    /// Why estimating its expiry failed; the product is saved either way
+    pub estimation_error: Option<ProductError>,
+}
+
+#[async_trait]
+pub trait CreateProductsBatchUseCase: Send + Sync {
+    /// Saves every item or none: an invalid item, or not enough room left in
+    /// the user's plan, fails the whole batch. Outcomes follow the order of
+    /// the items.
+    async fn execute(
+        &self,
+        params: CreateProductsBatchParams,
+    ) -> Result<Vec<CreatedProductOutcome>, ProductError>;
+}
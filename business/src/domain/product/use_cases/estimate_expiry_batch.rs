@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct EstimateExpiryBatchParams {
+    pub user_id: UserId,
+    pub product_ids: Vec<Uuid>,
+}
+
+/// Result of estimating one product of a batch.
+pub struct EstimateExpiryOutcome {
+    pub product_id: Uuid,
+    pub result: Result<Product, ProductError>,
+}
+
+#[async_trait]
+pub trait EstimateExpiryBatchUseCase: Send + Sync {
+    /// Estimates every product independently; one failure does not stop the
+    /// rest. Outcomes follow the order of the (deduplicated) input IDs.
+    async fn execute(&self, params: EstimateExpiryBatchParams) -> Vec<EstimateExpiryOutcome>;
+}
@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::services::ExpiryEstimation;
+use crate::domain::product::value_objects::{ProductLocation, ProductStatus};
+use crate::domain::shared::value_objects::UserId;
+
+/// Estimation for a product that has not been saved yet.
+pub struct EstimateExpiryForAttributesParams {
+    pub user_id: UserId,
+    pub product_name: String,
+    pub status: ProductStatus,
+    pub location: Option<ProductLocation>,
+}
+
+#[async_trait]
+pub trait EstimateExpiryForAttributesUseCase: Send + Sync {
+    /// Fails with `NameEmpty` for a blank name, before any AI call is made.
+    async fn execute(
+        &self,
+        params: EstimateExpiryForAttributesParams,
+    ) -> Result<ExpiryEstimation, ProductError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::job::model::Job;
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct EstimateMissingParams {
+    pub user_id: UserId,
+}
+
+/// Estimates expiry dates in the background for every active product that
+/// has neither a printed nor an estimated one. Returns the job to poll; when
+/// one is already underway for the user, that job is returned instead.
+#[async_trait]
+pub trait EstimateMissingUseCase: Send + Sync {
+    async fn execute(&self, params: EstimateMissingParams) -> Result<Job, ProductError>;
+}
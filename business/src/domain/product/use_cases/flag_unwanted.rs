@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct FlagUnwantedParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    /// `false` clears the flag
+    pub unwanted: bool,
+}
+
+#[async_trait]
+pub trait FlagUnwantedUseCase: Send + Sync {
+    async fn execute(&self, params: FlagUnwantedParams) -> Result<(), ProductError>;
+}
@@ -2,10 +2,44 @@ use async_trait::async_trait;
 
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::model::Product;
+use crate::domain::product::query::{Page, ProductSort};
+use crate::domain::product::urgency::UrgencyLevel;
+use crate::domain::product::value_objects::{ProductCategory, ProductLocation, ProductStatus};
 use crate::domain::shared::value_objects::UserId;
 
 pub struct GetAllProductsParams {
     pub user_id: UserId,
+    /// Case-insensitive substring of the product name
+    pub search: Option<String>,
+    /// Only products expiring within this many days from now
+    pub expiring_within_days: Option<u32>,
+    pub status: Option<ProductStatus>,
+    pub location: Option<ProductLocation>,
+    pub category: Option<ProductCategory>,
+    /// Only products carrying this tag
+    pub tag: Option<String>,
+    /// Only products at this urgency, rated with the user's expiring-soon window
+    pub urgency: Option<UrgencyLevel>,
+    pub sort: ProductSort,
+    pub page: Option<Page>,
+}
+
+impl GetAllProductsParams {
+    /// Every active product of the user, newest first.
+    pub fn active(user_id: UserId) -> Self {
+        Self {
+            user_id,
+            search: None,
+            expiring_within_days: None,
+            status: None,
+            location: None,
+            category: None,
+            tag: None,
+            urgency: None,
+            sort: ProductSort::default(),
+            page: None,
+        }
+    }
 }
 
 #[async_trait]
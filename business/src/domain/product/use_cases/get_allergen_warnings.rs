@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::enrichment::Allergen;
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetAllergenWarningsParams {
+    pub user_id: UserId,
+    pub product_ids: Vec<Uuid>,
+}
+
+#[async_trait]
+pub trait GetAllergenWarningsUseCase: Send + Sync {
+    /// Declared allergens matching the user's allergies, per product.
+    /// Products without a match are left out of the map.
+    async fn execute(
+        &self,
+        params: GetAllergenWarningsParams,
+    ) -> Result<HashMap<Uuid, Vec<Allergen>>, ProductError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::product::calendar::{CalendarMonth, ExpiryCalendar};
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetExpiryCalendarParams {
+    pub user_id: UserId,
+    pub month: CalendarMonth,
+}
+
+#[async_trait]
+pub trait GetExpiryCalendarUseCase: Send + Sync {
+    /// Active products expiring during the month, grouped by day.
+    async fn execute(
+        &self,
+        params: GetExpiryCalendarParams,
+    ) -> Result<ExpiryCalendar, ProductError>;
+}
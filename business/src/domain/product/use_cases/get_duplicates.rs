@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::product::duplicates::DuplicateCluster;
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetDuplicateProductsParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetDuplicateProductsUseCase: Send + Sync {
+    /// Active products that are probably the same thing entered twice.
+    async fn execute(
+        &self,
+        params: GetDuplicateProductsParams,
+    ) -> Result<Vec<DuplicateCluster>, ProductError>;
+}
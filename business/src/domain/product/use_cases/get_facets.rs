@@ -0,0 +1,20 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::facets::{ProductFacet, ProductFacets};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetProductFacetsParams {
+    pub user_id: UserId,
+    pub product_ids: Vec<Uuid>,
+    pub facets: HashSet<ProductFacet>,
+}
+
+#[async_trait]
+pub trait GetProductFacetsUseCase: Send + Sync {
+    /// Runs one query per asked-for facet, none for the rest.
+    async fn execute(&self, params: GetProductFacetsParams) -> Result<ProductFacets, ProductError>;
+}
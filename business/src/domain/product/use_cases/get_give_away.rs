@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetGiveAwayCandidatesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetGiveAwayCandidatesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetGiveAwayCandidatesParams,
+    ) -> Result<Vec<Product>, ProductError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::shared::pagination::{CursorPage, KeysetPage};
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetProductHistoryParams {
+    pub user_id: UserId,
+    pub page: KeysetPage,
+}
+
+#[async_trait]
+pub trait GetProductHistoryUseCase: Send + Sync {
+    /// Finished products, newest first, one keyset page at a time.
+    async fn execute(
+        &self,
+        params: GetProductHistoryParams,
+    ) -> Result<CursorPage<Product>, ProductError>;
+}
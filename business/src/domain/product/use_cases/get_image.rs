@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::storage::model::ImageLinks;
+
+pub struct GetProductImageParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetProductImageUseCase: Send + Sync {
+    /// The thumbnail is left out until it has been made.
+    async fn execute(&self, params: GetProductImageParams) -> Result<ImageLinks, ProductError>;
+}
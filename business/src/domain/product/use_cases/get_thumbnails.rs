@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::storage::model::SignedUrl;
+
+pub struct GetProductThumbnailsParams {
+    pub user_id: UserId,
+    pub product_ids: Vec<Uuid>,
+}
+
+#[async_trait]
+pub trait GetProductThumbnailsUseCase: Send + Sync {
+    /// Products without a thumbnail are left out of the map.
+    async fn execute(
+        &self,
+        params: GetProductThumbnailsParams,
+    ) -> Result<HashMap<Uuid, SignedUrl>, ProductError>;
+}
@@ -2,12 +2,15 @@ use async_trait::async_trait;
 
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::services::ProductIdentification;
+use crate::domain::shared::value_objects::UserId;
 
 pub struct IdentifyByImageParams {
+    pub user_id: UserId,
     pub image_base64: String,
 }
 
 pub struct IdentifyByBarcodeParams {
+    pub user_id: UserId,
     pub barcode: String,
 }
 
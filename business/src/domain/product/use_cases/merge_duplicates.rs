@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct MergeDuplicateProductsParams {
+    pub user_id: UserId,
+    /// At least two active products with the same duplicate key
+    pub product_ids: Vec<Uuid>,
+    /// One of `product_ids`; the oldest product when `None`
+    pub keep_id: Option<Uuid>,
+}
+
+/// Folds a cluster of duplicates into one product, deleting the rest.
+/// Returns the product kept. Nothing changes when any product is missing
+/// or they aren't duplicates of each other.
+#[async_trait]
+pub trait MergeDuplicateProductsUseCase: Send + Sync {
+    async fn execute(&self, params: MergeDuplicateProductsParams) -> Result<Product, ProductError>;
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::model::Product;
+use crate::domain::product::value_objects::{
+    ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
+use crate::domain::shared::value_objects::UserId;
+
+/// Fields to change on a product; `None` keeps the current value. Fields the
+/// product may lack are cleared with `Some(None)`.
+pub struct PatchProductParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub name: Option<String>,
+    pub status: Option<ProductStatus>,
+    pub location: Option<Option<ProductLocation>>,
+    pub quantity: Option<Option<String>>,
+    pub expiry_date: Option<Option<DateTime<Utc>>>,
+    pub estimated_expiry_date: Option<Option<DateTime<Utc>>>,
+    pub expiry_type: Option<ExpiryType>,
+    pub outcome: Option<Option<ProductOutcome>>,
+    pub category: Option<Option<ProductCategory>>,
+    /// Replaces every tag of the product
+    pub tags: Option<Vec<String>>,
+}
+
+#[async_trait]
+pub trait PatchProductUseCase: Send + Sync {
+    /// Applies the changes on top of the stored product and saves it with the
+    /// same validation, status rules and events as a full update.
+    async fn execute(&self, params: PatchProductParams) -> Result<Product, ProductError>;
+}
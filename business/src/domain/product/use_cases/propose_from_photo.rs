@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::product::photo_diff::PhotoDiff;
+use crate::domain::product::value_objects::ProductLocation;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct ProposeFromPhotoParams {
+    pub user_id: UserId,
+    pub image_base64: String,
+    /// Where the photo was taken.
+    pub location: ProductLocation,
+}
+
+/// Identifies the products in a photo of a shelf and proposes the pantry
+/// changes it implies. Nothing is saved; the client confirms each change.
+#[async_trait]
+pub trait ProposeFromPhotoUseCase: Send + Sync {
+    async fn execute(&self, params: ProposeFromPhotoParams) -> Result<PhotoDiff, ProductError>;
+}
@@ -2,8 +2,10 @@ use async_trait::async_trait;
 
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::services::ReceiptScanResult;
+use crate::domain::shared::value_objects::UserId;
 
 pub struct ScanReceiptParams {
+    pub user_id: UserId,
     pub image_base64: String,
 }
 
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Most products a single seeding request may create.
+pub const MAX_SEED_PRODUCTS: usize = 5000;
+
+pub struct SeedProductsParams {
+    pub user_id: UserId,
+    /// Capped at [`MAX_SEED_PRODUCTS`]
+    pub count: usize,
+    /// Same seed, same pantry; see [`crate::domain::product::fixtures::sample_pantry`]
+    pub seed: u64,
+}
+
+#[async_trait]
+pub trait SeedProductsUseCase: Send + Sync {
+    /// Fills the user's pantry with generated products and returns how many
+    /// were created.
+    async fn execute(&self, params: SeedProductsParams) -> Result<usize, ProductError>;
+}
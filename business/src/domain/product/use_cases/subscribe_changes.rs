@@ -0,0 +1,15 @@
+use futures::stream::BoxStream;
+
+use crate::domain::product::events::ProductChange;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct SubscribeProductChangesParams {
+    pub user_id: UserId,
+}
+
+pub trait SubscribeProductChangesUseCase: Send + Sync {
+    /// Writes to the user's products from now on, as they happen. The stream
+    /// ends when the subscriber falls too far behind to catch up; clients
+    /// then reload the products and subscribe again.
+    fn execute(&self, params: SubscribeProductChangesParams) -> BoxStream<'static, ProductChange>;
+}
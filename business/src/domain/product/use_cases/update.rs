@@ -3,7 +3,9 @@ use uuid::Uuid;
 
 use crate::domain::product::errors::ProductError;
 use crate::domain::product::model::Product;
-use crate::domain::product::value_objects::{ProductLocation, ProductOutcome, ProductStatus};
+use crate::domain::product::value_objects::{
+    ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
 use crate::domain::shared::value_objects::UserId;
 
 pub struct UpdateProductParams {
@@ -15,7 +17,10 @@ pub struct UpdateProductParams {
     pub quantity: Option<String>,
     pub expiry_date: Option<chrono::DateTime<chrono::Utc>>,
     pub estimated_expiry_date: Option<chrono::DateTime<chrono::Utc>>,
+    pub expiry_type: ExpiryType,
     pub outcome: Option<ProductOutcome>,
+    pub category: Option<ProductCategory>,
+    pub tags: Vec<String>,
 }
 
 #[async_trait]
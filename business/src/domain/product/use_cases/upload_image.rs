@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::product::errors::ProductError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::storage::model::SignedUrl;
+
+pub struct UploadProductImageParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub image_base64: String,
+}
+
+#[async_trait]
+pub trait UploadProductImageUseCase: Send + Sync {
+    /// Stores the product's photo, replacing any previous one, and returns a
+    /// URL to download it.
+    async fn execute(&self, params: UploadProductImageParams) -> Result<SignedUrl, ProductError>;
+}
@@ -9,6 +9,17 @@ pub enum ProductStatus {
     Finished,
 }
 
+impl ProductStatus {
+    /// Status a product moves to after being used once in a recipe.
+    pub fn after_use(&self) -> ProductStatus {
+        match self {
+            ProductStatus::New => ProductStatus::Opened,
+            ProductStatus::Opened => ProductStatus::AlmostEmpty,
+            ProductStatus::AlmostEmpty | ProductStatus::Finished => ProductStatus::Finished,
+        }
+    }
+}
+
 impl std::fmt::Display for ProductStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -65,11 +76,71 @@ impl std::str::FromStr for ProductLocation {
     }
 }
 
+/// Kind of food a product is, for grouping and filtering the pantry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductCategory {
+    Dairy,
+    Produce,
+    Meat,
+    Fish,
+    Bakery,
+    DryGoods,
+    Canned,
+    Frozen,
+    Beverages,
+    Condiments,
+    Snacks,
+    Other,
+}
+
+impl std::fmt::Display for ProductCategory {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProductCategory::Dairy => write!(f, "dairy"),
+            ProductCategory::Produce => write!(f, "produce"),
+            ProductCategory::Meat => write!(f, "meat"),
+            ProductCategory::Fish => write!(f, "fish"),
+            ProductCategory::Bakery => write!(f, "bakery"),
+            ProductCategory::DryGoods => write!(f, "dry_goods"),
+            ProductCategory::Canned => write!(f, "canned"),
+            ProductCategory::Frozen => write!(f, "frozen"),
+            ProductCategory::Beverages => write!(f, "beverages"),
+            ProductCategory::Condiments => write!(f, "condiments"),
+            ProductCategory::Snacks => write!(f, "snacks"),
+            ProductCategory::Other => write!(f, "other"),
+        }
+    }
+}
+
+impl std::str::FromStr for ProductCategory {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dairy" => Ok(ProductCategory::Dairy),
+            "produce" => Ok(ProductCategory::Produce),
+            "meat" => Ok(ProductCategory::Meat),
+            "fish" => Ok(ProductCategory::Fish),
+            "bakery" => Ok(ProductCategory::Bakery),
+            "dry_goods" => Ok(ProductCategory::DryGoods),
+            "canned" => Ok(ProductCategory::Canned),
+            "frozen" => Ok(ProductCategory::Frozen),
+            "beverages" => Ok(ProductCategory::Beverages),
+            "condiments" => Ok(ProductCategory::Condiments),
+            "snacks" => Ok(ProductCategory::Snacks),
+            "other" => Ok(ProductCategory::Other),
+            _ => Err(format!("Invalid product category: {}", s)),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ProductOutcome {
     Used,
     ThrownAway,
+    GivenAway,
 }
 
 impl std::fmt::Display for ProductOutcome {
@@ -77,6 +148,7 @@ impl std::fmt::Display for ProductOutcome {
         match self {
             ProductOutcome::Used => write!(f, "used"),
             ProductOutcome::ThrownAway => write!(f, "thrown_away"),
+            ProductOutcome::GivenAway => write!(f, "given_away"),
         }
     }
 }
@@ -88,7 +160,83 @@ impl std::str::FromStr for ProductOutcome {
         match s {
             "used" => Ok(ProductOutcome::Used),
             "thrown_away" => Ok(ProductOutcome::ThrownAway),
+            "given_away" => Ok(ProductOutcome::GivenAway),
             _ => Err(format!("Invalid product outcome: {}", s)),
         }
     }
 }
+
+/// What the date printed on the label means.
+///
+/// A "use by" date is about safety: the food shouldn't be eaten afterwards. A
+/// "best before" date is about quality: the food is usually fine for a while
+/// after it, only less good.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExpiryType {
+    UseBy,
+    BestBefore,
+    /// No label, or unknown. Treated like "use by".
+    #[default]
+    None,
+}
+
+impl std::fmt::Display for ExpiryType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpiryType::UseBy => write!(f, "use_by"),
+            ExpiryType::BestBefore => write!(f, "best_before"),
+            ExpiryType::None => write!(f, "none"),
+        }
+    }
+}
+
+impl std::str::FromStr for ExpiryType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "use_by" => Ok(ExpiryType::UseBy),
+            "best_before" => Ok(ExpiryType::BestBefore),
+            "none" => Ok(ExpiryType::None),
+            _ => Err(format!("Invalid expiry type: {}", s)),
+        }
+    }
+}
+
+/// Where the expiry estimation of a product stands, so clients can show that
+/// an estimate is still on its way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EstimationStatus {
+    /// Nothing outstanding: estimated, dated by the user, or nothing to estimate
+    #[default]
+    Done,
+    /// The estimator was down when the product was added; a retry job will
+    /// try again
+    Pending,
+    /// Every attempt found the estimator down
+    Failed,
+}
+
+impl std::fmt::Display for EstimationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EstimationStatus::Done => write!(f, "done"),
+            EstimationStatus::Pending => write!(f, "pending"),
+            EstimationStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for EstimationStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "done" => Ok(EstimationStatus::Done),
+            "pending" => Ok(EstimationStatus::Pending),
+            "failed" => Ok(EstimationStatus::Failed),
+            _ => Err(format!("Invalid estimation status: {}", s)),
+        }
+    }
+}
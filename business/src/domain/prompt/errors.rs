@@ -0,0 +1,20 @@
+use crate::domain::errors::ErrorSource;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PromptError {
+    /// A template file is misnamed, unknown or does not render
+    #[error("prompt.invalid_template")]
+    InvalidTemplate(#[source] ErrorSource),
+    #[error("prompt.unreadable")]
+    Unreadable(#[source] ErrorSource),
+}
+
+impl PromptError {
+    pub fn invalid_template(cause: impl Into<ErrorSource>) -> Self {
+        PromptError::InvalidTemplate(cause.into())
+    }
+
+    pub fn unreadable(cause: impl Into<ErrorSource>) -> Self {
+        PromptError::Unreadable(cause.into())
+    }
+}
@@ -0,0 +1,26 @@
+/// Where the template in use for a prompt came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// Shipped with the server
+    Builtin,
+    /// Read from the template directory, overriding the built-in one
+    Directory,
+}
+
+impl std::fmt::Display for TemplateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TemplateSource::Builtin => write!(f, "builtin"),
+            TemplateSource::Directory => write!(f, "directory"),
+        }
+    }
+}
+
+/// A system prompt template in use, as recorded on the AI calls made with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptTemplateInfo {
+    /// Prompt it renders, e.g. "expiry_estimator"
+    pub name: String,
+    pub version: u32,
+    pub source: TemplateSource,
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+
+use super::errors::PromptError;
+use super::model::PromptTemplateInfo;
+
+/// Service port for the system prompt templates the AI adapters render.
+#[async_trait]
+pub trait PromptTemplateService: Send + Sync {
+    /// Reads the templates again and swaps them in for the next AI calls.
+    /// When any of them is invalid, the ones in use are kept.
+    async fn reload(&self) -> Result<Vec<PromptTemplateInfo>, PromptError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::prompt::errors::PromptError;
+use crate::domain::prompt::model::PromptTemplateInfo;
+
+#[async_trait]
+pub trait ReloadPromptTemplatesUseCase: Send + Sync {
+    /// Reloads the prompt templates without a restart and returns the ones
+    /// now in use, sorted by name.
+    async fn execute(&self) -> Result<Vec<PromptTemplateInfo>, PromptError>;
+}
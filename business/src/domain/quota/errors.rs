@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum QuotaError {
+    #[error("quota.ai_calls_exceeded")]
+    AiCallsExceeded,
+    #[error("quota.product_limit_reached")]
+    ProductLimitReached,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,112 @@
+/// Daily AI calls allowed on the free plan.
+pub const FREE_AI_CALLS_PER_DAY: u32 = 20;
+
+/// Non-finished products a free user can keep in the pantry.
+pub const FREE_MAX_PRODUCTS: u32 = 100;
+
+/// Subscription tier that determines a user's limits.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum PlanTier {
+    #[default]
+    Free,
+    Premium,
+}
+
+impl std::fmt::Display for PlanTier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanTier::Free => write!(f, "free"),
+            PlanTier::Premium => write!(f, "premium"),
+        }
+    }
+}
+
+impl std::str::FromStr for PlanTier {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "free" => Ok(PlanTier::Free),
+            "premium" => Ok(PlanTier::Premium),
+            _ => Err(format!("Invalid plan tier: {}", s)),
+        }
+    }
+}
+
+/// Limits granted by a plan. `None` means unlimited.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanLimits {
+    pub ai_calls_per_day: Option<u32>,
+    pub max_products: Option<u32>,
+}
+
+impl PlanTier {
+    pub fn limits(&self) -> PlanLimits {
+        match self {
+            PlanTier::Free => PlanLimits {
+                ai_calls_per_day: Some(FREE_AI_CALLS_PER_DAY),
+                max_products: Some(FREE_MAX_PRODUCTS),
+            },
+            PlanTier::Premium => PlanLimits {
+                ai_calls_per_day: None,
+                max_products: None,
+            },
+        }
+    }
+}
+
+/// A user's plan and how much of it they have used.
+#[derive(Debug, Clone)]
+pub struct Usage {
+    pub plan: PlanTier,
+    pub limits: PlanLimits,
+    /// AI calls made today (UTC)
+    pub ai_calls_today: u32,
+    /// Products that are not finished
+    pub products: u32,
+}
+
+impl Usage {
+    /// Whether another AI call fits in today's allowance.
+    pub fn has_ai_calls_left(&self) -> bool {
+        self.limits
+            .ai_calls_per_day
+            .is_none_or(|limit| self.ai_calls_today < limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_limit_free_plan_and_not_premium() {
+        assert_eq!(
+            PlanTier::Free.limits().ai_calls_per_day,
+            Some(FREE_AI_CALLS_PER_DAY)
+        );
+        assert_eq!(PlanTier::Premium.limits().max_products, None);
+    }
+
+    #[test]
+    fn should_have_no_ai_calls_left_once_free_allowance_used() {
+        let usage = |plan: PlanTier, ai_calls_today| Usage {
+            limits: plan.limits(),
+            plan,
+            ai_calls_today,
+            products: 0,
+        };
+
+        assert!(usage(PlanTier::Free, FREE_AI_CALLS_PER_DAY - 1).has_ai_calls_left());
+        assert!(!usage(PlanTier::Free, FREE_AI_CALLS_PER_DAY).has_ai_calls_left());
+        assert!(usage(PlanTier::Premium, 1000).has_ai_calls_left());
+    }
+
+    #[test]
+    fn should_round_trip_plan_tier_through_string() {
+        let tier: PlanTier = PlanTier::Premium.to_string().parse().unwrap();
+
+        assert_eq!(tier, PlanTier::Premium);
+        assert!("gold".parse::<PlanTier>().is_err());
+    }
+}
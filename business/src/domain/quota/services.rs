@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use super::errors::QuotaError;
+use super::model::Usage;
+use crate::domain::shared::value_objects::UserId;
+
+/// Service port for plan limits backed by persistent usage counters.
+///
+/// Use cases call it before doing metered work; limits come from the user's
+/// [`PlanTier`](super::model::PlanTier).
+#[async_trait]
+pub trait QuotaService: Send + Sync {
+    /// Records an AI call for today, failing with `AiCallsExceeded` when the
+    /// daily allowance is already used up.
+    async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+
+    /// Fails with `ProductLimitReached` when the user cannot add another product.
+    async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+
+    async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+}
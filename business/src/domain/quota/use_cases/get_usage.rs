@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::quota::errors::QuotaError;
+use crate::domain::quota::model::Usage;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetUsageParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetUsageUseCase: Send + Sync {
+    async fn execute(&self, params: GetUsageParams) -> Result<Usage, QuotaError>;
+}
@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ReceiptImportError {
+    #[error("receipt_import.not_found")]
+    NotFound,
+    #[error("receipt_import.image_not_found")]
+    ImageNotFound,
+    #[error(transparent)]
+    Quota(#[from] crate::domain::quota::errors::QuotaError),
+    #[error(transparent)]
+    Storage(#[from] crate::domain::storage::errors::StorageError),
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,262 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+
+/// Lifecycle state of a receipt import.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReceiptImportStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl std::fmt::Display for ReceiptImportStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReceiptImportStatus::Pending => write!(f, "pending"),
+            ReceiptImportStatus::Running => write!(f, "running"),
+            ReceiptImportStatus::Completed => write!(f, "completed"),
+            ReceiptImportStatus::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReceiptImportStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(ReceiptImportStatus::Pending),
+            "running" => Ok(ReceiptImportStatus::Running),
+            "completed" => Ok(ReceiptImportStatus::Completed),
+            "failed" => Ok(ReceiptImportStatus::Failed),
+            _ => Err(format!("Invalid receipt import status: {}", s)),
+        }
+    }
+}
+
+/// Why a receipt line did not become a product.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SkipReason {
+    /// Nothing usable left after normalizing the line.
+    InvalidName,
+    /// Same product appears earlier on the receipt.
+    DuplicateOnReceipt,
+    /// An active product with the same name is already in the pantry.
+    AlreadyInPantry,
+    /// The plan's product limit was reached.
+    PlanLimitReached,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::InvalidName => write!(f, "invalid_name"),
+            SkipReason::DuplicateOnReceipt => write!(f, "duplicate_on_receipt"),
+            SkipReason::AlreadyInPantry => write!(f, "already_in_pantry"),
+            SkipReason::PlanLimitReached => write!(f, "plan_limit_reached"),
+        }
+    }
+}
+
+impl std::str::FromStr for SkipReason {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "invalid_name" => Ok(SkipReason::InvalidName),
+            "duplicate_on_receipt" => Ok(SkipReason::DuplicateOnReceipt),
+            "already_in_pantry" => Ok(SkipReason::AlreadyInPantry),
+            "plan_limit_reached" => Ok(SkipReason::PlanLimitReached),
+            _ => Err(format!("Invalid skip reason: {}", s)),
+        }
+    }
+}
+
+/// A product created from a receipt line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportedProduct {
+    pub product_id: Uuid,
+    pub name: String,
+    pub estimated_expiry_date: Option<DateTime<Utc>>,
+}
+
+/// A receipt line that was not turned into a product.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SkippedLine {
+    /// Name from the receipt line.
+    pub name: String,
+    pub reason: SkipReason,
+    /// The pantry product it matched, for `AlreadyInPantry`.
+    pub existing_product_id: Option<Uuid>,
+}
+
+/// What an import did, for the user to review.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ImportReview {
+    pub created: Vec<ImportedProduct>,
+    pub skipped: Vec<SkippedLine>,
+}
+
+/// A receipt being turned into pantry products in the background.
+#[derive(Debug, Clone)]
+pub struct ReceiptImport {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub status: ReceiptImportStatus,
+    /// Present once the import completed.
+    pub review: Option<ImportReview>,
+    /// Error code, present once the import failed.
+    pub error: Option<String>,
+    /// Storage key of the receipt photo, when it could be kept.
+    pub image_key: Option<String>,
+    /// Storage key of the photo's thumbnail, once it has been made.
+    pub thumbnail_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReceiptImport {
+    pub fn new(user_id: UserId) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            status: ReceiptImportStatus::Pending,
+            review: None,
+            error: None,
+            image_key: None,
+            thumbnail_key: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        status: ReceiptImportStatus,
+        review: Option<ImportReview>,
+        error: Option<String>,
+        image_key: Option<String>,
+        thumbnail_key: Option<String>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            status,
+            review,
+            error,
+            image_key,
+            thumbnail_key,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn start(&mut self) {
+        self.status = ReceiptImportStatus::Running;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn complete(&mut self, review: ImportReview) {
+        self.status = ReceiptImportStatus::Completed;
+        self.review = Some(review);
+        self.updated_at = Utc::now();
+    }
+
+    pub fn fail(&mut self, error: impl ToString) {
+        self.status = ReceiptImportStatus::Failed;
+        self.error = Some(error.to_string());
+        self.updated_at = Utc::now();
+    }
+}
+
+/// Turns a raw receipt line into a product name.
+///
+/// Business rules:
+/// - Whitespace is collapsed and a leading multiplier ("2x", "3 X") is dropped
+/// - All-caps lines, as most tickets print them, are converted to sentence case
+/// - Lines without letters are not products
+pub fn normalize_receipt_name(raw: &str) -> Option<String> {
+    let words: Vec<&str> = raw.split_whitespace().collect();
+    let words = match words.split_first() {
+        Some((first, rest)) if is_multiplier(first) => rest.to_vec(),
+        Some((first, rest)) if first.chars().all(|c| c.is_ascii_digit()) => {
+            match rest.split_first() {
+                Some((x, rest)) if x.eq_ignore_ascii_case("x") => rest.to_vec(),
+                _ => words,
+            }
+        }
+        _ => words,
+    };
+
+    let name = words.join(" ");
+    if !name.chars().any(char::is_alphabetic) {
+        return None;
+    }
+
+    let is_shouting = !name.chars().any(char::is_lowercase);
+    if !is_shouting {
+        return Some(name);
+    }
+
+    let lower = name.to_lowercase();
+    let mut chars = lower.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+}
+
+fn is_multiplier(word: &str) -> bool {
+    let lower = word.to_lowercase();
+    lower
+        .strip_suffix('x')
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Key used to tell receipt lines and pantry products apart.
+pub fn name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_normalize_receipt_lines() {
+        assert_eq!(
+            normalize_receipt_name("  LECHE   ENTERA 1L "),
+            Some("Leche entera 1l".to_string())
+        );
+        assert_eq!(
+            normalize_receipt_name("2x Yogur griego"),
+            Some("Yogur griego".to_string())
+        );
+        assert_eq!(
+            normalize_receipt_name("3 X TOMATE RAMA"),
+            Some("Tomate rama".to_string())
+        );
+        assert_eq!(normalize_receipt_name(" 1,25 "), None);
+    }
+
+    #[test]
+    fn should_move_through_lifecycle() {
+        let mut import = ReceiptImport::new(UserId::new("test-user-id"));
+        assert_eq!(import.status, ReceiptImportStatus::Pending);
+
+        import.start();
+        assert_eq!(import.status, ReceiptImportStatus::Running);
+
+        import.fail("product.scan_failed");
+        assert_eq!(import.status, ReceiptImportStatus::Failed);
+        assert_eq!(import.error.as_deref(), Some("product.scan_failed"));
+    }
+}
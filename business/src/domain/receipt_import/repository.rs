@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::ReceiptImport;
+
+#[async_trait]
+pub trait ReceiptImportRepository: Send + Sync {
+    async fn get_by_id(&self, id: Uuid, user_id: &UserId)
+    -> Result<ReceiptImport, RepositoryError>;
+    async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+    async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::receipt_import::model::ReceiptImport;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetReceiptImportParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetReceiptImportUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetReceiptImportParams,
+    ) -> Result<ReceiptImport, ReceiptImportError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::storage::model::ImageLinks;
+
+pub struct GetReceiptImageParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetReceiptImageUseCase: Send + Sync {
+    /// The thumbnail is left out until it has been made.
+    async fn execute(
+        &self,
+        params: GetReceiptImageParams,
+    ) -> Result<ImageLinks, ReceiptImportError>;
+}
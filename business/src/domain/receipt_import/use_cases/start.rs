@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::receipt_import::errors::ReceiptImportError;
+use crate::domain::receipt_import::model::ReceiptImport;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct StartReceiptImportParams {
+    pub user_id: UserId,
+    pub image_base64: String,
+}
+
+/// Accepts a receipt photo and turns it into pantry products in the background.
+/// Returns the pending import to poll.
+#[async_trait]
+pub trait StartReceiptImportUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: StartReceiptImportParams,
+    ) -> Result<ReceiptImport, ReceiptImportError>;
+}
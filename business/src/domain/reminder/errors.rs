@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ReminderError {
+    #[error("reminder.note_empty")]
+    NoteEmpty,
+    #[error("reminder.remind_at_in_past")]
+    RemindAtInPast,
+    #[error("reminder.not_found")]
+    NotFound,
+    #[error("product.not_found")]
+    ProductNotFound,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,195 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::errors::ReminderError;
+use crate::domain::shared::value_objects::UserId;
+
+/// A note the user wants brought back at a chosen time, attached to one of
+/// their products, e.g. "check the sourdough starter" on Friday.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub product_id: Uuid,
+    pub note: String,
+    pub remind_at: DateTime<Utc>,
+    /// When the reminder went out; `None` until it does
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    pub fn new(
+        user_id: UserId,
+        product_id: Uuid,
+        note: String,
+        remind_at: DateTime<Utc>,
+    ) -> Result<Self, ReminderError> {
+        let now = Utc::now();
+        validate_note(&note)?;
+        validate_remind_at(remind_at, now)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            product_id,
+            note,
+            remind_at,
+            sent_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        product_id: Uuid,
+        note: String,
+        remind_at: DateTime<Utc>,
+        sent_at: Option<DateTime<Utc>>,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            product_id,
+            note,
+            remind_at,
+            sent_at,
+            created_at,
+            updated_at,
+        }
+    }
+
+    /// Moving the reminder to a new time sends it again once that comes.
+    pub fn update(
+        &mut self,
+        note: Option<String>,
+        remind_at: Option<DateTime<Utc>>,
+    ) -> Result<(), ReminderError> {
+        let now = Utc::now();
+        if let Some(note) = note {
+            validate_note(&note)?;
+            self.note = note;
+        }
+        if let Some(remind_at) = remind_at {
+            validate_remind_at(remind_at, now)?;
+            self.remind_at = remind_at;
+            self.sent_at = None;
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// Whether the reminder's time has come and it hasn't been sent yet.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.sent_at.is_none() && self.remind_at <= now
+    }
+}
+
+fn validate_note(note: &str) -> Result<(), ReminderError> {
+    if note.trim().is_empty() {
+        return Err(ReminderError::NoteEmpty);
+    }
+    Ok(())
+}
+
+fn validate_remind_at(remind_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), ReminderError> {
+    if remind_at <= now {
+        return Err(ReminderError::RemindAtInPast);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn friday() -> DateTime<Utc> {
+        Utc::now() + Duration::days(3)
+    }
+
+    #[test]
+    fn should_create_reminder_when_note_and_date_valid() {
+        let product_id = Uuid::new_v4();
+
+        let reminder = Reminder::new(
+            test_user_id(),
+            product_id,
+            "Check the sourdough starter".to_string(),
+            friday(),
+        )
+        .unwrap();
+
+        assert_eq!(reminder.product_id, product_id);
+        assert_eq!(reminder.note, "Check the sourdough starter");
+        assert!(!reminder.is_due(Utc::now()));
+    }
+
+    #[test]
+    fn should_reject_reminder_when_note_is_blank() {
+        let result = Reminder::new(test_user_id(), Uuid::new_v4(), "  ".to_string(), friday());
+
+        assert!(matches!(result, Err(ReminderError::NoteEmpty)));
+    }
+
+    #[test]
+    fn should_reject_reminder_when_date_already_passed() {
+        let result = Reminder::new(
+            test_user_id(),
+            Uuid::new_v4(),
+            "Feed the starter".to_string(),
+            Utc::now() - Duration::hours(1),
+        );
+
+        assert!(matches!(result, Err(ReminderError::RemindAtInPast)));
+    }
+
+    #[test]
+    fn should_keep_fields_not_given_when_updating() {
+        let mut reminder = Reminder::new(
+            test_user_id(),
+            Uuid::new_v4(),
+            "Feed the starter".to_string(),
+            friday(),
+        )
+        .unwrap();
+        let later = friday() + Duration::days(1);
+
+        reminder.update(None, Some(later)).unwrap();
+
+        assert_eq!(reminder.note, "Feed the starter");
+        assert_eq!(reminder.remind_at, later);
+    }
+
+    #[test]
+    fn should_send_again_once_moved_to_new_time() {
+        let now = Utc::now();
+        let mut reminder = Reminder::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            Uuid::new_v4(),
+            "Feed the starter".to_string(),
+            now - Duration::hours(1),
+            Some(now - Duration::hours(1)),
+            now - Duration::days(1),
+            now - Duration::days(1),
+        );
+        assert!(!reminder.is_due(now));
+
+        reminder.update(None, Some(friday())).unwrap();
+
+        assert_eq!(reminder.sent_at, None);
+        assert!(reminder.is_due(friday() + Duration::days(1)));
+    }
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::Reminder;
+
+#[async_trait]
+pub trait ReminderRepository: Send + Sync {
+    /// Reminders of one product, soonest first.
+    async fn get_by_product(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<Vec<Reminder>, RepositoryError>;
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<Reminder, RepositoryError>;
+    async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+    async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError>;
+    async fn delete(
+        &self,
+        id: Uuid,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<(), RepositoryError>;
+    /// Reminders of every user that are due at `now` and not sent yet,
+    /// earliest first.
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Reminder>, RepositoryError>;
+    /// Records that the reminder went out, so it isn't sent again.
+    async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CreateReminderParams {
+    pub user_id: UserId,
+    pub product_id: Uuid,
+    pub note: String,
+    pub remind_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait CreateReminderUseCase: Send + Sync {
+    async fn execute(&self, params: CreateReminderParams) -> Result<Reminder, ReminderError>;
+}
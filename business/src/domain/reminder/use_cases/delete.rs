@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct DeleteReminderParams {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait DeleteReminderUseCase: Send + Sync {
+    async fn execute(&self, params: DeleteReminderParams) -> Result<(), ReminderError>;
+}
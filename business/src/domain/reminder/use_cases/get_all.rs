@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GetRemindersParams {
+    pub user_id: UserId,
+    pub product_id: Uuid,
+}
+
+#[async_trait]
+pub trait GetRemindersUseCase: Send + Sync {
+    async fn execute(&self, params: GetRemindersParams) -> Result<Vec<Reminder>, ReminderError>;
+}
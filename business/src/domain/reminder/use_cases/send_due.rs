@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::reminder::errors::ReminderError;
+
+pub struct SendDueRemindersParams {
+    /// Cap on reminders sent in a single run
+    pub max_reminders: usize,
+}
+
+#[async_trait]
+pub trait SendDueRemindersUseCase: Send + Sync {
+    /// Notifies the users of their reminders whose time has come, each only
+    /// once; returns how many were handled.
+    async fn execute(&self, params: SendDueRemindersParams) -> Result<u32, ReminderError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::reminder::errors::ReminderError;
+use crate::domain::reminder::model::Reminder;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct UpdateReminderParams {
+    pub id: Uuid,
+    pub product_id: Uuid,
+    pub user_id: UserId,
+    pub note: Option<String>,
+    pub remind_at: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait UpdateReminderUseCase: Send + Sync {
+    async fn execute(&self, params: UpdateReminderParams) -> Result<Reminder, ReminderError>;
+}
@@ -0,0 +1,5 @@
+#[derive(Debug, thiserror::Error)]
+pub enum SandboxError {
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait SandboxRepository: Send + Sync {
+    /// Deletes everything the user has written (pantry, lists, sessions,
+    /// settings, history) while keeping the account and its plan.
+    async fn wipe(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+}
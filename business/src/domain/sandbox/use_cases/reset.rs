@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+use crate::domain::sandbox::errors::SandboxError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Products in the curated demo pantry.
+pub const DEMO_PANTRY_SIZE: usize = 30;
+
+/// Seed of the curated demo pantry, so every reset looks the same.
+pub const DEMO_PANTRY_SEED: u64 = 2026;
+
+pub struct ResetSandboxParams {
+    /// The demo user being reset
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait ResetSandboxUseCase: Send + Sync {
+    /// Throws away whatever the demo user did and restores the curated
+    /// pantry. Returns how many products were restored.
+    async fn execute(&self, params: ResetSandboxParams) -> Result<usize, SandboxError>;
+}
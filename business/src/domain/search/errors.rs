@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum SearchError {
+    #[error("search.query_too_short")]
+    QueryTooShort,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
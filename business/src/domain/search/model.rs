@@ -0,0 +1,172 @@
+use chrono::{DateTime, Utc};
+
+use super::errors::SearchError;
+use crate::domain::product::model::Product;
+use crate::domain::product::value_objects::ProductStatus;
+use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::suggestion::model::Suggestion;
+
+/// A trimmed search query, long enough to be worth running.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchTerm(String);
+
+impl SearchTerm {
+    pub const MIN_CHARS: usize = 2;
+
+    pub fn new(raw: &str) -> Result<Self, SearchError> {
+        let term = raw.trim();
+        if term.chars().count() < Self::MIN_CHARS {
+            return Err(SearchError::QueryTooShort);
+        }
+        Ok(Self(term.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// How well `text` matches: the whole text, its start, the start of one
+    /// of its words, or anywhere in it, best first. `None` when it doesn't.
+    pub fn relevance(&self, text: &str) -> Option<u32> {
+        let term = self.0.to_lowercase();
+        let text = text.trim().to_lowercase();
+        if text == term {
+            Some(100)
+        } else if text.starts_with(&term) {
+            Some(80)
+        } else if text
+            .match_indices(&term)
+            .any(|(at, _)| !text[..at].ends_with(char::is_alphanumeric))
+        {
+            Some(60)
+        } else if text.contains(&term) {
+            Some(40)
+        } else {
+            None
+        }
+    }
+}
+
+/// Anything the global search can find.
+#[derive(Debug, Clone)]
+pub enum SearchResult {
+    Product(Product),
+    ShoppingItem(ShoppingItem),
+    /// A recipe from the suggestion history
+    Suggestion(Suggestion),
+}
+
+impl SearchResult {
+    /// The text the query is matched against.
+    fn text(&self) -> &str {
+        match self {
+            SearchResult::Product(product) => &product.name,
+            SearchResult::ShoppingItem(item) => &item.name,
+            SearchResult::Suggestion(suggestion) => &suggestion.title,
+        }
+    }
+
+    /// Finished products and bought items are history: they rank below
+    /// what is still in the pantry or on the list.
+    fn is_history(&self) -> bool {
+        match self {
+            SearchResult::Product(product) => product.status == ProductStatus::Finished,
+            SearchResult::ShoppingItem(item) => item.is_bought,
+            SearchResult::Suggestion(_) => false,
+        }
+    }
+
+    fn updated_at(&self) -> DateTime<Utc> {
+        match self {
+            SearchResult::Product(product) => product.updated_at,
+            SearchResult::ShoppingItem(item) => item.updated_at,
+            SearchResult::Suggestion(suggestion) => suggestion.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    /// Higher is more relevant; only meaningful within one search
+    pub score: u32,
+    pub result: SearchResult,
+}
+
+/// Scores `results` against `term` and keeps the best `limit` of them,
+/// most relevant first and, on ties, most recently updated first.
+pub fn rank(term: &SearchTerm, results: Vec<SearchResult>, limit: usize) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = results
+        .into_iter()
+        .filter_map(|result| {
+            let relevance = term.relevance(result.text())?;
+            let penalty = if result.is_history() { 10 } else { 0 };
+            Some(SearchHit {
+                score: relevance - penalty,
+                result,
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then_with(|| b.result.updated_at().cmp(&a.result.updated_at()))
+    });
+    hits.truncate(limit);
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared::value_objects::UserId;
+
+    fn term(raw: &str) -> SearchTerm {
+        SearchTerm::new(raw).unwrap()
+    }
+
+    fn item(name: &str, is_bought: bool) -> SearchResult {
+        let mut item =
+            ShoppingItem::new(UserId::new("test-user-id"), name.to_string(), None).unwrap();
+        item.is_bought = is_bought;
+        SearchResult::ShoppingItem(item)
+    }
+
+    #[test]
+    fn should_reject_queries_shorter_than_two_characters() {
+        assert!(matches!(
+            SearchTerm::new(" a "),
+            Err(SearchError::QueryTooShort)
+        ));
+        assert_eq!(term("  milk ").as_str(), "milk");
+    }
+
+    #[test]
+    fn should_rank_exact_over_prefix_over_word_over_substring() {
+        let term = term("Pan");
+        assert_eq!(term.relevance("pan"), Some(100));
+        assert_eq!(term.relevance("Pan de molde"), Some(80));
+        assert_eq!(term.relevance("Harina para pan"), Some(60));
+        assert_eq!(term.relevance("Empanada"), Some(40));
+        assert_eq!(term.relevance("Leche"), None);
+    }
+
+    #[test]
+    fn should_put_history_below_equally_relevant_live_results() {
+        let hits = rank(
+            &term("leche"),
+            vec![
+                item("Leche", true),
+                item("Leche", false),
+                item("Café con leche", false),
+                item("Pan", false),
+            ],
+            10,
+        );
+
+        let scores: Vec<(u32, bool)> = hits
+            .iter()
+            .map(|hit| (hit.score, hit.result.is_history()))
+            .collect();
+        assert_eq!(scores, vec![(100, false), (90, true), (60, false)]);
+    }
+}
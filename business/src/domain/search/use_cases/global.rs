@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::search::errors::SearchError;
+use crate::domain::search::model::SearchHit;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct GlobalSearchParams {
+    pub user_id: UserId,
+    /// At least [`SearchTerm::MIN_CHARS`](crate::domain::search::model::SearchTerm::MIN_CHARS)
+    /// characters once trimmed
+    pub query: String,
+    pub limit: u32,
+}
+
+#[async_trait]
+pub trait GlobalSearchUseCase: Send + Sync {
+    /// Hits across the user's data, most relevant first, up to `limit`.
+    async fn execute(&self, params: GlobalSearchParams) -> Result<Vec<SearchHit>, SearchError>;
+}
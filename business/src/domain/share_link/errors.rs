@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ShareLinkError {
+    #[error("share_link.invalid_expiry")]
+    InvalidExpiry,
+    #[error("share_link.not_found")]
+    NotFound,
+    #[error("share_link.expired")]
+    Expired,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,134 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Duration, Utc};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::errors::ShareLinkError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Maximum lifetime of a share link (7 days).
+pub const MAX_SHARE_LINK_TTL_HOURS: i64 = 168;
+
+const TOKEN_BYTES: usize = 32;
+
+/// Read-only access to a user's shopping list for people without an account.
+///
+/// Only the SHA-256 hash of the token is stored, so a leaked database row
+/// cannot be turned back into a working URL.
+#[derive(Debug, Clone)]
+pub struct ShareLink {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub include_expiring_products: bool,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLink {
+    /// Issues a new link and returns it together with the plain token, which is
+    /// only available at creation time.
+    pub fn issue(
+        user_id: UserId,
+        include_expiring_products: bool,
+        ttl_hours: i64,
+    ) -> Result<(Self, String), ShareLinkError> {
+        if !(1..=MAX_SHARE_LINK_TTL_HOURS).contains(&ttl_hours) {
+            return Err(ShareLinkError::InvalidExpiry);
+        }
+
+        let mut bytes = [0u8; TOKEN_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        let token = URL_SAFE_NO_PAD.encode(bytes);
+
+        let now = Utc::now();
+        let link = Self {
+            id: Uuid::new_v4(),
+            user_id,
+            token_hash: hash_token(&token),
+            include_expiring_products,
+            expires_at: now + Duration::hours(ttl_hours),
+            created_at: now,
+        };
+
+        Ok((link, token))
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        token_hash: String,
+        include_expiring_products: bool,
+        expires_at: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            token_hash,
+            include_expiring_products,
+            expires_at,
+            created_at,
+        }
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Hashes a plain share token into the form stored by the repository.
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    #[test]
+    fn should_store_only_token_hash() {
+        let (link, token) = ShareLink::issue(test_user_id(), false, 24).unwrap();
+
+        assert_ne!(link.token_hash, token);
+        assert_eq!(link.token_hash, hash_token(&token));
+    }
+
+    #[test]
+    fn should_generate_unique_tokens() {
+        let (_, first) = ShareLink::issue(test_user_id(), false, 24).unwrap();
+        let (_, second) = ShareLink::issue(test_user_id(), false, 24).unwrap();
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn should_reject_when_ttl_out_of_range() {
+        assert!(matches!(
+            ShareLink::issue(test_user_id(), false, 0).unwrap_err(),
+            ShareLinkError::InvalidExpiry
+        ));
+        assert!(matches!(
+            ShareLink::issue(test_user_id(), false, MAX_SHARE_LINK_TTL_HOURS + 1).unwrap_err(),
+            ShareLinkError::InvalidExpiry
+        ));
+    }
+
+    #[test]
+    fn should_be_expired_after_expiry_date() {
+        let (link, _) = ShareLink::issue(test_user_id(), true, 1).unwrap();
+
+        assert!(!link.is_expired(Utc::now()));
+        assert!(link.is_expired(Utc::now() + Duration::hours(2)));
+    }
+}
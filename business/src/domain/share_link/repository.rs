@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::ShareLink;
+
+#[async_trait]
+pub trait ShareLinkRepository: Send + Sync {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ShareLink>, RepositoryError>;
+    async fn save(&self, link: &ShareLink) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+}
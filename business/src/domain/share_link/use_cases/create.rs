@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::share_link::model::ShareLink;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct CreateShareLinkParams {
+    pub user_id: UserId,
+    pub include_expiring_products: bool,
+    pub expires_in_hours: i64,
+}
+
+/// A newly issued link along with its plain token.
+#[derive(Debug)]
+pub struct CreatedShareLink {
+    pub link: ShareLink,
+    pub token: String,
+}
+
+#[async_trait]
+pub trait CreateShareLinkUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: CreateShareLinkParams,
+    ) -> Result<CreatedShareLink, ShareLinkError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::product::model::Product;
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+pub struct GetSharedViewParams {
+    pub token: String,
+}
+
+/// Read-only snapshot exposed through a share link.
+#[derive(Debug)]
+pub struct SharedView {
+    pub shopping_items: Vec<ShoppingItem>,
+    /// Only present when the link was issued with expiring products enabled.
+    pub expiring_products: Option<Vec<Product>>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait GetSharedViewUseCase: Send + Sync {
+    async fn execute(&self, params: GetSharedViewParams) -> Result<SharedView, ShareLinkError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::share_link::errors::ShareLinkError;
+use crate::domain::shared::value_objects::UserId;
+
+pub struct RevokeShareLinkParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait RevokeShareLinkUseCase: Send + Sync {
+    async fn execute(&self, params: RevokeShareLinkParams) -> Result<(), ShareLinkError>;
+}
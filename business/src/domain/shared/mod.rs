@@ -1 +1,3 @@
-pub mod value_objects;
+//! Re-exports of the shared kernel under their historical paths.
+
+pub use shared_kernel::{pagination, value_objects};
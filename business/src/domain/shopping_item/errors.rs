@@ -1,3 +1,5 @@
+use crate::domain::errors::ErrorSource;
+
 #[derive(Debug, thiserror::Error)]
 pub enum ShoppingItemError {
     #[error("shopping_item.name_empty")]
@@ -6,6 +8,18 @@ pub enum ShoppingItemError {
     NotFound,
     #[error("shopping_item.already_exists")]
     AlreadyExists,
+    #[error("shopping_item.barcode_not_recognized")]
+    BarcodeNotRecognized(#[source] ErrorSource),
+    #[error("shopping_item.invalid_attachment")]
+    InvalidAttachment,
+    #[error(transparent)]
+    Storage(#[from] crate::domain::storage::errors::StorageError),
     #[error("repository.persistence")]
     Repository(#[from] crate::domain::errors::RepositoryError),
 }
+
+impl ShoppingItemError {
+    pub fn barcode_not_recognized(cause: impl Into<ErrorSource>) -> Self {
+        ShoppingItemError::BarcodeNotRecognized(cause.into())
+    }
+}
@@ -2,6 +2,10 @@ use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use super::errors::ShoppingItemError;
+use crate::domain::product::model::Product;
+use crate::domain::product::urgency::{
+    ExpiringSoonWindow, UrgencyLevel, days_until_expiry, get_urgency_level,
+};
 use crate::domain::shared::value_objects::UserId;
 
 #[derive(Debug, Clone)]
@@ -11,6 +15,8 @@ pub struct ShoppingItem {
     pub name: String,
     pub product_id: Option<Uuid>,
     pub is_bought: bool,
+    /// Media type of the attached photo or voice note, if any.
+    pub attachment_content_type: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,6 +38,7 @@ impl ShoppingItem {
             name,
             product_id,
             is_bought: false,
+            attachment_content_type: None,
             created_at: now,
             updated_at: now,
         })
@@ -53,10 +60,47 @@ impl ShoppingItem {
             name,
             product_id,
             is_bought,
+            attachment_content_type: None,
             created_at,
             updated_at,
         }
     }
+
+    pub fn has_attachment(&self) -> bool {
+        self.attachment_content_type.is_some()
+    }
+}
+
+/// A shopping item read together with the pantry product it is linked to.
+#[derive(Debug, Clone)]
+pub struct ShoppingItemView {
+    pub item: ShoppingItem,
+    /// `None` for free-text items and for items whose product no longer exists.
+    pub product: Option<Product>,
+    /// The owner's expiring-soon window the product urgency is judged against.
+    pub expiring_soon: ExpiringSoonWindow,
+}
+
+impl ShoppingItemView {
+    pub fn without_product(item: ShoppingItem) -> Self {
+        Self {
+            item,
+            product: None,
+            expiring_soon: ExpiringSoonWindow::default(),
+        }
+    }
+
+    /// How urgently the linked product should be used, so the list can warn
+    /// that one is already at home and about to expire.
+    pub fn product_urgency(&self) -> Option<UrgencyLevel> {
+        self.product
+            .as_ref()
+            .map(|product| get_urgency_level(product, self.expiring_soon))
+    }
+
+    pub fn product_days_until_expiry(&self) -> Option<i64> {
+        self.product.as_ref().and_then(days_until_expiry)
+    }
 }
 
 #[cfg(test)]
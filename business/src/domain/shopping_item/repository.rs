@@ -4,18 +4,30 @@ use uuid::Uuid;
 use crate::domain::errors::RepositoryError;
 use crate::domain::shared::value_objects::UserId;
 
-use super::model::ShoppingItem;
+use super::model::{ShoppingItem, ShoppingItemView};
 
 #[async_trait]
 pub trait ShoppingItemRepository: Send + Sync {
     async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError>;
+    /// Same order as `get_all`, with each item's linked product joined in.
+    async fn get_all_with_products(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<ShoppingItemView>, RepositoryError>;
     async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError>;
     async fn find_by_product_id(
         &self,
         product_id: Uuid,
         user_id: &UserId,
     ) -> Result<Option<ShoppingItem>, RepositoryError>;
-    async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+    async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+    /// Fails with `Duplicated` when unmarking an item as bought while the
+    /// product already has another unbought item.
+    async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError>;
+    /// Inserts a product-linked item unless the product already has an unbought
+    /// item, in which case that existing item is returned instead. Safe against
+    /// concurrent inserts for the same product.
+    async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError>;
     async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
     async fn delete_by_product_id(
         &self,
@@ -23,4 +35,11 @@ pub trait ShoppingItemRepository: Send + Sync {
         user_id: &UserId,
     ) -> Result<(), RepositoryError>;
     async fn delete_bought(&self, user_id: &UserId) -> Result<u64, RepositoryError>;
+    /// Items whose name contains `term`, case-insensitively, newest first.
+    async fn search(
+        &self,
+        user_id: &UserId,
+        term: &str,
+        limit: u32,
+    ) -> Result<Vec<ShoppingItem>, RepositoryError>;
 }
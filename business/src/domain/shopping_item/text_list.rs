@@ -0,0 +1,125 @@
+/// Longest item still read as one; anything longer is prose.
+const MAX_ITEM_CHARS: usize = 60;
+const MAX_ITEM_WORDS: usize = 6;
+
+/// Reads a shopping list written as plain text, such as the body of an
+/// email: one item per line or comma-separated, with bullets, numbering and
+/// checkboxes stripped. Greetings and headings (ending in `!`, `?` or `:`)
+/// and lines holding a sentence are skipped. Reading stops at a signature
+/// (`-- `) or the quoted message of a reply. Repeated items are kept once,
+/// and at most `max` are returned.
+pub fn parse_items(text: &str, max: usize) -> Vec<String> {
+    let mut items: Vec<String> = Vec::new();
+
+    for line in text.lines() {
+        if is_end_of_list(line) {
+            break;
+        }
+        if line.trim_start().starts_with('>') {
+            continue;
+        }
+
+        let names: Vec<&str> = line
+            .split([',', ';'])
+            .map(|part| strip_marker(part.trim()))
+            .filter(|name| !name.is_empty())
+            .collect();
+        if names.iter().any(|name| is_prose(name)) {
+            continue;
+        }
+
+        for name in names {
+            if name.ends_with([':', '!', '?']) {
+                continue;
+            }
+            if items.iter().any(|item| item.eq_ignore_ascii_case(name)) {
+                continue;
+            }
+            items.push(name.to_string());
+            if items.len() == max {
+                return items;
+            }
+        }
+    }
+    items
+}
+
+fn is_prose(name: &str) -> bool {
+    name.chars().count() > MAX_ITEM_CHARS || name.split_whitespace().count() > MAX_ITEM_WORDS
+}
+
+fn is_end_of_list(line: &str) -> bool {
+    let trimmed = line.trim();
+    line == "-- "
+        || trimmed == "--"
+        || trimmed.starts_with("-----Original Message")
+        || (trimmed.starts_with("On ") && trimmed.ends_with("wrote:"))
+        || (trimmed.starts_with("El ") && trimmed.ends_with("escribió:"))
+}
+
+/// Drops leading bullets (`-`, `*`, `•`), numbers (`1.`, `2)`) and
+/// checkboxes (`[ ]`, `[x]`), in any combination.
+fn strip_marker(part: &str) -> &str {
+    let mut rest = part;
+    loop {
+        let before = rest;
+        for marker in ["- ", "* ", "• ", "[ ]", "[x]", "[X]"] {
+            if let Some(stripped) = rest.strip_prefix(marker) {
+                rest = stripped.trim_start();
+            }
+        }
+
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        if digits > 0
+            && let Some(stripped) = rest[digits..]
+                .strip_prefix('.')
+                .or_else(|| rest[digits..].strip_prefix(')'))
+        {
+            rest = stripped.trim_start();
+        }
+
+        if rest == before {
+            return rest.trim();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_bulleted_and_numbered_lines() {
+        let text = "Hi!\n\nWe need:\n- Leche\n* huevos\n1. Pan de molde\n2) [ ] Tomates\n";
+
+        let items = parse_items(text, 10);
+
+        assert_eq!(items, vec!["Leche", "huevos", "Pan de molde", "Tomates"]);
+    }
+
+    #[test]
+    fn should_split_comma_separated_items_and_skip_repeats() {
+        let items = parse_items("milk, eggs; bread\nMilk\n", 10);
+
+        assert_eq!(items, vec!["milk", "eggs", "bread"]);
+    }
+
+    #[test]
+    fn should_stop_at_signature_and_quoted_reply() {
+        let text = "apples\n-- \nAna\n";
+        let reply = "pears\nOn Mon, 9 Mar 2026, Ana wrote:\n> apples\n";
+
+        assert_eq!(parse_items(text, 10), vec!["apples"]);
+        assert_eq!(parse_items(reply, 10), vec!["pears"]);
+    }
+
+    #[test]
+    fn should_skip_prose_and_stop_at_the_limit() {
+        let text =
+            "Could you please pick these up on the way home tonight, thanks a lot\na\nb\nc\n";
+
+        let items = parse_items(text, 2);
+
+        assert_eq!(items, vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::storage::model::SignedUrl;
+
+pub struct AttachToShoppingItemParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub attachment_base64: String,
+}
+
+#[async_trait]
+pub trait AttachToShoppingItemUseCase: Send + Sync {
+    /// Stores a photo or voice note for the item, replacing any previous one,
+    /// and returns a URL to download it.
+    async fn execute(
+        &self,
+        params: AttachToShoppingItemParams,
+    ) -> Result<SignedUrl, ShoppingItemError>;
+}
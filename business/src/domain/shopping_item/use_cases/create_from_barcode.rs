@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+pub struct CreateShoppingItemFromBarcodeParams {
+    pub user_id: UserId,
+    pub barcode: String,
+}
+
+#[async_trait]
+pub trait CreateShoppingItemFromBarcodeUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: CreateShoppingItemFromBarcodeParams,
+    ) -> Result<ShoppingItem, ShoppingItemError>;
+}
@@ -2,10 +2,23 @@ use async_trait::async_trait;
 
 use crate::domain::shared::value_objects::UserId;
 use crate::domain::shopping_item::errors::ShoppingItemError;
-use crate::domain::shopping_item::model::ShoppingItem;
+use crate::domain::shopping_item::model::ShoppingItemView;
+
+/// Order of the returned shopping list.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum ShoppingItemSort {
+    /// Newest first.
+    #[default]
+    CreatedAt,
+    /// Walking order of the active store profile; newest first without one.
+    StoreOrder,
+}
 
 pub struct GetAllShoppingItemsParams {
     pub user_id: UserId,
+    pub sort: ShoppingItemSort,
+    /// Join each item's linked product (status and urgency) into the result
+    pub include_product: bool,
 }
 
 #[async_trait]
@@ -13,5 +26,5 @@ pub trait GetAllShoppingItemsUseCase: Send + Sync {
     async fn execute(
         &self,
         params: GetAllShoppingItemsParams,
-    ) -> Result<Vec<ShoppingItem>, ShoppingItemError>;
+    ) -> Result<Vec<ShoppingItemView>, ShoppingItemError>;
 }
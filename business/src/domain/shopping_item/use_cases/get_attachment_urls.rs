@@ -0,0 +1,22 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::errors::ShoppingItemError;
+use crate::domain::storage::model::SignedUrl;
+
+pub struct GetAttachmentUrlsParams {
+    pub user_id: UserId,
+    /// Items known to have an attachment
+    pub item_ids: Vec<Uuid>,
+}
+
+#[async_trait]
+pub trait GetAttachmentUrlsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetAttachmentUrlsParams,
+    ) -> Result<HashMap<Uuid, SignedUrl>, ShoppingItemError>;
+}
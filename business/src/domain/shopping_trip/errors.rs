@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ShoppingTripError {
+    #[error("shopping_trip.not_in_progress")]
+    NotInProgress,
+    #[error("shopping_trip.empty_list")]
+    EmptyList,
+    #[error("shopping_trip.item_not_in_trip")]
+    ItemNotInTrip,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
@@ -0,0 +1,11 @@
+use crate::domain::shared::value_objects::UserId;
+
+/// An item was recorded as bought on a shopping trip at a price that added
+/// to what the user spent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PurchaseRecorded {
+    pub user_id: UserId,
+    /// What the recording added to the spend; re-recording an item only adds
+    /// the difference with its earlier price
+    pub added_cents: u64,
+}
@@ -0,0 +1,254 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::errors::ShoppingTripError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+/// Lifecycle state of a shopping trip.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShoppingTripStatus {
+    InProgress,
+    Finished,
+}
+
+impl std::fmt::Display for ShoppingTripStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShoppingTripStatus::InProgress => write!(f, "in_progress"),
+            ShoppingTripStatus::Finished => write!(f, "finished"),
+        }
+    }
+}
+
+impl std::str::FromStr for ShoppingTripStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "in_progress" => Ok(ShoppingTripStatus::InProgress),
+            "finished" => Ok(ShoppingTripStatus::Finished),
+            _ => Err(format!("Invalid shopping trip status: {}", s)),
+        }
+    }
+}
+
+/// A shopping list item as it was when the trip started, and whether it was
+/// bought during the trip.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TripItem {
+    pub shopping_item_id: Uuid,
+    pub name: String,
+    pub bought_at: Option<DateTime<Utc>>,
+    /// What the user paid, in cents of their currency
+    pub price_cents: Option<u32>,
+}
+
+/// Totals of a trip, stored when it finishes for spending analytics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TripSummary {
+    /// Items on the list when the trip started
+    pub items_planned: u32,
+    pub items_bought: u32,
+    /// Sum of the recorded prices; items bought without a price add nothing
+    pub total_spend_cents: u64,
+}
+
+/// A user going through their shopping list at the store.
+///
+/// The trip keeps its own snapshot of the list, so items added or removed
+/// meanwhile don't change what it reports.
+#[derive(Debug, Clone)]
+pub struct ShoppingTrip {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub items: Vec<TripItem>,
+    pub status: ShoppingTripStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl ShoppingTrip {
+    /// Starts a trip over the items of the list not bought yet.
+    pub fn start(user_id: UserId, list: &[ShoppingItem]) -> Result<Self, ShoppingTripError> {
+        let items: Vec<TripItem> = list
+            .iter()
+            .filter(|item| !item.is_bought)
+            .map(|item| TripItem {
+                shopping_item_id: item.id,
+                name: item.name.clone(),
+                bought_at: None,
+                price_cents: None,
+            })
+            .collect();
+
+        if items.is_empty() {
+            return Err(ShoppingTripError::EmptyList);
+        }
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            items,
+            status: ShoppingTripStatus::InProgress,
+            started_at: Utc::now(),
+            finished_at: None,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        items: Vec<TripItem>,
+        status: ShoppingTripStatus,
+        started_at: DateTime<Utc>,
+        finished_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            items,
+            status,
+            started_at,
+            finished_at,
+        }
+    }
+
+    /// Records an item as bought now. Recording it again replaces the price.
+    pub fn record_bought(
+        &mut self,
+        shopping_item_id: Uuid,
+        price_cents: Option<u32>,
+    ) -> Result<&TripItem, ShoppingTripError> {
+        self.ensure_in_progress()?;
+
+        let item = self
+            .items
+            .iter_mut()
+            .find(|item| item.shopping_item_id == shopping_item_id)
+            .ok_or(ShoppingTripError::ItemNotInTrip)?;
+        item.bought_at.get_or_insert_with(Utc::now);
+        item.price_cents = price_cents;
+        Ok(item)
+    }
+
+    pub fn finish(&mut self) -> Result<TripSummary, ShoppingTripError> {
+        self.ensure_in_progress()?;
+
+        self.status = ShoppingTripStatus::Finished;
+        self.finished_at = Some(Utc::now());
+        Ok(self.summary())
+    }
+
+    pub fn summary(&self) -> TripSummary {
+        let bought = self.items.iter().filter(|item| item.bought_at.is_some());
+        TripSummary {
+            items_planned: self.items.len() as u32,
+            items_bought: bought.clone().count() as u32,
+            total_spend_cents: bought
+                .filter_map(|item| item.price_cents)
+                .map(u64::from)
+                .sum(),
+        }
+    }
+
+    fn ensure_in_progress(&self) -> Result<(), ShoppingTripError> {
+        if self.status != ShoppingTripStatus::InProgress {
+            return Err(ShoppingTripError::NotInProgress);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn list(names: &[(&str, bool)]) -> Vec<ShoppingItem> {
+        names
+            .iter()
+            .map(|(name, is_bought)| {
+                let mut item = ShoppingItem::new(test_user_id(), name.to_string(), None).unwrap();
+                item.is_bought = *is_bought;
+                item
+            })
+            .collect()
+    }
+
+    #[test]
+    fn should_snapshot_only_unbought_items_when_starting() {
+        let trip = ShoppingTrip::start(
+            test_user_id(),
+            &list(&[("Leche", false), ("Pan", true), ("Huevos", false)]),
+        )
+        .unwrap();
+
+        let names: Vec<_> = trip.items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["Leche", "Huevos"]);
+        assert_eq!(trip.status, ShoppingTripStatus::InProgress);
+    }
+
+    #[test]
+    fn should_reject_trip_when_nothing_left_to_buy() {
+        let result = ShoppingTrip::start(test_user_id(), &list(&[("Pan", true)]));
+
+        assert!(matches!(result, Err(ShoppingTripError::EmptyList)));
+    }
+
+    #[test]
+    fn should_sum_recorded_prices_in_summary() {
+        let mut trip = ShoppingTrip::start(
+            test_user_id(),
+            &list(&[("Leche", false), ("Pan", false), ("Huevos", false)]),
+        )
+        .unwrap();
+        let (leche, pan) = (
+            trip.items[0].shopping_item_id,
+            trip.items[1].shopping_item_id,
+        );
+
+        trip.record_bought(leche, Some(115)).unwrap();
+        trip.record_bought(pan, None).unwrap();
+        let summary = trip.finish().unwrap();
+
+        assert_eq!(
+            summary,
+            TripSummary {
+                items_planned: 3,
+                items_bought: 2,
+                total_spend_cents: 115,
+            }
+        );
+        assert!(trip.finished_at.is_some());
+    }
+
+    #[test]
+    fn should_reject_recording_item_not_in_snapshot() {
+        let mut trip = ShoppingTrip::start(test_user_id(), &list(&[("Leche", false)])).unwrap();
+
+        let result = trip.record_bought(Uuid::new_v4(), None);
+
+        assert!(matches!(result, Err(ShoppingTripError::ItemNotInTrip)));
+    }
+
+    #[test]
+    fn should_reject_changes_after_finishing() {
+        let mut trip = ShoppingTrip::start(test_user_id(), &list(&[("Leche", false)])).unwrap();
+        let leche = trip.items[0].shopping_item_id;
+        trip.finish().unwrap();
+
+        assert!(matches!(
+            trip.record_bought(leche, Some(100)),
+            Err(ShoppingTripError::NotInProgress)
+        ));
+        assert!(matches!(
+            trip.finish(),
+            Err(ShoppingTripError::NotInProgress)
+        ));
+    }
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::ShoppingTrip;
+
+#[async_trait]
+pub trait ShoppingTripRepository: Send + Sync {
+    /// The user's unfinished trip; there is at most one.
+    async fn find_in_progress(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<ShoppingTrip>, RepositoryError>;
+    async fn insert(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+    /// Also stores the trip summary once the trip is finished.
+    async fn update(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+
+pub struct FinishShoppingTripParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait FinishShoppingTripUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: FinishShoppingTripParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError>;
+}
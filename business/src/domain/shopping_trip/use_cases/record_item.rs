@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+
+pub struct RecordTripItemParams {
+    pub user_id: UserId,
+    pub shopping_item_id: Uuid,
+    /// What the user paid, in cents of their currency
+    pub price_cents: Option<u32>,
+}
+
+#[async_trait]
+pub trait RecordTripItemUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: RecordTripItemParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_trip::errors::ShoppingTripError;
+use crate::domain::shopping_trip::model::ShoppingTrip;
+
+pub struct StartShoppingTripParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait StartShoppingTripUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: StartShoppingTripParams,
+    ) -> Result<ShoppingTrip, ShoppingTripError>;
+}
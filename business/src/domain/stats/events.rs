@@ -0,0 +1,14 @@
+use chrono::NaiveDate;
+
+use crate::domain::shared::value_objects::UserId;
+
+/// A user's zero-waste streak reached one of the milestones worth
+/// congratulating them on.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreakMilestoneReached {
+    pub user_id: UserId,
+    /// Zero-waste weeks in a row
+    pub weeks: u32,
+    /// Monday of the week that reached the milestone
+    pub week: NaiveDate,
+}
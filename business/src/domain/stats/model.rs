@@ -0,0 +1,532 @@
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, Utc};
+
+use crate::domain::product::model::Product;
+use crate::domain::product::urgency::{ExpiringSoonWindow, UrgencyLevel, get_urgency_level};
+use crate::domain::product::value_objects::{ProductLocation, ProductStatus};
+
+/// Money value of a group of products, in cents of the configured currency.
+///
+/// Products are valued at the latest price paid for an item with the same
+/// name on a shopping trip; products never bought with a price count in
+/// `products` but add nothing to `value_cents`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ProductValue {
+    pub products: u32,
+    /// Products a price was found for
+    pub priced_products: u32,
+    pub value_cents: u64,
+}
+
+impl std::iter::Sum for ProductValue {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, v| Self {
+            products: total.products + v.products,
+            priced_products: total.priced_products + v.priced_products,
+            value_cents: total.value_cents + v.value_cents,
+        })
+    }
+}
+
+/// Products finished without being used during one calendar month (UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MonthlyOutcomes {
+    /// First day of the month
+    pub month: NaiveDate,
+    pub wasted: ProductValue,
+    pub given_away: ProductValue,
+}
+
+impl MonthlyOutcomes {
+    fn empty(month: NaiveDate) -> Self {
+        Self {
+            month,
+            wasted: ProductValue::default(),
+            given_away: ProductValue::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InventoryValue {
+    /// Products not finished yet
+    pub active: ProductValue,
+    /// One entry per month of the period, oldest first
+    pub by_month: Vec<MonthlyOutcomes>,
+}
+
+/// Calendar months covered by the outcome history, the current one included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WastePeriod(u32);
+
+impl WastePeriod {
+    pub const DEFAULT_MONTHS: u32 = 6;
+    pub const MAX_MONTHS: u32 = 24;
+
+    /// Clamps `months` to 1..=24.
+    pub fn new(months: u32) -> Self {
+        Self(months.clamp(1, Self::MAX_MONTHS))
+    }
+
+    pub fn months(&self) -> u32 {
+        self.0
+    }
+
+    /// First day of each month in the period, oldest first.
+    pub fn month_starts(&self, now: DateTime<Utc>) -> Vec<NaiveDate> {
+        let current = now.date_naive().with_day(1).unwrap_or(now.date_naive());
+        (0..self.0)
+            .rev()
+            .map(|back| current - Months::new(back))
+            .collect()
+    }
+
+    /// Products finished on or after this instant fall within the period.
+    pub fn start_at(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let first = self.month_starts(now)[0];
+        first.and_time(NaiveTime::MIN).and_utc()
+    }
+}
+
+impl Default for WastePeriod {
+    fn default() -> Self {
+        Self(Self::DEFAULT_MONTHS)
+    }
+}
+
+/// Lays the months the repository found outcomes in over every month of the
+/// period, so quiet months show up as zero.
+pub fn fill_months(
+    period: WastePeriod,
+    now: DateTime<Utc>,
+    found: &[MonthlyOutcomes],
+) -> Vec<MonthlyOutcomes> {
+    period
+        .month_starts(now)
+        .into_iter()
+        .map(|month| {
+            found
+                .iter()
+                .find(|outcomes| outcomes.month == month)
+                .copied()
+                .unwrap_or(MonthlyOutcomes::empty(month))
+        })
+        .collect()
+}
+
+/// Products finished during one week, Monday to Sunday (UTC), by outcome.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeeklyOutcomes {
+    /// Monday the week starts on
+    pub week: NaiveDate,
+    pub used: u32,
+    pub thrown_away: u32,
+    pub given_away: u32,
+}
+
+impl WeeklyOutcomes {
+    pub fn empty(week: NaiveDate) -> Self {
+        Self {
+            week,
+            used: 0,
+            thrown_away: 0,
+            given_away: 0,
+        }
+    }
+
+    pub fn finished(&self) -> u32 {
+        self.used + self.thrown_away + self.given_away
+    }
+
+    /// Share of the finished products that were thrown away, 0 when nothing
+    /// was finished.
+    pub fn waste_ratio(&self) -> f64 {
+        match self.finished() {
+            0 => 0.0,
+            finished => f64::from(self.thrown_away) / f64::from(finished),
+        }
+    }
+
+    /// Products were finished and none of them thrown away. A week where
+    /// nothing was finished says nothing about waste, so it is not one.
+    pub fn zero_waste(&self) -> bool {
+        self.finished() > 0 && self.thrown_away == 0
+    }
+}
+
+/// Streak lengths, in weeks, the user is congratulated on.
+pub const STREAK_MILESTONES: [u32; 6] = [2, 4, 8, 12, 26, 52];
+
+/// A user's run of zero-waste weeks, as of the last week the stats job
+/// closed for them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WasteStreak {
+    /// Zero-waste weeks in a row up to `last_week`
+    pub current: u32,
+    pub longest: u32,
+    /// Monday of the last week counted; `None` until the job first runs
+    pub last_week: Option<NaiveDate>,
+}
+
+impl WasteStreak {
+    /// Weeks looked back on the first time a user's streak is computed.
+    pub const LOOKBACK_WEEKS: u32 = 52;
+    /// Closed weeks returned along with the streak.
+    pub const HISTORY_WEEKS: u32 = 12;
+
+    /// Mondays of the weeks that ended after `last_week` and before the week
+    /// containing `now`, oldest first.
+    pub fn weeks_to_close(&self, now: DateTime<Utc>) -> Vec<NaiveDate> {
+        let current = week_of(now.date_naive());
+        let mut week = match self.last_week {
+            Some(last) => last + Days::new(7),
+            None => current - Days::new(7 * u64::from(Self::LOOKBACK_WEEKS)),
+        };
+
+        let mut weeks = Vec::new();
+        while week < current {
+            weeks.push(week);
+            week = week + Days::new(7);
+        }
+        weeks
+    }
+
+    /// Counts the closed `weeks` in order, skipping any already counted.
+    /// Returns the highest milestone the streak reached along the way.
+    pub fn close(&mut self, weeks: &[WeeklyOutcomes]) -> Option<u32> {
+        let mut reached = None;
+        for outcomes in weeks {
+            if self.last_week.is_some_and(|last| outcomes.week <= last) {
+                continue;
+            }
+
+            let follows = self
+                .last_week
+                .is_some_and(|last| last + Days::new(7) == outcomes.week);
+            self.current = match (outcomes.zero_waste(), follows) {
+                (false, _) => 0,
+                (true, true) => self.current + 1,
+                (true, false) => 1,
+            };
+            self.longest = self.longest.max(self.current);
+            self.last_week = Some(outcomes.week);
+
+            if STREAK_MILESTONES.contains(&self.current) {
+                reached = Some(self.current);
+            }
+        }
+        reached
+    }
+
+    /// Monday of the oldest week in the history shown with the streak.
+    pub fn history_start(now: DateTime<Utc>) -> NaiveDate {
+        week_of(now.date_naive()) - Days::new(7 * u64::from(Self::HISTORY_WEEKS))
+    }
+}
+
+/// A streak with the weeks behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WasteStreakHistory {
+    pub streak: WasteStreak,
+    /// Closed weeks, oldest first
+    pub weeks: Vec<WeeklyOutcomes>,
+}
+
+/// Lays the weeks the repository found outcomes in over every week to close,
+/// so weeks where nothing was finished show up as empty.
+pub fn fill_weeks(weeks: &[NaiveDate], found: &[WeeklyOutcomes]) -> Vec<WeeklyOutcomes> {
+    weeks
+        .iter()
+        .map(|&week| {
+            found
+                .iter()
+                .find(|outcomes| outcomes.week == week)
+                .copied()
+                .unwrap_or(WeeklyOutcomes::empty(week))
+        })
+        .collect()
+}
+
+/// Monday of the week containing `date`.
+fn week_of(date: NaiveDate) -> NaiveDate {
+    date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+}
+
+/// Active products by lifecycle status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct StatusCounts {
+    pub new: u32,
+    pub opened: u32,
+    pub almost_empty: u32,
+}
+
+/// Active products by storage location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LocationCounts {
+    pub fridge: u32,
+    pub pantry: u32,
+    pub freezer: u32,
+    /// Products with no location set
+    pub unassigned: u32,
+}
+
+/// A user's pantry aggregates on one day, recorded nightly for trend charts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InventorySnapshot {
+    pub date: NaiveDate,
+    pub by_status: StatusCounts,
+    pub by_location: LocationCounts,
+    /// Products to use today or within the user's expiring-soon window
+    pub urgent: u32,
+    /// Products thrown away since the user started
+    pub wasted_to_date: ProductValue,
+    /// The user was on vacation when the snapshot was taken
+    pub on_vacation: bool,
+}
+
+impl InventorySnapshot {
+    /// Aggregates the active `products`; finished ones are ignored.
+    pub fn capture(
+        date: NaiveDate,
+        products: &[Product],
+        window: ExpiringSoonWindow,
+        wasted_to_date: ProductValue,
+    ) -> Self {
+        let mut by_status = StatusCounts::default();
+        let mut by_location = LocationCounts::default();
+        let mut urgent = 0;
+
+        for product in products {
+            match product.status {
+                ProductStatus::New => by_status.new += 1,
+                ProductStatus::Opened => by_status.opened += 1,
+                ProductStatus::AlmostEmpty => by_status.almost_empty += 1,
+                ProductStatus::Finished => continue,
+            }
+            match product.location {
+                Some(ProductLocation::Fridge) => by_location.fridge += 1,
+                Some(ProductLocation::Pantry) => by_location.pantry += 1,
+                Some(ProductLocation::Freezer) => by_location.freezer += 1,
+                None => by_location.unassigned += 1,
+            }
+            if matches!(
+                get_urgency_level(product, window),
+                UrgencyLevel::UseToday | UrgencyLevel::UseSoon
+            ) {
+                urgent += 1;
+            }
+        }
+
+        Self {
+            date,
+            by_status,
+            by_location,
+            urgent,
+            wasted_to_date,
+            on_vacation: false,
+        }
+    }
+}
+
+/// Days of snapshots a trend covers, today included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrendPeriod(u32);
+
+impl TrendPeriod {
+    pub const DEFAULT_DAYS: u32 = 30;
+    pub const MAX_DAYS: u32 = 365;
+
+    /// Clamps `days` to 1..=365.
+    pub fn new(days: u32) -> Self {
+        Self(days.clamp(1, Self::MAX_DAYS))
+    }
+
+    pub fn days(&self) -> u32 {
+        self.0
+    }
+
+    /// Snapshots taken on or after this date fall within the period.
+    pub fn start_date(&self, now: DateTime<Utc>) -> NaiveDate {
+        now.date_naive() - Days::new(u64::from(self.0 - 1))
+    }
+}
+
+impl Default for TrendPeriod {
+    fn default() -> Self {
+        Self(Self::DEFAULT_DAYS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::value_objects::ExpiryType;
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, TimeZone};
+    use uuid::Uuid;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn should_cover_months_back_across_year_boundary() {
+        let now = Utc.with_ymd_and_hms(2026, 2, 17, 10, 0, 0).unwrap();
+
+        let months = WastePeriod::new(3).month_starts(now);
+
+        assert_eq!(
+            months,
+            vec![date(2025, 12, 1), date(2026, 1, 1), date(2026, 2, 1)]
+        );
+        assert_eq!(
+            WastePeriod::new(3).start_at(now),
+            Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_clamp_period_length() {
+        assert_eq!(WastePeriod::new(0).months(), 1);
+        assert_eq!(WastePeriod::new(100).months(), WastePeriod::MAX_MONTHS);
+    }
+
+    #[test]
+    fn should_fill_quiet_months_with_zero() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 5, 0, 0, 0).unwrap();
+        let january = MonthlyOutcomes {
+            month: date(2026, 1, 1),
+            wasted: ProductValue {
+                products: 2,
+                priced_products: 1,
+                value_cents: 250,
+            },
+            given_away: ProductValue::default(),
+        };
+
+        let months = fill_months(WastePeriod::new(3), now, &[january]);
+
+        assert_eq!(months.len(), 3);
+        assert_eq!(months[0], january);
+        assert_eq!(months[1], MonthlyOutcomes::empty(date(2026, 2, 1)));
+        assert_eq!(months[2].month, date(2026, 3, 1));
+    }
+
+    fn week(day: u32, used: u32, thrown_away: u32) -> WeeklyOutcomes {
+        WeeklyOutcomes {
+            week: date(2026, 3, day),
+            used,
+            thrown_away,
+            given_away: 0,
+        }
+    }
+
+    #[test]
+    fn should_close_weeks_since_last_counted_up_to_current() {
+        let streak = WasteStreak {
+            current: 1,
+            longest: 1,
+            last_week: Some(date(2026, 2, 23)),
+        };
+        let wednesday = Utc.with_ymd_and_hms(2026, 3, 18, 9, 0, 0).unwrap();
+
+        let weeks = streak.weeks_to_close(wednesday);
+
+        assert_eq!(weeks, vec![date(2026, 3, 2), date(2026, 3, 9)]);
+        assert_eq!(
+            WasteStreak::default().weeks_to_close(wednesday).len(),
+            WasteStreak::LOOKBACK_WEEKS as usize
+        );
+    }
+
+    #[test]
+    fn should_break_streak_on_waste_or_idle_week() {
+        let mut streak = WasteStreak::default();
+
+        let reached = streak.close(&[week(2, 3, 0), week(9, 1, 0), week(16, 2, 1)]);
+        assert_eq!(reached, Some(2));
+        assert_eq!((streak.current, streak.longest), (0, 2));
+
+        streak.close(&[week(23, 4, 0), week(30, 0, 0)]);
+        assert_eq!((streak.current, streak.longest), (0, 2));
+        assert_eq!(streak.last_week, Some(date(2026, 3, 30)));
+    }
+
+    #[test]
+    fn should_not_count_a_week_twice() {
+        let mut streak = WasteStreak::default();
+        streak.close(&[week(2, 1, 0)]);
+
+        let reached = streak.close(&[week(2, 1, 0), week(9, 1, 0)]);
+
+        assert_eq!(reached, Some(2));
+        assert_eq!(streak.current, 2);
+        assert_eq!(week(9, 1, 3).waste_ratio(), 0.75);
+    }
+
+    fn product(status: ProductStatus, location: Option<ProductLocation>, days: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            "Yogur".to_string(),
+            status,
+            location,
+            None,
+            Some(Utc::now() + Duration::days(days)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn should_count_active_products_in_snapshot() {
+        let products = vec![
+            product(ProductStatus::Opened, Some(ProductLocation::Fridge), 1),
+            product(ProductStatus::New, Some(ProductLocation::Pantry), 60),
+            product(ProductStatus::AlmostEmpty, None, 1),
+            product(ProductStatus::Finished, Some(ProductLocation::Fridge), 1),
+        ];
+        let wasted = ProductValue {
+            products: 4,
+            priced_products: 2,
+            value_cents: 520,
+        };
+
+        let snapshot = InventorySnapshot::capture(
+            date(2026, 3, 5),
+            &products,
+            ExpiringSoonWindow::default(),
+            wasted,
+        );
+
+        assert_eq!(
+            snapshot.by_status,
+            StatusCounts {
+                new: 1,
+                opened: 1,
+                almost_empty: 1,
+            }
+        );
+        assert_eq!(
+            snapshot.by_location,
+            LocationCounts {
+                fridge: 1,
+                pantry: 1,
+                freezer: 0,
+                unassigned: 1,
+            }
+        );
+        assert_eq!(snapshot.urgent, 2);
+        assert_eq!(snapshot.wasted_to_date, wasted);
+    }
+
+    #[test]
+    fn should_start_trend_period_days_back_including_today() {
+        let now = Utc.with_ymd_and_hms(2026, 3, 5, 10, 0, 0).unwrap();
+
+        assert_eq!(TrendPeriod::new(7).start_date(now), date(2026, 2, 27));
+        assert_eq!(TrendPeriod::new(0).start_date(now), date(2026, 3, 5));
+    }
+}
@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+
+use super::model::{InventorySnapshot, MonthlyOutcomes, ProductValue, WasteStreak, WeeklyOutcomes};
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+/// Read model for pantry statistics, computed with aggregate queries.
+#[async_trait]
+pub trait StatsRepository: Send + Sync {
+    /// Value of the products that are not finished.
+    async fn get_active_value(&self, user_id: &UserId) -> Result<ProductValue, RepositoryError>;
+    /// Value of the products thrown away and given away since `since`,
+    /// grouped by calendar month (UTC). Months with neither are left out.
+    async fn get_outcomes_by_month(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<MonthlyOutcomes>, RepositoryError>;
+    /// Products finished from `since` up to `until`, grouped by week
+    /// (Monday, UTC). Weeks where nothing was finished are left out.
+    async fn get_outcomes_by_week(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+}
+
+/// Daily pantry snapshots behind the trend charts.
+#[async_trait]
+pub trait InventorySnapshotRepository: Send + Sync {
+    /// Replaces any snapshot the user already has for the same date.
+    async fn save(
+        &self,
+        user_id: &UserId,
+        snapshot: &InventorySnapshot,
+    ) -> Result<(), RepositoryError>;
+    /// Snapshots taken on or after `since`, oldest first.
+    async fn find_since(
+        &self,
+        user_id: &UserId,
+        since: NaiveDate,
+    ) -> Result<Vec<InventorySnapshot>, RepositoryError>;
+}
+
+/// Zero-waste streaks and the weekly outcomes they were counted from.
+#[async_trait]
+pub trait WasteStreakRepository: Send + Sync {
+    /// The user's streak, or an empty one before the job first ran for them.
+    async fn get(&self, user_id: &UserId) -> Result<WasteStreak, RepositoryError>;
+    /// Stores the weeks just closed together with the streak they led to.
+    async fn save(
+        &self,
+        user_id: &UserId,
+        streak: &WasteStreak,
+        weeks: &[WeeklyOutcomes],
+    ) -> Result<(), RepositoryError>;
+    /// Closed weeks starting on or after `since`, oldest first.
+    async fn find_weeks_since(
+        &self,
+        user_id: &UserId,
+        since: NaiveDate,
+    ) -> Result<Vec<WeeklyOutcomes>, RepositoryError>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::{InventorySnapshot, TrendPeriod};
+
+pub struct GetInventoryTrendsParams {
+    pub user_id: UserId,
+    pub period: TrendPeriod,
+}
+
+#[async_trait]
+pub trait GetInventoryTrendsUseCase: Send + Sync {
+    /// Snapshots of the period, oldest first. Days the job did not run for
+    /// the user are missing.
+    async fn execute(
+        &self,
+        params: GetInventoryTrendsParams,
+    ) -> Result<Vec<InventorySnapshot>, StatsError>;
+}
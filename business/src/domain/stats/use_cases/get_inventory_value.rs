@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::{InventoryValue, WastePeriod};
+
+pub struct GetInventoryValueParams {
+    pub user_id: UserId,
+    pub period: WastePeriod,
+}
+
+#[async_trait]
+pub trait GetInventoryValueUseCase: Send + Sync {
+    async fn execute(&self, params: GetInventoryValueParams) -> Result<InventoryValue, StatsError>;
+}
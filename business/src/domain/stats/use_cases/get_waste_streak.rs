@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::stats::errors::StatsError;
+use crate::domain::stats::model::WasteStreakHistory;
+
+pub struct GetWasteStreakParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetWasteStreakUseCase: Send + Sync {
+    /// The streak and the recent weeks behind it, as of the last run of the
+    /// stats job.
+    async fn execute(&self, params: GetWasteStreakParams)
+    -> Result<WasteStreakHistory, StatsError>;
+}
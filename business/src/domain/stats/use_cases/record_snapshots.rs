@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use crate::domain::stats::errors::StatsError;
+
+pub struct RecordInventorySnapshotsParams {
+    /// Users loaded at a time. Each run walks every user with active
+    /// products, a batch at a time.
+    pub batch_size: usize,
+}
+
+/// Outcome of a snapshot run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SnapshotRunSummary {
+    pub recorded: usize,
+    pub failed: usize,
+}
+
+#[async_trait]
+pub trait RecordInventorySnapshotsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: RecordInventorySnapshotsParams,
+    ) -> Result<SnapshotRunSummary, StatsError>;
+}
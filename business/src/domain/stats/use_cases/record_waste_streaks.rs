@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+use crate::domain::stats::errors::StatsError;
+
+pub struct RecordWasteStreaksParams {
+    /// Users loaded at a time. Each run walks every user with active
+    /// products, a batch at a time.
+    pub batch_size: usize,
+}
+
+/// Outcome of a streak run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreakRunSummary {
+    pub recorded: usize,
+    /// Users whose streak reached a milestone
+    pub milestones: usize,
+    pub failed: usize,
+}
+
+#[async_trait]
+pub trait RecordWasteStreaksUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: RecordWasteStreaksParams,
+    ) -> Result<StreakRunSummary, StatsError>;
+}
@@ -0,0 +1,19 @@
+use crate::domain::errors::ErrorSource;
+
+#[derive(Debug, thiserror::Error)]
+pub enum StorageError {
+    #[error("storage.not_found")]
+    NotFound,
+    #[error("storage.invalid_signature")]
+    InvalidSignature,
+    #[error("storage.unreadable_image")]
+    UnreadableImage,
+    #[error("storage.unavailable")]
+    Unavailable(#[source] ErrorSource),
+}
+
+impl StorageError {
+    pub fn unavailable(cause: impl Into<ErrorSource>) -> Self {
+        StorageError::Unavailable(cause.into())
+    }
+}
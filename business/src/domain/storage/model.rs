@@ -0,0 +1,193 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+
+/// An object read back from storage.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredObject {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+}
+
+/// A download URL that stops working at `expires_at`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignedUrl {
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Media type of every thumbnail.
+pub const THUMBNAIL_CONTENT_TYPE: &str = "image/jpeg";
+
+/// A decoded photo and its media type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageUpload {
+    pub bytes: Vec<u8>,
+    pub content_type: &'static str,
+}
+
+impl ImageUpload {
+    /// Decodes a base64 photo as the apps send it, with or without a
+    /// `data:image/...;base64,` prefix.
+    ///
+    /// Business rules:
+    /// - Only JPEG, PNG and WebP are accepted, recognized by their content
+    ///   rather than by what the client claims
+    pub fn from_base64(image_base64: &str) -> Option<Self> {
+        let bytes = decode_base64(image_base64)?;
+        let content_type = image_content_type(&bytes)?;
+        Some(Self {
+            bytes,
+            content_type,
+        })
+    }
+}
+
+/// A decoded photo or voice note attached to a shopping item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttachmentUpload {
+    pub bytes: Vec<u8>,
+    pub content_type: &'static str,
+}
+
+impl AttachmentUpload {
+    /// Decodes a base64 attachment, with or without a data URL prefix.
+    ///
+    /// Business rules:
+    /// - Photos are accepted as for product images; voice notes as MP3, AAC
+    ///   (MP4 container), Ogg, WAV or WebM, recognized by their content
+    pub fn from_base64(attachment_base64: &str) -> Option<Self> {
+        let bytes = decode_base64(attachment_base64)?;
+        let content_type = image_content_type(&bytes).or_else(|| audio_content_type(&bytes))?;
+        Some(Self {
+            bytes,
+            content_type,
+        })
+    }
+}
+
+fn decode_base64(encoded: &str) -> Option<Vec<u8>> {
+    let data = encoded
+        .split_once(";base64,")
+        .map_or(encoded, |(_, data)| data);
+    let clean: String = data.chars().filter(|c| !c.is_whitespace()).collect();
+    STANDARD.decode(clean).ok()
+}
+
+fn image_content_type(bytes: &[u8]) -> Option<&'static str> {
+    match bytes {
+        [0xFF, 0xD8, 0xFF, ..] => Some("image/jpeg"),
+        [0x89, b'P', b'N', b'G', ..] => Some("image/png"),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => Some("image/webp"),
+        _ => None,
+    }
+}
+
+fn audio_content_type(bytes: &[u8]) -> Option<&'static str> {
+    match bytes {
+        [b'I', b'D', b'3', ..] | [0xFF, 0xFB | 0xF3 | 0xF2, ..] => Some("audio/mpeg"),
+        [b'O', b'g', b'g', b'S', ..] => Some("audio/ogg"),
+        [_, _, _, _, b'f', b't', b'y', b'p', ..] => Some("audio/mp4"),
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'A',
+            b'V',
+            b'E',
+            ..,
+        ] => Some("audio/wav"),
+        [0x1A, 0x45, 0xDF, 0xA3, ..] => Some("audio/webm"),
+        _ => None,
+    }
+}
+
+/// Where a product's photo is stored.
+pub fn product_image_key(user_id: &UserId, product_id: Uuid) -> String {
+    format!("products/{}/{}", user_id.as_str(), product_id)
+}
+
+/// Where the photo of an imported receipt is stored.
+pub fn receipt_image_key(user_id: &UserId, import_id: Uuid) -> String {
+    format!("receipts/{}/{}", user_id.as_str(), import_id)
+}
+
+/// Where the attachment of a shopping item is stored.
+pub fn shopping_item_attachment_key(user_id: &UserId, item_id: Uuid) -> String {
+    format!("shopping_items/{}/{}", user_id.as_str(), item_id)
+}
+
+/// Where the thumbnail of the photo stored under `image_key` goes. Kept under
+/// its own prefix, since on disk the photo's key is a file.
+pub fn thumbnail_key(image_key: &str) -> String {
+    format!("thumbnails/{image_key}")
+}
+
+/// A stored photo with the thumbnail made from it, once there is one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageLinks {
+    pub original: SignedUrl,
+    pub thumbnail: Option<SignedUrl>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decode_image_with_or_without_data_url_prefix() {
+        let png = STANDARD.encode([0x89, b'P', b'N', b'G', 0x0D, 0x0A]);
+
+        let plain = ImageUpload::from_base64(&png).unwrap();
+        let prefixed = ImageUpload::from_base64(&format!("data:image/png;base64,{png}")).unwrap();
+
+        assert_eq!(plain.content_type, "image/png");
+        assert_eq!(plain, prefixed);
+    }
+
+    #[test]
+    fn should_reject_content_that_is_not_an_image() {
+        let pdf = STANDARD.encode(b"%PDF-1.7");
+
+        assert!(ImageUpload::from_base64(&pdf).is_none());
+        assert!(ImageUpload::from_base64("not base64!").is_none());
+    }
+
+    #[test]
+    fn should_accept_photos_and_voice_notes_as_attachments() {
+        let jpeg = STANDARD.encode([0xFF, 0xD8, 0xFF, 0xE0]);
+        let mp3 = STANDARD.encode(b"ID3\x04\x00");
+        let m4a = STANDARD.encode(b"\x00\x00\x00\x20ftypM4A ");
+        let pdf = STANDARD.encode(b"%PDF-1.7");
+
+        let content_type = |data: &str| AttachmentUpload::from_base64(data).map(|a| a.content_type);
+
+        assert_eq!(content_type(&jpeg), Some("image/jpeg"));
+        assert_eq!(content_type(&mp3), Some("audio/mpeg"));
+        assert_eq!(content_type(&m4a), Some("audio/mp4"));
+        assert_eq!(content_type(&pdf), None);
+    }
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use super::errors::StorageError;
+use super::model::{SignedUrl, StoredObject};
+
+/// Service port for the object store holding user uploads (product photos,
+/// receipts). Keys are `/`-separated paths such as `products/<user>/<id>`.
+#[async_trait]
+pub trait BlobStorage: Send + Sync {
+    /// Stores an object, replacing any previous one under the same key.
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError>;
+
+    async fn get(&self, key: &str) -> Result<StoredObject, StorageError>;
+
+    async fn exists(&self, key: &str) -> Result<bool, StorageError>;
+
+    /// Removes an object. Deleting a missing key is not an error.
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+
+    /// Time-limited URL a client can download the object from without a token.
+    fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError>;
+
+    /// Checks a signature issued by `signed_url`, for backends whose URLs
+    /// point back to this server. Backends serving downloads themselves
+    /// reject every signature.
+    fn verify_signature(
+        &self,
+        key: &str,
+        expires_at: i64,
+        signature: &str,
+    ) -> Result<(), StorageError>;
+}
+
+/// Service port that shrinks photos for list screens, so the apps don't
+/// download multi-megabyte originals to draw a row.
+pub trait ThumbnailService: Send + Sync {
+    /// Encodes a small version of a JPEG, PNG or WebP photo, in
+    /// `THUMBNAIL_CONTENT_TYPE`. CPU-bound; run it off the async workers.
+    fn thumbnail(&self, image: &[u8]) -> Result<Vec<u8>, StorageError>;
+}
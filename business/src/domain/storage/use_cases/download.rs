@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::domain::storage::errors::StorageError;
+use crate::domain::storage::model::StoredObject;
+
+pub struct DownloadBlobParams {
+    pub key: String,
+    /// Unix timestamp the URL was signed to expire at
+    pub expires_at: i64,
+    pub signature: String,
+}
+
+#[async_trait]
+pub trait DownloadBlobUseCase: Send + Sync {
+    async fn execute(&self, params: DownloadBlobParams) -> Result<StoredObject, StorageError>;
+}
@@ -0,0 +1,11 @@
+#[derive(Debug, thiserror::Error)]
+pub enum StoreProfileError {
+    #[error("store_profile.name_empty")]
+    NameEmpty,
+    #[error("store_profile.aisle_name_empty")]
+    AisleNameEmpty,
+    #[error("store_profile.not_found")]
+    NotFound,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
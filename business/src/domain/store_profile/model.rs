@@ -0,0 +1,207 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::errors::StoreProfileError;
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::shopping_item::model::ShoppingItem;
+
+/// A stretch of the store, e.g. "Fruta y verdura". Items go to the first aisle
+/// whose name or one of its keywords appears in the item name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aisle {
+    pub name: String,
+    pub keywords: Vec<String>,
+}
+
+impl Aisle {
+    fn matches(&self, item_key: &str) -> bool {
+        std::iter::once(&self.name)
+            .chain(self.keywords.iter())
+            .map(|term| term.trim().to_lowercase())
+            .any(|term| !term.is_empty() && item_key.contains(&term))
+    }
+}
+
+/// The order a user walks through a store, used to sort the shopping list.
+#[derive(Debug, Clone)]
+pub struct StoreProfile {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub name: String,
+    /// Aisles in walking order.
+    pub aisles: Vec<Aisle>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StoreProfile {
+    pub fn new(
+        user_id: UserId,
+        name: String,
+        aisles: Vec<Aisle>,
+    ) -> Result<Self, StoreProfileError> {
+        validate_name(&name)?;
+        validate_aisles(&aisles)?;
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            aisles,
+            is_active: false,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        name: String,
+        aisles: Vec<Aisle>,
+        is_active: bool,
+        created_at: DateTime<Utc>,
+        updated_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            name,
+            aisles,
+            is_active,
+            created_at,
+            updated_at,
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        name: Option<String>,
+        aisles: Option<Vec<Aisle>>,
+    ) -> Result<(), StoreProfileError> {
+        if let Some(name) = name {
+            validate_name(&name)?;
+            self.name = name;
+        }
+        if let Some(aisles) = aisles {
+            validate_aisles(&aisles)?;
+            self.aisles = aisles;
+        }
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Position of the aisle an item belongs to, if any.
+    pub fn aisle_index(&self, item_name: &str) -> Option<usize> {
+        let key = item_name.trim().to_lowercase();
+        self.aisles.iter().position(|aisle| aisle.matches(&key))
+    }
+
+    /// Sorts items in walking order. Items matching no aisle go last; ties
+    /// keep their current order.
+    pub fn sort_items(&self, items: &mut [ShoppingItem]) {
+        self.sort_by_aisle(items, |item| &item.name);
+    }
+
+    /// Same as [`Self::sort_items`] for any list keyed by item name.
+    pub fn sort_by_aisle<T>(&self, entries: &mut [T], item_name: impl Fn(&T) -> &str) {
+        entries.sort_by_key(|entry| self.aisle_index(item_name(entry)).unwrap_or(usize::MAX));
+    }
+}
+
+fn validate_name(name: &str) -> Result<(), StoreProfileError> {
+    if name.trim().is_empty() {
+        return Err(StoreProfileError::NameEmpty);
+    }
+    Ok(())
+}
+
+fn validate_aisles(aisles: &[Aisle]) -> Result<(), StoreProfileError> {
+    if aisles.iter().any(|aisle| aisle.name.trim().is_empty()) {
+        return Err(StoreProfileError::AisleNameEmpty);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn aisle(name: &str, keywords: &[&str]) -> Aisle {
+        Aisle {
+            name: name.to_string(),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    fn item(name: &str) -> ShoppingItem {
+        ShoppingItem::new(test_user_id(), name.to_string(), None).unwrap()
+    }
+
+    #[test]
+    fn should_reject_profile_with_unnamed_aisle() {
+        let result = StoreProfile::new(
+            test_user_id(),
+            "Mercadona".to_string(),
+            vec![aisle("Fruta", &[]), aisle("  ", &["leche"])],
+        );
+
+        assert!(matches!(result, Err(StoreProfileError::AisleNameEmpty)));
+    }
+
+    #[test]
+    fn should_sort_items_in_aisle_order_with_unmatched_last() {
+        let profile = StoreProfile::new(
+            test_user_id(),
+            "Mercadona".to_string(),
+            vec![
+                aisle("Fruta", &["manzana", "plátano"]),
+                aisle("Lácteos", &["leche", "yogur"]),
+                aisle("Limpieza", &["detergente"]),
+            ],
+        )
+        .unwrap();
+        let mut items = vec![
+            item("Detergente"),
+            item("Pilas"),
+            item("Leche entera"),
+            item("Manzanas golden"),
+            item("Yogur griego"),
+        ];
+
+        profile.sort_items(&mut items);
+
+        let names: Vec<_> = items.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Manzanas golden",
+                "Leche entera",
+                "Yogur griego",
+                "Detergente",
+                "Pilas"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_match_item_by_aisle_name() {
+        let profile = StoreProfile::new(
+            test_user_id(),
+            "Lidl".to_string(),
+            vec![aisle("Pan", &[]), aisle("Congelados", &[])],
+        )
+        .unwrap();
+
+        assert_eq!(profile.aisle_index("Pan de molde"), Some(0));
+        assert_eq!(profile.aisle_index("Guisantes congelados"), Some(1));
+        assert_eq!(profile.aisle_index("Aceite"), None);
+    }
+}
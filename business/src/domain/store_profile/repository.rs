@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::StoreProfile;
+
+#[async_trait]
+pub trait StoreProfileRepository: Send + Sync {
+    async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError>;
+    async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError>;
+    async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError>;
+    async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+    async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError>;
+    async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+    /// Makes the profile the user's only active one.
+    async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+}
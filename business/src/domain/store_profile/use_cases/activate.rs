@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+
+pub struct ActivateStoreProfileParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+/// Picks the store the shopping list is sorted for. Any previously active
+/// profile is deactivated.
+#[async_trait]
+pub trait ActivateStoreProfileUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: ActivateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError>;
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::{Aisle, StoreProfile};
+
+pub struct CreateStoreProfileParams {
+    pub user_id: UserId,
+    pub name: String,
+    pub aisles: Vec<Aisle>,
+}
+
+#[async_trait]
+pub trait CreateStoreProfileUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: CreateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError>;
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::store_profile::errors::StoreProfileError;
+
+pub struct DeleteStoreProfileParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait DeleteStoreProfileUseCase: Send + Sync {
+    async fn execute(&self, params: DeleteStoreProfileParams) -> Result<(), StoreProfileError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::StoreProfile;
+
+pub struct GetAllStoreProfilesParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetAllStoreProfilesUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: GetAllStoreProfilesParams,
+    ) -> Result<Vec<StoreProfile>, StoreProfileError>;
+}
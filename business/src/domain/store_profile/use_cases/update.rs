@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::store_profile::errors::StoreProfileError;
+use crate::domain::store_profile::model::{Aisle, StoreProfile};
+
+pub struct UpdateStoreProfileParams {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub name: Option<String>,
+    pub aisles: Option<Vec<Aisle>>,
+}
+
+#[async_trait]
+pub trait UpdateStoreProfileUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: UpdateStoreProfileParams,
+    ) -> Result<StoreProfile, StoreProfileError>;
+}
@@ -2,8 +2,36 @@
 pub enum SuggestionError {
     #[error("suggestion.not_enough_products")]
     NotEnoughProducts,
+    /// No usable (non-expired) products to cook with.
+    #[error("suggestion.empty_pantry")]
+    EmptyPantry,
     #[error("suggestion.generation_failed")]
     GenerationFailed,
+    /// The model provider timed out, was unreachable or failed on its side.
+    #[error("suggestion.provider_unavailable")]
+    ProviderUnavailable,
+    /// The provider is rate limiting us or its quota ran out.
+    #[error("suggestion.quota_exceeded")]
+    QuotaExceeded,
+    /// The model answered, but not with suggestions we could read.
+    #[error("suggestion.parse_failed")]
+    ParseFailed { raw: String },
     #[error("suggestion.invalid_suggestion")]
     InvalidSuggestion,
+    #[error(transparent)]
+    Quota(#[from] crate::domain::quota::errors::QuotaError),
+    #[error(transparent)]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
+
+impl SuggestionError {
+    /// Seconds after which retrying the same request may succeed, for
+    /// transient provider failures. `None` means retrying won't help.
+    pub fn retry_after_seconds(&self) -> Option<u64> {
+        match self {
+            SuggestionError::ProviderUnavailable => Some(30),
+            SuggestionError::QuotaExceeded => Some(60),
+            _ => None,
+        }
+    }
 }
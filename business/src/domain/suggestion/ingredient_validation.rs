@@ -0,0 +1,266 @@
+use super::model::Suggestion;
+use super::prioritized_pantry::{PrioritizedItem, PrioritizedPantry, normalize_name};
+use crate::domain::product::urgency::UrgencyLevel;
+
+/// Largest edit distance accepted when matching an ingredient name to a
+/// pantry product.
+const MAX_NAME_DISTANCE: usize = 2;
+
+/// Shortest name allowed to match by containment, so "sal" doesn't match "salmón".
+const MIN_CONTAINED_NAME_LEN: usize = 4;
+
+/// Outcome of checking generated suggestions against the pantry they were
+/// generated from.
+#[derive(Debug, Clone, Default)]
+pub struct IngredientValidation {
+    /// Suggestions whose ingredients all reference pantry products.
+    pub suggestions: Vec<Suggestion>,
+    /// Ingredients with an unknown id that were matched to a product by name.
+    pub remapped: usize,
+    /// Names of ingredients that matched no pantry product and were dropped.
+    pub hallucinated: Vec<String>,
+}
+
+/// Grounds every ingredient in the pantry.
+///
+/// Business rules:
+/// - An ingredient whose id is in the pantry is kept as is
+/// - An unknown id is re-mapped to the product with the closest name, if any
+/// - Ingredients matching nothing are dropped and reported as hallucinated
+/// - Urgency comes from the pantry, not from the generator
+/// - Suggestions left without ingredients are dropped
+pub fn validate_against_pantry(
+    suggestions: Vec<Suggestion>,
+    pantry: &PrioritizedPantry,
+) -> IngredientValidation {
+    let mut validation = IngredientValidation::default();
+
+    for mut suggestion in suggestions {
+        let mut ingredients = Vec::with_capacity(suggestion.ingredients.len());
+        for mut ingredient in suggestion.ingredients {
+            let item = match find_by_id(pantry, &ingredient.product_id) {
+                Some(item) => item,
+                None => match find_by_name(pantry, &ingredient.product_name) {
+                    Some(item) => {
+                        validation.remapped += 1;
+                        ingredient.product_id = item.product.id.to_string();
+                        item
+                    }
+                    None => {
+                        validation.hallucinated.push(ingredient.product_name);
+                        continue;
+                    }
+                },
+            };
+
+            ingredient.is_urgent =
+                matches!(item.urgency, UrgencyLevel::UseToday | UrgencyLevel::UseSoon);
+            if ingredient.quantity.is_none() {
+                ingredient.quantity = item.product.quantity.clone();
+            }
+            ingredients.push(ingredient);
+        }
+
+        if ingredients.is_empty() {
+            continue;
+        }
+
+        suggestion.urgent_ingredients = ingredients
+            .iter()
+            .filter(|ing| ing.is_urgent)
+            .map(|ing| ing.product_id.clone())
+            .collect();
+        suggestion.ingredients = ingredients;
+        validation.suggestions.push(suggestion);
+    }
+
+    validation
+}
+
+fn find_by_id<'a>(pantry: &'a PrioritizedPantry, product_id: &str) -> Option<&'a PrioritizedItem> {
+    pantry
+        .items
+        .iter()
+        .find(|item| item.product.id.to_string() == product_id)
+}
+
+/// Exact name first, then one name containing the other, then the closest
+/// name within [`MAX_NAME_DISTANCE`] edits.
+fn find_by_name<'a>(pantry: &'a PrioritizedPantry, name: &str) -> Option<&'a PrioritizedItem> {
+    let wanted = normalize_name(name);
+    if wanted.is_empty() {
+        return None;
+    }
+
+    let names: Vec<(String, &PrioritizedItem)> = pantry
+        .items
+        .iter()
+        .map(|item| (normalize_name(&item.product.name), item))
+        .collect();
+
+    if let Some((_, item)) = names.iter().find(|(n, _)| *n == wanted) {
+        return Some(item);
+    }
+
+    if let Some((_, item)) = names.iter().find(|(n, _)| {
+        let (short, long) = if n.len() < wanted.len() {
+            (n.as_str(), wanted.as_str())
+        } else {
+            (wanted.as_str(), n.as_str())
+        };
+        short.chars().count() >= MIN_CONTAINED_NAME_LEN && long.contains(short)
+    }) {
+        return Some(item);
+    }
+
+    names
+        .iter()
+        .map(|(n, item)| (levenshtein(n, &wanted), *item))
+        .filter(|(distance, _)| *distance <= MAX_NAME_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, item)| item)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::model::Product;
+    use crate::domain::product::urgency::ExpiringSoonWindow;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use crate::domain::suggestion::model::{SuggestionIngredient, TimeRange};
+    use crate::domain::suggestion::prioritized_pantry::{PantryPromptLimits, prioritize_pantry};
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn make_product(name: &str, expires_in_days: i64) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            name.to_string(),
+            ProductStatus::Opened,
+            None,
+            Some("500g".to_string()),
+            Some(Utc::now() + Duration::days(expires_in_days)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    fn ingredient(product_id: &str, product_name: &str) -> SuggestionIngredient {
+        SuggestionIngredient {
+            product_id: product_id.to_string(),
+            product_name: product_name.to_string(),
+            quantity: None,
+            is_urgent: false,
+        }
+    }
+
+    fn suggestion(ingredients: Vec<SuggestionIngredient>) -> Suggestion {
+        Suggestion {
+            id: "test-1".to_string(),
+            title: "Arroz con pollo".to_string(),
+            description: None,
+            estimated_time: TimeRange::Medium,
+            ingredients,
+            urgent_ingredients: vec![],
+            steps: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn pantry() -> PrioritizedPantry {
+        prioritize_pantry(
+            vec![
+                make_product("Pechuga de pollo", 1),
+                make_product("Arroz", 90),
+            ],
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        )
+    }
+
+    #[test]
+    fn should_keep_known_ids_and_take_urgency_from_pantry() {
+        let pantry = pantry();
+        let chicken_id = pantry.items[0].product.id.to_string();
+
+        let validation = validate_against_pantry(
+            vec![suggestion(vec![ingredient(&chicken_id, "Pollo")])],
+            &pantry,
+        );
+
+        let ingredient = &validation.suggestions[0].ingredients[0];
+        assert_eq!(ingredient.product_id, chicken_id);
+        assert!(ingredient.is_urgent);
+        assert_eq!(ingredient.quantity.as_deref(), Some("500g"));
+        assert_eq!(
+            validation.suggestions[0].urgent_ingredients,
+            vec![chicken_id]
+        );
+        assert_eq!(validation.remapped, 0);
+        assert!(validation.hallucinated.is_empty());
+    }
+
+    #[test]
+    fn should_remap_unknown_id_by_closest_name() {
+        let pantry = pantry();
+        let rice_id = pantry.items[1].product.id.to_string();
+
+        let validation = validate_against_pantry(
+            vec![suggestion(vec![
+                ingredient("p-42", "arroz"),
+                ingredient("p-43", "Aroz"),
+            ])],
+            &pantry,
+        );
+
+        let ids: Vec<_> = validation.suggestions[0]
+            .ingredients
+            .iter()
+            .map(|i| i.product_id.as_str())
+            .collect();
+        assert_eq!(ids, vec![rice_id.as_str(), rice_id.as_str()]);
+        assert_eq!(validation.remapped, 2);
+    }
+
+    #[test]
+    fn should_drop_hallucinated_ingredients_and_empty_suggestions() {
+        let pantry = pantry();
+        let rice_id = pantry.items[1].product.id.to_string();
+
+        let validation = validate_against_pantry(
+            vec![
+                suggestion(vec![
+                    ingredient(&rice_id, "Arroz"),
+                    ingredient("x", "Gambas"),
+                ]),
+                suggestion(vec![ingredient("y", "Salmón")]),
+            ],
+            &pantry,
+        );
+
+        assert_eq!(validation.suggestions.len(), 1);
+        assert_eq!(validation.suggestions[0].ingredients.len(), 1);
+        assert_eq!(validation.hallucinated, vec!["Gambas", "Salmón"]);
+    }
+}
@@ -1,4 +1,7 @@
 use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::shared::value_objects::UserId;
 
 /// Time range for recipe preparation.
 #[derive(Debug, Clone, PartialEq)]
@@ -90,3 +93,22 @@ pub fn create_suggestion(
         created_at: Utc::now(),
     })
 }
+
+/// A persisted batch of suggestions generated for a single user.
+///
+/// Batches are produced either on demand or by the morning pre-generation job
+/// and served as a cache by the suggestions endpoint.
+#[derive(Debug, Clone)]
+pub struct SuggestionBatch {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub suggestions: Vec<Suggestion>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl SuggestionBatch {
+    /// Returns true if the batch was generated on the same (UTC) day as `now`.
+    pub fn is_fresh(&self, now: DateTime<Utc>) -> bool {
+        self.generated_at.date_naive() == now.date_naive()
+    }
+}
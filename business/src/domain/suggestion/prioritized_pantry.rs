@@ -0,0 +1,286 @@
+use crate::domain::product::model::Product;
+use crate::domain::product::urgency::{
+    ExpiringSoonWindow, UrgencyLevel, days_until_expiry, get_urgency_level, is_expired,
+};
+
+/// Bounds on how much of the pantry goes into a suggestion prompt.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PantryPromptLimits {
+    /// Distinct products listed in full, most urgent first.
+    pub max_products: usize,
+    /// Whether products past `max_products` are mentioned by name.
+    pub summarize_long_tail: bool,
+    /// Names mentioned in the long-tail summary; the rest are only counted.
+    pub max_long_tail_names: usize,
+}
+
+impl Default for PantryPromptLimits {
+    fn default() -> Self {
+        Self {
+            max_products: 40,
+            summarize_long_tail: true,
+            max_long_tail_names: 30,
+        }
+    }
+}
+
+/// A pantry product with its urgency computed once, standing for every
+/// product with the same name.
+#[derive(Debug, Clone)]
+pub struct PrioritizedItem {
+    /// The most urgent product with this name.
+    pub product: Product,
+    pub urgency: UrgencyLevel,
+    pub days_until_expiry: Option<i64>,
+    /// How many products share the name.
+    pub count: usize,
+}
+
+/// Pantry as handed to a suggestion generator: usable products, most urgent
+/// first, bounded by [`PantryPromptLimits`].
+#[derive(Debug, Clone, Default)]
+pub struct PrioritizedPantry {
+    pub items: Vec<PrioritizedItem>,
+    /// Names of products left out of `items`, when the long tail is summarized.
+    pub long_tail: Vec<String>,
+    /// Products left out of `items`, named or not.
+    pub omitted: usize,
+}
+
+impl PrioritizedPantry {
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Representative products, in priority order.
+    pub fn products(&self) -> impl Iterator<Item = &Product> {
+        self.items.iter().map(|item| &item.product)
+    }
+}
+
+/// Builds the prioritized pantry every generator works from.
+///
+/// Business rules:
+/// - Expired products are dropped; products only past a best-before date are
+///   kept, as still safe to cook with
+/// - Products are ordered by urgency level for the user's expiring-soon
+///   window, then by days left (undated last)
+/// - Products with the same name (ignoring case and surrounding spaces) are
+///   merged into the most urgent one
+/// - Only `max_products` distinct products are kept; the rest form the long tail
+pub fn prioritize_pantry(
+    products: Vec<Product>,
+    limits: &PantryPromptLimits,
+    window: ExpiringSoonWindow,
+) -> PrioritizedPantry {
+    let mut ranked: Vec<PrioritizedItem> = products
+        .into_iter()
+        .filter(|p| !is_expired(p))
+        .map(|product| PrioritizedItem {
+            urgency: get_urgency_level(&product, window),
+            days_until_expiry: days_until_expiry(&product),
+            product,
+            count: 1,
+        })
+        .collect();
+
+    ranked.sort_by_key(|item| {
+        (
+            item.urgency.rank(),
+            item.days_until_expiry.is_none(),
+            item.days_until_expiry,
+        )
+    });
+
+    let mut distinct: Vec<PrioritizedItem> = Vec::new();
+    for item in ranked {
+        let key = normalize_name(&item.product.name);
+        match distinct
+            .iter_mut()
+            .find(|existing| normalize_name(&existing.product.name) == key)
+        {
+            Some(existing) => existing.count += 1,
+            None => distinct.push(item),
+        }
+    }
+
+    let tail = if distinct.len() > limits.max_products {
+        distinct.split_off(limits.max_products)
+    } else {
+        Vec::new()
+    };
+
+    let long_tail = if limits.summarize_long_tail {
+        tail.iter()
+            .take(limits.max_long_tail_names)
+            .map(|item| item.product.name.clone())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    PrioritizedPantry {
+        items: distinct,
+        long_tail,
+        omitted: tail.iter().map(|item| item.count).sum(),
+    }
+}
+
+/// Name key used to tell products apart: case and surrounding spaces ignored.
+pub(crate) fn normalize_name(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+    use crate::domain::shared::value_objects::UserId;
+    use chrono::{Duration, Utc};
+    use uuid::Uuid;
+
+    fn make_product(name: &str, expires_in_days: Option<i64>) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            UserId::new("test-user-id"),
+            name.to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            expires_in_days.map(|d| Utc::now() + Duration::days(d)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn should_order_by_urgency_then_days_left() {
+        let pantry = prioritize_pantry(
+            vec![
+                make_product("Arroz", None),
+                make_product("Lentejas", Some(20)),
+                make_product("Pollo", Some(1)),
+                make_product("Tomates", Some(5)),
+                make_product("Yogur", Some(2)),
+                make_product("Leche", Some(-3)),
+            ],
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        );
+
+        let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Pollo", "Yogur", "Tomates", "Lentejas", "Arroz"]
+        );
+        assert_eq!(pantry.items[0].urgency, UrgencyLevel::UseSoon);
+        assert_eq!(pantry.items[1].days_until_expiry, Some(2));
+        assert_eq!(pantry.items[4].days_until_expiry, None);
+    }
+
+    #[test]
+    fn should_keep_best_before_products_past_their_date_as_quality_declining() {
+        let mut biscuits = make_product("Galletas", Some(-3));
+        biscuits.expiry_type = ExpiryType::BestBefore;
+        let mut milk = make_product("Leche", Some(-3));
+        milk.expiry_type = ExpiryType::UseBy;
+
+        let pantry = prioritize_pantry(
+            vec![
+                make_product("Arroz", None),
+                biscuits,
+                milk,
+                make_product("Pollo", Some(1)),
+            ],
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        );
+
+        let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Pollo", "Galletas", "Arroz"]);
+        assert_eq!(pantry.items[1].urgency, UrgencyLevel::QualityDeclining);
+    }
+
+    #[test]
+    fn should_merge_products_with_same_name_into_most_urgent() {
+        let urgent = make_product(" yogur NATURAL ", Some(1));
+        let urgent_id = urgent.id;
+
+        let pantry = prioritize_pantry(
+            vec![
+                make_product("Yogur natural", Some(10)),
+                make_product("Leche", Some(4)),
+                urgent,
+            ],
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        );
+
+        assert_eq!(pantry.items.len(), 2);
+        assert_eq!(pantry.items[0].product.id, urgent_id);
+        assert_eq!(pantry.items[0].count, 2);
+        assert_eq!(pantry.omitted, 0);
+    }
+
+    #[test]
+    fn should_cap_items_and_summarize_long_tail() {
+        let products = ["Leche", "Huevos", "Arroz", "Pasta", "Lentejas"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| make_product(name, Some(i as i64 + 3)))
+            .collect();
+        let limits = PantryPromptLimits {
+            max_products: 2,
+            summarize_long_tail: true,
+            max_long_tail_names: 2,
+        };
+
+        let pantry = prioritize_pantry(products, &limits, ExpiringSoonWindow::default());
+
+        let names: Vec<_> = pantry.products().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Leche", "Huevos"]);
+        assert_eq!(pantry.long_tail, vec!["Arroz", "Pasta"]);
+        assert_eq!(pantry.omitted, 3);
+    }
+
+    #[test]
+    fn should_drop_long_tail_when_summary_disabled() {
+        let products = ["Leche", "Huevos", "Arroz"]
+            .into_iter()
+            .map(|name| make_product(name, None))
+            .collect();
+        let limits = PantryPromptLimits {
+            max_products: 1,
+            summarize_long_tail: false,
+            max_long_tail_names: 30,
+        };
+
+        let pantry = prioritize_pantry(products, &limits, ExpiringSoonWindow::default());
+
+        assert_eq!(pantry.items.len(), 1);
+        assert!(pantry.long_tail.is_empty());
+        assert_eq!(pantry.omitted, 2);
+    }
+
+    #[test]
+    fn should_treat_more_products_as_use_soon_with_wider_window() {
+        let products = || vec![make_product("Tomates", Some(5))];
+
+        let default = prioritize_pantry(
+            products(),
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        );
+        let wide = prioritize_pantry(
+            products(),
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::new(7).unwrap(),
+        );
+
+        assert_eq!(default.items[0].urgency, UrgencyLevel::Ok);
+        assert_eq!(wide.items[0].urgency, UrgencyLevel::UseSoon);
+    }
+}
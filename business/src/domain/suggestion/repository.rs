@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::pagination::KeysetPage;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::{Suggestion, SuggestionBatch};
+
+/// Batches are read back with each ingredient's name and quantity taken from
+/// its product as it is now; ingredients whose product was deleted keep the
+/// values from generation.
+#[async_trait]
+pub trait SuggestionRepository: Send + Sync {
+    async fn save_batch(
+        &self,
+        user_id: &UserId,
+        suggestions: &[Suggestion],
+    ) -> Result<(), RepositoryError>;
+    async fn get_latest_batch(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<SuggestionBatch>, RepositoryError>;
+    /// Batches newest first by `(generated_at, id)`, older than `page.after`,
+    /// up to [`KeysetPage::fetch_limit`] of them.
+    async fn get_batches(
+        &self,
+        user_id: &UserId,
+        page: &KeysetPage,
+    ) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+    /// Suggestions whose title contains `term`, case-insensitively, newest
+    /// first. A recipe suggested more than once comes back once, as last
+    /// suggested.
+    async fn search(
+        &self,
+        user_id: &UserId,
+        term: &str,
+        limit: u32,
+    ) -> Result<Vec<Suggestion>, RepositoryError>;
+}
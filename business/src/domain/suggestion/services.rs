@@ -1,16 +1,20 @@
 use async_trait::async_trait;
 
-use crate::domain::product::model::Product;
-
 use super::errors::SuggestionError;
 use super::model::Suggestion;
+use super::prioritized_pantry::PrioritizedPantry;
 
 /// Service port for generating cooking suggestions from available products.
+///
+/// Implementations receive the pantry already ranked by [`prioritize_pantry`]
+/// and should keep its order rather than re-deriving urgency.
+///
+/// [`prioritize_pantry`]: super::prioritized_pantry::prioritize_pantry
 #[async_trait]
 pub trait SuggestionGeneratorService: Send + Sync {
     async fn generate(
         &self,
-        products: &[Product],
+        pantry: &PrioritizedPantry,
         limit: usize,
     ) -> Result<Vec<Suggestion>, SuggestionError>;
 }
@@ -7,6 +7,11 @@ use crate::domain::suggestion::model::Suggestion;
 pub struct GenerateSuggestionsParams {
     pub user_id: UserId,
     pub limit: usize,
+    /// Bypass the cached batch and generate fresh suggestions.
+    pub refresh: bool,
+    /// Count a fresh generation against the user's daily AI quota. Background
+    /// jobs pass `false` so pre-generated batches are free.
+    pub metered: bool,
 }
 
 #[async_trait]
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::pagination::{CursorPage, KeysetPage};
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::suggestion::errors::SuggestionError;
+use crate::domain::suggestion::model::SuggestionBatch;
+
+pub struct GetSuggestionHistoryParams {
+    pub user_id: UserId,
+    pub page: KeysetPage,
+}
+
+#[async_trait]
+pub trait GetSuggestionHistoryUseCase: Send + Sync {
+    /// Past suggestion batches, newest first, one keyset page at a time.
+    async fn execute(
+        &self,
+        params: GetSuggestionHistoryParams,
+    ) -> Result<CursorPage<SuggestionBatch>, SuggestionError>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+use crate::domain::suggestion::errors::SuggestionError;
+
+pub struct PregenerateSuggestionsParams {
+    /// Number of suggestions to generate per user.
+    pub limit: usize,
+    /// Maximum number of users a batch is generated for in a single run.
+    /// Users skipped because their batch is still fresh don't count.
+    pub max_users: usize,
+}
+
+/// Outcome of a pre-generation run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PregenerationSummary {
+    pub generated: usize,
+    pub skipped: usize,
+    pub failed: usize,
+}
+
+#[async_trait]
+pub trait PregenerateSuggestionsUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: PregenerateSuggestionsParams,
+    ) -> Result<PregenerationSummary, SuggestionError>;
+}
@@ -0,0 +1,9 @@
+#[derive(Debug, thiserror::Error)]
+pub enum VacationError {
+    #[error("vacation.until_in_past")]
+    UntilInPast,
+    #[error("vacation.not_found")]
+    NotFound,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
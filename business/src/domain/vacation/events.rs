@@ -0,0 +1,10 @@
+use crate::domain::shared::value_objects::UserId;
+
+/// A user came back from vacation, by hand or because the planned return
+/// date passed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VacationEnded {
+    pub user_id: UserId,
+    /// Products whose expiry date fell inside the vacation
+    pub expired: u32,
+}
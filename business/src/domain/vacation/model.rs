@@ -0,0 +1,219 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::errors::VacationError;
+use crate::domain::product::model::Product;
+use crate::domain::product::value_objects::{ExpiryType, ProductStatus};
+use crate::domain::shared::value_objects::UserId;
+
+/// A period the user is away from home. Expiry alerts are paused while it
+/// lasts, and inventory snapshots taken meanwhile are tagged with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Vacation {
+    pub id: Uuid,
+    pub user_id: UserId,
+    pub started_at: DateTime<Utc>,
+    /// Planned return; the vacation ends by itself once it passes
+    pub until: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl Vacation {
+    pub fn start(
+        user_id: UserId,
+        until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, VacationError> {
+        validate_until(until, now)?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            started_at: now,
+            until,
+            ended_at: None,
+        })
+    }
+
+    /// Constructor for data already persisted in the repository (no validation).
+    pub fn from_repository(
+        id: Uuid,
+        user_id: UserId,
+        started_at: DateTime<Utc>,
+        until: Option<DateTime<Utc>>,
+        ended_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            id,
+            user_id,
+            started_at,
+            until,
+            ended_at,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// Whether the planned return has passed on a vacation not ended yet.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.is_active() && self.until.is_some_and(|until| until <= now)
+    }
+
+    /// Moves the planned return; `None` keeps the vacation on until ended by hand.
+    pub fn plan_return(
+        &mut self,
+        until: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), VacationError> {
+        validate_until(until, now)?;
+        self.until = until;
+        Ok(())
+    }
+
+    /// Returns false if the vacation had already ended.
+    pub fn end(&mut self, now: DateTime<Utc>) -> bool {
+        if !self.is_active() {
+            return false;
+        }
+        self.ended_at = Some(now);
+        true
+    }
+}
+
+fn validate_until(until: Option<DateTime<Utc>>, now: DateTime<Utc>) -> Result<(), VacationError> {
+    match until {
+        Some(until) if until <= now => Err(VacationError::UntilInPast),
+        _ => Ok(()),
+    }
+}
+
+/// What became of the pantry while the user was away.
+#[derive(Debug, Clone)]
+pub struct VacationReport {
+    pub vacation: Vacation,
+    /// Products not finished whose use-by or estimated expiry date fell
+    /// inside the vacation, soonest first
+    pub expired: Vec<Product>,
+    /// Products not finished still good after the vacation. Past a
+    /// best-before date counts as good: the food can still be eaten.
+    pub survived: u32,
+}
+
+impl VacationReport {
+    /// Builds the report over the user's products. A vacation still going on
+    /// is reported up to `now`.
+    pub fn build(vacation: Vacation, products: Vec<Product>, now: DateTime<Utc>) -> Self {
+        let end = vacation.ended_at.unwrap_or(now);
+        let mut expired = Vec::new();
+        let mut survived = 0;
+
+        for product in products {
+            if product.status == ProductStatus::Finished {
+                continue;
+            }
+            let date = product.expiry_date.or(product.estimated_expiry_date);
+            match date {
+                Some(date) if date < vacation.started_at => {}
+                Some(date) if date < end && product.expiry_type != ExpiryType::BestBefore => {
+                    expired.push(product)
+                }
+                _ => survived += 1,
+            }
+        }
+        expired.sort_by_key(|product| product.expiry_date.or(product.estimated_expiry_date));
+
+        Self {
+            vacation,
+            expired,
+            survived,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn test_user_id() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn product(name: &str, expiry: Option<DateTime<Utc>>, expiry_type: ExpiryType) -> Product {
+        Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            name.to_string(),
+            ProductStatus::New,
+            None,
+            None,
+            expiry,
+            None,
+            expiry_type,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn should_reject_return_in_the_past() {
+        let now = Utc::now();
+
+        let result = Vacation::start(test_user_id(), Some(now - Duration::hours(1)), now);
+
+        assert!(matches!(result, Err(VacationError::UntilInPast)));
+    }
+
+    #[test]
+    fn should_be_due_once_planned_return_passes() {
+        let now = Utc::now();
+        let mut vacation =
+            Vacation::start(test_user_id(), Some(now + Duration::days(7)), now).unwrap();
+
+        assert!(!vacation.is_due(now + Duration::days(6)));
+        assert!(vacation.is_due(now + Duration::days(7)));
+
+        assert!(vacation.end(now + Duration::days(7)));
+        assert!(!vacation.is_due(now + Duration::days(8)));
+        assert!(!vacation.end(now + Duration::days(8)));
+    }
+
+    #[test]
+    fn should_report_products_that_expired_during_the_vacation() {
+        let started = Utc::now() - Duration::days(10);
+        let mut vacation = Vacation::start(test_user_id(), None, started).unwrap();
+        vacation.end(started + Duration::days(7));
+
+        let report = VacationReport::build(
+            vacation,
+            vec![
+                product(
+                    "Yogur",
+                    Some(started + Duration::days(3)),
+                    ExpiryType::UseBy,
+                ),
+                product("Leche", Some(started + Duration::days(1)), ExpiryType::None),
+                product(
+                    "Galletas",
+                    Some(started + Duration::days(2)),
+                    ExpiryType::BestBefore,
+                ),
+                product("Arroz", None, ExpiryType::None),
+                product(
+                    "Queso",
+                    Some(started + Duration::days(9)),
+                    ExpiryType::UseBy,
+                ),
+                product("Pan", Some(started - Duration::days(1)), ExpiryType::None),
+            ],
+            Utc::now(),
+        );
+
+        let names: Vec<_> = report.expired.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["Leche", "Yogur"]);
+        assert_eq!(report.survived, 3);
+    }
+}
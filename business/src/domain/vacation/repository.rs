@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+use super::model::Vacation;
+
+#[async_trait]
+pub trait VacationRepository: Send + Sync {
+    /// The user's vacation that hasn't ended; there is at most one.
+    async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+    /// The user's most recently started vacation, ended or not.
+    async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError>;
+    /// Vacations not ended yet whose planned return is at or before `now`,
+    /// oldest return first.
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Vacation>, RepositoryError>;
+    async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+    async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::VacationReport;
+
+pub struct CleanUpVacationParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait CleanUpVacationUseCase: Send + Sync {
+    /// Marks every product that expired during the latest vacation as thrown
+    /// away, and returns the report afterwards.
+    async fn execute(&self, params: CleanUpVacationParams)
+    -> Result<VacationReport, VacationError>;
+}
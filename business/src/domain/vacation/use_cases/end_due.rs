@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::vacation::errors::VacationError;
+
+pub struct EndDueVacationsParams {
+    /// Cap on vacations ended in a single run
+    pub max_users: usize,
+}
+
+#[async_trait]
+pub trait EndDueVacationsUseCase: Send + Sync {
+    /// Ends the vacations whose planned return has passed; returns how many.
+    async fn execute(&self, params: EndDueVacationsParams) -> Result<u32, VacationError>;
+}
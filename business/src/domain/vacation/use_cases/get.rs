@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::Vacation;
+
+pub struct GetVacationModeParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetVacationModeUseCase: Send + Sync {
+    /// The vacation going on, or `None` when vacation mode is off.
+    async fn execute(
+        &self,
+        params: GetVacationModeParams,
+    ) -> Result<Option<Vacation>, VacationError>;
+}
@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::VacationReport;
+
+pub struct GetVacationReportParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait GetVacationReportUseCase: Send + Sync {
+    /// Report of the user's latest vacation.
+    async fn execute(
+        &self,
+        params: GetVacationReportParams,
+    ) -> Result<VacationReport, VacationError>;
+}
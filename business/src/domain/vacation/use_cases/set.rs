@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::vacation::errors::VacationError;
+use crate::domain::vacation::model::Vacation;
+
+pub struct SetVacationModeParams {
+    pub user_id: UserId,
+    pub enabled: bool,
+    /// Planned return, ignored when turning vacation mode off. Turning it on
+    /// while already on only moves the planned return.
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[async_trait]
+pub trait SetVacationModeUseCase: Send + Sync {
+    /// The vacation going on afterwards, or `None` when vacation mode is off.
+    async fn execute(
+        &self,
+        params: SetVacationModeParams,
+    ) -> Result<Option<Vacation>, VacationError>;
+}
@@ -0,0 +1,7 @@
+#[derive(Debug, thiserror::Error)]
+pub enum WidgetError {
+    #[error("widget.invalid_token")]
+    InvalidToken,
+    #[error("repository.persistence")]
+    Repository(#[from] crate::domain::errors::RepositoryError),
+}
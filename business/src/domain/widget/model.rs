@@ -0,0 +1,100 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{DateTime, Utc};
+use rand::RngCore;
+use uuid::Uuid;
+
+use crate::domain::product::urgency::{ExpiringSoonWindow, urgent_until};
+use crate::domain::share_link::model::hash_token;
+use crate::domain::shared::value_objects::UserId;
+
+/// Urgent products a widget shows.
+pub const WIDGET_URGENT_PRODUCTS: u32 = 3;
+/// Unbought shopping items a widget shows.
+pub const WIDGET_SHOPPING_ITEMS: u32 = 5;
+
+const TOKEN_BYTES: usize = 32;
+
+/// Read-only credential for home screen widgets. Unlike bearer tokens it
+/// never expires, so widgets keep working without the app refreshing a
+/// session; it only unlocks the widget snapshot. A user has at most one:
+/// issuing a new token replaces the previous one.
+///
+/// Only the SHA-256 hash of the token is stored.
+#[derive(Debug, Clone)]
+pub struct WidgetToken {
+    pub user_id: UserId,
+    pub token_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WidgetToken {
+    /// Issues a new token and returns it together with the plain value,
+    /// which is only available at creation time.
+    pub fn issue(user_id: UserId) -> (Self, String) {
+        let mut bytes = [0u8; TOKEN_BYTES];
+        rand::rng().fill_bytes(&mut bytes);
+        let token = URL_SAFE_NO_PAD.encode(bytes);
+
+        let widget_token = Self {
+            user_id,
+            token_hash: hash_token(&token),
+            created_at: Utc::now(),
+        };
+        (widget_token, token)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetProduct {
+    pub id: Uuid,
+    pub name: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetItem {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// Everything a widget renders: the most urgent products, soonest first,
+/// and the oldest unbought shopping items, with totals for a "+N more".
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WidgetSnapshot {
+    pub urgent_products: Vec<WidgetProduct>,
+    pub urgent_total: u32,
+    pub shopping_items: Vec<WidgetItem>,
+    pub unbought_total: u32,
+}
+
+/// Time bounds of "urgent", derived once per request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WidgetWindow {
+    /// Products expiring before this are already expired and not urgent.
+    pub now: DateTime<Utc>,
+    /// Products expiring at or after this are not urgent yet.
+    pub urgent_until: DateTime<Utc>,
+}
+
+impl WidgetWindow {
+    pub fn at(now: DateTime<Utc>, expiring_soon: ExpiringSoonWindow) -> Self {
+        Self {
+            now,
+            urgent_until: urgent_until(now, expiring_soon),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_store_only_token_hash() {
+        let (widget_token, token) = WidgetToken::issue(UserId::new("test-user-id"));
+
+        assert_ne!(widget_token.token_hash, token);
+        assert_eq!(widget_token.token_hash, hash_token(&token));
+    }
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::model::{WidgetSnapshot, WidgetToken, WidgetWindow};
+use crate::domain::errors::RepositoryError;
+use crate::domain::shared::value_objects::UserId;
+
+#[async_trait]
+pub trait WidgetRepository: Send + Sync {
+    async fn find_owner(&self, token_hash: &str) -> Result<Option<UserId>, RepositoryError>;
+    /// Stores the token, replacing the user's previous one.
+    async fn save_token(&self, token: &WidgetToken) -> Result<(), RepositoryError>;
+    async fn delete_token(&self, user_id: &UserId) -> Result<(), RepositoryError>;
+    /// Implementations should answer with a single query: widgets poll often
+    /// and give up quickly.
+    async fn get_snapshot(
+        &self,
+        user_id: &UserId,
+        window: &WidgetWindow,
+    ) -> Result<WidgetSnapshot, RepositoryError>;
+}
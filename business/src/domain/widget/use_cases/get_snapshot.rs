@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+
+use crate::domain::widget::errors::WidgetError;
+use crate::domain::widget::model::WidgetSnapshot;
+
+pub struct GetWidgetSnapshotParams {
+    pub token: String,
+}
+
+#[async_trait]
+pub trait GetWidgetSnapshotUseCase: Send + Sync {
+    async fn execute(&self, params: GetWidgetSnapshotParams)
+    -> Result<WidgetSnapshot, WidgetError>;
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::widget::errors::WidgetError;
+use crate::domain::widget::model::WidgetToken;
+
+pub struct IssueWidgetTokenParams {
+    pub user_id: UserId,
+}
+
+/// A newly issued widget token along with its plain value.
+#[derive(Debug)]
+pub struct IssuedWidgetToken {
+    pub widget_token: WidgetToken,
+    pub token: String,
+}
+
+#[async_trait]
+pub trait IssueWidgetTokenUseCase: Send + Sync {
+    async fn execute(
+        &self,
+        params: IssueWidgetTokenParams,
+    ) -> Result<IssuedWidgetToken, WidgetError>;
+}
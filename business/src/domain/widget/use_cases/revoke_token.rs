@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::shared::value_objects::UserId;
+use crate::domain::widget::errors::WidgetError;
+
+pub struct RevokeWidgetTokenParams {
+    pub user_id: UserId,
+}
+
+#[async_trait]
+pub trait RevokeWidgetTokenUseCase: Send + Sync {
+    async fn execute(&self, params: RevokeWidgetTokenParams) -> Result<(), WidgetError>;
+}
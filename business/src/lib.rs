@@ -1,55 +1,569 @@
 pub mod application {
+    pub mod ai_review {
+        pub mod accept;
+        pub mod get_mode;
+        pub mod get_pending;
+        pub mod reject;
+        pub mod set_mode;
+    }
+    pub mod auth {
+        pub mod finish_passkey_login;
+        pub mod finish_passkey_registration;
+        pub mod request_magic_link;
+        pub mod start_passkey_login;
+        pub mod start_passkey_registration;
+        pub mod verify_magic_link;
+    }
+    pub mod backup {
+        pub mod export;
+        pub mod restore;
+    }
+    pub mod badge {
+        pub mod get_badges;
+    }
+    pub mod barcode_contribution {
+        pub mod submit;
+    }
+    pub mod budget {
+        pub mod alert_policy;
+        pub mod delete;
+        pub mod get_status;
+        pub mod set;
+    }
+    pub mod billing {
+        pub mod create_checkout;
+        pub mod handle_webhook;
+    }
+    pub mod challenge {
+        pub mod get_current;
+        pub mod get_history;
+        pub mod progress_policy;
+    }
+    pub mod cooking_session {
+        pub mod advance;
+        pub mod complete;
+        pub mod complete_step;
+        pub mod get_by_id;
+        pub mod start;
+    }
+    pub mod device {
+        pub mod get_active_installs;
+        pub mod prune_stale;
+        pub mod register;
+    }
+    pub mod events {
+        pub mod in_process;
+    }
+    pub mod experiment {
+        pub mod accept_identification;
+        pub mod ai_experiment;
+        pub mod get_results;
+    }
+    pub mod feature_flag {
+        pub mod get_client_config;
+    }
+    pub mod inbound_email {
+        pub mod get_address;
+        pub mod receive;
+        pub mod rotate_address;
+    }
+    pub mod job {
+        pub mod get_by_id;
+    }
+    pub mod location_rule {
+        pub mod get;
+        pub mod replace;
+    }
+    pub mod meal_plan {
+        pub mod expire_reservations;
+        pub mod plan;
+    }
+    pub mod notification {
+        pub mod acknowledge;
+        pub mod dispatcher;
+        pub mod get_preferences;
+        pub mod prune;
+        pub mod send_expiry_alerts;
+        pub mod update_preferences;
+    }
+    pub mod preference {
+        pub mod get;
+        pub mod update;
+    }
     pub mod product {
+        pub mod bulk_move;
+        pub mod change_feed;
         pub mod create;
+        pub mod create_batch;
         pub mod delete;
         pub mod estimate_expiry;
+        pub mod estimate_expiry_batch;
+        pub mod estimate_expiry_for_attributes;
+        pub mod estimate_missing;
+        pub mod estimation_retry;
+        pub mod flag_unwanted;
         pub mod get_all;
+        pub mod get_allergen_warnings;
         pub mod get_by_id;
+        pub mod get_calendar;
+        pub mod get_duplicates;
+        pub mod get_facets;
+        pub mod get_give_away;
+        pub mod get_history;
+        pub mod get_image;
+        pub mod get_thumbnails;
         pub mod identify;
+        pub mod merge_duplicates;
+        pub mod ocr_fallback;
+        pub mod patch;
+        pub mod profiled_scanner;
+        pub mod propose_from_photo;
         pub mod scan_receipt;
+        pub mod seed;
+        pub mod subscribe_changes;
+        pub mod update;
+        pub mod upload_image;
+    }
+    pub mod prompt {
+        pub mod reload;
+    }
+    pub mod quota {
+        pub mod get_usage;
+    }
+    pub mod receipt_import {
+        pub mod get_by_id;
+        pub mod get_image;
+        pub mod pipeline;
+        pub mod start;
+    }
+    pub mod reminder {
+        pub mod create;
+        pub mod delete;
+        pub mod get_all;
+        pub mod send_due;
         pub mod update;
     }
+    pub mod sandbox {
+        pub mod reset;
+    }
+    pub mod search {
+        pub mod global;
+    }
+    pub mod share_link {
+        pub mod create;
+        pub mod get_shared_view;
+        pub mod revoke;
+    }
     pub mod shopping_item {
+        pub mod attach;
         pub mod clear_bought;
         pub mod create;
+        pub mod create_from_barcode;
+        pub mod delete;
+        pub mod get_all;
+        pub mod get_attachment_urls;
+        pub mod restock_policy;
+        pub mod update;
+    }
+    pub mod shopping_trip {
+        pub mod finish;
+        pub mod record_item;
+        pub mod start;
+    }
+    pub mod stats {
+        pub mod get_inventory_trends;
+        pub mod get_inventory_value;
+        pub mod get_waste_streak;
+        pub mod record_snapshots;
+        pub mod record_waste_streaks;
+    }
+    pub mod storage {
+        pub mod download;
+        pub mod thumbnail;
+    }
+    pub mod store_profile {
+        pub mod activate;
+        pub mod create;
         pub mod delete;
         pub mod get_all;
         pub mod update;
     }
     pub mod suggestion {
         pub mod generate;
+        pub mod get_history;
+        pub mod pregenerate;
+        pub mod refresh_policy;
+    }
+    pub mod widget {
+        pub mod get_snapshot;
+        pub mod issue_token;
+        pub mod revoke_token;
+    }
+    pub mod vacation {
+        pub mod clean_up;
+        pub mod end_due;
+        pub mod get;
+        pub mod get_report;
+        pub mod set;
     }
 }
 
 pub mod domain {
     pub mod errors;
+    pub mod events;
     pub mod logger;
+    pub mod metrics;
     pub mod shared;
+    pub mod ai_review {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod accept;
+            pub mod get_mode;
+            pub mod get_pending;
+            pub mod reject;
+            pub mod set_mode;
+        }
+    }
+    pub mod auth {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod services;
+        pub mod use_cases {
+            pub mod finish_passkey_login;
+            pub mod finish_passkey_registration;
+            pub mod request_magic_link;
+            pub mod start_passkey_login;
+            pub mod start_passkey_registration;
+            pub mod verify_magic_link;
+        }
+    }
+    pub mod backup {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod export;
+            pub mod restore;
+        }
+    }
+    pub mod badge {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_badges;
+        }
+    }
+    pub mod barcode_contribution {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod services;
+        pub mod use_cases {
+            pub mod submit;
+        }
+    }
+    pub mod budget {
+        pub mod errors;
+        pub mod events;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod delete;
+            pub mod get_status;
+            pub mod set;
+        }
+    }
+    pub mod billing {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod services;
+        pub mod use_cases {
+            pub mod create_checkout;
+            pub mod handle_webhook;
+        }
+    }
+    pub mod challenge {
+        pub mod errors;
+        pub mod events;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_current;
+            pub mod get_history;
+        }
+    }
+    pub mod cooking_session {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod advance;
+            pub mod complete;
+            pub mod complete_step;
+            pub mod get_by_id;
+            pub mod start;
+        }
+    }
+    pub mod device {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_active_installs;
+            pub mod prune_stale;
+            pub mod register;
+        }
+    }
+    pub mod experiment {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod accept_identification;
+            pub mod get_results;
+        }
+    }
+    pub mod feature_flag {
+        pub mod errors;
+        pub mod model;
+        pub mod use_cases {
+            pub mod get_client_config;
+        }
+    }
+    pub mod inbound_email {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod services;
+        pub mod use_cases {
+            pub mod get_address;
+            pub mod receive;
+            pub mod rotate_address;
+        }
+    }
+    pub mod job {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_by_id;
+        }
+    }
+    pub mod location_rule {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get;
+            pub mod replace;
+        }
+    }
+    pub mod meal_plan {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod expire_reservations;
+            pub mod plan;
+        }
+    }
+    pub mod notification {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod services;
+        pub mod use_cases {
+            pub mod acknowledge;
+            pub mod get_preferences;
+            pub mod prune;
+            pub mod send_expiry_alerts;
+            pub mod update_preferences;
+        }
+    }
+    pub mod preference {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get;
+            pub mod update;
+        }
+    }
     pub mod product {
+        pub mod calendar;
+        pub mod duplicates;
+        pub mod enrichment;
         pub mod errors;
+        pub mod events;
+        pub mod expiry_snap;
+        pub mod facets;
+        pub mod fixtures;
+        pub mod lifecycle;
+        pub mod local_day;
         pub mod model;
+        pub mod photo_diff;
+        pub mod query;
+        pub mod receipt_profile;
+        pub mod receipt_text;
         pub mod repository;
         pub mod services;
+        pub mod shelf_life;
+        pub mod tags;
         pub mod urgency;
         pub mod value_objects;
         pub mod use_cases {
+            pub mod bulk_move;
             pub mod create;
+            pub mod create_batch;
             pub mod delete;
             pub mod estimate_expiry;
+            pub mod estimate_expiry_batch;
+            pub mod estimate_expiry_for_attributes;
+            pub mod estimate_missing;
+            pub mod flag_unwanted;
             pub mod get_all;
+            pub mod get_allergen_warnings;
             pub mod get_by_id;
+            pub mod get_calendar;
+            pub mod get_duplicates;
+            pub mod get_facets;
+            pub mod get_give_away;
+            pub mod get_history;
+            pub mod get_image;
+            pub mod get_thumbnails;
             pub mod identify;
+            pub mod merge_duplicates;
+            pub mod patch;
+            pub mod propose_from_photo;
             pub mod scan_receipt;
+            pub mod seed;
+            pub mod subscribe_changes;
+            pub mod update;
+            pub mod upload_image;
+        }
+    }
+    pub mod prompt {
+        pub mod errors;
+        pub mod model;
+        pub mod services;
+        pub mod use_cases {
+            pub mod reload;
+        }
+    }
+    pub mod quota {
+        pub mod errors;
+        pub mod model;
+        pub mod services;
+        pub mod use_cases {
+            pub mod get_usage;
+        }
+    }
+    pub mod receipt_import {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_by_id;
+            pub mod get_image;
+            pub mod start;
+        }
+    }
+    pub mod reminder {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod create;
+            pub mod delete;
+            pub mod get_all;
+            pub mod send_due;
             pub mod update;
         }
     }
+    pub mod sandbox {
+        pub mod errors;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod reset;
+        }
+    }
+    pub mod search {
+        pub mod errors;
+        pub mod model;
+        pub mod use_cases {
+            pub mod global;
+        }
+    }
+    pub mod share_link {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod create;
+            pub mod get_shared_view;
+            pub mod revoke;
+        }
+    }
     pub mod shopping_item {
         pub mod errors;
         pub mod model;
         pub mod repository;
+        pub mod text_list;
         pub mod use_cases {
+            pub mod attach;
             pub mod clear_bought;
             pub mod create;
+            pub mod create_from_barcode;
+            pub mod delete;
+            pub mod get_all;
+            pub mod get_attachment_urls;
+            pub mod update;
+        }
+    }
+    pub mod shopping_trip {
+        pub mod errors;
+        pub mod events;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod finish;
+            pub mod record_item;
+            pub mod start;
+        }
+    }
+    pub mod stats {
+        pub mod errors;
+        pub mod events;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_inventory_trends;
+            pub mod get_inventory_value;
+            pub mod get_waste_streak;
+            pub mod record_snapshots;
+            pub mod record_waste_streaks;
+        }
+    }
+    pub mod storage {
+        pub mod errors;
+        pub mod model;
+        pub mod services;
+        pub mod use_cases {
+            pub mod download;
+        }
+    }
+    pub mod store_profile {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod activate;
+            pub mod create;
             pub mod delete;
             pub mod get_all;
             pub mod update;
@@ -57,10 +571,38 @@ pub mod domain {
     }
     pub mod suggestion {
         pub mod errors;
+        pub mod ingredient_validation;
         pub mod model;
+        pub mod prioritized_pantry;
+        pub mod repository;
         pub mod services;
         pub mod use_cases {
             pub mod generate;
+            pub mod get_history;
+            pub mod pregenerate;
+        }
+    }
+    pub mod widget {
+        pub mod errors;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod get_snapshot;
+            pub mod issue_token;
+            pub mod revoke_token;
+        }
+    }
+    pub mod vacation {
+        pub mod errors;
+        pub mod events;
+        pub mod model;
+        pub mod repository;
+        pub mod use_cases {
+            pub mod clean_up;
+            pub mod end_due;
+            pub mod get;
+            pub mod get_report;
+            pub mod set;
         }
     }
 }
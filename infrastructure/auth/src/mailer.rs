@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use business::domain::auth::errors::AuthError;
+use business::domain::auth::services::MagicLinkSender;
+use business::domain::logger::Logger;
+
+const SUBJECT: &str = "Your Foodie sign-in link";
+
+#[derive(Debug, Clone)]
+pub struct WebhookMailerSettings {
+    /// Endpoint of the mail relay
+    pub url: String,
+    /// Sent as a bearer token, if the relay wants one
+    pub token: Option<String>,
+}
+
+#[derive(Serialize)]
+struct MailRequest<'a> {
+    to: &'a str,
+    subject: &'a str,
+    text: String,
+}
+
+/// Hands sign-in emails to an HTTP relay as `{to, subject, text}` JSON, so
+/// self-hosters can plug in whatever delivers mail for them (a transactional
+/// email API, a small SMTP bridge, …).
+pub struct WebhookMailer {
+    client: reqwest::Client,
+    settings: WebhookMailerSettings,
+}
+
+impl WebhookMailer {
+    pub fn new(settings: WebhookMailerSettings) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { client, settings }
+    }
+}
+
+fn body(link: &str) -> String {
+    format!(
+        "Open this link to sign in to Foodie:\n\n{link}\n\nIf you did not ask for it, you can ignore this email."
+    )
+}
+
+#[async_trait]
+impl MagicLinkSender for WebhookMailer {
+    async fn send(&self, email: &str, link: &str) -> Result<(), AuthError> {
+        let mut request = self.client.post(&self.settings.url).json(&MailRequest {
+            to: email,
+            subject: SUBJECT,
+            text: body(link),
+        });
+        if let Some(token) = &self.settings.token {
+            request = request.bearer_auth(token);
+        }
+
+        request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(AuthError::delivery_failed)?;
+        Ok(())
+    }
+}
+
+/// Writes sign-in links to the log instead of mailing them, for development
+/// and single-user setups without a mail relay.
+pub struct LogMailer {
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl MagicLinkSender for LogMailer {
+    async fn send(&self, email: &str, link: &str) -> Result<(), AuthError> {
+        self.logger
+            .info(&format!("Sign-in link for {email}: {link}"));
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use serde::{Deserialize, Serialize};
+
+use business::domain::auth::errors::AuthError;
+use business::domain::auth::model::IssuedToken;
+use business::domain::auth::services::TokenIssuer;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, Clone)]
+pub struct LocalTokenSettings {
+    /// HMAC key tokens are signed with
+    pub secret: String,
+    /// `iss` claim of issued tokens; tokens from anyone else are refused
+    pub issuer: String,
+    /// How long a token stays valid
+    pub ttl: Duration,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct LocalClaims {
+    sub: String,
+    iss: String,
+    iat: i64,
+    exp: i64,
+}
+
+/// HS256 JWTs issued by this server after a passkey or magic-link sign-in,
+/// for deployments that do not use Firebase. Tokens are not revocable before
+/// they expire, so keep the lifetime short enough to live with that.
+pub struct LocalTokens {
+    settings: LocalTokenSettings,
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+}
+
+impl LocalTokens {
+    pub fn new(settings: LocalTokenSettings) -> Self {
+        Self {
+            encoding_key: EncodingKey::from_secret(settings.secret.as_bytes()),
+            decoding_key: DecodingKey::from_secret(settings.secret.as_bytes()),
+            settings,
+        }
+    }
+
+    /// User id of a token issued by [`LocalTokens::issue`], or an error code
+    /// if it is malformed, expired or signed with another key.
+    pub fn verify(&self, token: &str) -> Result<String, String> {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&[&self.settings.issuer]);
+        validation.validate_aud = false;
+
+        decode::<LocalClaims>(token, &self.decoding_key, &validation)
+            .map(|data| data.claims.sub)
+            .map_err(|e| format!("auth.token_validation_failed: {e}"))
+    }
+}
+
+impl TokenIssuer for LocalTokens {
+    fn issue(&self, user_id: &UserId) -> Result<IssuedToken, AuthError> {
+        let now = Utc::now();
+        let expires_at = now + self.settings.ttl;
+        let claims = LocalClaims {
+            sub: user_id.to_string(),
+            iss: self.settings.issuer.clone(),
+            iat: now.timestamp(),
+            exp: expires_at.timestamp(),
+        };
+
+        let token = encode(&Header::new(Algorithm::HS256), &claims, &self.encoding_key)
+            .map_err(AuthError::token_failed)?;
+        Ok(IssuedToken {
+            token,
+            user_id: user_id.clone(),
+            expires_at,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(secret: &str, ttl: Duration) -> LocalTokens {
+        LocalTokens::new(LocalTokenSettings {
+            secret: secret.to_string(),
+            issuer: "foodie-backend".to_string(),
+            ttl,
+        })
+    }
+
+    #[test]
+    fn should_verify_own_token() {
+        let tokens = tokens("secret", Duration::days(30));
+
+        let issued = tokens.issue(&UserId::new("user-1")).unwrap();
+
+        assert_eq!(tokens.verify(&issued.token).unwrap(), "user-1");
+    }
+
+    #[test]
+    fn should_reject_token_signed_with_another_secret() {
+        let issued = tokens("other-secret", Duration::days(30))
+            .issue(&UserId::new("user-1"))
+            .unwrap();
+
+        let result = tokens("secret", Duration::days(30)).verify(&issued.token);
+
+        assert!(result.unwrap_err().contains("auth.token_validation_failed"));
+    }
+
+    #[test]
+    fn should_reject_expired_token() {
+        let tokens = tokens("secret", Duration::hours(-1));
+
+        let issued = tokens.issue(&UserId::new("user-1")).unwrap();
+
+        assert!(tokens.verify(&issued.token).is_err());
+    }
+}
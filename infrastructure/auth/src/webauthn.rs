@@ -0,0 +1,345 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use ciborium::value::Value;
+use ring::signature::{ECDSA_P256_SHA256_ASN1, UnparsedPublicKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use business::domain::auth::errors::AuthError;
+use business::domain::auth::services::{
+    PasskeyAssertion, PasskeyAttestation, PasskeyVerifier, VerifiedAssertion, VerifiedRegistration,
+};
+
+/// User present
+const FLAG_UP: u8 = 0x01;
+/// Attested credential data included
+const FLAG_AT: u8 = 0x40;
+
+/// rpIdHash (32) + flags (1) + signCount (4)
+const AUTH_DATA_HEADER_LEN: usize = 37;
+const AAGUID_LEN: usize = 16;
+
+/// COSE key type EC2, algorithm ES256 and curve P-256
+const COSE_KTY_EC2: i128 = 2;
+const COSE_ALG_ES256: i128 = -7;
+const COSE_CRV_P256: i128 = 1;
+
+#[derive(Debug, Clone)]
+pub struct WebAuthnSettings {
+    /// Domain passkeys are bound to, e.g. "foodie.example.com"
+    pub rp_id: String,
+    /// Origins the apps sign in from, e.g. "https://foodie.example.com" or
+    /// "android:apk-key-hash:…"
+    pub origins: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    kind: String,
+    challenge: String,
+    origin: String,
+}
+
+/// Fixed part of the authenticator data.
+struct AuthData<'a> {
+    flags: u8,
+    sign_count: u32,
+    /// Attested credential data and extensions, if any
+    rest: &'a [u8],
+}
+
+/// WebAuthn checks for ES256 passkeys, the algorithm every platform
+/// authenticator offers. Attestation statements are not checked: passkeys
+/// here replace a password, so which device made them does not matter.
+pub struct WebAuthnVerifier {
+    settings: WebAuthnSettings,
+    rp_id_hash: [u8; 32],
+}
+
+impl WebAuthnVerifier {
+    pub fn new(settings: WebAuthnSettings) -> Self {
+        Self {
+            rp_id_hash: Sha256::digest(settings.rp_id.as_bytes()).into(),
+            settings,
+        }
+    }
+
+    /// Parses client data and checks it was made for `kind` on one of our
+    /// origins; returns its challenge.
+    fn client_data(&self, json: &[u8], kind: &str) -> Result<String, AuthError> {
+        let client_data: ClientData =
+            serde_json::from_slice(json).map_err(|_| AuthError::InvalidPasskey)?;
+        if client_data.kind != kind || !self.settings.origins.contains(&client_data.origin) {
+            return Err(AuthError::InvalidPasskey);
+        }
+        Ok(client_data.challenge)
+    }
+
+    /// Parses authenticator data and checks it was made for our relying
+    /// party with the user present.
+    fn auth_data<'a>(&self, bytes: &'a [u8]) -> Result<AuthData<'a>, AuthError> {
+        if bytes.len() < AUTH_DATA_HEADER_LEN || bytes[..32] != self.rp_id_hash {
+            return Err(AuthError::InvalidPasskey);
+        }
+        let flags = bytes[32];
+        if flags & FLAG_UP == 0 {
+            return Err(AuthError::InvalidPasskey);
+        }
+        let sign_count = u32::from_be_bytes([bytes[33], bytes[34], bytes[35], bytes[36]]);
+        Ok(AuthData {
+            flags,
+            sign_count,
+            rest: &bytes[AUTH_DATA_HEADER_LEN..],
+        })
+    }
+}
+
+fn decode(field: &str) -> Result<Vec<u8>, AuthError> {
+    URL_SAFE_NO_PAD
+        .decode(field.trim_end_matches('='))
+        .map_err(|_| AuthError::InvalidPasskey)
+}
+
+fn map_entry<'a>(map: &'a [(Value, Value)], key: &Value) -> Option<&'a Value> {
+    map.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+}
+
+/// Uncompressed point (`0x04 || x || y`) of an ES256 COSE key.
+fn cose_p256_point(cose: &[u8]) -> Result<Vec<u8>, AuthError> {
+    let key: Value = ciborium::de::from_reader(cose).map_err(|_| AuthError::InvalidPasskey)?;
+    let map = key.as_map().ok_or(AuthError::InvalidPasskey)?;
+    let int = |label: i64| {
+        map_entry(map, &Value::from(label))
+            .and_then(Value::as_integer)
+            .map(i128::from)
+    };
+    let coordinate = |label: i64| {
+        map_entry(map, &Value::from(label))
+            .and_then(Value::as_bytes)
+            .filter(|bytes| bytes.len() == 32)
+    };
+
+    if int(1) != Some(COSE_KTY_EC2)
+        || int(3) != Some(COSE_ALG_ES256)
+        || int(-1) != Some(COSE_CRV_P256)
+    {
+        return Err(AuthError::InvalidPasskey);
+    }
+    let (x, y) = coordinate(-2)
+        .zip(coordinate(-3))
+        .ok_or(AuthError::InvalidPasskey)?;
+
+    let mut point = Vec::with_capacity(65);
+    point.push(0x04);
+    point.extend_from_slice(x);
+    point.extend_from_slice(y);
+    Ok(point)
+}
+
+impl PasskeyVerifier for WebAuthnVerifier {
+    fn verify_registration(
+        &self,
+        attestation: &PasskeyAttestation,
+    ) -> Result<VerifiedRegistration, AuthError> {
+        let challenge =
+            self.client_data(&decode(&attestation.client_data_json)?, "webauthn.create")?;
+
+        let object: Value =
+            ciborium::de::from_reader(decode(&attestation.attestation_object)?.as_slice())
+                .map_err(|_| AuthError::InvalidPasskey)?;
+        let auth_data = object
+            .as_map()
+            .and_then(|map| map_entry(map, &Value::from("authData")))
+            .and_then(Value::as_bytes)
+            .ok_or(AuthError::InvalidPasskey)?;
+        let auth_data = self.auth_data(auth_data)?;
+        if auth_data.flags & FLAG_AT == 0 || auth_data.rest.len() < AAGUID_LEN + 2 {
+            return Err(AuthError::InvalidPasskey);
+        }
+
+        let credential = &auth_data.rest[AAGUID_LEN..];
+        let id_len = u16::from_be_bytes([credential[0], credential[1]]) as usize;
+        let credential = &credential[2..];
+        if credential.len() < id_len
+            || credential[..id_len] != decode(&attestation.credential_id)?[..]
+        {
+            return Err(AuthError::InvalidPasskey);
+        }
+
+        Ok(VerifiedRegistration {
+            challenge,
+            public_key: cose_p256_point(&credential[id_len..])?,
+            sign_count: auth_data.sign_count,
+        })
+    }
+
+    fn verify_assertion(
+        &self,
+        assertion: &PasskeyAssertion,
+        public_key: &[u8],
+    ) -> Result<VerifiedAssertion, AuthError> {
+        let client_data_json = decode(&assertion.client_data_json)?;
+        let challenge = self.client_data(&client_data_json, "webauthn.get")?;
+        let auth_data_bytes = decode(&assertion.authenticator_data)?;
+        let auth_data = self.auth_data(&auth_data_bytes)?;
+
+        let mut signed = auth_data_bytes.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data_json));
+        UnparsedPublicKey::new(&ECDSA_P256_SHA256_ASN1, public_key)
+            .verify(&signed, &decode(&assertion.signature)?)
+            .map_err(|_| AuthError::InvalidPasskey)?;
+
+        Ok(VerifiedAssertion {
+            challenge,
+            sign_count: auth_data.sign_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_ASN1_SIGNING, EcdsaKeyPair, KeyPair};
+
+    const RP_ID: &str = "foodie.example.com";
+    const ORIGIN: &str = "https://foodie.example.com";
+
+    fn verifier() -> WebAuthnVerifier {
+        WebAuthnVerifier::new(WebAuthnSettings {
+            rp_id: RP_ID.to_string(),
+            origins: vec![ORIGIN.to_string()],
+        })
+    }
+
+    fn key_pair() -> EcdsaKeyPair {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, &rng).unwrap();
+        EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_ASN1_SIGNING, pkcs8.as_ref(), &rng).unwrap()
+    }
+
+    fn client_data(kind: &str, origin: &str) -> Vec<u8> {
+        serde_json::json!({"type": kind, "challenge": "challenge", "origin": origin})
+            .to_string()
+            .into_bytes()
+    }
+
+    fn auth_data(flags: u8, sign_count: u32) -> Vec<u8> {
+        let mut data = Sha256::digest(RP_ID.as_bytes()).to_vec();
+        data.push(flags);
+        data.extend_from_slice(&sign_count.to_be_bytes());
+        data
+    }
+
+    fn attestation_object(point: &[u8], credential_id: &[u8]) -> Vec<u8> {
+        let cose = Value::Map(vec![
+            (Value::from(1), Value::from(COSE_KTY_EC2 as i64)),
+            (Value::from(3), Value::from(COSE_ALG_ES256 as i64)),
+            (Value::from(-1), Value::from(COSE_CRV_P256 as i64)),
+            (Value::from(-2), Value::Bytes(point[1..33].to_vec())),
+            (Value::from(-3), Value::Bytes(point[33..].to_vec())),
+        ]);
+        let mut data = auth_data(FLAG_UP | FLAG_AT, 0);
+        data.extend_from_slice(&[0; AAGUID_LEN]);
+        data.extend_from_slice(&(credential_id.len() as u16).to_be_bytes());
+        data.extend_from_slice(credential_id);
+        ciborium::ser::into_writer(&cose, &mut data).unwrap();
+
+        let object = Value::Map(vec![
+            (Value::from("fmt"), Value::from("none")),
+            (Value::from("attStmt"), Value::Map(vec![])),
+            (Value::from("authData"), Value::Bytes(data)),
+        ]);
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(&object, &mut out).unwrap();
+        out
+    }
+
+    fn assertion(key: &EcdsaKeyPair, origin: &str, sign_count: u32) -> PasskeyAssertion {
+        let client_data = client_data("webauthn.get", origin);
+        let auth_data = auth_data(FLAG_UP, sign_count);
+        let mut signed = auth_data.clone();
+        signed.extend_from_slice(&Sha256::digest(&client_data));
+        let signature = key.sign(&SystemRandom::new(), &signed).unwrap();
+
+        PasskeyAssertion {
+            credential_id: URL_SAFE_NO_PAD.encode(b"cred-1"),
+            client_data_json: URL_SAFE_NO_PAD.encode(client_data),
+            authenticator_data: URL_SAFE_NO_PAD.encode(auth_data),
+            signature: URL_SAFE_NO_PAD.encode(signature.as_ref()),
+        }
+    }
+
+    #[test]
+    fn should_extract_public_key_from_registration() {
+        let key = key_pair();
+        let attestation = PasskeyAttestation {
+            credential_id: URL_SAFE_NO_PAD.encode(b"cred-1"),
+            client_data_json: URL_SAFE_NO_PAD.encode(client_data("webauthn.create", ORIGIN)),
+            attestation_object: URL_SAFE_NO_PAD
+                .encode(attestation_object(key.public_key().as_ref(), b"cred-1")),
+        };
+
+        let verified = verifier().verify_registration(&attestation).unwrap();
+
+        assert_eq!(verified.challenge, "challenge");
+        assert_eq!(verified.public_key, key.public_key().as_ref());
+    }
+
+    #[test]
+    fn should_reject_registration_for_another_credential() {
+        let key = key_pair();
+        let attestation = PasskeyAttestation {
+            credential_id: URL_SAFE_NO_PAD.encode(b"cred-2"),
+            client_data_json: URL_SAFE_NO_PAD.encode(client_data("webauthn.create", ORIGIN)),
+            attestation_object: URL_SAFE_NO_PAD
+                .encode(attestation_object(key.public_key().as_ref(), b"cred-1")),
+        };
+
+        let result = verifier().verify_registration(&attestation);
+
+        assert!(matches!(result, Err(AuthError::InvalidPasskey)));
+    }
+
+    #[test]
+    fn should_accept_assertion_signed_by_passkey() {
+        let key = key_pair();
+
+        let verified = verifier()
+            .verify_assertion(&assertion(&key, ORIGIN, 5), key.public_key().as_ref())
+            .unwrap();
+
+        assert_eq!(
+            verified,
+            VerifiedAssertion {
+                challenge: "challenge".to_string(),
+                sign_count: 5,
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_assertion_signed_by_another_key() {
+        let key = key_pair();
+
+        let result = verifier().verify_assertion(
+            &assertion(&key, ORIGIN, 5),
+            key_pair().public_key().as_ref(),
+        );
+
+        assert!(matches!(result, Err(AuthError::InvalidPasskey)));
+    }
+
+    #[test]
+    fn should_reject_assertion_from_unknown_origin() {
+        let key = key_pair();
+
+        let result = verifier().verify_assertion(
+            &assertion(&key, "https://evil.example.com", 5),
+            key.public_key().as_ref(),
+        );
+
+        assert!(matches!(result, Err(AuthError::InvalidPasskey)));
+    }
+}
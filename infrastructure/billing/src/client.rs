@@ -0,0 +1,33 @@
+use reqwest::Client;
+
+/// Shared Stripe HTTP client configuration.
+pub struct StripeClient {
+    pub client: Client,
+    pub secret_key: String,
+    pub base_url: String,
+}
+
+impl StripeClient {
+    pub fn new(secret_key: String) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            secret_key,
+            base_url: "https://api.stripe.com/v1".to_string(),
+        }
+    }
+
+    /// Builds the authorization header value.
+    pub fn auth_header(&self) -> String {
+        format!("Bearer {}", self.secret_key)
+    }
+
+    /// Returns the checkout sessions endpoint URL.
+    pub fn checkout_sessions_url(&self) -> String {
+        format!("{}/checkout/sessions", self.base_url)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod client;
+pub mod plan_provider;
+pub mod webhook;
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use business::domain::billing::errors::BillingError;
+use business::domain::billing::model::{CheckoutSession, WebhookEvent};
+use business::domain::billing::services::PlanProvider;
+use business::domain::shared::value_objects::UserId;
+
+use crate::client::StripeClient;
+use crate::webhook;
+
+/// Sells the premium plan as a Stripe subscription.
+pub struct StripePlanProvider {
+    client: StripeClient,
+    price_id: String,
+    success_url: String,
+    cancel_url: String,
+    webhook_secret: String,
+}
+
+impl StripePlanProvider {
+    pub fn new(
+        client: StripeClient,
+        price_id: String,
+        success_url: String,
+        cancel_url: String,
+        webhook_secret: String,
+    ) -> Self {
+        Self {
+            client,
+            price_id,
+            success_url,
+            cancel_url,
+            webhook_secret,
+        }
+    }
+}
+
+#[async_trait]
+impl PlanProvider for StripePlanProvider {
+    async fn create_checkout_session(
+        &self,
+        user_id: &UserId,
+    ) -> Result<CheckoutSession, BillingError> {
+        // The user id travels as client_reference_id for checkout events and as
+        // subscription metadata for later subscription events
+        let form = [
+            ("mode", "subscription"),
+            ("line_items[0][price]", self.price_id.as_str()),
+            ("line_items[0][quantity]", "1"),
+            ("success_url", self.success_url.as_str()),
+            ("cancel_url", self.cancel_url.as_str()),
+            ("client_reference_id", user_id.as_str()),
+            ("subscription_data[metadata][user_id]", user_id.as_str()),
+        ];
+
+        let response = self
+            .client
+            .client
+            .post(self.client.checkout_sessions_url())
+            .header("Authorization", self.client.auth_header())
+            .form(&form)
+            .send()
+            .await
+            .map_err(|_| BillingError::CheckoutFailed)?;
+
+        if !response.status().is_success() {
+            return Err(BillingError::CheckoutFailed);
+        }
+
+        let data: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|_| BillingError::CheckoutFailed)?;
+
+        match (data["id"].as_str(), data["url"].as_str()) {
+            (Some(id), Some(url)) => Ok(CheckoutSession {
+                id: id.to_string(),
+                url: url.to_string(),
+            }),
+            _ => Err(BillingError::CheckoutFailed),
+        }
+    }
+
+    fn parse_webhook(&self, payload: &[u8], signature: &str) -> Result<WebhookEvent, BillingError> {
+        webhook::verify_signature(
+            payload,
+            signature,
+            &self.webhook_secret,
+            Utc::now().timestamp(),
+        )?;
+        webhook::parse_event(payload)
+    }
+}
@@ -0,0 +1,253 @@
+use chrono::DateTime;
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+
+use business::domain::billing::errors::BillingError;
+use business::domain::billing::model::{BillingEvent, WebhookEvent};
+use business::domain::shared::value_objects::UserId;
+
+/// Maximum age of a signed webhook, matching Stripe's own libraries.
+const SIGNATURE_TOLERANCE_SECONDS: i64 = 300;
+
+/// Verifies a `Stripe-Signature` header (`t=<timestamp>,v1=<hex hmac>,...`)
+/// against the raw request body. Any `v1` entry may match, which lets Stripe
+/// roll the endpoint secret without downtime.
+pub fn verify_signature(
+    payload: &[u8],
+    header: &str,
+    secret: &str,
+    now: i64,
+) -> Result<(), BillingError> {
+    let mut timestamp = None;
+    let mut signatures = Vec::new();
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => signatures.push(value),
+            _ => {}
+        }
+    }
+
+    let timestamp = timestamp.ok_or(BillingError::InvalidSignature)?;
+    if (now - timestamp).abs() > SIGNATURE_TOLERANCE_SECONDS {
+        return Err(BillingError::InvalidSignature);
+    }
+
+    let valid = signatures.iter().any(|signature| {
+        let Ok(expected) = hex::decode(signature) else {
+            return false;
+        };
+        let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+            return false;
+        };
+        mac.update(timestamp.to_string().as_bytes());
+        mac.update(b".");
+        mac.update(payload);
+        // Constant-time comparison
+        mac.verify_slice(&expected).is_ok()
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(BillingError::InvalidSignature)
+    }
+}
+
+/// Maps a Stripe event to a plan change, keeping its id and creation time.
+pub fn parse_event(payload: &[u8]) -> Result<WebhookEvent, BillingError> {
+    let event: Value = serde_json::from_slice(payload).map_err(|_| BillingError::InvalidEvent)?;
+    let id = event["id"].as_str().ok_or(BillingError::InvalidEvent)?;
+    let created = event["created"]
+        .as_i64()
+        .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+        .ok_or(BillingError::InvalidEvent)?;
+
+    Ok(WebhookEvent {
+        id: id.to_string(),
+        created,
+        change: plan_change(&event)?,
+    })
+}
+
+/// The user is taken from `client_reference_id` on checkout sessions and from
+/// the `user_id` metadata copied onto subscriptions at checkout. Checkouts
+/// only grant the plan once paid: delayed payment methods complete the
+/// session first and send `async_payment_succeeded` when the money arrives.
+fn plan_change(event: &Value) -> Result<BillingEvent, BillingError> {
+    let event_type = event["type"].as_str().ok_or(BillingError::InvalidEvent)?;
+    let object = &event["data"]["object"];
+
+    let customer_id = object["customer"].as_str().map(str::to_string);
+    let subscription_user = || {
+        object["metadata"]["user_id"]
+            .as_str()
+            .map(UserId::new)
+            .ok_or(BillingError::InvalidEvent)
+    };
+
+    match event_type {
+        "checkout.session.completed" | "checkout.session.async_payment_succeeded" => {
+            if object["payment_status"].as_str() != Some("paid") {
+                return Ok(BillingEvent::Ignored);
+            }
+            let user_id = object["client_reference_id"]
+                .as_str()
+                .map(UserId::new)
+                .ok_or(BillingError::InvalidEvent)?;
+            Ok(BillingEvent::SubscriptionActivated {
+                user_id,
+                customer_id,
+            })
+        }
+        "customer.subscription.updated" => match object["status"].as_str() {
+            Some("active" | "trialing") => Ok(BillingEvent::SubscriptionActivated {
+                user_id: subscription_user()?,
+                customer_id,
+            }),
+            Some("canceled" | "unpaid" | "incomplete_expired") => {
+                Ok(BillingEvent::SubscriptionCanceled {
+                    user_id: subscription_user()?,
+                })
+            }
+            _ => Ok(BillingEvent::Ignored),
+        },
+        "customer.subscription.deleted" => Ok(BillingEvent::SubscriptionCanceled {
+            user_id: subscription_user()?,
+        }),
+        _ => Ok(BillingEvent::Ignored),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "whsec_test";
+    const NOW: i64 = 1_700_000_000;
+
+    fn sign(payload: &[u8], timestamp: i64) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(format!("{}.", timestamp).as_bytes());
+        mac.update(payload);
+        format!(
+            "t={},v1={}",
+            timestamp,
+            hex::encode(mac.finalize().into_bytes())
+        )
+    }
+
+    #[test]
+    fn should_accept_valid_signature() {
+        let payload = br#"{"type":"checkout.session.completed"}"#;
+
+        assert!(verify_signature(payload, &sign(payload, NOW), SECRET, NOW).is_ok());
+    }
+
+    #[test]
+    fn should_reject_signature_when_payload_tampered() {
+        let header = sign(br#"{"type":"checkout.session.completed"}"#, NOW);
+
+        let result = verify_signature(br#"{"type":"tampered"}"#, &header, SECRET, NOW);
+
+        assert!(matches!(result, Err(BillingError::InvalidSignature)));
+    }
+
+    #[test]
+    fn should_reject_signature_when_timestamp_too_old() {
+        let payload = br#"{"type":"checkout.session.completed"}"#;
+        let header = sign(payload, NOW - SIGNATURE_TOLERANCE_SECONDS - 1);
+
+        let result = verify_signature(payload, &header, SECRET, NOW);
+
+        assert!(matches!(result, Err(BillingError::InvalidSignature)));
+    }
+
+    #[test]
+    fn should_activate_plan_when_checkout_completed() {
+        let payload = br#"{
+            "id": "evt_123",
+            "created": 1700000000,
+            "type": "checkout.session.completed",
+            "data": {"object": {"client_reference_id": "firebase-uid-123", "customer": "cus_123", "payment_status": "paid"}}
+        }"#;
+
+        let event = parse_event(payload).unwrap();
+
+        assert_eq!(event.id, "evt_123");
+        assert_eq!(event.created.timestamp(), NOW);
+        assert_eq!(
+            event.change,
+            BillingEvent::SubscriptionActivated {
+                user_id: UserId::new("firebase-uid-123"),
+                customer_id: Some("cus_123".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn should_ignore_checkout_when_payment_pending() {
+        let payload = br#"{
+            "id": "evt_123",
+            "created": 1700000000,
+            "type": "checkout.session.completed",
+            "data": {"object": {"client_reference_id": "firebase-uid-123", "payment_status": "unpaid"}}
+        }"#;
+
+        assert_eq!(parse_event(payload).unwrap().change, BillingEvent::Ignored);
+    }
+
+    #[test]
+    fn should_activate_plan_when_delayed_payment_succeeds() {
+        let payload = br#"{
+            "id": "evt_123",
+            "created": 1700000000,
+            "type": "checkout.session.async_payment_succeeded",
+            "data": {"object": {"client_reference_id": "firebase-uid-123", "payment_status": "paid"}}
+        }"#;
+
+        assert_eq!(
+            parse_event(payload).unwrap().change,
+            BillingEvent::SubscriptionActivated {
+                user_id: UserId::new("firebase-uid-123"),
+                customer_id: None,
+            }
+        );
+    }
+
+    #[test]
+    fn should_reject_event_without_id() {
+        let payload =
+            br#"{"created": 1700000000, "type": "invoice.created", "data": {"object": {}}}"#;
+
+        assert!(matches!(
+            parse_event(payload),
+            Err(BillingError::InvalidEvent)
+        ));
+    }
+
+    #[test]
+    fn should_cancel_plan_when_subscription_deleted() {
+        let payload = br#"{
+            "id": "evt_123",
+            "created": 1700000000,
+            "type": "customer.subscription.deleted",
+            "data": {"object": {"customer": "cus_123", "metadata": {"user_id": "firebase-uid-123"}}}
+        }"#;
+
+        assert_eq!(
+            parse_event(payload).unwrap().change,
+            BillingEvent::SubscriptionCanceled {
+                user_id: UserId::new("firebase-uid-123"),
+            }
+        );
+    }
+
+    #[test]
+    fn should_ignore_unrelated_events() {
+        let payload = br#"{"id": "evt_123", "created": 1700000000, "type": "invoice.created", "data": {"object": {}}}"#;
+
+        assert_eq!(parse_event(payload).unwrap().change, BillingEvent::Ignored);
+    }
+}
@@ -0,0 +1,108 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use business::domain::inbound_email::errors::InboundEmailError;
+use business::domain::inbound_email::model::WebhookSignature;
+use business::domain::inbound_email::services::InboundEmailVerifier;
+
+/// Maximum age of a signed webhook, as for Stripe's.
+const SIGNATURE_TOLERANCE_SECONDS: i64 = 300;
+
+/// Checks the `timestamp`, `token` and `signature` fields Mailgun adds to
+/// forwarded messages: the signature is the hex HMAC-SHA256 of timestamp and
+/// token, keyed with the account's webhook signing key. Calls signed outside
+/// the tolerance are rejected, so a leaked signature can't be replayed later;
+/// within it, the token stops replays, since the use case only accepts it once.
+pub struct MailgunVerifier {
+    signing_key: String,
+}
+
+impl MailgunVerifier {
+    pub fn new(signing_key: String) -> Self {
+        Self { signing_key }
+    }
+
+    /// `now` in seconds since the Unix epoch.
+    fn verify_at(&self, signature: &WebhookSignature, now: i64) -> Result<(), InboundEmailError> {
+        let timestamp = signature
+            .timestamp
+            .parse::<i64>()
+            .map_err(|_| InboundEmailError::InvalidSignature)?;
+        if (now - timestamp).abs() > SIGNATURE_TOLERANCE_SECONDS {
+            return Err(InboundEmailError::InvalidSignature);
+        }
+
+        let expected =
+            hex::decode(&signature.signature).map_err(|_| InboundEmailError::InvalidSignature)?;
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.signing_key.as_bytes())
+            .map_err(|_| InboundEmailError::InvalidSignature)?;
+        mac.update(signature.timestamp.as_bytes());
+        mac.update(signature.token.as_bytes());
+        // Constant-time comparison
+        mac.verify_slice(&expected)
+            .map_err(|_| InboundEmailError::InvalidSignature)
+    }
+}
+
+impl InboundEmailVerifier for MailgunVerifier {
+    fn verify(&self, signature: &WebhookSignature) -> Result<(), InboundEmailError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_secs() as i64)
+            .unwrap_or_default();
+        self.verify_at(signature, now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "key-test";
+    const NOW: i64 = 1_773_000_000;
+
+    fn signed(timestamp: &str, token: &str) -> WebhookSignature {
+        let mut mac = Hmac::<Sha256>::new_from_slice(KEY.as_bytes()).unwrap();
+        mac.update(timestamp.as_bytes());
+        mac.update(token.as_bytes());
+        WebhookSignature {
+            timestamp: timestamp.to_string(),
+            token: token.to_string(),
+            signature: hex::encode(mac.finalize().into_bytes()),
+        }
+    }
+
+    #[test]
+    fn should_accept_valid_signature() {
+        let verifier = MailgunVerifier::new(KEY.to_string());
+
+        assert!(
+            verifier
+                .verify_at(&signed("1773000000", "abc"), NOW + 10)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn should_reject_signature_when_token_tampered() {
+        let verifier = MailgunVerifier::new(KEY.to_string());
+        let mut signature = signed("1773000000", "abc");
+        signature.token = "other".to_string();
+
+        let result = verifier.verify_at(&signature, NOW);
+
+        assert!(matches!(result, Err(InboundEmailError::InvalidSignature)));
+    }
+
+    #[test]
+    fn should_reject_signature_when_timestamp_too_old() {
+        let verifier = MailgunVerifier::new(KEY.to_string());
+        let signature = signed("1773000000", "abc");
+
+        let result = verifier.verify_at(&signature, NOW + SIGNATURE_TOLERANCE_SECONDS + 1);
+
+        assert!(matches!(result, Err(InboundEmailError::InvalidSignature)));
+    }
+}
@@ -1,2 +1,6 @@
+pub mod redaction;
 pub mod tracing_logger;
+pub mod tracing_metrics;
+pub use redaction::RedactionPolicy;
 pub use tracing_logger::TracingLogger;
+pub use tracing_metrics::TracingMetrics;
@@ -1,19 +1,39 @@
 use business::domain::logger::Logger;
 use tracing::{debug, error, info, warn};
 
-pub struct TracingLogger;
+use crate::redaction::{RedactionPolicy, Redactor};
+
+/// Logger backed by `tracing` that redacts every message first, so lines are
+/// safe to ship to a log aggregator.
+pub struct TracingLogger {
+    redactor: Redactor,
+}
+
+impl TracingLogger {
+    pub fn new(policy: RedactionPolicy) -> Self {
+        Self {
+            redactor: Redactor::new(policy),
+        }
+    }
+}
+
+impl Default for TracingLogger {
+    fn default() -> Self {
+        Self::new(RedactionPolicy::default())
+    }
+}
 
 impl Logger for TracingLogger {
     fn info(&self, message: &str) {
-        info!(target: "Backend -- ", "{}", message);
+        info!(target: "Backend -- ", "{}", self.redactor.redact(message));
     }
     fn warn(&self, message: &str) {
-        warn!(target: "Backend -- ", "{}", message);
+        warn!(target: "Backend -- ", "{}", self.redactor.redact(message));
     }
     fn error(&self, message: &str) {
-        error!(target: "Backend -- ", "{}", message);
+        error!(target: "Backend -- ", "{}", self.redactor.redact(message));
     }
     fn debug(&self, message: &str) {
-        debug!(target: "Backend -- ", "{}", message);
+        debug!(target: "Backend -- ", "{}", self.redactor.redact(message));
     }
 }
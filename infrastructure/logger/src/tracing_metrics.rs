@@ -0,0 +1,12 @@
+use business::domain::metrics::Metrics;
+use tracing::info;
+
+/// Emits counters as structured tracing events under the `metrics` target,
+/// for the log pipeline to aggregate.
+pub struct TracingMetrics;
+
+impl Metrics for TracingMetrics {
+    fn increment(&self, name: &str, value: u64) {
+        info!(target: "metrics", metric = name, value, "counter");
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use business::domain::device::model::Device;
+use business::domain::logger::Logger;
+use business::domain::notification::errors::NotificationError;
+use business::domain::notification::model::Notification;
+use business::domain::notification::services::NotificationSender;
+
+#[derive(Debug, Clone)]
+pub struct WebhookNotifierSettings {
+    /// Endpoint of the push relay
+    pub url: String,
+    /// Sent as a bearer token, if the relay wants one
+    pub token: Option<String>,
+}
+
+#[derive(Serialize)]
+struct PushRequest<'a> {
+    id: String,
+    user_id: &'a str,
+    category: String,
+    title: &'a str,
+    body: &'a str,
+    devices: Vec<PushDevice<'a>>,
+}
+
+#[derive(Serialize)]
+struct PushDevice<'a> {
+    platform: String,
+    token: &'a str,
+}
+
+/// Hands notifications to an HTTP relay as `{id, user_id, category, title,
+/// body, devices}` JSON, which pushes them to the user's devices (FCM, APNs, ntfy,
+/// …). `devices` lists the `{platform, token}` of every registered install;
+/// when it is empty the relay has to find the user's devices itself.
+pub struct WebhookNotifier {
+    client: reqwest::Client,
+    settings: WebhookNotifierSettings,
+}
+
+impl WebhookNotifier {
+    pub fn new(settings: WebhookNotifierSettings) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        Self { client, settings }
+    }
+}
+
+#[async_trait]
+impl NotificationSender for WebhookNotifier {
+    async fn send(
+        &self,
+        notification: &Notification,
+        devices: &[Device],
+    ) -> Result<(), NotificationError> {
+        let devices = devices
+            .iter()
+            .filter_map(|device| {
+                Some(PushDevice {
+                    platform: device.platform.to_string(),
+                    token: device.push_token.as_deref()?,
+                })
+            })
+            .collect();
+        let mut request = self.client.post(&self.settings.url).json(&PushRequest {
+            id: notification.id.to_string(),
+            user_id: notification.user_id.as_str(),
+            category: notification.category.to_string(),
+            title: &notification.title,
+            body: &notification.body,
+            devices,
+        });
+        if let Some(token) = &self.settings.token {
+            request = request.bearer_auth(token);
+        }
+
+        request
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(NotificationError::delivery_failed)?;
+        Ok(())
+    }
+}
+
+/// Writes notifications to the log instead of pushing them, for development
+/// and setups without a push relay.
+pub struct LogNotifier {
+    pub logger: Arc<dyn Logger>,
+}
+
+#[async_trait]
+impl NotificationSender for LogNotifier {
+    async fn send(
+        &self,
+        notification: &Notification,
+        devices: &[Device],
+    ) -> Result<(), NotificationError> {
+        self.logger.info(&format!(
+            "Notification for {} ({}) to {} devices: {}",
+            notification.user_id,
+            notification.category,
+            devices.len(),
+            notification.title
+        ));
+        Ok(())
+    }
+}
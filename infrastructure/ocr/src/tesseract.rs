@@ -0,0 +1,107 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use business::domain::product::errors::ProductError;
+use business::domain::product::services::TextRecognitionService;
+use business::domain::storage::model::ImageUpload;
+
+#[derive(Debug, Clone)]
+pub struct TesseractSettings {
+    /// Path or name of the `tesseract` binary
+    pub binary: String,
+    /// Trained languages, as tesseract's `-l` takes them ("spa+eng")
+    pub languages: String,
+    /// Longest a single image may take to read
+    pub timeout: Duration,
+}
+
+/// Reads receipt text with a local `tesseract` install, piping the image
+/// through stdin and the text back through stdout.
+pub struct TesseractOcr {
+    settings: TesseractSettings,
+}
+
+impl TesseractOcr {
+    pub fn new(settings: TesseractSettings) -> Self {
+        Self { settings }
+    }
+
+    async fn run(&self, image: Vec<u8>) -> Result<String, String> {
+        let mut child = Command::new(&self.settings.binary)
+            .args(["stdin", "stdout", "-l", &self.settings.languages])
+            // Receipts are a single column of text
+            .args(["--psm", "4"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("could not start tesseract: {e}"))?;
+
+        let mut stdin = child.stdin.take().ok_or("tesseract stdin unavailable")?;
+        stdin
+            .write_all(&image)
+            .await
+            .map_err(|e| format!("could not send image to tesseract: {e}"))?;
+        drop(stdin);
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|e| format!("tesseract did not finish: {e}"))?;
+        if !output.status.success() {
+            return Err(format!(
+                "tesseract exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
+
+#[async_trait]
+impl TextRecognitionService for TesseractOcr {
+    async fn recognize(&self, image_base64: &str) -> Result<String, ProductError> {
+        let image = ImageUpload::from_base64(image_base64).ok_or(ProductError::InvalidImage)?;
+
+        match tokio::time::timeout(self.settings.timeout, self.run(image.bytes)).await {
+            Ok(result) => result.map_err(ProductError::scan_failed),
+            Err(_) => Err(ProductError::scan_failed("tesseract timed out")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1x1 transparent PNG
+    const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAQAAAC1HAwCAAAAC0lEQVR42mNkYAAAAAYAAjCB0C8AAAAASUVORK5CYII=";
+
+    fn ocr(binary: &str) -> TesseractOcr {
+        TesseractOcr::new(TesseractSettings {
+            binary: binary.to_string(),
+            languages: "spa+eng".to_string(),
+            timeout: Duration::from_secs(5),
+        })
+    }
+
+    #[tokio::test]
+    async fn should_reject_content_that_is_not_an_image() {
+        let result = ocr("tesseract").recognize("not an image").await;
+
+        assert!(matches!(result, Err(ProductError::InvalidImage)));
+    }
+
+    #[tokio::test]
+    async fn should_fail_scan_when_binary_is_missing() {
+        let result = ocr("/nonexistent/tesseract").recognize(PNG).await;
+
+        assert!(matches!(result, Err(ProductError::ScanFailed(_))));
+    }
+}
@@ -0,0 +1,32 @@
+//! Prompt rendering for suggestion requests, from a ranked pantry to the
+//! text sent to the model.
+//!
+//! Run with `cargo bench -p openai`.
+
+use chrono::Utc;
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+
+use business::domain::product::fixtures::sample_pantry;
+use business::domain::product::urgency::ExpiringSoonWindow;
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::prioritized_pantry::{PantryPromptLimits, prioritize_pantry};
+use openai::suggestion_generator::SuggestionGeneratorOpenAI;
+
+fn build_prompt(c: &mut Criterion) {
+    let user_id = UserId::new("bench-user");
+    let mut group = c.benchmark_group("suggestion_prompt");
+    for size in [50, 500, 5_000] {
+        let pantry = prioritize_pantry(
+            sample_pantry(&user_id, size, 42, Utc::now()),
+            &PantryPromptLimits::default(),
+            ExpiringSoonWindow::default(),
+        );
+        group.bench_with_input(BenchmarkId::from_parameter(size), &pantry, |b, pantry| {
+            b.iter(|| SuggestionGeneratorOpenAI::build_prompt(black_box(pantry), 5))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, build_prompt);
+criterion_main!(benches);
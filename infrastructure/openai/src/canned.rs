@@ -0,0 +1,174 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+use business::domain::product::errors::ProductError;
+use business::domain::product::services::{
+    Confidence, ExpiryEstimation, ExpiryEstimatorService, IdentificationConfidence,
+    IdentificationMethod, ProductIdentification, ProductIdentifierService, ReceiptItem,
+    ReceiptScanResult, ReceiptScannerService,
+};
+use business::domain::product::urgency::UrgencyLevel;
+use business::domain::product::value_objects::ProductLocation;
+use business::domain::suggestion::errors::SuggestionError;
+use business::domain::suggestion::model::{
+    Suggestion, SuggestionIngredient, TimeRange, create_suggestion,
+};
+use business::domain::suggestion::prioritized_pantry::PrioritizedPantry;
+use business::domain::suggestion::services::SuggestionGeneratorService;
+
+/// Products "seen" in any photo, in the order they are returned.
+const PHOTO_PRODUCTS: [(&str, ProductLocation, &str); 3] = [
+    ("Yogur natural", ProductLocation::Fridge, "4 x 125 g"),
+    ("Tomates", ProductLocation::Fridge, "1 kg"),
+    ("Pasta", ProductLocation::Pantry, "500 g"),
+];
+
+/// Items "read" from any receipt.
+const RECEIPT_ITEMS: [&str; 5] = [
+    "Leche entera",
+    "Huevos",
+    "Pan de molde",
+    "Plátanos",
+    "Queso manchego",
+];
+
+/// Ingredients per canned suggestion.
+const INGREDIENTS_PER_SUGGESTION: usize = 2;
+
+/// Stand-in for every AI port that answers from fixed data without calling
+/// any provider, for the sandbox deployment where the public demo and store
+/// reviewers must not cost anything. Answers are plausible, not accurate:
+/// photos and receipts always yield the same products, and suggestions pair
+/// up the most urgent products of the pantry.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CannedAi;
+
+#[async_trait]
+impl ExpiryEstimatorService for CannedAi {
+    async fn estimate_expiry_date(
+        &self,
+        _product_name: &str,
+        status: &str,
+        location: Option<String>,
+    ) -> ExpiryEstimation {
+        let days = match (location.as_deref(), status) {
+            (Some("freezer"), _) => 90,
+            (Some("pantry"), "opened") => 14,
+            (Some("pantry"), _) => 60,
+            (_, "opened") => 3,
+            _ => 7,
+        };
+        ExpiryEstimation {
+            date: Some(Utc::now() + Duration::days(days)),
+            confidence: Confidence::Medium,
+            unavailable: false,
+        }
+    }
+}
+
+fn photo_identification(
+    (name, location, quantity): &(&str, ProductLocation, &str),
+) -> ProductIdentification {
+    ProductIdentification {
+        name: name.to_string(),
+        confidence: IdentificationConfidence::High,
+        method: IdentificationMethod::Visual,
+        suggested_location: Some(location.clone()),
+        suggested_quantity: Some(quantity.to_string()),
+        suggested_expiry_type: None,
+        suggested_category: None,
+        enrichment: None,
+        ai_call_id: None,
+    }
+}
+
+#[async_trait]
+impl ProductIdentifierService for CannedAi {
+    async fn identify_by_image(
+        &self,
+        _image_base64: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        Ok(photo_identification(&PHOTO_PRODUCTS[0]))
+    }
+
+    async fn identify_by_barcode(
+        &self,
+        barcode: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        Ok(ProductIdentification {
+            name: format!("Producto {barcode}"),
+            confidence: IdentificationConfidence::Low,
+            method: IdentificationMethod::Barcode,
+            suggested_location: Some(ProductLocation::Pantry),
+            suggested_quantity: None,
+            suggested_expiry_type: None,
+            suggested_category: None,
+            enrichment: None,
+            ai_call_id: None,
+        })
+    }
+
+    async fn identify_all_by_image(
+        &self,
+        _image_base64: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError> {
+        Ok(PHOTO_PRODUCTS.iter().map(photo_identification).collect())
+    }
+}
+
+#[async_trait]
+impl ReceiptScannerService for CannedAi {
+    async fn scan(&self, _image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        Ok(ReceiptScanResult {
+            items: RECEIPT_ITEMS
+                .iter()
+                .map(|name| ReceiptItem {
+                    name: name.to_string(),
+                    confidence: IdentificationConfidence::High,
+                })
+                .collect(),
+            store: Some("Mercadona".to_string()),
+        })
+    }
+}
+
+#[async_trait]
+impl SuggestionGeneratorService for CannedAi {
+    async fn generate(
+        &self,
+        pantry: &PrioritizedPantry,
+        limit: usize,
+    ) -> Result<Vec<Suggestion>, SuggestionError> {
+        let now = Utc::now();
+        pantry
+            .items
+            .chunks(INGREDIENTS_PER_SUGGESTION)
+            .take(limit)
+            .enumerate()
+            .map(|(index, items)| {
+                let ingredients: Vec<SuggestionIngredient> = items
+                    .iter()
+                    .map(|item| SuggestionIngredient {
+                        product_id: item.product.id.to_string(),
+                        product_name: item.product.name.clone(),
+                        quantity: item.product.quantity.clone(),
+                        is_urgent: item.urgency != UrgencyLevel::Ok,
+                    })
+                    .collect();
+                let names: Vec<&str> = items.iter().map(|i| i.product.name.as_str()).collect();
+                create_suggestion(
+                    format!("canned-{}-{}", now.timestamp_millis(), index),
+                    format!("Salteado de {}", names.join(" y ").to_lowercase()),
+                    Some("Receta de demostración".to_string()),
+                    TimeRange::Quick,
+                    ingredients,
+                    Some(vec![
+                        "Trocea los ingredientes.".to_string(),
+                        "Saltéalos a fuego vivo unos minutos.".to_string(),
+                        "Sirve al momento.".to_string(),
+                    ]),
+                )
+            })
+            .collect()
+    }
+}
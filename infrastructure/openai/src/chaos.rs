@@ -0,0 +1,148 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use business::domain::product::errors::ProductError;
+use business::domain::product::services::{
+    ExpiryEstimation, ExpiryEstimatorService, ProductIdentification, ProductIdentifierService,
+    ReceiptScanResult, ReceiptScannerService,
+};
+use business::domain::suggestion::errors::SuggestionError;
+use business::domain::suggestion::model::Suggestion;
+use business::domain::suggestion::prioritized_pantry::PrioritizedPantry;
+use business::domain::suggestion::services::SuggestionGeneratorService;
+
+/// Cause attached to injected failures, so they stand out in logs.
+const INJECTED_FAILURE: &str = "chaos: injected provider failure";
+
+/// Raw model output reported when a malformed answer is injected.
+const MALFORMED_PAYLOAD: &str = r#"{"suggestions": [{"title": "#;
+
+/// Faults injected into every call of a [`Chaos`]-wrapped adapter.
+#[derive(Debug, Clone, Default)]
+pub struct ChaosSettings {
+    /// Delay added before each call reaches the provider
+    pub latency: Duration,
+    /// Share of calls (0.0–1.0) that fail as if the provider were down
+    pub failure_rate: f64,
+    /// Share of calls (0.0–1.0) answered with output that cannot be parsed
+    pub malformed_rate: f64,
+}
+
+enum Fault {
+    Failure,
+    Malformed,
+}
+
+/// Test-only decorator for the AI ports: delays calls and replaces some of
+/// them with the outcome the OpenAI adapters produce when the provider is
+/// unreachable or answers with garbage, so degraded paths can be exercised
+/// without a flaky network.
+pub struct Chaos<S> {
+    inner: S,
+    settings: ChaosSettings,
+}
+
+impl<S> Chaos<S> {
+    pub fn new(inner: S, settings: ChaosSettings) -> Self {
+        Self { inner, settings }
+    }
+
+    /// Waits out the configured latency and rolls for a fault.
+    async fn inject(&self) -> Option<Fault> {
+        if !self.settings.latency.is_zero() {
+            tokio::time::sleep(self.settings.latency).await;
+        }
+
+        let roll: f64 = rand::random();
+        let failure_rate = self.settings.failure_rate.clamp(0.0, 1.0);
+        let malformed_rate = self.settings.malformed_rate.clamp(0.0, 1.0);
+        if roll < failure_rate {
+            Some(Fault::Failure)
+        } else if roll < failure_rate + malformed_rate {
+            Some(Fault::Malformed)
+        } else {
+            None
+        }
+    }
+}
+
+#[async_trait]
+impl<S: ExpiryEstimatorService> ExpiryEstimatorService for Chaos<S> {
+    async fn estimate_expiry_date(
+        &self,
+        product_name: &str,
+        status: &str,
+        location: Option<String>,
+    ) -> ExpiryEstimation {
+        // The adapter never fails an estimation, it reports it unavailable
+        match self.inject().await {
+            Some(_) => ExpiryEstimation::unavailable(),
+            None => {
+                self.inner
+                    .estimate_expiry_date(product_name, status, location)
+                    .await
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl<S: ProductIdentifierService> ProductIdentifierService for Chaos<S> {
+    async fn identify_by_image(
+        &self,
+        image_base64: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        match self.inject().await {
+            Some(_) => Err(ProductError::identification_failed(INJECTED_FAILURE)),
+            None => self.inner.identify_by_image(image_base64).await,
+        }
+    }
+
+    async fn identify_by_barcode(
+        &self,
+        barcode: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        match self.inject().await {
+            Some(_) => Err(ProductError::identification_failed(INJECTED_FAILURE)),
+            None => self.inner.identify_by_barcode(barcode).await,
+        }
+    }
+
+    async fn identify_all_by_image(
+        &self,
+        image_base64: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError> {
+        match self.inject().await {
+            Some(_) => Err(ProductError::identification_failed(INJECTED_FAILURE)),
+            None => self.inner.identify_all_by_image(image_base64).await,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: ReceiptScannerService> ReceiptScannerService for Chaos<S> {
+    async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
+        match self.inject().await {
+            Some(_) => Err(ProductError::scan_failed(INJECTED_FAILURE)),
+            None => self.inner.scan(image_base64).await,
+        }
+    }
+}
+
+#[async_trait]
+impl<S: SuggestionGeneratorService> SuggestionGeneratorService for Chaos<S> {
+    async fn generate(
+        &self,
+        pantry: &PrioritizedPantry,
+        limit: usize,
+    ) -> Result<Vec<Suggestion>, SuggestionError> {
+        match self.inject().await {
+            Some(Fault::Failure) => Err(SuggestionError::ProviderUnavailable),
+            Some(Fault::Malformed) => Err(SuggestionError::ParseFailed {
+                raw: MALFORMED_PAYLOAD.to_string(),
+            }),
+            None => self.inner.generate(pantry, limit).await,
+        }
+    }
+}
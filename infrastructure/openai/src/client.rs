@@ -1,24 +1,77 @@
-use reqwest::Client;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::tls::Version;
+use reqwest::{Certificate, Client, NoProxy, Proxy};
+
+/// HTTP settings shared by every OpenAI adapter. They also apply to Open Food
+/// Facts barcode lookups, which go through the same client.
+#[derive(Debug, Clone)]
+pub struct OpenAIClientSettings {
+    pub base_url: String,
+    pub timeout: Duration,
+    pub connect_timeout: Duration,
+    pub proxy_url: Option<String>,
+    /// Comma-separated hosts reached directly instead of through the proxy
+    pub no_proxy: Option<String>,
+    /// PEM certificates to trust, e.g. the private CA of a TLS-inspecting proxy
+    pub ca_bundle_pem: Option<Vec<u8>>,
+    /// Trust only `ca_bundle_pem`, not the system roots
+    pub ca_bundle_only: bool,
+    pub min_tls_version: Option<Version>,
+}
+
+impl Default for OpenAIClientSettings {
+    fn default() -> Self {
+        Self {
+            base_url: "https://api.openai.com/v1".to_string(),
+            timeout: Duration::from_secs(30),
+            connect_timeout: Duration::from_secs(10),
+            proxy_url: None,
+            no_proxy: None,
+            ca_bundle_pem: None,
+            ca_bundle_only: false,
+            min_tls_version: None,
+        }
+    }
+}
 
 /// Shared OpenAI HTTP client configuration.
+///
+/// Cheap to clone: clones share the same connection pool, so a single instance
+/// should be built at startup and handed to every adapter.
+#[derive(Clone)]
 pub struct OpenAIClient {
     pub client: Client,
-    pub api_key: String,
-    pub base_url: String,
+    pub api_key: Arc<str>,
+    pub base_url: Arc<str>,
 }
 
 impl OpenAIClient {
-    pub fn new(api_key: String) -> Self {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(30))
-            .build()
-            .unwrap_or_default();
-
-        Self {
-            client,
-            api_key,
-            base_url: "https://api.openai.com/v1".to_string(),
+    /// Builds the client. Fails if the proxy URL or the CA bundle is invalid.
+    pub fn new(api_key: String, settings: OpenAIClientSettings) -> reqwest::Result<Self> {
+        let mut builder = Client::builder()
+            .timeout(settings.timeout)
+            .connect_timeout(settings.connect_timeout);
+        if let Some(proxy_url) = &settings.proxy_url {
+            let no_proxy = settings.no_proxy.as_deref().and_then(NoProxy::from_string);
+            builder = builder.proxy(Proxy::all(proxy_url)?.no_proxy(no_proxy));
+        }
+        if let Some(pem) = &settings.ca_bundle_pem {
+            for certificate in Certificate::from_pem_bundle(pem)? {
+                builder = builder.add_root_certificate(certificate);
+            }
+            builder = builder.tls_built_in_root_certs(!settings.ca_bundle_only);
         }
+        if let Some(version) = settings.min_tls_version {
+            builder = builder.min_tls_version(version);
+        }
+
+        Ok(Self {
+            client: builder.build()?,
+            api_key: api_key.into(),
+            base_url: settings.base_url.trim_end_matches('/').into(),
+        })
     }
 
     /// Builds the authorization header value.
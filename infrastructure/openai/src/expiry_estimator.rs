@@ -1,5 +1,5 @@
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
@@ -8,47 +8,19 @@ use serde_json::json;
 use business::domain::product::services::{Confidence, ExpiryEstimation, ExpiryEstimatorService};
 
 use crate::client::OpenAIClient;
-
-const SYSTEM_PROMPT: &str = r#"You are an expiry date estimator for a Spanish kitchen inventory app.
-Given a product name, its current status, and storage location, estimate how long until it expires.
-
-Rules:
-1. Return ONLY a JSON object with these fields:
-   - "daysUntilExpiry": number of days from TODAY until the product expires (integer)
-   - "confidence": "high" (well-known products), "medium" (reasonable guess), "low" (uncertain), or "none" (cannot estimate)
-
-2. Consider the product's current status:
-   - "new": Unopened, sealed package
-   - "opened": Package has been opened
-   - "almost_empty": Nearly finished
-   - "finished": Empty (should not be called, but treat as 0 days)
-
-3. Consider storage location (affects shelf life):
-   - "fridge": Refrigerated (extends perishables)
-   - "freezer": Frozen (significantly extends shelf life)
-   - "pantry": Room temperature (dry goods)
-   - undefined: Assume room temperature
-
-4. Base estimates on food safety guidelines, not "best before" dates.
-
-5. If you cannot estimate (e.g., too generic like "food"), return:
-   {"daysUntilExpiry":null,"confidence":"none"}
-
-Examples:
-{"daysUntilExpiry":3,"confidence":"high"}  // Opened milk in fridge
-{"daysUntilExpiry":180,"confidence":"high"} // New rice in pantry
-{"daysUntilExpiry":2,"confidence":"high"}  // Opened chicken in fridge
-{"daysUntilExpiry":null,"confidence":"none"} // Cannot estimate"#;
+use crate::prompts::{Prompt, PromptTemplates};
 
 pub struct ExpiryEstimatorOpenAI {
     client: OpenAIClient,
+    prompts: Arc<PromptTemplates>,
     cache: Mutex<HashMap<String, ExpiryEstimation>>,
 }
 
 impl ExpiryEstimatorOpenAI {
-    pub fn new(client: OpenAIClient) -> Self {
+    pub fn new(client: OpenAIClient, prompts: Arc<PromptTemplates>) -> Self {
         Self {
             client,
+            prompts,
             cache: Mutex::new(HashMap::new()),
         }
     }
@@ -85,6 +57,7 @@ impl ExpiryEstimatorOpenAI {
                 return ExpiryEstimation {
                     date: None,
                     confidence: Confidence::None,
+                    unavailable: false,
                 };
             }
         };
@@ -95,6 +68,7 @@ impl ExpiryEstimatorOpenAI {
                 return ExpiryEstimation {
                     date: None,
                     confidence: Confidence::None,
+                    unavailable: false,
                 };
             }
         };
@@ -111,7 +85,11 @@ impl ExpiryEstimatorOpenAI {
             .and_then(|d| d.as_i64())
             .map(|days| Utc::now() + Duration::days(days));
 
-        ExpiryEstimation { date, confidence }
+        ExpiryEstimation {
+            date,
+            confidence,
+            unavailable: false,
+        }
     }
 }
 
@@ -133,11 +111,13 @@ impl ExpiryEstimatorService for ExpiryEstimatorOpenAI {
         }
 
         let user_prompt = Self::build_user_prompt(product_name, status, location.as_deref());
+        let system_prompt = self.prompts.render(Prompt::ExpiryEstimator);
+        system_prompt.log_call("gpt-4o");
 
         let body = json!({
             "model": "gpt-4o",
             "input": [
-                {"role": "system", "content": SYSTEM_PROMPT},
+                {"role": "system", "content": &*system_prompt.text},
                 {"role": "user", "content": user_prompt},
             ],
             "temperature": 0.1,
@@ -171,23 +151,20 @@ impl ExpiryEstimatorService for ExpiryEstimatorOpenAI {
                             None => ExpiryEstimation {
                                 date: None,
                                 confidence: Confidence::None,
+                                unavailable: false,
                             },
                         }
                     }
-                    Err(_) => ExpiryEstimation {
-                        date: None,
-                        confidence: Confidence::None,
-                    },
+                    Err(_) => ExpiryEstimation::unavailable(),
                 }
             }
-            _ => ExpiryEstimation {
-                date: None,
-                confidence: Confidence::None,
-            },
+            _ => ExpiryEstimation::unavailable(),
         };
 
-        // Cache result
-        if let Ok(mut cache) = self.cache.lock() {
+        // Cache result, unless the call failed and is worth retrying
+        if !estimation.unavailable
+            && let Ok(mut cache) = self.cache.lock()
+        {
             cache.insert(cache_key, estimation.clone());
         }
 
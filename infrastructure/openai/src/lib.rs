@@ -1,5 +1,9 @@
+pub mod canned;
+pub mod chaos;
 pub mod client;
 pub mod expiry_estimator;
+pub mod open_food_facts;
 pub mod product_identifier;
+pub mod prompts;
 pub mod receipt_scanner;
 pub mod suggestion_generator;
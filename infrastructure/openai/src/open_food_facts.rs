@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+
+use business::domain::barcode_contribution::errors::BarcodeContributionError;
+use business::domain::barcode_contribution::services::ProductDatabaseContributor;
+use business::domain::storage::model::ImageUpload;
+
+use crate::client::OpenAIClient;
+
+#[derive(Debug, Clone)]
+pub struct OpenFoodFactsCredentials {
+    pub user_id: String,
+    pub password: String,
+    /// "https://world.openfoodfacts.org", or the ".net" staging server
+    pub base_url: String,
+    /// Language code names and front photos are filed under, e.g. "es"
+    pub language: String,
+}
+
+/// Product writes answer `status: 1`, photo uploads `status: "status ok"`.
+#[derive(Deserialize)]
+struct WriteResponse {
+    status: serde_json::Value,
+    #[serde(default)]
+    status_verbose: Option<String>,
+    #[serde(default)]
+    error: Option<String>,
+}
+
+impl WriteResponse {
+    fn accepted(&self) -> bool {
+        self.status == 1 || self.status == "status ok"
+    }
+}
+
+/// Adds products to Open Food Facts through its write API, as the
+/// configured account.
+pub struct OpenFoodFactsContributor {
+    client: OpenAIClient,
+    credentials: OpenFoodFactsCredentials,
+}
+
+impl OpenFoodFactsContributor {
+    /// Shares the pooled client (and its proxy settings) with the lookups.
+    pub fn new(client: OpenAIClient, credentials: OpenFoodFactsCredentials) -> Self {
+        Self {
+            client,
+            credentials,
+        }
+    }
+
+    fn url(&self, script: &str) -> String {
+        format!(
+            "{}/cgi/{}",
+            self.credentials.base_url.trim_end_matches('/'),
+            script
+        )
+    }
+
+    async fn check(response: reqwest::Response) -> Result<(), BarcodeContributionError> {
+        if !response.status().is_success() {
+            return Err(BarcodeContributionError::upstream_failed(format!(
+                "Open Food Facts answered {}",
+                response.status()
+            )));
+        }
+        let body: WriteResponse = response
+            .json()
+            .await
+            .map_err(BarcodeContributionError::upstream_failed)?;
+        if !body.accepted() {
+            return Err(BarcodeContributionError::upstream_failed(format!(
+                "Open Food Facts refused the write: {}",
+                body.error
+                    .or(body.status_verbose)
+                    .unwrap_or_else(|| body.status.to_string())
+            )));
+        }
+        Ok(())
+    }
+
+    fn photo_form(
+        &self,
+        barcode: &str,
+        photo: ImageUpload,
+    ) -> Result<Form, BarcodeContributionError> {
+        let language = &self.credentials.language;
+        let extension = photo.content_type.trim_start_matches("image/");
+        let part = Part::bytes(photo.bytes)
+            .file_name(format!("front.{}", extension))
+            .mime_str(photo.content_type)
+            .map_err(BarcodeContributionError::upstream_failed)?;
+
+        Ok(Form::new()
+            .text("code", barcode.to_string())
+            .text("user_id", self.credentials.user_id.clone())
+            .text("password", self.credentials.password.clone())
+            .text("imagefield", format!("front_{}", language))
+            .part(format!("imgupload_front_{}", language), part))
+    }
+}
+
+#[async_trait]
+impl ProductDatabaseContributor for OpenFoodFactsContributor {
+    async fn contribute(
+        &self,
+        barcode: &str,
+        name: &str,
+        photo: Option<ImageUpload>,
+    ) -> Result<(), BarcodeContributionError> {
+        let language = &self.credentials.language;
+        let response = self
+            .client
+            .client
+            .post(self.url("product_jqm2.pl"))
+            .form(&[
+                ("code", barcode),
+                ("user_id", &self.credentials.user_id),
+                ("password", &self.credentials.password),
+                ("lang", language),
+                (&format!("product_name_{}", language), name),
+                ("comment", "Added from the Foodie app"),
+            ])
+            .send()
+            .await
+            .map_err(BarcodeContributionError::upstream_failed)?;
+        Self::check(response).await?;
+
+        if let Some(photo) = photo {
+            let response = self
+                .client
+                .client
+                .post(self.url("product_image_upload.pl"))
+                .multipart(self.photo_form(barcode, photo)?)
+                .send()
+                .await
+                .map_err(BarcodeContributionError::upstream_failed)?;
+            Self::check(response).await?;
+        }
+
+        Ok(())
+    }
+}
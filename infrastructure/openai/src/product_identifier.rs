@@ -1,27 +1,20 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::json;
 
+use business::domain::product::enrichment::{
+    Allergen, NutritionFacts, ProductEnrichment, ScoreGrade,
+};
 use business::domain::product::errors::ProductError;
 use business::domain::product::services::{
     IdentificationConfidence, IdentificationMethod, ProductIdentification, ProductIdentifierService,
 };
-use business::domain::product::value_objects::ProductLocation;
+use business::domain::product::value_objects::{ExpiryType, ProductCategory, ProductLocation};
 
 use crate::client::OpenAIClient;
-
-const SYSTEM_PROMPT: &str = r#"You are a product identifier for a Spanish kitchen inventory app.
-Identify this single food product from the image.
-Return ONLY a JSON object with these fields:
-- "name": the product name in Spanish, cleaned up (no brand, no weight, no price)
-- "confidence": "high" if clearly identifiable, "low" if uncertain
-- "suggestedLocation": where this product is typically stored: "fridge", "pantry", or "freezer" (optional)
-- "suggestedQuantity": the quantity if visible on the package, e.g. "1 L", "500 g" (optional)
-- If you cannot identify the product at all, return {"name":"","confidence":"low"}
-
-Example outputs:
-{"name":"Yogur natural","confidence":"high","suggestedLocation":"fridge","suggestedQuantity":"4 x 125 g"}
-{"name":"Arroz","confidence":"high","suggestedLocation":"pantry"}"#;
+use crate::prompts::{Prompt, PromptTemplates};
 
 #[derive(Deserialize)]
 struct OpenFoodFactsResponse {
@@ -35,15 +28,39 @@ struct OpenFoodFactsProduct {
     product_name: Option<String>,
     quantity: Option<String>,
     categories_tags: Option<Vec<String>>,
+    nutriscore_grade: Option<String>,
+    ecoscore_grade: Option<String>,
+    allergens_tags: Option<Vec<String>>,
+    /// Kept loose: values come as numbers or numeric strings.
+    nutriments: Option<serde_json::Value>,
 }
 
 pub struct ProductIdentifierOpenAI {
     client: OpenAIClient,
+    prompts: Arc<PromptTemplates>,
+    model: String,
+    /// Version of the single product prompt; the latest when `None`
+    prompt_version: Option<u32>,
 }
 
 impl ProductIdentifierOpenAI {
-    pub fn new(client: OpenAIClient) -> Self {
-        Self { client }
+    pub fn new(client: OpenAIClient, prompts: Arc<PromptTemplates>) -> Self {
+        Self {
+            client,
+            prompts,
+            model: "gpt-4o".to_string(),
+            prompt_version: None,
+        }
+    }
+
+    /// Identifier for an experiment variant, overriding the model and the
+    /// version of the single product prompt where given.
+    pub fn with_variant(mut self, model: Option<String>, prompt_version: Option<u32>) -> Self {
+        if let Some(model) = model {
+            self.model = model;
+        }
+        self.prompt_version = prompt_version;
+        self
     }
 
     fn to_clean_data_url(raw: &str) -> String {
@@ -61,11 +78,36 @@ impl ProductIdentifierOpenAI {
 
         let json_str = json_match
             .map(|m| m.as_str())
-            .ok_or(ProductError::IdentificationFailed)?;
+            .ok_or_else(|| ProductError::identification_failed("no JSON object in model output"))?;
 
         let parsed: serde_json::Value =
-            serde_json::from_str(json_str).map_err(|_| ProductError::IdentificationFailed)?;
+            serde_json::from_str(json_str).map_err(ProductError::identification_failed)?;
+
+        Ok(Self::parse_identification(&parsed))
+    }
+
+    fn parse_multi_item_response(
+        content: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError> {
+        let json_match = regex::Regex::new(r"\[[\s\S]*\]")
+            .ok()
+            .and_then(|re| re.find(content));
+
+        let json_str = json_match
+            .map(|m| m.as_str())
+            .ok_or_else(|| ProductError::identification_failed("no JSON array in model output"))?;
+
+        let parsed: Vec<serde_json::Value> =
+            serde_json::from_str(json_str).map_err(ProductError::identification_failed)?;
+
+        Ok(parsed
+            .iter()
+            .map(Self::parse_identification)
+            .filter(|item| !item.name.trim().is_empty())
+            .collect())
+    }
 
+    fn parse_identification(parsed: &serde_json::Value) -> ProductIdentification {
         let name = parsed
             .get("name")
             .and_then(|n| n.as_str())
@@ -87,60 +129,62 @@ impl ProductIdentifierOpenAI {
             .and_then(|q| q.as_str())
             .map(|q| q.to_string());
 
-        Ok(ProductIdentification {
+        // "none" would add nothing over leaving the field out
+        let suggested_expiry_type = parsed
+            .get("suggestedExpiryType")
+            .and_then(|t| t.as_str())
+            .and_then(|t| t.parse::<ExpiryType>().ok())
+            .filter(|t| *t != ExpiryType::None);
+
+        let suggested_category = parsed
+            .get("suggestedCategory")
+            .and_then(|c| c.as_str())
+            .and_then(|c| c.parse::<ProductCategory>().ok());
+
+        ProductIdentification {
             name,
             confidence,
             method: IdentificationMethod::Visual,
             suggested_location,
             suggested_quantity,
-        })
-    }
-
-    fn infer_location_from_categories(categories: &[String]) -> Option<ProductLocation> {
-        let joined = categories.join(",").to_lowercase();
-
-        if joined.contains("frozen") || joined.contains("congel") {
-            return Some(ProductLocation::Freezer);
-        }
-        if joined.contains("dairy")
-            || joined.contains("lact")
-            || joined.contains("fresh")
-            || joined.contains("fresc")
-            || joined.contains("meat")
-            || joined.contains("carn")
-            || joined.contains("fish")
-            || joined.contains("pescad")
-        {
-            return Some(ProductLocation::Fridge);
+            suggested_expiry_type,
+            suggested_category,
+            enrichment: None,
+            ai_call_id: None,
         }
-
-        Some(ProductLocation::Pantry)
     }
-}
 
-#[async_trait]
-impl ProductIdentifierService for ProductIdentifierOpenAI {
-    async fn identify_by_image(
+    /// Sends an image with instructions and returns the model's text output.
+    async fn ask_about_image(
         &self,
+        prompt: Prompt,
         image_base64: &str,
-    ) -> Result<ProductIdentification, ProductError> {
+        detail: &str,
+        instruction: &str,
+    ) -> Result<String, ProductError> {
         let image_url = Self::to_clean_data_url(image_base64);
+        let version = match prompt {
+            Prompt::ProductIdentifier => self.prompt_version,
+            _ => None,
+        };
+        let system_prompt = self.prompts.render_version(prompt, version);
+        system_prompt.log_call(&self.model);
 
         let body = json!({
-            "model": "gpt-4o",
+            "model": &self.model,
             "input": [
-                {"role": "system", "content": SYSTEM_PROMPT},
+                {"role": "system", "content": &*system_prompt.text},
                 {
                     "role": "user",
                     "content": [
                         {
                             "type": "input_image",
                             "image_url": image_url,
-                            "detail": "low",
+                            "detail": detail,
                         },
                         {
                             "type": "input_text",
-                            "text": "Identify this food product.",
+                            "text": instruction,
                         },
                     ],
                 },
@@ -157,26 +201,115 @@ impl ProductIdentifierService for ProductIdentifierOpenAI {
             .json(&body)
             .send()
             .await
-            .map_err(|_| ProductError::IdentificationFailed)?;
+            .map_err(ProductError::identification_failed)?;
 
         if !response.status().is_success() {
-            return Err(ProductError::IdentificationFailed);
+            return Err(ProductError::identification_failed(format!(
+                "OpenAI answered {}",
+                response.status()
+            )));
         }
 
         let data: serde_json::Value = response
             .json()
             .await
-            .map_err(|_| ProductError::IdentificationFailed)?;
+            .map_err(ProductError::identification_failed)?;
 
-        let text = data["output"]
+        data["output"]
             .as_array()
             .and_then(|outputs| outputs.iter().find(|o| o["type"] == "message"))
             .and_then(|msg| msg["content"].as_array())
             .and_then(|contents| contents.iter().find(|c| c["type"] == "output_text"))
             .and_then(|c| c["text"].as_str())
-            .ok_or(ProductError::IdentificationFailed)?;
+            .map(|text| text.to_string())
+            .ok_or_else(|| ProductError::identification_failed("no output text in OpenAI response"))
+    }
+
+    /// Nutrition, eco and allergen data of an Open Food Facts product. Grades
+    /// such as "unknown" or "not-applicable" are left out.
+    fn parse_enrichment(
+        barcode: &str,
+        product: &OpenFoodFactsProduct,
+    ) -> Option<ProductEnrichment> {
+        let grade = |g: &Option<String>| g.as_deref().and_then(|g| g.parse::<ScoreGrade>().ok());
+        let nutriments = product.nutriments.as_ref();
+        let per_100g = |key: &str| {
+            nutriments
+                .and_then(|n| n.get(format!("{key}_100g")))
+                .and_then(|v| v.as_f64().or_else(|| v.as_str()?.parse().ok()))
+        };
+
+        ProductEnrichment::new(
+            barcode.to_string(),
+            grade(&product.nutriscore_grade),
+            grade(&product.ecoscore_grade),
+            NutritionFacts {
+                energy_kcal: per_100g("energy-kcal"),
+                fat: per_100g("fat"),
+                saturated_fat: per_100g("saturated-fat"),
+                carbohydrates: per_100g("carbohydrates"),
+                sugars: per_100g("sugars"),
+                fiber: per_100g("fiber"),
+                proteins: per_100g("proteins"),
+                salt: per_100g("salt"),
+            },
+            product
+                .allergens_tags
+                .iter()
+                .flatten()
+                .filter_map(|tag| Self::parse_allergen(tag))
+                .collect(),
+        )
+    }
 
-        Self::parse_image_response(text)
+    /// Maps an Open Food Facts allergen tag, such as "en:milk". Tags outside
+    /// the 14 declared allergens are skipped.
+    fn parse_allergen(tag: &str) -> Option<Allergen> {
+        match tag.strip_prefix("en:")? {
+            "sesame-seeds" => Some(Allergen::SesameSeeds),
+            "sulphur-dioxide-and-sulphites" => Some(Allergen::Sulphites),
+            name => name.parse().ok(),
+        }
+    }
+
+    fn infer_location_from_categories(categories: &[String]) -> Option<ProductLocation> {
+        let joined = categories.join(",").to_lowercase();
+
+        if joined.contains("frozen") || joined.contains("congel") {
+            return Some(ProductLocation::Freezer);
+        }
+        if joined.contains("dairy")
+            || joined.contains("lact")
+            || joined.contains("fresh")
+            || joined.contains("fresc")
+            || joined.contains("meat")
+            || joined.contains("carn")
+            || joined.contains("fish")
+            || joined.contains("pescad")
+        {
+            return Some(ProductLocation::Fridge);
+        }
+
+        Some(ProductLocation::Pantry)
+    }
+}
+
+#[async_trait]
+impl ProductIdentifierService for ProductIdentifierOpenAI {
+    async fn identify_by_image(
+        &self,
+        image_base64: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        let text = self
+            .ask_about_image(
+                Prompt::ProductIdentifier,
+                image_base64,
+                "low",
+                "Identify this food product.",
+            )
+            .await?;
+
+        Self::parse_image_response(&text)
     }
 
     async fn identify_by_barcode(
@@ -188,30 +321,44 @@ impl ProductIdentifierService for ProductIdentifierOpenAI {
             barcode
         );
 
-        let response = reqwest::get(&url)
+        // Reuse the pooled client instead of building one per lookup
+        let response = self
+            .client
+            .client
+            .get(&url)
+            .send()
             .await
-            .map_err(|_| ProductError::IdentificationFailed)?;
+            .map_err(ProductError::identification_failed)?;
 
         if !response.status().is_success() {
-            return Err(ProductError::IdentificationFailed);
+            return Err(ProductError::identification_failed(format!(
+                "Open Food Facts answered {}",
+                response.status()
+            )));
         }
 
         let data: OpenFoodFactsResponse = response
             .json()
             .await
-            .map_err(|_| ProductError::IdentificationFailed)?;
+            .map_err(ProductError::identification_failed)?;
 
         if data.status != 1 {
-            return Err(ProductError::IdentificationFailed);
+            return Err(ProductError::identification_failed(format!(
+                "barcode {barcode} not found in Open Food Facts"
+            )));
         }
 
-        let product = data.product.ok_or(ProductError::IdentificationFailed)?;
+        let product = data.product.ok_or_else(|| {
+            ProductError::identification_failed("Open Food Facts returned no product")
+        })?;
+
+        let enrichment = Self::parse_enrichment(barcode, &product);
 
         let name = product
             .product_name_es
             .or(product.product_name)
             .filter(|n| !n.is_empty())
-            .ok_or(ProductError::IdentificationFailed)?;
+            .ok_or_else(|| ProductError::identification_failed("product has no name"))?;
 
         let suggested_quantity = product.quantity;
         let categories = product.categories_tags.unwrap_or_default();
@@ -223,6 +370,27 @@ impl ProductIdentifierService for ProductIdentifierOpenAI {
             method: IdentificationMethod::Barcode,
             suggested_location,
             suggested_quantity,
+            suggested_expiry_type: None,
+            suggested_category: None,
+            enrichment,
+            ai_call_id: None,
         })
     }
+
+    async fn identify_all_by_image(
+        &self,
+        image_base64: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError> {
+        // High detail: a shelf holds many small items
+        let text = self
+            .ask_about_image(
+                Prompt::ProductIdentifierMulti,
+                image_base64,
+                "high",
+                "List the food products in this photo.",
+            )
+            .await?;
+
+        Self::parse_multi_item_response(&text)
+    }
 }
@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use minijinja::Environment;
+use serde::Serialize;
+
+use business::domain::prompt::errors::PromptError;
+use business::domain::prompt::model::{PromptTemplateInfo, TemplateSource};
+use business::domain::prompt::services::PromptTemplateService;
+
+/// Every system prompt the adapters send, by template name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Prompt {
+    ExpiryEstimator,
+    ProductIdentifier,
+    ProductIdentifierMulti,
+    ReceiptScanner,
+    SuggestionGenerator,
+}
+
+impl Prompt {
+    pub const ALL: [Prompt; 5] = [
+        Prompt::ExpiryEstimator,
+        Prompt::ProductIdentifier,
+        Prompt::ProductIdentifierMulti,
+        Prompt::ReceiptScanner,
+        Prompt::SuggestionGenerator,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Prompt::ExpiryEstimator => "expiry_estimator",
+            Prompt::ProductIdentifier => "product_identifier",
+            Prompt::ProductIdentifierMulti => "product_identifier_multi",
+            Prompt::ReceiptScanner => "receipt_scanner",
+            Prompt::SuggestionGenerator => "suggestion_generator",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Prompt> {
+        Prompt::ALL.into_iter().find(|prompt| prompt.name() == name)
+    }
+
+    /// Latest version shipped in `prompts/`, used unless the template
+    /// directory overrides it.
+    fn builtin(&self) -> (u32, &'static str) {
+        match self {
+            Prompt::ExpiryEstimator => (1, include_str!("../prompts/expiry_estimator.v1.jinja")),
+            Prompt::ProductIdentifier => {
+                (2, include_str!("../prompts/product_identifier.v2.jinja"))
+            }
+            Prompt::ProductIdentifierMulti => (
+                1,
+                include_str!("../prompts/product_identifier_multi.v1.jinja"),
+            ),
+            Prompt::ReceiptScanner => (1, include_str!("../prompts/receipt_scanner.v1.jinja")),
+            Prompt::SuggestionGenerator => {
+                (1, include_str!("../prompts/suggestion_generator.v1.jinja"))
+            }
+        }
+    }
+}
+
+/// Variables every template can use.
+#[derive(Debug, Clone, Serialize)]
+pub struct PromptContext {
+    /// Language product names and recipes are written in
+    pub language: String,
+    /// Cooking tradition the app is set in, e.g. "Spanish"
+    pub cuisine: String,
+    /// Extra rules added to every prompt
+    pub constraints: Vec<String>,
+}
+
+impl Default for PromptContext {
+    fn default() -> Self {
+        Self {
+            language: "Spanish".to_string(),
+            cuisine: "Spanish".to_string(),
+            constraints: Vec::new(),
+        }
+    }
+}
+
+/// A system prompt ready to send, with the template version it came from so
+/// the call can be logged with it.
+#[derive(Debug, Clone)]
+pub struct RenderedPrompt {
+    pub name: &'static str,
+    pub version: u32,
+    pub source: TemplateSource,
+    pub text: Arc<str>,
+}
+
+impl RenderedPrompt {
+    /// Logs an AI call made with this prompt.
+    pub fn log_call(&self, model: &str) {
+        tracing::info!(
+            prompt = self.name,
+            prompt_version = self.version,
+            prompt_source = %self.source,
+            model,
+            "Calling OpenAI"
+        );
+    }
+}
+
+/// System prompt templates, rendered once per load since their variables only
+/// change with the configuration.
+///
+/// Templates are named `<prompt>.v<version>.jinja`. Those in the template
+/// directory override the built-in ones; when a prompt has several versions
+/// there, the highest is used unless a caller pins another one, as prompt
+/// experiments do.
+pub struct PromptTemplates {
+    dir: Option<PathBuf>,
+    context: PromptContext,
+    rendered: RwLock<RenderedTemplates>,
+}
+
+struct RenderedTemplates {
+    latest: HashMap<Prompt, RenderedPrompt>,
+    versions: HashMap<(Prompt, u32), RenderedPrompt>,
+}
+
+impl PromptTemplates {
+    /// Built-in templates only.
+    pub fn builtin(context: PromptContext) -> Self {
+        Self::load(None, context).expect("built-in prompt templates must render")
+    }
+
+    /// Reads the templates at startup. Fails when the directory can't be read
+    /// or holds an invalid template.
+    pub fn load(dir: Option<PathBuf>, context: PromptContext) -> Result<Self, PromptError> {
+        let rendered = render_all(dir.as_deref(), &context)?;
+        Ok(Self {
+            dir,
+            context,
+            rendered: RwLock::new(rendered),
+        })
+    }
+
+    pub fn render(&self, prompt: Prompt) -> RenderedPrompt {
+        self.render_version(prompt, None)
+    }
+
+    /// A specific version of a prompt, or the latest for `None`. Falls back
+    /// to the latest when a reload removed the version.
+    pub fn render_version(&self, prompt: Prompt, version: Option<u32>) -> RenderedPrompt {
+        let rendered = self.rendered.read().unwrap_or_else(|e| e.into_inner());
+        if let Some(version) = version {
+            match rendered.versions.get(&(prompt, version)) {
+                Some(pinned) => return pinned.clone(),
+                None => tracing::warn!(
+                    prompt = prompt.name(),
+                    prompt_version = version,
+                    "Pinned prompt version not found, using the latest"
+                ),
+            }
+        }
+        rendered
+            .latest
+            .get(&prompt)
+            .cloned()
+            .expect("every prompt is rendered on load")
+    }
+
+    pub fn has_version(&self, prompt: Prompt, version: u32) -> bool {
+        let rendered = self.rendered.read().unwrap_or_else(|e| e.into_inner());
+        rendered.versions.contains_key(&(prompt, version))
+    }
+
+    fn infos(rendered: &RenderedTemplates) -> Vec<PromptTemplateInfo> {
+        rendered
+            .latest
+            .values()
+            .map(|r| PromptTemplateInfo {
+                name: r.name.to_string(),
+                version: r.version,
+                source: r.source,
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl PromptTemplateService for PromptTemplates {
+    async fn reload(&self) -> Result<Vec<PromptTemplateInfo>, PromptError> {
+        let fresh = render_all(self.dir.as_deref(), &self.context)?;
+        let infos = Self::infos(&fresh);
+        *self.rendered.write().unwrap_or_else(|e| e.into_inner()) = fresh;
+        Ok(infos)
+    }
+}
+
+fn render_all(
+    dir: Option<&Path>,
+    context: &PromptContext,
+) -> Result<RenderedTemplates, PromptError> {
+    let files = match dir {
+        Some(dir) => read_dir(dir)?,
+        None => HashMap::new(),
+    };
+
+    let env = Environment::new();
+    let render = |prompt: Prompt, version: u32, template: &str, source: TemplateSource| {
+        let text = env.render_str(template, context).map_err(|e| {
+            PromptError::invalid_template(format!("{}.v{}: {}", prompt.name(), version, e))
+        })?;
+        Ok::<_, PromptError>(RenderedPrompt {
+            name: prompt.name(),
+            version,
+            source,
+            text: text.into(),
+        })
+    };
+
+    let mut latest = HashMap::new();
+    let mut versions = HashMap::new();
+    for prompt in Prompt::ALL {
+        let (version, template) = prompt.builtin();
+        let builtin = render(prompt, version, template, TemplateSource::Builtin)?;
+        versions.insert((prompt, version), builtin.clone());
+        latest.insert(prompt, builtin);
+    }
+    for ((prompt, version), source) in &files {
+        let rendered = render(*prompt, *version, source, TemplateSource::Directory)?;
+        let newest = latest.get(prompt).is_none_or(|current: &RenderedPrompt| {
+            current.source == TemplateSource::Builtin || current.version < *version
+        });
+        if newest {
+            latest.insert(*prompt, rendered.clone());
+        }
+        versions.insert((*prompt, *version), rendered);
+    }
+    Ok(RenderedTemplates { latest, versions })
+}
+
+/// Every template in the directory, by prompt and version.
+fn read_dir(dir: &Path) -> Result<HashMap<(Prompt, u32), String>, PromptError> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| PromptError::unreadable(format!("{}: {}", dir.display(), e)))?;
+
+    let mut files = HashMap::new();
+    for entry in entries {
+        let path = entry.map_err(PromptError::unreadable)?.path();
+        let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        let Some(stem) = file_name.strip_suffix(".jinja") else {
+            continue;
+        };
+        let key = parse_template_name(stem).ok_or_else(|| {
+            PromptError::invalid_template(format!(
+                "{file_name}: expected <prompt>.v<version>.jinja with a known prompt"
+            ))
+        })?;
+        let source = std::fs::read_to_string(&path)
+            .map_err(|e| PromptError::unreadable(format!("{}: {}", path.display(), e)))?;
+        files.insert(key, source);
+    }
+    Ok(files)
+}
+
+fn parse_template_name(stem: &str) -> Option<(Prompt, u32)> {
+    let (name, version) = stem.rsplit_once(".v")?;
+    Some((Prompt::from_name(name)?, version.parse().ok()?))
+}
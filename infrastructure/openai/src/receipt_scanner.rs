@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use serde_json::json;
 
@@ -7,25 +9,16 @@ use business::domain::product::services::{
 };
 
 use crate::client::OpenAIClient;
-
-const SYSTEM_PROMPT: &str = r#"You are a receipt scanner for a Spanish kitchen inventory app.
-Extract product names from this supermarket receipt image.
-Return ONLY a JSON array of objects with "name" and "confidence" fields.
-- "name": the product name in Spanish, cleaned up (no brand, no weight, no price)
-- "confidence": "high" if clearly readable, "low" if uncertain
-- Filter out non-food items (bags, discounts, totals, store info)
-- Keep it simple: "Leche entera", not "LECHE ENTERA HACENDADO 1L 0.89"
-
-Example output:
-[{"name":"Leche entera","confidence":"high"},{"name":"Pan de molde","confidence":"high"},{"name":"Manzanas","confidence":"low"}]"#;
+use crate::prompts::{Prompt, PromptTemplates};
 
 pub struct ReceiptScannerOpenAI {
     client: OpenAIClient,
+    prompts: Arc<PromptTemplates>,
 }
 
 impl ReceiptScannerOpenAI {
-    pub fn new(client: OpenAIClient) -> Self {
-        Self { client }
+    pub fn new(client: OpenAIClient, prompts: Arc<PromptTemplates>) -> Self {
+        Self { client, prompts }
     }
 
     fn to_clean_data_url(raw: &str) -> String {
@@ -37,18 +30,31 @@ impl ReceiptScannerOpenAI {
     }
 
     fn parse_response(content: &str) -> Result<ReceiptScanResult, ProductError> {
-        let json_match = regex::Regex::new(r"\[[\s\S]*\]")
+        // A bare array of items is accepted too, without a store
+        let json_match = regex::Regex::new(r"\{[\s\S]*\}|\[[\s\S]*\]")
             .ok()
             .and_then(|re| re.find(content));
 
         let json_str = json_match
             .map(|m| m.as_str())
-            .ok_or(ProductError::ScanFailed)?;
-
-        let parsed: Vec<serde_json::Value> =
-            serde_json::from_str(json_str).map_err(|_| ProductError::ScanFailed)?;
+            .ok_or_else(|| ProductError::scan_failed("no JSON in model output"))?;
+
+        let parsed: serde_json::Value =
+            serde_json::from_str(json_str).map_err(ProductError::scan_failed)?;
+
+        let store = parsed
+            .get("store")
+            .and_then(|s| s.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string);
+        let entries = parsed
+            .get("items")
+            .unwrap_or(&parsed)
+            .as_array()
+            .ok_or_else(|| ProductError::scan_failed("no item array in model output"))?;
 
-        let items: Vec<ReceiptItem> = parsed
+        let items: Vec<ReceiptItem> = entries
             .iter()
             .filter_map(|item| {
                 let name = item.get("name")?.as_str()?.to_string();
@@ -60,7 +66,7 @@ impl ReceiptScannerOpenAI {
             })
             .collect();
 
-        Ok(ReceiptScanResult { items })
+        Ok(ReceiptScanResult { items, store })
     }
 }
 
@@ -68,11 +74,13 @@ impl ReceiptScannerOpenAI {
 impl ReceiptScannerService for ReceiptScannerOpenAI {
     async fn scan(&self, image_base64: &str) -> Result<ReceiptScanResult, ProductError> {
         let image_url = Self::to_clean_data_url(image_base64);
+        let system_prompt = self.prompts.render(Prompt::ReceiptScanner);
+        system_prompt.log_call("gpt-4o");
 
         let body = json!({
             "model": "gpt-4o",
             "input": [
-                {"role": "system", "content": SYSTEM_PROMPT},
+                {"role": "system", "content": &*system_prompt.text},
                 {
                     "role": "user",
                     "content": [
@@ -83,7 +91,7 @@ impl ReceiptScannerService for ReceiptScannerOpenAI {
                         },
                         {
                             "type": "input_text",
-                            "text": "Extract the product names from this receipt.",
+                            "text": "Extract the store and the product names from this receipt.",
                         },
                     ],
                 },
@@ -100,16 +108,16 @@ impl ReceiptScannerService for ReceiptScannerOpenAI {
             .json(&body)
             .send()
             .await
-            .map_err(|_| ProductError::ScanFailed)?;
+            .map_err(ProductError::scan_failed)?;
 
         if !response.status().is_success() {
-            return Err(ProductError::ScanFailed);
+            return Err(ProductError::scan_failed(format!(
+                "OpenAI answered {}",
+                response.status()
+            )));
         }
 
-        let data: serde_json::Value = response
-            .json()
-            .await
-            .map_err(|_| ProductError::ScanFailed)?;
+        let data: serde_json::Value = response.json().await.map_err(ProductError::scan_failed)?;
 
         let text = data["output"]
             .as_array()
@@ -117,7 +125,7 @@ impl ReceiptScannerService for ReceiptScannerOpenAI {
             .and_then(|msg| msg["content"].as_array())
             .and_then(|contents| contents.iter().find(|c| c["type"] == "output_text"))
             .and_then(|c| c["text"].as_str())
-            .ok_or(ProductError::ScanFailed)?;
+            .ok_or_else(|| ProductError::scan_failed("no output text in OpenAI response"))?;
 
         Self::parse_response(text)
     }
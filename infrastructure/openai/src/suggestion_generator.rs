@@ -1,52 +1,86 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use chrono::Utc;
 use serde_json::json;
 
 use business::domain::product::model::Product;
-use business::domain::product::urgency::{days_until_expiry, get_urgency_level};
 use business::domain::suggestion::errors::SuggestionError;
 use business::domain::suggestion::model::{Suggestion, SuggestionIngredient, TimeRange};
+use business::domain::suggestion::prioritized_pantry::PrioritizedPantry;
 use business::domain::suggestion::services::SuggestionGeneratorService;
 
 use crate::client::OpenAIClient;
-
-const SYSTEM_PROMPT: &str = r#"You are a helpful cooking assistant for a Spanish kitchen app called Foodie.
-Your goal: help tired users decide what to cook quickly, prioritizing ingredients that are expiring soon.
-
-Core principles:
-- Keep suggestions SIMPLE (max 30 min cooking time)
-- Prioritize products expiring soon
-- Use realistic ingredient combinations
-- Be calm and clear - this is for people who are tired
-- Suggest 3-5 recipes maximum
-- Focus on common Spanish/Mediterranean dishes when possible
-
-Return ONLY valid JSON array, no additional text."#;
+use crate::prompts::{Prompt, PromptTemplates};
 
 pub struct SuggestionGeneratorOpenAI {
     client: OpenAIClient,
+    prompts: Arc<PromptTemplates>,
+    model: String,
+    /// The latest prompt when `None`
+    prompt_version: Option<u32>,
 }
 
 impl SuggestionGeneratorOpenAI {
-    pub fn new(client: OpenAIClient) -> Self {
-        Self { client }
+    pub fn new(client: OpenAIClient, prompts: Arc<PromptTemplates>) -> Self {
+        Self {
+            client,
+            prompts,
+            model: "gpt-4o-mini".to_string(),
+            prompt_version: None,
+        }
+    }
+
+    /// Generator for an experiment variant, overriding the model and the
+    /// prompt version where given.
+    pub fn with_variant(mut self, model: Option<String>, prompt_version: Option<u32>) -> Self {
+        if let Some(model) = model {
+            self.model = model;
+        }
+        self.prompt_version = prompt_version;
+        self
     }
 
-    fn build_prompt(products: &[Product], limit: usize) -> String {
-        let product_list: String = products
+    /// User prompt listing the ranked pantry. Public so it can be benchmarked.
+    pub fn build_prompt(pantry: &PrioritizedPantry, limit: usize) -> String {
+        let mut product_list: String = pantry
+            .items
             .iter()
-            .map(|p| {
-                let urgency = get_urgency_level(p);
-                let days = days_until_expiry(p);
-                let days_text = match days {
+            .map(|item| {
+                let p = &item.product;
+                let days_text = match item.days_until_expiry {
                     Some(d) => format!("expires in {} days", d),
                     None => "no expiry date".to_string(),
                 };
-                format!("- {} [id:{}] ({}, {})", p.name, p.id, urgency, days_text)
+                let count_text = if item.count > 1 {
+                    format!(" x{}", item.count)
+                } else {
+                    String::new()
+                };
+                format!(
+                    "- {}{} [id:{}] ({}, {})",
+                    p.name, count_text, p.id, item.urgency, days_text
+                )
             })
             .collect::<Vec<_>>()
             .join("\n");
 
+        if !pantry.long_tail.is_empty() {
+            let unnamed = pantry.omitted.saturating_sub(pantry.long_tail.len());
+            let mut also = pantry.long_tail.join(", ");
+            if unnamed > 0 {
+                if !also.is_empty() {
+                    also.push_str(", ");
+                }
+                also.push_str(&format!("and {} more", unnamed));
+            }
+            product_list.push_str(&format!(
+                "\n\nAlso available (lower priority, no ids): {}",
+                also
+            ));
+        }
+
         format!(
             r#"Given these products from the user's pantry, suggest {} simple recipes they can make TODAY.
 
@@ -56,6 +90,7 @@ PRODUCTS (sorted by urgency):
 Requirements:
 - Return {} suggestions maximum
 - Prioritize recipes using products expiring soon (use_today, use_soon)
+- Products marked quality_declining are past their best-before date: safe to eat, best in cooked dishes
 - Keep recipes SIMPLE and realistic
 - Estimate time: "quick" (~10min), "medium" (~20min), "long" (~30min)
 - Provide 3-4 brief steps per recipe
@@ -83,7 +118,7 @@ Return JSON array with this EXACT structure:
 
     fn parse_response(
         content: &str,
-        products: &[Product],
+        products: &[&Product],
     ) -> Result<Vec<Suggestion>, SuggestionError> {
         // Remove markdown code blocks if present
         let mut json_text = content.trim().to_string();
@@ -98,7 +133,15 @@ Return JSON array with this EXACT structure:
         }
 
         let parsed: Vec<serde_json::Value> =
-            serde_json::from_str(&json_text).map_err(|_| SuggestionError::GenerationFailed)?;
+            serde_json::from_str(&json_text).map_err(|_| SuggestionError::ParseFailed {
+                raw: content.to_string(),
+            })?;
+
+        // Indexed once so each ingredient's quantity is a lookup, not a scan
+        let quantities: HashMap<String, Option<&String>> = products
+            .iter()
+            .map(|p| (p.id.to_string(), p.quantity.as_ref()))
+            .collect();
 
         let mut suggestions = Vec::new();
 
@@ -134,10 +177,7 @@ Return JSON array with this EXACT structure:
                                 .and_then(|u| u.as_bool())
                                 .unwrap_or(false);
 
-                            let quantity = products
-                                .iter()
-                                .find(|p| p.id.to_string() == product_id)
-                                .and_then(|p| p.quantity.clone());
+                            let quantity = quantities.get(&product_id).copied().flatten().cloned();
 
                             Some(SuggestionIngredient {
                                 product_id,
@@ -187,19 +227,23 @@ Return JSON array with this EXACT structure:
 impl SuggestionGeneratorService for SuggestionGeneratorOpenAI {
     async fn generate(
         &self,
-        products: &[Product],
+        pantry: &PrioritizedPantry,
         limit: usize,
     ) -> Result<Vec<Suggestion>, SuggestionError> {
-        if products.is_empty() {
+        if pantry.is_empty() {
             return Ok(vec![]);
         }
 
-        let prompt = Self::build_prompt(products, limit);
+        let prompt = Self::build_prompt(pantry, limit);
+        let system_prompt = self
+            .prompts
+            .render_version(Prompt::SuggestionGenerator, self.prompt_version);
+        system_prompt.log_call(&self.model);
 
         let body = json!({
-            "model": "gpt-4o-mini",
+            "model": &self.model,
             "messages": [
-                {"role": "system", "content": SYSTEM_PROMPT},
+                {"role": "system", "content": &*system_prompt.text},
                 {"role": "user", "content": prompt},
             ],
             "temperature": 0.7,
@@ -215,23 +259,32 @@ impl SuggestionGeneratorService for SuggestionGeneratorOpenAI {
             .json(&body)
             .send()
             .await
-            .map_err(|_| SuggestionError::GenerationFailed)?;
-
-        if !response.status().is_success() {
-            return Err(SuggestionError::GenerationFailed);
+            // Timeouts, refused connections and the like are worth retrying
+            .map_err(|_| SuggestionError::ProviderUnavailable)?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(match status.as_u16() {
+                429 => SuggestionError::QuotaExceeded,
+                500..=599 => SuggestionError::ProviderUnavailable,
+                _ => SuggestionError::GenerationFailed,
+            });
         }
 
-        let data: serde_json::Value = response
-            .json()
+        let raw = response
+            .text()
             .await
-            .map_err(|_| SuggestionError::GenerationFailed)?;
+            .map_err(|_| SuggestionError::ProviderUnavailable)?;
+        let data: serde_json::Value = serde_json::from_str(&raw)
+            .map_err(|_| SuggestionError::ParseFailed { raw: raw.clone() })?;
 
         let content = data["choices"]
             .as_array()
             .and_then(|choices| choices.first())
             .and_then(|choice| choice["message"]["content"].as_str())
-            .ok_or(SuggestionError::GenerationFailed)?;
+            .ok_or_else(|| SuggestionError::ParseFailed { raw: raw.clone() })?;
 
-        Self::parse_response(content, products)
+        let products: Vec<&Product> = pantry.products().collect();
+        Self::parse_response(content, &products)
     }
 }
@@ -0,0 +1,50 @@
+//! Checks that the canned AI answers are usable by the use cases: estimates
+//! carry a date and suggestions only use products from the pantry.
+
+use std::collections::HashSet;
+
+use chrono::Utc;
+
+use business::domain::product::fixtures::sample_pantry;
+use business::domain::product::services::{ExpiryEstimatorService, ProductIdentifierService};
+use business::domain::product::urgency::ExpiringSoonWindow;
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::prioritized_pantry::{PantryPromptLimits, prioritize_pantry};
+use business::domain::suggestion::services::SuggestionGeneratorService;
+use openai::canned::CannedAi;
+
+#[tokio::test]
+async fn should_estimate_a_future_date_for_any_product() {
+    let estimation = CannedAi
+        .estimate_expiry_date("Leche entera", "opened", Some("fridge".to_string()))
+        .await;
+
+    assert!(estimation.date.is_some_and(|date| date > Utc::now()));
+}
+
+#[tokio::test]
+async fn should_suggest_only_pantry_products_within_limit() {
+    let pantry = prioritize_pantry(
+        sample_pantry(&UserId::new("demo-user"), 30, 2026, Utc::now()),
+        &PantryPromptLimits::default(),
+        ExpiringSoonWindow::default(),
+    );
+    let pantry_ids: HashSet<String> = pantry.products().map(|p| p.id.to_string()).collect();
+
+    let suggestions = CannedAi.generate(&pantry, 3).await.unwrap();
+
+    assert_eq!(suggestions.len(), 3);
+    assert!(
+        suggestions
+            .iter()
+            .flat_map(|s| &s.ingredients)
+            .all(|ingredient| pantry_ids.contains(&ingredient.product_id))
+    );
+}
+
+#[tokio::test]
+async fn should_identify_several_products_in_any_photo() {
+    let identifications = CannedAi.identify_all_by_image("aW1hZ2U=").await.unwrap();
+
+    assert_eq!(identifications.len(), 3);
+}
@@ -0,0 +1,522 @@
+//! Runs the real use cases against chaos-wrapped AI adapters to check that a
+//! slow, failing or garbling provider degrades requests instead of breaking
+//! them.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use mockall::mock;
+use uuid::Uuid;
+
+use business::application::events::in_process::InProcessEventBus;
+use business::application::experiment::ai_experiment::AiExperiment;
+use business::application::product::create::CreateProductUseCaseImpl;
+use business::application::product::identify::IdentifyProductUseCaseImpl;
+use business::application::suggestion::generate::GenerateSuggestionsUseCaseImpl;
+use business::domain::ai_review::model::{AiWriteMode, PendingAiChange};
+use business::domain::ai_review::repository::AiReviewRepository;
+use business::domain::barcode_contribution::model::BarcodeContribution;
+use business::domain::barcode_contribution::repository::BarcodeContributionRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::experiment::model::{AiCall, AiFeature, VariantResult};
+use business::domain::experiment::repository::AiCallRepository;
+use business::domain::feature_flag::model::FeatureFlags;
+use business::domain::location_rule::model::LocationRuleSet;
+use business::domain::location_rule::repository::LocationRuleRepository;
+use business::domain::logger::Logger;
+use business::domain::meal_plan::model::PlannedMeal;
+use business::domain::meal_plan::repository::MealPlanRepository;
+use business::domain::metrics::Metrics;
+use business::domain::preference::model::UserPreferences;
+use business::domain::preference::repository::PreferenceRepository;
+use business::domain::product::enrichment::ProductEnrichment;
+use business::domain::product::errors::ProductError;
+use business::domain::product::expiry_snap::ExpirySnap;
+use business::domain::product::model::Product;
+use business::domain::product::query::ProductQuery;
+use business::domain::product::repository::{ProductEnrichmentRepository, ProductRepository};
+use business::domain::product::services::{
+    Confidence, ExpiryEstimation, ExpiryEstimatorService, IdentificationConfidence,
+    IdentificationMethod, ProductIdentification, ProductIdentifierService,
+};
+use business::domain::product::use_cases::create::{CreateProductParams, CreateProductUseCase};
+use business::domain::product::use_cases::identify::{
+    IdentifyByImageParams, IdentifyProductUseCase,
+};
+use business::domain::product::value_objects::{EstimationStatus, ExpiryType, ProductStatus};
+use business::domain::quota::errors::QuotaError;
+use business::domain::quota::model::Usage;
+use business::domain::quota::services::QuotaService;
+use business::domain::shared::pagination::KeysetPage;
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::errors::SuggestionError;
+use business::domain::suggestion::model::{Suggestion, SuggestionBatch};
+use business::domain::suggestion::prioritized_pantry::{PantryPromptLimits, PrioritizedPantry};
+use business::domain::suggestion::repository::SuggestionRepository;
+use business::domain::suggestion::services::SuggestionGeneratorService;
+use business::domain::suggestion::use_cases::generate::{
+    GenerateSuggestionsParams, GenerateSuggestionsUseCase,
+};
+use openai::chaos::{Chaos, ChaosSettings};
+
+mock! {
+    pub ProductRepo {}
+
+    #[async_trait]
+    impl ProductRepository for ProductRepo {
+        async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError>;
+        async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError>;
+        async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError>;
+        async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError>;
+        async fn insert(&self, product: &Product) -> Result<(), RepositoryError>;
+        async fn insert_unless_duplicate(&self, product: &Product, window: chrono::Duration) -> Result<Option<Product>, RepositoryError>;
+        async fn update(&self, product: &Product) -> Result<(), RepositoryError>;
+        async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+        async fn get_users_with_active_products(&self, after: Option<UserId>, limit: usize) -> Result<Vec<UserId>, RepositoryError>;
+    }
+}
+
+mock! {
+    pub Quota {}
+
+    #[async_trait]
+    impl QuotaService for Quota {
+        async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError>;
+        async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError>;
+        async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError>;
+    }
+}
+
+mock! {
+    pub LocationRuleRepo {}
+
+    #[async_trait]
+    impl LocationRuleRepository for LocationRuleRepo {
+        async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError>;
+        async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError>;
+    }
+}
+
+mock! {
+    pub EnrichmentRepo {}
+
+    #[async_trait]
+    impl ProductEnrichmentRepository for EnrichmentRepo {
+        async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError>;
+        async fn link(&self, product_id: Uuid, user_id: &UserId, barcode: &str) -> Result<(), RepositoryError>;
+        async fn find_for_products(&self, user_id: &UserId, product_ids: &[Uuid]) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError>;
+    }
+}
+
+mock! {
+    pub MealPlanRepo {}
+
+    #[async_trait]
+    impl MealPlanRepository for MealPlanRepo {
+        async fn find_by_suggestion(&self, suggestion_id: &str, user_id: &UserId) -> Result<Option<PlannedMeal>, RepositoryError>;
+        async fn get_reserved_product_ids(&self, user_id: &UserId) -> Result<HashSet<Uuid>, RepositoryError>;
+        async fn insert(&self, meal: &PlannedMeal) -> Result<(), RepositoryError>;
+        async fn delete_expired_reservations(&self, now: chrono::DateTime<Utc>) -> Result<u64, RepositoryError>;
+    }
+}
+
+mock! {
+    pub ContributionRepo {}
+
+    #[async_trait]
+    impl BarcodeContributionRepository for ContributionRepo {
+        async fn find(&self, user_id: &UserId, barcode: &str) -> Result<Option<BarcodeContribution>, RepositoryError>;
+        async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError>;
+    }
+}
+
+mock! {
+    pub AiReviewRepo {}
+
+    #[async_trait]
+    impl AiReviewRepository for AiReviewRepo {
+        async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError>;
+        async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError>;
+        async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError>;
+        async fn get_pending_by_id(&self, id: Uuid, user_id: &UserId) -> Result<PendingAiChange, RepositoryError>;
+        async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError>;
+        async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError>;
+    }
+}
+
+mock! {
+    pub PreferenceRepo {}
+
+    #[async_trait]
+    impl PreferenceRepository for PreferenceRepo {
+        async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError>;
+        async fn save(&self, user_id: &UserId, preferences: &UserPreferences) -> Result<(), RepositoryError>;
+    }
+}
+
+mock! {
+    pub SuggestionRepo {}
+
+    #[async_trait]
+    impl SuggestionRepository for SuggestionRepo {
+        async fn save_batch(&self, user_id: &UserId, suggestions: &[Suggestion]) -> Result<(), RepositoryError>;
+        async fn get_latest_batch(&self, user_id: &UserId) -> Result<Option<SuggestionBatch>, RepositoryError>;
+        async fn get_batches(&self, user_id: &UserId, page: &KeysetPage) -> Result<Vec<SuggestionBatch>, RepositoryError>;
+        async fn search(&self, user_id: &UserId, term: &str, limit: u32) -> Result<Vec<Suggestion>, RepositoryError>;
+    }
+}
+
+mock! {
+    pub AiCallRepo {}
+
+    #[async_trait]
+    impl AiCallRepository for AiCallRepo {
+        async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError>;
+        async fn accept(&self, id: Uuid, user_id: &UserId, feature: AiFeature) -> Result<bool, RepositoryError>;
+        async fn accept_by_subject(&self, user_id: &UserId, feature: AiFeature, subject_id: &str) -> Result<(), RepositoryError>;
+        async fn get_results(&self, since: chrono::DateTime<Utc>) -> Result<Vec<VariantResult>, RepositoryError>;
+    }
+}
+
+mock! {
+    pub Log {}
+
+    impl Logger for Log {
+        fn info(&self, message: &str);
+        fn warn(&self, message: &str);
+        fn error(&self, message: &str);
+        fn debug(&self, message: &str);
+    }
+}
+
+mock! {
+    pub MetricsRecorder {}
+
+    impl Metrics for MetricsRecorder {
+        fn increment(&self, name: &str, value: u64);
+    }
+}
+
+/// Stands in for the OpenAI adapters: always answers, and promptly.
+struct HealthyProvider;
+
+#[async_trait]
+impl ExpiryEstimatorService for HealthyProvider {
+    async fn estimate_expiry_date(
+        &self,
+        _product_name: &str,
+        _status: &str,
+        _location: Option<String>,
+    ) -> ExpiryEstimation {
+        ExpiryEstimation {
+            date: Some(Utc::now() + chrono::Duration::days(3)),
+            confidence: Confidence::High,
+            unavailable: false,
+        }
+    }
+}
+
+#[async_trait]
+impl ProductIdentifierService for HealthyProvider {
+    async fn identify_by_image(
+        &self,
+        _image_base64: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        Ok(identification())
+    }
+
+    async fn identify_by_barcode(
+        &self,
+        _barcode: &str,
+    ) -> Result<ProductIdentification, ProductError> {
+        Ok(identification())
+    }
+
+    async fn identify_all_by_image(
+        &self,
+        _image_base64: &str,
+    ) -> Result<Vec<ProductIdentification>, ProductError> {
+        Ok(vec![identification()])
+    }
+}
+
+#[async_trait]
+impl SuggestionGeneratorService for HealthyProvider {
+    async fn generate(
+        &self,
+        _pantry: &PrioritizedPantry,
+        _limit: usize,
+    ) -> Result<Vec<Suggestion>, SuggestionError> {
+        Ok(vec![])
+    }
+}
+
+fn identification() -> ProductIdentification {
+    ProductIdentification {
+        name: "Leche".to_string(),
+        confidence: IdentificationConfidence::High,
+        method: IdentificationMethod::Visual,
+        suggested_location: None,
+        suggested_quantity: None,
+        suggested_expiry_type: None,
+        suggested_category: None,
+        enrichment: None,
+        ai_call_id: None,
+    }
+}
+
+fn always_failing() -> ChaosSettings {
+    ChaosSettings {
+        failure_rate: 1.0,
+        ..ChaosSettings::default()
+    }
+}
+
+fn always_malformed() -> ChaosSettings {
+    ChaosSettings {
+        malformed_rate: 1.0,
+        ..ChaosSettings::default()
+    }
+}
+
+fn test_user_id() -> UserId {
+    UserId::new("test-user-id")
+}
+
+fn mock_logger() -> Arc<dyn Logger> {
+    let mut logger = MockLog::new();
+    logger.expect_info().returning(|_| ());
+    logger.expect_warn().returning(|_| ());
+    logger.expect_error().returning(|_| ());
+    logger.expect_debug().returning(|_| ());
+    Arc::new(logger)
+}
+
+fn unlimited_quota() -> Arc<dyn QuotaService> {
+    let mut quota = MockQuota::new();
+    quota.expect_consume_ai_call().returning(|_| Ok(()));
+    quota.expect_ensure_product_capacity().returning(|_| Ok(()));
+    Arc::new(quota)
+}
+
+fn no_location_rules() -> Arc<dyn LocationRuleRepository> {
+    let mut rules = MockLocationRuleRepo::new();
+    rules.expect_get().returning(|_| Ok(None));
+    Arc::new(rules)
+}
+
+fn no_enrichment() -> Arc<dyn ProductEnrichmentRepository> {
+    Arc::new(MockEnrichmentRepo::new())
+}
+
+fn no_contributions() -> Arc<dyn BarcodeContributionRepository> {
+    Arc::new(MockContributionRepo::new())
+}
+
+fn no_experiment<S: ?Sized>() -> Arc<AiExperiment<S>> {
+    Arc::new(AiExperiment::inactive(
+        Arc::new(MockAiCallRepo::new()),
+        mock_logger(),
+    ))
+}
+
+fn create_use_case(estimator: Arc<dyn ExpiryEstimatorService>) -> CreateProductUseCaseImpl {
+    let mut repository = MockProductRepo::new();
+    repository.expect_insert().returning(|_| Ok(()));
+    repository.expect_update().returning(|_| Ok(()));
+    let mut ai_review_repository = MockAiReviewRepo::new();
+    ai_review_repository
+        .expect_get_mode()
+        .returning(|_| Ok(AiWriteMode::Auto));
+
+    CreateProductUseCaseImpl {
+        repository: Arc::new(repository),
+        estimator,
+        expiry_snap: ExpirySnap::default(),
+        duplicate_window: None,
+        quota_service: unlimited_quota(),
+        flags: FeatureFlags::default(),
+        location_rules: no_location_rules(),
+        ai_review_repository: Arc::new(ai_review_repository),
+        enrichment_repository: no_enrichment(),
+        estimation_retry: None,
+        event_publisher: Arc::new(InProcessEventBus {
+            handlers: Vec::new(),
+        }),
+        logger: mock_logger(),
+    }
+}
+
+fn create_params() -> CreateProductParams {
+    CreateProductParams {
+        user_id: test_user_id(),
+        name: "Leche".to_string(),
+        status: ProductStatus::Opened,
+        location: None,
+        quantity: None,
+        expiry_date: None,
+        estimated_expiry_date: None,
+        expiry_type: ExpiryType::None,
+        outcome: None,
+        category: None,
+        tags: Vec::new(),
+        barcode: None,
+    }
+}
+
+fn suggestions_use_case(
+    generator: Arc<dyn SuggestionGeneratorService>,
+) -> GenerateSuggestionsUseCaseImpl {
+    let mut repository = MockProductRepo::new();
+    repository.expect_find().returning(|_| {
+        Ok(vec![Product::from_repository(
+            Uuid::new_v4(),
+            test_user_id(),
+            "Pollo".to_string(),
+            ProductStatus::Opened,
+            None,
+            None,
+            Some(Utc::now() + chrono::Duration::days(2)),
+            None,
+            ExpiryType::None,
+            None,
+            Utc::now(),
+            Utc::now(),
+        )])
+    });
+    let mut suggestion_repository = MockSuggestionRepo::new();
+    suggestion_repository
+        .expect_get_latest_batch()
+        .returning(|_| Ok(None));
+    suggestion_repository
+        .expect_save_batch()
+        .returning(|_, _| Ok(()));
+    let mut preference_repository = MockPreferenceRepo::new();
+    preference_repository
+        .expect_get()
+        .returning(|_| Ok(UserPreferences::default()));
+    let mut meal_plan_repository = MockMealPlanRepo::new();
+    meal_plan_repository
+        .expect_get_reserved_product_ids()
+        .returning(|_| Ok(HashSet::new()));
+    let mut metrics = MockMetricsRecorder::new();
+    metrics.expect_increment().returning(|_, _| ());
+
+    GenerateSuggestionsUseCaseImpl {
+        repository: Arc::new(repository),
+        suggestion_repository: Arc::new(suggestion_repository),
+        preference_repository: Arc::new(preference_repository),
+        enrichment_repository: no_enrichment(),
+        meal_plan_repository: Arc::new(meal_plan_repository),
+        generator,
+        experiment: no_experiment(),
+        quota_service: unlimited_quota(),
+        pantry_limits: PantryPromptLimits::default(),
+        metrics: Arc::new(metrics),
+        logger: mock_logger(),
+    }
+}
+
+fn suggestions_params() -> GenerateSuggestionsParams {
+    GenerateSuggestionsParams {
+        user_id: test_user_id(),
+        limit: 3,
+        refresh: true,
+        metered: true,
+    }
+}
+
+#[tokio::test]
+async fn should_pass_calls_through_when_no_fault_is_configured() {
+    let use_case = create_use_case(Arc::new(Chaos::new(
+        HealthyProvider,
+        ChaosSettings::default(),
+    )));
+
+    let product = use_case.execute(create_params()).await.unwrap();
+
+    assert!(product.estimated_expiry_date.is_some());
+}
+
+#[tokio::test]
+async fn should_delay_calls_by_configured_latency() {
+    let use_case = create_use_case(Arc::new(Chaos::new(
+        HealthyProvider,
+        ChaosSettings {
+            latency: Duration::from_millis(50),
+            ..ChaosSettings::default()
+        },
+    )));
+    let started = Instant::now();
+
+    let result = use_case.execute(create_params()).await;
+
+    assert!(result.is_ok());
+    assert!(started.elapsed() >= Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn should_create_product_without_estimate_when_provider_fails() {
+    for settings in [always_failing(), always_malformed()] {
+        let use_case = create_use_case(Arc::new(Chaos::new(HealthyProvider, settings)));
+
+        let product = use_case.execute(create_params()).await.unwrap();
+
+        assert_eq!(product.name, "Leche");
+        assert!(product.estimated_expiry_date.is_none());
+    }
+}
+
+#[tokio::test]
+async fn should_mark_estimation_failed_when_provider_is_down() {
+    let use_case = create_use_case(Arc::new(Chaos::new(HealthyProvider, always_failing())));
+
+    let product = use_case.execute(create_params()).await.unwrap();
+
+    assert_eq!(product.estimation_status, EstimationStatus::Failed);
+}
+
+#[tokio::test]
+async fn should_report_identification_failure_when_provider_fails() {
+    let use_case = IdentifyProductUseCaseImpl {
+        identifier: Arc::new(Chaos::new(HealthyProvider, always_failing())),
+        experiment: no_experiment(),
+        quota_service: unlimited_quota(),
+        location_rules: no_location_rules(),
+        enrichment_repository: no_enrichment(),
+        contribution_repository: no_contributions(),
+        logger: mock_logger(),
+    };
+
+    let result = use_case
+        .execute_by_image(IdentifyByImageParams {
+            user_id: test_user_id(),
+            image_base64: "aGVsbG8=".to_string(),
+        })
+        .await;
+
+    assert!(matches!(result, Err(ProductError::IdentificationFailed(_))));
+}
+
+#[tokio::test]
+async fn should_return_retryable_error_when_suggestion_provider_is_down() {
+    let use_case = suggestions_use_case(Arc::new(Chaos::new(HealthyProvider, always_failing())));
+
+    let error = use_case.execute(suggestions_params()).await.unwrap_err();
+
+    assert!(matches!(error, SuggestionError::ProviderUnavailable));
+    assert_eq!(error.retry_after_seconds(), Some(30));
+}
+
+#[tokio::test]
+async fn should_return_parse_error_when_suggestions_are_malformed() {
+    let use_case = suggestions_use_case(Arc::new(Chaos::new(HealthyProvider, always_malformed())));
+
+    let error = use_case.execute(suggestions_params()).await.unwrap_err();
+
+    assert!(matches!(error, SuggestionError::ParseFailed { .. }));
+    assert_eq!(error.retry_after_seconds(), None);
+}
@@ -0,0 +1,116 @@
+//! Checks loading, overriding and hot-reloading the system prompt templates.
+
+use std::path::PathBuf;
+
+use business::domain::prompt::errors::PromptError;
+use business::domain::prompt::model::TemplateSource;
+use business::domain::prompt::services::PromptTemplateService;
+use openai::prompts::{Prompt, PromptContext, PromptTemplates};
+
+fn temp_dir(files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("prompts-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for (name, content) in files {
+        std::fs::write(dir.join(name), content).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn should_render_builtin_templates_with_context() {
+    let templates = PromptTemplates::builtin(PromptContext {
+        language: "English".to_string(),
+        cuisine: "British".to_string(),
+        constraints: vec!["Never suggest alcohol".to_string()],
+    });
+
+    let prompt = templates.render(Prompt::ReceiptScanner);
+
+    assert_eq!(prompt.version, 1);
+    assert_eq!(prompt.source, TemplateSource::Builtin);
+    assert!(
+        prompt
+            .text
+            .starts_with("You are a receipt scanner for a British kitchen inventory app.")
+    );
+    assert!(prompt.text.contains("the product name in English"));
+    assert!(
+        prompt
+            .text
+            .ends_with("Additional rules:\n- Never suggest alcohol")
+    );
+}
+
+#[tokio::test]
+async fn should_use_highest_directory_version_on_reload() {
+    let dir = temp_dir(&[]);
+    let templates = PromptTemplates::load(Some(dir.clone()), PromptContext::default()).unwrap();
+    assert_eq!(
+        templates.render(Prompt::ExpiryEstimator).source,
+        TemplateSource::Builtin
+    );
+
+    std::fs::write(
+        dir.join("expiry_estimator.v2.jinja"),
+        "v2 for {{ language }}",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.join("expiry_estimator.v10.jinja"),
+        "v10 for {{ language }}",
+    )
+    .unwrap();
+    std::fs::write(dir.join("README.md"), "not a template").unwrap();
+    let infos = templates.reload().await.unwrap();
+
+    let prompt = templates.render(Prompt::ExpiryEstimator);
+    assert_eq!(prompt.version, 10);
+    assert_eq!(&*prompt.text, "v10 for Spanish");
+    assert_eq!(infos.len(), Prompt::ALL.len());
+}
+
+#[tokio::test]
+async fn should_keep_current_templates_when_reload_fails() {
+    let dir = temp_dir(&[("receipt_scanner.v2.jinja", "Scan {{ language }}")]);
+    let templates = PromptTemplates::load(Some(dir.clone()), PromptContext::default()).unwrap();
+
+    std::fs::write(dir.join("receipt_scanner.v3.jinja"), "{% if %}").unwrap();
+    let result = templates.reload().await;
+
+    assert!(matches!(result, Err(PromptError::InvalidTemplate(_))));
+    assert_eq!(
+        &*templates.render(Prompt::ReceiptScanner).text,
+        "Scan Spanish"
+    );
+}
+
+#[test]
+fn should_reject_templates_for_unknown_prompts() {
+    let dir = temp_dir(&[("recipe_writer.v1.jinja", "Hello")]);
+
+    let result = PromptTemplates::load(Some(dir), PromptContext::default());
+
+    assert!(matches!(result, Err(PromptError::InvalidTemplate(_))));
+}
+
+#[test]
+fn should_render_pinned_versions_next_to_the_latest() {
+    let dir = temp_dir(&[
+        ("suggestion_generator.v2.jinja", "v2 in {{ language }}"),
+        ("suggestion_generator.v3.jinja", "v3 in {{ language }}"),
+    ]);
+    let templates = PromptTemplates::load(Some(dir), PromptContext::default()).unwrap();
+
+    assert_eq!(templates.render(Prompt::SuggestionGenerator).version, 3);
+    let pinned = templates.render_version(Prompt::SuggestionGenerator, Some(2));
+    assert_eq!(&*pinned.text, "v2 in Spanish");
+    let builtin = templates.render_version(Prompt::SuggestionGenerator, Some(1));
+    assert_eq!(builtin.source, TemplateSource::Builtin);
+    assert!(!templates.has_version(Prompt::SuggestionGenerator, 4));
+    assert_eq!(
+        templates
+            .render_version(Prompt::SuggestionGenerator, Some(4))
+            .version,
+        3
+    );
+}
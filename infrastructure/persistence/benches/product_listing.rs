@@ -0,0 +1,117 @@
+//! Product listing queries against a pantry of 100k products, to catch
+//! regressions in `GET /products` filters, sorting and indexes.
+//!
+//! Needs a migrated database: `BENCH_DATABASE_URL=postgres://... cargo bench -p persistence`.
+//! The dataset is seeded once under a dedicated user and reused by later
+//! runs. Without `BENCH_DATABASE_URL` the benchmark is skipped.
+
+use std::env;
+
+use chrono::{Duration, Utc};
+use criterion::{Criterion, criterion_group, criterion_main};
+use sqlx::{PgPool, Postgres, QueryBuilder};
+use tokio::runtime::Runtime;
+
+use business::domain::product::fixtures::sample_pantry;
+use business::domain::product::query::{Page, ProductQuery, ProductSort};
+use business::domain::product::repository::ProductRepository;
+use business::domain::shared::value_objects::UserId;
+use persistence::db::{DatabaseConfig, create_postgres_pool};
+use persistence::product::repository::ProductRepositoryPostgres;
+
+const BENCH_USER: &str = "bench-listing-user";
+const DATASET_SIZE: i64 = 100_000;
+/// Rows per INSERT; 11 binds each stays under the Postgres parameter limit.
+const INSERT_CHUNK: usize = 1_000;
+
+async fn seed(pool: &PgPool, user_id: &UserId) {
+    let existing: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM products WHERE user_id = $1")
+        .bind(user_id.as_str())
+        .fetch_one(pool)
+        .await
+        .expect("count bench products");
+    if existing >= DATASET_SIZE {
+        return;
+    }
+
+    let products = sample_pantry(user_id, (DATASET_SIZE - existing) as usize, 42, Utc::now());
+    for chunk in products.chunks(INSERT_CHUNK) {
+        let mut insert: QueryBuilder<Postgres> = QueryBuilder::new(
+            "INSERT INTO products (id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, outcome, created_at, updated_at) ",
+        );
+        insert.push_values(chunk, |mut row, product| {
+            row.push_bind(product.id)
+                .push_bind(product.user_id.as_str())
+                .push_bind(&product.name)
+                .push_bind(product.status.to_string())
+                .push_bind(product.location.as_ref().map(|l| l.to_string()))
+                .push_bind(&product.quantity)
+                .push_bind(product.expiry_date)
+                .push_bind(product.estimated_expiry_date)
+                .push_bind(product.outcome.as_ref().map(|o| o.to_string()))
+                .push_bind(product.created_at)
+                .push_bind(product.updated_at);
+        });
+        insert
+            .build()
+            .execute(pool)
+            .await
+            .expect("insert bench products");
+    }
+}
+
+fn listing(c: &mut Criterion) {
+    let Ok(url) = env::var("BENCH_DATABASE_URL") else {
+        eprintln!("BENCH_DATABASE_URL not set, skipping product listing benchmarks");
+        return;
+    };
+
+    let runtime = Runtime::new().expect("tokio runtime");
+    let user_id = UserId::new(BENCH_USER);
+    let pool = runtime.block_on(async {
+        let pool = create_postgres_pool(&DatabaseConfig::new(url))
+            .await
+            .expect("connect to bench database");
+        seed(&pool, &user_id).await;
+        pool
+    });
+    let repository = ProductRepositoryPostgres::new(pool);
+
+    let queries = [
+        ("first_page", ProductQuery::all(user_id.clone())),
+        (
+            "active_by_expiry",
+            ProductQuery::active(user_id.clone()).sorted_by(ProductSort::ExpiryAsc),
+        ),
+        (
+            "search_by_name",
+            ProductQuery::all(user_id.clone()).name_contains("leche"),
+        ),
+        (
+            "expiring_within_3_days",
+            ProductQuery::active(user_id.clone()).expiring_before(Utc::now() + Duration::days(3)),
+        ),
+        (
+            "deep_page",
+            ProductQuery::all(user_id.clone())
+                .sorted_by(ProductSort::NameAsc)
+                .paged(Page::new(50, 50_000)),
+        ),
+    ];
+
+    let mut group = c.benchmark_group("product_listing_100k");
+    group.sample_size(20);
+    for (name, query) in &queries {
+        group.bench_function(*name, |b| {
+            b.iter(|| {
+                runtime
+                    .block_on(repository.find(query))
+                    .expect("list products")
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, listing);
+criterion_main!(benches);
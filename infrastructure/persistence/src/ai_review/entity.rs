@@ -0,0 +1,44 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::ai_review::model::{AiChange, PendingAiChange};
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct PendingAiChangeEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub product_id: Uuid,
+    pub field: String,
+    pub value: Json<serde_json::Value>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PendingAiChangeEntity {
+    /// `None` when the stored field or value is not a known change.
+    pub fn into_domain(self) -> Option<PendingAiChange> {
+        let change = match self.field.as_str() {
+            "estimated_expiry_date" => {
+                AiChange::EstimatedExpiryDate(serde_json::from_value(self.value.0).ok()?)
+            }
+            _ => return None,
+        };
+
+        Some(PendingAiChange::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.product_id,
+            change,
+            self.created_at,
+        ))
+    }
+}
+
+/// JSON value stored for a change.
+pub fn change_value(change: &AiChange) -> serde_json::Value {
+    match change {
+        AiChange::EstimatedExpiryDate(date) => serde_json::json!(date),
+    }
+}
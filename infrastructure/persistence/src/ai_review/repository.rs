@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::ai_review::model::{AiWriteMode, PendingAiChange};
+use business::domain::ai_review::repository::AiReviewRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::{PendingAiChangeEntity, change_value};
+
+pub struct AiReviewRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl AiReviewRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AiReviewRepository for AiReviewRepositoryPostgres {
+    async fn get_mode(&self, user_id: &UserId) -> Result<AiWriteMode, RepositoryError> {
+        let mode: Option<String> =
+            sqlx::query_scalar("SELECT mode FROM ai_review_settings WHERE user_id = $1")
+                .bind(user_id.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(RepositoryError::database_error)?;
+
+        Ok(mode
+            .and_then(|m| m.parse::<AiWriteMode>().ok())
+            .unwrap_or_default())
+    }
+
+    async fn set_mode(&self, user_id: &UserId, mode: AiWriteMode) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO ai_review_settings (user_id, mode, updated_at)
+            VALUES ($1, $2, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                mode = EXCLUDED.mode,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(mode.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_pending(&self, user_id: &UserId) -> Result<Vec<PendingAiChange>, RepositoryError> {
+        let entities = sqlx::query_as::<_, PendingAiChangeEntity>(
+            "SELECT id, user_id, product_id, field, value, created_at FROM pending_ai_changes WHERE user_id = $1 ORDER BY created_at",
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(|e| e.into_domain())
+            .collect())
+    }
+
+    async fn get_pending_by_id(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+    ) -> Result<PendingAiChange, RepositoryError> {
+        sqlx::query_as::<_, PendingAiChangeEntity>(
+            "SELECT id, user_id, product_id, field, value, created_at FROM pending_ai_changes WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .and_then(|e| e.into_domain())
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn stage(&self, change: &PendingAiChange) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO pending_ai_changes (id, user_id, product_id, field, value, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (product_id, field) DO UPDATE SET
+                value = EXCLUDED.value,
+                created_at = EXCLUDED.created_at"#,
+        )
+        .bind(change.id)
+        .bind(change.user_id.as_str())
+        .bind(change.product_id)
+        .bind(change.change.field())
+        .bind(Json(change_value(&change.change)))
+        .bind(change.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_pending(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM pending_ai_changes WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use business::domain::auth::model::{ChallengePurpose, LocalAccount, LoginChallenge, Passkey};
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct LoginChallengeEntity {
+    pub challenge_hash: String,
+    pub purpose: String,
+    pub email: Option<String>,
+    pub user_id: Option<String>,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl LoginChallengeEntity {
+    pub fn into_domain(self) -> Result<LoginChallenge, RepositoryError> {
+        let purpose = self
+            .purpose
+            .parse::<ChallengePurpose>()
+            .map_err(|_| RepositoryError::Persistence)?;
+        Ok(LoginChallenge::from_repository(
+            self.challenge_hash,
+            purpose,
+            self.email,
+            self.user_id.map(UserId::new),
+            self.expires_at,
+        ))
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LocalAccountEntity {
+    pub user_id: String,
+    pub email: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LocalAccountEntity {
+    pub fn into_domain(self) -> LocalAccount {
+        LocalAccount::from_repository(UserId::new(self.user_id), self.email, self.created_at)
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct PasskeyEntity {
+    pub credential_id: String,
+    pub user_id: String,
+    pub public_key: Vec<u8>,
+    pub sign_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl PasskeyEntity {
+    pub fn into_domain(self) -> Passkey {
+        Passkey {
+            credential_id: self.credential_id,
+            user_id: UserId::new(self.user_id),
+            public_key: self.public_key,
+            sign_count: self.sign_count as u32,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+        }
+    }
+}
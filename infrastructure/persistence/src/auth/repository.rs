@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::auth::model::{LocalAccount, LoginChallenge, Passkey};
+use business::domain::auth::repository::AuthRepository;
+use business::domain::errors::RepositoryError;
+
+use super::entity::{LocalAccountEntity, LoginChallengeEntity, PasskeyEntity};
+use crate::db::write_error;
+
+pub struct AuthRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl AuthRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AuthRepository for AuthRepositoryPostgres {
+    async fn save_challenge(&self, challenge: &LoginChallenge) -> Result<(), RepositoryError> {
+        // Challenges nobody answered would otherwise pile up
+        sqlx::query("DELETE FROM login_challenges WHERE expires_at <= NOW()")
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        sqlx::query(
+            r#"INSERT INTO login_challenges (challenge_hash, purpose, email, user_id, expires_at)
+            VALUES ($1, $2, $3, $4, $5)"#,
+        )
+        .bind(&challenge.challenge_hash)
+        .bind(challenge.purpose.to_string())
+        .bind(challenge.email.as_deref())
+        .bind(challenge.user_id.as_ref().map(|id| id.as_str()))
+        .bind(challenge.expires_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn take_challenge(
+        &self,
+        challenge_hash: &str,
+    ) -> Result<Option<LoginChallenge>, RepositoryError> {
+        let entity = sqlx::query_as::<_, LoginChallengeEntity>(
+            r#"DELETE FROM login_challenges WHERE challenge_hash = $1
+            RETURNING challenge_hash, purpose, email, user_id, expires_at"#,
+        )
+        .bind(challenge_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        entity.map(|e| e.into_domain()).transpose()
+    }
+
+    async fn find_account_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<LocalAccount>, RepositoryError> {
+        let entity = sqlx::query_as::<_, LocalAccountEntity>(
+            "SELECT user_id, email, created_at FROM local_accounts WHERE email = $1",
+        )
+        .bind(email)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn insert_account(&self, account: &LocalAccount) -> Result<(), RepositoryError> {
+        sqlx::query("INSERT INTO local_accounts (user_id, email, created_at) VALUES ($1, $2, $3)")
+            .bind(account.user_id.as_str())
+            .bind(&account.email)
+            .bind(account.created_at)
+            .execute(&self.pool)
+            .await
+            .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn insert_passkey(&self, passkey: &Passkey) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO passkeys (credential_id, user_id, public_key, sign_count, created_at, last_used_at)
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(&passkey.credential_id)
+        .bind(passkey.user_id.as_str())
+        .bind(&passkey.public_key)
+        .bind(i64::from(passkey.sign_count))
+        .bind(passkey.created_at)
+        .bind(passkey.last_used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn find_passkey(&self, credential_id: &str) -> Result<Option<Passkey>, RepositoryError> {
+        let entity = sqlx::query_as::<_, PasskeyEntity>(
+            r#"SELECT credential_id, user_id, public_key, sign_count, created_at, last_used_at
+            FROM passkeys WHERE credential_id = $1"#,
+        )
+        .bind(credential_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn record_passkey_use(
+        &self,
+        credential_id: &str,
+        sign_count: u32,
+        used_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE passkeys SET sign_count = $2, last_used_at = $3 WHERE credential_id = $1",
+        )
+        .bind(credential_id)
+        .bind(i64::from(sign_count))
+        .bind(used_at)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
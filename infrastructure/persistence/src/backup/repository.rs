@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+use business::domain::backup::errors::BackupError;
+use business::domain::backup::model::BackupRows;
+use business::domain::backup::repository::BackupRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+use crate::backup::restore::{BACKUP_TABLES, prepare};
+use crate::db::write_error;
+
+pub struct BackupRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl BackupRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BackupRepository for BackupRepositoryPostgres {
+    async fn schema_version(&self) -> Result<i64, RepositoryError> {
+        sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations WHERE success")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)
+    }
+
+    async fn export(
+        &self,
+        user_id: &UserId,
+    ) -> Result<BTreeMap<String, BackupRows>, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+        // One snapshot for every table, so rows don't point at missing parents
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        let mut tables = BTreeMap::new();
+        for table in &BACKUP_TABLES {
+            let Json(rows): Json<BackupRows> = sqlx::query_scalar(&format!(
+                "SELECT COALESCE(json_agg(t), '[]') FROM {} t WHERE t.user_id = $1",
+                table.name
+            ))
+            .bind(user_id.as_str())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+            tables.insert(table.name.to_string(), rows);
+        }
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(tables)
+    }
+
+    async fn restore(
+        &self,
+        user_id: &UserId,
+        tables: &BTreeMap<String, BackupRows>,
+    ) -> Result<usize, BackupError> {
+        let prepared = prepare(user_id, tables)?;
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // Held until commit, so a second restore for the user waits here and
+        // then finds the first one's rows.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(format!("backup-restore:{user_id}"))
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        let any_rows = BACKUP_TABLES
+            .iter()
+            .map(|table| format!("EXISTS(SELECT 1 FROM {} WHERE user_id = $1)", table.name))
+            .collect::<Vec<_>>()
+            .join(" OR ");
+        let has_data: bool = sqlx::query_scalar(&format!("SELECT {any_rows}"))
+            .bind(user_id.as_str())
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+        if has_data {
+            return Err(BackupError::AccountNotEmpty);
+        }
+
+        let mut restored = 0;
+        for (table, rows) in prepared {
+            let result = sqlx::query(&format!(
+                "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1)"
+            ))
+            .bind(Json(rows))
+            .execute(&mut *tx)
+            .await
+            .map_err(write_error)?;
+            restored += result.rows_affected() as usize;
+        }
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(restored)
+    }
+}
@@ -0,0 +1,612 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use business::domain::ai_review::model::AiWriteMode;
+use business::domain::backup::errors::BackupError;
+use business::domain::backup::model::BackupRows;
+use business::domain::barcode_contribution::model::UpstreamStatus;
+use business::domain::budget::model::Budget;
+use business::domain::challenge::model::ChallengeKind;
+use business::domain::cooking_session::model::{CookingSessionStatus, CookingStepStatus};
+use business::domain::product::enrichment::Allergen;
+use business::domain::product::services::Confidence;
+use business::domain::product::tags::normalize_tags;
+use business::domain::product::urgency::ExpiringSoonWindow;
+use business::domain::product::value_objects::{
+    EstimationStatus, ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
+use business::domain::receipt_import::model::ReceiptImportStatus;
+use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_trip::model::ShoppingTripStatus;
+
+use crate::cooking_session::entity::CookingStepRecord;
+use crate::location_rule::entity::LocationRuleRecord;
+use crate::receipt_import::entity::ImportReviewRecord;
+use crate::shopping_trip::entity::TripItemRecord;
+use crate::store_profile::entity::AisleRecord;
+use crate::suggestion::entity::{SuggestionIngredientRecord, SuggestionRecord};
+
+type Row = Map<String, Value>;
+
+/// A table backed up per user and how its rows are written back.
+pub struct BackupTable {
+    pub name: &'static str,
+    /// Whether rows are keyed by a UUID `id`, replaced by a new one on restore
+    keyed_by_id: bool,
+    /// Columns holding the id (or a list of ids) of a row restored with it,
+    /// and the table that row is in
+    references: &'static [(&'static str, &'static str)],
+    /// Columns naming files in blob storage, which archives don't carry
+    files: &'static [&'static str],
+    /// Whether the domain accepts the row's values
+    is_valid: fn(&Row) -> bool,
+}
+
+/// Tables backed up per user, parents before the rows that point to them so
+/// they can be restored in this order. Usage counters, jobs and experiment
+/// call logs are operational state and stay behind, as does the plan, which
+/// belongs to the instance's billing. Inbound email addresses belong to the
+/// instance's mail domain and stay behind too, as do widget tokens, share
+/// links and registered devices, which only work for the account they were
+/// made for. Sent notifications were already delivered and stay behind as
+/// well, and so does barcode enrichment, which every user shares.
+pub const BACKUP_TABLES: [BackupTable; 21] = [
+    BackupTable {
+        name: "products",
+        keyed_by_id: true,
+        references: &[],
+        files: &["thumbnail_key"],
+        is_valid: valid_product,
+    },
+    BackupTable {
+        name: "product_reminders",
+        keyed_by_id: true,
+        references: &[("product_id", "products")],
+        files: &[],
+        is_valid: |row| named(row, "note"),
+    },
+    BackupTable {
+        name: "pending_ai_changes",
+        keyed_by_id: true,
+        references: &[("product_id", "products")],
+        files: &[],
+        is_valid: valid_pending_ai_change,
+    },
+    BackupTable {
+        name: "shopping_items",
+        keyed_by_id: true,
+        references: &[("product_id", "products")],
+        files: &["attachment_content_type"],
+        is_valid: |row| named(row, "name"),
+    },
+    BackupTable {
+        name: "shopping_trips",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |row| {
+            parses::<ShoppingTripStatus>(row, "status")
+                && decodes::<Vec<TripItemRecord>>(row, "items")
+        },
+    },
+    BackupTable {
+        name: "suggestion_batches",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |row| decodes::<Vec<SuggestionRecord>>(row, "suggestions"),
+    },
+    BackupTable {
+        name: "cooking_sessions",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: valid_cooking_session,
+    },
+    BackupTable {
+        name: "planned_meals",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |row| named(row, "title"),
+    },
+    BackupTable {
+        name: "product_reservations",
+        keyed_by_id: false,
+        references: &[
+            ("planned_meal_id", "planned_meals"),
+            ("product_id", "products"),
+        ],
+        files: &[],
+        is_valid: |_| true,
+    },
+    BackupTable {
+        name: "receipt_imports",
+        keyed_by_id: true,
+        references: &[],
+        files: &["image_key", "thumbnail_key"],
+        is_valid: |row| {
+            parses::<ReceiptImportStatus>(row, "status")
+                && decodes::<ImportReviewRecord>(row, "review")
+        },
+    },
+    BackupTable {
+        name: "store_profiles",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |row| named(row, "name") && decodes::<Vec<AisleRecord>>(row, "aisles"),
+    },
+    BackupTable {
+        name: "location_rules",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |row| {
+            decoded::<Vec<LocationRuleRecord>>(row, "rules")
+                .is_some_and(|rules| rules.into_iter().all(|r| r.into_domain().is_some()))
+        },
+    },
+    BackupTable {
+        name: "ai_review_settings",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |row| parses::<AiWriteMode>(row, "mode"),
+    },
+    BackupTable {
+        name: "user_preferences",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: valid_user_preferences,
+    },
+    BackupTable {
+        name: "inventory_snapshots",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |_| true,
+    },
+    BackupTable {
+        name: "challenges",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |row| parses::<ChallengeKind>(row, "kind"),
+    },
+    BackupTable {
+        name: "weekly_waste",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |_| true,
+    },
+    BackupTable {
+        name: "waste_streaks",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |_| true,
+    },
+    BackupTable {
+        name: "barcode_contributions",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |row| named(row, "name") && parses::<UpstreamStatus>(row, "upstream_status"),
+    },
+    BackupTable {
+        name: "vacations",
+        keyed_by_id: true,
+        references: &[],
+        files: &[],
+        is_valid: |_| true,
+    },
+    BackupTable {
+        name: "budgets",
+        keyed_by_id: false,
+        references: &[],
+        files: &[],
+        is_valid: |row| {
+            let cents = row.get("monthly_cents").and_then(Value::as_u64);
+            let alerts = row.get("alerts_enabled").and_then(Value::as_bool);
+            matches!((cents, alerts), (Some(cents), Some(alerts)) if Budget::new(cents, alerts).is_ok())
+        },
+    },
+];
+
+/// The archive's rows as `user_id`'s, table by table in restore order.
+///
+/// Every row must hold values the domain accepts. Rows get new ids, and
+/// every reference to them (columns, id lists, JSON documents) follows, so a
+/// backup restores next to the account it was taken from without clashing.
+/// A reference to a row the archive doesn't hold would point into another
+/// account and fails the restore, as do files, which the archive doesn't
+/// carry and are cleared.
+pub fn prepare(
+    user_id: &UserId,
+    tables: &BTreeMap<String, BackupRows>,
+) -> Result<Vec<(&'static str, BackupRows)>, BackupError> {
+    let archived = |table: &BackupTable| tables.get(table.name).map_or(&[][..], Vec::as_slice);
+
+    // Every id is replaced up front, so a row can point at one restored later
+    let mut new_ids: HashMap<Uuid, (&'static str, Uuid)> = HashMap::new();
+    for table in BACKUP_TABLES.iter().filter(|t| t.keyed_by_id) {
+        for row in archived(table) {
+            let id = row
+                .get("id")
+                .and_then(as_uuid)
+                .ok_or_else(|| invalid(table))?;
+            if new_ids.insert(id, (table.name, Uuid::new_v4())).is_some() {
+                return Err(invalid(table));
+            }
+        }
+    }
+
+    let mut prepared = Vec::new();
+    for table in &BACKUP_TABLES {
+        let rows = archived(table)
+            .iter()
+            .map(|row| restored(table, row, user_id, &new_ids).ok_or_else(|| invalid(table)))
+            .collect::<Result<BackupRows, _>>()?;
+        if !rows.is_empty() {
+            prepared.push((table.name, rows));
+        }
+    }
+    Ok(prepared)
+}
+
+fn invalid(table: &BackupTable) -> BackupError {
+    BackupError::InvalidRow {
+        table: table.name.to_string(),
+    }
+}
+
+/// The row as it is written back, or `None` when it can't be.
+fn restored(
+    table: &BackupTable,
+    row: &Value,
+    user_id: &UserId,
+    new_ids: &HashMap<Uuid, (&'static str, Uuid)>,
+) -> Option<Value> {
+    let mut row = row.as_object()?.clone();
+    if !(table.is_valid)(&row) {
+        return None;
+    }
+
+    for (column, value) in row.iter_mut() {
+        match table.references.iter().find(|(c, _)| c == column) {
+            Some((_, parent)) => *value = referenced(value, parent, new_ids)?,
+            None => replace_ids(value, new_ids),
+        }
+    }
+    for column in table.files {
+        row.insert(column.to_string(), Value::Null);
+    }
+    row.insert("user_id".to_string(), user_id.as_str().into());
+    Some(Value::Object(row))
+}
+
+/// The new id of the `parent` row `value` points at, or of every row in a
+/// list of them. `None` when one isn't in the archive.
+fn referenced(
+    value: &Value,
+    parent: &str,
+    new_ids: &HashMap<Uuid, (&'static str, Uuid)>,
+) -> Option<Value> {
+    match value {
+        Value::Null => Some(Value::Null),
+        Value::Array(ids) => ids
+            .iter()
+            .map(|id| referenced(id, parent, new_ids))
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Array),
+        id => match new_ids.get(&as_uuid(id)?) {
+            Some((table, new_id)) if *table == parent => Some(new_id.to_string().into()),
+            _ => None,
+        },
+    }
+}
+
+/// Swaps the archive's ids for the new ones wherever they appear, including
+/// inside JSON documents.
+fn replace_ids(value: &mut Value, new_ids: &HashMap<Uuid, (&'static str, Uuid)>) {
+    match value {
+        Value::String(text) => {
+            if let Some((_, new_id)) = Uuid::parse_str(text).ok().and_then(|id| new_ids.get(&id)) {
+                *text = new_id.to_string();
+            }
+        }
+        Value::Array(values) => values.iter_mut().for_each(|v| replace_ids(v, new_ids)),
+        Value::Object(columns) => columns.values_mut().for_each(|v| replace_ids(v, new_ids)),
+        _ => {}
+    }
+}
+
+fn as_uuid(value: &Value) -> Option<Uuid> {
+    value.as_str().and_then(|id| Uuid::parse_str(id).ok())
+}
+
+/// Whether the column holds text that isn't blank.
+fn named(row: &Row, column: &str) -> bool {
+    row.get(column)
+        .and_then(Value::as_str)
+        .is_some_and(|text| !text.trim().is_empty())
+}
+
+/// Whether the column is empty or holds one of the domain's values of `T`.
+fn parses<T: FromStr>(row: &Row, column: &str) -> bool {
+    match row.get(column) {
+        None | Some(Value::Null) => true,
+        Some(Value::String(text)) => text.parse::<T>().is_ok(),
+        Some(_) => false,
+    }
+}
+
+/// Whether the column is empty or holds a JSON document shaped like `T`.
+fn decodes<T: DeserializeOwned>(row: &Row, column: &str) -> bool {
+    match row.get(column) {
+        None | Some(Value::Null) => true,
+        Some(_) => decoded::<T>(row, column).is_some(),
+    }
+}
+
+fn decoded<T: DeserializeOwned>(row: &Row, column: &str) -> Option<T> {
+    row.get(column).and_then(|value| T::deserialize(value).ok())
+}
+
+fn valid_product(row: &Row) -> bool {
+    named(row, "name")
+        && parses::<ProductStatus>(row, "status")
+        && parses::<ProductLocation>(row, "location")
+        && parses::<ExpiryType>(row, "expiry_type")
+        && parses::<ProductOutcome>(row, "outcome")
+        && parses::<EstimationStatus>(row, "estimation_status")
+        && parses::<ProductCategory>(row, "category")
+        && parses::<Confidence>(row, "expiry_confidence")
+        && decoded::<Vec<String>>(row, "tags").is_none_or(|tags| normalize_tags(tags).is_ok())
+}
+
+/// Estimated expiry dates are the only change staged for review.
+fn valid_pending_ai_change(row: &Row) -> bool {
+    row.get("field").and_then(Value::as_str) == Some("estimated_expiry_date")
+        && decoded::<DateTime<Utc>>(row, "value").is_some()
+}
+
+fn valid_cooking_session(row: &Row) -> bool {
+    named(row, "title")
+        && parses::<CookingSessionStatus>(row, "status")
+        && decodes::<Vec<SuggestionIngredientRecord>>(row, "ingredients")
+        && decoded::<Vec<CookingStepRecord>>(row, "steps").is_some_and(|steps| {
+            steps
+                .iter()
+                .all(|step| step.status.parse::<CookingStepStatus>().is_ok())
+        })
+}
+
+fn valid_user_preferences(row: &Row) -> bool {
+    let window = row
+        .get("expiring_soon_days")
+        .and_then(Value::as_u64)
+        .and_then(|days| u8::try_from(days).ok())
+        .and_then(ExpiringSoonWindow::new);
+    window.is_some()
+        && decoded::<Vec<String>>(row, "allergies")
+            .is_none_or(|allergies| allergies.iter().all(|a| a.parse::<Allergen>().is_ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn archive(tables: Value) -> BTreeMap<String, BackupRows> {
+        serde_json::from_value(tables).unwrap()
+    }
+
+    fn product(id: &str) -> Value {
+        json!({
+            "id": id,
+            "user_id": "old-account",
+            "name": "Leche",
+            "status": "opened",
+            "expiry_type": "best_before",
+            "estimation_status": "done",
+            "tags": ["desayuno"],
+            "thumbnail_key": "thumbnails/products/old-account/a"
+        })
+    }
+
+    const LECHE: &str = "6f1c1a56-2b0c-4c6e-9a8f-3f0b1f5e2d11";
+    const ELSEWHERE: &str = "0b8e8c2e-4a51-4e1b-8f63-6d5e3c9b7a20";
+    const MEAL: &str = "9a3d2f61-7c84-4b0e-b5a2-1e6f8d4c3b97";
+
+    /// Splits on the commas outside parentheses.
+    fn top_level(text: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let (mut depth, mut start) = (0, 0);
+        for (i, c) in text.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    parts.push(&text[start..i]);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        parts.push(&text[start..]);
+        parts
+    }
+
+    /// Columns of every table once all migrations have run, read off their
+    /// CREATE TABLE and ALTER TABLE statements.
+    fn schema() -> HashMap<String, Vec<String>> {
+        const CONSTRAINTS: [&str; 5] = ["PRIMARY", "UNIQUE", "FOREIGN", "CONSTRAINT", "CHECK"];
+        let mut tables: HashMap<String, Vec<String>> = HashMap::new();
+        for migration in crate::db::MIGRATOR.iter() {
+            let sql = migration
+                .sql
+                .lines()
+                .map(|line| line.split("--").next().unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join(" ");
+            for statement in sql.split(';') {
+                let words: Vec<&str> = statement.split_whitespace().collect();
+                match words.as_slice() {
+                    ["CREATE", "TABLE", name, ..] => {
+                        let body = &statement
+                            [statement.find('(').unwrap() + 1..statement.rfind(')').unwrap()];
+                        let columns = top_level(body)
+                            .into_iter()
+                            .filter_map(|definition| definition.split_whitespace().next())
+                            .filter(|first| !CONSTRAINTS.contains(first))
+                            .map(String::from)
+                            .collect();
+                        tables.insert(name.to_string(), columns);
+                    }
+                    ["ALTER", "TABLE", name, "RENAME", "TO", renamed] => {
+                        let columns = tables.remove(*name).unwrap_or_default();
+                        tables.insert(renamed.to_string(), columns);
+                    }
+                    ["ALTER", "TABLE", name, clauses @ ..] => {
+                        let columns = tables.entry(name.to_string()).or_default();
+                        for clause in top_level(&clauses.join(" ")) {
+                            match clause.split_whitespace().collect::<Vec<_>>().as_slice() {
+                                ["ADD", "COLUMN", column, ..] => columns.push(column.to_string()),
+                                ["DROP", "COLUMN", column] => columns.retain(|c| c != column),
+                                ["RENAME", "COLUMN", from, "TO", to] => {
+                                    for c in columns.iter_mut().filter(|c| c == from) {
+                                        *c = to.to_string();
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        tables
+    }
+
+    fn rows<'a>(prepared: &'a [(&str, BackupRows)], table: &str) -> &'a BackupRows {
+        &prepared.iter().find(|(name, _)| *name == table).unwrap().1
+    }
+
+    #[test]
+    fn should_give_rows_new_ids_and_follow_references_to_them() {
+        let tables = archive(json!({
+            "products": [product(LECHE)],
+            "product_reminders": [{"id": ELSEWHERE, "product_id": LECHE, "note": "Abrir"}],
+            "planned_meals": [{"id": MEAL, "title": "Tortilla"}],
+            "product_reservations": [{"planned_meal_id": MEAL, "product_id": LECHE}],
+            "suggestion_batches": [{"id": Uuid::new_v4(), "suggestions": []}],
+        }));
+
+        let prepared = prepare(&UserId::new("new-account"), &tables).unwrap();
+
+        let product = &rows(&prepared, "products")[0];
+        let new_id = product["id"].as_str().unwrap();
+        assert_ne!(new_id, LECHE);
+        assert_eq!(product["user_id"], "new-account");
+        assert_eq!(product["thumbnail_key"], Value::Null);
+        assert_eq!(
+            rows(&prepared, "product_reminders")[0]["product_id"],
+            new_id
+        );
+        let reservation = &rows(&prepared, "product_reservations")[0];
+        assert_eq!(reservation["product_id"], new_id);
+        assert_eq!(
+            reservation["planned_meal_id"],
+            rows(&prepared, "planned_meals")[0]["id"]
+        );
+    }
+
+    #[test]
+    fn should_reject_references_to_rows_outside_the_archive() {
+        let tables = archive(json!({
+            "products": [product(LECHE)],
+            "shopping_items": [{"id": Uuid::new_v4(), "name": "Leche", "product_id": ELSEWHERE}],
+        }));
+
+        let result = prepare(&UserId::new("new-account"), &tables);
+
+        assert!(matches!(
+            result,
+            Err(BackupError::InvalidRow { table }) if table == "shopping_items"
+        ));
+    }
+
+    #[test]
+    fn should_reject_values_the_domain_does_not_accept() {
+        let mut spoiled = product(LECHE);
+        spoiled["status"] = json!("spoiled");
+        let tables = archive(json!({"products": [spoiled]}));
+
+        let result = prepare(&UserId::new("new-account"), &tables);
+
+        assert!(matches!(
+            result,
+            Err(BackupError::InvalidRow { table }) if table == "products"
+        ));
+    }
+
+    #[test]
+    fn should_leave_out_tables_that_are_not_restored() {
+        let tables = archive(json!({
+            "products": [product(LECHE)],
+            "product_enrichment": [{"barcode": "8410000000000", "nutriscore": "a"}],
+        }));
+
+        let prepared = prepare(&UserId::new("new-account"), &tables).unwrap();
+
+        assert_eq!(
+            prepared.iter().map(|(name, _)| *name).collect::<Vec<_>>(),
+            vec!["products"]
+        );
+    }
+
+    #[test]
+    fn should_only_name_columns_the_schema_has() {
+        let schema = schema();
+
+        for table in &BACKUP_TABLES {
+            let columns = schema
+                .get(table.name)
+                .unwrap_or_else(|| panic!("no table {}", table.name));
+            assert!(
+                columns.iter().any(|c| c == "user_id"),
+                "{}.user_id",
+                table.name
+            );
+            for (column, parent) in table.references {
+                assert!(
+                    columns.iter().any(|c| c == column),
+                    "{}.{column}",
+                    table.name
+                );
+                assert!(
+                    schema.contains_key(*parent),
+                    "{}.{column} -> {parent}",
+                    table.name
+                );
+            }
+            for column in table.files {
+                assert!(
+                    columns.iter().any(|c| c == column),
+                    "{}.{column}",
+                    table.name
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::badge::model::{BadgeCounts, BadgeWindow};
+use business::domain::badge::repository::BadgeRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+pub struct BadgeRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl BadgeRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BadgeRepository for BadgeRepositoryPostgres {
+    async fn get_counts(
+        &self,
+        user_id: &UserId,
+        window: &BadgeWindow,
+    ) -> Result<BadgeCounts, RepositoryError> {
+        let (urgent_products, unbought_items, new_suggestions, unread_notifications): (
+            i64,
+            i64,
+            i32,
+            i64,
+        ) = sqlx::query_as(
+            r#"SELECT
+                (SELECT COUNT(*) FROM products
+                    WHERE user_id = $1 AND status != 'finished'
+                    AND COALESCE(expiry_date, estimated_expiry_date) >= $2
+                    AND COALESCE(expiry_date, estimated_expiry_date) < $3),
+                (SELECT COUNT(*) FROM shopping_items
+                    WHERE user_id = $1 AND is_bought = FALSE),
+                COALESCE((SELECT jsonb_array_length(suggestions) FROM suggestion_batches
+                    WHERE user_id = $1 AND generated_at >= $4
+                    ORDER BY generated_at DESC LIMIT 1), 0),
+                (SELECT COUNT(*) FROM notifications
+                    WHERE user_id = $1 AND read_at IS NULL)"#,
+        )
+        .bind(user_id.as_str())
+        .bind(window.now)
+        .bind(window.urgent_until)
+        .bind(window.fresh_since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(BadgeCounts {
+            urgent_products: urgent_products as u32,
+            unbought_items: unbought_items as u32,
+            new_suggestions: new_suggestions as u32,
+            unread_notifications: unread_notifications as u32,
+        })
+    }
+}
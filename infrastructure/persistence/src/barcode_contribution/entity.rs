@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use business::domain::barcode_contribution::model::{BarcodeContribution, UpstreamStatus};
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct BarcodeContributionEntity {
+    pub user_id: String,
+    pub barcode: String,
+    pub name: String,
+    pub upstream_status: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BarcodeContributionEntity {
+    pub fn into_domain(self) -> BarcodeContribution {
+        BarcodeContribution {
+            user_id: UserId::new(self.user_id),
+            barcode: self.barcode,
+            name: self.name,
+            upstream_status: self
+                .upstream_status
+                .parse()
+                .unwrap_or(UpstreamStatus::Failed),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
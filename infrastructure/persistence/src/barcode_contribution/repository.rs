@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::barcode_contribution::model::BarcodeContribution;
+use business::domain::barcode_contribution::repository::BarcodeContributionRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::BarcodeContributionEntity;
+use crate::db::write_error;
+
+pub struct BarcodeContributionRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl BarcodeContributionRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BarcodeContributionRepository for BarcodeContributionRepositoryPostgres {
+    async fn find(
+        &self,
+        user_id: &UserId,
+        barcode: &str,
+    ) -> Result<Option<BarcodeContribution>, RepositoryError> {
+        let entity = sqlx::query_as::<_, BarcodeContributionEntity>(
+            r#"SELECT user_id, barcode, name, upstream_status, created_at, updated_at
+            FROM barcode_contributions
+            WHERE user_id = $1 AND barcode = $2"#,
+        )
+        .bind(user_id.as_str())
+        .bind(barcode)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(BarcodeContributionEntity::into_domain))
+    }
+
+    async fn save(&self, contribution: &BarcodeContribution) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO barcode_contributions
+                (user_id, barcode, name, upstream_status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id, barcode) DO UPDATE SET
+                name = EXCLUDED.name,
+                upstream_status = EXCLUDED.upstream_status,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(contribution.user_id.as_str())
+        .bind(&contribution.barcode)
+        .bind(&contribution.name)
+        .bind(contribution.upstream_status.to_string())
+        .bind(contribution.created_at)
+        .bind(contribution.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+}
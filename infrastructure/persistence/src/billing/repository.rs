@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::billing::model::{PlanChangeOutcome, WebhookEvent};
+use business::domain::billing::repository::PlanRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::quota::model::PlanTier;
+use business::domain::shared::value_objects::UserId;
+
+pub struct PlanRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl PlanRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PlanRepository for PlanRepositoryPostgres {
+    async fn set_plan(
+        &self,
+        user_id: &UserId,
+        plan: PlanTier,
+        customer_id: Option<String>,
+        event: &WebhookEvent,
+    ) -> Result<PlanChangeOutcome, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // The event id's primary key settles concurrent retries of the same event
+        let recorded =
+            sqlx::query("INSERT INTO billing_events (id) VALUES ($1) ON CONFLICT (id) DO NOTHING")
+                .bind(&event.id)
+                .execute(&mut *tx)
+                .await
+                .map_err(RepositoryError::database_error)?;
+        if recorded.rows_affected() == 0 {
+            return Ok(PlanChangeOutcome::Duplicate);
+        }
+
+        // Stripe timestamps have second precision, so events from the same
+        // second apply in arrival order
+        let updated = sqlx::query(
+            r#"INSERT INTO users (user_id, plan, stripe_customer_id, plan_changed_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE SET
+                plan = EXCLUDED.plan,
+                stripe_customer_id = COALESCE(EXCLUDED.stripe_customer_id, users.stripe_customer_id),
+                plan_changed_at = EXCLUDED.plan_changed_at,
+                updated_at = NOW()
+            WHERE users.plan_changed_at IS NULL OR users.plan_changed_at <= EXCLUDED.plan_changed_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(plan.to_string())
+        .bind(customer_id)
+        .bind(event.created)
+        .execute(&mut *tx)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        // A stale event is still recorded, so its retries are skipped as duplicates
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(if updated.rows_affected() == 0 {
+            PlanChangeOutcome::Stale
+        } else {
+            PlanChangeOutcome::Applied
+        })
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::budget::model::Budget;
+use business::domain::budget::repository::BudgetRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+pub struct BudgetRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl BudgetRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BudgetRepository for BudgetRepositoryPostgres {
+    async fn get(&self, user_id: &UserId) -> Result<Option<Budget>, RepositoryError> {
+        let row: Option<(i64, bool)> =
+            sqlx::query_as("SELECT monthly_cents, alerts_enabled FROM budgets WHERE user_id = $1")
+                .bind(user_id.as_str())
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(RepositoryError::database_error)?;
+
+        Ok(row.map(|(monthly_cents, alerts_enabled)| Budget {
+            monthly_cents: monthly_cents.max(0) as u64,
+            alerts_enabled,
+        }))
+    }
+
+    async fn save(&self, user_id: &UserId, budget: &Budget) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO budgets (user_id, monthly_cents, alerts_enabled, updated_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                monthly_cents = EXCLUDED.monthly_cents,
+                alerts_enabled = EXCLUDED.alerts_enabled,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(budget.monthly_cents as i64)
+        .bind(budget.alerts_enabled)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM budgets WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_spent_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<u64, RepositoryError> {
+        // Prices live on the trip items, stored as JSON inside each trip
+        let (spent,): (i64,) = sqlx::query_as(
+            r#"SELECT COALESCE(SUM((item->>'price_cents')::BIGINT), 0)::BIGINT
+            FROM shopping_trips, jsonb_array_elements(items) AS item
+            WHERE user_id = $1
+              AND item->>'price_cents' IS NOT NULL
+              AND (item->>'bought_at')::TIMESTAMPTZ >= $2"#,
+        )
+        .bind(user_id.as_str())
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(spent.max(0) as u64)
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::challenge::model::{Challenge, ChallengeKind};
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct ChallengeEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub kind: String,
+    pub target: i32,
+    pub progress: i32,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ChallengeEntity {
+    pub fn into_domain(self) -> Challenge {
+        Challenge::from_repository(
+            self.id,
+            UserId::new(self.user_id),
+            self.kind
+                .parse::<ChallengeKind>()
+                .unwrap_or(ChallengeKind::UseUrgentItems),
+            self.target as u32,
+            self.progress as u32,
+            self.starts_at,
+            self.ends_at,
+            self.completed_at,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::challenge::model::Challenge;
+use business::domain::challenge::repository::ChallengeRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::pagination::KeysetPage;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::ChallengeEntity;
+use crate::db::write_error;
+
+const CHALLENGE_COLUMNS: &str =
+    "id, user_id, kind, target, progress, starts_at, ends_at, completed_at, created_at, updated_at";
+
+pub struct ChallengeRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl ChallengeRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ChallengeRepository for ChallengeRepositoryPostgres {
+    async fn start(&self, challenge: &Challenge) -> Result<Challenge, RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO challenges (id, user_id, kind, target, progress, starts_at, ends_at, completed_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (user_id, kind, starts_at) DO NOTHING"#,
+        )
+        .bind(challenge.id)
+        .bind(challenge.user_id.as_str())
+        .bind(challenge.kind.to_string())
+        .bind(challenge.target as i32)
+        .bind(challenge.progress as i32)
+        .bind(challenge.starts_at)
+        .bind(challenge.ends_at)
+        .bind(challenge.completed_at)
+        .bind(challenge.created_at)
+        .bind(challenge.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        let entity = sqlx::query_as::<_, ChallengeEntity>(&format!(
+            "SELECT {CHALLENGE_COLUMNS} FROM challenges WHERE user_id = $1 AND kind = $2 AND starts_at = $3"
+        ))
+        .bind(challenge.user_id.as_str())
+        .bind(challenge.kind.to_string())
+        .bind(challenge.starts_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn update(&self, challenge: &Challenge) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE challenges SET
+                progress = $3,
+                completed_at = $4,
+                updated_at = $5
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(challenge.id)
+        .bind(challenge.user_id.as_str())
+        .bind(challenge.progress as i32)
+        .bind(challenge.completed_at)
+        .bind(challenge.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn get_history(
+        &self,
+        user_id: &UserId,
+        page: &KeysetPage,
+    ) -> Result<Vec<Challenge>, RepositoryError> {
+        let (after_starts_at, after_id) = page
+            .after
+            .map(|cursor| (cursor.created_at, cursor.id))
+            .unzip();
+
+        let entities = sqlx::query_as::<_, ChallengeEntity>(&format!(
+            "SELECT {CHALLENGE_COLUMNS} FROM challenges \
+             WHERE user_id = $1 AND ($2::timestamptz IS NULL OR (starts_at, id) < ($2, $3)) \
+             ORDER BY starts_at DESC, id DESC LIMIT $4"
+        ))
+        .bind(user_id.as_str())
+        .bind(after_starts_at)
+        .bind(after_id)
+        .bind(i64::from(page.fetch_limit()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::cooking_session::model::{
+    CookingSession, CookingSessionStatus, CookingStep, CookingStepStatus,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::suggestion::entity::SuggestionIngredientRecord;
+
+/// JSON representation of a cooking step stored inside a session.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CookingStepRecord {
+    pub position: usize,
+    pub instruction: String,
+    pub status: String,
+}
+
+impl From<&CookingStep> for CookingStepRecord {
+    fn from(s: &CookingStep) -> Self {
+        Self {
+            position: s.position,
+            instruction: s.instruction.clone(),
+            status: s.status.to_string(),
+        }
+    }
+}
+
+impl CookingStepRecord {
+    pub fn into_domain(self) -> CookingStep {
+        CookingStep {
+            position: self.position,
+            instruction: self.instruction,
+            status: self
+                .status
+                .parse::<CookingStepStatus>()
+                .unwrap_or(CookingStepStatus::Pending),
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct CookingSessionEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub suggestion_id: String,
+    pub title: String,
+    pub ingredients: Json<Vec<SuggestionIngredientRecord>>,
+    pub steps: Json<Vec<CookingStepRecord>>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl CookingSessionEntity {
+    pub fn into_domain(self) -> CookingSession {
+        CookingSession::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.suggestion_id,
+            self.title,
+            self.ingredients
+                .0
+                .into_iter()
+                .map(|i| i.into_domain())
+                .collect(),
+            self.steps.0.into_iter().map(|s| s.into_domain()).collect(),
+            self.status
+                .parse::<CookingSessionStatus>()
+                .unwrap_or(CookingSessionStatus::InProgress),
+            self.created_at,
+            self.updated_at,
+            self.completed_at,
+        )
+    }
+}
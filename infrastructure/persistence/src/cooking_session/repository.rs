@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::cooking_session::model::{CookingSession, CookingSessionStatus};
+use business::domain::cooking_session::repository::CookingSessionRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::{CookingSessionEntity, CookingStepRecord};
+use crate::db::write_error;
+use crate::suggestion::entity::SuggestionIngredientRecord;
+
+pub struct CookingSessionRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl CookingSessionRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CookingSessionRepository for CookingSessionRepositoryPostgres {
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+    ) -> Result<CookingSession, RepositoryError> {
+        let entity = sqlx::query_as::<_, CookingSessionEntity>(
+            "SELECT id, user_id, suggestion_id, title, ingredients, steps, status, created_at, updated_at, completed_at FROM cooking_sessions WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn find_in_progress_by_suggestion(
+        &self,
+        suggestion_id: &str,
+        user_id: &UserId,
+    ) -> Result<Option<CookingSession>, RepositoryError> {
+        let entity = sqlx::query_as::<_, CookingSessionEntity>(
+            "SELECT id, user_id, suggestion_id, title, ingredients, steps, status, created_at, updated_at, completed_at FROM cooking_sessions WHERE suggestion_id = $1 AND user_id = $2 AND status = $3 ORDER BY created_at DESC LIMIT 1",
+        )
+        .bind(suggestion_id)
+        .bind(user_id.as_str())
+        .bind(CookingSessionStatus::InProgress.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn insert(&self, session: &CookingSession) -> Result<(), RepositoryError> {
+        let ingredients: Vec<SuggestionIngredientRecord> =
+            session.ingredients.iter().map(|i| i.into()).collect();
+        let steps: Vec<CookingStepRecord> = session.steps.iter().map(|s| s.into()).collect();
+
+        sqlx::query(
+            r#"INSERT INTO cooking_sessions (id, user_id, suggestion_id, title, ingredients, steps, status, created_at, updated_at, completed_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+        )
+        .bind(session.id)
+        .bind(session.user_id.as_str())
+        .bind(&session.suggestion_id)
+        .bind(&session.title)
+        .bind(Json(ingredients))
+        .bind(Json(steps))
+        .bind(session.status.to_string())
+        .bind(session.created_at)
+        .bind(session.updated_at)
+        .bind(session.completed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, session: &CookingSession) -> Result<(), RepositoryError> {
+        let steps: Vec<CookingStepRecord> = session.steps.iter().map(|s| s.into()).collect();
+
+        let result = sqlx::query(
+            r#"UPDATE cooking_sessions SET
+                steps = $3,
+                status = $4,
+                updated_at = $5,
+                completed_at = $6
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(session.id)
+        .bind(session.user_id.as_str())
+        .bind(Json(steps))
+        .bind(session.status.to_string())
+        .bind(session.updated_at)
+        .bind(session.completed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,30 @@
-use sqlx::{PgPool, postgres::PgPoolOptions};
-use std::{path::Path, time::Duration};
+use business::domain::errors::RepositoryError;
+use sqlx::{
+    PgPool,
+    migrate::{Migration, Migrator},
+    postgres::PgPoolOptions,
+};
+use std::{
+    future::Future,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use thiserror::Error;
 
+/// How long a replica health check is trusted before running another.
+const REPLICA_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Seconds the replica is behind the primary; zero once it has replayed
+/// everything it received, so an idle primary doesn't read as lag.
+const REPLICA_LAG_QUERY: &str = r#"SELECT COALESCE(
+        CASE WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0
+        ELSE EXTRACT(EPOCH FROM NOW() - pg_last_xact_replay_timestamp()) END,
+        0)::FLOAT8"#;
+
+/// Migrations this build expects, embedded at compile time.
+pub(crate) static MIGRATOR: Migrator = sqlx::migrate!("./src/migrations");
+
 #[derive(Error, Debug)]
 pub enum DatabaseError {
     #[error("database.connection_error")]
@@ -10,6 +33,22 @@ pub enum DatabaseError {
     MigrationError,
 }
 
+/// Maps a failed write, telling a unique constraint conflict apart from any
+/// other database failure.
+pub(crate) fn write_error(e: sqlx::Error) -> RepositoryError {
+    match e {
+        sqlx::Error::Database(ref db) if db.is_unique_violation() => RepositoryError::Duplicated,
+        e => RepositoryError::database_error(e),
+    }
+}
+
+/// Escapes the ILIKE wildcards so a search term matches literally.
+pub(crate) fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 /// Configuration for the database connection
 pub struct DatabaseConfig {
     pub connection_string: String,
@@ -40,6 +79,163 @@ pub async fn create_postgres_pool(config: &DatabaseConfig) -> Result<PgPool, Dat
     Ok(pool)
 }
 
+/// Read-only replica with its last known health.
+#[derive(Clone)]
+struct Replica {
+    pool: PgPool,
+    max_lag: Duration,
+    /// When the replica was last checked and whether it was usable
+    health: Arc<Mutex<Option<(Instant, bool)>>>,
+    /// Held while a health check runs, so concurrent reads don't each start one
+    checking: Arc<tokio::sync::Mutex<()>>,
+}
+
+/// Pool for read-heavy queries (listings, stats) that can tolerate slightly
+/// stale data. Uses the replica when one is configured, reachable and no
+/// further behind than `max_lag`; the primary otherwise. Reads that must see
+/// the caller's own writes should keep using the primary.
+#[derive(Clone)]
+pub struct ReadPool {
+    primary: PgPool,
+    replica: Option<Replica>,
+}
+
+impl ReadPool {
+    /// Sends every read to the primary.
+    pub fn primary(primary: PgPool) -> Self {
+        Self {
+            primary,
+            replica: None,
+        }
+    }
+
+    /// Sends reads to `replica` while it lags at most `max_lag`.
+    pub fn with_replica(primary: PgPool, replica: PgPool, max_lag: Duration) -> Self {
+        Self {
+            primary,
+            replica: Some(Replica {
+                pool: replica,
+                max_lag,
+                health: Arc::new(Mutex::new(None)),
+                checking: Arc::new(tokio::sync::Mutex::new(())),
+            }),
+        }
+    }
+
+    /// Runs a read on the replica when it is usable, on the primary
+    /// otherwise. A read the replica fails for a reason of its own (gone,
+    /// restarting, cancelled by WAL replay) is run again on the primary, and
+    /// the replica is skipped until the next health check.
+    pub async fn read<T, F, Fut>(&self, query: F) -> Result<T, sqlx::Error>
+    where
+        F: Fn(PgPool) -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        let replica = match &self.replica {
+            Some(replica) if replica.is_healthy().await => replica,
+            _ => return query(self.primary.clone()).await,
+        };
+
+        match query(replica.pool.clone()).await {
+            Err(e) if is_replica_failure(&e) => {
+                tracing::warn!("Read replica query failed, retrying on the primary: {e}");
+                replica.record(false);
+                query(self.primary.clone()).await
+            }
+            result => result,
+        }
+    }
+}
+
+impl Replica {
+    /// Last check's verdict while it is fresh. Once it expires, one caller
+    /// runs the next check and the others keep going by the expired verdict
+    /// (the primary if there is none yet) instead of waiting for it.
+    async fn is_healthy(&self) -> bool {
+        let last = self.last_health();
+        if let Some(healthy) = fresh(last) {
+            return healthy;
+        }
+
+        let Ok(_checking) = self.checking.try_lock() else {
+            return last.is_some_and(|(_, healthy)| healthy);
+        };
+        // Another caller may have finished a check between the read and the lock
+        if let Some(healthy) = fresh(self.last_health()) {
+            return healthy;
+        }
+        let healthy = self.check().await;
+        self.record(healthy);
+        healthy
+    }
+
+    fn last_health(&self) -> Option<(Instant, bool)> {
+        *self.health.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn record(&self, healthy: bool) {
+        *self.health.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), healthy));
+    }
+
+    async fn check(&self) -> bool {
+        match sqlx::query_scalar::<_, f64>(REPLICA_LAG_QUERY)
+            .fetch_one(&self.pool)
+            .await
+        {
+            Ok(lag) if is_within_lag(lag, self.max_lag) => true,
+            Ok(lag) => {
+                tracing::warn!("Read replica is {lag:.1}s behind, reading from the primary");
+                false
+            }
+            Err(e) => {
+                tracing::warn!("Read replica unavailable, reading from the primary: {e}");
+                false
+            }
+        }
+    }
+}
+
+/// The verdict of a health check still within [`REPLICA_CHECK_INTERVAL`].
+fn fresh(health: Option<(Instant, bool)>) -> Option<bool> {
+    health
+        .filter(|(checked_at, _)| checked_at.elapsed() < REPLICA_CHECK_INTERVAL)
+        .map(|(_, healthy)| healthy)
+}
+
+/// Whether a failed read says something about the replica rather than the
+/// query: the connection is gone, or the server refused or cancelled it
+/// (SQLSTATE classes 08 and 57, and 40001 for a conflict with WAL replay).
+/// Those are worth retrying on the primary; any other error would fail there
+/// too.
+fn is_replica_failure(e: &sqlx::Error) -> bool {
+    match e {
+        sqlx::Error::Io(_)
+        | sqlx::Error::Tls(_)
+        | sqlx::Error::Protocol(_)
+        | sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed
+        | sqlx::Error::WorkerCrashed => true,
+        sqlx::Error::Database(db) => db.code().is_some_and(|code| {
+            code.starts_with("08") || code.starts_with("57") || code == "40001"
+        }),
+        _ => false,
+    }
+}
+
+fn is_within_lag(lag_seconds: f64, max_lag: Duration) -> bool {
+    lag_seconds <= max_lag.as_secs_f64()
+}
+
+/// Creates a pool for a read replica without connecting, so an unreachable
+/// replica doesn't stop the API from starting.
+pub fn create_replica_pool(config: &DatabaseConfig) -> Result<PgPool, DatabaseError> {
+    PgPoolOptions::new()
+        .max_connections(config.max_connections)
+        .acquire_timeout(config.acquire_timeout)
+        .connect_lazy(&config.connection_string)
+        .map_err(|_| DatabaseError::ConnectionError)
+}
+
 /// Runs database migrations from the specified directory
 pub async fn run_migrations(pool: &PgPool, migrations_path: &str) -> Result<(), DatabaseError> {
     let path = Path::new(migrations_path);
@@ -57,3 +253,139 @@ pub async fn run_migrations(pool: &PgPool, migrations_path: &str) -> Result<(),
         .await
         .map_err(|_| DatabaseError::MigrationError)
 }
+
+/// Migrations of this build not yet applied to the database, as
+/// "<version> <description>". All of them when the database was never migrated.
+pub async fn pending_migrations(pool: &PgPool) -> Result<Vec<String>, DatabaseError> {
+    let tracked: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(pool)
+        .await
+        .map_err(|_| DatabaseError::ConnectionError)?;
+    let applied: Vec<i64> = if tracked {
+        sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success")
+            .fetch_all(pool)
+            .await
+            .map_err(|_| DatabaseError::MigrationError)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(unapplied(MIGRATOR.iter(), &applied))
+}
+
+fn unapplied<'a>(migrations: impl Iterator<Item = &'a Migration>, applied: &[i64]) -> Vec<String> {
+    migrations
+        .filter(|m| !m.migration_type.is_down_migration() && !applied.contains(&m.version))
+        .map(|m| format!("{} {}", m.version, m.description))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn should_accept_replica_only_within_max_lag() {
+        // Arrange
+        let max_lag = Duration::from_secs(5);
+
+        // Act & Assert
+        assert!(is_within_lag(0.0, max_lag));
+        assert!(is_within_lag(5.0, max_lag));
+        assert!(!is_within_lag(5.5, max_lag));
+    }
+
+    /// Read pool whose pools never connect; the replica last checked healthy
+    /// at `checked_at`.
+    fn read_pool(checked_at: Instant) -> ReadPool {
+        let unreachable = || {
+            PgPoolOptions::new()
+                .connect_lazy("postgres://foodie@127.0.0.1:1/foodie")
+                .unwrap()
+        };
+        let pool = ReadPool::with_replica(unreachable(), unreachable(), Duration::from_secs(5));
+        let replica = pool.replica.as_ref().unwrap();
+        *replica.health.lock().unwrap() = Some((checked_at, true));
+        pool
+    }
+
+    #[tokio::test]
+    async fn should_not_wait_on_a_health_check_already_running() {
+        // Arrange
+        let expired = Instant::now() - REPLICA_CHECK_INTERVAL * 2;
+        let pool = read_pool(expired);
+        let replica = pool.replica.as_ref().unwrap();
+        let _running = replica.checking.lock().await;
+
+        // Act
+        let healthy = replica.is_healthy().await;
+
+        // Assert
+        assert!(
+            healthy,
+            "goes by the last verdict instead of checking again"
+        );
+    }
+
+    #[tokio::test]
+    async fn should_retry_on_primary_when_replica_connection_fails() {
+        // Arrange
+        let pool = read_pool(Instant::now());
+        let calls = AtomicU32::new(0);
+
+        // Act
+        let result = pool
+            .read(|_| async {
+                if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(sqlx::Error::Io(std::io::ErrorKind::ConnectionReset.into()))
+                } else {
+                    Ok(7)
+                }
+            })
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 7);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        let replica = pool.replica.as_ref().unwrap();
+        assert_eq!(
+            replica.last_health().map(|(_, healthy)| healthy),
+            Some(false)
+        );
+    }
+
+    #[tokio::test]
+    async fn should_not_retry_on_primary_when_the_query_itself_fails() {
+        // Arrange
+        let pool = read_pool(Instant::now());
+        let calls = AtomicU32::new(0);
+
+        // Act
+        let result: Result<(), _> = pool
+            .read(|_| async {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err(sqlx::Error::RowNotFound)
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn should_list_only_migrations_missing_from_database() {
+        // Arrange
+        let versions: Vec<i64> = MIGRATOR.iter().map(|m| m.version).collect();
+        let (last, applied) = versions.split_last().unwrap();
+
+        // Act
+        let pending = unapplied(MIGRATOR.iter(), applied);
+
+        // Assert
+        assert_eq!(pending.len(), 1);
+        assert!(pending[0].starts_with(&last.to_string()));
+        assert!(unapplied(MIGRATOR.iter(), &versions).is_empty());
+    }
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use business::domain::device::model::{Device, DevicePlatform, InstallCount};
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct DeviceEntity {
+    pub user_id: String,
+    pub installation_id: String,
+    pub platform: String,
+    pub app_version: String,
+    pub push_token: Option<String>,
+    pub registered_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl DeviceEntity {
+    pub fn into_domain(self) -> Device {
+        Device::from_repository(
+            UserId::new(self.user_id),
+            self.installation_id,
+            parse_platform(&self.platform),
+            self.app_version,
+            self.push_token,
+            self.registered_at,
+            self.last_seen_at,
+        )
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct InstallCountEntity {
+    pub platform: String,
+    pub app_version: String,
+    pub installs: i64,
+}
+
+impl InstallCountEntity {
+    pub fn into_domain(self) -> InstallCount {
+        InstallCount {
+            platform: parse_platform(&self.platform),
+            app_version: self.app_version,
+            installs: self.installs.max(0) as u64,
+        }
+    }
+}
+
+fn parse_platform(platform: &str) -> DevicePlatform {
+    platform
+        .parse::<DevicePlatform>()
+        .unwrap_or(DevicePlatform::Web)
+}
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::device::model::{Device, InstallCount};
+use business::domain::device::repository::DeviceRepository;
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::{DeviceEntity, InstallCountEntity};
+use crate::db::write_error;
+
+pub struct DeviceRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl DeviceRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DeviceRepository for DeviceRepositoryPostgres {
+    async fn register(&self, device: &Device) -> Result<Device, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // Release the token first so the one-install-per-token index never
+        // sees it twice
+        if let Some(token) = &device.push_token {
+            sqlx::query(
+                r#"UPDATE devices SET push_token = NULL
+                WHERE push_token = $1 AND NOT (user_id = $2 AND installation_id = $3)"#,
+            )
+            .bind(token)
+            .bind(device.user_id.as_str())
+            .bind(&device.installation_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+        }
+
+        let entity = sqlx::query_as::<_, DeviceEntity>(
+            r#"INSERT INTO devices (user_id, installation_id, platform, app_version, push_token, registered_at, last_seen_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id, installation_id) DO UPDATE SET
+                platform = EXCLUDED.platform,
+                app_version = EXCLUDED.app_version,
+                push_token = EXCLUDED.push_token,
+                last_seen_at = EXCLUDED.last_seen_at
+            RETURNING user_id, installation_id, platform, app_version, push_token, registered_at, last_seen_at"#,
+        )
+        .bind(device.user_id.as_str())
+        .bind(&device.installation_id)
+        .bind(device.platform.to_string())
+        .bind(&device.app_version)
+        .bind(&device.push_token)
+        .bind(device.registered_at)
+        .bind(device.last_seen_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(write_error)?;
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn find_pushable(&self, user_id: &UserId) -> Result<Vec<Device>, RepositoryError> {
+        let entities = sqlx::query_as::<_, DeviceEntity>(
+            r#"SELECT user_id, installation_id, platform, app_version, push_token, registered_at, last_seen_at
+            FROM devices
+            WHERE user_id = $1 AND push_token IS NOT NULL
+            ORDER BY last_seen_at DESC"#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities
+            .into_iter()
+            .map(DeviceEntity::into_domain)
+            .collect())
+    }
+
+    async fn count_seen_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<InstallCount>, RepositoryError> {
+        let entities = sqlx::query_as::<_, InstallCountEntity>(
+            r#"SELECT platform, app_version, COUNT(*) AS installs
+            FROM devices
+            WHERE last_seen_at >= $1
+            GROUP BY platform, app_version
+            ORDER BY installs DESC, platform, app_version"#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities
+            .into_iter()
+            .map(InstallCountEntity::into_domain)
+            .collect())
+    }
+
+    async fn delete_not_seen_since(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM devices WHERE last_seen_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
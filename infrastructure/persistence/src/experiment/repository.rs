@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::experiment::model::{AiCall, AiFeature, VariantResult};
+use business::domain::experiment::repository::AiCallRepository;
+use business::domain::shared::value_objects::UserId;
+
+use crate::db::write_error;
+
+pub struct AiCallRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl AiCallRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AiCallRepository for AiCallRepositoryPostgres {
+    async fn insert(&self, call: &AiCall) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO ai_calls (id, user_id, feature, variant, subject_ids, created_at, accepted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+        )
+        .bind(call.id)
+        .bind(call.user_id.as_str())
+        .bind(call.feature.to_string())
+        .bind(&call.variant)
+        .bind(&call.subject_ids)
+        .bind(call.created_at)
+        .bind(call.accepted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn accept(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+        feature: AiFeature,
+    ) -> Result<bool, RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE ai_calls SET accepted_at = COALESCE(accepted_at, NOW())
+            WHERE id = $1 AND user_id = $2 AND feature = $3"#,
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .bind(feature.to_string())
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn accept_by_subject(
+        &self,
+        user_id: &UserId,
+        feature: AiFeature,
+        subject_id: &str,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"UPDATE ai_calls SET accepted_at = NOW()
+            WHERE user_id = $1 AND feature = $2 AND $3 = ANY(subject_ids)
+              AND accepted_at IS NULL"#,
+        )
+        .bind(user_id.as_str())
+        .bind(feature.to_string())
+        .bind(subject_id)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_results(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<VariantResult>, RepositoryError> {
+        let rows: Vec<(String, String, i64, i64)> = sqlx::query_as(
+            r#"SELECT feature, variant, COUNT(*), COUNT(accepted_at)
+            FROM ai_calls
+            WHERE created_at >= $1
+            GROUP BY feature, variant"#,
+        )
+        .bind(since)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        // Features dropped from the code since are left out
+        Ok(rows
+            .into_iter()
+            .filter_map(|(feature, variant, calls, accepted)| {
+                Some(VariantResult {
+                    feature: feature.parse().ok()?,
+                    variant,
+                    calls: calls.max(0) as u64,
+                    accepted: accepted.max(0) as u64,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+
+use business::domain::inbound_email::model::InboundAddress;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct InboundAddressEntity {
+    pub user_id: String,
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl InboundAddressEntity {
+    pub fn into_domain(self) -> InboundAddress {
+        InboundAddress {
+            user_id: UserId::new(self.user_id),
+            token: self.token,
+            created_at: self.created_at,
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::inbound_email::model::InboundAddress;
+use business::domain::inbound_email::repository::InboundAddressRepository;
+use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_item::model::ShoppingItem;
+
+use super::entity::InboundAddressEntity;
+use crate::db::write_error;
+
+pub struct InboundAddressRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl InboundAddressRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InboundAddressRepository for InboundAddressRepositoryPostgres {
+    async fn find_by_user(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<InboundAddress>, RepositoryError> {
+        let entity = sqlx::query_as::<_, InboundAddressEntity>(
+            "SELECT user_id, token, created_at FROM inbound_addresses WHERE user_id = $1",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(InboundAddressEntity::into_domain))
+    }
+
+    async fn find_by_token(&self, token: &str) -> Result<Option<InboundAddress>, RepositoryError> {
+        let entity = sqlx::query_as::<_, InboundAddressEntity>(
+            "SELECT user_id, token, created_at FROM inbound_addresses WHERE token = $1",
+        )
+        .bind(token)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(InboundAddressEntity::into_domain))
+    }
+
+    async fn create(&self, address: &InboundAddress) -> Result<InboundAddress, RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO inbound_addresses (user_id, token, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id) DO NOTHING"#,
+        )
+        .bind(address.user_id.as_str())
+        .bind(&address.token)
+        .bind(address.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        self.find_by_user(&address.user_id)
+            .await?
+            .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn replace(&self, address: &InboundAddress) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO inbound_addresses (user_id, token, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id) DO UPDATE SET
+                token = EXCLUDED.token,
+                created_at = EXCLUDED.created_at"#,
+        )
+        .bind(address.user_id.as_str())
+        .bind(&address.token)
+        .bind(address.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn count_received_since(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<u32, RepositoryError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM inbound_emails WHERE user_id = $1 AND received_at >= $2",
+        )
+        .bind(user_id.as_str())
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(count as u32)
+    }
+
+    async fn record_received(
+        &self,
+        user_id: &UserId,
+        webhook_token: &str,
+        items: &[ShoppingItem],
+    ) -> Result<bool, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // A concurrent delivery of the same webhook waits on the unique token
+        // until this one commits, then finds it taken
+        let result = sqlx::query(
+            r#"INSERT INTO inbound_emails (user_id, webhook_token, items)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (webhook_token) DO NOTHING"#,
+        )
+        .bind(user_id.as_str())
+        .bind(webhook_token)
+        .bind(items.len() as i32)
+        .execute(&mut *tx)
+        .await
+        .map_err(write_error)?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        for item in items {
+            sqlx::query(
+                r#"INSERT INTO shopping_items (id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            )
+            .bind(item.id)
+            .bind(item.user_id.as_str())
+            .bind(&item.name)
+            .bind(item.product_id)
+            .bind(item.is_bought)
+            .bind(&item.attachment_content_type)
+            .bind(item.created_at)
+            .bind(item.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(write_error)?;
+        }
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+        Ok(true)
+    }
+}
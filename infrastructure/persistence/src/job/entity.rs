@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::job::model::{Job, JobKind, JobProgress, JobStatus};
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct JobEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub kind: String,
+    pub status: String,
+    pub total: i32,
+    pub processed: i32,
+    pub failed: i32,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl JobEntity {
+    pub fn into_domain(self) -> Job {
+        Job::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.kind
+                .parse::<JobKind>()
+                .unwrap_or(JobKind::EstimateMissingExpiry),
+            self.status
+                .parse::<JobStatus>()
+                .unwrap_or(JobStatus::Failed),
+            JobProgress {
+                total: self.total as u32,
+                processed: self.processed as u32,
+                failed: self.failed as u32,
+            },
+            self.error,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::job::model::{Job, JobKind, JobStatus};
+use business::domain::job::repository::JobRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::JobEntity;
+use crate::db::write_error;
+
+const JOB_COLUMNS: &str =
+    "id, user_id, kind, status, total, processed, failed, error, created_at, updated_at";
+
+pub struct JobRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl JobRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JobRepository for JobRepositoryPostgres {
+    async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Job, RepositoryError> {
+        let entity = sqlx::query_as::<_, JobEntity>(&format!(
+            "SELECT {JOB_COLUMNS} FROM jobs WHERE id = $1 AND user_id = $2"
+        ))
+        .bind(id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn find_unfinished(
+        &self,
+        user_id: &UserId,
+        kind: JobKind,
+    ) -> Result<Option<Job>, RepositoryError> {
+        let entity = sqlx::query_as::<_, JobEntity>(&format!(
+            r#"SELECT {JOB_COLUMNS} FROM jobs
+            WHERE user_id = $1 AND kind = $2 AND status IN ($3, $4)
+            ORDER BY created_at DESC
+            LIMIT 1"#
+        ))
+        .bind(user_id.as_str())
+        .bind(kind.to_string())
+        .bind(JobStatus::Pending.to_string())
+        .bind(JobStatus::Running.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn insert(&self, job: &Job) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO jobs (id, user_id, kind, status, total, processed, failed, error, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)"#,
+        )
+        .bind(job.id)
+        .bind(job.user_id.as_str())
+        .bind(job.kind.to_string())
+        .bind(job.status.to_string())
+        .bind(job.progress.total as i32)
+        .bind(job.progress.processed as i32)
+        .bind(job.progress.failed as i32)
+        .bind(&job.error)
+        .bind(job.created_at)
+        .bind(job.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, job: &Job) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE jobs SET
+                status = $3,
+                processed = $4,
+                failed = $5,
+                error = $6,
+                updated_at = $7
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(job.id)
+        .bind(job.user_id.as_str())
+        .bind(job.status.to_string())
+        .bind(job.progress.processed as i32)
+        .bind(job.progress.failed as i32)
+        .bind(&job.error)
+        .bind(job.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
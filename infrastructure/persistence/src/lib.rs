@@ -1,5 +1,80 @@
 pub mod db;
+pub mod ai_review {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod auth {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod backup {
+    pub mod repository;
+    pub mod restore;
+}
+pub mod badge {
+    pub mod repository;
+}
+pub mod barcode_contribution {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod billing {
+    pub mod repository;
+}
+pub mod budget {
+    pub mod repository;
+}
+pub mod challenge {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod cooking_session {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod experiment {
+    pub mod repository;
+}
+pub mod inbound_email {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod job {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod location_rule {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod meal_plan {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod preference {
+    pub mod entity;
+    pub mod repository;
+}
 pub mod product {
+    pub mod entity;
+    pub mod query;
+    pub mod repository;
+}
+pub mod quota {
+    pub mod service;
+}
+pub mod receipt_import {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod reminder {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod sandbox {
+    pub mod repository;
+}
+pub mod share_link {
     pub mod entity;
     pub mod repository;
 }
@@ -7,3 +82,34 @@ pub mod shopping_item {
     pub mod entity;
     pub mod repository;
 }
+pub mod shopping_trip {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod stats {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod store_profile {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod suggestion {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod widget {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod vacation {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod device {
+    pub mod entity;
+    pub mod repository;
+}
+pub mod notification {
+    pub mod repository;
+}
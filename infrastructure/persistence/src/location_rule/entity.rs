@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+
+use business::domain::location_rule::model::{LocationRule, LocationRuleSet};
+use business::domain::product::value_objects::ProductLocation;
+use business::domain::shared::value_objects::UserId;
+
+/// JSON representation of a rule stored inside a user's rule list.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocationRuleRecord {
+    pub keyword: String,
+    pub location: String,
+}
+
+impl From<&LocationRule> for LocationRuleRecord {
+    fn from(r: &LocationRule) -> Self {
+        Self {
+            keyword: r.keyword.clone(),
+            location: r.location.to_string(),
+        }
+    }
+}
+
+impl LocationRuleRecord {
+    /// `None` when the stored location is no longer a known one.
+    pub fn into_domain(self) -> Option<LocationRule> {
+        let location = self.location.parse::<ProductLocation>().ok()?;
+        Some(LocationRule {
+            keyword: self.keyword,
+            location,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct LocationRuleSetEntity {
+    pub user_id: String,
+    pub rules: Json<Vec<LocationRuleRecord>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl LocationRuleSetEntity {
+    pub fn into_domain(self) -> LocationRuleSet {
+        LocationRuleSet::from_repository(
+            UserId::new(&self.user_id),
+            self.rules
+                .0
+                .into_iter()
+                .filter_map(|r| r.into_domain())
+                .collect(),
+            self.updated_at,
+        )
+    }
+}
@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+use business::domain::errors::RepositoryError;
+use business::domain::location_rule::model::LocationRuleSet;
+use business::domain::location_rule::repository::LocationRuleRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::{LocationRuleRecord, LocationRuleSetEntity};
+
+pub struct LocationRuleRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl LocationRuleRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LocationRuleRepository for LocationRuleRepositoryPostgres {
+    async fn get(&self, user_id: &UserId) -> Result<Option<LocationRuleSet>, RepositoryError> {
+        let entity = sqlx::query_as::<_, LocationRuleSetEntity>(
+            "SELECT user_id, rules, updated_at FROM location_rules WHERE user_id = $1",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn save(&self, rule_set: &LocationRuleSet) -> Result<(), RepositoryError> {
+        let rules: Vec<LocationRuleRecord> = rule_set.rules.iter().map(|r| r.into()).collect();
+
+        sqlx::query(
+            r#"INSERT INTO location_rules (user_id, rules, updated_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id) DO UPDATE SET
+                rules = EXCLUDED.rules,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(rule_set.user_id.as_str())
+        .bind(Json(rules))
+        .bind(rule_set.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
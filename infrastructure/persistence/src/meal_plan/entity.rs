@@ -0,0 +1,32 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::meal_plan::model::PlannedMeal;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct PlannedMealEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub suggestion_id: String,
+    pub title: String,
+    pub planned_for: NaiveDate,
+    /// Products still reserved, aggregated from `product_reservations`
+    pub product_ids: Vec<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PlannedMealEntity {
+    pub fn into_domain(self) -> PlannedMeal {
+        PlannedMeal::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.suggestion_id,
+            self.title,
+            self.planned_for,
+            self.product_ids,
+            self.created_at,
+        )
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::meal_plan::model::PlannedMeal;
+use business::domain::meal_plan::repository::MealPlanRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::PlannedMealEntity;
+use crate::db::write_error;
+
+pub struct MealPlanRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl MealPlanRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl MealPlanRepository for MealPlanRepositoryPostgres {
+    async fn find_by_suggestion(
+        &self,
+        suggestion_id: &str,
+        user_id: &UserId,
+    ) -> Result<Option<PlannedMeal>, RepositoryError> {
+        let entity = sqlx::query_as::<_, PlannedMealEntity>(
+            r#"SELECT m.id, m.user_id, m.suggestion_id, m.title, m.planned_for, m.created_at,
+                ARRAY(SELECT r.product_id FROM product_reservations r WHERE r.planned_meal_id = m.id) AS product_ids
+            FROM planned_meals m WHERE m.suggestion_id = $1 AND m.user_id = $2"#,
+        )
+        .bind(suggestion_id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn get_reserved_product_ids(
+        &self,
+        user_id: &UserId,
+    ) -> Result<HashSet<Uuid>, RepositoryError> {
+        let ids: Vec<Uuid> = sqlx::query_scalar(
+            "SELECT DISTINCT product_id FROM product_reservations WHERE user_id = $1 AND expires_at > NOW()",
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(ids.into_iter().collect())
+    }
+
+    async fn insert(&self, meal: &PlannedMeal) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        sqlx::query(
+            r#"INSERT INTO planned_meals (id, user_id, suggestion_id, title, planned_for, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(meal.id)
+        .bind(meal.user_id.as_str())
+        .bind(&meal.suggestion_id)
+        .bind(&meal.title)
+        .bind(meal.planned_for)
+        .bind(meal.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(write_error)?;
+
+        for reservation in meal.reservations() {
+            sqlx::query(
+                r#"INSERT INTO product_reservations (planned_meal_id, product_id, user_id, planned_for, expires_at)
+                VALUES ($1, $2, $3, $4, $5)"#,
+            )
+            .bind(reservation.planned_meal_id)
+            .bind(reservation.product_id)
+            .bind(reservation.user_id.as_str())
+            .bind(reservation.planned_for)
+            .bind(reservation.expires_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(write_error)?;
+        }
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_expired_reservations(
+        &self,
+        now: DateTime<Utc>,
+    ) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM product_reservations WHERE expires_at <= $1")
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,8 @@
+CREATE TABLE suggestion_batches (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    suggestions JSONB NOT NULL,
+    generated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_suggestion_batches_user_generated_at ON suggestion_batches(user_id, generated_at DESC);
@@ -0,0 +1,14 @@
+CREATE TABLE cooking_sessions (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    suggestion_id VARCHAR(255) NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    ingredients JSONB NOT NULL,
+    steps JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'in_progress',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_cooking_sessions_user_suggestion ON cooking_sessions(user_id, suggestion_id);
@@ -0,0 +1,10 @@
+CREATE TABLE share_links (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    include_expiring_products BOOLEAN NOT NULL DEFAULT FALSE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_share_links_user_id ON share_links(user_id);
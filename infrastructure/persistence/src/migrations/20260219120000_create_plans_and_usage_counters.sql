@@ -0,0 +1,14 @@
+-- Plan tier per user; users without a row are on the free plan
+CREATE TABLE user_plans (
+    user_id VARCHAR(128) PRIMARY KEY,
+    tier VARCHAR(32) NOT NULL DEFAULT 'free',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Daily AI call counters (UTC days)
+CREATE TABLE ai_usage_counters (
+    user_id VARCHAR(128) NOT NULL,
+    day DATE NOT NULL,
+    calls INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, day)
+);
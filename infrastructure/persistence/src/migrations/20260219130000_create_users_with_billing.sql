@@ -0,0 +1,5 @@
+-- Plans now live on a users table that also links the Stripe customer
+ALTER TABLE user_plans RENAME TO users;
+ALTER TABLE users RENAME COLUMN tier TO plan;
+ALTER TABLE users ADD COLUMN stripe_customer_id VARCHAR(255);
+ALTER TABLE users ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
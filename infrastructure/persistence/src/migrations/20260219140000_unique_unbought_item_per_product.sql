@@ -0,0 +1,14 @@
+-- Keep the oldest unbought item when a product was added more than once
+DELETE FROM shopping_items a
+USING shopping_items b
+WHERE a.product_id IS NOT NULL
+  AND a.is_bought = FALSE
+  AND b.is_bought = FALSE
+  AND a.user_id = b.user_id
+  AND a.product_id = b.product_id
+  AND (a.created_at, a.id) > (b.created_at, b.id);
+
+-- At most one unbought item per product, enforced under concurrent inserts
+CREATE UNIQUE INDEX idx_shopping_items_unbought_product
+    ON shopping_items (user_id, product_id)
+    WHERE product_id IS NOT NULL AND is_bought = FALSE;
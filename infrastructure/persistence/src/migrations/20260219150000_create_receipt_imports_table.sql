@@ -0,0 +1,11 @@
+CREATE TABLE receipt_imports (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    review JSONB,
+    error VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_receipt_imports_user_id ON receipt_imports(user_id);
@@ -0,0 +1,16 @@
+CREATE TABLE store_profiles (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    aisles JSONB NOT NULL DEFAULT '[]',
+    is_active BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_store_profiles_user_id ON store_profiles(user_id);
+
+-- At most one active store per user
+CREATE UNIQUE INDEX idx_store_profiles_one_active
+    ON store_profiles(user_id)
+    WHERE is_active;
@@ -0,0 +1,5 @@
+CREATE TABLE location_rules (
+    user_id VARCHAR(128) PRIMARY KEY,
+    rules JSONB NOT NULL DEFAULT '[]',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
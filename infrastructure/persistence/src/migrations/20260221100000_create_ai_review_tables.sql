@@ -0,0 +1,18 @@
+CREATE TABLE ai_review_settings (
+    user_id VARCHAR(128) PRIMARY KEY,
+    mode VARCHAR(20) NOT NULL DEFAULT 'auto',
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE pending_ai_changes (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    field VARCHAR(50) NOT NULL,
+    value JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- A newer suggestion for the same field replaces the pending one
+    UNIQUE (product_id, field)
+);
+
+CREATE INDEX idx_pending_ai_changes_user_id ON pending_ai_changes(user_id);
@@ -0,0 +1,5 @@
+-- Keyset pagination walks (created_at, id) newest first within a user
+CREATE INDEX idx_products_user_created_at_id ON products(user_id, created_at DESC, id DESC);
+
+DROP INDEX idx_suggestion_batches_user_generated_at;
+CREATE INDEX idx_suggestion_batches_user_generated_at_id ON suggestion_batches(user_id, generated_at DESC, id DESC);
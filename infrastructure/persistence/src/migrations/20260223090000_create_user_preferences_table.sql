@@ -0,0 +1,5 @@
+CREATE TABLE user_preferences (
+    user_id VARCHAR(128) PRIMARY KEY,
+    expiring_soon_days SMALLINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
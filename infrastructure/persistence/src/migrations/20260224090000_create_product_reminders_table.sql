@@ -0,0 +1,11 @@
+CREATE TABLE product_reminders (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    note TEXT NOT NULL,
+    remind_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_product_reminders_product_id ON product_reminders(product_id, remind_at);
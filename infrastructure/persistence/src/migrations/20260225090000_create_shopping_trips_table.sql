@@ -0,0 +1,15 @@
+CREATE TABLE shopping_trips (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    items JSONB NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'in_progress',
+    items_planned INTEGER NOT NULL,
+    items_bought INTEGER NOT NULL DEFAULT 0,
+    total_spend_cents BIGINT NOT NULL DEFAULT 0,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMPTZ
+);
+
+-- A user has at most one trip in progress
+CREATE UNIQUE INDEX idx_shopping_trips_user_in_progress ON shopping_trips(user_id) WHERE status = 'in_progress';
+CREATE INDEX idx_shopping_trips_user_started_at ON shopping_trips(user_id, started_at);
@@ -0,0 +1,4 @@
+ALTER TABLE products ADD COLUMN unwanted BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Give-away candidates are looked up among the few flagged products
+CREATE INDEX idx_products_user_unwanted ON products(user_id) WHERE unwanted;
@@ -0,0 +1,2 @@
+-- What the label date means: 'use_by', 'best_before' or 'none' (unknown)
+ALTER TABLE products ADD COLUMN expiry_type VARCHAR(20) NOT NULL DEFAULT 'none';
@@ -0,0 +1,18 @@
+-- Open Food Facts data per barcode, shared by every product scanned with it.
+-- Nutrition values are per 100 g (or 100 ml).
+CREATE TABLE product_enrichment (
+    barcode VARCHAR(64) PRIMARY KEY,
+    nutriscore VARCHAR(1),
+    ecoscore VARCHAR(1),
+    energy_kcal_100g DOUBLE PRECISION,
+    fat_100g DOUBLE PRECISION,
+    saturated_fat_100g DOUBLE PRECISION,
+    carbohydrates_100g DOUBLE PRECISION,
+    sugars_100g DOUBLE PRECISION,
+    fiber_100g DOUBLE PRECISION,
+    proteins_100g DOUBLE PRECISION,
+    salt_100g DOUBLE PRECISION,
+    fetched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE products ADD COLUMN barcode VARCHAR(64);
@@ -0,0 +1,5 @@
+-- Allergens declared by Open Food Facts for a barcode, and the allergies a
+-- user wants to be warned about. Both hold snake_case allergen names.
+ALTER TABLE product_enrichment ADD COLUMN allergens TEXT[] NOT NULL DEFAULT '{}';
+
+ALTER TABLE user_preferences ADD COLUMN allergies TEXT[] NOT NULL DEFAULT '{}';
@@ -0,0 +1,19 @@
+-- One row per user and day, written by the nightly snapshot job.
+-- Counts cover active products; wasted_* sum everything thrown away so far.
+CREATE TABLE inventory_snapshots (
+    user_id VARCHAR(128) NOT NULL,
+    snapshot_date DATE NOT NULL,
+    new_count INTEGER NOT NULL,
+    opened_count INTEGER NOT NULL,
+    almost_empty_count INTEGER NOT NULL,
+    fridge_count INTEGER NOT NULL,
+    pantry_count INTEGER NOT NULL,
+    freezer_count INTEGER NOT NULL,
+    unassigned_count INTEGER NOT NULL,
+    urgent_count INTEGER NOT NULL,
+    wasted_products INTEGER NOT NULL,
+    wasted_priced_products INTEGER NOT NULL,
+    wasted_value_cents BIGINT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, snapshot_date)
+);
@@ -0,0 +1,14 @@
+CREATE TABLE jobs (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    kind VARCHAR(40) NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    total INTEGER NOT NULL,
+    processed INTEGER NOT NULL DEFAULT 0,
+    failed INTEGER NOT NULL DEFAULT 0,
+    error VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_jobs_user_kind_status ON jobs(user_id, kind, status);
@@ -0,0 +1,3 @@
+-- Storage key of the receipt photo, kept so the user can look at it later.
+-- NULL for imports started before photos were stored or whose upload failed.
+ALTER TABLE receipt_imports ADD COLUMN image_key TEXT;
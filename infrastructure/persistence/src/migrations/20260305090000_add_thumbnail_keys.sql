@@ -0,0 +1,4 @@
+-- Storage keys of photo thumbnails, made in the background after a photo is
+-- stored. NULL until then, or when the photo could not be shrunk.
+ALTER TABLE products ADD COLUMN thumbnail_key TEXT;
+ALTER TABLE receipt_imports ADD COLUMN thumbnail_key TEXT;
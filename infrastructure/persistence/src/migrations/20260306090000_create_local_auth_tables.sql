@@ -0,0 +1,32 @@
+-- Sign-in without Firebase: accounts known by email, their passkeys and the
+-- single-use challenges of magic links and passkey ceremonies.
+CREATE TABLE local_accounts (
+    user_id VARCHAR(128) PRIMARY KEY,
+    email VARCHAR(254) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE login_challenges (
+    challenge_hash CHAR(64) PRIMARY KEY,
+    purpose VARCHAR(32) NOT NULL,
+    email VARCHAR(254),
+    user_id VARCHAR(128),
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_login_challenges_expires_at ON login_challenges(expires_at);
+
+CREATE TABLE passkeys (
+    credential_id VARCHAR(1400) PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    public_key BYTEA NOT NULL,
+    sign_count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_passkeys_user_id ON passkeys(user_id);
+
+COMMENT ON COLUMN login_challenges.challenge_hash IS 'SHA-256 hex of the challenge; the plain value is never stored';
+COMMENT ON COLUMN passkeys.public_key IS 'Uncompressed P-256 point (0x04 || x || y)';
@@ -0,0 +1,15 @@
+CREATE TABLE challenges (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    kind VARCHAR(32) NOT NULL,
+    target INTEGER NOT NULL,
+    progress INTEGER NOT NULL DEFAULT 0,
+    starts_at TIMESTAMPTZ NOT NULL,
+    ends_at TIMESTAMPTZ NOT NULL,
+    completed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, kind, starts_at)
+);
+
+CREATE INDEX idx_challenges_user_history ON challenges(user_id, starts_at DESC, id DESC);
@@ -0,0 +1,18 @@
+-- Weeks closed by the nightly streak job, one row per user and week (Monday, UTC).
+CREATE TABLE weekly_waste (
+    user_id VARCHAR(128) NOT NULL,
+    week_start DATE NOT NULL,
+    used_count INTEGER NOT NULL,
+    thrown_away_count INTEGER NOT NULL,
+    given_away_count INTEGER NOT NULL,
+    PRIMARY KEY (user_id, week_start)
+);
+
+-- Zero-waste weeks in a row, counted up to last_week.
+CREATE TABLE waste_streaks (
+    user_id VARCHAR(128) PRIMARY KEY,
+    current_weeks INTEGER NOT NULL,
+    longest_weeks INTEGER NOT NULL,
+    last_week DATE NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
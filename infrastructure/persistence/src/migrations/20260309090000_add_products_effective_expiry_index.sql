@@ -0,0 +1,4 @@
+-- Serves expiry range lookups such as the monthly calendar
+CREATE INDEX idx_products_user_effective_expiry
+    ON products(user_id, (COALESCE(expiry_date, estimated_expiry_date)))
+    WHERE status != 'finished';
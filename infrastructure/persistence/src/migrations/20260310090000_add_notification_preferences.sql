@@ -0,0 +1,11 @@
+-- When notifications reach the user. Times are local to the user, who is
+-- utc_offset_minutes east of UTC. Quiet hours are off when either end is NULL.
+ALTER TABLE user_preferences
+    ADD COLUMN utc_offset_minutes SMALLINT NOT NULL DEFAULT 0,
+    ADD COLUMN digest_time TIME NOT NULL DEFAULT '18:00',
+    ADD COLUMN quiet_hours_start TIME DEFAULT '22:00',
+    ADD COLUMN quiet_hours_end TIME DEFAULT '08:00',
+    ADD COLUMN notify_expiry_alerts BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_shopping_reminders BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_suggestions BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_achievements BOOLEAN NOT NULL DEFAULT TRUE;
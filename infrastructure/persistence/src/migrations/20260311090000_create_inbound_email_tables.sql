@@ -0,0 +1,19 @@
+-- Private addresses users email shopping lists to, one per user. The token
+-- is the local part of the address.
+CREATE TABLE inbound_addresses (
+    user_id VARCHAR(128) PRIMARY KEY,
+    token VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Emails accepted per user, for the daily allowance. The provider's webhook
+-- token is unique per call, so retries and replays are recorded only once.
+CREATE TABLE inbound_emails (
+    id BIGSERIAL PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    webhook_token VARCHAR(255) NOT NULL UNIQUE,
+    items INTEGER NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_inbound_emails_user_received ON inbound_emails (user_id, received_at);
@@ -0,0 +1,7 @@
+-- Long-lived read-only tokens for home screen widgets, one per user. Only the
+-- SHA-256 hash of the token is stored.
+CREATE TABLE widget_tokens (
+    user_id VARCHAR(128) PRIMARY KEY,
+    token_hash VARCHAR(64) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
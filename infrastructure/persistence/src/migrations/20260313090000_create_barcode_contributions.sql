@@ -0,0 +1,11 @@
+-- Names users gave to barcodes Open Food Facts doesn't know, one per user
+-- and barcode. They answer that user's scans whatever happens upstream.
+CREATE TABLE barcode_contributions (
+    user_id VARCHAR(128) NOT NULL,
+    barcode VARCHAR(14) NOT NULL,
+    name VARCHAR(120) NOT NULL,
+    upstream_status VARCHAR(16) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, barcode)
+);
@@ -0,0 +1,17 @@
+-- Periods users are away from home: expiry alerts are paused meanwhile and
+-- the return report covers the products that expired in between.
+CREATE TABLE vacations (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    until TIMESTAMPTZ,
+    ended_at TIMESTAMPTZ
+);
+
+-- A user has at most one vacation going on
+CREATE UNIQUE INDEX idx_vacations_user_active ON vacations(user_id) WHERE ended_at IS NULL;
+CREATE INDEX idx_vacations_user_started_at ON vacations(user_id, started_at);
+CREATE INDEX idx_vacations_due ON vacations(until) WHERE ended_at IS NULL;
+
+-- Snapshots taken while the user was away, so trends can tell the dip apart
+ALTER TABLE inventory_snapshots ADD COLUMN on_vacation BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+-- Monthly grocery budget per user, compared against the prices recorded on
+-- shopping trips.
+CREATE TABLE budgets (
+    user_id VARCHAR(128) PRIMARY KEY,
+    monthly_cents BIGINT NOT NULL CHECK (monthly_cents > 0),
+    alerts_enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
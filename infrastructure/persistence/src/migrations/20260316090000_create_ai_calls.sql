@@ -0,0 +1,15 @@
+-- AI calls made under a prompt or model experiment, with the variant that
+-- served them and whether the user accepted the result.
+CREATE TABLE ai_calls (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    feature VARCHAR(40) NOT NULL,
+    variant VARCHAR(64) NOT NULL,
+    -- What the call produced that a later signal points to, e.g. suggestion IDs
+    subject_ids TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    accepted_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_ai_calls_created_at ON ai_calls(created_at);
+CREATE INDEX idx_ai_calls_user_feature ON ai_calls(user_id, feature);
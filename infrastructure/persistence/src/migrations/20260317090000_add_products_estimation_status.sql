@@ -0,0 +1,3 @@
+-- Where the expiry estimation of a product stands: 'done', 'pending' while a
+-- retry job waits for the estimator to come back, or 'failed'.
+ALTER TABLE products ADD COLUMN estimation_status VARCHAR(20) NOT NULL DEFAULT 'done';
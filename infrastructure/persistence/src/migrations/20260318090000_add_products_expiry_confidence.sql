@@ -0,0 +1,4 @@
+-- How sure the estimator was of the estimated expiry date: 'high', 'medium',
+-- 'low' or 'none'. NULL when the estimate came from the client or a reviewed
+-- change.
+ALTER TABLE products ADD COLUMN expiry_confidence VARCHAR(10);
@@ -0,0 +1,4 @@
+-- Media type of the photo or voice note attached to the item, NULL when it
+-- has none. The attachment itself lives in blob storage under a key derived
+-- from the item.
+ALTER TABLE shopping_items ADD COLUMN attachment_content_type VARCHAR(50);
@@ -0,0 +1,11 @@
+-- Suggestions the user plans to cook, with the pantry products set aside for
+-- each so that planning several meals doesn't count on the same product twice.
+CREATE TABLE planned_meals (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    suggestion_id VARCHAR(255) NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    product_ids UUID[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, suggestion_id)
+);
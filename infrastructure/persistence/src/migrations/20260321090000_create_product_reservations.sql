@@ -0,0 +1,25 @@
+-- Planned meals are planned for a day, and the products set aside for them
+-- move to their own table so each reservation can expire once the day has
+-- passed. Deleting a product releases its reservations.
+ALTER TABLE planned_meals ADD COLUMN planned_for DATE NOT NULL DEFAULT CURRENT_DATE;
+UPDATE planned_meals SET planned_for = created_at::date;
+
+CREATE TABLE product_reservations (
+    planned_meal_id UUID NOT NULL REFERENCES planned_meals(id) ON DELETE CASCADE,
+    product_id UUID NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    user_id VARCHAR(128) NOT NULL,
+    planned_for DATE NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (planned_meal_id, product_id)
+);
+
+CREATE INDEX idx_product_reservations_user_expires ON product_reservations (user_id, expires_at);
+CREATE INDEX idx_product_reservations_expires ON product_reservations (expires_at);
+
+INSERT INTO product_reservations (planned_meal_id, product_id, user_id, planned_for, expires_at)
+SELECT pm.id, p.id, pm.user_id, pm.planned_for, (pm.planned_for + 2)::timestamp AT TIME ZONE 'UTC'
+FROM planned_meals pm
+CROSS JOIN LATERAL UNNEST(pm.product_ids) AS reserved(product_id)
+JOIN products p ON p.id = reserved.product_id;
+
+ALTER TABLE planned_meals DROP COLUMN product_ids;
@@ -0,0 +1,16 @@
+-- App installs per user: platform, app version and push token, refreshed
+-- on every launch. Installs not seen for a while are pruned.
+CREATE TABLE devices (
+    user_id VARCHAR(128) NOT NULL,
+    installation_id VARCHAR(128) NOT NULL,
+    platform VARCHAR(16) NOT NULL,
+    app_version VARCHAR(32) NOT NULL,
+    push_token TEXT,
+    registered_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, installation_id)
+);
+
+-- A push token reaches one install only
+CREATE UNIQUE INDEX idx_devices_push_token ON devices(push_token) WHERE push_token IS NOT NULL;
+CREATE INDEX idx_devices_last_seen_at ON devices(last_seen_at);
@@ -0,0 +1,13 @@
+-- Notifications sent to each user, kept until acknowledged
+CREATE TABLE notifications (
+    id UUID PRIMARY KEY,
+    user_id VARCHAR(128) NOT NULL,
+    category VARCHAR(32) NOT NULL,
+    title TEXT NOT NULL,
+    body TEXT NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    read_at TIMESTAMPTZ
+);
+
+-- Unread counts for badges and "all before" acknowledgements
+CREATE INDEX idx_notifications_unread ON notifications(user_id, sent_at) WHERE read_at IS NULL;
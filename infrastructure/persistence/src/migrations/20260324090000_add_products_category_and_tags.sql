@@ -0,0 +1,8 @@
+-- Kind of food ('dairy', 'produce', 'dry_goods', ...) and free-form labels,
+-- stored trimmed and lowercased. Both narrow the product list.
+ALTER TABLE products ADD COLUMN category VARCHAR(20);
+ALTER TABLE products ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_products_user_category ON products(user_id, category)
+    WHERE category IS NOT NULL;
+CREATE INDEX idx_products_tags ON products USING GIN (tags);
@@ -0,0 +1,2 @@
+-- Retention job deleting notifications past their age, read or not
+CREATE INDEX idx_notifications_sent_at ON notifications(sent_at);
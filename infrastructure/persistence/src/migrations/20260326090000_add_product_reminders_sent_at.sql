@@ -0,0 +1,4 @@
+ALTER TABLE product_reminders ADD COLUMN sent_at TIMESTAMPTZ;
+
+-- Delivery job looking for reminders whose time has come
+CREATE INDEX idx_product_reminders_due ON product_reminders(remind_at) WHERE sent_at IS NULL;
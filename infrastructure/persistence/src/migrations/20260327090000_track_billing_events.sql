@@ -0,0 +1,8 @@
+-- Stripe delivers webhook events at least once and in no particular order
+CREATE TABLE billing_events (
+    id VARCHAR(255) PRIMARY KEY,
+    processed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Creation time of the event behind the current plan, so older ones are skipped
+ALTER TABLE users ADD COLUMN plan_changed_at TIMESTAMPTZ;
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::notification::model::{Acknowledgement, Notification};
+use business::domain::notification::repository::NotificationInboxRepository;
+use business::domain::shared::value_objects::UserId;
+
+use crate::db::write_error;
+
+pub struct NotificationInboxRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl NotificationInboxRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl NotificationInboxRepository for NotificationInboxRepositoryPostgres {
+    async fn record(
+        &self,
+        notification: &Notification,
+        sent_at: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO notifications (id, user_id, category, title, body, sent_at)
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(notification.id)
+        .bind(notification.user_id.as_str())
+        .bind(notification.category.to_string())
+        .bind(&notification.title)
+        .bind(&notification.body)
+        .bind(sent_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn acknowledge(
+        &self,
+        user_id: &UserId,
+        acknowledgement: &Acknowledgement,
+        read_at: DateTime<Utc>,
+    ) -> Result<u64, RepositoryError> {
+        let query = match acknowledgement {
+            Acknowledgement::Ids(ids) => sqlx::query(
+                r#"UPDATE notifications SET read_at = $2
+                WHERE user_id = $1 AND read_at IS NULL AND id = ANY($3)"#,
+            )
+            .bind(user_id.as_str())
+            .bind(read_at)
+            .bind(ids),
+            Acknowledgement::SentBefore(before) => sqlx::query(
+                r#"UPDATE notifications SET read_at = $2
+                WHERE user_id = $1 AND read_at IS NULL AND sent_at < $3"#,
+            )
+            .bind(user_id.as_str())
+            .bind(read_at)
+            .bind(before),
+        };
+
+        let result = query
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn delete_sent_before(&self, before: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM notifications WHERE sent_at < $1")
+            .bind(before)
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(result.rows_affected())
+    }
+}
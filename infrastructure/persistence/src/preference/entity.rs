@@ -0,0 +1,37 @@
+use chrono::{FixedOffset, NaiveTime};
+use sqlx::FromRow;
+
+use business::domain::notification::model::{CategoryToggles, NotificationPreferences, QuietHours};
+
+#[derive(Debug, FromRow)]
+pub struct NotificationPreferencesEntity {
+    pub utc_offset_minutes: i16,
+    pub digest_time: NaiveTime,
+    pub quiet_hours_start: Option<NaiveTime>,
+    pub quiet_hours_end: Option<NaiveTime>,
+    pub notify_expiry_alerts: bool,
+    pub notify_shopping_reminders: bool,
+    pub notify_suggestions: bool,
+    pub notify_achievements: bool,
+}
+
+impl NotificationPreferencesEntity {
+    pub fn into_domain(self) -> NotificationPreferences {
+        let defaults = NotificationPreferences::default();
+        NotificationPreferences {
+            utc_offset: FixedOffset::east_opt(i32::from(self.utc_offset_minutes) * 60)
+                .unwrap_or(defaults.utc_offset),
+            digest_at: self.digest_time,
+            quiet_hours: match (self.quiet_hours_start, self.quiet_hours_end) {
+                (Some(start), Some(end)) => QuietHours::new(start, end).ok(),
+                _ => None,
+            },
+            categories: CategoryToggles {
+                expiry_alerts: self.notify_expiry_alerts,
+                shopping_reminders: self.notify_shopping_reminders,
+                suggestions: self.notify_suggestions,
+                achievements: self.notify_achievements,
+            },
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::notification::model::NotificationPreferences;
+use business::domain::notification::repository::NotificationPreferenceRepository;
+use business::domain::preference::model::UserPreferences;
+use business::domain::preference::repository::PreferenceRepository;
+use business::domain::product::enrichment::Allergen;
+use business::domain::product::urgency::ExpiringSoonWindow;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::NotificationPreferencesEntity;
+
+pub struct PreferenceRepositoryPostgres {
+    pool: PgPool,
+    /// Served to users who never saved their preferences
+    defaults: UserPreferences,
+}
+
+impl PreferenceRepositoryPostgres {
+    pub fn new(pool: PgPool, defaults: UserPreferences) -> Self {
+        Self { pool, defaults }
+    }
+}
+
+#[async_trait]
+impl PreferenceRepository for PreferenceRepositoryPostgres {
+    async fn get(&self, user_id: &UserId) -> Result<UserPreferences, RepositoryError> {
+        let row: Option<(i16, Vec<String>)> = sqlx::query_as(
+            "SELECT expiring_soon_days, allergies FROM user_preferences WHERE user_id = $1",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        let Some((days, allergies)) = row else {
+            return Ok(self.defaults.clone());
+        };
+
+        let expiring_soon = u8::try_from(days)
+            .ok()
+            .and_then(ExpiringSoonWindow::new)
+            .unwrap_or(self.defaults.expiring_soon);
+        let allergies = allergies
+            .iter()
+            .filter_map(|a| a.parse::<Allergen>().ok())
+            .collect();
+
+        Ok(UserPreferences {
+            expiring_soon,
+            allergies,
+        })
+    }
+
+    async fn save(
+        &self,
+        user_id: &UserId,
+        preferences: &UserPreferences,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO user_preferences (user_id, expiring_soon_days, allergies, updated_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                expiring_soon_days = EXCLUDED.expiring_soon_days,
+                allergies = EXCLUDED.allergies,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(i16::from(preferences.expiring_soon.days()))
+        .bind(
+            preferences
+                .allergies
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
+
+/// Notification settings share the user's preferences row; saving them
+/// first creates it with the default preferences when missing.
+#[async_trait]
+impl NotificationPreferenceRepository for PreferenceRepositoryPostgres {
+    async fn get(&self, user_id: &UserId) -> Result<NotificationPreferences, RepositoryError> {
+        let entity: Option<NotificationPreferencesEntity> = sqlx::query_as(
+            r#"SELECT utc_offset_minutes, digest_time, quiet_hours_start, quiet_hours_end,
+                notify_expiry_alerts, notify_shopping_reminders, notify_suggestions,
+                notify_achievements
+            FROM user_preferences WHERE user_id = $1"#,
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity
+            .map(NotificationPreferencesEntity::into_domain)
+            .unwrap_or_default())
+    }
+
+    async fn save(
+        &self,
+        user_id: &UserId,
+        preferences: &NotificationPreferences,
+    ) -> Result<(), RepositoryError> {
+        let quiet_hours = preferences.quiet_hours;
+        sqlx::query(
+            r#"INSERT INTO user_preferences (user_id, expiring_soon_days, utc_offset_minutes,
+                digest_time, quiet_hours_start, quiet_hours_end, notify_expiry_alerts,
+                notify_shopping_reminders, notify_suggestions, notify_achievements, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                utc_offset_minutes = EXCLUDED.utc_offset_minutes,
+                digest_time = EXCLUDED.digest_time,
+                quiet_hours_start = EXCLUDED.quiet_hours_start,
+                quiet_hours_end = EXCLUDED.quiet_hours_end,
+                notify_expiry_alerts = EXCLUDED.notify_expiry_alerts,
+                notify_shopping_reminders = EXCLUDED.notify_shopping_reminders,
+                notify_suggestions = EXCLUDED.notify_suggestions,
+                notify_achievements = EXCLUDED.notify_achievements,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(i16::from(self.defaults.expiring_soon.days()))
+        .bind(preferences.utc_offset_minutes() as i16)
+        .bind(preferences.digest_at)
+        .bind(quiet_hours.map(|q| q.start))
+        .bind(quiet_hours.map(|q| q.end))
+        .bind(preferences.categories.expiry_alerts)
+        .bind(preferences.categories.shopping_reminders)
+        .bind(preferences.categories.suggestions)
+        .bind(preferences.categories.achievements)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
@@ -2,8 +2,14 @@ use chrono::{DateTime, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use business::domain::product::enrichment::{
+    Allergen, NutritionFacts, ProductEnrichment, ScoreGrade,
+};
 use business::domain::product::model::Product;
-use business::domain::product::value_objects::{ProductLocation, ProductOutcome, ProductStatus};
+use business::domain::product::services::Confidence;
+use business::domain::product::value_objects::{
+    EstimationStatus, ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
 use business::domain::shared::value_objects::UserId;
 
 #[derive(Debug, FromRow)]
@@ -16,14 +22,19 @@ pub struct ProductEntity {
     pub quantity: Option<String>,
     pub expiry_date: Option<DateTime<Utc>>,
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    pub expiry_confidence: Option<String>,
+    pub expiry_type: String,
     pub outcome: Option<String>,
+    pub estimation_status: String,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl ProductEntity {
     pub fn into_domain(self) -> Product {
-        Product::from_repository(
+        let mut product = Product::from_repository(
             self.id,
             UserId::new(&self.user_id),
             self.name,
@@ -35,9 +46,68 @@ impl ProductEntity {
             self.quantity,
             self.expiry_date,
             self.estimated_expiry_date,
+            self.expiry_type.parse::<ExpiryType>().unwrap_or_default(),
             self.outcome.and_then(|o| o.parse::<ProductOutcome>().ok()),
             self.created_at,
             self.updated_at,
-        )
+        );
+        product.expiry_confidence = self
+            .expiry_confidence
+            .and_then(|c| c.parse::<Confidence>().ok());
+        product.estimation_status = self
+            .estimation_status
+            .parse::<EstimationStatus>()
+            .unwrap_or_default();
+        product.category = self
+            .category
+            .and_then(|c| c.parse::<ProductCategory>().ok());
+        product.tags = self.tags;
+        product
+    }
+}
+
+/// `product_enrichment` row, joined to the product it was requested for.
+#[derive(Debug, FromRow)]
+pub struct ProductEnrichmentEntity {
+    pub product_id: Uuid,
+    pub barcode: String,
+    pub nutriscore: Option<String>,
+    pub ecoscore: Option<String>,
+    pub energy_kcal_100g: Option<f64>,
+    pub fat_100g: Option<f64>,
+    pub saturated_fat_100g: Option<f64>,
+    pub carbohydrates_100g: Option<f64>,
+    pub sugars_100g: Option<f64>,
+    pub fiber_100g: Option<f64>,
+    pub proteins_100g: Option<f64>,
+    pub salt_100g: Option<f64>,
+    pub allergens: Vec<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl ProductEnrichmentEntity {
+    pub fn into_domain(self) -> (Uuid, ProductEnrichment) {
+        let enrichment = ProductEnrichment {
+            barcode: self.barcode,
+            nutriscore: self.nutriscore.and_then(|g| g.parse::<ScoreGrade>().ok()),
+            ecoscore: self.ecoscore.and_then(|g| g.parse::<ScoreGrade>().ok()),
+            nutrition: NutritionFacts {
+                energy_kcal: self.energy_kcal_100g,
+                fat: self.fat_100g,
+                saturated_fat: self.saturated_fat_100g,
+                carbohydrates: self.carbohydrates_100g,
+                sugars: self.sugars_100g,
+                fiber: self.fiber_100g,
+                proteins: self.proteins_100g,
+                salt: self.salt_100g,
+            },
+            allergens: self
+                .allergens
+                .iter()
+                .filter_map(|a| a.parse::<Allergen>().ok())
+                .collect(),
+            fetched_at: self.fetched_at,
+        };
+        (self.product_id, enrichment)
     }
 }
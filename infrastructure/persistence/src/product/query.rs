@@ -0,0 +1,345 @@
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+
+use business::domain::product::query::{ProductQuery, ProductScope, ProductSort};
+use business::domain::product::services::Confidence;
+use business::domain::product::urgency::{
+    ExpiringSoonWindow, UrgencyLevel, end_of_today, urgent_until,
+};
+use business::domain::product::value_objects::ExpiryType;
+
+use crate::db::escape_like;
+
+const SELECT_PRODUCTS: &str = "SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, expiry_confidence, expiry_type, outcome, estimation_status, category, tags, created_at, updated_at FROM products";
+
+const COUNT_PRODUCTS: &str = "SELECT COUNT(*) FROM products";
+
+/// Real expiry date, falling back to the AI estimate.
+const EFFECTIVE_EXPIRY: &str = "COALESCE(expiry_date, estimated_expiry_date)";
+
+/// Builds the SELECT for a product query. Every value is bound, never inlined.
+pub(crate) fn build_select(query: &ProductQuery) -> QueryBuilder<'static, Postgres> {
+    let mut builder = QueryBuilder::new(SELECT_PRODUCTS);
+    push_filters(&mut builder, query);
+
+    if let Some(cursor) = query.after {
+        builder.push(" AND (created_at, id) < (");
+        builder.push_bind(cursor.created_at);
+        builder.push(", ");
+        builder.push_bind(cursor.id);
+        builder.push(")");
+    }
+
+    // The id tie-break keeps the order total, so pages never overlap
+    match query.sort {
+        ProductSort::CreatedAtDesc => {
+            builder.push(" ORDER BY created_at DESC, id DESC");
+        }
+        ProductSort::ExpiryAsc => {
+            builder.push(format!(
+                " ORDER BY {EFFECTIVE_EXPIRY} ASC NULLS LAST, created_at DESC, id DESC"
+            ));
+        }
+        ProductSort::NameAsc => {
+            builder.push(" ORDER BY LOWER(name) ASC, created_at DESC, id DESC");
+        }
+        ProductSort::UrgencyDesc => {
+            builder.push(" ORDER BY ");
+            push_urgency_rank(&mut builder, Utc::now(), query.expiring_soon);
+            builder.push(format!(
+                ", {EFFECTIVE_EXPIRY} ASC NULLS LAST, created_at DESC, id DESC"
+            ));
+        }
+    }
+
+    if let Some(page) = query.page {
+        builder.push(" LIMIT ");
+        builder.push_bind(i64::from(page.limit));
+        builder.push(" OFFSET ");
+        builder.push_bind(i64::from(page.offset));
+    }
+
+    builder
+}
+
+/// Builds the COUNT for a product query's filters; sort, page and cursor
+/// are ignored.
+pub(crate) fn build_count(query: &ProductQuery) -> QueryBuilder<'static, Postgres> {
+    let mut builder = QueryBuilder::new(COUNT_PRODUCTS);
+    push_filters(&mut builder, query);
+    builder
+}
+
+/// Pushes the WHERE clause shared by listings and counts.
+fn push_filters(builder: &mut QueryBuilder<'static, Postgres>, query: &ProductQuery) {
+    builder.push(" WHERE user_id = ");
+    builder.push_bind(query.user_id.as_str().to_string());
+
+    match query.scope {
+        ProductScope::All => {}
+        ProductScope::Active => {
+            builder.push(" AND status != 'finished'");
+        }
+        ProductScope::Finished => {
+            builder.push(" AND status = 'finished'");
+        }
+    }
+    if let Some(term) = &query.name_contains {
+        builder.push(" AND name ILIKE ");
+        builder.push_bind(format!("%{}%", escape_like(term)));
+    }
+    if let Some(date) = query.expiring_before {
+        builder.push(format!(" AND {EFFECTIVE_EXPIRY} <= "));
+        builder.push_bind(date);
+    }
+    if let Some((from, until)) = query.expiring_between {
+        builder.push(format!(" AND {EFFECTIVE_EXPIRY} >= "));
+        builder.push_bind(from);
+        builder.push(format!(" AND {EFFECTIVE_EXPIRY} < "));
+        builder.push_bind(until);
+    }
+    if query.unwanted_only {
+        builder.push(" AND unwanted");
+    }
+    if let Some(status) = &query.status {
+        builder.push(" AND status = ");
+        builder.push_bind(status.to_string());
+    }
+    if let Some(location) = &query.location {
+        builder.push(" AND location = ");
+        builder.push_bind(location.to_string());
+    }
+    if let Some(category) = &query.category {
+        builder.push(" AND category = ");
+        builder.push_bind(category.to_string());
+    }
+    if let Some(tag) = &query.tag {
+        builder.push(" AND tags @> ARRAY[");
+        builder.push_bind(tag.clone());
+        builder.push("]");
+    }
+    if let Some(level) = &query.urgency {
+        builder.push(" AND ");
+        push_urgency_rank(builder, Utc::now(), query.expiring_soon);
+        builder.push(format!(" = {}", level.rank()));
+    }
+}
+
+/// Pushes a CASE expression computing [`UrgencyLevel::rank`] from the
+/// effective expiry date, the label's expiry type and the estimate's
+/// confidence, mirroring `get_urgency_level` so the database can
+/// filter, order and page the list.
+fn push_urgency_rank(
+    builder: &mut QueryBuilder<'static, Postgres>,
+    now: DateTime<Utc>,
+    window: ExpiringSoonWindow,
+) {
+    builder.push(format!(
+        "CASE WHEN {EFFECTIVE_EXPIRY} IS NULL THEN {} WHEN {EFFECTIVE_EXPIRY} < ",
+        UrgencyLevel::Ok.rank()
+    ));
+    builder.push_bind(now);
+    builder.push(format!(
+        " THEN CASE WHEN expiry_type = '{}' OR (expiry_date IS NULL AND expiry_confidence = '{}') \
+         THEN {} ELSE {} END WHEN {EFFECTIVE_EXPIRY} < ",
+        ExpiryType::BestBefore,
+        Confidence::Low,
+        UrgencyLevel::QualityDeclining.rank(),
+        UrgencyLevel::WouldntTrust.rank()
+    ));
+    builder.push_bind(end_of_today(now));
+    builder.push(format!(
+        " THEN {} WHEN {EFFECTIVE_EXPIRY} < ",
+        UrgencyLevel::UseToday.rank()
+    ));
+    builder.push_bind(urgent_until(now, window));
+    builder.push(format!(
+        " THEN {} ELSE {} END",
+        UrgencyLevel::UseSoon.rank(),
+        UrgencyLevel::Ok.rank()
+    ));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use business::domain::product::query::Page;
+    use business::domain::product::value_objects::{
+        ProductCategory, ProductLocation, ProductStatus,
+    };
+    use business::domain::shared::pagination::{Cursor, KeysetPage};
+    use business::domain::shared::value_objects::UserId;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn user() -> UserId {
+        UserId::new("test-user-id")
+    }
+
+    fn clauses(query: &ProductQuery) -> String {
+        build_select(query)
+            .sql()
+            .trim_start_matches(SELECT_PRODUCTS)
+            .to_string()
+    }
+
+    #[test]
+    fn should_scope_all_query_to_user_newest_first() {
+        assert_eq!(
+            clauses(&ProductQuery::all(user())),
+            " WHERE user_id = $1 ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_exclude_finished_products_when_active_only() {
+        assert_eq!(
+            clauses(&ProductQuery::active(user())),
+            " WHERE user_id = $1 AND status != 'finished' ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_bind_filters_in_order_when_combined() {
+        let query = ProductQuery::active(user())
+            .name_contains("milk")
+            .expiring_before(Utc::now())
+            .sorted_by(ProductSort::ExpiryAsc)
+            .paged(Page::new(20, 40));
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' AND name ILIKE $2 \
+             AND COALESCE(expiry_date, estimated_expiry_date) <= $3 \
+             ORDER BY COALESCE(expiry_date, estimated_expiry_date) ASC NULLS LAST, created_at DESC, id DESC \
+             LIMIT $4 OFFSET $5"
+        );
+    }
+
+    #[test]
+    fn should_bound_effective_expiry_to_half_open_range() {
+        let query = ProductQuery::active(user()).expiring_between(Utc::now(), Utc::now());
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' \
+             AND COALESCE(expiry_date, estimated_expiry_date) >= $2 \
+             AND COALESCE(expiry_date, estimated_expiry_date) < $3 \
+             ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_keep_only_unwanted_products_when_flag_filter_set() {
+        let query = ProductQuery::active(user()).unwanted_only();
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' AND unwanted ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_bind_status_and_location_filters() {
+        let query = ProductQuery::active(user())
+            .in_status(ProductStatus::Opened)
+            .stored_in(ProductLocation::Fridge);
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' AND status = $2 AND location = $3 \
+             ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_bind_category_and_tag_filters() {
+        let query = ProductQuery::active(user())
+            .in_category(ProductCategory::Dairy)
+            .tagged("desayuno");
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' AND category = $2 AND tags @> ARRAY[$3] \
+             ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_filter_by_urgency_rank_of_requested_level() {
+        let query = ProductQuery::active(user()).with_urgency(UrgencyLevel::UseSoon);
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' AND CASE \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) IS NULL THEN 3 \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $2 \
+             THEN CASE WHEN expiry_type = 'best_before' \
+             OR (expiry_date IS NULL AND expiry_confidence = 'low') THEN 2 ELSE 4 END \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $3 THEN 0 \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $4 THEN 1 ELSE 3 END = 1 \
+             ORDER BY created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_sort_by_name_case_insensitively() {
+        let query = ProductQuery::all(user()).sorted_by(ProductSort::NameAsc);
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 ORDER BY LOWER(name) ASC, created_at DESC, id DESC"
+        );
+    }
+
+    #[test]
+    fn should_sort_by_urgency_rank_then_expiry_with_bound_day_boundaries() {
+        let query = ProductQuery::active(user())
+            .sorted_by(ProductSort::UrgencyDesc)
+            .paged(Page::new(20, 0));
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status != 'finished' ORDER BY CASE \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) IS NULL THEN 3 \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $2 \
+             THEN CASE WHEN expiry_type = 'best_before' \
+             OR (expiry_date IS NULL AND expiry_confidence = 'low') THEN 2 ELSE 4 END \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $3 THEN 0 \
+             WHEN COALESCE(expiry_date, estimated_expiry_date) < $4 THEN 1 ELSE 3 END, \
+             COALESCE(expiry_date, estimated_expiry_date) ASC NULLS LAST, created_at DESC, id DESC \
+             LIMIT $5 OFFSET $6"
+        );
+    }
+
+    #[test]
+    fn should_seek_past_cursor_newest_first_for_history() {
+        let cursor = Cursor::new(Utc::now(), Uuid::new_v4());
+        let query = ProductQuery::finished(user())
+            .sorted_by(ProductSort::NameAsc)
+            .keyset(KeysetPage::new(20, Some(cursor)));
+
+        assert_eq!(
+            clauses(&query),
+            " WHERE user_id = $1 AND status = 'finished' AND (created_at, id) < ($2, $3) \
+             ORDER BY created_at DESC, id DESC LIMIT $4 OFFSET $5"
+        );
+    }
+
+    #[test]
+    fn should_count_with_filters_but_without_order_or_page() {
+        let query = ProductQuery::active(user())
+            .sorted_by(ProductSort::NameAsc)
+            .paged(Page::new(10, 20));
+
+        assert_eq!(
+            build_count(&query).sql(),
+            "SELECT COUNT(*) FROM products WHERE user_id = $1 AND status != 'finished'"
+        );
+    }
+
+    #[test]
+    fn should_escape_like_wildcards_in_search_term() {
+        assert_eq!(escape_like("50%_off\\"), "50\\%\\_off\\\\");
+    }
+}
@@ -1,65 +1,210 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
+use sqlx::postgres::{PgArguments, Postgres};
+use sqlx::query::Query;
 use uuid::Uuid;
 
 use business::domain::errors::RepositoryError;
+use business::domain::product::enrichment::ProductEnrichment;
+use business::domain::product::facets::ProductHistorySummary;
 use business::domain::product::model::Product;
-use business::domain::product::repository::ProductRepository;
+use business::domain::product::query::ProductQuery;
+use business::domain::product::repository::{
+    ProductBatchRepository, ProductEnrichmentRepository, ProductFacetRepository,
+    ProductImageRepository, ProductRepository, UnwantedFlagRepository,
+};
 use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_item::model::ShoppingItem;
+
+use super::entity::{ProductEnrichmentEntity, ProductEntity};
+use super::query::{build_count, build_select};
+use crate::db::{ReadPool, write_error};
+use crate::shopping_item::entity::ShoppingItemEntity;
 
-use super::entity::ProductEntity;
+const INSERT_PRODUCT: &str = r#"INSERT INTO products (id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, expiry_confidence, expiry_type, outcome, estimation_status, category, tags, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)"#;
+
+fn insert_query(product: &Product) -> Query<'_, Postgres, PgArguments> {
+    sqlx::query(INSERT_PRODUCT)
+        .bind(product.id)
+        .bind(product.user_id.as_str())
+        .bind(&product.name)
+        .bind(product.status.to_string())
+        .bind(product.location.as_ref().map(|l| l.to_string()))
+        .bind(&product.quantity)
+        .bind(product.expiry_date)
+        .bind(product.estimated_expiry_date)
+        .bind(product.expiry_confidence.as_ref().map(|c| c.to_string()))
+        .bind(product.expiry_type.to_string())
+        .bind(product.outcome.as_ref().map(|o| o.to_string()))
+        .bind(product.estimation_status.to_string())
+        .bind(product.category.as_ref().map(|c| c.to_string()))
+        .bind(&product.tags)
+        .bind(product.created_at)
+        .bind(product.updated_at)
+}
+
+/// Advisory lock key shared by every insert of the same name for a user.
+fn duplicate_lock_key(product: &Product) -> String {
+    format!(
+        "product:{}:{}",
+        product.user_id.as_str(),
+        product.name.trim().to_lowercase()
+    )
+}
 
 pub struct ProductRepositoryPostgres {
     pool: PgPool,
+    /// Listings and other stale-tolerant reads
+    reads: ReadPool,
 }
 
 impl ProductRepositoryPostgres {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            reads: ReadPool::primary(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Sends listings, enrichment and other facet lookups to `reads` instead
+    /// of the primary.
+    pub fn with_reads(mut self, reads: ReadPool) -> Self {
+        self.reads = reads;
+        self
     }
 }
 
 #[async_trait]
 impl ProductRepository for ProductRepositoryPostgres {
-    async fn get_all(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError> {
-        let entities = sqlx::query_as::<_, ProductEntity>(
-            "SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, outcome, created_at, updated_at FROM products WHERE user_id = $1 ORDER BY created_at DESC",
-        )
-        .bind(user_id.as_str())
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+    async fn find(&self, query: &ProductQuery) -> Result<Vec<Product>, RepositoryError> {
+        let entities = self
+            .reads
+            .read(|pool| async move {
+                build_select(query)
+                    .build_query_as::<ProductEntity>()
+                    .fetch_all(&pool)
+                    .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
 
         Ok(entities.into_iter().map(|e| e.into_domain()).collect())
     }
 
     async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<Product, RepositoryError> {
         let entity = sqlx::query_as::<_, ProductEntity>(
-            "SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, outcome, created_at, updated_at FROM products WHERE id = $1 AND user_id = $2",
+            "SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, expiry_confidence, expiry_type, outcome, estimation_status, category, tags, created_at, updated_at FROM products WHERE id = $1 AND user_id = $2",
         )
         .bind(id)
         .bind(user_id.as_str())
         .fetch_optional(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?
+        .map_err(RepositoryError::database_error)?
         .ok_or(RepositoryError::NotFound)?;
 
         Ok(entity.into_domain())
     }
 
-    async fn save(&self, product: &Product) -> Result<(), RepositoryError> {
-        sqlx::query(
-            r#"INSERT INTO products (id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, outcome, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            ON CONFLICT (id) DO UPDATE SET
-                name = EXCLUDED.name,
-                status = EXCLUDED.status,
-                location = EXCLUDED.location,
-                quantity = EXCLUDED.quantity,
-                expiry_date = EXCLUDED.expiry_date,
-                estimated_expiry_date = EXCLUDED.estimated_expiry_date,
-                outcome = EXCLUDED.outcome,
-                updated_at = EXCLUDED.updated_at"#,
+    async fn exists(&self, id: Uuid, user_id: &UserId) -> Result<bool, RepositoryError> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM products WHERE id = $1 AND user_id = $2)")
+            .bind(id)
+            .bind(user_id.as_str())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)
+    }
+
+    async fn count(&self, query: &ProductQuery) -> Result<u64, RepositoryError> {
+        let count: i64 = self
+            .reads
+            .read(|pool| async move {
+                build_count(query)
+                    .build_query_scalar()
+                    .fetch_one(&pool)
+                    .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(count as u64)
+    }
+
+    async fn insert(&self, product: &Product) -> Result<(), RepositoryError> {
+        insert_query(product)
+            .execute(&self.pool)
+            .await
+            .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn insert_unless_duplicate(
+        &self,
+        product: &Product,
+        window: chrono::Duration,
+    ) -> Result<Option<Product>, RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // Held until commit, so a second device adding the same name waits
+        // here and then sees the first one's row.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1, 0))")
+            .bind(duplicate_lock_key(product))
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        let existing = sqlx::query_as::<_, ProductEntity>(
+            r#"SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, expiry_confidence, expiry_type, outcome, estimation_status, category, tags, created_at, updated_at
+            FROM products
+            WHERE user_id = $1 AND status != 'finished' AND lower(btrim(name)) = $2 AND created_at >= $3
+            ORDER BY created_at
+            LIMIT 1"#,
+        )
+        .bind(product.user_id.as_str())
+        .bind(product.name.trim().to_lowercase())
+        .bind(product.created_at - window)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        if let Some(existing) = existing {
+            return Ok(Some(existing.into_domain()));
+        }
+
+        insert_query(product)
+            .execute(&mut *tx)
+            .await
+            .map_err(write_error)?;
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(None)
+    }
+
+    async fn update(&self, product: &Product) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE products SET
+                name = $3,
+                status = $4,
+                location = $5,
+                quantity = $6,
+                expiry_date = $7,
+                estimated_expiry_date = $8,
+                expiry_confidence = $9,
+                expiry_type = $10,
+                outcome = $11,
+                estimation_status = $12,
+                category = $13,
+                tags = $14,
+                updated_at = $15
+            WHERE id = $1 AND user_id = $2"#,
         )
         .bind(product.id)
         .bind(product.user_id.as_str())
@@ -69,12 +214,20 @@ impl ProductRepository for ProductRepositoryPostgres {
         .bind(&product.quantity)
         .bind(product.expiry_date)
         .bind(product.estimated_expiry_date)
+        .bind(product.expiry_confidence.as_ref().map(|c| c.to_string()))
+        .bind(product.expiry_type.to_string())
         .bind(product.outcome.as_ref().map(|o| o.to_string()))
-        .bind(product.created_at)
+        .bind(product.estimation_status.to_string())
+        .bind(product.category.as_ref().map(|c| c.to_string()))
+        .bind(&product.tags)
         .bind(product.updated_at)
         .execute(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
@@ -85,20 +238,306 @@ impl ProductRepository for ProductRepositoryPostgres {
             .bind(user_id.as_str())
             .execute(&self.pool)
             .await
-            .map_err(|_| RepositoryError::DatabaseError)?;
+            .map_err(RepositoryError::database_error)?;
 
         Ok(())
     }
 
-    async fn get_active_products(&self, user_id: &UserId) -> Result<Vec<Product>, RepositoryError> {
-        let entities = sqlx::query_as::<_, ProductEntity>(
-            "SELECT id, user_id, name, status, location, quantity, expiry_date, estimated_expiry_date, outcome, created_at, updated_at FROM products WHERE user_id = $1 AND status != 'finished' ORDER BY created_at DESC",
+    async fn get_users_with_active_products(
+        &self,
+        after: Option<UserId>,
+        limit: usize,
+    ) -> Result<Vec<UserId>, RepositoryError> {
+        let after = after.as_ref().map(UserId::as_str);
+        let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+        let user_ids: Vec<(String,)> = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as(
+                    r#"SELECT DISTINCT user_id FROM products
+                    WHERE status != 'finished' AND ($1::text IS NULL OR user_id > $1)
+                    ORDER BY user_id
+                    LIMIT $2"#,
+                )
+                .bind(after)
+                .bind(limit)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(user_ids.into_iter().map(|(id,)| UserId::new(id)).collect())
+    }
+}
+
+#[async_trait]
+impl UnwantedFlagRepository for ProductRepositoryPostgres {
+    async fn set_unwanted(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+        unwanted: bool,
+    ) -> Result<(), RepositoryError> {
+        // updated_at is left alone: stats read it as the finish time of finished products
+        let result =
+            sqlx::query("UPDATE products SET unwanted = $3 WHERE id = $1 AND user_id = $2")
+                .bind(id)
+                .bind(user_id.as_str())
+                .bind(unwanted)
+                .execute(&self.pool)
+                .await
+                .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProductEnrichmentRepository for ProductRepositoryPostgres {
+    async fn save(&self, enrichment: &ProductEnrichment) -> Result<(), RepositoryError> {
+        let nutrition = &enrichment.nutrition;
+        sqlx::query(
+            r#"INSERT INTO product_enrichment (barcode, nutriscore, ecoscore, energy_kcal_100g, fat_100g,
+                saturated_fat_100g, carbohydrates_100g, sugars_100g, fiber_100g, proteins_100g, salt_100g, allergens,
+                fetched_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (barcode) DO UPDATE SET
+                nutriscore = EXCLUDED.nutriscore,
+                ecoscore = EXCLUDED.ecoscore,
+                energy_kcal_100g = EXCLUDED.energy_kcal_100g,
+                fat_100g = EXCLUDED.fat_100g,
+                saturated_fat_100g = EXCLUDED.saturated_fat_100g,
+                carbohydrates_100g = EXCLUDED.carbohydrates_100g,
+                sugars_100g = EXCLUDED.sugars_100g,
+                fiber_100g = EXCLUDED.fiber_100g,
+                proteins_100g = EXCLUDED.proteins_100g,
+                salt_100g = EXCLUDED.salt_100g,
+                allergens = EXCLUDED.allergens,
+                fetched_at = EXCLUDED.fetched_at"#,
         )
-        .bind(user_id.as_str())
-        .fetch_all(&self.pool)
+        .bind(&enrichment.barcode)
+        .bind(enrichment.nutriscore.map(|g| g.to_string()))
+        .bind(enrichment.ecoscore.map(|g| g.to_string()))
+        .bind(nutrition.energy_kcal)
+        .bind(nutrition.fat)
+        .bind(nutrition.saturated_fat)
+        .bind(nutrition.carbohydrates)
+        .bind(nutrition.sugars)
+        .bind(nutrition.fiber)
+        .bind(nutrition.proteins)
+        .bind(nutrition.salt)
+        .bind(
+            enrichment
+                .allergens
+                .iter()
+                .map(|a| a.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .bind(enrichment.fetched_at)
+        .execute(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn link(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+        barcode: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query("UPDATE products SET barcode = $3 WHERE id = $1 AND user_id = $2")
+            .bind(product_id)
+            .bind(user_id.as_str())
+            .bind(barcode)
+            .execute(&self.pool)
+            .await
+            .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_for_products(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ProductEnrichment>, RepositoryError> {
+        let entities = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, ProductEnrichmentEntity>(
+                    r#"SELECT p.id AS product_id, e.barcode, e.nutriscore, e.ecoscore, e.energy_kcal_100g, e.fat_100g,
+                        e.saturated_fat_100g, e.carbohydrates_100g, e.sugars_100g, e.fiber_100g, e.proteins_100g,
+                        e.salt_100g, e.allergens, e.fetched_at
+                    FROM products p
+                    JOIN product_enrichment e ON e.barcode = p.barcode
+                    WHERE p.user_id = $1 AND p.id = ANY($2)"#,
+                )
+                .bind(user_id.as_str())
+                .bind(product_ids)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
 
         Ok(entities.into_iter().map(|e| e.into_domain()).collect())
     }
 }
+
+#[async_trait]
+impl ProductBatchRepository for ProductRepositoryPostgres {
+    async fn insert_all(&self, products: &[Product]) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        for product in products {
+            insert_query(product)
+                .execute(&mut *tx)
+                .await
+                .map_err(write_error)?;
+        }
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ProductImageRepository for ProductRepositoryPostgres {
+    async fn save_thumbnail(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+        thumbnail_key: &str,
+    ) -> Result<(), RepositoryError> {
+        let result =
+            sqlx::query("UPDATE products SET thumbnail_key = $3 WHERE id = $1 AND user_id = $2")
+                .bind(product_id)
+                .bind(user_id.as_str())
+                .bind(thumbnail_key)
+                .execute(&self.pool)
+                .await
+                .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn find_thumbnails(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, String>, RepositoryError> {
+        let rows = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, (Uuid, String)>(
+                    r#"SELECT id, thumbnail_key FROM products
+                    WHERE user_id = $1 AND id = ANY($2) AND thumbnail_key IS NOT NULL"#,
+                )
+                .bind(user_id.as_str())
+                .bind(product_ids)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(rows.into_iter().collect())
+    }
+}
+
+#[async_trait]
+impl ProductFacetRepository for ProductRepositoryPostgres {
+    async fn find_shopping_items(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ShoppingItem>, RepositoryError> {
+        let entities = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, ShoppingItemEntity>(
+                    r#"SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at
+                    FROM shopping_items
+                    WHERE user_id = $1 AND product_id = ANY($2) AND is_bought = FALSE"#,
+                )
+                .bind(user_id.as_str())
+                .bind(product_ids)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(entities
+            .into_iter()
+            .filter_map(|e| {
+                let item = e.into_domain();
+                item.product_id.map(|id| (id, item))
+            })
+            .collect())
+    }
+
+    async fn summarize_history(
+        &self,
+        user_id: &UserId,
+        product_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, ProductHistorySummary>, RepositoryError> {
+        let rows = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, (Uuid, i64, i64, i64, i64, Option<DateTime<Utc>>)>(
+                    r#"SELECT p.id, COUNT(*),
+                        COUNT(*) FILTER (WHERE h.outcome = 'used'),
+                        COUNT(*) FILTER (WHERE h.outcome = 'thrown_away'),
+                        COUNT(*) FILTER (WHERE h.outcome = 'given_away'),
+                        MAX(h.updated_at)
+                    FROM products p
+                    JOIN products h ON h.user_id = p.user_id AND h.id != p.id
+                        AND h.status = 'finished' AND LOWER(TRIM(h.name)) = LOWER(TRIM(p.name))
+                    WHERE p.user_id = $1 AND p.id = ANY($2)
+                    GROUP BY p.id"#,
+                )
+                .bind(user_id.as_str())
+                .bind(product_ids)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(id, finished, used, thrown_away, given_away, last_finished_at)| {
+                    let summary = ProductHistorySummary {
+                        finished: finished as u32,
+                        used: used as u32,
+                        thrown_away: thrown_away as u32,
+                        given_away: given_away as u32,
+                        last_finished_at,
+                    };
+                    (id, summary)
+                },
+            )
+            .collect())
+    }
+}
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::product::query::build_count;
+
+use business::domain::errors::RepositoryError;
+use business::domain::product::query::ProductQuery;
+use business::domain::quota::errors::QuotaError;
+use business::domain::quota::model::{PlanTier, Usage};
+use business::domain::quota::services::QuotaService;
+use business::domain::shared::value_objects::UserId;
+
+pub struct QuotaServicePostgres {
+    pool: PgPool,
+}
+
+impl QuotaServicePostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn get_plan(&self, user_id: &UserId) -> Result<PlanTier, RepositoryError> {
+        let plan: Option<(String,)> = sqlx::query_as("SELECT plan FROM users WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(plan
+            .and_then(|(plan,)| plan.parse().ok())
+            .unwrap_or_default())
+    }
+
+    async fn count_products(&self, user_id: &UserId) -> Result<u32, RepositoryError> {
+        let count: i64 = build_count(&ProductQuery::active(user_id.clone()))
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(count as u32)
+    }
+
+    async fn count_ai_calls(
+        &self,
+        user_id: &UserId,
+        day: NaiveDate,
+    ) -> Result<u32, RepositoryError> {
+        let calls: Option<(i32,)> =
+            sqlx::query_as("SELECT calls FROM ai_usage_counters WHERE user_id = $1 AND day = $2")
+                .bind(user_id.as_str())
+                .bind(day)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(RepositoryError::database_error)?;
+
+        Ok(calls.map(|(calls,)| calls as u32).unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl QuotaService for QuotaServicePostgres {
+    async fn consume_ai_call(&self, user_id: &UserId) -> Result<(), QuotaError> {
+        let limit = self.get_plan(user_id).await?.limits().ai_calls_per_day;
+
+        // Increment only while under the limit so concurrent requests cannot overshoot
+        let updated: Option<(i32,)> = sqlx::query_as(
+            r#"INSERT INTO ai_usage_counters (user_id, day, calls)
+            VALUES ($1, $2, 1)
+            ON CONFLICT (user_id, day) DO UPDATE SET calls = ai_usage_counters.calls + 1
+            WHERE $3::INTEGER IS NULL OR ai_usage_counters.calls < $3
+            RETURNING calls"#,
+        )
+        .bind(user_id.as_str())
+        .bind(Utc::now().date_naive())
+        .bind(limit.map(|l| l as i32))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        updated.map(|_| ()).ok_or(QuotaError::AiCallsExceeded)
+    }
+
+    async fn ensure_product_capacity(&self, user_id: &UserId) -> Result<(), QuotaError> {
+        let Some(max_products) = self.get_plan(user_id).await?.limits().max_products else {
+            return Ok(());
+        };
+
+        if self.count_products(user_id).await? >= max_products {
+            return Err(QuotaError::ProductLimitReached);
+        }
+
+        Ok(())
+    }
+
+    async fn get_usage(&self, user_id: &UserId) -> Result<Usage, QuotaError> {
+        let plan = self.get_plan(user_id).await?;
+        let ai_calls_today = self
+            .count_ai_calls(user_id, Utc::now().date_naive())
+            .await?;
+        let products = self.count_products(user_id).await?;
+
+        Ok(Usage {
+            limits: plan.limits(),
+            plan,
+            ai_calls_today,
+            products,
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::receipt_import::model::{
+    ImportReview, ImportedProduct, ReceiptImport, ReceiptImportStatus, SkipReason, SkippedLine,
+};
+use business::domain::shared::value_objects::UserId;
+
+/// JSON representation of a created product inside an import review.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportedProductRecord {
+    pub product_id: Uuid,
+    pub name: String,
+    pub estimated_expiry_date: Option<DateTime<Utc>>,
+}
+
+/// JSON representation of a skipped receipt line inside an import review.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SkippedLineRecord {
+    pub name: String,
+    pub reason: String,
+    pub existing_product_id: Option<Uuid>,
+}
+
+/// JSON representation of an import review.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ImportReviewRecord {
+    pub created: Vec<ImportedProductRecord>,
+    pub skipped: Vec<SkippedLineRecord>,
+}
+
+impl From<&ImportReview> for ImportReviewRecord {
+    fn from(r: &ImportReview) -> Self {
+        Self {
+            created: r
+                .created
+                .iter()
+                .map(|p| ImportedProductRecord {
+                    product_id: p.product_id,
+                    name: p.name.clone(),
+                    estimated_expiry_date: p.estimated_expiry_date,
+                })
+                .collect(),
+            skipped: r
+                .skipped
+                .iter()
+                .map(|s| SkippedLineRecord {
+                    name: s.name.clone(),
+                    reason: s.reason.to_string(),
+                    existing_product_id: s.existing_product_id,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ImportReviewRecord {
+    pub fn into_domain(self) -> ImportReview {
+        ImportReview {
+            created: self
+                .created
+                .into_iter()
+                .map(|p| ImportedProduct {
+                    product_id: p.product_id,
+                    name: p.name,
+                    estimated_expiry_date: p.estimated_expiry_date,
+                })
+                .collect(),
+            skipped: self
+                .skipped
+                .into_iter()
+                .map(|s| SkippedLine {
+                    name: s.name,
+                    reason: s.reason.parse().unwrap_or(SkipReason::InvalidName),
+                    existing_product_id: s.existing_product_id,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct ReceiptImportEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub status: String,
+    pub review: Option<Json<ImportReviewRecord>>,
+    pub error: Option<String>,
+    pub image_key: Option<String>,
+    pub thumbnail_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReceiptImportEntity {
+    pub fn into_domain(self) -> ReceiptImport {
+        ReceiptImport::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.status
+                .parse::<ReceiptImportStatus>()
+                .unwrap_or(ReceiptImportStatus::Failed),
+            self.review.map(|r| r.0.into_domain()),
+            self.error,
+            self.image_key,
+            self.thumbnail_key,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
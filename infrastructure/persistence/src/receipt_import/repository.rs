@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::receipt_import::model::ReceiptImport;
+use business::domain::receipt_import::repository::ReceiptImportRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::{ImportReviewRecord, ReceiptImportEntity};
+use crate::db::write_error;
+
+pub struct ReceiptImportRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl ReceiptImportRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReceiptImportRepository for ReceiptImportRepositoryPostgres {
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        user_id: &UserId,
+    ) -> Result<ReceiptImport, RepositoryError> {
+        let entity = sqlx::query_as::<_, ReceiptImportEntity>(
+            "SELECT id, user_id, status, review, error, image_key, thumbnail_key, created_at, updated_at FROM receipt_imports WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn insert(&self, import: &ReceiptImport) -> Result<(), RepositoryError> {
+        let review = import
+            .review
+            .as_ref()
+            .map(|r| Json(ImportReviewRecord::from(r)));
+
+        sqlx::query(
+            r#"INSERT INTO receipt_imports (id, user_id, status, review, error, image_key, thumbnail_key, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#,
+        )
+        .bind(import.id)
+        .bind(import.user_id.as_str())
+        .bind(import.status.to_string())
+        .bind(review)
+        .bind(&import.error)
+        .bind(&import.image_key)
+        .bind(&import.thumbnail_key)
+        .bind(import.created_at)
+        .bind(import.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, import: &ReceiptImport) -> Result<(), RepositoryError> {
+        let review = import
+            .review
+            .as_ref()
+            .map(|r| Json(ImportReviewRecord::from(r)));
+
+        let result = sqlx::query(
+            r#"UPDATE receipt_imports SET
+                status = $3,
+                review = $4,
+                error = $5,
+                thumbnail_key = $6,
+                updated_at = $7
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(import.id)
+        .bind(import.user_id.as_str())
+        .bind(import.status.to_string())
+        .bind(review)
+        .bind(&import.error)
+        .bind(&import.thumbnail_key)
+        .bind(import.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::reminder::model::Reminder;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct ReminderEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub product_id: Uuid,
+    pub note: String,
+    pub remind_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ReminderEntity {
+    pub fn into_domain(self) -> Reminder {
+        Reminder::from_repository(
+            self.id,
+            UserId::new(self.user_id),
+            self.product_id,
+            self.note,
+            self.remind_at,
+            self.sent_at,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
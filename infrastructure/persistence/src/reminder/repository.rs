@@ -0,0 +1,160 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::reminder::model::Reminder;
+use business::domain::reminder::repository::ReminderRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::ReminderEntity;
+use crate::db::write_error;
+
+pub struct ReminderRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl ReminderRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for ReminderRepositoryPostgres {
+    async fn get_by_product(
+        &self,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<Vec<Reminder>, RepositoryError> {
+        let entities = sqlx::query_as::<_, ReminderEntity>(
+            "SELECT id, user_id, product_id, note, remind_at, sent_at, created_at, updated_at FROM product_reminders WHERE product_id = $1 AND user_id = $2 ORDER BY remind_at",
+        )
+        .bind(product_id)
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+
+    async fn get_by_id(
+        &self,
+        id: Uuid,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<Reminder, RepositoryError> {
+        let entity = sqlx::query_as::<_, ReminderEntity>(
+            "SELECT id, user_id, product_id, note, remind_at, sent_at, created_at, updated_at FROM product_reminders WHERE id = $1 AND product_id = $2 AND user_id = $3",
+        )
+        .bind(id)
+        .bind(product_id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn insert(&self, reminder: &Reminder) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO product_reminders (id, user_id, product_id, note, remind_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+        )
+        .bind(reminder.id)
+        .bind(reminder.user_id.as_str())
+        .bind(reminder.product_id)
+        .bind(&reminder.note)
+        .bind(reminder.remind_at)
+        .bind(reminder.created_at)
+        .bind(reminder.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, reminder: &Reminder) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE product_reminders SET
+                note = $4,
+                remind_at = $5,
+                sent_at = $6,
+                updated_at = $7
+            WHERE id = $1 AND product_id = $2 AND user_id = $3"#,
+        )
+        .bind(reminder.id)
+        .bind(reminder.product_id)
+        .bind(reminder.user_id.as_str())
+        .bind(&reminder.note)
+        .bind(reminder.remind_at)
+        .bind(reminder.sent_at)
+        .bind(reminder.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn delete(
+        &self,
+        id: Uuid,
+        product_id: Uuid,
+        user_id: &UserId,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "DELETE FROM product_reminders WHERE id = $1 AND product_id = $2 AND user_id = $3",
+        )
+        .bind(id)
+        .bind(product_id)
+        .bind(user_id.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Reminder>, RepositoryError> {
+        let entities = sqlx::query_as::<_, ReminderEntity>(
+            r#"SELECT id, user_id, product_id, note, remind_at, sent_at, created_at, updated_at FROM product_reminders
+            WHERE sent_at IS NULL AND remind_at <= $1
+            ORDER BY remind_at
+            LIMIT $2"#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+
+    async fn mark_sent(&self, id: Uuid, sent_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        sqlx::query("UPDATE product_reminders SET sent_at = $2 WHERE id = $1")
+            .bind(id)
+            .bind(sent_at)
+            .execute(&self.pool)
+            .await
+            .map_err(write_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::sandbox::repository::SandboxRepository;
+use business::domain::shared::value_objects::UserId;
+
+/// Tables holding user-written rows, children before the products they
+/// point to. `users` is left alone so the plan survives a reset.
+const USER_TABLES: [&str; 30] = [
+    "product_reservations",
+    "pending_ai_changes",
+    "product_reminders",
+    "shopping_items",
+    "products",
+    "shopping_trips",
+    "suggestion_batches",
+    "cooking_sessions",
+    "planned_meals",
+    "share_links",
+    "receipt_imports",
+    "store_profiles",
+    "location_rules",
+    "ai_review_settings",
+    "user_preferences",
+    "ai_usage_counters",
+    "inventory_snapshots",
+    "challenges",
+    "weekly_waste",
+    "waste_streaks",
+    "inbound_addresses",
+    "inbound_emails",
+    "widget_tokens",
+    "devices",
+    "notifications",
+    "barcode_contributions",
+    "vacations",
+    "budgets",
+    "ai_calls",
+    "jobs",
+];
+
+pub struct SandboxRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl SandboxRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SandboxRepository for SandboxRepositoryPostgres {
+    async fn wipe(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        for table in USER_TABLES {
+            sqlx::query(&format!("DELETE FROM {table} WHERE user_id = $1"))
+                .bind(user_id.as_str())
+                .execute(&mut *tx)
+                .await
+                .map_err(RepositoryError::database_error)?;
+        }
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::share_link::model::ShareLink;
+use business::domain::shared::value_objects::UserId;
+
+#[derive(Debug, FromRow)]
+pub struct ShareLinkEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub token_hash: String,
+    pub include_expiring_products: bool,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLinkEntity {
+    pub fn into_domain(self) -> ShareLink {
+        ShareLink::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.token_hash,
+            self.include_expiring_products,
+            self.expires_at,
+            self.created_at,
+        )
+    }
+}
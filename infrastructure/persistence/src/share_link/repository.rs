@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::share_link::model::ShareLink;
+use business::domain::share_link::repository::ShareLinkRepository;
+use business::domain::shared::value_objects::UserId;
+
+use super::entity::ShareLinkEntity;
+
+pub struct ShareLinkRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl ShareLinkRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShareLinkRepository for ShareLinkRepositoryPostgres {
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<ShareLink>, RepositoryError> {
+        let entity = sqlx::query_as::<_, ShareLinkEntity>(
+            "SELECT id, user_id, token_hash, include_expiring_products, expires_at, created_at FROM share_links WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn save(&self, link: &ShareLink) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO share_links (id, user_id, token_hash, include_expiring_products, expires_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(link.id)
+        .bind(link.user_id.as_str())
+        .bind(&link.token_hash)
+        .bind(link.include_expiring_products)
+        .bind(link.expires_at)
+        .bind(link.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM share_links WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -2,8 +2,11 @@ use chrono::{DateTime, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 
+use business::domain::product::urgency::ExpiringSoonWindow;
 use business::domain::shared::value_objects::UserId;
-use business::domain::shopping_item::model::ShoppingItem;
+use business::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
+
+use crate::product::entity::ProductEntity;
 
 #[derive(Debug, FromRow)]
 pub struct ShoppingItemEntity {
@@ -12,13 +15,14 @@ pub struct ShoppingItemEntity {
     pub name: String,
     pub product_id: Option<Uuid>,
     pub is_bought: bool,
+    pub attachment_content_type: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl ShoppingItemEntity {
     pub fn into_domain(self) -> ShoppingItem {
-        ShoppingItem::from_repository(
+        let mut item = ShoppingItem::from_repository(
             self.id,
             UserId::new(&self.user_id),
             self.name,
@@ -26,6 +30,71 @@ impl ShoppingItemEntity {
             self.is_bought,
             self.created_at,
             self.updated_at,
-        )
+        );
+        item.attachment_content_type = self.attachment_content_type;
+        item
+    }
+}
+
+/// Shopping item row LEFT JOINed with its product; the `product_` columns are
+/// all NULL when the item has no product or it was deleted.
+#[derive(Debug, FromRow)]
+pub struct ShoppingItemWithProductEntity {
+    #[sqlx(flatten)]
+    pub item: ShoppingItemEntity,
+    pub product_name: Option<String>,
+    pub product_status: Option<String>,
+    pub product_location: Option<String>,
+    pub product_quantity: Option<String>,
+    pub product_expiry_date: Option<DateTime<Utc>>,
+    pub product_estimated_expiry_date: Option<DateTime<Utc>>,
+    pub product_expiry_confidence: Option<String>,
+    pub product_expiry_type: Option<String>,
+    pub product_outcome: Option<String>,
+    pub product_estimation_status: Option<String>,
+    pub product_category: Option<String>,
+    pub product_tags: Option<Vec<String>>,
+    pub product_created_at: Option<DateTime<Utc>>,
+    pub product_updated_at: Option<DateTime<Utc>>,
+}
+
+impl ShoppingItemWithProductEntity {
+    pub fn into_domain(self) -> ShoppingItemView {
+        let product = match (
+            self.item.product_id,
+            self.product_name,
+            self.product_status,
+            self.product_created_at,
+            self.product_updated_at,
+        ) {
+            (Some(id), Some(name), Some(status), Some(created_at), Some(updated_at)) => Some(
+                ProductEntity {
+                    id,
+                    user_id: self.item.user_id.clone(),
+                    name,
+                    status,
+                    location: self.product_location,
+                    quantity: self.product_quantity,
+                    expiry_date: self.product_expiry_date,
+                    estimated_expiry_date: self.product_estimated_expiry_date,
+                    expiry_confidence: self.product_expiry_confidence,
+                    expiry_type: self.product_expiry_type.unwrap_or_default(),
+                    outcome: self.product_outcome,
+                    estimation_status: self.product_estimation_status.unwrap_or_default(),
+                    category: self.product_category,
+                    tags: self.product_tags.unwrap_or_default(),
+                    created_at,
+                    updated_at,
+                }
+                .into_domain(),
+            ),
+            _ => None,
+        };
+
+        ShoppingItemView {
+            item: self.item.into_domain(),
+            product,
+            expiring_soon: ExpiringSoonWindow::default(),
+        }
     }
 }
@@ -4,10 +4,11 @@ use uuid::Uuid;
 
 use business::domain::errors::RepositoryError;
 use business::domain::shared::value_objects::UserId;
-use business::domain::shopping_item::model::ShoppingItem;
+use business::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
 use business::domain::shopping_item::repository::ShoppingItemRepository;
 
-use super::entity::ShoppingItemEntity;
+use super::entity::{ShoppingItemEntity, ShoppingItemWithProductEntity};
+use crate::db::{escape_like, write_error};
 
 pub struct ShoppingItemRepositoryPostgres {
     pool: PgPool,
@@ -23,25 +24,52 @@ impl ShoppingItemRepositoryPostgres {
 impl ShoppingItemRepository for ShoppingItemRepositoryPostgres {
     async fn get_all(&self, user_id: &UserId) -> Result<Vec<ShoppingItem>, RepositoryError> {
         let entities = sqlx::query_as::<_, ShoppingItemEntity>(
-            "SELECT id, user_id, name, product_id, is_bought, created_at, updated_at FROM shopping_items WHERE user_id = $1 ORDER BY created_at DESC",
+            "SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at FROM shopping_items WHERE user_id = $1 ORDER BY created_at DESC",
         )
         .bind(user_id.as_str())
         .fetch_all(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+
+    async fn get_all_with_products(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Vec<ShoppingItemView>, RepositoryError> {
+        let entities = sqlx::query_as::<_, ShoppingItemWithProductEntity>(
+            r#"SELECT s.id, s.user_id, s.name, s.product_id, s.is_bought, s.attachment_content_type, s.created_at, s.updated_at,
+                p.name AS product_name, p.status AS product_status, p.location AS product_location,
+                p.quantity AS product_quantity, p.expiry_date AS product_expiry_date,
+                p.estimated_expiry_date AS product_estimated_expiry_date,
+                p.expiry_confidence AS product_expiry_confidence,
+                p.expiry_type AS product_expiry_type, p.outcome AS product_outcome,
+                p.estimation_status AS product_estimation_status,
+                p.category AS product_category, p.tags AS product_tags,
+                p.created_at AS product_created_at, p.updated_at AS product_updated_at
+            FROM shopping_items s
+            LEFT JOIN products p ON p.id = s.product_id AND p.user_id = s.user_id
+            WHERE s.user_id = $1
+            ORDER BY s.created_at DESC"#,
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
 
         Ok(entities.into_iter().map(|e| e.into_domain()).collect())
     }
 
     async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<ShoppingItem, RepositoryError> {
         let entity = sqlx::query_as::<_, ShoppingItemEntity>(
-            "SELECT id, user_id, name, product_id, is_bought, created_at, updated_at FROM shopping_items WHERE id = $1 AND user_id = $2",
+            "SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at FROM shopping_items WHERE id = $1 AND user_id = $2",
         )
         .bind(id)
         .bind(user_id.as_str())
         .fetch_optional(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?
+        .map_err(RepositoryError::database_error)?
         .ok_or(RepositoryError::NotFound)?;
 
         Ok(entity.into_domain())
@@ -53,47 +81,109 @@ impl ShoppingItemRepository for ShoppingItemRepositoryPostgres {
         user_id: &UserId,
     ) -> Result<Option<ShoppingItem>, RepositoryError> {
         let entity = sqlx::query_as::<_, ShoppingItemEntity>(
-            "SELECT id, user_id, name, product_id, is_bought, created_at, updated_at FROM shopping_items WHERE product_id = $1 AND user_id = $2",
+            "SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at FROM shopping_items WHERE product_id = $1 AND user_id = $2",
         )
         .bind(product_id)
         .bind(user_id.as_str())
         .fetch_optional(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+        .map_err(RepositoryError::database_error)?;
 
         Ok(entity.map(|e| e.into_domain()))
     }
 
-    async fn save(&self, item: &ShoppingItem) -> Result<(), RepositoryError> {
+    async fn insert(&self, item: &ShoppingItem) -> Result<(), RepositoryError> {
         sqlx::query(
-            r#"INSERT INTO shopping_items (id, user_id, name, product_id, is_bought, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            ON CONFLICT (id) DO UPDATE SET
-                name = EXCLUDED.name,
-                is_bought = EXCLUDED.is_bought,
-                updated_at = EXCLUDED.updated_at"#,
+            r#"INSERT INTO shopping_items (id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
         )
         .bind(item.id)
         .bind(item.user_id.as_str())
         .bind(&item.name)
         .bind(item.product_id)
         .bind(item.is_bought)
+        .bind(&item.attachment_content_type)
         .bind(item.created_at)
         .bind(item.updated_at)
         .execute(&self.pool)
         .await
-        .map_err(|_| RepositoryError::DatabaseError)?;
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, item: &ShoppingItem) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            r#"UPDATE shopping_items SET
+                name = $3,
+                is_bought = $4,
+                attachment_content_type = $5,
+                updated_at = $6
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(item.id)
+        .bind(item.user_id.as_str())
+        .bind(&item.name)
+        .bind(item.is_bought)
+        .bind(&item.attachment_content_type)
+        .bind(item.updated_at)
+        .execute(&self.pool)
+        .await
+        // Unmarking an item as bought while another unbought one exists for the product
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
 
         Ok(())
     }
 
+    async fn save_for_product(&self, item: &ShoppingItem) -> Result<ShoppingItem, RepositoryError> {
+        let inserted = sqlx::query_as::<_, ShoppingItemEntity>(
+            r#"INSERT INTO shopping_items (id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id, product_id) WHERE product_id IS NOT NULL AND is_bought = FALSE
+            DO NOTHING
+            RETURNING id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at"#,
+        )
+        .bind(item.id)
+        .bind(item.user_id.as_str())
+        .bind(&item.name)
+        .bind(item.product_id)
+        .bind(item.is_bought)
+        .bind(&item.attachment_content_type)
+        .bind(item.created_at)
+        .bind(item.updated_at)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        if let Some(entity) = inserted {
+            return Ok(entity.into_domain());
+        }
+
+        // Lost the race: a separate statement sees the row committed by the winner
+        let existing = sqlx::query_as::<_, ShoppingItemEntity>(
+            "SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at FROM shopping_items WHERE user_id = $1 AND product_id = $2 AND is_bought = FALSE",
+        )
+        .bind(item.user_id.as_str())
+        .bind(item.product_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(existing.into_domain())
+    }
+
     async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError> {
         sqlx::query("DELETE FROM shopping_items WHERE id = $1 AND user_id = $2")
             .bind(id)
             .bind(user_id.as_str())
             .execute(&self.pool)
             .await
-            .map_err(|_| RepositoryError::DatabaseError)?;
+            .map_err(RepositoryError::database_error)?;
 
         Ok(())
     }
@@ -108,7 +198,7 @@ impl ShoppingItemRepository for ShoppingItemRepositoryPostgres {
             .bind(user_id.as_str())
             .execute(&self.pool)
             .await
-            .map_err(|_| RepositoryError::DatabaseError)?;
+            .map_err(RepositoryError::database_error)?;
 
         Ok(())
     }
@@ -119,8 +209,30 @@ impl ShoppingItemRepository for ShoppingItemRepositoryPostgres {
                 .bind(user_id.as_str())
                 .execute(&self.pool)
                 .await
-                .map_err(|_| RepositoryError::DatabaseError)?;
+                .map_err(RepositoryError::database_error)?;
 
         Ok(result.rows_affected())
     }
+
+    async fn search(
+        &self,
+        user_id: &UserId,
+        term: &str,
+        limit: u32,
+    ) -> Result<Vec<ShoppingItem>, RepositoryError> {
+        let entities = sqlx::query_as::<_, ShoppingItemEntity>(
+            r#"SELECT id, user_id, name, product_id, is_bought, attachment_content_type, created_at, updated_at FROM shopping_items
+            WHERE user_id = $1 AND name ILIKE $2
+            ORDER BY created_at DESC
+            LIMIT $3"#,
+        )
+        .bind(user_id.as_str())
+        .bind(format!("%{}%", escape_like(term)))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
 }
@@ -0,0 +1,65 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_trip::model::{ShoppingTrip, ShoppingTripStatus, TripItem};
+
+/// JSON representation of a trip item stored inside a trip.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TripItemRecord {
+    pub shopping_item_id: Uuid,
+    pub name: String,
+    pub bought_at: Option<DateTime<Utc>>,
+    pub price_cents: Option<u32>,
+}
+
+impl From<&TripItem> for TripItemRecord {
+    fn from(i: &TripItem) -> Self {
+        Self {
+            shopping_item_id: i.shopping_item_id,
+            name: i.name.clone(),
+            bought_at: i.bought_at,
+            price_cents: i.price_cents,
+        }
+    }
+}
+
+impl TripItemRecord {
+    pub fn into_domain(self) -> TripItem {
+        TripItem {
+            shopping_item_id: self.shopping_item_id,
+            name: self.name,
+            bought_at: self.bought_at,
+            price_cents: self.price_cents,
+        }
+    }
+}
+
+/// The summary columns are derived from `items` and only read for analytics.
+#[derive(Debug, FromRow)]
+pub struct ShoppingTripEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub items: Json<Vec<TripItemRecord>>,
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl ShoppingTripEntity {
+    pub fn into_domain(self) -> ShoppingTrip {
+        ShoppingTrip::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.items.0.into_iter().map(|i| i.into_domain()).collect(),
+            self.status
+                .parse::<ShoppingTripStatus>()
+                .unwrap_or(ShoppingTripStatus::InProgress),
+            self.started_at,
+            self.finished_at,
+        )
+    }
+}
@@ -0,0 +1,95 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_trip::model::{ShoppingTrip, ShoppingTripStatus};
+use business::domain::shopping_trip::repository::ShoppingTripRepository;
+
+use super::entity::{ShoppingTripEntity, TripItemRecord};
+use crate::db::write_error;
+
+pub struct ShoppingTripRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl ShoppingTripRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShoppingTripRepository for ShoppingTripRepositoryPostgres {
+    async fn find_in_progress(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<ShoppingTrip>, RepositoryError> {
+        let entity = sqlx::query_as::<_, ShoppingTripEntity>(
+            "SELECT id, user_id, items, status, started_at, finished_at FROM shopping_trips WHERE user_id = $1 AND status = $2",
+        )
+        .bind(user_id.as_str())
+        .bind(ShoppingTripStatus::InProgress.to_string())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn insert(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError> {
+        let items: Vec<TripItemRecord> = trip.items.iter().map(|i| i.into()).collect();
+        let summary = trip.summary();
+
+        sqlx::query(
+            r#"INSERT INTO shopping_trips (id, user_id, items, status, items_planned, items_bought, total_spend_cents, started_at, finished_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)"#,
+        )
+        .bind(trip.id)
+        .bind(trip.user_id.as_str())
+        .bind(Json(items))
+        .bind(trip.status.to_string())
+        .bind(summary.items_planned as i32)
+        .bind(summary.items_bought as i32)
+        .bind(summary.total_spend_cents as i64)
+        .bind(trip.started_at)
+        .bind(trip.finished_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, trip: &ShoppingTrip) -> Result<(), RepositoryError> {
+        let items: Vec<TripItemRecord> = trip.items.iter().map(|i| i.into()).collect();
+        let summary = trip.summary();
+
+        let result = sqlx::query(
+            r#"UPDATE shopping_trips SET
+                items = $3,
+                status = $4,
+                items_bought = $5,
+                total_spend_cents = $6,
+                finished_at = $7
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(trip.id)
+        .bind(trip.user_id.as_str())
+        .bind(Json(items))
+        .bind(trip.status.to_string())
+        .bind(summary.items_bought as i32)
+        .bind(summary.total_spend_cents as i64)
+        .bind(trip.finished_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
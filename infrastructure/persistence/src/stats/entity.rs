@@ -0,0 +1,85 @@
+use chrono::NaiveDate;
+use sqlx::FromRow;
+
+use business::domain::stats::model::{
+    InventorySnapshot, LocationCounts, ProductValue, StatusCounts, WasteStreak, WeeklyOutcomes,
+};
+
+#[derive(Debug, FromRow)]
+pub struct InventorySnapshotEntity {
+    pub snapshot_date: NaiveDate,
+    pub new_count: i32,
+    pub opened_count: i32,
+    pub almost_empty_count: i32,
+    pub fridge_count: i32,
+    pub pantry_count: i32,
+    pub freezer_count: i32,
+    pub unassigned_count: i32,
+    pub urgent_count: i32,
+    pub wasted_products: i32,
+    pub wasted_priced_products: i32,
+    pub wasted_value_cents: i64,
+    pub on_vacation: bool,
+}
+
+impl InventorySnapshotEntity {
+    pub fn into_domain(self) -> InventorySnapshot {
+        InventorySnapshot {
+            date: self.snapshot_date,
+            by_status: StatusCounts {
+                new: self.new_count as u32,
+                opened: self.opened_count as u32,
+                almost_empty: self.almost_empty_count as u32,
+            },
+            by_location: LocationCounts {
+                fridge: self.fridge_count as u32,
+                pantry: self.pantry_count as u32,
+                freezer: self.freezer_count as u32,
+                unassigned: self.unassigned_count as u32,
+            },
+            urgent: self.urgent_count as u32,
+            wasted_to_date: ProductValue {
+                products: self.wasted_products as u32,
+                priced_products: self.wasted_priced_products as u32,
+                value_cents: self.wasted_value_cents as u64,
+            },
+            on_vacation: self.on_vacation,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct WeeklyWasteEntity {
+    pub week_start: NaiveDate,
+    pub used_count: i32,
+    pub thrown_away_count: i32,
+    pub given_away_count: i32,
+}
+
+impl WeeklyWasteEntity {
+    pub fn into_domain(self) -> WeeklyOutcomes {
+        WeeklyOutcomes {
+            week: self.week_start,
+            used: self.used_count as u32,
+            thrown_away: self.thrown_away_count as u32,
+            given_away: self.given_away_count as u32,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct WasteStreakEntity {
+    pub current_weeks: i32,
+    pub longest_weeks: i32,
+    pub last_week: NaiveDate,
+}
+
+impl WasteStreakEntity {
+    pub fn into_domain(self) -> WasteStreak {
+        WasteStreak {
+            current: self.current_weeks as u32,
+            longest: self.longest_weeks as u32,
+            last_week: Some(self.last_week),
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+use business::domain::stats::model::{
+    InventorySnapshot, MonthlyOutcomes, ProductValue, WasteStreak, WeeklyOutcomes,
+};
+use business::domain::stats::repository::{
+    InventorySnapshotRepository, StatsRepository, WasteStreakRepository,
+};
+
+use super::entity::{InventorySnapshotEntity, WasteStreakEntity, WeeklyWasteEntity};
+use crate::db::ReadPool;
+
+/// Latest price paid per item name on the user's shopping trips, joined to
+/// their products by case-insensitive name.
+const VALUED_PRODUCTS: &str = r#"WITH prices AS (
+        SELECT DISTINCT ON (LOWER(item->>'name'))
+            LOWER(item->>'name') AS name,
+            (item->>'price_cents')::BIGINT AS price_cents
+        FROM shopping_trips, jsonb_array_elements(items) AS item
+        WHERE user_id = $1
+            AND item->>'price_cents' IS NOT NULL
+            AND item->>'bought_at' IS NOT NULL
+        ORDER BY LOWER(item->>'name'), (item->>'bought_at')::TIMESTAMPTZ DESC
+    ),
+    valued AS (
+        SELECT p.status, p.outcome, p.updated_at, prices.price_cents
+        FROM products p
+        LEFT JOIN prices ON prices.name = LOWER(p.name)
+        WHERE p.user_id = $1
+    )"#;
+
+/// Month, then products, priced products and value for waste and for donations.
+type OutcomeRow = (NaiveDate, i64, i64, i64, i64, i64, i64);
+
+/// Week, then products used, thrown away and given away.
+type WeeklyOutcomeRow = (NaiveDate, i64, i64, i64);
+
+pub struct StatsRepositoryPostgres {
+    pool: PgPool,
+    /// Listings and other stale-tolerant reads
+    reads: ReadPool,
+}
+
+impl StatsRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            reads: ReadPool::primary(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Sends stats and trend queries to `reads` instead of the primary.
+    pub fn with_reads(mut self, reads: ReadPool) -> Self {
+        self.reads = reads;
+        self
+    }
+}
+
+fn product_value(products: i64, priced_products: i64, value_cents: i64) -> ProductValue {
+    ProductValue {
+        products: products as u32,
+        priced_products: priced_products as u32,
+        value_cents: value_cents as u64,
+    }
+}
+
+#[async_trait]
+impl StatsRepository for StatsRepositoryPostgres {
+    async fn get_active_value(&self, user_id: &UserId) -> Result<ProductValue, RepositoryError> {
+        let (products, priced_products, value_cents): (i64, i64, i64) = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as(&format!(
+                    r#"{VALUED_PRODUCTS}
+                    SELECT COUNT(*), COUNT(price_cents), COALESCE(SUM(price_cents), 0)::BIGINT
+                    FROM valued WHERE status != 'finished'"#
+                ))
+                .bind(user_id.as_str())
+                .fetch_one(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(product_value(products, priced_products, value_cents))
+    }
+
+    async fn get_outcomes_by_month(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<MonthlyOutcomes>, RepositoryError> {
+        // Finished products are not edited anymore, so updated_at is when they left the pantry
+        let rows: Vec<OutcomeRow> = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as(&format!(
+                    r#"{VALUED_PRODUCTS}
+                    SELECT DATE_TRUNC('month', updated_at AT TIME ZONE 'UTC')::DATE AS month,
+                        COUNT(*) FILTER (WHERE outcome = 'thrown_away'),
+                        COUNT(price_cents) FILTER (WHERE outcome = 'thrown_away'),
+                        COALESCE(SUM(price_cents) FILTER (WHERE outcome = 'thrown_away'), 0)::BIGINT,
+                        COUNT(*) FILTER (WHERE outcome = 'given_away'),
+                        COUNT(price_cents) FILTER (WHERE outcome = 'given_away'),
+                        COALESCE(SUM(price_cents) FILTER (WHERE outcome = 'given_away'), 0)::BIGINT
+                    FROM valued
+                    WHERE status = 'finished'
+                        AND outcome IN ('thrown_away', 'given_away')
+                        AND updated_at >= $2
+                    GROUP BY month
+                    ORDER BY month"#
+                ))
+                .bind(user_id.as_str())
+                .bind(since)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(
+                |(month, wasted, wasted_priced, wasted_cents, given, given_priced, given_cents)| {
+                    MonthlyOutcomes {
+                        month,
+                        wasted: product_value(wasted, wasted_priced, wasted_cents),
+                        given_away: product_value(given, given_priced, given_cents),
+                    }
+                },
+            )
+            .collect())
+    }
+
+    async fn get_outcomes_by_week(
+        &self,
+        user_id: &UserId,
+        since: DateTime<Utc>,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<WeeklyOutcomes>, RepositoryError> {
+        // DATE_TRUNC weeks start on Monday, like the domain's
+        let rows: Vec<WeeklyOutcomeRow> = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as(
+                    r#"SELECT DATE_TRUNC('week', updated_at AT TIME ZONE 'UTC')::DATE AS week,
+                        COUNT(*) FILTER (WHERE outcome = 'used'),
+                        COUNT(*) FILTER (WHERE outcome = 'thrown_away'),
+                        COUNT(*) FILTER (WHERE outcome = 'given_away')
+                    FROM products
+                    WHERE user_id = $1
+                        AND status = 'finished'
+                        AND outcome IS NOT NULL
+                        AND updated_at >= $2
+                        AND updated_at < $3
+                    GROUP BY week
+                    ORDER BY week"#,
+                )
+                .bind(user_id.as_str())
+                .bind(since)
+                .bind(until)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(week, used, thrown_away, given_away)| WeeklyOutcomes {
+                week,
+                used: used as u32,
+                thrown_away: thrown_away as u32,
+                given_away: given_away as u32,
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl InventorySnapshotRepository for StatsRepositoryPostgres {
+    async fn save(
+        &self,
+        user_id: &UserId,
+        snapshot: &InventorySnapshot,
+    ) -> Result<(), RepositoryError> {
+        let status = &snapshot.by_status;
+        let location = &snapshot.by_location;
+        let wasted = &snapshot.wasted_to_date;
+        sqlx::query(
+            r#"INSERT INTO inventory_snapshots (user_id, snapshot_date, new_count, opened_count,
+                almost_empty_count, fridge_count, pantry_count, freezer_count, unassigned_count,
+                urgent_count, wasted_products, wasted_priced_products, wasted_value_cents, on_vacation,
+                recorded_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, NOW())
+            ON CONFLICT (user_id, snapshot_date) DO UPDATE SET
+                new_count = EXCLUDED.new_count,
+                opened_count = EXCLUDED.opened_count,
+                almost_empty_count = EXCLUDED.almost_empty_count,
+                fridge_count = EXCLUDED.fridge_count,
+                pantry_count = EXCLUDED.pantry_count,
+                freezer_count = EXCLUDED.freezer_count,
+                unassigned_count = EXCLUDED.unassigned_count,
+                urgent_count = EXCLUDED.urgent_count,
+                wasted_products = EXCLUDED.wasted_products,
+                wasted_priced_products = EXCLUDED.wasted_priced_products,
+                wasted_value_cents = EXCLUDED.wasted_value_cents,
+                on_vacation = EXCLUDED.on_vacation,
+                recorded_at = EXCLUDED.recorded_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(snapshot.date)
+        .bind(status.new as i32)
+        .bind(status.opened as i32)
+        .bind(status.almost_empty as i32)
+        .bind(location.fridge as i32)
+        .bind(location.pantry as i32)
+        .bind(location.freezer as i32)
+        .bind(location.unassigned as i32)
+        .bind(snapshot.urgent as i32)
+        .bind(wasted.products as i32)
+        .bind(wasted.priced_products as i32)
+        .bind(wasted.value_cents as i64)
+        .bind(snapshot.on_vacation)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_since(
+        &self,
+        user_id: &UserId,
+        since: NaiveDate,
+    ) -> Result<Vec<InventorySnapshot>, RepositoryError> {
+        let entities = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, InventorySnapshotEntity>(
+                    r#"SELECT snapshot_date, new_count, opened_count, almost_empty_count, fridge_count,
+                        pantry_count, freezer_count, unassigned_count, urgent_count, wasted_products,
+                        wasted_priced_products, wasted_value_cents, on_vacation
+                    FROM inventory_snapshots
+                    WHERE user_id = $1 AND snapshot_date >= $2
+                    ORDER BY snapshot_date"#,
+                )
+                .bind(user_id.as_str())
+                .bind(since)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+}
+
+#[async_trait]
+impl WasteStreakRepository for StatsRepositoryPostgres {
+    async fn get(&self, user_id: &UserId) -> Result<WasteStreak, RepositoryError> {
+        // From the primary: the job reads it back right before writing
+        let entity = sqlx::query_as::<_, WasteStreakEntity>(
+            "SELECT current_weeks, longest_weeks, last_week FROM waste_streaks WHERE user_id = $1",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()).unwrap_or_default())
+    }
+
+    async fn save(
+        &self,
+        user_id: &UserId,
+        streak: &WasteStreak,
+        weeks: &[WeeklyOutcomes],
+    ) -> Result<(), RepositoryError> {
+        let Some(last_week) = streak.last_week else {
+            return Ok(());
+        };
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        for week in weeks {
+            sqlx::query(
+                r#"INSERT INTO weekly_waste (user_id, week_start, used_count, thrown_away_count,
+                    given_away_count)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (user_id, week_start) DO UPDATE SET
+                    used_count = EXCLUDED.used_count,
+                    thrown_away_count = EXCLUDED.thrown_away_count,
+                    given_away_count = EXCLUDED.given_away_count"#,
+            )
+            .bind(user_id.as_str())
+            .bind(week.week)
+            .bind(week.used as i32)
+            .bind(week.thrown_away as i32)
+            .bind(week.given_away as i32)
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+        }
+
+        sqlx::query(
+            r#"INSERT INTO waste_streaks (user_id, current_weeks, longest_weeks, last_week, updated_at)
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (user_id) DO UPDATE SET
+                current_weeks = EXCLUDED.current_weeks,
+                longest_weeks = EXCLUDED.longest_weeks,
+                last_week = EXCLUDED.last_week,
+                updated_at = EXCLUDED.updated_at"#,
+        )
+        .bind(user_id.as_str())
+        .bind(streak.current as i32)
+        .bind(streak.longest as i32)
+        .bind(last_week)
+        .execute(&mut *tx)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn find_weeks_since(
+        &self,
+        user_id: &UserId,
+        since: NaiveDate,
+    ) -> Result<Vec<WeeklyOutcomes>, RepositoryError> {
+        let entities = self
+            .reads
+            .read(|pool| async move {
+                sqlx::query_as::<_, WeeklyWasteEntity>(
+                    r#"SELECT week_start, used_count, thrown_away_count, given_away_count
+                    FROM weekly_waste
+                    WHERE user_id = $1 AND week_start >= $2
+                    ORDER BY week_start"#,
+                )
+                .bind(user_id.as_str())
+                .bind(since)
+                .fetch_all(&pool)
+                .await
+            })
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+}
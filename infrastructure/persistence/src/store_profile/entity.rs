@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::store_profile::model::{Aisle, StoreProfile};
+
+/// JSON representation of an aisle stored inside a store profile.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AisleRecord {
+    pub name: String,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+impl From<&Aisle> for AisleRecord {
+    fn from(a: &Aisle) -> Self {
+        Self {
+            name: a.name.clone(),
+            keywords: a.keywords.clone(),
+        }
+    }
+}
+
+impl AisleRecord {
+    pub fn into_domain(self) -> Aisle {
+        Aisle {
+            name: self.name,
+            keywords: self.keywords,
+        }
+    }
+}
+
+#[derive(Debug, FromRow)]
+pub struct StoreProfileEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub name: String,
+    pub aisles: Json<Vec<AisleRecord>>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StoreProfileEntity {
+    pub fn into_domain(self) -> StoreProfile {
+        StoreProfile::from_repository(
+            self.id,
+            UserId::new(&self.user_id),
+            self.name,
+            self.aisles.0.into_iter().map(|a| a.into_domain()).collect(),
+            self.is_active,
+            self.created_at,
+            self.updated_at,
+        )
+    }
+}
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+use business::domain::store_profile::model::StoreProfile;
+use business::domain::store_profile::repository::StoreProfileRepository;
+
+use super::entity::{AisleRecord, StoreProfileEntity};
+use crate::db::write_error;
+
+pub struct StoreProfileRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl StoreProfileRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StoreProfileRepository for StoreProfileRepositoryPostgres {
+    async fn get_all(&self, user_id: &UserId) -> Result<Vec<StoreProfile>, RepositoryError> {
+        let entities = sqlx::query_as::<_, StoreProfileEntity>(
+            "SELECT id, user_id, name, aisles, is_active, created_at, updated_at FROM store_profiles WHERE user_id = $1 ORDER BY created_at",
+        )
+        .bind(user_id.as_str())
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities.into_iter().map(|e| e.into_domain()).collect())
+    }
+
+    async fn get_by_id(&self, id: Uuid, user_id: &UserId) -> Result<StoreProfile, RepositoryError> {
+        let entity = sqlx::query_as::<_, StoreProfileEntity>(
+            "SELECT id, user_id, name, aisles, is_active, created_at, updated_at FROM store_profiles WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?
+        .ok_or(RepositoryError::NotFound)?;
+
+        Ok(entity.into_domain())
+    }
+
+    async fn get_active(&self, user_id: &UserId) -> Result<Option<StoreProfile>, RepositoryError> {
+        let entity = sqlx::query_as::<_, StoreProfileEntity>(
+            "SELECT id, user_id, name, aisles, is_active, created_at, updated_at FROM store_profiles WHERE user_id = $1 AND is_active",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(|e| e.into_domain()))
+    }
+
+    async fn insert(&self, profile: &StoreProfile) -> Result<(), RepositoryError> {
+        let aisles: Vec<AisleRecord> = profile.aisles.iter().map(|a| a.into()).collect();
+
+        // is_active is only written through set_active
+        sqlx::query(
+            r#"INSERT INTO store_profiles (id, user_id, name, aisles, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)"#,
+        )
+        .bind(profile.id)
+        .bind(profile.user_id.as_str())
+        .bind(&profile.name)
+        .bind(Json(aisles))
+        .bind(profile.created_at)
+        .bind(profile.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, profile: &StoreProfile) -> Result<(), RepositoryError> {
+        let aisles: Vec<AisleRecord> = profile.aisles.iter().map(|a| a.into()).collect();
+
+        let result = sqlx::query(
+            r#"UPDATE store_profiles SET
+                name = $3,
+                aisles = $4,
+                updated_at = $5
+            WHERE id = $1 AND user_id = $2"#,
+        )
+        .bind(profile.id)
+        .bind(profile.user_id.as_str())
+        .bind(&profile.name)
+        .bind(Json(aisles))
+        .bind(profile.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM store_profiles WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn set_active(&self, id: Uuid, user_id: &UserId) -> Result<(), RepositoryError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        // Deactivate first so the one-active-per-user index never sees two rows
+        sqlx::query("UPDATE store_profiles SET is_active = FALSE WHERE user_id = $1 AND is_active")
+            .bind(user_id.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        let result = sqlx::query(
+            "UPDATE store_profiles SET is_active = TRUE WHERE id = $1 AND user_id = $2",
+        )
+        .bind(id)
+        .bind(user_id.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        tx.commit().await.map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::model::{
+    Suggestion, SuggestionBatch, SuggestionIngredient, TimeRange,
+};
+
+/// JSON representation of a suggestion ingredient stored inside a batch.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuggestionIngredientRecord {
+    pub product_id: String,
+    pub product_name: String,
+    pub quantity: Option<String>,
+    pub is_urgent: bool,
+}
+
+impl From<&SuggestionIngredient> for SuggestionIngredientRecord {
+    fn from(i: &SuggestionIngredient) -> Self {
+        Self {
+            product_id: i.product_id.clone(),
+            product_name: i.product_name.clone(),
+            quantity: i.quantity.clone(),
+            is_urgent: i.is_urgent,
+        }
+    }
+}
+
+impl SuggestionIngredientRecord {
+    pub fn into_domain(self) -> SuggestionIngredient {
+        SuggestionIngredient {
+            product_id: self.product_id,
+            product_name: self.product_name,
+            quantity: self.quantity,
+            is_urgent: self.is_urgent,
+        }
+    }
+}
+
+/// JSON representation of a suggestion stored inside a batch.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SuggestionRecord {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub estimated_time: String,
+    pub ingredients: Vec<SuggestionIngredientRecord>,
+    pub urgent_ingredients: Vec<String>,
+    pub steps: Option<Vec<String>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<&Suggestion> for SuggestionRecord {
+    fn from(s: &Suggestion) -> Self {
+        Self {
+            id: s.id.clone(),
+            title: s.title.clone(),
+            description: s.description.clone(),
+            estimated_time: s.estimated_time.to_string(),
+            ingredients: s.ingredients.iter().map(|i| i.into()).collect(),
+            urgent_ingredients: s.urgent_ingredients.clone(),
+            steps: s.steps.clone(),
+            created_at: s.created_at,
+        }
+    }
+}
+
+impl SuggestionRecord {
+    pub fn into_domain(self) -> Suggestion {
+        Suggestion {
+            id: self.id,
+            title: self.title,
+            description: self.description,
+            estimated_time: self
+                .estimated_time
+                .parse::<TimeRange>()
+                .unwrap_or(TimeRange::Medium),
+            ingredients: self
+                .ingredients
+                .into_iter()
+                .map(|i| i.into_domain())
+                .collect(),
+            urgent_ingredients: self.urgent_ingredients,
+            steps: self.steps,
+            created_at: self.created_at,
+        }
+    }
+}
+
+/// Current state of a product used as an ingredient.
+#[derive(Debug, FromRow)]
+pub struct IngredientProductEntity {
+    pub id: Uuid,
+    pub name: String,
+    pub quantity: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct SuggestionBatchEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub suggestions: Json<Vec<SuggestionRecord>>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl SuggestionBatchEntity {
+    pub fn into_domain(self) -> SuggestionBatch {
+        SuggestionBatch {
+            id: self.id,
+            user_id: UserId::new(&self.user_id),
+            suggestions: self
+                .suggestions
+                .0
+                .into_iter()
+                .map(|s| s.into_domain())
+                .collect(),
+            generated_at: self.generated_at,
+        }
+    }
+}
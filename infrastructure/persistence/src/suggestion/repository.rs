@@ -0,0 +1,258 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::PgPool;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::pagination::KeysetPage;
+use business::domain::shared::value_objects::UserId;
+use business::domain::suggestion::model::{Suggestion, SuggestionBatch};
+use business::domain::suggestion::repository::SuggestionRepository;
+
+use super::entity::{IngredientProductEntity, SuggestionBatchEntity, SuggestionRecord};
+use crate::db::escape_like;
+
+pub struct SuggestionRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl SuggestionRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Replaces the ingredient names and quantities stored with `batches` by
+    /// those of the products as they are now, in one query for all of them.
+    /// Ingredients whose product is gone keep the values from generation.
+    async fn hydrate(
+        &self,
+        user_id: &UserId,
+        mut batches: Vec<SuggestionBatch>,
+    ) -> Result<Vec<SuggestionBatch>, RepositoryError> {
+        let product_ids: Vec<Uuid> = batches
+            .iter()
+            .flat_map(|b| &b.suggestions)
+            .flat_map(|s| &s.ingredients)
+            .filter_map(|i| i.product_id.parse().ok())
+            .collect::<HashSet<Uuid>>()
+            .into_iter()
+            .collect();
+        if product_ids.is_empty() {
+            return Ok(batches);
+        }
+
+        let products: HashMap<Uuid, IngredientProductEntity> =
+            sqlx::query_as::<_, IngredientProductEntity>(
+                "SELECT p.id, p.name, p.quantity FROM products p \
+                 JOIN UNNEST($2::uuid[]) AS ingredient(id) ON ingredient.id = p.id \
+                 WHERE p.user_id = $1",
+            )
+            .bind(user_id.as_str())
+            .bind(&product_ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?
+            .into_iter()
+            .map(|p| (p.id, p))
+            .collect();
+
+        refresh_ingredients(&mut batches, &products);
+        Ok(batches)
+    }
+}
+
+fn refresh_ingredients(
+    batches: &mut [SuggestionBatch],
+    products: &HashMap<Uuid, IngredientProductEntity>,
+) {
+    let ingredients = batches
+        .iter_mut()
+        .flat_map(|b| &mut b.suggestions)
+        .flat_map(|s| &mut s.ingredients);
+    for ingredient in ingredients {
+        let current = ingredient
+            .product_id
+            .parse::<Uuid>()
+            .ok()
+            .and_then(|id| products.get(&id));
+        if let Some(product) = current {
+            ingredient.product_name = product.name.clone();
+            ingredient.quantity = product.quantity.clone();
+        }
+    }
+}
+
+#[async_trait]
+impl SuggestionRepository for SuggestionRepositoryPostgres {
+    async fn save_batch(
+        &self,
+        user_id: &UserId,
+        suggestions: &[Suggestion],
+    ) -> Result<(), RepositoryError> {
+        let records: Vec<SuggestionRecord> = suggestions.iter().map(|s| s.into()).collect();
+
+        sqlx::query(
+            "INSERT INTO suggestion_batches (id, user_id, suggestions, generated_at) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id.as_str())
+        .bind(Json(records))
+        .bind(Utc::now())
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_latest_batch(
+        &self,
+        user_id: &UserId,
+    ) -> Result<Option<SuggestionBatch>, RepositoryError> {
+        let entity = sqlx::query_as::<_, SuggestionBatchEntity>(
+            "SELECT id, user_id, suggestions, generated_at FROM suggestion_batches WHERE user_id = $1 ORDER BY generated_at DESC LIMIT 1",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        let batches = self
+            .hydrate(
+                user_id,
+                entity.map(|e| e.into_domain()).into_iter().collect(),
+            )
+            .await?;
+        Ok(batches.into_iter().next())
+    }
+
+    async fn get_batches(
+        &self,
+        user_id: &UserId,
+        page: &KeysetPage,
+    ) -> Result<Vec<SuggestionBatch>, RepositoryError> {
+        let (after_generated_at, after_id) = page
+            .after
+            .map(|cursor| (cursor.created_at, cursor.id))
+            .unzip();
+
+        let entities = sqlx::query_as::<_, SuggestionBatchEntity>(
+            "SELECT id, user_id, suggestions, generated_at FROM suggestion_batches \
+             WHERE user_id = $1 AND ($2::timestamptz IS NULL OR (generated_at, id) < ($2, $3)) \
+             ORDER BY generated_at DESC, id DESC LIMIT $4",
+        )
+        .bind(user_id.as_str())
+        .bind(after_generated_at)
+        .bind(after_id)
+        .bind(i64::from(page.fetch_limit()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        self.hydrate(
+            user_id,
+            entities.into_iter().map(|e| e.into_domain()).collect(),
+        )
+        .await
+    }
+
+    async fn search(
+        &self,
+        user_id: &UserId,
+        term: &str,
+        limit: u32,
+    ) -> Result<Vec<Suggestion>, RepositoryError> {
+        let rows: Vec<(Json<SuggestionRecord>,)> = sqlx::query_as(
+            r#"SELECT suggestion FROM (
+                SELECT DISTINCT ON (LOWER(s.value->>'title')) s.value AS suggestion, b.generated_at
+                FROM suggestion_batches b, jsonb_array_elements(b.suggestions) AS s
+                WHERE b.user_id = $1 AND s.value->>'title' ILIKE $2
+                ORDER BY LOWER(s.value->>'title'), b.generated_at DESC
+            ) latest
+            ORDER BY generated_at DESC
+            LIMIT $3"#,
+        )
+        .bind(user_id.as_str())
+        .bind(format!("%{}%", escape_like(term)))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        // Hydrated as one batch so the ingredients are looked up in a single query
+        let found = SuggestionBatch {
+            id: Uuid::nil(),
+            user_id: user_id.clone(),
+            suggestions: rows.into_iter().map(|(r,)| r.0.into_domain()).collect(),
+            generated_at: Utc::now(),
+        };
+        let batches = self.hydrate(user_id, vec![found]).await?;
+        Ok(batches
+            .into_iter()
+            .flat_map(|batch| batch.suggestions)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use business::domain::suggestion::model::{SuggestionIngredient, TimeRange};
+
+    fn ingredient(product_id: &str, name: &str) -> SuggestionIngredient {
+        SuggestionIngredient {
+            product_id: product_id.to_string(),
+            product_name: name.to_string(),
+            quantity: Some("1 L".to_string()),
+            is_urgent: true,
+        }
+    }
+
+    #[test]
+    fn should_refresh_ingredients_whose_product_still_exists() {
+        // Arrange
+        let kept = Uuid::new_v4();
+        let deleted = Uuid::new_v4();
+        let mut batches = vec![SuggestionBatch {
+            id: Uuid::new_v4(),
+            user_id: UserId::new("test-user-id"),
+            suggestions: vec![Suggestion {
+                id: "s1".to_string(),
+                title: "Tortilla".to_string(),
+                description: None,
+                estimated_time: TimeRange::Quick,
+                ingredients: vec![
+                    ingredient(&kept.to_string(), "Leche"),
+                    ingredient(&deleted.to_string(), "Huevos"),
+                    ingredient("not-a-uuid", "Sal"),
+                ],
+                urgent_ingredients: vec![],
+                steps: None,
+                created_at: Utc::now(),
+            }],
+            generated_at: Utc::now(),
+        }];
+        let products = HashMap::from([(
+            kept,
+            IngredientProductEntity {
+                id: kept,
+                name: "Leche semidesnatada".to_string(),
+                quantity: None,
+            },
+        )]);
+
+        // Act
+        refresh_ingredients(&mut batches, &products);
+
+        // Assert
+        let ingredients = &batches[0].suggestions[0].ingredients;
+        assert_eq!(ingredients[0].product_name, "Leche semidesnatada");
+        assert_eq!(ingredients[0].quantity, None);
+        assert!(ingredients[0].is_urgent);
+        assert_eq!(ingredients[1].product_name, "Huevos");
+        assert_eq!(ingredients[2].product_name, "Sal");
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::vacation::model::Vacation;
+
+#[derive(Debug, FromRow)]
+pub struct VacationEntity {
+    pub id: Uuid,
+    pub user_id: String,
+    pub started_at: DateTime<Utc>,
+    pub until: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl VacationEntity {
+    pub fn into_domain(self) -> Vacation {
+        Vacation::from_repository(
+            self.id,
+            UserId::new(self.user_id),
+            self.started_at,
+            self.until,
+            self.ended_at,
+        )
+    }
+}
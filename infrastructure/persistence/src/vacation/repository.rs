@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+use business::domain::vacation::model::Vacation;
+use business::domain::vacation::repository::VacationRepository;
+
+use super::entity::VacationEntity;
+use crate::db::write_error;
+
+pub struct VacationRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl VacationRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl VacationRepository for VacationRepositoryPostgres {
+    async fn find_current(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError> {
+        let entity = sqlx::query_as::<_, VacationEntity>(
+            "SELECT id, user_id, started_at, until, ended_at FROM vacations WHERE user_id = $1 AND ended_at IS NULL",
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(VacationEntity::into_domain))
+    }
+
+    async fn find_latest(&self, user_id: &UserId) -> Result<Option<Vacation>, RepositoryError> {
+        let entity = sqlx::query_as::<_, VacationEntity>(
+            r#"SELECT id, user_id, started_at, until, ended_at FROM vacations
+            WHERE user_id = $1
+            ORDER BY started_at DESC
+            LIMIT 1"#,
+        )
+        .bind(user_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.map(VacationEntity::into_domain))
+    }
+
+    async fn find_due(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<Vacation>, RepositoryError> {
+        let entities = sqlx::query_as::<_, VacationEntity>(
+            r#"SELECT id, user_id, started_at, until, ended_at FROM vacations
+            WHERE ended_at IS NULL AND until <= $1
+            ORDER BY until
+            LIMIT $2"#,
+        )
+        .bind(now)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entities
+            .into_iter()
+            .map(VacationEntity::into_domain)
+            .collect())
+    }
+
+    async fn insert(&self, vacation: &Vacation) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO vacations (id, user_id, started_at, until, ended_at)
+            VALUES ($1, $2, $3, $4, $5)"#,
+        )
+        .bind(vacation.id)
+        .bind(vacation.user_id.as_str())
+        .bind(vacation.started_at)
+        .bind(vacation.until)
+        .bind(vacation.ended_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        Ok(())
+    }
+
+    async fn update(&self, vacation: &Vacation) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE vacations SET until = $3, ended_at = $4 WHERE id = $1 AND user_id = $2",
+        )
+        .bind(vacation.id)
+        .bind(vacation.user_id.as_str())
+        .bind(vacation.until)
+        .bind(vacation.ended_at)
+        .execute(&self.pool)
+        .await
+        .map_err(write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sqlx::FromRow;
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use business::domain::widget::model::{WidgetItem, WidgetProduct, WidgetSnapshot};
+
+#[derive(Debug, Deserialize)]
+pub struct WidgetProductRecord {
+    pub id: Uuid,
+    pub name: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WidgetItemRecord {
+    pub id: Uuid,
+    pub name: String,
+}
+
+/// The whole snapshot as one row, lists aggregated to JSON.
+#[derive(Debug, FromRow)]
+pub struct WidgetSnapshotEntity {
+    pub urgent_products: Json<Vec<WidgetProductRecord>>,
+    pub urgent_total: i64,
+    pub shopping_items: Json<Vec<WidgetItemRecord>>,
+    pub unbought_total: i64,
+}
+
+impl WidgetSnapshotEntity {
+    pub fn into_domain(self) -> WidgetSnapshot {
+        WidgetSnapshot {
+            urgent_products: self
+                .urgent_products
+                .0
+                .into_iter()
+                .map(|p| WidgetProduct {
+                    id: p.id,
+                    name: p.name,
+                    expires_at: p.expires_at,
+                })
+                .collect(),
+            urgent_total: self.urgent_total as u32,
+            shopping_items: self
+                .shopping_items
+                .0
+                .into_iter()
+                .map(|i| WidgetItem {
+                    id: i.id,
+                    name: i.name,
+                })
+                .collect(),
+            unbought_total: self.unbought_total as u32,
+        }
+    }
+}
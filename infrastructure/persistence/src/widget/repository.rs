@@ -0,0 +1,100 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use business::domain::errors::RepositoryError;
+use business::domain::shared::value_objects::UserId;
+use business::domain::widget::model::{
+    WIDGET_SHOPPING_ITEMS, WIDGET_URGENT_PRODUCTS, WidgetSnapshot, WidgetToken, WidgetWindow,
+};
+use business::domain::widget::repository::WidgetRepository;
+
+use super::entity::WidgetSnapshotEntity;
+
+pub struct WidgetRepositoryPostgres {
+    pool: PgPool,
+}
+
+impl WidgetRepositoryPostgres {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl WidgetRepository for WidgetRepositoryPostgres {
+    async fn find_owner(&self, token_hash: &str) -> Result<Option<UserId>, RepositoryError> {
+        let user_id: Option<String> =
+            sqlx::query_scalar("SELECT user_id FROM widget_tokens WHERE token_hash = $1")
+                .bind(token_hash)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(RepositoryError::database_error)?;
+
+        Ok(user_id.map(UserId::new))
+    }
+
+    async fn save_token(&self, token: &WidgetToken) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"INSERT INTO widget_tokens (user_id, token_hash, created_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id) DO UPDATE
+            SET token_hash = EXCLUDED.token_hash, created_at = EXCLUDED.created_at"#,
+        )
+        .bind(token.user_id.as_str())
+        .bind(&token.token_hash)
+        .bind(token.created_at)
+        .execute(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn delete_token(&self, user_id: &UserId) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM widget_tokens WHERE user_id = $1")
+            .bind(user_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(RepositoryError::database_error)?;
+
+        Ok(())
+    }
+
+    async fn get_snapshot(
+        &self,
+        user_id: &UserId,
+        window: &WidgetWindow,
+    ) -> Result<WidgetSnapshot, RepositoryError> {
+        let entity = sqlx::query_as::<_, WidgetSnapshotEntity>(
+            r#"WITH urgent AS (
+                SELECT id, name, COALESCE(expiry_date, estimated_expiry_date) AS expires_at
+                FROM products
+                WHERE user_id = $1 AND status != 'finished'
+                AND COALESCE(expiry_date, estimated_expiry_date) >= $2
+                AND COALESCE(expiry_date, estimated_expiry_date) < $3
+            ), unbought AS (
+                SELECT id, name, created_at FROM shopping_items
+                WHERE user_id = $1 AND is_bought = FALSE
+            )
+            SELECT
+                COALESCE((SELECT json_agg(p) FROM (
+                    SELECT id, name, expires_at FROM urgent ORDER BY expires_at, name LIMIT $4
+                ) p), '[]'::json) AS urgent_products,
+                (SELECT COUNT(*) FROM urgent) AS urgent_total,
+                COALESCE((SELECT json_agg(i) FROM (
+                    SELECT id, name FROM unbought ORDER BY created_at, name LIMIT $5
+                ) i), '[]'::json) AS shopping_items,
+                (SELECT COUNT(*) FROM unbought) AS unbought_total"#,
+        )
+        .bind(user_id.as_str())
+        .bind(window.now)
+        .bind(window.urgent_until)
+        .bind(i64::from(WIDGET_URGENT_PRODUCTS))
+        .bind(i64::from(WIDGET_SHOPPING_ITEMS))
+        .fetch_one(&self.pool)
+        .await
+        .map_err(RepositoryError::database_error)?;
+
+        Ok(entity.into_domain())
+    }
+}
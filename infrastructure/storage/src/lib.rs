@@ -0,0 +1,4 @@
+pub mod local;
+pub mod s3;
+pub mod sigv4;
+pub mod thumbnail;
@@ -0,0 +1,287 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use business::domain::storage::errors::StorageError;
+use business::domain::storage::model::{SignedUrl, StoredObject};
+use business::domain::storage::services::BlobStorage;
+
+use crate::sigv4::uri_encode;
+
+/// Path the API serves signed local downloads from.
+pub const DOWNLOAD_PATH: &str = "/blobs";
+
+/// Suffix of the file next to each object that holds its media type.
+const CONTENT_TYPE_SUFFIX: &str = ".content-type";
+
+const DEFAULT_CONTENT_TYPE: &str = "application/octet-stream";
+
+#[derive(Debug, Clone)]
+pub struct LocalDiskSettings {
+    /// Directory objects are written under
+    pub root: PathBuf,
+    /// Base URL of this API as clients reach it, e.g. "https://api.example.com"
+    pub public_url: String,
+    /// Key signing download URLs
+    pub signing_secret: String,
+    /// How long signed URLs stay valid
+    pub url_ttl: Duration,
+}
+
+/// Objects as files under a directory, for development and single-node
+/// deployments. Downloads go through this API at [`DOWNLOAD_PATH`], with an
+/// HMAC over the key and expiry in place of a token.
+pub struct LocalDiskStorage {
+    settings: LocalDiskSettings,
+}
+
+impl LocalDiskStorage {
+    pub fn new(settings: LocalDiskSettings) -> Self {
+        Self { settings }
+    }
+
+    /// File of a key. Keys are generated by the API, but downloads carry one
+    /// from the query string, so anything that could leave `root` is refused.
+    fn path_of(&self, key: &str) -> Result<PathBuf, StorageError> {
+        let valid = !key.is_empty()
+            && key.split('/').all(|segment| {
+                !segment.is_empty()
+                    && segment != "."
+                    && segment != ".."
+                    && segment
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+            });
+        if !valid {
+            return Err(StorageError::NotFound);
+        }
+        Ok(self.settings.root.join(key))
+    }
+
+    fn sign(&self, key: &str, expires_at: i64) -> Hmac<Sha256> {
+        // HMAC accepts keys of any length
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.settings.signing_secret.as_bytes())
+            .expect("HMAC key of any size");
+        mac.update(key.as_bytes());
+        mac.update(b"\n");
+        mac.update(expires_at.to_string().as_bytes());
+        mac
+    }
+
+    fn verify_at(
+        &self,
+        key: &str,
+        expires_at: i64,
+        signature: &str,
+        now: DateTime<Utc>,
+    ) -> Result<(), StorageError> {
+        if expires_at < now.timestamp() {
+            return Err(StorageError::InvalidSignature);
+        }
+        let expected = hex::decode(signature).map_err(|_| StorageError::InvalidSignature)?;
+        // Constant-time comparison
+        self.sign(key, expires_at)
+            .verify_slice(&expected)
+            .map_err(|_| StorageError::InvalidSignature)
+    }
+}
+
+fn content_type_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(CONTENT_TYPE_SUFFIX);
+    PathBuf::from(name)
+}
+
+async fn remove_if_present(path: &Path) -> Result<(), StorageError> {
+    match tokio::fs::remove_file(path).await {
+        Err(e) if e.kind() != ErrorKind::NotFound => Err(StorageError::unavailable(e)),
+        _ => Ok(()),
+    }
+}
+
+#[async_trait]
+impl BlobStorage for LocalDiskStorage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError> {
+        let path = self.path_of(key)?;
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir)
+                .await
+                .map_err(StorageError::unavailable)?;
+        }
+
+        // Written aside and renamed, so readers never see half a file
+        let partial = path.with_extension("partial");
+        tokio::fs::write(&partial, bytes)
+            .await
+            .map_err(StorageError::unavailable)?;
+        tokio::fs::write(content_type_path(&path), content_type)
+            .await
+            .map_err(StorageError::unavailable)?;
+        tokio::fs::rename(&partial, &path)
+            .await
+            .map_err(StorageError::unavailable)
+    }
+
+    async fn get(&self, key: &str) -> Result<StoredObject, StorageError> {
+        let path = self.path_of(key)?;
+        let bytes = tokio::fs::read(&path).await.map_err(|e| match e.kind() {
+            ErrorKind::NotFound => StorageError::NotFound,
+            _ => StorageError::unavailable(e),
+        })?;
+        let content_type = tokio::fs::read_to_string(content_type_path(&path))
+            .await
+            .unwrap_or_else(|_| DEFAULT_CONTENT_TYPE.to_string());
+
+        Ok(StoredObject {
+            bytes,
+            content_type,
+        })
+    }
+
+    async fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        let path = self.path_of(key)?;
+        tokio::fs::try_exists(&path)
+            .await
+            .map_err(StorageError::unavailable)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let path = self.path_of(key)?;
+        remove_if_present(&path).await?;
+        remove_if_present(&content_type_path(&path)).await
+    }
+
+    fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError> {
+        let expires_at = Utc::now() + self.settings.url_ttl;
+        let signature = hex::encode(
+            self.sign(key, expires_at.timestamp())
+                .finalize()
+                .into_bytes(),
+        );
+
+        Ok(SignedUrl {
+            url: format!(
+                "{}{DOWNLOAD_PATH}?key={}&expires={}&signature={signature}",
+                self.settings.public_url.trim_end_matches('/'),
+                uri_encode(key, false),
+                expires_at.timestamp()
+            ),
+            expires_at,
+        })
+    }
+
+    fn verify_signature(
+        &self,
+        key: &str,
+        expires_at: i64,
+        signature: &str,
+    ) -> Result<(), StorageError> {
+        self.verify_at(key, expires_at, signature, Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage(root: PathBuf) -> LocalDiskStorage {
+        LocalDiskStorage::new(LocalDiskSettings {
+            root,
+            public_url: "https://api.example.com/".to_string(),
+            signing_secret: "test-secret".to_string(),
+            url_ttl: Duration::minutes(15),
+        })
+    }
+
+    fn temp_root(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("foodie-blobs-{name}-{}", std::process::id()))
+    }
+
+    /// Splits a signed URL back into the query values the download route reads.
+    fn query_of(url: &str) -> (String, i64, String) {
+        let query = url.split_once('?').unwrap().1;
+        let value = |name: &str| {
+            query
+                .split('&')
+                .find_map(|pair| pair.strip_prefix(&format!("{name}=")))
+                .unwrap()
+                .to_string()
+        };
+        (
+            value("key").replace("%2F", "/"),
+            value("expires").parse().unwrap(),
+            value("signature"),
+        )
+    }
+
+    #[test]
+    fn should_accept_own_signature_until_it_expires() {
+        let storage = storage(temp_root("sign"));
+        let signed = storage.signed_url("products/user-1/abc").unwrap();
+        let (key, expires_at, signature) = query_of(&signed.url);
+
+        assert!(
+            signed
+                .url
+                .starts_with("https://api.example.com/blobs?key=products%2Fuser-1%2Fabc")
+        );
+        assert!(
+            storage
+                .verify_at(&key, expires_at, &signature, Utc::now())
+                .is_ok()
+        );
+        assert!(matches!(
+            storage.verify_at(
+                &key,
+                expires_at,
+                &signature,
+                Utc::now() + Duration::hours(1)
+            ),
+            Err(StorageError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn should_reject_signature_for_another_key() {
+        let storage = storage(temp_root("tamper"));
+        let signed = storage.signed_url("products/user-1/abc").unwrap();
+        let (_, expires_at, signature) = query_of(&signed.url);
+
+        let result = storage.verify_at("products/user-2/abc", expires_at, &signature, Utc::now());
+
+        assert!(matches!(result, Err(StorageError::InvalidSignature)));
+    }
+
+    #[test]
+    fn should_refuse_keys_that_leave_the_root() {
+        let storage = storage(temp_root("escape"));
+
+        assert!(storage.path_of("../etc/passwd").is_err());
+        assert!(storage.path_of("/etc/passwd").is_err());
+        assert!(storage.path_of("products//abc").is_err());
+        assert!(storage.path_of("products/user-1/abc").is_ok());
+    }
+
+    #[tokio::test]
+    async fn should_read_back_stored_object_until_deleted() {
+        let root = temp_root("roundtrip");
+        let storage = storage(root.clone());
+
+        storage
+            .put("receipts/user-1/abc", vec![1, 2, 3], "image/png")
+            .await
+            .unwrap();
+        let object = storage.get("receipts/user-1/abc").await.unwrap();
+        storage.delete("receipts/user-1/abc").await.unwrap();
+
+        assert_eq!(object.bytes, vec![1, 2, 3]);
+        assert_eq!(object.content_type, "image/png");
+        assert!(!storage.exists("receipts/user-1/abc").await.unwrap());
+        assert!(storage.delete("receipts/user-1/abc").await.is_ok());
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
@@ -0,0 +1,233 @@
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+use reqwest::{Client, Method, StatusCode, Url};
+
+use business::domain::storage::errors::StorageError;
+use business::domain::storage::model::{SignedUrl, StoredObject};
+use business::domain::storage::services::BlobStorage;
+
+use crate::sigv4::{
+    Credentials, SignableRequest, UNSIGNED_PAYLOAD, authorization_headers, presigned_query,
+    sha256_hex, uri_encode,
+};
+
+#[derive(Debug, Clone)]
+pub struct S3Settings {
+    /// Service endpoint, e.g. "https://s3.eu-west-1.amazonaws.com",
+    /// "https://storage.googleapis.com" or a MinIO URL
+    pub endpoint: String,
+    pub bucket: String,
+    /// Signing region; "auto" for Google Cloud Storage and Cloudflare R2
+    pub region: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// How long presigned URLs stay valid (at most 7 days)
+    pub url_ttl: Duration,
+}
+
+/// Objects in a bucket of any store speaking the S3 API: AWS S3, MinIO,
+/// Cloudflare R2, or Google Cloud Storage through its XML API with HMAC keys.
+/// Requests are signed with SigV4 and use path-style addressing
+/// (`<endpoint>/<bucket>/<key>`), which every one of them accepts. Clients
+/// download straight from the store through presigned URLs.
+pub struct S3Storage {
+    client: Client,
+    settings: S3Settings,
+}
+
+impl S3Storage {
+    pub fn new(settings: S3Settings) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self { client, settings }
+    }
+
+    fn credentials(&self) -> Credentials<'_> {
+        Credentials {
+            access_key_id: &self.settings.access_key_id,
+            secret_access_key: &self.settings.secret_access_key,
+            region: &self.settings.region,
+        }
+    }
+
+    /// URL of an object, with its URI-encoded path and the `Host` value to sign.
+    fn object_url(&self, key: &str) -> Result<(Url, String, String), StorageError> {
+        let path = format!(
+            "/{}/{}",
+            uri_encode(&self.settings.bucket, false),
+            uri_encode(key, true)
+        );
+        let url = Url::parse(&format!(
+            "{}{path}",
+            self.settings.endpoint.trim_end_matches('/')
+        ))
+        .map_err(StorageError::unavailable)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{host}:{port}"),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err(StorageError::unavailable("S3 endpoint has no host")),
+        };
+        // The endpoint may carry a path prefix of its own
+        let path = url.path().to_string();
+
+        Ok((url, host, path))
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        body: Option<(Vec<u8>, &str)>,
+    ) -> Result<reqwest::Response, StorageError> {
+        let (url, host, path) = self.object_url(key)?;
+        let payload_hash = match &body {
+            Some((bytes, _)) => sha256_hex(bytes),
+            None => sha256_hex(b""),
+        };
+        let headers = authorization_headers(
+            &SignableRequest {
+                method: method.as_str(),
+                host: &host,
+                path: &path,
+                query: Vec::new(),
+                payload_hash: &payload_hash,
+            },
+            Utc::now(),
+            &self.credentials(),
+        );
+
+        let mut request = self.client.request(method, url);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+        if let Some((bytes, content_type)) = body {
+            request = request.header("content-type", content_type).body(bytes);
+        }
+
+        request.send().await.map_err(StorageError::unavailable)
+    }
+}
+
+fn unexpected_status(status: StatusCode) -> StorageError {
+    StorageError::unavailable(format!("object store answered {status}"))
+}
+
+#[async_trait]
+impl BlobStorage for S3Storage {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> Result<(), StorageError> {
+        let response = self
+            .send(Method::PUT, key, Some((bytes, content_type)))
+            .await?;
+        if !response.status().is_success() {
+            return Err(unexpected_status(response.status()));
+        }
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<StoredObject, StorageError> {
+        let response = self.send(Method::GET, key, None).await?;
+        match response.status() {
+            status if status.is_success() => {}
+            StatusCode::NOT_FOUND => return Err(StorageError::NotFound),
+            status => return Err(unexpected_status(status)),
+        }
+
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("application/octet-stream")
+            .to_string();
+        let bytes = response.bytes().await.map_err(StorageError::unavailable)?;
+
+        Ok(StoredObject {
+            bytes: bytes.to_vec(),
+            content_type,
+        })
+    }
+
+    /// Needs list permission on the bucket: without it, S3 answers `403`
+    /// rather than `404` for missing keys.
+    async fn exists(&self, key: &str) -> Result<bool, StorageError> {
+        let response = self.send(Method::HEAD, key, None).await?;
+        match response.status() {
+            status if status.is_success() => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            status => Err(unexpected_status(status)),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let response = self.send(Method::DELETE, key, None).await?;
+        match response.status() {
+            status if status.is_success() => Ok(()),
+            StatusCode::NOT_FOUND => Ok(()),
+            status => Err(unexpected_status(status)),
+        }
+    }
+
+    fn signed_url(&self, key: &str) -> Result<SignedUrl, StorageError> {
+        let (mut url, host, path) = self.object_url(key)?;
+        let now = Utc::now();
+        let query = presigned_query(
+            &SignableRequest {
+                method: "GET",
+                host: &host,
+                path: &path,
+                query: Vec::new(),
+                payload_hash: UNSIGNED_PAYLOAD,
+            },
+            self.settings.url_ttl.num_seconds(),
+            now,
+            &self.credentials(),
+        );
+        url.set_query(Some(&query));
+
+        Ok(SignedUrl {
+            url: url.to_string(),
+            expires_at: now + self.settings.url_ttl,
+        })
+    }
+
+    /// Presigned URLs point at the store, never back at this API.
+    fn verify_signature(
+        &self,
+        _key: &str,
+        _expires_at: i64,
+        _signature: &str,
+    ) -> Result<(), StorageError> {
+        Err(StorageError::InvalidSignature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn storage(endpoint: &str) -> S3Storage {
+        S3Storage::new(S3Settings {
+            endpoint: endpoint.to_string(),
+            bucket: "foodie-uploads".to_string(),
+            region: "auto".to_string(),
+            access_key_id: "GOOG1EXAMPLE".to_string(),
+            secret_access_key: "secret".to_string(),
+            url_ttl: Duration::minutes(15),
+        })
+    }
+
+    #[test]
+    fn should_presign_path_style_url_with_endpoint_port() {
+        let storage = storage("http://localhost:9000/");
+
+        let signed = storage.signed_url("products/user-1/abc").unwrap();
+
+        assert!(signed.url.starts_with(
+            "http://localhost:9000/foodie-uploads/products/user-1/abc?X-Amz-Algorithm=AWS4-HMAC-SHA256"
+        ));
+        assert!(signed.url.contains("X-Amz-Expires=900"));
+        assert!(signed.url.contains("X-Amz-Signature="));
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageReader};
+
+use business::domain::storage::errors::StorageError;
+use business::domain::storage::services::ThumbnailService;
+
+#[derive(Debug, Clone, Copy)]
+pub struct ThumbnailSettings {
+    /// Longest side of a thumbnail, in pixels
+    pub max_side: u32,
+    /// JPEG quality, 1–100
+    pub quality: u8,
+}
+
+impl Default for ThumbnailSettings {
+    fn default() -> Self {
+        Self {
+            max_side: 320,
+            quality: 80,
+        }
+    }
+}
+
+/// Thumbnails resized with the `image` crate: turned upright from the photo's
+/// EXIF orientation, scaled to fit `max_side` keeping the aspect ratio, and
+/// encoded as JPEG. Photos already smaller are re-encoded but not enlarged.
+pub struct ImageThumbnailer {
+    settings: ThumbnailSettings,
+}
+
+impl ImageThumbnailer {
+    pub fn new(settings: ThumbnailSettings) -> Self {
+        Self { settings }
+    }
+
+    fn decode(image: &[u8]) -> Result<DynamicImage, StorageError> {
+        let mut decoder = ImageReader::new(Cursor::new(image))
+            .with_guessed_format()
+            .map_err(|_| StorageError::UnreadableImage)?
+            .into_decoder()
+            .map_err(|_| StorageError::UnreadableImage)?;
+        let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+        let mut decoded =
+            DynamicImage::from_decoder(decoder).map_err(|_| StorageError::UnreadableImage)?;
+        decoded.apply_orientation(orientation);
+        Ok(decoded)
+    }
+}
+
+impl ThumbnailService for ImageThumbnailer {
+    fn thumbnail(&self, image: &[u8]) -> Result<Vec<u8>, StorageError> {
+        let decoded = Self::decode(image)?;
+        let side = self.settings.max_side;
+        let resized = if decoded.width() > side || decoded.height() > side {
+            decoded.thumbnail(side, side)
+        } else {
+            decoded
+        };
+
+        // JPEG has no alpha channel
+        let rgb = resized.into_rgb8();
+        let mut out = Vec::new();
+        JpegEncoder::new_with_quality(&mut out, self.settings.quality)
+            .encode_image(&rgb)
+            .map_err(StorageError::unavailable)?;
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageFormat, RgbaImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        RgbaImage::new(width, height)
+            .write_to(&mut Cursor::new(&mut out), ImageFormat::Png)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn should_shrink_to_fit_keeping_aspect_ratio() {
+        let thumbnailer = ImageThumbnailer::new(ThumbnailSettings {
+            max_side: 100,
+            quality: 80,
+        });
+
+        let thumbnail = thumbnailer.thumbnail(&png(400, 200)).unwrap();
+
+        let decoded = image::load_from_memory(&thumbnail).unwrap();
+        assert_eq!(image::guess_format(&thumbnail).unwrap(), ImageFormat::Jpeg);
+        assert_eq!((decoded.width(), decoded.height()), (100, 50));
+    }
+
+    #[test]
+    fn should_not_enlarge_small_photos() {
+        let thumbnailer = ImageThumbnailer::new(ThumbnailSettings::default());
+
+        let thumbnail = thumbnailer.thumbnail(&png(40, 30)).unwrap();
+
+        let decoded = image::load_from_memory(&thumbnail).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (40, 30));
+    }
+
+    #[test]
+    fn should_reject_bytes_that_are_not_a_photo() {
+        let thumbnailer = ImageThumbnailer::new(ThumbnailSettings::default());
+
+        let result = thumbnailer.thumbnail(b"%PDF-1.7");
+
+        assert!(matches!(result, Err(StorageError::UnreadableImage)));
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::ai_review::model::{
+    AiChange, AiWriteMode, PendingAiChange, PendingProductChanges,
+};
+
+use crate::api::examples::example_date;
+use crate::api::product::dto::ProductResponse;
+
+/// How AI-derived product facts are written.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum AiWriteModeDto {
+    /// Applied to the product straight away
+    #[oai(rename = "auto")]
+    Auto,
+    /// Staged as pending changes until the user accepts them
+    #[oai(rename = "review")]
+    Review,
+}
+
+impl From<AiWriteMode> for AiWriteModeDto {
+    fn from(mode: AiWriteMode) -> Self {
+        match mode {
+            AiWriteMode::Auto => AiWriteModeDto::Auto,
+            AiWriteMode::Review => AiWriteModeDto::Review,
+        }
+    }
+}
+
+impl From<AiWriteModeDto> for AiWriteMode {
+    fn from(dto: AiWriteModeDto) -> Self {
+        match dto {
+            AiWriteModeDto::Auto => AiWriteMode::Auto,
+            AiWriteModeDto::Review => AiWriteMode::Review,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct AiWriteSettingsDto {
+    /// Whether AI estimations are applied automatically or staged for review
+    pub mode: AiWriteModeDto,
+}
+
+/// Product field an AI change writes.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum AiChangeFieldDto {
+    #[oai(rename = "estimated_expiry_date")]
+    EstimatedExpiryDate,
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct PendingAiChangeResponse {
+    /// Pending change identifier, used to accept or reject it
+    pub id: String,
+    /// Product field the change writes
+    pub field: AiChangeFieldDto,
+    /// Proposed estimated expiry date (when field is `estimated_expiry_date`)
+    #[oai(skip_serializing_if_is_none)]
+    pub estimated_expiry_date: Option<DateTime<Utc>>,
+    /// When the AI proposed the change
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<PendingAiChange> for PendingAiChangeResponse {
+    fn from(pending: PendingAiChange) -> Self {
+        let (field, estimated_expiry_date) = match pending.change {
+            AiChange::EstimatedExpiryDate(date) => {
+                (AiChangeFieldDto::EstimatedExpiryDate, Some(date))
+            }
+        };
+        Self {
+            id: pending.id.to_string(),
+            field,
+            estimated_expiry_date,
+            created_at: pending.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PendingProductChangesResponse {
+    /// The product as currently stored
+    pub product: ProductResponse,
+    /// Changes waiting for confirmation, oldest first
+    pub changes: Vec<PendingAiChangeResponse>,
+}
+
+impl From<PendingProductChanges> for PendingProductChangesResponse {
+    fn from(pending: PendingProductChanges) -> Self {
+        Self {
+            product: pending.product.into(),
+            changes: pending.changes.into_iter().map(|c| c.into()).collect(),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for AiWriteSettingsDto {
+    fn example() -> Self {
+        Self {
+            mode: AiWriteModeDto::Review,
+        }
+    }
+}
+
+impl Example for PendingProductChangesResponse {
+    fn example() -> Self {
+        let mut product = ProductResponse::example();
+        product.expiry_date = None;
+        Self {
+            product,
+            changes: vec![PendingAiChangeResponse {
+                id: "9c1d2e3f-4a5b-4c6d-8e7f-0a1b2c3d4e5f".to_string(),
+                field: AiChangeFieldDto::EstimatedExpiryDate,
+                estimated_expiry_date: Some(example_date()),
+                created_at: example_date(),
+            }],
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::ai_review::errors::AiReviewError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for AiReviewError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            AiReviewError::NotFound => (StatusCode::NOT_FOUND, "NotFound", "ai_review.not_found"),
+            AiReviewError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
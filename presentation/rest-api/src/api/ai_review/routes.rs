@@ -0,0 +1,308 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::ai_review::use_cases::accept::{AcceptAiChangeParams, AcceptAiChangeUseCase};
+use business::domain::ai_review::use_cases::get_mode::{
+    GetAiWriteModeParams, GetAiWriteModeUseCase,
+};
+use business::domain::ai_review::use_cases::get_pending::{
+    GetPendingAiChangesParams, GetPendingAiChangesUseCase,
+};
+use business::domain::ai_review::use_cases::reject::{RejectAiChangeParams, RejectAiChangeUseCase};
+use business::domain::ai_review::use_cases::set_mode::{
+    SetAiWriteModeParams, SetAiWriteModeUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::ai_review::dto::{AiWriteSettingsDto, PendingProductChangesResponse};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::product::dto::ProductResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct AiReviewApi {
+    get_mode_use_case: Arc<dyn GetAiWriteModeUseCase>,
+    set_mode_use_case: Arc<dyn SetAiWriteModeUseCase>,
+    get_pending_use_case: Arc<dyn GetPendingAiChangesUseCase>,
+    accept_use_case: Arc<dyn AcceptAiChangeUseCase>,
+    reject_use_case: Arc<dyn RejectAiChangeUseCase>,
+}
+
+impl AiReviewApi {
+    pub fn new(
+        get_mode_use_case: Arc<dyn GetAiWriteModeUseCase>,
+        set_mode_use_case: Arc<dyn SetAiWriteModeUseCase>,
+        get_pending_use_case: Arc<dyn GetPendingAiChangesUseCase>,
+        accept_use_case: Arc<dyn AcceptAiChangeUseCase>,
+        reject_use_case: Arc<dyn RejectAiChangeUseCase>,
+    ) -> Self {
+        Self {
+            get_mode_use_case,
+            set_mode_use_case,
+            get_pending_use_case,
+            accept_use_case,
+            reject_use_case,
+        }
+    }
+}
+
+/// AI review API
+///
+/// Endpoints for choosing whether AI expiry estimations are applied to
+/// products automatically or staged for confirmation, and for reviewing the
+/// staged changes.
+#[OpenApi]
+impl AiReviewApi {
+    /// Get AI write settings
+    ///
+    /// Returns whether AI estimations are applied automatically (`auto`, the
+    /// default) or staged for review (`review`).
+    #[oai(
+        path = "/settings/ai-writes",
+        method = "get",
+        tag = "ApiTags::Settings"
+    )]
+    async fn get_settings(&self, auth: BearerAuth) -> GetAiWriteSettingsResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_mode_use_case
+            .execute(GetAiWriteModeParams { user_id })
+            .await
+        {
+            Ok(mode) => {
+                GetAiWriteSettingsResponse::Ok(Json(AiWriteSettingsDto { mode: mode.into() }))
+            }
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetAiWriteSettingsResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Update AI write settings
+    ///
+    /// Switching back to `auto` does not apply changes already staged; they
+    /// stay pending until accepted or rejected.
+    #[oai(
+        path = "/settings/ai-writes",
+        method = "put",
+        tag = "ApiTags::Settings"
+    )]
+    async fn update_settings(
+        &self,
+        auth: BearerAuth,
+        body: Json<AiWriteSettingsDto>,
+    ) -> UpdateAiWriteSettingsResponse {
+        let params = SetAiWriteModeParams {
+            user_id: UserId::new(auth.0),
+            mode: body.0.mode.into(),
+        };
+
+        match self.set_mode_use_case.execute(params).await {
+            Ok(mode) => {
+                UpdateAiWriteSettingsResponse::Ok(Json(AiWriteSettingsDto { mode: mode.into() }))
+            }
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                UpdateAiWriteSettingsResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// List pending AI changes
+    ///
+    /// Returns the products with AI changes waiting for confirmation, each
+    /// with its staged changes.
+    #[oai(
+        path = "/products/pending-changes",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_pending(&self, auth: BearerAuth) -> GetPendingAiChangesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_pending_use_case
+            .execute(GetPendingAiChangesParams { user_id })
+            .await
+        {
+            Ok(pending) => GetPendingAiChangesResponse::Ok(Json(
+                pending.into_iter().map(|p| p.into()).collect(),
+            )),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetPendingAiChangesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Accept a pending AI change
+    ///
+    /// Applies the change to its product and returns the updated product.
+    #[oai(
+        path = "/products/pending-changes/:id/accept",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn accept(&self, auth: BearerAuth, id: Path<String>) -> AcceptAiChangeResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return AcceptAiChangeResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "ai_review.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .accept_use_case
+            .execute(AcceptAiChangeParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(product) => AcceptAiChangeResponse::Ok(Json(product.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => AcceptAiChangeResponse::NotFound(json),
+                    _ => AcceptAiChangeResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Reject a pending AI change
+    ///
+    /// Discards the change without touching the product.
+    #[oai(
+        path = "/products/pending-changes/:id",
+        method = "delete",
+        tag = "ApiTags::Products"
+    )]
+    async fn reject(&self, auth: BearerAuth, id: Path<String>) -> RejectAiChangeResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return RejectAiChangeResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "ai_review.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .reject_use_case
+            .execute(RejectAiChangeParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(()) => RejectAiChangeResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => RejectAiChangeResponse::NotFound(json),
+                    _ => RejectAiChangeResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetAiWriteSettingsResponse {
+    #[oai(status = 200)]
+    Ok(Json<AiWriteSettingsDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UpdateAiWriteSettingsResponse {
+    #[oai(status = 200)]
+    Ok(Json<AiWriteSettingsDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetPendingAiChangesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<PendingProductChangesResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum AcceptAiChangeResponse {
+    #[oai(status = 200)]
+    Ok(Json<ProductResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RejectAiChangeResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetAiWriteSettingsResponse,
+    UpdateAiWriteSettingsResponse,
+    GetPendingAiChangesResponse,
+    AcceptAiChangeResponse,
+    RejectAiChangeResponse,
+);
@@ -0,0 +1,157 @@
+use poem::http::StatusCode;
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+use poem_openapi::{Object, payload::Json, types::Example};
+
+use crate::config::app_version_config::{AppVersion, AppVersionConfig};
+
+/// Header the apps send their release version in.
+pub const APP_VERSION_HEADER: &str = "x-app-version";
+
+/// Error returned to apps older than the minimum supported version.
+#[derive(Object, Debug)]
+#[oai(example)]
+pub struct UpgradeRequiredResponse {
+    /// Error category
+    pub name: String,
+    /// Code-style error identifier for i18n
+    pub message: String,
+    /// Human-readable message, present when `Accept-Language` is es or en
+    #[oai(skip_serializing_if_is_none)]
+    pub description: Option<String>,
+    /// Oldest supported app version
+    pub min_version: String,
+    /// Version the app reported
+    pub current_version: String,
+}
+
+impl Example for UpgradeRequiredResponse {
+    fn example() -> Self {
+        upgrade_required(
+            AppVersion {
+                major: 1,
+                minor: 4,
+                patch: 0,
+            },
+            AppVersion {
+                major: 1,
+                minor: 2,
+                patch: 3,
+            },
+        )
+        .0
+    }
+}
+
+pub fn upgrade_required(minimum: AppVersion, current: AppVersion) -> Json<UpgradeRequiredResponse> {
+    Json(UpgradeRequiredResponse {
+        name: "UpgradeRequired".to_string(),
+        message: "app.upgrade_required".to_string(),
+        description: None,
+        min_version: minimum.to_string(),
+        current_version: current.to_string(),
+    })
+}
+
+/// Turns away apps older than the configured minimum with 426, before they
+/// can write data in a shape the server no longer expects. Requests without
+/// a readable `X-App-Version`, such as webhooks and the web client, pass.
+pub struct MinAppVersion {
+    config: AppVersionConfig,
+}
+
+impl MinAppVersion {
+    pub fn new(config: AppVersionConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for MinAppVersion {
+    type Output = MinAppVersionEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        MinAppVersionEndpoint {
+            inner: ep,
+            config: self.config.clone(),
+        }
+    }
+}
+
+pub struct MinAppVersionEndpoint<E> {
+    inner: E,
+    config: AppVersionConfig,
+}
+
+impl<E: Endpoint> Endpoint for MinAppVersionEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let version = req
+            .headers()
+            .get(APP_VERSION_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<AppVersion>().ok());
+
+        if let Some(version) = version
+            && version < self.config.minimum
+        {
+            return Ok(upgrade_required(self.config.minimum, version)
+                .with_status(StatusCode::UPGRADE_REQUIRED)
+                .into_response());
+        }
+
+        self.inner.call(req).await.map(IntoResponse::into_response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poem::{EndpointExt, handler, test::TestClient};
+
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    fn client() -> TestClient<impl Endpoint> {
+        let app = ok.with(MinAppVersion::new(AppVersionConfig {
+            minimum: "1.4.0".parse().unwrap(),
+        }));
+        TestClient::new(app)
+    }
+
+    #[tokio::test]
+    async fn should_return_structured_426_when_app_too_old() {
+        let resp = client()
+            .get("/products")
+            .header(APP_VERSION_HEADER, "1.3.9")
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::UPGRADE_REQUIRED);
+        let json = resp.json().await;
+        let body = json.value().object();
+        body.get("message").assert_string("app.upgrade_required");
+        body.get("min_version").assert_string("1.4.0");
+        body.get("current_version").assert_string("1.3.9");
+    }
+
+    #[tokio::test]
+    async fn should_pass_through_supported_or_unversioned_requests() {
+        let client = client();
+
+        client
+            .get("/products")
+            .header(APP_VERSION_HEADER, "1.4.0")
+            .send()
+            .await
+            .assert_status_is_ok();
+        client.get("/products").send().await.assert_status_is_ok();
+        client
+            .get("/products")
+            .header(APP_VERSION_HEADER, "nightly")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+}
@@ -0,0 +1,59 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::auth::errors::AuthError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for AuthError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            AuthError::InvalidEmail => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "auth.invalid_email",
+            ),
+            AuthError::InvalidLink => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized",
+                "auth.invalid_link",
+            ),
+            AuthError::InvalidPasskey => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized",
+                "auth.invalid_passkey",
+            ),
+            AuthError::UnknownPasskey => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized",
+                "auth.unknown_passkey",
+            ),
+            AuthError::DeliveryFailed(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "ServiceUnavailable",
+                "auth.delivery_failed",
+            ),
+            AuthError::TokenFailed(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "auth.token_failed",
+            ),
+            AuthError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
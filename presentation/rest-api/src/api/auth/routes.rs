@@ -0,0 +1,347 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::auth::use_cases::finish_passkey_login::{
+    FinishPasskeyLoginParams, FinishPasskeyLoginUseCase,
+};
+use business::domain::auth::use_cases::finish_passkey_registration::{
+    FinishPasskeyRegistrationParams, FinishPasskeyRegistrationUseCase,
+};
+use business::domain::auth::use_cases::request_magic_link::{
+    RequestMagicLinkParams, RequestMagicLinkUseCase,
+};
+use business::domain::auth::use_cases::start_passkey_login::StartPasskeyLoginUseCase;
+use business::domain::auth::use_cases::start_passkey_registration::{
+    StartPasskeyRegistrationParams, StartPasskeyRegistrationUseCase,
+};
+use business::domain::auth::use_cases::verify_magic_link::{
+    VerifyMagicLinkParams, VerifyMagicLinkUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::auth::dto::{
+    MagicLinkRequest, PasskeyLoginRequest, PasskeyOptionsResponse, PasskeyRegistrationRequest,
+    PasskeyResponse, TokenResponse, VerifyMagicLinkRequest,
+};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+/// Use cases of sign-in without Firebase, present when `AUTH_PROVIDER` is
+/// "local".
+pub struct LocalSignIn {
+    pub request_magic_link_use_case: Arc<dyn RequestMagicLinkUseCase>,
+    pub verify_magic_link_use_case: Arc<dyn VerifyMagicLinkUseCase>,
+    pub start_passkey_registration_use_case: Arc<dyn StartPasskeyRegistrationUseCase>,
+    pub finish_passkey_registration_use_case: Arc<dyn FinishPasskeyRegistrationUseCase>,
+    pub start_passkey_login_use_case: Arc<dyn StartPasskeyLoginUseCase>,
+    pub finish_passkey_login_use_case: Arc<dyn FinishPasskeyLoginUseCase>,
+}
+
+pub struct AuthApi {
+    local: Option<LocalSignIn>,
+}
+
+impl AuthApi {
+    pub fn new(local: Option<LocalSignIn>) -> Self {
+        Self { local }
+    }
+}
+
+/// Sign-in with this server as the identity provider answers `404` on
+/// Firebase instances, whose apps sign in with Firebase directly.
+fn local_disabled() -> Json<ErrorResponse> {
+    Json(ErrorResponse {
+        name: "NotFound".to_string(),
+        message: "auth.local_disabled".to_string(),
+        description: None,
+    })
+}
+
+/// Self-hosted sign-in API
+///
+/// Passkey and email magic-link sign-in for instances run with
+/// `AUTH_PROVIDER=local`. Both end in a bearer token accepted by every other
+/// endpoint.
+#[OpenApi]
+impl AuthApi {
+    /// Email a sign-in link
+    ///
+    /// Sends a single-use link to the address; opening it within
+    /// `AUTH_CHALLENGE_TTL_SECS` signs in, creating the account on first use.
+    #[oai(path = "/auth/magic-link", method = "post", tag = "ApiTags::Auth")]
+    async fn request_magic_link(&self, body: Json<MagicLinkRequest>) -> RequestMagicLinkResponse {
+        let Some(local) = &self.local else {
+            return RequestMagicLinkResponse::NotFound(local_disabled());
+        };
+
+        match local
+            .request_magic_link_use_case
+            .execute(RequestMagicLinkParams {
+                email: body.0.email,
+            })
+            .await
+        {
+            Ok(()) => RequestMagicLinkResponse::Accepted,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => RequestMagicLinkResponse::BadRequest(json),
+                    503 => RequestMagicLinkResponse::ServiceUnavailable(json),
+                    _ => RequestMagicLinkResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Sign in with a magic link
+    ///
+    /// Exchanges the token of an emailed link for a bearer token. Each link
+    /// works once.
+    #[oai(
+        path = "/auth/magic-link/verify",
+        method = "post",
+        tag = "ApiTags::Auth"
+    )]
+    async fn verify_magic_link(
+        &self,
+        body: Json<VerifyMagicLinkRequest>,
+    ) -> VerifyMagicLinkResponse {
+        let Some(local) = &self.local else {
+            return VerifyMagicLinkResponse::NotFound(local_disabled());
+        };
+
+        match local
+            .verify_magic_link_use_case
+            .execute(VerifyMagicLinkParams {
+                token: body.0.token,
+            })
+            .await
+        {
+            Ok(token) => VerifyMagicLinkResponse::Ok(Json(token.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    401 => VerifyMagicLinkResponse::Unauthorized(json),
+                    _ => VerifyMagicLinkResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Start adding a passkey
+    ///
+    /// Returns a challenge for the platform passkey API to create a passkey
+    /// for the signed-in user. Send the result to `POST /auth/passkeys`.
+    #[oai(
+        path = "/auth/passkeys/registration/options",
+        method = "post",
+        tag = "ApiTags::Auth"
+    )]
+    async fn start_passkey_registration(&self, auth: BearerAuth) -> PasskeyOptionsResult {
+        let Some(local) = &self.local else {
+            return PasskeyOptionsResult::NotFound(local_disabled());
+        };
+
+        match local
+            .start_passkey_registration_use_case
+            .execute(StartPasskeyRegistrationParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(options) => PasskeyOptionsResult::Ok(Json(options.into())),
+            Err(err) => PasskeyOptionsResult::InternalError(err.into_error_response().1),
+        }
+    }
+
+    /// Add a passkey
+    ///
+    /// Registers the passkey created from the registration challenge, so the
+    /// user can sign in with it from then on.
+    #[oai(path = "/auth/passkeys", method = "post", tag = "ApiTags::Auth")]
+    async fn finish_passkey_registration(
+        &self,
+        auth: BearerAuth,
+        body: Json<PasskeyRegistrationRequest>,
+    ) -> RegisterPasskeyResponse {
+        let Some(local) = &self.local else {
+            return RegisterPasskeyResponse::NotFound(local_disabled());
+        };
+
+        match local
+            .finish_passkey_registration_use_case
+            .execute(FinishPasskeyRegistrationParams {
+                user_id: UserId::new(auth.0),
+                attestation: body.0.into(),
+            })
+            .await
+        {
+            Ok(passkey) => RegisterPasskeyResponse::Created(Json(passkey.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    // The bearer token was fine; the passkey response was not
+                    401 => RegisterPasskeyResponse::BadRequest(json),
+                    _ => RegisterPasskeyResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Start signing in with a passkey
+    ///
+    /// Returns a challenge for the platform passkey API to sign with any of
+    /// the user's passkeys. Send the result to `POST /auth/passkeys/login`.
+    #[oai(
+        path = "/auth/passkeys/login/options",
+        method = "post",
+        tag = "ApiTags::Auth"
+    )]
+    async fn start_passkey_login(&self) -> PasskeyOptionsResult {
+        let Some(local) = &self.local else {
+            return PasskeyOptionsResult::NotFound(local_disabled());
+        };
+
+        match local.start_passkey_login_use_case.execute().await {
+            Ok(options) => PasskeyOptionsResult::Ok(Json(options.into())),
+            Err(err) => PasskeyOptionsResult::InternalError(err.into_error_response().1),
+        }
+    }
+
+    /// Sign in with a passkey
+    ///
+    /// Checks the signed challenge and returns a bearer token for the
+    /// passkey's owner.
+    #[oai(path = "/auth/passkeys/login", method = "post", tag = "ApiTags::Auth")]
+    async fn finish_passkey_login(&self, body: Json<PasskeyLoginRequest>) -> PasskeyLoginResponse {
+        let Some(local) = &self.local else {
+            return PasskeyLoginResponse::NotFound(local_disabled());
+        };
+
+        match local
+            .finish_passkey_login_use_case
+            .execute(FinishPasskeyLoginParams {
+                assertion: body.0.into(),
+            })
+            .await
+        {
+            Ok(token) => PasskeyLoginResponse::Ok(Json(token.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    401 => PasskeyLoginResponse::Unauthorized(json),
+                    _ => PasskeyLoginResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RequestMagicLinkResponse {
+    /// Link sent
+    #[oai(status = 202)]
+    Accepted,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// The instance signs in with Firebase
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    /// The mail relay could not be reached
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum VerifyMagicLinkResponse {
+    #[oai(status = 200)]
+    Ok(Json<TokenResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    /// Link unknown, expired or already used
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// The instance signs in with Firebase
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum PasskeyOptionsResult {
+    #[oai(status = 200)]
+    Ok(Json<PasskeyOptionsResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// The instance signs in with Firebase
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RegisterPasskeyResponse {
+    #[oai(status = 201)]
+    Created(Json<PasskeyResponse>),
+    /// Malformed or unverifiable passkey, or a stale challenge
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// The instance signs in with Firebase
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum PasskeyLoginResponse {
+    #[oai(status = 200)]
+    Ok(Json<TokenResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    /// Unknown passkey, bad signature or stale challenge
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// The instance signs in with Firebase
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    RequestMagicLinkResponse,
+    VerifyMagicLinkResponse,
+    PasskeyOptionsResult,
+    RegisterPasskeyResponse,
+    PasskeyLoginResponse,
+);
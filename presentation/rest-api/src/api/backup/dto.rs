@@ -0,0 +1,85 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use poem_openapi::{Object, types::Example};
+use serde_json::json;
+
+use business::domain::backup::model::{BACKUP_FORMAT_VERSION, BackupArchive};
+
+/// Everything a user stored, to move it to another instance.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BackupArchiveDto {
+    /// Archive layout version
+    pub format_version: u32,
+    /// Latest database migration of the instance that made the backup;
+    /// instances on an older schema refuse to restore it
+    pub schema_version: i64,
+    /// When the backup was taken
+    pub exported_at: DateTime<Utc>,
+    /// Rows per table, as stored
+    pub tables: BTreeMap<String, Vec<serde_json::Value>>,
+}
+
+impl From<BackupArchive> for BackupArchiveDto {
+    fn from(archive: BackupArchive) -> Self {
+        Self {
+            format_version: archive.format_version,
+            schema_version: archive.schema_version,
+            exported_at: archive.exported_at,
+            tables: archive.tables,
+        }
+    }
+}
+
+impl From<BackupArchiveDto> for BackupArchive {
+    fn from(dto: BackupArchiveDto) -> Self {
+        Self {
+            format_version: dto.format_version,
+            schema_version: dto.schema_version,
+            exported_at: dto.exported_at,
+            tables: dto.tables,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct RestoreSummaryResponse {
+    /// Rows written to the account
+    pub restored_rows: u64,
+}
+
+// --- OpenAPI examples ---
+
+impl Example for BackupArchiveDto {
+    fn example() -> Self {
+        Self {
+            format_version: BACKUP_FORMAT_VERSION,
+            schema_version: 20260303090000,
+            exported_at: DateTime::parse_from_rfc3339("2026-03-04T09:30:00Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            tables: BTreeMap::from([(
+                "products".to_string(),
+                vec![json!({
+                    "id": "550e8400-e29b-41d4-a716-446655440000",
+                    "user_id": "kR2xV9mQpLs7TfW3bN8cJ1hYd4E2",
+                    "name": "Leche entera",
+                    "status": "opened",
+                    "location": "fridge",
+                    "quantity": "1 L",
+                    "expiry_date": "2026-03-08T00:00:00+00:00",
+                    "created_at": "2026-03-01T18:12:00+00:00",
+                    "updated_at": "2026-03-02T08:40:00+00:00"
+                })],
+            )]),
+        }
+    }
+}
+
+impl Example for RestoreSummaryResponse {
+    fn example() -> Self {
+        Self { restored_rows: 142 }
+    }
+}
@@ -0,0 +1,47 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::backup::errors::BackupError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for BackupError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            BackupError::UnsupportedFormat => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "backup.unsupported_format",
+            ),
+            BackupError::NewerSchema => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "backup.newer_schema",
+            ),
+            BackupError::InvalidRow { .. } => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "backup.invalid_row",
+            ),
+            BackupError::AccountNotEmpty => {
+                (StatusCode::CONFLICT, "Conflict", "backup.account_not_empty")
+            }
+            BackupError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::backup::use_cases::export::{ExportBackupParams, ExportBackupUseCase};
+use business::domain::backup::use_cases::restore::{RestoreBackupParams, RestoreBackupUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::backup::dto::{BackupArchiveDto, RestoreSummaryResponse};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct BackupApi {
+    export_use_case: Arc<dyn ExportBackupUseCase>,
+    restore_use_case: Arc<dyn RestoreBackupUseCase>,
+}
+
+impl BackupApi {
+    pub fn new(
+        export_use_case: Arc<dyn ExportBackupUseCase>,
+        restore_use_case: Arc<dyn RestoreBackupUseCase>,
+    ) -> Self {
+        Self {
+            export_use_case,
+            restore_use_case,
+        }
+    }
+}
+
+/// Backups API
+///
+/// Endpoints that export a user's data and restore it on another instance.
+#[OpenApi]
+impl BackupApi {
+    /// Export a backup
+    ///
+    /// Returns every product, list, setting and history entry of the user as
+    /// a versioned archive that `POST /me/restore` accepts on any instance.
+    #[oai(path = "/me/backup", method = "get", tag = "ApiTags::Backups")]
+    async fn export(&self, auth: BearerAuth) -> ExportBackupResponse {
+        match self
+            .export_use_case
+            .execute(ExportBackupParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(archive) => ExportBackupResponse::Ok(Json(archive.into())),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                ExportBackupResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Restore a backup
+    ///
+    /// Writes an archive from `GET /me/backup` into the user's account, all or
+    /// nothing, as the user's own data under new ids. The account must be
+    /// empty (`409` otherwise). Archives from an instance on a newer schema,
+    /// and rows the API wouldn't accept or that point at data outside the
+    /// archive, are refused with `422`.
+    #[oai(path = "/me/restore", method = "post", tag = "ApiTags::Backups")]
+    async fn restore(
+        &self,
+        auth: BearerAuth,
+        body: Json<BackupArchiveDto>,
+    ) -> RestoreBackupResponse {
+        match self
+            .restore_use_case
+            .execute(RestoreBackupParams {
+                user_id: UserId::new(auth.0),
+                archive: body.0.into(),
+            })
+            .await
+        {
+            Ok(restored) => RestoreBackupResponse::Ok(Json(RestoreSummaryResponse {
+                restored_rows: restored as u64,
+            })),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    409 => RestoreBackupResponse::Conflict(json),
+                    422 => RestoreBackupResponse::UnprocessableEntity(json),
+                    _ => RestoreBackupResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ExportBackupResponse {
+    #[oai(status = 200)]
+    Ok(Json<BackupArchiveDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RestoreBackupResponse {
+    #[oai(status = 200)]
+    Ok(Json<RestoreSummaryResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(ExportBackupResponse, RestoreBackupResponse);
@@ -0,0 +1,40 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::badge::model::BadgeCounts;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BadgesResponse {
+    /// Products to use today or in the next two days
+    pub urgent_products: u32,
+    /// Shopping list items not bought yet
+    pub unbought_items: u32,
+    /// Suggestions generated today
+    pub new_suggestions: u32,
+    /// Notifications not acknowledged yet
+    pub unread_notifications: u32,
+}
+
+impl From<BadgeCounts> for BadgesResponse {
+    fn from(counts: BadgeCounts) -> Self {
+        Self {
+            urgent_products: counts.urgent_products,
+            unbought_items: counts.unbought_items,
+            new_suggestions: counts.new_suggestions,
+            unread_notifications: counts.unread_notifications,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for BadgesResponse {
+    fn example() -> Self {
+        Self {
+            urgent_products: 3,
+            unbought_items: 7,
+            new_suggestions: 5,
+            unread_notifications: 2,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::badge::errors::BadgeError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for BadgeError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            BadgeError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,102 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Header, payload::Json};
+
+use business::domain::badge::use_cases::get_badges::{GetBadgesParams, GetBadgesUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::badge::dto::BadgesResponse;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::http_cache::{BADGES_CACHE_CONTROL, cache_key, is_not_modified};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct BadgeApi {
+    get_badges_use_case: Arc<dyn GetBadgesUseCase>,
+}
+
+impl BadgeApi {
+    pub fn new(get_badges_use_case: Arc<dyn GetBadgesUseCase>) -> Self {
+        Self {
+            get_badges_use_case,
+        }
+    }
+}
+
+/// Badge API
+///
+/// Lightweight counters for app icons and widgets.
+#[OpenApi]
+impl BadgeApi {
+    /// Get badge counts
+    ///
+    /// Returns urgent products, unbought shopping items, today's suggestions
+    /// and unread notifications as plain counts, computed in a single query.
+    /// Meant for frequent polling: send the last `ETag` in `If-None-Match` to
+    /// get `304` while nothing changed.
+    #[oai(path = "/badges", method = "get", tag = "ApiTags::Badges")]
+    async fn get_badges(
+        &self,
+        auth: BearerAuth,
+        #[oai(name = "If-None-Match")] if_none_match: Header<Option<String>>,
+    ) -> GetBadgesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_badges_use_case
+            .execute(GetBadgesParams {
+                user_id: user_id.clone(),
+            })
+            .await
+        {
+            Ok(counts) => {
+                let etag = cache_key(
+                    "badges",
+                    &[
+                        user_id.as_str(),
+                        &counts.urgent_products.to_string(),
+                        &counts.unbought_items.to_string(),
+                        &counts.new_suggestions.to_string(),
+                        &counts.unread_notifications.to_string(),
+                    ],
+                );
+                if is_not_modified(if_none_match.0.as_deref(), &etag) {
+                    return GetBadgesResponse::NotModified(BADGES_CACHE_CONTROL.to_string(), etag);
+                }
+                GetBadgesResponse::Ok(Json(counts.into()), BADGES_CACHE_CONTROL.to_string(), etag)
+            }
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetBadgesResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetBadgesResponse {
+    #[oai(status = 200)]
+    Ok(
+        Json<BadgesResponse>,
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 304)]
+    NotModified(
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetBadgesResponse);
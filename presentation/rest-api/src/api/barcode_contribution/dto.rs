@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::barcode_contribution::model::{BarcodeContribution, UpstreamStatus};
+
+use crate::api::examples::example_date;
+
+/// Where a contribution stands with Open Food Facts.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum UpstreamStatusDto {
+    /// This server has no Open Food Facts account; the name is only used here
+    #[oai(rename = "local_only")]
+    LocalOnly,
+    /// Being sent to Open Food Facts
+    #[oai(rename = "pending")]
+    Pending,
+    /// Open Food Facts accepted the product
+    #[oai(rename = "submitted")]
+    Submitted,
+    /// Open Food Facts could not be reached or refused the product
+    #[oai(rename = "failed")]
+    Failed,
+}
+
+impl From<UpstreamStatus> for UpstreamStatusDto {
+    fn from(status: UpstreamStatus) -> Self {
+        match status {
+            UpstreamStatus::LocalOnly => UpstreamStatusDto::LocalOnly,
+            UpstreamStatus::Pending => UpstreamStatusDto::Pending,
+            UpstreamStatus::Submitted => UpstreamStatusDto::Submitted,
+            UpstreamStatus::Failed => UpstreamStatusDto::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SubmitBarcodeContributionRequest {
+    /// EAN-8, UPC-A, EAN-13 or GTIN-14 barcode the lookup didn't recognize
+    #[oai(validator(pattern = "^\\s*[0-9]{8,14}\\s*$"))]
+    pub barcode: String,
+    /// Product name as printed on the package
+    #[oai(validator(min_length = 1, max_length = 120))]
+    pub name: String,
+    /// Optional base64 JPEG, PNG or WebP photo of the front of the package,
+    /// sent to Open Food Facts along with the name
+    pub image_base64: Option<String>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BarcodeContributionResponse {
+    pub barcode: String,
+    pub name: String,
+    pub upstream_status: UpstreamStatusDto,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BarcodeContribution> for BarcodeContributionResponse {
+    fn from(contribution: BarcodeContribution) -> Self {
+        Self {
+            barcode: contribution.barcode,
+            name: contribution.name,
+            upstream_status: contribution.upstream_status.into(),
+            created_at: contribution.created_at,
+            updated_at: contribution.updated_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for SubmitBarcodeContributionRequest {
+    fn example() -> Self {
+        Self {
+            barcode: "8410000000001".to_string(),
+            name: "Turrón blando".to_string(),
+            image_base64: None,
+        }
+    }
+}
+
+impl Example for BarcodeContributionResponse {
+    fn example() -> Self {
+        Self {
+            barcode: "8410000000001".to_string(),
+            name: "Turrón blando".to_string(),
+            upstream_status: UpstreamStatusDto::Pending,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
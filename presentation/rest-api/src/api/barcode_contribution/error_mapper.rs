@@ -0,0 +1,54 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::barcode_contribution::errors::BarcodeContributionError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for BarcodeContributionError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            BarcodeContributionError::InvalidBarcode => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "barcode_contribution.invalid_barcode",
+            ),
+            BarcodeContributionError::NameEmpty => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "barcode_contribution.name_empty",
+            ),
+            BarcodeContributionError::NameTooLong => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "barcode_contribution.name_too_long",
+            ),
+            BarcodeContributionError::InvalidImage => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "barcode_contribution.invalid_image",
+            ),
+            BarcodeContributionError::UpstreamFailed(_) => (
+                StatusCode::BAD_GATEWAY,
+                "UpstreamError",
+                "barcode_contribution.upstream_failed",
+            ),
+            BarcodeContributionError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::barcode_contribution::use_cases::submit::{
+    SubmitBarcodeContributionParams, SubmitBarcodeContributionUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::barcode_contribution::dto::{
+    BarcodeContributionResponse, SubmitBarcodeContributionRequest,
+};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::payload_limit::{PayloadTooLargeResponse, payload_too_large};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::payload_config::PayloadConfig;
+
+pub struct BarcodeContributionApi {
+    submit_use_case: Arc<dyn SubmitBarcodeContributionUseCase>,
+    payload_config: PayloadConfig,
+}
+
+impl BarcodeContributionApi {
+    pub fn new(
+        submit_use_case: Arc<dyn SubmitBarcodeContributionUseCase>,
+        payload_config: PayloadConfig,
+    ) -> Self {
+        Self {
+            submit_use_case,
+            payload_config,
+        }
+    }
+}
+
+/// Barcode contribution API
+///
+/// Endpoints for naming barcodes that `POST /products/identify/barcode` and
+/// `POST /shopping-items/from-barcode` don't recognize.
+#[OpenApi]
+impl BarcodeContributionApi {
+    /// Contribute an unknown barcode
+    ///
+    /// Saves the name the user gave to a barcode the lookup didn't recognize.
+    /// The user's later scans of the barcode answer with this name right away,
+    /// replacing any earlier contribution. When the server has an Open Food
+    /// Facts account (`OFF_USER_ID`), the name and the optional front photo
+    /// are also sent there in the background; `upstreamStatus` starts as
+    /// `pending`. Bodies over `IDENTIFY_IMAGE_MAX_BYTES` are rejected with `413`.
+    #[oai(
+        path = "/barcode-contributions",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn submit(
+        &self,
+        auth: BearerAuth,
+        body: Json<SubmitBarcodeContributionRequest>,
+    ) -> SubmitBarcodeContributionResponse {
+        let max_bytes = self.payload_config.identify_image_max_bytes;
+        if body.0.image_base64.as_ref().map_or(0, String::len) > max_bytes {
+            return SubmitBarcodeContributionResponse::PayloadTooLarge(payload_too_large(
+                max_bytes,
+            ));
+        }
+
+        let params = SubmitBarcodeContributionParams {
+            user_id: UserId::new(auth.0),
+            barcode: body.0.barcode,
+            name: body.0.name,
+            image_base64: body.0.image_base64,
+        };
+
+        match self.submit_use_case.execute(params).await {
+            Ok(contribution) => {
+                SubmitBarcodeContributionResponse::Created(Json(contribution.into()))
+            }
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => SubmitBarcodeContributionResponse::BadRequest(json),
+                    _ => SubmitBarcodeContributionResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum SubmitBarcodeContributionResponse {
+    #[oai(status = 201)]
+    Created(Json<BarcodeContributionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(SubmitBarcodeContributionResponse,);
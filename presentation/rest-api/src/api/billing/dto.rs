@@ -0,0 +1,32 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::billing::model::CheckoutSession;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CheckoutSessionResponse {
+    /// Stripe checkout session ID
+    pub id: String,
+    /// Hosted checkout page to open in the browser
+    pub url: String,
+}
+
+impl From<CheckoutSession> for CheckoutSessionResponse {
+    fn from(session: CheckoutSession) -> Self {
+        Self {
+            id: session.id,
+            url: session.url,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for CheckoutSessionResponse {
+    fn example() -> Self {
+        Self {
+            id: "cs_test_a1b2c3".to_string(),
+            url: "https://checkout.stripe.com/c/pay/cs_test_a1b2c3".to_string(),
+        }
+    }
+}
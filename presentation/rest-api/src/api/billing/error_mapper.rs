@@ -0,0 +1,44 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::billing::errors::BillingError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for BillingError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            BillingError::CheckoutFailed => (
+                StatusCode::BAD_GATEWAY,
+                "BillingError",
+                "billing.checkout_failed",
+            ),
+            BillingError::InvalidSignature => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "billing.invalid_signature",
+            ),
+            BillingError::InvalidEvent => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "billing.invalid_event",
+            ),
+            BillingError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use poem_openapi::{
+    OpenApi,
+    param::Header,
+    payload::{Binary, Json},
+};
+
+use business::domain::billing::use_cases::create_checkout::{
+    CreateCheckoutParams, CreateCheckoutUseCase,
+};
+use business::domain::billing::use_cases::handle_webhook::{
+    HandleWebhookParams, HandleWebhookUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::billing::dto::CheckoutSessionResponse;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct BillingApi {
+    create_checkout_use_case: Arc<dyn CreateCheckoutUseCase>,
+    handle_webhook_use_case: Arc<dyn HandleWebhookUseCase>,
+}
+
+impl BillingApi {
+    pub fn new(
+        create_checkout_use_case: Arc<dyn CreateCheckoutUseCase>,
+        handle_webhook_use_case: Arc<dyn HandleWebhookUseCase>,
+    ) -> Self {
+        Self {
+            create_checkout_use_case,
+            handle_webhook_use_case,
+        }
+    }
+}
+
+/// Billing API
+///
+/// Endpoints for upgrading to the premium plan through Stripe.
+#[OpenApi]
+impl BillingApi {
+    /// Start a premium checkout
+    ///
+    /// Creates a Stripe checkout session for the premium subscription. Open the
+    /// returned URL to pay; the plan switches once Stripe confirms the payment
+    /// through the webhook.
+    #[oai(path = "/billing/checkout", method = "post", tag = "ApiTags::Billing")]
+    async fn create_checkout(&self, auth: BearerAuth) -> CreateCheckoutResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .create_checkout_use_case
+            .execute(CreateCheckoutParams { user_id })
+            .await
+        {
+            Ok(session) => CreateCheckoutResponse::Created(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    502 => CreateCheckoutResponse::BadGateway(json),
+                    _ => CreateCheckoutResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Receive Stripe events
+    ///
+    /// Called by Stripe, not by clients. The raw body is checked against the
+    /// `Stripe-Signature` header before the user's plan is updated.
+    #[oai(path = "/billing/webhook", method = "post", tag = "ApiTags::Billing")]
+    async fn webhook(
+        &self,
+        #[oai(name = "Stripe-Signature")] signature: Header<String>,
+        body: Binary<Vec<u8>>,
+    ) -> StripeWebhookResponse {
+        match self
+            .handle_webhook_use_case
+            .execute(HandleWebhookParams {
+                payload: body.0,
+                signature: signature.0,
+            })
+            .await
+        {
+            Ok(()) => StripeWebhookResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => StripeWebhookResponse::BadRequest(json),
+                    _ => StripeWebhookResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateCheckoutResponse {
+    #[oai(status = 201)]
+    Created(Json<CheckoutSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    #[oai(status = 502)]
+    BadGateway(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+pub enum StripeWebhookResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(CreateCheckoutResponse);
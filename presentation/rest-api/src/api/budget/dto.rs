@@ -0,0 +1,99 @@
+use chrono::NaiveDate;
+use poem_openapi::{Object, types::Example};
+
+use business::domain::budget::model::{Budget, BudgetStatus};
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BudgetRequest {
+    /// Monthly grocery budget, in minor units of the currency
+    #[oai(validator(minimum(value = "1"), maximum(value = "100000000")))]
+    pub monthly_budget_cents: u64,
+    /// Notify when 80% and 100% of the budget are spent (default: true)
+    pub alerts_enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BudgetResponse {
+    pub monthly_budget_cents: u64,
+    pub alerts_enabled: bool,
+}
+
+impl From<Budget> for BudgetResponse {
+    fn from(budget: Budget) -> Self {
+        Self {
+            monthly_budget_cents: budget.monthly_cents,
+            alerts_enabled: budget.alerts_enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BudgetStatusResponse {
+    /// ISO 4217 code of the amounts
+    pub currency: String,
+    /// First day of the month the figures cover (UTC)
+    pub month: NaiveDate,
+    pub budget_cents: u64,
+    /// Prices recorded on shopping trips this month
+    pub spent_cents: u64,
+    /// Spend by the end of the month at the current pace
+    pub projected_cents: u64,
+    /// How far the projection goes over the budget; 0 when it stays within
+    pub projected_overrun_cents: u64,
+    /// Share of the budget spent so far, rounded down; can go over 100
+    pub percent_used: u32,
+    pub alerts_enabled: bool,
+}
+
+impl BudgetStatusResponse {
+    pub fn new(status: BudgetStatus, currency: String) -> Self {
+        Self {
+            currency,
+            month: status.month,
+            budget_cents: status.budget_cents,
+            spent_cents: status.spent_cents,
+            projected_cents: status.projected_cents,
+            projected_overrun_cents: status.projected_overrun_cents,
+            percent_used: status.percent_used(),
+            alerts_enabled: status.alerts_enabled,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for BudgetRequest {
+    fn example() -> Self {
+        Self {
+            monthly_budget_cents: 40_000,
+            alerts_enabled: Some(true),
+        }
+    }
+}
+
+impl Example for BudgetResponse {
+    fn example() -> Self {
+        Self {
+            monthly_budget_cents: 40_000,
+            alerts_enabled: true,
+        }
+    }
+}
+
+impl Example for BudgetStatusResponse {
+    fn example() -> Self {
+        Self {
+            currency: "EUR".to_string(),
+            month: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            budget_cents: 40_000,
+            spent_cents: 18_450,
+            projected_cents: 44_000,
+            projected_overrun_cents: 4_000,
+            percent_used: 46,
+            alerts_enabled: true,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::budget::errors::BudgetError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for BudgetError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            BudgetError::InvalidAmount => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "budget.invalid_amount",
+            ),
+            BudgetError::NotSet => (StatusCode::NOT_FOUND, "NotFound", "budget.not_set"),
+            BudgetError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
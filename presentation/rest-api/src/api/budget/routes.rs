@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::budget::use_cases::delete::{DeleteBudgetParams, DeleteBudgetUseCase};
+use business::domain::budget::use_cases::get_status::{
+    GetBudgetStatusParams, GetBudgetStatusUseCase,
+};
+use business::domain::budget::use_cases::set::{SetBudgetParams, SetBudgetUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::budget::dto::{BudgetRequest, BudgetResponse, BudgetStatusResponse};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::stats_config::StatsConfig;
+
+pub struct BudgetApi {
+    set_use_case: Arc<dyn SetBudgetUseCase>,
+    delete_use_case: Arc<dyn DeleteBudgetUseCase>,
+    get_status_use_case: Arc<dyn GetBudgetStatusUseCase>,
+    config: StatsConfig,
+}
+
+impl BudgetApi {
+    pub fn new(
+        set_use_case: Arc<dyn SetBudgetUseCase>,
+        delete_use_case: Arc<dyn DeleteBudgetUseCase>,
+        get_status_use_case: Arc<dyn GetBudgetStatusUseCase>,
+        config: StatsConfig,
+    ) -> Self {
+        Self {
+            set_use_case,
+            delete_use_case,
+            get_status_use_case,
+            config,
+        }
+    }
+}
+
+/// Budget API
+///
+/// A monthly grocery budget compared against the prices recorded on
+/// shopping trips.
+#[OpenApi]
+impl BudgetApi {
+    /// Set the monthly grocery budget
+    ///
+    /// With alerts on, a notification is sent the first time a recorded
+    /// purchase takes the month's spend past 80% and 100% of the budget.
+    #[oai(path = "/settings/budget", method = "put", tag = "ApiTags::Settings")]
+    async fn set_budget(&self, auth: BearerAuth, body: Json<BudgetRequest>) -> SetBudgetResponse {
+        let params = SetBudgetParams {
+            user_id: UserId::new(auth.0),
+            monthly_cents: body.0.monthly_budget_cents,
+            alerts_enabled: body.0.alerts_enabled.unwrap_or(true),
+        };
+
+        match self.set_use_case.execute(params).await {
+            Ok(budget) => SetBudgetResponse::Ok(Json(budget.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => SetBudgetResponse::BadRequest(json),
+                    _ => SetBudgetResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Remove the monthly grocery budget
+    #[oai(
+        path = "/settings/budget",
+        method = "delete",
+        tag = "ApiTags::Settings"
+    )]
+    async fn delete_budget(&self, auth: BearerAuth) -> DeleteBudgetResponse {
+        let params = DeleteBudgetParams {
+            user_id: UserId::new(auth.0),
+        };
+
+        match self.delete_use_case.execute(params).await {
+            Ok(()) => DeleteBudgetResponse::NoContent,
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                DeleteBudgetResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Get spend against the budget
+    ///
+    /// Sums the prices recorded on shopping trips this calendar month (UTC)
+    /// and projects the month's total at the current pace. Items bought
+    /// without a price don't count. Answers `404` until a budget is set.
+    #[oai(path = "/stats/budget", method = "get", tag = "ApiTags::Stats")]
+    async fn get_budget_status(&self, auth: BearerAuth) -> GetBudgetStatusResponse {
+        let params = GetBudgetStatusParams {
+            user_id: UserId::new(auth.0),
+        };
+
+        match self.get_status_use_case.execute(params).await {
+            Ok(status) => GetBudgetStatusResponse::Ok(Json(BudgetStatusResponse::new(
+                status,
+                self.config.currency.clone(),
+            ))),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetBudgetStatusResponse::NotFound(json),
+                    _ => GetBudgetStatusResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum SetBudgetResponse {
+    #[oai(status = 200)]
+    Ok(Json<BudgetResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum DeleteBudgetResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetBudgetStatusResponse {
+    #[oai(status = 200)]
+    Ok(Json<BudgetStatusResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    SetBudgetResponse,
+    DeleteBudgetResponse,
+    GetBudgetStatusResponse
+);
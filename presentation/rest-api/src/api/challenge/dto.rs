@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::challenge::model::{Challenge, ChallengeKind, ChallengeStatus, week_start};
+use business::domain::shared::pagination::CursorPage;
+
+use crate::api::examples::example_date;
+
+/// What a challenge asks for.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ChallengeKindDto {
+    /// Use products that are about to expire
+    #[oai(rename = "use_urgent_items")]
+    UseUrgentItems,
+}
+
+impl From<ChallengeKind> for ChallengeKindDto {
+    fn from(kind: ChallengeKind) -> Self {
+        match kind {
+            ChallengeKind::UseUrgentItems => ChallengeKindDto::UseUrgentItems,
+        }
+    }
+}
+
+/// Where a challenge stands.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ChallengeStatusDto {
+    /// The week is still running
+    #[oai(rename = "active")]
+    Active,
+    /// The target was reached
+    #[oai(rename = "completed")]
+    Completed,
+    /// The week ended before the target was reached
+    #[oai(rename = "expired")]
+    Expired,
+}
+
+impl From<ChallengeStatus> for ChallengeStatusDto {
+    fn from(status: ChallengeStatus) -> Self {
+        match status {
+            ChallengeStatus::Active => ChallengeStatusDto::Active,
+            ChallengeStatus::Completed => ChallengeStatusDto::Completed,
+            ChallengeStatus::Expired => ChallengeStatusDto::Expired,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ChallengeResponse {
+    /// Challenge unique identifier
+    pub id: String,
+    pub kind: ChallengeKindDto,
+    pub status: ChallengeStatusDto,
+    /// Products to count before the week ends
+    pub target: u32,
+    /// Products counted so far
+    pub progress: u32,
+    /// Monday at midnight (UTC) the challenge started
+    pub starts_at: DateTime<Utc>,
+    /// Following Monday at midnight (UTC), when it ends
+    pub ends_at: DateTime<Utc>,
+    /// When the target was reached
+    #[oai(skip_serializing_if_is_none)]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<Challenge> for ChallengeResponse {
+    fn from(challenge: Challenge) -> Self {
+        Self {
+            id: challenge.id.to_string(),
+            kind: challenge.kind.into(),
+            status: challenge.status(Utc::now()).into(),
+            target: challenge.target,
+            progress: challenge.progress,
+            starts_at: challenge.starts_at,
+            ends_at: challenge.ends_at,
+            completed_at: challenge.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ChallengeHistoryResponse {
+    /// Challenges, newest week first
+    pub items: Vec<ChallengeResponse>,
+    /// Pass as `cursor` to fetch the next page; absent on the last page
+    #[oai(skip_serializing_if_is_none)]
+    pub next_cursor: Option<String>,
+}
+
+impl From<CursorPage<Challenge>> for ChallengeHistoryResponse {
+    fn from(page: CursorPage<Challenge>) -> Self {
+        let page = page.map(ChallengeResponse::from);
+        Self {
+            items: page.items,
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for ChallengeResponse {
+    fn example() -> Self {
+        Self {
+            id: "5c7e9a1b-3d5f-4a7c-9e1b-3d5f7a9c1e3b".to_string(),
+            kind: ChallengeKindDto::UseUrgentItems,
+            status: ChallengeStatusDto::Active,
+            target: 3,
+            progress: 1,
+            starts_at: week_start(example_date()),
+            ends_at: week_start(example_date()) + chrono::Duration::days(7),
+            completed_at: None,
+        }
+    }
+}
+
+impl Example for ChallengeHistoryResponse {
+    fn example() -> Self {
+        Self {
+            items: vec![ChallengeResponse::example()],
+            next_cursor: Some(
+                "MTc3MjM1NTYwMDAwMDAwMDo1YzdlOWExYi0zZDVmLTRhN2MtOWUxYi0zZDVmN2E5YzFlM2I"
+                    .to_string(),
+            ),
+        }
+    }
+}
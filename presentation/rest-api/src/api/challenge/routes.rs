@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Query, payload::Json};
+
+use business::domain::challenge::use_cases::get_current::{
+    GetCurrentChallengeParams, GetCurrentChallengeUseCase,
+};
+use business::domain::challenge::use_cases::get_history::{
+    GetChallengeHistoryParams, GetChallengeHistoryUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::challenge::dto::{ChallengeHistoryResponse, ChallengeResponse};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::pagination::keyset_page;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct ChallengeApi {
+    get_current_use_case: Arc<dyn GetCurrentChallengeUseCase>,
+    get_history_use_case: Arc<dyn GetChallengeHistoryUseCase>,
+}
+
+impl ChallengeApi {
+    pub fn new(
+        get_current_use_case: Arc<dyn GetCurrentChallengeUseCase>,
+        get_history_use_case: Arc<dyn GetChallengeHistoryUseCase>,
+    ) -> Self {
+        Self {
+            get_current_use_case,
+            get_history_use_case,
+        }
+    }
+}
+
+/// Challenge API
+///
+/// Weekly "use it up" challenges.
+#[OpenApi]
+impl ChallengeApi {
+    /// Get this week's challenge
+    ///
+    /// Returns the challenge running until Sunday ends (UTC), starting it if
+    /// the user has none yet this week. Progress counts urgent products
+    /// marked as finished with the outcome `used`.
+    #[oai(
+        path = "/challenges/current",
+        method = "get",
+        tag = "ApiTags::Challenges"
+    )]
+    async fn get_current_challenge(&self, auth: BearerAuth) -> GetCurrentChallengeResponse {
+        match self
+            .get_current_use_case
+            .execute(GetCurrentChallengeParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(challenge) => GetCurrentChallengeResponse::Ok(Json(challenge.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetCurrentChallengeResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// List challenges
+    ///
+    /// Returns the user's challenges, newest week first, this week's
+    /// included. Pages are cursor-based: pass the `next_cursor` of a response
+    /// as `cursor` to get the following page.
+    #[oai(path = "/challenges", method = "get", tag = "ApiTags::Challenges")]
+    async fn get_challenge_history(
+        &self,
+        auth: BearerAuth,
+        /// Page size (default: 20, max: 100)
+        limit: Query<Option<u32>>,
+        /// Opaque cursor from a previous page
+        cursor: Query<Option<String>>,
+    ) -> GetChallengeHistoryResponse {
+        let page = match keyset_page(limit.0, cursor.0) {
+            Ok(page) => page,
+            Err(json) => return GetChallengeHistoryResponse::BadRequest(json),
+        };
+
+        match self
+            .get_history_use_case
+            .execute(GetChallengeHistoryParams {
+                user_id: UserId::new(auth.0),
+                page,
+            })
+            .await
+        {
+            Ok(page) => GetChallengeHistoryResponse::Ok(Json(page.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetChallengeHistoryResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetCurrentChallengeResponse {
+    #[oai(status = 200)]
+    Ok(Json<ChallengeResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetChallengeHistoryResponse {
+    #[oai(status = 200)]
+    Ok(Json<ChallengeHistoryResponse>),
+    /// Malformed cursor
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetCurrentChallengeResponse, GetChallengeHistoryResponse,);
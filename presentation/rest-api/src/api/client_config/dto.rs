@@ -0,0 +1,106 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::feature_flag::model::{ClientConfig, Feature};
+
+use crate::api::i18n::Language;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct FeaturesResponse {
+    /// Importing products from a photo of a till receipt
+    pub receipt_scanning: bool,
+    /// Adding products from a photo of the shelf
+    pub photo_recognition: bool,
+    /// AI recipe suggestions
+    pub suggestions: bool,
+    /// Read-only share links
+    pub share_links: bool,
+    /// AI expiry estimates; when off, estimates come from category defaults
+    pub expiry_estimation: bool,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct QuotasRemainingResponse {
+    /// AI calls left today (UTC), or null if unlimited
+    pub ai_calls: Option<u32>,
+    /// Products that can still be added, or null if unlimited
+    pub products: Option<u32>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ClientConfigResponse {
+    /// Features available on this server; hide the ones that are off
+    pub features: FeaturesResponse,
+    /// What is left of the user's plan limits
+    pub quotas_remaining: QuotasRemainingResponse,
+    /// Languages errors and content are translated to
+    pub supported_locales: Vec<String>,
+    /// Oldest supported app version; older apps should ask to update
+    pub min_app_version: String,
+}
+
+impl From<ClientConfig> for ClientConfigResponse {
+    fn from(config: ClientConfig) -> Self {
+        let usage = config.usage;
+        Self {
+            features: FeaturesResponse {
+                receipt_scanning: config.flags.is_enabled(Feature::ReceiptScanning),
+                photo_recognition: config.flags.is_enabled(Feature::PhotoRecognition),
+                suggestions: config.flags.is_enabled(Feature::Suggestions),
+                share_links: config.flags.is_enabled(Feature::ShareLinks),
+                expiry_estimation: config.flags.is_enabled(Feature::ExpiryEstimation),
+            },
+            quotas_remaining: QuotasRemainingResponse {
+                ai_calls: usage
+                    .limits
+                    .ai_calls_per_day
+                    .map(|limit| limit.saturating_sub(usage.ai_calls_today)),
+                products: usage
+                    .limits
+                    .max_products
+                    .map(|limit| limit.saturating_sub(usage.products)),
+            },
+            supported_locales: Language::ALL
+                .iter()
+                .map(|language| language.tag().to_string())
+                .collect(),
+            min_app_version: config.min_app_version,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for FeaturesResponse {
+    fn example() -> Self {
+        Self {
+            receipt_scanning: true,
+            photo_recognition: true,
+            suggestions: true,
+            share_links: false,
+            expiry_estimation: true,
+        }
+    }
+}
+
+impl Example for QuotasRemainingResponse {
+    fn example() -> Self {
+        Self {
+            ai_calls: Some(16),
+            products: Some(63),
+        }
+    }
+}
+
+impl Example for ClientConfigResponse {
+    fn example() -> Self {
+        Self {
+            features: FeaturesResponse::example(),
+            quotas_remaining: QuotasRemainingResponse::example(),
+            supported_locales: vec!["en".to_string(), "es".to_string()],
+            min_app_version: "1.4.0".to_string(),
+        }
+    }
+}
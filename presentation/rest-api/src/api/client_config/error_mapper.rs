@@ -0,0 +1,26 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::feature_flag::errors::FeatureFlagError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+use crate::api::me::error_mapper::quota_error_parts;
+
+impl IntoErrorResponse for FeatureFlagError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            FeatureFlagError::Quota(err) => quota_error_parts(err),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::feature_flag::use_cases::get_client_config::{
+    GetClientConfigParams, GetClientConfigUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::client_config::dto::ClientConfigResponse;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct ClientConfigApi {
+    get_client_config_use_case: Arc<dyn GetClientConfigUseCase>,
+}
+
+impl ClientConfigApi {
+    pub fn new(get_client_config_use_case: Arc<dyn GetClientConfigUseCase>) -> Self {
+        Self {
+            get_client_config_use_case,
+        }
+    }
+}
+
+/// Client configuration API
+///
+/// Lets the apps switch features on and off without shipping an update.
+#[OpenApi]
+impl ClientConfigApi {
+    /// Get the client configuration
+    ///
+    /// Returns which features this server offers, what is left of the user's
+    /// quotas, the supported locales and the oldest supported app version.
+    /// Meant to be fetched at app start.
+    #[oai(path = "/client-config", method = "get", tag = "ApiTags::ClientConfig")]
+    async fn get_client_config(&self, auth: BearerAuth) -> GetClientConfigResponse {
+        match self
+            .get_client_config_use_case
+            .execute(GetClientConfigParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(config) => GetClientConfigResponse::Ok(Json(config.into())),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetClientConfigResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetClientConfigResponse {
+    #[oai(status = 200)]
+    Ok(Json<ClientConfigResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetClientConfigResponse);
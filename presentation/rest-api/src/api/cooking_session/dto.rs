@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::cooking_session::model::{
+    CookingSession, CookingSessionStatus, CookingStep, CookingStepStatus,
+};
+
+use crate::api::examples::example_date;
+use crate::api::suggestion::dto::SuggestionIngredientResponse;
+
+/// State of a recipe step in cooking mode.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum CookingStepStatusDto {
+    /// Not started yet
+    #[oai(rename = "pending")]
+    Pending,
+    /// Currently being cooked
+    #[oai(rename = "active")]
+    Active,
+    /// Finished
+    #[oai(rename = "done")]
+    Done,
+}
+
+impl From<CookingStepStatus> for CookingStepStatusDto {
+    fn from(status: CookingStepStatus) -> Self {
+        match status {
+            CookingStepStatus::Pending => CookingStepStatusDto::Pending,
+            CookingStepStatus::Active => CookingStepStatusDto::Active,
+            CookingStepStatus::Done => CookingStepStatusDto::Done,
+        }
+    }
+}
+
+/// Lifecycle state of a cooking session.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum CookingSessionStatusDto {
+    /// Still cooking; can be resumed
+    #[oai(rename = "in_progress")]
+    InProgress,
+    /// Finished; pantry has been updated
+    #[oai(rename = "completed")]
+    Completed,
+}
+
+impl From<CookingSessionStatus> for CookingSessionStatusDto {
+    fn from(status: CookingSessionStatus) -> Self {
+        match status {
+            CookingSessionStatus::InProgress => CookingSessionStatusDto::InProgress,
+            CookingSessionStatus::Completed => CookingSessionStatusDto::Completed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CookingStepResponse {
+    /// Zero-based step position
+    pub position: u32,
+    /// Step instruction
+    pub instruction: String,
+    /// Step state
+    pub status: CookingStepStatusDto,
+}
+
+impl From<CookingStep> for CookingStepResponse {
+    fn from(s: CookingStep) -> Self {
+        Self {
+            position: s.position as u32,
+            instruction: s.instruction,
+            status: s.status.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CookingSessionResponse {
+    /// Session unique identifier
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
+    pub id: String,
+    /// Suggestion the session was started from
+    pub suggestion_id: String,
+    /// Recipe title
+    pub title: String,
+    /// Ingredients from user's pantry
+    pub ingredients: Vec<SuggestionIngredientResponse>,
+    /// Recipe steps with their current state
+    pub steps: Vec<CookingStepResponse>,
+    /// Position of the step being cooked
+    #[oai(skip_serializing_if_is_none)]
+    pub current_step: Option<u32>,
+    /// Session state
+    pub status: CookingSessionStatusDto,
+    /// Creation timestamp
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
+    /// Completion timestamp
+    #[oai(skip_serializing_if_is_none)]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl From<CookingSession> for CookingSessionResponse {
+    fn from(s: CookingSession) -> Self {
+        let current_step = s.current_step().map(|step| step.position as u32);
+        Self {
+            id: s.id.to_string(),
+            suggestion_id: s.suggestion_id,
+            title: s.title,
+            ingredients: s
+                .ingredients
+                .into_iter()
+                .map(|i| SuggestionIngredientResponse {
+                    product_id: i.product_id,
+                    product_name: i.product_name,
+                    quantity: i.quantity,
+                    is_urgent: i.is_urgent,
+                })
+                .collect(),
+            steps: s.steps.into_iter().map(|step| step.into()).collect(),
+            current_step,
+            status: s.status.into(),
+            created_at: s.created_at,
+            updated_at: s.updated_at,
+            completed_at: s.completed_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for CookingStepResponse {
+    fn example() -> Self {
+        Self {
+            position: 0,
+            instruction: "Pelar y cortar las patatas".to_string(),
+            status: CookingStepStatusDto::Active,
+        }
+    }
+}
+
+impl Example for CookingSessionResponse {
+    fn example() -> Self {
+        Self {
+            id: "9c8b7a6d-5e4f-4a3b-9c2d-1e0f9a8b7c6d".to_string(),
+            suggestion_id: "openai-1772355600000-0".to_string(),
+            title: "Tortilla de patatas".to_string(),
+            ingredients: vec![SuggestionIngredientResponse::example()],
+            steps: vec![
+                CookingStepResponse::example(),
+                CookingStepResponse {
+                    position: 1,
+                    instruction: "Freír las patatas a fuego lento".to_string(),
+                    status: CookingStepStatusDto::Pending,
+                },
+            ],
+            current_step: Some(0),
+            status: CookingSessionStatusDto::InProgress,
+            created_at: example_date(),
+            updated_at: example_date(),
+            completed_at: None,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::cooking_session::errors::CookingSessionError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for CookingSessionError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            CookingSessionError::NotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "cooking_session.not_found",
+            ),
+            CookingSessionError::SuggestionNotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "cooking_session.suggestion_not_found",
+            ),
+            CookingSessionError::NoSteps => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "cooking_session.no_steps",
+            ),
+            CookingSessionError::InvalidStep => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "cooking_session.invalid_step",
+            ),
+            CookingSessionError::NoRemainingSteps => (
+                StatusCode::CONFLICT,
+                "Conflict",
+                "cooking_session.no_remaining_steps",
+            ),
+            CookingSessionError::AlreadyCompleted => (
+                StatusCode::CONFLICT,
+                "Conflict",
+                "cooking_session.already_completed",
+            ),
+            CookingSessionError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,361 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::cooking_session::use_cases::advance::{
+    AdvanceCookingStepParams, AdvanceCookingStepUseCase,
+};
+use business::domain::cooking_session::use_cases::complete::{
+    CompleteCookingSessionParams, CompleteCookingSessionUseCase,
+};
+use business::domain::cooking_session::use_cases::complete_step::{
+    CompleteCookingStepParams, CompleteCookingStepUseCase,
+};
+use business::domain::cooking_session::use_cases::get_by_id::{
+    GetCookingSessionParams, GetCookingSessionUseCase,
+};
+use business::domain::cooking_session::use_cases::start::{
+    StartCookingParams, StartCookingUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::cooking_session::dto::CookingSessionResponse;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct CookingSessionApi {
+    start_use_case: Arc<dyn StartCookingUseCase>,
+    get_by_id_use_case: Arc<dyn GetCookingSessionUseCase>,
+    advance_use_case: Arc<dyn AdvanceCookingStepUseCase>,
+    complete_step_use_case: Arc<dyn CompleteCookingStepUseCase>,
+    complete_use_case: Arc<dyn CompleteCookingSessionUseCase>,
+}
+
+impl CookingSessionApi {
+    pub fn new(
+        start_use_case: Arc<dyn StartCookingUseCase>,
+        get_by_id_use_case: Arc<dyn GetCookingSessionUseCase>,
+        advance_use_case: Arc<dyn AdvanceCookingStepUseCase>,
+        complete_step_use_case: Arc<dyn CompleteCookingStepUseCase>,
+        complete_use_case: Arc<dyn CompleteCookingSessionUseCase>,
+    ) -> Self {
+        Self {
+            start_use_case,
+            get_by_id_use_case,
+            advance_use_case,
+            complete_step_use_case,
+            complete_use_case,
+        }
+    }
+}
+
+/// Cooking mode API
+///
+/// Endpoints for cooking a suggestion step by step.
+#[OpenApi]
+impl CookingSessionApi {
+    /// Start cooking a suggestion
+    ///
+    /// Creates a cooking session from a suggestion in the latest batch, with the
+    /// first step active. If an unfinished session already exists for the
+    /// suggestion, it is returned so the user can resume where they left off.
+    #[oai(
+        path = "/suggestions/:id/start-cooking",
+        method = "post",
+        tag = "ApiTags::CookingSessions"
+    )]
+    async fn start_cooking(&self, auth: BearerAuth, id: Path<String>) -> StartCookingResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .start_use_case
+            .execute(StartCookingParams {
+                user_id,
+                suggestion_id: id.0,
+            })
+            .await
+        {
+            Ok(session) => StartCookingResponse::Ok(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => StartCookingResponse::NotFound(json),
+                    422 => StartCookingResponse::UnprocessableEntity(json),
+                    _ => StartCookingResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get a cooking session
+    ///
+    /// Returns the session with the state of every step.
+    #[oai(
+        path = "/cooking-sessions/:id",
+        method = "get",
+        tag = "ApiTags::CookingSessions"
+    )]
+    async fn get_by_id(&self, auth: BearerAuth, id: Path<String>) -> GetCookingSessionResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return GetCookingSessionResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "cooking_session.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .get_by_id_use_case
+            .execute(GetCookingSessionParams { id: uuid, user_id })
+            .await
+        {
+            Ok(session) => GetCookingSessionResponse::Ok(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetCookingSessionResponse::NotFound(json),
+                    _ => GetCookingSessionResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Advance to the next step
+    ///
+    /// Marks the active step as done and activates the next pending one.
+    #[oai(
+        path = "/cooking-sessions/:id/advance",
+        method = "post",
+        tag = "ApiTags::CookingSessions"
+    )]
+    async fn advance(&self, auth: BearerAuth, id: Path<String>) -> AdvanceCookingStepResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return AdvanceCookingStepResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "cooking_session.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .advance_use_case
+            .execute(AdvanceCookingStepParams { id: uuid, user_id })
+            .await
+        {
+            Ok(session) => AdvanceCookingStepResponse::Ok(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => AdvanceCookingStepResponse::NotFound(json),
+                    409 => AdvanceCookingStepResponse::Conflict(json),
+                    _ => AdvanceCookingStepResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Complete a step
+    ///
+    /// Marks the step at the given zero-based position as done. Steps can be
+    /// completed out of order.
+    #[oai(
+        path = "/cooking-sessions/:id/steps/:position/complete",
+        method = "post",
+        tag = "ApiTags::CookingSessions"
+    )]
+    async fn complete_step(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        position: Path<u32>,
+    ) -> CompleteCookingStepResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return CompleteCookingStepResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "cooking_session.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .complete_step_use_case
+            .execute(CompleteCookingStepParams {
+                id: uuid,
+                user_id,
+                position: position.0 as usize,
+            })
+            .await
+        {
+            Ok(session) => CompleteCookingStepResponse::Ok(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CompleteCookingStepResponse::BadRequest(json),
+                    404 => CompleteCookingStepResponse::NotFound(json),
+                    409 => CompleteCookingStepResponse::Conflict(json),
+                    _ => CompleteCookingStepResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Finish cooking
+    ///
+    /// Completes the session and decrements every pantry product used by the
+    /// recipe. Products that run out are added to the shopping list.
+    #[oai(
+        path = "/cooking-sessions/:id/complete",
+        method = "post",
+        tag = "ApiTags::CookingSessions"
+    )]
+    async fn complete(&self, auth: BearerAuth, id: Path<String>) -> CompleteCookingSessionResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return CompleteCookingSessionResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "cooking_session.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .complete_use_case
+            .execute(CompleteCookingSessionParams { id: uuid, user_id })
+            .await
+        {
+            Ok(session) => CompleteCookingSessionResponse::Ok(Json(session.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => CompleteCookingSessionResponse::NotFound(json),
+                    409 => CompleteCookingSessionResponse::Conflict(json),
+                    _ => CompleteCookingSessionResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum StartCookingResponse {
+    #[oai(status = 200)]
+    Ok(Json<CookingSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetCookingSessionResponse {
+    #[oai(status = 200)]
+    Ok(Json<CookingSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum AdvanceCookingStepResponse {
+    #[oai(status = 200)]
+    Ok(Json<CookingSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CompleteCookingStepResponse {
+    #[oai(status = 200)]
+    Ok(Json<CookingSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CompleteCookingSessionResponse {
+    #[oai(status = 200)]
+    Ok(Json<CookingSessionResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    StartCookingResponse,
+    GetCookingSessionResponse,
+    AdvanceCookingStepResponse,
+    CompleteCookingStepResponse,
+    CompleteCookingSessionResponse,
+);
@@ -0,0 +1,131 @@
+use chrono::{DateTime, Duration, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::device::model::{Device, DevicePlatform, InstallCount};
+
+use crate::api::examples::example_date;
+
+/// Operating system an app install runs on.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum DevicePlatformDto {
+    #[oai(rename = "ios")]
+    Ios,
+    #[oai(rename = "android")]
+    Android,
+    #[oai(rename = "web")]
+    Web,
+}
+
+impl From<DevicePlatform> for DevicePlatformDto {
+    fn from(platform: DevicePlatform) -> Self {
+        match platform {
+            DevicePlatform::Ios => DevicePlatformDto::Ios,
+            DevicePlatform::Android => DevicePlatformDto::Android,
+            DevicePlatform::Web => DevicePlatformDto::Web,
+        }
+    }
+}
+
+impl From<DevicePlatformDto> for DevicePlatform {
+    fn from(dto: DevicePlatformDto) -> Self {
+        match dto {
+            DevicePlatformDto::Ios => DevicePlatform::Ios,
+            DevicePlatformDto::Android => DevicePlatform::Android,
+            DevicePlatformDto::Web => DevicePlatform::Web,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct RegisterDeviceRequest {
+    /// ID the app generates on first launch and keeps until uninstalled (max 128 characters)
+    pub installation_id: String,
+    pub platform: DevicePlatformDto,
+    /// Version of the app, e.g. "3.1.0" (max 32 characters)
+    pub app_version: String,
+    /// FCM or APNs token; omit it when the user didn't allow notifications
+    pub push_token: Option<String>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct DeviceResponse {
+    pub installation_id: String,
+    pub platform: DevicePlatformDto,
+    pub app_version: String,
+    /// Whether notifications are pushed to this install
+    pub push_enabled: bool,
+    /// First registration of the install
+    pub registered_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+}
+
+impl From<Device> for DeviceResponse {
+    fn from(device: Device) -> Self {
+        Self {
+            push_enabled: device.push_token.is_some(),
+            installation_id: device.installation_id,
+            platform: device.platform.into(),
+            app_version: device.app_version,
+            registered_at: device.registered_at,
+            last_seen_at: device.last_seen_at,
+        }
+    }
+}
+
+/// Installs of one app version seen in the period.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct InstallCountResponse {
+    pub platform: DevicePlatformDto,
+    pub app_version: String,
+    pub installs: u64,
+}
+
+impl From<InstallCount> for InstallCountResponse {
+    fn from(count: InstallCount) -> Self {
+        Self {
+            platform: count.platform.into(),
+            app_version: count.app_version,
+            installs: count.installs,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for RegisterDeviceRequest {
+    fn example() -> Self {
+        Self {
+            installation_id: "5f0c2a7e-4f7e-4b7a-9d1c-2e8a1f3b6c90".to_string(),
+            platform: DevicePlatformDto::Android,
+            app_version: "3.1.0".to_string(),
+            push_token: Some("fcm-registration-token".to_string()),
+        }
+    }
+}
+
+impl Example for DeviceResponse {
+    fn example() -> Self {
+        Self {
+            installation_id: "5f0c2a7e-4f7e-4b7a-9d1c-2e8a1f3b6c90".to_string(),
+            platform: DevicePlatformDto::Android,
+            app_version: "3.1.0".to_string(),
+            push_enabled: true,
+            registered_at: example_date() - Duration::days(60),
+            last_seen_at: example_date(),
+        }
+    }
+}
+
+impl Example for InstallCountResponse {
+    fn example() -> Self {
+        Self {
+            platform: DevicePlatformDto::Ios,
+            app_version: "3.1.0".to_string(),
+            installs: 412,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::device::errors::DeviceError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for DeviceError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            DeviceError::InvalidInstallationId => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "device.invalid_installation_id",
+            ),
+            DeviceError::InvalidAppVersion => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "device.invalid_app_version",
+            ),
+            DeviceError::InvalidPushToken => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "device.invalid_push_token",
+            ),
+            DeviceError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Query, payload::Json};
+
+use business::domain::device::use_cases::get_active_installs::{
+    GetActiveInstallsParams, GetActiveInstallsUseCase,
+};
+use business::domain::device::use_cases::register::{RegisterDeviceParams, RegisterDeviceUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::device::dto::{DeviceResponse, InstallCountResponse, RegisterDeviceRequest};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::admin_config::AdminConfig;
+
+/// Default and largest period an install counts as active for, in days
+const DEFAULT_ACTIVE_DAYS: u32 = 30;
+const MAX_ACTIVE_DAYS: u32 = 365;
+
+pub struct DeviceApi {
+    register_use_case: Arc<dyn RegisterDeviceUseCase>,
+    get_active_installs_use_case: Arc<dyn GetActiveInstallsUseCase>,
+    admin_config: AdminConfig,
+}
+
+impl DeviceApi {
+    pub fn new(
+        register_use_case: Arc<dyn RegisterDeviceUseCase>,
+        get_active_installs_use_case: Arc<dyn GetActiveInstallsUseCase>,
+        admin_config: AdminConfig,
+    ) -> Self {
+        Self {
+            register_use_case,
+            get_active_installs_use_case,
+            admin_config,
+        }
+    }
+}
+
+/// Devices API
+///
+/// The app installs of each user: where notifications are pushed, and which
+/// app versions are still out there.
+#[OpenApi]
+impl DeviceApi {
+    /// Register this device
+    ///
+    /// Call it on every launch and whenever the push token changes. The first
+    /// call registers the install; later ones refresh its app version, push
+    /// token and last-seen time. Installs not seen for a while are pruned.
+    #[oai(path = "/devices", method = "post", tag = "ApiTags::Devices")]
+    async fn register_device(
+        &self,
+        auth: BearerAuth,
+        body: Json<RegisterDeviceRequest>,
+    ) -> RegisterDeviceResponse {
+        let body = body.0;
+        let params = RegisterDeviceParams {
+            user_id: UserId::new(auth.0),
+            installation_id: body.installation_id,
+            platform: body.platform.into(),
+            app_version: body.app_version,
+            push_token: body.push_token,
+        };
+
+        match self.register_use_case.execute(params).await {
+            Ok(device) => RegisterDeviceResponse::Ok(Json(device.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => RegisterDeviceResponse::BadRequest(json),
+                    _ => RegisterDeviceResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get active installs
+    ///
+    /// Installs seen in the period per platform and app version, across all
+    /// users, most installs first. Only users listed in `ADMIN_USER_IDS` may
+    /// call it.
+    #[oai(path = "/admin/devices", method = "get", tag = "ApiTags::Admin")]
+    async fn get_active_installs(
+        &self,
+        auth: BearerAuth,
+        /// Days since an install was last seen for it to count (default: 30, max: 365)
+        days: Query<Option<u32>>,
+    ) -> GetActiveInstallsResponse {
+        if !self.admin_config.is_admin(&auth.0) {
+            return GetActiveInstallsResponse::Forbidden(Json(ErrorResponse {
+                name: "Forbidden".to_string(),
+                message: "auth.forbidden".to_string(),
+                description: None,
+            }));
+        }
+
+        let days = days
+            .0
+            .unwrap_or(DEFAULT_ACTIVE_DAYS)
+            .clamp(1, MAX_ACTIVE_DAYS);
+
+        match self
+            .get_active_installs_use_case
+            .execute(GetActiveInstallsParams { days })
+            .await
+        {
+            Ok(counts) => {
+                GetActiveInstallsResponse::Ok(Json(counts.into_iter().map(Into::into).collect()))
+            }
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetActiveInstallsResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RegisterDeviceResponse {
+    #[oai(status = 200)]
+    Ok(Json<DeviceResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetActiveInstallsResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<InstallCountResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(RegisterDeviceResponse, GetActiveInstallsResponse);
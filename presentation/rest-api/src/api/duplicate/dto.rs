@@ -0,0 +1,65 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::product::duplicates::DuplicateCluster;
+
+use crate::api::product::dto::ProductResponse;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct DuplicateClusterResponse {
+    /// Name key the products share: lowercase letters and digits, accents dropped
+    pub key: String,
+    /// Oldest first
+    pub products: Vec<ProductResponse>,
+    /// Body for `POST /products/duplicates/merge` merging the whole cluster
+    /// into its oldest product
+    pub merge: MergeDuplicatesRequest,
+}
+
+impl From<DuplicateCluster> for DuplicateClusterResponse {
+    fn from(cluster: DuplicateCluster) -> Self {
+        Self {
+            merge: MergeDuplicatesRequest {
+                product_ids: cluster.products.iter().map(|p| p.id.to_string()).collect(),
+                keep_id: None,
+            },
+            key: cluster.key,
+            products: cluster.products.into_iter().map(|p| p.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct MergeDuplicatesRequest {
+    /// Active products with the same name key; duplicates are counted once
+    #[oai(validator(min_items = 2, max_items = 100))]
+    pub product_ids: Vec<String>,
+    /// Product to keep, one of `productIds` (default: the oldest)
+    #[oai(skip_serializing_if_is_none)]
+    pub keep_id: Option<String>,
+}
+
+// --- OpenAPI examples ---
+
+impl Example for DuplicateClusterResponse {
+    fn example() -> Self {
+        Self {
+            key: "lechesemidesnatada".to_string(),
+            products: vec![ProductResponse::example()],
+            merge: MergeDuplicatesRequest::example(),
+        }
+    }
+}
+
+impl Example for MergeDuplicatesRequest {
+    fn example() -> Self {
+        Self {
+            product_ids: vec![
+                "3f6b2c1a-8d4e-4a7b-9c2d-1e0f5a6b7c8d".to_string(),
+                "7c1e9a4b-2f3d-4e5a-8b6c-0d9e1f2a3b4c".to_string(),
+            ],
+            keep_id: None,
+        }
+    }
+}
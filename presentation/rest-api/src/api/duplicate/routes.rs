@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+use uuid::Uuid;
+
+use business::domain::product::use_cases::get_duplicates::{
+    GetDuplicateProductsParams, GetDuplicateProductsUseCase,
+};
+use business::domain::product::use_cases::merge_duplicates::{
+    MergeDuplicateProductsParams, MergeDuplicateProductsUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::duplicate::dto::{DuplicateClusterResponse, MergeDuplicatesRequest};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::product::dto::ProductResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct DuplicateApi {
+    get_duplicates_use_case: Arc<dyn GetDuplicateProductsUseCase>,
+    merge_duplicates_use_case: Arc<dyn MergeDuplicateProductsUseCase>,
+}
+
+impl DuplicateApi {
+    pub fn new(
+        get_duplicates_use_case: Arc<dyn GetDuplicateProductsUseCase>,
+        merge_duplicates_use_case: Arc<dyn MergeDuplicateProductsUseCase>,
+    ) -> Self {
+        Self {
+            get_duplicates_use_case,
+            merge_duplicates_use_case,
+        }
+    }
+}
+
+/// Duplicate products API
+///
+/// Endpoints for finding products entered twice, typically once from a
+/// receipt and once by hand or barcode, and merging them.
+#[OpenApi]
+impl DuplicateApi {
+    /// List probable duplicates
+    ///
+    /// Groups the active products whose names match once case, accents,
+    /// spacing and punctuation are ignored. Larger clusters come first. Each
+    /// cluster carries the body that merges it in one call.
+    #[oai(
+        path = "/products/duplicates",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_duplicates(&self, auth: BearerAuth) -> GetDuplicatesResponse {
+        match self
+            .get_duplicates_use_case
+            .execute(GetDuplicateProductsParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(clusters) => {
+                GetDuplicatesResponse::Ok(Json(clusters.into_iter().map(|c| c.into()).collect()))
+            }
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetDuplicatesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Merge duplicates
+    ///
+    /// Keeps one product and deletes the others. The kept product takes the
+    /// earliest expiry date and estimate among them, and their location and
+    /// quantity when it has none. Answers `400 product.not_duplicates` when
+    /// the products don't share a name key or any of them is finished.
+    #[oai(
+        path = "/products/duplicates/merge",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn merge_duplicates(
+        &self,
+        auth: BearerAuth,
+        body: Json<MergeDuplicatesRequest>,
+    ) -> MergeDuplicatesResponse {
+        let ids = body
+            .0
+            .product_ids
+            .iter()
+            .map(|id| Uuid::parse_str(id))
+            .collect::<Result<Vec<_>, _>>();
+        let keep_id = body.0.keep_id.as_deref().map(Uuid::parse_str).transpose();
+        let (product_ids, keep_id) = match (ids, keep_id) {
+            (Ok(ids), Ok(keep_id)) => (ids, keep_id),
+            _ => {
+                return MergeDuplicatesResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .merge_duplicates_use_case
+            .execute(MergeDuplicateProductsParams {
+                user_id: UserId::new(auth.0),
+                product_ids,
+                keep_id,
+            })
+            .await
+        {
+            Ok(product) => MergeDuplicatesResponse::Ok(Json(product.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => MergeDuplicatesResponse::BadRequest(json),
+                    404 => MergeDuplicatesResponse::NotFound(json),
+                    _ => MergeDuplicatesResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetDuplicatesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<DuplicateClusterResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum MergeDuplicatesResponse {
+    #[oai(status = 200)]
+    Ok(Json<ProductResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetDuplicatesResponse, MergeDuplicatesResponse);
@@ -1,12 +1,147 @@
+use std::error::Error;
+
 use poem::http::StatusCode;
-use poem_openapi::{Object, payload::Json};
+use poem_openapi::{Object, payload::Json, types::Example};
+
+use business::domain::errors::ErrorChain;
 
-#[derive(Object, Debug)]
+#[derive(Object, Debug, Clone)]
+#[oai(example)]
 pub struct ErrorResponse {
+    /// Error category (e.g. ValidationError, NotFound, InternalError)
     pub name: String,
+    /// Code-style error identifier for i18n (e.g. product.not_found)
     pub message: String,
+    /// Human-readable message, present when `Accept-Language` is es or en
+    #[oai(skip_serializing_if_is_none)]
+    pub description: Option<String>,
+}
+
+impl Example for ErrorResponse {
+    fn example() -> Self {
+        Self {
+            name: "NotFound".to_string(),
+            message: "product.not_found".to_string(),
+            description: None,
+        }
+    }
 }
 
 pub trait IntoErrorResponse {
     fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>);
 }
+
+/// Logs a domain error with its full source chain when it wraps a cause
+/// (driver, HTTP or parse failure). The response only carries the outer code,
+/// so this is the one place the cause is recorded.
+pub fn log_error_chain(status: StatusCode, err: &(dyn Error + 'static)) {
+    if err.source().is_none() {
+        return;
+    }
+    if status.is_server_error() {
+        tracing::error!("Request failed: {}", ErrorChain(err));
+    } else {
+        tracing::warn!("Request rejected: {}", ErrorChain(err));
+    }
+}
+
+/// Response enums that can report errors raised before the handler runs, such
+/// as a missing or invalid bearer token or a malformed path, query or body.
+pub trait RequestErrorResponse {
+    fn bad_request(json: Json<ErrorResponse>) -> Self;
+    fn unauthorized(json: Json<ErrorResponse>) -> Self;
+    fn forbidden(json: Json<ErrorResponse>) -> Self;
+}
+
+/// Turns extractor and authentication failures into structured error bodies
+/// instead of poem's plain-text defaults. Used as `bad_request_handler` on every
+/// protected endpoint response.
+pub fn handle_request_error<T: RequestErrorResponse>(err: poem::Error) -> T {
+    match err.status() {
+        StatusCode::UNAUTHORIZED => T::unauthorized(Json(ErrorResponse {
+            name: "Unauthorized".to_string(),
+            message: "auth.unauthorized".to_string(),
+            description: None,
+        })),
+        StatusCode::FORBIDDEN => T::forbidden(Json(ErrorResponse {
+            name: "Forbidden".to_string(),
+            message: "auth.forbidden".to_string(),
+            description: None,
+        })),
+        _ => T::bad_request(Json(ErrorResponse {
+            name: "ValidationError".to_string(),
+            message: "request.invalid".to_string(),
+            description: None,
+        })),
+    }
+}
+
+/// Implements [`RequestErrorResponse`] for response enums that declare
+/// `BadRequest`, `Unauthorized` and `Forbidden` variants.
+macro_rules! impl_request_error_response {
+    ($($response:ty),+ $(,)?) => {
+        $(
+            impl $crate::api::error::RequestErrorResponse for $response {
+                fn bad_request(json: Json<ErrorResponse>) -> Self {
+                    Self::BadRequest(json)
+                }
+
+                fn unauthorized(json: Json<ErrorResponse>) -> Self {
+                    Self::Unauthorized(json)
+                }
+
+                fn forbidden(json: Json<ErrorResponse>) -> Self {
+                    Self::Forbidden(json)
+                }
+            }
+        )+
+    };
+}
+
+pub(crate) use impl_request_error_response;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum TestResponse {
+        BadRequest(Json<ErrorResponse>),
+        Unauthorized(Json<ErrorResponse>),
+        Forbidden(Json<ErrorResponse>),
+    }
+
+    impl_request_error_response!(TestResponse);
+
+    #[test]
+    fn should_return_structured_unauthorized_when_auth_fails() {
+        let err = poem::Error::from_status(StatusCode::UNAUTHORIZED);
+
+        match handle_request_error::<TestResponse>(err) {
+            TestResponse::Unauthorized(json) => {
+                assert_eq!(json.0.name, "Unauthorized");
+                assert_eq!(json.0.message, "auth.unauthorized");
+            }
+            _ => panic!("expected unauthorized"),
+        }
+    }
+
+    #[test]
+    fn should_return_structured_forbidden_when_access_denied() {
+        let err = poem::Error::from_status(StatusCode::FORBIDDEN);
+
+        match handle_request_error::<TestResponse>(err) {
+            TestResponse::Forbidden(json) => assert_eq!(json.0.message, "auth.forbidden"),
+            _ => panic!("expected forbidden"),
+        }
+    }
+
+    #[test]
+    fn should_return_validation_error_when_request_malformed() {
+        let err = poem::Error::from_status(StatusCode::BAD_REQUEST);
+
+        match handle_request_error::<TestResponse>(err) {
+            TestResponse::BadRequest(json) => assert_eq!(json.0.message, "request.invalid"),
+            _ => panic!("expected bad request"),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! Shared helpers for OpenAPI `Example` implementations.
+
+use chrono::{DateTime, Utc};
+
+/// Fixed timestamp used in examples so the generated spec is stable across builds.
+pub fn example_date() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339("2026-03-01T09:00:00Z")
+        .map(|d| d.with_timezone(&Utc))
+        .unwrap_or_default()
+}
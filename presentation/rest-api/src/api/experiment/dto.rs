@@ -0,0 +1,65 @@
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::experiment::model::{AiFeature, VariantResult};
+
+/// AI feature an experiment runs on.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum AiFeatureDto {
+    /// Naming a product from a photo
+    #[oai(rename = "product_identification")]
+    ProductIdentification,
+    /// Recipe suggestions
+    #[oai(rename = "suggestions")]
+    Suggestions,
+}
+
+impl From<AiFeature> for AiFeatureDto {
+    fn from(feature: AiFeature) -> Self {
+        match feature {
+            AiFeature::ProductIdentification => AiFeatureDto::ProductIdentification,
+            AiFeature::Suggestions => AiFeatureDto::Suggestions,
+        }
+    }
+}
+
+/// How one variant of an experiment did.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct VariantResultResponse {
+    pub feature: AiFeatureDto,
+    pub variant: String,
+    /// AI calls made under the variant in the period
+    pub calls: u64,
+    /// Calls whose result the user kept: identifications reported as
+    /// accepted, suggestion batches with a recipe cooked
+    pub accepted: u64,
+    /// `accepted` over `calls`, between 0 and 1
+    pub acceptance_rate: f64,
+}
+
+impl From<VariantResult> for VariantResultResponse {
+    fn from(result: VariantResult) -> Self {
+        Self {
+            acceptance_rate: result.acceptance_rate(),
+            feature: result.feature.into(),
+            variant: result.variant,
+            calls: result.calls,
+            accepted: result.accepted,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for VariantResultResponse {
+    fn example() -> Self {
+        Self {
+            feature: AiFeatureDto::Suggestions,
+            variant: "v2".to_string(),
+            calls: 240,
+            accepted: 66,
+            acceptance_rate: 0.275,
+        }
+    }
+}
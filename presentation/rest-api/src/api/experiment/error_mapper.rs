@@ -0,0 +1,34 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::experiment::errors::ExperimentError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for ExperimentError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            ExperimentError::AiCallNotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "experiment.ai_call_not_found",
+            ),
+            ExperimentError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
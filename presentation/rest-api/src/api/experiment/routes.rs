@@ -0,0 +1,175 @@
+use std::sync::Arc;
+
+use poem_openapi::{
+    OpenApi,
+    param::{Path, Query},
+    payload::Json,
+};
+use uuid::Uuid;
+
+use business::domain::experiment::use_cases::accept_identification::{
+    AcceptIdentificationParams, AcceptIdentificationUseCase,
+};
+use business::domain::experiment::use_cases::get_results::{
+    GetExperimentResultsParams, GetExperimentResultsUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::experiment::dto::VariantResultResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::admin_config::AdminConfig;
+
+/// Default and largest period of the results, in days
+const DEFAULT_RESULT_DAYS: u32 = 30;
+const MAX_RESULT_DAYS: u32 = 365;
+
+pub struct ExperimentApi {
+    accept_identification_use_case: Arc<dyn AcceptIdentificationUseCase>,
+    get_results_use_case: Arc<dyn GetExperimentResultsUseCase>,
+    admin_config: AdminConfig,
+}
+
+impl ExperimentApi {
+    pub fn new(
+        accept_identification_use_case: Arc<dyn AcceptIdentificationUseCase>,
+        get_results_use_case: Arc<dyn GetExperimentResultsUseCase>,
+        admin_config: AdminConfig,
+    ) -> Self {
+        Self {
+            accept_identification_use_case,
+            get_results_use_case,
+            admin_config,
+        }
+    }
+}
+
+/// Experiment API
+///
+/// Prompt and model experiments on AI features: the quality signals clients
+/// report and the results operators compare variants on.
+#[OpenApi]
+impl ExperimentApi {
+    /// Report an identification as accepted
+    ///
+    /// Call it when the user keeps a photo identification that came with an
+    /// `aiCallId`, so the experiment variant that made it is credited.
+    /// Reporting the same identification again does nothing.
+    #[oai(
+        path = "/products/identify/:ai_call_id/accept",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn accept_identification(
+        &self,
+        auth: BearerAuth,
+        ai_call_id: Path<String>,
+    ) -> AcceptIdentificationResponse {
+        let Ok(ai_call_id) = Uuid::parse_str(&ai_call_id.0) else {
+            return AcceptIdentificationResponse::BadRequest(Json(ErrorResponse {
+                name: "ValidationError".to_string(),
+                message: "experiment.invalid_ai_call_id".to_string(),
+                description: None,
+            }));
+        };
+
+        let params = AcceptIdentificationParams {
+            user_id: UserId::new(auth.0),
+            ai_call_id,
+        };
+
+        match self.accept_identification_use_case.execute(params).await {
+            Ok(()) => AcceptIdentificationResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => AcceptIdentificationResponse::NotFound(json),
+                    _ => AcceptIdentificationResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get experiment results
+    ///
+    /// Calls and acceptances per feature and variant over the period, across
+    /// all users. Variants of the experiments configured in
+    /// `AI_EXPERIMENT_*` are listed even without calls; variants no longer
+    /// configured are listed while they have calls in the period. Only users
+    /// listed in `ADMIN_USER_IDS` may call it.
+    #[oai(
+        path = "/admin/experiments/results",
+        method = "get",
+        tag = "ApiTags::Admin"
+    )]
+    async fn get_results(
+        &self,
+        auth: BearerAuth,
+        /// Days of calls to count, today included (default: 30, max: 365)
+        days: Query<Option<u32>>,
+    ) -> GetExperimentResultsResponse {
+        if !self.admin_config.is_admin(&auth.0) {
+            return GetExperimentResultsResponse::Forbidden(Json(ErrorResponse {
+                name: "Forbidden".to_string(),
+                message: "auth.forbidden".to_string(),
+                description: None,
+            }));
+        }
+
+        let days = days
+            .0
+            .unwrap_or(DEFAULT_RESULT_DAYS)
+            .clamp(1, MAX_RESULT_DAYS);
+
+        match self
+            .get_results_use_case
+            .execute(GetExperimentResultsParams { days })
+            .await
+        {
+            Ok(results) => GetExperimentResultsResponse::Ok(Json(
+                results.into_iter().map(Into::into).collect(),
+            )),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetExperimentResultsResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum AcceptIdentificationResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetExperimentResultsResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<VariantResultResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(AcceptIdentificationResponse, GetExperimentResultsResponse);
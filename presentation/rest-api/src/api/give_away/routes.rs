@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::product::use_cases::flag_unwanted::{
+    FlagUnwantedParams, FlagUnwantedUseCase,
+};
+use business::domain::product::use_cases::get_give_away::{
+    GetGiveAwayCandidatesParams, GetGiveAwayCandidatesUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::product::dto::ProductResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct GiveAwayApi {
+    get_candidates_use_case: Arc<dyn GetGiveAwayCandidatesUseCase>,
+    flag_unwanted_use_case: Arc<dyn FlagUnwantedUseCase>,
+}
+
+impl GiveAwayApi {
+    pub fn new(
+        get_candidates_use_case: Arc<dyn GetGiveAwayCandidatesUseCase>,
+        flag_unwanted_use_case: Arc<dyn FlagUnwantedUseCase>,
+    ) -> Self {
+        Self {
+            get_candidates_use_case,
+            flag_unwanted_use_case,
+        }
+    }
+
+    async fn set_unwanted(
+        &self,
+        auth: BearerAuth,
+        id: &str,
+        unwanted: bool,
+    ) -> FlagUnwantedResponse {
+        let uuid = match Uuid::parse_str(id) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return FlagUnwantedResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .flag_unwanted_use_case
+            .execute(FlagUnwantedParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+                unwanted,
+            })
+            .await
+        {
+            Ok(()) => FlagUnwantedResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => FlagUnwantedResponse::NotFound(json),
+                    _ => FlagUnwantedResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+/// Give-away API
+///
+/// Endpoints for flagging products the user won't use and listing the ones
+/// to give away before they expire.
+#[OpenApi]
+impl GiveAwayApi {
+    /// List give-away candidates
+    ///
+    /// Returns the products flagged as unwanted that expire within the user's
+    /// expiring-soon window, soonest first. Expired products are left out.
+    #[oai(
+        path = "/products/give-away",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_candidates(&self, auth: BearerAuth) -> GetGiveAwayCandidatesResponse {
+        match self
+            .get_candidates_use_case
+            .execute(GetGiveAwayCandidatesParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(products) => GetGiveAwayCandidatesResponse::Ok(Json(
+                products.into_iter().map(|p| p.into()).collect(),
+            )),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetGiveAwayCandidatesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Flag a product as unwanted
+    ///
+    /// Marks a product the user won't use, so it is suggested for giving away
+    /// once it is about to expire. Finish it with the `given_away` outcome once
+    /// it has been given away.
+    #[oai(
+        path = "/products/:id/unwanted",
+        method = "put",
+        tag = "ApiTags::Products"
+    )]
+    async fn flag_unwanted(&self, auth: BearerAuth, id: Path<String>) -> FlagUnwantedResponse {
+        self.set_unwanted(auth, &id.0, true).await
+    }
+
+    /// Clear the unwanted flag
+    ///
+    /// The product is no longer suggested for giving away.
+    #[oai(
+        path = "/products/:id/unwanted",
+        method = "delete",
+        tag = "ApiTags::Products"
+    )]
+    async fn clear_unwanted(&self, auth: BearerAuth, id: Path<String>) -> FlagUnwantedResponse {
+        self.set_unwanted(auth, &id.0, false).await
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetGiveAwayCandidatesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<ProductResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum FlagUnwantedResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetGiveAwayCandidatesResponse, FlagUnwantedResponse);
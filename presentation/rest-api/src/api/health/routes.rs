@@ -1,11 +1,12 @@
 use chrono::Utc;
-use poem_openapi::{Object, OpenApi, payload::Json};
+use poem_openapi::{Object, OpenApi, payload::Json, types::Example};
 use serde::{Deserialize, Serialize};
 
 use crate::api::tags::ApiTags;
 
 /// Health check response
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[oai(example)]
 pub struct HealthCheckResponse {
     /// Service status
     pub status: String,
@@ -15,6 +16,16 @@ pub struct HealthCheckResponse {
     pub version: String,
 }
 
+impl Example for HealthCheckResponse {
+    fn example() -> Self {
+        Self {
+            status: "healthy".to_string(),
+            timestamp: "2026-03-01T09:00:00Z".to_string(),
+            version: "0.1.0".to_string(),
+        }
+    }
+}
+
 /// Health API for monitoring and infrastructure checks
 ///
 /// This module provides a health check endpoint for Kubernetes, Docker,
@@ -0,0 +1,93 @@
+use sha2::{Digest, Sha256};
+
+/// Badge counts change any time, so clients always revalidate; unchanged
+/// counts come back as an empty `304`.
+pub const BADGES_CACHE_CONTROL: &str = "private, no-cache";
+
+/// Barcode lookups resolve to the same Open Food Facts entry for a long time.
+pub const BARCODE_CACHE_CONTROL: &str = "private, max-age=604800";
+
+/// Answers from a user's own contribution can be renamed or superseded by the
+/// catalog at any time, so they are never stored.
+pub const CONTRIBUTED_BARCODE_CACHE_CONTROL: &str = "private, no-store";
+
+/// Expiry estimates are absolute dates counted from the moment of estimation,
+/// so they are kept no longer than the server reuses them (one hour).
+pub const EXPIRY_ESTIMATE_CACHE_CONTROL: &str = "private, max-age=3600";
+
+/// Widgets poll often and must render even when the request fails, so a
+/// snapshot is reused for a few minutes and can be served stale for a day.
+pub const WIDGET_CACHE_CONTROL: &str =
+    "private, max-age=300, stale-while-revalidate=3600, stale-if-error=86400";
+
+/// Builds a strong ETag from the inputs that determine a response.
+///
+/// Inputs are trimmed and lowercased so trivially different requests share the
+/// same key. The namespace keeps keys from different endpoints apart.
+pub fn cache_key(namespace: &str, parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(namespace.as_bytes());
+    for part in parts {
+        hasher.update([0x1f]);
+        hasher.update(part.trim().to_lowercase().as_bytes());
+    }
+
+    let hash: String = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!("\"{}\"", hash)
+}
+
+/// Returns true when an `If-None-Match` header matches the given ETag, so the
+/// request can be answered with `304 Not Modified`. `*` is not honoured: the
+/// callers can't tell whether a representation exists without computing it.
+pub fn is_not_modified(if_none_match: Option<&str>, etag: &str) -> bool {
+    let Some(header) = if_none_match else {
+        return false;
+    };
+
+    header
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate.strip_prefix("W/").unwrap_or(candidate) == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_produce_same_key_when_inputs_differ_only_in_case_and_spacing() {
+        let a = cache_key("expiry", &["Leche entera", "opened", "fridge"]);
+        let b = cache_key("expiry", &["  leche ENTERA ", "opened", "fridge"]);
+
+        assert_eq!(a, b);
+        assert!(a.starts_with('"') && a.ends_with('"'));
+    }
+
+    #[test]
+    fn should_produce_different_keys_when_namespace_differs() {
+        let a = cache_key("barcode", &["8410188012092"]);
+        let b = cache_key("expiry", &["8410188012092"]);
+
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn should_match_when_if_none_match_contains_etag() {
+        let etag = cache_key("barcode", &["8410188012092"]);
+        let header = format!("\"other\", W/{}", etag);
+
+        assert!(is_not_modified(Some(&header), &etag));
+    }
+
+    #[test]
+    fn should_not_match_when_header_missing_or_different() {
+        let etag = cache_key("barcode", &["8410188012092"]);
+
+        assert!(!is_not_modified(None, &etag));
+        assert!(!is_not_modified(Some("\"other\""), &etag));
+    }
+}
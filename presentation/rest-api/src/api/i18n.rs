@@ -0,0 +1,649 @@
+use poem::http::{HeaderValue, header};
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+
+/// Languages with a translated error catalog.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    En,
+    Es,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::En, Language::Es];
+
+    /// BCP 47 tag of the language.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+        }
+    }
+
+    /// Picks the preferred supported language from an `Accept-Language` header,
+    /// honouring quality values. Returns `None` when no supported language is
+    /// acceptable, in which case errors are left untranslated.
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut candidates: Vec<(Self, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.trim().split(';');
+                let tag = parts.next()?.trim().to_lowercase();
+                let quality = parts
+                    .find_map(|p| p.trim().strip_prefix("q="))
+                    .and_then(|q| q.parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                let primary = tag.split('-').next()?;
+                let language = match primary {
+                    "en" => Language::En,
+                    "es" => Language::Es,
+                    _ => return None,
+                };
+                (quality > 0.0).then_some((language, quality))
+            })
+            .collect();
+
+        // Stable sort keeps header order for equal weights
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        candidates.first().map(|(language, _)| *language)
+    }
+}
+
+/// Resolves an error code to a human-readable message.
+pub fn translate(code: &str, language: Language) -> Option<&'static str> {
+    let (en, es) = match code {
+        "auth.unauthorized" => (
+            "You need to sign in to do this.",
+            "Necesitas iniciar sesión para hacer esto.",
+        ),
+        "auth.forbidden" => (
+            "You don't have access to this resource.",
+            "No tienes acceso a este recurso.",
+        ),
+        "auth.local_disabled" => (
+            "This server signs in with Firebase.",
+            "Este servidor inicia sesión con Firebase.",
+        ),
+        "auth.invalid_email" => (
+            "Enter a valid email address.",
+            "Introduce un correo electrónico válido.",
+        ),
+        "auth.invalid_link" => (
+            "This sign-in link has expired or was already used. Request a new one.",
+            "Este enlace de inicio de sesión ha caducado o ya se usó. Solicita uno nuevo.",
+        ),
+        "auth.invalid_passkey" => (
+            "The passkey could not be verified. Try again.",
+            "No se pudo verificar la llave de acceso. Inténtalo de nuevo.",
+        ),
+        "auth.unknown_passkey" => (
+            "This passkey is not registered.",
+            "Esta llave de acceso no está registrada.",
+        ),
+        "auth.delivery_failed" => (
+            "The sign-in email could not be sent. Try again later.",
+            "No se pudo enviar el correo de inicio de sesión. Inténtalo más tarde.",
+        ),
+        "auth.token_failed" => (
+            "You could not be signed in. Try again later.",
+            "No se pudo iniciar sesión. Inténtalo más tarde.",
+        ),
+        "request.invalid" => ("The request is not valid.", "La solicitud no es válida."),
+        "request.unsupported_media_type" => (
+            "The request body must be JSON.",
+            "El cuerpo de la solicitud debe ser JSON.",
+        ),
+        "app.upgrade_required" => (
+            "This version of the app is no longer supported. Update it to continue.",
+            "Esta versión de la app ya no es compatible. Actualízala para continuar.",
+        ),
+        "backup.unsupported_format" => (
+            "This backup was made by an incompatible version and can't be restored.",
+            "Esta copia la creó una versión incompatible y no se puede restaurar.",
+        ),
+        "backup.newer_schema" => (
+            "This backup comes from a newer server. Update this one before restoring it.",
+            "Esta copia viene de un servidor más reciente. Actualiza este antes de restaurarla.",
+        ),
+        "backup.account_not_empty" => (
+            "Backups can only be restored into an empty account.",
+            "Las copias solo se pueden restaurar en una cuenta vacía.",
+        ),
+        "backup.invalid_row" => (
+            "This backup holds data that can't be restored, so nothing was restored.",
+            "Esta copia contiene datos que no se pueden restaurar, así que no se ha restaurado nada.",
+        ),
+        "maintenance.read_only" => (
+            "We're doing maintenance. You can look around, but changes can't be saved right now.",
+            "Estamos en mantenimiento. Puedes consultar tus datos, pero ahora no se pueden guardar cambios.",
+        ),
+        "image.too_large" => (
+            "The image is too large. Try a smaller photo.",
+            "La imagen es demasiado grande. Prueba con una foto más pequeña.",
+        ),
+        "load_test.disabled" => (
+            "Load-test endpoints are disabled.",
+            "Los endpoints de pruebas de carga están desactivados.",
+        ),
+        "rate_limit.exceeded" => (
+            "Too many requests. Please wait a moment and try again.",
+            "Demasiadas peticiones. Espera un momento y vuelve a intentarlo.",
+        ),
+        "quota.ai_calls_exceeded" => (
+            "You've used all of today's AI requests. Try again tomorrow or upgrade your plan.",
+            "Has agotado las peticiones de IA de hoy. Vuelve mañana o mejora tu plan.",
+        ),
+        "quota.product_limit_reached" => (
+            "You've reached your plan's product limit. Upgrade to add more.",
+            "Has alcanzado el límite de productos de tu plan. Mejóralo para añadir más.",
+        ),
+        "billing.checkout_failed" => (
+            "We couldn't start the checkout. Please try again later.",
+            "No hemos podido iniciar el pago. Inténtalo de nuevo más tarde.",
+        ),
+        "billing.invalid_signature" => (
+            "The webhook signature is not valid.",
+            "La firma del webhook no es válida.",
+        ),
+        "billing.invalid_event" => (
+            "The billing event is not valid.",
+            "El evento de facturación no es válido.",
+        ),
+        "repository.persistence" => (
+            "Something went wrong. Please try again later.",
+            "Algo salió mal. Inténtalo de nuevo más tarde.",
+        ),
+        "ai_review.invalid_id" => (
+            "The pending change ID is not valid.",
+            "El ID del cambio pendiente no es válido.",
+        ),
+        "ai_review.not_found" => (
+            "Pending change not found.",
+            "Cambio pendiente no encontrado.",
+        ),
+        "job.invalid_id" => (
+            "The job ID is not valid.",
+            "El ID de la tarea no es válido.",
+        ),
+        "job.not_found" => ("Job not found.", "Tarea no encontrada."),
+        "pagination.invalid_cursor" => (
+            "The page cursor is not valid.",
+            "El cursor de página no es válido.",
+        ),
+        "product.invalid_id" => (
+            "The product ID is not valid.",
+            "El ID del producto no es válido.",
+        ),
+        "product.name_empty" => (
+            "The product name can't be empty.",
+            "El nombre del producto no puede estar vacío.",
+        ),
+        "product.not_found" => ("Product not found.", "Producto no encontrado."),
+        "product.invalid_status_transition" => (
+            "A product can't go back to that status.",
+            "Un producto no puede volver a ese estado.",
+        ),
+        "product.outcome_requires_finished_status" => (
+            "An outcome can only be set on finished products.",
+            "Solo se puede indicar el destino de productos terminados.",
+        ),
+        "product.identification_failed" => (
+            "We couldn't identify the product.",
+            "No hemos podido identificar el producto.",
+        ),
+        "product.scan_failed" => (
+            "We couldn't read the receipt.",
+            "No hemos podido leer el ticket.",
+        ),
+        "product.invalid_image" => (
+            "The photo must be a JPEG, PNG or WebP image.",
+            "La foto debe ser una imagen JPEG, PNG o WebP.",
+        ),
+        "product.image_not_found" => ("This product has no photo.", "Este producto no tiene foto."),
+        "product.not_duplicates" => (
+            "Only active products with the same name can be merged.",
+            "Solo se pueden unir productos activos con el mismo nombre.",
+        ),
+        "product.too_many_tags" => (
+            "A product can have at most 10 tags.",
+            "Un producto puede tener como máximo 10 etiquetas.",
+        ),
+        "product.tag_too_long" => (
+            "Tags can be at most 30 characters long.",
+            "Las etiquetas pueden tener como máximo 30 caracteres.",
+        ),
+        "product.finished_not_in_active_list" => (
+            "Finished products are listed in the product history.",
+            "Los productos terminados aparecen en el historial de productos.",
+        ),
+        "product.invalid_batch_item" => (
+            "One of the products is not valid, so none was added.",
+            "Uno de los productos no es válido, así que no se ha añadido ninguno.",
+        ),
+        "product.invalid_month" => (
+            "The month must be written as YYYY-MM.",
+            "El mes debe escribirse como AAAA-MM.",
+        ),
+        "barcode_contribution.invalid_barcode" => (
+            "The barcode must have between 8 and 14 digits.",
+            "El código de barras debe tener entre 8 y 14 dígitos.",
+        ),
+        "barcode_contribution.name_empty" => (
+            "Tell us the product's name.",
+            "Indica el nombre del producto.",
+        ),
+        "barcode_contribution.name_too_long" => (
+            "The product name can be at most 120 characters long.",
+            "El nombre del producto puede tener como máximo 120 caracteres.",
+        ),
+        "barcode_contribution.invalid_image" => (
+            "The photo must be a JPEG, PNG or WebP image.",
+            "La foto debe ser una imagen JPEG, PNG o WebP.",
+        ),
+        "barcode_contribution.upstream_failed" => (
+            "We couldn't send the product to Open Food Facts.",
+            "No hemos podido enviar el producto a Open Food Facts.",
+        ),
+        "location_rule.keyword_empty" => (
+            "Every rule needs a keyword.",
+            "Cada regla necesita una palabra clave.",
+        ),
+        "location_rule.too_many" => (
+            "You can have at most 50 location rules.",
+            "Puedes tener como máximo 50 reglas de ubicación.",
+        ),
+        "preference.invalid_expiring_soon_days" => (
+            "The expiring-soon window must be between 1 and 14 days.",
+            "El aviso de caducidad debe estar entre 1 y 14 días.",
+        ),
+        "inbound_email.disabled" => (
+            "Emailing items to your list is not available on this server.",
+            "Enviar artículos a tu lista por correo no está disponible en este servidor.",
+        ),
+        "inbound_email.invalid_signature" => (
+            "The email could not be verified.",
+            "No se ha podido verificar el correo.",
+        ),
+        "inbound_email.unknown_recipient" => (
+            "This address does not belong to any shopping list.",
+            "Esta dirección no pertenece a ninguna lista de la compra.",
+        ),
+        "inbound_email.too_many_emails" => (
+            "This address has received too many emails today.",
+            "Esta dirección ha recibido demasiados correos hoy.",
+        ),
+        "notification.invalid_utc_offset" => (
+            "The time zone offset must be within 14 hours of UTC.",
+            "La diferencia horaria debe estar a menos de 14 horas de UTC.",
+        ),
+        "notification.invalid_quiet_hours" => (
+            "Quiet hours must start and end at different times.",
+            "Las horas de silencio deben empezar y terminar a horas distintas.",
+        ),
+        "notification.invalid_id" => (
+            "The notification ID is not valid.",
+            "El ID de la notificación no es válido.",
+        ),
+        "notification.invalid_acknowledgement" => (
+            "Send either up to 500 notification IDs or a time to acknowledge everything before it.",
+            "Envía hasta 500 identificadores de notificación o una fecha para marcar todo lo anterior.",
+        ),
+        "notification.delivery_failed" => (
+            "We couldn't send the notification. Please try again later.",
+            "No hemos podido enviar la notificación. Inténtalo más tarde.",
+        ),
+        "reminder.invalid_id" => (
+            "The reminder ID is not valid.",
+            "El ID del recordatorio no es válido.",
+        ),
+        "reminder.note_empty" => (
+            "The reminder note can't be empty.",
+            "La nota del recordatorio no puede estar vacía.",
+        ),
+        "reminder.remind_at_in_past" => (
+            "The reminder time must be in the future.",
+            "La hora del recordatorio debe ser futura.",
+        ),
+        "reminder.not_found" => ("Reminder not found.", "Recordatorio no encontrado."),
+        "store_profile.invalid_id" => (
+            "The store ID is not valid.",
+            "El ID de la tienda no es válido.",
+        ),
+        "store_profile.name_empty" => (
+            "The store name cannot be empty.",
+            "El nombre de la tienda no puede estar vacío.",
+        ),
+        "store_profile.aisle_name_empty" => (
+            "Every aisle needs a name.",
+            "Cada pasillo necesita un nombre.",
+        ),
+        "store_profile.not_found" => ("Store not found.", "Tienda no encontrada."),
+        "shopping_item.invalid_id" => (
+            "The shopping item ID is not valid.",
+            "El ID del artículo de la compra no es válido.",
+        ),
+        "shopping_item.invalid_product_id" => (
+            "The product ID is not valid.",
+            "El ID del producto no es válido.",
+        ),
+        "shopping_item.name_empty" => (
+            "The item name can't be empty.",
+            "El nombre del artículo no puede estar vacío.",
+        ),
+        "shopping_item.not_found" => (
+            "Shopping item not found.",
+            "Artículo de la compra no encontrado.",
+        ),
+        "shopping_item.already_exists" => (
+            "This product is already on your shopping list.",
+            "Este producto ya está en tu lista de la compra.",
+        ),
+        "shopping_item.barcode_not_recognized" => (
+            "We couldn't recognize this barcode.",
+            "No hemos podido reconocer este código de barras.",
+        ),
+        "shopping_item.invalid_attachment" => (
+            "The attachment must be a JPEG, PNG or WebP photo or an MP3, AAC, Ogg, WAV or WebM voice note.",
+            "El adjunto debe ser una foto JPEG, PNG o WebP o una nota de voz MP3, AAC, Ogg, WAV o WebM.",
+        ),
+        "shopping_trip.not_in_progress" => (
+            "You don't have a shopping trip in progress.",
+            "No tienes ninguna compra en curso.",
+        ),
+        "shopping_trip.empty_list" => (
+            "Your shopping list has nothing left to buy.",
+            "No te queda nada por comprar en la lista.",
+        ),
+        "shopping_trip.item_not_in_trip" => (
+            "This item wasn't on the list when the trip started.",
+            "Este artículo no estaba en la lista al empezar la compra.",
+        ),
+        "budget.invalid_amount" => (
+            "The monthly budget must be greater than zero and at most 1,000,000.",
+            "El presupuesto mensual debe ser mayor que cero y como mucho 1.000.000.",
+        ),
+        "budget.not_set" => (
+            "You haven't set a monthly budget yet.",
+            "Todavía no has fijado un presupuesto mensual.",
+        ),
+        "prompt.invalid_template" => (
+            "A prompt template is misnamed or invalid; the current templates were kept.",
+            "Una plantilla de prompt tiene un nombre o formato no válido; se mantienen las actuales.",
+        ),
+        "prompt.unreadable" => (
+            "The prompt template directory could not be read.",
+            "No se pudo leer el directorio de plantillas de prompt.",
+        ),
+        "experiment.ai_call_not_found" => (
+            "That identification doesn't exist or isn't yours.",
+            "Esa identificación no existe o no es tuya.",
+        ),
+        "experiment.invalid_ai_call_id" => (
+            "The identification ID is not valid.",
+            "El identificador de la identificación no es válido.",
+        ),
+        "search.query_too_short" => (
+            "Type at least 2 characters to search.",
+            "Escribe al menos 2 caracteres para buscar.",
+        ),
+        "vacation.until_in_past" => (
+            "The return date must be in the future.",
+            "La fecha de vuelta debe ser futura.",
+        ),
+        "vacation.not_found" => (
+            "You haven't been on vacation yet.",
+            "Todavía no has estado de vacaciones.",
+        ),
+        "device.invalid_installation_id" => (
+            "The installation ID must be between 1 and 128 characters.",
+            "El identificador de instalación debe tener entre 1 y 128 caracteres.",
+        ),
+        "device.invalid_app_version" => (
+            "The app version must be between 1 and 32 characters.",
+            "La versión de la app debe tener entre 1 y 32 caracteres.",
+        ),
+        "device.invalid_push_token" => (
+            "The push token is too long.",
+            "El token de notificaciones es demasiado largo.",
+        ),
+        "suggestion.not_enough_products" => (
+            "Add more products to get suggestions.",
+            "Añade más productos para recibir sugerencias.",
+        ),
+        "suggestion.empty_pantry" => (
+            "You have no products left to cook with. Add some to get suggestions.",
+            "No te quedan productos para cocinar. Añade alguno para recibir sugerencias.",
+        ),
+        "suggestion.provider_unavailable" => (
+            "Suggestions are temporarily unavailable. Try again in a moment.",
+            "Las sugerencias no están disponibles ahora mismo. Inténtalo en un momento.",
+        ),
+        "suggestion.quota_exceeded" => (
+            "We're getting too many suggestion requests. Try again in a minute.",
+            "Estamos recibiendo demasiadas peticiones de sugerencias. Inténtalo en un minuto.",
+        ),
+        "suggestion.parse_failed" => (
+            "We got an unexpected answer while generating suggestions. Please try again.",
+            "Hemos recibido una respuesta inesperada al generar sugerencias. Inténtalo de nuevo.",
+        ),
+        "suggestion.generation_failed" => (
+            "We couldn't generate suggestions right now.",
+            "No hemos podido generar sugerencias ahora mismo.",
+        ),
+        "suggestion.invalid_suggestion" => (
+            "The suggestion is not valid.",
+            "La sugerencia no es válida.",
+        ),
+        "cooking_session.invalid_id" => (
+            "The cooking session ID is not valid.",
+            "El ID de la sesión de cocina no es válido.",
+        ),
+        "cooking_session.not_found" => (
+            "Cooking session not found.",
+            "Sesión de cocina no encontrada.",
+        ),
+        "cooking_session.suggestion_not_found" => (
+            "This suggestion is no longer available.",
+            "Esta sugerencia ya no está disponible.",
+        ),
+        "cooking_session.no_steps" => (
+            "This recipe has no steps to follow.",
+            "Esta receta no tiene pasos que seguir.",
+        ),
+        "cooking_session.invalid_step" => ("That step doesn't exist.", "Ese paso no existe."),
+        "cooking_session.no_remaining_steps" => (
+            "All steps are already done.",
+            "Ya has completado todos los pasos.",
+        ),
+        "cooking_session.already_completed" => (
+            "This cooking session is already finished.",
+            "Esta sesión de cocina ya ha terminado.",
+        ),
+        "meal_plan.suggestion_not_found" => (
+            "This suggestion is no longer available.",
+            "Esta sugerencia ya no está disponible.",
+        ),
+        "meal_plan.already_planned" => (
+            "You have already planned this meal.",
+            "Ya has planificado esta comida.",
+        ),
+        "meal_plan.date_in_past" => (
+            "Meals can't be planned for a day that has passed.",
+            "No se pueden planificar comidas para un día que ya ha pasado.",
+        ),
+        "receipt_import.invalid_id" => (
+            "The receipt import ID is not valid.",
+            "El ID de la importación del ticket no es válido.",
+        ),
+        "receipt_import.not_found" => (
+            "Receipt import not found.",
+            "Importación del ticket no encontrada.",
+        ),
+        "receipt_import.image_not_found" => (
+            "The photo of this receipt was not kept.",
+            "No se ha guardado la foto de este ticket.",
+        ),
+        "storage.not_found" => ("File not found.", "Archivo no encontrado."),
+        "storage.invalid_signature" => (
+            "This link is not valid or has expired.",
+            "Este enlace no es válido o ha caducado.",
+        ),
+        "storage.unreadable_image" => (
+            "The photo could not be read.",
+            "No se ha podido leer la foto.",
+        ),
+        "storage.unavailable" => (
+            "Stored photos are unavailable right now. Please try again later.",
+            "Las fotos guardadas no están disponibles ahora mismo. Inténtalo más tarde.",
+        ),
+        "share_link.invalid_id" => (
+            "The share link ID is not valid.",
+            "El ID del enlace compartido no es válido.",
+        ),
+        "share_link.invalid_expiry" => (
+            "The link duration is not valid.",
+            "La duración del enlace no es válida.",
+        ),
+        "share_link.not_found" => ("Share link not found.", "Enlace compartido no encontrado."),
+        "share_link.expired" => (
+            "This share link has expired.",
+            "Este enlace compartido ha caducado.",
+        ),
+        "widget.invalid_token" => (
+            "This widget is no longer connected. Open the app to set it up again.",
+            "Este widget ya no está conectado. Abre la aplicación para configurarlo de nuevo.",
+        ),
+        _ => return None,
+    };
+
+    Some(match language {
+        Language::En => en,
+        Language::Es => es,
+    })
+}
+
+/// Adds a localized `description` to JSON error bodies when the client sends
+/// a supported `Accept-Language`. The machine-readable `message` code is kept.
+pub struct Localization;
+
+impl<E: Endpoint> Middleware<E> for Localization {
+    type Output = LocalizationEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        LocalizationEndpoint { inner: ep }
+    }
+}
+
+pub struct LocalizationEndpoint<E> {
+    inner: E,
+}
+
+impl<E: Endpoint> Endpoint for LocalizationEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let language = req
+            .headers()
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(Language::from_accept_language);
+
+        let mut resp = self.inner.call(req).await?.into_response();
+
+        let Some(language) = language else {
+            return Ok(resp);
+        };
+        let is_json = resp
+            .content_type()
+            .is_some_and(|ct| ct.starts_with("application/json"));
+        let is_error = resp.status().is_client_error() || resp.status().is_server_error();
+        if !is_error || !is_json {
+            return Ok(resp);
+        }
+
+        let bytes = resp.take_body().into_bytes().await?;
+        let body = match serde_json::from_slice::<serde_json::Value>(&bytes) {
+            Ok(serde_json::Value::Object(mut body)) => {
+                let description = body
+                    .get("message")
+                    .and_then(|m| m.as_str())
+                    .and_then(|code| translate(code, language));
+                if let Some(description) = description {
+                    body.insert("description".to_string(), description.into());
+                    resp.headers_mut()
+                        .append(header::VARY, HeaderValue::from_static("accept-language"));
+                }
+                serde_json::to_vec(&body).unwrap_or_else(|_| bytes.to_vec())
+            }
+            _ => bytes.to_vec(),
+        };
+
+        resp.set_body(body);
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poem::http::StatusCode;
+    use poem::{EndpointExt, handler, test::TestClient};
+
+    #[handler]
+    fn not_found() -> Response {
+        Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .content_type("application/json; charset=utf-8")
+            .body(r#"{"name":"NotFound","message":"product.not_found"}"#)
+    }
+
+    #[test]
+    fn should_pick_highest_weighted_supported_language() {
+        assert_eq!(
+            Language::from_accept_language("fr-FR, en;q=0.5, es-ES;q=0.8"),
+            Some(Language::Es)
+        );
+        assert_eq!(
+            Language::from_accept_language("en-GB,en;q=0.9"),
+            Some(Language::En)
+        );
+        assert_eq!(Language::from_accept_language("fr, de;q=0.7"), None);
+    }
+
+    #[test]
+    fn should_return_none_when_code_unknown() {
+        assert_eq!(translate("unknown.code", Language::En), None);
+        assert_eq!(
+            translate("product.not_found", Language::Es),
+            Some("Producto no encontrado.")
+        );
+    }
+
+    #[tokio::test]
+    async fn should_add_description_and_keep_code_when_language_supported() {
+        let client = TestClient::new(not_found.with(Localization));
+
+        let resp = client
+            .get("/")
+            .header("accept-language", "es-ES,es;q=0.9")
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::NOT_FOUND);
+        resp.assert_header("vary", "accept-language");
+        let json = resp.json().await;
+        let body = json.value().object();
+        body.get("message").assert_string("product.not_found");
+        body.get("description")
+            .assert_string("Producto no encontrado.");
+    }
+
+    #[tokio::test]
+    async fn should_leave_body_untouched_when_no_accept_language() {
+        let client = TestClient::new(not_found.with(Localization));
+
+        let resp = client.get("/").send().await;
+
+        let json = resp.json().await;
+        assert!(json.value().object().get_opt("description").is_none());
+    }
+}
@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Multipart, Object, types::Example};
+
+use business::domain::inbound_email::model::{
+    InboundEmail, InboundEmailReceipt, InboundMailbox, WebhookSignature,
+};
+
+use crate::api::examples::example_date;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct InboundAddressResponse {
+    /// Private address; emails sent to it add their lines to the shopping list
+    pub address: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<InboundMailbox> for InboundAddressResponse {
+    fn from(mailbox: InboundMailbox) -> Self {
+        Self {
+            address: mailbox.address,
+            created_at: mailbox.created_at,
+        }
+    }
+}
+
+impl Example for InboundAddressResponse {
+    fn example() -> Self {
+        Self {
+            address: "k3v9q2m7x1c8b4n6z0wp@in.foodie.app".to_string(),
+            created_at: example_date(),
+        }
+    }
+}
+
+/// Form fields Mailgun posts for a routed message. Other fields are ignored.
+#[derive(Debug, Multipart)]
+pub struct MailgunMessageForm {
+    pub recipient: String,
+    pub sender: String,
+    #[oai(rename = "body-plain")]
+    pub body_plain: String,
+    /// Body without the quoted reply and signature, when Mailgun found them
+    #[oai(rename = "stripped-text")]
+    pub stripped_text: Option<String>,
+    pub timestamp: String,
+    pub token: String,
+    pub signature: String,
+}
+
+impl MailgunMessageForm {
+    pub fn into_domain(self) -> (InboundEmail, WebhookSignature) {
+        let text = self
+            .stripped_text
+            .filter(|text| !text.trim().is_empty())
+            .unwrap_or(self.body_plain);
+        (
+            InboundEmail {
+                recipient: self.recipient,
+                sender: self.sender,
+                text,
+            },
+            WebhookSignature {
+                timestamp: self.timestamp,
+                token: self.token,
+                signature: self.signature,
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct InboundEmailReceiptResponse {
+    /// Items added to the shopping list
+    pub added: u32,
+    /// The call repeated an email already handled, so nothing was added
+    pub duplicate: bool,
+}
+
+impl From<InboundEmailReceipt> for InboundEmailReceiptResponse {
+    fn from(receipt: InboundEmailReceipt) -> Self {
+        Self {
+            added: receipt.added,
+            duplicate: receipt.duplicate,
+        }
+    }
+}
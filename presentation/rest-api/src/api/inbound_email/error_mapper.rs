@@ -0,0 +1,45 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::inbound_email::errors::InboundEmailError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for InboundEmailError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        // 406 tells Mailgun the email is rejected for good, so it does not retry
+        let (status, name, message) = match &self {
+            InboundEmailError::InvalidSignature => (
+                StatusCode::UNAUTHORIZED,
+                "Unauthorized",
+                "inbound_email.invalid_signature",
+            ),
+            InboundEmailError::UnknownRecipient => (
+                StatusCode::NOT_ACCEPTABLE,
+                "NotAcceptable",
+                "inbound_email.unknown_recipient",
+            ),
+            InboundEmailError::TooManyEmails => (
+                StatusCode::NOT_ACCEPTABLE,
+                "NotAcceptable",
+                "inbound_email.too_many_emails",
+            ),
+            InboundEmailError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
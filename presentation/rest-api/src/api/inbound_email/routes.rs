@@ -0,0 +1,183 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::inbound_email::use_cases::get_address::{
+    GetInboundAddressParams, GetInboundAddressUseCase,
+};
+use business::domain::inbound_email::use_cases::receive::{
+    ReceiveInboundEmailParams, ReceiveInboundEmailUseCase,
+};
+use business::domain::inbound_email::use_cases::rotate_address::{
+    RotateInboundAddressParams, RotateInboundAddressUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::inbound_email::dto::{
+    InboundAddressResponse, InboundEmailReceiptResponse, MailgunMessageForm,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+/// Use cases of emailing items to the shopping list, present when
+/// `INBOUND_EMAIL_DOMAIN` is set.
+pub struct InboundEmailUseCases {
+    pub get_address_use_case: Arc<dyn GetInboundAddressUseCase>,
+    pub rotate_address_use_case: Arc<dyn RotateInboundAddressUseCase>,
+    pub receive_use_case: Arc<dyn ReceiveInboundEmailUseCase>,
+}
+
+pub struct InboundEmailApi {
+    inbound: Option<InboundEmailUseCases>,
+}
+
+impl InboundEmailApi {
+    pub fn new(inbound: Option<InboundEmailUseCases>) -> Self {
+        Self { inbound }
+    }
+}
+
+/// Instances without an inbound domain answer `404`.
+fn inbound_disabled() -> Json<ErrorResponse> {
+    Json(ErrorResponse {
+        name: "NotFound".to_string(),
+        message: "inbound_email.disabled".to_string(),
+        description: None,
+    })
+}
+
+/// Inbound email API
+///
+/// Each user gets a private address; forwarding a list to it, one item per
+/// line or comma-separated, adds the items to their shopping list.
+#[OpenApi]
+impl InboundEmailApi {
+    /// Get the inbound address
+    ///
+    /// Returns the user's private address for emailing items to their
+    /// shopping list, creating it on first use.
+    #[oai(
+        path = "/shopping-items/inbound-address",
+        method = "get",
+        tag = "ApiTags::InboundEmail"
+    )]
+    async fn get_inbound_address(&self, auth: BearerAuth) -> InboundAddressResult {
+        let Some(inbound) = &self.inbound else {
+            return InboundAddressResult::NotFound(inbound_disabled());
+        };
+
+        match inbound
+            .get_address_use_case
+            .execute(GetInboundAddressParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(mailbox) => InboundAddressResult::Ok(Json(mailbox.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                InboundAddressResult::InternalError(json)
+            }
+        }
+    }
+
+    /// Rotate the inbound address
+    ///
+    /// Replaces the address with a new random one. Emails sent to the old
+    /// address are rejected from then on; use it when the address gets spam.
+    #[oai(
+        path = "/shopping-items/inbound-address/rotate",
+        method = "post",
+        tag = "ApiTags::InboundEmail"
+    )]
+    async fn rotate_inbound_address(&self, auth: BearerAuth) -> InboundAddressResult {
+        let Some(inbound) = &self.inbound else {
+            return InboundAddressResult::NotFound(inbound_disabled());
+        };
+
+        match inbound
+            .rotate_address_use_case
+            .execute(RotateInboundAddressParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(mailbox) => InboundAddressResult::Ok(Json(mailbox.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                InboundAddressResult::InternalError(json)
+            }
+        }
+    }
+
+    /// Receive an email from Mailgun
+    ///
+    /// Called by a Mailgun route forwarding the inbound domain, not by
+    /// clients. The call is checked against `MAILGUN_SIGNING_KEY`; emails to
+    /// unknown addresses or beyond the daily allowance answer `406` so
+    /// Mailgun does not retry them, and retries of an email already handled
+    /// add nothing.
+    #[oai(
+        path = "/inbound-email/mailgun",
+        method = "post",
+        tag = "ApiTags::InboundEmail"
+    )]
+    async fn receive_mailgun(&self, form: MailgunMessageForm) -> MailgunWebhookResponse {
+        let Some(inbound) = &self.inbound else {
+            return MailgunWebhookResponse::NotFound(inbound_disabled());
+        };
+
+        let (email, signature) = form.into_domain();
+        match inbound
+            .receive_use_case
+            .execute(ReceiveInboundEmailParams { email, signature })
+            .await
+        {
+            Ok(receipt) => MailgunWebhookResponse::Ok(Json(receipt.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    401 => MailgunWebhookResponse::Unauthorized(json),
+                    406 => MailgunWebhookResponse::NotAcceptable(json),
+                    _ => MailgunWebhookResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum InboundAddressResult {
+    #[oai(status = 200)]
+    Ok(Json<InboundAddressResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+pub enum MailgunWebhookResponse {
+    #[oai(status = 200)]
+    Ok(Json<InboundEmailReceiptResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 406)]
+    NotAcceptable(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(InboundAddressResult);
@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::job::model::{Job, JobKind, JobProgress, JobStatus};
+
+use crate::api::examples::example_date;
+use crate::api::product::dto::ProductLocationDto;
+
+/// What a background job does.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum JobKindDto {
+    /// Estimating expiry dates for products that have none
+    #[oai(rename = "estimate_missing_expiry")]
+    EstimateMissingExpiry,
+    /// Re-estimating expiry dates of products moved together
+    #[oai(rename = "reestimate_moved_expiry")]
+    ReestimateMovedExpiry,
+    /// Retrying the expiry estimation of a product added while the estimator
+    /// was down; progress counts attempts
+    #[oai(rename = "retry_expiry_estimation")]
+    RetryExpiryEstimation,
+}
+
+impl From<JobKind> for JobKindDto {
+    fn from(kind: JobKind) -> Self {
+        match kind {
+            JobKind::EstimateMissingExpiry => JobKindDto::EstimateMissingExpiry,
+            JobKind::ReestimateMovedExpiry => JobKindDto::ReestimateMovedExpiry,
+            JobKind::RetryExpiryEstimation => JobKindDto::RetryExpiryEstimation,
+        }
+    }
+}
+
+/// Progress of a background job.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum JobStatusDto {
+    /// Accepted, not started yet
+    #[oai(rename = "pending")]
+    Pending,
+    /// Working through its items
+    #[oai(rename = "running")]
+    Running,
+    /// Done; some items may have failed
+    #[oai(rename = "completed")]
+    Completed,
+    /// Stopped; see `error`
+    #[oai(rename = "failed")]
+    Failed,
+}
+
+impl From<JobStatus> for JobStatusDto {
+    fn from(status: JobStatus) -> Self {
+        match status {
+            JobStatus::Pending => JobStatusDto::Pending,
+            JobStatus::Running => JobStatusDto::Running,
+            JobStatus::Completed => JobStatusDto::Completed,
+            JobStatus::Failed => JobStatusDto::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BulkMoveProductsRequest {
+    /// Products to move; duplicates are moved once
+    #[oai(validator(min_items = 1, max_items = 100))]
+    pub product_ids: Vec<String>,
+    /// Where the products are now kept
+    pub location: ProductLocationDto,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct JobProgressResponse {
+    /// Items the job will go through
+    pub total: u32,
+    /// Items handled so far, failed ones included
+    pub processed: u32,
+    /// Items that could not be handled
+    pub failed: u32,
+}
+
+impl From<JobProgress> for JobProgressResponse {
+    fn from(p: JobProgress) -> Self {
+        Self {
+            total: p.total,
+            processed: p.processed,
+            failed: p.failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct JobResponse {
+    pub id: String,
+    pub kind: JobKindDto,
+    pub status: JobStatusDto,
+    pub progress: JobProgressResponse,
+    /// Error code, present once the job failed (e.g. quota.ai_calls_exceeded)
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Job> for JobResponse {
+    fn from(job: Job) -> Self {
+        Self {
+            id: job.id.to_string(),
+            kind: job.kind.into(),
+            status: job.status.into(),
+            progress: job.progress.into(),
+            error: job.error,
+            created_at: job.created_at,
+            updated_at: job.updated_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for BulkMoveProductsRequest {
+    fn example() -> Self {
+        Self {
+            product_ids: vec![
+                "3f6b2c1a-8d4e-4a7b-9c2d-1e0f5a6b7c8d".to_string(),
+                "7c1e9a4b-2f3d-4e5a-8b6c-0d9e1f2a3b4c".to_string(),
+            ],
+            location: ProductLocationDto::Freezer,
+        }
+    }
+}
+
+impl Example for JobProgressResponse {
+    fn example() -> Self {
+        Self {
+            total: 12,
+            processed: 5,
+            failed: 1,
+        }
+    }
+}
+
+impl Example for JobResponse {
+    fn example() -> Self {
+        Self {
+            id: "5a9c3e2f-1b4d-4f6a-8c7e-9d0b1a2c3e4f".to_string(),
+            kind: JobKindDto::EstimateMissingExpiry,
+            status: JobStatusDto::Running,
+            progress: JobProgressResponse::example(),
+            error: None,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::job::use_cases::get_by_id::{GetJobParams, GetJobUseCase};
+use business::domain::product::use_cases::bulk_move::{
+    BulkMoveProductsParams, BulkMoveProductsUseCase,
+};
+use business::domain::product::use_cases::estimate_missing::{
+    EstimateMissingParams, EstimateMissingUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::job::dto::{BulkMoveProductsRequest, JobResponse};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct JobApi {
+    estimate_missing_use_case: Arc<dyn EstimateMissingUseCase>,
+    bulk_move_use_case: Arc<dyn BulkMoveProductsUseCase>,
+    get_by_id_use_case: Arc<dyn GetJobUseCase>,
+}
+
+impl JobApi {
+    pub fn new(
+        estimate_missing_use_case: Arc<dyn EstimateMissingUseCase>,
+        bulk_move_use_case: Arc<dyn BulkMoveProductsUseCase>,
+        get_by_id_use_case: Arc<dyn GetJobUseCase>,
+    ) -> Self {
+        Self {
+            estimate_missing_use_case,
+            bulk_move_use_case,
+            get_by_id_use_case,
+        }
+    }
+}
+
+/// Jobs API
+///
+/// Endpoints that start long-running maintenance work and report its progress.
+#[OpenApi]
+impl JobApi {
+    /// Estimate every missing expiry date
+    ///
+    /// Returns `202` with a job that estimates, one product at a time, the
+    /// expiry date of every active product that has neither a printed nor an
+    /// estimated one. Useful after imports. Each estimation counts as an AI
+    /// call; the job fails with `quota.ai_calls_exceeded` when they run out.
+    /// While a job is underway, calling again returns that same job. Poll
+    /// `GET /jobs/{id}` for progress.
+    #[oai(
+        path = "/products/estimate-missing",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn estimate_missing(&self, auth: BearerAuth) -> EstimateMissingResponse {
+        match self
+            .estimate_missing_use_case
+            .execute(EstimateMissingParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(job) => EstimateMissingResponse::Accepted(Json(job.into())),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                EstimateMissingResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Move products in bulk
+    ///
+    /// Moves every listed product to `location` at once, e.g. everything put
+    /// in the freezer before a trip, and returns `202` with a job that
+    /// re-estimates, one product at a time, the expiry date of the products
+    /// that changed place. Printed expiry dates and finished products are left
+    /// alone. The move itself is saved before the response; when any product
+    /// is not found, nothing moves. Each estimation counts as an AI call.
+    /// Poll `GET /jobs/{id}` for progress.
+    #[oai(
+        path = "/products/bulk-move",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn bulk_move(
+        &self,
+        auth: BearerAuth,
+        body: Json<BulkMoveProductsRequest>,
+    ) -> BulkMoveProductsResponse {
+        let product_ids = match body
+            .0
+            .product_ids
+            .iter()
+            .map(|id| Uuid::parse_str(id))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(ids) => ids,
+            Err(_) => {
+                return BulkMoveProductsResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .bulk_move_use_case
+            .execute(BulkMoveProductsParams {
+                user_id: UserId::new(auth.0),
+                product_ids,
+                location: body.0.location.into(),
+            })
+            .await
+        {
+            Ok(job) => BulkMoveProductsResponse::Accepted(Json(job.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => BulkMoveProductsResponse::NotFound(json),
+                    _ => BulkMoveProductsResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get a job
+    ///
+    /// Returns the job status and how many items it has gone through.
+    #[oai(path = "/jobs/:id", method = "get", tag = "ApiTags::Jobs")]
+    async fn get_by_id(&self, auth: BearerAuth, id: Path<String>) -> GetJobResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return GetJobResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "job.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .get_by_id_use_case
+            .execute(GetJobParams { id: uuid, user_id })
+            .await
+        {
+            Ok(job) => GetJobResponse::Ok(Json(job.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetJobResponse::NotFound(json),
+                    _ => GetJobResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum EstimateMissingResponse {
+    #[oai(status = 202)]
+    Accepted(Json<JobResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum BulkMoveProductsResponse {
+    #[oai(status = 202)]
+    Accepted(Json<JobResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetJobResponse {
+    #[oai(status = 200)]
+    Ok(Json<JobResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    EstimateMissingResponse,
+    BulkMoveProductsResponse,
+    GetJobResponse
+);
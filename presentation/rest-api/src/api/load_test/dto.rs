@@ -0,0 +1,36 @@
+use poem_openapi::{Object, types::Example};
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SeedProductsRequest {
+    /// Products to create, at most 5000 per request
+    #[oai(validator(minimum(value = "1"), maximum(value = "5000")))]
+    pub count: u32,
+    /// Generator seed; the same seed yields the same pantry (default: 1)
+    #[oai(skip_serializing_if_is_none)]
+    pub seed: Option<u64>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SeedProductsResponse {
+    /// Products created for the caller
+    pub created: u32,
+}
+
+// --- OpenAPI examples ---
+
+impl Example for SeedProductsRequest {
+    fn example() -> Self {
+        Self {
+            count: 1000,
+            seed: Some(42),
+        }
+    }
+}
+
+impl Example for SeedProductsResponse {
+    fn example() -> Self {
+        Self { created: 1000 }
+    }
+}
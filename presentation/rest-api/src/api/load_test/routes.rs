@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::product::use_cases::seed::{SeedProductsParams, SeedProductsUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::load_test::dto::{SeedProductsRequest, SeedProductsResponse};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::load_test_config::LoadTestConfig;
+
+pub struct LoadTestApi {
+    seed_products_use_case: Arc<dyn SeedProductsUseCase>,
+    config: LoadTestConfig,
+}
+
+impl LoadTestApi {
+    pub fn new(
+        seed_products_use_case: Arc<dyn SeedProductsUseCase>,
+        config: LoadTestConfig,
+    ) -> Self {
+        Self {
+            seed_products_use_case,
+            config,
+        }
+    }
+}
+
+/// Load Test API
+///
+/// Fixtures for load tests. Disabled unless `LOAD_TEST_SEED_ENABLED` is set.
+#[OpenApi]
+impl LoadTestApi {
+    /// Seed the caller's pantry
+    ///
+    /// Creates `count` generated products (repeated names, mixed statuses and
+    /// locations, expiry dates spread over two months) for the signed-in test
+    /// user, without quotas or AI calls. Meant for k6/vegeta setup stages:
+    /// use dedicated test accounts, since the products are added to whatever
+    /// the pantry already holds. Returns `404` when seeding is disabled.
+    #[oai(path = "/load-test/seed", method = "post", tag = "ApiTags::LoadTest")]
+    async fn seed(&self, auth: BearerAuth, body: Json<SeedProductsRequest>) -> SeedResponse {
+        if !self.config.seed_enabled {
+            return SeedResponse::NotFound(Json(ErrorResponse {
+                name: "NotFound".to_string(),
+                message: "load_test.disabled".to_string(),
+                description: None,
+            }));
+        }
+
+        match self
+            .seed_products_use_case
+            .execute(SeedProductsParams {
+                user_id: UserId::new(auth.0),
+                count: body.0.count as usize,
+                seed: body.0.seed.unwrap_or(1),
+            })
+            .await
+        {
+            Ok(created) => SeedResponse::Created(Json(SeedProductsResponse {
+                created: created as u32,
+            })),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                SeedResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum SeedResponse {
+    #[oai(status = 201)]
+    Created(Json<SeedProductsResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(SeedResponse);
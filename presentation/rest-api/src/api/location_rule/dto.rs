@@ -0,0 +1,92 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::location_rule::model::LocationRule;
+
+use crate::api::product::dto::ProductLocationDto;
+
+/// Products whose name contains `keyword` as whole words are stored in
+/// `location`, e.g. "leche" → fridge.
+#[derive(Debug, Clone, Object)]
+pub struct LocationRuleDto {
+    /// Word or words to look for in the product name, e.g. "pan de molde"
+    #[oai(validator(min_length = 1, max_length = 100))]
+    pub keyword: String,
+    /// Where matching products are kept
+    pub location: ProductLocationDto,
+}
+
+impl From<LocationRuleDto> for LocationRule {
+    fn from(dto: LocationRuleDto) -> Self {
+        Self {
+            keyword: dto.keyword,
+            location: dto.location.into(),
+        }
+    }
+}
+
+impl From<LocationRule> for LocationRuleDto {
+    fn from(rule: LocationRule) -> Self {
+        Self {
+            keyword: rule.keyword,
+            location: rule.location.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ReplaceLocationRulesRequest {
+    /// Rules in priority order; the first match wins. An empty list removes all rules.
+    #[oai(validator(max_items = 50))]
+    pub rules: Vec<LocationRuleDto>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct LocationRulesResponse {
+    /// Rules in priority order; the first match wins
+    pub rules: Vec<LocationRuleDto>,
+}
+
+impl From<Vec<LocationRule>> for LocationRulesResponse {
+    fn from(rules: Vec<LocationRule>) -> Self {
+        Self {
+            rules: rules.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+fn example_rules() -> Vec<LocationRuleDto> {
+    vec![
+        LocationRuleDto {
+            keyword: "leche".to_string(),
+            location: ProductLocationDto::Fridge,
+        },
+        LocationRuleDto {
+            keyword: "pan".to_string(),
+            location: ProductLocationDto::Freezer,
+        },
+        LocationRuleDto {
+            keyword: "tomate".to_string(),
+            location: ProductLocationDto::Pantry,
+        },
+    ]
+}
+
+impl Example for ReplaceLocationRulesRequest {
+    fn example() -> Self {
+        Self {
+            rules: example_rules(),
+        }
+    }
+}
+
+impl Example for LocationRulesResponse {
+    fn example() -> Self {
+        Self {
+            rules: example_rules(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::location_rule::errors::LocationRuleError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for LocationRuleError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            LocationRuleError::KeywordEmpty => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "location_rule.keyword_empty",
+            ),
+            LocationRuleError::TooMany => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "location_rule.too_many",
+            ),
+            LocationRuleError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
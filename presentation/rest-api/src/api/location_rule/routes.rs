@@ -0,0 +1,130 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::location_rule::use_cases::get::{
+    GetLocationRulesParams, GetLocationRulesUseCase,
+};
+use business::domain::location_rule::use_cases::replace::{
+    ReplaceLocationRulesParams, ReplaceLocationRulesUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::location_rule::dto::{LocationRulesResponse, ReplaceLocationRulesRequest};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct LocationRuleApi {
+    get_use_case: Arc<dyn GetLocationRulesUseCase>,
+    replace_use_case: Arc<dyn ReplaceLocationRulesUseCase>,
+}
+
+impl LocationRuleApi {
+    pub fn new(
+        get_use_case: Arc<dyn GetLocationRulesUseCase>,
+        replace_use_case: Arc<dyn ReplaceLocationRulesUseCase>,
+    ) -> Self {
+        Self {
+            get_use_case,
+            replace_use_case,
+        }
+    }
+}
+
+/// Location rule API
+///
+/// Endpoints for the user's default storage locations. Rules fill in the
+/// location of products created without one and override the location
+/// suggested by `POST /products/identify`. Barcode lookups are shared and
+/// cached, so they are not affected.
+#[OpenApi]
+impl LocationRuleApi {
+    /// Get location rules
+    ///
+    /// Returns the user's rules in priority order; empty if none were saved.
+    #[oai(
+        path = "/settings/location-rules",
+        method = "get",
+        tag = "ApiTags::Settings"
+    )]
+    async fn get(&self, auth: BearerAuth) -> GetLocationRulesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_use_case
+            .execute(GetLocationRulesParams { user_id })
+            .await
+        {
+            Ok(rules) => GetLocationRulesResponse::Ok(Json(rules.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetLocationRulesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Replace location rules
+    ///
+    /// Saves the full list of rules, replacing the previous one.
+    #[oai(
+        path = "/settings/location-rules",
+        method = "put",
+        tag = "ApiTags::Settings"
+    )]
+    async fn replace(
+        &self,
+        auth: BearerAuth,
+        body: Json<ReplaceLocationRulesRequest>,
+    ) -> ReplaceLocationRulesResponse {
+        let params = ReplaceLocationRulesParams {
+            user_id: UserId::new(auth.0),
+            rules: body.0.rules.into_iter().map(|r| r.into()).collect(),
+        };
+
+        match self.replace_use_case.execute(params).await {
+            Ok(rules) => ReplaceLocationRulesResponse::Ok(Json(rules.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => ReplaceLocationRulesResponse::BadRequest(json),
+                    _ => ReplaceLocationRulesResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetLocationRulesResponse {
+    #[oai(status = 200)]
+    Ok(Json<LocationRulesResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ReplaceLocationRulesResponse {
+    #[oai(status = 200)]
+    Ok(Json<LocationRulesResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetLocationRulesResponse, ReplaceLocationRulesResponse,);
@@ -0,0 +1,142 @@
+use poem::http::{HeaderValue, Method, StatusCode, header};
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+use poem_openapi::{Object, payload::Json, types::Example};
+
+use crate::config::maintenance_config::MaintenanceConfig;
+
+/// Error returned for writes while the server is in maintenance mode.
+#[derive(Object, Debug)]
+#[oai(example)]
+pub struct MaintenanceResponse {
+    /// Error category
+    pub name: String,
+    /// Code-style error identifier for i18n
+    pub message: String,
+    /// Human-readable message, present when `Accept-Language` is es or en
+    #[oai(skip_serializing_if_is_none)]
+    pub description: Option<String>,
+    /// Seconds to wait before retrying, also sent as `Retry-After`
+    pub retry_after_secs: u64,
+}
+
+impl Example for MaintenanceResponse {
+    fn example() -> Self {
+        under_maintenance(300).0
+    }
+}
+
+pub fn under_maintenance(retry_after_secs: u64) -> Json<MaintenanceResponse> {
+    Json(MaintenanceResponse {
+        name: "ServiceUnavailable".to_string(),
+        message: "maintenance.read_only".to_string(),
+        description: None,
+        retry_after_secs,
+    })
+}
+
+/// Whether a request only reads, and so is still served during maintenance.
+fn is_read(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+/// While maintenance mode is on, answers every write with 503 and a
+/// `Retry-After` before it reaches a handler, so nothing is written during a
+/// risky migration. Reads are served as usual.
+pub struct Maintenance {
+    config: MaintenanceConfig,
+}
+
+impl Maintenance {
+    pub fn new(config: MaintenanceConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for Maintenance {
+    type Output = MaintenanceEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        MaintenanceEndpoint {
+            inner: ep,
+            config: self.config.clone(),
+        }
+    }
+}
+
+pub struct MaintenanceEndpoint<E> {
+    inner: E,
+    config: MaintenanceConfig,
+}
+
+impl<E: Endpoint> Endpoint for MaintenanceEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        if !self.config.enabled || is_read(req.method()) {
+            return self.inner.call(req).await.map(IntoResponse::into_response);
+        }
+
+        let retry_after_secs = self.config.retry_after.as_secs().max(1);
+        let mut resp = under_maintenance(retry_after_secs)
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+            .into_response();
+        resp.headers_mut()
+            .insert(header::RETRY_AFTER, HeaderValue::from(retry_after_secs));
+        Ok(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poem::{EndpointExt, handler, test::TestClient};
+    use std::time::Duration;
+
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    fn client(enabled: bool) -> TestClient<impl Endpoint> {
+        let app = ok.with(Maintenance::new(MaintenanceConfig {
+            enabled,
+            retry_after: Duration::from_secs(120),
+        }));
+        TestClient::new(app)
+    }
+
+    #[tokio::test]
+    async fn should_reject_writes_with_retry_after_during_maintenance() {
+        let resp = client(true).post("/products").send().await;
+
+        resp.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+        resp.assert_header(header::RETRY_AFTER, "120");
+        let json = resp.json().await;
+        json.value()
+            .object()
+            .get("message")
+            .assert_string("maintenance.read_only");
+        json.value()
+            .object()
+            .get("retry_after_secs")
+            .assert_i64(120);
+    }
+
+    #[tokio::test]
+    async fn should_keep_serving_reads_during_maintenance() {
+        client(true)
+            .get("/products")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_pass_writes_through_when_disabled() {
+        client(false)
+            .delete("/products/42")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+}
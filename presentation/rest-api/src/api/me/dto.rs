@@ -0,0 +1,86 @@
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::quota::model::{PlanTier, Usage};
+
+/// Subscription tier.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum PlanTierDto {
+    /// Daily AI calls and pantry size are limited
+    #[oai(rename = "free")]
+    Free,
+    /// No limits
+    #[oai(rename = "premium")]
+    Premium,
+}
+
+impl From<PlanTier> for PlanTierDto {
+    fn from(tier: PlanTier) -> Self {
+        match tier {
+            PlanTier::Free => PlanTierDto::Free,
+            PlanTier::Premium => PlanTierDto::Premium,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct UsageResponse {
+    /// AI calls made today (UTC)
+    pub ai_calls_today: u32,
+    /// Daily AI call allowance, or null if unlimited
+    pub ai_calls_limit: Option<u32>,
+    /// Products that are not finished
+    pub products: u32,
+    /// Maximum number of products, or null if unlimited
+    pub products_limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct MeResponse {
+    /// Id of the authenticated user (the Firebase UID, or the local account id on self-hosted instances)
+    pub user_id: String,
+    /// Current plan
+    pub plan: PlanTierDto,
+    /// Usage against the plan limits
+    pub usage: UsageResponse,
+}
+
+impl MeResponse {
+    pub fn new(user_id: String, usage: Usage) -> Self {
+        Self {
+            user_id,
+            plan: usage.plan.into(),
+            usage: UsageResponse {
+                ai_calls_today: usage.ai_calls_today,
+                ai_calls_limit: usage.limits.ai_calls_per_day,
+                products: usage.products,
+                products_limit: usage.limits.max_products,
+            },
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for UsageResponse {
+    fn example() -> Self {
+        Self {
+            ai_calls_today: 4,
+            ai_calls_limit: Some(20),
+            products: 37,
+            products_limit: Some(100),
+        }
+    }
+}
+
+impl Example for MeResponse {
+    fn example() -> Self {
+        Self {
+            user_id: "kR2xV9mQpLs7TfW3bN8cJ1hYd4E2".to_string(),
+            plan: PlanTierDto::Free,
+            usage: UsageResponse::example(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::quota::errors::QuotaError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+/// Status, name and code for a quota error. Shared by every error type that
+/// wraps [`QuotaError`] so limits surface the same way on all endpoints.
+pub fn quota_error_parts(err: &QuotaError) -> (StatusCode, &'static str, &'static str) {
+    match err {
+        QuotaError::AiCallsExceeded => (
+            StatusCode::TOO_MANY_REQUESTS,
+            "QuotaExceeded",
+            "quota.ai_calls_exceeded",
+        ),
+        QuotaError::ProductLimitReached => (
+            StatusCode::PAYMENT_REQUIRED,
+            "PlanLimitReached",
+            "quota.product_limit_reached",
+        ),
+        QuotaError::Repository(_) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "InternalError",
+            "repository.persistence",
+        ),
+    }
+}
+
+impl IntoErrorResponse for QuotaError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = quota_error_parts(&self);
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
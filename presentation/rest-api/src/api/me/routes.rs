@@ -0,0 +1,68 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::quota::use_cases::get_usage::{GetUsageParams, GetUsageUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::me::dto::MeResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct MeApi {
+    get_usage_use_case: Arc<dyn GetUsageUseCase>,
+}
+
+impl MeApi {
+    pub fn new(get_usage_use_case: Arc<dyn GetUsageUseCase>) -> Self {
+        Self { get_usage_use_case }
+    }
+}
+
+/// Current user API
+///
+/// Endpoints describing the authenticated user's account.
+#[OpenApi]
+impl MeApi {
+    /// Get the current user
+    ///
+    /// Returns the user's plan and today's usage against its limits.
+    #[oai(path = "/me", method = "get", tag = "ApiTags::Me")]
+    async fn get_me(&self, auth: BearerAuth) -> GetMeResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_usage_use_case
+            .execute(GetUsageParams {
+                user_id: user_id.clone(),
+            })
+            .await
+        {
+            Ok(usage) => GetMeResponse::Ok(Json(MeResponse::new(user_id.to_string(), usage))),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetMeResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetMeResponse {
+    #[oai(status = 200)]
+    Ok(Json<MeResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetMeResponse);
@@ -0,0 +1,70 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::meal_plan::model::MealPlanOutcome;
+
+use crate::api::examples::example_date;
+use crate::api::shopping_item::dto::ShoppingItemResponse;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PlannedMealResponse {
+    /// Planned meal unique identifier
+    pub id: String,
+    /// Suggestion the meal was planned from
+    pub suggestion_id: String,
+    /// Recipe title
+    pub title: String,
+    /// Day the meal will be cooked
+    pub planned_for: NaiveDate,
+    /// Pantry products set aside for this meal until the day after it;
+    /// other planned meals and new suggestions won't count on them
+    pub reserved_product_ids: Vec<String>,
+    /// Ingredients the pantry can't cover, added to the shopping list. Those
+    /// already on the list are left out.
+    pub added_to_shopping_list: Vec<ShoppingItemResponse>,
+    /// When the meal was planned
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<MealPlanOutcome> for PlannedMealResponse {
+    fn from(outcome: MealPlanOutcome) -> Self {
+        let meal = outcome.meal;
+        Self {
+            id: meal.id.to_string(),
+            suggestion_id: meal.suggestion_id,
+            title: meal.title,
+            planned_for: meal.planned_for,
+            reserved_product_ids: meal.product_ids.iter().map(|id| id.to_string()).collect(),
+            added_to_shopping_list: outcome
+                .added_to_shopping_list
+                .into_iter()
+                .map(|item| item.into())
+                .collect(),
+            created_at: meal.created_at,
+        }
+    }
+}
+
+impl Example for PlannedMealResponse {
+    fn example() -> Self {
+        let mut item = ShoppingItemResponse::example();
+        item.name = "Cebolla".to_string();
+        item.product_id = None;
+        item.attachment_url = None;
+        item.attachment_content_type = None;
+        item.product = None;
+        Self {
+            id: "6d1f0c2e-8a4b-4c3d-9e5f-7a8b9c0d1e2f".to_string(),
+            suggestion_id: "openai-1700000000000-0".to_string(),
+            title: "Tortilla de patatas".to_string(),
+            planned_for: NaiveDate::from_ymd_opt(2026, 3, 21).unwrap(),
+            reserved_product_ids: vec![
+                "3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string(),
+                "a1b2c3d4-e5f6-4a7b-8c9d-0e1f2a3b4c5d".to_string(),
+            ],
+            added_to_shopping_list: vec![item],
+            created_at: example_date(),
+        }
+    }
+}
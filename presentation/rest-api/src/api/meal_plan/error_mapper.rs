@@ -0,0 +1,44 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::meal_plan::errors::MealPlanError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for MealPlanError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            MealPlanError::SuggestionNotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "meal_plan.suggestion_not_found",
+            ),
+            MealPlanError::AlreadyPlanned => (
+                StatusCode::CONFLICT,
+                "Conflict",
+                "meal_plan.already_planned",
+            ),
+            MealPlanError::DateInPast => (
+                StatusCode::BAD_REQUEST,
+                "BadRequest",
+                "meal_plan.date_in_past",
+            ),
+            MealPlanError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
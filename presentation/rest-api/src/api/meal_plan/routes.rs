@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use chrono::{NaiveDate, Utc};
+use poem_openapi::{
+    OpenApi,
+    param::{Path, Query},
+    payload::Json,
+};
+
+use business::domain::meal_plan::use_cases::plan::{PlanSuggestionParams, PlanSuggestionUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::meal_plan::dto::PlannedMealResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct MealPlanApi {
+    plan_use_case: Arc<dyn PlanSuggestionUseCase>,
+}
+
+impl MealPlanApi {
+    pub fn new(plan_use_case: Arc<dyn PlanSuggestionUseCase>) -> Self {
+        Self { plan_use_case }
+    }
+}
+
+/// Meal planning API
+///
+/// Endpoints for planning several meals from suggestions.
+#[OpenApi]
+impl MealPlanApi {
+    /// Plan a suggestion
+    ///
+    /// Plans to cook a suggestion from the latest batch on a given day. The
+    /// pantry products it uses are reserved for it until the day after, so
+    /// planning another meal won't count on them and new suggestions leave
+    /// them out. Ingredients the pantry can't cover (finished, deleted, or already
+    /// reserved for another meal) are added to the shopping list unless they
+    /// are on it already. A suggestion can only be planned once.
+    #[oai(
+        path = "/suggestions/:id/plan",
+        method = "post",
+        tag = "ApiTags::MealPlans"
+    )]
+    async fn plan(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        /// Day the meal will be cooked (default: today)
+        planned_for: Query<Option<NaiveDate>>,
+    ) -> PlanSuggestionResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .plan_use_case
+            .execute(PlanSuggestionParams {
+                user_id,
+                suggestion_id: id.0,
+                planned_for: planned_for.0.unwrap_or_else(|| Utc::now().date_naive()),
+            })
+            .await
+        {
+            Ok(outcome) => PlanSuggestionResponse::Created(Json(outcome.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => PlanSuggestionResponse::BadRequest(json),
+                    404 => PlanSuggestionResponse::NotFound(json),
+                    409 => PlanSuggestionResponse::Conflict(json),
+                    _ => PlanSuggestionResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum PlanSuggestionResponse {
+    #[oai(status = 201)]
+    Created(Json<PlannedMealResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(PlanSuggestionResponse);
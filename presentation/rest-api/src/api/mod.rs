@@ -1,7 +1,50 @@
+pub mod ai_review;
+pub mod app_version;
+pub mod auth;
+pub mod backup;
+pub mod badge;
+pub mod barcode_contribution;
+pub mod billing;
+pub mod budget;
+pub mod challenge;
+pub mod client_config;
+pub mod cooking_session;
+pub mod device;
+pub mod duplicate;
 pub mod error;
+pub mod examples;
+pub mod experiment;
+pub mod give_away;
 pub mod health;
+pub mod http_cache;
+pub mod i18n;
+pub mod inbound_email;
+pub mod job;
+pub mod load_test;
+pub mod location_rule;
+pub mod maintenance;
+pub mod me;
+pub mod meal_plan;
+pub mod notification;
+pub mod pagination;
+pub mod payload_limit;
+pub mod preference;
 pub mod product;
+pub mod prompt;
+pub mod rate_limit;
+pub mod receipt_import;
+pub mod reminder;
+pub mod search;
 pub mod security;
+pub mod security_headers;
+pub mod share_link;
 pub mod shopping_item;
+pub mod shopping_trip;
+pub mod stats;
+pub mod storage;
+pub mod store_profile;
 pub mod suggestion;
 pub mod tags;
+pub mod traffic_log;
+pub mod vacation;
+pub mod widget;
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::notification::model::{CategoryToggles, NotificationPreferences};
+
+use crate::api::examples::example_date;
+
+#[derive(Debug, Clone, Object)]
+pub struct QuietHoursDto {
+    /// Local time pushes stop, e.g. "22:00:00"
+    pub start: NaiveTime,
+    /// Local time pushes resume; may be earlier than `start` to span midnight
+    pub end: NaiveTime,
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct NotificationCategoriesDto {
+    /// Products about to expire
+    pub expiry_alerts: bool,
+    /// Items left on the shopping list and reminders set on products
+    pub shopping_reminders: bool,
+    /// Recipe ideas, sent with the daily digest
+    pub suggestions: bool,
+    /// Completed challenges and zero-waste streaks, sent with the daily digest
+    pub achievements: bool,
+}
+
+impl From<CategoryToggles> for NotificationCategoriesDto {
+    fn from(toggles: CategoryToggles) -> Self {
+        Self {
+            expiry_alerts: toggles.expiry_alerts,
+            shopping_reminders: toggles.shopping_reminders,
+            suggestions: toggles.suggestions,
+            achievements: toggles.achievements,
+        }
+    }
+}
+
+impl From<NotificationCategoriesDto> for CategoryToggles {
+    fn from(dto: NotificationCategoriesDto) -> Self {
+        Self {
+            expiry_alerts: dto.expiry_alerts,
+            shopping_reminders: dto.shopping_reminders,
+            suggestions: dto.suggestions,
+            achievements: dto.achievements,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct NotificationPreferencesDto {
+    /// Minutes east of UTC of the user's local time, e.g. 60 for CET
+    pub utc_offset_minutes: i32,
+    /// Local time of the daily digest
+    pub digest_at: NaiveTime,
+    /// Hours in which nothing is pushed; alerts wait for them to end
+    pub quiet_hours: Option<QuietHoursDto>,
+    pub categories: NotificationCategoriesDto,
+}
+
+impl From<NotificationPreferences> for NotificationPreferencesDto {
+    fn from(preferences: NotificationPreferences) -> Self {
+        Self {
+            utc_offset_minutes: preferences.utc_offset_minutes(),
+            digest_at: preferences.digest_at,
+            quiet_hours: preferences.quiet_hours.map(|quiet| QuietHoursDto {
+                start: quiet.start,
+                end: quiet.end,
+            }),
+            categories: preferences.categories.into(),
+        }
+    }
+}
+
+impl Example for NotificationPreferencesDto {
+    fn example() -> Self {
+        Self {
+            utc_offset_minutes: 60,
+            ..NotificationPreferences::default().into()
+        }
+    }
+}
+
+/// Either `ids` or `before`, not both.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct AcknowledgeNotificationsRequest {
+    /// Notifications to mark read, as received in the push payload; up to 500
+    #[oai(validator(max_items = 500))]
+    pub ids: Option<Vec<String>>,
+    /// Marks read every notification sent before this time instead
+    pub before: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct AcknowledgeNotificationsResponse {
+    /// Notifications that were unread until now
+    pub acknowledged: u64,
+}
+
+impl Example for AcknowledgeNotificationsRequest {
+    fn example() -> Self {
+        Self {
+            ids: None,
+            before: Some(example_date() - Duration::hours(1)),
+        }
+    }
+}
+
+impl Example for AcknowledgeNotificationsResponse {
+    fn example() -> Self {
+        Self { acknowledged: 4 }
+    }
+}
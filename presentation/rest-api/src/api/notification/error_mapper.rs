@@ -0,0 +1,49 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::notification::errors::NotificationError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for NotificationError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            NotificationError::InvalidUtcOffset => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "notification.invalid_utc_offset",
+            ),
+            NotificationError::InvalidQuietHours => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "notification.invalid_quiet_hours",
+            ),
+            NotificationError::InvalidAcknowledgement => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "notification.invalid_acknowledgement",
+            ),
+            NotificationError::DeliveryFailed(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "ServiceUnavailable",
+                "notification.delivery_failed",
+            ),
+            NotificationError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,231 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+use uuid::Uuid;
+
+use business::domain::notification::errors::NotificationError;
+use business::domain::notification::model::Acknowledgement;
+use business::domain::notification::use_cases::acknowledge::{
+    AcknowledgeNotificationsParams, AcknowledgeNotificationsUseCase,
+};
+use business::domain::notification::use_cases::get_preferences::{
+    GetNotificationPreferencesParams, GetNotificationPreferencesUseCase,
+};
+use business::domain::notification::use_cases::update_preferences::{
+    UpdateNotificationPreferencesParams, UpdateNotificationPreferencesUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::notification::dto::{
+    AcknowledgeNotificationsRequest, AcknowledgeNotificationsResponse, NotificationPreferencesDto,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct NotificationApi {
+    get_preferences_use_case: Arc<dyn GetNotificationPreferencesUseCase>,
+    update_preferences_use_case: Arc<dyn UpdateNotificationPreferencesUseCase>,
+    acknowledge_use_case: Arc<dyn AcknowledgeNotificationsUseCase>,
+}
+
+impl NotificationApi {
+    pub fn new(
+        get_preferences_use_case: Arc<dyn GetNotificationPreferencesUseCase>,
+        update_preferences_use_case: Arc<dyn UpdateNotificationPreferencesUseCase>,
+        acknowledge_use_case: Arc<dyn AcknowledgeNotificationsUseCase>,
+    ) -> Self {
+        Self {
+            get_preferences_use_case,
+            update_preferences_use_case,
+            acknowledge_use_case,
+        }
+    }
+}
+
+/// Notifications API
+///
+/// When notifications reach the user: the daily digest time, quiet hours
+/// and which categories they want at all. Sent notifications stay unread
+/// until acknowledged.
+#[OpenApi]
+impl NotificationApi {
+    /// Get notification settings
+    ///
+    /// Users who never saved them get a digest at 18:00 UTC, quiet hours
+    /// from 22:00 to 08:00 and every category on.
+    #[oai(
+        path = "/settings/notifications",
+        method = "get",
+        tag = "ApiTags::Settings"
+    )]
+    async fn get_notification_preferences(
+        &self,
+        auth: BearerAuth,
+    ) -> GetNotificationPreferencesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_preferences_use_case
+            .execute(GetNotificationPreferencesParams { user_id })
+            .await
+        {
+            Ok(preferences) => GetNotificationPreferencesResponse::Ok(Json(preferences.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetNotificationPreferencesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Update notification settings
+    ///
+    /// Expiry alerts and shopping reminders go out right away; suggestions
+    /// and achievements wait for the digest. Anything due in the quiet hours
+    /// is held until they end.
+    #[oai(
+        path = "/settings/notifications",
+        method = "put",
+        tag = "ApiTags::Settings"
+    )]
+    async fn update_notification_preferences(
+        &self,
+        auth: BearerAuth,
+        body: Json<NotificationPreferencesDto>,
+    ) -> UpdateNotificationPreferencesResponse {
+        let params = UpdateNotificationPreferencesParams {
+            user_id: UserId::new(auth.0),
+            utc_offset_minutes: body.0.utc_offset_minutes,
+            digest_at: body.0.digest_at,
+            quiet_hours: body.0.quiet_hours.map(|quiet| (quiet.start, quiet.end)),
+            categories: body.0.categories.into(),
+        };
+
+        match self.update_preferences_use_case.execute(params).await {
+            Ok(preferences) => UpdateNotificationPreferencesResponse::Ok(Json(preferences.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UpdateNotificationPreferencesResponse::BadRequest(json),
+                    _ => UpdateNotificationPreferencesResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Acknowledge notifications
+    ///
+    /// Marks sent notifications read in one go: either the listed `ids`, as
+    /// received in the push payload, or everything sent `before` a time, e.g.
+    /// when the user opens a digest. Notifications already read or belonging
+    /// to someone else are skipped. The unread count in `GET /badges` drops
+    /// right away, so its `ETag` changes and cached badges are refreshed.
+    #[oai(
+        path = "/notifications/ack",
+        method = "post",
+        tag = "ApiTags::Notifications"
+    )]
+    async fn acknowledge_notifications(
+        &self,
+        auth: BearerAuth,
+        body: Json<AcknowledgeNotificationsRequest>,
+    ) -> AcknowledgeNotificationsApiResponse {
+        let acknowledgement = match (body.0.ids, body.0.before) {
+            (Some(ids), None) => {
+                let Ok(ids) = ids
+                    .iter()
+                    .map(|id| Uuid::parse_str(id))
+                    .collect::<Result<Vec<_>, _>>()
+                else {
+                    return AcknowledgeNotificationsApiResponse::BadRequest(Json(ErrorResponse {
+                        name: "ValidationError".to_string(),
+                        message: "notification.invalid_id".to_string(),
+                        description: None,
+                    }));
+                };
+                Acknowledgement::ids(ids)
+            }
+            (None, Some(before)) => Ok(Acknowledgement::SentBefore(before)),
+            _ => Err(NotificationError::InvalidAcknowledgement),
+        };
+        let acknowledgement = match acknowledgement {
+            Ok(acknowledgement) => acknowledgement,
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                return AcknowledgeNotificationsApiResponse::BadRequest(json);
+            }
+        };
+
+        match self
+            .acknowledge_use_case
+            .execute(AcknowledgeNotificationsParams {
+                user_id: UserId::new(auth.0),
+                acknowledgement,
+            })
+            .await
+        {
+            Ok(acknowledged) => {
+                AcknowledgeNotificationsApiResponse::Ok(Json(AcknowledgeNotificationsResponse {
+                    acknowledged,
+                }))
+            }
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                AcknowledgeNotificationsApiResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetNotificationPreferencesResponse {
+    #[oai(status = 200)]
+    Ok(Json<NotificationPreferencesDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UpdateNotificationPreferencesResponse {
+    #[oai(status = 200)]
+    Ok(Json<NotificationPreferencesDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum AcknowledgeNotificationsApiResponse {
+    #[oai(status = 200)]
+    Ok(Json<AcknowledgeNotificationsResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetNotificationPreferencesResponse,
+    UpdateNotificationPreferencesResponse,
+    AcknowledgeNotificationsApiResponse
+);
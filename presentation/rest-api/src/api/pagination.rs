@@ -0,0 +1,23 @@
+use poem_openapi::payload::Json;
+
+use business::domain::shared::pagination::KeysetPage;
+
+use crate::api::error::ErrorResponse;
+
+/// Page size of history endpoints when `limit` is omitted.
+pub const DEFAULT_HISTORY_LIMIT: u32 = 20;
+
+/// Builds a keyset page from the `limit` and `cursor` query parameters,
+/// rejecting cursors we did not issue.
+pub fn keyset_page(
+    limit: Option<u32>,
+    cursor: Option<String>,
+) -> Result<KeysetPage, Json<ErrorResponse>> {
+    KeysetPage::from_params(limit, cursor.as_deref(), DEFAULT_HISTORY_LIMIT).map_err(|err| {
+        Json(ErrorResponse {
+            name: "ValidationError".to_string(),
+            message: err.to_string(),
+            description: None,
+        })
+    })
+}
@@ -0,0 +1,229 @@
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::StreamExt;
+use poem::http::StatusCode;
+use poem::{Body, Endpoint, IntoResponse, Middleware, Request, Response};
+use poem_openapi::{Object, payload::Json, types::Example};
+
+use crate::config::payload_config::PayloadConfig;
+
+const DOWNSCALE_HINT: &str = "Downscale or recompress the image on the device before uploading \
+(e.g. longest side 1600px, JPEG quality 80).";
+
+/// Error returned when an image upload exceeds the endpoint's body limit.
+#[derive(Object, Debug)]
+#[oai(example)]
+pub struct PayloadTooLargeResponse {
+    /// Error category
+    pub name: String,
+    /// Code-style error identifier for i18n
+    pub message: String,
+    /// Human-readable message, present when `Accept-Language` is es or en
+    #[oai(skip_serializing_if_is_none)]
+    pub description: Option<String>,
+    /// Maximum accepted request body size in bytes
+    pub max_bytes: u64,
+    /// Suggested client-side remedy
+    pub hint: String,
+}
+
+impl Example for PayloadTooLargeResponse {
+    fn example() -> Self {
+        payload_too_large(5 * 1024 * 1024).0
+    }
+}
+
+pub fn payload_too_large(max_bytes: usize) -> Json<PayloadTooLargeResponse> {
+    Json(PayloadTooLargeResponse {
+        name: "PayloadTooLarge".to_string(),
+        message: "image.too_large".to_string(),
+        description: None,
+        max_bytes: max_bytes as u64,
+        hint: DOWNSCALE_HINT.to_string(),
+    })
+}
+
+/// Rejects image uploads larger than the configured limit: a declared
+/// `Content-Length` is checked before the body is read, and chunked or
+/// unlabelled bodies are cut off as soon as reading them goes past the limit.
+pub struct PayloadLimit {
+    config: PayloadConfig,
+}
+
+impl PayloadLimit {
+    pub fn new(config: PayloadConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for PayloadLimit {
+    type Output = PayloadLimitEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        PayloadLimitEndpoint {
+            inner: ep,
+            config: self.config.clone(),
+        }
+    }
+}
+
+pub struct PayloadLimitEndpoint<E> {
+    inner: E,
+    config: PayloadConfig,
+}
+
+impl<E: Endpoint> Endpoint for PayloadLimitEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, mut req: Request) -> poem::Result<Self::Output> {
+        let Some(limit) = self.config.limit_for(req.uri().path()) else {
+            return self.inner.call(req).await.map(IntoResponse::into_response);
+        };
+
+        let content_length = req
+            .headers()
+            .get(poem::http::header::CONTENT_LENGTH)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<usize>().ok());
+        if content_length.is_some_and(|len| len > limit) {
+            return Ok(too_large_response(limit));
+        }
+
+        let exceeded = Arc::new(AtomicBool::new(false));
+        let body = req.take_body();
+        req.set_body(limited(body, limit, exceeded.clone()));
+
+        let result = self.inner.call(req).await;
+        // The handler only saw a failed read; tell the client why
+        if exceeded.load(Ordering::Relaxed) {
+            return Ok(too_large_response(limit));
+        }
+        result.map(IntoResponse::into_response)
+    }
+}
+
+fn too_large_response(limit: usize) -> Response {
+    payload_too_large(limit)
+        .with_status(StatusCode::PAYLOAD_TOO_LARGE)
+        .into_response()
+}
+
+/// Passes the body through until more than `limit` bytes were read, then
+/// fails the read and raises `exceeded`.
+fn limited(body: Body, limit: usize, exceeded: Arc<AtomicBool>) -> Body {
+    let mut read = 0usize;
+    Body::from_bytes_stream(body.into_bytes_stream().map(move |chunk| {
+        let chunk = chunk?;
+        read += chunk.len();
+        if read > limit {
+            exceeded.store(true, Ordering::Relaxed);
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "request body exceeds the limit",
+            ));
+        }
+        Ok(chunk)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poem::{EndpointExt, handler, test::TestClient};
+
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    #[handler]
+    fn echo(body: String) -> String {
+        body
+    }
+
+    fn reading_client() -> TestClient<impl Endpoint> {
+        let app = echo.with(PayloadLimit::new(PayloadConfig {
+            identify_image_max_bytes: 10,
+            scan_receipt_max_bytes: 10,
+            attachment_max_bytes: 10,
+        }));
+        TestClient::new(app)
+    }
+
+    fn client() -> TestClient<impl Endpoint> {
+        let app = ok.with(PayloadLimit::new(PayloadConfig {
+            identify_image_max_bytes: 10,
+            scan_receipt_max_bytes: 10,
+            attachment_max_bytes: 10,
+        }));
+        TestClient::new(app)
+    }
+
+    #[tokio::test]
+    async fn should_return_structured_413_when_image_body_too_large() {
+        let resp = client()
+            .post("/products/identify/image")
+            .header("content-length", "11")
+            .body("x".repeat(11))
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+        let json = resp.json().await;
+        json.value()
+            .object()
+            .get("message")
+            .assert_string("image.too_large");
+        json.value().object().get("max_bytes").assert_i64(10);
+    }
+
+    #[tokio::test]
+    async fn should_pass_through_when_path_has_no_limit() {
+        let resp = client()
+            .post("/products")
+            .header("content-length", "11")
+            .body("x".repeat(11))
+            .send()
+            .await;
+
+        resp.assert_status_is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_cut_off_body_sent_without_length_once_over_limit() {
+        let resp = reading_client()
+            .post("/products/identify/image")
+            .body(Body::from_bytes_stream(futures::stream::iter(vec![
+                Ok::<_, io::Error>("x".repeat(6)),
+                Ok("x".repeat(6)),
+            ])))
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::PAYLOAD_TOO_LARGE);
+        resp.assert_json(serde_json::json!({
+            "name": "PayloadTooLarge",
+            "message": "image.too_large",
+            "max_bytes": 10,
+            "hint": DOWNSCALE_HINT,
+        }))
+        .await;
+    }
+
+    #[tokio::test]
+    async fn should_read_body_sent_without_length_within_limit() {
+        let resp = reading_client()
+            .post("/products/identify/image")
+            .body(Body::from_bytes_stream(futures::stream::iter(vec![
+                Ok::<_, io::Error>("x".repeat(5)),
+                Ok("x".repeat(5)),
+            ])))
+            .send()
+            .await;
+
+        resp.assert_status_is_ok();
+        resp.assert_text("x".repeat(10)).await;
+    }
+}
@@ -0,0 +1,37 @@
+use poem_openapi::{Object, types::Example};
+
+use business::domain::preference::model::UserPreferences;
+
+use crate::api::product::dto::AllergenDto;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PreferencesDto {
+    /// Days ahead a product counts as expiring soon, from 1 to 14
+    pub expiring_soon_days: u8,
+    /// Allergens to warn about and keep out of suggestions
+    #[oai(default)]
+    pub allergies: Vec<AllergenDto>,
+}
+
+impl From<UserPreferences> for PreferencesDto {
+    fn from(preferences: UserPreferences) -> Self {
+        Self {
+            expiring_soon_days: preferences.expiring_soon.days(),
+            allergies: preferences
+                .allergies
+                .into_iter()
+                .map(|a| a.into())
+                .collect(),
+        }
+    }
+}
+
+impl Example for PreferencesDto {
+    fn example() -> Self {
+        Self {
+            expiring_soon_days: 3,
+            allergies: vec![AllergenDto::Peanuts],
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::preference::errors::PreferenceError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for PreferenceError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            PreferenceError::InvalidExpiringSoonDays => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "preference.invalid_expiring_soon_days",
+            ),
+            PreferenceError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::preference::use_cases::get::{GetPreferencesParams, GetPreferencesUseCase};
+use business::domain::preference::use_cases::update::{
+    UpdatePreferencesParams, UpdatePreferencesUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::preference::dto::PreferencesDto;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct PreferenceApi {
+    get_use_case: Arc<dyn GetPreferencesUseCase>,
+    update_use_case: Arc<dyn UpdatePreferencesUseCase>,
+}
+
+impl PreferenceApi {
+    pub fn new(
+        get_use_case: Arc<dyn GetPreferencesUseCase>,
+        update_use_case: Arc<dyn UpdatePreferencesUseCase>,
+    ) -> Self {
+        Self {
+            get_use_case,
+            update_use_case,
+        }
+    }
+}
+
+/// Preferences API
+///
+/// Per-user settings that tune how the pantry is judged, such as how many
+/// days ahead a product counts as expiring soon.
+#[OpenApi]
+impl PreferenceApi {
+    /// Get preferences
+    ///
+    /// Users who never saved their preferences get the server defaults.
+    #[oai(
+        path = "/settings/preferences",
+        method = "get",
+        tag = "ApiTags::Settings"
+    )]
+    async fn get_preferences(&self, auth: BearerAuth) -> GetPreferencesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_use_case
+            .execute(GetPreferencesParams { user_id })
+            .await
+        {
+            Ok(preferences) => GetPreferencesResponse::Ok(Json(preferences.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetPreferencesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Update preferences
+    ///
+    /// The expiring-soon window drives urgency everywhere: the urgency sort,
+    /// badges, shared views, shopping list warnings and suggestion prompts.
+    #[oai(
+        path = "/settings/preferences",
+        method = "put",
+        tag = "ApiTags::Settings"
+    )]
+    async fn update_preferences(
+        &self,
+        auth: BearerAuth,
+        body: Json<PreferencesDto>,
+    ) -> UpdatePreferencesResponse {
+        let params = UpdatePreferencesParams {
+            user_id: UserId::new(auth.0),
+            expiring_soon_days: body.0.expiring_soon_days,
+            allergies: body.0.allergies.into_iter().map(|a| a.into()).collect(),
+        };
+
+        match self.update_use_case.execute(params).await {
+            Ok(preferences) => UpdatePreferencesResponse::Ok(Json(preferences.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UpdatePreferencesResponse::BadRequest(json),
+                    _ => UpdatePreferencesResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetPreferencesResponse {
+    #[oai(status = 200)]
+    Ok(Json<PreferencesDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UpdatePreferencesResponse {
+    #[oai(status = 200)]
+    Ok(Json<PreferencesDto>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetPreferencesResponse, UpdatePreferencesResponse);
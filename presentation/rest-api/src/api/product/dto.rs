@@ -1,18 +1,44 @@
-use chrono::{DateTime, Utc};
-use poem_openapi::{Enum, Object};
+use chrono::{DateTime, NaiveDate, Utc};
+use poem_openapi::{
+    Enum, Object,
+    types::{Example, MaybeUndefined},
+};
 use serde::{Deserialize, Serialize};
 
+use business::domain::product::calendar::{CalendarDay, ExpiryCalendar};
+use business::domain::product::enrichment::{
+    Allergen, NutritionFacts, ProductEnrichment, ScoreGrade,
+};
+use business::domain::product::events::{ProductChange, ProductChangeKind};
+use business::domain::product::facets::{ProductFacet, ProductHistorySummary};
 use business::domain::product::model::Product;
-use business::domain::product::value_objects::{ProductLocation, ProductOutcome, ProductStatus};
+use business::domain::product::photo_diff::PhotoDiff;
+use business::domain::product::query::ProductSort;
+use business::domain::product::urgency::UrgencyLevel;
+use business::domain::product::use_cases::create_batch::CreateProductsBatchItem;
+use business::domain::product::value_objects::{
+    EstimationStatus, ExpiryType, ProductCategory, ProductLocation, ProductOutcome, ProductStatus,
+};
+use business::domain::shared::pagination::CursorPage;
+use business::domain::shopping_item::model::ShoppingItem;
+use business::domain::storage::model::SignedUrl;
 
+use crate::api::error::ErrorResponse;
+use crate::api::examples::example_date;
+
+/// Lifecycle status of a pantry product.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum ProductStatusDto {
+    /// Unopened
     #[oai(rename = "new")]
     New,
+    /// Opened and in use
     #[oai(rename = "opened")]
     Opened,
+    /// Running low; good time to buy more
     #[oai(rename = "almost_empty")]
     AlmostEmpty,
+    /// Used up or discarded
     #[oai(rename = "finished")]
     Finished,
 }
@@ -39,12 +65,16 @@ impl From<ProductStatusDto> for ProductStatus {
     }
 }
 
+/// Where the product is stored.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum ProductLocationDto {
+    /// Refrigerator
     #[oai(rename = "fridge")]
     Fridge,
+    /// Pantry or cupboard
     #[oai(rename = "pantry")]
     Pantry,
+    /// Freezer
     #[oai(rename = "freezer")]
     Freezer,
 }
@@ -69,12 +99,200 @@ impl From<ProductLocationDto> for ProductLocation {
     }
 }
 
+/// Kind of food the product is.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ProductCategoryDto {
+    /// Milk, yogurt, cheese, eggs
+    #[oai(rename = "dairy")]
+    Dairy,
+    /// Fresh fruit and vegetables
+    #[oai(rename = "produce")]
+    Produce,
+    /// Meat and cold cuts
+    #[oai(rename = "meat")]
+    Meat,
+    /// Fish and seafood
+    #[oai(rename = "fish")]
+    Fish,
+    /// Bread and pastries
+    #[oai(rename = "bakery")]
+    Bakery,
+    /// Rice, pasta, flour, legumes and other staples
+    #[oai(rename = "dry_goods")]
+    DryGoods,
+    /// Tins and jars
+    #[oai(rename = "canned")]
+    Canned,
+    /// Frozen food
+    #[oai(rename = "frozen")]
+    Frozen,
+    /// Drinks
+    #[oai(rename = "beverages")]
+    Beverages,
+    /// Sauces, oils, spices and dressings
+    #[oai(rename = "condiments")]
+    Condiments,
+    /// Sweets and snacks
+    #[oai(rename = "snacks")]
+    Snacks,
+    /// Anything else
+    #[oai(rename = "other")]
+    Other,
+}
+
+impl From<ProductCategory> for ProductCategoryDto {
+    fn from(category: ProductCategory) -> Self {
+        match category {
+            ProductCategory::Dairy => ProductCategoryDto::Dairy,
+            ProductCategory::Produce => ProductCategoryDto::Produce,
+            ProductCategory::Meat => ProductCategoryDto::Meat,
+            ProductCategory::Fish => ProductCategoryDto::Fish,
+            ProductCategory::Bakery => ProductCategoryDto::Bakery,
+            ProductCategory::DryGoods => ProductCategoryDto::DryGoods,
+            ProductCategory::Canned => ProductCategoryDto::Canned,
+            ProductCategory::Frozen => ProductCategoryDto::Frozen,
+            ProductCategory::Beverages => ProductCategoryDto::Beverages,
+            ProductCategory::Condiments => ProductCategoryDto::Condiments,
+            ProductCategory::Snacks => ProductCategoryDto::Snacks,
+            ProductCategory::Other => ProductCategoryDto::Other,
+        }
+    }
+}
+
+impl From<ProductCategoryDto> for ProductCategory {
+    fn from(dto: ProductCategoryDto) -> Self {
+        match dto {
+            ProductCategoryDto::Dairy => ProductCategory::Dairy,
+            ProductCategoryDto::Produce => ProductCategory::Produce,
+            ProductCategoryDto::Meat => ProductCategory::Meat,
+            ProductCategoryDto::Fish => ProductCategory::Fish,
+            ProductCategoryDto::Bakery => ProductCategory::Bakery,
+            ProductCategoryDto::DryGoods => ProductCategory::DryGoods,
+            ProductCategoryDto::Canned => ProductCategory::Canned,
+            ProductCategoryDto::Frozen => ProductCategory::Frozen,
+            ProductCategoryDto::Beverages => ProductCategory::Beverages,
+            ProductCategoryDto::Condiments => ProductCategory::Condiments,
+            ProductCategoryDto::Snacks => ProductCategory::Snacks,
+            ProductCategoryDto::Other => ProductCategory::Other,
+        }
+    }
+}
+
+/// What the date printed on the label means.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ExpiryTypeDto {
+    /// "Use by": not safe to eat after the date
+    #[oai(rename = "use_by")]
+    UseBy,
+    /// "Best before": still safe after the date, but losing quality
+    #[oai(rename = "best_before")]
+    BestBefore,
+    /// No label, or unknown; treated like "use by"
+    #[oai(rename = "none")]
+    None,
+}
+
+impl From<ExpiryType> for ExpiryTypeDto {
+    fn from(expiry_type: ExpiryType) -> Self {
+        match expiry_type {
+            ExpiryType::UseBy => ExpiryTypeDto::UseBy,
+            ExpiryType::BestBefore => ExpiryTypeDto::BestBefore,
+            ExpiryType::None => ExpiryTypeDto::None,
+        }
+    }
+}
+
+impl From<ExpiryTypeDto> for ExpiryType {
+    fn from(dto: ExpiryTypeDto) -> Self {
+        match dto {
+            ExpiryTypeDto::UseBy => ExpiryType::UseBy,
+            ExpiryTypeDto::BestBefore => ExpiryType::BestBefore,
+            ExpiryTypeDto::None => ExpiryType::None,
+        }
+    }
+}
+
+/// Where the expiry estimation of a product stands.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum EstimationStatusDto {
+    /// Nothing outstanding
+    #[oai(rename = "done")]
+    Done,
+    /// The estimator was down; the estimate is retried in the background
+    #[oai(rename = "pending")]
+    Pending,
+    /// Every retry found the estimator down; the date can be entered by hand
+    /// or estimated again later
+    #[oai(rename = "failed")]
+    Failed,
+}
+
+impl From<EstimationStatus> for EstimationStatusDto {
+    fn from(status: EstimationStatus) -> Self {
+        match status {
+            EstimationStatus::Done => EstimationStatusDto::Done,
+            EstimationStatus::Pending => EstimationStatusDto::Pending,
+            EstimationStatus::Failed => EstimationStatusDto::Failed,
+        }
+    }
+}
+
+/// How urgently a product should be used before it expires.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum UrgencyLevelDto {
+    /// Fresh, or no known expiry
+    #[oai(rename = "ok")]
+    Ok,
+    /// Expires in 1-2 days
+    #[oai(rename = "use_soon")]
+    UseSoon,
+    /// Expires today
+    #[oai(rename = "use_today")]
+    UseToday,
+    /// Past its best-before date: still safe, but losing quality
+    #[oai(rename = "quality_declining")]
+    QualityDeclining,
+    /// Already expired
+    #[oai(rename = "wouldnt_trust")]
+    WouldntTrust,
+}
+
+impl From<UrgencyLevel> for UrgencyLevelDto {
+    fn from(level: UrgencyLevel) -> Self {
+        match level {
+            UrgencyLevel::Ok => UrgencyLevelDto::Ok,
+            UrgencyLevel::UseSoon => UrgencyLevelDto::UseSoon,
+            UrgencyLevel::UseToday => UrgencyLevelDto::UseToday,
+            UrgencyLevel::QualityDeclining => UrgencyLevelDto::QualityDeclining,
+            UrgencyLevel::WouldntTrust => UrgencyLevelDto::WouldntTrust,
+        }
+    }
+}
+
+impl From<UrgencyLevelDto> for UrgencyLevel {
+    fn from(dto: UrgencyLevelDto) -> Self {
+        match dto {
+            UrgencyLevelDto::Ok => UrgencyLevel::Ok,
+            UrgencyLevelDto::UseSoon => UrgencyLevel::UseSoon,
+            UrgencyLevelDto::UseToday => UrgencyLevel::UseToday,
+            UrgencyLevelDto::QualityDeclining => UrgencyLevel::QualityDeclining,
+            UrgencyLevelDto::WouldntTrust => UrgencyLevel::WouldntTrust,
+        }
+    }
+}
+
+/// What happened to a finished product.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum ProductOutcomeDto {
+    /// Consumed
     #[oai(rename = "used")]
     Used,
+    /// Discarded without being used
     #[oai(rename = "thrown_away")]
     ThrownAway,
+    /// Donated or given to someone who will use it
+    #[oai(rename = "given_away")]
+    GivenAway,
 }
 
 impl From<ProductOutcome> for ProductOutcomeDto {
@@ -82,6 +300,7 @@ impl From<ProductOutcome> for ProductOutcomeDto {
         match outcome {
             ProductOutcome::Used => ProductOutcomeDto::Used,
             ProductOutcome::ThrownAway => ProductOutcomeDto::ThrownAway,
+            ProductOutcome::GivenAway => ProductOutcomeDto::GivenAway,
         }
     }
 }
@@ -91,13 +310,69 @@ impl From<ProductOutcomeDto> for ProductOutcome {
         match dto {
             ProductOutcomeDto::Used => ProductOutcome::Used,
             ProductOutcomeDto::ThrownAway => ProductOutcome::ThrownAway,
+            ProductOutcomeDto::GivenAway => ProductOutcome::GivenAway,
+        }
+    }
+}
+
+/// Order of the product list.
+#[derive(Debug, Clone, Enum)]
+pub enum ProductSortDto {
+    /// Newest first
+    #[oai(rename = "created_at")]
+    CreatedAt,
+    /// Soonest expiry first (real date, else the estimate); undated products last
+    #[oai(rename = "expiry")]
+    Expiry,
+    /// Alphabetical by name
+    #[oai(rename = "name")]
+    Name,
+    /// Most urgent first (use today, use soon, past best-before, fine, expired),
+    /// then soonest expiry
+    #[oai(rename = "urgency")]
+    Urgency,
+}
+
+impl From<ProductSortDto> for ProductSort {
+    fn from(dto: ProductSortDto) -> Self {
+        match dto {
+            ProductSortDto::CreatedAt => ProductSort::CreatedAtDesc,
+            ProductSortDto::Expiry => ProductSort::ExpiryAsc,
+            ProductSortDto::Name => ProductSort::NameAsc,
+            ProductSortDto::Urgency => ProductSort::UrgencyDesc,
+        }
+    }
+}
+
+/// Related data to embed in product responses.
+#[derive(Debug, Clone, Enum)]
+pub enum ProductIncludeDto {
+    /// The unbought shopping item restocking the product
+    #[oai(rename = "shopping_item")]
+    ShoppingItem,
+    /// Nutrition facts and scores of products created from a scanned barcode
+    #[oai(rename = "enrichment")]
+    Enrichment,
+    /// How earlier products with the same name ended
+    #[oai(rename = "history_summary")]
+    HistorySummary,
+}
+
+impl From<ProductIncludeDto> for ProductFacet {
+    fn from(dto: ProductIncludeDto) -> Self {
+        match dto {
+            ProductIncludeDto::ShoppingItem => ProductFacet::ShoppingItem,
+            ProductIncludeDto::Enrichment => ProductFacet::Enrichment,
+            ProductIncludeDto::HistorySummary => ProductFacet::HistorySummary,
         }
     }
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct CreateProductRequest {
     /// Product name (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 255))]
     pub name: String,
     /// Product status
     pub status: ProductStatusDto,
@@ -105,6 +380,7 @@ pub struct CreateProductRequest {
     #[oai(skip_serializing_if_is_none)]
     pub location: Option<ProductLocationDto>,
     /// Quantity description
+    #[oai(validator(max_length = 100))]
     #[oai(skip_serializing_if_is_none)]
     pub quantity: Option<String>,
     /// Expiry date
@@ -113,14 +389,78 @@ pub struct CreateProductRequest {
     /// Estimated expiry date
     #[oai(skip_serializing_if_is_none)]
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    /// What the expiry date means (defaults to 'none')
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_type: Option<ExpiryTypeDto>,
     /// Product outcome (only valid when status is 'finished')
     #[oai(skip_serializing_if_is_none)]
     pub outcome: Option<ProductOutcomeDto>,
+    /// Kind of food
+    #[oai(skip_serializing_if_is_none)]
+    pub category: Option<ProductCategoryDto>,
+    /// Free-form labels, up to 10 of at most 30 characters; stored trimmed
+    /// and lowercased
+    #[oai(default, skip_serializing_if_is_empty)]
+    pub tags: Vec<String>,
+    /// Barcode the product was scanned with; links it to the nutrition data
+    /// found by the barcode lookup
+    #[oai(validator(min_length = 1, max_length = 64))]
+    #[oai(skip_serializing_if_is_none)]
+    pub barcode: Option<String>,
+}
+
+impl From<CreateProductRequest> for CreateProductsBatchItem {
+    fn from(request: CreateProductRequest) -> Self {
+        Self {
+            name: request.name,
+            status: request.status.into(),
+            location: request.location.map(|l| l.into()),
+            quantity: request.quantity,
+            expiry_date: request.expiry_date,
+            estimated_expiry_date: request.estimated_expiry_date,
+            expiry_type: request.expiry_type.map(|t| t.into()).unwrap_or_default(),
+            outcome: request.outcome.map(|o| o.into()),
+            category: request.category.map(|c| c.into()),
+            tags: request.tags,
+            barcode: request.barcode,
+        }
+    }
+}
+
+/// Request to create several products at once, e.g. the items of a receipt.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CreateProductsBatchRequest {
+    /// Products to create; saved all together or not at all
+    #[oai(validator(min_items = 1, max_items = 50))]
+    pub products: Vec<CreateProductRequest>,
+}
+
+/// One product created by a batch.
+#[derive(Debug, Clone, Object)]
+pub struct BatchProductCreationItem {
+    /// Saved product, with its expiry estimate when one was made
+    pub product: ProductResponse,
+    /// Why estimating its expiry failed; the product is saved anyway
+    #[oai(skip_serializing_if_is_none)]
+    pub estimation_error: Option<ErrorResponse>,
+}
+
+/// Products created by a batch.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BatchProductCreationResponse {
+    /// One entry per requested product, in request order
+    pub results: Vec<BatchProductCreationItem>,
+    /// Number of products whose expiry estimation failed
+    pub estimation_failed: u32,
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct UpdateProductRequest {
     /// Product name (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 255))]
     pub name: String,
     /// Product status
     pub status: ProductStatusDto,
@@ -128,6 +468,7 @@ pub struct UpdateProductRequest {
     #[oai(skip_serializing_if_is_none)]
     pub location: Option<ProductLocationDto>,
     /// Quantity description
+    #[oai(validator(max_length = 100))]
     #[oai(skip_serializing_if_is_none)]
     pub quantity: Option<String>,
     /// Expiry date
@@ -136,14 +477,69 @@ pub struct UpdateProductRequest {
     /// Estimated expiry date
     #[oai(skip_serializing_if_is_none)]
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    /// What the expiry date means (defaults to 'none')
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_type: Option<ExpiryTypeDto>,
     /// Product outcome (only valid when status is 'finished')
     #[oai(skip_serializing_if_is_none)]
     pub outcome: Option<ProductOutcomeDto>,
+    /// Kind of food
+    #[oai(skip_serializing_if_is_none)]
+    pub category: Option<ProductCategoryDto>,
+    /// Free-form labels, replacing the current ones; up to 10 of at most 30
+    /// characters
+    #[oai(default, skip_serializing_if_is_empty)]
+    pub tags: Vec<String>,
+}
+
+/// Changes to a product. Fields left out keep their value; `null` clears the
+/// optional ones.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PatchProductRequest {
+    /// Product name (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 255))]
+    #[oai(skip_serializing_if_is_none)]
+    pub name: Option<String>,
+    /// Product status
+    #[oai(skip_serializing_if_is_none)]
+    pub status: Option<ProductStatusDto>,
+    /// Storage location
+    #[oai(skip_serializing_if_is_none)]
+    pub location: MaybeUndefined<ProductLocationDto>,
+    /// Quantity description
+    #[oai(validator(max_length = 100))]
+    #[oai(skip_serializing_if_is_none)]
+    pub quantity: MaybeUndefined<String>,
+    /// Expiry date
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_date: MaybeUndefined<DateTime<Utc>>,
+    /// Estimated expiry date
+    #[oai(skip_serializing_if_is_none)]
+    pub estimated_expiry_date: MaybeUndefined<DateTime<Utc>>,
+    /// What the expiry date means
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_type: Option<ExpiryTypeDto>,
+    /// Product outcome (only valid when status is 'finished'). Cleared when
+    /// a finished product moves back to an active status
+    #[oai(skip_serializing_if_is_none)]
+    pub outcome: MaybeUndefined<ProductOutcomeDto>,
+    /// Kind of food
+    #[oai(skip_serializing_if_is_none)]
+    pub category: MaybeUndefined<ProductCategoryDto>,
+    /// Free-form labels, replacing the current ones; up to 10 of at most 30
+    /// characters
+    #[oai(skip_serializing_if_is_none)]
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ProductResponse {
     /// Product unique identifier
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
     pub id: String,
     /// Product name
     pub name: String,
@@ -161,9 +557,43 @@ pub struct ProductResponse {
     /// Estimated expiry date
     #[oai(skip_serializing_if_is_none)]
     pub estimated_expiry_date: Option<DateTime<Utc>>,
+    /// How sure the estimator was of the estimated expiry date; omitted when
+    /// unknown. Past low-confidence estimates count as quality declining
+    /// rather than expired
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_confidence: Option<ConfidenceDto>,
+    /// What the expiry date means
+    pub expiry_type: ExpiryTypeDto,
     /// Product outcome
     #[oai(skip_serializing_if_is_none)]
     pub outcome: Option<ProductOutcomeDto>,
+    /// Whether the estimated expiry date is still on its way
+    pub estimation_status: EstimationStatusDto,
+    /// Kind of food
+    #[oai(skip_serializing_if_is_none)]
+    pub category: Option<ProductCategoryDto>,
+    /// Free-form labels
+    #[oai(skip_serializing_if_is_empty)]
+    pub tags: Vec<String>,
+    /// Shopping list entry restocking the product; only with
+    /// `include=shopping_item`, and omitted when there is none or it was bought
+    #[oai(skip_serializing_if_is_none)]
+    pub shopping_item: Option<Box<ProductShoppingItemResponse>>,
+    /// Nutrition data; only with `include=enrichment`, and omitted for
+    /// products not created from a scanned barcode
+    #[oai(skip_serializing_if_is_none)]
+    pub enrichment: Option<Box<ProductEnrichmentResponse>>,
+    /// How earlier products with the same name ended; only with
+    /// `include=history_summary`, and omitted when there were none
+    #[oai(skip_serializing_if_is_none)]
+    pub history_summary: Option<Box<ProductHistorySummaryResponse>>,
+    /// Declared allergens matching the user's allergies
+    #[oai(skip_serializing_if_is_empty)]
+    pub allergen_warnings: Vec<AllergenDto>,
+    /// Small JPEG of the product photo for list rows; expires like every
+    /// signed URL. Omitted until a photo is uploaded and its thumbnail made
+    #[oai(skip_serializing_if_is_none)]
+    pub thumbnail_url: Option<String>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
@@ -180,23 +610,293 @@ impl From<Product> for ProductResponse {
             quantity: product.quantity,
             expiry_date: product.expiry_date,
             estimated_expiry_date: product.estimated_expiry_date,
+            expiry_confidence: product.expiry_confidence.map(|c| c.into()),
+            expiry_type: product.expiry_type.into(),
             outcome: product.outcome.map(|o| o.into()),
+            estimation_status: product.estimation_status.into(),
+            category: product.category.map(|c| c.into()),
+            tags: product.tags,
+            shopping_item: None,
+            enrichment: None,
+            history_summary: None,
+            allergen_warnings: vec![],
+            thumbnail_url: None,
             created_at: product.created_at,
             updated_at: product.updated_at,
         }
     }
 }
 
+impl ProductResponse {
+    pub fn with_shopping_item(mut self, item: Option<ShoppingItem>) -> Self {
+        self.shopping_item = item.map(|i| Box::new(i.into()));
+        self
+    }
+
+    pub fn with_enrichment(mut self, enrichment: Option<ProductEnrichment>) -> Self {
+        self.enrichment = enrichment.map(|e| Box::new(e.into()));
+        self
+    }
+
+    pub fn with_history_summary(mut self, summary: Option<ProductHistorySummary>) -> Self {
+        self.history_summary = summary.map(|s| Box::new(s.into()));
+        self
+    }
+
+    pub fn with_allergen_warnings(mut self, allergens: Vec<Allergen>) -> Self {
+        self.allergen_warnings = allergens.into_iter().map(|a| a.into()).collect();
+        self
+    }
+
+    pub fn with_thumbnail(mut self, thumbnail: Option<SignedUrl>) -> Self {
+        self.thumbnail_url = thumbnail.map(|t| t.url);
+        self
+    }
+}
+
+/// Unbought shopping list entry restocking a product.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProductShoppingItemResponse {
+    /// Shopping item unique identifier
+    pub id: String,
+    /// Item name
+    pub name: String,
+    /// When the item was added to the list
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<ShoppingItem> for ProductShoppingItemResponse {
+    fn from(item: ShoppingItem) -> Self {
+        Self {
+            id: item.id.to_string(),
+            name: item.name,
+            created_at: item.created_at,
+        }
+    }
+}
+
+/// How the user's earlier products with the same name ended.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProductHistorySummaryResponse {
+    /// Earlier products finished, with or without an outcome
+    pub finished: u32,
+    /// Of those, used up
+    pub used: u32,
+    /// Of those, thrown away
+    pub thrown_away: u32,
+    /// Of those, given away
+    pub given_away: u32,
+    /// Last update of the latest one
+    #[oai(skip_serializing_if_is_none)]
+    pub last_finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<ProductHistorySummary> for ProductHistorySummaryResponse {
+    fn from(summary: ProductHistorySummary) -> Self {
+        Self {
+            finished: summary.finished,
+            used: summary.used,
+            thrown_away: summary.thrown_away,
+            given_away: summary.given_away,
+            last_finished_at: summary.last_finished_at,
+        }
+    }
+}
+
+// --- DTOs for Open Food Facts enrichment ---
+
+/// Nutri-Score or Eco-Score grade, from A (best) to E.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ScoreGradeDto {
+    #[oai(rename = "a")]
+    A,
+    #[oai(rename = "b")]
+    B,
+    #[oai(rename = "c")]
+    C,
+    #[oai(rename = "d")]
+    D,
+    #[oai(rename = "e")]
+    E,
+}
+
+impl From<ScoreGrade> for ScoreGradeDto {
+    fn from(grade: ScoreGrade) -> Self {
+        match grade {
+            ScoreGrade::A => ScoreGradeDto::A,
+            ScoreGrade::B => ScoreGradeDto::B,
+            ScoreGrade::C => ScoreGradeDto::C,
+            ScoreGrade::D => ScoreGradeDto::D,
+            ScoreGrade::E => ScoreGradeDto::E,
+        }
+    }
+}
+
+/// One of the 14 allergens that must be declared on EU food labels.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Enum)]
+pub enum AllergenDto {
+    #[oai(rename = "celery")]
+    Celery,
+    #[oai(rename = "crustaceans")]
+    Crustaceans,
+    #[oai(rename = "eggs")]
+    Eggs,
+    #[oai(rename = "fish")]
+    Fish,
+    #[oai(rename = "gluten")]
+    Gluten,
+    #[oai(rename = "lupin")]
+    Lupin,
+    #[oai(rename = "milk")]
+    Milk,
+    #[oai(rename = "molluscs")]
+    Molluscs,
+    #[oai(rename = "mustard")]
+    Mustard,
+    /// Tree nuts
+    #[oai(rename = "nuts")]
+    Nuts,
+    #[oai(rename = "peanuts")]
+    Peanuts,
+    #[oai(rename = "sesame_seeds")]
+    SesameSeeds,
+    #[oai(rename = "soybeans")]
+    Soybeans,
+    /// Sulphur dioxide and sulphites
+    #[oai(rename = "sulphites")]
+    Sulphites,
+}
+
+impl From<Allergen> for AllergenDto {
+    fn from(allergen: Allergen) -> Self {
+        match allergen {
+            Allergen::Celery => AllergenDto::Celery,
+            Allergen::Crustaceans => AllergenDto::Crustaceans,
+            Allergen::Eggs => AllergenDto::Eggs,
+            Allergen::Fish => AllergenDto::Fish,
+            Allergen::Gluten => AllergenDto::Gluten,
+            Allergen::Lupin => AllergenDto::Lupin,
+            Allergen::Milk => AllergenDto::Milk,
+            Allergen::Molluscs => AllergenDto::Molluscs,
+            Allergen::Mustard => AllergenDto::Mustard,
+            Allergen::Nuts => AllergenDto::Nuts,
+            Allergen::Peanuts => AllergenDto::Peanuts,
+            Allergen::SesameSeeds => AllergenDto::SesameSeeds,
+            Allergen::Soybeans => AllergenDto::Soybeans,
+            Allergen::Sulphites => AllergenDto::Sulphites,
+        }
+    }
+}
+
+impl From<AllergenDto> for Allergen {
+    fn from(dto: AllergenDto) -> Self {
+        match dto {
+            AllergenDto::Celery => Allergen::Celery,
+            AllergenDto::Crustaceans => Allergen::Crustaceans,
+            AllergenDto::Eggs => Allergen::Eggs,
+            AllergenDto::Fish => Allergen::Fish,
+            AllergenDto::Gluten => Allergen::Gluten,
+            AllergenDto::Lupin => Allergen::Lupin,
+            AllergenDto::Milk => Allergen::Milk,
+            AllergenDto::Molluscs => Allergen::Molluscs,
+            AllergenDto::Mustard => Allergen::Mustard,
+            AllergenDto::Nuts => Allergen::Nuts,
+            AllergenDto::Peanuts => Allergen::Peanuts,
+            AllergenDto::SesameSeeds => Allergen::SesameSeeds,
+            AllergenDto::Soybeans => Allergen::Soybeans,
+            AllergenDto::Sulphites => Allergen::Sulphites,
+        }
+    }
+}
+
+/// Nutrition facts per 100 g (or 100 ml); missing values are not published.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct NutritionFactsResponse {
+    #[oai(skip_serializing_if_is_none)]
+    pub energy_kcal: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub fat_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub saturated_fat_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub carbohydrates_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub sugars_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub fiber_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub proteins_g: Option<f64>,
+    #[oai(skip_serializing_if_is_none)]
+    pub salt_g: Option<f64>,
+}
+
+impl From<NutritionFacts> for NutritionFactsResponse {
+    fn from(n: NutritionFacts) -> Self {
+        Self {
+            energy_kcal: n.energy_kcal,
+            fat_g: n.fat,
+            saturated_fat_g: n.saturated_fat,
+            carbohydrates_g: n.carbohydrates,
+            sugars_g: n.sugars,
+            fiber_g: n.fiber,
+            proteins_g: n.proteins,
+            salt_g: n.salt,
+        }
+    }
+}
+
+/// Open Food Facts data for a barcode.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProductEnrichmentResponse {
+    /// Barcode the data belongs to
+    pub barcode: String,
+    /// Nutri-Score grade
+    #[oai(skip_serializing_if_is_none)]
+    pub nutriscore: Option<ScoreGradeDto>,
+    /// Eco-Score grade
+    #[oai(skip_serializing_if_is_none)]
+    pub ecoscore: Option<ScoreGradeDto>,
+    /// Nutrition facts per 100 g (or 100 ml)
+    pub nutrition: NutritionFactsResponse,
+    /// Declared allergens
+    #[oai(skip_serializing_if_is_empty)]
+    pub allergens: Vec<AllergenDto>,
+    /// When the data was fetched from Open Food Facts
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl From<ProductEnrichment> for ProductEnrichmentResponse {
+    fn from(e: ProductEnrichment) -> Self {
+        Self {
+            barcode: e.barcode,
+            nutriscore: e.nutriscore.map(|g| g.into()),
+            ecoscore: e.ecoscore.map(|g| g.into()),
+            nutrition: e.nutrition.into(),
+            allergens: e.allergens.into_iter().map(|a| a.into()).collect(),
+            fetched_at: e.fetched_at,
+        }
+    }
+}
+
 // --- DTOs for expiry estimation ---
 
+/// Confidence of an AI expiry estimation.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum ConfidenceDto {
+    /// Reliable estimate
     #[oai(rename = "high")]
     High,
+    /// Reasonable estimate
     #[oai(rename = "medium")]
     Medium,
+    /// Rough guess
     #[oai(rename = "low")]
     Low,
+    /// No estimate could be made
     #[oai(rename = "none")]
     None,
 }
@@ -214,18 +914,21 @@ impl From<business::domain::product::services::Confidence> for ConfidenceDto {
 
 /// Request to estimate expiry date based on product attributes.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct EstimateExpiryDateRequest {
     /// Product name
+    #[oai(validator(min_length = 1, max_length = 255))]
     pub product_name: String,
-    /// Product status (new, opened, almost_empty, finished)
-    pub status: String,
-    /// Storage location (fridge, pantry, freezer)
+    /// Product status
+    pub status: ProductStatusDto,
+    /// Storage location
     #[oai(skip_serializing_if_is_none)]
-    pub location: Option<String>,
+    pub location: Option<ProductLocationDto>,
 }
 
 /// Expiry date estimation result.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ExpiryEstimationResponse {
     /// Estimated expiry date (ISO 8601), or null if unable to estimate
     #[oai(skip_serializing_if_is_none)]
@@ -234,12 +937,49 @@ pub struct ExpiryEstimationResponse {
     pub confidence: ConfidenceDto,
 }
 
+/// Request to estimate expiry dates for several saved products.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct EstimateExpiryBatchRequest {
+    /// Product IDs to estimate; duplicates are estimated once
+    #[oai(validator(min_items = 1, max_items = 50))]
+    pub product_ids: Vec<String>,
+}
+
+/// Outcome for one product of a batch estimation.
+#[derive(Debug, Clone, Object)]
+pub struct BatchExpiryEstimationItem {
+    /// Product the outcome refers to
+    pub product_id: String,
+    /// Updated product, when the estimation succeeded
+    #[oai(skip_serializing_if_is_none)]
+    pub product: Option<ProductResponse>,
+    /// Why the estimation failed, otherwise
+    #[oai(skip_serializing_if_is_none)]
+    pub error: Option<ErrorResponse>,
+}
+
+/// Per-product outcomes of a batch estimation.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct BatchExpiryEstimationResponse {
+    /// One entry per distinct product ID, in request order
+    pub results: Vec<BatchExpiryEstimationItem>,
+    /// Number of products estimated successfully
+    pub succeeded: u32,
+    /// Number of products that failed
+    pub failed: u32,
+}
+
 // --- DTOs for product identification ---
 
+/// Confidence of an AI product identification.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum IdentificationConfidenceDto {
+    /// Product clearly identified
     #[oai(rename = "high")]
     High,
+    /// Best guess; ask the user to confirm
     #[oai(rename = "low")]
     Low,
 }
@@ -259,12 +999,18 @@ impl From<business::domain::product::services::IdentificationConfidence>
     }
 }
 
+/// How a product was identified.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum IdentificationMethodDto {
+    /// Barcode lookup
     #[oai(rename = "barcode")]
     Barcode,
+    /// Image recognition
     #[oai(rename = "visual")]
     Visual,
+    /// Name the user gave a barcode Open Food Facts doesn't know
+    #[oai(rename = "contribution")]
+    Contribution,
 }
 
 impl From<business::domain::product::services::IdentificationMethod> for IdentificationMethodDto {
@@ -276,26 +1022,49 @@ impl From<business::domain::product::services::IdentificationMethod> for Identif
             business::domain::product::services::IdentificationMethod::Visual => {
                 IdentificationMethodDto::Visual
             }
+            business::domain::product::services::IdentificationMethod::Contribution => {
+                IdentificationMethodDto::Contribution
+            }
         }
     }
 }
 
 /// Request to identify a product by image.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct IdentifyByImageRequest {
-    /// Base64-encoded image data
+    /// Base64-encoded image data, optionally prefixed with a `data:image/...;base64,` header
+    #[oai(validator(
+        min_length = 1,
+        pattern = "^(data:image/[a-z]+;base64,)?[A-Za-z0-9+/]+={0,2}$"
+    ))]
+    pub image_base64: String,
+}
+
+/// Request to set a product's photo.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct UploadProductImageRequest {
+    /// Base64-encoded JPEG, PNG or WebP, optionally prefixed with a `data:image/...;base64,` header
+    #[oai(validator(
+        min_length = 1,
+        pattern = "^(data:image/[a-z]+;base64,)?[A-Za-z0-9+/]+={0,2}$"
+    ))]
     pub image_base64: String,
 }
 
 /// Request to identify a product by barcode.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct IdentifyByBarcodeRequest {
     /// Barcode string (e.g., EAN-13)
+    #[oai(validator(min_length = 1, max_length = 64))]
     pub barcode: String,
 }
 
 /// Product identification result.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ProductIdentificationResponse {
     /// Identified product name
     pub name: String,
@@ -309,6 +1078,19 @@ pub struct ProductIdentificationResponse {
     /// Suggested quantity
     #[oai(skip_serializing_if_is_none)]
     pub suggested_quantity: Option<String>,
+    /// Kind of date printed on the label, when it could be read
+    #[oai(skip_serializing_if_is_none)]
+    pub suggested_expiry_type: Option<ExpiryTypeDto>,
+    /// Kind of food the product looks like
+    #[oai(skip_serializing_if_is_none)]
+    pub suggested_category: Option<ProductCategoryDto>,
+    /// Nutrition data; only for barcode lookups that found some
+    #[oai(skip_serializing_if_is_none)]
+    pub enrichment: Option<Box<ProductEnrichmentResponse>>,
+    /// Set for photo identifications made under an experiment; pass it to
+    /// `POST /products/identify/:ai_call_id/accept` when the user keeps the result
+    #[oai(skip_serializing_if_is_none)]
+    pub ai_call_id: Option<String>,
 }
 
 impl From<business::domain::product::services::ProductIdentification>
@@ -321,19 +1103,29 @@ impl From<business::domain::product::services::ProductIdentification>
             method: id.method.into(),
             suggested_location: id.suggested_location.map(|l| l.into()),
             suggested_quantity: id.suggested_quantity,
+            suggested_expiry_type: id.suggested_expiry_type.map(|t| t.into()),
+            suggested_category: id.suggested_category.map(|c| c.into()),
+            enrichment: id.enrichment.map(|e| Box::new(e.into())),
+            ai_call_id: id.ai_call_id.map(|id| id.to_string()),
         }
     }
 }
 
 /// Request to scan a receipt image.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ScanReceiptRequest {
-    /// Base64-encoded receipt image data
+    /// Base64-encoded receipt image data, optionally prefixed with a `data:image/...;base64,` header
+    #[oai(validator(
+        min_length = 1,
+        pattern = "^(data:image/[a-z]+;base64,)?[A-Za-z0-9+/]+={0,2}$"
+    ))]
     pub image_base64: String,
 }
 
 /// A single item extracted from a receipt.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ReceiptItemResponse {
     /// Product name
     pub name: String,
@@ -343,9 +1135,12 @@ pub struct ReceiptItemResponse {
 
 /// Receipt scan result.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ReceiptScanResponse {
     /// Extracted product items
     pub items: Vec<ReceiptItemResponse>,
+    /// Supermarket chain the receipt comes from, when recognized
+    pub store: Option<String>,
 }
 
 impl From<business::domain::product::services::ReceiptScanResult> for ReceiptScanResponse {
@@ -359,6 +1154,543 @@ impl From<business::domain::product::services::ReceiptScanResult> for ReceiptSca
                     confidence: item.confidence.into(),
                 })
                 .collect(),
+            store: result.store,
+        }
+    }
+}
+
+// --- DTOs for photo diff ---
+
+/// Request to propose pantry changes from a photo of a shelf.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProposeFromPhotoRequest {
+    /// Base64-encoded photo, optionally prefixed with a `data:image/...;base64,` header
+    #[oai(validator(
+        min_length = 1,
+        pattern = "^(data:image/[a-z]+;base64,)?[A-Za-z0-9+/]+={0,2}$"
+    ))]
+    pub image_base64: String,
+    /// Where the photo was taken (default: fridge)
+    pub location: Option<ProductLocationDto>,
+}
+
+/// A product seen in the photo that is not in the pantry yet.
+#[derive(Debug, Clone, Object)]
+pub struct ProposedCreationResponse {
+    pub name: String,
+    pub confidence: IdentificationConfidenceDto,
+    pub location: ProductLocationDto,
+    #[oai(skip_serializing_if_is_none)]
+    pub quantity: Option<String>,
+}
+
+/// A pantry product seen in the photo; only the fields to change are present.
+#[derive(Debug, Clone, Object)]
+pub struct ProposedUpdateResponse {
+    pub product_id: String,
+    /// Name stored in the pantry
+    pub name: String,
+    /// Name given to the item seen in the photo
+    pub detected_name: String,
+    pub confidence: IdentificationConfidenceDto,
+    #[oai(skip_serializing_if_is_none)]
+    pub location: Option<ProductLocationDto>,
+    #[oai(skip_serializing_if_is_none)]
+    pub quantity: Option<String>,
+}
+
+/// Proposed pantry changes. Confirm creations with `POST /products` and
+/// updates with `PUT /products/{id}`.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PhotoDiffResponse {
+    pub creations: Vec<ProposedCreationResponse>,
+    pub updates: Vec<ProposedUpdateResponse>,
+    /// IDs of pantry products seen in the photo that need no change
+    pub unchanged: Vec<String>,
+}
+
+impl From<PhotoDiff> for PhotoDiffResponse {
+    fn from(diff: PhotoDiff) -> Self {
+        Self {
+            creations: diff
+                .creations
+                .into_iter()
+                .map(|c| ProposedCreationResponse {
+                    name: c.name,
+                    confidence: c.confidence.into(),
+                    location: c.location.into(),
+                    quantity: c.quantity,
+                })
+                .collect(),
+            updates: diff
+                .updates
+                .into_iter()
+                .map(|u| ProposedUpdateResponse {
+                    product_id: u.product_id.to_string(),
+                    name: u.name,
+                    detected_name: u.detected_name,
+                    confidence: u.confidence.into(),
+                    location: u.location.map(|l| l.into()),
+                    quantity: u.quantity,
+                })
+                .collect(),
+            unchanged: diff.unchanged.iter().map(|id| id.to_string()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProductHistoryResponse {
+    /// Finished products, most recently added first
+    pub items: Vec<ProductResponse>,
+    /// Pass as `cursor` to fetch the next page; absent on the last page
+    #[oai(skip_serializing_if_is_none)]
+    pub next_cursor: Option<String>,
+}
+
+impl From<CursorPage<Product>> for ProductHistoryResponse {
+    fn from(page: CursorPage<Product>) -> Self {
+        let page = page.map(ProductResponse::from);
+        Self {
+            items: page.items,
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CalendarDayResponse {
+    /// Day the products expire (UTC)
+    pub date: NaiveDate,
+    /// Products whose expiry date is on the label or was entered
+    pub real: Vec<ProductResponse>,
+    /// Products with only an AI-estimated expiry date
+    pub estimated: Vec<ProductResponse>,
+}
+
+impl From<CalendarDay> for CalendarDayResponse {
+    fn from(day: CalendarDay) -> Self {
+        Self {
+            date: day.date,
+            real: day.real.into_iter().map(ProductResponse::from).collect(),
+            estimated: day
+                .estimated
+                .into_iter()
+                .map(ProductResponse::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ExpiryCalendarResponse {
+    /// Month shown, as YYYY-MM
+    pub month: String,
+    /// Days with something expiring, in order; other days are omitted
+    pub days: Vec<CalendarDayResponse>,
+}
+
+impl From<ExpiryCalendar> for ExpiryCalendarResponse {
+    fn from(calendar: ExpiryCalendar) -> Self {
+        Self {
+            month: calendar.month.to_string(),
+            days: calendar.days.into_iter().map(|d| d.into()).collect(),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for CreateProductRequest {
+    fn example() -> Self {
+        Self {
+            name: "Yogur natural".to_string(),
+            status: ProductStatusDto::New,
+            location: Some(ProductLocationDto::Fridge),
+            quantity: Some("4 unidades".to_string()),
+            expiry_date: Some(example_date()),
+            estimated_expiry_date: None,
+            expiry_type: Some(ExpiryTypeDto::UseBy),
+            outcome: None,
+            category: Some(ProductCategoryDto::Dairy),
+            tags: vec!["desayuno".to_string()],
+            barcode: Some("8480000107701".to_string()),
+        }
+    }
+}
+
+impl Example for CreateProductsBatchRequest {
+    fn example() -> Self {
+        Self {
+            products: vec![
+                CreateProductRequest::example(),
+                CreateProductRequest {
+                    name: "Pan de molde".to_string(),
+                    status: ProductStatusDto::New,
+                    location: Some(ProductLocationDto::Pantry),
+                    quantity: None,
+                    expiry_date: None,
+                    estimated_expiry_date: None,
+                    expiry_type: None,
+                    outcome: None,
+                    category: Some(ProductCategoryDto::Bakery),
+                    tags: vec![],
+                    barcode: None,
+                },
+            ],
+        }
+    }
+}
+
+impl Example for BatchProductCreationResponse {
+    fn example() -> Self {
+        Self {
+            results: vec![
+                BatchProductCreationItem {
+                    product: ProductResponse::example(),
+                    estimation_error: None,
+                },
+                BatchProductCreationItem {
+                    product: ProductResponse::example(),
+                    estimation_error: Some(ErrorResponse::example()),
+                },
+            ],
+            estimation_failed: 1,
+        }
+    }
+}
+
+impl Example for UpdateProductRequest {
+    fn example() -> Self {
+        Self {
+            name: "Yogur natural".to_string(),
+            status: ProductStatusDto::Opened,
+            location: Some(ProductLocationDto::Fridge),
+            quantity: Some("2 unidades".to_string()),
+            expiry_date: Some(example_date()),
+            estimated_expiry_date: None,
+            expiry_type: Some(ExpiryTypeDto::UseBy),
+            outcome: None,
+            category: Some(ProductCategoryDto::Dairy),
+            tags: vec!["desayuno".to_string()],
+        }
+    }
+}
+
+impl Example for PatchProductRequest {
+    fn example() -> Self {
+        Self {
+            name: None,
+            status: Some(ProductStatusDto::Opened),
+            location: MaybeUndefined::Undefined,
+            quantity: MaybeUndefined::Value("1 unidad".to_string()),
+            expiry_date: MaybeUndefined::Undefined,
+            estimated_expiry_date: MaybeUndefined::Null,
+            expiry_type: None,
+            outcome: MaybeUndefined::Undefined,
+            category: MaybeUndefined::Undefined,
+            tags: Some(vec!["desayuno".to_string(), "sin lactosa".to_string()]),
+        }
+    }
+}
+
+impl Example for ProductResponse {
+    fn example() -> Self {
+        Self {
+            id: "3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string(),
+            name: "Yogur natural".to_string(),
+            status: ProductStatusDto::Opened,
+            location: Some(ProductLocationDto::Fridge),
+            quantity: Some("2 unidades".to_string()),
+            expiry_date: Some(example_date()),
+            estimated_expiry_date: None,
+            expiry_confidence: None,
+            expiry_type: ExpiryTypeDto::UseBy,
+            outcome: None,
+            estimation_status: EstimationStatusDto::Done,
+            category: Some(ProductCategoryDto::Dairy),
+            tags: vec!["desayuno".to_string()],
+            shopping_item: None,
+            enrichment: None,
+            history_summary: None,
+            allergen_warnings: vec![AllergenDto::Milk],
+            thumbnail_url: None,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
+
+impl Example for NutritionFactsResponse {
+    fn example() -> Self {
+        Self {
+            energy_kcal: Some(824.0),
+            fat_g: Some(91.6),
+            saturated_fat_g: Some(13.8),
+            carbohydrates_g: Some(0.0),
+            sugars_g: Some(0.0),
+            fiber_g: None,
+            proteins_g: Some(0.0),
+            salt_g: Some(0.0),
+        }
+    }
+}
+
+impl Example for ProductShoppingItemResponse {
+    fn example() -> Self {
+        Self {
+            id: "7c1d2e3f-4a5b-4c6d-8e9f-0a1b2c3d4e5f".to_string(),
+            name: "Yogur natural".to_string(),
+            created_at: example_date(),
+        }
+    }
+}
+
+impl Example for ProductHistorySummaryResponse {
+    fn example() -> Self {
+        Self {
+            finished: 4,
+            used: 3,
+            thrown_away: 1,
+            given_away: 0,
+            last_finished_at: Some(example_date()),
+        }
+    }
+}
+
+impl Example for ProductEnrichmentResponse {
+    fn example() -> Self {
+        Self {
+            barcode: "8410000810004".to_string(),
+            nutriscore: Some(ScoreGradeDto::B),
+            ecoscore: Some(ScoreGradeDto::C),
+            nutrition: NutritionFactsResponse::example(),
+            allergens: vec![],
+            fetched_at: example_date(),
+        }
+    }
+}
+
+impl Example for ProductHistoryResponse {
+    fn example() -> Self {
+        Self {
+            items: vec![ProductResponse::example()],
+            next_cursor: Some(
+                "MTc3MjM1NTYwMDAwMDAwMDozZjJiOGMxZS00ZDVhLTRlNmYtOWE3Yi04YzlkMGUxZjJhM2I"
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+impl Example for CalendarDayResponse {
+    fn example() -> Self {
+        Self {
+            date: example_date().date_naive(),
+            real: vec![ProductResponse::example()],
+            estimated: vec![],
+        }
+    }
+}
+
+impl Example for ExpiryCalendarResponse {
+    fn example() -> Self {
+        let date = example_date().date_naive();
+        Self {
+            month: date.format("%Y-%m").to_string(),
+            days: vec![CalendarDayResponse::example()],
+        }
+    }
+}
+
+impl Example for EstimateExpiryDateRequest {
+    fn example() -> Self {
+        Self {
+            product_name: "Leche entera".to_string(),
+            status: ProductStatusDto::Opened,
+            location: Some(ProductLocationDto::Fridge),
+        }
+    }
+}
+
+impl Example for ExpiryEstimationResponse {
+    fn example() -> Self {
+        Self {
+            date: Some(example_date()),
+            confidence: ConfidenceDto::High,
+        }
+    }
+}
+
+impl Example for EstimateExpiryBatchRequest {
+    fn example() -> Self {
+        Self {
+            product_ids: vec![
+                "3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string(),
+                "7a1c2d3e-5f6a-4b7c-8d9e-0f1a2b3c4d5e".to_string(),
+            ],
+        }
+    }
+}
+
+impl Example for BatchExpiryEstimationResponse {
+    fn example() -> Self {
+        Self {
+            results: vec![
+                BatchExpiryEstimationItem {
+                    product_id: "3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string(),
+                    product: Some(ProductResponse::example()),
+                    error: None,
+                },
+                BatchExpiryEstimationItem {
+                    product_id: "7a1c2d3e-5f6a-4b7c-8d9e-0f1a2b3c4d5e".to_string(),
+                    product: None,
+                    error: Some(ErrorResponse::example()),
+                },
+            ],
+            succeeded: 1,
+            failed: 1,
+        }
+    }
+}
+
+impl Example for IdentifyByImageRequest {
+    fn example() -> Self {
+        Self {
+            image_base64: "data:image/jpeg;base64,/9j/4AAQSkZJRgABAQAAAQABAAD".to_string(),
+        }
+    }
+}
+
+impl Example for UploadProductImageRequest {
+    fn example() -> Self {
+        Self {
+            image_base64: "data:image/jpeg;base64,/9j/4AAQSkZJRgABAQAAAQABAAD".to_string(),
+        }
+    }
+}
+
+impl Example for IdentifyByBarcodeRequest {
+    fn example() -> Self {
+        Self {
+            barcode: "8410188012092".to_string(),
+        }
+    }
+}
+
+impl Example for ProductIdentificationResponse {
+    fn example() -> Self {
+        Self {
+            name: "Aceite de oliva virgen extra".to_string(),
+            confidence: IdentificationConfidenceDto::High,
+            method: IdentificationMethodDto::Barcode,
+            suggested_location: Some(ProductLocationDto::Pantry),
+            suggested_quantity: Some("1 L".to_string()),
+            suggested_expiry_type: Some(ExpiryTypeDto::BestBefore),
+            suggested_category: Some(ProductCategoryDto::Condiments),
+            enrichment: Some(Box::new(ProductEnrichmentResponse::example())),
+            ai_call_id: None,
+        }
+    }
+}
+
+impl Example for ScanReceiptRequest {
+    fn example() -> Self {
+        Self {
+            image_base64: "data:image/jpeg;base64,/9j/4AAQSkZJRgABAQAAAQABAAD".to_string(),
+        }
+    }
+}
+
+impl Example for ReceiptItemResponse {
+    fn example() -> Self {
+        Self {
+            name: "Tomate rama".to_string(),
+            confidence: IdentificationConfidenceDto::High,
+        }
+    }
+}
+
+impl Example for ReceiptScanResponse {
+    fn example() -> Self {
+        Self {
+            items: vec![ReceiptItemResponse::example()],
+            store: Some("Mercadona".to_string()),
+        }
+    }
+}
+
+impl Example for ProposeFromPhotoRequest {
+    fn example() -> Self {
+        Self {
+            image_base64: "data:image/jpeg;base64,/9j/4AAQSkZJRgABAQAAAQABAAD".to_string(),
+            location: Some(ProductLocationDto::Fridge),
+        }
+    }
+}
+
+impl Example for PhotoDiffResponse {
+    fn example() -> Self {
+        Self {
+            creations: vec![ProposedCreationResponse {
+                name: "Pimientos rojos".to_string(),
+                confidence: IdentificationConfidenceDto::High,
+                location: ProductLocationDto::Fridge,
+                quantity: None,
+            }],
+            updates: vec![ProposedUpdateResponse {
+                product_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+                name: "Leche entera".to_string(),
+                detected_name: "Leche".to_string(),
+                confidence: IdentificationConfidenceDto::High,
+                location: Some(ProductLocationDto::Fridge),
+                quantity: None,
+            }],
+            unchanged: vec!["7a1c2d3e-5f6a-4b7c-8d9e-0f1a2b3c4d5e".to_string()],
+        }
+    }
+}
+
+/// Kind of write made to a product.
+#[derive(Debug, Clone, Enum)]
+pub enum ProductChangeKindDto {
+    #[oai(rename = "created")]
+    Created,
+    #[oai(rename = "updated")]
+    Updated,
+    #[oai(rename = "deleted")]
+    Deleted,
+}
+
+impl From<ProductChangeKind> for ProductChangeKindDto {
+    fn from(kind: ProductChangeKind) -> Self {
+        match kind {
+            ProductChangeKind::Created => ProductChangeKindDto::Created,
+            ProductChangeKind::Updated => ProductChangeKindDto::Updated,
+            ProductChangeKind::Deleted => ProductChangeKindDto::Deleted,
+        }
+    }
+}
+
+/// A write to one of the user's products. Fetch the product to get its new
+/// state, or drop it from the list when deleted.
+#[derive(Debug, Clone, Object)]
+pub struct ProductChangeEvent {
+    /// Product written
+    pub product_id: String,
+    pub kind: ProductChangeKindDto,
+}
+
+impl From<ProductChange> for ProductChangeEvent {
+    fn from(change: ProductChange) -> Self {
+        Self {
+            product_id: change.product_id.to_string(),
+            kind: change.kind.into(),
         }
     }
 }
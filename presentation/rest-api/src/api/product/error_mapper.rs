@@ -3,7 +3,9 @@ use poem_openapi::payload::Json;
 
 use business::domain::product::errors::ProductError;
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+use crate::api::me::error_mapper::quota_error_parts;
+use crate::api::storage::error_mapper::storage_error_parts;
 
 impl IntoErrorResponse for ProductError {
     fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
@@ -19,16 +21,56 @@ impl IntoErrorResponse for ProductError {
                 "ValidationError",
                 "product.outcome_requires_finished_status",
             ),
-            ProductError::IdentificationFailed => (
+            ProductError::InvalidStatusTransition { .. } => (
+                StatusCode::CONFLICT,
+                "Conflict",
+                "product.invalid_status_transition",
+            ),
+            ProductError::IdentificationFailed(_) => (
                 StatusCode::UNPROCESSABLE_ENTITY,
                 "IdentificationError",
                 "product.identification_failed",
             ),
-            ProductError::ScanFailed => (
+            ProductError::ScanFailed(_) => (
                 StatusCode::UNPROCESSABLE_ENTITY,
                 "ScanError",
                 "product.scan_failed",
             ),
+            ProductError::InvalidImage => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.invalid_image",
+            ),
+            ProductError::ImageNotFound => {
+                (StatusCode::NOT_FOUND, "NotFound", "product.image_not_found")
+            }
+            ProductError::NotDuplicates => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.not_duplicates",
+            ),
+            ProductError::TooManyTags => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.too_many_tags",
+            ),
+            ProductError::TagTooLong => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.tag_too_long",
+            ),
+            ProductError::FinishedNotInActiveList => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.finished_not_in_active_list",
+            ),
+            ProductError::InvalidBatchItem { .. } => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "product.invalid_batch_item",
+            ),
+            ProductError::Quota(err) => quota_error_parts(err),
+            ProductError::Storage(err) => storage_error_parts(err),
             ProductError::Repository(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "InternalError",
@@ -36,11 +78,22 @@ impl IntoErrorResponse for ProductError {
             ),
         };
 
+        // Points at the offending item, e.g. "Item 2: product.name_empty"
+        let description = match &self {
+            ProductError::InvalidBatchItem { index, source } => {
+                Some(format!("Item {}: {}", index, source))
+            }
+            _ => None,
+        };
+
+        log_error_chain(status, &self);
+
         (
             status,
             Json(ErrorResponse {
                 name: name.to_string(),
                 message: message.to_string(),
+                description,
             }),
         )
     }
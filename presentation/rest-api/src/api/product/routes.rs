@@ -1,71 +1,233 @@
 use std::sync::Arc;
+use std::time::Duration;
 
-use poem_openapi::{OpenApi, param::Path, payload::Json};
+use chrono::Utc;
+use futures::StreamExt;
+use futures::stream::BoxStream;
+use poem_openapi::{
+    OpenApi,
+    param::{Header, Path, Query},
+    payload::{EventStream, Json},
+    types::ToJSON,
+};
 use uuid::Uuid;
 
-use business::domain::product::services::ExpiryEstimatorService;
+use business::domain::product::calendar::CalendarMonth;
+use business::domain::product::errors::ProductError;
+use business::domain::product::facets::ProductFacets;
+use business::domain::product::model::Product;
+use business::domain::product::query::Page;
 use business::domain::product::use_cases::create::{CreateProductParams, CreateProductUseCase};
+use business::domain::product::use_cases::create_batch::{
+    CreateProductsBatchParams, CreateProductsBatchUseCase,
+};
 use business::domain::product::use_cases::delete::{DeleteProductParams, DeleteProductUseCase};
 use business::domain::product::use_cases::estimate_expiry::{
     EstimateExpiryParams, EstimateExpiryUseCase,
 };
+use business::domain::product::use_cases::estimate_expiry_batch::{
+    EstimateExpiryBatchParams, EstimateExpiryBatchUseCase,
+};
+use business::domain::product::use_cases::estimate_expiry_for_attributes::{
+    EstimateExpiryForAttributesParams, EstimateExpiryForAttributesUseCase,
+};
 use business::domain::product::use_cases::get_all::{GetAllProductsParams, GetAllProductsUseCase};
+use business::domain::product::use_cases::get_allergen_warnings::{
+    GetAllergenWarningsParams, GetAllergenWarningsUseCase,
+};
 use business::domain::product::use_cases::get_by_id::{
     GetProductByIdParams, GetProductByIdUseCase,
 };
+use business::domain::product::use_cases::get_calendar::{
+    GetExpiryCalendarParams, GetExpiryCalendarUseCase,
+};
+use business::domain::product::use_cases::get_facets::{
+    GetProductFacetsParams, GetProductFacetsUseCase,
+};
+use business::domain::product::use_cases::get_history::{
+    GetProductHistoryParams, GetProductHistoryUseCase,
+};
+use business::domain::product::use_cases::get_image::{
+    GetProductImageParams, GetProductImageUseCase,
+};
+use business::domain::product::use_cases::get_thumbnails::{
+    GetProductThumbnailsParams, GetProductThumbnailsUseCase,
+};
 use business::domain::product::use_cases::identify::{
     IdentifyByBarcodeParams, IdentifyByImageParams, IdentifyProductUseCase,
 };
+use business::domain::product::use_cases::patch::{PatchProductParams, PatchProductUseCase};
+use business::domain::product::use_cases::propose_from_photo::{
+    ProposeFromPhotoParams, ProposeFromPhotoUseCase,
+};
 use business::domain::product::use_cases::scan_receipt::{ScanReceiptParams, ScanReceiptUseCase};
+use business::domain::product::use_cases::subscribe_changes::{
+    SubscribeProductChangesParams, SubscribeProductChangesUseCase,
+};
 use business::domain::product::use_cases::update::{UpdateProductParams, UpdateProductUseCase};
+use business::domain::product::use_cases::upload_image::{
+    UploadProductImageParams, UploadProductImageUseCase,
+};
+use business::domain::product::value_objects::ProductLocation;
 use business::domain::shared::value_objects::UserId;
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::http_cache::{
+    BARCODE_CACHE_CONTROL, CONTRIBUTED_BARCODE_CACHE_CONTROL, EXPIRY_ESTIMATE_CACHE_CONTROL,
+    cache_key, is_not_modified,
+};
+use crate::api::pagination::keyset_page;
+use crate::api::payload_limit::{PayloadTooLargeResponse, payload_too_large};
 use crate::api::product::dto::{
-    CreateProductRequest, EstimateExpiryDateRequest, ExpiryEstimationResponse,
-    IdentifyByBarcodeRequest, IdentifyByImageRequest, ProductIdentificationResponse,
-    ProductResponse, ReceiptScanResponse, ScanReceiptRequest, UpdateProductRequest,
+    BatchExpiryEstimationItem, BatchExpiryEstimationResponse, BatchProductCreationItem,
+    BatchProductCreationResponse, CreateProductRequest, CreateProductsBatchRequest,
+    EstimateExpiryBatchRequest, EstimateExpiryDateRequest, ExpiryCalendarResponse,
+    ExpiryEstimationResponse, IdentificationMethodDto, IdentifyByBarcodeRequest,
+    IdentifyByImageRequest, PatchProductRequest, PhotoDiffResponse, ProductCategoryDto,
+    ProductChangeEvent, ProductHistoryResponse, ProductIdentificationResponse, ProductIncludeDto,
+    ProductLocationDto, ProductResponse, ProductSortDto, ProductStatusDto, ProposeFromPhotoRequest,
+    ReceiptScanResponse, ScanReceiptRequest, UpdateProductRequest, UploadProductImageRequest,
+    UrgencyLevelDto,
 };
-use crate::api::security::FirebaseBearer;
+use crate::api::security::BearerAuth;
+use crate::api::storage::dto::{ImageLinksResponse, SignedUrlResponse};
 use crate::api::tags::ApiTags;
+use crate::config::payload_config::PayloadConfig;
 
 pub struct ProductApi {
     create_use_case: Arc<dyn CreateProductUseCase>,
+    create_batch_use_case: Arc<dyn CreateProductsBatchUseCase>,
     get_all_use_case: Arc<dyn GetAllProductsUseCase>,
     get_by_id_use_case: Arc<dyn GetProductByIdUseCase>,
+    get_history_use_case: Arc<dyn GetProductHistoryUseCase>,
+    get_calendar_use_case: Arc<dyn GetExpiryCalendarUseCase>,
     update_use_case: Arc<dyn UpdateProductUseCase>,
+    patch_use_case: Arc<dyn PatchProductUseCase>,
     delete_use_case: Arc<dyn DeleteProductUseCase>,
     estimate_expiry_use_case: Arc<dyn EstimateExpiryUseCase>,
-    expiry_estimator_service: Arc<dyn ExpiryEstimatorService>,
+    estimate_expiry_batch_use_case: Arc<dyn EstimateExpiryBatchUseCase>,
+    estimate_expiry_for_attributes_use_case: Arc<dyn EstimateExpiryForAttributesUseCase>,
     identify_use_case: Arc<dyn IdentifyProductUseCase>,
     scan_receipt_use_case: Arc<dyn ScanReceiptUseCase>,
+    propose_from_photo_use_case: Arc<dyn ProposeFromPhotoUseCase>,
+    get_facets_use_case: Arc<dyn GetProductFacetsUseCase>,
+    get_allergen_warnings_use_case: Arc<dyn GetAllergenWarningsUseCase>,
+    upload_image_use_case: Arc<dyn UploadProductImageUseCase>,
+    get_image_use_case: Arc<dyn GetProductImageUseCase>,
+    get_thumbnails_use_case: Arc<dyn GetProductThumbnailsUseCase>,
+    subscribe_changes_use_case: Arc<dyn SubscribeProductChangesUseCase>,
+    payload_config: PayloadConfig,
 }
 
+/// Interval of the comments keeping idle event streams open through proxies
+const EVENT_STREAM_KEEP_ALIVE: Duration = Duration::from_secs(15);
+
 impl ProductApi {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         create_use_case: Arc<dyn CreateProductUseCase>,
+        create_batch_use_case: Arc<dyn CreateProductsBatchUseCase>,
         get_all_use_case: Arc<dyn GetAllProductsUseCase>,
         get_by_id_use_case: Arc<dyn GetProductByIdUseCase>,
+        get_history_use_case: Arc<dyn GetProductHistoryUseCase>,
+        get_calendar_use_case: Arc<dyn GetExpiryCalendarUseCase>,
         update_use_case: Arc<dyn UpdateProductUseCase>,
+        patch_use_case: Arc<dyn PatchProductUseCase>,
         delete_use_case: Arc<dyn DeleteProductUseCase>,
         estimate_expiry_use_case: Arc<dyn EstimateExpiryUseCase>,
-        expiry_estimator_service: Arc<dyn ExpiryEstimatorService>,
+        estimate_expiry_batch_use_case: Arc<dyn EstimateExpiryBatchUseCase>,
+        estimate_expiry_for_attributes_use_case: Arc<dyn EstimateExpiryForAttributesUseCase>,
         identify_use_case: Arc<dyn IdentifyProductUseCase>,
         scan_receipt_use_case: Arc<dyn ScanReceiptUseCase>,
+        propose_from_photo_use_case: Arc<dyn ProposeFromPhotoUseCase>,
+        get_facets_use_case: Arc<dyn GetProductFacetsUseCase>,
+        get_allergen_warnings_use_case: Arc<dyn GetAllergenWarningsUseCase>,
+        upload_image_use_case: Arc<dyn UploadProductImageUseCase>,
+        get_image_use_case: Arc<dyn GetProductImageUseCase>,
+        get_thumbnails_use_case: Arc<dyn GetProductThumbnailsUseCase>,
+        subscribe_changes_use_case: Arc<dyn SubscribeProductChangesUseCase>,
+        payload_config: PayloadConfig,
     ) -> Self {
         Self {
             create_use_case,
+            create_batch_use_case,
             get_all_use_case,
             get_by_id_use_case,
+            get_history_use_case,
+            get_calendar_use_case,
             update_use_case,
+            patch_use_case,
             delete_use_case,
             estimate_expiry_use_case,
-            expiry_estimator_service,
+            estimate_expiry_batch_use_case,
+            estimate_expiry_for_attributes_use_case,
             identify_use_case,
             scan_receipt_use_case,
+            propose_from_photo_use_case,
+            get_facets_use_case,
+            get_allergen_warnings_use_case,
+            upload_image_use_case,
+            get_image_use_case,
+            get_thumbnails_use_case,
+            subscribe_changes_use_case,
+            payload_config,
         }
     }
+
+    /// Product responses with their allergen warnings and photo thumbnails,
+    /// plus the facets asked for with `include`.
+    async fn product_responses(
+        &self,
+        user_id: UserId,
+        products: Vec<Product>,
+        include: Vec<ProductIncludeDto>,
+    ) -> Result<Vec<ProductResponse>, ProductError> {
+        let product_ids: Vec<Uuid> = products.iter().map(|p| p.id).collect();
+        let mut facets = if include.is_empty() {
+            ProductFacets::default()
+        } else {
+            self.get_facets_use_case
+                .execute(GetProductFacetsParams {
+                    user_id: user_id.clone(),
+                    product_ids: product_ids.clone(),
+                    facets: include.into_iter().map(Into::into).collect(),
+                })
+                .await?
+        };
+        let mut warnings = self
+            .get_allergen_warnings_use_case
+            .execute(GetAllergenWarningsParams {
+                user_id: user_id.clone(),
+                product_ids: product_ids.clone(),
+            })
+            .await?;
+        let mut thumbnails = self
+            .get_thumbnails_use_case
+            .execute(GetProductThumbnailsParams {
+                user_id,
+                product_ids,
+            })
+            .await?;
+
+        Ok(products
+            .into_iter()
+            .map(|p| {
+                let shopping_item = facets.shopping_items.remove(&p.id);
+                let enrichment = facets.enrichments.remove(&p.id);
+                let history = facets.history.remove(&p.id);
+                let allergens = warnings.remove(&p.id).unwrap_or_default();
+                let thumbnail = thumbnails.remove(&p.id);
+                ProductResponse::from(p)
+                    .with_shopping_item(shopping_item)
+                    .with_enrichment(enrichment)
+                    .with_history_summary(history)
+                    .with_allergen_warnings(allergens)
+                    .with_thumbnail(thumbnail)
+            })
+            .collect())
+    }
 }
 
 /// Product management API
@@ -79,7 +241,7 @@ impl ProductApi {
     #[oai(path = "/products", method = "post", tag = "ApiTags::Products")]
     async fn create_product(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         body: Json<CreateProductRequest>,
     ) -> CreateProductResponse {
         let user_id = UserId::new(auth.0);
@@ -91,7 +253,11 @@ impl ProductApi {
             quantity: body.0.quantity,
             expiry_date: body.0.expiry_date,
             estimated_expiry_date: body.0.estimated_expiry_date,
+            expiry_type: body.0.expiry_type.map(|t| t.into()).unwrap_or_default(),
             outcome: body.0.outcome.map(|o| o.into()),
+            category: body.0.category.map(|c| c.into()),
+            tags: body.0.tags,
+            barcode: body.0.barcode,
         };
 
         match self.create_use_case.execute(params).await {
@@ -100,43 +266,249 @@ impl ProductApi {
                 let (status, json) = err.into_error_response();
                 match status.as_u16() {
                     400 => CreateProductResponse::BadRequest(json),
+                    402 => CreateProductResponse::PaymentRequired(json),
                     _ => CreateProductResponse::InternalError(json),
                 }
             }
         }
     }
 
+    /// Create several products at once
+    ///
+    /// Creates up to 50 products, e.g. the items of a scanned receipt, in one
+    /// request. Every product is validated first and they are saved together:
+    /// one invalid product (`400`, with its position in `description`) or a
+    /// plan without room for all of them (`402`) saves none. Products without
+    /// an expiry date get it estimated as with a single create; a failed
+    /// estimation is reported with its product, which stays saved. Unlike a
+    /// single create, a name added moments ago is created again, since a
+    /// receipt can list the same item twice.
+    #[oai(path = "/products/batch", method = "post", tag = "ApiTags::Products")]
+    async fn create_products_batch(
+        &self,
+        auth: BearerAuth,
+        body: Json<CreateProductsBatchRequest>,
+    ) -> CreateProductsBatchResponse {
+        let params = CreateProductsBatchParams {
+            user_id: UserId::new(auth.0),
+            items: body.0.products.into_iter().map(Into::into).collect(),
+        };
+
+        match self.create_batch_use_case.execute(params).await {
+            Ok(outcomes) => {
+                let results: Vec<BatchProductCreationItem> = outcomes
+                    .into_iter()
+                    .map(|outcome| BatchProductCreationItem {
+                        product: outcome.product.into(),
+                        estimation_error: outcome
+                            .estimation_error
+                            .map(|err| err.into_error_response().1.0),
+                    })
+                    .collect();
+                let estimation_failed = results
+                    .iter()
+                    .filter(|r| r.estimation_error.is_some())
+                    .count() as u32;
+                CreateProductsBatchResponse::Created(Json(BatchProductCreationResponse {
+                    results,
+                    estimation_failed,
+                }))
+            }
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CreateProductsBatchResponse::BadRequest(json),
+                    402 => CreateProductsBatchResponse::PaymentRequired(json),
+                    _ => CreateProductsBatchResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
     /// List all active products
     ///
-    /// Returns all products that are not in 'finished' status.
+    /// Returns all products that are not in 'finished' status, newest first
+    /// unless `sort` asks for soonest expiry (`expiry`), name (`name`) or
+    /// most urgent first (`urgency`). Optional filters narrow the list by
+    /// name, upcoming expiry, status, location, category, tag or urgency;
+    /// `limit` (capped at 100) and `offset` page through it. `include` embeds related data in each product, e.g.
+    /// `include=shopping_item,history_summary`; each one asked for costs a
+    /// single extra query for the whole list.
     #[oai(path = "/products", method = "get", tag = "ApiTags::Products")]
-    async fn get_all_products(&self, auth: FirebaseBearer) -> GetAllProductsResponse {
+    #[allow(clippy::too_many_arguments)]
+    async fn get_all_products(
+        &self,
+        auth: BearerAuth,
+        /// Case-insensitive substring of the product name
+        search: Query<Option<String>>,
+        /// Only products expiring (real or estimated date) within this many days
+        expiring_within_days: Query<Option<u32>>,
+        /// Only products in this status; `finished` is rejected with `400`,
+        /// see `/products/history`
+        status: Query<Option<ProductStatusDto>>,
+        /// Only products stored here
+        location: Query<Option<ProductLocationDto>>,
+        /// Only products of this kind
+        category: Query<Option<ProductCategoryDto>>,
+        /// Only products carrying this tag (case-insensitive)
+        tag: Query<Option<String>>,
+        /// Only products at this urgency, as shown on each product
+        urgency: Query<Option<UrgencyLevelDto>>,
+        /// Order of the list: created_at (newest first), expiry, name or
+        /// urgency (default: created_at)
+        sort: Query<Option<ProductSortDto>>,
+        /// Page size; all products are returned when omitted
+        limit: Query<Option<u32>>,
+        /// Number of products to skip (requires limit)
+        offset: Query<Option<u32>>,
+        /// Related data to embed, comma-separated (shopping_item, enrichment,
+        /// history_summary)
+        #[oai(explode = false)]
+        include: Query<Vec<ProductIncludeDto>>,
+    ) -> GetAllProductsResponse {
         let user_id = UserId::new(auth.0);
+        let params = GetAllProductsParams {
+            user_id: user_id.clone(),
+            search: search.0,
+            expiring_within_days: expiring_within_days.0,
+            status: status.0.map(Into::into),
+            location: location.0.map(Into::into),
+            category: category.0.map(Into::into),
+            tag: tag.0,
+            urgency: urgency.0.map(Into::into),
+            sort: sort.0.map(|s| s.into()).unwrap_or_default(),
+            page: limit.0.map(|l| Page::new(l, offset.0.unwrap_or(0))),
+        };
+        let result = match self.get_all_use_case.execute(params).await {
+            Ok(products) => self.product_responses(user_id, products, include.0).await,
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(responses) => GetAllProductsResponse::Ok(Json(responses)),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => GetAllProductsResponse::BadRequest(json),
+                    _ => GetAllProductsResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// List finished products
+    ///
+    /// Returns products already marked as 'finished', most recently added
+    /// first. Pages are cursor-based: pass the `next_cursor` of a response as
+    /// `cursor` to get the following page, which stays stable while new
+    /// products are added.
+    #[oai(path = "/products/history", method = "get", tag = "ApiTags::Products")]
+    async fn get_product_history(
+        &self,
+        auth: BearerAuth,
+        /// Page size (default: 20, max: 100)
+        limit: Query<Option<u32>>,
+        /// Opaque cursor from a previous page
+        cursor: Query<Option<String>>,
+    ) -> GetProductHistoryResponse {
+        let page = match keyset_page(limit.0, cursor.0) {
+            Ok(page) => page,
+            Err(json) => return GetProductHistoryResponse::BadRequest(json),
+        };
+
         match self
-            .get_all_use_case
-            .execute(GetAllProductsParams { user_id })
+            .get_history_use_case
+            .execute(GetProductHistoryParams {
+                user_id: UserId::new(auth.0),
+                page,
+            })
             .await
         {
-            Ok(products) => {
-                let responses: Vec<ProductResponse> =
-                    products.into_iter().map(|p| p.into()).collect();
-                GetAllProductsResponse::Ok(Json(responses))
+            Ok(page) => GetProductHistoryResponse::Ok(Json(page.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetProductHistoryResponse::InternalError(json)
             }
+        }
+    }
+
+    /// Get the expiry calendar
+    ///
+    /// Active products grouped by the day they expire during a month (UTC),
+    /// for calendar views. Each day lists products with a real expiry date
+    /// apart from those with only an AI estimate; days with nothing expiring
+    /// are omitted.
+    #[oai(path = "/products/calendar", method = "get", tag = "ApiTags::Products")]
+    async fn get_expiry_calendar(
+        &self,
+        auth: BearerAuth,
+        /// Month to show, as YYYY-MM (default: the current month)
+        month: Query<Option<String>>,
+    ) -> GetExpiryCalendarResponse {
+        let month = match month.0 {
+            Some(month) => match month.parse::<CalendarMonth>() {
+                Ok(month) => month,
+                Err(_) => {
+                    return GetExpiryCalendarResponse::BadRequest(Json(ErrorResponse {
+                        name: "ValidationError".to_string(),
+                        message: "product.invalid_month".to_string(),
+                        description: None,
+                    }));
+                }
+            },
+            None => CalendarMonth::current(Utc::now()),
+        };
+
+        match self
+            .get_calendar_use_case
+            .execute(GetExpiryCalendarParams {
+                user_id: UserId::new(auth.0),
+                month,
+            })
+            .await
+        {
+            Ok(calendar) => GetExpiryCalendarResponse::Ok(Json(calendar.into())),
             Err(err) => {
                 let (_status, json) = err.into_error_response();
-                GetAllProductsResponse::InternalError(json)
+                GetExpiryCalendarResponse::InternalError(json)
             }
         }
     }
 
+    /// Follow product changes
+    ///
+    /// Server-sent events announcing every product the user creates, edits or
+    /// deletes from now on, from any device, so several phones show the same
+    /// pantry without reloading. Each event carries the product ID and the
+    /// kind of change; fetch the product for its new state. Missed changes
+    /// are not replayed: the stream ends when the client falls behind, and
+    /// after reconnecting the client should reload the product list.
+    #[oai(path = "/products/events", method = "get", tag = "ApiTags::Products")]
+    async fn follow_product_changes(&self, auth: BearerAuth) -> ProductChangesResponse {
+        let changes = self
+            .subscribe_changes_use_case
+            .execute(SubscribeProductChangesParams {
+                user_id: UserId::new(auth.0),
+            })
+            .map(ProductChangeEvent::from)
+            .boxed();
+
+        ProductChangesResponse::Ok(EventStream::new(changes).keep_alive(EVENT_STREAM_KEEP_ALIVE))
+    }
+
     /// Get a product by ID
     ///
-    /// Returns a single product by its unique identifier.
+    /// Returns a single product by its unique identifier. `include` embeds
+    /// the same related data as the list, e.g. `include=enrichment` for the
+    /// nutrition data of its barcode.
     #[oai(path = "/products/:id", method = "get", tag = "ApiTags::Products")]
     async fn get_product_by_id(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         id: Path<String>,
+        /// Related data to embed, comma-separated (shopping_item, enrichment,
+        /// history_summary)
+        #[oai(explode = false)]
+        include: Query<Vec<ProductIncludeDto>>,
     ) -> GetProductByIdResponse {
         let uuid = match Uuid::parse_str(&id.0) {
             Ok(uuid) => uuid,
@@ -144,17 +516,28 @@ impl ProductApi {
                 return GetProductByIdResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "product.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
 
         let user_id = UserId::new(auth.0);
-        match self
+        let result = match self
             .get_by_id_use_case
-            .execute(GetProductByIdParams { id: uuid, user_id })
+            .execute(GetProductByIdParams {
+                id: uuid,
+                user_id: user_id.clone(),
+            })
             .await
         {
-            Ok(product) => GetProductByIdResponse::Ok(Json(product.into())),
+            Ok(product) => self
+                .product_responses(user_id, vec![product], include.0)
+                .await
+                .map(|mut responses| responses.remove(0)),
+            Err(err) => Err(err),
+        };
+        match result {
+            Ok(response) => GetProductByIdResponse::Ok(Json(response)),
             Err(err) => {
                 let (status, json) = err.into_error_response();
                 match status.as_u16() {
@@ -167,11 +550,13 @@ impl ProductApi {
 
     /// Update a product
     ///
-    /// Updates an existing product by its unique identifier.
+    /// Updates an existing product by its unique identifier. Status moves
+    /// forward through `new → opened → almost_empty → finished`; going back
+    /// is only allowed from `almost_empty` to `opened` and out of `finished`.
     #[oai(path = "/products/:id", method = "put", tag = "ApiTags::Products")]
     async fn update_product(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         id: Path<String>,
         body: Json<UpdateProductRequest>,
     ) -> UpdateProductResponse {
@@ -181,6 +566,7 @@ impl ProductApi {
                 return UpdateProductResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "product.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
@@ -195,7 +581,10 @@ impl ProductApi {
             quantity: body.0.quantity,
             expiry_date: body.0.expiry_date,
             estimated_expiry_date: body.0.estimated_expiry_date,
+            expiry_type: body.0.expiry_type.map(|t| t.into()).unwrap_or_default(),
             outcome: body.0.outcome.map(|o| o.into()),
+            category: body.0.category.map(|c| c.into()),
+            tags: body.0.tags,
         };
 
         match self.update_use_case.execute(params).await {
@@ -205,6 +594,61 @@ impl ProductApi {
                 match status.as_u16() {
                     400 => UpdateProductResponse::BadRequest(json),
                     404 => UpdateProductResponse::NotFound(json),
+                    409 => UpdateProductResponse::Conflict(json),
+                    _ => UpdateProductResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Patch a product
+    ///
+    /// Changes only the fields sent in the body and keeps the rest, so
+    /// clients don't need to resend the whole product. Send `null` to clear
+    /// an optional field. Status changes follow the same rules as a full
+    /// update.
+    #[oai(path = "/products/:id", method = "patch", tag = "ApiTags::Products")]
+    async fn patch_product(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        body: Json<PatchProductRequest>,
+    ) -> UpdateProductResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return UpdateProductResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        let user_id = UserId::new(auth.0);
+        let params = PatchProductParams {
+            id: uuid,
+            user_id,
+            name: body.0.name,
+            status: body.0.status.map(|s| s.into()),
+            location: Option::from(body.0.location.map_value(|l| l.into())),
+            quantity: body.0.quantity.into(),
+            expiry_date: body.0.expiry_date.into(),
+            estimated_expiry_date: body.0.estimated_expiry_date.into(),
+            expiry_type: body.0.expiry_type.map(|t| t.into()),
+            outcome: Option::from(body.0.outcome.map_value(|o| o.into())),
+            category: Option::from(body.0.category.map_value(|c| c.into())),
+            tags: body.0.tags,
+        };
+
+        match self.patch_use_case.execute(params).await {
+            Ok(product) => UpdateProductResponse::Ok(Json(product.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UpdateProductResponse::BadRequest(json),
+                    404 => UpdateProductResponse::NotFound(json),
+                    409 => UpdateProductResponse::Conflict(json),
                     _ => UpdateProductResponse::InternalError(json),
                 }
             }
@@ -215,17 +659,14 @@ impl ProductApi {
     ///
     /// Permanently removes a product from the inventory.
     #[oai(path = "/products/:id", method = "delete", tag = "ApiTags::Products")]
-    async fn delete_product(
-        &self,
-        auth: FirebaseBearer,
-        id: Path<String>,
-    ) -> DeleteProductResponse {
+    async fn delete_product(&self, auth: BearerAuth, id: Path<String>) -> DeleteProductResponse {
         let uuid = match Uuid::parse_str(&id.0) {
             Ok(uuid) => uuid,
             Err(_) => {
                 return DeleteProductResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "product.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
@@ -247,6 +688,104 @@ impl ProductApi {
         }
     }
 
+    /// Set a product's photo
+    ///
+    /// Stores a JPEG, PNG or WebP photo of the product, replacing any previous
+    /// one, and returns a temporary URL to show it. A thumbnail for lists is
+    /// made in the background and appears as `thumbnailUrl` on the product
+    /// shortly after. The photo is deleted with the product. Bodies over
+    /// `IDENTIFY_IMAGE_MAX_BYTES` are rejected with `413`.
+    #[oai(
+        path = "/products/:id/image",
+        method = "put",
+        tag = "ApiTags::Products"
+    )]
+    async fn upload_image(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        body: Json<UploadProductImageRequest>,
+    ) -> UploadProductImageResponse {
+        let max_bytes = self.payload_config.identify_image_max_bytes;
+        if body.0.image_base64.len() > max_bytes {
+            return UploadProductImageResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return UploadProductImageResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .upload_image_use_case
+            .execute(UploadProductImageParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+                image_base64: body.0.image_base64,
+            })
+            .await
+        {
+            Ok(signed) => UploadProductImageResponse::Ok(Json(signed.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UploadProductImageResponse::BadRequest(json),
+                    404 => UploadProductImageResponse::NotFound(json),
+                    503 => UploadProductImageResponse::ServiceUnavailable(json),
+                    _ => UploadProductImageResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get a product's photo
+    ///
+    /// Returns temporary URLs to the product's photo and, once made, its
+    /// thumbnail. The URLs need no token, so they can go straight into an
+    /// image view; ask again once they expire.
+    #[oai(
+        path = "/products/:id/image",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_image(&self, auth: BearerAuth, id: Path<String>) -> GetProductImageResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return GetProductImageResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .get_image_use_case
+            .execute(GetProductImageParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(links) => GetProductImageResponse::Ok(Json(links.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetProductImageResponse::NotFound(json),
+                    503 => GetProductImageResponse::ServiceUnavailable(json),
+                    _ => GetProductImageResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
     /// Estimate expiry date for a product
     ///
     /// Uses AI to estimate when a product will expire based on its name,
@@ -256,17 +795,14 @@ impl ProductApi {
         method = "post",
         tag = "ApiTags::Products"
     )]
-    async fn estimate_expiry(
-        &self,
-        auth: FirebaseBearer,
-        id: Path<String>,
-    ) -> EstimateExpiryResponse {
+    async fn estimate_expiry(&self, auth: BearerAuth, id: Path<String>) -> EstimateExpiryResponse {
         let uuid = match Uuid::parse_str(&id.0) {
             Ok(uuid) => uuid,
             Err(_) => {
                 return EstimateExpiryResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "product.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
@@ -285,15 +821,81 @@ impl ProductApi {
                 let (status, json) = err.into_error_response();
                 match status.as_u16() {
                     404 => EstimateExpiryResponse::NotFound(json),
+                    429 => EstimateExpiryResponse::TooManyRequests(json),
                     _ => EstimateExpiryResponse::InternalError(json),
                 }
             }
         }
     }
 
+    /// Estimate expiry dates for several products
+    ///
+    /// Runs the AI estimation for up to 50 saved products, a few at a time,
+    /// and saves every date found. Each product gets its own outcome, so one
+    /// failure (not found, daily AI quota used up) does not fail the batch.
+    #[oai(
+        path = "/products/estimate-expiry/batch",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn estimate_expiry_batch(
+        &self,
+        auth: BearerAuth,
+        body: Json<EstimateExpiryBatchRequest>,
+    ) -> EstimateExpiryBatchResponse {
+        let product_ids = match body
+            .0
+            .product_ids
+            .iter()
+            .map(|id| Uuid::parse_str(id))
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(ids) => ids,
+            Err(_) => {
+                return EstimateExpiryBatchResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "product.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        let user_id = UserId::new(auth.0);
+        let outcomes = self
+            .estimate_expiry_batch_use_case
+            .execute(EstimateExpiryBatchParams {
+                user_id,
+                product_ids,
+            })
+            .await;
+
+        let results: Vec<BatchExpiryEstimationItem> = outcomes
+            .into_iter()
+            .map(|outcome| {
+                let (product, error) = match outcome.result {
+                    Ok(product) => (Some(product.into()), None),
+                    Err(err) => (None, Some(err.into_error_response().1.0)),
+                };
+                BatchExpiryEstimationItem {
+                    product_id: outcome.product_id.to_string(),
+                    product,
+                    error,
+                }
+            })
+            .collect();
+        let failed = results.iter().filter(|r| r.error.is_some()).count() as u32;
+
+        EstimateExpiryBatchResponse::Ok(Json(BatchExpiryEstimationResponse {
+            succeeded: results.len() as u32 - failed,
+            failed,
+            results,
+        }))
+    }
+
     /// Identify a product by image
     ///
-    /// Uses AI vision to identify a food product from a photo.
+    /// Uses AI vision to identify a food product from a photo. Bodies over
+    /// `IDENTIFY_IMAGE_MAX_BYTES` are rejected with `413`.
     #[oai(
         path = "/products/identify/image",
         method = "post",
@@ -301,20 +903,29 @@ impl ProductApi {
     )]
     async fn identify_by_image(
         &self,
-        _auth: FirebaseBearer,
+        auth: BearerAuth,
         body: Json<IdentifyByImageRequest>,
     ) -> IdentifyByImageResponse {
+        let max_bytes = self.payload_config.identify_image_max_bytes;
+        if body.0.image_base64.len() > max_bytes {
+            return IdentifyByImageResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
         match self
             .identify_use_case
             .execute_by_image(IdentifyByImageParams {
+                user_id: UserId::new(auth.0),
                 image_base64: body.0.image_base64,
             })
             .await
         {
             Ok(identification) => IdentifyByImageResponse::Ok(Json(identification.into())),
             Err(err) => {
-                let (_, json) = err.into_error_response();
-                IdentifyByImageResponse::UnprocessableEntity(json)
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    429 => IdentifyByImageResponse::TooManyRequests(json),
+                    _ => IdentifyByImageResponse::UnprocessableEntity(json),
+                }
             }
         }
     }
@@ -322,6 +933,12 @@ impl ProductApi {
     /// Identify a product by barcode
     ///
     /// Looks up a product in the Open Food Facts database using its barcode.
+    /// Barcodes it doesn't know are answered with the name the user gave them
+    /// through `POST /barcode-contributions`, if any.
+    /// Catalog answers carry `Cache-Control` and an `ETag` over the barcode and
+    /// everything returned; sending it back in `If-None-Match` returns an empty
+    /// `304` while the answer is unchanged. Answers from a contribution are sent
+    /// with `no-store` and no `ETag`.
     #[oai(
         path = "/products/identify/barcode",
         method = "post",
@@ -329,17 +946,44 @@ impl ProductApi {
     )]
     async fn identify_by_barcode(
         &self,
-        _auth: FirebaseBearer,
+        auth: BearerAuth,
+        #[oai(name = "If-None-Match")] if_none_match: Header<Option<String>>,
         body: Json<IdentifyByBarcodeRequest>,
     ) -> IdentifyByBarcodeResponse {
+        let barcode = body.0.barcode;
         match self
             .identify_use_case
             .execute_by_barcode(IdentifyByBarcodeParams {
-                barcode: body.0.barcode,
+                user_id: UserId::new(auth.0),
+                barcode: barcode.clone(),
             })
             .await
         {
-            Ok(identification) => IdentifyByBarcodeResponse::Ok(Json(identification.into())),
+            Ok(identification) => {
+                let response: ProductIdentificationResponse = identification.into();
+                if matches!(response.method, IdentificationMethodDto::Contribution) {
+                    return IdentifyByBarcodeResponse::Ok(
+                        Json(response),
+                        CONTRIBUTED_BARCODE_CACHE_CONTROL.to_string(),
+                        None,
+                    );
+                }
+
+                // From the answer itself: the catalog entry behind a barcode
+                // can change, and the same barcode must not keep a stale body
+                let etag = cache_key("barcode", &[&barcode, &response.to_json_string()]);
+                if is_not_modified(if_none_match.0.as_deref(), &etag) {
+                    return IdentifyByBarcodeResponse::NotModified(
+                        BARCODE_CACHE_CONTROL.to_string(),
+                        etag,
+                    );
+                }
+                IdentifyByBarcodeResponse::Ok(
+                    Json(response),
+                    BARCODE_CACHE_CONTROL.to_string(),
+                    Some(etag),
+                )
+            }
             Err(err) => {
                 let (_, json) = err.into_error_response();
                 IdentifyByBarcodeResponse::UnprocessableEntity(json)
@@ -349,7 +993,10 @@ impl ProductApi {
 
     /// Scan a receipt image
     ///
-    /// Uses AI to extract product names from a supermarket receipt photo.
+    /// Uses AI to extract product names from a supermarket receipt photo. Bodies
+    /// over `SCAN_RECEIPT_MAX_BYTES` are rejected with `413`. When OCR is
+    /// configured, a failing model or an exhausted AI quota falls back to it,
+    /// returning best-effort items with `low` confidence.
     #[oai(
         path = "/products/scan-receipt",
         method = "post",
@@ -357,20 +1004,77 @@ impl ProductApi {
     )]
     async fn scan_receipt(
         &self,
-        _auth: FirebaseBearer,
+        auth: BearerAuth,
         body: Json<ScanReceiptRequest>,
     ) -> ScanReceiptResponse {
+        let max_bytes = self.payload_config.scan_receipt_max_bytes;
+        if body.0.image_base64.len() > max_bytes {
+            return ScanReceiptResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
         match self
             .scan_receipt_use_case
             .execute(ScanReceiptParams {
+                user_id: UserId::new(auth.0),
                 image_base64: body.0.image_base64,
             })
             .await
         {
             Ok(result) => ScanReceiptResponse::Ok(Json(result.into())),
             Err(err) => {
-                let (_, json) = err.into_error_response();
-                ScanReceiptResponse::UnprocessableEntity(json)
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    429 => ScanReceiptResponse::TooManyRequests(json),
+                    _ => ScanReceiptResponse::UnprocessableEntity(json),
+                }
+            }
+        }
+    }
+
+    /// Propose pantry changes from a shelf photo
+    ///
+    /// Identifies every product in a photo of an open fridge (or another
+    /// `location`) and compares them with the active pantry. Returns the
+    /// products to create, the products to update (only the changed fields)
+    /// and the products already up to date. Nothing is saved: the client
+    /// confirms each change with the regular create and update endpoints.
+    /// Bodies over `IDENTIFY_IMAGE_MAX_BYTES` are rejected with `413`.
+    #[oai(
+        path = "/products/from-photo",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn propose_from_photo(
+        &self,
+        auth: BearerAuth,
+        body: Json<ProposeFromPhotoRequest>,
+    ) -> ProposeFromPhotoResponse {
+        let max_bytes = self.payload_config.identify_image_max_bytes;
+        if body.0.image_base64.len() > max_bytes {
+            return ProposeFromPhotoResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
+        match self
+            .propose_from_photo_use_case
+            .execute(ProposeFromPhotoParams {
+                user_id: UserId::new(auth.0),
+                image_base64: body.0.image_base64,
+                location: body
+                    .0
+                    .location
+                    .map(|l| l.into())
+                    .unwrap_or(ProductLocation::Fridge),
+            })
+            .await
+        {
+            Ok(diff) => ProposeFromPhotoResponse::Ok(Json(diff.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    429 => ProposeFromPhotoResponse::TooManyRequests(json),
+                    500 => ProposeFromPhotoResponse::InternalError(json),
+                    _ => ProposeFromPhotoResponse::UnprocessableEntity(json),
+                }
             }
         }
     }
@@ -379,6 +1083,12 @@ impl ProductApi {
     ///
     /// Uses AI to estimate when a product will expire based on its name,
     /// status, and storage location. Does not require an existing product in the database.
+    /// A status or location outside the documented values, or a blank name,
+    /// is rejected with `400` before any AI call is made. The same attributes
+    /// asked again within the hour are answered without using up an AI call.
+    /// Responses carry `Cache-Control` and an `ETag` derived from the estimate;
+    /// sending it back in `If-None-Match` returns `304` while the estimate is
+    /// unchanged.
     #[oai(
         path = "/products/estimate-expiry-date",
         method = "post",
@@ -386,22 +1096,59 @@ impl ProductApi {
     )]
     async fn estimate_expiry_date(
         &self,
-        _auth: FirebaseBearer,
+        auth: BearerAuth,
+        #[oai(name = "If-None-Match")] if_none_match: Header<Option<String>>,
         body: Json<EstimateExpiryDateRequest>,
     ) -> EstimateExpiryDateResponse {
-        let estimation = self
-            .expiry_estimator_service
-            .estimate_expiry_date(&body.0.product_name, &body.0.status, body.0.location)
-            .await;
-
-        EstimateExpiryDateResponse::Ok(Json(ExpiryEstimationResponse {
-            date: estimation.date,
-            confidence: estimation.confidence.into(),
-        }))
+        match self
+            .estimate_expiry_for_attributes_use_case
+            .execute(EstimateExpiryForAttributesParams {
+                user_id: UserId::new(auth.0),
+                product_name: body.0.product_name,
+                status: body.0.status.into(),
+                location: body.0.location.map(|l| l.into()),
+            })
+            .await
+        {
+            Ok(estimation) => {
+                // From the estimate itself: the date is absolute, so the same
+                // inputs answer differently as time passes
+                let etag = cache_key(
+                    "expiry",
+                    &[
+                        &estimation.date.map(|d| d.to_rfc3339()).unwrap_or_default(),
+                        &estimation.confidence.to_string(),
+                    ],
+                );
+                if is_not_modified(if_none_match.0.as_deref(), &etag) {
+                    return EstimateExpiryDateResponse::NotModified(
+                        EXPIRY_ESTIMATE_CACHE_CONTROL.to_string(),
+                        etag,
+                    );
+                }
+                EstimateExpiryDateResponse::Ok(
+                    Json(ExpiryEstimationResponse {
+                        date: estimation.date,
+                        confidence: estimation.confidence.into(),
+                    }),
+                    EXPIRY_ESTIMATE_CACHE_CONTROL.to_string(),
+                    etag,
+                )
+            }
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => EstimateExpiryDateResponse::BadRequest(json),
+                    429 => EstimateExpiryDateResponse::TooManyRequests(json),
+                    _ => EstimateExpiryDateResponse::InternalError(json),
+                }
+            }
+        }
     }
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum CreateProductResponse {
     #[oai(status = 201)]
     Created(Json<ProductResponse>),
@@ -409,21 +1156,95 @@ pub enum CreateProductResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 402)]
+    PaymentRequired(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateProductsBatchResponse {
+    #[oai(status = 201)]
+    Created(Json<BatchProductCreationResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 402)]
+    PaymentRequired(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum GetAllProductsResponse {
     #[oai(status = 200)]
     Ok(Json<Vec<ProductResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetProductHistoryResponse {
+    #[oai(status = 200)]
+    Ok(Json<ProductHistoryResponse>),
+    /// Malformed cursor
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ProductChangesResponse {
+    #[oai(status = 200)]
+    Ok(EventStream<BoxStream<'static, ProductChangeEvent>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(ProductChangesResponse);
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetExpiryCalendarResponse {
+    #[oai(status = 200)]
+    Ok(Json<ExpiryCalendarResponse>),
+    /// Malformed month
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum GetProductByIdResponse {
     #[oai(status = 200)]
     Ok(Json<ProductResponse>),
@@ -431,6 +1252,8 @@ pub enum GetProductByIdResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
     #[oai(status = 500)]
@@ -438,6 +1261,7 @@ pub enum GetProductByIdResponse {
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum UpdateProductResponse {
     #[oai(status = 200)]
     Ok(Json<ProductResponse>),
@@ -445,13 +1269,19 @@ pub enum UpdateProductResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
+    /// The status change is not allowed (e.g. an opened product back to new)
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum DeleteProductResponse {
     #[oai(status = 204)]
     NoContent,
@@ -459,6 +1289,8 @@ pub enum DeleteProductResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
     #[oai(status = 500)]
@@ -466,6 +1298,7 @@ pub enum DeleteProductResponse {
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum EstimateExpiryResponse {
     #[oai(status = 200)]
     Ok(Json<ProductResponse>),
@@ -473,46 +1306,196 @@ pub enum EstimateExpiryResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum EstimateExpiryBatchResponse {
+    #[oai(status = 200)]
+    Ok(Json<BatchExpiryEstimationResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum IdentifyByImageResponse {
     #[oai(status = 200)]
     Ok(Json<ProductIdentificationResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ProposeFromPhotoResponse {
+    #[oai(status = 200)]
+    Ok(Json<PhotoDiffResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
     #[oai(status = 422)]
     UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum IdentifyByBarcodeResponse {
     #[oai(status = 200)]
-    Ok(Json<ProductIdentificationResponse>),
+    Ok(
+        Json<ProductIdentificationResponse>,
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] Option<String>,
+    ),
+    #[oai(status = 304)]
+    NotModified(
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 422)]
     UnprocessableEntity(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum ScanReceiptResponse {
     #[oai(status = 200)]
     Ok(Json<ReceiptScanResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
     #[oai(status = 422)]
     UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum EstimateExpiryDateResponse {
     #[oai(status = 200)]
-    Ok(Json<ExpiryEstimationResponse>),
+    Ok(
+        Json<ExpiryEstimationResponse>,
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 304)]
+    NotModified(
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
 }
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UploadProductImageResponse {
+    #[oai(status = 200)]
+    Ok(Json<SignedUrlResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetProductImageResponse {
+    #[oai(status = 200)]
+    Ok(Json<ImageLinksResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    CreateProductResponse,
+    CreateProductsBatchResponse,
+    GetAllProductsResponse,
+    GetProductHistoryResponse,
+    GetExpiryCalendarResponse,
+    GetProductByIdResponse,
+    UpdateProductResponse,
+    DeleteProductResponse,
+    UploadProductImageResponse,
+    GetProductImageResponse,
+    EstimateExpiryResponse,
+    EstimateExpiryBatchResponse,
+    IdentifyByImageResponse,
+    IdentifyByBarcodeResponse,
+    ScanReceiptResponse,
+    ProposeFromPhotoResponse,
+    EstimateExpiryDateResponse,
+);
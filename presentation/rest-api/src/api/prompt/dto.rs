@@ -0,0 +1,56 @@
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::prompt::model::{PromptTemplateInfo, TemplateSource};
+
+/// Where a prompt template in use came from.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum TemplateSourceDto {
+    /// Shipped with the server
+    #[oai(rename = "builtin")]
+    Builtin,
+    /// Read from `PROMPT_TEMPLATES_DIR`
+    #[oai(rename = "directory")]
+    Directory,
+}
+
+impl From<TemplateSource> for TemplateSourceDto {
+    fn from(source: TemplateSource) -> Self {
+        match source {
+            TemplateSource::Builtin => TemplateSourceDto::Builtin,
+            TemplateSource::Directory => TemplateSourceDto::Directory,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct PromptTemplateResponse {
+    /// Prompt the template renders
+    pub name: String,
+    /// Version logged with every AI call made with it
+    pub version: u32,
+    pub source: TemplateSourceDto,
+}
+
+impl From<PromptTemplateInfo> for PromptTemplateResponse {
+    fn from(info: PromptTemplateInfo) -> Self {
+        Self {
+            name: info.name,
+            version: info.version,
+            source: info.source.into(),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for PromptTemplateResponse {
+    fn example() -> Self {
+        Self {
+            name: "expiry_estimator".to_string(),
+            version: 2,
+            source: TemplateSourceDto::Directory,
+        }
+    }
+}
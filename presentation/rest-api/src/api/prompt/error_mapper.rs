@@ -0,0 +1,34 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::prompt::errors::PromptError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for PromptError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            PromptError::InvalidTemplate(_) => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "prompt.invalid_template",
+            ),
+            PromptError::Unreadable(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "prompt.unreadable",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
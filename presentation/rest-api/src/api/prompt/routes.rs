@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::prompt::use_cases::reload::ReloadPromptTemplatesUseCase;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::prompt::dto::PromptTemplateResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::config::admin_config::AdminConfig;
+
+pub struct PromptTemplateApi {
+    reload_use_case: Arc<dyn ReloadPromptTemplatesUseCase>,
+    admin_config: AdminConfig,
+}
+
+impl PromptTemplateApi {
+    pub fn new(
+        reload_use_case: Arc<dyn ReloadPromptTemplatesUseCase>,
+        admin_config: AdminConfig,
+    ) -> Self {
+        Self {
+            reload_use_case,
+            admin_config,
+        }
+    }
+}
+
+/// Prompt template API
+///
+/// Operator endpoints for the system prompts sent to the AI provider.
+#[OpenApi]
+impl PromptTemplateApi {
+    /// Reload prompt templates
+    ///
+    /// Reads `PROMPT_TEMPLATES_DIR` again and uses its templates for the next
+    /// AI calls, without a restart. Templates are named
+    /// `<prompt>.v<version>.jinja`; the highest version of each prompt wins
+    /// and prompts without one keep the built-in template. When any template
+    /// is misnamed or doesn't render, nothing changes and `400` is returned.
+    /// Only users listed in `ADMIN_USER_IDS` may call it.
+    #[oai(
+        path = "/admin/prompt-templates/reload",
+        method = "post",
+        tag = "ApiTags::Admin"
+    )]
+    async fn reload(&self, auth: BearerAuth) -> ReloadPromptTemplatesResponse {
+        if !self.admin_config.is_admin(&auth.0) {
+            return ReloadPromptTemplatesResponse::Forbidden(Json(ErrorResponse {
+                name: "Forbidden".to_string(),
+                message: "auth.forbidden".to_string(),
+                description: None,
+            }));
+        }
+
+        match self.reload_use_case.execute().await {
+            Ok(templates) => ReloadPromptTemplatesResponse::Ok(Json(
+                templates.into_iter().map(Into::into).collect(),
+            )),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => ReloadPromptTemplatesResponse::BadRequest(json),
+                    _ => ReloadPromptTemplatesResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ReloadPromptTemplatesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<PromptTemplateResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(ReloadPromptTemplatesResponse,);
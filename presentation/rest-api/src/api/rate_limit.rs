@@ -0,0 +1,470 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use poem::http::{HeaderMap, HeaderValue, StatusCode, header};
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+use poem_openapi::payload::Json;
+
+use crate::api::error::ErrorResponse;
+use crate::api::security::verified_uid;
+use crate::config::rate_limit_config::{RateLimitConfig, RouteGroup};
+
+/// Above this many tracked clients, windows that can no longer affect a
+/// decision are dropped.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Who a budget is counted against.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Subject {
+    User(String),
+    Ip(String),
+}
+
+#[derive(Debug)]
+struct Window {
+    start: Instant,
+    previous: u32,
+    current: u32,
+}
+
+impl Window {
+    /// Moves the window forward so that `now` falls inside it.
+    fn roll(&mut self, now: Instant, length: Duration) {
+        let elapsed = now.duration_since(self.start);
+        if elapsed < length {
+            return;
+        }
+        let windows = (elapsed.as_nanos() / length.as_nanos()) as u32;
+        self.previous = if windows == 1 { self.current } else { 0 };
+        self.current = 0;
+        self.start += length * windows;
+    }
+
+    /// Sliding-window estimate: the previous window's count weighted by how
+    /// much of it still overlaps the last `length`, plus the current count.
+    fn estimate(&self, now: Instant, length: Duration) -> f64 {
+        let into_window = now.duration_since(self.start).as_secs_f64() / length.as_secs_f64();
+        f64::from(self.previous) * (1.0 - into_window) + f64::from(self.current)
+    }
+}
+
+/// Outcome of a rate limit check, for the most restrictive budget involved.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    /// Time until the current window ends
+    pub reset_after: Duration,
+}
+
+/// In-memory sliding-window counters, per route group and subject.
+pub struct SlidingWindowLimiter {
+    window: Duration,
+    windows: Mutex<HashMap<(RouteGroup, Subject), Window>>,
+}
+
+impl SlidingWindowLimiter {
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Counts one request against the IP budget and, when the caller is
+    /// known, the user budget, or against neither if either is exhausted.
+    fn acquire(
+        &self,
+        group: RouteGroup,
+        ip: (Subject, u32),
+        user: Option<(Subject, u32)>,
+        now: Instant,
+    ) -> Decision {
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        if windows.len() > PRUNE_THRESHOLD {
+            let stale_after = self.window * 2;
+            windows.retain(|_, w| now.duration_since(w.start) < stale_after);
+        }
+
+        let mut check = |subject: &Subject, limit: u32| {
+            let window = windows
+                .entry((group, subject.clone()))
+                .or_insert_with(|| Window {
+                    start: now,
+                    previous: 0,
+                    current: 0,
+                });
+            window.roll(now, self.window);
+
+            let used = window.estimate(now, self.window);
+            Decision {
+                allowed: used + 1.0 <= f64::from(limit),
+                limit,
+                remaining: (f64::from(limit) - used - 1.0).max(0.0) as u32,
+                reset_after: (window.start + self.window).duration_since(now),
+            }
+        };
+        let ip_decision = check(&ip.0, ip.1);
+        // The rejecting budget wins, otherwise the one with less left
+        let decision = match &user {
+            Some((subject, limit)) => {
+                let user_decision = check(subject, *limit);
+                if !ip_decision.allowed
+                    || (user_decision.allowed && ip_decision.remaining <= user_decision.remaining)
+                {
+                    ip_decision
+                } else {
+                    user_decision
+                }
+            }
+            None => ip_decision,
+        };
+
+        if decision.allowed {
+            for (subject, _) in std::iter::once(&ip).chain(user.as_ref()) {
+                if let Some(window) = windows.get_mut(&(group, subject.clone())) {
+                    window.current += 1;
+                }
+            }
+        }
+        decision
+    }
+}
+
+/// Per-user and per-IP request limits over a sliding window, with
+/// `X-RateLimit-*` headers on every limited response. Runs before
+/// authentication, so the user is only known once Google certs are cached;
+/// until then the IP budget alone applies.
+pub struct RateLimit {
+    config: RateLimitConfig,
+    limiter: Arc<SlidingWindowLimiter>,
+    resolve_user: fn(&Request) -> Option<String>,
+}
+
+impl RateLimit {
+    pub fn new(config: RateLimitConfig) -> Self {
+        Self {
+            limiter: Arc::new(SlidingWindowLimiter::new(config.window)),
+            config,
+            resolve_user: verified_uid,
+        }
+    }
+
+    #[cfg(test)]
+    fn with_user_resolver(mut self, resolve_user: fn(&Request) -> Option<String>) -> Self {
+        self.resolve_user = resolve_user;
+        self
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for RateLimit {
+    type Output = RateLimitEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        RateLimitEndpoint {
+            inner: ep,
+            config: self.config.clone(),
+            limiter: self.limiter.clone(),
+            resolve_user: self.resolve_user,
+        }
+    }
+}
+
+pub struct RateLimitEndpoint<E> {
+    inner: E,
+    config: RateLimitConfig,
+    limiter: Arc<SlidingWindowLimiter>,
+    resolve_user: fn(&Request) -> Option<String>,
+}
+
+impl<E> RateLimitEndpoint<E> {
+    /// The `X-Forwarded-For` entry added by the outermost trusted proxy:
+    /// entries left of it are whatever the client sent.
+    fn client_ip(&self, req: &Request) -> String {
+        let hops = self.config.trusted_proxies;
+        let forwarded = (hops > 0)
+            .then(|| req.headers().get("x-forwarded-for"))
+            .flatten()
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.rsplit(',').nth(hops - 1))
+            .map(|ip| ip.trim().to_string())
+            .filter(|ip| !ip.is_empty());
+        forwarded
+            .or_else(|| {
+                req.remote_addr()
+                    .as_socket_addr()
+                    .map(|a| a.ip().to_string())
+            })
+            .unwrap_or_else(|| "unknown".to_string())
+    }
+}
+
+impl<E: Endpoint> Endpoint for RateLimitEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let group = match self.config.group_for(req.method(), req.uri().path()) {
+            Some(group) if self.config.enabled => group,
+            _ => return self.inner.call(req).await.map(IntoResponse::into_response),
+        };
+
+        let limits = self.config.limits(group);
+        let ip = (Subject::Ip(self.client_ip(&req)), limits.per_ip);
+        let user = (self.resolve_user)(&req).map(|uid| (Subject::User(uid), limits.per_user));
+        let decision = self.limiter.acquire(group, ip, user, Instant::now());
+
+        let mut resp = if decision.allowed {
+            self.inner.call(req).await?.into_response()
+        } else {
+            let mut resp = Json(ErrorResponse {
+                name: "TooManyRequests".to_string(),
+                message: "rate_limit.exceeded".to_string(),
+                description: None,
+            })
+            .with_status(StatusCode::TOO_MANY_REQUESTS)
+            .into_response();
+            resp.headers_mut().insert(
+                header::RETRY_AFTER,
+                HeaderValue::from(decision.reset_after.as_secs().max(1)),
+            );
+            resp
+        };
+        insert_rate_limit_headers(resp.headers_mut(), &decision);
+        Ok(resp)
+    }
+}
+
+fn insert_rate_limit_headers(headers: &mut HeaderMap, decision: &Decision) {
+    headers.insert("x-ratelimit-limit", HeaderValue::from(decision.limit));
+    headers.insert(
+        "x-ratelimit-remaining",
+        HeaderValue::from(decision.remaining),
+    );
+    headers.insert(
+        "x-ratelimit-reset",
+        HeaderValue::from(decision.reset_after.as_secs().max(1)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::rate_limit_config::RateLimits;
+    use poem::{EndpointExt, handler, test::TestClient};
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    fn config(per_user: u32, per_ip: u32) -> RateLimitConfig {
+        let limits = RateLimits { per_user, per_ip };
+        RateLimitConfig {
+            enabled: true,
+            window: MINUTE,
+            ai: limits,
+            standard: limits,
+            trusted_proxies: 1,
+        }
+    }
+
+    fn user_from_header(req: &Request) -> Option<String> {
+        req.header("x-test-user").map(str::to_string)
+    }
+
+    fn ip(address: &str) -> (Subject, u32) {
+        (Subject::Ip(address.to_string()), 2)
+    }
+
+    #[test]
+    fn should_reject_once_budget_is_used_within_window() {
+        let limiter = SlidingWindowLimiter::new(MINUTE);
+        let now = Instant::now();
+
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), None, now)
+                .allowed
+        );
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), None, now)
+                .allowed
+        );
+        let rejected = limiter.acquire(RouteGroup::Standard, ip("1.1.1.1"), None, now);
+
+        assert!(!rejected.allowed);
+        assert_eq!(rejected.remaining, 0);
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("2.2.2.2"), None, now)
+                .allowed
+        );
+        assert!(
+            limiter
+                .acquire(RouteGroup::Ai, ip("1.1.1.1"), None, now)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn should_weight_previous_window_when_sliding() {
+        let limiter = SlidingWindowLimiter::new(MINUTE);
+        let start = Instant::now();
+        limiter.acquire(RouteGroup::Standard, ip("1.1.1.1"), None, start);
+        limiter.acquire(RouteGroup::Standard, ip("1.1.1.1"), None, start);
+
+        // A quarter into the next window, 1.5 of the 2 previous requests still count
+        let quarter = start + MINUTE + MINUTE / 4;
+        assert!(
+            !limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), None, quarter)
+                .allowed
+        );
+
+        // Past half of it, one slot frees up
+        let later = start + MINUTE + MINUTE * 3 / 4;
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), None, later)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn should_not_count_against_ip_when_user_budget_exhausted() {
+        let limiter = SlidingWindowLimiter::new(MINUTE);
+        let now = Instant::now();
+        let user = || Some((Subject::User("alice".to_string()), 1));
+
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), user(), now)
+                .allowed
+        );
+        assert!(
+            !limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), user(), now)
+                .allowed
+        );
+
+        assert!(
+            limiter
+                .acquire(RouteGroup::Standard, ip("1.1.1.1"), None, now)
+                .allowed
+        );
+    }
+
+    #[tokio::test]
+    async fn should_return_429_with_rate_limit_headers_when_exceeded() {
+        let app = ok.with(RateLimit::new(config(1, 10)).with_user_resolver(user_from_header));
+        let client = TestClient::new(app);
+
+        let first = client
+            .get("/products")
+            .header("x-test-user", "alice")
+            .send()
+            .await;
+        first.assert_status_is_ok();
+        first.assert_header("x-ratelimit-limit", "1");
+        first.assert_header("x-ratelimit-remaining", "0");
+
+        let second = client
+            .get("/products")
+            .header("x-test-user", "alice")
+            .send()
+            .await;
+        second.assert_status(StatusCode::TOO_MANY_REQUESTS);
+        second.assert_header_exist(header::RETRY_AFTER);
+        second
+            .json()
+            .await
+            .value()
+            .object()
+            .get("message")
+            .assert_string("rate_limit.exceeded");
+
+        client
+            .get("/products")
+            .header("x-test-user", "bob")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_limit_by_forwarded_ip_when_anonymous() {
+        let app = ok.with(RateLimit::new(config(10, 1)).with_user_resolver(|_| None));
+        let client = TestClient::new(app);
+
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "203.0.113.7")
+            .send()
+            .await
+            .assert_status_is_ok();
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "203.0.113.7")
+            .send()
+            .await
+            .assert_status(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn should_ignore_forwarded_entries_left_by_the_client() {
+        let app = ok.with(RateLimit::new(config(10, 1)).with_user_resolver(|_| None));
+        let client = TestClient::new(app);
+
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "198.51.100.1, 203.0.113.7")
+            .send()
+            .await
+            .assert_status_is_ok();
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "198.51.100.2, 203.0.113.7")
+            .send()
+            .await
+            .assert_status(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn should_take_the_entry_added_by_the_outermost_trusted_proxy() {
+        let config = RateLimitConfig {
+            trusted_proxies: 2,
+            ..config(10, 1)
+        };
+        let app = ok.with(RateLimit::new(config).with_user_resolver(|_| None));
+        let client = TestClient::new(app);
+
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.1")
+            .send()
+            .await
+            .assert_status_is_ok();
+        client
+            .get("/shared/abc")
+            .header("x-forwarded-for", "203.0.113.7, 10.0.0.2")
+            .send()
+            .await
+            .assert_status(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn should_skip_exempt_routes() {
+        let app = ok.with(RateLimit::new(config(0, 0)).with_user_resolver(|_| None));
+        let client = TestClient::new(app);
+
+        let resp = client.get("/health").send().await;
+
+        resp.assert_status_is_ok();
+        resp.assert_header_is_not_exist("x-ratelimit-limit");
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::receipt_import::model::{
+    ImportReview, ImportedProduct, ReceiptImport, ReceiptImportStatus, SkipReason, SkippedLine,
+};
+
+use crate::api::examples::example_date;
+
+/// Progress of a receipt import.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ReceiptImportStatusDto {
+    /// Accepted, not started yet
+    #[oai(rename = "pending")]
+    Pending,
+    /// Scanning and creating products
+    #[oai(rename = "running")]
+    Running,
+    /// Done; see `review`
+    #[oai(rename = "completed")]
+    Completed,
+    /// Stopped; see `error`
+    #[oai(rename = "failed")]
+    Failed,
+}
+
+impl From<ReceiptImportStatus> for ReceiptImportStatusDto {
+    fn from(status: ReceiptImportStatus) -> Self {
+        match status {
+            ReceiptImportStatus::Pending => ReceiptImportStatusDto::Pending,
+            ReceiptImportStatus::Running => ReceiptImportStatusDto::Running,
+            ReceiptImportStatus::Completed => ReceiptImportStatusDto::Completed,
+            ReceiptImportStatus::Failed => ReceiptImportStatusDto::Failed,
+        }
+    }
+}
+
+/// Why a receipt line was not added.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum SkipReasonDto {
+    /// The line is not a product name (prices, totals...)
+    #[oai(rename = "invalid_name")]
+    InvalidName,
+    /// The product appears earlier on the receipt
+    #[oai(rename = "duplicate_on_receipt")]
+    DuplicateOnReceipt,
+    /// A product with the same name is already in the pantry
+    #[oai(rename = "already_in_pantry")]
+    AlreadyInPantry,
+    /// The plan's product limit was reached
+    #[oai(rename = "plan_limit_reached")]
+    PlanLimitReached,
+}
+
+impl From<SkipReason> for SkipReasonDto {
+    fn from(reason: SkipReason) -> Self {
+        match reason {
+            SkipReason::InvalidName => SkipReasonDto::InvalidName,
+            SkipReason::DuplicateOnReceipt => SkipReasonDto::DuplicateOnReceipt,
+            SkipReason::AlreadyInPantry => SkipReasonDto::AlreadyInPantry,
+            SkipReason::PlanLimitReached => SkipReasonDto::PlanLimitReached,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct ImportedProductResponse {
+    /// ID of the created product
+    pub product_id: String,
+    pub name: String,
+    /// AI-estimated expiry date, if one could be estimated
+    pub estimated_expiry_date: Option<DateTime<Utc>>,
+}
+
+impl From<ImportedProduct> for ImportedProductResponse {
+    fn from(p: ImportedProduct) -> Self {
+        Self {
+            product_id: p.product_id.to_string(),
+            name: p.name,
+            estimated_expiry_date: p.estimated_expiry_date,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct SkippedLineResponse {
+    /// Name from the receipt line
+    pub name: String,
+    pub reason: SkipReasonDto,
+    /// Pantry product the line matched, for `already_in_pantry`
+    pub existing_product_id: Option<String>,
+}
+
+impl From<SkippedLine> for SkippedLineResponse {
+    fn from(s: SkippedLine) -> Self {
+        Self {
+            name: s.name,
+            reason: s.reason.into(),
+            existing_product_id: s.existing_product_id.map(|id| id.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct ImportReviewResponse {
+    /// Products added to the pantry
+    pub created: Vec<ImportedProductResponse>,
+    /// Receipt lines left out, with the reason
+    pub skipped: Vec<SkippedLineResponse>,
+}
+
+impl From<ImportReview> for ImportReviewResponse {
+    fn from(r: ImportReview) -> Self {
+        Self {
+            created: r.created.into_iter().map(|p| p.into()).collect(),
+            skipped: r.skipped.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ReceiptImportResponse {
+    pub id: String,
+    pub status: ReceiptImportStatusDto,
+    /// Present once the import completed
+    pub review: Option<ImportReviewResponse>,
+    /// Error code, present once the import failed (e.g. product.scan_failed)
+    pub error: Option<String>,
+    /// Whether the receipt photo was kept; fetch it from `GET /products/from-receipt/{id}/image`
+    pub has_image: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ReceiptImport> for ReceiptImportResponse {
+    fn from(import: ReceiptImport) -> Self {
+        Self {
+            id: import.id.to_string(),
+            status: import.status.into(),
+            review: import.review.map(|r| r.into()),
+            error: import.error,
+            has_image: import.image_key.is_some(),
+            created_at: import.created_at,
+            updated_at: import.updated_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for ReceiptImportResponse {
+    fn example() -> Self {
+        Self {
+            id: "7d4f2b1e-9c3a-4e8b-a1f6-2b5c8d9e0f13".to_string(),
+            status: ReceiptImportStatusDto::Completed,
+            review: Some(ImportReviewResponse {
+                created: vec![ImportedProductResponse {
+                    product_id: "b3e1c2d4-5f6a-4b7c-8d9e-0a1b2c3d4e5f".to_string(),
+                    name: "Tomate rama".to_string(),
+                    estimated_expiry_date: Some(example_date()),
+                }],
+                skipped: vec![SkippedLineResponse {
+                    name: "LECHE ENTERA".to_string(),
+                    reason: SkipReasonDto::AlreadyInPantry,
+                    existing_product_id: Some("c4f2d3e5-6a7b-4c8d-9e0f-1a2b3c4d5e6f".to_string()),
+                }],
+            }),
+            error: None,
+            has_image: true,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::receipt_import::errors::ReceiptImportError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+use crate::api::me::error_mapper::quota_error_parts;
+use crate::api::storage::error_mapper::storage_error_parts;
+
+impl IntoErrorResponse for ReceiptImportError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            ReceiptImportError::NotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "receipt_import.not_found",
+            ),
+            ReceiptImportError::ImageNotFound => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "receipt_import.image_not_found",
+            ),
+            ReceiptImportError::Quota(err) => quota_error_parts(err),
+            ReceiptImportError::Storage(err) => storage_error_parts(err),
+            ReceiptImportError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
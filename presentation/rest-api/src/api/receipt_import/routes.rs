@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::receipt_import::use_cases::get_by_id::{
+    GetReceiptImportParams, GetReceiptImportUseCase,
+};
+use business::domain::receipt_import::use_cases::get_image::{
+    GetReceiptImageParams, GetReceiptImageUseCase,
+};
+use business::domain::receipt_import::use_cases::start::{
+    StartReceiptImportParams, StartReceiptImportUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::payload_limit::{PayloadTooLargeResponse, payload_too_large};
+use crate::api::product::dto::ScanReceiptRequest;
+use crate::api::receipt_import::dto::ReceiptImportResponse;
+use crate::api::security::BearerAuth;
+use crate::api::storage::dto::ImageLinksResponse;
+use crate::api::tags::ApiTags;
+use crate::config::payload_config::PayloadConfig;
+
+pub struct ReceiptImportApi {
+    start_use_case: Arc<dyn StartReceiptImportUseCase>,
+    get_by_id_use_case: Arc<dyn GetReceiptImportUseCase>,
+    get_image_use_case: Arc<dyn GetReceiptImageUseCase>,
+    payload_config: PayloadConfig,
+}
+
+impl ReceiptImportApi {
+    pub fn new(
+        start_use_case: Arc<dyn StartReceiptImportUseCase>,
+        get_by_id_use_case: Arc<dyn GetReceiptImportUseCase>,
+        get_image_use_case: Arc<dyn GetReceiptImageUseCase>,
+        payload_config: PayloadConfig,
+    ) -> Self {
+        Self {
+            start_use_case,
+            get_by_id_use_case,
+            get_image_use_case,
+            payload_config,
+        }
+    }
+}
+
+/// Receipt import API
+///
+/// Endpoints for turning a receipt photo into pantry products in one call.
+#[OpenApi]
+impl ReceiptImportApi {
+    /// Import a receipt into the pantry
+    ///
+    /// Accepts a receipt photo and returns `202` with a pending import. In the
+    /// background the receipt is scanned, lines are cleaned up and checked
+    /// against the pantry, expiry dates are estimated and the products are
+    /// created. Poll `GET /products/from-receipt/{id}` for the review of what
+    /// was added and what was skipped. Bodies over `SCAN_RECEIPT_MAX_BYTES` are
+    /// rejected with `413`. With OCR configured, users out of AI calls still
+    /// get their receipt imported, without estimated expiry dates.
+    #[oai(
+        path = "/products/from-receipt",
+        method = "post",
+        tag = "ApiTags::Products"
+    )]
+    async fn start(
+        &self,
+        auth: BearerAuth,
+        body: Json<ScanReceiptRequest>,
+    ) -> StartReceiptImportResponse {
+        let max_bytes = self.payload_config.scan_receipt_max_bytes;
+        if body.0.image_base64.len() > max_bytes {
+            return StartReceiptImportResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
+        match self
+            .start_use_case
+            .execute(StartReceiptImportParams {
+                user_id: UserId::new(auth.0),
+                image_base64: body.0.image_base64,
+            })
+            .await
+        {
+            Ok(import) => StartReceiptImportResponse::Accepted(Json(import.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    429 => StartReceiptImportResponse::TooManyRequests(json),
+                    _ => StartReceiptImportResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get a receipt import
+    ///
+    /// Returns the import status and, once completed, the products created and
+    /// the receipt lines skipped with the reason.
+    #[oai(
+        path = "/products/from-receipt/:id",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_by_id(&self, auth: BearerAuth, id: Path<String>) -> GetReceiptImportResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return GetReceiptImportResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "receipt_import.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .get_by_id_use_case
+            .execute(GetReceiptImportParams { id: uuid, user_id })
+            .await
+        {
+            Ok(import) => GetReceiptImportResponse::Ok(Json(import.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetReceiptImportResponse::NotFound(json),
+                    _ => GetReceiptImportResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get the photo of an imported receipt
+    ///
+    /// Returns temporary URLs to the receipt photo sent with the import, when
+    /// `hasImage` is true, and to its thumbnail once made. The URLs need no
+    /// token; ask again once they expire.
+    #[oai(
+        path = "/products/from-receipt/:id/image",
+        method = "get",
+        tag = "ApiTags::Products"
+    )]
+    async fn get_image(&self, auth: BearerAuth, id: Path<String>) -> GetReceiptImageResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return GetReceiptImageResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "receipt_import.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .get_image_use_case
+            .execute(GetReceiptImageParams { id: uuid, user_id })
+            .await
+        {
+            Ok(links) => GetReceiptImageResponse::Ok(Json(links.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetReceiptImageResponse::NotFound(json),
+                    503 => GetReceiptImageResponse::ServiceUnavailable(json),
+                    _ => GetReceiptImageResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum StartReceiptImportResponse {
+    #[oai(status = 202)]
+    Accepted(Json<ReceiptImportResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
+    #[oai(status = 429)]
+    TooManyRequests(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetReceiptImportResponse {
+    #[oai(status = 200)]
+    Ok(Json<ReceiptImportResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetReceiptImageResponse {
+    #[oai(status = 200)]
+    Ok(Json<ImageLinksResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    StartReceiptImportResponse,
+    GetReceiptImportResponse,
+    GetReceiptImageResponse,
+);
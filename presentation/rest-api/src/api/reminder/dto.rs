@@ -0,0 +1,100 @@
+use chrono::{DateTime, Duration, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::reminder::model::Reminder;
+
+use crate::api::examples::example_date;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CreateReminderRequest {
+    /// What to be reminded of (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 500))]
+    pub note: String,
+    /// When to be reminded; must be in the future
+    pub remind_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct UpdateReminderRequest {
+    /// New note
+    #[oai(validator(min_length = 1, max_length = 500))]
+    #[oai(skip_serializing_if_is_none)]
+    pub note: Option<String>,
+    /// New reminder time; must be in the future
+    #[oai(skip_serializing_if_is_none)]
+    pub remind_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ReminderResponse {
+    /// Reminder unique identifier
+    pub id: String,
+    /// Product the reminder is attached to
+    pub product_id: String,
+    /// What to be reminded of
+    pub note: String,
+    /// When to be reminded
+    pub remind_at: DateTime<Utc>,
+    /// When the reminder was sent; absent until then
+    #[oai(skip_serializing_if_is_none)]
+    pub sent_at: Option<DateTime<Utc>>,
+    /// Creation timestamp
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Reminder> for ReminderResponse {
+    fn from(reminder: Reminder) -> Self {
+        Self {
+            id: reminder.id.to_string(),
+            product_id: reminder.product_id.to_string(),
+            note: reminder.note,
+            remind_at: reminder.remind_at,
+            sent_at: reminder.sent_at,
+            created_at: reminder.created_at,
+            updated_at: reminder.updated_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+fn example_remind_at() -> DateTime<Utc> {
+    example_date() + Duration::days(4)
+}
+
+impl Example for CreateReminderRequest {
+    fn example() -> Self {
+        Self {
+            note: "Check the sourdough starter".to_string(),
+            remind_at: example_remind_at(),
+        }
+    }
+}
+
+impl Example for UpdateReminderRequest {
+    fn example() -> Self {
+        Self {
+            note: None,
+            remind_at: Some(example_remind_at() + Duration::days(1)),
+        }
+    }
+}
+
+impl Example for ReminderResponse {
+    fn example() -> Self {
+        Self {
+            id: "8d2f1c4e-3b7a-4e59-9c1d-6f0a2b8e4d13".to_string(),
+            product_id: "550e8400-e29b-41d4-a716-446655440000".to_string(),
+            note: "Check the sourdough starter".to_string(),
+            remind_at: example_remind_at(),
+            sent_at: None,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
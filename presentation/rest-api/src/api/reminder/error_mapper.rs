@@ -0,0 +1,43 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::reminder::errors::ReminderError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for ReminderError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            ReminderError::NoteEmpty => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "reminder.note_empty",
+            ),
+            ReminderError::RemindAtInPast => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "reminder.remind_at_in_past",
+            ),
+            ReminderError::NotFound => (StatusCode::NOT_FOUND, "NotFound", "reminder.not_found"),
+            ReminderError::ProductNotFound => {
+                (StatusCode::NOT_FOUND, "NotFound", "product.not_found")
+            }
+            ReminderError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::reminder::use_cases::create::{CreateReminderParams, CreateReminderUseCase};
+use business::domain::reminder::use_cases::delete::{DeleteReminderParams, DeleteReminderUseCase};
+use business::domain::reminder::use_cases::get_all::{GetRemindersParams, GetRemindersUseCase};
+use business::domain::reminder::use_cases::update::{UpdateReminderParams, UpdateReminderUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::reminder::dto::{CreateReminderRequest, ReminderResponse, UpdateReminderRequest};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct ReminderApi {
+    get_all_use_case: Arc<dyn GetRemindersUseCase>,
+    create_use_case: Arc<dyn CreateReminderUseCase>,
+    update_use_case: Arc<dyn UpdateReminderUseCase>,
+    delete_use_case: Arc<dyn DeleteReminderUseCase>,
+}
+
+impl ReminderApi {
+    pub fn new(
+        get_all_use_case: Arc<dyn GetRemindersUseCase>,
+        create_use_case: Arc<dyn CreateReminderUseCase>,
+        update_use_case: Arc<dyn UpdateReminderUseCase>,
+        delete_use_case: Arc<dyn DeleteReminderUseCase>,
+    ) -> Self {
+        Self {
+            get_all_use_case,
+            create_use_case,
+            update_use_case,
+            delete_use_case,
+        }
+    }
+}
+
+/// Reminder API
+///
+/// Endpoints for reminders the user sets on a product at a time of their
+/// choosing, beyond its expiry date.
+#[OpenApi]
+impl ReminderApi {
+    /// List reminders of a product
+    ///
+    /// Returns the product's reminders, soonest first.
+    #[oai(
+        path = "/products/:id/reminders",
+        method = "get",
+        tag = "ApiTags::Reminders"
+    )]
+    async fn get_all(&self, auth: BearerAuth, id: Path<String>) -> GetRemindersResponse {
+        let product_id = match parse_id(&id.0, "product.invalid_id") {
+            Ok(uuid) => uuid,
+            Err(json) => return GetRemindersResponse::BadRequest(json),
+        };
+
+        match self
+            .get_all_use_case
+            .execute(GetRemindersParams {
+                user_id: UserId::new(auth.0),
+                product_id,
+            })
+            .await
+        {
+            Ok(reminders) => {
+                GetRemindersResponse::Ok(Json(reminders.into_iter().map(|r| r.into()).collect()))
+            }
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetRemindersResponse::NotFound(json),
+                    _ => GetRemindersResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Create a reminder
+    ///
+    /// Sets a reminder on the product, e.g. "check the sourdough starter" on
+    /// Friday. The time must be in the future.
+    #[oai(
+        path = "/products/:id/reminders",
+        method = "post",
+        tag = "ApiTags::Reminders"
+    )]
+    async fn create(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        body: Json<CreateReminderRequest>,
+    ) -> CreateReminderResponse {
+        let product_id = match parse_id(&id.0, "product.invalid_id") {
+            Ok(uuid) => uuid,
+            Err(json) => return CreateReminderResponse::BadRequest(json),
+        };
+
+        let params = CreateReminderParams {
+            user_id: UserId::new(auth.0),
+            product_id,
+            note: body.0.note,
+            remind_at: body.0.remind_at,
+        };
+
+        match self.create_use_case.execute(params).await {
+            Ok(reminder) => CreateReminderResponse::Created(Json(reminder.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CreateReminderResponse::BadRequest(json),
+                    404 => CreateReminderResponse::NotFound(json),
+                    _ => CreateReminderResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Update a reminder
+    ///
+    /// Changes the note and/or the reminder time.
+    #[oai(
+        path = "/products/:id/reminders/:reminder_id",
+        method = "put",
+        tag = "ApiTags::Reminders"
+    )]
+    async fn update(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        reminder_id: Path<String>,
+        body: Json<UpdateReminderRequest>,
+    ) -> UpdateReminderResponse {
+        let (product_id, reminder_id) = match (
+            parse_id(&id.0, "product.invalid_id"),
+            parse_id(&reminder_id.0, "reminder.invalid_id"),
+        ) {
+            (Ok(product_id), Ok(reminder_id)) => (product_id, reminder_id),
+            (Err(json), _) | (_, Err(json)) => return UpdateReminderResponse::BadRequest(json),
+        };
+
+        let params = UpdateReminderParams {
+            id: reminder_id,
+            product_id,
+            user_id: UserId::new(auth.0),
+            note: body.0.note,
+            remind_at: body.0.remind_at,
+        };
+
+        match self.update_use_case.execute(params).await {
+            Ok(reminder) => UpdateReminderResponse::Ok(Json(reminder.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UpdateReminderResponse::BadRequest(json),
+                    404 => UpdateReminderResponse::NotFound(json),
+                    _ => UpdateReminderResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Delete a reminder
+    #[oai(
+        path = "/products/:id/reminders/:reminder_id",
+        method = "delete",
+        tag = "ApiTags::Reminders"
+    )]
+    async fn delete(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        reminder_id: Path<String>,
+    ) -> DeleteReminderResponse {
+        let (product_id, reminder_id) = match (
+            parse_id(&id.0, "product.invalid_id"),
+            parse_id(&reminder_id.0, "reminder.invalid_id"),
+        ) {
+            (Ok(product_id), Ok(reminder_id)) => (product_id, reminder_id),
+            (Err(json), _) | (_, Err(json)) => return DeleteReminderResponse::BadRequest(json),
+        };
+
+        match self
+            .delete_use_case
+            .execute(DeleteReminderParams {
+                id: reminder_id,
+                product_id,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(()) => DeleteReminderResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => DeleteReminderResponse::NotFound(json),
+                    _ => DeleteReminderResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a path id, answering with `message` when it is not a UUID.
+fn parse_id(raw: &str, message: &str) -> Result<Uuid, Json<ErrorResponse>> {
+    Uuid::parse_str(raw).map_err(|_| {
+        Json(ErrorResponse {
+            name: "ValidationError".to_string(),
+            message: message.to_string(),
+            description: None,
+        })
+    })
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetRemindersResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<ReminderResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateReminderResponse {
+    #[oai(status = 201)]
+    Created(Json<ReminderResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UpdateReminderResponse {
+    #[oai(status = 200)]
+    Ok(Json<ReminderResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum DeleteReminderResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetRemindersResponse,
+    CreateReminderResponse,
+    UpdateReminderResponse,
+    DeleteReminderResponse,
+);
@@ -0,0 +1,94 @@
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::search::model::{SearchHit, SearchResult};
+
+use crate::api::product::dto::ProductResponse;
+use crate::api::shopping_item::dto::ShoppingItemResponse;
+use crate::api::suggestion::dto::SuggestionResponse;
+
+/// What a search hit is; tells which of its fields is set.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum SearchHitKindDto {
+    #[oai(rename = "product")]
+    Product,
+    #[oai(rename = "shopping_item")]
+    ShoppingItem,
+    /// A recipe from the suggestion history
+    #[oai(rename = "suggestion")]
+    Suggestion,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SearchHitResponse {
+    pub kind: SearchHitKindDto,
+    /// Relevance; higher is better. Only comparable within one response
+    pub score: u32,
+    /// Set when `kind` is `product`
+    #[oai(skip_serializing_if_is_none)]
+    pub product: Option<ProductResponse>,
+    /// Set when `kind` is `shopping_item`
+    #[oai(skip_serializing_if_is_none)]
+    pub shopping_item: Option<ShoppingItemResponse>,
+    /// Set when `kind` is `suggestion`
+    #[oai(skip_serializing_if_is_none)]
+    pub suggestion: Option<SuggestionResponse>,
+}
+
+impl From<SearchHit> for SearchHitResponse {
+    fn from(hit: SearchHit) -> Self {
+        let empty = Self {
+            kind: SearchHitKindDto::Product,
+            score: hit.score,
+            product: None,
+            shopping_item: None,
+            suggestion: None,
+        };
+        match hit.result {
+            SearchResult::Product(product) => Self {
+                product: Some(product.into()),
+                ..empty
+            },
+            SearchResult::ShoppingItem(item) => Self {
+                kind: SearchHitKindDto::ShoppingItem,
+                shopping_item: Some(item.into()),
+                ..empty
+            },
+            SearchResult::Suggestion(suggestion) => Self {
+                kind: SearchHitKindDto::Suggestion,
+                suggestion: Some(suggestion.into()),
+                ..empty
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SearchResponse {
+    /// Most relevant first; ties go to the most recently updated
+    pub hits: Vec<SearchHitResponse>,
+}
+
+// --- OpenAPI examples ---
+
+impl Example for SearchHitResponse {
+    fn example() -> Self {
+        Self {
+            kind: SearchHitKindDto::ShoppingItem,
+            score: 80,
+            product: None,
+            shopping_item: Some(ShoppingItemResponse::example()),
+            suggestion: None,
+        }
+    }
+}
+
+impl Example for SearchResponse {
+    fn example() -> Self {
+        Self {
+            hits: vec![SearchHitResponse::example()],
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::search::errors::SearchError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for SearchError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            SearchError::QueryTooShort => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "search.query_too_short",
+            ),
+            SearchError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
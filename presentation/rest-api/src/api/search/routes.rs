@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Query, payload::Json};
+
+use business::domain::search::use_cases::global::{GlobalSearchParams, GlobalSearchUseCase};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::search::dto::SearchResponse;
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+
+pub struct SearchApi {
+    global_search_use_case: Arc<dyn GlobalSearchUseCase>,
+}
+
+impl SearchApi {
+    pub fn new(global_search_use_case: Arc<dyn GlobalSearchUseCase>) -> Self {
+        Self {
+            global_search_use_case,
+        }
+    }
+}
+
+/// Search API
+///
+/// One query over everything the user has, for the app's global search bar.
+#[OpenApi]
+impl SearchApi {
+    /// Search across everything
+    ///
+    /// Matches the query against product names, shopping list item names and
+    /// the titles of recipes in the suggestion history, case-insensitively.
+    /// Whole-name matches rank first, then names starting with the query,
+    /// then a word starting with it, then the query anywhere. Finished
+    /// products and bought items rank a little below live ones. A recipe
+    /// suggested several times is returned once.
+    #[oai(path = "/search", method = "get", tag = "ApiTags::Search")]
+    async fn search(
+        &self,
+        auth: BearerAuth,
+        /// Text to look for, at least 2 characters
+        #[oai(validator(max_length = 100))]
+        q: Query<String>,
+        /// Maximum number of hits (default: 20, max: 50)
+        limit: Query<Option<u32>>,
+    ) -> SearchApiResponse {
+        let params = GlobalSearchParams {
+            user_id: UserId::new(auth.0),
+            query: q.0,
+            limit: limit.0.unwrap_or(20).clamp(1, 50),
+        };
+
+        match self.global_search_use_case.execute(params).await {
+            Ok(hits) => SearchApiResponse::Ok(Json(SearchResponse {
+                hits: hits.into_iter().map(|hit| hit.into()).collect(),
+            })),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => SearchApiResponse::BadRequest(json),
+                    _ => SearchApiResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum SearchApiResponse {
+    #[oai(status = 200)]
+    Ok(Json<SearchResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(SearchApiResponse);
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use auth::tokens::LocalTokens;
+
+use super::AuthProvider;
+
+/// Tokens this server issued itself after a passkey or magic-link sign-in.
+#[async_trait]
+impl AuthProvider for LocalTokens {
+    async fn prepare(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn verify(&self, token: &str) -> Result<String, String> {
+        LocalTokens::verify(self, token)
+    }
+}
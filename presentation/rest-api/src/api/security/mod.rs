@@ -0,0 +1,77 @@
+mod firebase;
+mod local;
+
+use async_trait::async_trait;
+use auth::tokens::LocalTokens;
+use firebase::FirebaseAuth;
+pub(crate) use firebase::GOOGLE_CERTS_URL;
+use once_cell::sync::Lazy;
+use poem::Request;
+use poem_openapi::SecurityScheme;
+
+use crate::config::auth_config::AuthConfig;
+use crate::config::sandbox_config::SandboxConfig;
+
+/// Who issues the bearer tokens this API accepts. Picked at startup by
+/// `AUTH_PROVIDER`, so self-hosters can sign users in without Firebase.
+#[async_trait]
+pub trait AuthProvider: Send + Sync {
+    /// Loads or refreshes whatever `verify` needs, such as signing keys.
+    /// Runs before every authenticated request, so it must be cheap when
+    /// nothing is stale.
+    async fn prepare(&self) -> Result<(), String>;
+
+    /// User id of a valid token, or an error code explaining the rejection.
+    fn verify(&self, token: &str) -> Result<String, String>;
+}
+
+static PROVIDER: Lazy<Box<dyn AuthProvider>> = Lazy::new(|| match AuthConfig::from_env() {
+    AuthConfig::Firebase(config) => Box::new(FirebaseAuth::new(config)),
+    AuthConfig::Local(config) => Box::new(LocalTokens::new(config.tokens)),
+});
+
+/// Demo user every signed-in user acts as in sandbox mode.
+static SANDBOX_USER: Lazy<Option<String>> = Lazy::new(|| SandboxConfig::from_env().demo_user_id);
+
+/// User id of a request's bearer token, verified with what the provider has
+/// already loaded and without fetching anything. For middleware that runs
+/// before the security scheme; `None` when the token is missing or invalid,
+/// or the provider's keys have not been loaded yet.
+pub fn verified_uid(req: &Request) -> Option<String> {
+    let token = req
+        .headers()
+        .get(poem::http::header::AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")?;
+    PROVIDER.verify(token).ok()
+}
+
+/// Bearer token authentication
+///
+/// Send a token from the configured provider as `Authorization: Bearer
+/// <token>`: a Firebase ID token, or on self-hosted instances a token from
+/// `/auth/magic-link/verify` or `/auth/passkeys/login`. A missing, expired or
+/// invalid token yields `401` with `auth.unauthorized`; `403` with
+/// `auth.forbidden` is reserved for authenticated users lacking access. In
+/// sandbox mode every valid token acts as the shared demo user.
+#[derive(SecurityScheme)]
+#[oai(ty = "bearer", bearer_format = "JWT", checker = "bearer_checker")]
+#[allow(dead_code)]
+pub struct BearerAuth(pub String);
+
+async fn bearer_checker(_req: &Request, bearer: poem_openapi::auth::Bearer) -> Option<String> {
+    if let Err(e) = PROVIDER.prepare().await {
+        tracing::error!("Failed to prepare auth provider: {e}");
+        return None;
+    }
+
+    match PROVIDER.verify(&bearer.token) {
+        // In the sandbox every signed-in user shares the demo user's data
+        Ok(uid) => Some(SANDBOX_USER.clone().unwrap_or(uid)),
+        Err(e) => {
+            tracing::warn!("Authentication failed: {e}");
+            None
+        }
+    }
+}
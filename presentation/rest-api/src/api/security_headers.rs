@@ -0,0 +1,244 @@
+use poem::http::{HeaderValue, Method, StatusCode, header};
+use poem::web::Redirect;
+use poem::{Endpoint, IntoResponse, Middleware, Request, Response};
+use poem_openapi::payload::Json;
+
+use crate::api::error::ErrorResponse;
+use crate::config::security_config::SecurityConfig;
+
+/// Adds HSTS, `X-Content-Type-Options` and `Referrer-Policy` to every
+/// response and, when HTTPS-only is on, redirects requests the proxy
+/// received over plain HTTP. Requests without `X-Forwarded-Proto` (e.g.
+/// platform health checks hitting the container directly) pass through.
+pub struct SecurityHeaders {
+    config: SecurityConfig,
+}
+
+impl SecurityHeaders {
+    pub fn new(config: SecurityConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl<E: Endpoint> Middleware<E> for SecurityHeaders {
+    type Output = SecurityHeadersEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        SecurityHeadersEndpoint {
+            inner: ep,
+            config: self.config.clone(),
+        }
+    }
+}
+
+pub struct SecurityHeadersEndpoint<E> {
+    inner: E,
+    config: SecurityConfig,
+}
+
+impl<E: Endpoint> Endpoint for SecurityHeadersEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        let mut resp = match https_redirect(&self.config, &req) {
+            Some(redirect) => redirect,
+            None => self.inner.call(req).await?.into_response(),
+        };
+
+        let headers = resp.headers_mut();
+        headers.insert(
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        );
+        headers.insert(
+            header::REFERRER_POLICY,
+            HeaderValue::from_static("no-referrer"),
+        );
+        if let Some(max_age) = self.config.hsts_max_age
+            && let Ok(value) =
+                HeaderValue::from_str(&format!("max-age={max_age}; includeSubDomains"))
+        {
+            headers.insert(header::STRICT_TRANSPORT_SECURITY, value);
+        }
+        Ok(resp)
+    }
+}
+
+/// Permanent redirect (method-preserving) to the HTTPS URL of a request the
+/// proxy reports as plain HTTP.
+fn https_redirect(config: &SecurityConfig, req: &Request) -> Option<Response> {
+    if !config.https_only {
+        return None;
+    }
+    let proto = req.header("x-forwarded-proto")?;
+    if !proto.eq_ignore_ascii_case("http") {
+        return None;
+    }
+    let host = req
+        .header("x-forwarded-host")
+        .or_else(|| req.header(header::HOST))?;
+    let path = req.uri().path_and_query().map_or("/", |p| p.as_str());
+    Some(Redirect::permanent(format!("https://{host}{path}")).into_response())
+}
+
+/// Rejects requests whose body is not declared as JSON with `415`,
+/// instead of letting each endpoint fail on a payload it cannot parse.
+pub struct JsonContentType;
+
+impl<E: Endpoint> Middleware<E> for JsonContentType {
+    type Output = JsonContentTypeEndpoint<E>;
+
+    fn transform(&self, ep: E) -> Self::Output {
+        JsonContentTypeEndpoint { inner: ep }
+    }
+}
+
+pub struct JsonContentTypeEndpoint<E> {
+    inner: E,
+}
+
+impl<E: Endpoint> Endpoint for JsonContentTypeEndpoint<E> {
+    type Output = Response;
+
+    async fn call(&self, req: Request) -> poem::Result<Self::Output> {
+        if is_unsupported_media_type(&req) {
+            return Ok(Json(ErrorResponse {
+                name: "UnsupportedMediaType".to_string(),
+                message: "request.unsupported_media_type".to_string(),
+                description: None,
+            })
+            .with_status(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+            .into_response());
+        }
+
+        self.inner.call(req).await.map(IntoResponse::into_response)
+    }
+}
+
+/// A body-carrying method with a non-JSON `Content-Type`, or with a body but
+/// no `Content-Type` at all. Body-less commands (e.g. accept) pass.
+fn is_unsupported_media_type(req: &Request) -> bool {
+    if !matches!(*req.method(), Method::POST | Method::PUT | Method::PATCH) {
+        return false;
+    }
+    match req.header(header::CONTENT_TYPE) {
+        Some(content_type) => !is_json(content_type),
+        None => {
+            req.header(header::CONTENT_LENGTH)
+                .and_then(|v| v.parse::<u64>().ok())
+                .is_some_and(|length| length > 0)
+                || req.header(header::TRANSFER_ENCODING).is_some()
+        }
+    }
+}
+
+/// `application/json`, optionally with parameters such as `charset`.
+fn is_json(content_type: &str) -> bool {
+    content_type
+        .split(';')
+        .next()
+        .is_some_and(|mime| mime.trim().eq_ignore_ascii_case("application/json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use poem::{EndpointExt, handler, test::TestClient};
+
+    #[handler]
+    fn ok() -> &'static str {
+        "ok"
+    }
+
+    fn client(config: SecurityConfig) -> TestClient<impl Endpoint> {
+        TestClient::new(ok.with(JsonContentType).with(SecurityHeaders::new(config)))
+    }
+
+    fn https_only() -> SecurityConfig {
+        SecurityConfig {
+            hsts_max_age: Some(60),
+            https_only: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn should_add_security_headers_to_responses() {
+        let resp = client(https_only()).get("/products").send().await;
+
+        resp.assert_status_is_ok();
+        resp.assert_header("x-content-type-options", "nosniff");
+        resp.assert_header("referrer-policy", "no-referrer");
+        resp.assert_header("strict-transport-security", "max-age=60; includeSubDomains");
+    }
+
+    #[tokio::test]
+    async fn should_omit_hsts_when_disabled() {
+        let resp = client(SecurityConfig {
+            hsts_max_age: None,
+            https_only: false,
+        })
+        .get("/products")
+        .send()
+        .await;
+
+        resp.assert_header_is_not_exist("strict-transport-security");
+    }
+
+    #[tokio::test]
+    async fn should_redirect_to_https_when_proxy_received_http() {
+        let resp = client(https_only())
+            .post("/products?x=1")
+            .header("x-forwarded-proto", "http")
+            .header("host", "api.foodie.app")
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::PERMANENT_REDIRECT);
+        resp.assert_header("location", "https://api.foodie.app/products?x=1");
+    }
+
+    #[tokio::test]
+    async fn should_pass_through_when_no_forwarded_proto() {
+        client(https_only())
+            .get("/health")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+
+    #[tokio::test]
+    async fn should_reject_body_that_is_not_json() {
+        let resp = client(https_only())
+            .post("/products")
+            .content_type("text/plain")
+            .body("name=milk")
+            .send()
+            .await;
+
+        resp.assert_status(StatusCode::UNSUPPORTED_MEDIA_TYPE);
+        resp.json()
+            .await
+            .value()
+            .object()
+            .get("message")
+            .assert_string("request.unsupported_media_type");
+    }
+
+    #[tokio::test]
+    async fn should_accept_json_with_charset_and_empty_bodies() {
+        let client = client(https_only());
+
+        client
+            .put("/products/1")
+            .content_type("application/json; charset=utf-8")
+            .body("{}")
+            .send()
+            .await
+            .assert_status_is_ok();
+        client
+            .post("/products/pending-changes/1/accept")
+            .send()
+            .await
+            .assert_status_is_ok();
+    }
+}
@@ -0,0 +1,166 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::product::model::Product;
+use business::domain::share_link::use_cases::create::CreatedShareLink;
+use business::domain::share_link::use_cases::get_shared_view::SharedView;
+use business::domain::shopping_item::model::ShoppingItem;
+
+use crate::api::examples::example_date;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CreateShareLinkRequest {
+    /// Also expose products expiring within the next two days (default: false)
+    #[oai(skip_serializing_if_is_none)]
+    pub include_expiring_products: Option<bool>,
+    /// Link lifetime in hours, between 1 and 168 (default: 24)
+    #[oai(validator(minimum(value = "1"), maximum(value = "168")))]
+    #[oai(skip_serializing_if_is_none)]
+    pub expires_in_hours: Option<i64>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ShareLinkResponse {
+    /// Share link unique identifier (used to revoke it)
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
+    pub id: String,
+    /// Secret token; only returned once, at creation time
+    #[oai(validator(pattern = "^[A-Za-z0-9_-]{43}$"))]
+    pub token: String,
+    /// Relative URL of the read-only view
+    pub url: String,
+    /// Whether expiring products are included in the view
+    pub include_expiring_products: bool,
+    /// Expiration timestamp
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<CreatedShareLink> for ShareLinkResponse {
+    fn from(created: CreatedShareLink) -> Self {
+        Self {
+            id: created.link.id.to_string(),
+            url: format!("/shared/{}", created.token),
+            token: created.token,
+            include_expiring_products: created.link.include_expiring_products,
+            expires_at: created.link.expires_at,
+        }
+    }
+}
+
+/// Shopping item as seen by a guest: no identifiers or links to the pantry.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SharedShoppingItemResponse {
+    /// Item name
+    pub name: String,
+    /// Whether the item has been bought
+    pub is_bought: bool,
+}
+
+impl From<ShoppingItem> for SharedShoppingItemResponse {
+    fn from(item: ShoppingItem) -> Self {
+        Self {
+            name: item.name,
+            is_bought: item.is_bought,
+        }
+    }
+}
+
+/// Expiring product as seen by a guest.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SharedProductResponse {
+    /// Product name
+    pub name: String,
+    /// Expiry date (actual or estimated)
+    #[oai(skip_serializing_if_is_none)]
+    pub expiry_date: Option<DateTime<Utc>>,
+}
+
+impl From<Product> for SharedProductResponse {
+    fn from(product: Product) -> Self {
+        Self {
+            name: product.name,
+            expiry_date: product.expiry_date.or(product.estimated_expiry_date),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SharedViewResponse {
+    /// Shopping list items
+    pub shopping_items: Vec<SharedShoppingItemResponse>,
+    /// Products expiring soon, when shared
+    #[oai(skip_serializing_if_is_none)]
+    pub expiring_products: Option<Vec<SharedProductResponse>>,
+    /// When this link stops working
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<SharedView> for SharedViewResponse {
+    fn from(view: SharedView) -> Self {
+        Self {
+            shopping_items: view.shopping_items.into_iter().map(|i| i.into()).collect(),
+            expiring_products: view
+                .expiring_products
+                .map(|products| products.into_iter().map(|p| p.into()).collect()),
+            expires_at: view.expires_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for CreateShareLinkRequest {
+    fn example() -> Self {
+        Self {
+            include_expiring_products: Some(true),
+            expires_in_hours: Some(48),
+        }
+    }
+}
+
+impl Example for ShareLinkResponse {
+    fn example() -> Self {
+        Self {
+            id: "0d1e2f3a-4b5c-4d6e-8f9a-0b1c2d3e4f5a".to_string(),
+            token: "q3Vh8JmZp0xYcN2LtR7wK5sD1fG9bE4aU6iO8yT3rWk".to_string(),
+            url: "/shared/q3Vh8JmZp0xYcN2LtR7wK5sD1fG9bE4aU6iO8yT3rWk".to_string(),
+            include_expiring_products: true,
+            expires_at: example_date(),
+        }
+    }
+}
+
+impl Example for SharedShoppingItemResponse {
+    fn example() -> Self {
+        Self {
+            name: "Leche semidesnatada".to_string(),
+            is_bought: false,
+        }
+    }
+}
+
+impl Example for SharedProductResponse {
+    fn example() -> Self {
+        Self {
+            name: "Yogur natural".to_string(),
+            expiry_date: Some(example_date()),
+        }
+    }
+}
+
+impl Example for SharedViewResponse {
+    fn example() -> Self {
+        Self {
+            shopping_items: vec![SharedShoppingItemResponse::example()],
+            expiring_products: Some(vec![SharedProductResponse::example()]),
+            expires_at: example_date(),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::share_link::errors::ShareLinkError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for ShareLinkError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            ShareLinkError::InvalidExpiry => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "share_link.invalid_expiry",
+            ),
+            ShareLinkError::NotFound => (StatusCode::NOT_FOUND, "NotFound", "share_link.not_found"),
+            ShareLinkError::Expired => (StatusCode::GONE, "Gone", "share_link.expired"),
+            ShareLinkError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod error_mapper;
+pub mod routes;
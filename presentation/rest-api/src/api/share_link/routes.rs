@@ -0,0 +1,203 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::share_link::use_cases::create::{
+    CreateShareLinkParams, CreateShareLinkUseCase,
+};
+use business::domain::share_link::use_cases::get_shared_view::{
+    GetSharedViewParams, GetSharedViewUseCase,
+};
+use business::domain::share_link::use_cases::revoke::{
+    RevokeShareLinkParams, RevokeShareLinkUseCase,
+};
+use business::domain::shared::value_objects::UserId;
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::share_link::dto::{CreateShareLinkRequest, ShareLinkResponse, SharedViewResponse};
+use crate::api::tags::ApiTags;
+
+const DEFAULT_EXPIRES_IN_HOURS: i64 = 24;
+
+pub struct ShareLinkApi {
+    create_use_case: Arc<dyn CreateShareLinkUseCase>,
+    revoke_use_case: Arc<dyn RevokeShareLinkUseCase>,
+}
+
+impl ShareLinkApi {
+    pub fn new(
+        create_use_case: Arc<dyn CreateShareLinkUseCase>,
+        revoke_use_case: Arc<dyn RevokeShareLinkUseCase>,
+    ) -> Self {
+        Self {
+            create_use_case,
+            revoke_use_case,
+        }
+    }
+}
+
+/// Share link management API
+///
+/// Endpoints for issuing and revoking read-only share links.
+#[OpenApi]
+impl ShareLinkApi {
+    /// Create a share link
+    ///
+    /// Issues an expiring link that exposes a read-only view of the shopping
+    /// list (and optionally expiring products) without authentication. The
+    /// token is only returned in this response.
+    #[oai(path = "/share-links", method = "post", tag = "ApiTags::ShareLinks")]
+    async fn create(
+        &self,
+        auth: BearerAuth,
+        body: Json<CreateShareLinkRequest>,
+    ) -> CreateShareLinkResponse {
+        let user_id = UserId::new(auth.0);
+
+        let params = CreateShareLinkParams {
+            user_id,
+            include_expiring_products: body.0.include_expiring_products.unwrap_or(false),
+            expires_in_hours: body.0.expires_in_hours.unwrap_or(DEFAULT_EXPIRES_IN_HOURS),
+        };
+
+        match self.create_use_case.execute(params).await {
+            Ok(created) => CreateShareLinkResponse::Created(Json(created.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CreateShareLinkResponse::BadRequest(json),
+                    _ => CreateShareLinkResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Revoke a share link
+    ///
+    /// Immediately disables a share link.
+    #[oai(
+        path = "/share-links/:id",
+        method = "delete",
+        tag = "ApiTags::ShareLinks"
+    )]
+    async fn revoke(&self, auth: BearerAuth, id: Path<String>) -> RevokeShareLinkResponse {
+        let user_id = UserId::new(auth.0);
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return RevokeShareLinkResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "share_link.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .revoke_use_case
+            .execute(RevokeShareLinkParams { id: uuid, user_id })
+            .await
+        {
+            Ok(()) => RevokeShareLinkResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => RevokeShareLinkResponse::NotFound(json),
+                    _ => RevokeShareLinkResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+pub struct SharedViewApi {
+    get_shared_view_use_case: Arc<dyn GetSharedViewUseCase>,
+}
+
+impl SharedViewApi {
+    pub fn new(get_shared_view_use_case: Arc<dyn GetSharedViewUseCase>) -> Self {
+        Self {
+            get_shared_view_use_case,
+        }
+    }
+}
+
+/// Public shared view API
+///
+/// Unauthenticated, read-only endpoints scoped to a single share token.
+#[OpenApi]
+impl SharedViewApi {
+    /// Get a shared view
+    ///
+    /// Returns the shopping list behind a share token. No authentication is
+    /// required; the token itself grants access until it expires or is revoked.
+    #[oai(path = "/shared/:token", method = "get", tag = "ApiTags::Shared")]
+    async fn get_shared_view(&self, token: Path<String>) -> GetSharedViewResponse {
+        match self
+            .get_shared_view_use_case
+            .execute(GetSharedViewParams { token: token.0 })
+            .await
+        {
+            Ok(view) => GetSharedViewResponse::Ok(Json(view.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => GetSharedViewResponse::NotFound(json),
+                    410 => GetSharedViewResponse::Gone(json),
+                    _ => GetSharedViewResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateShareLinkResponse {
+    #[oai(status = 201)]
+    Created(Json<ShareLinkResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RevokeShareLinkResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+pub enum GetSharedViewResponse {
+    #[oai(status = 200)]
+    Ok(Json<SharedViewResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 410)]
+    Gone(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(CreateShareLinkResponse, RevokeShareLinkResponse,);
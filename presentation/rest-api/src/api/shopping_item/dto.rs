@@ -1,20 +1,41 @@
 use chrono::{DateTime, Utc};
-use poem_openapi::Object;
+use poem_openapi::{Enum, Object, types::Example};
 
-use business::domain::shopping_item::model::ShoppingItem;
+use business::domain::product::urgency::days_until_expiry;
+use business::domain::shopping_item::model::{ShoppingItem, ShoppingItemView};
+use business::domain::shopping_item::use_cases::get_all::ShoppingItemSort;
+use business::domain::storage::model::SignedUrl;
+
+use crate::api::examples::example_date;
+use crate::api::product::dto::{ProductStatusDto, UrgencyLevelDto};
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CreateShoppingItemFromBarcodeRequest {
+    /// Barcode string (e.g., EAN-13)
+    #[oai(validator(min_length = 1, max_length = 64))]
+    pub barcode: String,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct CreateShoppingItemRequest {
     /// Item name (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 255))]
     pub name: String,
     /// Optional associated product ID
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
     #[oai(skip_serializing_if_is_none)]
     pub product_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct UpdateShoppingItemRequest {
     /// New item name
+    #[oai(validator(min_length = 1, max_length = 255))]
     #[oai(skip_serializing_if_is_none)]
     pub name: Option<String>,
     /// Whether the item has been bought
@@ -22,21 +43,52 @@ pub struct UpdateShoppingItemRequest {
     pub is_bought: Option<bool>,
 }
 
+/// Photo or voice note to attach to a shopping item.
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct AttachToShoppingItemRequest {
+    /// Base64-encoded JPEG, PNG or WebP photo, or MP3, AAC, Ogg, WAV or WebM
+    /// voice note, optionally prefixed with a `data:...;base64,` header
+    #[oai(validator(
+        min_length = 1,
+        pattern = "^(data:[a-z]+/[a-z0-9.+-]+;base64,)?[A-Za-z0-9+/]+={0,2}$"
+    ))]
+    pub attachment_base64: String,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ShoppingItemResponse {
     /// Shopping item unique identifier
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
     pub id: String,
     /// Item name
     pub name: String,
     /// Associated product ID
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
     #[oai(skip_serializing_if_is_none)]
     pub product_id: Option<String>,
     /// Whether the item has been bought
     pub is_bought: bool,
+    /// Temporary URL of the attached photo or voice note; ask for the list
+    /// again once it expires
+    #[oai(skip_serializing_if_is_none)]
+    pub attachment_url: Option<String>,
+    /// Media type of the attachment, e.g. `image/jpeg` or `audio/ogg`
+    #[oai(skip_serializing_if_is_none)]
+    pub attachment_content_type: Option<String>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
     /// Last update timestamp
     pub updated_at: DateTime<Utc>,
+    /// Linked pantry product; only with `include=product`, and omitted when
+    /// the item has no product or it was deleted
+    #[oai(skip_serializing_if_is_none)]
+    pub product: Option<LinkedProductResponse>,
 }
 
 impl From<ShoppingItem> for ShoppingItemResponse {
@@ -46,14 +98,159 @@ impl From<ShoppingItem> for ShoppingItemResponse {
             name: item.name,
             product_id: item.product_id.map(|id| id.to_string()),
             is_bought: item.is_bought,
+            attachment_url: None,
+            attachment_content_type: item.attachment_content_type,
             created_at: item.created_at,
             updated_at: item.updated_at,
+            product: None,
+        }
+    }
+}
+
+impl From<ShoppingItemView> for ShoppingItemResponse {
+    fn from(view: ShoppingItemView) -> Self {
+        let urgency = view.product_urgency();
+        let product = view
+            .product
+            .as_ref()
+            .zip(urgency)
+            .map(|(product, urgency)| LinkedProductResponse {
+                name: product.name.clone(),
+                status: product.status.clone().into(),
+                urgency: urgency.into(),
+                days_until_expiry: days_until_expiry(product),
+            });
+        Self {
+            product,
+            ..view.item.into()
+        }
+    }
+}
+
+impl ShoppingItemResponse {
+    pub fn with_attachment_url(mut self, attachment: Option<SignedUrl>) -> Self {
+        self.attachment_url = attachment.map(|a| a.url);
+        self
+    }
+}
+
+/// State of the pantry product a shopping item is linked to, e.g. to warn
+/// that one is already at home and about to expire.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct LinkedProductResponse {
+    /// Product name
+    pub name: String,
+    /// Product status
+    pub status: ProductStatusDto,
+    /// How urgently the product should be used
+    pub urgency: UrgencyLevelDto,
+    /// Days until the product expires (real date, else the estimate);
+    /// negative when expired, omitted without any date
+    #[oai(skip_serializing_if_is_none)]
+    pub days_until_expiry: Option<i64>,
+}
+
+/// Related data to embed in shopping list responses.
+#[derive(Debug, Clone, Enum)]
+pub enum ShoppingItemIncludeDto {
+    /// The linked product's status and urgency
+    #[oai(rename = "product")]
+    Product,
+}
+
+/// Order of the shopping list.
+#[derive(Debug, Clone, Enum)]
+pub enum ShoppingItemSortDto {
+    /// Newest first
+    #[oai(rename = "created_at")]
+    CreatedAt,
+    /// Walking order of the active store profile
+    #[oai(rename = "store_order")]
+    StoreOrder,
+}
+
+impl From<ShoppingItemSortDto> for ShoppingItemSort {
+    fn from(dto: ShoppingItemSortDto) -> Self {
+        match dto {
+            ShoppingItemSortDto::CreatedAt => ShoppingItemSort::CreatedAt,
+            ShoppingItemSortDto::StoreOrder => ShoppingItemSort::StoreOrder,
         }
     }
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct ClearBoughtResponse {
     /// Number of items cleared
     pub count: u64,
 }
+
+// --- OpenAPI examples ---
+
+impl Example for CreateShoppingItemFromBarcodeRequest {
+    fn example() -> Self {
+        Self {
+            barcode: "8410188012092".to_string(),
+        }
+    }
+}
+
+impl Example for CreateShoppingItemRequest {
+    fn example() -> Self {
+        Self {
+            name: "Aceite de oliva virgen extra".to_string(),
+            product_id: Some("3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string()),
+        }
+    }
+}
+
+impl Example for UpdateShoppingItemRequest {
+    fn example() -> Self {
+        Self {
+            name: None,
+            is_bought: Some(true),
+        }
+    }
+}
+
+impl Example for AttachToShoppingItemRequest {
+    fn example() -> Self {
+        Self {
+            attachment_base64: "data:audio/ogg;base64,T2dnUwACAAAAAAAAAAA=".to_string(),
+        }
+    }
+}
+
+impl Example for ShoppingItemResponse {
+    fn example() -> Self {
+        Self {
+            id: "b7e6d5c4-3a2b-4c1d-8e9f-0a1b2c3d4e5f".to_string(),
+            name: "Aceite de oliva virgen extra".to_string(),
+            product_id: Some("3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string()),
+            is_bought: false,
+            attachment_url: Some("https://api.example.com/blobs?key=shopping_items%2Fuser-1%2Fb7e6d5c4-3a2b-4c1d-8e9f-0a1b2c3d4e5f&expires=1772355600&signature=7d1e…".to_string()),
+            attachment_content_type: Some("image/jpeg".to_string()),
+            created_at: example_date(),
+            updated_at: example_date(),
+            product: Some(LinkedProductResponse::example()),
+        }
+    }
+}
+
+impl Example for LinkedProductResponse {
+    fn example() -> Self {
+        Self {
+            name: "Aceite de oliva virgen extra".to_string(),
+            status: ProductStatusDto::AlmostEmpty,
+            urgency: UrgencyLevelDto::Ok,
+            days_until_expiry: Some(120),
+        }
+    }
+}
+
+impl Example for ClearBoughtResponse {
+    fn example() -> Self {
+        Self { count: 3 }
+    }
+}
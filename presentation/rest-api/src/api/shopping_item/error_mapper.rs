@@ -3,7 +3,8 @@ use poem_openapi::payload::Json;
 
 use business::domain::shopping_item::errors::ShoppingItemError;
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+use crate::api::storage::error_mapper::storage_error_parts;
 
 impl IntoErrorResponse for ShoppingItemError {
     fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
@@ -21,6 +22,17 @@ impl IntoErrorResponse for ShoppingItemError {
                 "Conflict",
                 "shopping_item.already_exists",
             ),
+            ShoppingItemError::BarcodeNotRecognized(_) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "IdentificationError",
+                "shopping_item.barcode_not_recognized",
+            ),
+            ShoppingItemError::InvalidAttachment => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "shopping_item.invalid_attachment",
+            ),
+            ShoppingItemError::Storage(err) => storage_error_parts(err),
             ShoppingItemError::Repository(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "InternalError",
@@ -28,11 +40,14 @@ impl IntoErrorResponse for ShoppingItemError {
             ),
         };
 
+        log_error_chain(status, &self);
+
         (
             status,
             Json(ErrorResponse {
                 name: name.to_string(),
                 message: message.to_string(),
+                description: None,
             }),
         )
     }
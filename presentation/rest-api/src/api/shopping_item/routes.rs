@@ -1,56 +1,118 @@
 use std::sync::Arc;
 
-use poem_openapi::{OpenApi, param::Path, payload::Json};
+use poem_openapi::{
+    OpenApi,
+    param::{Path, Query},
+    payload::Json,
+};
 use uuid::Uuid;
 
 use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_item::errors::ShoppingItemError;
+use business::domain::shopping_item::use_cases::attach::{
+    AttachToShoppingItemParams, AttachToShoppingItemUseCase,
+};
 use business::domain::shopping_item::use_cases::clear_bought::{
     ClearBoughtItemsParams, ClearBoughtItemsUseCase,
 };
 use business::domain::shopping_item::use_cases::create::{
     CreateShoppingItemParams, CreateShoppingItemUseCase,
 };
+use business::domain::shopping_item::use_cases::create_from_barcode::{
+    CreateShoppingItemFromBarcodeParams, CreateShoppingItemFromBarcodeUseCase,
+};
 use business::domain::shopping_item::use_cases::delete::{
     DeleteShoppingItemParams, DeleteShoppingItemUseCase,
 };
 use business::domain::shopping_item::use_cases::get_all::{
     GetAllShoppingItemsParams, GetAllShoppingItemsUseCase,
 };
+use business::domain::shopping_item::use_cases::get_attachment_urls::{
+    GetAttachmentUrlsParams, GetAttachmentUrlsUseCase,
+};
 use business::domain::shopping_item::use_cases::update::{
     UpdateShoppingItemParams, UpdateShoppingItemUseCase,
 };
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
-use crate::api::security::FirebaseBearer;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::payload_limit::{PayloadTooLargeResponse, payload_too_large};
+use crate::api::security::BearerAuth;
 use crate::api::shopping_item::dto::{
-    ClearBoughtResponse, CreateShoppingItemRequest, ShoppingItemResponse, UpdateShoppingItemRequest,
+    AttachToShoppingItemRequest, ClearBoughtResponse, CreateShoppingItemFromBarcodeRequest,
+    CreateShoppingItemRequest, ShoppingItemIncludeDto, ShoppingItemResponse, ShoppingItemSortDto,
+    UpdateShoppingItemRequest,
 };
+use crate::api::storage::dto::SignedUrlResponse;
 use crate::api::tags::ApiTags;
+use crate::config::payload_config::PayloadConfig;
 
 pub struct ShoppingItemApi {
     create_use_case: Arc<dyn CreateShoppingItemUseCase>,
+    create_from_barcode_use_case: Arc<dyn CreateShoppingItemFromBarcodeUseCase>,
     get_all_use_case: Arc<dyn GetAllShoppingItemsUseCase>,
     update_use_case: Arc<dyn UpdateShoppingItemUseCase>,
     delete_use_case: Arc<dyn DeleteShoppingItemUseCase>,
     clear_bought_use_case: Arc<dyn ClearBoughtItemsUseCase>,
+    attach_use_case: Arc<dyn AttachToShoppingItemUseCase>,
+    get_attachment_urls_use_case: Arc<dyn GetAttachmentUrlsUseCase>,
+    payload_config: PayloadConfig,
 }
 
 impl ShoppingItemApi {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         create_use_case: Arc<dyn CreateShoppingItemUseCase>,
+        create_from_barcode_use_case: Arc<dyn CreateShoppingItemFromBarcodeUseCase>,
         get_all_use_case: Arc<dyn GetAllShoppingItemsUseCase>,
         update_use_case: Arc<dyn UpdateShoppingItemUseCase>,
         delete_use_case: Arc<dyn DeleteShoppingItemUseCase>,
         clear_bought_use_case: Arc<dyn ClearBoughtItemsUseCase>,
+        attach_use_case: Arc<dyn AttachToShoppingItemUseCase>,
+        get_attachment_urls_use_case: Arc<dyn GetAttachmentUrlsUseCase>,
+        payload_config: PayloadConfig,
     ) -> Self {
         Self {
             create_use_case,
+            create_from_barcode_use_case,
             get_all_use_case,
             update_use_case,
             delete_use_case,
             clear_bought_use_case,
+            attach_use_case,
+            get_attachment_urls_use_case,
+            payload_config,
         }
     }
+
+    /// Turns items into responses carrying a download URL for their
+    /// attachments.
+    async fn with_attachment_urls(
+        &self,
+        user_id: UserId,
+        items: Vec<ShoppingItemResponse>,
+    ) -> Result<Vec<ShoppingItemResponse>, ShoppingItemError> {
+        let item_ids = items
+            .iter()
+            .filter(|item| item.attachment_content_type.is_some())
+            .filter_map(|item| Uuid::parse_str(&item.id).ok())
+            .collect();
+        let mut urls = self
+            .get_attachment_urls_use_case
+            .execute(GetAttachmentUrlsParams { user_id, item_ids })
+            .await?;
+
+        Ok(items
+            .into_iter()
+            .map(|item| {
+                let url = Uuid::parse_str(&item.id)
+                    .ok()
+                    .and_then(|id| urls.remove(&id));
+                item.with_attachment_url(url)
+            })
+            .collect())
+    }
 }
 
 /// Shopping list management API
@@ -60,22 +122,41 @@ impl ShoppingItemApi {
 impl ShoppingItemApi {
     /// List all shopping items
     ///
-    /// Returns all shopping list items ordered by creation date.
+    /// Returns all shopping list items, newest first. With `sort=store_order`
+    /// items follow the aisles of the active store profile; items matching no
+    /// aisle come last, and without an active store the default order is kept.
+    /// With `include=product` each item also carries its linked product's
+    /// status and expiry urgency.
     #[oai(
         path = "/shopping-items",
         method = "get",
         tag = "ApiTags::ShoppingItems"
     )]
-    async fn get_all(&self, auth: FirebaseBearer) -> GetAllShoppingItemsResponse {
+    async fn get_all(
+        &self,
+        auth: BearerAuth,
+        /// Order of the list (default: created_at)
+        sort: Query<Option<ShoppingItemSortDto>>,
+        /// Related data to embed (product)
+        include: Query<Option<ShoppingItemIncludeDto>>,
+    ) -> GetAllShoppingItemsResponse {
         let user_id = UserId::new(auth.0);
-        let params = GetAllShoppingItemsParams { user_id };
+        let params = GetAllShoppingItemsParams {
+            user_id: user_id.clone(),
+            sort: sort.0.map(|s| s.into()).unwrap_or_default(),
+            include_product: matches!(include.0, Some(ShoppingItemIncludeDto::Product)),
+        };
 
-        match self.get_all_use_case.execute(params).await {
+        let result = match self.get_all_use_case.execute(params).await {
             Ok(items) => {
-                let responses: Vec<ShoppingItemResponse> =
-                    items.into_iter().map(|i| i.into()).collect();
-                GetAllShoppingItemsResponse::Ok(Json(responses))
+                let responses = items.into_iter().map(|i| i.into()).collect();
+                self.with_attachment_urls(user_id, responses).await
             }
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(responses) => GetAllShoppingItemsResponse::Ok(Json(responses)),
             Err(err) => {
                 let (_status, json) = err.into_error_response();
                 GetAllShoppingItemsResponse::InternalError(json)
@@ -94,7 +175,7 @@ impl ShoppingItemApi {
     )]
     async fn create(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         body: Json<CreateShoppingItemRequest>,
     ) -> CreateShoppingItemResponse {
         let user_id = UserId::new(auth.0);
@@ -106,6 +187,7 @@ impl ShoppingItemApi {
                     return CreateShoppingItemResponse::BadRequest(Json(ErrorResponse {
                         name: "ValidationError".to_string(),
                         message: "shopping_item.invalid_product_id".to_string(),
+                        description: None,
                     }));
                 }
             },
@@ -130,6 +212,43 @@ impl ShoppingItemApi {
         }
     }
 
+    /// Add a shopping item by barcode
+    ///
+    /// Identifies the scanned barcode and adds it to the shopping list. When a
+    /// pantry product has the identified name, the item is linked to it (and an
+    /// unbought item already linked to that product is returned instead).
+    #[oai(
+        path = "/shopping-items/from-barcode",
+        method = "post",
+        tag = "ApiTags::ShoppingItems"
+    )]
+    async fn create_from_barcode(
+        &self,
+        auth: BearerAuth,
+        body: Json<CreateShoppingItemFromBarcodeRequest>,
+    ) -> CreateShoppingItemFromBarcodeResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .create_from_barcode_use_case
+            .execute(CreateShoppingItemFromBarcodeParams {
+                user_id,
+                barcode: body.0.barcode,
+            })
+            .await
+        {
+            Ok(item) => CreateShoppingItemFromBarcodeResponse::Created(Json(item.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CreateShoppingItemFromBarcodeResponse::BadRequest(json),
+                    422 => CreateShoppingItemFromBarcodeResponse::UnprocessableEntity(json),
+                    _ => CreateShoppingItemFromBarcodeResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
     /// Update a shopping item
     ///
     /// Updates the name and/or bought status of a shopping item.
@@ -140,7 +259,7 @@ impl ShoppingItemApi {
     )]
     async fn update(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         id: Path<String>,
         body: Json<UpdateShoppingItemRequest>,
     ) -> UpdateShoppingItemResponse {
@@ -152,30 +271,96 @@ impl ShoppingItemApi {
                 return UpdateShoppingItemResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "shopping_item.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
 
         let params = UpdateShoppingItemParams {
-            user_id,
+            user_id: user_id.clone(),
             id: uuid,
             name: body.0.name,
             is_bought: body.0.is_bought,
         };
 
-        match self.update_use_case.execute(params).await {
-            Ok(item) => UpdateShoppingItemResponse::Ok(Json(item.into())),
+        let result = match self.update_use_case.execute(params).await {
+            Ok(item) => self
+                .with_attachment_urls(user_id, vec![item.into()])
+                .await
+                .map(|mut items| items.remove(0)),
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(item) => UpdateShoppingItemResponse::Ok(Json(item)),
             Err(err) => {
                 let (status, json) = err.into_error_response();
                 match status.as_u16() {
                     400 => UpdateShoppingItemResponse::BadRequest(json),
                     404 => UpdateShoppingItemResponse::NotFound(json),
+                    409 => UpdateShoppingItemResponse::Conflict(json),
                     _ => UpdateShoppingItemResponse::InternalError(json),
                 }
             }
         }
     }
 
+    /// Attach a photo or voice note to a shopping item
+    ///
+    /// Stores a small photo (JPEG, PNG or WebP) or voice note (MP3, AAC, Ogg,
+    /// WAV or WebM) with the item, replacing any previous one, and returns a
+    /// temporary URL to it. Listed items carry the URL as `attachmentUrl`. The
+    /// attachment is deleted with the item. Bodies over `ATTACHMENT_MAX_BYTES`
+    /// are rejected with `413`.
+    #[oai(
+        path = "/shopping-items/:id/attachment",
+        method = "put",
+        tag = "ApiTags::ShoppingItems"
+    )]
+    async fn attach(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        body: Json<AttachToShoppingItemRequest>,
+    ) -> AttachToShoppingItemResponse {
+        let max_bytes = self.payload_config.attachment_max_bytes;
+        if body.0.attachment_base64.len() > max_bytes {
+            return AttachToShoppingItemResponse::PayloadTooLarge(payload_too_large(max_bytes));
+        }
+
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return AttachToShoppingItemResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "shopping_item.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .attach_use_case
+            .execute(AttachToShoppingItemParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+                attachment_base64: body.0.attachment_base64,
+            })
+            .await
+        {
+            Ok(signed) => AttachToShoppingItemResponse::Ok(Json(signed.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => AttachToShoppingItemResponse::BadRequest(json),
+                    404 => AttachToShoppingItemResponse::NotFound(json),
+                    503 => AttachToShoppingItemResponse::ServiceUnavailable(json),
+                    _ => AttachToShoppingItemResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
     /// Delete a shopping item
     ///
     /// Permanently removes a shopping item from the list.
@@ -184,7 +369,7 @@ impl ShoppingItemApi {
         method = "delete",
         tag = "ApiTags::ShoppingItems"
     )]
-    async fn delete(&self, auth: FirebaseBearer, id: Path<String>) -> DeleteShoppingItemResponse {
+    async fn delete(&self, auth: BearerAuth, id: Path<String>) -> DeleteShoppingItemResponse {
         let user_id = UserId::new(auth.0);
 
         let uuid = match Uuid::parse_str(&id.0) {
@@ -193,6 +378,7 @@ impl ShoppingItemApi {
                 return DeleteShoppingItemResponse::BadRequest(Json(ErrorResponse {
                     name: "ValidationError".to_string(),
                     message: "shopping_item.invalid_id".to_string(),
+                    description: None,
                 }));
             }
         };
@@ -221,7 +407,7 @@ impl ShoppingItemApi {
         method = "delete",
         tag = "ApiTags::ShoppingItems"
     )]
-    async fn clear_bought(&self, auth: FirebaseBearer) -> ClearBoughtItemsResponse {
+    async fn clear_bought(&self, auth: BearerAuth) -> ClearBoughtItemsResponse {
         let user_id = UserId::new(auth.0);
         let params = ClearBoughtItemsParams { user_id };
 
@@ -236,16 +422,22 @@ impl ShoppingItemApi {
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum GetAllShoppingItemsResponse {
     #[oai(status = 200)]
     Ok(Json<Vec<ShoppingItemResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum CreateShoppingItemResponse {
     #[oai(status = 201)]
     Created(Json<ShoppingItemResponse>),
@@ -253,11 +445,31 @@ pub enum CreateShoppingItemResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateShoppingItemFromBarcodeResponse {
+    #[oai(status = 201)]
+    Created(Json<ShoppingItemResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum UpdateShoppingItemResponse {
     #[oai(status = 200)]
     Ok(Json<ShoppingItemResponse>),
@@ -265,13 +477,39 @@ pub enum UpdateShoppingItemResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
+    #[oai(status = 409)]
+    Conflict(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum AttachToShoppingItemResponse {
+    #[oai(status = 200)]
+    Ok(Json<SignedUrlResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 413)]
+    PayloadTooLarge(Json<PayloadTooLargeResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum DeleteShoppingItemResponse {
     #[oai(status = 204)]
     NoContent,
@@ -279,6 +517,8 @@ pub enum DeleteShoppingItemResponse {
     BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 404)]
     NotFound(Json<ErrorResponse>),
     #[oai(status = 500)]
@@ -286,11 +526,26 @@ pub enum DeleteShoppingItemResponse {
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum ClearBoughtItemsResponse {
     #[oai(status = 200)]
     Ok(Json<ClearBoughtResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
 }
+
+impl_request_error_response!(
+    GetAllShoppingItemsResponse,
+    CreateShoppingItemResponse,
+    CreateShoppingItemFromBarcodeResponse,
+    UpdateShoppingItemResponse,
+    AttachToShoppingItemResponse,
+    DeleteShoppingItemResponse,
+    ClearBoughtItemsResponse,
+);
@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Enum, Object, types::Example};
+use serde::{Deserialize, Serialize};
+
+use business::domain::shopping_trip::model::{
+    ShoppingTrip, ShoppingTripStatus, TripItem, TripSummary,
+};
+
+use crate::api::examples::example_date;
+
+/// Lifecycle state of a shopping trip.
+#[derive(Debug, Clone, Serialize, Deserialize, Enum)]
+pub enum ShoppingTripStatusDto {
+    /// Still shopping; can be resumed
+    #[oai(rename = "in_progress")]
+    InProgress,
+    /// Finished; the summary is final
+    #[oai(rename = "finished")]
+    Finished,
+}
+
+impl From<ShoppingTripStatus> for ShoppingTripStatusDto {
+    fn from(status: ShoppingTripStatus) -> Self {
+        match status {
+            ShoppingTripStatus::InProgress => ShoppingTripStatusDto::InProgress,
+            ShoppingTripStatus::Finished => ShoppingTripStatusDto::Finished,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct RecordTripItemRequest {
+    /// What was paid for the item, in cents
+    #[oai(skip_serializing_if_is_none)]
+    pub price_cents: Option<u32>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct TripItemResponse {
+    /// Shopping list item the entry was taken from
+    pub shopping_item_id: String,
+    /// Item name when the trip started
+    pub name: String,
+    /// When the item was bought during the trip
+    #[oai(skip_serializing_if_is_none)]
+    pub bought_at: Option<DateTime<Utc>>,
+    /// What was paid for the item, in cents
+    #[oai(skip_serializing_if_is_none)]
+    pub price_cents: Option<u32>,
+}
+
+impl From<TripItem> for TripItemResponse {
+    fn from(i: TripItem) -> Self {
+        Self {
+            shopping_item_id: i.shopping_item_id.to_string(),
+            name: i.name,
+            bought_at: i.bought_at,
+            price_cents: i.price_cents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct TripSummaryResponse {
+    /// Items on the list when the trip started
+    pub items_planned: u32,
+    /// Items bought during the trip
+    pub items_bought: u32,
+    /// Sum of the recorded prices, in cents
+    pub total_spend_cents: u64,
+}
+
+impl From<TripSummary> for TripSummaryResponse {
+    fn from(s: TripSummary) -> Self {
+        Self {
+            items_planned: s.items_planned,
+            items_bought: s.items_bought,
+            total_spend_cents: s.total_spend_cents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ShoppingTripResponse {
+    /// Trip unique identifier
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
+    pub id: String,
+    /// Snapshot of the list taken when the trip started
+    pub items: Vec<TripItemResponse>,
+    /// Totals so far; final once the trip is finished
+    pub summary: TripSummaryResponse,
+    /// Trip state
+    pub status: ShoppingTripStatusDto,
+    /// Start timestamp
+    pub started_at: DateTime<Utc>,
+    /// Finish timestamp
+    #[oai(skip_serializing_if_is_none)]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+impl From<ShoppingTrip> for ShoppingTripResponse {
+    fn from(t: ShoppingTrip) -> Self {
+        let summary = t.summary().into();
+        Self {
+            id: t.id.to_string(),
+            items: t.items.into_iter().map(|i| i.into()).collect(),
+            summary,
+            status: t.status.into(),
+            started_at: t.started_at,
+            finished_at: t.finished_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for RecordTripItemRequest {
+    fn example() -> Self {
+        Self {
+            price_cents: Some(115),
+        }
+    }
+}
+
+impl Example for TripItemResponse {
+    fn example() -> Self {
+        Self {
+            shopping_item_id: "3f2b1c4d-5e6f-4a7b-8c9d-0e1f2a3b4c5d".to_string(),
+            name: "Leche".to_string(),
+            bought_at: Some(example_date()),
+            price_cents: Some(115),
+        }
+    }
+}
+
+impl Example for TripSummaryResponse {
+    fn example() -> Self {
+        Self {
+            items_planned: 2,
+            items_bought: 1,
+            total_spend_cents: 115,
+        }
+    }
+}
+
+impl Example for ShoppingTripResponse {
+    fn example() -> Self {
+        Self {
+            id: "7d6c5b4a-3f2e-4d1c-8b0a-9f8e7d6c5b4a".to_string(),
+            items: vec![
+                TripItemResponse::example(),
+                TripItemResponse {
+                    shopping_item_id: "4a3b2c1d-6e5f-4b8a-9d0c-1f2e3a4b5c6d".to_string(),
+                    name: "Pan".to_string(),
+                    bought_at: None,
+                    price_cents: None,
+                },
+            ],
+            summary: TripSummaryResponse::example(),
+            status: ShoppingTripStatusDto::InProgress,
+            started_at: example_date(),
+            finished_at: None,
+        }
+    }
+}
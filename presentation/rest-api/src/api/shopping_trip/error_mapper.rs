@@ -0,0 +1,44 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::shopping_trip::errors::ShoppingTripError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for ShoppingTripError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            ShoppingTripError::NotInProgress => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "shopping_trip.not_in_progress",
+            ),
+            ShoppingTripError::EmptyList => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "shopping_trip.empty_list",
+            ),
+            ShoppingTripError::ItemNotInTrip => (
+                StatusCode::NOT_FOUND,
+                "NotFound",
+                "shopping_trip.item_not_in_trip",
+            ),
+            ShoppingTripError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::shopping_trip::use_cases::finish::{
+    FinishShoppingTripParams, FinishShoppingTripUseCase,
+};
+use business::domain::shopping_trip::use_cases::record_item::{
+    RecordTripItemParams, RecordTripItemUseCase,
+};
+use business::domain::shopping_trip::use_cases::start::{
+    StartShoppingTripParams, StartShoppingTripUseCase,
+};
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::shopping_trip::dto::{RecordTripItemRequest, ShoppingTripResponse};
+use crate::api::tags::ApiTags;
+
+pub struct ShoppingTripApi {
+    start_use_case: Arc<dyn StartShoppingTripUseCase>,
+    record_item_use_case: Arc<dyn RecordTripItemUseCase>,
+    finish_use_case: Arc<dyn FinishShoppingTripUseCase>,
+}
+
+impl ShoppingTripApi {
+    pub fn new(
+        start_use_case: Arc<dyn StartShoppingTripUseCase>,
+        record_item_use_case: Arc<dyn RecordTripItemUseCase>,
+        finish_use_case: Arc<dyn FinishShoppingTripUseCase>,
+    ) -> Self {
+        Self {
+            start_use_case,
+            record_item_use_case,
+            finish_use_case,
+        }
+    }
+}
+
+/// Shopping trip API
+///
+/// Endpoints for going through the shopping list at the store.
+#[OpenApi]
+impl ShoppingTripApi {
+    /// Start a shopping trip
+    ///
+    /// Snapshots the items of the shopping list not bought yet. If a trip is
+    /// already in progress, it is returned so the user can resume it.
+    #[oai(
+        path = "/shopping-trips/start",
+        method = "post",
+        tag = "ApiTags::ShoppingTrips"
+    )]
+    async fn start(&self, auth: BearerAuth) -> StartShoppingTripResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .start_use_case
+            .execute(StartShoppingTripParams { user_id })
+            .await
+        {
+            Ok(trip) => StartShoppingTripResponse::Ok(Json(trip.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    422 => StartShoppingTripResponse::UnprocessableEntity(json),
+                    _ => StartShoppingTripResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Record an item as bought
+    ///
+    /// Marks an item of the trip in progress as bought now, with an optional
+    /// price, and marks it as bought on the shopping list as well. Recording an
+    /// item again replaces its price.
+    #[oai(
+        path = "/shopping-trips/items/:item_id/bought",
+        method = "post",
+        tag = "ApiTags::ShoppingTrips"
+    )]
+    async fn record_item(
+        &self,
+        auth: BearerAuth,
+        item_id: Path<String>,
+        body: Json<RecordTripItemRequest>,
+    ) -> RecordTripItemResponse {
+        let user_id = UserId::new(auth.0);
+
+        let shopping_item_id = match Uuid::parse_str(&item_id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return RecordTripItemResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "shopping_item.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .record_item_use_case
+            .execute(RecordTripItemParams {
+                user_id,
+                shopping_item_id,
+                price_cents: body.0.price_cents,
+            })
+            .await
+        {
+            Ok(trip) => RecordTripItemResponse::Ok(Json(trip.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => RecordTripItemResponse::NotFound(json),
+                    _ => RecordTripItemResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Finish the shopping trip
+    ///
+    /// Closes the trip in progress and stores its summary (items bought and
+    /// total spend) in the user's trip history.
+    #[oai(
+        path = "/shopping-trips/finish",
+        method = "post",
+        tag = "ApiTags::ShoppingTrips"
+    )]
+    async fn finish(&self, auth: BearerAuth) -> FinishShoppingTripResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .finish_use_case
+            .execute(FinishShoppingTripParams { user_id })
+            .await
+        {
+            Ok(trip) => FinishShoppingTripResponse::Ok(Json(trip.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => FinishShoppingTripResponse::NotFound(json),
+                    _ => FinishShoppingTripResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum StartShoppingTripResponse {
+    #[oai(status = 200)]
+    Ok(Json<ShoppingTripResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RecordTripItemResponse {
+    #[oai(status = 200)]
+    Ok(Json<ShoppingTripResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum FinishShoppingTripResponse {
+    #[oai(status = 200)]
+    Ok(Json<ShoppingTripResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    StartShoppingTripResponse,
+    RecordTripItemResponse,
+    FinishShoppingTripResponse,
+);
@@ -0,0 +1,319 @@
+use chrono::NaiveDate;
+use poem_openapi::{Object, types::Example};
+
+use business::domain::stats::model::{
+    InventorySnapshot, InventoryValue, LocationCounts, MonthlyOutcomes, ProductValue, StatusCounts,
+    WasteStreakHistory, WeeklyOutcomes,
+};
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ProductValueResponse {
+    /// Products in the group
+    pub products: u32,
+    /// Products a recorded price was found for
+    pub priced_products: u32,
+    /// Estimated value, in minor units of the currency
+    pub value_cents: u64,
+}
+
+impl From<ProductValue> for ProductValueResponse {
+    fn from(v: ProductValue) -> Self {
+        Self {
+            products: v.products,
+            priced_products: v.priced_products,
+            value_cents: v.value_cents,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct MonthlyOutcomesResponse {
+    /// First day of the month (UTC)
+    pub month: NaiveDate,
+    /// Products thrown away that month
+    pub wasted: ProductValueResponse,
+    /// Products given away that month
+    pub given_away: ProductValueResponse,
+}
+
+impl From<MonthlyOutcomes> for MonthlyOutcomesResponse {
+    fn from(m: MonthlyOutcomes) -> Self {
+        Self {
+            month: m.month,
+            wasted: m.wasted.into(),
+            given_away: m.given_away.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct InventoryValueResponse {
+    /// ISO 4217 code of the values
+    pub currency: String,
+    /// Products not finished yet
+    pub active: ProductValueResponse,
+    /// Food thrown away and given away per month, oldest first
+    pub by_month: Vec<MonthlyOutcomesResponse>,
+}
+
+impl InventoryValueResponse {
+    pub fn new(value: InventoryValue, currency: String) -> Self {
+        Self {
+            currency,
+            active: value.active.into(),
+            by_month: value.by_month.into_iter().map(|m| m.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct StatusCountsResponse {
+    pub new: u32,
+    pub opened: u32,
+    pub almost_empty: u32,
+}
+
+impl From<StatusCounts> for StatusCountsResponse {
+    fn from(c: StatusCounts) -> Self {
+        Self {
+            new: c.new,
+            opened: c.opened,
+            almost_empty: c.almost_empty,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct LocationCountsResponse {
+    pub fridge: u32,
+    pub pantry: u32,
+    pub freezer: u32,
+    /// Products with no location set
+    pub unassigned: u32,
+}
+
+impl From<LocationCounts> for LocationCountsResponse {
+    fn from(c: LocationCounts) -> Self {
+        Self {
+            fridge: c.fridge,
+            pantry: c.pantry,
+            freezer: c.freezer,
+            unassigned: c.unassigned,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct InventorySnapshotResponse {
+    /// Day the snapshot was taken (UTC)
+    pub date: NaiveDate,
+    /// Active products by status
+    pub by_status: StatusCountsResponse,
+    /// Active products by location
+    pub by_location: LocationCountsResponse,
+    /// Products to use today or within the expiring-soon window
+    pub urgent: u32,
+    /// Food thrown away up to that day
+    pub wasted_to_date: ProductValueResponse,
+    /// The user was on vacation that day
+    pub on_vacation: bool,
+}
+
+impl From<InventorySnapshot> for InventorySnapshotResponse {
+    fn from(s: InventorySnapshot) -> Self {
+        Self {
+            date: s.date,
+            by_status: s.by_status.into(),
+            by_location: s.by_location.into(),
+            urgent: s.urgent,
+            wasted_to_date: s.wasted_to_date.into(),
+            on_vacation: s.on_vacation,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct InventoryTrendsResponse {
+    /// ISO 4217 code of the values
+    pub currency: String,
+    /// One snapshot per recorded day, oldest first
+    pub snapshots: Vec<InventorySnapshotResponse>,
+}
+
+impl InventoryTrendsResponse {
+    pub fn new(snapshots: Vec<InventorySnapshot>, currency: String) -> Self {
+        Self {
+            currency,
+            snapshots: snapshots.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct WeeklyOutcomesResponse {
+    /// Monday the week starts on (UTC)
+    pub week: NaiveDate,
+    pub used: u32,
+    pub thrown_away: u32,
+    pub given_away: u32,
+    /// Share of the finished products that were thrown away, 0 to 1
+    pub waste_ratio: f64,
+    /// Products were finished and none thrown away
+    pub zero_waste: bool,
+}
+
+impl From<WeeklyOutcomes> for WeeklyOutcomesResponse {
+    fn from(w: WeeklyOutcomes) -> Self {
+        Self {
+            week: w.week,
+            used: w.used,
+            thrown_away: w.thrown_away,
+            given_away: w.given_away,
+            waste_ratio: w.waste_ratio(),
+            zero_waste: w.zero_waste(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct WasteStreakResponse {
+    /// Zero-waste weeks in a row, up to the last closed week
+    pub current_weeks: u32,
+    /// Longest run of zero-waste weeks so far
+    pub longest_weeks: u32,
+    /// Monday of the last week counted; missing until the nightly job runs
+    pub last_week: Option<NaiveDate>,
+    /// Recent closed weeks, oldest first
+    pub weeks: Vec<WeeklyOutcomesResponse>,
+}
+
+impl From<WasteStreakHistory> for WasteStreakResponse {
+    fn from(h: WasteStreakHistory) -> Self {
+        Self {
+            current_weeks: h.streak.current,
+            longest_weeks: h.streak.longest,
+            last_week: h.streak.last_week,
+            weeks: h.weeks.into_iter().map(|w| w.into()).collect(),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for ProductValueResponse {
+    fn example() -> Self {
+        Self {
+            products: 18,
+            priced_products: 12,
+            value_cents: 4235,
+        }
+    }
+}
+
+impl Example for MonthlyOutcomesResponse {
+    fn example() -> Self {
+        Self {
+            month: NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            wasted: ProductValueResponse {
+                products: 2,
+                priced_products: 1,
+                value_cents: 189,
+            },
+            given_away: ProductValueResponse {
+                products: 1,
+                priced_products: 1,
+                value_cents: 245,
+            },
+        }
+    }
+}
+
+impl Example for InventoryValueResponse {
+    fn example() -> Self {
+        Self {
+            currency: "EUR".to_string(),
+            active: ProductValueResponse::example(),
+            by_month: vec![MonthlyOutcomesResponse::example()],
+        }
+    }
+}
+
+impl Example for StatusCountsResponse {
+    fn example() -> Self {
+        Self {
+            new: 9,
+            opened: 6,
+            almost_empty: 3,
+        }
+    }
+}
+
+impl Example for LocationCountsResponse {
+    fn example() -> Self {
+        Self {
+            fridge: 8,
+            pantry: 7,
+            freezer: 2,
+            unassigned: 1,
+        }
+    }
+}
+
+impl Example for InventorySnapshotResponse {
+    fn example() -> Self {
+        Self {
+            date: NaiveDate::from_ymd_opt(2026, 3, 14).unwrap(),
+            by_status: StatusCountsResponse::example(),
+            by_location: LocationCountsResponse::example(),
+            urgent: 4,
+            wasted_to_date: ProductValueResponse {
+                products: 11,
+                priced_products: 7,
+                value_cents: 1630,
+            },
+            on_vacation: false,
+        }
+    }
+}
+
+impl Example for InventoryTrendsResponse {
+    fn example() -> Self {
+        Self {
+            currency: "EUR".to_string(),
+            snapshots: vec![InventorySnapshotResponse::example()],
+        }
+    }
+}
+
+impl Example for WeeklyOutcomesResponse {
+    fn example() -> Self {
+        Self {
+            week: NaiveDate::from_ymd_opt(2026, 3, 9).unwrap(),
+            used: 7,
+            thrown_away: 0,
+            given_away: 1,
+            waste_ratio: 0.0,
+            zero_waste: true,
+        }
+    }
+}
+
+impl Example for WasteStreakResponse {
+    fn example() -> Self {
+        Self {
+            current_weeks: 3,
+            longest_weeks: 5,
+            last_week: NaiveDate::from_ymd_opt(2026, 3, 9),
+            weeks: vec![WeeklyOutcomesResponse::example()],
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Query, payload::Json};
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::stats::model::{TrendPeriod, WastePeriod};
+use business::domain::stats::use_cases::get_inventory_trends::{
+    GetInventoryTrendsParams, GetInventoryTrendsUseCase,
+};
+use business::domain::stats::use_cases::get_inventory_value::{
+    GetInventoryValueParams, GetInventoryValueUseCase,
+};
+use business::domain::stats::use_cases::get_waste_streak::{
+    GetWasteStreakParams, GetWasteStreakUseCase,
+};
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::stats::dto::{
+    InventoryTrendsResponse, InventoryValueResponse, WasteStreakResponse,
+};
+use crate::api::tags::ApiTags;
+use crate::config::stats_config::StatsConfig;
+
+pub struct StatsApi {
+    get_inventory_value_use_case: Arc<dyn GetInventoryValueUseCase>,
+    get_inventory_trends_use_case: Arc<dyn GetInventoryTrendsUseCase>,
+    get_waste_streak_use_case: Arc<dyn GetWasteStreakUseCase>,
+    config: StatsConfig,
+}
+
+impl StatsApi {
+    pub fn new(
+        get_inventory_value_use_case: Arc<dyn GetInventoryValueUseCase>,
+        get_inventory_trends_use_case: Arc<dyn GetInventoryTrendsUseCase>,
+        get_waste_streak_use_case: Arc<dyn GetWasteStreakUseCase>,
+        config: StatsConfig,
+    ) -> Self {
+        Self {
+            get_inventory_value_use_case,
+            get_inventory_trends_use_case,
+            get_waste_streak_use_case,
+            config,
+        }
+    }
+}
+
+/// Stats API
+///
+/// Aggregated figures about the user's pantry.
+#[OpenApi]
+impl StatsApi {
+    /// Get inventory value
+    ///
+    /// Estimates what the active products are worth and how much food was
+    /// thrown away and given away each month. Products are valued at the latest price recorded
+    /// for an item with the same name on a shopping trip; `priced_products`
+    /// tells how many of them had one.
+    #[oai(
+        path = "/stats/inventory-value",
+        method = "get",
+        tag = "ApiTags::Stats"
+    )]
+    async fn get_inventory_value(
+        &self,
+        auth: BearerAuth,
+        /// Months of history, the current one included (default: 6, max: 24)
+        months: Query<Option<u32>>,
+    ) -> GetInventoryValueResponse {
+        let period = months.0.map(WastePeriod::new).unwrap_or_default();
+
+        match self
+            .get_inventory_value_use_case
+            .execute(GetInventoryValueParams {
+                user_id: UserId::new(auth.0),
+                period,
+            })
+            .await
+        {
+            Ok(value) => GetInventoryValueResponse::Ok(Json(InventoryValueResponse::new(
+                value,
+                self.config.currency.clone(),
+            ))),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetInventoryValueResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Get inventory trends
+    ///
+    /// Daily pantry snapshots for charts: active products by status and
+    /// location, urgent products and food thrown away to date. Snapshots are
+    /// recorded by a nightly job, so days before the user's first run or
+    /// while the job was down are missing.
+    #[oai(path = "/stats/trends", method = "get", tag = "ApiTags::Stats")]
+    async fn get_inventory_trends(
+        &self,
+        auth: BearerAuth,
+        /// Days of history, today included (default: 30, max: 365)
+        days: Query<Option<u32>>,
+    ) -> GetInventoryTrendsResponse {
+        let period = days.0.map(TrendPeriod::new).unwrap_or_default();
+
+        match self
+            .get_inventory_trends_use_case
+            .execute(GetInventoryTrendsParams {
+                user_id: UserId::new(auth.0),
+                period,
+            })
+            .await
+        {
+            Ok(snapshots) => GetInventoryTrendsResponse::Ok(Json(InventoryTrendsResponse::new(
+                snapshots,
+                self.config.currency.clone(),
+            ))),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetInventoryTrendsResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Get zero-waste streaks
+    ///
+    /// Weeks in a row, Monday to Sunday (UTC), where products were finished
+    /// and none thrown away, with the waste ratio of the last 12 weeks. Weeks
+    /// are closed by a nightly job, so the current week is not counted yet;
+    /// a week where nothing was finished ends the streak.
+    #[oai(path = "/stats/streaks", method = "get", tag = "ApiTags::Stats")]
+    async fn get_waste_streak(&self, auth: BearerAuth) -> GetWasteStreakResponse {
+        match self
+            .get_waste_streak_use_case
+            .execute(GetWasteStreakParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(history) => GetWasteStreakResponse::Ok(Json(history.into())),
+            Err(err) => {
+                let (_, json) = err.into_error_response();
+                GetWasteStreakResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetInventoryValueResponse {
+    #[oai(status = 200)]
+    Ok(Json<InventoryValueResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetInventoryValueResponse);
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetInventoryTrendsResponse {
+    #[oai(status = 200)]
+    Ok(Json<InventoryTrendsResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetInventoryTrendsResponse);
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetWasteStreakResponse {
+    #[oai(status = 200)]
+    Ok(Json<WasteStreakResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetWasteStreakResponse);
@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::storage::model::{ImageLinks, SignedUrl};
+
+use crate::api::examples::example_date;
+
+/// Temporary link to a stored photo.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SignedUrlResponse {
+    /// Download URL; needs no token and stops working at `expiresAt`
+    pub url: String,
+    /// When the URL expires; ask for a new one after that
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<SignedUrl> for SignedUrlResponse {
+    fn from(signed: SignedUrl) -> Self {
+        Self {
+            url: signed.url,
+            expires_at: signed.expires_at,
+        }
+    }
+}
+
+/// Temporary links to a stored photo and its thumbnail.
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct ImageLinksResponse {
+    /// Download URL of the original photo; needs no token and stops working
+    /// at `expiresAt`
+    pub url: String,
+    /// Download URL of a small JPEG of the photo, expiring at the same time.
+    /// Omitted while the thumbnail is still being made
+    #[oai(skip_serializing_if_is_none)]
+    pub thumbnail_url: Option<String>,
+    /// When the URLs expire; ask for new ones after that
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<ImageLinks> for ImageLinksResponse {
+    fn from(links: ImageLinks) -> Self {
+        Self {
+            url: links.original.url,
+            thumbnail_url: links.thumbnail.map(|t| t.url),
+            expires_at: links.original.expires_at,
+        }
+    }
+}
+
+impl Example for SignedUrlResponse {
+    fn example() -> Self {
+        Self {
+            url: "https://api.example.com/blobs?key=products%2Fuser-1%2Fb3e1c2d4-5f6a-4b7c-8d9e-0a1b2c3d4e5f&expires=1772355600&signature=9f2c…".to_string(),
+            expires_at: example_date(),
+        }
+    }
+}
+
+impl Example for ImageLinksResponse {
+    fn example() -> Self {
+        Self {
+            url: "https://api.example.com/blobs?key=products%2Fuser-1%2Fb3e1c2d4-5f6a-4b7c-8d9e-0a1b2c3d4e5f&expires=1772355600&signature=9f2c…".to_string(),
+            thumbnail_url: Some("https://api.example.com/blobs?key=thumbnails%2Fproducts%2Fuser-1%2Fb3e1c2d4-5f6a-4b7c-8d9e-0a1b2c3d4e5f&expires=1772355600&signature=41ab…".to_string()),
+            expires_at: example_date(),
+        }
+    }
+}
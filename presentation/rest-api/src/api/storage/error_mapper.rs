@@ -0,0 +1,46 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::storage::errors::StorageError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+/// Status, name and message of a storage error, shared by every API whose
+/// use cases read or write stored photos.
+pub fn storage_error_parts(err: &StorageError) -> (StatusCode, &'static str, &'static str) {
+    match err {
+        StorageError::NotFound => (StatusCode::NOT_FOUND, "NotFound", "storage.not_found"),
+        StorageError::InvalidSignature => (
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "storage.invalid_signature",
+        ),
+        StorageError::UnreadableImage => (
+            StatusCode::BAD_REQUEST,
+            "BadRequest",
+            "storage.unreadable_image",
+        ),
+        StorageError::Unavailable(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "ServiceUnavailable",
+            "storage.unavailable",
+        ),
+    }
+}
+
+impl IntoErrorResponse for StorageError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = storage_error_parts(&self);
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
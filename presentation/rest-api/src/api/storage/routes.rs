@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use poem_openapi::{
+    OpenApi,
+    param::Query,
+    payload::{Binary, Json},
+};
+
+use business::domain::storage::use_cases::download::{DownloadBlobParams, DownloadBlobUseCase};
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse};
+use crate::api::tags::ApiTags;
+
+pub struct BlobApi {
+    download_use_case: Arc<dyn DownloadBlobUseCase>,
+}
+
+impl BlobApi {
+    pub fn new(download_use_case: Arc<dyn DownloadBlobUseCase>) -> Self {
+        Self { download_use_case }
+    }
+}
+
+/// Stored photo downloads
+#[OpenApi]
+impl BlobApi {
+    /// Download a stored photo
+    ///
+    /// Target of the signed URLs returned for product and receipt photos when
+    /// they are kept on the server's disk. No authentication is required; the
+    /// signature grants access until `expires`. Clients should follow the URL
+    /// they were given rather than build one. With an object store such as S3
+    /// the URLs point at the store instead and this endpoint answers `403`.
+    #[oai(path = "/blobs", method = "get", tag = "ApiTags::Storage")]
+    async fn download(
+        &self,
+        key: Query<String>,
+        expires: Query<i64>,
+        signature: Query<String>,
+    ) -> DownloadBlobResponse {
+        match self
+            .download_use_case
+            .execute(DownloadBlobParams {
+                key: key.0,
+                expires_at: expires.0,
+                signature: signature.0,
+            })
+            .await
+        {
+            // Signed URLs are short-lived, so caches must not outlive them
+            Ok(object) => DownloadBlobResponse::Ok(
+                Binary(object.bytes),
+                object.content_type,
+                "private, max-age=300".to_string(),
+            ),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    403 => DownloadBlobResponse::Forbidden(json),
+                    404 => DownloadBlobResponse::NotFound(json),
+                    _ => DownloadBlobResponse::ServiceUnavailable(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+pub enum DownloadBlobResponse {
+    /// The photo, with its media type (`image/jpeg`, `image/png` or `image/webp`)
+    #[oai(status = 200)]
+    Ok(
+        Binary<Vec<u8>>,
+        #[oai(header = "Content-Type")] String,
+        #[oai(header = "Cache-Control")] String,
+    ),
+    /// Signature invalid or expired
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 503)]
+    ServiceUnavailable(Json<ErrorResponse>),
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::store_profile::model::{Aisle, StoreProfile};
+
+use crate::api::examples::example_date;
+
+/// A stretch of the store. Shopping items go to the first aisle whose name or
+/// one of its keywords appears in the item name.
+#[derive(Debug, Clone, Object)]
+pub struct AisleDto {
+    /// Aisle name, e.g. "Fruta y verdura"
+    #[oai(validator(min_length = 1, max_length = 100))]
+    pub name: String,
+    /// Extra words that send an item to this aisle, e.g. "manzana"
+    #[oai(default)]
+    pub keywords: Vec<String>,
+}
+
+impl From<AisleDto> for Aisle {
+    fn from(dto: AisleDto) -> Self {
+        Self {
+            name: dto.name,
+            keywords: dto.keywords,
+        }
+    }
+}
+
+impl From<Aisle> for AisleDto {
+    fn from(aisle: Aisle) -> Self {
+        Self {
+            name: aisle.name,
+            keywords: aisle.keywords,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct CreateStoreProfileRequest {
+    /// Store name (cannot be empty)
+    #[oai(validator(min_length = 1, max_length = 255))]
+    pub name: String,
+    /// Aisles in walking order
+    #[oai(validator(max_items = 50))]
+    pub aisles: Vec<AisleDto>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct UpdateStoreProfileRequest {
+    /// New store name
+    #[oai(validator(min_length = 1, max_length = 255))]
+    #[oai(skip_serializing_if_is_none)]
+    pub name: Option<String>,
+    /// New aisles in walking order; replaces the current ones
+    #[oai(validator(max_items = 50))]
+    #[oai(skip_serializing_if_is_none)]
+    pub aisles: Option<Vec<AisleDto>>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct StoreProfileResponse {
+    /// Store profile unique identifier
+    pub id: String,
+    /// Store name
+    pub name: String,
+    /// Aisles in walking order
+    pub aisles: Vec<AisleDto>,
+    /// Whether `GET /shopping-items?sort=store_order` uses this store
+    pub is_active: bool,
+    /// Creation timestamp
+    pub created_at: DateTime<Utc>,
+    /// Last update timestamp
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<StoreProfile> for StoreProfileResponse {
+    fn from(profile: StoreProfile) -> Self {
+        Self {
+            id: profile.id.to_string(),
+            name: profile.name,
+            aisles: profile.aisles.into_iter().map(|a| a.into()).collect(),
+            is_active: profile.is_active,
+            created_at: profile.created_at,
+            updated_at: profile.updated_at,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+fn example_aisles() -> Vec<AisleDto> {
+    vec![
+        AisleDto {
+            name: "Fruta y verdura".to_string(),
+            keywords: vec!["manzana".to_string(), "tomate".to_string()],
+        },
+        AisleDto {
+            name: "Lácteos".to_string(),
+            keywords: vec!["leche".to_string(), "yogur".to_string()],
+        },
+        AisleDto {
+            name: "Limpieza".to_string(),
+            keywords: vec![],
+        },
+    ]
+}
+
+impl Example for CreateStoreProfileRequest {
+    fn example() -> Self {
+        Self {
+            name: "Mercadona del barrio".to_string(),
+            aisles: example_aisles(),
+        }
+    }
+}
+
+impl Example for UpdateStoreProfileRequest {
+    fn example() -> Self {
+        Self {
+            name: None,
+            aisles: Some(example_aisles()),
+        }
+    }
+}
+
+impl Example for StoreProfileResponse {
+    fn example() -> Self {
+        Self {
+            id: "4b8e2f1a-6c3d-4e9b-a7f0-1d2c3b4a5e6f".to_string(),
+            name: "Mercadona del barrio".to_string(),
+            aisles: example_aisles(),
+            is_active: true,
+            created_at: example_date(),
+            updated_at: example_date(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::store_profile::errors::StoreProfileError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for StoreProfileError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            StoreProfileError::NameEmpty => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "store_profile.name_empty",
+            ),
+            StoreProfileError::AisleNameEmpty => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "store_profile.aisle_name_empty",
+            ),
+            StoreProfileError::NotFound => {
+                (StatusCode::NOT_FOUND, "NotFound", "store_profile.not_found")
+            }
+            StoreProfileError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
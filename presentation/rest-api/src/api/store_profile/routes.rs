@@ -0,0 +1,338 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Path, payload::Json};
+use uuid::Uuid;
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::store_profile::use_cases::activate::{
+    ActivateStoreProfileParams, ActivateStoreProfileUseCase,
+};
+use business::domain::store_profile::use_cases::create::{
+    CreateStoreProfileParams, CreateStoreProfileUseCase,
+};
+use business::domain::store_profile::use_cases::delete::{
+    DeleteStoreProfileParams, DeleteStoreProfileUseCase,
+};
+use business::domain::store_profile::use_cases::get_all::{
+    GetAllStoreProfilesParams, GetAllStoreProfilesUseCase,
+};
+use business::domain::store_profile::use_cases::update::{
+    UpdateStoreProfileParams, UpdateStoreProfileUseCase,
+};
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::store_profile::dto::{
+    CreateStoreProfileRequest, StoreProfileResponse, UpdateStoreProfileRequest,
+};
+use crate::api::tags::ApiTags;
+
+pub struct StoreProfileApi {
+    get_all_use_case: Arc<dyn GetAllStoreProfilesUseCase>,
+    create_use_case: Arc<dyn CreateStoreProfileUseCase>,
+    update_use_case: Arc<dyn UpdateStoreProfileUseCase>,
+    delete_use_case: Arc<dyn DeleteStoreProfileUseCase>,
+    activate_use_case: Arc<dyn ActivateStoreProfileUseCase>,
+}
+
+impl StoreProfileApi {
+    pub fn new(
+        get_all_use_case: Arc<dyn GetAllStoreProfilesUseCase>,
+        create_use_case: Arc<dyn CreateStoreProfileUseCase>,
+        update_use_case: Arc<dyn UpdateStoreProfileUseCase>,
+        delete_use_case: Arc<dyn DeleteStoreProfileUseCase>,
+        activate_use_case: Arc<dyn ActivateStoreProfileUseCase>,
+    ) -> Self {
+        Self {
+            get_all_use_case,
+            create_use_case,
+            update_use_case,
+            delete_use_case,
+            activate_use_case,
+        }
+    }
+}
+
+/// Store profile API
+///
+/// Endpoints for describing the stores a user shops at, so the shopping list
+/// can be sorted in walking order.
+#[OpenApi]
+impl StoreProfileApi {
+    /// List store profiles
+    ///
+    /// Returns the user's store profiles, oldest first.
+    #[oai(
+        path = "/store-profiles",
+        method = "get",
+        tag = "ApiTags::StoreProfiles"
+    )]
+    async fn get_all(&self, auth: BearerAuth) -> GetAllStoreProfilesResponse {
+        let user_id = UserId::new(auth.0);
+
+        match self
+            .get_all_use_case
+            .execute(GetAllStoreProfilesParams { user_id })
+            .await
+        {
+            Ok(profiles) => GetAllStoreProfilesResponse::Ok(Json(
+                profiles.into_iter().map(|p| p.into()).collect(),
+            )),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetAllStoreProfilesResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Create a store profile
+    ///
+    /// Adds a store with its aisles in walking order. New profiles are not
+    /// active; activate one to sort the shopping list with it.
+    #[oai(
+        path = "/store-profiles",
+        method = "post",
+        tag = "ApiTags::StoreProfiles"
+    )]
+    async fn create(
+        &self,
+        auth: BearerAuth,
+        body: Json<CreateStoreProfileRequest>,
+    ) -> CreateStoreProfileResponse {
+        let params = CreateStoreProfileParams {
+            user_id: UserId::new(auth.0),
+            name: body.0.name,
+            aisles: body.0.aisles.into_iter().map(|a| a.into()).collect(),
+        };
+
+        match self.create_use_case.execute(params).await {
+            Ok(profile) => CreateStoreProfileResponse::Created(Json(profile.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => CreateStoreProfileResponse::BadRequest(json),
+                    _ => CreateStoreProfileResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Update a store profile
+    ///
+    /// Renames the store and/or replaces its aisles.
+    #[oai(
+        path = "/store-profiles/:id",
+        method = "put",
+        tag = "ApiTags::StoreProfiles"
+    )]
+    async fn update(
+        &self,
+        auth: BearerAuth,
+        id: Path<String>,
+        body: Json<UpdateStoreProfileRequest>,
+    ) -> UpdateStoreProfileResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return UpdateStoreProfileResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "store_profile.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        let params = UpdateStoreProfileParams {
+            id: uuid,
+            user_id: UserId::new(auth.0),
+            name: body.0.name,
+            aisles: body
+                .0
+                .aisles
+                .map(|aisles| aisles.into_iter().map(|a| a.into()).collect()),
+        };
+
+        match self.update_use_case.execute(params).await {
+            Ok(profile) => UpdateStoreProfileResponse::Ok(Json(profile.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => UpdateStoreProfileResponse::BadRequest(json),
+                    404 => UpdateStoreProfileResponse::NotFound(json),
+                    _ => UpdateStoreProfileResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Delete a store profile
+    ///
+    /// Removes the store. If it was active, the shopping list goes back to
+    /// creation order.
+    #[oai(
+        path = "/store-profiles/:id",
+        method = "delete",
+        tag = "ApiTags::StoreProfiles"
+    )]
+    async fn delete(&self, auth: BearerAuth, id: Path<String>) -> DeleteStoreProfileResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return DeleteStoreProfileResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "store_profile.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .delete_use_case
+            .execute(DeleteStoreProfileParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(()) => DeleteStoreProfileResponse::NoContent,
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => DeleteStoreProfileResponse::NotFound(json),
+                    _ => DeleteStoreProfileResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Activate a store profile
+    ///
+    /// Makes this the store `GET /shopping-items?sort=store_order` sorts for.
+    /// The previously active store, if any, is deactivated.
+    #[oai(
+        path = "/store-profiles/:id/activate",
+        method = "post",
+        tag = "ApiTags::StoreProfiles"
+    )]
+    async fn activate(&self, auth: BearerAuth, id: Path<String>) -> ActivateStoreProfileResponse {
+        let uuid = match Uuid::parse_str(&id.0) {
+            Ok(uuid) => uuid,
+            Err(_) => {
+                return ActivateStoreProfileResponse::BadRequest(Json(ErrorResponse {
+                    name: "ValidationError".to_string(),
+                    message: "store_profile.invalid_id".to_string(),
+                    description: None,
+                }));
+            }
+        };
+
+        match self
+            .activate_use_case
+            .execute(ActivateStoreProfileParams {
+                id: uuid,
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(profile) => ActivateStoreProfileResponse::Ok(Json(profile.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => ActivateStoreProfileResponse::NotFound(json),
+                    _ => ActivateStoreProfileResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetAllStoreProfilesResponse {
+    #[oai(status = 200)]
+    Ok(Json<Vec<StoreProfileResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum CreateStoreProfileResponse {
+    #[oai(status = 201)]
+    Created(Json<StoreProfileResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum UpdateStoreProfileResponse {
+    #[oai(status = 200)]
+    Ok(Json<StoreProfileResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum DeleteStoreProfileResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum ActivateStoreProfileResponse {
+    #[oai(status = 200)]
+    Ok(Json<StoreProfileResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetAllStoreProfilesResponse,
+    CreateStoreProfileResponse,
+    UpdateStoreProfileResponse,
+    DeleteStoreProfileResponse,
+    ActivateStoreProfileResponse,
+);
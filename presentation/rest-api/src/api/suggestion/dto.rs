@@ -1,15 +1,22 @@
 use chrono::{DateTime, Utc};
-use poem_openapi::{Enum, Object};
+use poem_openapi::{Enum, Object, types::Example};
 use serde::{Deserialize, Serialize};
 
-use business::domain::suggestion::model::{Suggestion, TimeRange};
+use business::domain::shared::pagination::CursorPage;
+use business::domain::suggestion::model::{Suggestion, SuggestionBatch, TimeRange};
 
+use crate::api::examples::example_date;
+
+/// Approximate preparation time of a recipe.
 #[derive(Debug, Clone, Serialize, Deserialize, Enum)]
 pub enum TimeRangeDto {
+    /// About 10 minutes
     #[oai(rename = "quick")]
     Quick,
+    /// About 20 minutes
     #[oai(rename = "medium")]
     Medium,
+    /// 30 minutes or more
     #[oai(rename = "long")]
     Long,
 }
@@ -25,8 +32,12 @@ impl From<TimeRange> for TimeRangeDto {
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct SuggestionIngredientResponse {
     /// Product ID from user's pantry
+    #[oai(validator(
+        pattern = "^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$"
+    ))]
     pub product_id: String,
     /// Product name
     pub product_name: String,
@@ -38,6 +49,7 @@ pub struct SuggestionIngredientResponse {
 }
 
 #[derive(Debug, Clone, Object)]
+#[oai(example)]
 pub struct SuggestionResponse {
     /// Suggestion unique identifier
     pub id: String,
@@ -82,3 +94,98 @@ impl From<Suggestion> for SuggestionResponse {
         }
     }
 }
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SuggestionBatchResponse {
+    /// Batch unique identifier
+    pub id: String,
+    /// When the batch was generated
+    pub generated_at: DateTime<Utc>,
+    /// Suggestions generated together
+    pub suggestions: Vec<SuggestionResponse>,
+}
+
+impl From<SuggestionBatch> for SuggestionBatchResponse {
+    fn from(batch: SuggestionBatch) -> Self {
+        Self {
+            id: batch.id.to_string(),
+            generated_at: batch.generated_at,
+            suggestions: batch.suggestions.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct SuggestionHistoryResponse {
+    /// Past suggestion batches, newest first
+    pub items: Vec<SuggestionBatchResponse>,
+    /// Pass as `cursor` to fetch the next page; absent on the last page
+    #[oai(skip_serializing_if_is_none)]
+    pub next_cursor: Option<String>,
+}
+
+impl From<CursorPage<SuggestionBatch>> for SuggestionHistoryResponse {
+    fn from(page: CursorPage<SuggestionBatch>) -> Self {
+        let page = page.map(SuggestionBatchResponse::from);
+        Self {
+            items: page.items,
+            next_cursor: page.next_cursor.map(|c| c.to_string()),
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for SuggestionIngredientResponse {
+    fn example() -> Self {
+        Self {
+            product_id: "3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string(),
+            product_name: "Huevos camperos".to_string(),
+            quantity: Some("4 unidades".to_string()),
+            is_urgent: true,
+        }
+    }
+}
+
+impl Example for SuggestionResponse {
+    fn example() -> Self {
+        Self {
+            id: "openai-1772355600000-0".to_string(),
+            title: "Tortilla de patatas".to_string(),
+            description: Some("Clásica tortilla jugosa para aprovechar los huevos".to_string()),
+            estimated_time: TimeRangeDto::Medium,
+            ingredients: vec![SuggestionIngredientResponse::example()],
+            urgent_ingredients: vec!["3f2b8c1e-4d5a-4e6f-9a7b-8c9d0e1f2a3b".to_string()],
+            steps: Some(vec![
+                "Pelar y cortar las patatas".to_string(),
+                "Freír las patatas a fuego lento".to_string(),
+                "Cuajar la tortilla con los huevos batidos".to_string(),
+            ]),
+            created_at: example_date(),
+        }
+    }
+}
+
+impl Example for SuggestionBatchResponse {
+    fn example() -> Self {
+        Self {
+            id: "9b1d3e5f-7a2c-4b6d-8e0f-1a3c5e7b9d2f".to_string(),
+            generated_at: example_date(),
+            suggestions: vec![SuggestionResponse::example()],
+        }
+    }
+}
+
+impl Example for SuggestionHistoryResponse {
+    fn example() -> Self {
+        Self {
+            items: vec![SuggestionBatchResponse::example()],
+            next_cursor: Some(
+                "MTc3MjM1NTYwMDAwMDAwMDo5YjFkM2U1Zi03YTJjLTRiNmQtOGUwZi0xYTNjNWU3YjlkMmY"
+                    .to_string(),
+            ),
+        }
+    }
+}
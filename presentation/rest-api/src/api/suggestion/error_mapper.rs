@@ -3,7 +3,8 @@ use poem_openapi::payload::Json;
 
 use business::domain::suggestion::errors::SuggestionError;
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+use crate::api::me::error_mapper::quota_error_parts;
 
 impl IntoErrorResponse for SuggestionError {
     fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
@@ -13,23 +14,52 @@ impl IntoErrorResponse for SuggestionError {
                 "ValidationError",
                 "suggestion.not_enough_products",
             ),
+            SuggestionError::EmptyPantry => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "ValidationError",
+                "suggestion.empty_pantry",
+            ),
             SuggestionError::GenerationFailed => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "GenerationError",
                 "suggestion.generation_failed",
             ),
+            SuggestionError::ProviderUnavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "ProviderUnavailable",
+                "suggestion.provider_unavailable",
+            ),
+            SuggestionError::QuotaExceeded => (
+                StatusCode::TOO_MANY_REQUESTS,
+                "ProviderRateLimited",
+                "suggestion.quota_exceeded",
+            ),
+            SuggestionError::ParseFailed { .. } => (
+                StatusCode::BAD_GATEWAY,
+                "GenerationError",
+                "suggestion.parse_failed",
+            ),
             SuggestionError::InvalidSuggestion => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "GenerationError",
                 "suggestion.invalid_suggestion",
             ),
+            SuggestionError::Quota(err) => quota_error_parts(err),
+            SuggestionError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
         };
 
+        log_error_chain(status, &self);
+
         (
             status,
             Json(ErrorResponse {
                 name: name.to_string(),
                 message: message.to_string(),
+                description: None,
             }),
         )
     }
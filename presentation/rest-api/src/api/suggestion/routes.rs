@@ -6,19 +6,32 @@ use business::domain::shared::value_objects::UserId;
 use business::domain::suggestion::use_cases::generate::{
     GenerateSuggestionsParams, GenerateSuggestionsUseCase,
 };
+use business::domain::suggestion::use_cases::get_history::{
+    GetSuggestionHistoryParams, GetSuggestionHistoryUseCase,
+};
 
-use crate::api::error::{ErrorResponse, IntoErrorResponse};
-use crate::api::security::FirebaseBearer;
-use crate::api::suggestion::dto::SuggestionResponse;
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::pagination::keyset_page;
+use crate::api::security::BearerAuth;
+use crate::api::suggestion::dto::{SuggestionHistoryResponse, SuggestionResponse};
 use crate::api::tags::ApiTags;
 
 pub struct SuggestionApi {
     generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+    get_history_use_case: Arc<dyn GetSuggestionHistoryUseCase>,
 }
 
 impl SuggestionApi {
-    pub fn new(generate_use_case: Arc<dyn GenerateSuggestionsUseCase>) -> Self {
-        Self { generate_use_case }
+    pub fn new(
+        generate_use_case: Arc<dyn GenerateSuggestionsUseCase>,
+        get_history_use_case: Arc<dyn GetSuggestionHistoryUseCase>,
+    ) -> Self {
+        Self {
+            generate_use_case,
+            get_history_use_case,
+        }
     }
 }
 
@@ -30,20 +43,29 @@ impl SuggestionApi {
     /// Generate cooking suggestions
     ///
     /// Returns AI-generated cooking suggestions based on available pantry products,
-    /// prioritizing ingredients that are expiring soon.
+    /// prioritizing ingredients that are expiring soon. Suggestions pre-generated
+    /// earlier the same day are served from cache unless `refresh=true`.
     #[oai(path = "/suggestions", method = "get", tag = "ApiTags::Suggestions")]
     async fn get_suggestions(
         &self,
-        auth: FirebaseBearer,
+        auth: BearerAuth,
         /// Maximum number of suggestions to generate (default: 5)
         limit: Query<Option<usize>>,
+        /// Force a fresh generation instead of serving today's cached batch (default: false)
+        refresh: Query<Option<bool>>,
     ) -> GetSuggestionsResponse {
         let user_id = UserId::new(auth.0);
         let limit = limit.0.unwrap_or(5).min(10);
+        let refresh = refresh.0.unwrap_or(false);
 
         match self
             .generate_use_case
-            .execute(GenerateSuggestionsParams { user_id, limit })
+            .execute(GenerateSuggestionsParams {
+                user_id,
+                limit,
+                refresh,
+                metered: true,
+            })
             .await
         {
             Ok(suggestions) => {
@@ -52,19 +74,110 @@ impl SuggestionApi {
                 GetSuggestionsResponse::Ok(Json(responses))
             }
             Err(err) => {
-                let (_, json) = err.into_error_response();
-                GetSuggestionsResponse::InternalError(json)
+                let retry_after = err.retry_after_seconds();
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    422 => GetSuggestionsResponse::UnprocessableEntity(json),
+                    429 => GetSuggestionsResponse::TooManyRequests(json, retry_after),
+                    502 => GetSuggestionsResponse::BadGateway(json),
+                    503 => GetSuggestionsResponse::ServiceUnavailable(json, retry_after),
+                    _ => GetSuggestionsResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// List past suggestion batches
+    ///
+    /// Returns previously generated suggestion batches, newest first. Pages
+    /// are cursor-based: pass the `next_cursor` of a response as `cursor` to
+    /// get the following page.
+    #[oai(
+        path = "/suggestions/history",
+        method = "get",
+        tag = "ApiTags::Suggestions"
+    )]
+    async fn get_suggestion_history(
+        &self,
+        auth: BearerAuth,
+        /// Page size (default: 20, max: 100)
+        limit: Query<Option<u32>>,
+        /// Opaque cursor from a previous page
+        cursor: Query<Option<String>>,
+    ) -> GetSuggestionHistoryResponse {
+        let page = match keyset_page(limit.0, cursor.0) {
+            Ok(page) => page,
+            Err(json) => return GetSuggestionHistoryResponse::BadRequest(json),
+        };
+
+        match self
+            .get_history_use_case
+            .execute(GetSuggestionHistoryParams {
+                user_id: UserId::new(auth.0),
+                page,
+            })
+            .await
+        {
+            Ok(page) => GetSuggestionHistoryResponse::Ok(Json(page.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetSuggestionHistoryResponse::InternalError(json)
             }
         }
     }
 }
 
 #[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
 pub enum GetSuggestionsResponse {
     #[oai(status = 200)]
     Ok(Json<Vec<SuggestionResponse>>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
     #[oai(status = 401)]
     Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    /// No usable products in the pantry
+    #[oai(status = 422)]
+    UnprocessableEntity(Json<ErrorResponse>),
+    /// Daily AI quota used up, or the model provider is rate limiting
+    #[oai(status = 429)]
+    TooManyRequests(
+        Json<ErrorResponse>,
+        /// Seconds to wait before retrying, when a retry can succeed
+        #[oai(header = "Retry-After")]
+        Option<u64>,
+    ),
     #[oai(status = 500)]
     InternalError(Json<ErrorResponse>),
+    /// The model answered with something that isn't a list of suggestions
+    #[oai(status = 502)]
+    BadGateway(Json<ErrorResponse>),
+    /// The model provider timed out or is down
+    #[oai(status = 503)]
+    ServiceUnavailable(
+        Json<ErrorResponse>,
+        /// Seconds to wait before retrying
+        #[oai(header = "Retry-After")]
+        Option<u64>,
+    ),
 }
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetSuggestionHistoryResponse {
+    #[oai(status = 200)]
+    Ok(Json<SuggestionHistoryResponse>),
+    /// Malformed cursor
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(GetSuggestionsResponse, GetSuggestionHistoryResponse,);
@@ -2,8 +2,62 @@ use poem_openapi::Tags;
 
 #[derive(Debug, Tags)]
 pub enum ApiTags {
+    /// Operator endpoints. Requires a bearer token of a user listed in `ADMIN_USER_IDS`.
+    Admin,
+    /// Passkey and magic-link sign-in on self-hosted instances (`AUTH_PROVIDER=local`). Public, except adding a passkey, which requires a bearer token.
+    Auth,
+    /// Lightweight counters for app badges and widgets. Requires a bearer token (`Authorization: Bearer <token>`).
+    Badges,
+    /// Exporting a user's data and restoring it on another instance. Requires a bearer token (`Authorization: Bearer <token>`).
+    Backups,
+    /// Premium plan checkout (requires a bearer token) and the Stripe webhook (public, verified by `Stripe-Signature`).
+    Billing,
+    /// Weekly "use it up" challenges. Requires a bearer token (`Authorization: Bearer <token>`).
+    Challenges,
+    /// Feature availability and limits the apps read at startup. Requires a bearer token (`Authorization: Bearer <token>`).
+    ClientConfig,
+    /// Step-by-step cooking mode. Requires a bearer token (`Authorization: Bearer <token>`).
+    CookingSessions,
+    /// App installs registered for push notifications. Requires a bearer token (`Authorization: Bearer <token>`).
+    Devices,
+    /// Service health. Public.
     Health,
+    /// Emailing items to the shopping list: the user's private address (requires a bearer token) and the Mailgun webhook (public, verified by its signature).
+    InboundEmail,
+    /// Progress of background maintenance jobs. Requires a bearer token (`Authorization: Bearer <token>`).
+    Jobs,
+    /// Load-test fixtures, disabled by default. Requires a bearer token (`Authorization: Bearer <token>`).
+    LoadTest,
+    /// Planning meals from suggestions, with pantry products reserved per meal. Requires a bearer token (`Authorization: Bearer <token>`).
+    MealPlans,
+    /// Current user, plan and usage. Requires a bearer token (`Authorization: Bearer <token>`).
+    Me,
+    /// Notifications sent to the user and acknowledging them. Requires a bearer token (`Authorization: Bearer <token>`).
+    Notifications,
+    /// Pantry products. Requires a bearer token (`Authorization: Bearer <token>`).
     Products,
+    /// Reminders set on pantry products. Requires a bearer token (`Authorization: Bearer <token>`).
+    Reminders,
+    /// Global search over products, the shopping list and suggestion history. Requires a bearer token (`Authorization: Bearer <token>`).
+    Search,
+    /// Issuing and revoking share links. Requires a bearer token (`Authorization: Bearer <token>`).
+    ShareLinks,
+    /// Read-only views behind a share token. Public; the token in the path grants access.
+    Shared,
+    /// User preferences such as default storage locations. Requires a bearer token (`Authorization: Bearer <token>`).
+    Settings,
+    /// Shopping list. Requires a bearer token (`Authorization: Bearer <token>`).
     ShoppingItems,
+    /// Shopping trips through the list at the store, with spend summaries. Requires a bearer token (`Authorization: Bearer <token>`).
+    ShoppingTrips,
+    /// Pantry statistics such as inventory value. Requires a bearer token (`Authorization: Bearer <token>`).
+    Stats,
+    /// Downloads of stored photos. Public; the signature in the URL grants access.
+    Storage,
+    /// Store layouts used to sort the shopping list. Requires a bearer token (`Authorization: Bearer <token>`).
+    StoreProfiles,
+    /// Recipe suggestions. Requires a bearer token (`Authorization: Bearer <token>`).
     Suggestions,
+    /// Home screen widgets. Issuing a widget token requires a bearer token; the snapshot takes the widget token in `X-Widget-Token`.
+    Widget,
 }
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Duration, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::vacation::model::{Vacation, VacationReport};
+
+use crate::api::examples::example_date;
+use crate::api::product::dto::ProductResponse;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct VacationModeRequest {
+    pub enabled: bool,
+    /// Planned return; vacation mode turns itself off once it passes.
+    /// Ignored when turning vacation mode off
+    pub until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct VacationModeResponse {
+    pub enabled: bool,
+    /// When the vacation going on started
+    #[oai(skip_serializing_if_is_none)]
+    pub started_at: Option<DateTime<Utc>>,
+    /// Planned return of the vacation going on
+    #[oai(skip_serializing_if_is_none)]
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl From<Option<Vacation>> for VacationModeResponse {
+    fn from(vacation: Option<Vacation>) -> Self {
+        Self {
+            enabled: vacation.is_some(),
+            started_at: vacation.as_ref().map(|v| v.started_at),
+            until: vacation.and_then(|v| v.until),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct VacationReportResponse {
+    pub started_at: DateTime<Utc>,
+    /// Omitted while the vacation is still going on; the report then runs
+    /// up to now
+    #[oai(skip_serializing_if_is_none)]
+    pub ended_at: Option<DateTime<Utc>>,
+    /// Products whose use-by or estimated expiry date fell inside the
+    /// vacation, soonest first
+    pub expired: Vec<ProductResponse>,
+    /// Products still good afterwards
+    pub survived: u32,
+}
+
+impl From<VacationReport> for VacationReportResponse {
+    fn from(report: VacationReport) -> Self {
+        Self {
+            started_at: report.vacation.started_at,
+            ended_at: report.vacation.ended_at,
+            expired: report.expired.into_iter().map(|p| p.into()).collect(),
+            survived: report.survived,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for VacationModeRequest {
+    fn example() -> Self {
+        Self {
+            enabled: true,
+            until: Some(example_date() + Duration::days(10)),
+        }
+    }
+}
+
+impl Example for VacationModeResponse {
+    fn example() -> Self {
+        Self {
+            enabled: true,
+            started_at: Some(example_date()),
+            until: Some(example_date() + Duration::days(10)),
+        }
+    }
+}
+
+impl Example for VacationReportResponse {
+    fn example() -> Self {
+        Self {
+            started_at: example_date(),
+            ended_at: Some(example_date() + Duration::days(10)),
+            expired: vec![ProductResponse::example()],
+            survived: 14,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use poem::http::StatusCode;
+use poem_openapi::payload::Json;
+
+use business::domain::vacation::errors::VacationError;
+
+use crate::api::error::{ErrorResponse, IntoErrorResponse, log_error_chain};
+
+impl IntoErrorResponse for VacationError {
+    fn into_error_response(self) -> (StatusCode, Json<ErrorResponse>) {
+        let (status, name, message) = match &self {
+            VacationError::UntilInPast => (
+                StatusCode::BAD_REQUEST,
+                "ValidationError",
+                "vacation.until_in_past",
+            ),
+            VacationError::NotFound => (StatusCode::NOT_FOUND, "NotFound", "vacation.not_found"),
+            VacationError::Repository(_) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "InternalError",
+                "repository.persistence",
+            ),
+        };
+
+        log_error_chain(status, &self);
+
+        (
+            status,
+            Json(ErrorResponse {
+                name: name.to_string(),
+                message: message.to_string(),
+                description: None,
+            }),
+        )
+    }
+}
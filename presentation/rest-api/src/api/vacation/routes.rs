@@ -0,0 +1,202 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, payload::Json};
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::vacation::use_cases::clean_up::{
+    CleanUpVacationParams, CleanUpVacationUseCase,
+};
+use business::domain::vacation::use_cases::get::{GetVacationModeParams, GetVacationModeUseCase};
+use business::domain::vacation::use_cases::get_report::{
+    GetVacationReportParams, GetVacationReportUseCase,
+};
+use business::domain::vacation::use_cases::set::{SetVacationModeParams, SetVacationModeUseCase};
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::api::vacation::dto::{
+    VacationModeRequest, VacationModeResponse, VacationReportResponse,
+};
+
+pub struct VacationApi {
+    get_use_case: Arc<dyn GetVacationModeUseCase>,
+    set_use_case: Arc<dyn SetVacationModeUseCase>,
+    get_report_use_case: Arc<dyn GetVacationReportUseCase>,
+    clean_up_use_case: Arc<dyn CleanUpVacationUseCase>,
+}
+
+impl VacationApi {
+    pub fn new(
+        get_use_case: Arc<dyn GetVacationModeUseCase>,
+        set_use_case: Arc<dyn SetVacationModeUseCase>,
+        get_report_use_case: Arc<dyn GetVacationReportUseCase>,
+        clean_up_use_case: Arc<dyn CleanUpVacationUseCase>,
+    ) -> Self {
+        Self {
+            get_use_case,
+            set_use_case,
+            get_report_use_case,
+            clean_up_use_case,
+        }
+    }
+}
+
+/// Vacation mode API
+///
+/// While the user is away, expiry alerts are paused and the daily inventory
+/// snapshots are tagged `onVacation`. On return, a report lists what expired
+/// meanwhile so it can be cleaned up in one go.
+#[OpenApi]
+impl VacationApi {
+    /// Get vacation mode
+    #[oai(path = "/settings/vacation", method = "get", tag = "ApiTags::Settings")]
+    async fn get_vacation_mode(&self, auth: BearerAuth) -> GetVacationModeResponse {
+        let params = GetVacationModeParams {
+            user_id: UserId::new(auth.0),
+        };
+
+        match self.get_use_case.execute(params).await {
+            Ok(vacation) => GetVacationModeResponse::Ok(Json(vacation.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                GetVacationModeResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Turn vacation mode on or off
+    ///
+    /// Turning it on while already on only moves the planned return. Turning
+    /// it off, by hand or when `until` passes, sends a "welcome back"
+    /// notification; `GET /settings/vacation/report` then has the details.
+    #[oai(path = "/settings/vacation", method = "put", tag = "ApiTags::Settings")]
+    async fn set_vacation_mode(
+        &self,
+        auth: BearerAuth,
+        body: Json<VacationModeRequest>,
+    ) -> SetVacationModeResponse {
+        let params = SetVacationModeParams {
+            user_id: UserId::new(auth.0),
+            enabled: body.0.enabled,
+            until: body.0.until,
+        };
+
+        match self.set_use_case.execute(params).await {
+            Ok(vacation) => SetVacationModeResponse::Ok(Json(vacation.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    400 => SetVacationModeResponse::BadRequest(json),
+                    _ => SetVacationModeResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Get the "what survived" report
+    ///
+    /// Covers the latest vacation, up to now while it is still going on.
+    #[oai(
+        path = "/settings/vacation/report",
+        method = "get",
+        tag = "ApiTags::Settings"
+    )]
+    async fn get_report(&self, auth: BearerAuth) -> VacationReportApiResponse {
+        let params = GetVacationReportParams {
+            user_id: UserId::new(auth.0),
+        };
+
+        match self.get_report_use_case.execute(params).await {
+            Ok(report) => VacationReportApiResponse::Ok(Json(report.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => VacationReportApiResponse::NotFound(json),
+                    _ => VacationReportApiResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Clean up after a vacation
+    ///
+    /// Marks every product in the report's `expired` list as finished and
+    /// thrown away, and returns the report afterwards.
+    #[oai(
+        path = "/settings/vacation/report/clean-up",
+        method = "post",
+        tag = "ApiTags::Settings"
+    )]
+    async fn clean_up(&self, auth: BearerAuth) -> VacationReportApiResponse {
+        let params = CleanUpVacationParams {
+            user_id: UserId::new(auth.0),
+        };
+
+        match self.clean_up_use_case.execute(params).await {
+            Ok(report) => VacationReportApiResponse::Ok(Json(report.into())),
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    404 => VacationReportApiResponse::NotFound(json),
+                    _ => VacationReportApiResponse::InternalError(json),
+                }
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetVacationModeResponse {
+    #[oai(status = 200)]
+    Ok(Json<VacationModeResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum SetVacationModeResponse {
+    #[oai(status = 200)]
+    Ok(Json<VacationModeResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum VacationReportApiResponse {
+    #[oai(status = 200)]
+    Ok(Json<VacationReportResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 404)]
+    NotFound(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetVacationModeResponse,
+    SetVacationModeResponse,
+    VacationReportApiResponse
+);
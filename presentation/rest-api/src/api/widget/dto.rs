@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use poem_openapi::{Object, types::Example};
+
+use business::domain::widget::model::{WidgetItem, WidgetProduct, WidgetSnapshot};
+use business::domain::widget::use_cases::issue_token::IssuedWidgetToken;
+
+use crate::api::examples::example_date;
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct WidgetTokenResponse {
+    /// Secret token for `X-Widget-Token`; only returned once, at creation time
+    #[oai(validator(pattern = "^[A-Za-z0-9_-]{43}$"))]
+    pub token: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<IssuedWidgetToken> for WidgetTokenResponse {
+    fn from(issued: IssuedWidgetToken) -> Self {
+        Self {
+            token: issued.token,
+            created_at: issued.widget_token.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct WidgetProductResponse {
+    pub id: String,
+    pub name: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl From<WidgetProduct> for WidgetProductResponse {
+    fn from(product: WidgetProduct) -> Self {
+        Self {
+            id: product.id.to_string(),
+            name: product.name,
+            expires_at: product.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+pub struct WidgetItemResponse {
+    pub id: String,
+    pub name: String,
+}
+
+impl From<WidgetItem> for WidgetItemResponse {
+    fn from(item: WidgetItem) -> Self {
+        Self {
+            id: item.id.to_string(),
+            name: item.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Object)]
+#[oai(example)]
+pub struct WidgetResponse {
+    /// Up to 3 products to use soonest
+    pub urgent_products: Vec<WidgetProductResponse>,
+    /// All urgent products, including those not listed
+    pub urgent_total: u32,
+    /// Up to 5 unbought shopping items, oldest first
+    pub shopping_items: Vec<WidgetItemResponse>,
+    /// All unbought items, including those not listed
+    pub unbought_total: u32,
+}
+
+impl From<WidgetSnapshot> for WidgetResponse {
+    fn from(snapshot: WidgetSnapshot) -> Self {
+        Self {
+            urgent_products: snapshot
+                .urgent_products
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            urgent_total: snapshot.urgent_total,
+            shopping_items: snapshot
+                .shopping_items
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            unbought_total: snapshot.unbought_total,
+        }
+    }
+}
+
+// --- OpenAPI examples ---
+
+impl Example for WidgetTokenResponse {
+    fn example() -> Self {
+        Self {
+            token: "Zb3kQ9vX2mLp7sT1wY5zB8nC4hF6gR0aE2uI9oK3jMq".to_string(),
+            created_at: example_date(),
+        }
+    }
+}
+
+impl Example for WidgetResponse {
+    fn example() -> Self {
+        Self {
+            urgent_products: vec![WidgetProductResponse {
+                id: "3f2a9c4e-7b1d-4e8a-9c6f-2d5b8e1a4c7f".to_string(),
+                name: "Leche entera".to_string(),
+                expires_at: example_date(),
+            }],
+            urgent_total: 1,
+            shopping_items: vec![WidgetItemResponse {
+                id: "8c1e4b7a-2d5f-4a9c-b3e6-7f0d1a2b3c4d".to_string(),
+                name: "Huevos".to_string(),
+            }],
+            unbought_total: 4,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use poem_openapi::{OpenApi, param::Header, payload::Json};
+
+use business::domain::shared::value_objects::UserId;
+use business::domain::widget::model::WidgetSnapshot;
+use business::domain::widget::use_cases::get_snapshot::{
+    GetWidgetSnapshotParams, GetWidgetSnapshotUseCase,
+};
+use business::domain::widget::use_cases::issue_token::{
+    IssueWidgetTokenParams, IssueWidgetTokenUseCase,
+};
+use business::domain::widget::use_cases::revoke_token::{
+    RevokeWidgetTokenParams, RevokeWidgetTokenUseCase,
+};
+
+use crate::api::error::{
+    ErrorResponse, IntoErrorResponse, handle_request_error, impl_request_error_response,
+};
+use crate::api::http_cache::{WIDGET_CACHE_CONTROL, cache_key, is_not_modified};
+use crate::api::security::BearerAuth;
+use crate::api::tags::ApiTags;
+use crate::api::widget::dto::{WidgetResponse, WidgetTokenResponse};
+
+pub struct WidgetApi {
+    get_snapshot_use_case: Arc<dyn GetWidgetSnapshotUseCase>,
+    issue_token_use_case: Arc<dyn IssueWidgetTokenUseCase>,
+    revoke_token_use_case: Arc<dyn RevokeWidgetTokenUseCase>,
+}
+
+impl WidgetApi {
+    pub fn new(
+        get_snapshot_use_case: Arc<dyn GetWidgetSnapshotUseCase>,
+        issue_token_use_case: Arc<dyn IssueWidgetTokenUseCase>,
+        revoke_token_use_case: Arc<dyn RevokeWidgetTokenUseCase>,
+    ) -> Self {
+        Self {
+            get_snapshot_use_case,
+            issue_token_use_case,
+            revoke_token_use_case,
+        }
+    }
+}
+
+/// ETag over everything a widget renders.
+fn snapshot_etag(snapshot: &WidgetSnapshot) -> String {
+    let parts: Vec<String> = [
+        snapshot.urgent_total.to_string(),
+        snapshot.unbought_total.to_string(),
+    ]
+    .into_iter()
+    .chain(
+        snapshot
+            .urgent_products
+            .iter()
+            .map(|p| format!("{}|{}|{}", p.id, p.name, p.expires_at)),
+    )
+    .chain(
+        snapshot
+            .shopping_items
+            .iter()
+            .map(|i| format!("{}|{}", i.id, i.name)),
+    )
+    .collect();
+    let parts: Vec<&str> = parts.iter().map(String::as_str).collect();
+    cache_key("widget", &parts)
+}
+
+/// Widget API
+///
+/// Compact snapshot for iOS and Android home screen widgets, which poll
+/// often, with tight timeouts and without a way to refresh a session.
+#[OpenApi]
+impl WidgetApi {
+    /// Get the widget snapshot
+    ///
+    /// Returns the 3 most urgent products and the 5 oldest unbought shopping
+    /// items, with totals, in a single query. Authenticated by the widget
+    /// token in `X-Widget-Token` instead of a bearer token. Send the last
+    /// `ETag` in `If-None-Match` to get `304` while nothing changed.
+    #[oai(path = "/widget", method = "get", tag = "ApiTags::Widget")]
+    async fn get_widget(
+        &self,
+        #[oai(name = "X-Widget-Token")] token: Header<String>,
+        #[oai(name = "If-None-Match")] if_none_match: Header<Option<String>>,
+    ) -> GetWidgetResponse {
+        match self
+            .get_snapshot_use_case
+            .execute(GetWidgetSnapshotParams { token: token.0 })
+            .await
+        {
+            Ok(snapshot) => {
+                let etag = snapshot_etag(&snapshot);
+                if is_not_modified(if_none_match.0.as_deref(), &etag) {
+                    return GetWidgetResponse::NotModified(WIDGET_CACHE_CONTROL.to_string(), etag);
+                }
+                GetWidgetResponse::Ok(
+                    Json(snapshot.into()),
+                    WIDGET_CACHE_CONTROL.to_string(),
+                    etag,
+                )
+            }
+            Err(err) => {
+                let (status, json) = err.into_error_response();
+                match status.as_u16() {
+                    401 => GetWidgetResponse::Unauthorized(json),
+                    _ => GetWidgetResponse::InternalError(json),
+                }
+            }
+        }
+    }
+
+    /// Issue a widget token
+    ///
+    /// Creates the long-lived token widgets send in `X-Widget-Token`. It
+    /// never expires and only reads the widget snapshot. Issuing a new one
+    /// replaces the previous token, which stops working right away.
+    #[oai(path = "/widget/token", method = "post", tag = "ApiTags::Widget")]
+    async fn issue_widget_token(&self, auth: BearerAuth) -> IssueWidgetTokenResponse {
+        match self
+            .issue_token_use_case
+            .execute(IssueWidgetTokenParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(issued) => IssueWidgetTokenResponse::Created(Json(issued.into())),
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                IssueWidgetTokenResponse::InternalError(json)
+            }
+        }
+    }
+
+    /// Revoke the widget token
+    ///
+    /// Disconnects every widget using the current token.
+    #[oai(path = "/widget/token", method = "delete", tag = "ApiTags::Widget")]
+    async fn revoke_widget_token(&self, auth: BearerAuth) -> RevokeWidgetTokenResponse {
+        match self
+            .revoke_token_use_case
+            .execute(RevokeWidgetTokenParams {
+                user_id: UserId::new(auth.0),
+            })
+            .await
+        {
+            Ok(()) => RevokeWidgetTokenResponse::NoContent,
+            Err(err) => {
+                let (_status, json) = err.into_error_response();
+                RevokeWidgetTokenResponse::InternalError(json)
+            }
+        }
+    }
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum GetWidgetResponse {
+    #[oai(status = 200)]
+    Ok(
+        Json<WidgetResponse>,
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 304)]
+    NotModified(
+        #[oai(header = "Cache-Control")] String,
+        #[oai(header = "ETag")] String,
+    ),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum IssueWidgetTokenResponse {
+    #[oai(status = 201)]
+    Created(Json<WidgetTokenResponse>),
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+#[derive(poem_openapi::ApiResponse)]
+#[oai(bad_request_handler = "handle_request_error")]
+pub enum RevokeWidgetTokenResponse {
+    #[oai(status = 204)]
+    NoContent,
+    #[oai(status = 400)]
+    BadRequest(Json<ErrorResponse>),
+    #[oai(status = 401)]
+    Unauthorized(Json<ErrorResponse>),
+    #[oai(status = 403)]
+    Forbidden(Json<ErrorResponse>),
+    #[oai(status = 500)]
+    InternalError(Json<ErrorResponse>),
+}
+
+impl_request_error_response!(
+    GetWidgetResponse,
+    IssueWidgetTokenResponse,
+    RevokeWidgetTokenResponse
+);
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+use std::env;
+
+/// Who may call the operator endpoints under `/admin`
+#[derive(Debug, Clone, Default)]
+pub struct AdminConfig {
+    /// Empty turns the admin endpoints off for everyone
+    pub user_ids: HashSet<String>,
+}
+
+impl AdminConfig {
+    /// Load admin configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - ADMIN_USER_IDS: Comma-separated user ids allowed to call `/admin` endpoints (default: none)
+    pub fn from_env() -> Self {
+        Self {
+            user_ids: env::var("ADMIN_USER_IDS")
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|id| !id.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    pub fn is_admin(&self, user_id: &str) -> bool {
+        self.user_ids.contains(user_id)
+    }
+}
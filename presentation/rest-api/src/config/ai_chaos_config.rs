@@ -0,0 +1,51 @@
+use std::env;
+use std::time::Duration;
+
+use openai::chaos::ChaosSettings;
+
+/// Fault injection for the AI adapters, meant for staging and load tests.
+/// Never enable it where real users are served.
+#[derive(Debug, Clone, Default)]
+pub struct AiChaosConfig {
+    /// Faults to inject; `None` leaves the adapters untouched
+    pub settings: Option<ChaosSettings>,
+}
+
+impl AiChaosConfig {
+    /// Load AI chaos configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - AI_CHAOS_ENABLED: Wrap the AI adapters with fault injection (default: "false")
+    /// - AI_CHAOS_LATENCY_MS: Delay added to every AI call (default: "0")
+    /// - AI_CHAOS_FAILURE_RATE: Share of calls failing as if the provider were down, 0.0–1.0 (default: "0")
+    /// - AI_CHAOS_MALFORMED_RATE: Share of calls answered with unreadable output, 0.0–1.0 (default: "0")
+    pub fn from_env() -> Self {
+        if !env::var("AI_CHAOS_ENABLED")
+            .map(|v| v == "true")
+            .unwrap_or(false)
+        {
+            return Self::default();
+        }
+
+        let rate = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .map(|rate| rate.clamp(0.0, 1.0))
+                .unwrap_or(0.0)
+        };
+
+        Self {
+            settings: Some(ChaosSettings {
+                latency: Duration::from_millis(
+                    env::var("AI_CHAOS_LATENCY_MS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .unwrap_or(0),
+                ),
+                failure_rate: rate("AI_CHAOS_FAILURE_RATE"),
+                malformed_rate: rate("AI_CHAOS_MALFORMED_RATE"),
+            }),
+        }
+    }
+}
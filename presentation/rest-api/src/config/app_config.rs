@@ -1,9 +1,23 @@
-use super::{cors_config, server_config::ServerConfig};
+use super::{
+    app_version_config::AppVersionConfig, cors_config, maintenance_config::MaintenanceConfig,
+    payload_config::PayloadConfig, rate_limit_config::RateLimitConfig,
+    sandbox_config::SandboxConfig, scheduler_config::SchedulerConfig,
+    security_config::SecurityConfig, server_config::ServerConfig,
+    traffic_log_config::TrafficLogConfig,
+};
 use poem::middleware::Cors;
 
 pub struct AppConfig {
     pub server: ServerConfig,
     pub cors: Cors,
+    pub scheduler: SchedulerConfig,
+    pub payload: PayloadConfig,
+    pub rate_limit: RateLimitConfig,
+    pub security: SecurityConfig,
+    pub sandbox: SandboxConfig,
+    pub traffic_log: TrafficLogConfig,
+    pub app_version: AppVersionConfig,
+    pub maintenance: MaintenanceConfig,
 }
 
 impl AppConfig {
@@ -11,6 +25,14 @@ impl AppConfig {
         Self {
             server: ServerConfig::from_env(),
             cors: cors_config::init_cors(),
+            scheduler: SchedulerConfig::from_env(),
+            payload: PayloadConfig::from_env(),
+            rate_limit: RateLimitConfig::from_env(),
+            security: SecurityConfig::from_env(),
+            sandbox: SandboxConfig::from_env(),
+            traffic_log: TrafficLogConfig::from_env(),
+            app_version: AppVersionConfig::from_env(),
+            maintenance: MaintenanceConfig::from_env(),
         }
     }
 }
@@ -0,0 +1,119 @@
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+
+/// App release as `major.minor.patch`. Missing parts count as zero and build
+/// suffixes such as `-beta` or ` (451)` are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AppVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl FromStr for AppVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let core = s
+            .trim()
+            .trim_start_matches('v')
+            .split(|c: char| c == '-' || c == '+' || c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+        let parts = core
+            .split('.')
+            .map(|part| part.parse::<u32>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| format!("Invalid app version: {}", s))?;
+        match parts[..] {
+            [major] => Ok(Self {
+                major,
+                minor: 0,
+                patch: 0,
+            }),
+            [major, minor] => Ok(Self {
+                major,
+                minor,
+                patch: 0,
+            }),
+            [major, minor, patch] => Ok(Self {
+                major,
+                minor,
+                patch,
+            }),
+            _ => Err(format!("Invalid app version: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for AppVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Oldest app release the server still accepts requests from
+#[derive(Debug, Clone)]
+pub struct AppVersionConfig {
+    pub minimum: AppVersion,
+}
+
+impl Default for AppVersionConfig {
+    fn default() -> Self {
+        Self {
+            minimum: AppVersion {
+                major: 1,
+                minor: 0,
+                patch: 0,
+            },
+        }
+    }
+}
+
+impl AppVersionConfig {
+    /// Load the minimum app version from environment variables
+    ///
+    /// Environment variables:
+    /// - MIN_APP_VERSION: Oldest supported app version; apps sending an older `X-App-Version` get 426 (default: "1.0.0")
+    pub fn from_env() -> Self {
+        env::var("MIN_APP_VERSION")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(|minimum| Self { minimum })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_versions_with_missing_parts_and_suffixes() {
+        // Arrange
+        let expected = AppVersion {
+            major: 2,
+            minor: 3,
+            patch: 0,
+        };
+
+        // Act & Assert
+        assert_eq!("2.3".parse::<AppVersion>(), Ok(expected));
+        assert_eq!("v2.3.0-beta.1".parse::<AppVersion>(), Ok(expected));
+        assert_eq!("2.3.0 (451)".parse::<AppVersion>(), Ok(expected));
+        assert!("latest".parse::<AppVersion>().is_err());
+        assert!("1.2.3.4".parse::<AppVersion>().is_err());
+    }
+
+    #[test]
+    fn should_compare_versions_numerically() {
+        // Arrange
+        let minimum: AppVersion = "1.10.0".parse().unwrap();
+
+        // Act & Assert
+        assert!("1.9.9".parse::<AppVersion>().unwrap() < minimum);
+        assert!("1.10.0".parse::<AppVersion>().unwrap() >= minimum);
+        assert!("2.0".parse::<AppVersion>().unwrap() > minimum);
+    }
+}
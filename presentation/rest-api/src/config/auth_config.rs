@@ -0,0 +1,97 @@
+use std::env;
+
+use auth::mailer::WebhookMailerSettings;
+use auth::tokens::LocalTokenSettings;
+use auth::webauthn::WebAuthnSettings;
+use chrono::Duration;
+
+use crate::config::firebase_config::FirebaseConfig;
+
+/// Sign-in for instances that issue their own tokens.
+#[derive(Debug, Clone)]
+pub struct LocalAuthConfig {
+    pub tokens: LocalTokenSettings,
+    pub webauthn: WebAuthnSettings,
+    /// App page magic links open; it posts the token to `/auth/magic-link/verify`
+    pub magic_link_url: String,
+    /// How long magic links and passkey challenges stay valid
+    pub challenge_ttl: Duration,
+    /// Mail relay for magic links; without one, links are only logged
+    pub mailer: Option<WebhookMailerSettings>,
+}
+
+/// Who issues the bearer tokens the API accepts.
+#[derive(Debug, Clone)]
+pub enum AuthConfig {
+    Firebase(FirebaseConfig),
+    Local(LocalAuthConfig),
+}
+
+impl AuthConfig {
+    /// Load authentication configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - AUTH_PROVIDER: "firebase" (Firebase ID tokens) or "local" (tokens issued here after a passkey or magic-link sign-in) (default: "firebase")
+    /// - FIREBASE_PROJECT_ID: Project whose ID tokens are accepted (required for the firebase provider)
+    /// - AUTH_TOKEN_SECRET: Key signing local tokens (required for the local provider)
+    /// - AUTH_TOKEN_ISSUER: Issuer claim of local tokens (default: "foodie-backend")
+    /// - AUTH_TOKEN_TTL_DAYS: Lifetime of local tokens (default: "30")
+    /// - AUTH_PASSKEY_RP_ID: Domain passkeys are bound to, e.g. "foodie.example.com" (required for the local provider)
+    /// - AUTH_PASSKEY_ORIGINS: Comma-separated origins allowed to use passkeys, including "android:apk-key-hash:…" for the Android app (default: "https://<AUTH_PASSKEY_RP_ID>")
+    /// - AUTH_MAGIC_LINK_URL: App page magic links point to (default: "https://<AUTH_PASSKEY_RP_ID>/sign-in")
+    /// - AUTH_CHALLENGE_TTL_SECS: Lifetime of magic links and passkey challenges (default: "900")
+    /// - AUTH_MAIL_WEBHOOK_URL: Relay that emails magic links, receiving `{to, subject, text}` JSON (default: links are only logged)
+    /// - AUTH_MAIL_WEBHOOK_TOKEN: Bearer token sent to the relay (optional)
+    pub fn from_env() -> Self {
+        let required = |name: &str| {
+            env::var(name).unwrap_or_else(|_| panic!("{name} environment variable must be set"))
+        };
+
+        match env::var("AUTH_PROVIDER").as_deref() {
+            Ok("local") => {
+                let rp_id = required("AUTH_PASSKEY_RP_ID");
+                let origins = env::var("AUTH_PASSKEY_ORIGINS")
+                    .map(|v| {
+                        v.split(',')
+                            .map(|origin| origin.trim().trim_end_matches('/').to_string())
+                            .filter(|origin| !origin.is_empty())
+                            .collect()
+                    })
+                    .unwrap_or_else(|_| vec![format!("https://{rp_id}")]);
+
+                AuthConfig::Local(LocalAuthConfig {
+                    tokens: LocalTokenSettings {
+                        secret: required("AUTH_TOKEN_SECRET"),
+                        issuer: env::var("AUTH_TOKEN_ISSUER")
+                            .unwrap_or_else(|_| "foodie-backend".to_string()),
+                        ttl: Duration::days(
+                            env::var("AUTH_TOKEN_TTL_DAYS")
+                                .ok()
+                                .and_then(|v| v.parse::<i64>().ok())
+                                .filter(|days| *days > 0)
+                                .unwrap_or(30),
+                        ),
+                    },
+                    magic_link_url: env::var("AUTH_MAGIC_LINK_URL")
+                        .unwrap_or_else(|_| format!("https://{rp_id}/sign-in")),
+                    webauthn: WebAuthnSettings { rp_id, origins },
+                    challenge_ttl: Duration::seconds(
+                        env::var("AUTH_CHALLENGE_TTL_SECS")
+                            .ok()
+                            .and_then(|v| v.parse::<i64>().ok())
+                            .filter(|secs| *secs > 0)
+                            .unwrap_or(900),
+                    ),
+                    mailer: env::var("AUTH_MAIL_WEBHOOK_URL").ok().map(|url| {
+                        WebhookMailerSettings {
+                            url,
+                            token: env::var("AUTH_MAIL_WEBHOOK_TOKEN").ok(),
+                        }
+                    }),
+                })
+            }
+            Ok("firebase") | Err(_) => AuthConfig::Firebase(FirebaseConfig::from_env()),
+            Ok(other) => panic!("AUTH_PROVIDER must be \"firebase\" or \"local\", got \"{other}\""),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::env;
+
+/// Configuration for Stripe billing of the premium plan.
+pub struct BillingConfig {
+    pub stripe_secret_key: String,
+    pub stripe_webhook_secret: String,
+    pub stripe_price_id: String,
+    pub success_url: String,
+    pub cancel_url: String,
+}
+
+impl BillingConfig {
+    /// Load billing configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - STRIPE_SECRET_KEY: Secret API key used to create checkout sessions
+    /// - STRIPE_WEBHOOK_SECRET: Signing secret of the webhook endpoint
+    /// - STRIPE_PRICE_ID: Price of the premium subscription
+    /// - BILLING_SUCCESS_URL: Where Stripe sends the user after paying
+    /// - BILLING_CANCEL_URL: Where Stripe sends the user after abandoning checkout
+    pub fn from_env() -> Self {
+        let required = |name: &str| {
+            env::var(name).unwrap_or_else(|_| panic!("{name} environment variable must be set"))
+        };
+
+        Self {
+            stripe_secret_key: required("STRIPE_SECRET_KEY"),
+            stripe_webhook_secret: required("STRIPE_WEBHOOK_SECRET"),
+            stripe_price_id: required("STRIPE_PRICE_ID"),
+            success_url: required("BILLING_SUCCESS_URL"),
+            cancel_url: required("BILLING_CANCEL_URL"),
+        }
+    }
+}
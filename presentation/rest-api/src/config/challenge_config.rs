@@ -0,0 +1,29 @@
+use std::env;
+
+use business::domain::challenge::model::ChallengeSettings;
+
+/// Configuration for weekly challenges
+#[derive(Debug, Clone, Default)]
+pub struct ChallengeConfig {
+    pub settings: ChallengeSettings,
+}
+
+impl ChallengeConfig {
+    /// Load challenge configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - CHALLENGE_WEEKLY_TARGET: Urgent products to use each week to complete the challenge (default: "3")
+    pub fn from_env() -> Self {
+        let defaults = ChallengeSettings::default();
+        Self {
+            settings: ChallengeSettings {
+                target: env::var("CHALLENGE_WEEKLY_TARGET")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(defaults.target),
+                ..defaults
+            },
+        }
+    }
+}
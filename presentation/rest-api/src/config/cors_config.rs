@@ -9,7 +9,7 @@ use std::env;
 ///
 /// Configuration:
 /// - Methods: GET, POST, PUT, DELETE, PATCH, OPTIONS
-/// - Headers: content-type, authorization, x-api-key
+/// - Headers: content-type, authorization, x-api-key, x-app-version
 /// - Credentials: Enabled
 ///
 pub fn init_cors() -> Cors {
@@ -22,6 +22,11 @@ pub fn init_cors() -> Cors {
     Cors::new()
         .allow_origins(origins)
         .allow_methods(vec!["GET", "POST", "PUT", "DELETE", "PATCH", "OPTIONS"])
-        .allow_headers(vec!["content-type", "authorization", "x-api-key"])
+        .allow_headers(vec![
+            "content-type",
+            "authorization",
+            "x-api-key",
+            "x-app-version",
+        ])
         .allow_credentials(true)
 }
@@ -1,6 +1,7 @@
-use persistence::db::{DatabaseConfig, create_postgres_pool};
+use persistence::db::{DatabaseConfig, ReadPool, create_postgres_pool, create_replica_pool};
 use sqlx::PgPool;
 use std::env;
+use std::time::Duration;
 
 /// Initialize database connection pool from environment variables
 ///
@@ -14,3 +15,32 @@ pub async fn init_database() -> anyhow::Result<PgPool> {
     let pool = create_postgres_pool(&DatabaseConfig::new(db_url)).await?;
     Ok(pool)
 }
+
+/// Build the pool for stale-tolerant reads
+///
+/// Environment variables:
+/// - DATABASE_REPLICA_URL: PostgreSQL connection string of a read-only replica (default: none, reads use the primary)
+/// - DATABASE_REPLICA_MAX_LAG_MS: Replication lag above which reads fall back to the primary (default: "5000")
+///
+/// # Errors
+/// Returns error if DATABASE_REPLICA_URL is not a valid connection string
+pub fn init_read_pool(primary: PgPool) -> anyhow::Result<ReadPool> {
+    let Some(replica_url) = env::var("DATABASE_REPLICA_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+    else {
+        return Ok(ReadPool::primary(primary));
+    };
+
+    let max_lag = Duration::from_millis(
+        env::var("DATABASE_REPLICA_MAX_LAG_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5000),
+    );
+    let mut config = DatabaseConfig::new(replica_url);
+    // Fail fast so an unreachable replica hands reads back to the primary
+    config.acquire_timeout = Duration::from_secs(2);
+    let replica = create_replica_pool(&config)?;
+    Ok(ReadPool::with_replica(primary, replica, max_lag))
+}
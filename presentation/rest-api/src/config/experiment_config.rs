@@ -0,0 +1,67 @@
+use std::env;
+
+use business::domain::experiment::model::{AiFeature, Experiment, Variant};
+
+/// Prompt and model experiments on AI features
+#[derive(Debug, Clone, Default)]
+pub struct ExperimentConfig {
+    pub experiments: Vec<Experiment>,
+}
+
+impl ExperimentConfig {
+    /// Load experiments from environment variables
+    ///
+    /// Each variable lists the variants of one feature's experiment, comma-separated,
+    /// as `name:weight[:model[:prompt_version]]`. A variant without model or prompt
+    /// version runs the regular configuration; malformed variants are ignored.
+    ///
+    /// Environment variables:
+    /// - AI_EXPERIMENT_PRODUCT_IDENTIFICATION: Variants for photo identification, e.g. "control:1,mini:1:gpt-4o-mini" (default: no experiment)
+    /// - AI_EXPERIMENT_SUGGESTIONS: Variants for recipe suggestions, e.g. "control:1,v2:1::2" (default: no experiment)
+    pub fn from_env() -> Self {
+        Self {
+            experiments: AiFeature::ALL
+                .into_iter()
+                .filter_map(|feature| {
+                    let variants: Vec<Variant> = env::var(Self::env_var(feature))
+                        .unwrap_or_default()
+                        .split(',')
+                        .filter_map(parse_variant)
+                        .collect();
+                    (!variants.is_empty()).then_some(Experiment { feature, variants })
+                })
+                .collect(),
+        }
+    }
+
+    fn env_var(feature: AiFeature) -> &'static str {
+        match feature {
+            AiFeature::ProductIdentification => "AI_EXPERIMENT_PRODUCT_IDENTIFICATION",
+            AiFeature::Suggestions => "AI_EXPERIMENT_SUGGESTIONS",
+        }
+    }
+
+    pub fn experiment(&self, feature: AiFeature) -> Option<Experiment> {
+        self.experiments
+            .iter()
+            .find(|e| e.feature == feature)
+            .cloned()
+    }
+}
+
+fn parse_variant(spec: &str) -> Option<Variant> {
+    let mut parts = spec.trim().split(':').map(str::trim);
+    let name = parts.next().filter(|n| !n.is_empty())?;
+    let weight = parts.next()?.parse().ok()?;
+    let model = parts.next().filter(|m| !m.is_empty()).map(str::to_string);
+    let prompt_version = match parts.next().filter(|v| !v.is_empty()) {
+        Some(version) => Some(version.parse().ok()?),
+        None => None,
+    };
+    Some(Variant {
+        name: name.to_string(),
+        weight,
+        model,
+        prompt_version,
+    })
+}
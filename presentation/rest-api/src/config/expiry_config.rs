@@ -0,0 +1,68 @@
+use std::env;
+use std::time::Duration;
+
+use chrono_tz::Tz;
+
+use business::domain::job::model::RetryBackoff;
+use business::domain::product::expiry_snap::ExpirySnap;
+
+/// Configuration for AI-estimated expiry dates
+#[derive(Debug, Clone)]
+pub struct ExpiryConfig {
+    /// Time zone of the day products expire on, for both snapping and urgency
+    pub time_zone: Tz,
+    pub snap: ExpirySnap,
+    /// Pause between estimates when filling in missing dates
+    pub estimate_missing_pause: Duration,
+    /// Retries of estimations that found the estimator down on create;
+    /// `None` marks those products failed straight away
+    pub estimation_retry: Option<RetryBackoff>,
+}
+
+impl ExpiryConfig {
+    /// Load expiry configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - ESTIMATED_EXPIRY_SNAP: "end_of_day" to store estimates at the end of their local day, "exact" to keep them as returned (default: "end_of_day")
+    /// - EXPIRY_TIME_ZONE: IANA time zone of the local day products expire on, e.g. "Europe/Madrid" (default: "UTC")
+    /// - ESTIMATE_MISSING_PAUSE_MS: Pause between products when estimating everything missing (default: 1000)
+    /// - ESTIMATION_RETRY_MAX_ATTEMPTS: Retries of an estimation that found the estimator down, 0 to disable (default: 5)
+    /// - ESTIMATION_RETRY_FIRST_DELAY_SECS: Wait before the first retry, doubled after each (default: 30)
+    pub fn from_env() -> Self {
+        let time_zone = env::var("EXPIRY_TIME_ZONE")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .map_or(Tz::UTC, |v| {
+                v.trim().parse().unwrap_or_else(|_| {
+                    panic!("EXPIRY_TIME_ZONE must be an IANA time zone, got {}", v)
+                })
+            });
+        let snap = match env::var("ESTIMATED_EXPIRY_SNAP").as_deref() {
+            Ok("exact") => ExpirySnap::Exact,
+            _ => ExpirySnap::EndOfLocalDay(time_zone),
+        };
+        let estimate_missing_pause = env::var("ESTIMATE_MISSING_PAUSE_MS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_millis)
+            .unwrap_or(Duration::from_millis(1000));
+        let max_attempts = env::var("ESTIMATION_RETRY_MAX_ATTEMPTS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(5);
+        let first_delay = env::var("ESTIMATION_RETRY_FIRST_DELAY_SECS")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(Duration::from_secs(30));
+        Self {
+            time_zone,
+            snap,
+            estimate_missing_pause,
+            estimation_retry: (max_attempts > 0).then_some(RetryBackoff {
+                first_delay,
+                max_attempts,
+            }),
+        }
+    }
+}
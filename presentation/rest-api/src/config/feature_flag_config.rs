@@ -0,0 +1,26 @@
+use std::env;
+
+use business::domain::feature_flag::model::{Feature, FeatureFlags};
+
+/// Features offered to clients
+#[derive(Debug, Clone)]
+pub struct FeatureFlagConfig {
+    pub flags: FeatureFlags,
+}
+
+impl FeatureFlagConfig {
+    /// Load feature flags from environment variables
+    ///
+    /// Environment variables:
+    /// - FEATURES_DISABLED: Comma-separated features switched off, from "receipt_scanning", "photo_recognition", "suggestions", "share_links", "expiry_estimation" (default: none)
+    pub fn from_env() -> Self {
+        Self {
+            flags: FeatureFlags::with_disabled(
+                env::var("FEATURES_DISABLED")
+                    .unwrap_or_default()
+                    .split(',')
+                    .filter_map(|name| name.trim().parse::<Feature>().ok()),
+            ),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+#[derive(Debug, Clone)]
 pub struct FirebaseConfig {
     pub project_id: String,
 }
@@ -0,0 +1,56 @@
+use std::env;
+
+use business::domain::inbound_email::model::InboundLimits;
+
+/// Where inbound emails arrive and how they are checked.
+#[derive(Debug, Clone)]
+pub struct InboundEmailSettings {
+    /// Domain routed to the webhook, e.g. "in.foodie.app"
+    pub domain: String,
+    pub mailgun_signing_key: String,
+    pub limits: InboundLimits,
+}
+
+/// Configuration for emailing items to the shopping list
+#[derive(Debug, Clone, Default)]
+pub struct InboundEmailConfig {
+    /// `None` turns the feature off
+    pub settings: Option<InboundEmailSettings>,
+}
+
+impl InboundEmailConfig {
+    /// Load inbound email configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - INBOUND_EMAIL_DOMAIN: Domain whose mail Mailgun forwards to `/inbound-email/mailgun` (default: unset, feature off)
+    /// - MAILGUN_SIGNING_KEY: Mailgun webhook signing key (required with INBOUND_EMAIL_DOMAIN)
+    /// - INBOUND_EMAIL_MAX_PER_DAY: Emails accepted per address over 24 hours (default: "20")
+    /// - INBOUND_EMAIL_MAX_ITEMS: Items read from one email (default: "30")
+    pub fn from_env() -> Self {
+        let Ok(domain) = env::var("INBOUND_EMAIL_DOMAIN") else {
+            return Self::default();
+        };
+        let defaults = InboundLimits::default();
+
+        Self {
+            settings: Some(InboundEmailSettings {
+                domain: domain.trim().to_lowercase(),
+                mailgun_signing_key: env::var("MAILGUN_SIGNING_KEY").unwrap_or_else(|_| {
+                    panic!("MAILGUN_SIGNING_KEY environment variable must be set")
+                }),
+                limits: InboundLimits {
+                    max_emails_per_day: env::var("INBOUND_EMAIL_MAX_PER_DAY")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .filter(|n| *n > 0)
+                        .unwrap_or(defaults.max_emails_per_day),
+                    max_items_per_email: env::var("INBOUND_EMAIL_MAX_ITEMS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .filter(|n| *n > 0)
+                        .unwrap_or(defaults.max_items_per_email),
+                },
+            }),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::env;
+
+/// Load-test support endpoints. Off unless explicitly enabled, since they
+/// write data without quotas.
+#[derive(Debug, Clone, Default)]
+pub struct LoadTestConfig {
+    /// Expose `POST /load-test/seed`
+    pub seed_enabled: bool,
+}
+
+impl LoadTestConfig {
+    /// Load load-test configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - LOAD_TEST_SEED_ENABLED: Allow seeding pantries through the API (default: "false")
+    pub fn from_env() -> Self {
+        Self {
+            seed_enabled: env::var("LOAD_TEST_SEED_ENABLED")
+                .map(|v| v == "true")
+                .unwrap_or(false),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use std::env;
+use std::time::Duration;
+
+/// Read-only mode for risky migrations: reads keep working, writes are
+/// turned away until it is switched off again.
+#[derive(Debug, Clone)]
+pub struct MaintenanceConfig {
+    pub enabled: bool,
+    /// How long clients are told to wait before retrying a write
+    pub retry_after: Duration,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retry_after: Duration::from_secs(300),
+        }
+    }
+}
+
+impl MaintenanceConfig {
+    /// Load maintenance mode from environment variables
+    ///
+    /// Environment variables:
+    /// - MAINTENANCE_MODE: Reject every write with 503 while reads keep working (default: "false")
+    /// - MAINTENANCE_RETRY_AFTER_SECS: Seconds clients are told to wait before retrying (default: "300")
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: env::var("MAINTENANCE_MODE")
+                .map(|v| v == "true")
+                .unwrap_or(defaults.enabled),
+            retry_after: env::var("MAINTENANCE_RETRY_AFTER_SECS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.retry_after),
+        }
+    }
+}
@@ -1,6 +1,34 @@
+pub mod admin_config;
+pub mod ai_chaos_config;
 pub mod app_config;
+pub mod app_version_config;
+pub mod auth_config;
+pub mod billing_config;
+pub mod challenge_config;
 pub mod cors_config;
 pub mod database_config;
+pub mod experiment_config;
+pub mod expiry_config;
+pub mod feature_flag_config;
 pub mod firebase_config;
+pub mod inbound_email_config;
+pub mod load_test_config;
+pub mod maintenance_config;
+pub mod notification_config;
+pub mod ocr_config;
+pub mod open_food_facts_config;
 pub mod openai_config;
+pub mod payload_config;
+pub mod preference_config;
+pub mod product_config;
+pub mod prompt_config;
+pub mod rate_limit_config;
+pub mod receipt_profile_config;
+pub mod sandbox_config;
+pub mod scheduler_config;
+pub mod security_config;
 pub mod server_config;
+pub mod stats_config;
+pub mod storage_config;
+pub mod suggestion_config;
+pub mod traffic_log_config;
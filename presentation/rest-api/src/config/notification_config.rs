@@ -0,0 +1,36 @@
+use std::env;
+
+use business::domain::notification::model::NotificationLanguage;
+use notifier::sender::WebhookNotifierSettings;
+
+/// Configuration for notification delivery
+#[derive(Debug, Clone, Default)]
+pub struct NotificationConfig {
+    /// Push relay; without one, notifications are only logged
+    pub webhook: Option<WebhookNotifierSettings>,
+    /// Language of the notifications the server writes, like expiry alerts
+    pub language: NotificationLanguage,
+}
+
+impl NotificationConfig {
+    /// Load notification configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - NOTIFICATION_WEBHOOK_URL: Relay that pushes notifications, receiving `{user_id, category, title, body}` JSON (default: notifications are only logged)
+    /// - NOTIFICATION_WEBHOOK_TOKEN: Bearer token sent to the relay (optional)
+    /// - NOTIFICATION_LANGUAGE: "en" or "es" (default: "en")
+    pub fn from_env() -> Self {
+        Self {
+            webhook: env::var("NOTIFICATION_WEBHOOK_URL")
+                .ok()
+                .map(|url| WebhookNotifierSettings {
+                    url,
+                    token: env::var("NOTIFICATION_WEBHOOK_TOKEN").ok(),
+                }),
+            language: env::var("NOTIFICATION_LANGUAGE")
+                .ok()
+                .and_then(|v| v.trim().to_lowercase().parse().ok())
+                .unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::env;
+use std::time::Duration;
+
+use ocr::tesseract::TesseractSettings;
+
+/// Configuration for reading receipts with local OCR
+#[derive(Debug, Clone, Default)]
+pub struct OcrConfig {
+    /// `None` leaves receipt scanning to the vision model alone
+    pub tesseract: Option<TesseractSettings>,
+}
+
+impl OcrConfig {
+    /// Load OCR configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - RECEIPT_OCR_TESSERACT: Path to the `tesseract` binary used when the vision model fails or AI calls run out (default: unset, no fallback)
+    /// - RECEIPT_OCR_LANGUAGES: Tesseract languages (default: "spa+eng")
+    /// - RECEIPT_OCR_TIMEOUT_SECS: Longest one receipt may take to read (default: "20")
+    pub fn from_env() -> Self {
+        Self {
+            tesseract: env::var("RECEIPT_OCR_TESSERACT")
+                .ok()
+                .filter(|binary| !binary.trim().is_empty())
+                .map(|binary| TesseractSettings {
+                    binary,
+                    languages: env::var("RECEIPT_OCR_LANGUAGES")
+                        .unwrap_or_else(|_| "spa+eng".to_string()),
+                    timeout: Duration::from_secs(
+                        env::var("RECEIPT_OCR_TIMEOUT_SECS")
+                            .ok()
+                            .and_then(|v| v.parse().ok())
+                            .filter(|secs| *secs > 0)
+                            .unwrap_or(20),
+                    ),
+                }),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::env;
+
+use openai::open_food_facts::OpenFoodFactsCredentials;
+
+/// Configuration for contributing unknown barcodes to Open Food Facts
+#[derive(Debug, Clone, Default)]
+pub struct OpenFoodFactsConfig {
+    /// `None` keeps contributions on this server only
+    pub credentials: Option<OpenFoodFactsCredentials>,
+}
+
+impl OpenFoodFactsConfig {
+    /// Load Open Food Facts configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - OFF_USER_ID: Open Food Facts account products are written as (default: unset, contributions stay local)
+    /// - OFF_PASSWORD: Password of that account (required with OFF_USER_ID)
+    /// - OFF_BASE_URL: Server written to (default: "https://world.openfoodfacts.org"; "https://world.openfoodfacts.net" is the staging server)
+    /// - OFF_LANGUAGE: Language code product names and front photos are filed under (default: "es")
+    pub fn from_env() -> Self {
+        let Some(user_id) = env::var("OFF_USER_ID")
+            .ok()
+            .filter(|user_id| !user_id.trim().is_empty())
+        else {
+            return Self::default();
+        };
+
+        Self {
+            credentials: Some(OpenFoodFactsCredentials {
+                user_id,
+                password: env::var("OFF_PASSWORD")
+                    .unwrap_or_else(|_| panic!("OFF_PASSWORD environment variable must be set")),
+                base_url: env::var("OFF_BASE_URL")
+                    .ok()
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or_else(|| "https://world.openfoodfacts.org".to_string()),
+                language: env::var("OFF_LANGUAGE")
+                    .ok()
+                    .map(|v| v.trim().to_lowercase())
+                    .filter(|v| !v.is_empty())
+                    .unwrap_or_else(|| "es".to_string()),
+            }),
+        }
+    }
+}
@@ -1,12 +1,70 @@
+use std::env;
+use std::fs;
+use std::time::Duration;
+
+use openai::client::OpenAIClientSettings;
+use reqwest::tls::Version;
+
 /// Configuration for OpenAI API access.
 pub struct OpenAIConfig {
     pub api_key: String,
+    pub client: OpenAIClientSettings,
 }
 
 impl OpenAIConfig {
+    /// Load OpenAI configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - OPENAI_API_KEY: API key (required)
+    /// - OPENAI_BASE_URL: API base URL (default: "https://api.openai.com/v1")
+    /// - OPENAI_TIMEOUT_SECONDS: Total request timeout (default: "30")
+    /// - OPENAI_CONNECT_TIMEOUT_SECONDS: Connection timeout (default: "10")
+    /// - OPENAI_PROXY_URL: Proxy for all OpenAI and Open Food Facts traffic (default: none)
+    /// - OPENAI_NO_PROXY: Comma-separated hosts reached without the proxy (default: none)
+    /// - OPENAI_CA_BUNDLE_PATH: PEM file with extra CA certificates to trust, e.g. a corporate proxy's (default: none)
+    /// - OPENAI_CA_BUNDLE_ONLY: Trust only the bundle, not the system roots (default: "false")
+    /// - OPENAI_TLS_MIN_VERSION: Lowest TLS version accepted, "1.2" or "1.3" (default: library default)
     pub fn from_env() -> Self {
-        let api_key = std::env::var("OPENAI_API_KEY")
-            .expect("OPENAI_API_KEY environment variable must be set");
-        Self { api_key }
+        let api_key =
+            env::var("OPENAI_API_KEY").expect("OPENAI_API_KEY environment variable must be set");
+
+        let defaults = OpenAIClientSettings::default();
+        let seconds = |name: &str, default: Duration| {
+            env::var(name)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .map(Duration::from_secs)
+                .unwrap_or(default)
+        };
+
+        Self {
+            api_key,
+            client: OpenAIClientSettings {
+                base_url: env::var("OPENAI_BASE_URL").unwrap_or(defaults.base_url),
+                timeout: seconds("OPENAI_TIMEOUT_SECONDS", defaults.timeout),
+                connect_timeout: seconds(
+                    "OPENAI_CONNECT_TIMEOUT_SECONDS",
+                    defaults.connect_timeout,
+                ),
+                proxy_url: env::var("OPENAI_PROXY_URL").ok().filter(|v| !v.is_empty()),
+                no_proxy: env::var("OPENAI_NO_PROXY").ok().filter(|v| !v.is_empty()),
+                ca_bundle_pem: env::var("OPENAI_CA_BUNDLE_PATH")
+                    .ok()
+                    .filter(|v| !v.is_empty())
+                    .map(|path| {
+                        fs::read(&path).unwrap_or_else(|e| {
+                            panic!("OPENAI_CA_BUNDLE_PATH {path} could not be read: {e}")
+                        })
+                    }),
+                ca_bundle_only: env::var("OPENAI_CA_BUNDLE_ONLY")
+                    .map(|v| v == "true")
+                    .unwrap_or(defaults.ca_bundle_only),
+                min_tls_version: match env::var("OPENAI_TLS_MIN_VERSION").as_deref() {
+                    Ok("1.2") => Some(Version::TLS_1_2),
+                    Ok("1.3") => Some(Version::TLS_1_3),
+                    _ => defaults.min_tls_version,
+                },
+            },
+        }
     }
 }
@@ -0,0 +1,93 @@
+use std::env;
+
+pub const IDENTIFY_IMAGE_PATH: &str = "/products/identify/image";
+pub const SCAN_RECEIPT_PATH: &str = "/products/scan-receipt";
+pub const FROM_PHOTO_PATH: &str = "/products/from-photo";
+pub const FROM_RECEIPT_PATH: &str = "/products/from-receipt";
+pub const CONTRIBUTE_BARCODE_PATH: &str = "/barcode-contributions";
+
+/// Maximum request body sizes for endpoints that receive base64 images
+#[derive(Debug, Clone)]
+pub struct PayloadConfig {
+    pub identify_image_max_bytes: usize,
+    pub scan_receipt_max_bytes: usize,
+    pub attachment_max_bytes: usize,
+}
+
+impl PayloadConfig {
+    /// Load payload limits from environment variables
+    ///
+    /// Environment variables:
+    /// - IDENTIFY_IMAGE_MAX_BYTES: Max body size for product, shelf and package photos (default: "5242880")
+    /// - SCAN_RECEIPT_MAX_BYTES: Max body size for receipt photos, scanned or imported (default: "10485760")
+    /// - ATTACHMENT_MAX_BYTES: Max body size for shopping item photos and voice notes (default: "2097152")
+    pub fn from_env() -> Self {
+        Self {
+            identify_image_max_bytes: env::var("IDENTIFY_IMAGE_MAX_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5 * 1024 * 1024),
+            scan_receipt_max_bytes: env::var("SCAN_RECEIPT_MAX_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(10 * 1024 * 1024),
+            attachment_max_bytes: env::var("ATTACHMENT_MAX_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(2 * 1024 * 1024),
+        }
+    }
+
+    /// Get the body limit for a request path, if the path is an image endpoint
+    pub fn limit_for(&self, path: &str) -> Option<usize> {
+        match path.trim_end_matches('/') {
+            IDENTIFY_IMAGE_PATH | FROM_PHOTO_PATH | CONTRIBUTE_BARCODE_PATH => {
+                Some(self.identify_image_max_bytes)
+            }
+            SCAN_RECEIPT_PATH | FROM_RECEIPT_PATH => Some(self.scan_receipt_max_bytes),
+            path if is_product_image_path(path) => Some(self.identify_image_max_bytes),
+            path if is_shopping_item_attachment_path(path) => Some(self.attachment_max_bytes),
+            _ => None,
+        }
+    }
+}
+
+/// `/products/{id}/image`, where product photos are uploaded.
+fn is_product_image_path(path: &str) -> bool {
+    path.strip_prefix("/products/")
+        .and_then(|rest| rest.strip_suffix("/image"))
+        .is_some_and(|id| !id.is_empty() && !id.contains('/'))
+}
+
+/// `/shopping-items/{id}/attachment`, where shopping item attachments are
+/// uploaded.
+fn is_shopping_item_attachment_path(path: &str) -> bool {
+    path.strip_prefix("/shopping-items/")
+        .and_then(|rest| rest.strip_suffix("/attachment"))
+        .is_some_and(|id| !id.is_empty() && !id.contains('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_return_limit_only_for_image_endpoints() {
+        // Arrange
+        let config = PayloadConfig {
+            identify_image_max_bytes: 100,
+            scan_receipt_max_bytes: 200,
+            attachment_max_bytes: 50,
+        };
+
+        // Act & Assert
+        assert_eq!(config.limit_for("/products/identify/image"), Some(100));
+        assert_eq!(config.limit_for("/products/from-photo"), Some(100));
+        assert_eq!(config.limit_for("/products/scan-receipt/"), Some(200));
+        assert_eq!(config.limit_for("/products/from-receipt"), Some(200));
+        assert_eq!(config.limit_for("/products/42/image"), Some(100));
+        assert_eq!(config.limit_for("/barcode-contributions"), Some(100));
+        assert_eq!(config.limit_for("/shopping-items/42/attachment"), Some(50));
+        assert_eq!(config.limit_for("/products"), None);
+    }
+}
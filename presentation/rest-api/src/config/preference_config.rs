@@ -0,0 +1,30 @@
+use std::env;
+
+use business::domain::preference::model::UserPreferences;
+use business::domain::product::urgency::ExpiringSoonWindow;
+
+/// Configuration for user preferences
+#[derive(Debug, Clone)]
+pub struct PreferenceConfig {
+    /// Served to users who never saved their preferences
+    pub defaults: UserPreferences,
+}
+
+impl PreferenceConfig {
+    /// Load preference defaults from environment variables
+    ///
+    /// Environment variables:
+    /// - EXPIRING_SOON_DAYS_DEFAULT: Days ahead a product counts as expiring soon, 1 to 14 (default: "2")
+    pub fn from_env() -> Self {
+        Self {
+            defaults: UserPreferences {
+                expiring_soon: env::var("EXPIRING_SOON_DAYS_DEFAULT")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .and_then(ExpiringSoonWindow::new)
+                    .unwrap_or_default(),
+                allergies: vec![],
+            },
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::env;
+
+use chrono::Duration;
+
+/// Configuration for adding products
+#[derive(Debug, Clone, Default)]
+pub struct ProductConfig {
+    /// Adding a name the user already added this recently returns the earlier
+    /// product; `None` allows duplicates
+    pub duplicate_window: Option<Duration>,
+}
+
+impl ProductConfig {
+    /// Load product configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - PRODUCT_DUPLICATE_WINDOW_SECS: Seconds within which adding the same name again returns the earlier product, e.g. "30" (default: unset, duplicates allowed)
+    pub fn from_env() -> Self {
+        Self {
+            duplicate_window: env::var("PRODUCT_DUPLICATE_WINDOW_SECS")
+                .ok()
+                .and_then(|v| v.parse::<i64>().ok())
+                .filter(|secs| *secs > 0)
+                .map(Duration::seconds),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::env;
+use std::path::PathBuf;
+
+use openai::prompts::PromptContext;
+
+/// System prompt templates sent to the AI provider
+#[derive(Debug, Clone, Default)]
+pub struct PromptConfig {
+    /// Directory whose templates override the built-in ones; `None` uses
+    /// the built-in templates only
+    pub templates_dir: Option<PathBuf>,
+    pub context: PromptContext,
+}
+
+impl PromptConfig {
+    /// Load prompt template configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - PROMPT_TEMPLATES_DIR: Directory of `<prompt>.v<version>.jinja` templates overriding the built-in ones (default: unset)
+    /// - PROMPT_LANGUAGE: Language product names and recipes are written in (default: "Spanish")
+    /// - PROMPT_CUISINE: Cooking tradition the prompts assume (default: "Spanish")
+    /// - PROMPT_CONSTRAINTS: Semicolon-separated extra rules added to every prompt (default: none)
+    pub fn from_env() -> Self {
+        let defaults = PromptContext::default();
+
+        Self {
+            templates_dir: env::var("PROMPT_TEMPLATES_DIR")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .map(PathBuf::from),
+            context: PromptContext {
+                language: env::var("PROMPT_LANGUAGE")
+                    .ok()
+                    .filter(|v| !v.trim().is_empty())
+                    .unwrap_or(defaults.language),
+                cuisine: env::var("PROMPT_CUISINE")
+                    .ok()
+                    .filter(|v| !v.trim().is_empty())
+                    .unwrap_or(defaults.cuisine),
+                constraints: env::var("PROMPT_CONSTRAINTS")
+                    .unwrap_or_default()
+                    .split(';')
+                    .map(str::trim)
+                    .filter(|c| !c.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use std::env;
+use std::time::Duration;
+
+use poem::http::Method;
+
+/// Route groups with their own request budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RouteGroup {
+    /// Endpoints that call the AI provider (identify, scan, estimate, suggest)
+    Ai,
+    /// Everything else that is not exempt
+    Standard,
+}
+
+/// Requests allowed per sliding window for one route group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimits {
+    /// Budget per authenticated user
+    pub per_user: u32,
+    /// Budget per client IP, authenticated or not
+    pub per_ip: u32,
+}
+
+/// General request rate limits, on top of the daily AI quotas
+#[derive(Debug, Clone)]
+pub struct RateLimitConfig {
+    pub enabled: bool,
+    pub window: Duration,
+    pub ai: RateLimits,
+    pub standard: RateLimits,
+    /// Proxies in front of the API that append to `X-Forwarded-For`. The
+    /// client IP is the entry this many hops from the right, since anything
+    /// left of it came from the client; 0 ignores the header
+    pub trusted_proxies: usize,
+}
+
+impl RateLimitConfig {
+    /// Load rate limits from environment variables
+    ///
+    /// Environment variables:
+    /// - RATE_LIMIT_ENABLED: Set to "false" to disable (default: "true")
+    /// - RATE_LIMIT_WINDOW_SECONDS: Length of the sliding window (default: "60")
+    /// - RATE_LIMIT_AI_PER_USER: AI requests per user and window (default: "20")
+    /// - RATE_LIMIT_AI_PER_IP: AI requests per IP and window (default: "40")
+    /// - RATE_LIMIT_STANDARD_PER_USER: Other requests per user and window (default: "120")
+    /// - RATE_LIMIT_STANDARD_PER_IP: Other requests per IP and window (default: "300")
+    /// - RATE_LIMIT_TRUSTED_PROXIES: Proxies in front of the API appending to `X-Forwarded-For`; 0 ignores the header (default: "0")
+    pub fn from_env() -> Self {
+        Self {
+            enabled: env::var("RATE_LIMIT_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            window: Duration::from_secs(
+                env::var("RATE_LIMIT_WINDOW_SECONDS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .filter(|s| *s > 0)
+                    .unwrap_or(60),
+            ),
+            ai: RateLimits {
+                per_user: env::var("RATE_LIMIT_AI_PER_USER")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(20),
+                per_ip: env::var("RATE_LIMIT_AI_PER_IP")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(40),
+            },
+            standard: RateLimits {
+                per_user: env::var("RATE_LIMIT_STANDARD_PER_USER")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(120),
+                per_ip: env::var("RATE_LIMIT_STANDARD_PER_IP")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(300),
+            },
+            trusted_proxies: env::var("RATE_LIMIT_TRUSTED_PROXIES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(0),
+        }
+    }
+
+    /// Group a request belongs to, or `None` for exempt routes (health checks,
+    /// billing webhooks and API docs)
+    pub fn group_for(&self, method: &Method, path: &str) -> Option<RouteGroup> {
+        let path = path.trim_end_matches('/');
+        let exempt = path == "/health"
+            || path == "/billing/webhook"
+            || path == "/openapi.json"
+            || path.starts_with("/docs");
+        if exempt {
+            return None;
+        }
+
+        let calls_ai = path.starts_with("/products/identify/")
+            || path.starts_with("/products/estimate-expiry")
+            || path.ends_with("/estimate-expiry")
+            || (*method == Method::GET && path == "/suggestions")
+            || (*method == Method::POST
+                && matches!(
+                    path,
+                    "/products/scan-receipt" | "/products/from-photo" | "/products/from-receipt"
+                ));
+        Some(if calls_ai {
+            RouteGroup::Ai
+        } else {
+            RouteGroup::Standard
+        })
+    }
+
+    pub fn limits(&self, group: RouteGroup) -> RateLimits {
+        match group {
+            RouteGroup::Ai => self.ai,
+            RouteGroup::Standard => self.standard,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RateLimitConfig {
+        RateLimitConfig {
+            enabled: true,
+            window: Duration::from_secs(60),
+            ai: RateLimits {
+                per_user: 1,
+                per_ip: 2,
+            },
+            standard: RateLimits {
+                per_user: 3,
+                per_ip: 4,
+            },
+            trusted_proxies: 0,
+        }
+    }
+
+    #[test]
+    fn should_group_ai_endpoints_separately() {
+        // Arrange
+        let config = config();
+
+        // Act & Assert
+        assert_eq!(
+            config.group_for(&Method::POST, "/products/identify/image"),
+            Some(RouteGroup::Ai)
+        );
+        assert_eq!(
+            config.group_for(&Method::POST, "/products/42/estimate-expiry"),
+            Some(RouteGroup::Ai)
+        );
+        assert_eq!(
+            config.group_for(&Method::GET, "/suggestions"),
+            Some(RouteGroup::Ai)
+        );
+        assert_eq!(
+            config.group_for(&Method::POST, "/products/from-receipt"),
+            Some(RouteGroup::Ai)
+        );
+        assert_eq!(
+            config.group_for(&Method::GET, "/products/from-receipt/42"),
+            Some(RouteGroup::Standard)
+        );
+        assert_eq!(
+            config.group_for(&Method::GET, "/products"),
+            Some(RouteGroup::Standard)
+        );
+    }
+
+    #[test]
+    fn should_exempt_health_webhook_and_docs() {
+        // Arrange
+        let config = config();
+
+        // Act & Assert
+        assert_eq!(config.group_for(&Method::GET, "/health"), None);
+        assert_eq!(config.group_for(&Method::POST, "/billing/webhook"), None);
+        assert_eq!(config.group_for(&Method::GET, "/docs/index.html"), None);
+    }
+}
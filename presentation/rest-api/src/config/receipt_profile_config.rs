@@ -0,0 +1,60 @@
+use std::collections::BTreeMap;
+use std::{env, fs};
+
+use serde::Deserialize;
+
+use business::domain::product::receipt_profile::{ReceiptProfile, ReceiptProfiles};
+
+/// One store in the profiles file.
+#[derive(Debug, Deserialize)]
+struct ProfileFile {
+    store: String,
+    markers: Vec<String>,
+    #[serde(default)]
+    abbreviations: BTreeMap<String, String>,
+    #[serde(default)]
+    non_food: Vec<String>,
+}
+
+impl From<ProfileFile> for ReceiptProfile {
+    fn from(file: ProfileFile) -> Self {
+        Self {
+            store: file.store,
+            markers: file.markers,
+            abbreviations: file.abbreviations.into_iter().collect(),
+            non_food: file.non_food,
+        }
+    }
+}
+
+/// Configuration for cleaning up receipts per store
+#[derive(Debug, Clone)]
+pub struct ReceiptProfileConfig {
+    pub profiles: ReceiptProfiles,
+}
+
+impl ReceiptProfileConfig {
+    /// Load receipt parsing profiles from environment variables
+    ///
+    /// Environment variables:
+    /// - RECEIPT_PROFILES_PATH: JSON file replacing the built-in profiles, an array of `{store, markers, abbreviations, non_food}` (default: built-in Mercadona, Lidl and Carrefour profiles)
+    pub fn from_env() -> Self {
+        let Some(path) = env::var("RECEIPT_PROFILES_PATH")
+            .ok()
+            .filter(|v| !v.is_empty())
+        else {
+            return Self {
+                profiles: ReceiptProfiles::default(),
+            };
+        };
+
+        let json = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("RECEIPT_PROFILES_PATH {path} could not be read: {e}"));
+        let files: Vec<ProfileFile> = serde_json::from_str(&json).unwrap_or_else(|e| {
+            panic!("RECEIPT_PROFILES_PATH {path} is not a valid profiles file: {e}")
+        });
+        Self {
+            profiles: ReceiptProfiles(files.into_iter().map(ReceiptProfile::from).collect()),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::env;
+
+/// Demo deployment for the public demo and store reviewers. Every signed-in
+/// request acts as one shared demo user, AI calls are answered from canned
+/// fixtures and the demo user is reset to a curated pantry every night, so
+/// writes are allowed but never last. Never enable it where real users are
+/// served.
+#[derive(Debug, Clone, Default)]
+pub struct SandboxConfig {
+    /// Demo user everyone acts as; `None` when sandbox mode is off
+    pub demo_user_id: Option<String>,
+    /// UTC hour at which the demo user is reset
+    pub reset_hour: u32,
+}
+
+impl SandboxConfig {
+    /// Load sandbox configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - SANDBOX_ENABLED: Run this deployment as the demo sandbox (default: "false")
+    /// - SANDBOX_DEMO_USER_ID: User id every request acts as (default: "demo-user")
+    /// - SANDBOX_RESET_HOUR: UTC hour at which the demo user is reset (default: "4")
+    pub fn from_env() -> Self {
+        if !env::var("SANDBOX_ENABLED")
+            .map(|v| v == "true")
+            .unwrap_or(false)
+        {
+            return Self::default();
+        }
+
+        Self {
+            demo_user_id: Some(
+                env::var("SANDBOX_DEMO_USER_ID")
+                    .ok()
+                    .filter(|v| !v.trim().is_empty())
+                    .unwrap_or_else(|| "demo-user".to_string()),
+            ),
+            reset_hour: env::var("SANDBOX_RESET_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(4),
+        }
+    }
+}
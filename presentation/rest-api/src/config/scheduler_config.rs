@@ -0,0 +1,183 @@
+use std::env;
+
+/// Configuration for background jobs
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    pub suggestion_pregeneration_enabled: bool,
+    pub suggestion_pregeneration_hour: u32,
+    pub suggestion_pregeneration_limit: usize,
+    pub suggestion_pregeneration_max_users: usize,
+    pub inventory_snapshot_enabled: bool,
+    pub inventory_snapshot_hour: u32,
+    pub inventory_snapshot_batch_size: usize,
+    pub waste_streak_enabled: bool,
+    pub waste_streak_hour: u32,
+    pub waste_streak_batch_size: usize,
+    pub vacation_return_enabled: bool,
+    pub vacation_return_interval_minutes: u64,
+    pub vacation_return_max_users: usize,
+    pub reservation_expiry_enabled: bool,
+    pub reservation_expiry_hour: u32,
+    pub device_prune_enabled: bool,
+    pub device_prune_hour: u32,
+    pub device_stale_after_days: u32,
+    pub notification_prune_enabled: bool,
+    pub notification_prune_hour: u32,
+    pub notification_retention_days: u32,
+    pub expiry_alert_enabled: bool,
+    pub expiry_alert_hour: u32,
+    pub expiry_alert_batch_size: usize,
+    pub reminder_delivery_enabled: bool,
+    pub reminder_delivery_interval_minutes: u64,
+    pub reminder_delivery_max_reminders: usize,
+}
+
+impl SchedulerConfig {
+    /// Load scheduler configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - SUGGESTION_PREGENERATION_ENABLED: Enable the morning job (default: "true")
+    /// - SUGGESTION_PREGENERATION_HOUR: UTC hour at which the job runs (default: "5")
+    /// - SUGGESTION_PREGENERATION_LIMIT: Suggestions generated per user (default: "5")
+    /// - SUGGESTION_PREGENERATION_MAX_USERS: Users a batch is generated for per run; users with a fresh batch do not count (default: "1000")
+    /// - INVENTORY_SNAPSHOT_ENABLED: Enable the nightly trend snapshot job (default: "true")
+    /// - INVENTORY_SNAPSHOT_HOUR: UTC hour at which the snapshot job runs (default: "23")
+    /// - INVENTORY_SNAPSHOT_BATCH_SIZE: Users loaded at a time; every run covers all users (default: "1000")
+    /// - WASTE_STREAK_ENABLED: Enable the nightly zero-waste streak job (default: "true")
+    /// - WASTE_STREAK_HOUR: UTC hour at which the streak job runs (default: "1")
+    /// - WASTE_STREAK_BATCH_SIZE: Users loaded at a time; every run covers all users (default: "1000")
+    /// - VACATION_RETURN_ENABLED: Enable the job ending vacations past their return date (default: "true")
+    /// - VACATION_RETURN_INTERVAL_MINUTES: Minutes between checks for due vacations (default: "15")
+    /// - VACATION_RETURN_MAX_USERS: Vacations ended per run (default: "1000")
+    /// - RESERVATION_EXPIRY_ENABLED: Enable the nightly job releasing stale pantry reservations (default: "true")
+    /// - RESERVATION_EXPIRY_HOUR: UTC hour at which the reservation job runs (default: "2")
+    /// - DEVICE_PRUNE_ENABLED: Enable the nightly job forgetting stale app installs (default: "true")
+    /// - DEVICE_PRUNE_HOUR: UTC hour at which the device job runs (default: "3")
+    /// - DEVICE_STALE_AFTER_DAYS: Days without being seen before an install is forgotten (default: "90")
+    /// - NOTIFICATION_PRUNE_ENABLED: Enable the nightly job deleting old inbox notifications (default: "true")
+    /// - NOTIFICATION_PRUNE_HOUR: UTC hour at which the notification job runs (default: "4")
+    /// - NOTIFICATION_RETENTION_DAYS: Days a notification stays in the inbox, read or not (default: "90")
+    /// - EXPIRY_ALERT_ENABLED: Enable the daily alert about products expiring within a day (default: "true")
+    /// - EXPIRY_ALERT_HOUR: UTC hour at which the alert job runs (default: "8")
+    /// - EXPIRY_ALERT_BATCH_SIZE: Users loaded at a time; every run covers all users (default: "1000")
+    /// - REMINDER_DELIVERY_ENABLED: Enable the job sending product reminders whose time has come (default: "true")
+    /// - REMINDER_DELIVERY_INTERVAL_MINUTES: Minutes between checks for due reminders (default: "5")
+    /// - REMINDER_DELIVERY_MAX_REMINDERS: Reminders sent per run (default: "1000")
+    pub fn from_env() -> Self {
+        Self {
+            suggestion_pregeneration_enabled: env::var("SUGGESTION_PREGENERATION_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            suggestion_pregeneration_hour: env::var("SUGGESTION_PREGENERATION_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(5),
+            suggestion_pregeneration_limit: env::var("SUGGESTION_PREGENERATION_LIMIT")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(5),
+            suggestion_pregeneration_max_users: env::var("SUGGESTION_PREGENERATION_MAX_USERS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1000),
+            inventory_snapshot_enabled: env::var("INVENTORY_SNAPSHOT_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            inventory_snapshot_hour: env::var("INVENTORY_SNAPSHOT_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(23),
+            inventory_snapshot_batch_size: env::var("INVENTORY_SNAPSHOT_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(1000),
+            waste_streak_enabled: env::var("WASTE_STREAK_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            waste_streak_hour: env::var("WASTE_STREAK_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(1),
+            waste_streak_batch_size: env::var("WASTE_STREAK_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(1000),
+            vacation_return_enabled: env::var("VACATION_RETURN_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            vacation_return_interval_minutes: env::var("VACATION_RETURN_INTERVAL_MINUTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|m| *m > 0)
+                .unwrap_or(15),
+            vacation_return_max_users: env::var("VACATION_RETURN_MAX_USERS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1000),
+            reservation_expiry_enabled: env::var("RESERVATION_EXPIRY_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            reservation_expiry_hour: env::var("RESERVATION_EXPIRY_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(2),
+            device_prune_enabled: env::var("DEVICE_PRUNE_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            device_prune_hour: env::var("DEVICE_PRUNE_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(3),
+            device_stale_after_days: env::var("DEVICE_STALE_AFTER_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|d| *d > 0)
+                .unwrap_or(90),
+            notification_prune_enabled: env::var("NOTIFICATION_PRUNE_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            notification_prune_hour: env::var("NOTIFICATION_PRUNE_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(4),
+            notification_retention_days: env::var("NOTIFICATION_RETENTION_DAYS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|d| *d > 0)
+                .unwrap_or(90),
+            expiry_alert_enabled: env::var("EXPIRY_ALERT_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            expiry_alert_hour: env::var("EXPIRY_ALERT_HOUR")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|h| *h < 24)
+                .unwrap_or(8),
+            expiry_alert_batch_size: env::var("EXPIRY_ALERT_BATCH_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|n| *n > 0)
+                .unwrap_or(1000),
+            reminder_delivery_enabled: env::var("REMINDER_DELIVERY_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true),
+            reminder_delivery_interval_minutes: env::var("REMINDER_DELIVERY_INTERVAL_MINUTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|m| *m > 0)
+                .unwrap_or(5),
+            reminder_delivery_max_reminders: env::var("REMINDER_DELIVERY_MAX_REMINDERS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(1000),
+        }
+    }
+}
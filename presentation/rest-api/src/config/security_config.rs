@@ -0,0 +1,32 @@
+use std::env;
+
+const ONE_YEAR_SECONDS: u64 = 365 * 24 * 60 * 60;
+
+/// Transport security settings applied to every response
+#[derive(Debug, Clone)]
+pub struct SecurityConfig {
+    /// `Strict-Transport-Security` max-age in seconds; `None` omits the header
+    pub hsts_max_age: Option<u64>,
+    /// Redirect requests that reached the proxy over plain HTTP to HTTPS
+    pub https_only: bool,
+}
+
+impl SecurityConfig {
+    /// Load security settings from environment variables
+    ///
+    /// Environment variables:
+    /// - HSTS_MAX_AGE_SECONDS: HSTS max-age, "0" disables the header (default: "31536000")
+    /// - HTTPS_ONLY: Redirect to HTTPS when `X-Forwarded-Proto` is http (default: "false")
+    pub fn from_env() -> Self {
+        Self {
+            hsts_max_age: Some(
+                env::var("HSTS_MAX_AGE_SECONDS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(ONE_YEAR_SECONDS),
+            )
+            .filter(|age| *age > 0),
+            https_only: env::var("HTTPS_ONLY").map(|v| v == "true").unwrap_or(false),
+        }
+    }
+}
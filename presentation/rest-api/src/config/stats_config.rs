@@ -0,0 +1,41 @@
+use std::env;
+
+/// Configuration for pantry statistics
+#[derive(Debug, Clone)]
+pub struct StatsConfig {
+    /// ISO 4217 code of the currency prices are recorded in
+    pub currency: String,
+}
+
+impl StatsConfig {
+    /// Load statistics configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - STATS_CURRENCY: Three-letter ISO 4217 code of recorded prices (default: "EUR")
+    pub fn from_env() -> Self {
+        Self {
+            currency: env::var("STATS_CURRENCY")
+                .ok()
+                .and_then(|v| parse_currency(&v))
+                .unwrap_or_else(|| "EUR".to_string()),
+        }
+    }
+}
+
+fn parse_currency(value: &str) -> Option<String> {
+    let code = value.trim();
+    (code.len() == 3 && code.chars().all(|c| c.is_ascii_alphabetic()))
+        .then(|| code.to_ascii_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_accept_only_three_letter_currency_codes() {
+        assert_eq!(parse_currency(" usd "), Some("USD".to_string()));
+        assert_eq!(parse_currency("€"), None);
+        assert_eq!(parse_currency("EURO"), None);
+    }
+}
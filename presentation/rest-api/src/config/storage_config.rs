@@ -0,0 +1,89 @@
+use std::env;
+use std::path::PathBuf;
+
+use chrono::Duration;
+use storage::local::LocalDiskSettings;
+use storage::s3::S3Settings;
+use storage::thumbnail::ThumbnailSettings;
+
+/// Longest lifetime S3 accepts for a presigned URL (7 days).
+const MAX_SIGNED_URL_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Where uploaded photos (products, receipts) are kept.
+#[derive(Debug, Clone)]
+pub enum StorageConfig {
+    LocalDisk(LocalDiskSettings),
+    S3(S3Settings),
+}
+
+impl StorageConfig {
+    /// Load object storage configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - STORAGE_BACKEND: "local" (files on this server) or "s3" (any S3-compatible store, including Google Cloud Storage) (default: "local")
+    /// - STORAGE_SIGNED_URL_TTL_SECS: Lifetime of download URLs handed to clients, at most 7 days (default: "900")
+    /// - STORAGE_LOCAL_DIR: Directory of the local backend (default: "./data/blobs")
+    /// - STORAGE_PUBLIC_URL: Base URL clients reach this API at, used in local download URLs (default: "http://127.0.0.1:8080")
+    /// - STORAGE_SIGNING_SECRET: Key signing local download URLs (required for the local backend)
+    /// - S3_ENDPOINT: e.g. "https://storage.googleapis.com" or a MinIO URL (default: "https://s3.<S3_REGION>.amazonaws.com")
+    /// - S3_REGION: Signing region, "auto" for Google Cloud Storage (default: "us-east-1")
+    /// - S3_BUCKET, S3_ACCESS_KEY_ID, S3_SECRET_ACCESS_KEY: Bucket and HMAC key pair (required for the s3 backend)
+    pub fn from_env() -> Self {
+        let required = |name: &str| {
+            env::var(name).unwrap_or_else(|_| panic!("{name} environment variable must be set"))
+        };
+        let url_ttl = Duration::seconds(
+            env::var("STORAGE_SIGNED_URL_TTL_SECS")
+                .ok()
+                .and_then(|v| v.parse::<i64>().ok())
+                .unwrap_or(900)
+                .clamp(1, MAX_SIGNED_URL_TTL_SECS),
+        );
+
+        match env::var("STORAGE_BACKEND").as_deref() {
+            Ok("s3") => {
+                let region = env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+                StorageConfig::S3(S3Settings {
+                    endpoint: env::var("S3_ENDPOINT")
+                        .unwrap_or_else(|_| format!("https://s3.{region}.amazonaws.com")),
+                    bucket: required("S3_BUCKET"),
+                    region,
+                    access_key_id: required("S3_ACCESS_KEY_ID"),
+                    secret_access_key: required("S3_SECRET_ACCESS_KEY"),
+                    url_ttl,
+                })
+            }
+            Ok("local") | Err(_) => StorageConfig::LocalDisk(LocalDiskSettings {
+                root: PathBuf::from(
+                    env::var("STORAGE_LOCAL_DIR").unwrap_or_else(|_| "./data/blobs".to_string()),
+                ),
+                public_url: env::var("STORAGE_PUBLIC_URL")
+                    .unwrap_or_else(|_| "http://127.0.0.1:8080".to_string()),
+                signing_secret: required("STORAGE_SIGNING_SECRET"),
+                url_ttl,
+            }),
+            Ok(other) => panic!("STORAGE_BACKEND must be \"local\" or \"s3\", got \"{other}\""),
+        }
+    }
+
+    /// Load thumbnail size and quality from environment variables
+    ///
+    /// Environment variables:
+    /// - THUMBNAIL_MAX_SIDE: Longest side of photo thumbnails in pixels (default: "320")
+    /// - THUMBNAIL_QUALITY: JPEG quality of thumbnails, 1–100 (default: "80")
+    pub fn thumbnails_from_env() -> ThumbnailSettings {
+        let defaults = ThumbnailSettings::default();
+        ThumbnailSettings {
+            max_side: env::var("THUMBNAIL_MAX_SIDE")
+                .ok()
+                .and_then(|v| v.parse::<u32>().ok())
+                .filter(|side| *side > 0)
+                .unwrap_or(defaults.max_side),
+            quality: env::var("THUMBNAIL_QUALITY")
+                .ok()
+                .and_then(|v| v.parse::<u8>().ok())
+                .map(|quality| quality.clamp(1, 100))
+                .unwrap_or(defaults.quality),
+        }
+    }
+}
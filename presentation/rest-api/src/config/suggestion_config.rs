@@ -0,0 +1,67 @@
+use std::env;
+use std::time::Duration;
+
+use business::application::suggestion::refresh_policy::SuggestionRefreshSettings;
+use business::domain::suggestion::prioritized_pantry::PantryPromptLimits;
+
+/// Configuration for suggestion generation
+#[derive(Debug, Clone)]
+pub struct SuggestionConfig {
+    pub pantry_limits: PantryPromptLimits,
+    /// Regeneration of cached suggestions after significant pantry changes;
+    /// `None` when disabled
+    pub refresh: Option<SuggestionRefreshSettings>,
+}
+
+impl SuggestionConfig {
+    /// Load suggestion configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - SUGGESTION_PROMPT_MAX_PRODUCTS: Distinct products listed in the prompt (default: "40")
+    /// - SUGGESTION_PROMPT_SUMMARIZE_LONG_TAIL: Mention the remaining products by name (default: "true")
+    /// - SUGGESTION_PROMPT_MAX_LONG_TAIL_NAMES: Names included in that summary (default: "30")
+    /// - SUGGESTION_REFRESH_ENABLED: Regenerate suggestions after significant pantry changes (default: "true")
+    /// - SUGGESTION_REFRESH_MIN_PRODUCTS: Products added in one burst that trigger it (default: "3")
+    /// - SUGGESTION_REFRESH_DEBOUNCE_SECS: Quiet time after the last addition before regenerating (default: "600")
+    /// - SUGGESTION_REFRESH_LIMIT: Suggestions generated per refresh (default: "5")
+    pub fn from_env() -> Self {
+        let defaults = PantryPromptLimits::default();
+        let refresh_defaults = SuggestionRefreshSettings::default();
+        Self {
+            pantry_limits: PantryPromptLimits {
+                max_products: env::var("SUGGESTION_PROMPT_MAX_PRODUCTS")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .filter(|n| *n > 0)
+                    .unwrap_or(defaults.max_products),
+                summarize_long_tail: env::var("SUGGESTION_PROMPT_SUMMARIZE_LONG_TAIL")
+                    .map(|v| v != "false")
+                    .unwrap_or(defaults.summarize_long_tail),
+                max_long_tail_names: env::var("SUGGESTION_PROMPT_MAX_LONG_TAIL_NAMES")
+                    .ok()
+                    .and_then(|v| v.parse().ok())
+                    .unwrap_or(defaults.max_long_tail_names),
+            },
+            refresh: env::var("SUGGESTION_REFRESH_ENABLED")
+                .map(|v| v != "false")
+                .unwrap_or(true)
+                .then(|| SuggestionRefreshSettings {
+                    min_added: env::var("SUGGESTION_REFRESH_MIN_PRODUCTS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .filter(|n| *n > 0)
+                        .unwrap_or(refresh_defaults.min_added),
+                    debounce: env::var("SUGGESTION_REFRESH_DEBOUNCE_SECS")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .map(Duration::from_secs)
+                        .unwrap_or(refresh_defaults.debounce),
+                    limit: env::var("SUGGESTION_REFRESH_LIMIT")
+                        .ok()
+                        .and_then(|v| v.parse().ok())
+                        .filter(|n| *n > 0)
+                        .unwrap_or(refresh_defaults.limit),
+                }),
+        }
+    }
+}
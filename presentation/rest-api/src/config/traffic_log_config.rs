@@ -0,0 +1,146 @@
+use std::env;
+
+/// Sampled request/response logging, for debugging client-reported issues.
+/// Off unless explicitly enabled, since bodies can hold personal data.
+#[derive(Debug, Clone)]
+pub struct TrafficLogConfig {
+    pub enabled: bool,
+    /// Share of matching requests (0.0–1.0) that are logged
+    pub sample_rate: f64,
+    /// Bodies are cut after this many bytes
+    pub max_body_bytes: usize,
+    /// Logged requests per minute whose bodies are kept; past it only the
+    /// request line and status are logged
+    pub max_bodies_per_minute: u32,
+    /// Path prefixes to log; empty logs every route
+    pub routes: Vec<String>,
+}
+
+impl Default for TrafficLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sample_rate: 0.01,
+            max_body_bytes: 2048,
+            max_bodies_per_minute: 60,
+            routes: Vec::new(),
+        }
+    }
+}
+
+impl TrafficLogConfig {
+    /// Load traffic logging configuration from environment variables
+    ///
+    /// Environment variables:
+    /// - TRAFFIC_LOG_ENABLED: Log sampled requests and responses (default: "false")
+    /// - TRAFFIC_LOG_SAMPLE_RATE: Share of matching requests logged, 0.0–1.0 (default: "0.01")
+    /// - TRAFFIC_LOG_MAX_BODY_BYTES: Bodies are truncated past this size (default: "2048")
+    /// - TRAFFIC_LOG_MAX_BODIES_PER_MINUTE: Requests per minute logged with bodies (default: "60")
+    /// - TRAFFIC_LOG_ROUTES: Comma-separated path prefixes to log, e.g. "/products,/shopping-items" (default: every route)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: env::var("TRAFFIC_LOG_ENABLED")
+                .map(|v| v == "true")
+                .unwrap_or(defaults.enabled),
+            sample_rate: env::var("TRAFFIC_LOG_SAMPLE_RATE")
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .map(|rate| rate.clamp(0.0, 1.0))
+                .unwrap_or(defaults.sample_rate),
+            max_body_bytes: env::var("TRAFFIC_LOG_MAX_BODY_BYTES")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_body_bytes),
+            max_bodies_per_minute: env::var("TRAFFIC_LOG_MAX_BODIES_PER_MINUTE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(defaults.max_bodies_per_minute),
+            routes: env::var("TRAFFIC_LOG_ROUTES")
+                .map(|v| {
+                    v.split(',')
+                        .map(|route| route.trim().trim_end_matches('/').to_string())
+                        .filter(|route| !route.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Whether requests to `path` may be logged. Health checks and API docs
+    /// never are, nor sign-in, shared-link and blob routes, whose paths,
+    /// queries and bodies carry tokens and signatures.
+    pub fn logs(&self, path: &str) -> bool {
+        let path = path.trim_end_matches('/');
+        let exempt = path == "/health"
+            || path == "/openapi.json"
+            || path.starts_with("/docs")
+            || EXEMPT_ROUTES.iter().any(|route| under(path, route));
+        if !self.enabled || exempt {
+            return false;
+        }
+
+        self.routes.is_empty() || self.routes.iter().any(|route| under(path, route))
+    }
+}
+
+/// Route prefixes never logged, whatever `TRAFFIC_LOG_ROUTES` says
+const EXEMPT_ROUTES: [&str; 3] = ["/auth", "/shared", "/blobs"];
+
+/// Whether `path` is `route` or below it
+fn under(path: &str, route: &str) -> bool {
+    path == route
+        || path
+            .strip_prefix(route)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_log_only_enabled_route_prefixes() {
+        // Arrange
+        let config = TrafficLogConfig {
+            enabled: true,
+            routes: vec!["/products".to_string()],
+            ..TrafficLogConfig::default()
+        };
+
+        // Act & Assert
+        assert!(config.logs("/products"));
+        assert!(config.logs("/products/42"));
+        assert!(!config.logs("/products-archive"));
+        assert!(!config.logs("/shopping-items"));
+    }
+
+    #[test]
+    fn should_never_log_health_or_docs() {
+        // Arrange
+        let config = TrafficLogConfig {
+            enabled: true,
+            ..TrafficLogConfig::default()
+        };
+
+        // Act & Assert
+        assert!(config.logs("/shopping-items"));
+        assert!(!config.logs("/health"));
+        assert!(!config.logs("/docs/index.html"));
+    }
+
+    #[test]
+    fn should_never_log_auth_shared_or_blob_routes() {
+        // Arrange
+        let config = TrafficLogConfig {
+            enabled: true,
+            routes: vec!["/auth".to_string(), "/shared".to_string()],
+            ..TrafficLogConfig::default()
+        };
+
+        // Act & Assert
+        assert!(!config.logs("/auth/magic-link/verify"));
+        assert!(!config.logs("/shared/q3Vh8JmZp0xYcN2L"));
+        assert!(!config.logs("/blobs"));
+    }
+}
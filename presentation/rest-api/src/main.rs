@@ -5,7 +5,7 @@ mod config;
 mod setup;
 
 use config::{app_config::AppConfig, database_config};
-use setup::{dependency_injection::DependencyContainer, server::Server};
+use setup::{dependency_injection::DependencyContainer, scheduler::Scheduler, server::Server};
 
 /// REST API Entry Point
 ///
@@ -15,6 +15,9 @@ use setup::{dependency_injection::DependencyContainer, server::Server};
 /// - config/: Application configuration (server, CORS, database, email)
 /// - setup/: Dependency injection and server setup
 /// - api/: Route handlers and DTOs (to be refactored to adapters/)
+///
+/// With `--doctor`, checks configuration and dependencies instead of serving,
+/// and exits with the readiness code (see `setup::doctor`).
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // 1. Initialize tracing with RUST_LOG env filter
@@ -25,16 +28,39 @@ async fn main() -> anyhow::Result<()> {
     // 2. Load environment variables
     dotenv().ok();
 
+    if std::env::args().any(|arg| arg == "--doctor") {
+        std::process::exit(setup::doctor::run().await);
+    }
+
     // 3. Load configuration
     let config = AppConfig::from_env();
 
     // 4. Initialize database
     let pool = database_config::init_database().await?;
+    let read_pool = database_config::init_read_pool(pool.clone())?;
 
     // 5. Wire dependencies
-    let container = DependencyContainer::new(pool).await?;
+    let container = DependencyContainer::new(pool, read_pool).await?;
+
+    // 6. Start background jobs, which write too and so stay off during maintenance
+    if !config.maintenance.enabled {
+        Scheduler::spawn(
+            config.scheduler.clone(),
+            container.pregenerate_suggestions_use_case.clone(),
+            container.record_snapshots_use_case.clone(),
+            container.record_waste_streaks_use_case.clone(),
+            container.end_due_vacations_use_case.clone(),
+            container.expire_reservations_use_case.clone(),
+            container.prune_stale_devices_use_case.clone(),
+            container.prune_notifications_use_case.clone(),
+            container.send_expiry_alerts_use_case.clone(),
+            container.send_due_reminders_use_case.clone(),
+            config.sandbox.clone(),
+            container.reset_sandbox_use_case.clone(),
+        );
+    }
 
-    // 6. Run server
+    // 7. Run server
     Server::run(config, container).await?;
 
     Ok(())
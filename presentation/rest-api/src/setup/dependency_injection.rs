@@ -1,93 +1,839 @@
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
 
-use logger::TracingLogger;
+use logger::{TracingLogger, TracingMetrics};
+use persistence::ai_review::repository::AiReviewRepositoryPostgres;
+use persistence::auth::repository::AuthRepositoryPostgres;
+use persistence::backup::repository::BackupRepositoryPostgres;
+use persistence::badge::repository::BadgeRepositoryPostgres;
+use persistence::barcode_contribution::repository::BarcodeContributionRepositoryPostgres;
+use persistence::billing::repository::PlanRepositoryPostgres;
+use persistence::budget::repository::BudgetRepositoryPostgres;
+use persistence::challenge::repository::ChallengeRepositoryPostgres;
+use persistence::cooking_session::repository::CookingSessionRepositoryPostgres;
+use persistence::db::ReadPool;
+use persistence::device::repository::DeviceRepositoryPostgres;
+use persistence::experiment::repository::AiCallRepositoryPostgres;
+use persistence::inbound_email::repository::InboundAddressRepositoryPostgres;
+use persistence::job::repository::JobRepositoryPostgres;
+use persistence::location_rule::repository::LocationRuleRepositoryPostgres;
+use persistence::meal_plan::repository::MealPlanRepositoryPostgres;
+use persistence::notification::repository::NotificationInboxRepositoryPostgres;
+use persistence::preference::repository::PreferenceRepositoryPostgres;
 use persistence::product::repository::ProductRepositoryPostgres;
+use persistence::quota::service::QuotaServicePostgres;
+use persistence::receipt_import::repository::ReceiptImportRepositoryPostgres;
+use persistence::reminder::repository::ReminderRepositoryPostgres;
+use persistence::sandbox::repository::SandboxRepositoryPostgres;
+use persistence::share_link::repository::ShareLinkRepositoryPostgres;
 use persistence::shopping_item::repository::ShoppingItemRepositoryPostgres;
+use persistence::shopping_trip::repository::ShoppingTripRepositoryPostgres;
+use persistence::stats::repository::StatsRepositoryPostgres;
+use persistence::store_profile::repository::StoreProfileRepositoryPostgres;
+use persistence::suggestion::repository::SuggestionRepositoryPostgres;
+use persistence::vacation::repository::VacationRepositoryPostgres;
+use persistence::widget::repository::WidgetRepositoryPostgres;
 
+use auth::mailer::{LogMailer, WebhookMailer};
+use auth::tokens::LocalTokens;
+use auth::webauthn::WebAuthnVerifier;
+
+use billing::client::StripeClient;
+use billing::plan_provider::StripePlanProvider;
+
+use inbound_email::mailgun::MailgunVerifier;
+
+use notifier::sender::{LogNotifier, WebhookNotifier};
+
+use ocr::tesseract::TesseractOcr;
+
+use openai::canned::CannedAi;
+use openai::chaos::Chaos;
 use openai::client::OpenAIClient;
 use openai::expiry_estimator::ExpiryEstimatorOpenAI;
+use openai::open_food_facts::OpenFoodFactsContributor;
 use openai::product_identifier::ProductIdentifierOpenAI;
+use openai::prompts::{Prompt, PromptTemplates};
 use openai::receipt_scanner::ReceiptScannerOpenAI;
 use openai::suggestion_generator::SuggestionGeneratorOpenAI;
 
+use storage::local::LocalDiskStorage;
+use storage::s3::S3Storage;
+use storage::thumbnail::ImageThumbnailer;
+
+use business::application::ai_review::accept::AcceptAiChangeUseCaseImpl;
+use business::application::ai_review::get_mode::GetAiWriteModeUseCaseImpl;
+use business::application::ai_review::get_pending::GetPendingAiChangesUseCaseImpl;
+use business::application::ai_review::reject::RejectAiChangeUseCaseImpl;
+use business::application::ai_review::set_mode::SetAiWriteModeUseCaseImpl;
+use business::application::auth::finish_passkey_login::FinishPasskeyLoginUseCaseImpl;
+use business::application::auth::finish_passkey_registration::FinishPasskeyRegistrationUseCaseImpl;
+use business::application::auth::request_magic_link::RequestMagicLinkUseCaseImpl;
+use business::application::auth::start_passkey_login::StartPasskeyLoginUseCaseImpl;
+use business::application::auth::start_passkey_registration::StartPasskeyRegistrationUseCaseImpl;
+use business::application::auth::verify_magic_link::VerifyMagicLinkUseCaseImpl;
+use business::application::backup::export::ExportBackupUseCaseImpl;
+use business::application::backup::restore::RestoreBackupUseCaseImpl;
+use business::application::badge::get_badges::GetBadgesUseCaseImpl;
+use business::application::barcode_contribution::submit::SubmitBarcodeContributionUseCaseImpl;
+use business::application::billing::create_checkout::CreateCheckoutUseCaseImpl;
+use business::application::billing::handle_webhook::HandleWebhookUseCaseImpl;
+use business::application::budget::alert_policy::BudgetAlertPolicy;
+use business::application::budget::delete::DeleteBudgetUseCaseImpl;
+use business::application::budget::get_status::GetBudgetStatusUseCaseImpl;
+use business::application::budget::set::SetBudgetUseCaseImpl;
+use business::application::challenge::get_current::GetCurrentChallengeUseCaseImpl;
+use business::application::challenge::get_history::GetChallengeHistoryUseCaseImpl;
+use business::application::challenge::progress_policy::ChallengeProgressPolicy;
+use business::application::cooking_session::advance::AdvanceCookingStepUseCaseImpl;
+use business::application::cooking_session::complete::CompleteCookingSessionUseCaseImpl;
+use business::application::cooking_session::complete_step::CompleteCookingStepUseCaseImpl;
+use business::application::cooking_session::get_by_id::GetCookingSessionUseCaseImpl;
+use business::application::cooking_session::start::StartCookingUseCaseImpl;
+use business::application::device::get_active_installs::GetActiveInstallsUseCaseImpl;
+use business::application::device::prune_stale::PruneStaleDevicesUseCaseImpl;
+use business::application::device::register::RegisterDeviceUseCaseImpl;
+use business::application::events::in_process::InProcessEventBus;
+use business::application::experiment::accept_identification::AcceptIdentificationUseCaseImpl;
+use business::application::experiment::ai_experiment::AiExperiment;
+use business::application::experiment::get_results::GetExperimentResultsUseCaseImpl;
+use business::application::feature_flag::get_client_config::GetClientConfigUseCaseImpl;
+use business::application::inbound_email::get_address::GetInboundAddressUseCaseImpl;
+use business::application::inbound_email::receive::ReceiveInboundEmailUseCaseImpl;
+use business::application::inbound_email::rotate_address::RotateInboundAddressUseCaseImpl;
+use business::application::job::get_by_id::GetJobUseCaseImpl;
+use business::application::location_rule::get::GetLocationRulesUseCaseImpl;
+use business::application::location_rule::replace::ReplaceLocationRulesUseCaseImpl;
+use business::application::meal_plan::expire_reservations::ExpireReservationsUseCaseImpl;
+use business::application::meal_plan::plan::PlanSuggestionUseCaseImpl;
+use business::application::notification::acknowledge::AcknowledgeNotificationsUseCaseImpl;
+use business::application::notification::dispatcher::NotificationDispatcher;
+use business::application::notification::get_preferences::GetNotificationPreferencesUseCaseImpl;
+use business::application::notification::prune::PruneNotificationsUseCaseImpl;
+use business::application::notification::send_expiry_alerts::SendExpiryAlertsUseCaseImpl;
+use business::application::notification::update_preferences::UpdateNotificationPreferencesUseCaseImpl;
+use business::application::preference::get::GetPreferencesUseCaseImpl;
+use business::application::preference::update::UpdatePreferencesUseCaseImpl;
+use business::application::product::bulk_move::BulkMoveProductsUseCaseImpl;
+use business::application::product::change_feed::ProductChangeFeed;
 use business::application::product::create::CreateProductUseCaseImpl;
+use business::application::product::create_batch::CreateProductsBatchUseCaseImpl;
 use business::application::product::delete::DeleteProductUseCaseImpl;
 use business::application::product::estimate_expiry::EstimateExpiryUseCaseImpl;
+use business::application::product::estimate_expiry_batch::EstimateExpiryBatchUseCaseImpl;
+use business::application::product::estimate_expiry_for_attributes::EstimateExpiryForAttributesUseCaseImpl;
+use business::application::product::estimate_missing::{
+    EstimateMissingRunner, EstimateMissingUseCaseImpl,
+};
+use business::application::product::estimation_retry::EstimationRetryRunner;
+use business::application::product::flag_unwanted::FlagUnwantedUseCaseImpl;
 use business::application::product::get_all::GetAllProductsUseCaseImpl;
+use business::application::product::get_allergen_warnings::GetAllergenWarningsUseCaseImpl;
 use business::application::product::get_by_id::GetProductByIdUseCaseImpl;
+use business::application::product::get_calendar::GetExpiryCalendarUseCaseImpl;
+use business::application::product::get_duplicates::GetDuplicateProductsUseCaseImpl;
+use business::application::product::get_facets::GetProductFacetsUseCaseImpl;
+use business::application::product::get_give_away::GetGiveAwayCandidatesUseCaseImpl;
+use business::application::product::get_history::GetProductHistoryUseCaseImpl;
+use business::application::product::get_image::GetProductImageUseCaseImpl;
+use business::application::product::get_thumbnails::GetProductThumbnailsUseCaseImpl;
 use business::application::product::identify::IdentifyProductUseCaseImpl;
+use business::application::product::merge_duplicates::MergeDuplicateProductsUseCaseImpl;
+use business::application::product::ocr_fallback::OcrFallbackScanner;
+use business::application::product::patch::PatchProductUseCaseImpl;
+use business::application::product::profiled_scanner::ProfiledReceiptScanner;
+use business::application::product::propose_from_photo::ProposeFromPhotoUseCaseImpl;
 use business::application::product::scan_receipt::ScanReceiptUseCaseImpl;
+use business::application::product::seed::SeedProductsUseCaseImpl;
+use business::application::product::subscribe_changes::SubscribeProductChangesUseCaseImpl;
 use business::application::product::update::UpdateProductUseCaseImpl;
+use business::application::product::upload_image::UploadProductImageUseCaseImpl;
+use business::application::prompt::reload::ReloadPromptTemplatesUseCaseImpl;
+use business::application::quota::get_usage::GetUsageUseCaseImpl;
+use business::application::receipt_import::get_by_id::GetReceiptImportUseCaseImpl;
+use business::application::receipt_import::get_image::GetReceiptImageUseCaseImpl;
+use business::application::receipt_import::pipeline::ReceiptImportPipeline;
+use business::application::receipt_import::start::StartReceiptImportUseCaseImpl;
+use business::application::reminder::create::CreateReminderUseCaseImpl;
+use business::application::reminder::delete::DeleteReminderUseCaseImpl;
+use business::application::reminder::get_all::GetRemindersUseCaseImpl;
+use business::application::reminder::send_due::SendDueRemindersUseCaseImpl;
+use business::application::reminder::update::UpdateReminderUseCaseImpl;
+use business::application::sandbox::reset::ResetSandboxUseCaseImpl;
+use business::application::search::global::GlobalSearchUseCaseImpl;
+use business::application::share_link::create::CreateShareLinkUseCaseImpl;
+use business::application::share_link::get_shared_view::GetSharedViewUseCaseImpl;
+use business::application::share_link::revoke::RevokeShareLinkUseCaseImpl;
+use business::application::shopping_item::attach::AttachToShoppingItemUseCaseImpl;
 use business::application::shopping_item::clear_bought::ClearBoughtItemsUseCaseImpl;
 use business::application::shopping_item::create::CreateShoppingItemUseCaseImpl;
+use business::application::shopping_item::create_from_barcode::CreateShoppingItemFromBarcodeUseCaseImpl;
 use business::application::shopping_item::delete::DeleteShoppingItemUseCaseImpl;
 use business::application::shopping_item::get_all::GetAllShoppingItemsUseCaseImpl;
+use business::application::shopping_item::get_attachment_urls::GetAttachmentUrlsUseCaseImpl;
+use business::application::shopping_item::restock_policy::ShoppingListRestockPolicy;
 use business::application::shopping_item::update::UpdateShoppingItemUseCaseImpl;
+use business::application::shopping_trip::finish::FinishShoppingTripUseCaseImpl;
+use business::application::shopping_trip::record_item::RecordTripItemUseCaseImpl;
+use business::application::shopping_trip::start::StartShoppingTripUseCaseImpl;
+use business::application::stats::get_inventory_trends::GetInventoryTrendsUseCaseImpl;
+use business::application::stats::get_inventory_value::GetInventoryValueUseCaseImpl;
+use business::application::stats::get_waste_streak::GetWasteStreakUseCaseImpl;
+use business::application::stats::record_snapshots::RecordInventorySnapshotsUseCaseImpl;
+use business::application::stats::record_waste_streaks::RecordWasteStreaksUseCaseImpl;
+use business::application::storage::download::DownloadBlobUseCaseImpl;
+use business::application::storage::thumbnail::ThumbnailJob;
+use business::application::store_profile::activate::ActivateStoreProfileUseCaseImpl;
+use business::application::store_profile::create::CreateStoreProfileUseCaseImpl;
+use business::application::store_profile::delete::DeleteStoreProfileUseCaseImpl;
+use business::application::store_profile::get_all::GetAllStoreProfilesUseCaseImpl;
+use business::application::store_profile::update::UpdateStoreProfileUseCaseImpl;
 use business::application::suggestion::generate::GenerateSuggestionsUseCaseImpl;
+use business::application::suggestion::get_history::GetSuggestionHistoryUseCaseImpl;
+use business::application::suggestion::pregenerate::PregenerateSuggestionsUseCaseImpl;
+use business::application::suggestion::refresh_policy::SuggestionRefreshPolicy;
+use business::application::vacation::clean_up::CleanUpVacationUseCaseImpl;
+use business::application::vacation::end_due::EndDueVacationsUseCaseImpl;
+use business::application::vacation::get::GetVacationModeUseCaseImpl;
+use business::application::vacation::get_report::GetVacationReportUseCaseImpl;
+use business::application::vacation::set::SetVacationModeUseCaseImpl;
+use business::application::widget::get_snapshot::GetWidgetSnapshotUseCaseImpl;
+use business::application::widget::issue_token::IssueWidgetTokenUseCaseImpl;
+use business::application::widget::revoke_token::RevokeWidgetTokenUseCaseImpl;
+use business::domain::auth::services::MagicLinkSender;
+use business::domain::barcode_contribution::services::ProductDatabaseContributor;
+use business::domain::device::use_cases::prune_stale::PruneStaleDevicesUseCase;
+use business::domain::events::EventHandler;
+use business::domain::experiment::model::{AiFeature, Experiment, Variant};
+use business::domain::meal_plan::use_cases::expire_reservations::ExpireReservationsUseCase;
+use business::domain::notification::services::NotificationSender;
+use business::domain::notification::use_cases::prune::PruneNotificationsUseCase;
+use business::domain::notification::use_cases::send_expiry_alerts::SendExpiryAlertsUseCase;
+use business::domain::product::local_day;
+use business::domain::product::services::{
+    ExpiryEstimatorService, ProductIdentifierService, ReceiptScannerService,
+};
+use business::domain::reminder::use_cases::send_due::SendDueRemindersUseCase;
+use business::domain::sandbox::use_cases::reset::ResetSandboxUseCase;
+use business::domain::stats::use_cases::record_snapshots::RecordInventorySnapshotsUseCase;
+use business::domain::stats::use_cases::record_waste_streaks::RecordWasteStreaksUseCase;
+use business::domain::storage::services::BlobStorage;
+use business::domain::suggestion::services::SuggestionGeneratorService;
+use business::domain::suggestion::use_cases::pregenerate::PregenerateSuggestionsUseCase;
+use business::domain::vacation::use_cases::end_due::EndDueVacationsUseCase;
 
+use crate::api::auth::routes::LocalSignIn;
+use crate::api::inbound_email::routes::InboundEmailUseCases;
+use crate::config::admin_config::AdminConfig;
+use crate::config::ai_chaos_config::AiChaosConfig;
+use crate::config::app_version_config::AppVersionConfig;
+use crate::config::auth_config::AuthConfig;
+use crate::config::billing_config::BillingConfig;
+use crate::config::challenge_config::ChallengeConfig;
+use crate::config::experiment_config::ExperimentConfig;
+use crate::config::expiry_config::ExpiryConfig;
+use crate::config::feature_flag_config::FeatureFlagConfig;
+use crate::config::inbound_email_config::InboundEmailConfig;
+use crate::config::load_test_config::LoadTestConfig;
+use crate::config::notification_config::NotificationConfig;
+use crate::config::ocr_config::OcrConfig;
+use crate::config::open_food_facts_config::OpenFoodFactsConfig;
 use crate::config::openai_config::OpenAIConfig;
+use crate::config::payload_config::PayloadConfig;
+use crate::config::preference_config::PreferenceConfig;
+use crate::config::product_config::ProductConfig;
+use crate::config::prompt_config::PromptConfig;
+use crate::config::receipt_profile_config::ReceiptProfileConfig;
+use crate::config::sandbox_config::SandboxConfig;
+use crate::config::stats_config::StatsConfig;
+use crate::config::storage_config::StorageConfig;
+use crate::config::suggestion_config::SuggestionConfig;
 
 pub struct DependencyContainer {
     pub health_api: crate::api::health::routes::Api,
     pub product_api: crate::api::product::routes::ProductApi,
+    pub barcode_contribution_api: crate::api::barcode_contribution::routes::BarcodeContributionApi,
+    pub ai_review_api: crate::api::ai_review::routes::AiReviewApi,
+    pub receipt_import_api: crate::api::receipt_import::routes::ReceiptImportApi,
     pub shopping_item_api: crate::api::shopping_item::routes::ShoppingItemApi,
+    pub inbound_email_api: crate::api::inbound_email::routes::InboundEmailApi,
+    pub shopping_trip_api: crate::api::shopping_trip::routes::ShoppingTripApi,
+    pub store_profile_api: crate::api::store_profile::routes::StoreProfileApi,
+    pub location_rule_api: crate::api::location_rule::routes::LocationRuleApi,
+    pub preference_api: crate::api::preference::routes::PreferenceApi,
+    pub notification_api: crate::api::notification::routes::NotificationApi,
+    pub vacation_api: crate::api::vacation::routes::VacationApi,
+    pub device_api: crate::api::device::routes::DeviceApi,
+    pub budget_api: crate::api::budget::routes::BudgetApi,
+    pub search_api: crate::api::search::routes::SearchApi,
+    pub prompt_template_api: crate::api::prompt::routes::PromptTemplateApi,
+    pub experiment_api: crate::api::experiment::routes::ExperimentApi,
+    pub reminder_api: crate::api::reminder::routes::ReminderApi,
+    pub give_away_api: crate::api::give_away::routes::GiveAwayApi,
+    pub duplicate_api: crate::api::duplicate::routes::DuplicateApi,
+    pub job_api: crate::api::job::routes::JobApi,
     pub suggestion_api: crate::api::suggestion::routes::SuggestionApi,
+    pub cooking_session_api: crate::api::cooking_session::routes::CookingSessionApi,
+    pub meal_plan_api: crate::api::meal_plan::routes::MealPlanApi,
+    pub share_link_api: crate::api::share_link::routes::ShareLinkApi,
+    pub shared_view_api: crate::api::share_link::routes::SharedViewApi,
+    pub auth_api: crate::api::auth::routes::AuthApi,
+    pub me_api: crate::api::me::routes::MeApi,
+    pub client_config_api: crate::api::client_config::routes::ClientConfigApi,
+    pub backup_api: crate::api::backup::routes::BackupApi,
+    pub blob_api: crate::api::storage::routes::BlobApi,
+    pub billing_api: crate::api::billing::routes::BillingApi,
+    pub badge_api: crate::api::badge::routes::BadgeApi,
+    pub challenge_api: crate::api::challenge::routes::ChallengeApi,
+    pub stats_api: crate::api::stats::routes::StatsApi,
+    pub load_test_api: crate::api::load_test::routes::LoadTestApi,
+    pub widget_api: crate::api::widget::routes::WidgetApi,
+    pub pregenerate_suggestions_use_case: Arc<dyn PregenerateSuggestionsUseCase>,
+    pub record_snapshots_use_case: Arc<dyn RecordInventorySnapshotsUseCase>,
+    pub record_waste_streaks_use_case: Arc<dyn RecordWasteStreaksUseCase>,
+    pub reset_sandbox_use_case: Arc<dyn ResetSandboxUseCase>,
+    pub end_due_vacations_use_case: Arc<dyn EndDueVacationsUseCase>,
+    pub expire_reservations_use_case: Arc<dyn ExpireReservationsUseCase>,
+    pub prune_stale_devices_use_case: Arc<dyn PruneStaleDevicesUseCase>,
+    pub prune_notifications_use_case: Arc<dyn PruneNotificationsUseCase>,
+    pub send_expiry_alerts_use_case: Arc<dyn SendExpiryAlertsUseCase>,
+    pub send_due_reminders_use_case: Arc<dyn SendDueRemindersUseCase>,
 }
 
 impl DependencyContainer {
-    pub async fn new(pool: sqlx::PgPool) -> anyhow::Result<Self> {
-        let logger = Arc::new(TracingLogger);
+    pub async fn new(pool: sqlx::PgPool, read_pool: ReadPool) -> anyhow::Result<Self> {
+        let logger = Arc::new(TracingLogger::default());
+        let metrics = Arc::new(TracingMetrics);
         let health_api = crate::api::health::routes::Api::new();
 
         // Infrastructure adapters
-        let product_repository = Arc::new(ProductRepositoryPostgres::new(pool.clone()));
-        let shopping_item_repository = Arc::new(ShoppingItemRepositoryPostgres::new(pool));
+        let product_repository =
+            Arc::new(ProductRepositoryPostgres::new(pool.clone()).with_reads(read_pool.clone()));
+        let shopping_item_repository = Arc::new(ShoppingItemRepositoryPostgres::new(pool.clone()));
+        let store_profile_repository = Arc::new(StoreProfileRepositoryPostgres::new(pool.clone()));
+        let ai_review_repository = Arc::new(AiReviewRepositoryPostgres::new(pool.clone()));
+        let location_rule_repository = Arc::new(LocationRuleRepositoryPostgres::new(pool.clone()));
+        let suggestion_repository = Arc::new(SuggestionRepositoryPostgres::new(pool.clone()));
+        let cooking_session_repository =
+            Arc::new(CookingSessionRepositoryPostgres::new(pool.clone()));
+        let meal_plan_repository = Arc::new(MealPlanRepositoryPostgres::new(pool.clone()));
+        let share_link_repository = Arc::new(ShareLinkRepositoryPostgres::new(pool.clone()));
+        let quota_service = Arc::new(QuotaServicePostgres::new(pool.clone()));
+        let receipt_import_repository =
+            Arc::new(ReceiptImportRepositoryPostgres::new(pool.clone()));
+        let badge_repository = Arc::new(BadgeRepositoryPostgres::new(pool.clone()));
+        let challenge_repository = Arc::new(ChallengeRepositoryPostgres::new(pool.clone()));
+        let stats_repository =
+            Arc::new(StatsRepositoryPostgres::new(pool.clone()).with_reads(read_pool));
+        let job_repository = Arc::new(JobRepositoryPostgres::new(pool.clone()));
+        let backup_repository = Arc::new(BackupRepositoryPostgres::new(pool.clone()));
+        let sandbox_repository = Arc::new(SandboxRepositoryPostgres::new(pool.clone()));
+        let auth_repository = Arc::new(AuthRepositoryPostgres::new(pool.clone()));
+        let reminder_repository = Arc::new(ReminderRepositoryPostgres::new(pool.clone()));
+        let shopping_trip_repository = Arc::new(ShoppingTripRepositoryPostgres::new(pool.clone()));
+        let preference_repository = Arc::new(PreferenceRepositoryPostgres::new(
+            pool.clone(),
+            PreferenceConfig::from_env().defaults,
+        ));
+        let widget_repository = Arc::new(WidgetRepositoryPostgres::new(pool.clone()));
+        let contribution_repository =
+            Arc::new(BarcodeContributionRepositoryPostgres::new(pool.clone()));
+        let vacation_repository = Arc::new(VacationRepositoryPostgres::new(pool.clone()));
+        let device_repository = Arc::new(DeviceRepositoryPostgres::new(pool.clone()));
+        let notification_inbox_repository =
+            Arc::new(NotificationInboxRepositoryPostgres::new(pool.clone()));
+        let budget_repository = Arc::new(BudgetRepositoryPostgres::new(pool.clone()));
+        let ai_call_repository = Arc::new(AiCallRepositoryPostgres::new(pool.clone()));
+        let inbound_address_repository =
+            Arc::new(InboundAddressRepositoryPostgres::new(pool.clone()));
+        let plan_repository = Arc::new(PlanRepositoryPostgres::new(pool));
+
+        let feature_flags = FeatureFlagConfig::from_env().flags;
 
         let openai_config = OpenAIConfig::from_env();
-        let openai_client = OpenAIClient::new(openai_config.api_key.clone());
-        let openai_client_2 = OpenAIClient::new(openai_config.api_key.clone());
-        let openai_client_3 = OpenAIClient::new(openai_config.api_key.clone());
-        let openai_client_4 = OpenAIClient::new(openai_config.api_key);
+        let openai_client = OpenAIClient::new(openai_config.api_key, openai_config.client)?;
+
+        // System prompts come from templates that can be reloaded without a restart
+        let prompt_config = PromptConfig::from_env();
+        let prompt_templates = Arc::new(PromptTemplates::load(
+            prompt_config.templates_dir,
+            prompt_config.context,
+        )?);
+
+        let expiry_estimator =
+            ExpiryEstimatorOpenAI::new(openai_client.clone(), prompt_templates.clone());
+        let product_identifier =
+            ProductIdentifierOpenAI::new(openai_client.clone(), prompt_templates.clone());
+        let receipt_scanner =
+            ReceiptScannerOpenAI::new(openai_client.clone(), prompt_templates.clone());
+        let suggestion_generator =
+            SuggestionGeneratorOpenAI::new(openai_client.clone(), prompt_templates.clone());
+
+        // Chaos mode puts a fault-injecting decorator in front of every AI adapter
+        let chaos = AiChaosConfig::from_env().settings;
+        if let Some(settings) = &chaos {
+            tracing::warn!("AI chaos mode is enabled: {settings:?}");
+        }
+        let expiry_estimator: Arc<dyn ExpiryEstimatorService> = match chaos.clone() {
+            Some(settings) => Arc::new(Chaos::new(expiry_estimator, settings)),
+            None => Arc::new(expiry_estimator),
+        };
+        let product_identifier: Arc<dyn ProductIdentifierService> = match chaos.clone() {
+            Some(settings) => Arc::new(Chaos::new(product_identifier, settings)),
+            None => Arc::new(product_identifier),
+        };
+        let receipt_scanner: Arc<dyn ReceiptScannerService> = match chaos.clone() {
+            Some(settings) => Arc::new(Chaos::new(receipt_scanner, settings)),
+            None => Arc::new(receipt_scanner),
+        };
+        let suggestion_generator: Arc<dyn SuggestionGeneratorService> = match chaos.clone() {
+            Some(settings) => Arc::new(Chaos::new(suggestion_generator, settings)),
+            None => Arc::new(suggestion_generator),
+        };
+
+        // With tesseract installed, receipts are still read when the vision model fails
+        // or the user's AI calls run out
+        let receipt_scanner: Arc<dyn ReceiptScannerService> = match OcrConfig::from_env().tesseract
+        {
+            Some(settings) => Arc::new(OcrFallbackScanner {
+                scanner: receipt_scanner,
+                recognizer: Arc::new(TesseractOcr::new(settings)),
+                logger: logger.clone(),
+            }),
+            None => receipt_scanner,
+        };
+        let receipt_scanner: Arc<dyn ReceiptScannerService> = Arc::new(ProfiledReceiptScanner {
+            scanner: receipt_scanner,
+            profiles: ReceiptProfileConfig::from_env().profiles,
+            logger: logger.clone(),
+        });
+
+        // The sandbox never reaches a provider: every AI call is answered from fixtures
+        let sandbox_config = SandboxConfig::from_env();
+        let canned = sandbox_config.demo_user_id.is_some();
+        if let Some(demo_user_id) = &sandbox_config.demo_user_id {
+            tracing::warn!("Sandbox mode is enabled, acting as {demo_user_id}");
+        }
+        let expiry_estimator: Arc<dyn ExpiryEstimatorService> = if canned {
+            Arc::new(CannedAi)
+        } else {
+            expiry_estimator
+        };
+        let product_identifier: Arc<dyn ProductIdentifierService> = if canned {
+            Arc::new(CannedAi)
+        } else {
+            product_identifier
+        };
+        let receipt_scanner: Arc<dyn ReceiptScannerService> = if canned {
+            Arc::new(CannedAi)
+        } else {
+            receipt_scanner
+        };
+        let suggestion_generator: Arc<dyn SuggestionGeneratorService> = if canned {
+            Arc::new(CannedAi)
+        } else {
+            suggestion_generator
+        };
+
+        // Prompt and model experiments; the sandbox's canned answers leave nothing to compare
+        let experiment_config = ExperimentConfig::from_env();
+        let identification_experiment = experiment_config
+            .experiment(AiFeature::ProductIdentification)
+            .filter(|_| !canned);
+        let identification_variants = variant_services(
+            identification_experiment.as_ref(),
+            Prompt::ProductIdentifier,
+            &prompt_templates,
+            |variant| {
+                let identifier =
+                    ProductIdentifierOpenAI::new(openai_client.clone(), prompt_templates.clone())
+                        .with_variant(variant.model.clone(), variant.prompt_version);
+                let identifier: Arc<dyn ProductIdentifierService> = match chaos.clone() {
+                    Some(settings) => Arc::new(Chaos::new(identifier, settings)),
+                    None => Arc::new(identifier),
+                };
+                identifier
+            },
+        )?;
+        let identification_experiment = Arc::new(AiExperiment {
+            experiment: identification_experiment,
+            variant_services: identification_variants,
+            ai_call_repository: ai_call_repository.clone(),
+            logger: logger.clone(),
+        });
+        let suggestion_experiment = experiment_config
+            .experiment(AiFeature::Suggestions)
+            .filter(|_| !canned);
+        let suggestion_variants = variant_services(
+            suggestion_experiment.as_ref(),
+            Prompt::SuggestionGenerator,
+            &prompt_templates,
+            |variant| {
+                let generator =
+                    SuggestionGeneratorOpenAI::new(openai_client.clone(), prompt_templates.clone())
+                        .with_variant(variant.model.clone(), variant.prompt_version);
+                let generator: Arc<dyn SuggestionGeneratorService> = match chaos.clone() {
+                    Some(settings) => Arc::new(Chaos::new(generator, settings)),
+                    None => Arc::new(generator),
+                };
+                generator
+            },
+        )?;
+        let suggestion_experiment = Arc::new(AiExperiment {
+            experiment: suggestion_experiment,
+            variant_services: suggestion_variants,
+            ai_call_repository: ai_call_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Unknown barcodes users name are also sent to Open Food Facts with an account;
+        // the sandbox keeps them local like every other outside call
+        let product_database_contributor: Option<Arc<dyn ProductDatabaseContributor>> =
+            match OpenFoodFactsConfig::from_env().credentials {
+                Some(credentials) if !canned => Some(Arc::new(OpenFoodFactsContributor::new(
+                    openai_client,
+                    credentials,
+                ))),
+                _ => None,
+            };
 
-        let expiry_estimator = Arc::new(ExpiryEstimatorOpenAI::new(openai_client));
-        let product_identifier = Arc::new(ProductIdentifierOpenAI::new(openai_client_2));
-        let receipt_scanner = Arc::new(ReceiptScannerOpenAI::new(openai_client_3));
-        let suggestion_generator = Arc::new(SuggestionGeneratorOpenAI::new(openai_client_4));
+        let suggestion_config = SuggestionConfig::from_env();
+        let expiry_config = ExpiryConfig::from_env();
+        local_day::configure(expiry_config.time_zone);
+        let product_config = ProductConfig::from_env();
+        let challenge_config = ChallengeConfig::from_env();
+
+        let billing_config = BillingConfig::from_env();
+        let plan_provider = Arc::new(StripePlanProvider::new(
+            StripeClient::new(billing_config.stripe_secret_key),
+            billing_config.stripe_price_id,
+            billing_config.success_url,
+            billing_config.cancel_url,
+            billing_config.stripe_webhook_secret,
+        ));
+
+        let blob_storage: Arc<dyn BlobStorage> = match StorageConfig::from_env() {
+            StorageConfig::LocalDisk(settings) => Arc::new(LocalDiskStorage::new(settings)),
+            StorageConfig::S3(settings) => Arc::new(S3Storage::new(settings)),
+        };
+        let thumbnail_job = Arc::new(ThumbnailJob {
+            storage: blob_storage.clone(),
+            thumbnailer: Arc::new(ImageThumbnailer::new(StorageConfig::thumbnails_from_env())),
+            logger: logger.clone(),
+        });
+
+        // Generation is shared by the suggestion endpoints, the morning job
+        // and the refresh policy below
+        let generate_suggestions_use_case = Arc::new(GenerateSuggestionsUseCaseImpl {
+            repository: product_repository.clone(),
+            suggestion_repository: suggestion_repository.clone(),
+            preference_repository: preference_repository.clone(),
+            enrichment_repository: product_repository.clone(),
+            meal_plan_repository: meal_plan_repository.clone(),
+            generator: suggestion_generator,
+            experiment: suggestion_experiment,
+            quota_service: quota_service.clone(),
+            pantry_limits: suggestion_config.pantry_limits,
+            metrics,
+            logger: logger.clone(),
+        });
+
+        let notification_config = NotificationConfig::from_env();
+        let notification_sender: Arc<dyn NotificationSender> = match notification_config.webhook {
+            Some(settings) => Arc::new(WebhookNotifier::new(settings)),
+            None => Arc::new(LogNotifier {
+                logger: logger.clone(),
+            }),
+        };
+
+        let notification_dispatcher = Arc::new(NotificationDispatcher {
+            preference_repository: preference_repository.clone(),
+            vacation_repository: vacation_repository.clone(),
+            device_repository: device_repository.clone(),
+            inbox_repository: notification_inbox_repository.clone(),
+            sender: notification_sender,
+            logger: logger.clone(),
+        });
+
+        // Domain event handlers
+        let product_change_feed = Arc::new(ProductChangeFeed::default());
+        let mut event_handlers: Vec<Arc<dyn EventHandler>> = vec![
+            product_change_feed.clone(),
+            Arc::new(ShoppingListRestockPolicy {
+                shopping_item_repository: shopping_item_repository.clone(),
+                storage: blob_storage.clone(),
+                logger: logger.clone(),
+            }),
+            notification_dispatcher.clone(),
+        ];
+        if let Some(settings) = suggestion_config.refresh {
+            event_handlers.push(Arc::new(SuggestionRefreshPolicy::new(
+                generate_suggestions_use_case.clone(),
+                settings,
+                logger.clone(),
+            )));
+        }
+        let event_bus = Arc::new_cyclic(|bus: &Weak<InProcessEventBus>| {
+            event_handlers.push(Arc::new(ChallengeProgressPolicy {
+                repository: challenge_repository.clone(),
+                settings: challenge_config.settings,
+                event_publisher: bus.clone(),
+                logger: logger.clone(),
+            }));
+            event_handlers.push(Arc::new(BudgetAlertPolicy {
+                repository: budget_repository.clone(),
+                event_publisher: bus.clone(),
+                logger: logger.clone(),
+            }));
+            InProcessEventBus {
+                handlers: event_handlers,
+            }
+        });
 
         // Product use cases
+        let estimation_retry = expiry_config.estimation_retry.map(|backoff| {
+            Arc::new(EstimationRetryRunner {
+                repository: product_repository.clone(),
+                job_repository: job_repository.clone(),
+                estimator: expiry_estimator.clone(),
+                expiry_snap: expiry_config.snap,
+                ai_review_repository: ai_review_repository.clone(),
+                backoff,
+                event_publisher: event_bus.clone(),
+                logger: logger.clone(),
+            })
+        });
         let create_use_case = Arc::new(CreateProductUseCaseImpl {
             repository: product_repository.clone(),
             estimator: expiry_estimator.clone(),
+            expiry_snap: expiry_config.snap,
+            duplicate_window: product_config.duplicate_window,
+            quota_service: quota_service.clone(),
+            flags: feature_flags.clone(),
+            location_rules: location_rule_repository.clone(),
+            ai_review_repository: ai_review_repository.clone(),
+            enrichment_repository: product_repository.clone(),
+            estimation_retry,
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let create_batch_use_case = Arc::new(CreateProductsBatchUseCaseImpl {
+            create: create_use_case.clone(),
+            batch_repository: product_repository.clone(),
             logger: logger.clone(),
         });
         let get_all_use_case = Arc::new(GetAllProductsUseCaseImpl {
             repository: product_repository.clone(),
+            preference_repository: preference_repository.clone(),
             logger: logger.clone(),
         });
         let get_by_id_use_case = Arc::new(GetProductByIdUseCaseImpl {
             repository: product_repository.clone(),
             logger: logger.clone(),
         });
+        let get_product_history_use_case = Arc::new(GetProductHistoryUseCaseImpl {
+            repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_expiry_calendar_use_case = Arc::new(GetExpiryCalendarUseCaseImpl {
+            repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_give_away_candidates_use_case = Arc::new(GetGiveAwayCandidatesUseCaseImpl {
+            repository: product_repository.clone(),
+            preference_repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+        let flag_unwanted_use_case = Arc::new(FlagUnwantedUseCaseImpl {
+            repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_duplicate_products_use_case = Arc::new(GetDuplicateProductsUseCaseImpl {
+            repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
         let update_use_case = Arc::new(UpdateProductUseCaseImpl {
             repository: product_repository.clone(),
-            shopping_item_repository: shopping_item_repository.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let patch_use_case = Arc::new(PatchProductUseCaseImpl {
+            repository: product_repository.clone(),
+            update_use_case: update_use_case.clone(),
             logger: logger.clone(),
         });
         let delete_use_case = Arc::new(DeleteProductUseCaseImpl {
             repository: product_repository.clone(),
+            storage: blob_storage.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let subscribe_product_changes_use_case = Arc::new(SubscribeProductChangesUseCaseImpl {
+            feed: product_change_feed,
+            logger: logger.clone(),
+        });
+        let merge_duplicate_products_use_case = Arc::new(MergeDuplicateProductsUseCaseImpl {
+            repository: product_repository.clone(),
+            delete_use_case: delete_use_case.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let upload_product_image_use_case = Arc::new(UploadProductImageUseCaseImpl {
+            repository: product_repository.clone(),
+            image_repository: product_repository.clone(),
+            storage: blob_storage.clone(),
+            thumbnails: thumbnail_job.clone(),
+            logger: logger.clone(),
+        });
+        let get_product_image_use_case = Arc::new(GetProductImageUseCaseImpl {
+            repository: product_repository.clone(),
+            image_repository: product_repository.clone(),
+            storage: blob_storage.clone(),
+            logger: logger.clone(),
+        });
+        let get_thumbnails_use_case = Arc::new(GetProductThumbnailsUseCaseImpl {
+            repository: product_repository.clone(),
+            storage: blob_storage.clone(),
             logger: logger.clone(),
         });
         let estimate_expiry_use_case = Arc::new(EstimateExpiryUseCaseImpl {
             repository: product_repository.clone(),
             estimator: expiry_estimator.clone(),
+            expiry_snap: expiry_config.snap,
+            quota_service: quota_service.clone(),
+            ai_review_repository: ai_review_repository.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let estimate_expiry_for_attributes_use_case =
+            Arc::new(EstimateExpiryForAttributesUseCaseImpl::new(
+                expiry_estimator.clone(),
+                expiry_config.snap,
+                quota_service.clone(),
+                logger.clone(),
+            ));
+        let estimate_expiry_batch_use_case = Arc::new(EstimateExpiryBatchUseCaseImpl {
+            estimate_use_case: estimate_expiry_use_case.clone(),
+            logger: logger.clone(),
+        });
+        let estimate_runner = Arc::new(EstimateMissingRunner {
+            job_repository: job_repository.clone(),
+            estimate_use_case: estimate_expiry_use_case.clone(),
+            pause: expiry_config.estimate_missing_pause,
+            logger: logger.clone(),
+        });
+        let estimate_missing_use_case = Arc::new(EstimateMissingUseCaseImpl {
+            product_repository: product_repository.clone(),
+            job_repository: job_repository.clone(),
+            runner: estimate_runner.clone(),
+            logger: logger.clone(),
+        });
+        let bulk_move_use_case = Arc::new(BulkMoveProductsUseCaseImpl {
+            product_repository: product_repository.clone(),
+            job_repository: job_repository.clone(),
+            runner: estimate_runner,
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let get_job_use_case = Arc::new(GetJobUseCaseImpl {
+            repository: job_repository,
             logger: logger.clone(),
         });
         let identify_use_case = Arc::new(IdentifyProductUseCaseImpl {
-            identifier: product_identifier,
+            identifier: product_identifier.clone(),
+            experiment: identification_experiment,
+            quota_service: quota_service.clone(),
+            location_rules: location_rule_repository.clone(),
+            enrichment_repository: product_repository.clone(),
+            contribution_repository: contribution_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_facets_use_case = Arc::new(GetProductFacetsUseCaseImpl {
+            enrichment_repository: product_repository.clone(),
+            facet_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_allergen_warnings_use_case = Arc::new(GetAllergenWarningsUseCaseImpl {
+            enrichment_repository: product_repository.clone(),
+            preference_repository: preference_repository.clone(),
             logger: logger.clone(),
         });
         let scan_receipt_use_case = Arc::new(ScanReceiptUseCaseImpl {
+            scanner: receipt_scanner.clone(),
+            quota_service: quota_service.clone(),
+            logger: logger.clone(),
+        });
+
+        let propose_from_photo_use_case = Arc::new(ProposeFromPhotoUseCaseImpl {
+            identifier: product_identifier.clone(),
+            repository: product_repository.clone(),
+            quota_service: quota_service.clone(),
+            logger: logger.clone(),
+        });
+
+        // Barcode contribution use cases
+        let submit_barcode_contribution_use_case = Arc::new(SubmitBarcodeContributionUseCaseImpl {
+            repository: contribution_repository.clone(),
+            contributor: product_database_contributor,
+            logger: logger.clone(),
+        });
+
+        // Receipt import use cases
+        let receipt_import_pipeline = Arc::new(ReceiptImportPipeline {
+            import_repository: receipt_import_repository.clone(),
+            product_repository: product_repository.clone(),
             scanner: receipt_scanner,
+            estimator: expiry_estimator,
+            expiry_snap: expiry_config.snap,
+            quota_service: quota_service.clone(),
+            flags: feature_flags.clone(),
+            ai_review_repository: ai_review_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // AI review use cases
+        let get_ai_write_mode_use_case = Arc::new(GetAiWriteModeUseCaseImpl {
+            repository: ai_review_repository.clone(),
+            logger: logger.clone(),
+        });
+        let set_ai_write_mode_use_case = Arc::new(SetAiWriteModeUseCaseImpl {
+            repository: ai_review_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_pending_ai_changes_use_case = Arc::new(GetPendingAiChangesUseCaseImpl {
+            repository: ai_review_repository.clone(),
+            product_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let accept_ai_change_use_case = Arc::new(AcceptAiChangeUseCaseImpl {
+            repository: ai_review_repository.clone(),
+            product_repository: product_repository.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let reject_ai_change_use_case = Arc::new(RejectAiChangeUseCaseImpl {
+            repository: ai_review_repository,
+            logger: logger.clone(),
+        });
+        let start_receipt_import_use_case = Arc::new(StartReceiptImportUseCaseImpl {
+            repository: receipt_import_repository.clone(),
+            quota_service: quota_service.clone(),
+            storage: blob_storage.clone(),
+            thumbnails: thumbnail_job,
+            pipeline: receipt_import_pipeline,
+            logger: logger.clone(),
+        });
+        let get_receipt_import_use_case = Arc::new(GetReceiptImportUseCaseImpl {
+            repository: receipt_import_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_receipt_image_use_case = Arc::new(GetReceiptImageUseCaseImpl {
+            repository: receipt_import_repository,
+            storage: blob_storage.clone(),
+            logger: logger.clone(),
+        });
+        let download_blob_use_case = Arc::new(DownloadBlobUseCaseImpl {
+            storage: blob_storage.clone(),
             logger: logger.clone(),
         });
 
@@ -96,8 +842,18 @@ impl DependencyContainer {
             repository: shopping_item_repository.clone(),
             logger: logger.clone(),
         });
+        let create_shopping_item_from_barcode_use_case =
+            Arc::new(CreateShoppingItemFromBarcodeUseCaseImpl {
+                identifier: product_identifier,
+                contribution_repository,
+                product_repository: product_repository.clone(),
+                create_use_case: create_shopping_item_use_case.clone(),
+                logger: logger.clone(),
+            });
         let get_all_shopping_items_use_case = Arc::new(GetAllShoppingItemsUseCaseImpl {
             repository: shopping_item_repository.clone(),
+            store_profile_repository: store_profile_repository.clone(),
+            preference_repository: preference_repository.clone(),
             logger: logger.clone(),
         });
         let update_shopping_item_use_case = Arc::new(UpdateShoppingItemUseCaseImpl {
@@ -106,48 +862,757 @@ impl DependencyContainer {
         });
         let delete_shopping_item_use_case = Arc::new(DeleteShoppingItemUseCaseImpl {
             repository: shopping_item_repository.clone(),
+            storage: blob_storage.clone(),
             logger: logger.clone(),
         });
         let clear_bought_use_case = Arc::new(ClearBoughtItemsUseCaseImpl {
-            repository: shopping_item_repository,
+            repository: shopping_item_repository.clone(),
+            storage: blob_storage.clone(),
+            logger: logger.clone(),
+        });
+        let attach_to_shopping_item_use_case = Arc::new(AttachToShoppingItemUseCaseImpl {
+            repository: shopping_item_repository.clone(),
+            storage: blob_storage.clone(),
+            logger: logger.clone(),
+        });
+        let get_attachment_urls_use_case = Arc::new(GetAttachmentUrlsUseCaseImpl {
+            storage: blob_storage,
+        });
+
+        // Shopping trip use cases
+        let start_shopping_trip_use_case = Arc::new(StartShoppingTripUseCaseImpl {
+            repository: shopping_trip_repository.clone(),
+            shopping_item_repository: shopping_item_repository.clone(),
+            logger: logger.clone(),
+        });
+        let record_trip_item_use_case = Arc::new(RecordTripItemUseCaseImpl {
+            repository: shopping_trip_repository.clone(),
+            shopping_item_repository: shopping_item_repository.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let finish_shopping_trip_use_case = Arc::new(FinishShoppingTripUseCaseImpl {
+            repository: shopping_trip_repository,
+            logger: logger.clone(),
+        });
+
+        // Store profile use cases
+        let get_all_store_profiles_use_case = Arc::new(GetAllStoreProfilesUseCaseImpl {
+            repository: store_profile_repository.clone(),
+            logger: logger.clone(),
+        });
+        let create_store_profile_use_case = Arc::new(CreateStoreProfileUseCaseImpl {
+            repository: store_profile_repository.clone(),
+            logger: logger.clone(),
+        });
+        let update_store_profile_use_case = Arc::new(UpdateStoreProfileUseCaseImpl {
+            repository: store_profile_repository.clone(),
+            logger: logger.clone(),
+        });
+        let delete_store_profile_use_case = Arc::new(DeleteStoreProfileUseCaseImpl {
+            repository: store_profile_repository.clone(),
+            logger: logger.clone(),
+        });
+        let activate_store_profile_use_case = Arc::new(ActivateStoreProfileUseCaseImpl {
+            repository: store_profile_repository,
+            logger: logger.clone(),
+        });
+
+        // Location rule use cases
+        let get_location_rules_use_case = Arc::new(GetLocationRulesUseCaseImpl {
+            repository: location_rule_repository.clone(),
+            logger: logger.clone(),
+        });
+        let replace_location_rules_use_case = Arc::new(ReplaceLocationRulesUseCaseImpl {
+            repository: location_rule_repository,
+            logger: logger.clone(),
+        });
+
+        // Preference use cases
+        let get_preferences_use_case = Arc::new(GetPreferencesUseCaseImpl {
+            repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+        let update_preferences_use_case = Arc::new(UpdatePreferencesUseCaseImpl {
+            repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_notification_preferences_use_case =
+            Arc::new(GetNotificationPreferencesUseCaseImpl {
+                repository: preference_repository.clone(),
+                logger: logger.clone(),
+            });
+        let update_notification_preferences_use_case =
+            Arc::new(UpdateNotificationPreferencesUseCaseImpl {
+                repository: preference_repository.clone(),
+                logger: logger.clone(),
+            });
+        let acknowledge_notifications_use_case = Arc::new(AcknowledgeNotificationsUseCaseImpl {
+            repository: notification_inbox_repository.clone(),
+            logger: logger.clone(),
+        });
+        let prune_notifications_use_case = Arc::new(PruneNotificationsUseCaseImpl {
+            repository: notification_inbox_repository,
+            logger: logger.clone(),
+        });
+        let send_expiry_alerts_use_case = Arc::new(SendExpiryAlertsUseCaseImpl {
+            product_repository: product_repository.clone(),
+            preference_repository: preference_repository.clone(),
+            dispatcher: notification_dispatcher.clone(),
+            language: notification_config.language,
+            logger: logger.clone(),
+        });
+
+        // Reminder use cases
+        let get_reminders_use_case = Arc::new(GetRemindersUseCaseImpl {
+            repository: reminder_repository.clone(),
+            product_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let create_reminder_use_case = Arc::new(CreateReminderUseCaseImpl {
+            repository: reminder_repository.clone(),
+            product_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let update_reminder_use_case = Arc::new(UpdateReminderUseCaseImpl {
+            repository: reminder_repository.clone(),
+            logger: logger.clone(),
+        });
+        let send_due_reminders_use_case = Arc::new(SendDueRemindersUseCaseImpl {
+            repository: reminder_repository.clone(),
+            dispatcher: notification_dispatcher.clone(),
+            logger: logger.clone(),
+        });
+        let delete_reminder_use_case = Arc::new(DeleteReminderUseCaseImpl {
+            repository: reminder_repository,
             logger: logger.clone(),
         });
 
+        // Share link use cases
+        let create_share_link_use_case = Arc::new(CreateShareLinkUseCaseImpl {
+            repository: share_link_repository.clone(),
+            logger: logger.clone(),
+        });
+        let revoke_share_link_use_case = Arc::new(RevokeShareLinkUseCaseImpl {
+            repository: share_link_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_shared_view_use_case = Arc::new(GetSharedViewUseCaseImpl {
+            repository: share_link_repository,
+            shopping_item_repository: shopping_item_repository.clone(),
+            product_repository: product_repository.clone(),
+            preference_repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Auth use cases (local sign-in only)
+        let local_sign_in = match AuthConfig::from_env() {
+            AuthConfig::Firebase(_) => None,
+            AuthConfig::Local(config) => {
+                let tokens = Arc::new(LocalTokens::new(config.tokens));
+                let verifier = Arc::new(WebAuthnVerifier::new(config.webauthn.clone()));
+                let sender: Arc<dyn MagicLinkSender> = match config.mailer {
+                    Some(settings) => Arc::new(WebhookMailer::new(settings)),
+                    None => Arc::new(LogMailer {
+                        logger: logger.clone(),
+                    }),
+                };
+                Some(LocalSignIn {
+                    request_magic_link_use_case: Arc::new(RequestMagicLinkUseCaseImpl {
+                        repository: auth_repository.clone(),
+                        sender,
+                        link_url: config.magic_link_url,
+                        ttl: config.challenge_ttl,
+                        logger: logger.clone(),
+                    }),
+                    verify_magic_link_use_case: Arc::new(VerifyMagicLinkUseCaseImpl {
+                        repository: auth_repository.clone(),
+                        tokens: tokens.clone(),
+                        logger: logger.clone(),
+                    }),
+                    start_passkey_registration_use_case: Arc::new(
+                        StartPasskeyRegistrationUseCaseImpl {
+                            repository: auth_repository.clone(),
+                            rp_id: config.webauthn.rp_id.clone(),
+                            ttl: config.challenge_ttl,
+                            logger: logger.clone(),
+                        },
+                    ),
+                    finish_passkey_registration_use_case: Arc::new(
+                        FinishPasskeyRegistrationUseCaseImpl {
+                            repository: auth_repository.clone(),
+                            verifier: verifier.clone(),
+                            logger: logger.clone(),
+                        },
+                    ),
+                    start_passkey_login_use_case: Arc::new(StartPasskeyLoginUseCaseImpl {
+                        repository: auth_repository.clone(),
+                        rp_id: config.webauthn.rp_id,
+                        ttl: config.challenge_ttl,
+                        logger: logger.clone(),
+                    }),
+                    finish_passkey_login_use_case: Arc::new(FinishPasskeyLoginUseCaseImpl {
+                        repository: auth_repository,
+                        verifier,
+                        tokens,
+                        logger: logger.clone(),
+                    }),
+                })
+            }
+        };
+
+        // Widget use cases
+        let get_widget_snapshot_use_case = Arc::new(GetWidgetSnapshotUseCaseImpl {
+            repository: widget_repository.clone(),
+            preference_repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+        let issue_widget_token_use_case = Arc::new(IssueWidgetTokenUseCaseImpl {
+            repository: widget_repository.clone(),
+            logger: logger.clone(),
+        });
+        let revoke_widget_token_use_case = Arc::new(RevokeWidgetTokenUseCaseImpl {
+            repository: widget_repository,
+            logger: logger.clone(),
+        });
+
+        // Search use cases
+        let global_search_use_case = Arc::new(GlobalSearchUseCaseImpl {
+            product_repository: product_repository.clone(),
+            shopping_item_repository: shopping_item_repository.clone(),
+            suggestion_repository: suggestion_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Prompt template use cases
+        let reload_prompt_templates_use_case = Arc::new(ReloadPromptTemplatesUseCaseImpl {
+            prompt_templates,
+            logger: logger.clone(),
+        });
+
+        // Experiment use cases
+        let accept_identification_use_case = Arc::new(AcceptIdentificationUseCaseImpl {
+            ai_call_repository: ai_call_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_experiment_results_use_case = Arc::new(GetExperimentResultsUseCaseImpl {
+            ai_call_repository: ai_call_repository.clone(),
+            experiments: experiment_config.experiments,
+            logger: logger.clone(),
+        });
+
+        // Meal plan use cases
+        let plan_suggestion_use_case = Arc::new(PlanSuggestionUseCaseImpl {
+            repository: meal_plan_repository.clone(),
+            suggestion_repository: suggestion_repository.clone(),
+            product_repository: product_repository.clone(),
+            shopping_item_repository: shopping_item_repository.clone(),
+            logger: logger.clone(),
+        });
+        let expire_reservations_use_case = Arc::new(ExpireReservationsUseCaseImpl {
+            repository: meal_plan_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Inbound email use cases (only with an inbound domain)
+        let inbound_email =
+            InboundEmailConfig::from_env()
+                .settings
+                .map(|settings| InboundEmailUseCases {
+                    get_address_use_case: Arc::new(GetInboundAddressUseCaseImpl {
+                        repository: inbound_address_repository.clone(),
+                        domain: settings.domain.clone(),
+                        logger: logger.clone(),
+                    }),
+                    rotate_address_use_case: Arc::new(RotateInboundAddressUseCaseImpl {
+                        repository: inbound_address_repository.clone(),
+                        domain: settings.domain.clone(),
+                        logger: logger.clone(),
+                    }),
+                    receive_use_case: Arc::new(ReceiveInboundEmailUseCaseImpl {
+                        repository: inbound_address_repository,
+                        verifier: Arc::new(MailgunVerifier::new(settings.mailgun_signing_key)),
+                        domain: settings.domain,
+                        limits: settings.limits,
+                        logger: logger.clone(),
+                    }),
+                });
+
         // Suggestion use cases
-        let generate_suggestions_use_case = Arc::new(GenerateSuggestionsUseCaseImpl {
-            repository: product_repository,
-            generator: suggestion_generator,
+        let pregenerate_suggestions_use_case = Arc::new(PregenerateSuggestionsUseCaseImpl {
+            product_repository: product_repository.clone(),
+            suggestion_repository: suggestion_repository.clone(),
+            generate_use_case: generate_suggestions_use_case.clone(),
+            quota_service: quota_service.clone(),
+            logger: logger.clone(),
+        });
+        let get_suggestion_history_use_case = Arc::new(GetSuggestionHistoryUseCaseImpl {
+            suggestion_repository: suggestion_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Quota use cases
+        let get_usage_use_case = Arc::new(GetUsageUseCaseImpl {
+            quota_service: quota_service.clone(),
+            logger: logger.clone(),
+        });
+
+        // Backup use cases
+        let export_backup_use_case = Arc::new(ExportBackupUseCaseImpl {
+            repository: backup_repository.clone(),
+            logger: logger.clone(),
+        });
+        let restore_backup_use_case = Arc::new(RestoreBackupUseCaseImpl {
+            repository: backup_repository,
+            logger: logger.clone(),
+        });
+
+        // Client configuration use cases
+        let get_client_config_use_case = Arc::new(GetClientConfigUseCaseImpl {
+            flags: feature_flags,
+            min_app_version: AppVersionConfig::from_env().minimum.to_string(),
+            quota_service,
+            logger: logger.clone(),
+        });
+
+        // Load-test fixtures
+        let seed_products_use_case = Arc::new(SeedProductsUseCaseImpl {
+            repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Badge use cases
+        let get_badges_use_case = Arc::new(GetBadgesUseCaseImpl {
+            repository: badge_repository,
+            preference_repository: preference_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Challenge use cases
+        let get_current_challenge_use_case = Arc::new(GetCurrentChallengeUseCaseImpl {
+            repository: challenge_repository.clone(),
+            settings: challenge_config.settings,
+            logger: logger.clone(),
+        });
+        let get_challenge_history_use_case = Arc::new(GetChallengeHistoryUseCaseImpl {
+            repository: challenge_repository,
+            logger: logger.clone(),
+        });
+
+        // Stats use cases
+        let get_inventory_value_use_case = Arc::new(GetInventoryValueUseCaseImpl {
+            repository: stats_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_inventory_trends_use_case = Arc::new(GetInventoryTrendsUseCaseImpl {
+            repository: stats_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_waste_streak_use_case = Arc::new(GetWasteStreakUseCaseImpl {
+            repository: stats_repository.clone(),
+            logger: logger.clone(),
+        });
+        let record_snapshots_use_case = Arc::new(RecordInventorySnapshotsUseCaseImpl {
+            product_repository: product_repository.clone(),
+            preference_repository,
+            stats_repository: stats_repository.clone(),
+            snapshot_repository: stats_repository.clone(),
+            vacation_repository: vacation_repository.clone(),
+            logger: logger.clone(),
+        });
+        let record_waste_streaks_use_case = Arc::new(RecordWasteStreaksUseCaseImpl {
+            product_repository: product_repository.clone(),
+            stats_repository: stats_repository.clone(),
+            streak_repository: stats_repository,
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+
+        // Vacation use cases
+        let get_vacation_mode_use_case = Arc::new(GetVacationModeUseCaseImpl {
+            repository: vacation_repository.clone(),
+            logger: logger.clone(),
+        });
+        let set_vacation_mode_use_case = Arc::new(SetVacationModeUseCaseImpl {
+            repository: vacation_repository.clone(),
+            product_repository: product_repository.clone(),
+            event_publisher: event_bus.clone(),
+            logger: logger.clone(),
+        });
+        let get_vacation_report_use_case = Arc::new(GetVacationReportUseCaseImpl {
+            repository: vacation_repository.clone(),
+            product_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+        let clean_up_vacation_use_case = Arc::new(CleanUpVacationUseCaseImpl {
+            repository: vacation_repository.clone(),
+            product_repository: product_repository.clone(),
+            update_product_use_case: update_use_case.clone(),
+            logger: logger.clone(),
+        });
+        let end_due_vacations_use_case = Arc::new(EndDueVacationsUseCaseImpl {
+            repository: vacation_repository,
+            set_use_case: set_vacation_mode_use_case.clone(),
+            logger: logger.clone(),
+        });
+
+        // Device use cases
+        let register_device_use_case = Arc::new(RegisterDeviceUseCaseImpl {
+            repository: device_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_active_installs_use_case = Arc::new(GetActiveInstallsUseCaseImpl {
+            repository: device_repository.clone(),
+            logger: logger.clone(),
+        });
+        let prune_stale_devices_use_case = Arc::new(PruneStaleDevicesUseCaseImpl {
+            repository: device_repository,
+            logger: logger.clone(),
+        });
+
+        // Budget use cases
+        let set_budget_use_case = Arc::new(SetBudgetUseCaseImpl {
+            repository: budget_repository.clone(),
+            logger: logger.clone(),
+        });
+        let delete_budget_use_case = Arc::new(DeleteBudgetUseCaseImpl {
+            repository: budget_repository.clone(),
+            logger: logger.clone(),
+        });
+        let get_budget_status_use_case = Arc::new(GetBudgetStatusUseCaseImpl {
+            repository: budget_repository,
+            logger: logger.clone(),
+        });
+
+        // Sandbox use cases
+        let reset_sandbox_use_case = Arc::new(ResetSandboxUseCaseImpl {
+            sandbox_repository,
+            product_repository: product_repository.clone(),
+            logger: logger.clone(),
+        });
+
+        // Billing use cases
+        let create_checkout_use_case = Arc::new(CreateCheckoutUseCaseImpl {
+            plan_provider: plan_provider.clone(),
+            logger: logger.clone(),
+        });
+        let handle_webhook_use_case = Arc::new(HandleWebhookUseCaseImpl {
+            plan_provider,
+            plan_repository,
+            logger: logger.clone(),
+        });
+
+        // Cooking session use cases
+        let start_cooking_use_case = Arc::new(StartCookingUseCaseImpl {
+            repository: cooking_session_repository.clone(),
+            suggestion_repository,
+            logger: logger.clone(),
+        });
+        let get_cooking_session_use_case = Arc::new(GetCookingSessionUseCaseImpl {
+            repository: cooking_session_repository.clone(),
+            logger: logger.clone(),
+        });
+        let advance_cooking_step_use_case = Arc::new(AdvanceCookingStepUseCaseImpl {
+            repository: cooking_session_repository.clone(),
+            logger: logger.clone(),
+        });
+        let complete_cooking_step_use_case = Arc::new(CompleteCookingStepUseCaseImpl {
+            repository: cooking_session_repository.clone(),
+            logger: logger.clone(),
+        });
+        let complete_cooking_session_use_case = Arc::new(CompleteCookingSessionUseCaseImpl {
+            repository: cooking_session_repository,
+            product_repository,
+            update_product_use_case: update_use_case.clone(),
+            ai_call_repository,
             logger,
         });
 
         let product_api = crate::api::product::routes::ProductApi::new(
             create_use_case,
+            create_batch_use_case,
             get_all_use_case,
             get_by_id_use_case,
+            get_product_history_use_case,
+            get_expiry_calendar_use_case,
             update_use_case,
+            patch_use_case,
             delete_use_case,
             estimate_expiry_use_case,
-            expiry_estimator,
+            estimate_expiry_batch_use_case,
+            estimate_expiry_for_attributes_use_case,
             identify_use_case,
             scan_receipt_use_case,
+            propose_from_photo_use_case,
+            get_facets_use_case,
+            get_allergen_warnings_use_case,
+            upload_product_image_use_case,
+            get_product_image_use_case,
+            get_thumbnails_use_case,
+            subscribe_product_changes_use_case,
+            PayloadConfig::from_env(),
+        );
+
+        let barcode_contribution_api =
+            crate::api::barcode_contribution::routes::BarcodeContributionApi::new(
+                submit_barcode_contribution_use_case,
+                PayloadConfig::from_env(),
+            );
+
+        let ai_review_api = crate::api::ai_review::routes::AiReviewApi::new(
+            get_ai_write_mode_use_case,
+            set_ai_write_mode_use_case,
+            get_pending_ai_changes_use_case,
+            accept_ai_change_use_case,
+            reject_ai_change_use_case,
+        );
+
+        let receipt_import_api = crate::api::receipt_import::routes::ReceiptImportApi::new(
+            start_receipt_import_use_case,
+            get_receipt_import_use_case,
+            get_receipt_image_use_case,
+            PayloadConfig::from_env(),
         );
 
         let shopping_item_api = crate::api::shopping_item::routes::ShoppingItemApi::new(
             create_shopping_item_use_case,
+            create_shopping_item_from_barcode_use_case,
             get_all_shopping_items_use_case,
             update_shopping_item_use_case,
             delete_shopping_item_use_case,
             clear_bought_use_case,
+            attach_to_shopping_item_use_case,
+            get_attachment_urls_use_case,
+            PayloadConfig::from_env(),
+        );
+
+        let shopping_trip_api = crate::api::shopping_trip::routes::ShoppingTripApi::new(
+            start_shopping_trip_use_case,
+            record_trip_item_use_case,
+            finish_shopping_trip_use_case,
+        );
+
+        let store_profile_api = crate::api::store_profile::routes::StoreProfileApi::new(
+            get_all_store_profiles_use_case,
+            create_store_profile_use_case,
+            update_store_profile_use_case,
+            delete_store_profile_use_case,
+            activate_store_profile_use_case,
         );
 
-        let suggestion_api =
-            crate::api::suggestion::routes::SuggestionApi::new(generate_suggestions_use_case);
+        let location_rule_api = crate::api::location_rule::routes::LocationRuleApi::new(
+            get_location_rules_use_case,
+            replace_location_rules_use_case,
+        );
+
+        let preference_api = crate::api::preference::routes::PreferenceApi::new(
+            get_preferences_use_case,
+            update_preferences_use_case,
+        );
+
+        let notification_api = crate::api::notification::routes::NotificationApi::new(
+            get_notification_preferences_use_case,
+            update_notification_preferences_use_case,
+            acknowledge_notifications_use_case,
+        );
+
+        let vacation_api = crate::api::vacation::routes::VacationApi::new(
+            get_vacation_mode_use_case,
+            set_vacation_mode_use_case,
+            get_vacation_report_use_case,
+            clean_up_vacation_use_case,
+        );
+
+        let search_api = crate::api::search::routes::SearchApi::new(global_search_use_case);
+
+        let admin_config = AdminConfig::from_env();
+        let prompt_template_api = crate::api::prompt::routes::PromptTemplateApi::new(
+            reload_prompt_templates_use_case,
+            admin_config.clone(),
+        );
+
+        let experiment_api = crate::api::experiment::routes::ExperimentApi::new(
+            accept_identification_use_case,
+            get_experiment_results_use_case,
+            admin_config.clone(),
+        );
+
+        let device_api = crate::api::device::routes::DeviceApi::new(
+            register_device_use_case,
+            get_active_installs_use_case,
+            admin_config,
+        );
+
+        let budget_api = crate::api::budget::routes::BudgetApi::new(
+            set_budget_use_case,
+            delete_budget_use_case,
+            get_budget_status_use_case,
+            StatsConfig::from_env(),
+        );
+
+        let give_away_api = crate::api::give_away::routes::GiveAwayApi::new(
+            get_give_away_candidates_use_case,
+            flag_unwanted_use_case,
+        );
+
+        let duplicate_api = crate::api::duplicate::routes::DuplicateApi::new(
+            get_duplicate_products_use_case,
+            merge_duplicate_products_use_case,
+        );
+
+        let job_api = crate::api::job::routes::JobApi::new(
+            estimate_missing_use_case,
+            bulk_move_use_case,
+            get_job_use_case,
+        );
+
+        let reminder_api = crate::api::reminder::routes::ReminderApi::new(
+            get_reminders_use_case,
+            create_reminder_use_case,
+            update_reminder_use_case,
+            delete_reminder_use_case,
+        );
+
+        let suggestion_api = crate::api::suggestion::routes::SuggestionApi::new(
+            generate_suggestions_use_case,
+            get_suggestion_history_use_case,
+        );
+
+        let meal_plan_api =
+            crate::api::meal_plan::routes::MealPlanApi::new(plan_suggestion_use_case);
+
+        let cooking_session_api = crate::api::cooking_session::routes::CookingSessionApi::new(
+            start_cooking_use_case,
+            get_cooking_session_use_case,
+            advance_cooking_step_use_case,
+            complete_cooking_step_use_case,
+            complete_cooking_session_use_case,
+        );
+
+        let share_link_api = crate::api::share_link::routes::ShareLinkApi::new(
+            create_share_link_use_case,
+            revoke_share_link_use_case,
+        );
+        let shared_view_api =
+            crate::api::share_link::routes::SharedViewApi::new(get_shared_view_use_case);
+
+        let auth_api = crate::api::auth::routes::AuthApi::new(local_sign_in);
+        let inbound_email_api =
+            crate::api::inbound_email::routes::InboundEmailApi::new(inbound_email);
+        let me_api = crate::api::me::routes::MeApi::new(get_usage_use_case);
+        let client_config_api =
+            crate::api::client_config::routes::ClientConfigApi::new(get_client_config_use_case);
+        let backup_api = crate::api::backup::routes::BackupApi::new(
+            export_backup_use_case,
+            restore_backup_use_case,
+        );
+        let blob_api = crate::api::storage::routes::BlobApi::new(download_blob_use_case);
+        let billing_api = crate::api::billing::routes::BillingApi::new(
+            create_checkout_use_case,
+            handle_webhook_use_case,
+        );
+        let badge_api = crate::api::badge::routes::BadgeApi::new(get_badges_use_case);
+        let challenge_api = crate::api::challenge::routes::ChallengeApi::new(
+            get_current_challenge_use_case,
+            get_challenge_history_use_case,
+        );
+        let stats_api = crate::api::stats::routes::StatsApi::new(
+            get_inventory_value_use_case,
+            get_inventory_trends_use_case,
+            get_waste_streak_use_case,
+            StatsConfig::from_env(),
+        );
+        let load_test_api = crate::api::load_test::routes::LoadTestApi::new(
+            seed_products_use_case,
+            LoadTestConfig::from_env(),
+        );
+
+        let widget_api = crate::api::widget::routes::WidgetApi::new(
+            get_widget_snapshot_use_case,
+            issue_widget_token_use_case,
+            revoke_widget_token_use_case,
+        );
 
         Ok(Self {
             health_api,
             product_api,
+            barcode_contribution_api,
+            ai_review_api,
+            receipt_import_api,
             shopping_item_api,
+            inbound_email_api,
+            shopping_trip_api,
+            store_profile_api,
+            location_rule_api,
+            preference_api,
+            notification_api,
+            vacation_api,
+            device_api,
+            budget_api,
+            search_api,
+            prompt_template_api,
+            experiment_api,
+            reminder_api,
+            give_away_api,
+            duplicate_api,
+            job_api,
             suggestion_api,
+            cooking_session_api,
+            meal_plan_api,
+            share_link_api,
+            shared_view_api,
+            auth_api,
+            me_api,
+            client_config_api,
+            backup_api,
+            blob_api,
+            billing_api,
+            badge_api,
+            challenge_api,
+            stats_api,
+            load_test_api,
+            widget_api,
+            pregenerate_suggestions_use_case,
+            record_snapshots_use_case,
+            record_waste_streaks_use_case,
+            reset_sandbox_use_case,
+            end_due_vacations_use_case,
+            expire_reservations_use_case,
+            prune_stale_devices_use_case,
+            prune_notifications_use_case,
+            send_expiry_alerts_use_case,
+            send_due_reminders_use_case,
         })
     }
 }
+
+/// Adapters of the variants that change the model or prompt. Fails when a
+/// variant pins a prompt version that doesn't exist.
+fn variant_services<S: ?Sized>(
+    experiment: Option<&Experiment>,
+    prompt: Prompt,
+    prompt_templates: &PromptTemplates,
+    build: impl Fn(&Variant) -> Arc<S>,
+) -> anyhow::Result<HashMap<String, Arc<S>>> {
+    let mut services = HashMap::new();
+    let Some(experiment) = experiment else {
+        return Ok(services);
+    };
+    for variant in &experiment.variants {
+        if let Some(version) = variant.prompt_version
+            && !prompt_templates.has_version(prompt, version)
+        {
+            anyhow::bail!(
+                "Experiment variant {} of {} uses {}.v{}, which doesn't exist",
+                variant.name,
+                experiment.feature,
+                prompt.name(),
+                version
+            );
+        }
+        if variant.model.is_some() || variant.prompt_version.is_some() {
+            services.insert(variant.name.clone(), build(variant));
+        }
+    }
+    Ok(services)
+}
@@ -0,0 +1,370 @@
+use std::env;
+use std::panic::{self, UnwindSafe};
+use std::time::Duration;
+
+use openai::client::OpenAIClient;
+use persistence::db::{DatabaseConfig, create_postgres_pool, pending_migrations};
+use reqwest::StatusCode;
+use reqwest::header::AUTHORIZATION;
+
+use crate::api::security::GOOGLE_CERTS_URL;
+use crate::config::admin_config::AdminConfig;
+use crate::config::ai_chaos_config::AiChaosConfig;
+use crate::config::app_config::AppConfig;
+use crate::config::auth_config::AuthConfig;
+use crate::config::billing_config::BillingConfig;
+use crate::config::challenge_config::ChallengeConfig;
+use crate::config::experiment_config::ExperimentConfig;
+use crate::config::expiry_config::ExpiryConfig;
+use crate::config::feature_flag_config::FeatureFlagConfig;
+use crate::config::inbound_email_config::InboundEmailConfig;
+use crate::config::load_test_config::LoadTestConfig;
+use crate::config::notification_config::NotificationConfig;
+use crate::config::ocr_config::OcrConfig;
+use crate::config::open_food_facts_config::OpenFoodFactsConfig;
+use crate::config::openai_config::OpenAIConfig;
+use crate::config::preference_config::PreferenceConfig;
+use crate::config::product_config::ProductConfig;
+use crate::config::prompt_config::PromptConfig;
+use crate::config::receipt_profile_config::ReceiptProfileConfig;
+use crate::config::stats_config::StatsConfig;
+use crate::config::storage_config::StorageConfig;
+use crate::config::suggestion_config::SuggestionConfig;
+
+/// Everything is reachable and the server can start.
+pub const EXIT_READY: i32 = 0;
+/// The configuration loads, but a dependency is unreachable or out of date.
+pub const EXIT_DEPENDENCY_FAILED: i32 = 1;
+/// The server would refuse to start with the current environment.
+pub const EXIT_INVALID_CONFIG: i32 = 2;
+
+/// How long each network check may take before it counts as failed.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Every configuration the server loads on startup, by name.
+const CONFIG_LOADERS: &[(&str, fn())] = &[
+    ("app", || {
+        AppConfig::from_env();
+    }),
+    ("auth", || {
+        AuthConfig::from_env();
+    }),
+    ("openai", || {
+        OpenAIConfig::from_env();
+    }),
+    ("prompt", || {
+        PromptConfig::from_env();
+    }),
+    ("ai_chaos", || {
+        AiChaosConfig::from_env();
+    }),
+    ("ocr", || {
+        OcrConfig::from_env();
+    }),
+    ("receipt_profile", || {
+        ReceiptProfileConfig::from_env();
+    }),
+    ("experiment", || {
+        ExperimentConfig::from_env();
+    }),
+    ("open_food_facts", || {
+        OpenFoodFactsConfig::from_env();
+    }),
+    ("feature_flag", || {
+        FeatureFlagConfig::from_env();
+    }),
+    ("preference", || {
+        PreferenceConfig::from_env();
+    }),
+    ("suggestion", || {
+        SuggestionConfig::from_env();
+    }),
+    ("expiry", || {
+        ExpiryConfig::from_env();
+    }),
+    ("product", || {
+        ProductConfig::from_env();
+    }),
+    ("challenge", || {
+        ChallengeConfig::from_env();
+    }),
+    ("billing", || {
+        BillingConfig::from_env();
+    }),
+    ("storage", || {
+        StorageConfig::from_env();
+    }),
+    ("notification", || {
+        NotificationConfig::from_env();
+    }),
+    ("inbound_email", || {
+        InboundEmailConfig::from_env();
+    }),
+    ("admin", || {
+        AdminConfig::from_env();
+    }),
+    ("stats", || {
+        StatsConfig::from_env();
+    }),
+    ("load_test", || {
+        LoadTestConfig::from_env();
+    }),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    Skip,
+    Fail,
+}
+
+impl Status {
+    fn label(self) -> &'static str {
+        match self {
+            Status::Ok => "OK",
+            Status::Skip => "SKIP",
+            Status::Fail => "FAIL",
+        }
+    }
+}
+
+struct Check {
+    name: &'static str,
+    status: Status,
+    detail: String,
+}
+
+impl Check {
+    fn new(name: &'static str, status: Status, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Readiness self-check, run with `rest-api --doctor`
+///
+/// Validates the configuration, connects to Postgres, compares the applied
+/// migrations with the ones this build ships, and pings the OpenAI and Google
+/// certs endpoints. Prints a report and returns the process exit code:
+/// [`EXIT_READY`], [`EXIT_DEPENDENCY_FAILED`] or [`EXIT_INVALID_CONFIG`].
+pub async fn run() -> i32 {
+    let mut checks = vec![check_config()];
+
+    let (database, migrations) = check_database().await;
+    checks.push(database);
+    checks.push(migrations);
+
+    // Loaded again here to reach the services; failures are in the config check
+    let (openai, auth) = silenced(|| {
+        (
+            load(OpenAIConfig::from_env).ok(),
+            load(AuthConfig::from_env).ok(),
+        )
+    });
+    checks.push(check_openai(openai).await);
+    checks.push(check_google_certs(auth).await);
+
+    print_report(&checks);
+    exit_code(&checks)
+}
+
+/// Runs every configuration loader, collecting the reason each one rejects
+/// the environment instead of aborting on the first.
+fn check_config() -> Check {
+    let problems: Vec<String> = silenced(|| {
+        CONFIG_LOADERS
+            .iter()
+            .filter_map(|(name, loader)| load(*loader).err().map(|e| format!("{name}: {e}")))
+            .collect()
+    });
+
+    if problems.is_empty() {
+        Check::new(
+            "config",
+            Status::Ok,
+            format!("{} sections loaded", CONFIG_LOADERS.len()),
+        )
+    } else {
+        Check::new("config", Status::Fail, problems.join("; "))
+    }
+}
+
+async fn check_database() -> (Check, Check) {
+    let Ok(url) = env::var("DATABASE_URL") else {
+        return (
+            Check::new("database", Status::Fail, "DATABASE_URL must be set"),
+            Check::new("migrations", Status::Skip, "no database"),
+        );
+    };
+
+    let mut config = DatabaseConfig::new(url);
+    config.max_connections = 1;
+    config.acquire_timeout = CHECK_TIMEOUT;
+    let pool = match create_postgres_pool(&config).await {
+        Ok(pool) => pool,
+        Err(e) => {
+            return (
+                Check::new("database", Status::Fail, e.to_string()),
+                Check::new("migrations", Status::Skip, "no database"),
+            );
+        }
+    };
+
+    let database = match sqlx::query("SELECT 1").execute(&pool).await {
+        Ok(_) => Check::new("database", Status::Ok, "connected"),
+        Err(e) => Check::new("database", Status::Fail, e.to_string()),
+    };
+    let migrations = match pending_migrations(&pool).await {
+        Ok(pending) if pending.is_empty() => Check::new("migrations", Status::Ok, "up to date"),
+        Ok(pending) => Check::new(
+            "migrations",
+            Status::Fail,
+            format!("{} pending: {}", pending.len(), pending.join(", ")),
+        ),
+        Err(e) => Check::new("migrations", Status::Fail, e.to_string()),
+    };
+    pool.close().await;
+
+    (database, migrations)
+}
+
+async fn check_openai(config: Option<OpenAIConfig>) -> Check {
+    let Some(config) = config else {
+        return Check::new("openai", Status::Skip, "invalid config");
+    };
+    let client = match OpenAIClient::new(config.api_key, config.client) {
+        Ok(client) => client,
+        Err(e) => return Check::new("openai", Status::Fail, e.to_string()),
+    };
+
+    let response = client
+        .client
+        .get(format!("{}/models", client.base_url))
+        .header(AUTHORIZATION, client.auth_header())
+        .timeout(CHECK_TIMEOUT)
+        .send()
+        .await;
+    match response {
+        Ok(r) if r.status().is_success() => Check::new("openai", Status::Ok, "reachable"),
+        Ok(r) if r.status() == StatusCode::UNAUTHORIZED => {
+            Check::new("openai", Status::Fail, "API key rejected")
+        }
+        Ok(r) => Check::new("openai", Status::Fail, format!("HTTP {}", r.status())),
+        Err(e) => Check::new("openai", Status::Fail, e.to_string()),
+    }
+}
+
+async fn check_google_certs(config: Option<AuthConfig>) -> Check {
+    match config {
+        Some(AuthConfig::Firebase(_)) => {}
+        Some(AuthConfig::Local(_)) => {
+            return Check::new("google_certs", Status::Skip, "local auth provider");
+        }
+        None => return Check::new("google_certs", Status::Skip, "invalid config"),
+    }
+
+    let response = match reqwest::Client::builder().timeout(CHECK_TIMEOUT).build() {
+        Ok(client) => client.get(GOOGLE_CERTS_URL).send().await,
+        Err(e) => return Check::new("google_certs", Status::Fail, e.to_string()),
+    };
+    match response {
+        Ok(r) if r.status().is_success() => Check::new("google_certs", Status::Ok, "reachable"),
+        Ok(r) => Check::new("google_certs", Status::Fail, format!("HTTP {}", r.status())),
+        Err(e) => Check::new("google_certs", Status::Fail, e.to_string()),
+    }
+}
+
+/// Runs `f` without printing the messages of panics caught inside it.
+fn silenced<T>(f: impl FnOnce() -> T) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = f();
+    panic::set_hook(hook);
+    result
+}
+
+/// Calls a panicking config loader, returning its panic message as the error.
+fn load<T>(loader: impl FnOnce() -> T + UnwindSafe) -> Result<T, String> {
+    panic::catch_unwind(loader).map_err(|payload| {
+        payload
+            .downcast_ref::<String>()
+            .cloned()
+            .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+            .unwrap_or_else(|| "invalid configuration".to_string())
+    })
+}
+
+fn print_report(checks: &[Check]) {
+    let width = checks.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    println!("Foodie readiness report");
+    for check in checks {
+        println!(
+            "  [{:<4}] {:<width$}  {}",
+            check.status.label(),
+            check.name,
+            check.detail
+        );
+    }
+    let verdict = match exit_code(checks) {
+        EXIT_READY => "ready",
+        EXIT_INVALID_CONFIG => "invalid configuration",
+        _ => "not ready",
+    };
+    println!("Result: {verdict}");
+}
+
+/// Invalid configuration wins over failed dependencies, since the server
+/// wouldn't get as far as using them.
+fn exit_code(checks: &[Check]) -> i32 {
+    let failed = |name: &str| {
+        checks
+            .iter()
+            .any(|c| c.status == Status::Fail && c.name == name)
+    };
+    if failed("config") {
+        EXIT_INVALID_CONFIG
+    } else if checks.iter().any(|c| c.status == Status::Fail) {
+        EXIT_DEPENDENCY_FAILED
+    } else {
+        EXIT_READY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_report_panic_message_of_failed_loader() {
+        // Act
+        let result = silenced(|| load(|| -> u32 { panic!("FOO must be set") }));
+
+        // Assert
+        assert_eq!(result, Err("FOO must be set".to_string()));
+    }
+
+    #[test]
+    fn should_exit_with_invalid_config_before_dependency_failure() {
+        // Arrange
+        let ready = vec![
+            Check::new("config", Status::Ok, ""),
+            Check::new("google_certs", Status::Skip, ""),
+        ];
+        let unreachable = vec![
+            Check::new("config", Status::Ok, ""),
+            Check::new("database", Status::Fail, ""),
+        ];
+        let invalid = vec![
+            Check::new("config", Status::Fail, ""),
+            Check::new("database", Status::Fail, ""),
+        ];
+
+        // Act & Assert
+        assert_eq!(exit_code(&ready), EXIT_READY);
+        assert_eq!(exit_code(&unreachable), EXIT_DEPENDENCY_FAILED);
+        assert_eq!(exit_code(&invalid), EXIT_INVALID_CONFIG);
+    }
+}
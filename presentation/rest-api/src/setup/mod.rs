@@ -1,2 +1,4 @@
 pub mod dependency_injection;
+pub mod doctor;
+pub mod scheduler;
 pub mod server;
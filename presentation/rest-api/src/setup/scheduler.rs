@@ -0,0 +1,384 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+
+use business::domain::device::use_cases::prune_stale::{
+    PruneStaleDevicesParams, PruneStaleDevicesUseCase,
+};
+use business::domain::meal_plan::use_cases::expire_reservations::ExpireReservationsUseCase;
+use business::domain::notification::use_cases::prune::{
+    PruneNotificationsParams, PruneNotificationsUseCase,
+};
+use business::domain::notification::use_cases::send_expiry_alerts::{
+    SendExpiryAlertsParams, SendExpiryAlertsUseCase,
+};
+use business::domain::reminder::use_cases::send_due::{
+    SendDueRemindersParams, SendDueRemindersUseCase,
+};
+use business::domain::sandbox::use_cases::reset::{ResetSandboxParams, ResetSandboxUseCase};
+use business::domain::shared::value_objects::UserId;
+use business::domain::stats::use_cases::record_snapshots::{
+    RecordInventorySnapshotsParams, RecordInventorySnapshotsUseCase,
+};
+use business::domain::stats::use_cases::record_waste_streaks::{
+    RecordWasteStreaksParams, RecordWasteStreaksUseCase,
+};
+use business::domain::suggestion::use_cases::pregenerate::{
+    PregenerateSuggestionsParams, PregenerateSuggestionsUseCase,
+};
+use business::domain::vacation::use_cases::end_due::{
+    EndDueVacationsParams, EndDueVacationsUseCase,
+};
+
+use crate::config::sandbox_config::SandboxConfig;
+use crate::config::scheduler_config::SchedulerConfig;
+
+/// Background job scheduler
+///
+/// Runs periodic jobs inside the API process using Tokio tasks.
+pub struct Scheduler;
+
+impl Scheduler {
+    #[allow(clippy::too_many_arguments)]
+    pub fn spawn(
+        config: SchedulerConfig,
+        pregenerate_use_case: Arc<dyn PregenerateSuggestionsUseCase>,
+        record_snapshots_use_case: Arc<dyn RecordInventorySnapshotsUseCase>,
+        record_waste_streaks_use_case: Arc<dyn RecordWasteStreaksUseCase>,
+        end_due_vacations_use_case: Arc<dyn EndDueVacationsUseCase>,
+        expire_reservations_use_case: Arc<dyn ExpireReservationsUseCase>,
+        prune_stale_devices_use_case: Arc<dyn PruneStaleDevicesUseCase>,
+        prune_notifications_use_case: Arc<dyn PruneNotificationsUseCase>,
+        send_expiry_alerts_use_case: Arc<dyn SendExpiryAlertsUseCase>,
+        send_due_reminders_use_case: Arc<dyn SendDueRemindersUseCase>,
+        sandbox: SandboxConfig,
+        reset_sandbox_use_case: Arc<dyn ResetSandboxUseCase>,
+    ) {
+        Self::spawn_suggestion_pregeneration(config.clone(), pregenerate_use_case);
+        Self::spawn_inventory_snapshots(config.clone(), record_snapshots_use_case);
+        Self::spawn_waste_streaks(config.clone(), record_waste_streaks_use_case);
+        Self::spawn_vacation_returns(config.clone(), end_due_vacations_use_case);
+        Self::spawn_reservation_expiry(config.clone(), expire_reservations_use_case);
+        Self::spawn_device_pruning(config.clone(), prune_stale_devices_use_case);
+        Self::spawn_notification_pruning(config.clone(), prune_notifications_use_case);
+        Self::spawn_expiry_alerts(config.clone(), send_expiry_alerts_use_case);
+        Self::spawn_reminder_delivery(config, send_due_reminders_use_case);
+        Self::spawn_sandbox_reset(sandbox, reset_sandbox_use_case);
+    }
+
+    fn spawn_suggestion_pregeneration(
+        config: SchedulerConfig,
+        pregenerate_use_case: Arc<dyn PregenerateSuggestionsUseCase>,
+    ) {
+        if !config.suggestion_pregeneration_enabled {
+            tracing::info!("Suggestion pre-generation job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait =
+                    duration_until_next_run(Utc::now(), config.suggestion_pregeneration_hour);
+                tracing::info!(
+                    "Next suggestion pre-generation run in {}s",
+                    wait.num_seconds()
+                );
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = PregenerateSuggestionsParams {
+                    limit: config.suggestion_pregeneration_limit,
+                    max_users: config.suggestion_pregeneration_max_users,
+                };
+                if let Err(e) = pregenerate_use_case.execute(params).await {
+                    tracing::error!("Suggestion pre-generation run failed: {e}");
+                }
+            }
+        });
+    }
+
+    fn spawn_inventory_snapshots(
+        config: SchedulerConfig,
+        record_snapshots_use_case: Arc<dyn RecordInventorySnapshotsUseCase>,
+    ) {
+        if !config.inventory_snapshot_enabled {
+            tracing::info!("Inventory snapshot job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.inventory_snapshot_hour);
+                tracing::info!("Next inventory snapshot run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = RecordInventorySnapshotsParams {
+                    batch_size: config.inventory_snapshot_batch_size,
+                };
+                if let Err(e) = record_snapshots_use_case.execute(params).await {
+                    tracing::error!("Inventory snapshot run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Runs nightly so a missed night is caught up the next one; each run
+    /// only counts the weeks that ended since the last.
+    fn spawn_waste_streaks(
+        config: SchedulerConfig,
+        record_waste_streaks_use_case: Arc<dyn RecordWasteStreaksUseCase>,
+    ) {
+        if !config.waste_streak_enabled {
+            tracing::info!("Waste streak job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.waste_streak_hour);
+                tracing::info!("Next waste streak run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = RecordWasteStreaksParams {
+                    batch_size: config.waste_streak_batch_size,
+                };
+                if let Err(e) = record_waste_streaks_use_case.execute(params).await {
+                    tracing::error!("Waste streak run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Checks every few minutes rather than nightly, so vacation mode turns
+    /// off close to the planned return and expiry alerts resume.
+    fn spawn_vacation_returns(
+        config: SchedulerConfig,
+        end_due_vacations_use_case: Arc<dyn EndDueVacationsUseCase>,
+    ) {
+        if !config.vacation_return_enabled {
+            tracing::info!("Vacation return job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            let period =
+                std::time::Duration::from_secs(config.vacation_return_interval_minutes * 60);
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+
+                let params = EndDueVacationsParams {
+                    max_users: config.vacation_return_max_users,
+                };
+                if let Err(e) = end_due_vacations_use_case.execute(params).await {
+                    tracing::error!("Vacation return run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Releases the pantry products set aside for meals whose day has passed,
+    /// so they count again for suggestions and new plans.
+    fn spawn_reservation_expiry(
+        config: SchedulerConfig,
+        expire_reservations_use_case: Arc<dyn ExpireReservationsUseCase>,
+    ) {
+        if !config.reservation_expiry_enabled {
+            tracing::info!("Reservation expiry job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.reservation_expiry_hour);
+                tracing::info!("Next reservation expiry run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                if let Err(e) = expire_reservations_use_case.execute().await {
+                    tracing::error!("Reservation expiry run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Forgets the installs not seen for a while, so notifications stop
+    /// going to uninstalled apps.
+    fn spawn_device_pruning(
+        config: SchedulerConfig,
+        prune_stale_devices_use_case: Arc<dyn PruneStaleDevicesUseCase>,
+    ) {
+        if !config.device_prune_enabled {
+            tracing::info!("Device prune job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.device_prune_hour);
+                tracing::info!("Next device prune run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = PruneStaleDevicesParams {
+                    stale_after_days: config.device_stale_after_days,
+                };
+                if let Err(e) = prune_stale_devices_use_case.execute(params).await {
+                    tracing::error!("Device prune run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Deletes inbox notifications past retention, so the table doesn't grow
+    /// with every notification ever sent.
+    fn spawn_notification_pruning(
+        config: SchedulerConfig,
+        prune_notifications_use_case: Arc<dyn PruneNotificationsUseCase>,
+    ) {
+        if !config.notification_prune_enabled {
+            tracing::info!("Notification prune job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.notification_prune_hour);
+                tracing::info!("Next notification prune run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = PruneNotificationsParams {
+                    retention_days: config.notification_retention_days,
+                };
+                if let Err(e) = prune_notifications_use_case.execute(params).await {
+                    tracing::error!("Notification prune run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Tells users about the products expiring within the next day. Users on
+    /// vacation are skipped by the dispatcher.
+    fn spawn_expiry_alerts(
+        config: SchedulerConfig,
+        send_expiry_alerts_use_case: Arc<dyn SendExpiryAlertsUseCase>,
+    ) {
+        if !config.expiry_alert_enabled {
+            tracing::info!("Expiry alert job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            loop {
+                let wait = duration_until_next_run(Utc::now(), config.expiry_alert_hour);
+                tracing::info!("Next expiry alert run in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+
+                let params = SendExpiryAlertsParams {
+                    batch_size: config.expiry_alert_batch_size,
+                };
+                if let Err(e) = send_expiry_alerts_use_case.execute(params).await {
+                    tracing::error!("Expiry alert run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Checks every few minutes, so reminders go out close to the time the
+    /// user picked.
+    fn spawn_reminder_delivery(
+        config: SchedulerConfig,
+        send_due_reminders_use_case: Arc<dyn SendDueRemindersUseCase>,
+    ) {
+        if !config.reminder_delivery_enabled {
+            tracing::info!("Reminder delivery job disabled");
+            return;
+        }
+
+        tokio::spawn(async move {
+            let period =
+                std::time::Duration::from_secs(config.reminder_delivery_interval_minutes * 60);
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+
+                let params = SendDueRemindersParams {
+                    max_reminders: config.reminder_delivery_max_reminders,
+                };
+                if let Err(e) = send_due_reminders_use_case.execute(params).await {
+                    tracing::error!("Reminder delivery run failed: {e}");
+                }
+            }
+        });
+    }
+
+    /// Resets the demo user right away, so a fresh deployment has data, then
+    /// every night.
+    fn spawn_sandbox_reset(
+        sandbox: SandboxConfig,
+        reset_sandbox_use_case: Arc<dyn ResetSandboxUseCase>,
+    ) {
+        let Some(demo_user_id) = sandbox.demo_user_id else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            loop {
+                let params = ResetSandboxParams {
+                    user_id: UserId::new(demo_user_id.clone()),
+                };
+                if let Err(e) = reset_sandbox_use_case.execute(params).await {
+                    tracing::error!("Sandbox reset failed: {e}");
+                }
+
+                let wait = duration_until_next_run(Utc::now(), sandbox.reset_hour);
+                tracing::info!("Next sandbox reset in {}s", wait.num_seconds());
+                tokio::time::sleep(wait.to_std().unwrap_or_default()).await;
+            }
+        });
+    }
+}
+
+/// Computes how long to wait until the next occurrence of `hour`:00 UTC.
+fn duration_until_next_run(now: DateTime<Utc>, hour: u32) -> Duration {
+    let today_run = now
+        .date_naive()
+        .and_hms_opt(hour, 0, 0)
+        .map(|dt| dt.and_utc())
+        .unwrap_or(now);
+
+    let next_run = if today_run > now {
+        today_run
+    } else {
+        today_run + Duration::days(1)
+    };
+
+    next_run - now
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn should_schedule_later_today_when_hour_not_reached() {
+        // Arrange
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 3, 30, 0).unwrap();
+
+        // Act
+        let wait = duration_until_next_run(now, 5);
+
+        // Assert
+        assert_eq!(wait, Duration::minutes(90));
+    }
+
+    #[test]
+    fn should_schedule_tomorrow_when_hour_already_passed() {
+        // Arrange
+        let now = Utc.with_ymd_and_hms(2026, 3, 10, 6, 0, 0).unwrap();
+
+        // Act
+        let wait = duration_until_next_run(now, 5);
+
+        // Assert
+        assert_eq!(wait, Duration::hours(23));
+    }
+}
@@ -1,6 +1,13 @@
 use poem::{EndpointExt, Route, Server as PoemServer, listener::TcpListener, middleware::Tracing};
 use poem_openapi::OpenApiService;
 
+use crate::api::app_version::MinAppVersion;
+use crate::api::i18n::Localization;
+use crate::api::maintenance::Maintenance;
+use crate::api::payload_limit::PayloadLimit;
+use crate::api::rate_limit::RateLimit;
+use crate::api::security_headers::{JsonContentType, SecurityHeaders};
+use crate::api::traffic_log::TrafficLog;
 use crate::{config::app_config::AppConfig, setup::dependency_injection::DependencyContainer};
 
 pub struct Server;
@@ -11,9 +18,58 @@ impl Server {
         let api_service = OpenApiService::new(
             (
                 container.health_api,
-                container.product_api,
-                container.shopping_item_api,
-                container.suggestion_api,
+                // Grouped so the tuple stays within poem-openapi's 16-element limit
+                (
+                    container.product_api,
+                    container.barcode_contribution_api,
+                    container.ai_review_api,
+                    container.receipt_import_api,
+                    container.reminder_api,
+                    container.give_away_api,
+                    container.duplicate_api,
+                    container.job_api,
+                ),
+                (
+                    container.shopping_item_api,
+                    container.inbound_email_api,
+                    container.search_api,
+                ),
+                container.shopping_trip_api,
+                container.store_profile_api,
+                container.location_rule_api,
+                (
+                    container.preference_api,
+                    container.notification_api,
+                    container.vacation_api,
+                    container.device_api,
+                    container.budget_api,
+                ),
+                (
+                    container.suggestion_api,
+                    container.cooking_session_api,
+                    container.meal_plan_api,
+                ),
+                container.share_link_api,
+                container.shared_view_api,
+                (
+                    container.auth_api,
+                    container.me_api,
+                    container.client_config_api,
+                    container.backup_api,
+                    container.blob_api,
+                ),
+                container.billing_api,
+                (
+                    container.badge_api,
+                    container.stats_api,
+                    container.challenge_api,
+                    container.widget_api,
+                ),
+                (
+                    container.load_test_api,
+                    container.prompt_template_api,
+                    container.experiment_api,
+                ),
             ),
             "Foodie Backend API",
             "0.1.0",
@@ -25,6 +81,14 @@ impl Server {
             .nest("/", api_service)
             .nest("/docs", ui)
             .nest("/openapi.json", spec)
+            .with(TrafficLog::new(config.traffic_log))
+            .with(PayloadLimit::new(config.payload))
+            .with(JsonContentType)
+            .with(RateLimit::new(config.rate_limit))
+            .with(MinAppVersion::new(config.app_version))
+            .with(Maintenance::new(config.maintenance))
+            .with(Localization)
+            .with(SecurityHeaders::new(config.security))
             .with(config.cors)
             .with(Tracing);
         println!("Server running at http://{}", addr);
@@ -0,0 +1,96 @@
+/// Confidence level for AI-based estimations and identifications.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+    None,
+}
+
+impl std::fmt::Display for Confidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Confidence::High => write!(f, "high"),
+            Confidence::Medium => write!(f, "medium"),
+            Confidence::Low => write!(f, "low"),
+            Confidence::None => write!(f, "none"),
+        }
+    }
+}
+
+impl std::str::FromStr for Confidence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(Confidence::High),
+            "medium" => Ok(Confidence::Medium),
+            "low" => Ok(Confidence::Low),
+            "none" => Ok(Confidence::None),
+            _ => Err(format!("Invalid confidence level: {}", s)),
+        }
+    }
+}
+
+/// Confidence level for product identification (high or low).
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdentificationConfidence {
+    High,
+    Low,
+}
+
+impl std::fmt::Display for IdentificationConfidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdentificationConfidence::High => write!(f, "high"),
+            IdentificationConfidence::Low => write!(f, "low"),
+        }
+    }
+}
+
+impl std::str::FromStr for IdentificationConfidence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(IdentificationConfidence::High),
+            "low" => Ok(IdentificationConfidence::Low),
+            _ => Err(format!("Invalid identification confidence: {}", s)),
+        }
+    }
+}
+
+impl From<IdentificationConfidence> for Confidence {
+    fn from(confidence: IdentificationConfidence) -> Self {
+        match confidence {
+            IdentificationConfidence::High => Confidence::High,
+            IdentificationConfidence::Low => Confidence::Low,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_confidence_through_its_code() {
+        for confidence in [
+            Confidence::High,
+            Confidence::Medium,
+            Confidence::Low,
+            Confidence::None,
+        ] {
+            assert_eq!(confidence.to_string().parse(), Ok(confidence));
+        }
+        assert!("certain".parse::<Confidence>().is_err());
+    }
+
+    #[test]
+    fn should_widen_identification_confidence() {
+        assert_eq!(
+            Confidence::from(IdentificationConfidence::Low),
+            Confidence::Low
+        );
+    }
+}
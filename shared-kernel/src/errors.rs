@@ -0,0 +1,23 @@
+use std::error::Error;
+use std::fmt;
+
+/// Underlying cause attached to a domain error: a driver, HTTP or parse
+/// failure from an adapter, or a plain description when there is none.
+pub type ErrorSource = Box<dyn Error + Send + Sync>;
+
+/// Displays an error followed by every cause in its source chain, e.g.
+/// `product.scan_failed: error decoding response body: EOF while parsing`.
+/// Meant for logs; clients only ever see the outermost code.
+pub struct ErrorChain<'a>(pub &'a (dyn Error + 'static));
+
+impl fmt::Display for ErrorChain<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        let mut source = self.0.source();
+        while let Some(cause) = source {
+            write!(f, ": {cause}")?;
+            source = cause.source();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+//! Value objects and conventions shared by every layer and presentation:
+//! user identity, keyset pagination, AI confidence levels and error-chain
+//! helpers. Kept free of business rules so any crate can depend on it.
+
+pub mod confidence;
+pub mod errors;
+pub mod pagination;
+pub mod value_objects;
+
+pub use value_objects::UserId;
@@ -0,0 +1,206 @@
+use std::fmt;
+use std::str::FromStr;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+/// Largest page a listing may request.
+pub const MAX_PAGE_LIMIT: u32 = 100;
+
+/// A cursor string that was not issued by us or was tampered with.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+#[error("pagination.invalid_cursor")]
+pub struct InvalidCursor;
+
+/// Position in a newest-first listing: the `(created_at, id)` of the last
+/// row a client has seen. The id breaks ties between rows created in the
+/// same instant, so no row is skipped or repeated across pages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cursor {
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn new(created_at: DateTime<Utc>, id: Uuid) -> Self {
+        Self { created_at, id }
+    }
+
+    /// Opaque, URL-safe form handed to clients.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}:{}",
+            self.created_at.timestamp_micros(),
+            self.id
+        ))
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, InvalidCursor> {
+        let bytes = URL_SAFE_NO_PAD
+            .decode(encoded.trim())
+            .map_err(|_| InvalidCursor)?;
+        let raw = String::from_utf8(bytes).map_err(|_| InvalidCursor)?;
+        let (micros, id) = raw.split_once(':').ok_or(InvalidCursor)?;
+        let created_at = micros
+            .parse()
+            .ok()
+            .and_then(DateTime::from_timestamp_micros)
+            .ok_or(InvalidCursor)?;
+        let id = Uuid::parse_str(id).map_err(|_| InvalidCursor)?;
+        Ok(Self { created_at, id })
+    }
+}
+
+impl fmt::Display for Cursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+impl FromStr for Cursor {
+    type Err = InvalidCursor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::decode(s)
+    }
+}
+
+impl TryFrom<&str> for Cursor {
+    type Error = InvalidCursor;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::decode(s)
+    }
+}
+
+/// Keyset window over a newest-first listing: up to `limit` rows strictly
+/// older than `after`. Unlike OFFSET, the cost does not grow with depth.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeysetPage {
+    pub limit: u32,
+    pub after: Option<Cursor>,
+}
+
+impl KeysetPage {
+    /// Clamps the limit to `1..=MAX_PAGE_LIMIT`.
+    pub fn new(limit: u32, after: Option<Cursor>) -> Self {
+        Self {
+            limit: limit.clamp(1, MAX_PAGE_LIMIT),
+            after,
+        }
+    }
+
+    /// Builds a page from the raw `limit` and `cursor` a client sent, the
+    /// way every presentation receives them.
+    pub fn from_params(
+        limit: Option<u32>,
+        cursor: Option<&str>,
+        default_limit: u32,
+    ) -> Result<Self, InvalidCursor> {
+        let after = cursor.map(Cursor::decode).transpose()?;
+        Ok(Self::new(limit.unwrap_or(default_limit), after))
+    }
+
+    /// Rows repositories fetch: one more than the limit, to tell whether
+    /// another page follows.
+    pub fn fetch_limit(&self) -> u32 {
+        self.limit + 1
+    }
+}
+
+/// One page of a keyset listing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    /// Pass back to get the next page; `None` on the last page
+    pub next_cursor: Option<Cursor>,
+}
+
+impl<T> CursorPage<T> {
+    /// Builds a page from rows fetched with [`KeysetPage::fetch_limit`].
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        page: &KeysetPage,
+        cursor_of: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let has_more = rows.len() > page.limit as usize;
+        rows.truncate(page.limit as usize);
+        let next_cursor = if has_more {
+            rows.last().map(cursor_of)
+        } else {
+            None
+        };
+        Self {
+            items: rows,
+            next_cursor,
+        }
+    }
+
+    /// Converts the items, e.g. into a presentation DTO, keeping the cursor.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> CursorPage<U> {
+        CursorPage {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_cursor_through_opaque_string() {
+        let cursor = Cursor::new(
+            DateTime::from_timestamp_micros(1_760_000_000_123_456).unwrap(),
+            Uuid::new_v4(),
+        );
+
+        let encoded = cursor.encode();
+
+        assert!(!encoded.contains(':'));
+        assert_eq!(Cursor::decode(&encoded), Ok(cursor));
+    }
+
+    #[test]
+    fn should_reject_malformed_cursors() {
+        assert_eq!(Cursor::decode("not a cursor"), Err(InvalidCursor));
+        assert_eq!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode("123:not-a-uuid")),
+            Err(InvalidCursor)
+        );
+    }
+
+    #[test]
+    fn should_build_page_from_client_params() {
+        let cursor = Cursor::new(Utc::now(), Uuid::new_v4());
+
+        let page = KeysetPage::from_params(None, Some(&cursor.to_string()), 20).unwrap();
+
+        assert_eq!(page.limit, 20);
+        assert_eq!(page.after.map(|c| c.id), Some(cursor.id));
+        assert_eq!(
+            KeysetPage::from_params(Some(500), None, 20).map(|p| p.limit),
+            Ok(MAX_PAGE_LIMIT)
+        );
+        assert_eq!(
+            KeysetPage::from_params(None, Some("garbage"), 20),
+            Err(InvalidCursor)
+        );
+    }
+
+    #[test]
+    fn should_set_next_cursor_only_when_more_rows_exist() {
+        let page = KeysetPage::new(2, None);
+        let now = Utc::now();
+        let rows: Vec<Cursor> = (0..3).map(|_| Cursor::new(now, Uuid::new_v4())).collect();
+
+        let full = CursorPage::from_rows(rows.clone(), &page, |c| *c);
+        let last = CursorPage::from_rows(rows[..2].to_vec(), &page, |c| *c);
+
+        assert_eq!(full.items.len(), 2);
+        assert_eq!(full.next_cursor, Some(rows[1]));
+        assert_eq!(last.next_cursor, None);
+    }
+}
@@ -35,6 +35,18 @@ impl From<&str> for UserId {
     }
 }
 
+impl From<UserId> for String {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+
+impl AsRef<str> for UserId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,4 +97,10 @@ mod tests {
         let user_id: UserId = "from-str".into();
         assert_eq!(user_id.as_str(), "from-str");
     }
+
+    #[test]
+    fn should_convert_back_into_string() {
+        let raw: String = UserId::new("round-trip").into();
+        assert_eq!(raw, "round-trip");
+    }
 }